@@ -5,7 +5,7 @@
 
 use arrow::array::RecordBatch;
 use arrow::pyarrow::FromPyArrow;
-use contracts_core::{Contract, ValidationContext, ValidationReport};
+use contracts_core::{CheckKind, Contract, ValidationContext, ValidationReport, Violation};
 use contracts_parser::{parse_toml, parse_yaml};
 use contracts_validator::{DataSet, DataValidator, DataValue};
 use pyo3::exceptions::PyValueError;
@@ -85,7 +85,7 @@ fn arrow_col_to_data_value(array: &dyn arrow::array::Array, idx: usize) -> DataV
         }
         DataType::UInt64 => {
             let a = array.as_any().downcast_ref::<UInt64Array>().unwrap();
-            DataValue::Int(a.value(idx) as i64)
+            DataValue::UInt(a.value(idx))
         }
         DataType::Float32 => {
             let a = array.as_any().downcast_ref::<Float32Array>().unwrap();
@@ -95,6 +95,13 @@ fn arrow_col_to_data_value(array: &dyn arrow::array::Array, idx: usize) -> DataV
             let a = array.as_any().downcast_ref::<Float64Array>().unwrap();
             DataValue::Float(a.value(idx))
         }
+        DataType::Decimal128(_, scale) => {
+            let a = array.as_any().downcast_ref::<Decimal128Array>().unwrap();
+            DataValue::Decimal {
+                value: a.value(idx),
+                scale: *scale,
+            }
+        }
         DataType::Utf8 => {
             let a = array.as_any().downcast_ref::<StringArray>().unwrap();
             DataValue::String(a.value(idx).to_owned())
@@ -135,19 +142,31 @@ fn arrow_col_to_data_value(array: &dyn arrow::array::Array, idx: usize) -> DataV
         }
         DataType::Date32 => {
             let a = array.as_any().downcast_ref::<Date32Array>().unwrap();
-            DataValue::Timestamp(
-                a.value_as_date(idx)
-                    .map(|d| d.to_string())
-                    .unwrap_or_default(),
-            )
+            a.value_as_date(idx)
+                .map(DataValue::Date)
+                .unwrap_or(DataValue::Null)
         }
         DataType::Date64 => {
             let a = array.as_any().downcast_ref::<Date64Array>().unwrap();
-            DataValue::Timestamp(
-                a.value_as_datetime(idx)
-                    .map(|d| d.to_string())
-                    .unwrap_or_default(),
-            )
+            a.value_as_date(idx)
+                .map(DataValue::Date)
+                .unwrap_or(DataValue::Null)
+        }
+        DataType::Time32(_) | DataType::Time64(_) => {
+            let a = array.as_any();
+            if let Some(arr) = a.downcast_ref::<Time32SecondArray>() {
+                arr.value_as_time(idx)
+            } else if let Some(arr) = a.downcast_ref::<Time32MillisecondArray>() {
+                arr.value_as_time(idx)
+            } else if let Some(arr) = a.downcast_ref::<Time64MicrosecondArray>() {
+                arr.value_as_time(idx)
+            } else if let Some(arr) = a.downcast_ref::<Time64NanosecondArray>() {
+                arr.value_as_time(idx)
+            } else {
+                None
+            }
+            .map(DataValue::Time)
+            .unwrap_or(DataValue::Null)
         }
         _ => {
             // Fallback: represent unknown types as Null
@@ -157,13 +176,21 @@ fn arrow_col_to_data_value(array: &dyn arrow::array::Array, idx: usize) -> DataV
 }
 
 /// Build a ValidationContext from Python keyword arguments.
-fn build_context(strict: bool, schema_only: bool, sample_size: Option<usize>) -> ValidationContext {
+fn build_context(
+    strict: bool,
+    schema_only: bool,
+    sample_size: Option<usize>,
+    segment_by: Option<String>,
+) -> ValidationContext {
     let mut ctx = ValidationContext::new()
         .with_strict(strict)
         .with_schema_only(schema_only);
     if let Some(s) = sample_size {
         ctx = ctx.with_sample_size(s);
     }
+    if let Some(column) = segment_by {
+        ctx = ctx.with_segment_by(column);
+    }
     ctx
 }
 
@@ -174,8 +201,41 @@ fn report_to_pydict<'py>(
 ) -> PyResult<Bound<'py, PyDict>> {
     let dict = PyDict::new(py);
     dict.set_item("passed", report.passed)?;
-    dict.set_item("errors", &report.errors)?;
-    dict.set_item("warnings", &report.warnings)?;
+    dict.set_item("errors", messages(&report.errors))?;
+    dict.set_item("warnings", messages(&report.warnings))?;
+    dict.set_item("info", messages(&report.info))?;
+    dict.set_item("inconclusive", messages(&report.inconclusive))?;
+
+    let violations = report
+        .violations()
+        .map(|violation| {
+            let entry = PyDict::new(py);
+            entry.set_item("code", violation.code.code)?;
+            entry.set_item("field", violation.field.as_deref())?;
+            entry.set_item("row_index", violation.row_index)?;
+            entry.set_item("check_kind", violation.check_kind.as_str())?;
+            entry.set_item("message", &violation.message)?;
+            entry.set_item("severity", violation.severity.as_str())?;
+            // Rows captured by custom checks, as plain dicts
+            let rows = serde_json::to_string(&violation.rows)
+                .map_err(|e| PyValueError::new_err(e.to_string()))?;
+            entry.set_item("rows", py.import("json")?.call_method1("loads", (rows,))?)?;
+            Ok(entry)
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    dict.set_item("violations", violations)?;
+
+    let segments = report
+        .segments
+        .iter()
+        .map(|segment| {
+            let entry = report_to_pydict(py, &segment.report)?;
+            entry.set_item("column", &segment.column)?;
+            entry.set_item("value", &segment.value)?;
+            Ok(entry)
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    dict.set_item("segments", segments)?;
 
     let stats = PyDict::new(py);
     stats.set_item("records_validated", report.stats.records_validated)?;
@@ -187,82 +247,45 @@ fn report_to_pydict<'py>(
     // Per-category breakdown of errors/warnings
     let checks = PyDict::new(py);
 
-    let schema_errors: Vec<&String> = report
-        .errors
-        .iter()
-        .filter(|e| {
-            e.contains("is null but nullability")
-                || e.contains("Missing required field")
-                || e.contains("Type mismatch")
-                || e.contains("not found in data")
-        })
-        .collect();
+    let errors_of = |kinds: &[CheckKind]| {
+        messages(
+            report
+                .errors
+                .iter()
+                .filter(|v| kinds.contains(&v.check_kind)),
+        )
+    };
+    let issues_of = |kinds: &[CheckKind]| {
+        messages(
+            report
+                .errors
+                .iter()
+                .chain(report.warnings.iter())
+                .filter(|v| kinds.contains(&v.check_kind)),
+        )
+    };
+
     let schema_dict = PyDict::new(py);
-    schema_dict.set_item(
-        "errors",
-        schema_errors.iter().map(|s| s.as_str()).collect::<Vec<_>>(),
-    )?;
+    schema_dict.set_item("errors", errors_of(&[CheckKind::Schema]))?;
     checks.set_item("schema", schema_dict)?;
 
-    let constraint_errors: Vec<&String> = report
-        .errors
-        .iter()
-        .filter(|e| e.contains("Constraint violation"))
-        .collect();
     let constraint_dict = PyDict::new(py);
-    constraint_dict.set_item(
-        "errors",
-        constraint_errors
-            .iter()
-            .map(|s| s.as_str())
-            .collect::<Vec<_>>(),
-    )?;
+    constraint_dict.set_item("errors", errors_of(&[CheckKind::Constraint]))?;
     checks.set_item("constraints", constraint_dict)?;
 
-    let quality_items: Vec<&String> = report
-        .errors
-        .iter()
-        .chain(report.warnings.iter())
-        .filter(|e| e.contains("Quality check failed"))
-        .collect();
     let quality_dict = PyDict::new(py);
     quality_dict.set_item(
         "issues",
-        quality_items.iter().map(|s| s.as_str()).collect::<Vec<_>>(),
+        issues_of(&[CheckKind::Quality, CheckKind::Freshness]),
     )?;
     checks.set_item("quality", quality_dict)?;
 
-    let ml_items: Vec<&String> = report
-        .errors
-        .iter()
-        .chain(report.warnings.iter())
-        .filter(|e| {
-            e.contains("NoOverlap")
-                || e.contains("TemporalSplit")
-                || e.contains("ClassBalance")
-                || e.contains("FeatureDrift")
-                || e.contains("TargetLeakage")
-                || e.contains("NullRateByGroup")
-        })
-        .collect();
     let ml_dict = PyDict::new(py);
-    ml_dict.set_item(
-        "issues",
-        ml_items.iter().map(|s| s.as_str()).collect::<Vec<_>>(),
-    )?;
+    ml_dict.set_item("issues", issues_of(&[CheckKind::Ml]))?;
     checks.set_item("ml", ml_dict)?;
 
-    let custom_items: Vec<&String> = report
-        .errors
-        .iter()
-        .chain(report.warnings.iter())
-        .filter(|e| e.contains("Custom check") || e.contains("custom_check"))
-        .collect();
     let custom_dict = PyDict::new(py);
-    custom_dict.set_item(
-        "issues",
-        custom_items.iter().map(|s| s.as_str()).collect::<Vec<_>>(),
-    )?;
+    custom_dict.set_item("issues", issues_of(&[CheckKind::Custom]))?;
     checks.set_item("custom", custom_dict)?;
 
     dict.set_item("checks", checks)?;
@@ -270,6 +293,11 @@ fn report_to_pydict<'py>(
     Ok(dict)
 }
 
+/// Renders violations as `[code] message` strings.
+fn messages<'a>(violations: impl IntoIterator<Item = &'a Violation>) -> Vec<String> {
+    violations.into_iter().map(|v| v.to_string()).collect()
+}
+
 /// Parse a contract from YAML or TOML, auto-detecting format.
 fn parse_contract(source: &str) -> PyResult<Contract> {
     // Try YAML first, fall back to TOML
@@ -316,7 +344,7 @@ fn validate_contract<'py>(py: Python<'py>, contract_yaml: &str) -> PyResult<Boun
 ///
 /// Uses the async DataFusion path for full custom SQL execution.
 #[pyfunction]
-#[pyo3(signature = (contract_yaml, batch, strict=false, schema_only=false, sample_size=None))]
+#[pyo3(signature = (contract_yaml, batch, strict=false, schema_only=false, sample_size=None, segment_by=None))]
 fn validate_batch<'py>(
     py: Python<'py>,
     contract_yaml: &str,
@@ -324,10 +352,11 @@ fn validate_batch<'py>(
     strict: bool,
     schema_only: bool,
     sample_size: Option<usize>,
+    segment_by: Option<String>,
 ) -> PyResult<Bound<'py, PyDict>> {
     let contract = parse_contract(contract_yaml)?;
     let dataset = batch_to_dataset(&batch)?;
-    let ctx = build_context(strict, schema_only, sample_size);
+    let ctx = build_context(strict, schema_only, sample_size, segment_by);
     let mut validator = DataValidator::new();
 
     let report =
@@ -340,7 +369,7 @@ fn validate_batch<'py>(
 ///
 /// Uses the async DataFusion path for full custom SQL execution.
 #[pyfunction]
-#[pyo3(signature = (contract_yaml, batches, strict=false, schema_only=false, sample_size=None))]
+#[pyo3(signature = (contract_yaml, batches, strict=false, schema_only=false, sample_size=None, segment_by=None))]
 fn validate_batches<'py>(
     py: Python<'py>,
     contract_yaml: &str,
@@ -348,6 +377,7 @@ fn validate_batches<'py>(
     strict: bool,
     schema_only: bool,
     sample_size: Option<usize>,
+    segment_by: Option<String>,
 ) -> PyResult<Bound<'py, PyDict>> {
     let contract = parse_contract(contract_yaml)?;
 
@@ -359,7 +389,7 @@ fn validate_batches<'py>(
         }
     }
     let dataset = DataSet::from_rows(all_rows);
-    let ctx = build_context(strict, schema_only, sample_size);
+    let ctx = build_context(strict, schema_only, sample_size, segment_by);
     let mut validator = DataValidator::new();
 
     let report =
@@ -446,6 +476,17 @@ fn profile_batch<'py>(py: Python<'py>, batch: Bound<'_, PyAny>) -> PyResult<Boun
                     unique_values.insert(format!("i:{i}"));
                     numeric_values.push(*i as f64);
                 }
+                DataValue::UInt(u) => {
+                    unique_values.insert(format!("u:{u}"));
+                    numeric_values.push(*u as f64);
+                }
+                DataValue::Decimal { value, scale } => {
+                    unique_values.insert(format!(
+                        "d:{}",
+                        DataValue::decimal_to_string(*value, *scale)
+                    ));
+                    numeric_values.extend(val.as_float());
+                }
                 DataValue::Float(f) => {
                     unique_values.insert(format!("f:{f}"));
                     numeric_values.push(*f);
@@ -459,6 +500,12 @@ fn profile_batch<'py>(py: Python<'py>, batch: Bound<'_, PyAny>) -> PyResult<Boun
                 DataValue::Timestamp(t) => {
                     unique_values.insert(format!("t:{t}"));
                 }
+                DataValue::Date(d) => {
+                    unique_values.insert(format!("t:{d}"));
+                }
+                DataValue::Time(t) => {
+                    unique_values.insert(format!("t:{t}"));
+                }
                 DataValue::Map(_) | DataValue::List(_) => {
                     unique_values.insert(format!("c:{:?}", val));
                 }
@@ -0,0 +1,54 @@
+use anyhow::{Context, Result};
+use colored::*;
+use contracts_core::declared_access;
+use contracts_iceberg::access_properties;
+use contracts_parser::parse_file;
+use std::path::Path;
+
+use crate::i18n::{Msg, tr};
+use crate::output;
+
+/// Exports the access metadata declared by the `access:` blocks of a
+/// contract's fields.
+///
+/// `json` is meant for governance tools, `properties` lists the Iceberg table
+/// properties that `dce drift` compares the contract with.
+pub fn execute(contract_path: &str, format: &str) -> Result<()> {
+    let contract = parse_file(Path::new(contract_path))
+        .with_context(|| format!("Failed to parse contract file: {}", contract_path))?;
+    let access = declared_access(&contract.schema);
+
+    match format {
+        "json" => {
+            let document = serde_json::json!({
+                "contract": contract.name,
+                "version": contract.version,
+                "location": contract.schema.location,
+                "fields": access,
+            });
+            println!("{}", serde_json::to_string_pretty(&document)?);
+        }
+        "properties" => {
+            for (key, value) in access_properties(&contract.schema) {
+                println!("{}={}", key, value);
+            }
+        }
+        _ => {
+            if access.is_empty() {
+                output::print_info(tr(Msg::AccessNone));
+                return Ok(());
+            }
+            let width = access.keys().map(String::len).max().unwrap_or(0);
+            for (field, access) in access {
+                println!(
+                    "  {:<width$}  {:<16} {}",
+                    field.bold(),
+                    access.classification.unwrap_or_default(),
+                    access.allowed_roles.join(", "),
+                    width = width
+                );
+            }
+        }
+    }
+    Ok(())
+}
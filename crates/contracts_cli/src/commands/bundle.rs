@@ -0,0 +1,69 @@
+use anyhow::{Context, Result, bail};
+use contracts_parser::ContractBundle;
+use contracts_validator::DataValidator;
+use std::path::{Path, PathBuf};
+
+use crate::i18n::{Msg, trf};
+use crate::output;
+
+/// Packages a contract, its resolved form and the `includes` into a tar
+/// archive holding an OCI image layout, and prints the bundle digest.
+///
+/// The archive is written to `output`, or to `<name>-<version>.tar` in the
+/// working directory. Contracts with definition errors are not bundled.
+pub fn execute(contract_path: &str, includes: &[String], output: Option<&str>) -> Result<()> {
+    let bundle = build(contract_path, includes)?;
+    let path = output.map_or_else(
+        || {
+            PathBuf::from(format!(
+                "{}-{}.tar",
+                bundle.contract.name, bundle.contract.version
+            ))
+        },
+        PathBuf::from,
+    );
+    let (archive, digest) = bundle.to_tar();
+    std::fs::write(&path, archive)
+        .with_context(|| format!("Failed to write bundle: {}", path.display()))?;
+
+    output::print_success(&trf(
+        Msg::BundleWritten,
+        &[
+            &bundle.contract.name,
+            &bundle.contract.version,
+            &path.display(),
+        ],
+    ));
+    output::print_info(&trf(Msg::BundleDigest, &[&digest]));
+    Ok(())
+}
+
+/// Bundles the contract at `contract_path` with the `includes`, refusing
+/// contracts with definition errors.
+pub fn build(contract_path: &str, includes: &[String]) -> Result<ContractBundle> {
+    let mut bundle = ContractBundle::from_file(Path::new(contract_path))
+        .with_context(|| format!("Failed to bundle contract file: {}", contract_path))?;
+    let contract = &bundle.contract;
+
+    let definition = DataValidator::new().validate_definition(contract);
+    if !definition.passed {
+        for error in &definition.errors {
+            output::print_error(&error.to_string());
+        }
+        bail!(
+            "{}",
+            trf(
+                Msg::BundleInvalidContract,
+                &[&contract.name, &definition.errors.len()]
+            )
+        );
+    }
+
+    for include in includes {
+        bundle
+            .include(Path::new(include))
+            .with_context(|| format!("Failed to include {}", include))?;
+    }
+
+    Ok(bundle)
+}
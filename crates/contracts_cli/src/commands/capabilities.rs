@@ -0,0 +1,44 @@
+use anyhow::Result;
+use contracts_core::{Capabilities, DataFormat};
+use contracts_iceberg::supported_catalogs;
+
+/// Formats `dce validate` reads data from; the others are checked against
+/// their schema only.
+const FORMATS: [DataFormat; 4] = [
+    DataFormat::Iceberg,
+    DataFormat::Parquet,
+    DataFormat::Csv,
+    DataFormat::Json,
+];
+
+/// Formats of `--format` and of `--output` report files.
+const OUTPUT_FORMATS: [&str; 4] = ["text", "json", "html", "junit"];
+
+/// Returns what this build of `dce` supports.
+pub fn capabilities() -> Capabilities {
+    let mut capabilities = Capabilities::new(env!("CARGO_PKG_VERSION"));
+    capabilities.formats = FORMATS
+        .iter()
+        .map(|format| format!("{:?}", format).to_lowercase())
+        .collect();
+    capabilities.catalogs = supported_catalogs().into_iter().map(String::from).collect();
+    capabilities.output_formats = OUTPUT_FORMATS.into_iter().map(String::from).collect();
+    capabilities.features = [
+        ("rest-catalog", cfg!(feature = "rest-catalog")),
+        ("glue-catalog", cfg!(feature = "glue-catalog")),
+        ("hms-catalog", cfg!(feature = "hms-catalog")),
+        ("statsd", cfg!(feature = "statsd")),
+        ("postgres", cfg!(feature = "postgres")),
+    ]
+    .into_iter()
+    .filter(|(_, enabled)| *enabled)
+    .map(|(feature, _)| feature.to_string())
+    .collect();
+    capabilities
+}
+
+/// Prints the capabilities of this build as JSON.
+pub fn execute() -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(&capabilities())?);
+    Ok(())
+}
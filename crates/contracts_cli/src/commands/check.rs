@@ -1,38 +1,115 @@
-use anyhow::{Context, Result};
-use contracts_parser::parse_file;
-use std::path::Path;
+use anyhow::{Context, Result, bail};
+use contracts_core::{CheckKind, Violation, codes};
+use contracts_parser::{
+    CONFIG_NAME, DescriptionLintConfig, WorkspaceConfig, parse_file_with_provenance,
+};
+use contracts_validator::{
+    ContractLinter, DESCRIPTION_RULES, DataValidator, DescriptionRules, LintFinding, LintSeverity,
+};
+use std::path::{Path, PathBuf};
 use tracing::info;
 
+use crate::commands::lint::{contract_files, lint_file, print_finding};
+use crate::i18n::{Msg, tr, trf};
 use crate::output;
+use crate::report;
 
-pub async fn execute(contract_path: &str, _format: &str) -> Result<()> {
+pub async fn execute(
+    contract_path: &str,
+    format: &str,
+    output_file: Option<&Path>,
+    extensions_schema: Option<&Path>,
+) -> Result<()> {
     info!("Checking contract schema: {}", contract_path);
 
-    // Parse the contract file
+    let mut linter = ContractLinter::new();
+    if let Some(schema_path) = extensions_schema {
+        linter = load_extensions_schema(linter, schema_path)?;
+    }
+    let workspace = WorkspaceConfig::load(Path::new(CONFIG_NAME))?;
+    linter = workspace_rules(linter, &workspace)?;
+
     let path = Path::new(contract_path);
-    let contract = parse_file(path)
+    if path.is_dir() {
+        if output_file.is_some() {
+            bail!("{}", tr(Msg::OutputWithDirectory));
+        }
+        let files = contract_files(path, &workspace)?;
+        return check_directory(&files, format, &linter);
+    }
+
+    // Parse the contract file
+    let (contract, provenance) = parse_file_with_provenance(path)
         .with_context(|| format!("Failed to parse contract file: {}", contract_path))?;
 
-    output::print_info(&format!(
-        "Contract loaded: {} v{} (owner: {})",
-        contract.name, contract.version, contract.owner
+    if format == "json" && output_file.is_none() {
+        return print_json(&contract, &linter);
+    }
+
+    // Only the extensions rule is enforced outside JSON mode, and only the
+    // opt-in description rules are shown; the other lint rules stay advisory
+    // there as before.
+    let findings = linter.lint(&contract);
+    let extension_findings: Vec<&LintFinding> = findings
+        .iter()
+        .filter(|f| f.rule == "extensions-schema")
+        .collect();
+    let description_findings: Vec<&LintFinding> = findings
+        .iter()
+        .filter(|f| DESCRIPTION_RULES.contains(&f.rule))
+        .collect();
+
+    output::print_info(&trf(
+        Msg::ContractLoaded,
+        &[&contract.name, &contract.version, &contract.owner],
     ));
 
+    // With --output, write the definition report and print only its summary
+    if let Some(output_file) = output_file {
+        let mut report = DataValidator::new()
+            .validate_definition(&contract)
+            .with_provenance(provenance);
+        for finding in &extension_findings {
+            report.add_error(Violation::new(
+                &codes::INVALID_EXTENSIONS,
+                CheckKind::Definition,
+                format!("{}: {}", finding.path, finding.message),
+            ));
+        }
+        for finding in &description_findings {
+            report.add_warning(Violation::new(
+                &codes::WEAK_DESCRIPTION,
+                CheckKind::Definition,
+                format!("{}: {}", finding.path, finding.message),
+            ));
+        }
+        report::write_report(&report, &contract.name, output_file)?;
+        output::print_info(&trf(Msg::ReportWritten, &[&output_file.display()]));
+        output::print_summary_line(&report);
+        if !report.passed {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
     // Contract parsed successfully means schema is valid
-    output::print_success("Contract schema is valid");
+    output::print_success(tr(Msg::ContractSchemaValid));
 
     // Print contract summary
-    println!("\nContract Summary:");
-    println!("  Name:        {}", contract.name);
-    println!("  Version:     {}", contract.version);
-    println!("  Owner:       {}", contract.owner);
-    println!(
-        "  Description: {}",
-        contract.description.as_deref().unwrap_or("N/A")
+    println!("\n{}", tr(Msg::ContractSummary));
+    print_row(Msg::LabelName, &contract.name);
+    print_row(Msg::LabelVersion, &contract.version);
+    print_row(Msg::LabelOwner, &contract.owner);
+    print_row(
+        Msg::LabelDescription,
+        contract
+            .description
+            .as_deref()
+            .unwrap_or(tr(Msg::NotAvailable)),
     );
-    println!("  Format:      {:?}", contract.schema.format);
-    println!("  Location:    {}", contract.schema.location);
-    println!("  Fields:      {}", contract.schema.fields.len());
+    print_row(Msg::LabelFormat, format!("{:?}", contract.schema.format));
+    print_row(Msg::LabelLocation, &contract.schema.location);
+    print_row(Msg::LabelFields, contract.schema.fields.len());
 
     if let Some(qc) = &contract.quality_checks {
         let mut checks = Vec::new();
@@ -50,18 +127,182 @@ pub async fn execute(contract_path: &str, _format: &str) -> Result<()> {
         {
             checks.push(format!("{} custom", custom.len()));
         }
-        println!("  Quality Checks: {}", checks.join(", "));
+        if let Some(rows) = &qc.row_checks
+            && !rows.is_empty()
+        {
+            checks.push(format!("{} row", rows.len()));
+        }
+        print_row(Msg::LabelQualityChecks, checks.join(", "));
     }
 
     if let Some(sla) = &contract.sla {
-        println!("\nSLA:");
+        println!("\n{}:", tr(Msg::LabelSla));
         if let Some(avail) = sla.availability {
-            println!("  Availability:   {}", avail);
+            print_row(Msg::LabelAvailability, avail);
         }
         if let Some(rt) = &sla.response_time {
-            println!("  Response Time:  {}", rt);
+            print_row(Msg::LabelResponseTime, rt);
+        }
+    }
+
+    if workspace.lint.descriptions.is_some() {
+        println!();
+        if description_findings.is_empty() {
+            output::print_success(tr(Msg::DescriptionsInformative));
+        }
+        for finding in &description_findings {
+            output::print_warning(
+                &codes::WEAK_DESCRIPTION.tag(format!("{}: {}", finding.path, finding.message)),
+            );
+        }
+    }
+
+    if extensions_schema.is_some() {
+        println!();
+        if extension_findings.is_empty() {
+            output::print_success(tr(Msg::ExtensionsMatchSchema));
+        } else {
+            for finding in &extension_findings {
+                output::print_error(
+                    &codes::INVALID_EXTENSIONS
+                        .tag(format!("{}: {}", finding.path, finding.message)),
+                );
+            }
+            std::process::exit(1);
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks every contract of `files` and prints one result per contract,
+/// followed by a summary.
+///
+/// A contract fails when it does not parse or a lint rule reports an error,
+/// as with `--format json` for a single contract. Exits with status 1 when
+/// any contract fails.
+fn check_directory(files: &[PathBuf], format: &str, linter: &ContractLinter) -> Result<()> {
+    let checked: Vec<_> = files
+        .iter()
+        .map(|file| {
+            let (contract, findings) = lint_file(linter, file);
+            let valid = findings.iter().all(|f| f.severity != LintSeverity::Error);
+            (file, contract, findings, valid)
+        })
+        .collect();
+    let failed = checked.iter().filter(|(_, _, _, valid)| !valid).count();
+
+    if format == "json" {
+        let contracts: Vec<_> = checked
+            .iter()
+            .map(|(file, contract, findings, valid)| {
+                serde_json::json!({
+                    "path": file.display().to_string(),
+                    "valid": valid,
+                    "contract": contract.as_ref().map(|contract| contract.normalized()),
+                    "findings": findings,
+                })
+            })
+            .collect();
+        let document = serde_json::json!({
+            "valid": failed == 0,
+            "total": checked.len(),
+            "failed": failed,
+            "contracts": contracts,
+        });
+        println!("{}", serde_json::to_string_pretty(&document)?);
+    } else {
+        for (file, contract, findings, valid) in &checked {
+            if let (Some(contract), true) = (contract, valid) {
+                output::print_success(&format!(
+                    "{}: {} {}",
+                    file.display(),
+                    contract.name,
+                    contract.version
+                ));
+            }
+            for finding in findings {
+                print_finding(file, finding);
+            }
+        }
+        println!();
+        let summary = trf(
+            Msg::CheckDirectorySummary,
+            &[&checked.len(), &(checked.len() - failed), &failed],
+        );
+        if failed > 0 {
+            output::print_error(&summary);
+        } else {
+            output::print_success(&summary);
         }
     }
 
+    if failed > 0 {
+        std::process::exit(1);
+    }
     Ok(())
 }
+
+/// Adds the optional rules enabled under `[lint]` in `dce.toml`.
+pub fn workspace_rules(
+    mut linter: ContractLinter,
+    workspace: &WorkspaceConfig,
+) -> Result<ContractLinter> {
+    if let Some(config) = &workspace.lint.descriptions {
+        linter = linter.with_description_rules(description_rules(config));
+    }
+    if let Some(taxonomy) = workspace.tag_taxonomy().with_context(|| {
+        format!(
+            "Failed to read tag taxonomy: {}",
+            workspace.lint.tag_taxonomy.as_deref().unwrap_or_default()
+        )
+    })? {
+        linter = linter.with_tag_taxonomy(taxonomy);
+    }
+    Ok(linter)
+}
+
+/// Applies the overrides of `[lint.descriptions]` to the default rules.
+fn description_rules(config: &DescriptionLintConfig) -> DescriptionRules {
+    let defaults = DescriptionRules::default();
+    DescriptionRules {
+        min_length: config.min_length.unwrap_or(defaults.min_length),
+        placeholders: config.placeholders.clone().unwrap_or(defaults.placeholders),
+        units: config.units.clone().unwrap_or(defaults.units),
+        require_units: config.require_units.unwrap_or(defaults.require_units),
+    }
+}
+
+/// Adds the JSON Schema read from `path` to the linter's extension checks.
+fn load_extensions_schema(linter: ContractLinter, path: &Path) -> Result<ContractLinter> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read extensions schema: {}", path.display()))?;
+    let schema: serde_json::Value = serde_json::from_str(&content)
+        .with_context(|| format!("Extensions schema is not valid JSON: {}", path.display()))?;
+    Ok(linter.with_extensions_schema(&schema)?)
+}
+
+/// Prints the normalized contract and its lint findings as one JSON document.
+///
+/// Exits with status 1 when any finding is an error.
+fn print_json(contract: &contracts_core::Contract, linter: &ContractLinter) -> Result<()> {
+    let findings = linter.lint(contract);
+    let valid = findings.iter().all(|f| f.severity != LintSeverity::Error);
+
+    let document = serde_json::json!({
+        "valid": valid,
+        "contract": contract.normalized(),
+        "findings": findings,
+    });
+    println!("{}", serde_json::to_string_pretty(&document)?);
+
+    if !valid {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Prints an aligned `label: value` line of the contract summary.
+fn print_row(label: Msg, value: impl std::fmt::Display) {
+    println!("  {:<14} {}", format!("{}:", tr(label)), value);
+}
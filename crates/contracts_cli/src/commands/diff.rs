@@ -0,0 +1,144 @@
+use anyhow::{Context, Result};
+use colored::*;
+use contracts_core::{
+    CheckKind, ContractDiff, ContractError, Severity, ValidationReport, VersionBump, Violation,
+    codes,
+};
+use contracts_parser::parse_file;
+use std::path::Path;
+use tracing::info;
+
+use crate::i18n::{Msg, tr, trf};
+use crate::output;
+use crate::report;
+
+/// Compares two versions of a contract and lists the schema changes, with a
+/// warning when the new version does not make the increment they call for.
+///
+/// Exits with status 1 when any change is breaking, so the command can gate
+/// contract changes in CI. With `output_file`, the changes are written as a
+/// report instead and only its summary is printed.
+pub fn execute(
+    old_path: &str,
+    new_path: &str,
+    format: &str,
+    output_file: Option<&Path>,
+) -> Result<()> {
+    info!("Comparing contract {} with {}", old_path, new_path);
+
+    let old = parse_file(Path::new(old_path))
+        .with_context(|| format!("Failed to parse contract file: {}", old_path))?;
+    let new = parse_file(Path::new(new_path))
+        .with_context(|| format!("Failed to parse contract file: {}", new_path))?;
+    let diff = ContractDiff::compare(&old, &new);
+    let required_bump = new.required_bump(&old);
+    // Unparsable versions are reported by `dce check`
+    let bumped = new.check_version_bump(&old);
+
+    let not_bumped = matches!(bumped, Err(ContractError::VersionMismatch { .. })).then(|| {
+        trf(
+            Msg::DiffVersionNotBumped,
+            &[&new.version, &required_bump, &old.version],
+        )
+    });
+
+    if let Some(output_file) = output_file {
+        let report = diff_report(&diff, not_bumped.as_deref());
+        report::write_report(&report, &new.name, output_file)?;
+        output::print_info(&trf(Msg::ReportWritten, &[&output_file.display()]));
+        output::print_summary_line(&report);
+    } else if format == "json" {
+        print_json(&diff, required_bump, bumped.is_ok())?;
+    } else {
+        print_text(&diff);
+        if let Some(warning) = &not_bumped {
+            output::print_warning(warning);
+        }
+    }
+
+    if diff.is_breaking() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn print_text(diff: &ContractDiff) {
+    if diff.is_empty() {
+        output::print_success(tr(Msg::DiffNoChanges));
+        return;
+    }
+
+    for change in &diff.changes {
+        let label = if change.is_breaking() {
+            tr(Msg::DiffBreaking).red().bold()
+        } else {
+            tr(Msg::DiffCompatible).green()
+        };
+        println!("  {:<12} {}", label, change);
+    }
+
+    let breaking = diff.breaking().count();
+    println!();
+    let summary = trf(Msg::DiffSummary, &[&diff.changes.len(), &breaking]);
+    if breaking > 0 {
+        output::print_error(&summary);
+    } else {
+        output::print_success(&summary);
+    }
+}
+
+/// Prints the changes as JSON, each with its `breaking` flag and message,
+/// with the version increment they call for and whether the new version
+/// makes it.
+fn print_json(diff: &ContractDiff, required_bump: VersionBump, bumped: bool) -> Result<()> {
+    let changes = diff
+        .changes
+        .iter()
+        .map(|change| {
+            let mut value = serde_json::to_value(change)?;
+            value["breaking"] = change.is_breaking().into();
+            value["message"] = change.to_string().into();
+            Ok(value)
+        })
+        .collect::<Result<Vec<_>, serde_json::Error>>()?;
+
+    let document = serde_json::json!({
+        "breaking": diff.is_breaking(),
+        "required_bump": required_bump,
+        "version_bumped": bumped,
+        "changes": changes,
+    });
+    println!("{}", serde_json::to_string_pretty(&document)?);
+    Ok(())
+}
+
+/// Builds a report of the changes: breaking changes are errors, compatible
+/// ones info, and a missing version increment a warning.
+fn diff_report(diff: &ContractDiff, not_bumped: Option<&str>) -> ValidationReport {
+    let mut report = ValidationReport::success();
+    for change in &diff.changes {
+        let severity = if change.is_breaking() {
+            Severity::Error
+        } else {
+            Severity::Info
+        };
+        let mut violation = Violation::new(
+            &codes::SCHEMA_CHANGE,
+            CheckKind::Definition,
+            change.to_string(),
+        )
+        .with_severity(severity);
+        if let Some(field) = change.field() {
+            violation = violation.with_field(field);
+        }
+        report.add_violation(violation);
+    }
+    if let Some(warning) = not_bumped {
+        report.add_warning(Violation::new(
+            &codes::VERSION_MISMATCH,
+            CheckKind::Definition,
+            warning,
+        ));
+    }
+    report
+}
@@ -0,0 +1,134 @@
+use anyhow::{Context, Result, bail};
+use colored::*;
+use contracts_core::{DataFormat, FieldDrift, SchemaDrift};
+use contracts_iceberg::CatalogCache;
+use contracts_parser::{parse_file, write_file};
+use serde_json::json;
+use std::path::Path;
+use tracing::info;
+
+use crate::commands::validate::{ReadOptions, iceberg_validator};
+use crate::i18n::{Msg, tr, trf};
+use crate::output;
+
+/// Compares a contract with the live schema of its Iceberg table and prints
+/// the contract edits that would bring it in line, or with `update` applies
+/// them and rewrites the contract file.
+///
+/// Without `update`, exits with status 1 when any drift is breaking, as
+/// `dce drift` does.
+pub async fn execute(contract_path: &str, update: bool, format: &str) -> Result<()> {
+    info!("Comparing {} with its table", contract_path);
+
+    let path = Path::new(contract_path);
+    let mut contract = parse_file(path)
+        .with_context(|| format!("Failed to parse contract file: {}", contract_path))?;
+    if contract.schema.format != DataFormat::Iceberg {
+        bail!(
+            "{}",
+            trf(
+                Msg::DriftIcebergOnly,
+                &[&format!("{:?}", contract.schema.format)]
+            )
+        );
+    }
+
+    let validator =
+        iceberg_validator(&contract, &ReadOptions::default(), &CatalogCache::new()).await?;
+    let table = validator
+        .extract_schema()
+        .await
+        .context("Failed to read the table schema")?;
+    let drift = SchemaDrift::compare(&contract.schema, &table);
+
+    let updated = update && !drift.is_empty();
+    if updated {
+        drift.apply(&mut contract.schema, &table);
+        write_file(path, &contract)
+            .with_context(|| format!("Failed to write contract file: {}", contract_path))?;
+    }
+
+    if format == "json" {
+        print_json(&drift, updated)?;
+    } else {
+        print_text(&drift, contract_path, updated);
+    }
+
+    if !updated && drift.is_breaking() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Returns the contract edit that resolves `change`.
+fn suggestion(change: &FieldDrift) -> String {
+    match change {
+        FieldDrift::Missing { field, .. } => trf(Msg::DiffTableRemove, &[field]),
+        FieldDrift::Extra {
+            field,
+            table_type,
+            nullable,
+        } => trf(Msg::DiffTableAdd, &[field, table_type, nullable]),
+        FieldDrift::TypeChanged {
+            field, table_type, ..
+        } => trf(Msg::DiffTableRetype, &[field, table_type]),
+        FieldDrift::NullabilityChanged {
+            field,
+            table_nullable,
+            ..
+        } => trf(Msg::DiffTableNullable, &[field, table_nullable]),
+    }
+}
+
+/// Prints each drift with the edit that resolves it, then how to apply the
+/// edits or that they were applied.
+fn print_text(drift: &SchemaDrift, contract_path: &str, updated: bool) {
+    if drift.is_empty() {
+        output::print_success(tr(Msg::DriftNone));
+        return;
+    }
+
+    for change in &drift.changes {
+        let label = if change.is_breaking() {
+            tr(Msg::DiffBreaking).red().bold()
+        } else {
+            tr(Msg::DiffCompatible).green()
+        };
+        println!("  {:<12} {}", label, change);
+        println!("  {:<12} {} {}", "", "→".bold(), suggestion(change));
+    }
+
+    println!();
+    if updated {
+        output::print_success(&trf(
+            Msg::DiffTableUpdated,
+            &[&drift.changes.len(), &contract_path],
+        ));
+    } else {
+        output::print_info(&trf(Msg::DiffTableUpdateHint, &[&contract_path]));
+    }
+}
+
+/// Prints the drift as JSON, each change with its `breaking` flag, message
+/// and suggested edit, and whether the contract was `updated`.
+fn print_json(drift: &SchemaDrift, updated: bool) -> Result<()> {
+    let changes = drift
+        .changes
+        .iter()
+        .map(|change| {
+            let mut value = serde_json::to_value(change)?;
+            value["breaking"] = change.is_breaking().into();
+            value["message"] = change.to_string().into();
+            value["suggestion"] = suggestion(change).into();
+            Ok(value)
+        })
+        .collect::<serde_json::Result<Vec<_>>>()?;
+    let document = json!({
+        "drifted": !drift.is_empty(),
+        "breaking": drift.is_breaking(),
+        "updated": updated,
+        "changes": changes,
+    });
+    println!("{}", serde_json::to_string_pretty(&document)?);
+    Ok(())
+}
@@ -0,0 +1,428 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, TimeDelta, Utc};
+use colored::*;
+use contracts_core::{ConnectionConfig, Contract, DataFormat};
+use contracts_iceberg::{
+    CatalogType, IcebergConfig, IcebergValidator, resolve_secret_refs, supported_catalogs,
+};
+use contracts_parser::parse_file;
+use serde_json::{Value, json};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tracing::info;
+
+use crate::commands::validate::{ReadOptions, connection_settings, iceberg_config};
+use crate::i18n::{Msg, tr, trf};
+use crate::output;
+
+/// Clock skew reported as a warning.
+const SKEW_WARNING: TimeDelta = TimeDelta::seconds(30);
+
+/// Clock skew reported as a failure.
+const SKEW_FAILURE: TimeDelta = TimeDelta::minutes(5);
+
+/// How long the clock check waits for the catalog.
+const CLOCK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Outcome of one diagnostic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Ok,
+    Warning,
+    Failed,
+    Skipped,
+}
+
+impl Status {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Warning => "warning",
+            Self::Failed => "failed",
+            Self::Skipped => "skipped",
+        }
+    }
+}
+
+/// One diagnostic: what was checked, what was found and how to fix it.
+#[derive(Debug)]
+struct Diagnosis {
+    check: &'static str,
+    status: Status,
+    detail: String,
+    remedy: Option<String>,
+}
+
+impl Diagnosis {
+    fn ok(check: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            check,
+            status: Status::Ok,
+            detail: detail.into(),
+            remedy: None,
+        }
+    }
+
+    fn warning(check: &'static str, detail: impl Into<String>, remedy: impl Into<String>) -> Self {
+        Self {
+            check,
+            status: Status::Warning,
+            detail: detail.into(),
+            remedy: Some(remedy.into()),
+        }
+    }
+
+    fn failed(check: &'static str, detail: impl Into<String>, remedy: impl Into<String>) -> Self {
+        Self {
+            check,
+            status: Status::Failed,
+            detail: detail.into(),
+            remedy: Some(remedy.into()),
+        }
+    }
+
+    fn skipped(check: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            check,
+            status: Status::Skipped,
+            detail: detail.into(),
+            remedy: None,
+        }
+    }
+}
+
+/// Checks that the environment can validate a contract's data: the catalog
+/// support compiled in, the catalog configuration and its secrets, the
+/// connection to the catalog, the table, the object store holding its files,
+/// and the local clock.
+///
+/// Every problem comes with a remedy. Checks that depend on a failed one are
+/// skipped. Exits with status 1 when any check fails.
+pub async fn execute(contract_path: &str, format: &str) -> Result<()> {
+    info!("Diagnosing the environment for {}", contract_path);
+
+    let contract = parse_file(Path::new(contract_path))
+        .with_context(|| format!("Failed to parse contract file: {}", contract_path))?;
+    let diagnoses = diagnose(&contract).await;
+
+    if format == "json" {
+        print_json(&diagnoses)?;
+    } else {
+        print_text(&diagnoses);
+    }
+
+    if diagnoses.iter().any(|d| d.status == Status::Failed) {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Runs every diagnostic for `contract`, in order.
+async fn diagnose(contract: &Contract) -> Vec<Diagnosis> {
+    let mut diagnoses = vec![Diagnosis::ok(
+        "build",
+        trf(
+            Msg::DoctorBuild,
+            &[&env!("CARGO_PKG_VERSION"), &supported_catalogs().join(", ")],
+        ),
+    )];
+    if contract.schema.format != DataFormat::Iceberg {
+        diagnoses.push(Diagnosis::skipped(
+            "configuration",
+            trf(
+                Msg::DoctorNoCatalog,
+                &[&format!("{:?}", contract.schema.format)],
+            ),
+        ));
+        return diagnoses;
+    }
+
+    let connection = match connection_settings(contract) {
+        Ok(connection) => connection,
+        Err(e) => {
+            diagnoses.push(Diagnosis::failed(
+                "configuration",
+                first_line(&e),
+                tr(Msg::DoctorConfigRemedy),
+            ));
+            return skip_rest(
+                diagnoses,
+                &["credentials", "catalog", "table", "object-store", "clock"],
+            );
+        }
+    };
+    let kind = connection
+        .catalog_type
+        .as_deref()
+        .unwrap_or(ConnectionConfig::DEFAULT_CATALOG_TYPE)
+        .to_lowercase();
+    if !supported_catalogs().contains(&kind.as_str()) {
+        diagnoses.push(Diagnosis::failed(
+            "configuration",
+            trf(Msg::DoctorCatalogNotCompiled, &[&kind]),
+            trf(Msg::DoctorRebuildRemedy, &[&kind]),
+        ));
+        return skip_rest(
+            diagnoses,
+            &["credentials", "catalog", "table", "object-store", "clock"],
+        );
+    }
+
+    let credentials = check_credentials(contract, &connection);
+    let credentials_failed = credentials.status == Status::Failed;
+    let config = iceberg_config(contract, &ReadOptions::default());
+    diagnoses.push(match &config {
+        Ok((_, reference)) => Diagnosis::ok(
+            "configuration",
+            trf(
+                Msg::DoctorConfigured,
+                &[
+                    &kind,
+                    &format!("{}.{}", reference.namespace_string(), reference.table),
+                ],
+            ),
+        ),
+        // Unresolved secrets are reported by the credentials check
+        Err(_) if credentials_failed => Diagnosis::ok(
+            "configuration",
+            trf(Msg::DoctorConfigured, &[&kind, &contract.schema.location]),
+        ),
+        Err(e) => Diagnosis::failed("configuration", first_line(e), tr(Msg::DoctorConfigRemedy)),
+    });
+    diagnoses.push(credentials);
+    let Ok((config, reference)) = config else {
+        return skip_rest(diagnoses, &["catalog", "table", "object-store", "clock"]);
+    };
+    let table = format!("{}.{}", reference.namespace_string(), reference.table);
+
+    let clock = check_clock(&config).await;
+    let validator = match IcebergValidator::new(config).await {
+        Ok(validator) => validator,
+        Err(e) => {
+            diagnoses.push(Diagnosis::failed(
+                "catalog",
+                e.to_string(),
+                tr(Msg::DoctorCatalogRemedy),
+            ));
+            diagnoses.extend(skip_rest(Vec::new(), &["table", "object-store"]));
+            diagnoses.push(clock);
+            return diagnoses;
+        }
+    };
+    diagnoses.push(Diagnosis::ok(
+        "catalog",
+        trf(Msg::DoctorCatalogConnected, &[&kind]),
+    ));
+
+    let snapshot = match validator.current_snapshot().await {
+        Ok(snapshot) => snapshot,
+        Err(e) => {
+            diagnoses.push(Diagnosis::failed(
+                "table",
+                e.to_string(),
+                trf(Msg::DoctorTableRemedy, &[&table]),
+            ));
+            diagnoses.extend(skip_rest(Vec::new(), &["object-store"]));
+            diagnoses.push(clock);
+            return diagnoses;
+        }
+    };
+    diagnoses.push(match &snapshot {
+        Some(snapshot) => Diagnosis::ok(
+            "table",
+            trf(Msg::DoctorTableLoaded, &[&table, &snapshot.snapshot_id]),
+        ),
+        None => Diagnosis::ok("table", trf(Msg::DoctorTableEmpty, &[&table])),
+    });
+
+    diagnoses.push(match snapshot {
+        None => Diagnosis::skipped("object-store", tr(Msg::DoctorNoDataFiles)),
+        Some(_) => match validator.read_sample_data(1).await {
+            Ok(_) => Diagnosis::ok("object-store", tr(Msg::DoctorStoreRead)),
+            Err(e) => Diagnosis::failed("object-store", e.to_string(), tr(Msg::DoctorStoreRemedy)),
+        },
+    });
+    diagnoses.push(clock);
+    diagnoses
+}
+
+/// Adds a skipped diagnosis for each of `checks`, which depend on one that failed.
+fn skip_rest(mut diagnoses: Vec<Diagnosis>, checks: &[&'static str]) -> Vec<Diagnosis> {
+    for check in checks {
+        diagnoses.push(Diagnosis::skipped(
+            check,
+            tr(Msg::DoctorSkippedAfterFailure),
+        ));
+    }
+    diagnoses
+}
+
+/// Resolves the secret references of the connection, then looks for cloud
+/// credentials when the table lives on S3.
+fn check_credentials(contract: &Contract, connection: &ConnectionConfig) -> Diagnosis {
+    let values = connection
+        .uri
+        .iter()
+        .chain(&connection.warehouse)
+        .chain(connection.properties.values());
+    let mut references = 0;
+    for value in values {
+        if let Err(e) = resolve_secret_refs(value) {
+            return Diagnosis::failed("credentials", e.to_string(), tr(Msg::DoctorSecretRemedy));
+        }
+        references += value.matches("${").count();
+    }
+
+    let on_s3 = [
+        Some(&contract.schema.location),
+        connection.warehouse.as_ref(),
+    ]
+    .into_iter()
+    .flatten()
+    .any(|location| location.starts_with("s3://") || location.starts_with("s3a://"));
+    if on_s3 && !has_aws_credentials(connection) {
+        return Diagnosis::warning(
+            "credentials",
+            tr(Msg::DoctorNoAwsCredentials),
+            tr(Msg::DoctorAwsRemedy),
+        );
+    }
+    Diagnosis::ok(
+        "credentials",
+        trf(Msg::DoctorSecretsResolved, &[&references]),
+    )
+}
+
+/// Returns true if S3 credentials are set in the connection properties, the
+/// environment or the AWS configuration files.
+fn has_aws_credentials(connection: &ConnectionConfig) -> bool {
+    const PROPERTIES: [&str; 2] = ["s3.access-key-id", "client.access-key-id"];
+    const VARIABLES: [&str; 5] = [
+        "AWS_ACCESS_KEY_ID",
+        "AWS_PROFILE",
+        "AWS_WEB_IDENTITY_TOKEN_FILE",
+        "AWS_CONTAINER_CREDENTIALS_RELATIVE_URI",
+        "AWS_CONTAINER_CREDENTIALS_FULL_URI",
+    ];
+    let aws_files = std::env::var_os("HOME").map(|home| {
+        let dir = PathBuf::from(home).join(".aws");
+        [dir.join("credentials"), dir.join("config")]
+    });
+    PROPERTIES
+        .iter()
+        .any(|key| connection.properties.contains_key(*key))
+        || VARIABLES
+            .iter()
+            .any(|name| std::env::var_os(name).is_some())
+        || aws_files.is_some_and(|files| files.iter().any(|file| file.exists()))
+}
+
+/// Compares the local clock with the `Date` header of a REST catalog.
+///
+/// Other catalogs do not report their time over HTTP, so the check is skipped
+/// for them.
+async fn check_clock(config: &IcebergConfig) -> Diagnosis {
+    let CatalogType::Rest { uri, .. } = &config.catalog else {
+        return Diagnosis::skipped("clock", tr(Msg::DoctorClockNotRest));
+    };
+    let client = reqwest::Client::builder().timeout(CLOCK_TIMEOUT).build();
+    let url = format!("{}/v1/config", uri.trim_end_matches('/'));
+    let response = match client {
+        Ok(client) => client.get(&url).send().await,
+        Err(e) => Err(e),
+    };
+    // An unreachable catalog is reported by the catalog check
+    let Ok(response) = response else {
+        return Diagnosis::skipped("clock", tr(Msg::DoctorClockUnknown));
+    };
+    let remote = response
+        .headers()
+        .get(reqwest::header::DATE)
+        .and_then(|date| date.to_str().ok())
+        .and_then(|date| DateTime::parse_from_rfc2822(date).ok());
+    match remote {
+        Some(remote) => clock_diagnosis(Utc::now() - remote.with_timezone(&Utc)),
+        None => Diagnosis::skipped("clock", tr(Msg::DoctorClockUnknown)),
+    }
+}
+
+/// Grades the difference between the local clock and the catalog's.
+fn clock_diagnosis(skew: TimeDelta) -> Diagnosis {
+    let detail = trf(Msg::DoctorClockSkew, &[&format!("{}s", skew.num_seconds())]);
+    let skew = skew.abs();
+    if skew >= SKEW_FAILURE {
+        Diagnosis::failed("clock", detail, tr(Msg::DoctorClockRemedy))
+    } else if skew >= SKEW_WARNING {
+        Diagnosis::warning("clock", detail, tr(Msg::DoctorClockRemedy))
+    } else {
+        Diagnosis::ok("clock", detail)
+    }
+}
+
+/// Returns the first line of an error, without the advice some errors carry.
+fn first_line(error: &anyhow::Error) -> String {
+    error
+        .to_string()
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Prints one line per check with its remedy below, then a summary.
+fn print_text(diagnoses: &[Diagnosis]) {
+    for diagnosis in diagnoses {
+        let symbol = match diagnosis.status {
+            Status::Ok => "✓".green().bold(),
+            Status::Warning => "⚠".yellow().bold(),
+            Status::Failed => "✗".red().bold(),
+            Status::Skipped => "-".dimmed(),
+        };
+        println!("{} {:<14} {}", symbol, diagnosis.check, diagnosis.detail);
+        if let Some(remedy) = &diagnosis.remedy {
+            println!("  {} {}", "→".bold(), remedy);
+        }
+    }
+
+    let count = |status| diagnoses.iter().filter(|d| d.status == status).count();
+    let summary = trf(
+        Msg::DoctorSummary,
+        &[
+            &diagnoses.len(),
+            &count(Status::Failed),
+            &count(Status::Warning),
+        ],
+    );
+    println!();
+    if count(Status::Failed) > 0 {
+        output::print_error(&summary);
+    } else if count(Status::Warning) > 0 {
+        output::print_warning(&summary);
+    } else {
+        output::print_success(&summary);
+    }
+}
+
+/// Prints the checks as JSON, with `healthy` set when none failed.
+fn print_json(diagnoses: &[Diagnosis]) -> Result<()> {
+    let checks: Vec<Value> = diagnoses
+        .iter()
+        .map(|d| {
+            let mut check = json!({
+                "check": d.check,
+                "status": d.status.as_str(),
+                "detail": d.detail,
+            });
+            if let Some(remedy) = &d.remedy {
+                check["remedy"] = json!(remedy);
+            }
+            check
+        })
+        .collect();
+    let document = json!({
+        "healthy": diagnoses.iter().all(|d| d.status != Status::Failed),
+        "checks": checks,
+    });
+    println!("{}", serde_json::to_string_pretty(&document)?);
+    Ok(())
+}
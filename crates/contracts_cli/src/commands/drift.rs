@@ -0,0 +1,136 @@
+use anyhow::{Context, Result, bail};
+use colored::*;
+use contracts_core::{AccessDrift, DataFormat, SchemaDrift};
+use contracts_iceberg::CatalogCache;
+use contracts_parser::parse_file;
+use serde_json::Value;
+use std::fmt::Display;
+use std::path::Path;
+use tracing::info;
+
+use crate::commands::validate::{ReadOptions, iceberg_validator};
+use crate::i18n::{Msg, tr, trf};
+use crate::output;
+
+/// Compares the live schema of a contract's Iceberg table with the contract
+/// and lists the fields on which they disagree, then does the same for the
+/// column access metadata when the table carries any.
+///
+/// Exits with status 1 when any drift is breaking, so the command can catch
+/// table changes in CI before validation starts failing.
+pub async fn execute(contract_path: &str, format: &str) -> Result<()> {
+    info!("Detecting schema drift for {}", contract_path);
+
+    let contract = parse_file(Path::new(contract_path))
+        .with_context(|| format!("Failed to parse contract file: {}", contract_path))?;
+    if contract.schema.format != DataFormat::Iceberg {
+        bail!(
+            "{}",
+            trf(
+                Msg::DriftIcebergOnly,
+                &[&format!("{:?}", contract.schema.format)]
+            )
+        );
+    }
+
+    let validator =
+        iceberg_validator(&contract, &ReadOptions::default(), &CatalogCache::new()).await?;
+    let drift = validator
+        .detect_drift(&contract)
+        .await
+        .context("Failed to read the table schema")?;
+    let access = validator
+        .detect_access_drift(&contract)
+        .await
+        .context("Failed to read the table properties")?;
+
+    if format == "json" {
+        print_json(&drift, access.as_ref())?;
+    } else {
+        print_text(&drift.changes, Msg::DriftNone, |c| c.is_breaking());
+        if let Some(access) = &access {
+            println!();
+            println!("{}", tr(Msg::DriftAccessHeading).bold());
+            print_text(&access.changes, Msg::DriftAccessNone, |c| c.is_breaking());
+        }
+    }
+
+    if drift.is_breaking() || access.is_some_and(|access| access.is_breaking()) {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Prints each change with its breaking or compatible label, then a summary,
+/// or `none` when there are no changes.
+fn print_text<C: Display>(changes: &[C], none: Msg, is_breaking: impl Fn(&C) -> bool) {
+    if changes.is_empty() {
+        output::print_success(tr(none));
+        return;
+    }
+
+    for change in changes {
+        let label = if is_breaking(change) {
+            tr(Msg::DiffBreaking).red().bold()
+        } else {
+            tr(Msg::DiffCompatible).green()
+        };
+        println!("  {:<12} {}", label, change);
+    }
+
+    let breaking = changes.iter().filter(|c| is_breaking(c)).count();
+    println!();
+    let summary = trf(Msg::DriftSummary, &[&changes.len(), &breaking]);
+    if breaking > 0 {
+        output::print_error(&summary);
+    } else {
+        output::print_success(&summary);
+    }
+}
+
+/// Prints the drift as JSON, each change with its `breaking` flag and message.
+///
+/// Access drift goes under `access`, which is null when the table carries no
+/// access metadata.
+fn print_json(drift: &SchemaDrift, access: Option<&AccessDrift>) -> Result<()> {
+    let changes = drift
+        .changes
+        .iter()
+        .map(|change| json_change(serde_json::to_value(change), change.is_breaking(), change))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut document = json_section(changes);
+    document["access"] = match access {
+        Some(access) => json_section(
+            access
+                .changes
+                .iter()
+                .map(|change| {
+                    json_change(serde_json::to_value(change), change.is_breaking(), change)
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+        ),
+        None => Value::Null,
+    };
+    println!("{}", serde_json::to_string_pretty(&document)?);
+    Ok(())
+}
+
+/// Adds the `breaking` flag and message to a serialized change.
+fn json_change(
+    value: serde_json::Result<Value>,
+    breaking: bool,
+    message: impl Display,
+) -> serde_json::Result<Value> {
+    let mut value = value?;
+    value["breaking"] = breaking.into();
+    value["message"] = message.to_string().into();
+    Ok(value)
+}
+
+fn json_section(changes: Vec<Value>) -> Value {
+    serde_json::json!({
+        "drifted": !changes.is_empty(),
+        "breaking": changes.iter().any(|change| change["breaking"] == true),
+        "changes": changes,
+    })
+}
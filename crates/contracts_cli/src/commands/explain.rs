@@ -0,0 +1,24 @@
+use anyhow::{Result, bail};
+use colored::*;
+use contracts_core::{ErrorCode, codes};
+
+use crate::i18n::{Msg, tr, trf};
+
+/// Prints the summary and remediation for `code`, or lists every code.
+pub fn execute(code: Option<&str>) -> Result<()> {
+    let Some(code) = code else {
+        for code in codes::ALL {
+            println!("{}  {:<28} {}", code.code.bold(), code.name, code.summary);
+        }
+        return Ok(());
+    };
+
+    let Some(code) = ErrorCode::lookup(code) else {
+        bail!(trf(Msg::UnknownErrorCode, &[&code]));
+    };
+    println!("{} {}", code.code.bold(), code.name);
+    println!("\n{}", code.summary);
+    println!("\n{}", tr(Msg::Remediation).bold());
+    println!("{}", code.remediation);
+    Ok(())
+}
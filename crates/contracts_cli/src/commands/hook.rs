@@ -0,0 +1,435 @@
+use anyhow::{Context, Result, anyhow};
+use contracts_core::{Contract, ContractDiff};
+use contracts_parser::{
+    CONFIG_NAME, ContractFormat, WorkspaceConfig, detect_format, parse_toml, parse_yaml,
+};
+use contracts_validator::{ContractLinter, DataValidator, LintSeverity};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::fs;
+use std::io::{BufRead, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tracing::{debug, info};
+
+use crate::commands::check::workspace_rules;
+use crate::i18n::{Msg, trf};
+use crate::output;
+
+/// Marker written into every hook installed by `dce`, used to avoid
+/// clobbering hooks that were written by other tools.
+const HOOK_MARKER: &str = "# Installed by `dce hook install`";
+
+/// File (inside the git directory) that caches content hashes of contracts
+/// that already passed the hook, so unchanged files are skipped on later runs.
+const CACHE_FILE: &str = "dce-hook-cache";
+
+/// Installs a git hook that runs `dce hook run` on contract files.
+pub fn install(hook: &str, paths: &[String], force: bool) -> Result<()> {
+    validate_hook_name(hook)?;
+
+    let hooks_dir = git_path("hooks")?;
+    fs::create_dir_all(&hooks_dir)
+        .with_context(|| format!("Failed to create hooks directory: {}", hooks_dir.display()))?;
+
+    let hook_path = hooks_dir.join(hook);
+    if hook_path.exists() && !force {
+        let existing = fs::read_to_string(&hook_path).unwrap_or_default();
+        if !existing.contains(HOOK_MARKER) {
+            return Err(anyhow!(
+                "A {} hook already exists at {} and was not installed by dce. \
+                 Use --force to overwrite it.",
+                hook,
+                hook_path.display()
+            ));
+        }
+    }
+
+    let mut run_args = format!("hook run --hook {}", hook);
+    for path in paths {
+        run_args.push_str(&format!(" --path '{}'", path.replace('\'', "'\\''")));
+    }
+
+    let script = format!(
+        "#!/bin/sh\n\
+         {HOOK_MARKER}\n\
+         # Validates data contracts before they are committed or pushed.\n\
+         # Bypass with `git {verb} --no-verify`.\n\
+         exec dce {run_args}\n",
+        verb = if hook == "pre-push" { "push" } else { "commit" },
+    );
+
+    fs::write(&hook_path, script)
+        .with_context(|| format!("Failed to write hook: {}", hook_path.display()))?;
+    make_executable(&hook_path)?;
+
+    output::print_success(&trf(Msg::HookInstalled, &[&hook, &hook_path.display()]));
+    Ok(())
+}
+
+/// Removes a hook previously installed by `dce hook install`.
+pub fn uninstall(hook: &str) -> Result<()> {
+    validate_hook_name(hook)?;
+
+    let hook_path = git_path("hooks")?.join(hook);
+    if !hook_path.exists() {
+        output::print_info(&trf(Msg::HookNotInstalled, &[&hook]));
+        return Ok(());
+    }
+
+    let existing = fs::read_to_string(&hook_path)
+        .with_context(|| format!("Failed to read hook: {}", hook_path.display()))?;
+    if !existing.contains(HOOK_MARKER) {
+        return Err(anyhow!(
+            "The {} hook at {} was not installed by dce; refusing to remove it",
+            hook,
+            hook_path.display()
+        ));
+    }
+
+    fs::remove_file(&hook_path)
+        .with_context(|| format!("Failed to remove hook: {}", hook_path.display()))?;
+    output::print_success(&trf(Msg::HookRemoved, &[&hook]));
+    Ok(())
+}
+
+/// Runs contract checks for the given hook type.
+///
+/// For `pre-commit`, the staged version of every added/modified contract file is
+/// checked against the version in `HEAD`. For `pre-push`, the contract files
+/// added or modified by the pushed commits are checked as they are in those
+/// commits, against the version on the remote (see [`pushed_files`]). Each
+/// file must pass `dce check` and `dce lint` without errors, and must not make
+/// breaking changes to its previous version. Files whose content already
+/// passed on a previous run are skipped (cached fast path).
+pub fn run(hook: &str, paths: &[String], no_cache: bool) -> Result<()> {
+    validate_hook_name(hook)?;
+
+    // Each file with the revision it is read from, the index when empty, and
+    // the revision holding its previous version, none when empty
+    let candidates = match hook {
+        "pre-commit" => git_lines(&["diff", "--cached", "--name-only", "--diff-filter=ACMR"])?
+            .into_iter()
+            .map(|file| (String::new(), "HEAD".to_string(), file))
+            .collect(),
+        _ => pushed_files()?,
+    };
+
+    let files: Vec<(String, String, String)> = candidates
+        .into_iter()
+        .filter(|(_, _, file)| is_contract_candidate(file, paths))
+        .collect();
+
+    if files.is_empty() {
+        debug!("No contract files to check");
+        return Ok(());
+    }
+
+    let cache_path = git_path(CACHE_FILE)?;
+    let mut cache = if no_cache {
+        HashMap::new()
+    } else {
+        load_cache(&cache_path)
+    };
+
+    let workspace = WorkspaceConfig::load(Path::new(CONFIG_NAME))?;
+    let checks = Checks {
+        validator: DataValidator::new(),
+        linter: workspace_rules(ContractLinter::new(), &workspace)?,
+    };
+    // Lint rules come from `dce.toml`, so a change to it invalidates the cache
+    let config = fs::read_to_string(CONFIG_NAME).unwrap_or_default();
+    let mut failures = Vec::new();
+    let mut checked = 0usize;
+    let mut skipped = 0usize;
+
+    for (revision, base, file) in &files {
+        let content = committed_content(revision, file)?;
+        // A new file, or one read before the first commit, has no previous
+        // version to compare with
+        let previous = if base.is_empty() {
+            None
+        } else {
+            committed_content(base, file).ok()
+        };
+        let hash = content_hash(&[&config, &content, previous.as_deref().unwrap_or_default()]);
+
+        if cache.get(file) == Some(&hash) {
+            skipped += 1;
+            continue;
+        }
+
+        checked += 1;
+        match check_contract(file, &content, previous.as_deref(), &checks) {
+            Ok(()) => {
+                cache.insert(file.clone(), hash);
+            }
+            Err(e) => {
+                cache.remove(file);
+                failures.push(format!("{}: {}", file, e));
+            }
+        }
+    }
+
+    if !no_cache {
+        save_cache(&cache_path, &cache);
+    }
+
+    info!(
+        "Checked {} contract file(s), {} unchanged file(s) skipped",
+        checked, skipped
+    );
+
+    if failures.is_empty() {
+        output::print_success(&trf(Msg::HookFilesOk, &[&files.len(), &skipped]));
+        Ok(())
+    } else {
+        for failure in &failures {
+            output::print_error(failure);
+        }
+        Err(anyhow!(
+            "{} contract file(s) failed the {} hook",
+            failures.len(),
+            hook
+        ))
+    }
+}
+
+/// What every contract file is checked with.
+struct Checks {
+    validator: DataValidator,
+    linter: ContractLinter,
+}
+
+/// Checks the definition of a single contract, its lint findings and, given
+/// its previous content, the changes it makes to it.
+fn check_contract(
+    file: &str,
+    content: &str,
+    previous: Option<&str>,
+    checks: &Checks,
+) -> Result<()> {
+    let contract = parse_contract(file, content)?;
+
+    let report = checks.validator.validate_definition(&contract);
+    let mut errors: Vec<String> = report.errors.iter().map(|e| e.to_string()).collect();
+
+    errors.extend(
+        checks
+            .linter
+            .lint(&contract)
+            .into_iter()
+            .filter(|finding| finding.severity == LintSeverity::Error)
+            .map(|finding| format!("{}: {}", finding.path, finding.message)),
+    );
+
+    // A previous version that no longer parses cannot be compared with
+    if let Some(old) = previous.and_then(|previous| parse_contract(file, previous).ok()) {
+        errors.extend(
+            ContractDiff::compare(&old, &contract)
+                .breaking()
+                .map(|change| format!("breaking change: {}", change)),
+        );
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(anyhow!(errors.join("; ")))
+    }
+}
+
+fn parse_contract(file: &str, content: &str) -> Result<Contract> {
+    Ok(match detect_format(Path::new(file))? {
+        ContractFormat::Yaml => parse_yaml(content)?,
+        ContractFormat::Toml => parse_toml(content)?,
+    })
+}
+
+fn validate_hook_name(hook: &str) -> Result<()> {
+    match hook {
+        "pre-commit" | "pre-push" => Ok(()),
+        other => Err(anyhow!(
+            "Unsupported hook type: {}. Supported types: pre-commit, pre-push",
+            other
+        )),
+    }
+}
+
+/// Returns true if `file` looks like a contract file within one of `paths`.
+///
+/// Without explicit paths, any YAML/TOML file inside a `contracts/` directory
+/// is considered a contract.
+fn is_contract_candidate(file: &str, paths: &[String]) -> bool {
+    if detect_format(Path::new(file)).is_err() {
+        return false;
+    }
+
+    if paths.is_empty() {
+        return Path::new(file)
+            .parent()
+            .map(|p| p.components().any(|c| c.as_os_str() == "contracts"))
+            .unwrap_or(false);
+    }
+
+    paths.iter().any(|p| {
+        let prefix = p.trim_end_matches('/');
+        file == prefix || file.starts_with(&format!("{}/", prefix))
+    })
+}
+
+/// Returns the contract candidates of a push, each with the commit it is
+/// read from and the remote commit its previous version is read from.
+///
+/// Git writes one `<local ref> <local sha> <remote ref> <remote sha>` line
+/// per pushed ref to the hook's stdin. The files added or modified between
+/// the remote and the local commit are checked; for a new branch, or a
+/// remote commit missing locally, every file of the local commit is, without
+/// a previous version. Deleted refs are skipped. Run by hand from a
+/// terminal, the hook checks the files of `HEAD`.
+fn pushed_files() -> Result<Vec<(String, String, String)>> {
+    let stdin = std::io::stdin();
+    let updates: Vec<(String, String)> = if stdin.is_terminal() {
+        vec![("HEAD".to_string(), String::new())]
+    } else {
+        stdin
+            .lock()
+            .lines()
+            .map_while(Result::ok)
+            .filter_map(|line| {
+                let fields: Vec<&str> = line.split_whitespace().collect();
+                match fields[..] {
+                    [_, local, _, remote] => Some((local.to_string(), remote.to_string())),
+                    _ => None,
+                }
+            })
+            .collect()
+    };
+
+    let is_null = |sha: &str| sha.bytes().all(|b| b == b'0');
+    let mut files = Vec::new();
+    for (local, remote) in updates {
+        if is_null(&local) {
+            continue;
+        }
+        let changed = if is_null(&remote) {
+            None
+        } else {
+            git_lines(&["diff", "--name-only", "--diff-filter=ACMR", &remote, &local]).ok()
+        };
+        let (changed, base) = match changed {
+            Some(changed) => (changed, remote),
+            None => (
+                git_lines(&["ls-tree", "-r", "--name-only", &local])?,
+                String::new(),
+            ),
+        };
+        for file in changed {
+            if !files.iter().any(|(rev, _, f)| *rev == local && *f == file) {
+                files.push((local.clone(), base.clone(), file));
+            }
+        }
+    }
+    Ok(files)
+}
+
+/// Reads `file` as it is in `revision`, or in the index when `revision` is
+/// empty.
+fn committed_content(revision: &str, file: &str) -> Result<String> {
+    let output = Command::new("git")
+        .args(["show", &format!("{}:{}", revision, file)])
+        .output()
+        .context("Failed to run git")?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "Failed to read {} content of {}: {}",
+            if revision.is_empty() {
+                "staged"
+            } else {
+                revision
+            },
+            file,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn content_hash(parts: &[&str]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(env!("CARGO_PKG_VERSION"));
+    for part in parts {
+        hasher.update([0]);
+        hasher.update(part);
+    }
+    let mut hex = String::with_capacity(64);
+    for byte in hasher.finalize() {
+        let _ = write!(hex, "{:02x}", byte);
+    }
+    hex
+}
+
+fn load_cache(path: &Path) -> HashMap<String, String> {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+fn save_cache(path: &Path, cache: &HashMap<String, String>) {
+    // The cache is an optimisation only; failing to persist it is not an error.
+    if let Ok(json) = serde_json::to_string(cache)
+        && let Err(e) = fs::write(path, json)
+    {
+        debug!("Failed to write hook cache {}: {}", path.display(), e);
+    }
+}
+
+/// Resolves `path` inside the git directory, following `core.hooksPath` for
+/// `hooks` and the private directory of a linked worktree.
+fn git_path(path: &str) -> Result<PathBuf> {
+    let lines = git_lines(&["rev-parse", "--git-path", path])?;
+    lines
+        .into_iter()
+        .next()
+        .map(PathBuf::from)
+        .ok_or_else(|| anyhow!("Not inside a git repository"))
+}
+
+fn git_lines(args: &[&str]) -> Result<Vec<String>> {
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .context("Failed to run git. Is git installed?")?;
+
+    if !output.status.success() {
+        return Err(anyhow!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(String::from)
+        .collect())
+}
+
+#[cfg(unix)]
+fn make_executable(path: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mut perms = fs::metadata(path)?.permissions();
+    perms.set_mode(0o755);
+    fs::set_permissions(path, perms)
+        .with_context(|| format!("Failed to make hook executable: {}", path.display()))
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> Result<()> {
+    Ok(())
+}
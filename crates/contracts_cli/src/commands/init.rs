@@ -1,29 +1,47 @@
 use anyhow::{Context, Result, anyhow};
 use contracts_core::{ContractBuilder, DataFormat};
 use contracts_iceberg::{IcebergConfig, IcebergValidator};
+use contracts_validator::propose_checks;
 use std::fs::File;
 use std::io::Write;
 use tracing::info;
 
+use crate::i18n::{Msg, trf};
 use crate::output;
 
+/// What `dce init` writes besides the table schema.
+pub struct InitOptions {
+    /// Contract owner (default: data-team)
+    pub owner: Option<String>,
+    /// Contract description (default: generated from the table name)
+    pub description: Option<String>,
+    /// Rows to sample for proposing constraints and quality checks; no
+    /// profiling when unset
+    pub profile_rows: Option<usize>,
+}
+
+/// Generates a contract from the schema of an Iceberg table.
 pub async fn execute(
     source: &str,
     output_path: Option<&str>,
     catalog_type: &str,
     namespace: Option<String>,
     table: Option<String>,
-    owner: Option<String>,
-    description: Option<String>,
+    options: InitOptions,
 ) -> Result<()> {
+    let InitOptions {
+        owner,
+        description,
+        profile_rows,
+    } = options;
     info!("Initializing contract from Iceberg source: {}", source);
 
     // Parse catalog type and build config
     let config = build_iceberg_config(source, catalog_type, namespace.clone(), table.clone())?;
 
-    output::print_info(&format!(
-        "Connecting to Iceberg catalog: {:?}",
-        config.catalog
+    output::print_info(&trf(
+        Msg::ConnectingToCatalogType,
+        &[&format!("{:?}", config.catalog)],
     ));
 
     // Create validator and extract schema
@@ -36,10 +54,7 @@ pub async fn execute(
         .await
         .context("Failed to extract schema from Iceberg table")?;
 
-    output::print_success(&format!(
-        "Extracted schema with {} fields",
-        schema.fields.len()
-    ));
+    output::print_success(&trf(Msg::ExtractedSchema, &[&schema.fields.len()]));
 
     // Build contract from extracted schema
     // Use the actual table name from config, not from first field
@@ -61,14 +76,48 @@ pub async fn execute(
         .version("1.0.0")
         .description(&contract_description)
         .location(&schema.location) // Use location from extracted schema
-        .format(DataFormat::Iceberg);
+        .format(DataFormat::Iceberg)
+        .namespace(config.namespace.join("."))
+        .table(table_name);
 
     // Add all fields from schema
     for field in &schema.fields {
         builder = builder.field(field.clone());
     }
 
-    let contract = builder.build();
+    let mut contract = builder.build();
+
+    if let Some(limit) = profile_rows {
+        output::print_info(&trf(Msg::ProfilingSample, &[&limit]));
+        let sample = validator
+            .read_sample_data(limit)
+            .await
+            .context("Failed to read sample data")?;
+        let constraints = |contract: &contracts_core::Contract| -> usize {
+            contract
+                .schema
+                .fields
+                .iter()
+                .map(|field| field.constraints.as_ref().map_or(0, Vec::len))
+                .sum()
+        };
+        let profiled = propose_checks(&contract, &sample);
+        let checks = usize::from(
+            profiled
+                .quality_checks
+                .as_ref()
+                .is_some_and(|quality| quality.completeness.is_some()),
+        );
+        output::print_success(&trf(
+            Msg::ProfiledSample,
+            &[
+                &sample.len(),
+                &(constraints(&profiled) - constraints(&contract)),
+                &checks,
+            ],
+        ));
+        contract = profiled;
+    }
 
     // Serialize to YAML
     let yaml =
@@ -80,7 +129,7 @@ pub async fn execute(
             .with_context(|| format!("Failed to create output file: {}", path))?;
         file.write_all(yaml.as_bytes())
             .with_context(|| format!("Failed to write to file: {}", path))?;
-        output::print_success(&format!("Contract written to: {}", path));
+        output::print_success(&trf(Msg::ContractWritten, &[&path]));
     } else {
         println!("{}", yaml);
     }
@@ -0,0 +1,142 @@
+use anyhow::{Result, bail};
+use contracts_core::Contract;
+use contracts_parser::{CONFIG_NAME, WorkspaceConfig, parse_file};
+use contracts_validator::{ContractLinter, LintFinding, LintSeverity};
+use serde_json::json;
+use std::path::{Path, PathBuf};
+use tracing::debug;
+
+use crate::commands::check::workspace_rules;
+use crate::commands::validate::collect_contract_files;
+use crate::i18n::{Msg, trf};
+use crate::output;
+
+/// Lints a contract, or every contract below a directory, with the built-in
+/// rules and those enabled under `[lint]` in `dce.toml`.
+///
+/// A file that does not parse is reported as a `parse-error` finding. Exits
+/// with status 1 when any finding is an error.
+pub fn execute(contract_path: &str, format: &str) -> Result<()> {
+    let workspace = WorkspaceConfig::load(Path::new(CONFIG_NAME))?;
+    let linter = workspace_rules(ContractLinter::new(), &workspace)?;
+
+    let path = Path::new(contract_path);
+    let files = if path.is_dir() {
+        contract_files(path, &workspace)?
+    } else {
+        vec![PathBuf::from(path)]
+    };
+
+    let mut linted = Vec::new();
+    for file in &files {
+        debug!("Linting {}", file.display());
+        let (contract, findings) = lint_file(&linter, file);
+        linted.push((file, contract.map(|contract| contract.name), findings));
+    }
+
+    let count = |severity| {
+        linted
+            .iter()
+            .flat_map(|(_, _, findings)| findings)
+            .filter(|f| f.severity == severity)
+            .count()
+    };
+    let (errors, warnings) = (count(LintSeverity::Error), count(LintSeverity::Warning));
+
+    if format == "json" {
+        let contracts: Vec<_> = linted
+            .iter()
+            .map(|(file, name, findings)| {
+                json!({
+                    "path": file.display().to_string(),
+                    "name": name,
+                    "findings": findings,
+                })
+            })
+            .collect();
+        let document = json!({
+            "valid": errors == 0,
+            "errors": errors,
+            "warnings": warnings,
+            "contracts": contracts,
+        });
+        println!("{}", serde_json::to_string_pretty(&document)?);
+    } else {
+        for (file, _, findings) in &linted {
+            if findings.is_empty() {
+                output::print_success(&trf(Msg::LintFileClean, &[&file.display()]));
+            }
+            for finding in findings {
+                print_finding(file, finding);
+            }
+        }
+        println!();
+        let summary = trf(Msg::LintSummary, &[&linted.len(), &errors, &warnings]);
+        if errors > 0 {
+            output::print_error(&summary);
+        } else {
+            output::print_success(&summary);
+        }
+    }
+
+    if errors > 0 {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Collects the contracts below `dir`, leaving out the tag taxonomy, which
+/// may live next to them.
+pub fn contract_files(dir: &Path, workspace: &WorkspaceConfig) -> Result<Vec<PathBuf>> {
+    let taxonomy = workspace
+        .lint
+        .tag_taxonomy
+        .as_deref()
+        .and_then(|taxonomy| std::fs::canonicalize(taxonomy).ok());
+    let files: Vec<PathBuf> = collect_contract_files(dir)?
+        .into_iter()
+        .filter(|file| taxonomy.is_none() || std::fs::canonicalize(file).ok() != taxonomy)
+        .collect();
+    if files.is_empty() {
+        bail!("{}", trf(Msg::NoContractsInDirectory, &[&dir.display()]));
+    }
+    Ok(files)
+}
+
+/// Parses and lints `file`, reporting a parse failure as a `parse-error` finding.
+pub fn lint_file(linter: &ContractLinter, file: &Path) -> (Option<Contract>, Vec<LintFinding>) {
+    match parse_file(file) {
+        Ok(contract) => {
+            let findings = linter.lint(&contract);
+            (Some(contract), findings)
+        }
+        Err(e) => (
+            None,
+            vec![LintFinding {
+                severity: LintSeverity::Error,
+                rule: "parse-error",
+                path: String::new(),
+                message: e.to_string(),
+            }],
+        ),
+    }
+}
+
+/// Prints a finding of the contract in `file` on one line.
+pub fn print_finding(file: &Path, finding: &LintFinding) {
+    let line = if finding.path.is_empty() {
+        format!("{} [{}] {}", file.display(), finding.rule, finding.message)
+    } else {
+        format!(
+            "{} [{}] {}: {}",
+            file.display(),
+            finding.rule,
+            finding.path,
+            finding.message
+        )
+    };
+    match finding.severity {
+        LintSeverity::Error => output::print_error(&line),
+        LintSeverity::Warning => output::print_warning(&line),
+    }
+}
@@ -1,3 +1,22 @@
+pub mod access;
+pub mod bundle;
+pub mod capabilities;
 pub mod check;
+pub mod diff;
+pub mod diff_table;
+pub mod doctor;
+pub mod drift;
+pub mod explain;
+pub mod hook;
 pub mod init;
+pub mod lint;
+pub mod monitor;
+pub mod owners;
+pub mod preview_change;
+pub mod publish;
+pub mod pull;
+pub mod push;
+pub mod reconcile;
+pub mod update;
 pub mod validate;
+pub mod wap;
@@ -0,0 +1,327 @@
+use anyhow::{Context, Result, anyhow};
+use contracts_core::{DataFormat, ValidationContext, ValidationReport};
+use contracts_iceberg::{CatalogCache, RequestLimits, SnapshotInfo};
+use contracts_parser::parse_file_with_provenance;
+use serde_json::json;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::str::FromStr;
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+use crate::commands::validate::{ReadOptions, iceberg_validator};
+use crate::hooks::Hooks;
+use crate::i18n::{Msg, tr, trf};
+use crate::metrics::Metrics;
+use crate::output;
+use crate::report;
+use crate::sinks::Sinks;
+
+/// Directory holding monitor state when `--state-dir` is not given.
+const DEFAULT_STATE_DIR: &str = ".dce-monitor";
+
+/// File (inside the state directory) recording the last validated snapshot.
+const STATE_FILE: &str = "state.json";
+
+/// When `dce monitor` runs its `--notify` command.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NotifyOn {
+    /// After every validation that does not pass (the default)
+    #[default]
+    Failure,
+    /// When the status differs from the previous validation
+    Change,
+    /// After every validation
+    Always,
+}
+
+impl FromStr for NotifyOn {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "failure" => Ok(Self::Failure),
+            "change" => Ok(Self::Change),
+            "always" => Ok(Self::Always),
+            other => Err(format!(
+                "unknown notification trigger '{}' (expected failure, change or always)",
+                other
+            )),
+        }
+    }
+}
+
+/// How `dce monitor` polls, persists and notifies.
+pub struct MonitorOptions<'a> {
+    /// Time between two polls of the table
+    pub interval: Duration,
+    /// Where the cursor and the reports are written
+    pub state_dir: Option<&'a Path>,
+    /// Shell command run after a validation
+    pub notify: Option<&'a str>,
+    /// Which validations run `notify`
+    pub notify_on: NotifyOn,
+    /// Poll once and exit with the validation result
+    pub once: bool,
+}
+
+/// Last validated snapshot, persisted so a restarted monitor resumes from it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Cursor {
+    snapshot_id: i64,
+    status: String,
+}
+
+/// Watches the Iceberg table of a contract and validates each new snapshot.
+///
+/// The first poll validates the table as a whole; later polls validate only
+/// the rows in data files added since the last validated snapshot. Commits
+/// made between two polls are validated together.
+pub async fn execute(
+    contract_path: &str,
+    context: ValidationContext,
+    limits: RequestLimits,
+    options: MonitorOptions<'_>,
+) -> Result<()> {
+    let path = Path::new(contract_path);
+    let state_dir = options
+        .state_dir
+        .map_or_else(|| default_state_dir(path), Path::to_path_buf);
+    std::fs::create_dir_all(state_dir.join("reports")).with_context(|| {
+        format!(
+            "Failed to create monitor state directory: {}",
+            state_dir.display()
+        )
+    })?;
+
+    let state_file = state_dir.join(STATE_FILE);
+    let mut cursor = load_cursor(&state_file)?;
+    let catalogs = CatalogCache::with_limits(limits);
+    let integrations = Integrations {
+        metrics: Metrics::from_workspace()?,
+        hooks: Hooks::from_workspace()?,
+        sinks: Sinks::from_workspace()?,
+    };
+
+    output::print_info(&trf(
+        Msg::MonitorStarted,
+        &[
+            &path.display(),
+            &options.interval.as_secs(),
+            &state_dir.display(),
+        ],
+    ));
+    if let Some(cursor) = &cursor {
+        output::print_info(&trf(Msg::MonitorResuming, &[&cursor.snapshot_id]));
+    }
+
+    loop {
+        let polled = poll(
+            path,
+            &context,
+            &catalogs,
+            &integrations,
+            &state_dir,
+            &mut cursor,
+            &options,
+        )
+        .await;
+        match polled {
+            Ok(Some(report)) if options.once && !report.passed => std::process::exit(1),
+            Ok(_) => {}
+            Err(e) if options.once => return Err(e),
+            // A daemon outlives catalog hiccups: report and retry on the next poll
+            Err(e) => output::print_error(&format!("{:#}", e)),
+        }
+        if options.once {
+            return Ok(());
+        }
+
+        tokio::select! {
+            _ = tokio::time::sleep(options.interval) => {}
+            _ = tokio::signal::ctrl_c() => {
+                output::print_info(tr(Msg::MonitorStopped));
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// The workspace integrations called around each validation.
+struct Integrations {
+    metrics: Metrics,
+    hooks: Hooks,
+    sinks: Sinks,
+}
+
+/// Validates the table if a snapshot was committed since `cursor`.
+///
+/// Returns the report of the validation, or `None` when there was nothing
+/// new to validate.
+async fn poll(
+    path: &Path,
+    context: &ValidationContext,
+    catalogs: &CatalogCache,
+    integrations: &Integrations,
+    state_dir: &Path,
+    cursor: &mut Option<Cursor>,
+    options: &MonitorOptions<'_>,
+) -> Result<Option<ValidationReport>> {
+    // Re-read on every poll, so contract edits apply without a restart
+    let (contract, provenance) = parse_file_with_provenance(path)
+        .with_context(|| format!("Failed to parse contract file: {}", path.display()))?;
+    if contract.schema.format != DataFormat::Iceberg {
+        return Err(anyhow!(
+            "{}",
+            trf(Msg::MonitorNeedsIceberg, &[&contract.name])
+        ));
+    }
+
+    let validator = iceberg_validator(&contract, &ReadOptions::default(), catalogs).await?;
+    let Some(snapshot) = validator.current_snapshot().await? else {
+        debug!("Table of {} has no snapshot yet", contract.name);
+        return Ok(None);
+    };
+
+    let previous = cursor.as_ref();
+    if previous.is_some_and(|c| c.snapshot_id == snapshot.snapshot_id) {
+        debug!("No new snapshot since {}", snapshot.snapshot_id);
+        return Ok(None);
+    }
+
+    let validator = match previous {
+        Some(previous) => {
+            output::print_info(&trf(
+                Msg::MonitorValidatingSince,
+                &[
+                    &snapshot.snapshot_id,
+                    &snapshot.operation,
+                    &previous.snapshot_id,
+                ],
+            ));
+            validator.with_base_snapshot(previous.snapshot_id)
+        }
+        None => {
+            output::print_info(&trf(
+                Msg::MonitorValidatingTable,
+                &[&snapshot.snapshot_id, &snapshot.operation],
+            ));
+            validator
+        }
+    };
+
+    let context = integrations.hooks.before(&contract, context).await?;
+    let report = validator
+        .validate_table(&contract, &context)
+        .await
+        .context("Validation failed")?
+        .with_provenance(provenance);
+
+    let report_path = state_dir
+        .join("reports")
+        .join(format!("{}.json", snapshot.snapshot_id));
+    report::write_report(&report, &contract.name, &report_path)?;
+    output::print_summary_line(&report);
+    integrations.metrics.record(&contract, &report);
+    integrations.hooks.after(&contract, &context, &report).await;
+    integrations.sinks.store(&contract, &report).await;
+
+    let status = output::status_name(report.status()).to_string();
+    let notify = match options.notify_on {
+        NotifyOn::Failure => !report.passed,
+        NotifyOn::Change => previous.map_or(!report.passed, |c| c.status != status),
+        NotifyOn::Always => true,
+    };
+
+    let next = Cursor {
+        snapshot_id: snapshot.snapshot_id,
+        status,
+    };
+    save_cursor(&state_dir.join(STATE_FILE), &next, &snapshot)?;
+
+    if notify && let Some(command) = options.notify {
+        run_notify(
+            command,
+            &contract.name,
+            &snapshot,
+            &next.status,
+            &report,
+            &report_path,
+        );
+    }
+
+    *cursor = Some(next);
+    Ok(Some(report))
+}
+
+/// State directory of a contract: `.dce-monitor/<file stem>`.
+fn default_state_dir(contract: &Path) -> PathBuf {
+    Path::new(DEFAULT_STATE_DIR).join(contract.file_stem().unwrap_or_default())
+}
+
+fn load_cursor(path: &Path) -> Result<Option<Cursor>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read monitor state: {}", path.display()))?;
+    let state: serde_json::Value = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse monitor state: {}", path.display()))?;
+
+    match (state["snapshot_id"].as_i64(), state["status"].as_str()) {
+        (Some(snapshot_id), Some(status)) => Ok(Some(Cursor {
+            snapshot_id,
+            status: status.to_string(),
+        })),
+        _ => Err(anyhow!(
+            "Monitor state {} has no snapshot_id and status; delete it to start over",
+            path.display()
+        )),
+    }
+}
+
+fn save_cursor(path: &Path, cursor: &Cursor, snapshot: &SnapshotInfo) -> Result<()> {
+    let state = json!({
+        "snapshot_id": cursor.snapshot_id,
+        "status": cursor.status,
+        "committed_at": snapshot.committed_at.map(|t| t.to_rfc3339()),
+        "validated_at": chrono::Utc::now().to_rfc3339(),
+    });
+    std::fs::write(path, serde_json::to_string_pretty(&state)?)
+        .with_context(|| format!("Failed to write monitor state: {}", path.display()))
+}
+
+/// Runs the `--notify` command through the shell, describing the validation
+/// in `DCE_*` environment variables.
+///
+/// A failing command is logged but does not stop the monitor.
+fn run_notify(
+    command: &str,
+    contract: &str,
+    snapshot: &SnapshotInfo,
+    status: &str,
+    report: &ValidationReport,
+    report_path: &Path,
+) {
+    let (shell, flag) = if cfg!(windows) {
+        ("cmd", "/C")
+    } else {
+        ("sh", "-c")
+    };
+    info!("Running notification command: {}", command);
+    let result = Command::new(shell)
+        .args([flag, command])
+        .env("DCE_CONTRACT", contract)
+        .env("DCE_SNAPSHOT_ID", snapshot.snapshot_id.to_string())
+        .env("DCE_STATUS", status)
+        .env("DCE_ERRORS", report.errors.len().to_string())
+        .env("DCE_WARNINGS", report.warnings.len().to_string())
+        .env("DCE_REPORT", report_path)
+        .status();
+
+    match result {
+        Ok(exit) if exit.success() => {}
+        Ok(exit) => warn!("Notification command exited with {}", exit),
+        Err(e) => warn!("Failed to run notification command: {}", e),
+    }
+}
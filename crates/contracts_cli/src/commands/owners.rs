@@ -0,0 +1,239 @@
+use anyhow::{Context, Result, bail};
+use contracts_parser::{CodeOwners, OrgDirectory, parse_file, same_owner};
+use contracts_validator::{LintFinding, LintSeverity};
+use serde_json::json;
+use std::path::{Path, PathBuf};
+use tracing::debug;
+
+use crate::commands::lint::print_finding;
+use crate::commands::validate::collect_contract_files;
+use crate::i18n::{Msg, tr, trf};
+use crate::output;
+
+/// Cross-checks the `owner` of a contract, or of every contract below a
+/// directory, against the `CODEOWNERS` file of the repository and an org
+/// directory exported as JSON.
+///
+/// Without `--codeowners`, the file is looked up at the locations GitHub
+/// uses, below the working directory. With both sources, the teams named by
+/// `CODEOWNERS` are also checked against the directory, which finds the
+/// teams a reorg removed. Exits with status 1 when any finding is an error.
+pub fn execute(
+    contract_path: &str,
+    codeowners: Option<&Path>,
+    org_directory: Option<&Path>,
+    format: &str,
+) -> Result<()> {
+    let codeowners_path = codeowners
+        .map(Path::to_path_buf)
+        .or_else(|| CodeOwners::find(Path::new(".")));
+    let codeowners = codeowners_path
+        .as_deref()
+        .map(|path| {
+            CodeOwners::load(path)
+                .with_context(|| format!("Failed to read CODEOWNERS file: {}", path.display()))
+        })
+        .transpose()?;
+    let directory = org_directory
+        .map(|path| -> Result<OrgDirectory> {
+            let content = std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read org directory: {}", path.display()))?;
+            serde_json::from_str(&content)
+                .with_context(|| format!("Org directory is not valid JSON: {}", path.display()))
+        })
+        .transpose()?;
+    if codeowners.is_none() && directory.is_none() {
+        bail!("{}", tr(Msg::NoOwnershipSource));
+    }
+
+    let path = Path::new(contract_path);
+    let files = if path.is_dir() {
+        let files = collect_contract_files(path)?;
+        if files.is_empty() {
+            bail!("{}", trf(Msg::NoContractsInDirectory, &[&path.display()]));
+        }
+        files
+    } else {
+        vec![PathBuf::from(path)]
+    };
+
+    let mut checked = Vec::new();
+    for file in files {
+        debug!("Checking owner of {}", file.display());
+        let contract = parse_file(&file)
+            .with_context(|| format!("Failed to parse contract file: {}", file.display()))?;
+        let findings = check_owner(
+            &file,
+            &contract.owner,
+            codeowners.as_ref(),
+            directory.as_ref(),
+        );
+        checked.push((file, contract.name, contract.owner, findings));
+    }
+
+    // Teams that CODEOWNERS still assigns paths to after they left the directory
+    let mut stale = Vec::new();
+    if let (Some(codeowners), Some(directory)) = (&codeowners, &directory) {
+        for rule in codeowners.rules() {
+            for owner in rule.owners.iter().filter(|owner| owner.contains('/')) {
+                let message = match directory.team(owner) {
+                    None => format!("Team '{}' is not in the org directory", owner),
+                    Some(team) if team.members.is_empty() => {
+                        format!("Team '{}' has no members left", owner)
+                    }
+                    Some(_) => continue,
+                };
+                stale.push(LintFinding {
+                    severity: LintSeverity::Error,
+                    rule: "stale-codeowner",
+                    path: format!("line {} ({})", rule.line, rule.pattern),
+                    message,
+                });
+            }
+        }
+    }
+
+    let findings = checked
+        .iter()
+        .flat_map(|(_, _, _, findings)| findings)
+        .chain(&stale);
+    let (errors, warnings) = findings.fold((0, 0), |(errors, warnings), finding| {
+        match finding.severity {
+            LintSeverity::Error => (errors + 1, warnings),
+            LintSeverity::Warning => (errors, warnings + 1),
+        }
+    });
+
+    if format == "json" {
+        let contracts: Vec<_> = checked
+            .iter()
+            .map(|(file, name, owner, findings)| {
+                json!({
+                    "path": file.display().to_string(),
+                    "name": name,
+                    "owner": owner,
+                    "findings": findings,
+                })
+            })
+            .collect();
+        let document = json!({
+            "valid": errors == 0,
+            "errors": errors,
+            "warnings": warnings,
+            "contracts": contracts,
+            "codeowners": {
+                "path": codeowners_path.as_ref().map(|path| path.display().to_string()),
+                "findings": stale,
+            },
+        });
+        println!("{}", serde_json::to_string_pretty(&document)?);
+    } else {
+        for (file, _, owner, findings) in &checked {
+            if findings.is_empty() {
+                output::print_success(&trf(Msg::OwnerKnown, &[&file.display(), owner]));
+            }
+            for finding in findings {
+                print_finding(file, finding);
+            }
+        }
+        if let Some(path) = &codeowners_path {
+            for finding in &stale {
+                print_finding(path, finding);
+            }
+        }
+        println!();
+        let summary = trf(Msg::OwnersSummary, &[&checked.len(), &errors, &warnings]);
+        if errors > 0 {
+            output::print_error(&summary);
+        } else {
+            output::print_success(&summary);
+        }
+    }
+
+    if errors > 0 {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Checks the owner of the contract in `file` against each source given.
+fn check_owner(
+    file: &Path,
+    owner: &str,
+    codeowners: Option<&CodeOwners>,
+    directory: Option<&OrgDirectory>,
+) -> Vec<LintFinding> {
+    let finding = |severity, rule, message| LintFinding {
+        severity,
+        rule,
+        path: "owner".to_string(),
+        message,
+    };
+    let mut findings = Vec::new();
+
+    match directory {
+        Some(directory) => match directory.team(owner) {
+            Some(team) if team.members.is_empty() => findings.push(finding(
+                LintSeverity::Error,
+                "orphaned-owner",
+                format!("Team '{}' has no members left", owner),
+            )),
+            Some(_) => {}
+            None if directory.is_member(owner) => {}
+            None => findings.push(finding(
+                LintSeverity::Error,
+                "unknown-owner",
+                format!(
+                    "'{}' is neither a team nor a member in the org directory",
+                    owner
+                ),
+            )),
+        },
+        None => {
+            let codeowners = codeowners.expect("at least one ownership source");
+            if !codeowners
+                .owners()
+                .into_iter()
+                .any(|known| same_owner(known, owner))
+            {
+                findings.push(finding(
+                    LintSeverity::Error,
+                    "unknown-owner",
+                    format!("'{}' does not own any path in CODEOWNERS", owner),
+                ));
+            }
+        }
+    }
+
+    if let Some(codeowners) = codeowners {
+        match codeowners.owners_of_file(file) {
+            Some(owners) if !owners.iter().any(|known| same_owner(known, owner)) => {
+                let message = if owners.is_empty() {
+                    format!(
+                        "CODEOWNERS leaves this file without owners, not '{}'",
+                        owner
+                    )
+                } else {
+                    format!(
+                        "CODEOWNERS assigns this file to {}, not '{}'",
+                        owners.join(", "),
+                        owner
+                    )
+                };
+                findings.push(finding(
+                    LintSeverity::Warning,
+                    "codeowners-mismatch",
+                    message,
+                ));
+            }
+            Some(_) => {}
+            None => findings.push(finding(
+                LintSeverity::Warning,
+                "codeowners-mismatch",
+                format!("No CODEOWNERS rule covers this file, owned by '{}'", owner),
+            )),
+        }
+    }
+
+    findings
+}
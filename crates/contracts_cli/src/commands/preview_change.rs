@@ -0,0 +1,184 @@
+use anyhow::{Context, Result};
+use contracts_core::{Contract, ValidationContext, Violation};
+use contracts_files::FileOptions;
+use contracts_iceberg::CatalogCache;
+use contracts_parser::parse_file;
+use contracts_validator::{ChangePreview, SampleOutcome};
+use serde_json::json;
+use std::path::Path;
+
+use crate::commands::reconcile::at_location;
+use crate::commands::validate::{DEFAULT_SAMPLE_SIZE, read_rows};
+use crate::i18n::{Msg, tr, trf};
+use crate::output;
+
+/// Row indices listed in text output before the rest are only counted.
+const LISTED_ROWS: usize = 10;
+
+/// Validates the same sample of the data at `data` against an old and a new
+/// version of a contract, and reports the rows and checks whose outcome
+/// changes.
+///
+/// Each version reads the first rows of the sample itself, so values are
+/// typed as that version declares them. Exits with status 1 when the new
+/// version fails rows or checks the old one passes.
+pub async fn execute(
+    old_path: &str,
+    new_path: &str,
+    data: &str,
+    context: ValidationContext,
+    file_options: FileOptions,
+    format: &str,
+) -> Result<()> {
+    let parse = |path: &str| {
+        parse_file(Path::new(path))
+            .with_context(|| format!("Failed to parse contract file: {}", path))
+    };
+    let (old, new) = (parse(old_path)?, parse(new_path)?);
+
+    let limit = context.sample_size.unwrap_or(DEFAULT_SAMPLE_SIZE);
+    // The sample is taken once per version, so the validator must not
+    // resample it
+    let context = ValidationContext {
+        sample_size: None,
+        ..context
+    };
+    let catalogs = CatalogCache::new();
+    let side = Side {
+        data,
+        limit,
+        context: &context,
+        files: &file_options,
+        catalogs: &catalogs,
+    };
+    let old = side.evaluate(Msg::PreviewOld, &old).await?;
+    let new = side.evaluate(Msg::PreviewNew, &new).await?;
+
+    let preview = ChangePreview::compare(&old, &new);
+    if format == "json" {
+        let checks = |violations: &[Violation]| {
+            violations
+                .iter()
+                .map(|v| {
+                    json!({
+                        "code": v.code.code,
+                        "check_kind": v.check_kind.as_str(),
+                        "field": v.field,
+                        "message": v.message,
+                    })
+                })
+                .collect::<Vec<_>>()
+        };
+        let document = json!({
+            "passed": !preview.breaks(),
+            "location": data,
+            "rows": preview.rows,
+            "old": {
+                "passed": preview.old_passed,
+                "failing_rows": preview.old_failing_rows,
+            },
+            "new": {
+                "passed": preview.new_passed,
+                "failing_rows": preview.new_failing_rows,
+            },
+            "blast_radius": preview.blast_radius(),
+            "newly_failing_rows": preview.newly_failing_rows,
+            "newly_passing_rows": preview.newly_passing_rows,
+            "newly_failing_checks": checks(&preview.newly_failing_checks),
+            "newly_passing_checks": checks(&preview.newly_passing_checks),
+        });
+        println!("{}", serde_json::to_string_pretty(&document)?);
+    } else {
+        print_preview(&preview);
+    }
+
+    if preview.breaks() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// What both versions are run against.
+struct Side<'a> {
+    data: &'a str,
+    limit: usize,
+    context: &'a ValidationContext,
+    files: &'a FileOptions,
+    catalogs: &'a CatalogCache,
+}
+
+impl Side<'_> {
+    /// Reads the sample as `contract` types it and evaluates it.
+    async fn evaluate(&self, side: Msg, contract: &Contract) -> Result<SampleOutcome> {
+        output::print_info(&trf(Msg::ReconcileValidating, &[&tr(side), &self.data]));
+        let contract = at_location(contract, self.data);
+        let sample = read_rows(&contract, self.files, self.catalogs, self.limit).await?;
+        Ok(SampleOutcome::evaluate(&contract, &sample, self.context).await)
+    }
+}
+
+fn print_preview(preview: &ChangePreview) {
+    println!();
+    for (side, failing) in [
+        (Msg::PreviewOld, preview.old_failing_rows),
+        (Msg::PreviewNew, preview.new_failing_rows),
+    ] {
+        output::print_info(&trf(
+            Msg::PreviewFailingRows,
+            &[&tr(side), &failing, &preview.rows],
+        ));
+    }
+
+    if !preview.newly_failing_rows.is_empty() {
+        output::print_error(&trf(
+            Msg::PreviewNewlyFailingRows,
+            &[
+                &preview.newly_failing_rows.len(),
+                &format!("{:.1}%", preview.blast_radius() * 100.0),
+                &list_rows(&preview.newly_failing_rows),
+            ],
+        ));
+    }
+    if !preview.newly_passing_rows.is_empty() {
+        output::print_success(&trf(
+            Msg::PreviewNewlyPassingRows,
+            &[
+                &preview.newly_passing_rows.len(),
+                &list_rows(&preview.newly_passing_rows),
+            ],
+        ));
+    }
+    for violation in &preview.newly_failing_checks {
+        output::print_error(&trf(Msg::PreviewNewlyFailingCheck, &[violation]));
+    }
+    for violation in &preview.newly_passing_checks {
+        output::print_success(&trf(Msg::PreviewNewlyPassingCheck, &[violation]));
+    }
+
+    println!();
+    if preview.breaks() {
+        output::print_error(&trf(
+            Msg::PreviewBreaks,
+            &[
+                &preview.newly_failing_rows.len(),
+                &preview.newly_failing_checks.len(),
+            ],
+        ));
+    } else {
+        output::print_success(tr(Msg::PreviewSafe));
+    }
+}
+
+/// Lists the first row indices, counting the rest.
+fn list_rows(rows: &[usize]) -> String {
+    let listed = rows
+        .iter()
+        .take(LISTED_ROWS)
+        .map(|row| row.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    match rows.len().saturating_sub(LISTED_ROWS) {
+        0 => listed,
+        more => format!("{}, +{}", listed, more),
+    }
+}
@@ -0,0 +1,332 @@
+use anyhow::{Context, Result, anyhow, bail};
+use contracts_iceberg::resolve_secret_refs;
+use contracts_parser::{CONFIG_NAME, ConfluenceConfig, NotionConfig, WorkspaceConfig, parse_file};
+use reqwest::{Client, RequestBuilder};
+use serde_json::{Value, json};
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+use crate::commands::validate::collect_contract_files;
+use crate::docs::Document;
+use crate::i18n::{Msg, trf};
+use crate::output;
+
+/// Notion API version the requests are written against.
+const NOTION_VERSION: &str = "2022-06-28";
+
+/// Most blocks Notion accepts in one request.
+const NOTION_BATCH: usize = 100;
+
+/// Publishes the documentation of a contract, or of every contract in a
+/// directory, to the Confluence space or Notion page configured in `dce.toml`.
+///
+/// Each contract has one page, found through the `pages` mapping or by its
+/// title and replaced on every run. With `dry_run`, the rendered pages are
+/// printed as JSON and nothing is sent.
+pub async fn execute(contract_path: &str, target: &str, dry_run: bool) -> Result<()> {
+    let path = Path::new(contract_path);
+    let files = if path.is_dir() {
+        let files = collect_contract_files(path)?;
+        if files.is_empty() {
+            bail!("{}", trf(Msg::NoContractsInDirectory, &[&path.display()]));
+        }
+        files
+    } else {
+        vec![PathBuf::from(path)]
+    };
+    let documents = files
+        .iter()
+        .map(|file| {
+            parse_file(file)
+                .map(|contract| Document::of(&contract))
+                .with_context(|| format!("Failed to parse contract file: {}", file.display()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    if dry_run {
+        let pages: Vec<Value> = documents
+            .iter()
+            .map(|document| {
+                let body = match target {
+                    "notion" => Value::Array(document.to_notion()),
+                    _ => Value::String(document.to_confluence()),
+                };
+                json!({ "title": document.title, "body": body })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&pages)?);
+        return Ok(());
+    }
+
+    let config = WorkspaceConfig::load(Path::new(CONFIG_NAME))?.publish;
+    let not_configured = || {
+        anyhow!(
+            "{}",
+            trf(Msg::PublishTargetNotConfigured, &[&target, &CONFIG_NAME])
+        )
+    };
+    let publisher = match target {
+        "notion" => Publisher::Notion(Notion::new(config.notion.ok_or_else(not_configured)?)?),
+        _ => Publisher::Confluence(Confluence::new(
+            config.confluence.ok_or_else(not_configured)?,
+        )?),
+    };
+
+    for document in &documents {
+        let url = publisher.publish(document).await?;
+        output::print_success(&trf(Msg::PublishedPage, &[&document.title, &url]));
+    }
+    println!();
+    output::print_info(&trf(Msg::PublishSummary, &[&documents.len(), &target]));
+    Ok(())
+}
+
+/// A documentation target.
+enum Publisher {
+    Confluence(Confluence),
+    Notion(Notion),
+}
+
+impl Publisher {
+    /// Creates or replaces the page of `document`, returning its URL.
+    async fn publish(&self, document: &Document) -> Result<String> {
+        match self {
+            Self::Confluence(confluence) => confluence.publish(document).await,
+            Self::Notion(notion) => notion.publish(document).await,
+        }
+    }
+}
+
+/// A Confluence space, through the REST API of Confluence Cloud and Server.
+struct Confluence {
+    client: Client,
+    config: ConfluenceConfig,
+}
+
+/// The fields of a Confluence page needed to replace it.
+struct ConfluencePage {
+    id: String,
+    title: String,
+    version: u64,
+}
+
+impl Confluence {
+    fn new(mut config: ConfluenceConfig) -> Result<Self> {
+        config.url = config.url.trim_end_matches('/').to_string();
+        config.user = resolve_secret_refs(&config.user)?;
+        config.token = resolve_secret_refs(&config.token)?;
+        Ok(Self {
+            client: Client::new(),
+            config,
+        })
+    }
+
+    fn request(&self, method: reqwest::Method, path: &str) -> RequestBuilder {
+        self.client
+            .request(
+                method,
+                format!("{}/rest/api/content{}", self.config.url, path),
+            )
+            .basic_auth(&self.config.user, Some(&self.config.token))
+    }
+
+    async fn publish(&self, document: &Document) -> Result<String> {
+        let existing = match self.config.pages.get(&document.title) {
+            Some(id) => {
+                let page = send(
+                    self.request(reqwest::Method::GET, &format!("/{}", id))
+                        .query(&[("expand", "version")]),
+                )
+                .await?;
+                Some(confluence_page(&page)?)
+            }
+            None => self.find(&document.title).await?,
+        };
+
+        let body = json!({ "storage": { "value": document.to_confluence(), "representation": "storage" } });
+        let id = match existing {
+            Some(page) => {
+                info!("Updating Confluence page {} ({})", page.id, page.title);
+                send(
+                    self.request(reqwest::Method::PUT, &format!("/{}", page.id))
+                        .json(&json!({
+                            "id": page.id,
+                            "type": "page",
+                            "title": page.title,
+                            "version": { "number": page.version + 1 },
+                            "body": body,
+                        })),
+                )
+                .await?;
+                page.id
+            }
+            None => {
+                info!("Creating Confluence page {}", document.title);
+                let mut page = json!({
+                    "type": "page",
+                    "title": document.title,
+                    "space": { "key": self.config.space },
+                    "body": body,
+                });
+                if let Some(parent) = &self.config.parent {
+                    page["ancestors"] = json!([{ "id": parent }]);
+                }
+                let created = send(self.request(reqwest::Method::POST, "").json(&page)).await?;
+                confluence_page(&created)?.id
+            }
+        };
+        Ok(format!(
+            "{}/pages/viewpage.action?pageId={}",
+            self.config.url, id
+        ))
+    }
+
+    /// Looks up the page of the space titled `title`.
+    async fn find(&self, title: &str) -> Result<Option<ConfluencePage>> {
+        let found = send(self.request(reqwest::Method::GET, "").query(&[
+            ("spaceKey", self.config.space.as_str()),
+            ("title", title),
+            ("type", "page"),
+            ("expand", "version"),
+        ]))
+        .await?;
+        found["results"]
+            .as_array()
+            .and_then(|results| results.first())
+            .map(confluence_page)
+            .transpose()
+    }
+}
+
+fn confluence_page(page: &Value) -> Result<ConfluencePage> {
+    let field = |value: &Value| value.as_str().map(str::to_string);
+    Ok(ConfluencePage {
+        id: field(&page["id"]).ok_or_else(|| anyhow!("Confluence page without an id: {}", page))?,
+        title: field(&page["title"]).unwrap_or_default(),
+        version: page["version"]["number"].as_u64().unwrap_or(1),
+    })
+}
+
+/// A Notion workspace, through the public API.
+struct Notion {
+    client: Client,
+    config: NotionConfig,
+}
+
+impl Notion {
+    fn new(mut config: NotionConfig) -> Result<Self> {
+        config.token = resolve_secret_refs(&config.token)?;
+        Ok(Self {
+            client: Client::new(),
+            config,
+        })
+    }
+
+    fn request(&self, method: reqwest::Method, path: &str) -> RequestBuilder {
+        self.client
+            .request(method, format!("https://api.notion.com/v1{}", path))
+            .bearer_auth(&self.config.token)
+            .header("Notion-Version", NOTION_VERSION)
+    }
+
+    async fn publish(&self, document: &Document) -> Result<String> {
+        let blocks = document.to_notion();
+        let existing = match self.config.pages.get(&document.title) {
+            Some(id) => Some(id.clone()),
+            None => self.find(&document.title).await?,
+        };
+
+        let (id, rest) = match existing {
+            Some(id) => {
+                info!("Replacing the content of Notion page {}", id);
+                for block in self.children(&id).await? {
+                    // Child pages are other documents, not content of this one
+                    if block["type"] == "child_page" || block["type"] == "child_database" {
+                        continue;
+                    }
+                    if let Some(block) = block["id"].as_str() {
+                        send(self.request(reqwest::Method::DELETE, &format!("/blocks/{}", block)))
+                            .await?;
+                    }
+                }
+                (id, blocks.as_slice())
+            }
+            None => {
+                info!("Creating Notion page {}", document.title);
+                let (first, rest) = blocks.split_at(blocks.len().min(NOTION_BATCH));
+                let created = send(self.request(reqwest::Method::POST, "/pages").json(&json!({
+                    "parent": { "page_id": self.config.parent },
+                    "properties": {
+                        "title": { "title": [{ "type": "text", "text": { "content": document.title } }] },
+                    },
+                    "children": first,
+                })))
+                .await?;
+                let id = created["id"]
+                    .as_str()
+                    .ok_or_else(|| anyhow!("Notion page without an id: {}", created))?;
+                (id.to_string(), rest)
+            }
+        };
+
+        for batch in rest.chunks(NOTION_BATCH) {
+            send(
+                self.request(reqwest::Method::PATCH, &format!("/blocks/{}/children", id))
+                    .json(&json!({ "children": batch })),
+            )
+            .await?;
+        }
+        Ok(format!("https://www.notion.so/{}", id.replace('-', "")))
+    }
+
+    /// Looks up the child page of the parent titled `title`.
+    async fn find(&self, title: &str) -> Result<Option<String>> {
+        let found = self
+            .children(&self.config.parent)
+            .await?
+            .into_iter()
+            .find(|block| block["type"] == "child_page" && block["child_page"]["title"] == title);
+        Ok(found.and_then(|block| block["id"].as_str().map(str::to_string)))
+    }
+
+    /// Lists every child block of `id`, following pagination.
+    async fn children(&self, id: &str) -> Result<Vec<Value>> {
+        let mut children = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let mut request = self
+                .request(reqwest::Method::GET, &format!("/blocks/{}/children", id))
+                .query(&[("page_size", NOTION_BATCH.to_string())]);
+            if let Some(cursor) = &cursor {
+                request = request.query(&[("start_cursor", cursor)]);
+            }
+            let page = send(request).await?;
+            if let Some(results) = page["results"].as_array() {
+                children.extend(results.iter().cloned());
+            }
+            match page["next_cursor"].as_str() {
+                Some(next) if page["has_more"] == true => cursor = Some(next.to_string()),
+                _ => return Ok(children),
+            }
+        }
+    }
+}
+
+/// Sends `request` and returns its JSON response, or an error with the
+/// response body when the status is not a success.
+async fn send(request: RequestBuilder) -> Result<Value> {
+    let response = request
+        .send()
+        .await
+        .context("Failed to reach the documentation target")?;
+    let status = response.status();
+    let url = response.url().clone();
+    let body = response.text().await?;
+    if !status.is_success() {
+        bail!("{} {}: {}", status, url, body);
+    }
+    if body.is_empty() {
+        return Ok(Value::Null);
+    }
+    serde_json::from_str(&body).with_context(|| format!("Invalid JSON response from {}", url))
+}
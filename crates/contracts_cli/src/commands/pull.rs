@@ -0,0 +1,47 @@
+use anyhow::{Context, Result};
+use contracts_parser::{contract_layer, manifest_provenance};
+
+use crate::commands::validate::open_registry;
+use crate::i18n::{Msg, trf};
+use crate::oci::{OciClient, OciReference};
+use crate::output;
+
+/// Pulls the contract bundle `source` from an OCI registry and publishes its
+/// contract file in the registry directory, where `--contract-from-registry`
+/// and `dce update` find it, with the provenance recorded in the bundle.
+///
+/// A version already published with different content is never replaced.
+pub async fn execute(source: &str, registry: Option<&str>) -> Result<()> {
+    let registry = open_registry(registry)?;
+    let source: OciReference = source.parse()?;
+    let client = OciClient::from_env();
+
+    let manifest = client
+        .pull_manifest(&source)
+        .await
+        .with_context(|| format!("Failed to pull {}", source))?;
+    let (file_name, digest) = contract_layer(&manifest)?;
+    let content = client
+        .pull_blob(&source, &digest)
+        .await
+        .with_context(|| format!("Failed to pull {}", source))?;
+    let content = String::from_utf8(content)
+        .with_context(|| format!("{} of {} is not UTF-8 text", file_name, source))?;
+
+    let path = registry.add_with_provenance(
+        &file_name,
+        &content,
+        manifest_provenance(&manifest).as_ref(),
+    )?;
+    output::print_success(&trf(
+        Msg::BundlePulled,
+        &[
+            &source,
+            &manifest["annotations"]["dce.contract.name"]
+                .as_str()
+                .unwrap_or(&file_name),
+            &path.display(),
+        ],
+    ));
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use anyhow::{Context, Result};
+
+use crate::commands::bundle;
+use crate::i18n::{Msg, trf};
+use crate::oci::{OciClient, OciReference};
+use crate::output;
+
+/// Bundles a contract with the `includes`, as `dce bundle` does, and pushes
+/// the bundle to the OCI repository `target` as an artifact.
+///
+/// Without a tag in `target`, the bundle is tagged with the contract
+/// version.
+pub async fn execute(contract_path: &str, target: &str, includes: &[String]) -> Result<()> {
+    let bundle = bundle::build(contract_path, includes)?;
+    let target = target
+        .parse::<OciReference>()?
+        .or_tag(&bundle.contract.version);
+
+    let digest = OciClient::from_env()
+        .push(&bundle, &target)
+        .await
+        .with_context(|| format!("Failed to push {}", target))?;
+
+    output::print_success(&trf(
+        Msg::BundlePushed,
+        &[&bundle.contract.name, &bundle.contract.version, &target],
+    ));
+    output::print_info(&trf(Msg::BundleDigest, &[&digest]));
+    Ok(())
+}
@@ -0,0 +1,185 @@
+use anyhow::{Context, Result, anyhow};
+use contracts_core::{
+    Contract, ContractProvenance, DataFormat, ValidationContext, ValidationReport,
+};
+use contracts_files::FileOptions;
+use contracts_iceberg::CatalogCache;
+use contracts_parser::parse_file_with_provenance;
+use contracts_validator::{DataSet, Reconciliation, TableProfile, reconcile};
+use serde_json::json;
+use std::path::Path;
+
+use crate::commands::validate::{ReadOptions, read_all, validate_data};
+use crate::i18n::{Msg, tr, trf};
+use crate::output::{self, Verbosity};
+
+/// Validates a source and a target against one contract, then compares their
+/// row counts, checksums and column statistics.
+///
+/// Both sides are read in full for the comparison, whatever the sample size
+/// used for validation. Exits with status 1 when either side fails validation
+/// or the two differ.
+pub async fn execute(
+    contract_path: &str,
+    source: &str,
+    target: &str,
+    context: ValidationContext,
+    file_options: FileOptions,
+    format: &str,
+    verbosity: Verbosity,
+) -> Result<()> {
+    let path = Path::new(contract_path);
+    let (contract, provenance) = parse_file_with_provenance(path)
+        .with_context(|| format!("Failed to parse contract file: {}", path.display()))?;
+    if !matches!(
+        contract.schema.format,
+        DataFormat::Iceberg | DataFormat::Csv | DataFormat::Json
+    ) {
+        return Err(anyhow!(
+            "{}",
+            trf(
+                Msg::ReconcileUnsupported,
+                &[&format!("{:?}", contract.schema.format)]
+            )
+        ));
+    }
+
+    let catalogs = CatalogCache::new();
+    let read = ReadOptions {
+        files: file_options,
+        ..ReadOptions::default()
+    };
+    let side = Side {
+        contract: &contract,
+        provenance: &provenance,
+        context: &context,
+        read: &read,
+        catalogs: &catalogs,
+        format,
+        verbosity,
+    };
+    let (source_report, source_data) = side.check(Msg::ReconcileSource, source).await?;
+    let (target_report, target_data) = side.check(Msg::ReconcileTarget, target).await?;
+
+    let reconciliation = reconcile(&contract, &source_data, &target_data);
+    let passed = source_report.passed && target_report.passed && reconciliation.matches();
+
+    if format == "json" {
+        let side = |report: &ValidationReport, location: &str, profile: &TableProfile| {
+            let mut value = output::json_report(report, verbosity);
+            value["location"] = json!(location);
+            value["profile"] = json_profile(profile);
+            value
+        };
+        let document = json!({
+            "passed": passed,
+            "source": side(&source_report, source, &reconciliation.source),
+            "target": side(&target_report, target, &reconciliation.target),
+            "matches": reconciliation.matches(),
+            "differences": reconciliation.differences.iter().map(|difference| json!({
+                "column": difference.column,
+                "metric": difference.metric,
+                "source": difference.source,
+                "target": difference.target,
+                "message": difference.to_string(),
+            })).collect::<Vec<_>>(),
+        });
+        println!("{}", serde_json::to_string_pretty(&document)?);
+    } else {
+        print_reconciliation(&reconciliation);
+    }
+
+    if !passed {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// What both sides of a reconciliation are checked with.
+struct Side<'a> {
+    contract: &'a Contract,
+    provenance: &'a ContractProvenance,
+    context: &'a ValidationContext,
+    read: &'a ReadOptions,
+    catalogs: &'a CatalogCache,
+    format: &'a str,
+    verbosity: Verbosity,
+}
+
+impl Side<'_> {
+    /// Validates the data at `location`, printing the report unless the
+    /// output is JSON, and reads every row.
+    async fn check(&self, side: Msg, location: &str) -> Result<(ValidationReport, DataSet)> {
+        output::print_info(&trf(Msg::ReconcileValidating, &[&tr(side), &location]));
+        let contract = at_location(self.contract, location);
+        let report = validate_data(&contract, self.context, None, self.read, self.catalogs)
+            .await?
+            .with_provenance(self.provenance.clone());
+        if self.format != "json" {
+            output::print_validation_report(&report, self.format, self.verbosity);
+        }
+
+        let data = read_all(&contract, &self.read.files, self.catalogs).await?;
+        output::print_info(&trf(Msg::ReconcileRowsRead, &[&data.len(), &tr(side)]));
+        Ok((report, data))
+    }
+}
+
+/// Returns `contract` with its data at `location`.
+///
+/// Explicit `namespace` and `table` fields would take precedence over the
+/// location, so they are cleared, as is the catalog of `iceberg://` URIs.
+pub(crate) fn at_location(contract: &Contract, location: &str) -> Contract {
+    let mut contract = contract.clone();
+    contract.schema.location = location.to_string();
+    contract.schema.namespace = None;
+    contract.schema.table = None;
+    if location.starts_with("iceberg://") {
+        contract.schema.catalog = None;
+    }
+    contract
+}
+
+fn print_reconciliation(reconciliation: &Reconciliation) {
+    println!();
+    if reconciliation.matches() {
+        output::print_success(&trf(
+            Msg::ReconcileMatch,
+            &[
+                &reconciliation.source.rows,
+                &format!("{:016x}", reconciliation.source.checksum),
+            ],
+        ));
+        return;
+    }
+
+    output::print_differences(&reconciliation.differences);
+    output::print_error(&trf(
+        Msg::ReconcileDifferences,
+        &[&reconciliation.differences.len()],
+    ));
+}
+
+/// Builds the JSON form of a profile, with checksums as hex strings.
+fn json_profile(profile: &TableProfile) -> serde_json::Value {
+    let columns: serde_json::Map<String, serde_json::Value> = profile
+        .columns
+        .iter()
+        .map(|(column, profile)| {
+            let value = json!({
+                "checksum": format!("{:016x}", profile.checksum),
+                "null_count": profile.stats.null_count,
+                "min": profile.stats.min,
+                "max": profile.stats.max,
+                "distinct_count": profile.stats.distinct_count,
+                "distinct_exact": profile.stats.distinct_exact,
+            });
+            (column.clone(), value)
+        })
+        .collect();
+    json!({
+        "rows": profile.rows,
+        "checksum": format!("{:016x}", profile.checksum),
+        "columns": columns,
+    })
+}
@@ -0,0 +1,47 @@
+use anyhow::Result;
+use contracts_parser::{LOCKFILE_NAME, Lockfile, RegistryRef};
+use std::path::Path;
+
+use crate::commands::validate::open_registry;
+use crate::i18n::{Msg, tr, trf};
+use crate::output;
+
+/// Re-resolves registry references and rewrites `dce.lock`.
+///
+/// With no `references`, every reference already in the lockfile is
+/// refreshed; otherwise only the given ones, which are added if new.
+pub fn execute(references: &[String], registry: Option<&str>) -> Result<()> {
+    let registry = open_registry(registry)?;
+    let references = references
+        .iter()
+        .map(|r| r.parse())
+        .collect::<Result<Vec<RegistryRef>, _>>()?;
+
+    let lock_path = Path::new(LOCKFILE_NAME);
+    let mut lock = Lockfile::load(lock_path)?;
+    let changes = lock.update(&registry, &references)?;
+    if changes.is_empty() {
+        output::print_info(tr(Msg::NothingToUpdate));
+        return Ok(());
+    }
+
+    for (reference, previous, locked) in &changes {
+        match previous {
+            None => output::print_info(&trf(Msg::LockAdded, &[reference, &locked.version])),
+            Some(previous) if previous == locked => {
+                output::print_info(&trf(Msg::LockUnchanged, &[reference, &locked.version]))
+            }
+            Some(previous) if previous.version == locked.version => {
+                output::print_info(&trf(Msg::LockRehashed, &[reference, &locked.version]))
+            }
+            Some(previous) => output::print_info(&trf(
+                Msg::LockUpdated,
+                &[reference, &previous.version, &locked.version],
+            )),
+        }
+    }
+
+    lock.save(lock_path)?;
+    output::print_success(&trf(Msg::LockfileWritten, &[&LOCKFILE_NAME]));
+    Ok(())
+}
@@ -1,160 +1,843 @@
 use anyhow::{Context, Result, anyhow};
-use contracts_core::{DataFormat, ValidationContext};
-use contracts_iceberg::{IcebergConfig, IcebergValidator};
-use contracts_parser::parse_file;
-use contracts_validator::{DataSet, DataValidator};
-use std::path::Path;
+use contracts_core::{
+    Coded, ConnectionConfig, Contract, ContractProvenance, DataFormat, DatasetReport,
+    SamplingStrategy, ValidationContext, ValidationReport, codes,
+};
+use contracts_files::{
+    FileFormat, FileLocation, FileOptions, FileValidator, FilesError, TypeInference,
+};
+use contracts_iceberg::{
+    CatalogCache, CatalogType, IcebergConfig, IcebergError, IcebergValidator, RequestLimits,
+    ScanOptions, SnapshotSelector, TableReference,
+};
+use contracts_parquet::{ParquetError, ParquetLocation, ParquetValidator};
+use contracts_parser::{
+    CONFIG_NAME, ContractRegistry, LOCKFILE_NAME, Lockfile, ParserError, RegistryRef,
+    detect_format, parse_file_with_provenance,
+};
+use contracts_validator::{DataSet, DataValidator, ValidationError, check_references};
+use futures::stream::{self, StreamExt};
+use serde_json::json;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
 use tracing::info;
 
-use crate::output;
+use crate::hooks::Hooks;
+use crate::i18n::{Msg, tr, trf};
+use crate::metrics::Metrics;
+use crate::output::{self, Verbosity};
+use crate::report;
+use crate::runs::{self, Completed, RunLog};
+use crate::sinks::Sinks;
 
+/// Rows sampled when `--sample-size` is not given, matching the validator default.
+pub(crate) const DEFAULT_SAMPLE_SIZE: usize = 1000;
+
+#[allow(clippy::too_many_arguments)]
 pub async fn execute(
     contract_path: &str,
-    strict: bool,
-    schema_only: bool,
-    sample_size: Option<usize>,
+    context: ValidationContext,
+    limits: Limits,
     format: &str,
+    files: ReportFiles<'_>,
+    read: ReadOptions,
+    verbosity: Verbosity,
+    resume: Option<&str>,
 ) -> Result<()> {
-    info!("Validating contract: {}", contract_path);
-    info!("Strict mode: {}", strict);
-    info!("Schema only: {}", schema_only);
-    if let Some(size) = sample_size {
+    info!("Strict mode: {}", context.strict);
+    info!("Schema only: {}", context.schema_only);
+    if let Some(size) = context.sample_size {
         info!("Sample size: {}", size);
     }
 
-    // Parse the contract file
+    // Catalog clients and request limits are shared by every contract validated in this run
+    let catalogs = CatalogCache::with_limits(limits.requests);
+    let metrics = Metrics::from_workspace()?;
+    let hooks = Hooks::from_workspace()?;
+    let sinks = Sinks::from_workspace()?;
+
     let path = Path::new(contract_path);
-    let contract = parse_file(path)
-        .with_context(|| format!("Failed to parse contract file: {}", contract_path))?;
+    if !path.is_dir() {
+        if resume.is_some() {
+            return Err(anyhow!("{}", tr(Msg::ResumeWithFile)));
+        }
+        let validated = validate_contract(
+            path, &context, format, files, &read, verbosity, &catalogs, &hooks,
+        )
+        .await;
+        let passed = match validated {
+            Ok((contract, report)) => {
+                metrics.record(&contract, &report);
+                sinks.store(&contract, &report).await;
+                report.passed
+            }
+            // Keep machine-readable output complete when validation cannot run
+            Err(e) if format == "json" || files.report.is_some() => {
+                let report = failure_report(path, &e);
+                match files.report {
+                    Some(out) => {
+                        let name = path.file_stem().unwrap_or_default().to_string_lossy();
+                        report::write_report(&report, &name, out)?;
+                        output::print_info(&trf(Msg::ReportWritten, &[&out.display()]));
+                        output::print_summary_line(&report);
+                    }
+                    None => output::print_validation_report(&report, format, verbosity),
+                }
+                false
+            }
+            Err(e) => return Err(e),
+        };
+        if !passed {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    if files.sample.is_some() {
+        return Err(anyhow!("{}", tr(Msg::DumpSampleWithDirectory)));
+    }
+    if files.report.is_some() {
+        return Err(anyhow!("{}", tr(Msg::OutputWithDirectory)));
+    }
+    if let Some(SnapshotSelector::Id(_)) = read.snapshot {
+        return Err(anyhow!("{}", tr(Msg::SnapshotIdWithDirectory)));
+    }
+
+    let files = collect_contract_files(path)?;
+    if files.is_empty() {
+        return Err(anyhow!(
+            "{}",
+            trf(Msg::NoContractsInDirectory, &[&path.display()])
+        ));
+    }
+
+    let mut run = match resume {
+        Some(id) => RunLog::resume(id, path)?,
+        None => RunLog::start(path),
+    };
+    // Contracts completed in the run with unchanged inputs are not validated again
+    let options = run_options(&context, &read);
+    let mut pending = Vec::with_capacity(files.len());
+    for file in &files {
+        let fingerprint = runs::fingerprint(file, &options)?;
+        let completed = run.completed(file, &fingerprint).cloned();
+        pending.push((file, fingerprint, completed));
+    }
+    if format != "json" {
+        let skipped = pending.iter().filter(|(_, _, c)| c.is_some()).count();
+        match resume {
+            Some(id) => output::print_info(&trf(Msg::RunResumed, &[&id, &skipped])),
+            None => output::print_info(&trf(Msg::RunStarted, &[&run.id()])),
+        }
+    }
+
+    // Contracts are validated concurrently, but reported in file order
+    let (context, read, catalogs, hooks) = (&context, &read, &catalogs, &hooks);
+    let mut validations = stream::iter(pending)
+        .map(|(file, fingerprint, completed)| async move {
+            if let Some(completed) = completed {
+                return (file, fingerprint, Ok(Outcome::Skipped(completed)));
+            }
+            let result = async {
+                let (contract, provenance) =
+                    parse_file_with_provenance(file).with_context(|| {
+                        format!("Failed to parse contract file: {}", file.display())
+                    })?;
+                let context = hooks.before(&contract, context).await?;
+                let report = validate_all(&contract, &context, None, read, catalogs)
+                    .await?
+                    .with_provenance(provenance);
+                hooks.after(&contract, &context, &report).await;
+                Ok::<_, anyhow::Error>(Outcome::Validated(Box::new(contract), Box::new(report)))
+            }
+            .await;
+            (file, fingerprint, result)
+        })
+        .buffered(limits.jobs);
+
+    let mut results = Vec::with_capacity(files.len());
+    let mut contracts = Vec::with_capacity(files.len());
+    let (mut total, mut failed) = (0, 0);
+    while let Some((file, fingerprint, result)) = validations.next().await {
+        total += 1;
+        let (name, report) = match result {
+            Ok(Outcome::Skipped(completed)) => {
+                if !completed.passed {
+                    failed += 1;
+                }
+                if format != "json" {
+                    let status = if completed.passed {
+                        tr(Msg::SegmentPassed)
+                    } else {
+                        tr(Msg::SegmentFailed)
+                    };
+                    output::print_info(&trf(
+                        Msg::ContractSkipped,
+                        &[&file.display(), &run.id(), &status],
+                    ));
+                }
+                contracts.push(json!({
+                    "path": file.display().to_string(),
+                    "name": completed.name,
+                    "skipped": true,
+                    "report": completed.report,
+                }));
+                continue;
+            }
+            Ok(Outcome::Validated(contract, report)) => {
+                metrics.record(&contract, &report);
+                sinks.store(&contract, &report).await;
+                if format != "json" {
+                    output::print_info(&trf(Msg::ValidatingContractFile, &[&file.display()]));
+                    print_contract_loaded(&contract, context);
+                    output::print_validation_report(&report, format, verbosity);
+                }
+                let json = output::json_report(&report, verbosity);
+                run.record(
+                    file,
+                    Completed {
+                        fingerprint,
+                        name: Some(contract.name.clone()),
+                        passed: report.passed,
+                        errors: report.errors.len(),
+                        warnings: report.warnings.len(),
+                        report: json.clone(),
+                    },
+                )?;
+                contracts.push(json!({
+                    "path": file.display().to_string(),
+                    "name": contract.name,
+                    "report": json,
+                }));
+                (Some(contract.name), *report)
+            }
+            // Not recorded, so resuming the run retries the contract
+            Err(e) => {
+                let report = failure_report(file, &e);
+                if format != "json" {
+                    output::print_error(&format!("{}: {}", file.display(), report.errors[0]));
+                }
+                contracts.push(json!({
+                    "path": file.display().to_string(),
+                    "name": null,
+                    "report": output::json_report(&report, verbosity),
+                }));
+                (None, report)
+            }
+        };
+        if !report.passed {
+            failed += 1;
+        }
+        results.push((file.display().to_string(), name, report));
+    }
+
+    if format == "json" {
+        let document = json!({
+            "passed": failed == 0,
+            "run_id": run.id(),
+            "total": total,
+            "failed": failed,
+            "contracts": contracts,
+        });
+        println!("{}", serde_json::to_string_pretty(&document)?);
+    } else {
+        output::print_contract_results(&results);
+        output::print_info(&trf(
+            Msg::DirectorySummary,
+            &[&total, &(total - failed), &failed, &catalogs.len().await],
+        ));
+    }
+
+    if failed > 0 {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+/// What became of a contract of a directory run.
+enum Outcome {
+    /// Validated in this run
+    Validated(Box<Contract>, Box<ValidationReport>),
+    /// Completed earlier in the resumed run
+    Skipped(Completed),
+}
+
+/// Builds a failed report for an error that stopped validation of `path`.
+///
+/// The report carries the code of the first coded error in the chain and the
+/// message of every cause, so catalog and parser failures are reported with
+/// the same detail as failed checks.
+fn failure_report(path: &Path, error: &anyhow::Error) -> ValidationReport {
+    error_report(error).with_provenance(ContractProvenance::new(path.display().to_string()))
+}
+
+/// Builds a failed report for an error that stopped validation, under the
+/// code of the first coded error in its chain.
+fn error_report(error: &anyhow::Error) -> ValidationReport {
+    let code = error
+        .chain()
+        .find_map(|e| {
+            e.downcast_ref::<IcebergError>()
+                .map(Coded::error_code)
+                .or_else(|| e.downcast_ref::<ParquetError>().map(Coded::error_code))
+                .or_else(|| e.downcast_ref::<FilesError>().map(Coded::error_code))
+                .or_else(|| e.downcast_ref::<ParserError>().map(Coded::error_code))
+                .or_else(|| e.downcast_ref::<ValidationError>().map(Coded::error_code))
+        })
+        .unwrap_or(&codes::GENERAL);
+    ValidationReport::from_error(code, &**error)
+}
+
+/// Returns the registry file of the published contract `reference` (`name@version`).
+///
+/// When a `dce.lock` exists in the working directory, the reference resolves
+/// to its pinned version, and references not yet pinned are added to it.
+pub fn resolve_from_registry(reference: &str, registry: Option<&str>) -> Result<PathBuf> {
+    let registry = open_registry(registry)?;
+    let reference: RegistryRef = reference.parse()?;
 
-    output::print_info(&format!(
-        "Contract loaded: {} v{} (owner: {})",
-        contract.name, contract.version, contract.owner
+    let lock_path = Path::new(LOCKFILE_NAME);
+    let path = if lock_path.exists() {
+        let mut lock = Lockfile::load(lock_path)?;
+        let pinned = lock.contracts.len();
+        let path = lock.resolve(&registry, &reference)?;
+        if lock.contracts.len() != pinned {
+            lock.save(lock_path)?;
+        }
+        path
+    } else {
+        registry.resolve(&reference)?
+    };
+
+    output::print_info(&trf(
+        Msg::ResolvedFromRegistry,
+        &[&reference, &path.display()],
     ));
+    Ok(path)
+}
 
-    // Create validation context with user-provided options
-    let context = ValidationContext {
-        strict,
-        schema_only,
-        sample_size,
-        metadata: Default::default(),
+/// Opens the registry given by `--registry`, falling back to `DCE_REGISTRY`.
+pub fn open_registry(registry: Option<&str>) -> Result<ContractRegistry> {
+    registry
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("DCE_REGISTRY").map(PathBuf::from))
+        .map(ContractRegistry::new)
+        .ok_or_else(|| anyhow!("{}", tr(Msg::NoRegistryConfigured)))
+}
+
+/// How much work `validate` runs at once.
+#[derive(Clone, Copy)]
+pub struct Limits {
+    /// Catalog and storage requests, shared by every contract of the run
+    pub requests: RequestLimits,
+    /// Contracts of a directory validated at once
+    pub jobs: usize,
+}
+
+/// Files that `validate` writes besides printing the report.
+#[derive(Clone, Copy, Default)]
+pub struct ReportFiles<'a> {
+    /// Destination of `--dump-sample`
+    pub sample: Option<&'a Path>,
+    /// Destination of `--output`
+    pub report: Option<&'a Path>,
+}
+
+/// How `validate` reads the data of a contract.
+#[derive(Debug, Clone, Default)]
+pub struct ReadOptions {
+    /// How CSV and JSON values are read
+    pub files: FileOptions,
+    /// Snapshot Iceberg tables are validated at, instead of their current one
+    pub snapshot: Option<SnapshotSelector>,
+    /// Whether Iceberg rows are validated batch by batch as they are read
+    pub chunked: bool,
+    /// Whether Iceberg tables are validated from their manifest statistics, without reading rows
+    pub stats_only: bool,
+    /// Scan tuning for Iceberg tables, overriding the contract's `scan.*` properties
+    pub scan: ScanOptions,
+}
+
+/// Describes the options a contract is validated with, for its fingerprint
+/// in a run log. Parallelism is left out, as it does not change the report.
+fn run_options(context: &ValidationContext, read: &ReadOptions) -> serde_json::Value {
+    let sampling = match &context.sampling {
+        SamplingStrategy::Head => json!({ "strategy": "head" }),
+        SamplingStrategy::Random { seed } => json!({ "strategy": "random", "seed": seed }),
+        SamplingStrategy::Stratified { column, seed } => {
+            json!({ "strategy": "stratified", "column": column, "seed": seed })
+        }
     };
+    let inference = match read.files.inference {
+        TypeInference::Contract => "contract",
+        TypeInference::Data => "data",
+        TypeInference::Strings => "strings",
+    };
+    json!({
+        "context": {
+            "strict": context.strict,
+            "schema_only": context.schema_only,
+            "sample_size": context.sample_size,
+            "sampling": sampling,
+            "on_empty": context.on_empty,
+            "time_window": context.time_window.as_ref().map(|window| json!({
+                "column": window.column,
+                "since": window.since.map(|t| t.to_rfc3339()),
+                "until": window.until.map(|t| t.to_rfc3339()),
+            })),
+            "row_filter": context.row_filter,
+            "segment_by": context.segment_by,
+            "fill_defaults": context.fill_defaults,
+            "max_errors": context.max_errors,
+            "max_errors_per_check": context.max_errors_per_check,
+            "fail_fast": context.fail_fast,
+            "metadata": context.metadata.iter().collect::<BTreeMap<_, _>>(),
+        },
+        "read": {
+            "files": {
+                "inference": inference,
+                "infer_max_records": read.files.infer_max_records,
+                "delimiter": read.files.delimiter,
+                "has_header": read.files.has_header,
+            },
+            "snapshot": read.snapshot,
+            "chunked": read.chunked,
+            "stats_only": read.stats_only,
+            "scan": {
+                "batch_size": read.scan.batch_size,
+                "target_split_size": read.scan.target_split_size,
+                "prefetch_depth": read.scan.prefetch_depth,
+            },
+        },
+    })
+}
+
+/// Validates a single contract file and prints its report.
+///
+/// Returns the parsed contract and its report.
+#[allow(clippy::too_many_arguments)]
+async fn validate_contract(
+    path: &Path,
+    context: &ValidationContext,
+    format: &str,
+    files: ReportFiles<'_>,
+    read: &ReadOptions,
+    verbosity: Verbosity,
+    catalogs: &CatalogCache,
+    hooks: &Hooks,
+) -> Result<(Contract, ValidationReport)> {
+    let started = Instant::now();
+    info!("Validating contract: {}", path.display());
+
+    // Parse the contract file
+    let (contract, provenance) = parse_file_with_provenance(path)
+        .with_context(|| format!("Failed to parse contract file: {}", path.display()))?;
+    let parse_time = started.elapsed();
+
+    print_contract_loaded(&contract, context);
+
+    if files.sample.is_some()
+        && (context.schema_only || contract.schema.format != DataFormat::Iceberg)
+    {
+        output::print_info(tr(Msg::DumpSampleUnsupported));
+    }
+    if read.stats_only && !context.schema_only && contract.schema.format != DataFormat::Iceberg {
+        output::print_info(tr(Msg::StatsOnlyUnsupported));
+    }
+
+    let context = &hooks.before(&contract, context).await?;
+    let report = validate_all(&contract, context, files.sample, read, catalogs).await?;
+
+    let validation_time = started.elapsed() - parse_time;
+    let report = report.with_provenance(provenance);
+    hooks.after(&contract, context, &report).await;
+
+    // Print the validation report, or write it and keep stdout to the summary
+    if let Some(path) = files.report {
+        report::write_report(&report, &contract.name, path)?;
+        output::print_info(&trf(Msg::ReportWritten, &[&path.display()]));
+        output::print_summary_line(&report);
+    } else {
+        output::print_validation_report(&report, format, verbosity);
+    }
+
+    if verbosity >= Verbosity::Detailed && format != "json" && files.report.is_none() {
+        output::print_timings(&[
+            (Msg::PhaseParsing, parse_time),
+            (Msg::PhaseValidation, validation_time),
+            (Msg::PhaseTotal, started.elapsed()),
+        ]);
+    }
+
+    Ok((contract, report))
+}
+
+/// Prints which contract was loaded and the row filters applied to its data.
+fn print_contract_loaded(contract: &Contract, context: &ValidationContext) {
+    output::print_info(&trf(
+        Msg::ContractLoaded,
+        &[&contract.name, &contract.version, &contract.owner],
+    ));
+    if context.schema_only {
+        return;
+    }
+    if let Some(window) = &context.time_window {
+        let bound = |b: Option<chrono::DateTime<chrono::Utc>>| {
+            b.map_or_else(|| "…".to_string(), |t| t.to_rfc3339())
+        };
+        output::print_info(&trf(
+            Msg::TimeWindowFilter,
+            &[
+                &context.time_window_column(contract).unwrap_or("?"),
+                &bound(window.since),
+                &bound(window.until),
+            ],
+        ));
+    }
+    if let Some(filter) = &context.row_filter {
+        output::print_info(&trf(Msg::RowFilter, &[filter]));
+    }
+}
 
-    // Dispatch to appropriate validator based on contract format
+/// Validates the data at a contract's location with the validator of its format.
+pub async fn validate_data(
+    contract: &contracts_core::Contract,
+    context: &ValidationContext,
+    dump_sample: Option<&Path>,
+    read: &ReadOptions,
+    catalogs: &CatalogCache,
+) -> Result<ValidationReport> {
+    let schema_only = context.schema_only;
     let report = match contract.schema.format {
         DataFormat::Iceberg => {
             // In schema-only mode, skip catalog connection
             if schema_only {
-                output::print_info(
-                    "Schema-only mode: validating contract structure without catalog",
-                );
+                output::print_info(tr(Msg::SchemaOnlyWithoutCatalog));
                 let dataset = DataSet::empty();
                 let mut validator = DataValidator::new();
                 validator
-                    .validate_with_data_async(&contract, &dataset, &context)
+                    .validate_with_data_async(contract, &dataset, context)
                     .await
             } else {
-                output::print_info("Detected Iceberg format, connecting to catalog...");
-                validate_iceberg_table(&contract, &context).await?
+                output::print_info(tr(Msg::DetectedIceberg));
+                validate_iceberg_table(contract, context, read, catalogs, dump_sample).await?
+            }
+        }
+        DataFormat::Parquet => {
+            if schema_only {
+                output::print_info(tr(Msg::SchemaOnlyFromFooters));
+            } else {
+                output::print_info(&trf(
+                    Msg::ReadingFile,
+                    &[&"Parquet", &contract.schema.location],
+                ));
             }
+            let location = ParquetLocation::parse(&contract.schema.location)?
+                .with_limiter(catalogs.limiter().clone());
+            ParquetValidator::with_location(location)
+                .await?
+                .validate(contract, context)
+                .await
+                .context("Validation failed")?
         }
-        DataFormat::Parquet | DataFormat::Csv | DataFormat::Json => {
+        DataFormat::Csv | DataFormat::Json => {
             if schema_only {
-                output::print_info("Schema-only mode: validating contract structure without data");
+                output::print_info(tr(Msg::SchemaOnlyWithoutData));
                 let dataset = DataSet::empty();
                 let mut validator = DataValidator::new();
                 validator
-                    .validate_with_data_async(&contract, &dataset, &context)
+                    .validate_with_data_async(contract, &dataset, context)
                     .await
             } else {
-                output::print_info(&format!(
-                    "Reading {:?} file from: {}",
-                    contract.schema.format, contract.schema.location
+                output::print_info(&trf(
+                    Msg::ReadingFile,
+                    &[
+                        &format!("{:?}", contract.schema.format),
+                        &contract.schema.location,
+                    ],
                 ));
-                let ctx = contracts_validator::register_file_as_table(
-                    &contract.schema.format,
-                    &contract.schema.location,
-                    context.sample_size,
-                )
-                .await
-                .map_err(|e| anyhow!("{}", e))?;
-
-                let mut validator = DataValidator::new();
-                validator
-                    .validate_with_context(&contract, &ctx, &context)
+                let file_format = FileFormat::from_data_format(&contract.schema.format)
+                    .expect("CSV and JSON are file formats");
+                let location = FileLocation::parse(&contract.schema.location)?
+                    .with_limiter(catalogs.limiter().clone());
+                FileValidator::with_location(location, file_format)
+                    .await?
+                    .with_options(read.files.clone())
+                    .validate(contract, context)
                     .await
+                    .context("Validation failed")?
             }
         }
         _ => {
-            output::print_info(&format!(
-                "Format {:?} not yet fully supported, performing schema-only validation",
-                contract.schema.format
+            output::print_info(&trf(
+                Msg::FormatNotSupported,
+                &[&format!("{:?}", contract.schema.format)],
             ));
             let dataset = DataSet::empty();
             let mut validator = DataValidator::new();
             validator
-                .validate_with_data_async(&contract, &dataset, &context)
+                .validate_with_data_async(contract, &dataset, context)
                 .await
         }
     };
+    Ok(report)
+}
 
-    // Print the validation report
-    output::print_validation_report(&report, format);
+/// Validates the data of a contract and of its related datasets, then runs
+/// the referential checks between them.
+async fn validate_all(
+    contract: &Contract,
+    context: &ValidationContext,
+    dump_sample: Option<&Path>,
+    read: &ReadOptions,
+    catalogs: &CatalogCache,
+) -> Result<ValidationReport> {
+    let mut report = validate_data(contract, context, dump_sample, read, catalogs).await?;
+    let mut datasets = validate_datasets(contract, context, read, catalogs).await;
+    if !context.schema_only {
+        let reports = std::iter::once(&mut report)
+            .chain(datasets.iter_mut().map(|dataset| &mut dataset.report))
+            .collect();
+        run_referential_checks(contract, reports, context.strict, &read.files, catalogs).await;
+    }
+    Ok(report.with_datasets(datasets))
+}
 
-    if !report.passed {
-        std::process::exit(1);
+/// Runs the referential checks of a contract and of its related datasets,
+/// adding their failures to `reports`: the contract's, then one per dataset
+/// in name order.
+///
+/// Both sides of a check are read in full, and each dataset once.
+async fn run_referential_checks(
+    contract: &Contract,
+    mut reports: Vec<&mut ValidationReport>,
+    strict: bool,
+    files: &FileOptions,
+    catalogs: &CatalogCache,
+) {
+    let datasets = contract.dataset_contracts();
+    let parts: Vec<(&str, &Contract)> = std::iter::once((contract.name.as_str(), contract))
+        .chain(datasets.iter().map(|(name, dataset)| (*name, dataset)))
+        .collect();
+    let mut data: HashMap<&str, DataSet> = HashMap::new();
+
+    for (i, (name, part)) in parts.iter().enumerate() {
+        let checks = part
+            .quality_checks
+            .as_ref()
+            .and_then(|qc| qc.referential.as_ref());
+        for check in checks.into_iter().flatten() {
+            let target = check
+                .target()
+                .and_then(|(dataset, _)| parts.iter().find(|(other, _)| *other == dataset));
+            let Some((target_name, target)) = target else {
+                let error = ValidationError::ReferentialIntegrity {
+                    field: check.field.clone(),
+                    message: format!(
+                        "'{}' does not name a field of a dataset of the contract",
+                        check.references
+                    ),
+                };
+                reports[i].add_error(error.to_violation());
+                continue;
+            };
+
+            let loaded = async {
+                for (name, part) in [(*name, *part), (*target_name, *target)] {
+                    if !data.contains_key(name) {
+                        data.insert(name, read_all(part, files, catalogs).await?);
+                    }
+                }
+                Ok::<_, anyhow::Error>(())
+            }
+            .await;
+            let violation = match loaded {
+                Ok(()) => check_references(check, &data[name], &data[target_name], strict),
+                Err(e) => error_report(&e).errors.into_iter().next(),
+            };
+            if let Some(violation) = violation {
+                reports[i].add_violation(violation);
+            }
+        }
     }
+}
 
-    Ok(())
+/// Validates the related datasets of a multi-dataset contract, one after
+/// the other.
+///
+/// A dataset that cannot be validated gets a failed report instead of
+/// stopping the others. Snapshot IDs belong to the primary table, so the
+/// datasets are read at their current snapshot instead.
+pub async fn validate_datasets(
+    contract: &Contract,
+    context: &ValidationContext,
+    read: &ReadOptions,
+    catalogs: &CatalogCache,
+) -> Vec<DatasetReport> {
+    let mut read = read.clone();
+    if let Some(SnapshotSelector::Id(_)) = read.snapshot {
+        read.snapshot = None;
+    }
+    let mut reports = Vec::new();
+    for (name, dataset) in contract.dataset_contracts() {
+        output::print_info(&trf(Msg::ValidatingDataset, &[&name]));
+        let report = validate_data(&dataset, context, None, &read, catalogs)
+            .await
+            .unwrap_or_else(|e| error_report(&e));
+        reports.push(DatasetReport {
+            name: name.to_string(),
+            report,
+        });
+    }
+    reports
+}
+
+/// Reads every row at the contract's location.
+pub async fn read_all(
+    contract: &Contract,
+    file_options: &FileOptions,
+    catalogs: &CatalogCache,
+) -> Result<DataSet> {
+    read_rows(contract, file_options, catalogs, usize::MAX).await
+}
+
+/// Reads the first `limit` rows at the contract's location.
+pub async fn read_rows(
+    contract: &Contract,
+    file_options: &FileOptions,
+    catalogs: &CatalogCache,
+    limit: usize,
+) -> Result<DataSet> {
+    match FileFormat::from_data_format(&contract.schema.format) {
+        Some(file_format) => Ok(FileValidator::with_location(
+            FileLocation::parse(&contract.schema.location)?
+                .with_limiter(catalogs.limiter().clone()),
+            file_format,
+        )
+        .await?
+        .with_options(file_options.clone())
+        .read_data_set(contract, limit)
+        .await?),
+        None => Ok(
+            iceberg_validator(contract, &ReadOptions::default(), catalogs)
+                .await?
+                .read_sample_data(limit)
+                .await
+                .context("Failed to read table data")?,
+        ),
+    }
+}
+
+/// Recursively collects YAML and TOML contract files under `dir`, sorted by path,
+/// leaving out `dce.toml`.
+pub fn collect_contract_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let entries = std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read directory: {}", dir.display()))?;
+
+    for entry in entries {
+        let path = entry?.path();
+        if path.is_dir() {
+            files.extend(collect_contract_files(&path)?);
+        } else if detect_format(&path).is_ok()
+            && path.file_name().is_none_or(|name| name != CONFIG_NAME)
+        {
+            files.push(path);
+        }
+    }
+
+    files.sort();
+    Ok(files)
 }
 
 /// Validates an Iceberg table against a contract.
-///
-/// Extracts catalog configuration from environment variables and contract location.
 async fn validate_iceberg_table(
     contract: &contracts_core::Contract,
     context: &ValidationContext,
+    read: &ReadOptions,
+    catalogs: &CatalogCache,
+    dump_sample: Option<&Path>,
 ) -> Result<contracts_core::ValidationReport> {
-    // Parse location to extract namespace and table name
-    // Expected formats:
-    // - s3://warehouse/namespace/table
-    // - /path/to/warehouse/namespace/table
-    let location = &contract.schema.location;
+    let validator = iceberg_validator(contract, read, catalogs)
+        .await?
+        .with_chunked_validation(read.chunked)
+        .with_sampling(context.sampling.clone());
 
-    // Extract namespace and table name from location
-    // This is a simplified parser - in production you'd want more robust parsing
-    let (namespace, table_name) = parse_iceberg_location(location)?;
+    let report = if read.stats_only {
+        output::print_info(tr(Msg::ReadingIcebergStats));
+        validator.validate_from_stats(contract, context).await
+    } else {
+        output::print_info(tr(Msg::ReadingIcebergTable));
+        validator.validate_table(contract, context).await
+    }
+    .context("Validation failed")?;
+
+    if let Some(path) = dump_sample {
+        let sample = validator
+            .read_sample_data(context.sample_size.unwrap_or(DEFAULT_SAMPLE_SIZE))
+            .await
+            .context("Failed to read sample data")?;
+        write_sample(&sample, path)?;
+        output::print_info(&trf(Msg::SampleDumped, &[&sample.len(), &path.display()]));
+    }
+
+    Ok(report)
+}
 
-    output::print_info(&format!(
-        "Parsed location: namespace={}, table={}",
-        namespace.join("."),
-        table_name
+/// Connects to the Iceberg table of a contract.
+///
+/// Catalog settings come from the contract's `connection:` block, with
+/// environment variables overriding the embedded URI and warehouse. Reads are
+/// pinned to `read.snapshot` when one is given and tuned with `read.scan`.
+pub async fn iceberg_validator(
+    contract: &contracts_core::Contract,
+    read: &ReadOptions,
+    catalogs: &CatalogCache,
+) -> Result<IcebergValidator> {
+    let (config, reference) = iceberg_config(contract, read)?;
+    output::print_info(&trf(
+        Msg::ParsedLocation,
+        &[&reference.namespace_string(), &reference.table],
     ));
+    if let CatalogType::Rest { uri, .. } = &config.catalog {
+        output::print_info(&trf(Msg::UsingRestCatalog, &[uri]));
+    }
+
+    // Create validator and validate
+    output::print_info(tr(Msg::ConnectingToCatalog));
+    IcebergValidator::with_cache(config, catalogs)
+        .await
+        .context(
+            "Failed to connect to Iceberg catalog. Check that:\n\
+                  1. The catalog is running and accessible\n\
+                  2. Network connectivity is available\n\
+                  3. Credentials are configured correctly (for cloud storage)",
+        )
+}
 
-    // Get catalog configuration from environment variables
+/// Returns the catalog connection of a contract: its `connection:` block,
+/// with the URI and warehouse overridden by environment variables.
+///
+/// Secret references are left unresolved.
+pub fn connection_settings(contract: &contracts_core::Contract) -> Result<ConnectionConfig> {
+    // Environment variables take precedence over the contract's connection block
     // REST_CATALOG_URI: e.g., "http://localhost:8181"
-    // WAREHOUSE: e.g., "s3://warehouse" or derived from location
-    let catalog_uri = std::env::var("REST_CATALOG_URI")
+    // WAREHOUSE: e.g., "s3://warehouse"
+    let env_uri = std::env::var("REST_CATALOG_URI")
         .ok()
         .or_else(|| std::env::var("ICEBERG_REST_URI").ok());
 
-    let warehouse = std::env::var("WAREHOUSE")
+    let env_warehouse = std::env::var("WAREHOUSE")
         .ok()
-        .or_else(|| std::env::var("ICEBERG_WAREHOUSE").ok())
-        .or_else(|| extract_warehouse_from_location(location));
+        .or_else(|| std::env::var("ICEBERG_WAREHOUSE").ok());
 
-    // Build Iceberg configuration
-    let config = if let (Some(uri), Some(warehouse)) = (catalog_uri, warehouse) {
-        output::print_info(&format!("Using REST catalog: {}", uri));
-        IcebergConfig::builder()
-            .rest_catalog(uri, warehouse)
-            .namespace(namespace)
-            .table_name(table_name)
-            .build()
-            .context("Failed to build Iceberg configuration")?
-    } else {
+    let mut connection = contract.schema.connection.clone().unwrap_or_default();
+    if env_uri.is_none() && connection.uri.is_none() && connection.catalog_type.is_none() {
         return Err(anyhow!(
-            "Missing Iceberg catalog configuration. Please set environment variables:\n\
+            "Missing Iceberg catalog configuration. Add a schema.connection block to the \
+             contract or set environment variables:\n\
              - REST_CATALOG_URI or ICEBERG_REST_URI (e.g., http://localhost:8181)\n\
              - WAREHOUSE or ICEBERG_WAREHOUSE (e.g., s3://my-warehouse)\n\
              \n\
@@ -162,59 +845,44 @@ async fn validate_iceberg_table(
              export REST_CATALOG_URI=http://localhost:8181\n\
              export WAREHOUSE=s3://my-data-lake"
         ));
-    };
+    }
+    connection.uri = env_uri.or(connection.uri);
+    connection.warehouse = env_warehouse.or(connection.warehouse);
+    Ok(connection)
+}
 
-    // Create validator and validate
-    output::print_info("Connecting to Iceberg catalog...");
-    let validator = IcebergValidator::new(config).await.context(
-        "Failed to connect to Iceberg catalog. Check that:\n\
-                  1. The catalog is running and accessible\n\
-                  2. Network connectivity is available\n\
-                  3. Credentials are configured correctly (for cloud storage)",
-    )?;
+/// Builds the catalog configuration of a contract's Iceberg table, and the
+/// table it resolves to.
+pub fn iceberg_config(
+    contract: &contracts_core::Contract,
+    read: &ReadOptions,
+) -> Result<(IcebergConfig, TableReference)> {
+    let location = &contract.schema.location;
+    let mut connection = connection_settings(contract)?;
 
-    output::print_info("Reading data from Iceberg table...");
+    // Resolve the table from explicit schema fields, an iceberg:// URI, or the
+    // storage location (relative to the warehouse when known)
+    let reference = TableReference::resolve(&contract.schema, connection.warehouse.as_deref())?;
 
-    // Use the unified API with ValidationContext
-    let report = validator
-        .validate_table(contract, context)
-        .await
-        .context("Validation failed")?;
+    // REST catalogs commonly use the catalog name as the warehouse identifier
+    connection.warehouse = connection
+        .warehouse
+        .or_else(|| reference.catalog.clone())
+        .or_else(|| extract_warehouse_from_location(location));
 
-    Ok(report)
+    let mut config = IcebergConfig::from_connection(&connection, &reference)
+        .context("Failed to build Iceberg configuration")?;
+    config.snapshot = read.snapshot;
+    config.properties.extend(read.scan.properties());
+    Ok((config, reference))
 }
 
-/// Parses an Iceberg location to extract namespace and table name.
-///
-/// Examples:
-/// - "s3://warehouse/db/table" -> (["db"], "table")
-/// - "/warehouse/db.schema/table" -> (["db", "schema"], "table")
-fn parse_iceberg_location(location: &str) -> Result<(Vec<String>, String)> {
-    // Remove scheme if present (s3://, file://, etc.)
-    let path = location
-        .strip_prefix("s3://")
-        .or_else(|| location.strip_prefix("file://"))
-        .or_else(|| location.strip_prefix("hdfs://"))
-        .unwrap_or(location);
-
-    // Split by '/' and take the last components
-    let parts: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
-
-    if parts.len() < 2 {
-        return Err(anyhow!(
-            "Invalid Iceberg location format: {}. Expected format: <warehouse>/<namespace>/<table>",
-            location
-        ));
-    }
-
-    // Last part is table name, second-to-last is namespace (may contain dots)
-    let table_name = parts[parts.len() - 1].to_string();
-    let namespace_part = parts[parts.len() - 2];
-
-    // Namespace may be dot-separated (e.g., "db.schema")
-    let namespace: Vec<String> = namespace_part.split('.').map(String::from).collect();
-
-    Ok((namespace, table_name))
+/// Writes sampled rows as pretty-printed JSON.
+fn write_sample(sample: &DataSet, path: &Path) -> Result<()> {
+    let file = std::fs::File::create(path)
+        .with_context(|| format!("Failed to create sample file: {}", path.display()))?;
+    serde_json::to_writer_pretty(std::io::BufWriter::new(file), sample)
+        .with_context(|| format!("Failed to write sample file: {}", path.display()))
 }
 
 /// Extracts warehouse path from a full location.
@@ -0,0 +1,136 @@
+use anyhow::{Context, Result, anyhow};
+use contracts_core::{DataFormat, ValidationContext};
+use contracts_iceberg::wap::{Publication, StagedRef, StagedSnapshot};
+use contracts_iceberg::{CatalogCache, RequestLimits, TableReference};
+use contracts_parser::parse_file_with_provenance;
+use serde_json::json;
+use std::path::Path;
+
+use crate::commands::validate::{ReadOptions, iceberg_validator};
+use crate::i18n::{Msg, trf};
+use crate::output::{self, Verbosity};
+
+/// Spark catalog the publish calls name when the contract does not.
+const DEFAULT_SPARK_CATALOG: &str = "spark_catalog";
+
+/// Validates a staged snapshot of the Iceberg table of a contract, and prints
+/// how to publish it to `main` when it passes.
+///
+/// iceberg-rust cannot move table refs, so the snapshot is not published
+/// here: the Spark procedure call that publishes it is printed instead.
+pub async fn execute(
+    contract_path: &str,
+    staged: StagedRef,
+    context: ValidationContext,
+    limits: RequestLimits,
+    catalog_name: Option<&str>,
+    format: &str,
+    verbosity: Verbosity,
+) -> Result<()> {
+    let path = Path::new(contract_path);
+    let (contract, provenance) = parse_file_with_provenance(path)
+        .with_context(|| format!("Failed to parse contract file: {}", path.display()))?;
+    if contract.schema.format != DataFormat::Iceberg {
+        return Err(anyhow!("{}", trf(Msg::WapNeedsIceberg, &[&contract.name])));
+    }
+
+    let catalogs = CatalogCache::with_limits(limits);
+    let validator = iceberg_validator(&contract, &ReadOptions::default(), &catalogs).await?;
+    let target = validator
+        .staged_snapshot(&staged)
+        .await
+        .with_context(|| format!("Failed to find {}", staged))?;
+
+    output::print_info(&trf(
+        Msg::WapAuditing,
+        &[
+            &staged,
+            &target.snapshot.snapshot_id,
+            &target.snapshot.operation,
+        ],
+    ));
+
+    let report = validator
+        .with_snapshot(target.snapshot.snapshot_id)
+        .validate_table(&contract, &context)
+        .await
+        .context("Validation failed")?
+        .with_provenance(provenance);
+
+    // Spark addresses the table as <namespace>.<table> within a catalog
+    let reference = table_reference(&contract);
+    let catalog = catalog_name
+        .map(str::to_string)
+        .or_else(|| reference.catalog.clone())
+        .unwrap_or_else(|| DEFAULT_SPARK_CATALOG.to_string());
+    let table = format!("{}.{}", reference.namespace_string(), reference.table);
+    let publish_sql = report
+        .passed
+        .then(|| target.publish_sql(&catalog, &table))
+        .flatten();
+
+    if format == "json" {
+        let mut output = output::json_report(&report, verbosity);
+        output["wap"] = json!({
+            "snapshot_id": target.snapshot.snapshot_id,
+            "branch": target.branch,
+            "main_snapshot_id": target.main_snapshot_id,
+            "publication": publication_name(&target.publication),
+            "publish_sql": publish_sql,
+        });
+        println!("{}", serde_json::to_string_pretty(&output)?);
+    } else {
+        output::print_validation_report(&report, format, verbosity);
+        print_publication(&target, report.passed, publish_sql.as_deref());
+    }
+
+    if !report.passed {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Table identity of a contract, for the publish calls.
+///
+/// Warehouse-relative locations resolve without the warehouse prefix being
+/// stripped; set `schema.namespace` and `schema.table` for such contracts.
+fn table_reference(contract: &contracts_core::Contract) -> TableReference {
+    TableReference::resolve(&contract.schema, None).unwrap_or_else(|_| TableReference {
+        catalog: None,
+        namespace: Vec::new(),
+        table: contract.name.clone(),
+    })
+}
+
+fn print_publication(target: &StagedSnapshot, passed: bool, publish_sql: Option<&str>) {
+    let snapshot_id = target.snapshot.snapshot_id;
+    if !passed {
+        output::print_error(&trf(Msg::WapNotPublished, &[&snapshot_id]));
+        return;
+    }
+    match (&target.publication, publish_sql) {
+        (Publication::UpToDate, _) => {
+            output::print_info(&trf(Msg::WapUpToDate, &[&snapshot_id]));
+        }
+        (Publication::Diverged { snapshots }, _) => {
+            let snapshots = snapshots
+                .iter()
+                .map(i64::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+            output::print_info(&trf(Msg::WapDiverged, &[&snapshots]));
+        }
+        (_, Some(sql)) => output::print_info(&trf(Msg::WapPublishWith, &[&sql])),
+        (_, None) => {}
+    }
+}
+
+/// Machine-readable name of a publication, as used in JSON output.
+fn publication_name(publication: &Publication) -> &'static str {
+    match publication {
+        Publication::UpToDate => "up_to_date",
+        Publication::FastForward => "fast_forward",
+        Publication::CherryPick => "cherry_pick",
+        Publication::Diverged { .. } => "diverged",
+    }
+}
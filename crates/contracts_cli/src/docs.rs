@@ -0,0 +1,468 @@
+//! Contract documentation for wikis (`dce publish`).
+//!
+//! A contract is rendered once into a [`Document`] of headings, paragraphs
+//! and tables, which is then written in the markup of each target: the
+//! storage format (XHTML) of Confluence pages or the blocks of Notion pages.
+
+use contracts_core::{Contract, FreshnessSource, QualityChecks, declared_access};
+use serde_json::{Value, json};
+
+use crate::i18n::{Msg, tr, trf};
+use crate::report::escape;
+
+/// Longest text Notion accepts in one rich text object.
+const NOTION_TEXT_LIMIT: usize = 2000;
+
+/// Most rows Notion accepts in one table, header included.
+const NOTION_TABLE_ROWS: usize = 100;
+
+/// Documentation of one contract.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Document {
+    /// Page title, the contract name
+    pub title: String,
+
+    /// Page content
+    pub blocks: Vec<Block>,
+}
+
+/// A piece of page content.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Block {
+    Heading(String),
+    Paragraph(String),
+    /// Label and value pairs, shown as a two-column table
+    Properties(Vec<(String, String)>),
+    Table {
+        header: Vec<String>,
+        rows: Vec<Vec<String>>,
+    },
+}
+
+impl Document {
+    /// Renders the documentation of `contract`: its description and
+    /// properties, fields, field access, quality checks and SLA.
+    pub fn of(contract: &Contract) -> Self {
+        let mut blocks = Vec::new();
+        if let Some(description) = &contract.description {
+            blocks.push(Block::Paragraph(description.clone()));
+        }
+        blocks.push(Block::Properties(vec![
+            (tr(Msg::LabelVersion).to_string(), contract.version.clone()),
+            (tr(Msg::LabelOwner).to_string(), contract.owner.clone()),
+            (
+                tr(Msg::LabelFormat).to_string(),
+                format!("{:?}", contract.schema.format),
+            ),
+            (
+                tr(Msg::LabelLocation).to_string(),
+                contract.schema.location.clone(),
+            ),
+        ]));
+
+        blocks.push(Block::Heading(tr(Msg::LabelFields).to_string()));
+        blocks.push(Block::Table {
+            header: [
+                Msg::ColumnField,
+                Msg::ColumnType,
+                Msg::ColumnNullable,
+                Msg::LabelDescription,
+                Msg::ColumnConstraints,
+            ]
+            .map(|msg| tr(msg).to_string())
+            .to_vec(),
+            rows: contract
+                .schema
+                .fields
+                .iter()
+                .map(|field| {
+                    let mut description = field.description.clone().unwrap_or_default();
+                    if let Some(deprecation) = &field.deprecated {
+                        let note = match &deprecation.replacement {
+                            Some(replacement) => trf(Msg::DeprecatedUse, &[replacement]),
+                            None => tr(Msg::Deprecated).to_string(),
+                        };
+                        description = [description, note]
+                            .into_iter()
+                            .filter(|part| !part.is_empty())
+                            .collect::<Vec<_>>()
+                            .join(". ");
+                    }
+                    vec![
+                        field.name.clone(),
+                        field.field_type.to_string(),
+                        tr(if field.nullable { Msg::Yes } else { Msg::No }).to_string(),
+                        description,
+                        field
+                            .constraints
+                            .iter()
+                            .flatten()
+                            .map(ToString::to_string)
+                            .collect::<Vec<_>>()
+                            .join("; "),
+                    ]
+                })
+                .collect(),
+        });
+
+        let access = declared_access(&contract.schema);
+        if !access.is_empty() {
+            blocks.push(Block::Heading(tr(Msg::LabelAccess).to_string()));
+            blocks.push(Block::Table {
+                header: [
+                    Msg::ColumnField,
+                    Msg::ColumnClassification,
+                    Msg::ColumnAllowedRoles,
+                ]
+                .map(|msg| tr(msg).to_string())
+                .to_vec(),
+                rows: access
+                    .into_iter()
+                    .map(|(field, access)| {
+                        vec![
+                            field,
+                            access.classification.unwrap_or_default(),
+                            access.allowed_roles.join(", "),
+                        ]
+                    })
+                    .collect(),
+            });
+        }
+
+        if let Some(checks) = &contract.quality_checks {
+            let checks = describe_checks(checks);
+            if !checks.is_empty() {
+                blocks.push(Block::Heading(tr(Msg::LabelQualityChecks).to_string()));
+                blocks.push(Block::Properties(checks));
+            }
+        }
+
+        if let Some(sla) = &contract.sla {
+            let mut terms = Vec::new();
+            if let Some(availability) = sla.availability {
+                terms.push((
+                    tr(Msg::LabelAvailability).to_string(),
+                    availability.to_string(),
+                ));
+            }
+            if let Some(response_time) = &sla.response_time {
+                terms.push((
+                    tr(Msg::LabelResponseTime).to_string(),
+                    response_time.clone(),
+                ));
+            }
+            if let Some(penalties) = &sla.penalties {
+                terms.push((tr(Msg::LabelPenalties).to_string(), penalties.clone()));
+            }
+            if !terms.is_empty() {
+                blocks.push(Block::Heading(tr(Msg::LabelSla).to_string()));
+                blocks.push(Block::Properties(terms));
+            }
+        }
+
+        Self {
+            title: contract.name.clone(),
+            blocks,
+        }
+    }
+
+    /// Writes the document in Confluence's storage format.
+    pub fn to_confluence(&self) -> String {
+        let mut page = String::new();
+        for block in &self.blocks {
+            match block {
+                Block::Heading(text) => page.push_str(&format!("<h2>{}</h2>", escape(text))),
+                Block::Paragraph(text) => page.push_str(&format!("<p>{}</p>", escape(text))),
+                Block::Properties(properties) => {
+                    page.push_str("<table><tbody>");
+                    for (label, value) in properties {
+                        page.push_str(&format!(
+                            "<tr><th>{}</th><td>{}</td></tr>",
+                            escape(label),
+                            escape(value)
+                        ));
+                    }
+                    page.push_str("</tbody></table>");
+                }
+                Block::Table { header, rows } => {
+                    page.push_str("<table><tbody><tr>");
+                    for cell in header {
+                        page.push_str(&format!("<th>{}</th>", escape(cell)));
+                    }
+                    page.push_str("</tr>");
+                    for row in rows {
+                        page.push_str("<tr>");
+                        for cell in row {
+                            page.push_str(&format!("<td>{}</td>", escape(cell)));
+                        }
+                        page.push_str("</tr>");
+                    }
+                    page.push_str("</tbody></table>");
+                }
+            }
+        }
+        page
+    }
+
+    /// Writes the document as Notion blocks.
+    ///
+    /// Tables longer than Notion allows are split, repeating the header.
+    pub fn to_notion(&self) -> Vec<Value> {
+        let mut blocks = Vec::new();
+        for block in &self.blocks {
+            match block {
+                Block::Heading(text) => blocks.push(json!({
+                    "object": "block",
+                    "type": "heading_2",
+                    "heading_2": { "rich_text": notion_text(text) },
+                })),
+                Block::Paragraph(text) => blocks.push(json!({
+                    "object": "block",
+                    "type": "paragraph",
+                    "paragraph": { "rich_text": notion_text(text) },
+                })),
+                Block::Properties(properties) => {
+                    let rows: Vec<Vec<String>> = properties
+                        .iter()
+                        .map(|(label, value)| vec![label.clone(), value.clone()])
+                        .collect();
+                    blocks.push(notion_table(None, &rows));
+                }
+                Block::Table { header, rows } => {
+                    for rows in rows.chunks(NOTION_TABLE_ROWS - 1) {
+                        blocks.push(notion_table(Some(header), rows));
+                    }
+                }
+            }
+        }
+        blocks
+    }
+}
+
+/// Describes each quality check in one line, by check name.
+fn describe_checks(checks: &QualityChecks) -> Vec<(String, String)> {
+    let mut described = Vec::new();
+    if let Some(completeness) = &checks.completeness {
+        described.push((
+            "completeness".to_string(),
+            format!(
+                "at least {}% non-null: {}",
+                completeness.threshold * 100.0,
+                completeness.fields.join(", ")
+            ),
+        ));
+    }
+    if let Some(uniqueness) = &checks.uniqueness {
+        let mut text = format!("unique: {}", uniqueness.fields.join(", "));
+        if let Some(scope) = &uniqueness.scope {
+            text.push_str(&format!(" ({})", scope));
+        }
+        described.push(("uniqueness".to_string(), text));
+    }
+    if let Some(freshness) = &checks.freshness {
+        let mut text = match freshness.source() {
+            FreshnessSource::Column => {
+                format!("{} at most {} old", freshness.metric, freshness.max_delay)
+            }
+            FreshnessSource::SnapshotCommittedAt => {
+                format!("last snapshot at most {} old", freshness.max_delay)
+            }
+            FreshnessSource::Partition => format!(
+                "newest {} partition at most {} old",
+                freshness.metric, freshness.max_delay
+            ),
+        };
+        if let Some(lateness) = &freshness.allowed_lateness {
+            text.push_str(&format!(", plus {} for late data", lateness));
+        }
+        described.push(("freshness".to_string(), text));
+    }
+    if let Some(volume) = &checks.volume {
+        let mut text = format!(
+            "at least {} rows per {} of {}",
+            volume.min_rows, volume.bucket, volume.metric
+        );
+        if let Some(lateness) = &volume.allowed_lateness {
+            text.push_str(&format!(", counting rows up to {} late", lateness));
+        }
+        described.push(("volume".to_string(), text));
+    }
+    if let Some(deleted_rows) = &checks.deleted_rows {
+        described.push((
+            "deleted_rows".to_string(),
+            format!(
+                "at most {}% deleted per snapshot",
+                deleted_rows.max_fraction * 100.0
+            ),
+        ));
+    }
+    if let Some(column) = checks
+        .soft_delete
+        .as_ref()
+        .and_then(|s| s.excluded_column())
+    {
+        described.push((
+            "soft_delete".to_string(),
+            format!("rows deleted through {} left out of the checks", column),
+        ));
+    }
+    for reference in checks.referential.iter().flatten() {
+        described.push((
+            "referential".to_string(),
+            format!(
+                "{} found in {} ({}% of values)",
+                reference.field,
+                reference.references,
+                reference.threshold() * 100.0
+            ),
+        ));
+    }
+    for custom in checks.custom_checks.iter().flatten() {
+        described.push((custom.name.clone(), custom.definition.clone()));
+    }
+    for row in checks.row_checks.iter().flatten() {
+        described.push((row.name.clone(), row.expression.clone()));
+    }
+    described
+}
+
+/// A Notion table of `rows`, with `header` as its first row.
+fn notion_table(header: Option<&Vec<String>>, rows: &[Vec<String>]) -> Value {
+    let width = header.or(rows.first()).map_or(0, Vec::len);
+    let children: Vec<Value> = header
+        .into_iter()
+        .chain(rows)
+        .map(|row| {
+            let cells: Vec<Value> = row.iter().map(|cell| notion_text(cell)).collect();
+            json!({
+                "object": "block",
+                "type": "table_row",
+                "table_row": { "cells": cells },
+            })
+        })
+        .collect();
+    json!({
+        "object": "block",
+        "type": "table",
+        "table": {
+            "table_width": width,
+            "has_column_header": header.is_some(),
+            "has_row_header": header.is_none(),
+            "children": children,
+        },
+    })
+}
+
+/// Rich text holding `text`, in as many pieces as Notion's limit requires.
+fn notion_text(text: &str) -> Value {
+    let chars: Vec<char> = text.chars().collect();
+    let pieces: Vec<Value> = chars
+        .chunks(NOTION_TEXT_LIMIT)
+        .map(|piece| {
+            json!({
+                "type": "text",
+                "text": { "content": piece.iter().collect::<String>() },
+            })
+        })
+        .collect();
+    Value::Array(pieces)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use contracts_core::{
+        ContractBuilder, DataFormat, FieldAccess, FieldBuilder, FieldConstraints,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn contract() -> Contract {
+        let mut contract = ContractBuilder::new("orders", "sales")
+            .description("Orders & refunds")
+            .location("s3://lake/orders")
+            .format(DataFormat::Iceberg)
+            .field(
+                FieldBuilder::new("status", "string")
+                    .description("Order <status>")
+                    .build(),
+            )
+            .build();
+        contract.schema.fields[0].constraints = Some(vec![
+            FieldConstraints::AllowedValues {
+                values: vec!["open".to_string(), "paid".to_string()],
+            },
+            FieldConstraints::NonEmpty,
+        ]);
+        contract
+    }
+
+    #[test]
+    fn test_document_lists_fields() {
+        let document = Document::of(&contract());
+        assert_eq!(document.title, "orders");
+        assert_eq!(
+            document.blocks[0],
+            Block::Paragraph("Orders & refunds".to_string())
+        );
+        let Block::Table { rows, .. } = &document.blocks[3] else {
+            panic!("expected the fields table, got {:?}", document.blocks[3]);
+        };
+        assert_eq!(
+            rows[0],
+            vec![
+                "status",
+                "string",
+                "yes",
+                "Order <status>",
+                "allowedvalues [open, paid]; nonempty"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_document_lists_field_access() {
+        let mut contract = contract();
+        assert!(
+            !Document::of(&contract)
+                .blocks
+                .contains(&Block::Heading("Access".to_string()))
+        );
+
+        contract.schema.fields[0].access = Some(FieldAccess {
+            classification: Some("internal".to_string()),
+            allowed_roles: vec!["sales".to_string(), "finance".to_string()],
+        });
+        let document = Document::of(&contract);
+        assert_eq!(document.blocks[4], Block::Heading("Access".to_string()));
+        let Block::Table { rows, .. } = &document.blocks[5] else {
+            panic!("expected the access table, got {:?}", document.blocks[5]);
+        };
+        assert_eq!(rows[0], vec!["status", "internal", "sales, finance"]);
+    }
+
+    #[test]
+    fn test_confluence_storage_is_escaped() {
+        let page = Document::of(&contract()).to_confluence();
+        assert!(page.starts_with("<p>Orders &amp; refunds</p><table><tbody>"));
+        assert!(page.contains("<td>Order &lt;status&gt;</td>"), "{page}");
+    }
+
+    #[test]
+    fn test_notion_tables_are_split() {
+        let document = Document {
+            title: "orders".to_string(),
+            blocks: vec![Block::Table {
+                header: vec!["Field".to_string()],
+                rows: (0..150).map(|i| vec![format!("f{i}")]).collect(),
+            }],
+        };
+        let blocks = document.to_notion();
+        assert_eq!(blocks.len(), 2);
+        let rows = |block: &Value| block["table"]["children"].as_array().unwrap().len();
+        assert_eq!((rows(&blocks[0]), rows(&blocks[1])), (100, 52));
+        assert_eq!(
+            blocks[1]["table"]["children"][1]["table_row"]["cells"][0][0]["text"]["content"],
+            "f99"
+        );
+    }
+}
@@ -0,0 +1,258 @@
+//! HTTP hooks around validations, configured in `dce.toml`.
+//!
+//! Before each validation, every `[[hooks.pre]]` endpoint receives a JSON
+//! `POST` with the contract and the validation context:
+//!
+//! ```json
+//! { "event": "pre_validation", "contract": { ... }, "context": { ... } }
+//! ```
+//!
+//! It may answer with an empty body to let the validation run, or with
+//!
+//! ```json
+//! { "allow": false, "reason": "orders are frozen until Monday" }
+//! ```
+//!
+//! to veto it, or with a `context` object to add `metadata`, narrow the
+//! `row_filter` or set `strict` and `sample_size` before the run. A status
+//! other than a success also vetoes the run, and so does a hook that cannot
+//! be reached: pre-validation hooks are gates.
+//!
+//! After each validation, every `[[hooks.post]]` endpoint receives the same
+//! payload with the `post_validation` event and the JSON report under
+//! `report`. Post-validation hooks never fail a validation.
+
+use anyhow::{Context, Result, anyhow};
+use contracts_core::{Contract, ValidationContext, ValidationReport};
+use contracts_iceberg::resolve_secret_refs;
+use contracts_parser::{CONFIG_NAME, HookConfig, WorkspaceConfig};
+use reqwest::Client;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::Duration;
+use tracing::{debug, warn};
+
+use crate::i18n::{Msg, trf};
+use crate::output::{self, Verbosity};
+
+/// The validation hooks of a run.
+pub struct Hooks {
+    client: Client,
+    pre: Vec<HookConfig>,
+    post: Vec<HookConfig>,
+}
+
+/// The answer of a pre-validation hook.
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+struct Verdict {
+    allow: bool,
+    reason: Option<String>,
+    context: ContextPatch,
+}
+
+impl Default for Verdict {
+    fn default() -> Self {
+        Self {
+            allow: true,
+            reason: None,
+            context: ContextPatch::default(),
+        }
+    }
+}
+
+/// What a pre-validation hook may change in the validation context.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct ContextPatch {
+    strict: Option<bool>,
+    sample_size: Option<usize>,
+    row_filter: Option<String>,
+    metadata: HashMap<String, String>,
+}
+
+impl ContextPatch {
+    /// Applies the patch; a row filter is combined with the one already set,
+    /// so a hook can narrow a run but not widen it.
+    fn apply(self, context: &mut ValidationContext) {
+        if let Some(strict) = self.strict {
+            context.strict = strict;
+        }
+        if let Some(size) = self.sample_size {
+            context.sample_size = Some(size);
+        }
+        if let Some(filter) = self.row_filter {
+            context.row_filter = Some(match context.row_filter.take() {
+                Some(current) => format!("({current}) AND ({filter})"),
+                None => filter,
+            });
+        }
+        context.metadata.extend(self.metadata);
+    }
+}
+
+impl Hooks {
+    /// Sets up the hooks configured in the working directory's `dce.toml`.
+    pub fn from_workspace() -> Result<Self> {
+        let config = WorkspaceConfig::load(Path::new(CONFIG_NAME))?;
+        Self::new(&config)
+    }
+
+    /// Sets up the hooks configured in `config`, resolving the secret
+    /// references of their headers.
+    pub fn new(config: &WorkspaceConfig) -> Result<Self> {
+        let resolve = |hooks: &[HookConfig]| {
+            hooks
+                .iter()
+                .map(|hook| {
+                    let mut hook = hook.clone();
+                    for value in hook.headers.values_mut() {
+                        *value = resolve_secret_refs(value)?;
+                    }
+                    Ok(hook)
+                })
+                .collect::<Result<Vec<_>>>()
+        };
+        Ok(Self {
+            client: Client::new(),
+            pre: resolve(&config.hooks.pre)?,
+            post: resolve(&config.hooks.post)?,
+        })
+    }
+
+    /// Calls the pre-validation hooks in order and returns the context to
+    /// validate with.
+    ///
+    /// # Errors
+    ///
+    /// Fails when a hook vetoes the validation or cannot be reached.
+    pub async fn before(
+        &self,
+        contract: &Contract,
+        context: &ValidationContext,
+    ) -> Result<ValidationContext> {
+        let mut context = context.clone();
+        for hook in &self.pre {
+            let payload = payload("pre_validation", contract, &context);
+            let verdict = self
+                .call(hook, &payload)
+                .await
+                .and_then(|body| {
+                    if body.trim().is_empty() {
+                        return Ok(Verdict::default());
+                    }
+                    serde_json::from_str(&body)
+                        .with_context(|| format!("Invalid JSON response from {}", hook.url))
+                })
+                .map_err(|e| {
+                    anyhow!(
+                        "{}",
+                        trf(
+                            Msg::HookVetoed,
+                            &[&contract.name, &hook.url, &format!("{e:#}")]
+                        )
+                    )
+                })?;
+            if !verdict.allow {
+                let reason = verdict.reason.as_deref().unwrap_or("no reason given");
+                return Err(anyhow!(
+                    "{}",
+                    trf(Msg::HookVetoed, &[&contract.name, &hook.url, &reason])
+                ));
+            }
+            verdict.context.apply(&mut context);
+        }
+        Ok(context)
+    }
+
+    /// Sends the report of a validation to the post-validation hooks,
+    /// warning about the ones that fail.
+    pub async fn after(
+        &self,
+        contract: &Contract,
+        context: &ValidationContext,
+        report: &ValidationReport,
+    ) {
+        if self.post.is_empty() {
+            return;
+        }
+        let mut payload = payload("post_validation", contract, context);
+        payload["report"] = output::json_report(report, Verbosity::Detailed);
+        for hook in &self.post {
+            if let Err(e) = self.call(hook, &payload).await {
+                warn!("Post-validation hook {} failed: {:#}", hook.url, e);
+            }
+        }
+    }
+
+    /// Posts `payload` to `hook` and returns the response body, or an error
+    /// with the body when the status is not a success.
+    async fn call(&self, hook: &HookConfig, payload: &Value) -> Result<String> {
+        debug!("Calling validation hook {}", hook.url);
+        let mut request = self
+            .client
+            .post(&hook.url)
+            .timeout(Duration::from_secs(hook.timeout_secs))
+            .json(payload);
+        for (name, value) in &hook.headers {
+            request = request.header(name, value);
+        }
+        let response = request.send().await.context("Failed to reach the hook")?;
+        let status = response.status();
+        let body = response.text().await?;
+        if !status.is_success() {
+            return Err(anyhow!("{} {}", status, body.trim()));
+        }
+        Ok(body)
+    }
+}
+
+/// Builds the JSON payload sent to the hooks of `event`.
+fn payload(event: &str, contract: &Contract, context: &ValidationContext) -> Value {
+    let time = |t: Option<chrono::DateTime<chrono::Utc>>| t.map(|t| t.to_rfc3339());
+    json!({
+        "event": event,
+        "contract": contract,
+        "context": {
+            "strict": context.strict,
+            "schema_only": context.schema_only,
+            "sample_size": context.sample_size,
+            "time_window": context.time_window.as_ref().map(|window| json!({
+                "column": window.column,
+                "since": time(window.since),
+                "until": time(window.until),
+            })),
+            "row_filter": context.row_filter,
+            "segment_by": context.segment_by,
+            "fill_defaults": context.fill_defaults,
+            "metadata": context.metadata,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_context_patch_narrows_row_filter() {
+        let mut context = ValidationContext::new().with_row_filter("status = 'failed'");
+        let verdict: Verdict = serde_json::from_str(
+            r#"{"context": {"row_filter": "region = 'EU'", "sample_size": 50, "metadata": {"ticket": "OPS-7"}}}"#,
+        )
+        .unwrap();
+        assert!(verdict.allow);
+        verdict.context.apply(&mut context);
+
+        assert_eq!(
+            context.row_filter.as_deref(),
+            Some("(status = 'failed') AND (region = 'EU')")
+        );
+        assert_eq!(context.sample_size, Some(50));
+        assert_eq!(context.metadata["ticket"], "OPS-7");
+        assert!(!context.strict);
+    }
+}
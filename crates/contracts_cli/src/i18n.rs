@@ -0,0 +1,734 @@
+//! Message catalog for user-facing CLI output.
+//!
+//! The locale is selected with the `DCE_LANG` environment variable
+//! (e.g. `DCE_LANG=it` or `DCE_LANG=it_IT.UTF-8`) and defaults to English.
+//! Messages may contain positional placeholders (`{0}`, `{1}`, ...) that are
+//! filled in by [`trf`].
+
+use std::fmt::Display;
+use std::sync::OnceLock;
+
+/// Supported output languages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    /// English (default)
+    En,
+    /// Italian
+    It,
+}
+
+impl Locale {
+    /// All supported locales.
+    #[cfg(test)]
+    pub const ALL: &'static [Locale] = &[Locale::En, Locale::It];
+
+    /// Parses a locale tag such as `it`, `it_IT` or `it-IT.UTF-8`.
+    pub fn parse(tag: &str) -> Option<Self> {
+        let lang = tag
+            .split(['_', '-', '.'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+        match lang.as_str() {
+            "en" => Some(Locale::En),
+            "it" => Some(Locale::It),
+            _ => None,
+        }
+    }
+
+    /// Reads the locale from `DCE_LANG`, falling back to English.
+    pub fn from_env() -> Self {
+        std::env::var("DCE_LANG")
+            .ok()
+            .and_then(|tag| Self::parse(&tag))
+            .unwrap_or(Locale::En)
+    }
+}
+
+static LOCALE: OnceLock<Locale> = OnceLock::new();
+
+/// Returns the locale used for this process.
+pub fn locale() -> Locale {
+    *LOCALE.get_or_init(Locale::from_env)
+}
+
+macro_rules! messages {
+    ($($key:ident => { en: $en:expr, it: $it:expr $(,)? }),* $(,)?) => {
+        /// Identifier of a translatable message.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum Msg {
+            $($key),*
+        }
+
+        impl Msg {
+            /// All messages in the catalog.
+            #[cfg(test)]
+            pub const ALL: &'static [Msg] = &[$(Msg::$key),*];
+
+            /// Returns the text of this message in the given locale.
+            pub fn text(self, locale: Locale) -> &'static str {
+                match (self, locale) {
+                    $(
+                        (Msg::$key, Locale::En) => $en,
+                        (Msg::$key, Locale::It) => $it,
+                    )*
+                }
+            }
+        }
+    };
+}
+
+messages! {
+    // Validation report
+    ReportTitle => { en: "VALIDATION REPORT", it: "REPORT DI VALIDAZIONE" },
+    ValidationPassed => { en: "Validation PASSED", it: "Validazione SUPERATA" },
+    ValidationFailed => { en: "Validation FAILED", it: "Validazione FALLITA" },
+    ValidationInconclusive => { en: "Validation INCONCLUSIVE", it: "Validazione NON CONCLUSIVA" },
+    SummaryCounts => {
+        en: "({0} error(s), {1} warning(s))",
+        it: "({0} errore/i, {1} avviso/i)",
+    },
+    Errors => { en: "Errors", it: "Errori" },
+    Warnings => { en: "Warnings", it: "Avvisi" },
+    Inconclusive => { en: "Inconclusive", it: "Non conclusivi" },
+    Info => { en: "Info", it: "Informazioni" },
+    RecordsValidated => { en: "Records validated", it: "Record validati" },
+    FieldsChecked => { en: "Fields checked", it: "Campi verificati" },
+    ConstraintsEvaluated => { en: "Constraints evaluated", it: "Vincoli valutati" },
+    DeletedRows => { en: "Deleted rows", it: "Righe eliminate" },
+    TruncatedViolations => { en: "Violations not listed", it: "Violazioni non elencate" },
+    DeletedRowsValue => {
+        en: "{0} of {1} ({2}%)",
+        it: "{0} su {1} ({2}%)",
+    },
+    Duration => { en: "Duration", it: "Durata" },
+    Throughput => { en: "Throughput", it: "Velocità" },
+    RecordsPerSecond => { en: "{0} records/s", it: "{0} record/s" },
+    CheckResults => { en: "Check Results", it: "Risultati dei controlli" },
+    FieldBreakdown => { en: "Per-field Breakdown", it: "Dettaglio per campo" },
+    ColumnSeverity => { en: "Severity", it: "Gravità" },
+    ColumnCode => { en: "Code", it: "Codice" },
+    ColumnCheck => { en: "Check", it: "Controllo" },
+    ColumnField => { en: "Field", it: "Campo" },
+    ColumnMessage => { en: "Message", it: "Messaggio" },
+    ColumnStatus => { en: "Status", it: "Stato" },
+    ColumnStatistics => { en: "Column Statistics", it: "Statistiche per colonna" },
+    ColumnNulls => { en: "Nulls", it: "Nulli" },
+    ColumnMin => { en: "Min", it: "Min" },
+    ColumnMax => { en: "Max", it: "Max" },
+    ColumnDistinct => { en: "Distinct", it: "Distinti" },
+    ColumnMetric => { en: "Metric", it: "Metrica" },
+    ColumnSource => { en: "Source", it: "Origine" },
+    ColumnTarget => { en: "Target", it: "Destinazione" },
+    SeverityError => { en: "error", it: "errore" },
+    SeverityWarning => { en: "warning", it: "avviso" },
+    SeverityInconclusive => { en: "inconclusive", it: "non conclusivo" },
+    SeverityInfo => { en: "info", it: "info" },
+    CategorySchema => { en: "schema", it: "schema" },
+    CategoryConstraint => { en: "constraint", it: "vincolo" },
+    CategoryQuality => { en: "quality", it: "qualità" },
+    CategoryCustom => { en: "custom", it: "personalizzato" },
+    CategoryOther => { en: "other", it: "altro" },
+    FieldFailing => { en: "✗ failing", it: "✗ non conforme" },
+    FieldHasWarnings => { en: "⚠ warnings", it: "⚠ avvisi" },
+    SegmentsBy => { en: "Segments by {0}", it: "Segmenti per {0}" },
+    CapturedRows => { en: "Rows captured by check #{0}", it: "Righe catturate dal controllo n. {0}" },
+    ColumnSegment => { en: "Segment", it: "Segmento" },
+    Datasets => { en: "Datasets", it: "Dataset" },
+    ColumnDataset => { en: "Dataset", it: "Dataset" },
+    ColumnRecords => { en: "Records", it: "Record" },
+    SegmentPassed => { en: "✓ passed", it: "✓ superato" },
+    SegmentFailed => { en: "✗ failed", it: "✗ fallito" },
+    SegmentInconclusive => { en: "? inconclusive", it: "? non conclusivo" },
+    LabelContract => { en: "Contract", it: "Contratto" },
+    LabelConformance => { en: "Conformance level", it: "Livello di conformità" },
+    LabelSnapshot => { en: "Snapshot", it: "Snapshot" },
+    UncommittedChanges => { en: "uncommitted changes", it: "modifiche non committate" },
+    Timings => { en: "Timings", it: "Tempi" },
+    ColumnPhase => { en: "Phase", it: "Fase" },
+    PhaseParsing => { en: "Contract parsing", it: "Lettura del contratto" },
+    PhaseValidation => { en: "Validation", it: "Validazione" },
+    PhaseTotal => { en: "Total", it: "Totale" },
+
+    // validate
+    ContractLoaded => {
+        en: "Contract loaded: {0} v{1} (owner: {2})",
+        it: "Contratto caricato: {0} v{1} (proprietario: {2})",
+    },
+    SchemaOnlyWithoutCatalog => {
+        en: "Schema-only mode: validating contract structure without catalog",
+        it: "Modalità solo schema: validazione della struttura del contratto senza catalogo",
+    },
+    SchemaOnlyWithoutData => {
+        en: "Schema-only mode: validating contract structure without data",
+        it: "Modalità solo schema: validazione della struttura del contratto senza dati",
+    },
+    SchemaOnlyFromFooters => {
+        en: "Schema-only mode: checking Parquet footers without reading rows",
+        it: "Modalità solo schema: controllo dei footer Parquet senza leggere righe",
+    },
+    DetectedIceberg => {
+        en: "Detected Iceberg format, connecting to catalog...",
+        it: "Rilevato formato Iceberg, connessione al catalogo...",
+    },
+    ReadingFile => { en: "Reading {0} file from: {1}", it: "Lettura del file {0} da: {1}" },
+    TimeWindowFilter => {
+        en: "Validating rows with {0} in [{1}, {2})",
+        it: "Validazione delle righe con {0} in [{1}, {2})",
+    },
+    RowFilter => {
+        en: "Validating rows where {0}",
+        it: "Validazione delle righe dove {0}",
+    },
+    FormatNotSupported => {
+        en: "Format {0} not yet fully supported, performing schema-only validation",
+        it: "Formato {0} non ancora supportato del tutto, validazione del solo schema",
+    },
+    ParsedLocation => {
+        en: "Parsed location: namespace={0}, table={1}",
+        it: "Posizione interpretata: namespace={0}, tabella={1}",
+    },
+    UsingRestCatalog => { en: "Using REST catalog: {0}", it: "Uso del catalogo REST: {0}" },
+    ConnectingToCatalog => {
+        en: "Connecting to Iceberg catalog...",
+        it: "Connessione al catalogo Iceberg...",
+    },
+    ReadingIcebergTable => {
+        en: "Reading data from Iceberg table...",
+        it: "Lettura dei dati dalla tabella Iceberg...",
+    },
+    ReadingIcebergStats => {
+        en: "Reading column statistics from Iceberg manifests (no data is read)...",
+        it: "Lettura delle statistiche di colonna dai manifest Iceberg (nessun dato letto)...",
+    },
+    ValidatingContractFile => { en: "Validating {0}", it: "Validazione di {0}" },
+    ValidatingDataset => { en: "Validating dataset {0}", it: "Validazione del dataset {0}" },
+    NoContractsInDirectory => {
+        en: "No contract files (.yml, .yaml, .toml) found in {0}",
+        it: "Nessun file di contratto (.yml, .yaml, .toml) trovato in {0}",
+    },
+    DumpSampleWithDirectory => {
+        en: "--dump-sample cannot be used when validating a directory of contracts",
+        it: "--dump-sample non può essere usato validando una cartella di contratti",
+    },
+    SnapshotIdWithDirectory => {
+        en: "--snapshot-id cannot be used when validating a directory of contracts; use --as-of",
+        it: "--snapshot-id non può essere usato validando una cartella di contratti; usa --as-of",
+    },
+    DumpSampleUnsupported => {
+        en: "--dump-sample is only supported for Iceberg tables; no sample written",
+        it: "--dump-sample è supportato solo per le tabelle Iceberg; nessun campione scritto",
+    },
+    StatsOnlyUnsupported => {
+        en: "--stats-only is only supported for Iceberg tables; validating the data instead",
+        it: "--stats-only è supportato solo per le tabelle Iceberg; vengono validati i dati",
+    },
+    OutputWithDirectory => {
+        en: "--output cannot be used with a directory of contracts",
+        it: "--output non può essere usato con una cartella di contratti",
+    },
+    ReportWritten => { en: "Report written to {0}", it: "Report scritto in {0}" },
+    NoRegistryConfigured => {
+        en: "--contract-from-registry needs a registry: pass --registry <DIR> or set DCE_REGISTRY",
+        it: "--contract-from-registry richiede un registro: usa --registry <DIR> o imposta DCE_REGISTRY",
+    },
+    ResolvedFromRegistry => {
+        en: "Resolved {0} to {1}",
+        it: "{0} risolto in {1}",
+    },
+    SampleDumped => {
+        en: "Wrote {0} sampled rows to {1}",
+        it: "Scritte {0} righe campionate in {1}",
+    },
+    MonitorStarted => {
+        en: "Monitoring {0} every {1}s (state in {2}); press Ctrl-C to stop",
+        it: "Monitoraggio di {0} ogni {1}s (stato in {2}); premi Ctrl-C per interrompere",
+    },
+    MonitorResuming => {
+        en: "Resuming after snapshot {0}",
+        it: "Ripresa dopo lo snapshot {0}",
+    },
+    MonitorValidatingTable => {
+        en: "Snapshot {0} ({1}): validating the table",
+        it: "Snapshot {0} ({1}): validazione della tabella",
+    },
+    MonitorValidatingSince => {
+        en: "Snapshot {0} ({1}): validating rows added since snapshot {2}",
+        it: "Snapshot {0} ({1}): validazione delle righe aggiunte dopo lo snapshot {2}",
+    },
+    HookVetoed => {
+        en: "Validation of {0} vetoed by hook {1}: {2}",
+        it: "Validazione di {0} bloccata dall'hook {1}: {2}",
+    },
+    MonitorNeedsIceberg => {
+        en: "Contract {0} is not an Iceberg table; dce monitor watches Iceberg snapshots",
+        it: "Il contratto {0} non è una tabella Iceberg; dce monitor osserva gli snapshot Iceberg",
+    },
+    MonitorStopped => { en: "Monitor stopped", it: "Monitoraggio interrotto" },
+    WapAuditing => {
+        en: "Auditing {0}: snapshot {1} ({2})",
+        it: "Verifica di {0}: snapshot {1} ({2})",
+    },
+    WapNeedsIceberg => {
+        en: "Contract {0} is not an Iceberg table; dce wap audits staged Iceberg snapshots",
+        it: "Il contratto {0} non è una tabella Iceberg; dce wap verifica gli snapshot Iceberg in staging",
+    },
+    WapPublishWith => {
+        en: "Publish to main with: {0}",
+        it: "Pubblica su main con: {0}",
+    },
+    WapUpToDate => {
+        en: "Snapshot {0} is already on main; nothing to publish",
+        it: "Lo snapshot {0} è già su main; niente da pubblicare",
+    },
+    WapDiverged => {
+        en: "Main moved past the staged snapshots {0}; cherry-pick them one by one",
+        it: "Main è andato oltre gli snapshot in staging {0}; applicali uno alla volta con cherry-pick",
+    },
+    WapNotPublished => {
+        en: "Snapshot {0} failed validation; do not publish it",
+        it: "Lo snapshot {0} non ha superato la validazione; non pubblicarlo",
+    },
+    DirectorySummary => {
+        en: "{0} contracts validated: {1} passed, {2} failed ({3} catalog connections)",
+        it: "{0} contratti validati: {1} superati, {2} falliti ({3} connessioni al catalogo)",
+    },
+    ContractResults => { en: "Results by contract", it: "Risultati per contratto" },
+    ResumeWithFile => {
+        en: "--resume only applies to a directory of contracts",
+        it: "--resume si applica solo a una cartella di contratti",
+    },
+    RunStarted => {
+        en: "Run {0}; if it is interrupted, resume it with --resume {0}",
+        it: "Esecuzione {0}; se viene interrotta, riprendila con --resume {0}",
+    },
+    RunResumed => {
+        en: "Resuming run {0}: {1} contracts already completed",
+        it: "Ripresa dell'esecuzione {0}: {1} contratti già completati",
+    },
+    ContractSkipped => {
+        en: "Skipping {0}: completed in run {1} ({2})",
+        it: "{0} saltato: completato nell'esecuzione {1} ({2})",
+    },
+    ColumnFile => { en: "File", it: "File" },
+
+    // reconcile
+    ReconcileSource => { en: "source", it: "origine" },
+    ReconcileTarget => { en: "target", it: "destinazione" },
+    ReconcileValidating => {
+        en: "Validating {0}: {1}",
+        it: "Validazione di {0}: {1}",
+    },
+    ReconcileRowsRead => {
+        en: "Read {0} rows from {1}",
+        it: "Lette {0} righe da {1}",
+    },
+    ReconcileUnsupported => {
+        en: "dce reconcile compares Iceberg, CSV and JSON data, not {0}",
+        it: "dce reconcile confronta dati Iceberg, CSV e JSON, non {0}",
+    },
+    ReconcileMatch => {
+        en: "Source and target match: {0} rows, checksum {1}",
+        it: "Origine e destinazione coincidono: {0} righe, checksum {1}",
+    },
+    ReconcileDifferences => {
+        en: "{0} differences between source and target",
+        it: "{0} differenze tra origine e destinazione",
+    },
+
+    // preview-change
+    PreviewOld => { en: "old contract", it: "contratto precedente" },
+    PreviewNew => { en: "new contract", it: "nuovo contratto" },
+    PreviewFailingRows => {
+        en: "Under the {0}, {1} of {2} rows fail",
+        it: "Con il {0}, {1} righe su {2} falliscono",
+    },
+    PreviewNewlyFailingRows => {
+        en: "{0} rows would newly fail ({1} of the sample): {2}",
+        it: "{0} righe fallirebbero per la prima volta ({1} del campione): {2}",
+    },
+    PreviewNewlyPassingRows => {
+        en: "{0} rows would newly pass: {1}",
+        it: "{0} righe passerebbero per la prima volta: {1}",
+    },
+    PreviewNewlyFailingCheck => { en: "Newly failing: {0}", it: "Nuovo errore: {0}" },
+    PreviewNewlyPassingCheck => { en: "Newly passing: {0}", it: "Ora supera: {0}" },
+    PreviewBreaks => {
+        en: "The new contract would newly fail {0} rows and {1} checks",
+        it: "Il nuovo contratto farebbe fallire {0} nuove righe e {1} nuovi controlli",
+    },
+    PreviewSafe => {
+        en: "The new contract fails nothing the old one passes",
+        it: "Il nuovo contratto non fa fallire nulla che il precedente accetti",
+    },
+
+    // explain
+    UnknownErrorCode => {
+        en: "Unknown error code '{0}'; run `dce explain` to list all codes",
+        it: "Codice di errore '{0}' sconosciuto; esegui `dce explain` per l'elenco completo",
+    },
+    Remediation => { en: "How to fix", it: "Come risolvere" },
+
+    // update
+    NothingToUpdate => {
+        en: "No contracts are locked; pass NAME@VERSION references to pin them",
+        it: "Nessun contratto bloccato; indica riferimenti NOME@VERSIONE per fissarli",
+    },
+    LockAdded => { en: "Locked {0} to {1}", it: "{0} fissato a {1}" },
+    LockUpdated => { en: "Updated {0}: {1} -> {2}", it: "{0} aggiornato: {1} -> {2}" },
+    LockRehashed => {
+        en: "{0} {1} changed in the registry; recorded its new hash",
+        it: "{0} {1} è cambiato nel registro; registrato il nuovo hash",
+    },
+    LockUnchanged => { en: "{0} is up to date ({1})", it: "{0} è aggiornato ({1})" },
+    LockfileWritten => { en: "Wrote {0}", it: "Scritto {0}" },
+
+    // check
+    ContractSchemaValid => {
+        en: "Contract schema is valid",
+        it: "Lo schema del contratto è valido",
+    },
+    ContractSummary => { en: "Contract Summary:", it: "Riepilogo del contratto:" },
+    LabelName => { en: "Name", it: "Nome" },
+    LabelVersion => { en: "Version", it: "Versione" },
+    LabelOwner => { en: "Owner", it: "Proprietario" },
+    LabelDescription => { en: "Description", it: "Descrizione" },
+    LabelFormat => { en: "Format", it: "Formato" },
+    LabelLocation => { en: "Location", it: "Posizione" },
+    LabelFields => { en: "Fields", it: "Campi" },
+    LabelQualityChecks => { en: "Quality Checks", it: "Controlli di qualità" },
+    DiffNoChanges => {
+        en: "No schema changes",
+        it: "Nessuna modifica allo schema",
+    },
+    DiffBreaking => { en: "BREAKING", it: "INCOMPATIBILE" },
+    DiffCompatible => { en: "compatible", it: "compatibile" },
+    DiffSummary => {
+        en: "{0} change(s), {1} breaking",
+        it: "{0} modifiche, {1} incompatibili",
+    },
+    DiffVersionNotBumped => {
+        en: "Version {0} does not make the {1} increment from {2} these changes call for",
+        it: "La versione {0} non applica l'incremento {1} da {2} richiesto da queste modifiche",
+    },
+
+    // drift
+    DriftIcebergOnly => {
+        en: "Schema drift is only detected for Iceberg tables, not {0}",
+        it: "La deriva dello schema è rilevata solo per tabelle Iceberg, non {0}",
+    },
+    DriftNone => {
+        en: "The table schema matches the contract",
+        it: "Lo schema della tabella corrisponde al contratto",
+    },
+    DriftSummary => {
+        en: "{0} drift(s), {1} breaking",
+        it: "{0} differenze, {1} incompatibili",
+    },
+    DriftAccessHeading => { en: "Column access", it: "Accesso alle colonne" },
+    DriftAccessNone => {
+        en: "The table's access metadata matches the contract",
+        it: "I metadati di accesso della tabella corrispondono al contratto",
+    },
+
+    // diff-table
+    DiffTableRemove => {
+        en: "Remove field '{0}', or restore the column in the table",
+        it: "Rimuovi il campo '{0}', o ripristina la colonna nella tabella",
+    },
+    DiffTableAdd => {
+        en: "Add field '{0}' with type {1} and nullable: {2}",
+        it: "Aggiungi il campo '{0}' con tipo {1} e nullable: {2}",
+    },
+    DiffTableRetype => {
+        en: "Change the type of '{0}' to {1}",
+        it: "Cambia il tipo di '{0}' in {1}",
+    },
+    DiffTableNullable => {
+        en: "Set nullable: {1} on '{0}'",
+        it: "Imposta nullable: {1} su '{0}'",
+    },
+    DiffTableUpdateHint => {
+        en: "Run with --update to apply these edits to {0}",
+        it: "Esegui con --update per applicare queste modifiche a {0}",
+    },
+    DiffTableUpdated => {
+        en: "Applied {0} edit(s) to {1}",
+        it: "Applicate {0} modifiche a {1}",
+    },
+
+    // doctor
+    DoctorBuild => {
+        en: "dce {0} with catalogs: {1}",
+        it: "dce {0} con cataloghi: {1}",
+    },
+    DoctorNoCatalog => {
+        en: "No catalog to check for {0} data",
+        it: "Nessun catalogo da verificare per dati {0}",
+    },
+    DoctorConfigured => {
+        en: "{0} catalog for {1}",
+        it: "Catalogo {0} per {1}",
+    },
+    DoctorConfigRemedy => {
+        en: "Set schema.connection in the contract or REST_CATALOG_URI in the environment",
+        it: "Imposta schema.connection nel contratto o REST_CATALOG_URI nell'ambiente",
+    },
+    DoctorCatalogNotCompiled => {
+        en: "This build of dce does not include the {0} catalog",
+        it: "Questa build di dce non include il catalogo {0}",
+    },
+    DoctorRebuildRemedy => {
+        en: "Rebuild dce with --features {0}-catalog, or --features all-catalogs",
+        it: "Ricompila dce con --features {0}-catalog, o --features all-catalogs",
+    },
+    DoctorSecretsResolved => {
+        en: "{0} secret reference(s) resolved",
+        it: "{0} riferimenti a segreti risolti",
+    },
+    DoctorSecretRemedy => {
+        en: "Export the variable or create the file the reference names",
+        it: "Esporta la variabile o crea il file indicato dal riferimento",
+    },
+    DoctorNoAwsCredentials => {
+        en: "No AWS credentials found for the table's S3 location",
+        it: "Nessuna credenziale AWS trovata per la posizione S3 della tabella",
+    },
+    DoctorAwsRemedy => {
+        en: "Set AWS_ACCESS_KEY_ID or AWS_PROFILE, or s3.access-key-id in the connection properties",
+        it: "Imposta AWS_ACCESS_KEY_ID o AWS_PROFILE, o s3.access-key-id nelle proprietà della connessione",
+    },
+    DoctorCatalogConnected => {
+        en: "Connected to the {0} catalog",
+        it: "Connesso al catalogo {0}",
+    },
+    DoctorCatalogRemedy => {
+        en: "Check that the catalog URI is reachable from this machine and its credentials are valid",
+        it: "Verifica che l'URI del catalogo sia raggiungibile da questa macchina e che le credenziali siano valide",
+    },
+    DoctorTableLoaded => {
+        en: "Loaded {0} at snapshot {1}",
+        it: "Caricata {0} allo snapshot {1}",
+    },
+    DoctorTableEmpty => {
+        en: "Loaded {0}, which has no snapshot",
+        it: "Caricata {0}, che non ha snapshot",
+    },
+    DoctorTableRemedy => {
+        en: "Check that {0} exists and schema.location names it",
+        it: "Verifica che {0} esista e che schema.location la indichi",
+    },
+    DoctorNoDataFiles => {
+        en: "The table has no data files to read",
+        it: "La tabella non ha file di dati da leggere",
+    },
+    DoctorStoreRead => {
+        en: "Read a data file from the object store",
+        it: "Letto un file di dati dall'object store",
+    },
+    DoctorStoreRemedy => {
+        en: "Check the object store credentials and endpoint in the connection properties",
+        it: "Verifica le credenziali e l'endpoint dell'object store nelle proprietà della connessione",
+    },
+    DoctorClockNotRest => {
+        en: "Only REST catalogs report their time",
+        it: "Solo i cataloghi REST riportano la propria ora",
+    },
+    DoctorClockUnknown => {
+        en: "The catalog did not report its time",
+        it: "Il catalogo non ha riportato la propria ora",
+    },
+    DoctorClockSkew => {
+        en: "Local clock differs from the catalog's by {0}",
+        it: "L'orologio locale differisce da quello del catalogo di {0}",
+    },
+    DoctorClockRemedy => {
+        en: "Synchronize the system clock (e.g. enable NTP); signed requests and freshness checks depend on it",
+        it: "Sincronizza l'orologio di sistema (es. abilita NTP); richieste firmate e controlli di freschezza dipendono da esso",
+    },
+    DoctorSkippedAfterFailure => {
+        en: "Skipped after an earlier failure",
+        it: "Saltato dopo un errore precedente",
+    },
+    DoctorSummary => {
+        en: "{0} check(s), {1} failed, {2} warning(s)",
+        it: "{0} controlli, {1} falliti, {2} avvisi",
+    },
+
+    // access
+    AccessNone => {
+        en: "The contract declares no field access",
+        it: "Il contratto non dichiara l'accesso ai campi",
+    },
+    ExtensionsMatchSchema => {
+        en: "Contract extensions match the schema",
+        it: "Le estensioni del contratto rispettano lo schema",
+    },
+    DescriptionsInformative => {
+        en: "Descriptions pass the description rules",
+        it: "Le descrizioni rispettano le regole sulle descrizioni",
+    },
+    CheckDirectorySummary => {
+        en: "{0} contracts checked: {1} passed, {2} failed",
+        it: "{0} contratti verificati: {1} superati, {2} falliti",
+    },
+    LabelSla => { en: "SLA", it: "SLA" },
+    LabelAvailability => { en: "Availability", it: "Disponibilità" },
+    LabelResponseTime => { en: "Response Time", it: "Tempo di risposta" },
+    LabelPenalties => { en: "Penalties", it: "Penali" },
+    NotAvailable => { en: "N/A", it: "N/D" },
+
+    // lint
+    LintFileClean => { en: "{0}: no findings", it: "{0}: nessun problema" },
+    LintSummary => {
+        en: "{0} contract(s) linted: {1} error(s), {2} warning(s)",
+        it: "{0} contratti analizzati: {1} errore/i, {2} avviso/i",
+    },
+
+    // owners
+    NoOwnershipSource => {
+        en: "No CODEOWNERS file found; pass --codeowners or --org-directory",
+        it: "Nessun file CODEOWNERS trovato; indica --codeowners o --org-directory",
+    },
+    OwnerKnown => { en: "{0}: owner {1} is known", it: "{0}: il proprietario {1} è noto" },
+    OwnersSummary => {
+        en: "{0} contract owner(s) checked: {1} error(s), {2} warning(s)",
+        it: "{0} proprietari di contratti verificati: {1} errore/i, {2} avviso/i",
+    },
+
+    // init
+    ConnectingToCatalogType => {
+        en: "Connecting to Iceberg catalog: {0}",
+        it: "Connessione al catalogo Iceberg: {0}",
+    },
+    ExtractedSchema => {
+        en: "Extracted schema with {0} fields",
+        it: "Schema estratto con {0} campi",
+    },
+    ContractWritten => { en: "Contract written to: {0}", it: "Contratto scritto in: {0}" },
+    ProfilingSample => {
+        en: "Profiling up to {0} rows",
+        it: "Profilazione di al massimo {0} righe",
+    },
+    ProfiledSample => {
+        en: "Profiled {0} rows: proposed {1} constraints and {2} quality checks",
+        it: "Profilate {0} righe: proposti {1} vincoli e {2} controlli di qualità",
+    },
+
+    // publish
+    ColumnType => { en: "Type", it: "Tipo" },
+    ColumnNullable => { en: "Nullable", it: "Nullabile" },
+    ColumnConstraints => { en: "Constraints", it: "Vincoli" },
+    ColumnClassification => { en: "Classification", it: "Classificazione" },
+    ColumnAllowedRoles => { en: "Allowed Roles", it: "Ruoli ammessi" },
+    LabelAccess => { en: "Access", it: "Accesso" },
+    Yes => { en: "yes", it: "sì" },
+    No => { en: "no", it: "no" },
+    Deprecated => { en: "Deprecated", it: "Deprecato" },
+    DeprecatedUse => { en: "Deprecated: use {0}", it: "Deprecato: usa {0}" },
+    PublishTargetNotConfigured => {
+        en: "No [publish.{0}] section in {1}",
+        it: "Nessuna sezione [publish.{0}] in {1}",
+    },
+    PublishedPage => { en: "Published {0} to {1}", it: "{0} pubblicato in {1}" },
+    PublishSummary => {
+        en: "{0} contract page(s) published to {1}",
+        it: "{0} pagine di contratto pubblicate su {1}",
+    },
+
+    // bundle
+    BundleInvalidContract => {
+        en: "{0} has {1} definition error(s); run `dce check` and fix them before bundling",
+        it: "{0} ha {1} errori di definizione; esegui `dce check` e correggili prima del bundle",
+    },
+    BundleWritten => {
+        en: "Bundled {0} {1} into {2}",
+        it: "{0} {1} impacchettato in {2}",
+    },
+    BundleDigest => { en: "Digest: {0}", it: "Digest: {0}" },
+    BundlePushed => { en: "Pushed {0} {1} to {2}", it: "{0} {1} caricato in {2}" },
+    BundlePulled => {
+        en: "Pulled {0} ({1}) into {2}",
+        it: "{0} ({1}) scaricato in {2}",
+    },
+
+    // hook
+    HookInstalled => { en: "Installed {0} hook at {1}", it: "Hook {0} installato in {1}" },
+    HookNotInstalled => { en: "No {0} hook installed", it: "Nessun hook {0} installato" },
+    HookRemoved => { en: "Removed {0} hook", it: "Hook {0} rimosso" },
+    HookFilesOk => {
+        en: "{0} contract file(s) OK ({1} cached)",
+        it: "{0} file di contratto OK ({1} in cache)",
+    },
+}
+
+/// Returns the text of `msg` in the current locale.
+pub fn tr(msg: Msg) -> &'static str {
+    msg.text(locale())
+}
+
+/// Returns the text of `msg` in the current locale with `{N}` placeholders
+/// replaced by the corresponding argument.
+pub fn trf(msg: Msg, args: &[&dyn Display]) -> String {
+    fill(tr(msg), args)
+}
+
+fn fill(template: &str, args: &[&dyn Display]) -> String {
+    let mut text = template.to_string();
+    for (i, arg) in args.iter().enumerate() {
+        text = text.replace(&format!("{{{}}}", i), &arg.to_string());
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn placeholders(text: &str) -> Vec<usize> {
+        let mut found: Vec<usize> = (0..10)
+            .filter(|i| text.contains(&format!("{{{}}}", i)))
+            .collect();
+        found.sort();
+        found
+    }
+
+    #[test]
+    fn test_parse_locale() {
+        assert_eq!(Locale::parse("it"), Some(Locale::It));
+        assert_eq!(Locale::parse("it_IT.UTF-8"), Some(Locale::It));
+        assert_eq!(Locale::parse("EN-us"), Some(Locale::En));
+        assert_eq!(Locale::parse("fr"), None);
+        assert_eq!(Locale::parse(""), None);
+    }
+
+    #[test]
+    fn test_translations_are_complete() {
+        for msg in Msg::ALL {
+            let en = msg.text(Locale::En);
+            for locale in Locale::ALL {
+                let text = msg.text(*locale);
+                assert!(!text.is_empty(), "{:?} is empty for {:?}", msg, locale);
+                assert_eq!(
+                    placeholders(en),
+                    placeholders(text),
+                    "{:?} has mismatched placeholders for {:?}",
+                    msg,
+                    locale
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_fill_placeholders() {
+        assert_eq!(
+            fill(Msg::HookFilesOk.text(Locale::En), &[&3, &1]),
+            "3 contract file(s) OK (1 cached)"
+        );
+        assert_eq!(
+            fill(Msg::HookFilesOk.text(Locale::It), &[&3, &1]),
+            "3 file di contratto OK (1 in cache)"
+        );
+    }
+}
@@ -1,8 +1,25 @@
 mod commands;
+mod docs;
+mod hooks;
+mod i18n;
+mod metrics;
+mod oci;
 mod output;
+mod report;
+mod runs;
+mod sinks;
 
 use anyhow::Result;
-use clap::{Parser, Subcommand};
+use chrono::{DateTime, NaiveDate, Utc};
+use clap::error::ErrorKind;
+use clap::{ArgAction, ArgGroup, Args, CommandFactory, Parser, Subcommand};
+use contracts_core::{EmptyDataPolicy, SamplingStrategy, TimeWindow, ValidationContext};
+use contracts_files::{FileOptions, TypeInference};
+use contracts_iceberg::wap::StagedRef;
+use contracts_iceberg::{RequestLimits, ScanOptions, SnapshotSelector};
+use contracts_parser::{CONFIG_NAME, WorkspaceConfig};
+use std::path::Path;
+use std::time::Duration;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 #[derive(Parser)]
@@ -10,19 +27,46 @@ use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 #[command(version, about = "Data Contracts Engine CLI", long_about = None)]
 struct Cli {
     #[command(subcommand)]
-    command: Commands,
+    command: Option<Commands>,
 
-    /// Enable verbose logging
+    /// Print the formats, catalogs, output formats and checks this build supports, as JSON
+    #[arg(long, exclusive = true)]
+    capabilities: bool,
+
+    /// Increase verbosity (-v for debug logs, -vv for per-check details and timings)
+    #[arg(short, long, global = true, action = ArgAction::Count, conflicts_with = "quiet")]
+    verbose: u8,
+
+    /// Only print a one-line summary; rely on the exit code for the result
     #[arg(short, long, global = true)]
-    verbose: bool,
+    quiet: bool,
+
+    /// Disable colored output (also honors the NO_COLOR environment variable)
+    #[arg(long, global = true)]
+    no_color: bool,
 }
 
+// Parsed once per process, so the size of the largest command does not matter
+#[allow(clippy::large_enum_variant)]
 #[derive(Subcommand)]
 enum Commands {
     /// Validate a contract against actual data
+    #[command(group(ArgGroup::new("window").multiple(true)))]
     Validate {
-        /// Path to the contract file (YAML or TOML)
-        contract: String,
+        /// Path to the contract file (YAML or TOML), or a directory of contracts
+        #[arg(
+            required_unless_present = "contract_from_registry",
+            conflicts_with = "contract_from_registry"
+        )]
+        contract: Option<String>,
+
+        /// Validate the published contract NAME@VERSION (or NAME@latest) from the registry
+        #[arg(long, value_name = "NAME@VERSION")]
+        contract_from_registry: Option<String>,
+
+        /// Contract registry directory (default: $DCE_REGISTRY)
+        #[arg(long, value_name = "DIR", requires = "contract_from_registry")]
+        registry: Option<String>,
 
         /// Enable strict validation mode (fail on warnings)
         #[arg(short, long)]
@@ -36,14 +80,351 @@ enum Commands {
         #[arg(long)]
         sample_size: Option<usize>,
 
+        #[command(flatten)]
+        sampling: Box<SamplingArgs>,
+
+        /// Output format: text, json
+        #[arg(short, long, default_value = "text")]
+        format: String,
+
+        /// Write the sampled rows to this JSON file (Iceberg tables only)
+        #[arg(long, value_name = "FILE")]
+        dump_sample: Option<String>,
+
+        /// Write the report to a .json, .html or .xml (JUnit) file and print only the summary
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<String>,
+
+        /// Maximum number of catalog and storage requests in flight at once (overrides limits.max_concurrent in dce.toml)
+        #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+        max_catalog_requests: Option<u32>,
+
+        /// Maximum number of catalog and storage requests started per second (overrides limits.per_second in dce.toml)
+        #[arg(long, value_name = "RATE", value_parser = parse_rate)]
+        catalog_rate_limit: Option<f64>,
+
+        /// Contracts of a directory validated at once
+        #[arg(short, long, value_name = "N", default_value = "4", value_parser = clap::value_parser!(u32).range(1..))]
+        jobs: u32,
+
+        /// Outcome when no records are read: pass, warn or fail (overrides quality_checks.on_empty)
+        #[arg(long, value_name = "POLICY")]
+        on_empty: Option<EmptyDataPolicy>,
+
+        /// Only validate rows at or after this time (RFC 3339 timestamp or YYYY-MM-DD)
+        #[arg(long, value_name = "TIME", value_parser = parse_time_bound, group = "window")]
+        since: Option<DateTime<Utc>>,
+
+        /// Only validate rows before this time (RFC 3339 timestamp or YYYY-MM-DD)
+        #[arg(long, value_name = "TIME", value_parser = parse_time_bound, group = "window")]
+        until: Option<DateTime<Utc>>,
+
+        /// Timestamp column for --since/--until (default: the freshness check's metric)
+        #[arg(long, value_name = "COLUMN", requires = "window")]
+        time_column: Option<String>,
+
+        /// Validate the Iceberg table as of this snapshot
+        #[arg(long, value_name = "ID", conflicts_with = "as_of")]
+        snapshot_id: Option<i64>,
+
+        /// Validate the Iceberg table as it was at this time (RFC 3339 timestamp or YYYY-MM-DD)
+        #[arg(long, value_name = "TIME", value_parser = parse_time_bound)]
+        as_of: Option<DateTime<Utc>>,
+
+        /// Validate Iceberg rows batch by batch as they are read, so large samples fit in memory
+        #[arg(long)]
+        chunked: bool,
+
+        /// Validate Iceberg tables from the column statistics in their manifests, without reading data
+        #[arg(long, conflicts_with_all = ["schema_only", "chunked", "dump_sample", "row_filter", "segment_by", "window"])]
+        stats_only: bool,
+
+        /// Rows per record batch read from Iceberg data files (default: 1024)
+        #[arg(long, value_name = "ROWS", value_parser = clap::value_parser!(u32).range(1..))]
+        batch_size: Option<u32>,
+
+        /// Split Iceberg data files larger than this many bytes at row group boundaries
+        #[arg(long, value_name = "BYTES", value_parser = clap::value_parser!(u64).range(1..))]
+        target_split_size: Option<u64>,
+
+        /// Number of Iceberg data files read ahead concurrently (default: one per CPU)
+        #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+        prefetch_depth: Option<u32>,
+
+        /// Only validate rows matching this SQL predicate (e.g. "status = 'failed'")
+        #[arg(long = "where", value_name = "PREDICATE")]
+        row_filter: Option<String>,
+
+        /// Also report results per value of this column (e.g. country)
+        #[arg(long, value_name = "COLUMN")]
+        segment_by: Option<String>,
+
+        /// Treat fields missing from a record as holding their contract default
+        #[arg(long)]
+        fill_defaults: bool,
+
+        /// List at most this many errors per contract; the rest are only counted
+        #[arg(long, value_name = "N")]
+        max_errors: Option<usize>,
+
+        /// List at most this many violations of each schema or constraint check per contract
+        #[arg(long, value_name = "N")]
+        max_errors_per_check: Option<usize>,
+
+        /// Stop validating a contract at its first error
+        #[arg(long)]
+        fail_fast: bool,
+
+        /// How CSV and JSON values are typed: contract, data or strings
+        #[arg(long, value_name = "MODE", default_value = "contract")]
+        type_inference: TypeInference,
+
+        /// Resume an interrupted directory run, skipping contracts it completed with unchanged inputs
+        #[arg(long, value_name = "RUN_ID")]
+        resume: Option<String>,
+    },
+
+    /// Validate an Iceberg table every time a new snapshot is committed
+    Monitor {
+        /// Path to the contract file (YAML or TOML) of an Iceberg table
+        contract: String,
+
+        /// Seconds between two polls of the table
+        #[arg(long, value_name = "SECONDS", default_value = "60",
+              value_parser = clap::value_parser!(u64).range(1..))]
+        interval: u64,
+
+        /// Directory for the last validated snapshot and the reports (default: .dce-monitor/<contract>)
+        #[arg(long, value_name = "DIR")]
+        state_dir: Option<String>,
+
+        /// Shell command to run after a validation, with DCE_STATUS, DCE_REPORT, ... set
+        #[arg(long, value_name = "COMMAND")]
+        notify: Option<String>,
+
+        /// When to run --notify: failure, change or always
+        #[arg(
+            long,
+            value_name = "WHEN",
+            default_value = "failure",
+            requires = "notify"
+        )]
+        notify_on: commands::monitor::NotifyOn,
+
+        /// Poll once and exit, failing if the validation fails (e.g. from cron)
+        #[arg(long)]
+        once: bool,
+
+        /// Enable strict validation mode (fail on warnings)
+        #[arg(short, long)]
+        strict: bool,
+
+        /// Number of rows to sample from each increment (default: every added row)
+        #[arg(long)]
+        sample_size: Option<usize>,
+
+        /// Maximum number of catalog and storage requests in flight at once (overrides limits.max_concurrent in dce.toml)
+        #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+        max_catalog_requests: Option<u32>,
+
+        /// Maximum number of catalog and storage requests started per second (overrides limits.per_second in dce.toml)
+        #[arg(long, value_name = "RATE", value_parser = parse_rate)]
+        catalog_rate_limit: Option<f64>,
+    },
+
+    /// Validate a staged Iceberg snapshot before it is published to main
+    #[command(group(ArgGroup::new("staged").required(true)))]
+    Wap {
+        /// Path to the contract file (YAML or TOML) of an Iceberg table
+        contract: String,
+
+        /// Validate the head of this branch
+        #[arg(long, value_name = "NAME", group = "staged")]
+        branch: Option<String>,
+
+        /// Validate this snapshot
+        #[arg(long, value_name = "ID", group = "staged")]
+        snapshot_id: Option<i64>,
+
+        /// Validate the latest snapshot committed with this wap.id
+        #[arg(long, value_name = "ID", group = "staged")]
+        wap_id: Option<String>,
+
+        /// Spark catalog named in the publish command (default: the contract's, or spark_catalog)
+        #[arg(long, value_name = "NAME")]
+        catalog_name: Option<String>,
+
+        /// Enable strict validation mode (fail on warnings)
+        #[arg(short, long)]
+        strict: bool,
+
+        /// Number of rows to sample from the snapshot (default: every row)
+        #[arg(long)]
+        sample_size: Option<usize>,
+
+        /// Output format: text, json
+        #[arg(short, long, default_value = "text")]
+        format: String,
+
+        /// Maximum number of catalog and storage requests in flight at once (overrides limits.max_concurrent in dce.toml)
+        #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+        max_catalog_requests: Option<u32>,
+
+        /// Maximum number of catalog and storage requests started per second (overrides limits.per_second in dce.toml)
+        #[arg(long, value_name = "RATE", value_parser = parse_rate)]
+        catalog_rate_limit: Option<f64>,
+    },
+
+    /// Validate a source and a target against a contract and compare their data
+    Reconcile {
+        /// Path to the contract file (YAML or TOML)
+        #[arg(long)]
+        contract: String,
+
+        /// Location of the source data, replacing the contract's schema.location
+        #[arg(long, value_name = "LOCATION")]
+        source: String,
+
+        /// Location of the target data, replacing the contract's schema.location
+        #[arg(long, value_name = "LOCATION")]
+        target: String,
+
+        /// Enable strict validation mode (fail on warnings)
+        #[arg(short, long)]
+        strict: bool,
+
+        /// Number of rows to sample for validation (the comparison reads every row)
+        #[arg(long)]
+        sample_size: Option<usize>,
+
+        /// Output format: text, json
+        #[arg(short, long, default_value = "text")]
+        format: String,
+
+        /// How CSV and JSON values are typed: contract, data or strings
+        #[arg(long, value_name = "MODE", default_value = "contract")]
+        type_inference: TypeInference,
+    },
+
+    /// Preview which rows and checks a new version of a contract would newly fail
+    PreviewChange {
+        /// Path to the current version of the contract (YAML or TOML)
+        old: String,
+
+        /// Path to the proposed version of the contract (YAML or TOML)
+        new: String,
+
+        /// Location of the data, replacing the contracts' schema.location
+        #[arg(long, value_name = "LOCATION")]
+        data: String,
+
+        /// Enable strict validation mode (fail on warnings)
+        #[arg(short, long)]
+        strict: bool,
+
+        /// Number of leading rows both versions are run against
+        #[arg(long)]
+        sample_size: Option<usize>,
+
         /// Output format: text, json
         #[arg(short, long, default_value = "text")]
         format: String,
+
+        /// How CSV and JSON values are typed: contract, data or strings
+        #[arg(long, value_name = "MODE", default_value = "contract")]
+        type_inference: TypeInference,
     },
 
     /// Check contract schema without validating data
     Check {
-        /// Path to the contract file (YAML or TOML)
+        /// Path to the contract file (YAML or TOML), or a directory of contracts
+        contract: String,
+
+        /// Output format: text, json
+        #[arg(short, long, default_value = "text")]
+        format: String,
+
+        /// Write the definition report to a .json, .html or .xml (JUnit) file
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<String>,
+
+        /// JSON Schema the contract's `x-extensions` block must satisfy
+        #[arg(long, value_name = "FILE")]
+        extensions_schema: Option<String>,
+    },
+
+    /// Lint contract definitions with the built-in rules and those enabled in dce.toml
+    Lint {
+        /// Path to the contract file (YAML or TOML), or a directory of contracts
+        contract: String,
+
+        /// Output format: text, json
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
+
+    /// Check contract owners against CODEOWNERS and the org directory
+    Owners {
+        /// Path to the contract file (YAML or TOML), or a directory of contracts
+        contract: String,
+
+        /// CODEOWNERS file (default: .github/CODEOWNERS, CODEOWNERS or docs/CODEOWNERS)
+        #[arg(long, value_name = "FILE")]
+        codeowners: Option<String>,
+
+        /// JSON file mapping each team to its members and channel
+        #[arg(long, value_name = "FILE")]
+        org_directory: Option<String>,
+
+        /// Output format: text, json
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
+
+    /// Compare two versions of a contract and report breaking changes
+    Diff {
+        /// Path to the old contract file
+        old: String,
+
+        /// Path to the new contract file
+        new: String,
+
+        /// Output format: text, json
+        #[arg(short, long, default_value = "text")]
+        format: String,
+
+        /// Write the diff report to a .json, .html or .xml (JUnit) file
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<String>,
+    },
+
+    /// Compare a contract with the live schema of its Iceberg table
+    Drift {
+        /// Path to the contract file (YAML or TOML) of an Iceberg table
+        contract: String,
+
+        /// Output format: text, json
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
+
+    /// Compare a contract with its live Iceberg table and suggest, or apply, contract edits
+    DiffTable {
+        /// Path to the contract file (YAML or TOML) of an Iceberg table
+        contract: String,
+
+        /// Rewrite the contract file to match the table
+        #[arg(long)]
+        update: bool,
+
+        /// Output format: text, json
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
+
+    /// Diagnose the environment: catalog support, credentials, connectivity and clock skew
+    Doctor {
+        /// Path to the contract file (YAML or TOML) whose connection to check
         contract: String,
 
         /// Output format: text, json
@@ -51,6 +432,16 @@ enum Commands {
         format: String,
     },
 
+    /// Export the field access (classification, allowed roles) a contract declares
+    Access {
+        /// Path to the contract file (YAML or TOML)
+        contract: String,
+
+        /// Output format: text, json, or properties (Iceberg table properties)
+        #[arg(short, long, default_value = "text", value_parser = ["text", "json", "properties"])]
+        format: String,
+    },
+
     /// Initialize a new contract from an existing Iceberg table
     Init {
         /// Iceberg table location or catalog URI
@@ -79,25 +470,259 @@ enum Commands {
         /// Contract description (auto-generated if not provided)
         #[arg(long)]
         description: Option<String>,
+
+        /// Sample the table and propose constraints and quality checks from its data
+        #[arg(long)]
+        profile: bool,
+
+        /// Number of rows sampled by --profile
+        #[arg(long, value_name = "N", default_value_t = 10_000, requires = "profile")]
+        sample_size: usize,
+    },
+
+    /// Publish contract documentation to the Confluence space or Notion page set in dce.toml
+    Publish {
+        /// Path to the contract file (YAML or TOML), or a directory of contracts
+        contract: String,
+
+        /// Where to publish: confluence or notion
+        #[arg(long, value_parser = ["confluence", "notion"])]
+        target: String,
+
+        /// Print the rendered pages as JSON instead of publishing them
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Package a contract with its resolved form and included files into an OCI layout tar archive
+    Bundle {
+        /// Path to the contract file (YAML or TOML)
+        contract: String,
+
+        /// File to package with the contract, such as a tag taxonomy (repeatable)
+        #[arg(long = "include", value_name = "FILE")]
+        includes: Vec<String>,
+
+        /// Archive path (default: <name>-<version>.tar)
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<String>,
+    },
+
+    /// Bundle a contract and push it to an OCI registry as an artifact
+    Push {
+        /// Path to the contract file (YAML or TOML)
+        contract: String,
+
+        /// Target repository, REGISTRY/REPOSITORY[:TAG] (default tag: the contract version)
+        #[arg(value_name = "REFERENCE")]
+        target: String,
+
+        /// File to package with the contract, such as a tag taxonomy (repeatable)
+        #[arg(long = "include", value_name = "FILE")]
+        includes: Vec<String>,
+    },
+
+    /// Pull a contract bundle from an OCI registry into the contract registry
+    Pull {
+        /// Bundle to pull, REGISTRY/REPOSITORY:TAG or REGISTRY/REPOSITORY@DIGEST
+        #[arg(value_name = "REFERENCE")]
+        source: String,
+
+        /// Contract registry directory (default: $DCE_REGISTRY)
+        #[arg(long, value_name = "DIR")]
+        registry: Option<String>,
     },
+
+    /// Pin registry contracts in dce.lock, refreshing them to the latest published files
+    Update {
+        /// References to pin or refresh (NAME@VERSION or NAME@latest); all locked ones if omitted
+        #[arg(value_name = "NAME@VERSION")]
+        references: Vec<String>,
+
+        /// Contract registry directory (default: $DCE_REGISTRY)
+        #[arg(long, value_name = "DIR")]
+        registry: Option<String>,
+    },
+
+    /// Explain an error code (e.g. DCE0102), or list all codes
+    Explain {
+        /// Error code as shown in reports
+        #[arg(value_name = "CODE")]
+        code: Option<String>,
+    },
+
+    /// Manage git hooks that check contract files before commit or push
+    Hook {
+        #[command(subcommand)]
+        action: HookAction,
+    },
+}
+
+impl Commands {
+    /// The `--format` the command prints, for commands that have one.
+    fn format(&self) -> Option<&str> {
+        match self {
+            Commands::Validate { format, .. }
+            | Commands::Wap { format, .. }
+            | Commands::Reconcile { format, .. }
+            | Commands::PreviewChange { format, .. }
+            | Commands::Check { format, .. }
+            | Commands::Lint { format, .. }
+            | Commands::Owners { format, .. }
+            | Commands::Diff { format, .. }
+            | Commands::Drift { format, .. }
+            | Commands::DiffTable { format, .. }
+            | Commands::Doctor { format, .. }
+            | Commands::Access { format, .. } => Some(format),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum HookAction {
+    /// Install a git hook that checks contract files
+    Install {
+        /// Hook type: pre-commit, pre-push
+        #[arg(long, default_value = "pre-commit")]
+        hook: String,
+
+        /// Only check contracts under these paths (defaults to any `contracts/` directory)
+        #[arg(long = "path")]
+        paths: Vec<String>,
+
+        /// Overwrite an existing hook not installed by dce
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Remove a git hook installed by dce
+    Uninstall {
+        /// Hook type: pre-commit, pre-push
+        #[arg(long, default_value = "pre-commit")]
+        hook: String,
+    },
+
+    /// Check contract files as the installed hook does
+    Run {
+        /// Hook type: pre-commit, pre-push
+        #[arg(long, default_value = "pre-commit")]
+        hook: String,
+
+        /// Only check contracts under these paths (defaults to any `contracts/` directory)
+        #[arg(long = "path")]
+        paths: Vec<String>,
+
+        /// Re-check every file, ignoring the cache of previously passing contracts
+        #[arg(long)]
+        no_cache: bool,
+    },
+}
+
+/// How `validate` chooses the rows of a sample.
+#[derive(Args)]
+struct SamplingArgs {
+    /// How sampled rows are chosen: head (the first rows), random or stratified
+    #[arg(long, value_name = "STRATEGY", default_value = "head",
+          value_parser = ["head", "random", "stratified"])]
+    sampling: String,
+
+    /// Seed of random and stratified samples; the same seed draws the same rows
+    #[arg(long, value_name = "N", default_value = "0")]
+    seed: u64,
+
+    /// Stratify the sample by the values of this column (implies --sampling stratified)
+    #[arg(long, value_name = "COLUMN")]
+    stratify_by: Option<String>,
+}
+
+impl SamplingArgs {
+    /// Returns the strategy the flags select.
+    fn strategy(self) -> SamplingStrategy {
+        let seed = self.seed;
+        match (self.sampling.as_str(), self.stratify_by) {
+            (_, Some(column)) => SamplingStrategy::Stratified {
+                column: Some(column),
+                seed,
+            },
+            ("random", None) => SamplingStrategy::Random { seed },
+            ("stratified", None) => SamplingStrategy::Stratified { column: None, seed },
+            _ => SamplingStrategy::Head,
+        }
+    }
+}
+
+/// Parses a time window bound; a bare date means midnight UTC.
+fn parse_time_bound(value: &str) -> Result<DateTime<Utc>, String> {
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(value) {
+        return Ok(timestamp.with_timezone(&Utc));
+    }
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map(|date| date.and_time(Default::default()).and_utc())
+        .map_err(|_| {
+            format!(
+                "'{}' is not an RFC 3339 timestamp or a YYYY-MM-DD date",
+                value
+            )
+        })
+}
+
+/// Parses a positive requests-per-second rate.
+fn parse_rate(value: &str) -> Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(rate) if rate.is_finite() && rate > 0.0 => Ok(rate),
+        _ => Err(format!("'{}' is not a positive number", value)),
+    }
+}
+
+/// Returns the request limits of `[limits]` in `dce.toml`, each overridden
+/// by its command-line flag when given.
+fn request_limits(max_concurrent: Option<u32>, per_second: Option<f64>) -> Result<RequestLimits> {
+    let config = WorkspaceConfig::load(Path::new(CONFIG_NAME))?.limits;
+    if config.max_concurrent == Some(0) {
+        anyhow::bail!(
+            "limits.max_concurrent in {} must be at least 1",
+            CONFIG_NAME
+        );
+    }
+    if let Some(rate) = config.per_second
+        && !(rate.is_finite() && rate > 0.0)
+    {
+        anyhow::bail!(
+            "limits.per_second in {} must be a positive number",
+            CONFIG_NAME
+        );
+    }
+    Ok(RequestLimits {
+        max_concurrent: max_concurrent.map(|n| n as usize).or(config.max_concurrent),
+        per_second: per_second.or(config.per_second),
+    })
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
 
+    output::init_colors(cli.no_color);
+
+    let verbosity = output::Verbosity::from_flags(cli.quiet, cli.verbose);
+    output::set_verbosity(verbosity);
+
     // Initialize tracing
-    let log_level = if cli.verbose {
-        tracing::Level::DEBUG
-    } else {
-        tracing::Level::INFO
+    let log_level = match verbosity {
+        output::Verbosity::Quiet => tracing::Level::ERROR,
+        output::Verbosity::Normal => tracing::Level::INFO,
+        output::Verbosity::Verbose => tracing::Level::DEBUG,
+        output::Verbosity::Detailed => tracing::Level::TRACE,
     };
 
     tracing_subscriber::registry()
         .with(
             tracing_subscriber::fmt::layer()
+                .with_writer(std::io::stderr)
                 .with_target(false)
                 .with_level(true)
+                .with_ansi(output::colors_enabled())
                 .compact(),
         )
         .with(tracing_subscriber::filter::LevelFilter::from_level(
@@ -105,19 +730,296 @@ async fn main() -> Result<()> {
         ))
         .init();
 
+    let command = match (cli.command, cli.capabilities) {
+        (None, true) => return commands::capabilities::execute(),
+        (Some(_), true) => Cli::command()
+            .error(
+                ErrorKind::ArgumentConflict,
+                "--capabilities cannot be used with a command",
+            )
+            .exit(),
+        (None, false) => Cli::command()
+            .error(ErrorKind::MissingSubcommand, "a command is required")
+            .exit(),
+        (Some(command), false) => command,
+    };
+    // Keep stdout a single JSON document for machine-readable formats
+    output::set_json_output(command.format() == Some("json"));
+
     // Execute command
-    match cli.command {
+    match command {
         Commands::Validate {
             contract,
+            contract_from_registry,
+            registry,
             strict,
             schema_only,
             sample_size,
+            sampling,
+            format,
+            dump_sample,
+            output,
+            max_catalog_requests,
+            catalog_rate_limit,
+            jobs,
+            on_empty,
+            since,
+            until,
+            time_column,
+            snapshot_id,
+            as_of,
+            chunked,
+            stats_only,
+            batch_size,
+            target_split_size,
+            prefetch_depth,
+            row_filter,
+            segment_by,
+            fill_defaults,
+            max_errors,
+            max_errors_per_check,
+            fail_fast,
+            type_inference,
+            resume,
+        } => {
+            let contract = match contract_from_registry {
+                Some(reference) => {
+                    commands::validate::resolve_from_registry(&reference, registry.as_deref())?
+                        .display()
+                        .to_string()
+                }
+                None => contract.unwrap_or_default(),
+            };
+            let context = ValidationContext {
+                strict,
+                schema_only,
+                sample_size,
+                sampling: sampling.strategy(),
+                on_empty,
+                time_window: (since.is_some() || until.is_some()).then_some(TimeWindow {
+                    column: time_column,
+                    since,
+                    until,
+                }),
+                row_filter,
+                segment_by,
+                fill_defaults,
+                parallelism: None,
+                max_errors,
+                max_errors_per_check,
+                fail_fast,
+                metadata: Default::default(),
+            };
+            let limits = commands::validate::Limits {
+                requests: request_limits(max_catalog_requests, catalog_rate_limit)?,
+                jobs: jobs as usize,
+            };
+            commands::validate::execute(
+                &contract,
+                context,
+                limits,
+                &format,
+                commands::validate::ReportFiles {
+                    sample: dump_sample.as_deref().map(Path::new),
+                    report: output.as_deref().map(Path::new),
+                },
+                commands::validate::ReadOptions {
+                    files: FileOptions {
+                        inference: type_inference,
+                        ..FileOptions::default()
+                    },
+                    snapshot: snapshot_id
+                        .map(SnapshotSelector::Id)
+                        .or(as_of.map(SnapshotSelector::AsOf)),
+                    chunked,
+                    stats_only,
+                    scan: ScanOptions {
+                        batch_size: batch_size.map(|n| n as usize),
+                        target_split_size,
+                        prefetch_depth: prefetch_depth.map(|n| n as usize),
+                    },
+                },
+                verbosity,
+                resume.as_deref(),
+            )
+            .await
+        }
+
+        Commands::Monitor {
+            contract,
+            interval,
+            state_dir,
+            notify,
+            notify_on,
+            once,
+            strict,
+            sample_size,
+            max_catalog_requests,
+            catalog_rate_limit,
+        } => {
+            let context = ValidationContext::new().with_strict(strict);
+            let context = match sample_size {
+                Some(size) => context.with_sample_size(size),
+                None => context,
+            };
+            let limits = request_limits(max_catalog_requests, catalog_rate_limit)?;
+            commands::monitor::execute(
+                &contract,
+                context,
+                limits,
+                commands::monitor::MonitorOptions {
+                    interval: Duration::from_secs(interval),
+                    state_dir: state_dir.as_deref().map(Path::new),
+                    notify: notify.as_deref(),
+                    notify_on,
+                    once,
+                },
+            )
+            .await
+        }
+
+        Commands::Wap {
+            contract,
+            branch,
+            snapshot_id,
+            wap_id,
+            catalog_name,
+            strict,
+            sample_size,
             format,
+            max_catalog_requests,
+            catalog_rate_limit,
         } => {
-            commands::validate::execute(&contract, strict, schema_only, sample_size, &format).await
+            let staged = match (branch, snapshot_id, wap_id) {
+                (Some(branch), _, _) => StagedRef::Branch(branch),
+                (_, Some(snapshot_id), _) => StagedRef::Snapshot(snapshot_id),
+                (_, _, Some(wap_id)) => StagedRef::WapId(wap_id),
+                (None, None, None) => unreachable!("clap requires one staged reference"),
+            };
+            let context = ValidationContext::new().with_strict(strict);
+            let context = match sample_size {
+                Some(size) => context.with_sample_size(size),
+                None => context,
+            };
+            let limits = request_limits(max_catalog_requests, catalog_rate_limit)?;
+            commands::wap::execute(
+                &contract,
+                staged,
+                context,
+                limits,
+                catalog_name.as_deref(),
+                &format,
+                verbosity,
+            )
+            .await
         }
 
-        Commands::Check { contract, format } => commands::check::execute(&contract, &format).await,
+        Commands::Reconcile {
+            contract,
+            source,
+            target,
+            strict,
+            sample_size,
+            format,
+            type_inference,
+        } => {
+            let context = ValidationContext::new().with_strict(strict);
+            let context = match sample_size {
+                Some(size) => context.with_sample_size(size),
+                None => context,
+            };
+            commands::reconcile::execute(
+                &contract,
+                &source,
+                &target,
+                context,
+                FileOptions {
+                    inference: type_inference,
+                    ..FileOptions::default()
+                },
+                &format,
+                verbosity,
+            )
+            .await
+        }
+
+        Commands::PreviewChange {
+            old,
+            new,
+            data,
+            strict,
+            sample_size,
+            format,
+            type_inference,
+        } => {
+            let context = ValidationContext::new().with_strict(strict);
+            let context = match sample_size {
+                Some(size) => context.with_sample_size(size),
+                None => context,
+            };
+            commands::preview_change::execute(
+                &old,
+                &new,
+                &data,
+                context,
+                FileOptions {
+                    inference: type_inference,
+                    ..FileOptions::default()
+                },
+                &format,
+            )
+            .await
+        }
+
+        Commands::Check {
+            contract,
+            format,
+            output,
+            extensions_schema,
+        } => {
+            commands::check::execute(
+                &contract,
+                &format,
+                output.as_deref().map(Path::new),
+                extensions_schema.as_deref().map(Path::new),
+            )
+            .await
+        }
+
+        Commands::Lint { contract, format } => commands::lint::execute(&contract, &format),
+
+        Commands::Owners {
+            contract,
+            codeowners,
+            org_directory,
+            format,
+        } => commands::owners::execute(
+            &contract,
+            codeowners.as_deref().map(Path::new),
+            org_directory.as_deref().map(Path::new),
+            &format,
+        ),
+
+        Commands::Diff {
+            old,
+            new,
+            format,
+            output,
+        } => commands::diff::execute(&old, &new, &format, output.as_deref().map(Path::new)),
+
+        Commands::Drift { contract, format } => commands::drift::execute(&contract, &format).await,
+
+        Commands::DiffTable {
+            contract,
+            update,
+            format,
+        } => commands::diff_table::execute(&contract, update, &format).await,
+
+        Commands::Doctor { contract, format } => {
+            commands::doctor::execute(&contract, &format).await
+        }
+
+        Commands::Access { contract, format } => commands::access::execute(&contract, &format),
 
         Commands::Init {
             source,
@@ -127,6 +1029,8 @@ async fn main() -> Result<()> {
             table,
             owner,
             description,
+            profile,
+            sample_size,
         } => {
             commands::init::execute(
                 &source,
@@ -134,10 +1038,54 @@ async fn main() -> Result<()> {
                 &catalog,
                 namespace,
                 table,
-                owner,
-                description,
+                commands::init::InitOptions {
+                    owner,
+                    description,
+                    profile_rows: profile.then_some(sample_size),
+                },
             )
             .await
         }
+
+        Commands::Publish {
+            contract,
+            target,
+            dry_run,
+        } => commands::publish::execute(&contract, &target, dry_run).await,
+
+        Commands::Bundle {
+            contract,
+            includes,
+            output,
+        } => commands::bundle::execute(&contract, &includes, output.as_deref()),
+
+        Commands::Push {
+            contract,
+            target,
+            includes,
+        } => commands::push::execute(&contract, &target, &includes).await,
+
+        Commands::Pull { source, registry } => {
+            commands::pull::execute(&source, registry.as_deref()).await
+        }
+
+        Commands::Update {
+            references,
+            registry,
+        } => commands::update::execute(&references, registry.as_deref()),
+
+        Commands::Explain { code } => commands::explain::execute(code.as_deref()),
+
+        Commands::Hook { action } => match action {
+            HookAction::Install { hook, paths, force } => {
+                commands::hook::install(&hook, &paths, force)
+            }
+            HookAction::Uninstall { hook } => commands::hook::uninstall(&hook),
+            HookAction::Run {
+                hook,
+                paths,
+                no_cache,
+            } => commands::hook::run(&hook, &paths, no_cache),
+        },
     }
 }
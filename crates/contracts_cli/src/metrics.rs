@@ -0,0 +1,294 @@
+//! Validation metrics for the exporters configured in `dce.toml`.
+//!
+//! With the `statsd` feature, every validated contract sends its validation
+//! duration, error and warning counts and quality score to a StatsD or
+//! DogStatsD agent, tagged with the contract name and owner:
+//!
+//! ```text
+//! dce.validation.duration:412|ms|#contract:orders,owner:sales
+//! dce.validation.errors:2|g|#contract:orders,owner:sales
+//! dce.validation.warnings:0|g|#contract:orders,owner:sales
+//! dce.validation.quality_score:0.75|g|#contract:orders,owner:sales
+//! ```
+//!
+//! Metrics are sent over UDP and never fail a validation.
+
+use anyhow::Result;
+use contracts_core::{Contract, ValidationReport};
+use contracts_parser::{CONFIG_NAME, WorkspaceConfig};
+use std::path::Path;
+
+/// The metrics exporters of a run.
+pub struct Metrics {
+    #[cfg(feature = "statsd")]
+    statsd: Option<statsd::Statsd>,
+}
+
+impl Metrics {
+    /// Sets up the exporters configured in the working directory's `dce.toml`.
+    pub fn from_workspace() -> Result<Self> {
+        let config = WorkspaceConfig::load(Path::new(CONFIG_NAME))?;
+        Self::new(&config)
+    }
+
+    /// Sets up the exporters configured in `config`.
+    pub fn new(config: &WorkspaceConfig) -> Result<Self> {
+        #[cfg(feature = "statsd")]
+        return Ok(Self {
+            statsd: config
+                .metrics
+                .statsd
+                .as_ref()
+                .map(statsd::Statsd::new)
+                .transpose()?,
+        });
+
+        #[cfg(not(feature = "statsd"))]
+        {
+            if config.metrics.statsd.is_some() {
+                tracing::warn!(
+                    "{} configures StatsD metrics, but dce was built without the statsd feature",
+                    CONFIG_NAME
+                );
+            }
+            Ok(Self {})
+        }
+    }
+
+    /// Sends the metrics of one validation.
+    #[cfg_attr(not(feature = "statsd"), allow(unused_variables))]
+    pub fn record(&self, contract: &Contract, report: &ValidationReport) {
+        #[cfg(feature = "statsd")]
+        if let Some(statsd) = &self.statsd {
+            statsd.send(contract, report);
+        }
+    }
+}
+
+/// Share of the checked fields without errors or warnings, from 0.0 to 1.0.
+///
+/// A finding without a field, such as a failed freshness check, concerns
+/// every field and brings the score to 0.
+#[cfg_attr(not(feature = "statsd"), allow(dead_code))]
+fn quality_score(report: &ValidationReport) -> f64 {
+    let findings = report.errors.iter().chain(&report.warnings);
+    let mut flagged = std::collections::BTreeSet::new();
+    for finding in findings {
+        match &finding.field {
+            Some(field) => flagged.insert(field.as_str()),
+            None => return 0.0,
+        };
+    }
+    let fields = report.stats.fields_checked.max(flagged.len());
+    if fields == 0 {
+        return 1.0;
+    }
+    (fields - flagged.len()) as f64 / fields as f64
+}
+
+#[cfg(feature = "statsd")]
+mod statsd {
+    use super::quality_score;
+    use anyhow::{Context, Result, anyhow};
+    use contracts_core::{Contract, ValidationReport};
+    use contracts_parser::{StatsdConfig, StatsdProtocol};
+    use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
+    use tracing::warn;
+
+    /// A StatsD or DogStatsD agent.
+    pub struct Statsd {
+        config: StatsdConfig,
+        agent: SocketAddr,
+        socket: UdpSocket,
+    }
+
+    impl Statsd {
+        /// Resolves the agent address and opens a socket towards it.
+        pub fn new(config: &StatsdConfig) -> Result<Self> {
+            let agent = config
+                .address
+                .to_socket_addrs()
+                .with_context(|| format!("Invalid StatsD address '{}'", config.address))?
+                .next()
+                .ok_or_else(|| anyhow!("StatsD address '{}' did not resolve", config.address))?;
+            let local = if agent.is_ipv4() {
+                "0.0.0.0:0"
+            } else {
+                "[::]:0"
+            };
+            Ok(Self {
+                config: config.clone(),
+                agent,
+                socket: UdpSocket::bind(local).context("Failed to open a StatsD socket")?,
+            })
+        }
+
+        /// Sends the metrics of one validation as a single datagram.
+        pub fn send(&self, contract: &Contract, report: &ValidationReport) {
+            let payload = self.lines(contract, report).join("\n");
+            if let Err(e) = self.socket.send_to(payload.as_bytes(), self.agent) {
+                warn!("Failed to send metrics to {}: {}", self.config.address, e);
+            }
+        }
+
+        /// Formats the metrics of one validation, one per line.
+        pub(super) fn lines(&self, contract: &Contract, report: &ValidationReport) -> Vec<String> {
+            let metrics = [
+                ("duration", report.stats.duration_ms.to_string(), "ms"),
+                ("errors", report.errors.len().to_string(), "g"),
+                ("warnings", report.warnings.len().to_string(), "g"),
+                ("quality_score", quality_score(report).to_string(), "g"),
+            ];
+
+            let prefix = &self.config.prefix;
+            match self.config.protocol {
+                StatsdProtocol::DogStatsd => {
+                    let tags = [
+                        ("contract", contract.name.as_str()),
+                        ("owner", &contract.owner),
+                    ]
+                    .into_iter()
+                    .chain(
+                        self.config
+                            .tags
+                            .iter()
+                            .map(|(k, v)| (k.as_str(), v.as_str())),
+                    )
+                    .map(|(key, value)| format!("{}:{}", sanitize(key), sanitize(value)))
+                    .collect::<Vec<_>>()
+                    .join(",");
+                    metrics
+                        .iter()
+                        .map(|(name, value, kind)| {
+                            format!("{prefix}.validation.{name}:{value}|{kind}|#{tags}")
+                        })
+                        .collect()
+                }
+                StatsdProtocol::Statsd => {
+                    let contract = sanitize(&contract.name).replace(['.', ':'], "_");
+                    metrics
+                        .iter()
+                        .map(|(name, value, kind)| {
+                            format!("{prefix}.{contract}.validation.{name}:{value}|{kind}")
+                        })
+                        .collect()
+                }
+            }
+        }
+    }
+
+    /// Replaces the characters that delimit StatsD fields and tags.
+    fn sanitize(text: &str) -> String {
+        text.replace(['|', ',', '#', '\n', ' '], "_")
+    }
+}
+
+#[cfg(all(test, feature = "statsd"))]
+mod tests {
+    use super::*;
+    use contracts_core::{
+        CheckKind, ContractBuilder, DataFormat, FieldBuilder, ValidationStats, Violation, codes,
+    };
+    use contracts_parser::{StatsdConfig, StatsdProtocol};
+    use pretty_assertions::assert_eq;
+    use std::collections::BTreeMap;
+    use std::net::UdpSocket;
+    use std::time::Duration;
+
+    fn contract() -> Contract {
+        ContractBuilder::new("orders", "sales team")
+            .location("s3://lake/orders")
+            .format(DataFormat::Iceberg)
+            .fields(
+                ["id", "total", "status", "placed_at"]
+                    .map(|name| FieldBuilder::new(name, "string").build())
+                    .to_vec(),
+            )
+            .build()
+    }
+
+    fn report() -> ValidationReport {
+        let mut report = ValidationReport::success();
+        report.stats = ValidationStats {
+            fields_checked: 4,
+            duration_ms: 412,
+            ..ValidationStats::default()
+        };
+        let violation = |field: &str| {
+            let mut violation =
+                Violation::new(&codes::OUT_OF_RANGE, CheckKind::Constraint, "out of range");
+            violation.field = Some(field.to_string());
+            violation
+        };
+        report.add_error(violation("total"));
+        report.add_error(violation("total"));
+        report
+    }
+
+    fn config(protocol: StatsdProtocol) -> StatsdConfig {
+        StatsdConfig {
+            address: "127.0.0.1:8125".to_string(),
+            prefix: "dce".to_string(),
+            protocol,
+            tags: BTreeMap::from([("env".to_string(), "prod".to_string())]),
+        }
+    }
+
+    #[test]
+    fn test_quality_score() {
+        assert_eq!(quality_score(&report()), 0.75);
+        assert_eq!(quality_score(&ValidationReport::success()), 1.0);
+
+        let mut report = report();
+        report.add_warning(Violation::new(
+            &codes::STALE_DATA,
+            CheckKind::Freshness,
+            "stale",
+        ));
+        assert_eq!(quality_score(&report), 0.0);
+    }
+
+    #[test]
+    fn test_dogstatsd_lines_are_tagged() {
+        let statsd = statsd::Statsd::new(&config(StatsdProtocol::DogStatsd)).unwrap();
+        assert_eq!(
+            statsd.lines(&contract(), &report()),
+            vec![
+                "dce.validation.duration:412|ms|#contract:orders,owner:sales_team,env:prod",
+                "dce.validation.errors:2|g|#contract:orders,owner:sales_team,env:prod",
+                "dce.validation.warnings:0|g|#contract:orders,owner:sales_team,env:prod",
+                "dce.validation.quality_score:0.75|g|#contract:orders,owner:sales_team,env:prod",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_statsd_lines_name_the_contract() {
+        let statsd = statsd::Statsd::new(&config(StatsdProtocol::Statsd)).unwrap();
+        let lines = statsd.lines(&contract(), &report());
+        assert_eq!(lines[1], "dce.orders.validation.errors:2|g");
+    }
+
+    #[test]
+    fn test_record_sends_one_datagram() {
+        let agent = UdpSocket::bind("127.0.0.1:0").unwrap();
+        agent
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+        let mut config = WorkspaceConfig::default();
+        config.metrics.statsd = Some(StatsdConfig {
+            address: agent.local_addr().unwrap().to_string(),
+            ..self::config(StatsdProtocol::DogStatsd)
+        });
+
+        Metrics::new(&config)
+            .unwrap()
+            .record(&contract(), &report());
+
+        let mut buf = [0u8; 1024];
+        let len = agent.recv(&mut buf).unwrap();
+        let payload = std::str::from_utf8(&buf[..len]).unwrap();
+        assert_eq!(payload.lines().count(), 4);
+        assert!(payload.starts_with("dce.validation.duration:412|ms|#contract:orders"));
+    }
+}
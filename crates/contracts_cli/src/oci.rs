@@ -0,0 +1,401 @@
+//! Pushing and pulling contract bundles to and from OCI registries.
+//!
+//! Bundles are stored as OCI artifacts, as `oras push` would store them, so
+//! any registry implementing the OCI distribution API (ECR, GHCR,
+//! Artifactory, Harbor, `registry:2`) keeps them under its own access
+//! control and retention policies. References name a repository and a tag
+//! or digest:
+//!
+//! ```text
+//! ghcr.io/acme/contracts/orders:1.2.0
+//! 123456789012.dkr.ecr.eu-west-1.amazonaws.com/contracts/orders@sha256:...
+//! ```
+//!
+//! Credentials come from the environment: `DCE_OCI_TOKEN` is sent as a
+//! bearer token, `DCE_OCI_USERNAME` and `DCE_OCI_PASSWORD` as basic
+//! credentials or exchanged for a token when the registry asks for one.
+//! Registries on `localhost` are reached over plain HTTP.
+
+use anyhow::{Context, Result, anyhow, bail};
+use contracts_parser::{ContractBundle, MANIFEST_MEDIA_TYPE, blob_digest};
+use reqwest::header::{ACCEPT, CONTENT_TYPE, LOCATION, WWW_AUTHENTICATE};
+use reqwest::{Client, RequestBuilder, Response, StatusCode};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Mutex;
+use tracing::debug;
+
+/// A repository in an OCI registry, with the tag or digest of one manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OciReference {
+    /// Registry host, with its port if any
+    pub registry: String,
+    /// Repository path within the registry
+    pub repository: String,
+    /// Tag or `sha256:` digest of the manifest, if given
+    pub reference: Option<String>,
+}
+
+impl OciReference {
+    /// Returns the reference with `tag` if it names no tag or digest.
+    pub fn or_tag(mut self, tag: &str) -> Self {
+        self.reference.get_or_insert_with(|| tag.to_string());
+        self
+    }
+
+    /// Base URL of the registry's distribution API.
+    fn base_url(&self) -> String {
+        let host = self.registry.split(':').next().unwrap_or_default();
+        let scheme = if host == "localhost" || host == "127.0.0.1" {
+            "http"
+        } else {
+            "https"
+        };
+        format!("{}://{}/v2/{}", scheme, self.registry, self.repository)
+    }
+}
+
+impl FromStr for OciReference {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || {
+            anyhow!(
+                "Invalid OCI reference '{}' (expected registry/repository:tag)",
+                s
+            )
+        };
+        let rest = s.strip_prefix("oci://").unwrap_or(s);
+        let (registry, path) = rest.split_once('/').ok_or_else(invalid)?;
+        if !(registry.contains(['.', ':']) || registry == "localhost") {
+            return Err(invalid());
+        }
+
+        let (repository, reference) = if let Some((repository, digest)) = path.split_once('@') {
+            (repository, Some(digest))
+        } else {
+            match path.rsplit_once(':') {
+                Some((repository, tag)) if !tag.contains('/') => (repository, Some(tag)),
+                _ => (path, None),
+            }
+        };
+        if repository.is_empty() || reference.is_some_and(str::is_empty) {
+            return Err(invalid());
+        }
+        Ok(Self {
+            registry: registry.to_string(),
+            repository: repository.to_string(),
+            reference: reference.map(str::to_string),
+        })
+    }
+}
+
+impl fmt::Display for OciReference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.registry, self.repository)?;
+        match &self.reference {
+            Some(digest) if digest.starts_with("sha256:") => write!(f, "@{}", digest),
+            Some(tag) => write!(f, ":{}", tag),
+            None => Ok(()),
+        }
+    }
+}
+
+/// How requests are authorized once the registry asked for credentials.
+#[derive(Debug, Clone)]
+enum Authorization {
+    Basic,
+    Bearer(String),
+}
+
+/// A client of the OCI distribution API.
+pub struct OciClient {
+    client: Client,
+    username: Option<String>,
+    password: Option<String>,
+    authorization: Mutex<Option<Authorization>>,
+}
+
+impl OciClient {
+    /// Creates a client with the credentials of the environment.
+    pub fn from_env() -> Self {
+        let var = |name| std::env::var(name).ok().filter(|v: &String| !v.is_empty());
+        Self {
+            client: Client::new(),
+            username: var("DCE_OCI_USERNAME"),
+            password: var("DCE_OCI_PASSWORD"),
+            authorization: Mutex::new(var("DCE_OCI_TOKEN").map(Authorization::Bearer)),
+        }
+    }
+
+    /// Pushes the blobs and manifest of `bundle` to `target`, tagged with
+    /// its reference, and returns the manifest digest.
+    pub async fn push(&self, bundle: &ContractBundle, target: &OciReference) -> Result<String> {
+        let tag = target
+            .reference
+            .as_deref()
+            .ok_or_else(|| anyhow!("No tag to push {} to", target))?;
+        let base = target.base_url();
+        for (digest, content) in bundle.blobs() {
+            self.push_blob(&base, &digest, content).await?;
+        }
+
+        let manifest = bundle.manifest().to_string();
+        let digest = blob_digest(manifest.as_bytes());
+        let response = self
+            .send(
+                self.client
+                    .put(format!("{}/manifests/{}", base, tag))
+                    .header(CONTENT_TYPE, MANIFEST_MEDIA_TYPE)
+                    .body(manifest),
+            )
+            .await?;
+        expect_success(response, "push the manifest").await?;
+        Ok(digest)
+    }
+
+    /// Uploads one blob, unless the repository already has it.
+    async fn push_blob(&self, base: &str, digest: &str, content: &[u8]) -> Result<()> {
+        let url = format!("{}/blobs/{}", base, digest);
+        let response = self.send(self.client.head(&url)).await?;
+        if response.status().is_success() {
+            debug!("Blob {} is already in the registry", digest);
+            return Ok(());
+        }
+
+        let response = self
+            .send(self.client.post(format!("{}/blobs/uploads/", base)))
+            .await?;
+        let response = expect_success(response, "start a blob upload").await?;
+        let location = response
+            .headers()
+            .get(LOCATION)
+            .and_then(|value| value.to_str().ok())
+            .ok_or_else(|| anyhow!("The registry started an upload without a location"))?;
+        let location = response.url().join(location)?;
+        let response = self
+            .send(
+                self.client
+                    .put(location)
+                    .query(&[("digest", digest)])
+                    .header(CONTENT_TYPE, "application/octet-stream")
+                    .body(content.to_vec()),
+            )
+            .await?;
+        expect_success(response, "upload a blob").await?;
+        Ok(())
+    }
+
+    /// Fetches the manifest `source` points to.
+    pub async fn pull_manifest(&self, source: &OciReference) -> Result<Value> {
+        let reference = source
+            .reference
+            .as_deref()
+            .ok_or_else(|| anyhow!("{} names no tag or digest to pull", source))?;
+        let response = self
+            .send(
+                self.client
+                    .get(format!("{}/manifests/{}", source.base_url(), reference))
+                    .header(ACCEPT, MANIFEST_MEDIA_TYPE),
+            )
+            .await?;
+        let body = expect_success(response, "fetch the manifest")
+            .await?
+            .bytes()
+            .await?;
+        if reference.starts_with("sha256:") && blob_digest(&body) != reference {
+            bail!("The manifest of {} does not match its digest", source);
+        }
+        serde_json::from_slice(&body).context("The manifest is not JSON")
+    }
+
+    /// Fetches the blob `digest` of the repository of `source` and checks
+    /// its content against the digest.
+    pub async fn pull_blob(&self, source: &OciReference, digest: &str) -> Result<Vec<u8>> {
+        let response = self
+            .send(
+                self.client
+                    .get(format!("{}/blobs/{}", source.base_url(), digest)),
+            )
+            .await?;
+        let body = expect_success(response, "fetch a blob")
+            .await?
+            .bytes()
+            .await?;
+        if blob_digest(&body) != digest {
+            bail!("Blob {} of {} does not match its digest", digest, source);
+        }
+        Ok(body.to_vec())
+    }
+
+    /// Sends `request`, answering an authentication challenge once.
+    async fn send(&self, request: RequestBuilder) -> Result<Response> {
+        let retry = request.try_clone();
+        let response = self
+            .authorize(request)
+            .send()
+            .await
+            .context("Failed to reach the OCI registry")?;
+        if response.status() != StatusCode::UNAUTHORIZED {
+            return Ok(response);
+        }
+        let Some(retry) = retry else {
+            return Ok(response);
+        };
+
+        let challenge = response
+            .headers()
+            .get(WWW_AUTHENTICATE)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+        let authorization = self.answer(&challenge).await?;
+        *self.authorization.lock().unwrap() = Some(authorization);
+        self.authorize(retry)
+            .send()
+            .await
+            .context("Failed to reach the OCI registry")
+    }
+
+    /// Authorizes `request` as the registry last asked.
+    fn authorize(&self, request: RequestBuilder) -> RequestBuilder {
+        match self.authorization.lock().unwrap().clone() {
+            Some(Authorization::Bearer(token)) => request.bearer_auth(token),
+            Some(Authorization::Basic) => match &self.username {
+                Some(username) => request.basic_auth(username, self.password.as_ref()),
+                None => request,
+            },
+            None => request,
+        }
+    }
+
+    /// Answers a `WWW-Authenticate` challenge, fetching a token from the
+    /// realm it names for bearer challenges.
+    async fn answer(&self, challenge: &str) -> Result<Authorization> {
+        let Some((scheme, params)) = parse_challenge(challenge) else {
+            bail!("The OCI registry refused the request without a challenge");
+        };
+        if scheme.eq_ignore_ascii_case("basic") {
+            if self.username.is_none() {
+                bail!("The OCI registry requires DCE_OCI_USERNAME and DCE_OCI_PASSWORD");
+            }
+            return Ok(Authorization::Basic);
+        }
+
+        let realm = params
+            .get("realm")
+            .ok_or_else(|| anyhow!("The OCI registry challenge names no realm"))?;
+        let query: Vec<(&str, &String)> = ["service", "scope"]
+            .into_iter()
+            .filter_map(|key| params.get(key).map(|value| (key, value)))
+            .collect();
+        let mut request = self.client.get(realm).query(&query);
+        if let Some(username) = &self.username {
+            request = request.basic_auth(username, self.password.as_ref());
+        }
+        let response = request
+            .send()
+            .await
+            .context("Failed to reach the OCI token service")?;
+        let body: Value = expect_success(response, "obtain a registry token")
+            .await?
+            .json()
+            .await?;
+        body["token"]
+            .as_str()
+            .or_else(|| body["access_token"].as_str())
+            .map(|token| Authorization::Bearer(token.to_string()))
+            .ok_or_else(|| anyhow!("The OCI token service returned no token"))
+    }
+}
+
+/// Returns `response` if it succeeded, or an error naming `action`.
+async fn expect_success(response: Response, action: &str) -> Result<Response> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    bail!(
+        "Failed to {}: {} {}",
+        action,
+        status,
+        response.text().await.unwrap_or_default().trim()
+    )
+}
+
+/// Splits a `WWW-Authenticate` header into its scheme and parameters, such
+/// as `Bearer` and `realm`, `service` and `scope`.
+fn parse_challenge(challenge: &str) -> Option<(&str, BTreeMap<String, String>)> {
+    let (scheme, mut rest) = challenge
+        .trim()
+        .split_once(' ')
+        .unwrap_or((challenge.trim(), ""));
+    if scheme.is_empty() {
+        return None;
+    }
+    let mut params = BTreeMap::new();
+    while let Some((key, value)) = rest.split_once('=') {
+        let key = key.trim().trim_start_matches(',').trim().to_lowercase();
+        let value = value.trim_start();
+        let (value, remainder) = match value.strip_prefix('"') {
+            Some(quoted) => quoted.split_once('"').unwrap_or((quoted, "")),
+            None => value.split_once(',').unwrap_or((value, "")),
+        };
+        params.insert(key, value.to_string());
+        rest = remainder;
+    }
+    Some((scheme, params))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse_references() {
+        let reference: OciReference = "ghcr.io/acme/contracts/orders:1.2.0".parse().unwrap();
+        assert_eq!(reference.registry, "ghcr.io");
+        assert_eq!(reference.repository, "acme/contracts/orders");
+        assert_eq!(reference.reference.as_deref(), Some("1.2.0"));
+        assert_eq!(
+            reference.base_url(),
+            "https://ghcr.io/v2/acme/contracts/orders"
+        );
+
+        let untagged: OciReference = "oci://localhost:5000/orders".parse().unwrap();
+        assert_eq!(untagged.reference, None);
+        assert_eq!(untagged.base_url(), "http://localhost:5000/v2/orders");
+        assert_eq!(
+            untagged.or_tag("2.0.0").to_string(),
+            "localhost:5000/orders:2.0.0"
+        );
+
+        let pinned: OciReference = "registry.example.com/orders@sha256:abc".parse().unwrap();
+        assert_eq!(pinned.reference.as_deref(), Some("sha256:abc"));
+        assert_eq!(pinned.to_string(), "registry.example.com/orders@sha256:abc");
+
+        assert!("orders:1.0.0".parse::<OciReference>().is_err());
+        assert!("acme/orders:1.0.0".parse::<OciReference>().is_err());
+        assert!("ghcr.io/orders:".parse::<OciReference>().is_err());
+    }
+
+    #[test]
+    fn test_parse_challenge() {
+        let (scheme, params) = parse_challenge(
+            r#"Bearer realm="https://ghcr.io/token",service="ghcr.io",scope="repository:acme/orders:pull,push""#,
+        )
+        .unwrap();
+        assert_eq!(scheme, "Bearer");
+        assert_eq!(params["realm"], "https://ghcr.io/token");
+        assert_eq!(params["service"], "ghcr.io");
+        assert_eq!(params["scope"], "repository:acme/orders:pull,push");
+
+        let (scheme, params) = parse_challenge(r#"Basic realm="Artifactory""#).unwrap();
+        assert_eq!(scheme, "Basic");
+        assert_eq!(params["realm"], "Artifactory");
+        assert!(parse_challenge("").is_none());
+    }
+}
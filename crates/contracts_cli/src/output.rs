@@ -1,76 +1,846 @@
 use colored::*;
-use contracts_core::ValidationReport;
+use comfy_table::presets::UTF8_FULL_CONDENSED;
+use comfy_table::{Attribute, Cell, CellAlignment, Color, ContentArrangement, Table};
+use contracts_core::{
+    CheckKind, ColumnStats, ConformanceLevel, ContractProvenance, DeleteStats, ValidationReport,
+    ValidationStatus,
+};
+use contracts_validator::Difference;
 use serde_json::json;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::time::Duration;
 
-pub fn print_validation_report(report: &ValidationReport, format: &str) {
-    match format {
-        "json" => print_json_report(report),
-        _ => print_text_report(report),
+use crate::i18n::{Msg, tr, trf};
+
+/// Fallback width used when the terminal size cannot be detected.
+const DEFAULT_WIDTH: u16 = 80;
+
+/// Upper bound for rendered output, so reports stay readable on wide terminals.
+const MAX_WIDTH: u16 = 120;
+
+/// Messages in the results table are shortened to this many characters
+/// unless detailed output (`-vv`) is requested.
+const MAX_MESSAGE_LEN: usize = 160;
+
+/// How much detail the CLI prints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    /// Summary line only (`--quiet`)
+    Quiet,
+    /// Default output
+    Normal,
+    /// Debug logging (`-v`)
+    Verbose,
+    /// Full messages, statistics and timings (`-vv`)
+    Detailed,
+}
+
+impl Verbosity {
+    /// Builds the verbosity from the `--quiet` flag and the number of `-v` flags.
+    pub fn from_flags(quiet: bool, verbose: u8) -> Self {
+        match (quiet, verbose) {
+            (true, _) => Verbosity::Quiet,
+            (false, 0) => Verbosity::Normal,
+            (false, 1) => Verbosity::Verbose,
+            (false, _) => Verbosity::Detailed,
+        }
     }
 }
 
-fn print_text_report(report: &ValidationReport) {
-    println!("\n{}", "═".repeat(60));
-    println!("{}", "  VALIDATION REPORT".bold());
-    println!("{}", "═".repeat(60));
+static VERBOSITY: AtomicU8 = AtomicU8::new(Verbosity::Normal as u8);
 
-    if report.passed {
-        println!(
-            "\n{} {}",
-            "✓".green().bold(),
-            "Validation PASSED".green().bold()
-        );
+/// Sets the process-wide verbosity used by the `print_*` helpers.
+pub fn set_verbosity(verbosity: Verbosity) {
+    VERBOSITY.store(verbosity as u8, Ordering::Relaxed);
+}
+
+fn is_quiet() -> bool {
+    VERBOSITY.load(Ordering::Relaxed) == Verbosity::Quiet as u8
+}
+
+static JSON_OUTPUT: AtomicBool = AtomicBool::new(false);
+
+/// Sends the status messages of the `print_*` helpers to stderr, so stdout
+/// carries only the JSON document a command prints.
+pub fn set_json_output(json: bool) {
+    JSON_OUTPUT.store(json, Ordering::Relaxed);
+}
+
+/// Prints a status message to stdout, or to stderr when stdout is reserved
+/// for JSON output.
+fn print_status(line: String) {
+    if JSON_OUTPUT.load(Ordering::Relaxed) {
+        eprintln!("{}", line);
     } else {
-        println!(
-            "\n{} {}",
-            "✗".red().bold(),
-            "Validation FAILED".red().bold()
-        );
+        println!("{}", line);
+    }
+}
+
+/// Enables or disables colored output for the whole process.
+///
+/// Colors are disabled when `--no-color` is passed or the `NO_COLOR`
+/// environment variable is set.
+pub fn init_colors(no_color: bool) {
+    if no_color || std::env::var_os("NO_COLOR").is_some() {
+        colored::control::set_override(false);
+    }
+}
+
+/// Returns true if output should be colored.
+pub fn colors_enabled() -> bool {
+    colored::control::SHOULD_COLORIZE.should_colorize()
+}
+
+/// Returns the width available for rendering.
+///
+/// Uses the terminal size when stdout is a tty, then the `COLUMNS` environment
+/// variable, and finally [`DEFAULT_WIDTH`].
+fn terminal_width() -> u16 {
+    Table::new()
+        .width()
+        .or_else(|| std::env::var("COLUMNS").ok()?.parse().ok())
+        .unwrap_or(DEFAULT_WIDTH)
+        .clamp(40, MAX_WIDTH)
+}
+
+fn new_table() -> Table {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL_CONDENSED)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(terminal_width());
+    if colors_enabled() {
+        table.enforce_styling();
+    }
+    table
+}
+
+fn header_cell(text: &str) -> Cell {
+    Cell::new(text).add_attribute(Attribute::Bold)
+}
+
+fn colored_cell(text: impl ToString, color: Color) -> Cell {
+    let cell = Cell::new(text);
+    if colors_enabled() {
+        cell.fg(color)
+    } else {
+        cell
+    }
+}
+
+pub fn print_validation_report(report: &ValidationReport, format: &str, verbosity: Verbosity) {
+    match (format, verbosity) {
+        ("json", _) => print_json_report(report, verbosity),
+        (_, Verbosity::Quiet) => print_summary_line(report),
+        _ => print_text_report(report, verbosity),
+    }
+}
+
+/// Machine-readable name of a report status, as used in JSON output.
+pub fn status_name(status: ValidationStatus) -> &'static str {
+    match status {
+        ValidationStatus::Passed => "passed",
+        ValidationStatus::Failed => "failed",
+        ValidationStatus::Inconclusive => "inconclusive",
+    }
+}
+
+/// Prints the one-line pass/fail summary of a report.
+pub fn print_summary_line(report: &ValidationReport) {
+    let status = match report.status() {
+        ValidationStatus::Passed => format!("✓ {}", tr(Msg::ValidationPassed)).green().bold(),
+        ValidationStatus::Failed => format!("✗ {}", tr(Msg::ValidationFailed)).red().bold(),
+        ValidationStatus::Inconclusive => format!("? {}", tr(Msg::ValidationInconclusive))
+            .cyan()
+            .bold(),
+    };
+    println!(
+        "{} {}",
+        status,
+        trf(
+            Msg::SummaryCounts,
+            &[&report.errors.len(), &report.warnings.len()]
+        )
+    );
+}
+
+fn print_text_report(report: &ValidationReport, verbosity: Verbosity) {
+    println!();
+    println!("{}", summary_panel(report, verbosity));
+
+    let results = collect_results(report);
+    if !results.is_empty() {
+        println!("\n{}", tr(Msg::CheckResults).bold());
+        println!("{}", results_table(&results, verbosity));
+
+        for (i, result) in results.iter().enumerate() {
+            if !result.rows.is_empty() {
+                println!("\n{}", trf(Msg::CapturedRows, &[&(i + 1)]).bold());
+                println!("{}", captured_rows_table(result.rows));
+            }
+        }
+
+        let fields = field_breakdown(&results);
+        if !fields.is_empty() {
+            println!("\n{}", tr(Msg::FieldBreakdown).bold());
+            println!("{}", field_table(&fields));
+        }
+    }
+
+    if let Some(first) = report.segments.first() {
+        println!("\n{}", trf(Msg::SegmentsBy, &[&first.column]).bold());
+        let rows = report
+            .segments
+            .iter()
+            .map(|s| (s.value.as_str(), &s.report));
+        println!("{}", outcome_table(Msg::ColumnSegment, rows));
+    }
+
+    if !report.datasets.is_empty() {
+        println!("\n{}", tr(Msg::Datasets).bold());
+        let rows = report.datasets.iter().map(|d| (d.name.as_str(), &d.report));
+        println!("{}", outcome_table(Msg::ColumnDataset, rows));
+    }
+
+    if verbosity >= Verbosity::Detailed && !report.stats.columns.is_empty() {
+        println!("\n{}", tr(Msg::ColumnStatistics).bold());
+        println!("{}", column_stats_table(&report.stats.columns));
+    }
+}
+
+/// Severity of a single reported issue.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Severity {
+    Error,
+    Warning,
+    Info,
+    Inconclusive,
+}
+
+/// A single reported issue, classified for display.
+pub(crate) struct CheckResult<'a> {
+    pub(crate) severity: Severity,
+    pub(crate) code: &'a str,
+    pub(crate) check_kind: CheckKind,
+    pub(crate) category: Msg,
+    pub(crate) field: Option<&'a str>,
+    pub(crate) row: Option<usize>,
+    pub(crate) message: &'a str,
+    pub(crate) rows: &'a [serde_json::Map<String, serde_json::Value>],
+}
+
+pub(crate) fn collect_results(report: &ValidationReport) -> Vec<CheckResult<'_>> {
+    let errors = report.errors.iter().map(|v| (Severity::Error, v));
+    let warnings = report.warnings.iter().map(|v| (Severity::Warning, v));
+    let info = report.info.iter().map(|v| (Severity::Info, v));
+    let inconclusive = report
+        .inconclusive
+        .iter()
+        .map(|v| (Severity::Inconclusive, v));
+
+    errors
+        .chain(warnings)
+        .chain(info)
+        .chain(inconclusive)
+        .map(|(severity, violation)| CheckResult {
+            severity,
+            code: violation.code.code,
+            check_kind: violation.check_kind,
+            category: categorize(violation.check_kind),
+            field: violation.field.as_deref(),
+            row: violation.row_index,
+            message: &violation.message,
+            rows: &violation.rows,
+        })
+        .collect()
+}
+
+/// Groups check kinds into the categories shown to users.
+fn categorize(kind: CheckKind) -> Msg {
+    match kind {
+        CheckKind::Definition | CheckKind::Schema => Msg::CategorySchema,
+        CheckKind::Constraint => Msg::CategoryConstraint,
+        CheckKind::Quality | CheckKind::Freshness | CheckKind::Ml => Msg::CategoryQuality,
+        CheckKind::Custom => Msg::CategoryCustom,
+        CheckKind::Sla | CheckKind::Execution => Msg::CategoryOther,
+    }
+}
+
+/// Returns a locale-independent category name for machine-readable output.
+pub(crate) fn category_slug(category: Msg) -> &'static str {
+    match category {
+        Msg::CategorySchema => "schema",
+        Msg::CategoryConstraint => "constraint",
+        Msg::CategoryQuality => "quality",
+        Msg::CategoryCustom => "custom",
+        _ => "other",
+    }
+}
+
+fn level_color(level: ConformanceLevel) -> Color {
+    match level {
+        ConformanceLevel::Bronze => Color::DarkYellow,
+        ConformanceLevel::Silver => Color::Grey,
+        ConformanceLevel::Gold => Color::Yellow,
+    }
+}
+
+fn summary_panel(report: &ValidationReport, verbosity: Verbosity) -> Table {
+    let mut table = new_table();
+
+    let status = match report.status() {
+        ValidationStatus::Passed => {
+            colored_cell(format!("✓ {}", tr(Msg::ValidationPassed)), Color::Green)
+        }
+        ValidationStatus::Failed => {
+            colored_cell(format!("✗ {}", tr(Msg::ValidationFailed)), Color::Red)
+        }
+        ValidationStatus::Inconclusive => colored_cell(
+            format!("? {}", tr(Msg::ValidationInconclusive)),
+            Color::Cyan,
+        ),
+    }
+    .add_attribute(Attribute::Bold);
+
+    table.set_header(vec![header_cell(tr(Msg::ReportTitle)), status]);
+
+    if let Some(level) = report.conformance_level {
+        table.add_row(vec![
+            Cell::new(tr(Msg::LabelConformance)).add_attribute(Attribute::Bold),
+            colored_cell(level.to_string().to_uppercase(), level_color(level))
+                .add_attribute(Attribute::Bold),
+        ]);
+    }
+    if let Some(provenance) = &report.provenance {
+        table.add_row(vec![
+            Cell::new(tr(Msg::LabelContract)),
+            Cell::new(describe_provenance(provenance)),
+        ]);
+    }
+    if let Some(snapshot) = describe_snapshot(report) {
+        table.add_row(vec![Cell::new(tr(Msg::LabelSnapshot)), Cell::new(snapshot)]);
+    }
+
+    let error_color = if report.errors.is_empty() {
+        Color::Green
+    } else {
+        Color::Red
+    };
+    let warning_color = if report.warnings.is_empty() {
+        Color::Green
+    } else {
+        Color::Yellow
+    };
+
+    table.add_row(vec![
+        Cell::new(tr(Msg::Errors)),
+        colored_cell(report.errors.len(), error_color),
+    ]);
+    table.add_row(vec![
+        Cell::new(tr(Msg::Warnings)),
+        colored_cell(report.warnings.len(), warning_color),
+    ]);
+    if !report.info.is_empty() {
+        table.add_row(vec![
+            Cell::new(tr(Msg::Info)),
+            colored_cell(report.info.len(), Color::Blue),
+        ]);
+    }
+    if !report.inconclusive.is_empty() {
+        table.add_row(vec![
+            Cell::new(tr(Msg::Inconclusive)),
+            colored_cell(report.inconclusive.len(), Color::Cyan),
+        ]);
+    }
+    table.add_row(vec![
+        Cell::new(tr(Msg::RecordsValidated)),
+        Cell::new(report.stats.records_validated),
+    ]);
+    table.add_row(vec![
+        Cell::new(tr(Msg::FieldsChecked)),
+        Cell::new(report.stats.fields_checked),
+    ]);
+    table.add_row(vec![
+        Cell::new(tr(Msg::ConstraintsEvaluated)),
+        Cell::new(report.stats.constraints_evaluated),
+    ]);
+    if let Some(deletes) = &report.stats.deletes {
+        table.add_row(vec![
+            Cell::new(tr(Msg::DeletedRows)),
+            Cell::new(describe_deletes(deletes)),
+        ]);
+    }
+    if report.stats.truncated_violations > 0 {
+        table.add_row(vec![
+            Cell::new(tr(Msg::TruncatedViolations)),
+            colored_cell(report.stats.truncated_violations, Color::Yellow),
+        ]);
     }
 
-    if !report.errors.is_empty() {
-        println!("\n{}", "Errors:".red().bold());
-        for (i, error) in report.errors.iter().enumerate() {
-            println!("  {}. {}", i + 1, error.to_string().red());
+    if verbosity >= Verbosity::Detailed {
+        table.add_row(vec![
+            Cell::new(tr(Msg::Duration)),
+            Cell::new(format!("{} ms", report.stats.duration_ms)),
+        ]);
+        if report.stats.duration_ms > 0 {
+            let rate =
+                report.stats.records_validated as f64 * 1000.0 / report.stats.duration_ms as f64;
+            table.add_row(vec![
+                Cell::new(tr(Msg::Throughput)),
+                Cell::new(trf(Msg::RecordsPerSecond, &[&format!("{:.0}", rate)])),
+            ]);
         }
     }
 
-    if !report.warnings.is_empty() {
-        println!("\n{}", "Warnings:".yellow().bold());
-        for (i, warning) in report.warnings.iter().enumerate() {
-            println!("  {}. {}", i + 1, warning.to_string().yellow());
+    table
+}
+
+/// Formats row-level deletes as `deleted of total (percent%)`.
+pub(crate) fn describe_deletes(deletes: &DeleteStats) -> String {
+    trf(
+        Msg::DeletedRowsValue,
+        &[
+            &(deletes.position_deletes + deletes.equality_deletes),
+            &deletes.data_records,
+            &format!("{:.2}", deletes.deleted_fraction() * 100.0),
+        ],
+    )
+}
+
+/// Formats the validated snapshot, as `base → snapshot` for incremental runs.
+pub(crate) fn describe_snapshot(report: &ValidationReport) -> Option<String> {
+    let snapshot_id = report.snapshot_id?;
+    Some(match report.base_snapshot_id {
+        Some(base) => format!("{} → {}", base, snapshot_id),
+        None => snapshot_id.to_string(),
+    })
+}
+
+/// Formats provenance as `path @ commit`, flagging uncommitted changes.
+fn describe_provenance(provenance: &ContractProvenance) -> String {
+    let mut text = provenance.path.clone();
+    if let Some(commit) = provenance.short_commit() {
+        text.push_str(&format!(" @ {}", commit));
+    }
+    if provenance.dirty == Some(true) {
+        text.push_str(&format!(" ({})", tr(Msg::UncommittedChanges)));
+    }
+    text
+}
+
+fn results_table(results: &[CheckResult<'_>], verbosity: Verbosity) -> Table {
+    let mut table = new_table();
+    table.set_header(vec![
+        header_cell("#"),
+        header_cell(tr(Msg::ColumnSeverity)),
+        header_cell(tr(Msg::ColumnCode)),
+        header_cell(tr(Msg::ColumnCheck)),
+        header_cell(tr(Msg::ColumnField)),
+        header_cell(tr(Msg::ColumnMessage)),
+    ]);
+
+    for (i, result) in results.iter().enumerate() {
+        let severity = match result.severity {
+            Severity::Error => colored_cell(tr(Msg::SeverityError), Color::Red),
+            Severity::Warning => colored_cell(tr(Msg::SeverityWarning), Color::Yellow),
+            Severity::Info => colored_cell(tr(Msg::SeverityInfo), Color::Blue),
+            Severity::Inconclusive => colored_cell(tr(Msg::SeverityInconclusive), Color::Cyan),
+        };
+        table.add_row(vec![
+            Cell::new(i + 1).set_alignment(CellAlignment::Right),
+            severity,
+            Cell::new(result.code),
+            Cell::new(tr(result.category)),
+            Cell::new(result.field.unwrap_or("-")),
+            Cell::new(if verbosity >= Verbosity::Detailed {
+                result.message.to_string()
+            } else {
+                truncate(result.message, MAX_MESSAGE_LEN)
+            }),
+        ]);
+    }
+
+    table
+}
+
+fn truncate(message: &str, max_chars: usize) -> String {
+    match message.char_indices().nth(max_chars) {
+        Some((idx, _)) => format!("{}…", &message[..idx]),
+        None => message.to_string(),
+    }
+}
+
+/// Error and warning counts for a single field.
+#[derive(Default)]
+struct FieldCounts {
+    errors: usize,
+    warnings: usize,
+}
+
+fn field_breakdown<'a>(results: &[CheckResult<'a>]) -> BTreeMap<&'a str, FieldCounts> {
+    let mut fields: BTreeMap<&str, FieldCounts> = BTreeMap::new();
+    for result in results {
+        let Some(field) = result.field else {
+            continue;
+        };
+        match result.severity {
+            Severity::Error => fields.entry(field).or_default().errors += 1,
+            Severity::Warning => fields.entry(field).or_default().warnings += 1,
+            Severity::Info | Severity::Inconclusive => {}
         }
     }
+    fields
+}
+
+fn field_table(fields: &BTreeMap<&str, FieldCounts>) -> Table {
+    let mut table = new_table();
+    table.set_header(vec![
+        header_cell(tr(Msg::ColumnField)),
+        header_cell(tr(Msg::Errors)),
+        header_cell(tr(Msg::Warnings)),
+        header_cell(tr(Msg::ColumnStatus)),
+    ]);
+
+    for (field, counts) in fields {
+        let status = if counts.errors > 0 {
+            colored_cell(tr(Msg::FieldFailing), Color::Red)
+        } else {
+            colored_cell(tr(Msg::FieldHasWarnings), Color::Yellow)
+        };
+        table.add_row(vec![
+            Cell::new(field),
+            Cell::new(counts.errors).set_alignment(CellAlignment::Right),
+            Cell::new(counts.warnings).set_alignment(CellAlignment::Right),
+            status,
+        ]);
+    }
+
+    table
+}
+
+/// Tabulates the outcome of each segment or dataset, labelled in a `label`
+/// column.
+fn outcome_table<'a>(
+    label: Msg,
+    rows: impl Iterator<Item = (&'a str, &'a ValidationReport)>,
+) -> Table {
+    let mut table = new_table();
+    table.set_header(vec![
+        header_cell(tr(label)),
+        header_cell(tr(Msg::ColumnStatus)),
+        header_cell(tr(Msg::ColumnRecords)),
+        header_cell(tr(Msg::Errors)),
+        header_cell(tr(Msg::Warnings)),
+    ]);
+
+    for (name, report) in rows {
+        let status = match report.status() {
+            ValidationStatus::Passed => colored_cell(tr(Msg::SegmentPassed), Color::Green),
+            ValidationStatus::Failed => colored_cell(tr(Msg::SegmentFailed), Color::Red),
+            ValidationStatus::Inconclusive => {
+                colored_cell(tr(Msg::SegmentInconclusive), Color::Cyan)
+            }
+        };
+        table.add_row(vec![
+            Cell::new(truncate(name, 40)),
+            status,
+            Cell::new(report.stats.records_validated).set_alignment(CellAlignment::Right),
+            Cell::new(report.errors.len()).set_alignment(CellAlignment::Right),
+            Cell::new(report.warnings.len()).set_alignment(CellAlignment::Right),
+        ]);
+    }
 
-    println!("\n{}", "Summary:".bold());
-    println!("  Total errors:   {}", report.errors.len());
-    println!("  Total warnings: {}", report.warnings.len());
-    println!("{}", "═".repeat(60));
+    table
 }
 
-fn print_json_report(report: &ValidationReport) {
-    let output = json!({
+fn column_stats_table(columns: &BTreeMap<String, ColumnStats>) -> Table {
+    let mut table = new_table();
+    table.set_header(vec![
+        header_cell(tr(Msg::ColumnField)),
+        header_cell(tr(Msg::ColumnNulls)),
+        header_cell(tr(Msg::ColumnDistinct)),
+        header_cell(tr(Msg::ColumnMin)),
+        header_cell(tr(Msg::ColumnMax)),
+    ]);
+
+    for (column, stats) in columns {
+        let distinct = if stats.distinct_exact {
+            stats.distinct_count.to_string()
+        } else {
+            format!("≥{}", stats.distinct_count)
+        };
+        table.add_row(vec![
+            Cell::new(column),
+            Cell::new(stats.null_count).set_alignment(CellAlignment::Right),
+            Cell::new(distinct).set_alignment(CellAlignment::Right),
+            Cell::new(truncate(stats.min.as_deref().unwrap_or("-"), 40)),
+            Cell::new(truncate(stats.max.as_deref().unwrap_or("-"), 40)),
+        ]);
+    }
+
+    table
+}
+
+fn captured_rows_table(rows: &[serde_json::Map<String, serde_json::Value>]) -> Table {
+    let columns = captured_columns(rows);
+    let mut table = new_table();
+    table.set_header(columns.iter().map(|column| header_cell(column)));
+    for row in rows {
+        table.add_row(
+            columns
+                .iter()
+                .map(|column| Cell::new(truncate(&captured_value(row.get(*column)), 40))),
+        );
+    }
+    table
+}
+
+/// Returns the columns of rows captured by a check, in the order they first
+/// appear.
+pub(crate) fn captured_columns(rows: &[serde_json::Map<String, serde_json::Value>]) -> Vec<&str> {
+    let mut columns: Vec<&str> = Vec::new();
+    for column in rows.iter().flat_map(|row| row.keys()) {
+        if !columns.contains(&column.as_str()) {
+            columns.push(column);
+        }
+    }
+    columns
+}
+
+/// Renders a value of a captured row, strings without quotes.
+pub(crate) fn captured_value(value: Option<&serde_json::Value>) -> String {
+    match value {
+        None | Some(serde_json::Value::Null) => "NULL".to_string(),
+        Some(serde_json::Value::String(s)) => s.clone(),
+        Some(value) => value.to_string(),
+    }
+}
+
+/// Prints the metrics that differ between a reconciled source and target.
+pub fn print_differences(differences: &[Difference]) {
+    let mut table = new_table();
+    table.set_header(vec![
+        header_cell(tr(Msg::ColumnField)),
+        header_cell(tr(Msg::ColumnMetric)),
+        header_cell(tr(Msg::ColumnSource)),
+        header_cell(tr(Msg::ColumnTarget)),
+    ]);
+
+    for difference in differences {
+        table.add_row(vec![
+            Cell::new(difference.column.as_deref().unwrap_or("-")),
+            Cell::new(difference.metric),
+            Cell::new(truncate(&difference.source, 40)),
+            Cell::new(truncate(&difference.target, 40)),
+        ]);
+    }
+
+    println!("{}", table);
+}
+
+fn print_json_report(report: &ValidationReport, verbosity: Verbosity) {
+    let output = json_report(report, verbosity);
+    println!("{}", serde_json::to_string_pretty(&output).unwrap());
+}
+
+/// Builds the JSON form of a report; statistics are included from `-vv` on.
+pub(crate) fn json_report(report: &ValidationReport, verbosity: Verbosity) -> serde_json::Value {
+    let mut output = json!({
         "passed": report.passed,
+        "status": status_name(report.status()),
         "errors": report.errors.iter().map(|e| e.to_string()).collect::<Vec<_>>(),
         "warnings": report.warnings.iter().map(|w| w.to_string()).collect::<Vec<_>>(),
+        "info": report.info.iter().map(|i| i.to_string()).collect::<Vec<_>>(),
+        "inconclusive": report.inconclusive.iter().map(|i| i.to_string()).collect::<Vec<_>>(),
+        "results": collect_results(report).iter().map(|r| {
+            let mut result = json!({
+                "severity": match r.severity {
+                    Severity::Error => "error",
+                    Severity::Warning => "warning",
+                    Severity::Info => "info",
+                    Severity::Inconclusive => "inconclusive",
+                },
+                "code": r.code,
+                "category": category_slug(r.category),
+                "check_kind": r.check_kind.as_str(),
+                "field": r.field,
+                "row": r.row,
+                "message": r.message,
+            });
+            if !r.rows.is_empty() {
+                result["rows"] = json!(r.rows);
+            }
+            result
+        }).collect::<Vec<_>>(),
         "summary": {
             "error_count": report.errors.len(),
             "warning_count": report.warnings.len(),
+            "info_count": report.info.len(),
+            "inconclusive_count": report.inconclusive.len(),
         }
     });
 
-    println!("{}", serde_json::to_string_pretty(&output).unwrap());
+    if let Some(level) = report.conformance_level {
+        output["conformance_level"] = json!(level);
+    }
+    if !report.segments.is_empty() {
+        output["segments"] = report
+            .segments
+            .iter()
+            .map(|segment| {
+                let mut value = json_report(&segment.report, verbosity);
+                value["column"] = json!(segment.column);
+                value["value"] = json!(segment.value);
+                value["records_validated"] = json!(segment.report.stats.records_validated);
+                value
+            })
+            .collect();
+    }
+
+    if !report.datasets.is_empty() {
+        output["datasets"] = report
+            .datasets
+            .iter()
+            .map(|dataset| {
+                let mut value = json_report(&dataset.report, verbosity);
+                value["name"] = json!(dataset.name);
+                value["records_validated"] = json!(dataset.report.stats.records_validated);
+                value
+            })
+            .collect();
+    }
+
+    if let Some(provenance) = &report.provenance {
+        output["provenance"] = json!({
+            "path": provenance.path,
+            "commit": provenance.commit,
+            "dirty": provenance.dirty,
+        });
+    }
+    if let Some(snapshot_id) = report.snapshot_id {
+        output["snapshot_id"] = json!(snapshot_id);
+    }
+    if let Some(base_snapshot_id) = report.base_snapshot_id {
+        output["base_snapshot_id"] = json!(base_snapshot_id);
+    }
+
+    if verbosity >= Verbosity::Detailed {
+        output["stats"] = json!({
+            "records_validated": report.stats.records_validated,
+            "fields_checked": report.stats.fields_checked,
+            "constraints_evaluated": report.stats.constraints_evaluated,
+            "duration_ms": report.stats.duration_ms,
+        });
+        if report.stats.truncated_violations > 0 {
+            output["stats"]["truncated_violations"] = json!(report.stats.truncated_violations);
+        }
+
+        if let Some(deletes) = &report.stats.deletes {
+            output["stats"]["deletes"] = json!({
+                "data_records": deletes.data_records,
+                "position_deletes": deletes.position_deletes,
+                "equality_deletes": deletes.equality_deletes,
+                "deleted_fraction": deletes.deleted_fraction(),
+            });
+        }
+        if !report.stats.columns.is_empty() {
+            let columns: serde_json::Map<String, serde_json::Value> = report
+                .stats
+                .columns
+                .iter()
+                .map(|(column, stats)| {
+                    let value = json!({
+                        "null_count": stats.null_count,
+                        "min": stats.min,
+                        "max": stats.max,
+                        "distinct_count": stats.distinct_count,
+                        "distinct_exact": stats.distinct_exact,
+                    });
+                    (column.clone(), value)
+                })
+                .collect();
+            output["stats"]["columns"] = columns.into();
+        }
+    }
+
+    output
+}
+
+/// Prints how long each phase of a command took.
+pub fn print_timings(timings: &[(Msg, Duration)]) {
+    println!("\n{}", tr(Msg::Timings).bold());
+    let mut table = new_table();
+    table.set_header(vec![
+        header_cell(tr(Msg::ColumnPhase)),
+        header_cell(tr(Msg::Duration)),
+    ]);
+    for (phase, duration) in timings {
+        table.add_row(vec![
+            Cell::new(tr(*phase)),
+            Cell::new(format!("{:.1} ms", duration.as_secs_f64() * 1000.0))
+                .set_alignment(CellAlignment::Right),
+        ]);
+    }
+    println!("{}", table);
+}
+
+/// Prints the status of each contract of a directory, by file.
+///
+/// `name` is `None` for a contract that could not be parsed.
+pub fn print_contract_results(results: &[(String, Option<String>, ValidationReport)]) {
+    if is_quiet() {
+        return;
+    }
+    println!("\n{}", tr(Msg::ContractResults).bold());
+    let mut table = new_table();
+    table.set_header(vec![
+        header_cell(tr(Msg::ColumnFile)),
+        header_cell(tr(Msg::LabelContract)),
+        header_cell(tr(Msg::ColumnStatus)),
+        header_cell(tr(Msg::Errors)),
+        header_cell(tr(Msg::Warnings)),
+    ]);
+    for (file, name, report) in results {
+        let status = match report.status() {
+            ValidationStatus::Passed => colored_cell(tr(Msg::SegmentPassed), Color::Green),
+            ValidationStatus::Failed => colored_cell(tr(Msg::SegmentFailed), Color::Red),
+            ValidationStatus::Inconclusive => {
+                colored_cell(tr(Msg::SegmentInconclusive), Color::Cyan)
+            }
+        };
+        table.add_row(vec![
+            Cell::new(file),
+            Cell::new(name.as_deref().unwrap_or("-")),
+            status,
+            Cell::new(report.errors.len()).set_alignment(CellAlignment::Right),
+            Cell::new(report.warnings.len()).set_alignment(CellAlignment::Right),
+        ]);
+    }
+    println!("{}", table);
 }
 
 pub fn print_success(message: &str) {
-    println!("{} {}", "✓".green().bold(), message.green());
+    if is_quiet() {
+        return;
+    }
+    print_status(format!("{} {}", "✓".green().bold(), message.green()));
+}
+
+pub fn print_warning(message: &str) {
+    if is_quiet() {
+        return;
+    }
+    print_status(format!("{} {}", "⚠".yellow().bold(), message.yellow()));
 }
 
-#[allow(dead_code)]
 pub fn print_error(message: &str) {
     eprintln!("{} {}", "✗".red().bold(), message.red());
 }
 
 pub fn print_info(message: &str) {
-    println!("{} {}", "ℹ".blue().bold(), message);
+    if is_quiet() {
+        return;
+    }
+    print_status(format!("{} {}", "ℹ".blue().bold(), message));
 }
@@ -0,0 +1,519 @@
+//! Rendering of reports to files (`--output`).
+//!
+//! The file format is inferred from the extension: `.json` for the same JSON
+//! as `--format json`, `.html`/`.htm` for a standalone page, and `.xml` for a
+//! JUnit report that CI systems can display as test results. Files always
+//! contain the full report, including statistics.
+
+use anyhow::{Context, Result, anyhow};
+use contracts_core::{ValidationReport, ValidationStatus};
+use std::fmt::Write as _;
+use std::path::Path;
+
+use crate::i18n::{Msg, tr, trf};
+use crate::output::{self, CheckResult, Severity, Verbosity, category_slug, collect_results};
+
+/// File formats a report can be written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Json,
+    Html,
+    Junit,
+}
+
+impl ReportFormat {
+    /// Infers the format from the extension of `path`.
+    pub fn from_path(path: &Path) -> Result<Self> {
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(str::to_ascii_lowercase);
+        match extension.as_deref() {
+            Some("json") => Ok(Self::Json),
+            Some("html" | "htm") => Ok(Self::Html),
+            Some("xml") => Ok(Self::Junit),
+            _ => Err(anyhow!(
+                "Cannot infer the report format of '{}': use a .json, .html or .xml extension",
+                path.display()
+            )),
+        }
+    }
+}
+
+/// Writes `report` for the contract `name` to `path`, in the format given by its extension.
+pub fn write_report(report: &ValidationReport, name: &str, path: &Path) -> Result<()> {
+    let rendered = render_report(report, name, ReportFormat::from_path(path)?)?;
+    std::fs::write(path, rendered)
+        .with_context(|| format!("Failed to write report file: {}", path.display()))
+}
+
+/// Renders `report` for the contract `name` in `format`.
+pub fn render_report(
+    report: &ValidationReport,
+    name: &str,
+    format: ReportFormat,
+) -> Result<String> {
+    Ok(match format {
+        ReportFormat::Json => {
+            serde_json::to_string_pretty(&output::json_report(report, Verbosity::Detailed))?
+        }
+        ReportFormat::Html => render_html(report, name),
+        ReportFormat::Junit => render_junit(report, name),
+    })
+}
+
+fn render_html(report: &ValidationReport, name: &str) -> String {
+    let (status, class) = match report.status() {
+        ValidationStatus::Passed => (tr(Msg::ValidationPassed), "passed"),
+        ValidationStatus::Failed => (tr(Msg::ValidationFailed), "failed"),
+        ValidationStatus::Inconclusive => (tr(Msg::ValidationInconclusive), "inconclusive"),
+    };
+
+    let mut html = String::new();
+    let _ = write!(
+        html,
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}: {name}</title>\n\
+         <style>\n\
+         body {{ font-family: sans-serif; margin: 2em; }}\n\
+         table {{ border-collapse: collapse; margin-bottom: 1.5em; }}\n\
+         th, td {{ border: 1px solid #ccc; padding: 4px 8px; text-align: left; }}\n\
+         .passed {{ color: #1a7f37; }}\n\
+         .failed, .error {{ color: #cf222e; }}\n\
+         .warning {{ color: #9a6700; }}\n\
+         .info {{ color: #57606a; }}\n\
+         .inconclusive {{ color: #0969da; }}\n\
+         .level {{ letter-spacing: 0.1em; }}\n\
+         .level.bronze {{ color: #8c5a2b; }}\n\
+         .level.silver {{ color: #6e7781; }}\n\
+         .level.gold {{ color: #b08800; }}\n\
+         </style>\n</head>\n<body>\n<h1>{name}</h1>\n<h2 class=\"{class}\">{status}</h2>\n",
+        title = escape(tr(Msg::ReportTitle)),
+        name = escape(name),
+    );
+
+    html.push_str("<table>\n");
+    if let Some(level) = report.conformance_level {
+        let _ = writeln!(
+            html,
+            "<tr><th>{}</th><td><strong class=\"level {level}\">{}</strong></td></tr>",
+            escape(tr(Msg::LabelConformance)),
+            level.to_string().to_uppercase(),
+        );
+    }
+    if let Some(provenance) = &report.provenance {
+        let mut source = provenance.path.clone();
+        if let Some(commit) = provenance.short_commit() {
+            let _ = write!(source, " @ {}", commit);
+        }
+        summary_row(&mut html, tr(Msg::LabelContract), &source);
+    }
+    if let Some(snapshot) = output::describe_snapshot(report) {
+        summary_row(&mut html, tr(Msg::LabelSnapshot), snapshot);
+    }
+    summary_row(&mut html, tr(Msg::Errors), report.errors.len());
+    summary_row(&mut html, tr(Msg::Warnings), report.warnings.len());
+    if !report.info.is_empty() {
+        summary_row(&mut html, tr(Msg::Info), report.info.len());
+    }
+    if !report.inconclusive.is_empty() {
+        summary_row(&mut html, tr(Msg::Inconclusive), report.inconclusive.len());
+    }
+    summary_row(
+        &mut html,
+        tr(Msg::RecordsValidated),
+        report.stats.records_validated,
+    );
+    summary_row(
+        &mut html,
+        tr(Msg::FieldsChecked),
+        report.stats.fields_checked,
+    );
+    summary_row(
+        &mut html,
+        tr(Msg::ConstraintsEvaluated),
+        report.stats.constraints_evaluated,
+    );
+    if let Some(deletes) = &report.stats.deletes {
+        summary_row(
+            &mut html,
+            tr(Msg::DeletedRows),
+            output::describe_deletes(deletes),
+        );
+    }
+    summary_row(
+        &mut html,
+        tr(Msg::Duration),
+        format!("{} ms", report.stats.duration_ms),
+    );
+    html.push_str("</table>\n");
+
+    let results = collect_results(report);
+    if !results.is_empty() {
+        let _ = write!(
+            html,
+            "<h3>{}</h3>\n<table>\n<tr><th>{}</th><th>{}</th><th>{}</th><th>{}</th><th>{}</th></tr>\n",
+            escape(tr(Msg::CheckResults)),
+            escape(tr(Msg::ColumnSeverity)),
+            escape(tr(Msg::ColumnCode)),
+            escape(tr(Msg::ColumnCheck)),
+            escape(tr(Msg::ColumnField)),
+            escape(tr(Msg::ColumnMessage)),
+        );
+        for result in &results {
+            let (severity, class) = match result.severity {
+                Severity::Error => (tr(Msg::SeverityError), "error"),
+                Severity::Warning => (tr(Msg::SeverityWarning), "warning"),
+                Severity::Info => (tr(Msg::SeverityInfo), "info"),
+                Severity::Inconclusive => (tr(Msg::SeverityInconclusive), "inconclusive"),
+            };
+            let _ = writeln!(
+                html,
+                "<tr class=\"{class}\"><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                escape(severity),
+                escape(result.code),
+                escape(tr(result.category)),
+                escape(result.field.unwrap_or("-")),
+                escape(result.message),
+            );
+        }
+        html.push_str("</table>\n");
+
+        for (i, result) in results.iter().enumerate() {
+            if !result.rows.is_empty() {
+                write_captured_rows(&mut html, i + 1, result.rows);
+            }
+        }
+    }
+
+    if let Some(first) = report.segments.first() {
+        let rows = report
+            .segments
+            .iter()
+            .map(|s| (s.value.as_str(), &s.report));
+        write_outcome_table(
+            &mut html,
+            &trf(Msg::SegmentsBy, &[&first.column]),
+            Msg::ColumnSegment,
+            rows,
+        );
+    }
+
+    if !report.datasets.is_empty() {
+        let rows = report.datasets.iter().map(|d| (d.name.as_str(), &d.report));
+        write_outcome_table(&mut html, tr(Msg::Datasets), Msg::ColumnDataset, rows);
+    }
+
+    if !report.stats.columns.is_empty() {
+        let _ = write!(
+            html,
+            "<h3>{}</h3>\n<table>\n<tr><th>{}</th><th>{}</th><th>{}</th><th>{}</th><th>{}</th></tr>\n",
+            escape(tr(Msg::ColumnStatistics)),
+            escape(tr(Msg::ColumnField)),
+            escape(tr(Msg::ColumnNulls)),
+            escape(tr(Msg::ColumnDistinct)),
+            escape(tr(Msg::ColumnMin)),
+            escape(tr(Msg::ColumnMax)),
+        );
+        for (column, stats) in &report.stats.columns {
+            let distinct = if stats.distinct_exact {
+                stats.distinct_count.to_string()
+            } else {
+                format!("≥{}", stats.distinct_count)
+            };
+            let _ = writeln!(
+                html,
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                escape(column),
+                stats.null_count,
+                escape(&distinct),
+                escape(stats.min.as_deref().unwrap_or("-")),
+                escape(stats.max.as_deref().unwrap_or("-")),
+            );
+        }
+        html.push_str("</table>\n");
+    }
+
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+fn summary_row(html: &mut String, label: &str, value: impl ToString) {
+    let _ = writeln!(
+        html,
+        "<tr><th>{}</th><td>{}</td></tr>",
+        escape(label),
+        escape(&value.to_string())
+    );
+}
+
+/// Writes the outcome of each segment or dataset as a table under `title`,
+/// labelled in a `label` column.
+fn write_outcome_table<'a>(
+    html: &mut String,
+    title: &str,
+    label: Msg,
+    rows: impl Iterator<Item = (&'a str, &'a ValidationReport)>,
+) {
+    let _ = write!(
+        html,
+        "<h3>{}</h3>\n<table>\n<tr><th>{}</th><th>{}</th><th>{}</th><th>{}</th><th>{}</th></tr>\n",
+        escape(title),
+        escape(tr(label)),
+        escape(tr(Msg::ColumnStatus)),
+        escape(tr(Msg::ColumnRecords)),
+        escape(tr(Msg::Errors)),
+        escape(tr(Msg::Warnings)),
+    );
+    for (name, report) in rows {
+        let (status, class) = match report.status() {
+            ValidationStatus::Passed => (tr(Msg::SegmentPassed), "passed"),
+            ValidationStatus::Failed => (tr(Msg::SegmentFailed), "failed"),
+            ValidationStatus::Inconclusive => (tr(Msg::SegmentInconclusive), "inconclusive"),
+        };
+        let _ = writeln!(
+            html,
+            "<tr><td>{}</td><td class=\"{class}\">{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+            escape(name),
+            escape(status),
+            report.stats.records_validated,
+            report.errors.len(),
+            report.warnings.len(),
+        );
+    }
+    html.push_str("</table>\n");
+}
+
+/// Writes the rows a check captured as a table headed by the check's number.
+fn write_captured_rows(
+    html: &mut String,
+    number: usize,
+    rows: &[serde_json::Map<String, serde_json::Value>],
+) {
+    let columns = output::captured_columns(rows);
+    let _ = write!(
+        html,
+        "<h3>{}</h3>\n<table>\n<tr>",
+        escape(&trf(Msg::CapturedRows, &[&number]))
+    );
+    for column in &columns {
+        let _ = write!(html, "<th>{}</th>", escape(column));
+    }
+    html.push_str("</tr>\n");
+    for row in rows {
+        html.push_str("<tr>");
+        for column in &columns {
+            let value = output::captured_value(row.get(*column));
+            let _ = write!(html, "<td>{}</td>", escape(&value));
+        }
+        html.push_str("</tr>\n");
+    }
+    html.push_str("</table>\n");
+}
+
+/// Renders a JUnit report: one failing test case per error, one passing
+/// test case per warning or info finding, one skipped test case per
+/// inconclusive check, or a single passing case for a clean report.
+fn render_junit(report: &ValidationReport, name: &str) -> String {
+    let results = collect_results(report);
+    let tests = results.len().max(1);
+    let failures = report.errors.len();
+    let skipped = report.inconclusive.len();
+    let seconds = report.stats.duration_ms as f64 / 1000.0;
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        "<testsuites name=\"dce\" tests=\"{tests}\" failures=\"{failures}\" time=\"{seconds:.3}\">"
+    );
+    let _ = writeln!(
+        xml,
+        "  <testsuite name=\"{}\" tests=\"{tests}\" failures=\"{failures}\" errors=\"0\" \
+         skipped=\"{skipped}\" time=\"{seconds:.3}\">",
+        escape(name)
+    );
+
+    if results.is_empty() {
+        let _ = writeln!(
+            xml,
+            "    <testcase classname=\"{}\" name=\"contract\"/>",
+            escape(name)
+        );
+    }
+    for (i, result) in results.iter().enumerate() {
+        let _ = writeln!(
+            xml,
+            "    <testcase classname=\"{}.{}\" name=\"{}\">",
+            escape(name),
+            category_slug(result.category),
+            escape(&case_name(i, result))
+        );
+        match result.severity {
+            Severity::Error => {
+                let _ = writeln!(
+                    xml,
+                    "      <failure type=\"{}\" message=\"{}\"/>",
+                    result.code,
+                    escape(result.message)
+                );
+            }
+            Severity::Warning => {
+                let _ = writeln!(
+                    xml,
+                    "      <system-out>warning: {}</system-out>",
+                    escape(result.message)
+                );
+            }
+            Severity::Info => {
+                let _ = writeln!(
+                    xml,
+                    "      <system-out>info: {}</system-out>",
+                    escape(result.message)
+                );
+            }
+            Severity::Inconclusive => {
+                let _ = writeln!(
+                    xml,
+                    "      <skipped message=\"{}\"/>",
+                    escape(result.message)
+                );
+            }
+        }
+        xml.push_str("    </testcase>\n");
+    }
+
+    xml.push_str("  </testsuite>\n</testsuites>\n");
+    xml
+}
+
+/// Names a test case after its field, numbered to keep names unique.
+fn case_name(index: usize, result: &CheckResult<'_>) -> String {
+    match result.field {
+        Some(field) => format!("{} {}", index + 1, field),
+        None => format!("{} {}", index + 1, category_slug(result.category)),
+    }
+}
+
+/// Escapes text for HTML and XML content and attribute values.
+pub(crate) fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use contracts_core::{CheckKind, Violation, codes};
+
+    fn failing_report() -> ValidationReport {
+        let mut report = ValidationReport::success();
+        report.add_error(
+            Violation::new(
+                &codes::TYPE_MISMATCH,
+                CheckKind::Schema,
+                "Field 'email' type mismatch: <invalid>",
+            )
+            .with_field("email"),
+        );
+        report.add_warning(Violation::new(
+            &codes::QUALITY_CHECK_FAILED,
+            CheckKind::Quality,
+            "Quality check warning",
+        ));
+        report
+    }
+
+    #[test]
+    fn test_format_from_extension() {
+        assert_eq!(
+            ReportFormat::from_path(Path::new("out/report.JSON")).unwrap(),
+            ReportFormat::Json
+        );
+        assert_eq!(
+            ReportFormat::from_path(Path::new("report.htm")).unwrap(),
+            ReportFormat::Html
+        );
+        assert_eq!(
+            ReportFormat::from_path(Path::new("junit.xml")).unwrap(),
+            ReportFormat::Junit
+        );
+        assert!(ReportFormat::from_path(Path::new("report.txt")).is_err());
+        assert!(ReportFormat::from_path(Path::new("report")).is_err());
+    }
+
+    #[test]
+    fn test_junit_has_one_case_per_result() {
+        let xml = render_junit(&failing_report(), "users");
+
+        assert!(xml.contains("tests=\"2\" failures=\"1\""));
+        assert!(xml.contains("classname=\"users.schema\" name=\"1 email\""));
+        assert!(xml.contains(
+            "<failure type=\"DCE0103\" message=\"Field &#39;email&#39; type mismatch: &lt;invalid&gt;\"/>"
+        ));
+        assert_eq!(xml.matches("<testcase").count(), 2);
+
+        let clean = render_junit(&ValidationReport::success(), "users");
+        assert!(clean.contains("<testcase classname=\"users\" name=\"contract\"/>"));
+    }
+
+    #[test]
+    fn test_junit_skips_inconclusive_checks() {
+        let mut report = ValidationReport::success();
+        report.add_inconclusive(Violation::new(
+            &codes::INSUFFICIENT_SAMPLE,
+            CheckKind::Quality,
+            "Uniqueness check is inconclusive",
+        ));
+        let xml = render_junit(&report, "users");
+
+        assert!(xml.contains("failures=\"0\" errors=\"0\" skipped=\"1\""));
+        assert!(xml.contains("classname=\"users.quality\""));
+        assert!(xml.contains("<skipped message=\"Uniqueness check is inconclusive\"/>"));
+    }
+
+    #[test]
+    fn test_html_escapes_messages() {
+        let html = render_html(&failing_report(), "users & co");
+
+        assert!(html.contains("<h1>users &amp; co</h1>"));
+        assert!(html.contains("&lt;invalid&gt;"));
+        assert!(!html.contains("<invalid>"));
+    }
+
+    #[test]
+    fn test_html_lists_captured_rows() {
+        let mut report = failing_report();
+        let row = |id: i64, name: serde_json::Value| {
+            serde_json::Map::from_iter([
+                ("id".to_string(), serde_json::json!(id)),
+                ("name".to_string(), name),
+            ])
+        };
+        report.add_error(
+            Violation::new(
+                &codes::CUSTOM_CHECK_FAILED,
+                CheckKind::Custom,
+                "Custom check 'named' failed: returned 2 row(s) (expected empty result)",
+            )
+            .with_rows(vec![
+                row(7, serde_json::Value::Null),
+                row(9, serde_json::json!("<b>")),
+            ]),
+        );
+        let html = render_html(&report, "users");
+
+        assert!(html.contains("<h3>Rows captured by check #2</h3>"));
+        assert!(html.contains("<tr><th>id</th><th>name</th></tr>"));
+        assert!(html.contains("<tr><td>7</td><td>NULL</td></tr>"));
+        assert!(html.contains("<tr><td>9</td><td>&lt;b&gt;</td></tr>"));
+    }
+}
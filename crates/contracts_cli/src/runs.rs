@@ -0,0 +1,159 @@
+//! Progress of directory validation runs, so an interrupted run can resume.
+//!
+//! Every `dce validate <directory>` run gets an id and records each contract
+//! it validates in `.dce-runs/<id>.json` as soon as its report is in:
+//!
+//! ```json
+//! {
+//!   "id": "20260412T081502Z-4211",
+//!   "directory": "contracts",
+//!   "contracts": {
+//!     "contracts/orders.yml": {
+//!       "fingerprint": "4b1d0c…e93a",
+//!       "name": "orders",
+//!       "passed": true,
+//!       "errors": 0,
+//!       "warnings": 1,
+//!       "report": { "passed": true, "errors": [], "warnings": [...] }
+//!     }
+//!   }
+//! }
+//! ```
+//!
+//! `--resume <id>` skips the contracts recorded with the fingerprint they
+//! still have. Contracts whose validation could not run are not recorded,
+//! so resuming retries them.
+
+use anyhow::{Context, Result, anyhow};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+/// Directory the run logs are written to.
+const RUNS_DIR: &str = ".dce-runs";
+
+/// Outcome of a contract completed in a run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Completed {
+    /// Fingerprint of the contract file and options it was validated with
+    pub fingerprint: String,
+    /// Name of the contract
+    pub name: Option<String>,
+    /// Whether the contract passed
+    pub passed: bool,
+    /// Errors reported
+    pub errors: usize,
+    /// Warnings reported
+    pub warnings: usize,
+    /// Report as printed by `--format json`
+    pub report: serde_json::Value,
+}
+
+#[derive(Serialize, Deserialize)]
+struct State {
+    id: String,
+    directory: String,
+    contracts: BTreeMap<String, Completed>,
+}
+
+/// The progress log of a directory validation run.
+pub struct RunLog {
+    path: PathBuf,
+    state: State,
+}
+
+impl RunLog {
+    /// Starts a new run over `directory`.
+    pub fn start(directory: &Path) -> Self {
+        let id = format!(
+            "{}-{}",
+            chrono::Utc::now().format("%Y%m%dT%H%M%SZ"),
+            std::process::id()
+        );
+        Self {
+            path: Self::path(&id),
+            state: State {
+                id,
+                directory: directory.display().to_string(),
+                contracts: BTreeMap::new(),
+            },
+        }
+    }
+
+    /// Loads run `id` to resume it over `directory`.
+    pub fn resume(id: &str, directory: &Path) -> Result<Self> {
+        let path = Self::path(id);
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read run {}: {}", id, path.display()))?;
+        let state: State = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse run log: {}", path.display()))?;
+        let directory = directory.display().to_string();
+        if state.directory != directory {
+            return Err(anyhow!(
+                "Run {} validated {}, not {}",
+                id,
+                state.directory,
+                directory
+            ));
+        }
+        Ok(Self { path, state })
+    }
+
+    fn path(id: &str) -> PathBuf {
+        Path::new(RUNS_DIR).join(format!("{}.json", id))
+    }
+
+    /// Returns the id of the run.
+    pub fn id(&self) -> &str {
+        &self.state.id
+    }
+
+    /// Returns the outcome `file` completed with, if its fingerprint is
+    /// still `fingerprint`.
+    pub fn completed(&self, file: &Path, fingerprint: &str) -> Option<&Completed> {
+        self.state
+            .contracts
+            .get(&file.display().to_string())
+            .filter(|completed| completed.fingerprint == fingerprint)
+    }
+
+    /// Records that `file` completed and saves the log.
+    pub fn record(&mut self, file: &Path, completed: Completed) -> Result<()> {
+        self.state
+            .contracts
+            .insert(file.display().to_string(), completed);
+        std::fs::create_dir_all(RUNS_DIR)
+            .with_context(|| format!("Failed to create {}", RUNS_DIR))?;
+        // Written aside and renamed, so an interrupted write keeps the last log
+        let partial = self.path.with_extension("json.partial");
+        std::fs::write(&partial, serde_json::to_string_pretty(&self.state)?)
+            .with_context(|| format!("Failed to write run log: {}", partial.display()))?;
+        std::fs::rename(&partial, &self.path)
+            .with_context(|| format!("Failed to write run log: {}", self.path.display()))
+    }
+}
+
+/// Fingerprints the inputs of a contract validation: the `dce` version, the
+/// contract file and the `options` it is validated with, as a SHA-256 hex
+/// digest.
+pub fn fingerprint(file: &Path, options: &serde_json::Value) -> Result<String> {
+    let content = std::fs::read(file)
+        .with_context(|| format!("Failed to read contract file: {}", file.display()))?;
+    let mut hasher = Sha256::new();
+    // Each input is length-prefixed, so no two sets of inputs hash alike
+    for input in [
+        env!("CARGO_PKG_VERSION").as_bytes(),
+        &content,
+        options.to_string().as_bytes(),
+    ] {
+        hasher.update((input.len() as u64).to_le_bytes());
+        hasher.update(input);
+    }
+    let mut hex = String::with_capacity(64);
+    for byte in hasher.finalize() {
+        let _ = write!(hex, "{:02x}", byte);
+    }
+    Ok(hex)
+}
@@ -0,0 +1,459 @@
+//! Durable storage of validation reports, configured in `dce.toml`.
+//!
+//! Every `[[sinks]]` entry receives the report of each validation, so runs
+//! leave a history outside the terminal:
+//!
+//! ```toml
+//! [[sinks]]
+//! type = "s3"
+//! path = "s3://lake/dce-reports/{contract}/{date}/{timestamp}.json"
+//!
+//! [[sinks]]
+//! type = "postgres"
+//! url = "postgres://dce:${env:PGPASSWORD}@db.example.com/quality?sslmode=require"
+//!
+//! [[sinks]]
+//! type = "postgrest"
+//! url = "https://db.example.com/validation_reports"
+//! headers = { Authorization = "Bearer ${env:DB_TOKEN}" }
+//! ```
+//!
+//! File, S3 and GCS sinks write the report in the format of the path's
+//! extension, as `--output` does. Postgres and PostgREST sinks insert one row
+//! per report with the contract name and version, the status, the error and
+//! warning counts, the validation time and the JSON report under `report`.
+//! Postgres sinks need the `postgres` feature and create their table when it
+//! is missing.
+//!
+//! A sink that fails is logged and never fails a validation.
+
+use anyhow::{Context, Result, anyhow, bail};
+use chrono::{DateTime, Utc};
+use contracts_core::{Contract, ValidationReport};
+use contracts_files::FileLocation;
+use contracts_iceberg::resolve_secret_refs;
+use contracts_parser::{CONFIG_NAME, SinkConfig, WorkspaceConfig};
+use futures::future::BoxFuture;
+use object_store::PutPayload;
+use object_store::path::Path as ObjectPath;
+use reqwest::Client;
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::path::Path;
+use tracing::{info, warn};
+
+use crate::output::{self, Verbosity};
+use crate::report::{ReportFormat, render_report};
+
+/// Upload endpoint of the Google Cloud Storage JSON API.
+const GCS_UPLOAD_URL: &str = "https://storage.googleapis.com/upload/storage/v1";
+
+/// A durable location validation reports are written to.
+pub trait ReportSink: Send + Sync {
+    /// Writes the report of `contract`, validated at `at`, and returns where
+    /// it was written.
+    fn store<'a>(
+        &'a self,
+        contract: &'a Contract,
+        report: &'a ValidationReport,
+        at: DateTime<Utc>,
+    ) -> BoxFuture<'a, Result<String>>;
+}
+
+/// The report sinks of a run.
+pub struct Sinks {
+    sinks: Vec<Box<dyn ReportSink>>,
+}
+
+impl Sinks {
+    /// Sets up the sinks configured in the working directory's `dce.toml`.
+    pub fn from_workspace() -> Result<Self> {
+        let config = WorkspaceConfig::load(Path::new(CONFIG_NAME))?;
+        Self::new(&config)
+    }
+
+    /// Sets up the sinks configured in `config`, resolving the secret
+    /// references of their credentials.
+    pub fn new(config: &WorkspaceConfig) -> Result<Self> {
+        let client = Client::new();
+        let sinks = config
+            .sinks
+            .iter()
+            .map(|sink| -> Result<Option<Box<dyn ReportSink>>> {
+                Ok(Some(match sink {
+                    SinkConfig::File { path } => Box::new(ObjectSink::new(path)?),
+                    SinkConfig::S3 { path } => {
+                        if !path.starts_with("s3://") {
+                            bail!("S3 sink path '{}' must start with s3://", path);
+                        }
+                        Box::new(ObjectSink::new(path)?)
+                    }
+                    SinkConfig::Gcs { path, token } => Box::new(GcsSink::new(
+                        client.clone(),
+                        path,
+                        resolve_secret_refs(token)?,
+                    )?),
+                    SinkConfig::Postgres { url, table } => match postgres_sink(url, table)? {
+                        Some(sink) => sink,
+                        None => return Ok(None),
+                    },
+                    SinkConfig::Postgrest { url, headers } => Box::new(PostgrestSink {
+                        client: client.clone(),
+                        url: url.clone(),
+                        headers: headers
+                            .iter()
+                            .map(|(name, value)| Ok((name.clone(), resolve_secret_refs(value)?)))
+                            .collect::<Result<_>>()?,
+                    }),
+                }))
+            })
+            .filter_map(Result::transpose)
+            .collect::<Result<_>>()?;
+        Ok(Self { sinks })
+    }
+
+    /// Writes the report of one validation to every sink, warning about the
+    /// ones that fail.
+    pub async fn store(&self, contract: &Contract, report: &ValidationReport) {
+        let at = Utc::now();
+        for sink in &self.sinks {
+            match sink.store(contract, report, at).await {
+                Ok(location) => info!("Stored the report of {} at {}", contract.name, location),
+                Err(e) => warn!("Failed to store the report of {}: {:#}", contract.name, e),
+            }
+        }
+    }
+}
+
+/// Sets up a Postgres sink, resolving the secret references of its
+/// connection string.
+#[cfg(feature = "postgres")]
+fn postgres_sink(url: &str, table: &str) -> Result<Option<Box<dyn ReportSink>>> {
+    Ok(Some(Box::new(postgres::PostgresSink::new(
+        &resolve_secret_refs(url)?,
+        table,
+    )?)))
+}
+
+/// Without the `postgres` feature, Postgres sinks are skipped with a warning.
+#[cfg(not(feature = "postgres"))]
+fn postgres_sink(_url: &str, _table: &str) -> Result<Option<Box<dyn ReportSink>>> {
+    warn!(
+        "{} configures a Postgres sink, but dce was built without the postgres feature",
+        CONFIG_NAME
+    );
+    Ok(None)
+}
+
+/// Quotes a table name, optionally schema-qualified, as a SQL identifier.
+#[cfg_attr(not(feature = "postgres"), allow(dead_code))]
+fn quote_table(table: &str) -> Result<String> {
+    let parts: Vec<&str> = table.split('.').collect();
+    if parts.len() > 2 || parts.iter().any(|part| part.is_empty()) {
+        bail!(
+            "Postgres sink table '{}' is not of the form [schema.]table",
+            table
+        );
+    }
+    Ok(parts
+        .iter()
+        .map(|part| format!("\"{}\"", part.replace('"', "\"\"")))
+        .collect::<Vec<_>>()
+        .join("."))
+}
+
+/// Replaces the placeholders of a sink path template.
+fn render_path(template: &str, contract: &Contract, at: DateTime<Utc>) -> String {
+    template
+        .replace("{contract}", &contract.name)
+        .replace("{version}", &contract.version)
+        .replace("{date}", &at.format("%Y-%m-%d").to_string())
+        .replace("{timestamp}", &at.format("%Y%m%dT%H%M%SZ").to_string())
+}
+
+/// Writes reports to local files or S3 objects.
+struct ObjectSink {
+    template: String,
+    format: ReportFormat,
+}
+
+impl ObjectSink {
+    fn new(template: &str) -> Result<Self> {
+        Ok(Self {
+            template: template.to_string(),
+            format: ReportFormat::from_path(Path::new(template))?,
+        })
+    }
+}
+
+impl ReportSink for ObjectSink {
+    fn store<'a>(
+        &'a self,
+        contract: &'a Contract,
+        report: &'a ValidationReport,
+        at: DateTime<Utc>,
+    ) -> BoxFuture<'a, Result<String>> {
+        Box::pin(async move {
+            let location = FileLocation::parse(&render_path(&self.template, contract, at))?;
+            let body = render_report(report, &contract.name, self.format)?;
+            location
+                .store()
+                .put(&ObjectPath::parse(location.path())?, PutPayload::from(body))
+                .await
+                .with_context(|| format!("Failed to write {}", location.url()))?;
+            Ok(location.url())
+        })
+    }
+}
+
+/// Writes reports to Google Cloud Storage objects.
+struct GcsSink {
+    client: Client,
+    bucket: String,
+    template: String,
+    format: ReportFormat,
+    token: String,
+}
+
+impl GcsSink {
+    fn new(client: Client, path: &str, token: String) -> Result<Self> {
+        let (bucket, template) = path
+            .strip_prefix("gs://")
+            .and_then(|rest| rest.split_once('/'))
+            .filter(|(bucket, name)| !bucket.is_empty() && !name.is_empty())
+            .ok_or_else(|| {
+                anyhow!(
+                    "GCS sink path '{}' is not of the form gs://bucket/name",
+                    path
+                )
+            })?;
+        Ok(Self {
+            client,
+            bucket: bucket.to_string(),
+            template: template.to_string(),
+            format: ReportFormat::from_path(Path::new(template))?,
+            token,
+        })
+    }
+}
+
+impl ReportSink for GcsSink {
+    fn store<'a>(
+        &'a self,
+        contract: &'a Contract,
+        report: &'a ValidationReport,
+        at: DateTime<Utc>,
+    ) -> BoxFuture<'a, Result<String>> {
+        Box::pin(async move {
+            let name = render_path(&self.template, contract, at);
+            let content_type = match self.format {
+                ReportFormat::Json => "application/json",
+                ReportFormat::Html => "text/html",
+                ReportFormat::Junit => "application/xml",
+            };
+            let response = self
+                .client
+                .post(format!("{}/b/{}/o", GCS_UPLOAD_URL, self.bucket))
+                .query(&[("uploadType", "media"), ("name", name.as_str())])
+                .bearer_auth(&self.token)
+                .header(reqwest::header::CONTENT_TYPE, content_type)
+                .body(render_report(report, &contract.name, self.format)?)
+                .send()
+                .await
+                .context("Failed to reach Google Cloud Storage")?;
+            let status = response.status();
+            if !status.is_success() {
+                bail!("{} {}", status, response.text().await?.trim());
+            }
+            Ok(format!("gs://{}/{}", self.bucket, name))
+        })
+    }
+}
+
+/// Inserts one row per report into a table behind a PostgREST endpoint.
+struct PostgrestSink {
+    client: Client,
+    url: String,
+    headers: BTreeMap<String, String>,
+}
+
+impl ReportSink for PostgrestSink {
+    fn store<'a>(
+        &'a self,
+        contract: &'a Contract,
+        report: &'a ValidationReport,
+        at: DateTime<Utc>,
+    ) -> BoxFuture<'a, Result<String>> {
+        Box::pin(async move {
+            let row = json!({
+                "contract": contract.name,
+                "version": contract.version,
+                "status": output::status_name(report.status()),
+                "errors": report.errors.len(),
+                "warnings": report.warnings.len(),
+                "validated_at": at.to_rfc3339(),
+                "report": output::json_report(report, Verbosity::Detailed),
+            });
+            let mut request = self
+                .client
+                .post(&self.url)
+                .header("Prefer", "return=minimal")
+                .json(&row);
+            for (name, value) in &self.headers {
+                request = request.header(name, value);
+            }
+            let response = request.send().await.context("Failed to reach PostgREST")?;
+            let status = response.status();
+            if !status.is_success() {
+                bail!("{} {}", status, response.text().await?.trim());
+            }
+            Ok(self.url.clone())
+        })
+    }
+}
+
+/// Inserts one row per report into a PostgreSQL table.
+#[cfg(feature = "postgres")]
+mod postgres {
+    use super::*;
+    use std::sync::Arc;
+    use tokio::sync::OnceCell;
+    use tokio_postgres_rustls::MakeRustlsConnect;
+
+    pub struct PostgresSink {
+        config: tokio_postgres::Config,
+        table: String,
+        client: OnceCell<tokio_postgres::Client>,
+    }
+
+    impl PostgresSink {
+        pub fn new(url: &str, table: &str) -> Result<Self> {
+            Ok(Self {
+                config: url
+                    .parse()
+                    .context("Invalid Postgres sink connection string")?,
+                table: quote_table(table)?,
+                client: OnceCell::new(),
+            })
+        }
+
+        /// Connects on first use, creating the table when it is missing.
+        async fn client(&self) -> Result<&tokio_postgres::Client> {
+            self.client
+                .get_or_try_init(|| async {
+                    let roots = rustls::RootCertStore {
+                        roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+                    };
+                    let tls = rustls::ClientConfig::builder_with_provider(Arc::new(
+                        rustls::crypto::ring::default_provider(),
+                    ))
+                    .with_safe_default_protocol_versions()?
+                    .with_root_certificates(roots)
+                    .with_no_client_auth();
+                    let (client, connection) = self
+                        .config
+                        .connect(MakeRustlsConnect::new(tls))
+                        .await
+                        .context("Failed to connect to Postgres")?;
+                    tokio::spawn(async move {
+                        if let Err(e) = connection.await {
+                            warn!("Postgres sink connection failed: {}", e);
+                        }
+                    });
+                    client
+                        .batch_execute(&format!(
+                            "CREATE TABLE IF NOT EXISTS {} (
+                                id BIGSERIAL PRIMARY KEY,
+                                contract TEXT NOT NULL,
+                                version TEXT NOT NULL,
+                                status TEXT NOT NULL,
+                                errors BIGINT NOT NULL,
+                                warnings BIGINT NOT NULL,
+                                validated_at TIMESTAMPTZ NOT NULL,
+                                report JSONB NOT NULL
+                            )",
+                            self.table
+                        ))
+                        .await
+                        .with_context(|| format!("Failed to create table {}", self.table))?;
+                    Ok(client)
+                })
+                .await
+        }
+    }
+
+    impl ReportSink for PostgresSink {
+        fn store<'a>(
+            &'a self,
+            contract: &'a Contract,
+            report: &'a ValidationReport,
+            at: DateTime<Utc>,
+        ) -> BoxFuture<'a, Result<String>> {
+            Box::pin(async move {
+                let client = self.client().await?;
+                // Timestamps and JSON are sent as text and cast by the server
+                client
+                    .execute(
+                        &format!(
+                            "INSERT INTO {} (contract, version, status, errors, warnings, \
+                             validated_at, report) \
+                             VALUES ($1, $2, $3, $4, $5, $6::text::timestamptz, $7::text::jsonb)",
+                            self.table
+                        ),
+                        &[
+                            &contract.name,
+                            &contract.version,
+                            &output::status_name(report.status()),
+                            &(report.errors.len() as i64),
+                            &(report.warnings.len() as i64),
+                            &at.to_rfc3339(),
+                            &output::json_report(report, Verbosity::Detailed).to_string(),
+                        ],
+                    )
+                    .await
+                    .with_context(|| format!("Failed to insert into {}", self.table))?;
+                Ok(format!("Postgres table {}", self.table))
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use contracts_core::{ContractBuilder, DataFormat};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_render_path() {
+        let contract = ContractBuilder::new("orders", "sales")
+            .version("2.1.0")
+            .location("s3://lake/orders")
+            .format(DataFormat::Iceberg)
+            .build();
+        let at = Utc.with_ymd_and_hms(2024, 5, 31, 14, 25, 0).unwrap();
+
+        assert_eq!(
+            render_path(
+                "s3://lake/{contract}/{version}/{date}/{timestamp}.json",
+                &contract,
+                at
+            ),
+            "s3://lake/orders/2.1.0/2024-05-31/20240531T142500Z.json"
+        );
+        assert!(GcsSink::new(Client::new(), "gs://bucket", String::new()).is_err());
+        assert!(ObjectSink::new("reports/{contract}.txt").is_err());
+    }
+
+    #[test]
+    fn test_quote_table() {
+        assert_eq!(quote_table("reports").unwrap(), "\"reports\"");
+        assert_eq!(
+            quote_table("dq.validation\"; --").unwrap(),
+            "\"dq\".\"validation\"\"; --\""
+        );
+        assert!(quote_table("a.b.c").is_err());
+        assert!(quote_table("dq.").is_err());
+    }
+}
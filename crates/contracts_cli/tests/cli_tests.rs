@@ -89,6 +89,624 @@ fn test_check_contract_schema_details() {
         .stdout(predicate::str::contains("2")); // 2 fields
 }
 
+#[test]
+fn test_check_applies_workspace_description_rules() {
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join("orders.yml"),
+        "version: \"1.0.0\"\nname: orders\nowner: sales\ndescription: Orders placed in the web shop\nschema:\n  format: iceberg\n  location: s3://lake/orders\n  fields:\n    - name: status\n      type: string\n      nullable: false\n      description: TBD\n    - name: amount\n      type: float64\n      nullable: false\n      description: Amount paid for the order\n",
+    )
+    .unwrap();
+
+    // Without a dce.toml the rules are off
+    dce()
+        .current_dir(dir.path())
+        .arg("check")
+        .arg("orders.yml")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("placeholder").not());
+
+    fs::write(dir.path().join("dce.toml"), "[lint.descriptions]\n").unwrap();
+    dce()
+        .current_dir(dir.path())
+        .arg("check")
+        .arg("orders.yml")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "[DCE0013] schema.fields.status.description: Description 'TBD' of 'status' is a placeholder",
+        ))
+        .stdout(predicate::str::contains(
+            "[DCE0013] schema.fields.amount.description: Description of numeric field 'amount' does not state its unit",
+        ));
+
+    fs::write(
+        dir.path().join("dce.toml"),
+        "[lint.descriptions]\nplaceholders = []\nrequire_units = false\n",
+    )
+    .unwrap();
+    dce()
+        .current_dir(dir.path())
+        .arg("check")
+        .arg("orders.yml")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("short"))
+        .stdout(predicate::str::contains("unit").not());
+}
+
+// ============================================================================
+// lint command tests
+// ============================================================================
+
+#[test]
+fn test_lint_checks_tags_against_the_taxonomy() {
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join("dce.toml"),
+        "[lint]\ntag_taxonomy = \"tags.yml\"\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("tags.yml"),
+        "tags:\n  pii:\n    children:\n      direct: {}\n      indirect: {}\n  personal:\n    deprecated: true\n    replacement: pii.direct\n",
+    )
+    .unwrap();
+    let contract = |tags: &str| {
+        format!(
+            "version: \"1.0.0\"\nname: users\nowner: team\ndescription: Registered users\nschema:\n  format: iceberg\n  location: s3://lake/users\n  fields:\n    - name: email\n      type: string\n      nullable: false\n      tags: [{}]\n",
+            tags
+        )
+    };
+    fs::write(dir.path().join("users.yml"), contract("pii.direct")).unwrap();
+
+    dce()
+        .current_dir(dir.path())
+        .arg("lint")
+        .arg(".")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "1 contract(s) linted: 0 error(s), 0 warning(s)",
+        ));
+
+    fs::write(
+        dir.path().join("users.yml"),
+        contract("pii.drect, personal"),
+    )
+    .unwrap();
+    let output = dce()
+        .current_dir(dir.path())
+        .arg("lint")
+        .arg(".")
+        .arg("--format")
+        .arg("json")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["errors"], 1);
+    assert_eq!(json["warnings"], 1);
+    let findings = &json["contracts"][0]["findings"];
+    assert_eq!(findings[0]["rule"], "unknown-tag");
+    assert_eq!(findings[0]["path"], "schema.fields.email.tags");
+    assert_eq!(findings[1]["rule"], "deprecated-tag");
+}
+
+#[test]
+fn test_lint_checks_declared_conformance_level() {
+    let dir = TempDir::new().unwrap();
+    let original = fs::read_to_string(fixture_path("simple_contract.yml")).unwrap();
+    fs::write(
+        dir.path().join("simple.yml"),
+        original.replace(
+            "description: Simple contract for testing\n",
+            "description: Simple contract for testing\nconformance_level: silver\n",
+        ),
+    )
+    .unwrap();
+
+    let output = dce()
+        .current_dir(dir.path())
+        .args(["lint", "simple.yml", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let finding = &json["contracts"][0]["findings"][0];
+    assert_eq!(finding["rule"], "conformance-level");
+    assert_eq!(finding["path"], "quality_checks.completeness");
+
+    let output = dce()
+        .current_dir(dir.path())
+        .args([
+            "validate",
+            "--schema-only",
+            "--format",
+            "json",
+            "simple.yml",
+        ])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let report: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(report["conformance_level"], "silver");
+}
+
+#[test]
+fn test_owners_cross_checks_codeowners_and_org_directory() {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir_all(dir.path().join(".github")).unwrap();
+    fs::create_dir_all(dir.path().join("contracts")).unwrap();
+    fs::write(
+        dir.path().join(".github/CODEOWNERS"),
+        "/contracts/ @acme/payments\n/contracts/users.yml @acme/identity\n",
+    )
+    .unwrap();
+    let contract = |name: &str, owner: &str| {
+        format!(
+            "version: \"1.0.0\"\nname: {}\nowner: {}\nschema:\n  format: iceberg\n  location: s3://lake/{}\n  fields: []\n",
+            name, owner, name
+        )
+    };
+    fs::write(
+        dir.path().join("contracts/orders.yml"),
+        contract("orders", "payments"),
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("contracts/users.yml"),
+        contract("users", "identity"),
+    )
+    .unwrap();
+
+    dce()
+        .env("NO_COLOR", "1")
+        .current_dir(dir.path())
+        .args(["owners", "contracts"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "2 contract owner(s) checked: 0 error(s), 0 warning(s)",
+        ));
+
+    // After a reorg, identity is merged into accounts and left empty
+    fs::write(
+        dir.path().join("org.json"),
+        r#"{ "payments": { "members": ["alice"] }, "identity": { "members": [] } }"#,
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("contracts/users.yml"),
+        contract("users", "accounts"),
+    )
+    .unwrap();
+    let output = dce()
+        .current_dir(dir.path())
+        .args(["owners", "contracts", "--org-directory", "org.json"])
+        .args(["--format", "json"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["contracts"][0]["findings"], serde_json::json!([]));
+    let rules: Vec<&str> = json["contracts"][1]["findings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|f| f["rule"].as_str().unwrap())
+        .collect();
+    assert_eq!(rules, vec!["unknown-owner", "codeowners-mismatch"]);
+    assert_eq!(json["codeowners"]["findings"][0]["rule"], "stale-codeowner");
+    assert_eq!(
+        json["codeowners"]["findings"][0]["path"],
+        "line 2 (/contracts/users.yml)"
+    );
+}
+
+#[test]
+fn test_owners_requires_a_source() {
+    let dir = TempDir::new().unwrap();
+    let contract = fs::canonicalize(fixture_path("simple_contract.yml")).unwrap();
+
+    dce()
+        .current_dir(dir.path())
+        .arg("owners")
+        .arg(contract)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No CODEOWNERS file found"));
+}
+
+// ============================================================================
+// diff command tests
+// ============================================================================
+
+#[test]
+fn test_diff_identical_contracts() {
+    dce()
+        .arg("diff")
+        .arg(fixture_path("simple_contract.yml"))
+        .arg(fixture_path("simple_contract.yml"))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No schema changes"));
+}
+
+#[test]
+fn test_diff_reports_breaking_changes() {
+    let dir = TempDir::new().unwrap();
+    let new = dir.path().join("simple_v2.yml");
+    let original = fs::read_to_string(fixture_path("simple_contract.yml")).unwrap();
+    fs::write(
+        &new,
+        original.replace(
+            "type: int\n      nullable: true",
+            "type: int\n      nullable: false",
+        ) + "    - name: note\n      type: string\n      nullable: true\n",
+    )
+    .unwrap();
+
+    dce()
+        .arg("diff")
+        .arg(fixture_path("simple_contract.yml"))
+        .arg(&new)
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "field 'value' is no longer nullable",
+        ))
+        .stdout(predicate::str::contains("nullable field 'note' added"))
+        .stdout(predicate::str::contains(
+            "does not make the major increment from 1.0.0",
+        ));
+
+    let output = dce()
+        .arg("diff")
+        .arg("--format")
+        .arg("json")
+        .arg(fixture_path("simple_contract.yml"))
+        .arg(&new)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let diff: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(diff["breaking"], true);
+    assert_eq!(diff["required_bump"], "major");
+    assert_eq!(diff["version_bumped"], false);
+    let kinds: Vec<(&str, bool)> = diff["changes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| {
+            (
+                c["kind"].as_str().unwrap(),
+                c["breaking"].as_bool().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![("nullability_tightened", true), ("field_added", false)]
+    );
+
+    // Going back only relaxes the contract, apart from dropping the field
+    dce()
+        .arg("diff")
+        .arg(&new)
+        .arg(fixture_path("simple_contract.yml"))
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("field 'note' removed"));
+}
+
+// ============================================================================
+// publish command tests
+// ============================================================================
+
+#[test]
+fn test_publish_dry_run_renders_pages() {
+    let output = dce()
+        .arg("publish")
+        .arg(fixture_path("simple_contract.yml"))
+        .arg("--target")
+        .arg("confluence")
+        .arg("--dry-run")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let pages: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(pages[0]["title"], "simple_test");
+    let body = pages[0]["body"].as_str().unwrap();
+    assert!(
+        body.starts_with("<p>Simple contract for testing</p>"),
+        "{body}"
+    );
+    assert!(
+        body.contains("<tr><td>value</td><td>int32</td><td>yes</td>"),
+        "{body}"
+    );
+
+    let output = dce()
+        .arg("publish")
+        .arg(fixture_path("simple_contract.yml"))
+        .arg("--target")
+        .arg("notion")
+        .arg("--dry-run")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let pages: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(pages[0]["body"][0]["type"], "paragraph");
+}
+
+#[test]
+fn test_publish_requires_a_configured_target() {
+    let dir = TempDir::new().unwrap();
+    let contract = fs::canonicalize(fixture_path("simple_contract.yml")).unwrap();
+    dce()
+        .current_dir(dir.path())
+        .arg("publish")
+        .arg(&contract)
+        .arg("--target")
+        .arg("notion")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "No [publish.notion] section in dce.toml",
+        ));
+}
+
+// ============================================================================
+// bundle command tests
+// ============================================================================
+
+#[test]
+fn test_bundle_writes_oci_layout_archive() {
+    let dir = TempDir::new().unwrap();
+    let contract = fs::canonicalize(fixture_path("simple_contract.yml")).unwrap();
+    fs::write(dir.path().join("tags.yml"), "tags: [pii]\n").unwrap();
+
+    let output = dce()
+        .current_dir(dir.path())
+        .arg("bundle")
+        .arg(&contract)
+        .arg("--include")
+        .arg("tags.yml")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Bundled simple_test"), "{stdout}");
+    assert!(stdout.contains("Digest: sha256:"), "{stdout}");
+
+    let archive = fs::read_dir(dir.path())
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .find(|path| path.extension().is_some_and(|e| e == "tar"))
+        .unwrap();
+    let content = fs::read(&archive).unwrap();
+    assert_eq!(&content[..10], b"oci-layout");
+    assert_eq!(content.len() % 512, 0);
+
+    // Bundling the same files again gives the same archive
+    let again = dir.path().join("again.tar");
+    dce()
+        .current_dir(dir.path())
+        .arg("bundle")
+        .arg(&contract)
+        .arg("--include")
+        .arg("tags.yml")
+        .arg("--output")
+        .arg(&again)
+        .assert()
+        .success();
+    assert_eq!(fs::read(&again).unwrap(), content);
+}
+
+#[test]
+fn test_bundle_rejects_definition_errors() {
+    let dir = TempDir::new().unwrap();
+    let contract = dir.path().join("orders.yml");
+    fs::write(
+        &contract,
+        "version: \"1.0.0\"\nname: orders\nowner: team\nschema:\n  format: iceberg\n  location: s3://lake/orders\n  fields:\n    - name: id\n      type: int64\n      nullable: false\n      constraints:\n        - type: custom\n          definition: \"value >\"\n",
+    )
+    .unwrap();
+
+    dce()
+        .current_dir(dir.path())
+        .arg("bundle")
+        .arg(&contract)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("orders has 1 definition error(s)"));
+    assert!(!dir.path().join("orders-1.0.0.tar").exists());
+}
+
+/// Manifests of the in-memory OCI registry, by tag.
+type OciManifests = std::sync::Arc<std::sync::Mutex<std::collections::HashMap<String, Vec<u8>>>>;
+
+/// Serves an in-memory OCI registry that asks for a bearer token obtained
+/// with basic credentials, and returns its address and its manifests.
+fn oci_registry() -> (String, OciManifests) {
+    use std::collections::HashMap;
+    use std::io::{Read, Write};
+    use std::sync::{Arc, Mutex};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    let manifests = Arc::new(Mutex::new(HashMap::new()));
+    let tags = Arc::clone(&manifests);
+    let realm = format!("http://{}/token", address);
+    std::thread::spawn(move || {
+        let mut blobs: HashMap<String, Vec<u8>> = HashMap::new();
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0u8; 4096];
+            let header_end = loop {
+                let n = stream.read(&mut buffer).unwrap();
+                request.extend_from_slice(&buffer[..n]);
+                if let Some(end) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                    break end + 4;
+                }
+            };
+            let headers = String::from_utf8_lossy(&request[..header_end]).to_string();
+            let lower = headers.to_lowercase();
+            let length: usize = lower
+                .lines()
+                .find_map(|line| line.strip_prefix("content-length:"))
+                .map_or(0, |value| value.trim().parse().unwrap());
+            while request.len() < header_end + length {
+                let n = stream.read(&mut buffer).unwrap();
+                request.extend_from_slice(&buffer[..n]);
+            }
+            let body = request[header_end..].to_vec();
+            let mut words = headers.split_whitespace();
+            let method = words.next().unwrap().to_string();
+            let target = words.next().unwrap().replace("%3A", ":");
+            let (path, query) = target.split_once('?').unwrap_or((&target, ""));
+
+            let (status, extra, content) = if path == "/token" {
+                assert!(lower.contains("authorization: basic"));
+                ("200 OK", String::new(), br#"{"token":"secret"}"#.to_vec())
+            } else if !lower.contains("authorization: bearer secret") {
+                let challenge = format!(
+                    "WWW-Authenticate: Bearer realm=\"{}\",service=\"test\",scope=\"repository:contracts/orders:pull,push\"\r\n",
+                    realm
+                );
+                ("401 Unauthorized", challenge, Vec::new())
+            } else if let Some(tag) = path.strip_prefix("/v2/contracts/orders/manifests/") {
+                if method == "PUT" {
+                    tags.lock().unwrap().insert(tag.to_string(), body);
+                    ("201 Created", String::new(), Vec::new())
+                } else {
+                    match tags.lock().unwrap().get(tag) {
+                        Some(manifest) => ("200 OK", String::new(), manifest.clone()),
+                        None => ("404 Not Found", String::new(), Vec::new()),
+                    }
+                }
+            } else if path == "/v2/contracts/orders/blobs/uploads/" {
+                let location = "Location: /v2/contracts/orders/blobs/uploads/1\r\n".to_string();
+                ("202 Accepted", location, Vec::new())
+            } else if path.starts_with("/v2/contracts/orders/blobs/uploads/") {
+                let digest = query.strip_prefix("digest=").unwrap().to_string();
+                blobs.insert(digest, body);
+                ("201 Created", String::new(), Vec::new())
+            } else if let Some(digest) = path.strip_prefix("/v2/contracts/orders/blobs/") {
+                match blobs.get(digest) {
+                    Some(blob) if method == "GET" => ("200 OK", String::new(), blob.clone()),
+                    Some(_) => ("200 OK", String::new(), Vec::new()),
+                    None => ("404 Not Found", String::new(), Vec::new()),
+                }
+            } else {
+                ("404 Not Found", String::new(), Vec::new())
+            };
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n",
+                status,
+                extra,
+                content.len()
+            )
+            .unwrap();
+            stream.write_all(&content).unwrap();
+        }
+    });
+    (address, manifests)
+}
+
+#[test]
+fn test_push_and_pull_bundles_through_an_oci_registry() {
+    let dir = TempDir::new().unwrap();
+    let content = "version: \"1.2.0\"\nname: orders\nowner: sales\nschema:\n  format: iceberg\n  location: s3://lake/orders\n  fields:\n    - name: id\n      type: string\n      nullable: false\n";
+    let contract = dir.path().join("orders.yml");
+    fs::write(&contract, content).unwrap();
+    git(&dir, &["init", "-q"]);
+    git(&dir, &["add", "orders.yml"]);
+    git(&dir, &["commit", "-q", "-m", "Add orders"]);
+    let head = std::process::Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    let head = String::from_utf8(head.stdout).unwrap().trim().to_string();
+    let (address, manifests) = oci_registry();
+
+    dce()
+        .current_dir(dir.path())
+        .env("DCE_OCI_USERNAME", "ci")
+        .env("DCE_OCI_PASSWORD", "hunter2")
+        .arg("push")
+        .arg(&contract)
+        .arg(format!("{}/contracts/orders", address))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "Pushed orders 1.2.0 to {}/contracts/orders:1.2.0",
+            address
+        )))
+        .stdout(predicate::str::contains("Digest: sha256:"));
+    let manifest: serde_json::Value =
+        serde_json::from_slice(&manifests.lock().unwrap()["1.2.0"]).unwrap();
+    assert_eq!(
+        manifest["artifactType"],
+        "application/vnd.dce.contract.bundle.v1"
+    );
+    assert_eq!(manifest["annotations"]["dce.contract.path"], "orders.yml");
+    assert_eq!(manifest["annotations"]["dce.contract.commit"], head);
+    assert_eq!(manifest["annotations"]["dce.contract.dirty"], "false");
+
+    let registry = dir.path().join("registry");
+    dce()
+        .current_dir(dir.path())
+        .env("DCE_OCI_USERNAME", "ci")
+        .env("DCE_OCI_PASSWORD", "hunter2")
+        .arg("pull")
+        .arg(format!("{}/contracts/orders:1.2.0", address))
+        .arg("--registry")
+        .arg(&registry)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Pulled"));
+    assert_eq!(
+        fs::read_to_string(registry.join("orders/1.2.0.yml")).unwrap(),
+        content
+    );
+    let provenance: serde_json::Value = serde_json::from_str(
+        &fs::read_to_string(registry.join("orders/1.2.0.provenance.json")).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(provenance["path"], "orders.yml");
+    assert_eq!(provenance["commit"], head);
+
+    // A published version is never replaced by different content
+    fs::write(&contract, content.replace("sales", "finance")).unwrap();
+    dce()
+        .current_dir(dir.path())
+        .env("DCE_OCI_USERNAME", "ci")
+        .env("DCE_OCI_PASSWORD", "hunter2")
+        .arg("push")
+        .arg(&contract)
+        .arg(format!("{}/contracts/orders", address))
+        .assert()
+        .success();
+    dce()
+        .current_dir(dir.path())
+        .env("DCE_OCI_USERNAME", "ci")
+        .env("DCE_OCI_PASSWORD", "hunter2")
+        .arg("pull")
+        .arg(format!("{}/contracts/orders:1.2.0", address))
+        .arg("--registry")
+        .arg(&registry)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already published"));
+}
+
 // ============================================================================
 // validate command tests (schema-only mode)
 // ============================================================================
@@ -154,15 +772,11 @@ fn test_validate_json_output() {
 
     let output_str = String::from_utf8_lossy(&output);
 
-    // Output may have logs before JSON, extract the JSON part
-    let json_start = output_str.find('{').expect("Should contain JSON object");
-    let json_part = &output_str[json_start..];
-
-    // Should be valid JSON
+    // Logs go to stderr, so stdout is the JSON document alone
     assert!(
-        serde_json::from_str::<serde_json::Value>(json_part).is_ok(),
+        serde_json::from_str::<serde_json::Value>(&output_str).is_ok(),
         "Output should be valid JSON: {}",
-        json_part
+        output_str
     );
 }
 
@@ -200,6 +814,37 @@ fn test_validate_strict_mode() {
         .success();
 }
 
+#[test]
+fn test_validate_reads_limits_from_config() {
+    let dir = TempDir::new().unwrap();
+    let contract = fs::canonicalize(fixture_path("simple_contract.yml")).unwrap();
+
+    fs::write(
+        dir.path().join("dce.toml"),
+        "[limits]\nmax_concurrent = 4\nper_second = 10.0\n",
+    )
+    .unwrap();
+    dce()
+        .current_dir(dir.path())
+        .arg("validate")
+        .arg("--schema-only")
+        .arg(&contract)
+        .assert()
+        .success();
+
+    fs::write(dir.path().join("dce.toml"), "[limits]\nper_second = 0.0\n").unwrap();
+    dce()
+        .current_dir(dir.path())
+        .arg("validate")
+        .arg("--schema-only")
+        .arg("--catalog-rate-limit")
+        .arg("5")
+        .arg(&contract)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("limits.per_second"));
+}
+
 // ============================================================================
 // init command tests
 // ============================================================================
@@ -231,6 +876,24 @@ fn test_init_help() {
         .stdout(predicate::str::contains("table"));
 }
 
+#[test]
+fn test_init_sample_size_requires_profile() {
+    dce()
+        .arg("init")
+        .arg("http://localhost:8181")
+        .args([
+            "--namespace",
+            "db",
+            "--table",
+            "events",
+            "--sample-size",
+            "50",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--profile"));
+}
+
 #[test]
 fn test_init_with_output_file() {
     let temp_dir = TempDir::new().unwrap();
@@ -330,6 +993,37 @@ fn test_cli_version() {
         .stdout(predicate::str::contains(env!("CARGO_PKG_VERSION")));
 }
 
+#[test]
+fn test_cli_capabilities() {
+    let output = dce().arg("--capabilities").output().unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["version"], env!("CARGO_PKG_VERSION"));
+    let names = |key: &str| -> Vec<String> {
+        json[key]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v.as_str().unwrap().to_string())
+            .collect()
+    };
+    assert!(names("formats").contains(&"parquet".to_string()));
+    assert!(names("catalogs").contains(&"fileio".to_string()));
+    assert_eq!(
+        names("catalogs").contains(&"glue".to_string()),
+        names("features").contains(&"glue-catalog".to_string())
+    );
+    assert!(names("output_formats").contains(&"junit".to_string()));
+    assert!(names("check_types").contains(&"freshness".to_string()));
+    assert!(names("constraint_types").contains(&"pattern".to_string()));
+
+    dce()
+        .args(["--capabilities", "lint", "contract.yml"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with a command"));
+}
+
 #[test]
 fn test_validate_help() {
     dce()
@@ -449,3 +1143,3000 @@ fn test_multiple_field_types() {
         .stdout(predicate::str::contains("Fields"))
         .stdout(predicate::str::contains("3")); // 3 fields
 }
+
+// ============================================================================
+// hook command tests
+// ============================================================================
+
+/// Helper to run git inside a temporary repository
+fn git(dir: &TempDir, args: &[&str]) {
+    let status = std::process::Command::new("git")
+        .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+        .args(args)
+        .current_dir(dir.path())
+        .status()
+        .expect("Failed to run git");
+    assert!(status.success(), "git {:?} failed", args);
+}
+
+/// Helper to create a git repository with a `contracts/` directory
+fn git_repo() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    git(&temp_dir, &["init", "-q"]);
+    fs::create_dir(temp_dir.path().join("contracts")).unwrap();
+    temp_dir
+}
+
+#[test]
+fn test_hook_install_creates_executable_hook() {
+    let repo = git_repo();
+
+    dce()
+        .current_dir(repo.path())
+        .args(["hook", "install"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("pre-commit"));
+
+    let hook = repo.path().join(".git/hooks/pre-commit");
+    let content = fs::read_to_string(&hook).unwrap();
+    assert!(content.contains("dce hook run --hook pre-commit"));
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = fs::metadata(&hook).unwrap().permissions().mode();
+        assert_eq!(mode & 0o111, 0o111);
+    }
+}
+
+#[test]
+fn test_hook_install_refuses_foreign_hook() {
+    let repo = git_repo();
+    let hook = repo.path().join(".git/hooks/pre-push");
+    fs::create_dir_all(hook.parent().unwrap()).unwrap();
+    fs::write(&hook, "#!/bin/sh\nexit 0\n").unwrap();
+
+    dce()
+        .current_dir(repo.path())
+        .args(["hook", "install", "--hook", "pre-push"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--force"));
+
+    dce()
+        .current_dir(repo.path())
+        .args(["hook", "install", "--hook", "pre-push", "--force"])
+        .assert()
+        .success();
+
+    dce()
+        .current_dir(repo.path())
+        .args(["hook", "uninstall", "--hook", "pre-push"])
+        .assert()
+        .success();
+    assert!(!hook.exists());
+}
+
+#[test]
+fn test_hook_install_unsupported_hook() {
+    let repo = git_repo();
+
+    dce()
+        .current_dir(repo.path())
+        .args(["hook", "install", "--hook", "post-merge"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Unsupported hook type"));
+}
+
+#[test]
+fn test_hook_run_checks_staged_contracts() {
+    let repo = git_repo();
+    fs::copy(
+        fixture_path("simple_contract.yml"),
+        repo.path().join("contracts/simple.yml"),
+    )
+    .unwrap();
+    git(&repo, &["add", "contracts/simple.yml"]);
+
+    dce()
+        .current_dir(repo.path())
+        .args(["hook", "run"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("1 contract file(s) OK (0 cached)"));
+
+    // Second run hits the cache for the unchanged file
+    dce()
+        .current_dir(repo.path())
+        .args(["hook", "run"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("(1 cached)"));
+
+    fs::copy(
+        fixture_path("invalid_contract.yml"),
+        repo.path().join("contracts/invalid.yml"),
+    )
+    .unwrap();
+    git(&repo, &["add", "contracts/invalid.yml"]);
+
+    dce()
+        .current_dir(repo.path())
+        .args(["hook", "run"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("contracts/invalid.yml"));
+}
+
+#[test]
+fn test_hook_run_fails_on_lint_errors() {
+    let repo = git_repo();
+    let original = fs::read_to_string(fixture_path("simple_contract.yml")).unwrap();
+    fs::write(
+        repo.path().join("contracts/simple.yml"),
+        original + "\nquality_checks:\n  completeness:\n    threshold: 0.9\n    fields: [email]\n",
+    )
+    .unwrap();
+    git(&repo, &["add", "contracts/simple.yml"]);
+
+    dce()
+        .current_dir(repo.path())
+        .args(["hook", "run"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "quality_checks.completeness.fields",
+        ));
+}
+
+#[test]
+fn test_hook_run_fails_on_breaking_changes() {
+    let repo = git_repo();
+    let contract = repo.path().join("contracts/simple.yml");
+    let original = fs::read_to_string(fixture_path("simple_contract.yml")).unwrap();
+    fs::write(&contract, &original).unwrap();
+    git(&repo, &["add", "."]);
+    git(&repo, &["commit", "-q", "-m", "Add contract"]);
+
+    // Adding a nullable field is compatible
+    fs::write(
+        &contract,
+        original.clone() + "    - name: note\n      type: string\n      nullable: true\n",
+    )
+    .unwrap();
+    git(&repo, &["add", "."]);
+    dce()
+        .current_dir(repo.path())
+        .args(["hook", "run"])
+        .assert()
+        .success();
+
+    fs::write(
+        &contract,
+        original.replace(
+            "    - name: value\n      type: int\n      nullable: true\n",
+            "",
+        ),
+    )
+    .unwrap();
+    git(&repo, &["add", "."]);
+    dce()
+        .current_dir(repo.path())
+        .args(["hook", "run"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("breaking change"));
+
+    // The cache does not hide the failure on a second run
+    dce()
+        .current_dir(repo.path())
+        .args(["hook", "run"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_hook_install_follows_hooks_path() {
+    let repo = git_repo();
+    git(&repo, &["config", "core.hooksPath", ".githooks"]);
+
+    dce()
+        .current_dir(repo.path())
+        .args(["hook", "install"])
+        .assert()
+        .success();
+    assert!(repo.path().join(".githooks/pre-commit").exists());
+    assert!(!repo.path().join(".git/hooks/pre-commit").exists());
+}
+
+#[test]
+fn test_hook_run_pre_push_checks_pushed_commits() {
+    let repo = git_repo();
+    let head = || {
+        let output = std::process::Command::new("git")
+            .args(["rev-parse", "HEAD"])
+            .current_dir(repo.path())
+            .output()
+            .unwrap();
+        String::from_utf8(output.stdout).unwrap().trim().to_string()
+    };
+    let null = "0".repeat(40);
+    let push = |local: &str, remote: &str| {
+        dce()
+            .current_dir(repo.path())
+            .args(["hook", "run", "--hook", "pre-push", "--no-cache"])
+            .write_stdin(format!(
+                "refs/heads/main {local} refs/heads/main {remote}\n"
+            ))
+            .assert()
+    };
+
+    fs::copy(
+        fixture_path("simple_contract.yml"),
+        repo.path().join("contracts/simple.yml"),
+    )
+    .unwrap();
+    git(&repo, &["add", "."]);
+    git(&repo, &["commit", "-q", "-m", "Add contract"]);
+    let first = head();
+
+    // Uncommitted changes are not pushed, so they are not checked
+    fs::copy(
+        fixture_path("invalid_contract.yml"),
+        repo.path().join("contracts/simple.yml"),
+    )
+    .unwrap();
+    push(&first, &null)
+        .success()
+        .stdout(predicate::str::contains("1 contract file(s) OK"));
+
+    git(&repo, &["commit", "-q", "-am", "Break contract"]);
+    push(&head(), &first)
+        .failure()
+        .stderr(predicate::str::contains("contracts/simple.yml"));
+
+    // Deleting a branch pushes nothing
+    push(&null, &first).success();
+}
+
+#[test]
+fn test_hook_run_ignores_files_outside_contracts() {
+    let repo = git_repo();
+    fs::write(repo.path().join("config.yml"), "not: [a contract").unwrap();
+    git(&repo, &["add", "config.yml"]);
+
+    dce()
+        .current_dir(repo.path())
+        .args(["hook", "run"])
+        .assert()
+        .success();
+}
+
+// ============================================================================
+// report rendering tests
+// ============================================================================
+
+#[test]
+fn test_validate_text_report_renders_tables() {
+    dce()
+        .arg("validate")
+        .arg("--schema-only")
+        .arg("--no-color")
+        .arg(fixture_path("simple_contract.yml"))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("VALIDATION REPORT"))
+        .stdout(predicate::str::contains("PASSED"))
+        .stdout(predicate::str::contains("Fields checked"))
+        .stdout(predicate::str::contains("\u{1b}[").not());
+}
+
+#[test]
+fn test_validate_report_shows_check_results_and_fields() {
+    let temp_dir = TempDir::new().unwrap();
+    let data = temp_dir.path().join("data.csv");
+    fs::write(&data, "id,status\n1,active\n2,bogus\n").unwrap();
+
+    let contract = temp_dir.path().join("contract.yml");
+    fs::write(
+        &contract,
+        format!(
+            r#"version: "1.0.0"
+name: csv_report
+owner: test-team
+schema:
+  format: csv
+  location: {}
+  fields:
+    - name: id
+      type: int64
+      nullable: false
+    - name: status
+      type: string
+      nullable: false
+      constraints:
+        - type: allowedvalues
+          values: ["active", "inactive"]
+"#,
+            data.display()
+        ),
+    )
+    .unwrap();
+
+    dce()
+        .env("NO_COLOR", "1")
+        .arg("validate")
+        .arg(contract.to_str().unwrap())
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("FAILED"))
+        .stdout(predicate::str::contains("Check Results"))
+        .stdout(predicate::str::contains("Per-field Breakdown"))
+        .stdout(predicate::str::contains("status"))
+        .stdout(predicate::str::contains("\u{1b}[").not());
+}
+
+#[test]
+fn test_validate_parquet_reads_rows_and_footer() {
+    use arrow_array::{ArrayRef, Int64Array, RecordBatch, StringArray};
+    use std::sync::Arc;
+
+    let temp_dir = TempDir::new().unwrap();
+    let data = temp_dir.path().join("users.parquet");
+    let batch = RecordBatch::try_from_iter(vec![
+        ("id", Arc::new(Int64Array::from(vec![1, 2])) as ArrayRef),
+        (
+            "name",
+            Arc::new(StringArray::from(vec![Some("ada"), None])) as ArrayRef,
+        ),
+    ])
+    .unwrap();
+    let mut writer = parquet::arrow::ArrowWriter::try_new(
+        fs::File::create(&data).unwrap(),
+        batch.schema(),
+        None,
+    )
+    .unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+
+    let contract = temp_dir.path().join("contract.yml");
+    fs::write(
+        &contract,
+        format!(
+            r#"version: "1.0.0"
+name: parquet_users
+owner: test-team
+schema:
+  format: parquet
+  location: {}
+  fields:
+    - name: id
+      type: int32
+      nullable: true
+    - name: name
+      type: string
+      nullable: false
+"#,
+            data.display()
+        ),
+    )
+    .unwrap();
+
+    let run = |args: &[&str]| {
+        let output = dce()
+            .arg("validate")
+            .arg(&contract)
+            .args(["--format", "json"])
+            .args(args)
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+        let mut codes: Vec<String> = json["results"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|r| r["code"].as_str().unwrap().to_string())
+            .collect();
+        codes.sort();
+        (output.status.success(), codes)
+    };
+
+    // The int64 column is wider than the declared int32, and a name is null
+    assert_eq!(run(&[]), (false, vec!["DCE0102".into(), "DCE0103".into()]));
+    // Footers alone cannot show the null
+    assert_eq!(run(&["--schema-only"]), (false, vec!["DCE0103".into()]));
+}
+
+#[test]
+fn test_validate_time_window_selects_rows() {
+    let temp_dir = TempDir::new().unwrap();
+    let data = temp_dir.path().join("events.csv");
+    fs::write(
+        &data,
+        "id,status,created_at\n\
+         1,bogus,2026-10-14T23:00:00Z\n\
+         2,active,2026-10-15T08:00:00Z\n\
+         3,inactive,2026-10-15T20:00:00Z\n\
+         4,bogus,2026-10-16T00:00:00Z\n",
+    )
+    .unwrap();
+
+    let contract = temp_dir.path().join("contract.yml");
+    fs::write(
+        &contract,
+        format!(
+            r#"version: "1.0.0"
+name: csv_window
+owner: test-team
+schema:
+  format: csv
+  location: {}
+  fields:
+    - name: id
+      type: int64
+      nullable: false
+    - name: status
+      type: string
+      nullable: false
+      constraints:
+        - type: allowedvalues
+          values: ["active", "inactive"]
+    - name: created_at
+      type: timestamp
+      nullable: false
+quality_checks:
+  freshness:
+    max_delay: 1000d
+    metric: created_at
+"#,
+            data.display()
+        ),
+    )
+    .unwrap();
+
+    let run = |args: &[&str]| {
+        let output = dce()
+            .arg("validate")
+            .arg(&contract)
+            .args(["--format", "json", "-vv"])
+            .args(args)
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+        (output.status.success(), json)
+    };
+
+    // The bad rows sit on either side of 2026-10-15
+    let (passed, json) = run(&["--since", "2026-10-15", "--until", "2026-10-16"]);
+    assert!(passed, "{json}");
+    assert_eq!(json["stats"]["records_validated"], 2);
+
+    let (passed, json) = run(&["--since", "2026-10-15T12:00:00+02:00"]);
+    assert!(!passed);
+    assert_eq!(json["stats"]["records_validated"], 2);
+
+    let (passed, _) = run(&[]);
+    assert!(!passed);
+
+    dce()
+        .arg("validate")
+        .arg(&contract)
+        .args(["--time-column", "created_at"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--since"));
+
+    dce()
+        .arg("validate")
+        .arg(&contract)
+        .args(["--since", "yesterday"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("YYYY-MM-DD"));
+}
+
+#[test]
+fn test_validate_max_errors_and_fail_fast() {
+    let temp_dir = TempDir::new().unwrap();
+    let data = temp_dir.path().join("scores.csv");
+    // Each column is mistyped in one row, which fails one check per field
+    fs::write(
+        &data,
+        "a,b,c,d,e\nx,1,1,1,1\n1,x,1,1,1\n1,1,x,1,1\n1,1,1,x,1\n1,1,1,1,x\n",
+    )
+    .unwrap();
+
+    let fields: String = ["a", "b", "c", "d", "e"]
+        .iter()
+        .map(|name| format!("    - name: {name}\n      type: int64\n      nullable: true\n"))
+        .collect();
+    let contract = temp_dir.path().join("contract.yml");
+    fs::write(
+        &contract,
+        format!(
+            "version: \"1.0.0\"\nname: csv_limits\nowner: test-team\nschema:\n  \
+             format: csv\n  location: {}\n  fields:\n{fields}",
+            data.display()
+        ),
+    )
+    .unwrap();
+
+    let run = |args: &[&str]| {
+        let output = dce()
+            .arg("validate")
+            .arg(&contract)
+            .args(["--format", "json", "-vv"])
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(!output.status.success());
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        serde_json::from_str::<serde_json::Value>(&stdout).unwrap()
+    };
+
+    let json = run(&[]);
+    assert_eq!(json["errors"].as_array().unwrap().len(), 5);
+    assert!(json["stats"].get("truncated_violations").is_none());
+
+    let json = run(&["--max-errors", "2"]);
+    assert_eq!(json["errors"].as_array().unwrap().len(), 2);
+    assert_eq!(json["stats"]["truncated_violations"], 3);
+
+    let json = run(&["--fail-fast"]);
+    assert_eq!(json["errors"].as_array().unwrap().len(), 1);
+}
+
+#[test]
+fn test_validate_where_selects_rows() {
+    let temp_dir = TempDir::new().unwrap();
+    let data = temp_dir.path().join("jobs.csv");
+    fs::write(
+        &data,
+        "id,status,attempts\n\
+         1,done,1\n\
+         2,failed,3\n\
+         3,failed,-1\n\
+         4,done,2\n",
+    )
+    .unwrap();
+
+    let contract = temp_dir.path().join("contract.yml");
+    fs::write(
+        &contract,
+        format!(
+            r#"version: "1.0.0"
+name: csv_where
+owner: test-team
+schema:
+  format: csv
+  location: {}
+  fields:
+    - name: id
+      type: int64
+      nullable: false
+    - name: status
+      type: string
+      nullable: false
+    - name: attempts
+      type: int64
+      nullable: false
+      constraints:
+        - type: range
+          min: 0
+          max: 10
+"#,
+            data.display()
+        ),
+    )
+    .unwrap();
+
+    let run = |predicate: &str| {
+        let output = dce()
+            .arg("validate")
+            .arg(&contract)
+            .args(["--format", "json", "-vv", "--where", predicate])
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+        (output.status.success(), json)
+    };
+
+    let (passed, json) = run("status = 'done'");
+    assert!(passed, "{json}");
+    assert_eq!(json["stats"]["records_validated"], 2);
+
+    let (passed, json) = run("status = 'failed' AND id > 1");
+    assert!(!passed);
+    assert_eq!(json["stats"]["records_validated"], 2);
+
+    dce()
+        .arg("validate")
+        .arg(&contract)
+        .args(["--where", "priority = 1"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("DCE0011"));
+}
+
+#[test]
+fn test_validate_segment_by_reports_each_value() {
+    let temp_dir = TempDir::new().unwrap();
+    let data = temp_dir.path().join("orders.csv");
+    fs::write(
+        &data,
+        "id,country,status\n\
+         1,IT,active\n\
+         2,IT,bogus\n\
+         3,FR,active\n\
+         4,FR,inactive\n\
+         5,FR,active\n",
+    )
+    .unwrap();
+
+    let contract = temp_dir.path().join("contract.yml");
+    fs::write(
+        &contract,
+        format!(
+            r#"version: "1.0.0"
+name: csv_segments
+owner: test-team
+schema:
+  format: csv
+  location: {}
+  fields:
+    - name: id
+      type: int64
+      nullable: false
+    - name: country
+      type: string
+      nullable: false
+    - name: status
+      type: string
+      nullable: false
+      constraints:
+        - type: allowedvalues
+          values: ["active", "inactive"]
+"#,
+            data.display()
+        ),
+    )
+    .unwrap();
+
+    let output = dce()
+        .arg("validate")
+        .arg(&contract)
+        .args(["--format", "json", "--segment-by", "country"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+
+    let segments = json["segments"].as_array().expect("segments");
+    let summary: Vec<_> = segments
+        .iter()
+        .map(|s| {
+            (
+                s["value"].as_str().unwrap(),
+                s["passed"].as_bool().unwrap(),
+                s["records_validated"].as_u64().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(summary, vec![("FR", true, 3), ("IT", false, 2)]);
+    assert!(
+        json["warnings"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|w| w.to_string().contains("DCE0305")),
+        "{json}"
+    );
+
+    dce()
+        .arg("validate")
+        .arg(&contract)
+        .args(["--segment-by", "region"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("region"));
+}
+
+#[test]
+fn test_validate_ndjson_directory() {
+    let temp_dir = TempDir::new().unwrap();
+    let data = temp_dir.path().join("events");
+    fs::create_dir(&data).unwrap();
+    fs::write(
+        data.join("part-0.jsonl"),
+        "{\"id\": 1, \"at\": \"2024-01-31T10:00:00Z\"}\n{\"id\": 2, \"at\": \"2024-02-01T09:30:00Z\"}\n",
+    )
+    .unwrap();
+    fs::write(
+        data.join("part-1.jsonl"),
+        "{\"id\": 3, \"at\": \"yesterday\"}\n",
+    )
+    .unwrap();
+
+    let contract = temp_dir.path().join("contract.yml");
+    fs::write(
+        &contract,
+        format!(
+            r#"version: "1.0.0"
+name: json_events
+owner: test-team
+schema:
+  format: json
+  location: {}
+  fields:
+    - name: id
+      type: int64
+      nullable: false
+    - name: at
+      type: timestamp
+      nullable: false
+"#,
+            data.display()
+        ),
+    )
+    .unwrap();
+
+    let output = dce()
+        .arg("validate")
+        .arg(&contract)
+        .args(["--format", "json"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["results"][0]["code"], "DCE0103", "{json}");
+    let message = json["results"][0]["message"].as_str().unwrap();
+    assert!(message.contains("part-1.jsonl, line 1"), "{message}");
+
+    // Without contract typing, no timestamp is recognised
+    dce()
+        .arg("validate")
+        .arg(&contract)
+        .args(["--type-inference", "data"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("and in 2 more rows"));
+}
+
+#[test]
+fn test_validate_reports_unparseable_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let data = temp_dir.path().join("events.jsonl");
+    fs::write(&data, "{\"id\": 1}\nnot json\n").unwrap();
+
+    let contract = temp_dir.path().join("contract.yml");
+    fs::write(
+        &contract,
+        format!(
+            "version: \"1.0.0\"\nname: broken\nowner: team\nschema:\n  format: json\n  location: {}\n  fields:\n    - name: id\n      type: int64\n      nullable: false\n",
+            data.display()
+        ),
+    )
+    .unwrap();
+
+    let output = dce()
+        .arg("validate")
+        .arg(&contract)
+        .args(["--format", "json"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["results"][0]["code"], "DCE2203", "{json}");
+
+    dce()
+        .arg("validate")
+        .arg(&contract)
+        .args(["--type-inference", "guess"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "expected contract, data or strings",
+        ));
+}
+
+#[test]
+fn test_preview_change_reports_newly_failing_rows() {
+    let temp_dir = TempDir::new().unwrap();
+    let data = temp_dir.path().join("orders.csv");
+    fs::write(&data, "id,amount\n1,9.5\n2,12\n3,50\n4,\n").unwrap();
+
+    let contract = |name: &str, max: u32| {
+        let path = temp_dir.path().join(name);
+        fs::write(
+            &path,
+            format!(
+                r#"version: "1.0.0"
+name: csv_orders
+owner: test-team
+schema:
+  format: csv
+  location: orders.csv
+  fields:
+    - name: id
+      type: int64
+      nullable: false
+    - name: amount
+      type: float64
+      nullable: true
+      constraints:
+        - type: range
+          min: 0
+          max: {max}
+"#
+            ),
+        )
+        .unwrap();
+        path
+    };
+    let (loose, tight) = (contract("old.yml", 100), contract("new.yml", 10));
+
+    let run = |old: &std::path::Path, new: &std::path::Path| {
+        let output = dce()
+            .arg("preview-change")
+            .arg(old)
+            .arg(new)
+            .arg("--data")
+            .arg(&data)
+            .args(["--format", "json"])
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+        (output.status.success(), json)
+    };
+
+    let (passed, json) = run(&loose, &tight);
+    assert!(!passed, "{json}");
+    assert_eq!(json["rows"], 4);
+    assert_eq!(json["old"]["passed"], true);
+    assert_eq!(json["newly_failing_rows"], serde_json::json!([1, 2]));
+    assert_eq!(json["blast_radius"], 0.5);
+    assert_eq!(json["newly_failing_checks"][0]["field"], "amount");
+
+    let (passed, json) = run(&tight, &loose);
+    assert!(passed, "{json}");
+    assert_eq!(json["newly_passing_rows"], serde_json::json!([1, 2]));
+
+    dce()
+        .arg("preview-change")
+        .arg(&loose)
+        .arg(&tight)
+        .arg("--data")
+        .arg(&data)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "2 rows would newly fail (50.0% of the sample): 1, 2",
+        ));
+}
+
+#[test]
+fn test_reconcile_compares_source_and_target() {
+    let temp_dir = TempDir::new().unwrap();
+    let source = temp_dir.path().join("source.csv");
+    fs::write(&source, "id,amount\n1,9.5\n2,12\n3,\n").unwrap();
+    // The same rows in another order and another format
+    let copy = temp_dir.path().join("copy.csv");
+    fs::write(&copy, "amount,id\n,3\n12.0,2\n9.5,1\n").unwrap();
+    let lossy = temp_dir.path().join("lossy.csv");
+    fs::write(&lossy, "id,amount\n1,9.5\n2,12\n").unwrap();
+
+    let contract = temp_dir.path().join("contract.yml");
+    fs::write(
+        &contract,
+        r#"version: "1.0.0"
+name: csv_orders
+owner: test-team
+schema:
+  format: csv
+  location: orders.csv
+  fields:
+    - name: id
+      type: int64
+      nullable: false
+    - name: amount
+      type: float64
+      nullable: true
+"#,
+    )
+    .unwrap();
+
+    let run = |target: &std::path::Path| {
+        let output = dce()
+            .arg("reconcile")
+            .arg("--contract")
+            .arg(&contract)
+            .arg("--source")
+            .arg(&source)
+            .arg("--target")
+            .arg(target)
+            .args(["--format", "json"])
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+        (output.status.success(), json)
+    };
+
+    let (passed, json) = run(&copy);
+    assert!(passed, "{json}");
+    assert_eq!(json["source"]["profile"]["rows"], 3);
+    assert_eq!(
+        json["source"]["profile"]["checksum"],
+        json["target"]["profile"]["checksum"]
+    );
+
+    let (passed, json) = run(&lossy);
+    assert!(!passed);
+    assert_eq!(json["source"]["passed"], true);
+    assert_eq!(json["target"]["passed"], true);
+    let metrics: Vec<String> = json["differences"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|d| format!("{}:{}", d["column"].as_str().unwrap_or("-"), d["metric"]))
+        .collect();
+    assert_eq!(
+        metrics[..3],
+        ["-:\"rows\"", "-:\"checksum\"", "id:\"checksum\""]
+    );
+
+    dce()
+        .arg("reconcile")
+        .arg("--contract")
+        .arg(&contract)
+        .arg("--source")
+        .arg(&source)
+        .arg("--target")
+        .arg(&lossy)
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("differences between source and target").not())
+        .stderr(predicate::str::contains(
+            "differences between source and target",
+        ));
+}
+
+#[test]
+fn test_validate_quiet_prints_summary_line_only() {
+    dce()
+        .arg("validate")
+        .arg("--schema-only")
+        .arg("--quiet")
+        .arg("--no-color")
+        .arg(fixture_path("simple_contract.yml"))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Validation PASSED (0 error(s), 0 warning(s))",
+        ))
+        .stdout(predicate::str::contains("Contract loaded").not())
+        .stdout(predicate::str::contains("VALIDATION REPORT").not());
+}
+
+#[test]
+fn test_validate_quiet_failure_prints_one_line() {
+    let temp_dir = TempDir::new().unwrap();
+    let data = temp_dir.path().join("jobs.csv");
+    fs::write(&data, "id,attempts\n1,1\n2,-1\n").unwrap();
+    let contract = temp_dir.path().join("contract.yml");
+    fs::write(
+        &contract,
+        format!(
+            r#"version: "1.0.0"
+name: csv_quiet
+owner: test-team
+schema:
+  format: csv
+  location: {}
+  fields:
+    - name: id
+      type: int64
+      nullable: false
+    - name: attempts
+      type: int64
+      nullable: false
+      constraints:
+        - type: range
+          min: 0
+          max: 10
+"#,
+            data.display()
+        ),
+    )
+    .unwrap();
+
+    let output = dce()
+        .arg("validate")
+        .arg(&contract)
+        .args(["--quiet", "--no-color"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert_eq!(stdout.lines().count(), 1, "stdout: {}", stdout);
+    assert!(
+        stdout.contains("Validation FAILED (1 error(s)"),
+        "stdout: {}",
+        stdout
+    );
+    assert!(stderr.is_empty(), "stderr: {}", stderr);
+}
+
+#[test]
+fn test_validate_very_verbose_includes_timings() {
+    dce()
+        .arg("validate")
+        .arg("--schema-only")
+        .arg("-vv")
+        .arg("--no-color")
+        .arg(fixture_path("simple_contract.yml"))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Duration"))
+        .stdout(predicate::str::contains("Timings"))
+        .stdout(predicate::str::contains("Contract parsing"));
+}
+
+#[test]
+fn test_quiet_conflicts_with_verbose() {
+    dce()
+        .arg("validate")
+        .arg("--schema-only")
+        .arg("--quiet")
+        .arg("-v")
+        .arg(fixture_path("simple_contract.yml"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+// ============================================================================
+// localization tests
+// ============================================================================
+
+#[test]
+fn test_check_italian_locale() {
+    dce()
+        .env("DCE_LANG", "it_IT.UTF-8")
+        .arg("check")
+        .arg(fixture_path("contract_with_quality.yml"))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Riepilogo del contratto"))
+        .stdout(predicate::str::contains("Controlli di qualità"))
+        .stdout(predicate::str::contains("quality_test"));
+}
+
+#[test]
+fn test_validate_italian_locale() {
+    dce()
+        .env("DCE_LANG", "it")
+        .arg("validate")
+        .arg("--schema-only")
+        .arg("--no-color")
+        .arg(fixture_path("simple_contract.yml"))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("REPORT DI VALIDAZIONE"))
+        .stdout(predicate::str::contains("Validazione SUPERATA"));
+}
+
+#[test]
+fn test_unknown_locale_falls_back_to_english() {
+    dce()
+        .env("DCE_LANG", "xx")
+        .arg("check")
+        .arg(fixture_path("simple_contract.yml"))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Contract Summary"));
+}
+
+#[test]
+fn test_validate_json_includes_provenance() {
+    let output = dce()
+        .arg("validate")
+        .arg("--schema-only")
+        .arg("--format")
+        .arg("json")
+        .arg(fixture_path("simple_contract.yml"))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let report: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert!(
+        report["provenance"]["path"]
+            .as_str()
+            .unwrap()
+            .ends_with("simple_contract.yml")
+    );
+}
+
+// ============================================================================
+// Catalog connection tests
+// ============================================================================
+
+#[test]
+fn test_validate_missing_catalog_configuration() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("orders.yml");
+    fs::write(
+        &path,
+        "version: \"1.0.0\"\nname: orders\nowner: sales\nschema:\n  format: iceberg\n  \
+         location: iceberg://prod/sales/orders\n  fields: []\n",
+    )
+    .unwrap();
+
+    dce()
+        .env_remove("REST_CATALOG_URI")
+        .env_remove("ICEBERG_REST_URI")
+        .arg("validate")
+        .arg(&path)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("schema.connection"));
+}
+
+#[test]
+fn test_validate_resolves_connection_secrets() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("orders.yml");
+    fs::write(
+        &path,
+        "version: \"1.0.0\"\nname: orders\nowner: sales\nschema:\n  format: iceberg\n  \
+         location: iceberg://prod/sales/orders\n  connection:\n    uri: http://127.0.0.1:1\n    \
+         properties:\n      token: ${env:DCE_TEST_CATALOG_TOKEN}\n  fields: []\n",
+    )
+    .unwrap();
+
+    dce()
+        .env_remove("REST_CATALOG_URI")
+        .env_remove("ICEBERG_REST_URI")
+        .env_remove("DCE_TEST_CATALOG_TOKEN")
+        .arg("validate")
+        .arg(&path)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("DCE_TEST_CATALOG_TOKEN"));
+}
+
+// ============================================================================
+// Directory validation tests
+// ============================================================================
+
+#[test]
+fn test_validate_directory_of_contracts() {
+    let dir = TempDir::new().unwrap();
+    let nested = dir.path().join("sales");
+    fs::create_dir(&nested).unwrap();
+    fs::copy(
+        fixture_path("simple_contract.yml"),
+        dir.path().join("simple.yml"),
+    )
+    .unwrap();
+    fs::copy(fixture_path("contract.toml"), nested.join("orders.toml")).unwrap();
+    fs::write(dir.path().join("README.md"), "not a contract").unwrap();
+
+    dce()
+        .current_dir(dir.path())
+        .env("NO_COLOR", "1")
+        .arg("validate")
+        .arg("--schema-only")
+        .arg(dir.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Results by contract"))
+        .stdout(predicate::str::contains(
+            "2 contracts validated: 2 passed, 0 failed",
+        ));
+}
+
+#[test]
+fn test_validate_directory_reports_failures() {
+    let dir = TempDir::new().unwrap();
+    fs::copy(
+        fixture_path("simple_contract.yml"),
+        dir.path().join("simple.yml"),
+    )
+    .unwrap();
+    fs::copy(
+        fixture_path("invalid_contract.yml"),
+        dir.path().join("invalid.yml"),
+    )
+    .unwrap();
+
+    dce()
+        .current_dir(dir.path())
+        .env("NO_COLOR", "1")
+        .arg("validate")
+        .arg("--schema-only")
+        .arg(dir.path())
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "2 contracts validated: 1 passed, 1 failed",
+        ))
+        .stderr(predicate::str::contains("invalid.yml"));
+}
+
+#[test]
+fn test_validate_directory_json_aggregates_contracts() {
+    let dir = TempDir::new().unwrap();
+    fs::copy(
+        fixture_path("simple_contract.yml"),
+        dir.path().join("b_simple.yml"),
+    )
+    .unwrap();
+    fs::copy(
+        fixture_path("invalid_contract.yml"),
+        dir.path().join("a_invalid.yml"),
+    )
+    .unwrap();
+    fs::copy(
+        fixture_path("contract.toml"),
+        dir.path().join("c_orders.toml"),
+    )
+    .unwrap();
+
+    let output = dce()
+        .current_dir(dir.path())
+        .arg("validate")
+        .arg("--schema-only")
+        .args(["--format", "json", "--jobs", "2"])
+        .arg(dir.path())
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout).expect("directory output should be one JSON document");
+    assert_eq!(json["passed"], false);
+    assert_eq!(json["total"], 3);
+    assert_eq!(json["failed"], 1);
+
+    let contracts = json["contracts"].as_array().unwrap();
+    let files: Vec<&str> = contracts
+        .iter()
+        .map(|c| c["path"].as_str().unwrap().rsplit('/').next().unwrap())
+        .collect();
+    assert_eq!(
+        files,
+        vec!["a_invalid.yml", "b_simple.yml", "c_orders.toml"]
+    );
+    assert_eq!(contracts[0]["name"], serde_json::Value::Null);
+    assert_eq!(contracts[0]["report"]["passed"], false);
+    assert_eq!(contracts[1]["report"]["passed"], true);
+}
+
+#[test]
+fn test_validate_directory_rejects_zero_jobs() {
+    dce()
+        .arg("validate")
+        .args(["--jobs", "0"])
+        .arg(fixture_path(""))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--jobs"));
+}
+
+#[test]
+fn test_check_directory_of_contracts() {
+    let dir = TempDir::new().unwrap();
+    fs::copy(
+        fixture_path("simple_contract.yml"),
+        dir.path().join("simple.yml"),
+    )
+    .unwrap();
+    fs::copy(
+        fixture_path("contract.toml"),
+        dir.path().join("orders.toml"),
+    )
+    .unwrap();
+
+    dce()
+        .env("NO_COLOR", "1")
+        .current_dir(dir.path())
+        .args(["check", "."])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "2 contracts checked: 2 passed, 0 failed",
+        ));
+
+    fs::copy(
+        fixture_path("invalid_contract.yml"),
+        dir.path().join("invalid.yml"),
+    )
+    .unwrap();
+    let output = dce()
+        .current_dir(dir.path())
+        .args(["check", ".", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout).expect("directory output should be one JSON document");
+    assert_eq!(json["valid"], false);
+    assert_eq!(
+        (json["total"].as_u64(), json["failed"].as_u64()),
+        (Some(3), Some(1))
+    );
+    assert_eq!(json["contracts"][0]["valid"], false);
+    assert_eq!(json["contracts"][0]["findings"][0]["rule"], "parse-error");
+}
+
+#[test]
+fn test_validate_directory_resumes_run() {
+    let dir = TempDir::new().unwrap();
+    let contracts = dir.path().join("contracts");
+    fs::create_dir(&contracts).unwrap();
+    fs::copy(
+        fixture_path("simple_contract.yml"),
+        contracts.join("a_simple.yml"),
+    )
+    .unwrap();
+    fs::copy(
+        fixture_path("invalid_contract.yml"),
+        contracts.join("b_broken.yml"),
+    )
+    .unwrap();
+
+    let run = |resume: Option<&str>| {
+        let mut command = dce();
+        command.current_dir(dir.path()).args([
+            "validate",
+            "--schema-only",
+            "--format",
+            "json",
+            "contracts",
+        ]);
+        if let Some(id) = resume {
+            command.args(["--resume", id]);
+        }
+        let output = command.output().unwrap();
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+        let skipped: Vec<bool> = json["contracts"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|c| c["skipped"] == true)
+            .collect();
+        (json, skipped)
+    };
+
+    let (first, skipped) = run(None);
+    assert_eq!(first["failed"], 1);
+    assert_eq!(skipped, vec![false, false]);
+    let id = first["run_id"].as_str().unwrap().to_string();
+    let log: serde_json::Value = serde_json::from_str(
+        &fs::read_to_string(dir.path().join(".dce-runs").join(format!("{}.json", id))).unwrap(),
+    )
+    .unwrap();
+    // Recorded with a SHA-256 fingerprint
+    let fingerprint = log["contracts"]["contracts/a_simple.yml"]["fingerprint"]
+        .as_str()
+        .unwrap();
+    assert_eq!(fingerprint.len(), 64, "{log}");
+
+    // The broken contract could not be validated, so resuming retries it
+    fs::copy(
+        fixture_path("simple_contract.yml"),
+        contracts.join("b_broken.yml"),
+    )
+    .unwrap();
+    let (resumed, skipped) = run(Some(&id));
+    assert_eq!(resumed["run_id"], id.as_str());
+    assert_eq!(resumed["passed"], true);
+    assert_eq!(resumed["total"], 2);
+    assert_eq!(skipped, vec![true, false]);
+    assert_eq!(resumed["contracts"][0]["report"]["passed"], true);
+
+    // A contract edited since it completed is validated again
+    let edited = fs::read_to_string(contracts.join("a_simple.yml")).unwrap() + "\n# edited\n";
+    fs::write(contracts.join("a_simple.yml"), edited).unwrap();
+    let (_, skipped) = run(Some(&id));
+    assert_eq!(skipped, vec![false, true]);
+
+    dce()
+        .current_dir(dir.path())
+        .args(["validate", "contracts", "--resume", "unknown"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Failed to read run unknown"));
+    dce()
+        .current_dir(dir.path())
+        .args(["validate", "contracts/a_simple.yml", "--resume", &id])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--resume only applies to a directory of contracts",
+        ));
+}
+
+#[test]
+fn test_validate_empty_directory() {
+    let dir = TempDir::new().unwrap();
+
+    dce()
+        .arg("validate")
+        .arg(dir.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No contract files"));
+}
+
+// ============================================================================
+// End-to-end Iceberg tests against a local warehouse
+// ============================================================================
+
+async fn local_users_table(
+    names: Vec<Option<&str>>,
+) -> (
+    contracts_iceberg::testing::TestWarehouse,
+    contracts_iceberg::testing::TestTable,
+) {
+    use arrow_array::{Int64Array, RecordBatch, StringArray};
+    use arrow_schema::{DataType, Field, Schema};
+    use std::sync::Arc;
+
+    let ids: Vec<i64> = (1..=names.len() as i64).collect();
+    let batch = RecordBatch::try_new(
+        Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+        ])),
+        vec![
+            Arc::new(Int64Array::from(ids)),
+            Arc::new(StringArray::from(names)),
+        ],
+    )
+    .unwrap();
+
+    let warehouse = contracts_iceberg::testing::TestWarehouse::new()
+        .await
+        .unwrap();
+    let table = warehouse
+        .create_table(&["db"], "users", &batch)
+        .await
+        .unwrap();
+    (warehouse, table)
+}
+
+fn write_users_contract(dir: &TempDir, metadata_location: &str) -> std::path::PathBuf {
+    let path = dir.path().join("users.yml");
+    fs::write(
+        &path,
+        format!(
+            "version: \"1.0.0\"\nname: users\nowner: test-team\nschema:\n  format: iceberg\n  \
+             location: iceberg://local/db/users\n  connection:\n    type: fileio\n    \
+             properties:\n      metadata_location: {}\n  fields:\n    - name: id\n      \
+             type: int64\n      nullable: false\n    - name: name\n      type: string\n      \
+             nullable: false\n",
+            metadata_location
+        ),
+    )
+    .unwrap();
+    path
+}
+
+#[tokio::test]
+async fn test_validate_local_iceberg_table() {
+    let (_warehouse, table) = local_users_table(vec![Some("ada"), Some("grace")]).await;
+    let dir = TempDir::new().unwrap();
+    let contract = write_users_contract(&dir, &table.metadata_location);
+
+    dce()
+        .env_remove("REST_CATALOG_URI")
+        .env_remove("ICEBERG_REST_URI")
+        .arg("validate")
+        .arg(&contract)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("PASSED"));
+}
+
+#[tokio::test]
+async fn test_validate_local_iceberg_table_with_nulls() {
+    let (_warehouse, table) = local_users_table(vec![Some("ada"), None]).await;
+    let dir = TempDir::new().unwrap();
+    let contract = write_users_contract(&dir, &table.metadata_location);
+
+    dce()
+        .env_remove("REST_CATALOG_URI")
+        .env_remove("ICEBERG_REST_URI")
+        .arg("validate")
+        .arg(&contract)
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("FAILED"));
+}
+
+#[tokio::test]
+async fn test_validate_local_iceberg_table_chunked() {
+    let (_warehouse, table) = local_users_table(vec![Some("ada"), None, Some("grace")]).await;
+    let dir = TempDir::new().unwrap();
+    let contract = write_users_contract(&dir, &table.metadata_location);
+
+    let output = dce()
+        .env_remove("REST_CATALOG_URI")
+        .env_remove("ICEBERG_REST_URI")
+        .args(["validate", "--format", "json", "--chunked"])
+        .arg(&contract)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    let errors = json["errors"].as_array().unwrap();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].to_string().contains("name"), "{}", errors[0]);
+}
+
+#[tokio::test]
+async fn test_validate_local_iceberg_table_stats_only() {
+    let (_warehouse, table) = local_users_table(vec![Some("ada"), None, Some("grace")]).await;
+    let dir = TempDir::new().unwrap();
+    let contract = write_users_contract(&dir, &table.metadata_location);
+
+    let output = dce()
+        .env_remove("REST_CATALOG_URI")
+        .env_remove("ICEBERG_REST_URI")
+        .args(["validate", "--format", "json", "--stats-only"])
+        .arg(&contract)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    let errors = json["errors"].as_array().unwrap();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].to_string().contains("DCE0102"), "{}", errors[0]);
+    assert!(json["snapshot_id"].is_i64());
+
+    dce()
+        .args(["validate", "--stats-only", "--chunked"])
+        .arg(&contract)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[tokio::test]
+async fn test_validate_local_iceberg_table_with_scan_options() {
+    let (_warehouse, table) = local_users_table(vec![Some("ada"), Some("grace")]).await;
+    let dir = TempDir::new().unwrap();
+    let contract = write_users_contract(&dir, &table.metadata_location);
+
+    let output = dce()
+        .env_remove("REST_CATALOG_URI")
+        .env_remove("ICEBERG_REST_URI")
+        .args(["validate", "--format", "json", "--chunked"])
+        .args(["--batch-size", "1", "--target-split-size", "1"])
+        .args(["--prefetch-depth", "2"])
+        .arg(&contract)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stdout: {}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+
+    let output = dce()
+        .args(["validate", "--batch-size", "0"])
+        .arg(&contract)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
+
+#[tokio::test]
+async fn test_validate_local_iceberg_table_random_sample() {
+    let names = vec![Some("ada"); 20];
+    let (_warehouse, table) = local_users_table(names).await;
+    let dir = TempDir::new().unwrap();
+    let contract = write_users_contract(&dir, &table.metadata_location);
+
+    let output = dce()
+        .env_remove("REST_CATALOG_URI")
+        .env_remove("ICEBERG_REST_URI")
+        .args(["validate", "--sample-size", "5", "--sampling", "random"])
+        .args(["--seed", "7"])
+        .arg(&contract)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // Iceberg samples are stratified by partition, not by column
+    let output = dce()
+        .env_remove("REST_CATALOG_URI")
+        .env_remove("ICEBERG_REST_URI")
+        .args(["validate", "--stratify-by", "name"])
+        .arg(&contract)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("stratified by table partition"),
+        "{}",
+        stderr
+    );
+}
+
+#[tokio::test]
+async fn test_validate_local_iceberg_table_at_snapshot() {
+    let (_warehouse, table) = local_users_table(vec![Some("ada"), Some("grace")]).await;
+    let dir = TempDir::new().unwrap();
+    let contract = write_users_contract(&dir, &table.metadata_location);
+    let snapshot = contracts_iceberg::IcebergValidator::new(table.config())
+        .await
+        .unwrap()
+        .current_snapshot()
+        .await
+        .unwrap()
+        .unwrap();
+
+    let output = dce()
+        .env_remove("REST_CATALOG_URI")
+        .env_remove("ICEBERG_REST_URI")
+        .args(["validate", "--format", "json", "--snapshot-id"])
+        .arg(snapshot.snapshot_id.to_string())
+        .arg(&contract)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["snapshot_id"], snapshot.snapshot_id);
+
+    // The table did not exist yet
+    dce()
+        .env_remove("REST_CATALOG_URI")
+        .env_remove("ICEBERG_REST_URI")
+        .args(["validate", "--as-of", "2000-01-01"])
+        .arg(&contract)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no snapshot as of"));
+}
+
+#[tokio::test]
+async fn test_drift_against_local_iceberg_table() {
+    let (_warehouse, table) = local_users_table(vec![Some("ada"), Some("grace")]).await;
+    let dir = TempDir::new().unwrap();
+    let contract = write_users_contract(&dir, &table.metadata_location);
+
+    // The table allows nulls in `name`, which the contract does not
+    let output = dce()
+        .env_remove("REST_CATALOG_URI")
+        .env_remove("ICEBERG_REST_URI")
+        .args(["drift", "--format", "json"])
+        .arg(&contract)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["breaking"], true);
+    assert_eq!(json["changes"][0]["kind"], "nullability_changed");
+    assert_eq!(json["changes"][0]["field"], "name");
+
+    let content = fs::read_to_string(&contract).unwrap().replace(
+        "type: string\n      nullable: false",
+        "type: string\n      nullable: true",
+    );
+    fs::write(&contract, content).unwrap();
+    dce()
+        .env("NO_COLOR", "1")
+        .env_remove("REST_CATALOG_URI")
+        .env_remove("ICEBERG_REST_URI")
+        .arg("drift")
+        .arg(&contract)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "The table schema matches the contract",
+        ));
+}
+
+#[tokio::test]
+async fn test_diff_table_suggests_and_applies_edits() {
+    let (_warehouse, table) = local_users_table(vec![Some("ada"), Some("grace")]).await;
+    let dir = TempDir::new().unwrap();
+    let contract = write_users_contract(&dir, &table.metadata_location);
+    let content = fs::read_to_string(&contract).unwrap().replace(
+        "      type: string\n      nullable: false\n",
+        "      type: string\n      nullable: false\n      constraints:\n        - type: pattern\n          \
+         regex: \"^[a-z]+$\"\n    - name: email\n      type: string\n      nullable: true\n",
+    );
+    fs::write(&contract, content).unwrap();
+
+    // The table allows nulls in `name` and has no `email` column
+    dce()
+        .env("NO_COLOR", "1")
+        .env_remove("REST_CATALOG_URI")
+        .env_remove("ICEBERG_REST_URI")
+        .arg("diff-table")
+        .arg(&contract)
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("Set nullable: true on 'name'"))
+        .stdout(predicate::str::contains("Remove field 'email'"))
+        .stdout(predicate::str::contains("--update"));
+
+    let output = dce()
+        .env_remove("REST_CATALOG_URI")
+        .env_remove("ICEBERG_REST_URI")
+        .args(["diff-table", "--update", "--format", "json"])
+        .arg(&contract)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["updated"], true);
+    assert_eq!(json["changes"].as_array().unwrap().len(), 2);
+
+    let updated = fs::read_to_string(&contract).unwrap();
+    assert!(!updated.contains("email"));
+    assert!(updated.contains("regex: ^[a-z]+$"));
+    dce()
+        .env_remove("REST_CATALOG_URI")
+        .env_remove("ICEBERG_REST_URI")
+        .arg("drift")
+        .arg(&contract)
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_drift_requires_an_iceberg_contract() {
+    let dir = TempDir::new().unwrap();
+    let contract = dir.path().join("events.yml");
+    fs::write(
+        &contract,
+        "version: \"1.0.0\"\nname: events\nowner: team\nschema:\n  format: parquet\n  location: /tmp/events.parquet\n  fields: []\n",
+    )
+    .unwrap();
+
+    dce()
+        .arg("drift")
+        .arg(&contract)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("only detected for Iceberg tables"));
+}
+
+#[tokio::test]
+async fn test_doctor_local_iceberg_table() {
+    let (_warehouse, table) = local_users_table(vec![Some("ada")]).await;
+    let dir = TempDir::new().unwrap();
+    let contract = write_users_contract(&dir, &table.metadata_location);
+
+    let output = dce()
+        .env_remove("REST_CATALOG_URI")
+        .env_remove("ICEBERG_REST_URI")
+        .args(["doctor", "--format", "json"])
+        .arg(&contract)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["healthy"], true);
+    let status = |check: &str| {
+        json["checks"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|c| c["check"] == check)
+            .map(|c| c["status"].as_str().unwrap().to_string())
+            .unwrap()
+    };
+    assert_eq!(status("catalog"), "ok");
+    assert_eq!(status("table"), "ok");
+    assert_eq!(status("object-store"), "ok");
+    assert_eq!(status("clock"), "skipped");
+}
+
+#[tokio::test]
+async fn test_doctor_reports_unresolved_secret() {
+    let (_warehouse, table) = local_users_table(vec![Some("ada")]).await;
+    let dir = TempDir::new().unwrap();
+    let contract = write_users_contract(&dir, &table.metadata_location);
+    let content = fs::read_to_string(&contract).unwrap().replace(
+        "    properties:\n",
+        "    properties:\n      token: ${env:DCE_DOCTOR_UNSET_TOKEN}\n",
+    );
+    fs::write(&contract, content).unwrap();
+
+    dce()
+        .env("NO_COLOR", "1")
+        .env_remove("REST_CATALOG_URI")
+        .env_remove("ICEBERG_REST_URI")
+        .env_remove("DCE_DOCTOR_UNSET_TOKEN")
+        .arg("doctor")
+        .arg(&contract)
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("DCE_DOCTOR_UNSET_TOKEN"))
+        .stdout(predicate::str::contains("Export the variable"));
+}
+
+#[tokio::test]
+async fn test_access_export_and_drift() {
+    let (warehouse, table) = local_users_table(vec![Some("ada"), Some("grace")]).await;
+    let dir = TempDir::new().unwrap();
+    let write_contract = |metadata_location: &str| {
+        let contract = write_users_contract(&dir, metadata_location);
+        let content = fs::read_to_string(&contract).unwrap().replace(
+            "type: string\n      nullable: false",
+            "type: string\n      nullable: true\n      access:\n        classification: pii\n        \
+             allowed_roles: [support]",
+        );
+        fs::write(&contract, content).unwrap();
+        contract
+    };
+    let drift = |contract: &std::path::Path| {
+        let output = dce()
+            .env_remove("REST_CATALOG_URI")
+            .env_remove("ICEBERG_REST_URI")
+            .args(["drift", "--format", "json"])
+            .arg(contract)
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+        (output.status.success(), json)
+    };
+
+    // Without access properties on the table there is nothing to compare
+    let contract = write_contract(&table.metadata_location);
+    let (success, json) = drift(&contract);
+    assert!(success);
+    assert!(json["access"].is_null());
+
+    let output = dce()
+        .args(["access", "--format", "properties"])
+        .arg(&contract)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let properties: Vec<(String, String)> = String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect();
+    assert_eq!(properties.len(), 2);
+
+    let table = warehouse.set_properties(&table, properties).await.unwrap();
+    let contract = write_contract(&table.metadata_location);
+    let (success, json) = drift(&contract);
+    assert!(success);
+    assert_eq!(json["access"]["drifted"], false);
+
+    let table = warehouse
+        .set_properties(
+            &table,
+            [(
+                "dce.access.name.classification".to_string(),
+                "public".to_string(),
+            )],
+        )
+        .await
+        .unwrap();
+    let contract = write_contract(&table.metadata_location);
+    let (success, json) = drift(&contract);
+    assert!(!success);
+    assert_eq!(json["breaking"], false);
+    assert_eq!(json["access"]["breaking"], true);
+    assert_eq!(
+        json["access"]["changes"][0]["kind"],
+        "classification_changed"
+    );
+
+    dce()
+        .args(["access", "--format", "json"])
+        .arg(&contract)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"classification\": \"pii\""));
+}
+
+#[tokio::test]
+async fn test_validate_dump_sample() {
+    let (_warehouse, table) = local_users_table(vec![Some("ada"), Some("grace")]).await;
+    let dir = TempDir::new().unwrap();
+    let contract = write_users_contract(&dir, &table.metadata_location);
+    let sample = dir.path().join("sample.json");
+
+    dce()
+        .env_remove("REST_CATALOG_URI")
+        .env_remove("ICEBERG_REST_URI")
+        .arg("validate")
+        .arg(&contract)
+        .arg("--dump-sample")
+        .arg(&sample)
+        .assert()
+        .success();
+
+    let rows: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&sample).unwrap()).unwrap();
+    assert_eq!(rows.as_array().unwrap().len(), 2);
+    assert_eq!(rows[1]["name"], serde_json::json!({"string": "grace"}));
+}
+
+#[tokio::test]
+async fn test_monitor_validates_new_snapshots() {
+    use arrow_array::{Int64Array, RecordBatch, StringArray};
+    use arrow_schema::{DataType, Field, Schema};
+    use std::sync::Arc;
+
+    let (warehouse, table) = local_users_table(vec![Some("ada"), Some("grace")]).await;
+    let dir = TempDir::new().unwrap();
+    let state = dir.path().join("state");
+    let notified = dir.path().join("notified.txt");
+    let monitor = |contract: &std::path::Path| {
+        dce()
+            .env_remove("REST_CATALOG_URI")
+            .env_remove("ICEBERG_REST_URI")
+            .arg("monitor")
+            .arg(contract)
+            .arg("--once")
+            .arg("--state-dir")
+            .arg(&state)
+            .arg("--notify")
+            .arg(format!(
+                "echo \"$DCE_STATUS $DCE_ERRORS $DCE_REPORT\" >> '{}'",
+                notified.display()
+            ))
+            .assert()
+    };
+
+    // The first poll validates the whole table and records the snapshot
+    let contract = write_users_contract(&dir, &table.metadata_location);
+    monitor(&contract)
+        .success()
+        .stdout(predicate::str::contains("validating the table"));
+    let cursor: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(state.join("state.json")).unwrap()).unwrap();
+    assert_eq!(cursor["status"], "passed");
+    assert!(!notified.exists());
+
+    // Nothing was committed, so nothing is validated
+    monitor(&contract)
+        .success()
+        .stdout(predicate::str::contains("validating").not());
+
+    // Only the appended row is validated, and its null name fails the contract
+    let batch = RecordBatch::try_new(
+        Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+        ])),
+        vec![
+            Arc::new(Int64Array::from(vec![3])),
+            Arc::new(StringArray::from(vec![None::<&str>])),
+        ],
+    )
+    .unwrap();
+    let appended = warehouse.append(&table, &batch).await.unwrap();
+    let contract = write_users_contract(&dir, &appended.metadata_location);
+    monitor(&contract)
+        .failure()
+        .stdout(predicate::str::contains(format!(
+            "added since snapshot {}",
+            cursor["snapshot_id"]
+        )));
+
+    let notification = fs::read_to_string(&notified).unwrap();
+    let [status, errors, report] = notification.split_whitespace().collect::<Vec<_>>()[..] else {
+        panic!("unexpected notification: {notification}");
+    };
+    assert_eq!((status, errors), ("failed", "1"));
+    let report: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(report).unwrap()).unwrap();
+    assert_eq!(report["stats"]["records_validated"], 1);
+}
+
+#[tokio::test]
+async fn test_wap_audits_snapshot() {
+    use arrow_array::{Int64Array, RecordBatch, StringArray};
+    use arrow_schema::{DataType, Field, Schema};
+    use contracts_iceberg::IcebergValidator;
+    use std::sync::Arc;
+
+    let (warehouse, table) = local_users_table(vec![Some("ada"), Some("grace")]).await;
+    let first = IcebergValidator::new(table.config())
+        .await
+        .unwrap()
+        .current_snapshot()
+        .await
+        .unwrap()
+        .unwrap();
+    let batch = RecordBatch::try_new(
+        Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+        ])),
+        vec![
+            Arc::new(Int64Array::from(vec![3])),
+            Arc::new(StringArray::from(vec![None::<&str>])),
+        ],
+    )
+    .unwrap();
+    let appended = warehouse.append(&table, &batch).await.unwrap();
+    let dir = TempDir::new().unwrap();
+    let contract = write_users_contract(&dir, &appended.metadata_location);
+
+    // The first snapshot predates the null name
+    dce()
+        .env_remove("REST_CATALOG_URI")
+        .env_remove("ICEBERG_REST_URI")
+        .arg("wap")
+        .arg(&contract)
+        .arg("--snapshot-id")
+        .arg(first.snapshot_id.to_string())
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("PASSED").and(predicate::str::contains("already on main")),
+        );
+
+    // The appended snapshot is main: it fails, and there is nothing to publish
+    let appended = IcebergValidator::new(appended.config())
+        .await
+        .unwrap()
+        .current_snapshot()
+        .await
+        .unwrap()
+        .unwrap();
+    let output = dce()
+        .env_remove("REST_CATALOG_URI")
+        .env_remove("ICEBERG_REST_URI")
+        .arg("wap")
+        .arg(&contract)
+        .arg("--snapshot-id")
+        .arg(appended.snapshot_id.to_string())
+        .arg("--format")
+        .arg("json")
+        .assert()
+        .failure()
+        .get_output()
+        .stdout
+        .clone();
+    let stdout = String::from_utf8(output).unwrap();
+    let report: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(report["passed"], false);
+    assert_eq!(report["wap"]["snapshot_id"], appended.snapshot_id);
+    assert_eq!(report["wap"]["publication"], "up_to_date");
+    assert_eq!(report["wap"]["publish_sql"], serde_json::Value::Null);
+}
+
+#[test]
+fn test_wap_needs_staged_reference() {
+    dce()
+        .arg("wap")
+        .arg("users.yml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--branch"));
+}
+
+#[test]
+fn test_monitor_rejects_non_iceberg_contract() {
+    let dir = TempDir::new().unwrap();
+    let contract = dir.path().join("events.yml");
+    fs::write(
+        &contract,
+        "version: \"1.0.0\"\nname: events\nowner: test-team\nschema:\n  format: csv\n  \
+         location: events.csv\n  fields:\n    - name: id\n      type: int64\n      \
+         nullable: false\n",
+    )
+    .unwrap();
+
+    dce()
+        .arg("monitor")
+        .arg(&contract)
+        .arg("--once")
+        .arg("--state-dir")
+        .arg(dir.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not an Iceberg table"));
+}
+
+#[test]
+fn test_validate_dump_sample_rejects_directory() {
+    let dir = TempDir::new().unwrap();
+
+    dce()
+        .arg("validate")
+        .arg(dir.path())
+        .arg("--dump-sample")
+        .arg(dir.path().join("sample.json"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--dump-sample"));
+}
+
+// ============================================================================
+// --output report file tests
+// ============================================================================
+
+#[test]
+fn test_validate_writes_junit_report() {
+    let dir = TempDir::new().unwrap();
+    let report = dir.path().join("junit.xml");
+
+    dce()
+        .arg("validate")
+        .arg("--schema-only")
+        .arg(fixture_path("simple_contract.yml"))
+        .arg("--output")
+        .arg(&report)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("PASSED"))
+        .stdout(predicate::str::contains("Check Results").not());
+
+    let xml = fs::read_to_string(&report).unwrap();
+    assert!(xml.starts_with("<?xml"));
+    assert!(xml.contains("<testsuite name=\"simple_test\""));
+    assert!(xml.contains("failures=\"0\""));
+}
+
+#[test]
+fn test_check_writes_json_report() {
+    let dir = TempDir::new().unwrap();
+    let report = dir.path().join("report.json");
+
+    dce()
+        .arg("check")
+        .arg(fixture_path("simple_contract.yml"))
+        .arg("-o")
+        .arg(&report)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Contract Summary").not());
+
+    let json: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&report).unwrap()).unwrap();
+    assert_eq!(json["passed"], true);
+    assert!(json["stats"]["fields_checked"].as_u64().unwrap() > 0);
+    assert!(json["provenance"]["path"].is_string());
+}
+
+#[test]
+fn test_diff_writes_json_report() {
+    let dir = TempDir::new().unwrap();
+    let new = dir.path().join("simple_v2.yml");
+    let report = dir.path().join("diff.json");
+    let original = fs::read_to_string(fixture_path("simple_contract.yml")).unwrap();
+    fs::write(
+        &new,
+        original.replace(
+            "type: int\n      nullable: true",
+            "type: int\n      nullable: false",
+        ) + "    - name: note\n      type: string\n      nullable: true\n",
+    )
+    .unwrap();
+
+    dce()
+        .arg("diff")
+        .arg(fixture_path("simple_contract.yml"))
+        .arg(&new)
+        .arg("--output")
+        .arg(&report)
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("FAILED"))
+        .stdout(predicate::str::contains("no longer nullable").not());
+
+    let json: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&report).unwrap()).unwrap();
+    assert_eq!(json["passed"], false);
+    assert_eq!(json["summary"]["error_count"], 1);
+    assert_eq!(json["summary"]["warning_count"], 1);
+    assert_eq!(json["summary"]["info_count"], 1);
+    let results = json["results"].as_array().unwrap();
+    let breaking = results.iter().find(|r| r["severity"] == "error").unwrap();
+    assert_eq!(breaking["code"], "DCE0017");
+    assert_eq!(breaking["field"], "value");
+    assert!(
+        results
+            .iter()
+            .any(|r| r["code"] == "DCE0006" && r["severity"] == "warning")
+    );
+
+    // Identical contracts give a passing JUnit report
+    let junit = dir.path().join("diff.xml");
+    dce()
+        .arg("diff")
+        .arg(fixture_path("simple_contract.yml"))
+        .arg(fixture_path("simple_contract.yml"))
+        .arg("-o")
+        .arg(&junit)
+        .assert()
+        .success();
+    let xml = fs::read_to_string(&junit).unwrap();
+    assert!(xml.contains("failures=\"0\""));
+}
+
+#[test]
+fn test_check_json_prints_normalized_contract_and_findings() {
+    let dir = TempDir::new().unwrap();
+    let contract = dir.path().join("contract.yml");
+    fs::write(
+        &contract,
+        r#"version: "1.0.0"
+name: lint_test
+owner: test-team
+schema:
+  format: iceberg
+  location: s3://test/lint
+  connection:
+    uri: http://localhost:8181
+  fields:
+    - name: id
+      type: string
+      nullable: false
+quality_checks:
+  completeness:
+    threshold: 0.9
+    fields: [email]
+"#,
+    )
+    .unwrap();
+
+    let output = dce()
+        .arg("check")
+        .arg(&contract)
+        .arg("--format")
+        .arg("json")
+        .assert()
+        .code(1)
+        .get_output()
+        .stdout
+        .clone();
+
+    let stdout = String::from_utf8(output).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["valid"], false);
+    assert_eq!(json["contract"]["schema"]["connection"]["type"], "rest");
+    assert_eq!(json["findings"][0]["severity"], "error");
+    assert_eq!(json["findings"][0]["rule"], "unknown-field");
+    assert_eq!(json["findings"][1]["rule"], "missing-description");
+
+    dce()
+        .arg("check")
+        .arg(fixture_path("contract_with_quality.yml"))
+        .arg("--format")
+        .arg("json")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"valid\": true"));
+}
+
+#[test]
+fn test_check_json_materializes_defaults() {
+    let dir = TempDir::new().unwrap();
+    let contract = dir.path().join("contract.yml");
+    fs::write(
+        &contract,
+        r#"version: "1.0.0"
+name: defaults_test
+owner: test-team
+description: Orders
+schema:
+  format: iceberg
+  location: s3://test/defaults
+  fields:
+    - name: id
+      type: string
+      nullable: false
+    - name: parent_id
+      type: string
+      nullable: true
+quality_checks:
+  completeness:
+    threshold: 0.9
+    fields: [id]
+  uniqueness:
+    fields: [id]
+    approximate: {}
+  referential:
+    - field: parent_id
+      references: defaults_test.id
+"#,
+    )
+    .unwrap();
+
+    let output = dce()
+        .arg("check")
+        .arg(&contract)
+        .args(["--format", "json"])
+        .output()
+        .unwrap();
+    let json: serde_json::Value =
+        serde_json::from_str(&String::from_utf8(output.stdout).unwrap()).unwrap();
+    let checks = &json["contract"]["quality_checks"];
+    assert_eq!(checks["on_empty"], "warn");
+    assert_eq!(checks["uniqueness"]["scope"], "global");
+    assert_eq!(checks["uniqueness"]["approximate"]["error_tolerance"], 0.01);
+    assert_eq!(checks["referential"][0]["threshold"], 1.0);
+
+    // Unset values without a fixed default are left out, never null
+    let completeness = checks["completeness"].as_object().unwrap();
+    assert!(!completeness.contains_key("min_sample_size"));
+    assert!(!completeness.contains_key("severity"));
+    assert!(!checks.as_object().unwrap().contains_key("custom_checks"));
+    assert!(!json["contract"].as_object().unwrap().contains_key("sla"));
+}
+
+#[test]
+fn test_check_validates_extensions_against_schema() {
+    let dir = TempDir::new().unwrap();
+    let contract = dir.path().join("contract.yml");
+    fs::write(
+        &contract,
+        r#"version: "1.0.0"
+name: invoices
+owner: finance
+description: Issued invoices
+schema:
+  format: iceberg
+  location: s3://test/invoices
+  fields:
+    - name: id
+      type: string
+      nullable: false
+x-extensions:
+  cost_center: FIN-042
+  data_tier: platinum
+"#,
+    )
+    .unwrap();
+    let schema = dir.path().join("extensions.schema.json");
+    fs::write(
+        &schema,
+        r#"{
+  "type": "object",
+  "properties": { "data_tier": { "enum": ["gold", "silver", "bronze"] } },
+  "required": ["cost_center", "data_tier"]
+}"#,
+    )
+    .unwrap();
+
+    // Without a schema the block is carried along and never checked
+    let output = dce()
+        .arg("check")
+        .arg(&contract)
+        .arg("--format")
+        .arg("json")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let stdout = String::from_utf8(output).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["contract"]["x-extensions"]["cost_center"], "FIN-042");
+
+    let output = dce()
+        .arg("check")
+        .arg(&contract)
+        .arg("--format")
+        .arg("json")
+        .arg("--extensions-schema")
+        .arg(&schema)
+        .assert()
+        .code(1)
+        .get_output()
+        .stdout
+        .clone();
+    let stdout = String::from_utf8(output).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["valid"], false);
+    assert_eq!(json["findings"][0]["rule"], "extensions-schema");
+    assert_eq!(json["findings"][0]["path"], "x-extensions.data_tier");
+
+    dce()
+        .arg("check")
+        .arg(&contract)
+        .arg("--extensions-schema")
+        .arg(&schema)
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("[DCE0009] x-extensions.data_tier"));
+}
+
+#[test]
+fn test_output_requires_known_extension() {
+    let dir = TempDir::new().unwrap();
+
+    dce()
+        .arg("validate")
+        .arg("--schema-only")
+        .arg(fixture_path("simple_contract.yml"))
+        .arg("--output")
+        .arg(dir.path().join("report.txt"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(".json, .html or .xml"));
+}
+
+#[test]
+fn test_validate_contract_from_registry() {
+    let registry = TempDir::new().unwrap();
+    let published = registry.path().join("simple_test");
+    fs::create_dir_all(&published).unwrap();
+    fs::copy(
+        fixture_path("simple_contract.yml"),
+        published.join("1.0.0.yml"),
+    )
+    .unwrap();
+
+    dce()
+        .arg("validate")
+        .arg("--schema-only")
+        .arg("--contract-from-registry")
+        .arg("simple_test@latest")
+        .arg("--registry")
+        .arg(registry.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("simple_test@latest"))
+        .stdout(predicate::str::contains("1.0.0.yml"));
+
+    dce()
+        .arg("validate")
+        .arg("--schema-only")
+        .arg("--contract-from-registry")
+        .arg("simple_test@2.0.0")
+        .env("DCE_REGISTRY", registry.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not found in registry"));
+}
+
+#[test]
+fn test_validate_contract_from_registry_requires_registry() {
+    dce()
+        .arg("validate")
+        .arg("--contract-from-registry")
+        .arg("simple_test@latest")
+        .env_remove("DCE_REGISTRY")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("DCE_REGISTRY"));
+
+    dce()
+        .arg("validate")
+        .arg(fixture_path("simple_contract.yml"))
+        .arg("--contract-from-registry")
+        .arg("simple_test@latest")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn test_update_pins_registry_contracts() {
+    let registry = TempDir::new().unwrap();
+    let workspace = TempDir::new().unwrap();
+    let published = registry.path().join("simple_test");
+    fs::create_dir_all(&published).unwrap();
+    fs::copy(
+        fixture_path("simple_contract.yml"),
+        published.join("1.0.0.yml"),
+    )
+    .unwrap();
+
+    dce()
+        .current_dir(workspace.path())
+        .arg("update")
+        .arg("simple_test@latest")
+        .arg("--registry")
+        .arg(registry.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Locked simple_test@latest to 1.0.0",
+        ));
+
+    let lock = fs::read_to_string(workspace.path().join("dce.lock")).unwrap();
+    assert!(lock.contains("[contracts.\"simple_test@latest\"]"));
+    assert!(lock.contains("version = \"1.0.0\""));
+
+    // Editing the published file breaks the pin until the lock is refreshed
+    let edited = fs::read_to_string(published.join("1.0.0.yml"))
+        .unwrap()
+        .replace("test-team", "other-team");
+    fs::write(published.join("1.0.0.yml"), edited).unwrap();
+
+    dce()
+        .current_dir(workspace.path())
+        .arg("validate")
+        .arg("--schema-only")
+        .arg("--contract-from-registry")
+        .arg("simple_test@latest")
+        .arg("--registry")
+        .arg(registry.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Lockfile mismatch"));
+
+    dce()
+        .current_dir(workspace.path())
+        .arg("update")
+        .arg("--registry")
+        .arg(registry.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "simple_test@latest 1.0.0 changed in the registry",
+        ));
+
+    dce()
+        .current_dir(workspace.path())
+        .arg("validate")
+        .arg("--schema-only")
+        .arg("--contract-from-registry")
+        .arg("simple_test@latest")
+        .arg("--registry")
+        .arg(registry.path())
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_validate_catalog_limits_are_checked() {
+    dce()
+        .arg("validate")
+        .arg("--schema-only")
+        .arg(fixture_path("simple_contract.yml"))
+        .arg("--max-catalog-requests")
+        .arg("4")
+        .arg("--catalog-rate-limit")
+        .arg("2.5")
+        .assert()
+        .success();
+
+    dce()
+        .arg("validate")
+        .arg(fixture_path("simple_contract.yml"))
+        .arg("--max-catalog-requests")
+        .arg("0")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--max-catalog-requests"));
+
+    dce()
+        .arg("validate")
+        .arg(fixture_path("simple_contract.yml"))
+        .arg("--catalog-rate-limit=0")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--catalog-rate-limit"));
+}
+
+// ============================================================================
+// Error code tests
+// ============================================================================
+
+#[test]
+fn test_validate_json_results_carry_error_codes() {
+    let dir = TempDir::new().unwrap();
+    let data = dir.path().join("people.csv");
+    fs::write(&data, "id,age\na,10\n,200\n").unwrap();
+    let contract = dir.path().join("people.yml");
+    fs::write(
+        &contract,
+        format!(
+            "version: \"1.0.0\"\nname: people\nowner: team\nschema:\n  format: csv\n  location: {}\n  fields:\n    - name: id\n      type: string\n      nullable: false\n    - name: age\n      type: int64\n      nullable: true\n      constraints:\n        - type: range\n          min: 0\n          max: 120\n",
+            data.display()
+        ),
+    )
+    .unwrap();
+
+    let output = dce()
+        .arg("validate")
+        .arg("--format")
+        .arg("json")
+        .arg(&contract)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let report: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    let codes: Vec<&str> = report["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r["code"].as_str().unwrap())
+        .collect();
+    assert_eq!(codes, vec!["DCE0102", "DCE0203"]);
+    assert_eq!(report["results"][0]["field"], "id");
+    assert_eq!(report["results"][0]["check_kind"], "schema");
+    assert_eq!(report["results"][1]["field"], "age");
+    assert_eq!(report["results"][1]["check_kind"], "constraint");
+    assert!(
+        report["errors"][0]
+            .as_str()
+            .unwrap()
+            .starts_with("[DCE0102] ")
+    );
+}
+
+#[test]
+fn test_explain_error_code() {
+    dce()
+        .arg("explain")
+        .arg("DCE0102")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("NullConstraintViolation"))
+        .stdout(predicate::str::contains("nullable: true"));
+
+    dce()
+        .arg("explain")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("DCE0102"))
+        .stdout(predicate::str::contains("DCE2002"));
+
+    dce()
+        .arg("explain")
+        .arg("DCE9999")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("DCE9999"));
+}
+
+#[test]
+fn test_validate_json_reports_errors_that_stop_validation() {
+    let output = dce()
+        .arg("validate")
+        .arg("--schema-only")
+        .arg("--format")
+        .arg("json")
+        .arg(fixture_path("invalid_contract.yml"))
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let report: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(report["passed"], false);
+    assert_eq!(report["results"][0]["code"], "DCE1001");
+    let message = report["results"][0]["message"].as_str().unwrap();
+    // The parser's cause is kept after the CLI's own context
+    assert!(message.starts_with("Failed to parse contract file"));
+    assert!(message.contains("missing field `fields`"));
+}
+
+#[test]
+fn test_validate_on_empty_policy() {
+    let dir = TempDir::new().unwrap();
+    let data = dir.path().join("empty.csv");
+    fs::write(&data, "id\n").unwrap();
+    let contract = dir.path().join("empty.yml");
+    fs::write(
+        &contract,
+        format!(
+            "version: \"1.0.0\"\nname: empty\nowner: team\nschema:\n  format: csv\n  location: {}\n  fields:\n    - name: id\n      type: string\n      nullable: false\n",
+            data.display()
+        ),
+    )
+    .unwrap();
+
+    dce()
+        .arg("validate")
+        .arg(&contract)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("DCE0303"));
+
+    dce()
+        .arg("validate")
+        .arg(&contract)
+        .arg("--on-empty")
+        .arg("fail")
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("DCE0303"));
+
+    dce()
+        .arg("validate")
+        .arg(&contract)
+        .arg("--on-empty")
+        .arg("maybe")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("pass, warn or fail"));
+}
+
+#[test]
+fn test_validate_reports_undersampled_checks_as_inconclusive() {
+    let dir = TempDir::new().unwrap();
+    let data = dir.path().join("small.csv");
+    fs::write(&data, "id\n1\n\n3\n").unwrap();
+    let contract = dir.path().join("small.yml");
+    fs::write(
+        &contract,
+        format!(
+            "version: \"1.0.0\"\nname: small\nowner: team\nschema:\n  format: csv\n  location: {}\n  fields:\n    - name: id\n      type: string\n      nullable: true\nquality_checks:\n  completeness:\n    threshold: 0.99\n    fields: [id]\n    min_sample_size: 100\n",
+            data.display()
+        ),
+    )
+    .unwrap();
+
+    let output = dce()
+        .arg("validate")
+        .arg(&contract)
+        .arg("--format")
+        .arg("json")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["status"], "inconclusive");
+    assert_eq!(json["summary"]["inconclusive_count"], 1);
+    assert_eq!(json["results"][0]["severity"], "inconclusive");
+    assert_eq!(json["results"][0]["code"], "DCE0304");
+}
+
+#[test]
+fn test_validate_multi_dataset_contract() {
+    let dir = TempDir::new().unwrap();
+    let orders = dir.path().join("orders.csv");
+    fs::write(&orders, "id\n1\n2\n").unwrap();
+    let items = dir.path().join("items.csv");
+    fs::write(&items, "order_id,sku\n1,a\n,b\n").unwrap();
+    let contract = dir.path().join("orders.yml");
+    fs::write(
+        &contract,
+        format!(
+            "version: \"1.0.0\"\nname: orders\nowner: team\nschema:\n  format: csv\n  location: {}\n  fields:\n    - name: id\n      type: int64\n      nullable: false\ndatasets:\n  items:\n    schema:\n      format: csv\n      location: {}\n      fields:\n        - name: order_id\n          type: int64\n          nullable: false\n",
+            orders.display(),
+            items.display()
+        ),
+    )
+    .unwrap();
+
+    let output = dce()
+        .arg("validate")
+        .arg("--format")
+        .arg("json")
+        .arg(&contract)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let report: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(report["results"][0]["code"], "DCE0309");
+    assert_eq!(report["datasets"][0]["name"], "items");
+    assert_eq!(report["datasets"][0]["passed"], false);
+    assert_eq!(report["datasets"][0]["results"][0]["code"], "DCE0102");
+
+    dce()
+        .arg("validate")
+        .arg(&contract)
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("Validating dataset items"))
+        .stdout(predicate::str::contains("Datasets"));
+}
+
+#[test]
+fn test_validate_referential_check_across_datasets() {
+    let dir = TempDir::new().unwrap();
+    let orders = dir.path().join("orders.csv");
+    fs::write(&orders, "id,customer_id\n1,10\n2,11\n3,99\n").unwrap();
+    let customers = dir.path().join("customers.csv");
+    fs::write(&customers, "id\n10\n11\n").unwrap();
+    let contract = dir.path().join("orders.yml");
+    fs::write(
+        &contract,
+        format!(
+            "version: \"1.0.0\"\nname: orders\nowner: team\nschema:\n  format: csv\n  location: {}\n  fields:\n    - name: id\n      type: int64\n      nullable: false\n    - name: customer_id\n      type: int64\n      nullable: true\nquality_checks:\n  referential:\n    - field: customer_id\n      references: customers.id\ndatasets:\n  customers:\n    schema:\n      format: csv\n      location: {}\n      fields:\n        - name: id\n          type: int64\n          nullable: false\n",
+            orders.display(),
+            customers.display()
+        ),
+    )
+    .unwrap();
+
+    let output = dce()
+        .arg("validate")
+        .arg("--strict")
+        .arg("--format")
+        .arg("json")
+        .arg(&contract)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let report: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(report["results"][0]["code"], "DCE0310");
+    assert_eq!(report["results"][0]["field"], "customer_id");
+    assert!(
+        report["results"][0]["message"]
+            .as_str()
+            .unwrap()
+            .contains("1 of 3 value(s) not found in customers.id (first: '99' at row 2)")
+    );
+    assert_eq!(report["datasets"][0]["passed"], true);
+}
+
+/// Serves one HTTP response per accepted connection, in order, and returns
+/// the server's base URL and the request bodies it received.
+fn hook_server(
+    responses: Vec<&'static str>,
+) -> (String, std::thread::JoinHandle<Vec<serde_json::Value>>) {
+    use std::io::{Read, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let handle = std::thread::spawn(move || {
+        let mut bodies = Vec::new();
+        for response in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0u8; 4096];
+            let header_end = loop {
+                let n = stream.read(&mut buffer).unwrap();
+                request.extend_from_slice(&buffer[..n]);
+                if let Some(end) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                    break end + 4;
+                }
+            };
+            let headers = String::from_utf8_lossy(&request[..header_end]).to_lowercase();
+            let length: usize = headers
+                .lines()
+                .find_map(|line| line.strip_prefix("content-length:"))
+                .map_or(0, |value| value.trim().parse().unwrap());
+            while request.len() < header_end + length {
+                let n = stream.read(&mut buffer).unwrap();
+                request.extend_from_slice(&buffer[..n]);
+            }
+            bodies.push(serde_json::from_slice(&request[header_end..]).unwrap());
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                response.len(),
+                response
+            )
+            .unwrap();
+        }
+        bodies
+    });
+    (url, handle)
+}
+
+#[test]
+fn test_validate_calls_pre_and_post_hooks() {
+    let dir = TempDir::new().unwrap();
+    let data = dir.path().join("orders.csv");
+    fs::write(&data, "id\n1\n2\n").unwrap();
+    let contract = dir.path().join("orders.yml");
+    fs::write(
+        &contract,
+        format!(
+            "version: \"1.0.0\"\nname: orders\nowner: team\nschema:\n  format: csv\n  location: {}\n  fields:\n    - name: id\n      type: int64\n      nullable: false\n",
+            data.display()
+        ),
+    )
+    .unwrap();
+
+    let (url, server) = hook_server(vec![
+        r#"{"context": {"metadata": {"ticket": "OPS-7"}}}"#,
+        "",
+        r#"{"allow": false, "reason": "orders are frozen"}"#,
+    ]);
+    fs::write(
+        dir.path().join("dce.toml"),
+        format!("[[hooks.pre]]\nurl = \"{url}/pre\"\n\n[[hooks.post]]\nurl = \"{url}/post\"\n"),
+    )
+    .unwrap();
+
+    dce()
+        .current_dir(dir.path())
+        .arg("validate")
+        .arg(&contract)
+        .assert()
+        .success();
+    // The pre-validation hook vetoes the second run
+    dce()
+        .current_dir(dir.path())
+        .arg("validate")
+        .arg(&contract)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Validation of orders vetoed by hook",
+        ))
+        .stderr(predicate::str::contains("orders are frozen"));
+
+    let bodies = server.join().unwrap();
+    assert_eq!(bodies[0]["event"], "pre_validation");
+    assert_eq!(bodies[0]["contract"]["name"], "orders");
+    assert_eq!(bodies[1]["event"], "post_validation");
+    assert_eq!(bodies[1]["context"]["metadata"]["ticket"], "OPS-7");
+    assert_eq!(bodies[1]["report"]["passed"], true);
+    assert_eq!(bodies[2]["event"], "pre_validation");
+}
+
+#[test]
+fn test_validate_stores_reports_in_sinks() {
+    let dir = TempDir::new().unwrap();
+    let data = dir.path().join("orders.csv");
+    fs::write(&data, "id\n1\n2\n").unwrap();
+    let contract = dir.path().join("orders.yml");
+    fs::write(
+        &contract,
+        format!(
+            "version: \"1.0.0\"\nname: orders\nowner: team\nschema:\n  format: csv\n  location: {}\n  fields:\n    - name: id\n      type: int64\n      nullable: false\n",
+            data.display()
+        ),
+    )
+    .unwrap();
+
+    let (url, server) = hook_server(vec![""]);
+    fs::write(
+        dir.path().join("dce.toml"),
+        format!(
+            "[[sinks]]\ntype = \"file\"\npath = \"reports/{{contract}}/{{version}}.json\"\n\n\
+             [[sinks]]\ntype = \"postgrest\"\nurl = \"{url}/validation_reports\"\n"
+        ),
+    )
+    .unwrap();
+
+    dce()
+        .current_dir(dir.path())
+        .arg("validate")
+        .arg(&contract)
+        .assert()
+        .success();
+
+    let stored = fs::read_to_string(dir.path().join("reports/orders/1.0.0.json")).unwrap();
+    let stored: serde_json::Value = serde_json::from_str(&stored).unwrap();
+    assert_eq!(stored["passed"], true);
+
+    let rows = server.join().unwrap();
+    assert_eq!(rows[0]["contract"], "orders");
+    assert_eq!(rows[0]["status"], "passed");
+    assert_eq!(rows[0]["errors"], 0);
+    assert_eq!(rows[0]["report"]["passed"], true);
+}
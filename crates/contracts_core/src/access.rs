@@ -0,0 +1,310 @@
+//! Drift between declared and actual column access metadata.
+//!
+//! A contract declares who may read each field in its `access:` blocks;
+//! catalogs and governance tools keep their own column tags. [`AccessDrift`]
+//! lines the two up, so a column tagged `public` in the catalog while the
+//! contract classifies it as `pii` is caught before it is shared.
+
+use crate::{FieldAccess, Schema};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Returns the access metadata declared by the fields of `schema`, by field
+/// name. Fields without an `access:` block, or with an empty one, are left
+/// out.
+pub fn declared_access(schema: &Schema) -> BTreeMap<String, FieldAccess> {
+    schema
+        .fields
+        .iter()
+        .filter_map(|field| {
+            let access = field.access.as_ref().filter(|access| !access.is_empty())?;
+            Some((field.name.clone(), access.clone()))
+        })
+        .collect()
+}
+
+/// A field on which a contract and the catalog disagree about access.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AccessChange {
+    /// The contract declares access metadata the catalog does not carry
+    Untagged {
+        /// Field name
+        field: String,
+        /// Access declared by the contract
+        declared: FieldAccess,
+    },
+
+    /// The catalog carries access metadata the contract does not declare
+    Undeclared {
+        /// Column name
+        field: String,
+        /// Access tagged in the catalog
+        actual: FieldAccess,
+    },
+
+    /// The catalog classifies the column differently
+    ClassificationChanged {
+        /// Field name
+        field: String,
+        /// Classification declared by the contract
+        contract: Option<String>,
+        /// Classification tagged in the catalog
+        catalog: Option<String>,
+    },
+
+    /// The catalog allows other roles to read the column
+    RolesChanged {
+        /// Field name
+        field: String,
+        /// Roles declared by the contract, sorted
+        contract: Vec<String>,
+        /// Roles tagged in the catalog, sorted
+        catalog: Vec<String>,
+    },
+}
+
+impl AccessChange {
+    /// Returns the field the change applies to.
+    pub fn field(&self) -> &str {
+        match self {
+            Self::Untagged { field, .. }
+            | Self::Undeclared { field, .. }
+            | Self::ClassificationChanged { field, .. }
+            | Self::RolesChanged { field, .. } => field,
+        }
+    }
+
+    /// Returns true if the catalog does not enforce what the contract
+    /// declares. Tags on columns the contract says nothing about are not.
+    pub fn is_breaking(&self) -> bool {
+        !matches!(self, Self::Undeclared { .. })
+    }
+}
+
+impl fmt::Display for AccessChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let or_none = |value: &Option<String>| value.clone().unwrap_or_else(|| "none".to_string());
+        let roles = |roles: &[String]| {
+            if roles.is_empty() {
+                "any role".to_string()
+            } else {
+                roles.join(", ")
+            }
+        };
+        match self {
+            Self::Untagged { field, .. } => write!(
+                f,
+                "field '{}' declares access metadata the catalog does not carry",
+                field
+            ),
+            Self::Undeclared { field, .. } => write!(
+                f,
+                "column '{}' is tagged in the catalog but declares no access",
+                field
+            ),
+            Self::ClassificationChanged {
+                field,
+                contract,
+                catalog,
+            } => write!(
+                f,
+                "field '{}' is classified {} in the catalog, {} in the contract",
+                field,
+                or_none(catalog),
+                or_none(contract)
+            ),
+            Self::RolesChanged {
+                field,
+                contract,
+                catalog,
+            } => write!(
+                f,
+                "field '{}' is readable by {} in the catalog, {} in the contract",
+                field,
+                roles(catalog),
+                roles(contract)
+            ),
+        }
+    }
+}
+
+/// The differences between the access metadata of a contract and of the
+/// catalog.
+///
+/// # Example
+///
+/// ```rust
+/// use contracts_core::{
+///     AccessChange, AccessDrift, ContractBuilder, DataFormat, FieldAccess, FieldBuilder,
+/// };
+/// use std::collections::BTreeMap;
+///
+/// let pii = |roles: &[&str]| FieldAccess {
+///     classification: Some("pii".to_string()),
+///     allowed_roles: roles.iter().map(|role| role.to_string()).collect(),
+/// };
+/// let contract = ContractBuilder::new("users", "identity")
+///     .location("s3://lake/users")
+///     .format(DataFormat::Iceberg)
+///     .field(FieldBuilder::new("email", "string").access(pii(&["support"])).build())
+///     .build();
+/// let catalog = BTreeMap::from([("email".to_string(), pii(&["support", "marketing"]))]);
+///
+/// let drift = AccessDrift::compare(&contract.schema, &catalog);
+/// assert!(matches!(drift.changes[0], AccessChange::RolesChanged { .. }));
+/// assert_eq!(
+///     drift.changes[0].to_string(),
+///     "field 'email' is readable by marketing, support in the catalog, support in the contract"
+/// );
+/// assert!(drift.is_breaking());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct AccessDrift {
+    /// Every change, in contract field order, undeclared columns last
+    pub changes: Vec<AccessChange>,
+}
+
+impl AccessDrift {
+    /// Compares the access declared by a contract schema with the access
+    /// tagged in the catalog, by column name.
+    ///
+    /// Roles are compared as sets, so their order does not matter.
+    pub fn compare(contract: &Schema, catalog: &BTreeMap<String, FieldAccess>) -> Self {
+        let declared = declared_access(contract);
+        let mut changes = Vec::new();
+
+        for field in &contract.fields {
+            let Some(declared) = declared.get(&field.name) else {
+                continue;
+            };
+            let Some(actual) = catalog.get(&field.name).filter(|access| !access.is_empty()) else {
+                changes.push(AccessChange::Untagged {
+                    field: field.name.clone(),
+                    declared: declared.clone(),
+                });
+                continue;
+            };
+            if declared.classification != actual.classification {
+                changes.push(AccessChange::ClassificationChanged {
+                    field: field.name.clone(),
+                    contract: declared.classification.clone(),
+                    catalog: actual.classification.clone(),
+                });
+            }
+            let (contract_roles, catalog_roles) = (
+                sorted(&declared.allowed_roles),
+                sorted(&actual.allowed_roles),
+            );
+            if contract_roles != catalog_roles {
+                changes.push(AccessChange::RolesChanged {
+                    field: field.name.clone(),
+                    contract: contract_roles,
+                    catalog: catalog_roles,
+                });
+            }
+        }
+        for (column, actual) in catalog {
+            if !actual.is_empty() && !declared.contains_key(column) {
+                changes.push(AccessChange::Undeclared {
+                    field: column.clone(),
+                    actual: actual.clone(),
+                });
+            }
+        }
+
+        Self { changes }
+    }
+
+    /// Returns true if the contract and the catalog agree.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Returns true if any change is breaking.
+    pub fn is_breaking(&self) -> bool {
+        self.changes.iter().any(AccessChange::is_breaking)
+    }
+}
+
+fn sorted(roles: &[String]) -> Vec<String> {
+    let mut roles = roles.to_vec();
+    roles.sort();
+    roles.dedup();
+    roles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ContractBuilder, DataFormat, FieldBuilder};
+
+    fn access(classification: &str, roles: &[&str]) -> FieldAccess {
+        FieldAccess {
+            classification: Some(classification.to_string()),
+            allowed_roles: roles.iter().map(|role| role.to_string()).collect(),
+        }
+    }
+
+    fn schema() -> Schema {
+        ContractBuilder::new("users", "identity")
+            .location("s3://lake/users")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("id", "int64").build())
+            .field(
+                FieldBuilder::new("email", "string")
+                    .access(access("pii", &["support", "admin"]))
+                    .build(),
+            )
+            .field(
+                FieldBuilder::new("country", "string")
+                    .access(access("internal", &[]))
+                    .build(),
+            )
+            .build()
+            .schema
+    }
+
+    #[test]
+    fn test_matching_access_has_no_drift() {
+        let catalog = BTreeMap::from([
+            ("email".to_string(), access("pii", &["admin", "support"])),
+            ("country".to_string(), access("internal", &[])),
+        ]);
+        assert!(AccessDrift::compare(&schema(), &catalog).is_empty());
+        assert_eq!(declared_access(&schema()).len(), 2);
+    }
+
+    #[test]
+    fn test_access_changes() {
+        let catalog = BTreeMap::from([
+            ("email".to_string(), access("public", &["support"])),
+            ("id".to_string(), access("internal", &[])),
+        ]);
+        let drift = AccessDrift::compare(&schema(), &catalog);
+        let kinds: Vec<(&str, bool)> = drift
+            .changes
+            .iter()
+            .map(|change| (change.field(), change.is_breaking()))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("email", true),
+                ("email", true),
+                ("country", true),
+                ("id", false)
+            ]
+        );
+        assert_eq!(
+            drift.changes[0].to_string(),
+            "field 'email' is classified public in the catalog, pii in the contract"
+        );
+        assert!(matches!(drift.changes[2], AccessChange::Untagged { .. }));
+
+        let json = serde_json::to_value(&drift.changes[3]).unwrap();
+        assert_eq!(json["kind"], "undeclared");
+        assert_eq!(json["actual"]["classification"], "internal");
+    }
+}
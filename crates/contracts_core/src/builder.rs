@@ -4,9 +4,13 @@
 //! and their components with a fluent API.
 
 use crate::{
-    CompletenessCheck, Contract, CustomCheck, DataFormat, DataType, Field, FieldConstraints,
-    FreshnessCheck, MlChecks, QualityChecks, SLA, Schema, UniquenessCheck,
+    CdcConfig, CompletenessCheck, ConformanceLevel, ConnectionConfig, Contract, ContractError,
+    CustomCheck, DataFormat, DataType, Dataset, DeletedRowsCheck, Deprecation, EmptyDataPolicy,
+    Field, FieldAccess, FieldConstraints, FreshnessCheck, MlChecks, QualityChecks,
+    ReferentialCheck, Result, RowCheck, SLA, Schema, Severity, SoftDelete, UniquenessCheck,
+    VolumeCheck,
 };
+use std::collections::BTreeMap;
 
 /// Builder for creating a `Contract`.
 ///
@@ -30,9 +34,17 @@ pub struct ContractBuilder {
     description: Option<String>,
     location: Option<String>,
     format: Option<DataFormat>,
+    catalog: Option<String>,
+    namespace: Option<String>,
+    table: Option<String>,
+    connection: Option<ConnectionConfig>,
     fields: Vec<Field>,
     quality_checks: Option<QualityChecks>,
     sla: Option<SLA>,
+    conformance_level: Option<ConformanceLevel>,
+    cdc: Option<CdcConfig>,
+    datasets: Option<BTreeMap<String, Dataset>>,
+    extensions: Option<BTreeMap<String, serde_json::Value>>,
 }
 
 impl ContractBuilder {
@@ -75,6 +87,30 @@ impl ContractBuilder {
         self
     }
 
+    /// Sets the catalog holding the table.
+    pub fn catalog(mut self, catalog: impl Into<String>) -> Self {
+        self.catalog = Some(catalog.into());
+        self
+    }
+
+    /// Sets the table namespace (dot-separated for nested namespaces).
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Sets the table name within the namespace.
+    pub fn table(mut self, table: impl Into<String>) -> Self {
+        self.table = Some(table.into());
+        self
+    }
+
+    /// Sets the catalog connection settings.
+    pub fn connection(mut self, connection: ConnectionConfig) -> Self {
+        self.connection = Some(connection);
+        self
+    }
+
     /// Adds a field to the schema.
     pub fn field(mut self, field: Field) -> Self {
         self.fields.push(field);
@@ -99,25 +135,85 @@ impl ContractBuilder {
         self
     }
 
+    /// Sets the conformance level the contract is held to.
+    pub fn conformance_level(mut self, level: ConformanceLevel) -> Self {
+        self.conformance_level = Some(level);
+        self
+    }
+
+    /// Sets the change data capture envelopes the rows arrive in.
+    pub fn cdc(mut self, cdc: CdcConfig) -> Self {
+        self.cdc = Some(cdc);
+        self
+    }
+
+    /// Adds a related dataset the contract also covers.
+    pub fn dataset(mut self, name: impl Into<String>, dataset: Dataset) -> Self {
+        self.datasets
+            .get_or_insert_with(BTreeMap::new)
+            .insert(name.into(), dataset);
+        self
+    }
+
+    /// Adds an entry to the contract's `x-extensions` metadata block.
+    pub fn extension(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.extensions
+            .get_or_insert_with(BTreeMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
     /// Builds the contract.
     ///
     /// # Panics
     ///
     /// Panics if required fields (name, owner, location, format) are not set.
+    /// Use [`try_build`](Self::try_build) when they come from user input.
     pub fn build(self) -> Contract {
-        Contract {
-            version: self.version.expect("version is required"),
-            name: self.name.expect("name is required"),
-            owner: self.owner.expect("owner is required"),
+        unwrap_built(self.try_build())
+    }
+
+    /// Builds the contract, or reports the first required field that is not set.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ContractError::MissingField`] naming the missing field.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use contracts_core::{ContractBuilder, ContractError};
+    ///
+    /// let result = ContractBuilder::new("user_events", "analytics-team").try_build();
+    /// assert!(matches!(result, Err(ContractError::MissingField(field)) if field == "format"));
+    /// ```
+    pub fn try_build(self) -> Result<Contract> {
+        Ok(Contract {
+            version: required(self.version, "version")?,
+            name: required(self.name, "name")?,
+            owner: required(self.owner, "owner")?,
             description: self.description,
             schema: Schema {
                 fields: self.fields,
-                format: self.format.expect("format is required"),
-                location: self.location.expect("location is required"),
+                format: required(self.format, "format")?,
+                location: required(self.location, "location")?,
+                catalog: self.catalog,
+                namespace: self.namespace,
+                table: self.table,
+                connection: self.connection,
             },
             quality_checks: self.quality_checks,
             sla: self.sla,
-        }
+            conformance_level: self.conformance_level,
+            cdc: self.cdc,
+            datasets: self.datasets,
+            changelog: None,
+            extensions: self.extensions,
+        })
     }
 }
 
@@ -138,9 +234,14 @@ pub struct FieldBuilder {
     name: Option<String>,
     field_type: Option<DataType>,
     nullable: bool,
+    required: Option<bool>,
     description: Option<String>,
     tags: Option<Vec<String>>,
     constraints: Option<Vec<FieldConstraints>>,
+    severity: Option<Severity>,
+    default: Option<serde_json::Value>,
+    deprecated: Option<Deprecation>,
+    access: Option<FieldAccess>,
 }
 
 impl FieldBuilder {
@@ -165,6 +266,14 @@ impl FieldBuilder {
         self
     }
 
+    /// Sets whether the field must be present in every record.
+    ///
+    /// When not set, the field is required exactly when it is not nullable.
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = Some(required);
+        self
+    }
+
     /// Sets the field description.
     pub fn description(mut self, description: impl Into<String>) -> Self {
         self.description = Some(description.into());
@@ -185,20 +294,73 @@ impl FieldBuilder {
         self
     }
 
+    /// Sets how constraint violations on the field are reported.
+    pub fn severity(mut self, severity: Severity) -> Self {
+        self.severity = Some(severity);
+        self
+    }
+
+    /// Sets the value producers write when they leave the field out.
+    pub fn default_value(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.default = Some(value.into());
+        self
+    }
+
+    /// Marks the field as deprecated.
+    pub fn deprecated(mut self, deprecation: Deprecation) -> Self {
+        self.deprecated = Some(deprecation);
+        self
+    }
+
+    /// Sets who may read the field.
+    pub fn access(mut self, access: FieldAccess) -> Self {
+        self.access = Some(access);
+        self
+    }
+
     /// Builds the field.
     ///
     /// # Panics
     ///
     /// Panics if required fields (name, field_type) are not set.
+    /// Use [`try_build`](Self::try_build) to get an error instead.
     pub fn build(self) -> Field {
-        Field {
-            name: self.name.expect("name is required"),
-            field_type: self.field_type.expect("field_type is required"),
+        unwrap_built(self.try_build())
+    }
+
+    /// Builds the field, or reports the first required field that is not set.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ContractError::MissingField`] naming the missing field.
+    pub fn try_build(self) -> Result<Field> {
+        Ok(Field {
+            name: required(self.name, "name")?,
+            field_type: required(self.field_type, "field_type")?,
             nullable: self.nullable,
+            required: self.required,
             description: self.description,
             tags: self.tags,
             constraints: self.constraints,
-        }
+            severity: self.severity,
+            default: self.default,
+            deprecated: self.deprecated,
+            access: self.access,
+        })
+    }
+}
+
+/// Takes a required builder value, or names it as missing.
+fn required<T>(value: Option<T>, field: &str) -> Result<T> {
+    value.ok_or_else(|| ContractError::MissingField(field.to_string()))
+}
+
+/// Unwraps a `try_build` result for the panicking `build` methods.
+fn unwrap_built<T>(result: Result<T>) -> T {
+    match result {
+        Ok(built) => built,
+        Err(ContractError::MissingField(field)) => panic!("{} is required", field),
+        Err(e) => panic!("{}", e),
     }
 }
 
@@ -208,8 +370,14 @@ pub struct QualityChecksBuilder {
     completeness: Option<CompletenessCheck>,
     uniqueness: Option<UniquenessCheck>,
     freshness: Option<FreshnessCheck>,
+    volume: Option<VolumeCheck>,
     custom_checks: Option<Vec<CustomCheck>>,
     ml_checks: Option<MlChecks>,
+    on_empty: Option<EmptyDataPolicy>,
+    deleted_rows: Option<DeletedRowsCheck>,
+    soft_delete: Option<SoftDelete>,
+    referential: Option<Vec<ReferentialCheck>>,
+    row_checks: Option<Vec<RowCheck>>,
 }
 
 impl QualityChecksBuilder {
@@ -236,6 +404,12 @@ impl QualityChecksBuilder {
         self
     }
 
+    /// Sets the per-bucket volume check.
+    pub fn volume(mut self, check: VolumeCheck) -> Self {
+        self.volume = Some(check);
+        self
+    }
+
     /// Adds a custom check.
     pub fn custom_check(mut self, check: CustomCheck) -> Self {
         self.custom_checks.get_or_insert_with(Vec::new).push(check);
@@ -248,14 +422,50 @@ impl QualityChecksBuilder {
         self
     }
 
+    /// Sets what happens when there are no records to check.
+    pub fn on_empty(mut self, policy: EmptyDataPolicy) -> Self {
+        self.on_empty = Some(policy);
+        self
+    }
+
+    /// Sets the limit on rows hidden by row-level deletes.
+    pub fn deleted_rows(mut self, check: DeletedRowsCheck) -> Self {
+        self.deleted_rows = Some(check);
+        self
+    }
+
+    /// Sets the column marking soft-deleted rows.
+    pub fn soft_delete(mut self, soft_delete: SoftDelete) -> Self {
+        self.soft_delete = Some(soft_delete);
+        self
+    }
+
+    /// Adds a referential integrity check.
+    pub fn referential(mut self, check: ReferentialCheck) -> Self {
+        self.referential.get_or_insert_with(Vec::new).push(check);
+        self
+    }
+
+    /// Adds a row check.
+    pub fn row_check(mut self, check: RowCheck) -> Self {
+        self.row_checks.get_or_insert_with(Vec::new).push(check);
+        self
+    }
+
     /// Builds the quality checks.
     pub fn build(self) -> QualityChecks {
         QualityChecks {
             completeness: self.completeness,
             uniqueness: self.uniqueness,
             freshness: self.freshness,
+            volume: self.volume,
             custom_checks: self.custom_checks,
             ml_checks: self.ml_checks,
+            on_empty: self.on_empty,
+            deleted_rows: self.deleted_rows,
+            soft_delete: self.soft_delete,
+            referential: self.referential,
+            row_checks: self.row_checks,
         }
     }
 }
@@ -287,6 +497,8 @@ mod tests {
             .completeness(CompletenessCheck {
                 threshold: 0.95,
                 fields: vec!["id".to_string()],
+                min_sample_size: None,
+                severity: None,
             })
             .build();
         let sla = SLA {
@@ -344,6 +556,27 @@ mod tests {
             .build();
     }
 
+    #[test]
+    fn test_contract_builder_try_build_reports_missing_field() {
+        let missing = |builder: ContractBuilder| match builder.try_build() {
+            Err(ContractError::MissingField(field)) => field,
+            other => panic!("expected a missing field, got {:?}", other),
+        };
+
+        assert_eq!(missing(ContractBuilder::default()), "version");
+        assert_eq!(
+            missing(ContractBuilder::new("test", "team").format(DataFormat::Parquet)),
+            "location"
+        );
+        assert!(
+            ContractBuilder::new("test", "team")
+                .location("s3://data")
+                .format(DataFormat::Parquet)
+                .try_build()
+                .is_ok()
+        );
+    }
+
     #[test]
     fn test_contract_builder_multiple_fields() {
         let fields = vec![
@@ -411,6 +644,18 @@ mod tests {
         assert_eq!(constraints.len(), 2);
     }
 
+    #[test]
+    fn test_field_builder_try_build() {
+        assert!(matches!(
+            FieldBuilder::default().try_build(),
+            Err(ContractError::MissingField(field)) if field == "name"
+        ));
+        assert_eq!(
+            FieldBuilder::new("id", "int64").try_build().unwrap().name,
+            "id"
+        );
+    }
+
     #[test]
     #[should_panic(expected = "name is required")]
     fn test_field_builder_panic_missing_name() {
@@ -445,24 +690,34 @@ mod tests {
             .completeness(CompletenessCheck {
                 threshold: 0.99,
                 fields: vec!["id".to_string()],
+                min_sample_size: None,
+                severity: None,
             })
             .uniqueness(UniquenessCheck {
                 fields: vec!["id".to_string()],
                 scope: Some("global".to_string()),
+                min_sample_size: None,
+                severity: None,
+                approximate: None,
             })
             .freshness(FreshnessCheck {
                 max_delay: "1h".to_string(),
                 metric: "updated_at".to_string(),
+                source: None,
+                allowed_lateness: None,
+                severity: None,
             })
             .custom_check(CustomCheck {
                 name: "check1".to_string(),
                 definition: "COUNT(*) > 0".to_string(),
                 severity: Some("error".to_string()),
+                capture_rows: None,
             })
             .custom_check(CustomCheck {
                 name: "check2".to_string(),
                 definition: "AVG(value) < 100".to_string(),
                 severity: Some("warning".to_string()),
+                capture_rows: None,
             })
             .build();
 
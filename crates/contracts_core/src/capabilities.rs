@@ -0,0 +1,91 @@
+//! What a build of the engine supports.
+//!
+//! Binaries are built with different cargo features, so tooling that drives
+//! them cannot assume which catalogs or formats are available. A
+//! [`Capabilities`] lists them: the contract model fills in the check types
+//! every build evaluates, and the crates providing readers, catalogs and
+//! outputs add theirs.
+
+use serde::{Deserialize, Serialize};
+
+/// Quality checks a contract can declare, by their key under `quality_checks`.
+pub const QUALITY_CHECK_TYPES: &[&str] = &[
+    "completeness",
+    "uniqueness",
+    "freshness",
+    "volume",
+    "custom_checks",
+    "row_checks",
+    "deleted_rows",
+    "ml_checks.no_overlap",
+    "ml_checks.temporal_split",
+    "ml_checks.class_balance",
+    "ml_checks.feature_drift",
+    "ml_checks.target_leakage",
+    "ml_checks.null_rate_by_group",
+];
+
+/// Field constraints a contract can declare, by their `type`.
+pub const CONSTRAINT_TYPES: &[&str] = &[
+    "allowedvalues",
+    "range",
+    "pattern",
+    "length",
+    "bytelength",
+    "listlength",
+    "mapkeys",
+    "nonempty",
+    "maxnullfraction",
+    "cardinality",
+    "precision",
+    "timerange",
+];
+
+/// Formats, catalogs, outputs and checks supported by a build.
+///
+/// # Example
+///
+/// ```rust
+/// use contracts_core::Capabilities;
+///
+/// let mut capabilities = Capabilities::new("1.2.0");
+/// capabilities.formats.push("parquet".to_string());
+/// assert!(capabilities.check_types.contains(&"freshness".to_string()));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Capabilities {
+    /// Version of the build
+    pub version: String,
+
+    /// Data formats whose data can be validated (`schema.format`)
+    pub formats: Vec<String>,
+
+    /// Catalog types tables can be loaded from (`connection.type`)
+    pub catalogs: Vec<String>,
+
+    /// Formats reports can be rendered in
+    pub output_formats: Vec<String>,
+
+    /// Quality checks that are evaluated
+    pub check_types: Vec<String>,
+
+    /// Field constraints that are evaluated
+    pub constraint_types: Vec<String>,
+
+    /// Cargo features the build was compiled with
+    pub features: Vec<String>,
+}
+
+impl Capabilities {
+    /// Creates the capabilities of a build at `version`, with the check and
+    /// constraint types of the contract model.
+    pub fn new(version: impl Into<String>) -> Self {
+        let names = |names: &[&str]| names.iter().map(|name| name.to_string()).collect();
+        Self {
+            version: version.into(),
+            check_types: names(QUALITY_CHECK_TYPES),
+            constraint_types: names(CONSTRAINT_TYPES),
+            ..Self::default()
+        }
+    }
+}
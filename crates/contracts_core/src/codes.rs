@@ -0,0 +1,386 @@
+//! Stable error codes.
+//!
+//! Every error variant in the workspace maps to one [`ErrorCode`]. Codes
+//! never change meaning once released, so scripts and dashboards can match
+//! on them instead of on message wording, and `dce explain <CODE>` prints
+//! the remediation text kept here.
+//!
+//! Codes are grouped by area:
+//!
+//! | Range | Area |
+//! |-------|------|
+//! | `DCE00xx` | Contract definitions |
+//! | `DCE01xx` | Schema and nullability |
+//! | `DCE02xx` | Field constraints |
+//! | `DCE03xx` | Quality checks |
+//! | `DCE04xx` | Custom checks |
+//! | `DCE10xx` | Contract files, registry and lockfile |
+//! | `DCE20xx` | Iceberg catalogs and tables |
+//! | `DCE21xx` | Parquet files |
+//! | `DCE22xx` | CSV and NDJSON files |
+//!
+//! The same condition has the same code in every crate: a null in a
+//! non-nullable field is `DCE0102` whether the row validator or the
+//! DataFusion engine found it.
+
+use std::fmt;
+
+/// A stable error code with its documentation.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ErrorCode {
+    /// The code, such as `DCE0102`
+    pub code: &'static str,
+
+    /// Short name of the condition
+    pub name: &'static str,
+
+    /// One-line description of what went wrong
+    pub summary: &'static str,
+
+    /// How to fix it
+    pub remediation: &'static str,
+}
+
+impl ErrorCode {
+    /// Looks up a code, ignoring case and accepting the number alone (`0102`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use contracts_core::ErrorCode;
+    ///
+    /// let code = ErrorCode::lookup("dce0102").unwrap();
+    /// assert_eq!(code.name, "NullConstraintViolation");
+    /// assert_eq!(ErrorCode::lookup("0102"), Some(code));
+    /// assert!(ErrorCode::lookup("DCE9999").is_none());
+    /// ```
+    pub fn lookup(code: &str) -> Option<&'static ErrorCode> {
+        let code = code.trim();
+        let number = code
+            .get(..3)
+            .filter(|prefix| prefix.eq_ignore_ascii_case("DCE"))
+            .map_or(code, |_| &code[3..]);
+        ALL.iter().copied().find(|c| &c.code[3..] == number)
+    }
+
+    /// Prefixes `message` with this code, as it appears in validation reports.
+    pub fn tag(&self, message: impl fmt::Display) -> String {
+        format!("[{}] {}", self.code, message)
+    }
+
+    /// Splits a tagged report message into its code and the message text.
+    ///
+    /// Messages without a tag are returned unchanged with no code.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use contracts_core::ErrorCode;
+    ///
+    /// assert_eq!(
+    ///     ErrorCode::split("[DCE0102] Field 'id' cannot be null"),
+    ///     (Some("DCE0102"), "Field 'id' cannot be null")
+    /// );
+    /// assert_eq!(ErrorCode::split("plain message"), (None, "plain message"));
+    /// ```
+    pub fn split(message: &str) -> (Option<&str>, &str) {
+        message
+            .strip_prefix('[')
+            .and_then(|rest| rest.split_once("] "))
+            .filter(|(code, _)| code.starts_with("DCE") && code.len() == 7)
+            .map_or((None, message), |(code, text)| (Some(code), text))
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code)
+    }
+}
+
+/// Errors that carry a stable [`ErrorCode`].
+pub trait Coded {
+    /// Returns the code of this error.
+    fn error_code(&self) -> &'static ErrorCode;
+
+    /// Returns the message prefixed with its code, as stored in reports.
+    fn tagged(&self) -> String
+    where
+        Self: fmt::Display,
+    {
+        self.error_code().tag(self)
+    }
+}
+
+macro_rules! error_codes {
+    ($($ident:ident = $code:literal, $name:literal, $summary:literal, $remediation:literal;)*) => {
+        $(
+            #[doc = $summary]
+            pub static $ident: ErrorCode = ErrorCode {
+                code: $code,
+                name: $name,
+                summary: $summary,
+                remediation: $remediation,
+            };
+        )*
+
+        /// Every known error code, in numeric order.
+        pub static ALL: &[&ErrorCode] = &[$(&$ident),*];
+    };
+}
+
+error_codes! {
+    SLA_VIOLATION = "DCE0005", "SlaViolation",
+        "The dataset does not meet the contract's service level agreement.",
+        "Check the `sla` block of the contract against the producer's actual availability and latency, and fix the pipeline or renegotiate the SLA.";
+    VERSION_MISMATCH = "DCE0006", "VersionMismatch",
+        "The contract version differs from the version that was expected.",
+        "Validate against the intended contract version, or pin it with `dce update` and `--contract-from-registry`.";
+    UNSUPPORTED_FORMAT = "DCE0007", "UnsupportedFormat",
+        "The contract declares a data format this engine cannot read.",
+        "Use one of the supported `schema.format` values (iceberg, parquet, csv, json, ...).";
+    SERIALIZATION = "DCE0008", "Serialization",
+        "A contract or report could not be serialized or deserialized.",
+        "Check that the file is valid JSON, YAML or TOML and matches the contract structure.";
+    INVALID_EXTENSIONS = "DCE0009", "InvalidExtensions",
+        "The contract's `x-extensions` block does not match the extensions JSON Schema.",
+        "Fix the reported `x-extensions` entries, or update the schema passed with `--extensions-schema`.";
+    INVALID_DEFAULT = "DCE0010", "InvalidDefault",
+        "A field's `default` is not a value of the field's type.",
+        "Write the default as a JSON value of the field's type (an ISO string for dates and times), or remove it.";
+    INVALID_ROW_FILTER = "DCE0011", "InvalidRowFilter",
+        "The row filter (`--where`) is not a supported SQL predicate or names a column the contract does not declare.",
+        "Write a predicate over contract fields, such as `status = 'failed' AND amount > 100`.";
+    INVALID_CONDITION = "DCE0012", "InvalidCondition",
+        "A `conditional` constraint has a `when` or `require` that is not a supported predicate or names a column the contract does not declare.",
+        "Write them as predicates over contract fields, such as `when: status = 'closed'` and `require: closed_at IS NOT NULL`.";
+    WEAK_DESCRIPTION = "DCE0013", "WeakDescription",
+        "A description is a placeholder, only repeats the field name, is shorter than the workspace minimum, or does not state the unit of a numeric field.",
+        "Say what the value means and, for numbers, its unit (such as `in milliseconds`); the rules are set under `[lint.descriptions]` in `dce.toml`.";
+    INVALID_VERSION = "DCE0014", "InvalidVersion",
+        "The contract `version`, or a version in its `changelog`, is not a semantic version, or a changelog entry is newer than the contract or listed twice.",
+        "Write versions as MAJOR.MINOR.PATCH (such as `1.4.0` or `2.0.0-rc.1`) and keep one changelog entry per released version.";
+    INVALID_ROW_CHECK = "DCE0015", "InvalidRowCheck",
+        "A row check has an `expression` that is not a supported expression or names a column the contract does not declare.",
+        "Write it as a true/false expression over contract fields, such as `status != 'shipped' || shipped_at >= ordered_at`.";
+    INVALID_EXPRESSION = "DCE0016", "InvalidExpression",
+        "A `custom` constraint has a `definition` that is not a supported expression or names a column the contract does not declare.",
+        "Write it as a true/false expression over `value` and the other contract fields, such as `value > 0 && value < len(code)`.";
+    SCHEMA_CHANGE = "DCE0017", "SchemaChange",
+        "A contract version changes the schema of the previous one; breaking changes are errors, compatible ones informational.",
+        "Make breaking changes in a new major version, or keep the previous field, type or constraint so existing consumers still read the data.";
+    GENERAL = "DCE0099", "General",
+        "An error without a more specific code.",
+        "Read the message for details; run with `-v` for more logging.";
+
+    SCHEMA_ERROR = "DCE0100", "SchemaError",
+        "The contract schema is invalid or does not match the data.",
+        "Run `dce check <contract>` to list definition problems, and compare the contract fields with the table schema.";
+    MISSING_FIELD = "DCE0101", "MissingField",
+        "A field declared in the contract is missing from the data.",
+        "Add the column to the dataset, or remove or rename the field in the contract.";
+    NULL_CONSTRAINT_VIOLATION = "DCE0102", "NullConstraintViolation",
+        "A field declared `nullable: false` contains null values.",
+        "Fix the producer so the column is always populated, or set `nullable: true` if nulls are legitimate.";
+    TYPE_MISMATCH = "DCE0103", "TypeMismatch",
+        "A value or column does not have the type declared in the contract.",
+        "Cast the column in the producer, or change the field `type` to match the data.";
+    INVALID_LOCATION = "DCE0104", "InvalidLocation",
+        "The schema location is malformed or unsupported for the data format.",
+        "Use a URI scheme supported by the format (for example `s3://` or `iceberg://catalog/namespace/table`).";
+    IDENTIFIER_MISMATCH = "DCE0105", "IdentifierMismatch",
+        "The contract's key differs from the identifier fields of the Iceberg table.",
+        "Tag the table's identifier fields `primary_key` in the contract, or update the table's identifier fields.";
+    DEPRECATED_FIELD_POPULATED = "DCE0106", "DeprecatedFieldPopulated",
+        "Data still populates a deprecated field after its `remove_after` date.",
+        "Move the producer to the field's `replacement` and stop writing the deprecated field, or extend `remove_after` if consumers still need it.";
+
+    CONSTRAINT_VIOLATION = "DCE0200", "ConstraintViolation",
+        "A value violates a field constraint.",
+        "Inspect the offending rows (`dce validate --dump-sample`) and fix the data or relax the constraint.";
+    INVALID_REGEX = "DCE0201", "InvalidRegex",
+        "A `pattern` constraint is not a valid regular expression.",
+        "Fix the `regex` of the constraint; patterns use Rust regex syntax.";
+    NOT_IN_ALLOWED_VALUES = "DCE0202", "NotInAllowedValues",
+        "A value is not in the field's `allowed_values` list.",
+        "Add the new value to `allowed_values` if it is legitimate, or fix the producer.";
+    OUT_OF_RANGE = "DCE0203", "OutOfRange",
+        "A numeric value is outside the field's `range` constraint.",
+        "Check the producer for unit or overflow bugs, or widen `min`/`max`.";
+    PATTERN_MISMATCH = "DCE0204", "PatternMismatch",
+        "A value does not match the field's `pattern` constraint.",
+        "Fix the malformed values, or adjust the `regex` if the format changed intentionally.";
+    INVALID_LENGTH = "DCE0205", "InvalidLength",
+        "A string, list or map is shorter or longer than its `length`, `bytelength`, `listlength` or `nonempty` constraint allows.",
+        "Check the producer for truncated, padded, dropped or duplicated content, or adjust `min`/`max`.";
+    INVALID_MAP_KEY = "DCE0206", "InvalidMapKey",
+        "A map has a key that is not in the `mapkeys` constraint's `allowed` list or does not match its `pattern`.",
+        "Add the key to `allowed` if it is legitimate, or fix the producer that writes it.";
+    CONDITION_VIOLATION = "DCE0207", "ConditionViolation",
+        "A row matches the `when` predicate of a `conditional` constraint but not its `require` predicate.",
+        "Inspect the rows the rule applies to (`dce validate --where` with the `when` predicate), then fix the data or the rule.";
+    CDC_ENVELOPE = "DCE0208", "CdcEnvelope",
+        "A change event has an operation code outside `cdc.allowed_ops`, row images that do not fit its operation, or no `ts_ms`.",
+        "Check the connector configuration and the topic the events were read from; add legitimate operation codes to `allowed_ops`.";
+    NULL_FRACTION = "DCE0209", "NullFraction",
+        "A larger share of a field's values is null than its `maxnullfraction` constraint allows.",
+        "Check the producer for a dropped or renamed column, or raise `fraction` if the gaps are expected.";
+    CARDINALITY = "DCE0210", "Cardinality",
+        "A field has fewer or more distinct values than its `cardinality` constraint allows.",
+        "Too few distinct values often means a constant default was written; too many, that free text or IDs reached a categorical field. Fix the producer or adjust `min_distinct`/`max_distinct`.";
+    INVALID_PRECISION = "DCE0211", "InvalidPrecision",
+        "A numeric value has more integer or fractional digits than the field's `precision` constraint allows.",
+        "Check the producer for unrounded or scaled values, or raise `precision`/`scale` if the wider values are legitimate.";
+    OUT_OF_TIME_RANGE = "DCE0212", "OutOfTimeRange",
+        "A timestamp is outside the field's `timerange` constraint, or in the future where `allow_future` is false.",
+        "Future timestamps usually come from clock skew or a wrong time zone on the producer; old ones from replays or defaulted dates. Fix the producer or widen `min`/`max`.";
+
+    QUALITY_CHECK_FAILED = "DCE0300", "QualityCheckFailed",
+        "A quality or ML check (completeness, uniqueness, balance, drift, ...) failed.",
+        "Read the message for the measured value and threshold; fix the data or tune the check threshold.";
+    STALE_DATA = "DCE0301", "StaleData",
+        "The newest value of the freshness metric is older than `max_delay`.",
+        "Check that the producing job ran, or increase `max_delay` if the delivery schedule changed.";
+    INVALID_DURATION = "DCE0302", "InvalidDuration",
+        "A duration such as `max_delay` could not be parsed.",
+        "Use a number followed by a unit: `30s`, `15m`, `24h` or `7d`.";
+    EMPTY_DATASET = "DCE0303", "EmptyDataset",
+        "No records were read, so no data checks ran.",
+        "Check that the table or file has data and that the reader points at the right location. If empty data is expected, set `quality_checks.on_empty: pass` or pass `--on-empty pass`.";
+    INSUFFICIENT_SAMPLE = "DCE0304", "InsufficientSample",
+        "A statistical check was not evaluated because fewer records were validated than its `min_sample_size`.",
+        "Validate more rows (raise `--sample-size` or drop `--sample-size` altogether), or lower the check's `min_sample_size`.";
+    SEGMENT_FAILED = "DCE0305", "SegmentFailed",
+        "The rows of one segment fail checks when validated on their own.",
+        "Look at the segment's results: the data of that group (country, tenant, ...) is broken even if the whole dataset looks fine.";
+    DELETED_ROWS = "DCE0306", "DeletedRows",
+        "Row-level deletes hide a larger share of the table than `deleted_rows.max_fraction` allows.",
+        "Compact the table (rewrite its data files) so the deletes are applied, or raise `max_fraction` if the backlog is expected.";
+    STATS_ONLY = "DCE0307", "StatsOnly",
+        "A check was not evaluated because only table statistics were read, and they cannot answer it.",
+        "Validate without `--stats-only` to run the check on the rows. If the column has no statistics, have the table write them (`write.metadata.metrics.*` table properties).";
+    CDC_ORDER = "DCE0308", "CdcOrder",
+        "A change event of a key has an older `ts_ms` than a change of the same key before it.",
+        "Check that the events were read in partition order and that the connector keys events by `cdc.key`; out-of-order changes can overwrite newer state when applied.";
+    DATASET_FAILED = "DCE0309", "DatasetFailed",
+        "A related dataset of a multi-dataset contract fails its checks.",
+        "Look at the dataset's results under `datasets`: each related dataset is validated on its own, with its schema and checks.";
+    REFERENTIAL_INTEGRITY = "DCE0310", "ReferentialIntegrity",
+        "Values of a field are not found in the dataset field they reference.",
+        "Check that the referenced dataset was loaded before the rows referencing it, or lower the check's `threshold` if some orphans are expected.";
+    LOW_VOLUME = "DCE0311", "LowVolume",
+        "Event-time buckets hold fewer records than the volume check's `min_rows`, counting only records that arrived within `allowed_lateness` of the bucket's end.",
+        "Check the producer for gaps in the reported buckets. If records keep arriving later than the window, raise `allowed_lateness`.";
+
+    CUSTOM_CHECK_FAILED = "DCE0400", "CustomCheckFailed",
+        "A custom SQL check failed.",
+        "Run the check's `definition` against the table to see the failing rows.";
+    ROW_CHECK_FAILED = "DCE0401", "RowCheckFailed",
+        "Rows fail the expression of a row check.",
+        "Query the rows where the check's `expression` is false, and fix the data or the expression.";
+
+    YAML_PARSE = "DCE1001", "YamlParse",
+        "The contract file is not valid YAML or does not match the contract structure.",
+        "Fix the reported line; `dce check <contract>` shows the parse error.";
+    TOML_PARSE = "DCE1002", "TomlParse",
+        "The contract file is not valid TOML or does not match the contract structure.",
+        "Fix the reported line; `dce check <contract>` shows the parse error.";
+    CONTRACT_IO = "DCE1003", "ContractIo",
+        "The contract file could not be read.",
+        "Check that the path exists and is readable.";
+    UNSUPPORTED_CONTRACT_FORMAT = "DCE1004", "UnsupportedContractFormat",
+        "The contract file extension is not a supported format.",
+        "Name contract files `.yml`, `.yaml` or `.toml`.";
+    INVALID_EXTENSION = "DCE1005", "InvalidExtension",
+        "The contract file has no extension.",
+        "Name contract files `.yml`, `.yaml` or `.toml`.";
+    INVALID_REGISTRY_REFERENCE = "DCE1006", "InvalidRegistryReference",
+        "A registry reference is not of the form `name@version`.",
+        "Write references as `name@1.2.0` or `name@latest`.";
+    NOT_IN_REGISTRY = "DCE1007", "NotInRegistry",
+        "The requested contract or version is not published in the registry.",
+        "Check the name and version against the registry directory (`--registry` or `DCE_REGISTRY`).";
+    LOCK_MISMATCH = "DCE1008", "LockMismatch",
+        "A registry contract no longer matches its `dce.lock` entry.",
+        "Run `dce update <name@version>` if the published change is intended; otherwise restore the published file.";
+    INVALID_BUNDLE = "DCE1009", "InvalidBundle",
+        "A contract bundle cannot be built from the given files.",
+        "Give every file included in the bundle a distinct name; when pulling, check that the reference points to a bundle pushed by `dce push`.";
+    ALREADY_PUBLISHED = "DCE1010", "AlreadyPublished",
+        "A different file is already published in the registry for the same contract name and version.",
+        "Published versions never change: bump `version` in the contract and publish it again.";
+
+    CATALOG_CONNECTION = "DCE2001", "CatalogConnection",
+        "The Iceberg catalog could not be reached.",
+        "Check the catalog URI, network access and credentials (`connection:` block or REST_CATALOG_URI).";
+    TABLE_NOT_FOUND = "DCE2002", "TableNotFound",
+        "The Iceberg table does not exist in the catalog.",
+        "Check `schema.namespace` and `schema.table`, or the `iceberg://catalog/namespace/table` location.";
+    SCHEMA_EXTRACTION = "DCE2003", "SchemaExtraction",
+        "The Iceberg table schema could not be read.",
+        "Check that the table metadata is readable and not corrupted.";
+    TYPE_CONVERSION = "DCE2004", "TypeConversion",
+        "An Iceberg type has no equivalent contract type.",
+        "Declare the field with a supported type, or exclude the column from the contract.";
+    VALUE_CONVERSION = "DCE2005", "ValueConversion",
+        "A value read from the table could not be converted.",
+        "Inspect the reported column and row; `ConversionErrorPolicy::Skip` skips such rows.";
+    DATA_READ = "DCE2006", "DataRead",
+        "Rows could not be read from the Iceberg table.",
+        "Check storage credentials and that the data files referenced by the snapshot exist.";
+    ICEBERG_CONFIGURATION = "DCE2007", "IcebergConfiguration",
+        "The Iceberg connection configuration is invalid.",
+        "Check the catalog type, URI, warehouse and properties of the `connection:` block.";
+    UNSUPPORTED_OPERATION = "DCE2008", "UnsupportedOperation",
+        "The operation is not supported for this catalog or table.",
+        "Use a catalog type that supports the operation, or validate schema only.";
+    ICEBERG_OTHER = "DCE2099", "IcebergOther",
+        "An error reported by the Iceberg library.",
+        "Read the message for details; run with `-vv` for catalog and storage logging.";
+
+    PARQUET_NOT_FOUND = "DCE2101", "ParquetNotFound",
+        "No Parquet file exists at the contract location.",
+        "Check `schema.location`: it must name a `.parquet` file or a directory holding some.";
+    PARQUET_READ = "DCE2102", "ParquetRead",
+        "A Parquet file or its footer could not be read.",
+        "Check storage credentials (AWS_* variables for s3://) and that the files are valid Parquet.";
+    PARQUET_TYPE_CONVERSION = "DCE2103", "ParquetTypeConversion",
+        "A Parquet column type has no equivalent contract type.",
+        "Declare the field with a supported type, or exclude the column from the contract.";
+
+    FILE_NOT_FOUND = "DCE2201", "FileNotFound",
+        "No CSV or NDJSON file exists at the contract location.",
+        "Check `schema.location`: it must name a file or a directory holding files with the format's extension (.csv, .json, .jsonl, .ndjson).";
+    FILE_READ = "DCE2202", "FileRead",
+        "A data file could not be read from its store.",
+        "Check storage credentials (AWS_* variables for s3://) and file permissions.";
+    FILE_PARSE = "DCE2203", "FileParse",
+        "A data file is not valid CSV or NDJSON.",
+        "Fix the line named in the message: CSV records need as many values as the header, NDJSON lines must each hold one JSON object.";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_codes_are_unique_and_sorted() {
+        let codes: Vec<&str> = ALL.iter().map(|c| c.code).collect();
+        let unique: HashSet<&str> = codes.iter().copied().collect();
+        assert_eq!(unique.len(), codes.len());
+        assert!(codes.is_sorted());
+        assert!(codes.iter().all(|c| c.len() == 7 && c.starts_with("DCE")));
+    }
+
+    #[test]
+    fn test_tag_round_trips() {
+        let tagged = NULL_CONSTRAINT_VIOLATION.tag("Field 'id' cannot be null");
+        assert_eq!(tagged, "[DCE0102] Field 'id' cannot be null");
+        assert_eq!(
+            ErrorCode::split(&tagged),
+            (Some("DCE0102"), "Field 'id' cannot be null")
+        );
+        assert_eq!(ErrorCode::split("[note] text"), (None, "[note] text"));
+    }
+}
@@ -0,0 +1,222 @@
+//! Conformance levels of contracts.
+//!
+//! A contract declares the level it is held to with `conformance_level`, and
+//! each level requires the sections of the levels below it plus its own:
+//!
+//! - **bronze**: a contract `description`
+//! - **silver**: a `quality_checks.completeness` check
+//! - **gold**: an `sla`, `freshness` and `uniqueness` checks and a
+//!   description on every field
+//!
+//! Consumers can then tell at a glance how much a dataset promises, and
+//! linting keeps contracts from claiming more than they declare.
+
+use crate::Contract;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// A conformance level, from least to most demanding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConformanceLevel {
+    /// Described and owned
+    Bronze,
+    /// Bronze, with its completeness checked
+    Silver,
+    /// Silver, with an SLA, freshness and uniqueness checks and every field
+    /// described
+    Gold,
+}
+
+/// A section a contract lacks for a conformance level.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MissingSection {
+    /// Lowest level requiring the section
+    pub level: ConformanceLevel,
+
+    /// Dotted path of the missing section
+    pub path: String,
+}
+
+impl ConformanceLevel {
+    /// Every level, from least to most demanding.
+    pub const ALL: [ConformanceLevel; 3] = [Self::Bronze, Self::Silver, Self::Gold];
+
+    /// Returns the sections `contract` lacks to meet this level, those of
+    /// lower levels first.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use contracts_core::{ConformanceLevel, ContractBuilder, DataFormat};
+    ///
+    /// let contract = ContractBuilder::new("orders", "sales")
+    ///     .description("Customer orders")
+    ///     .location("s3://lake/orders")
+    ///     .format(DataFormat::Iceberg)
+    ///     .build();
+    ///
+    /// assert!(ConformanceLevel::Bronze.missing_sections(&contract).is_empty());
+    /// let missing = ConformanceLevel::Silver.missing_sections(&contract);
+    /// assert_eq!(missing[0].path, "quality_checks.completeness");
+    /// ```
+    pub fn missing_sections(self, contract: &Contract) -> Vec<MissingSection> {
+        let mut missing = Vec::new();
+        let mut require = |level: ConformanceLevel, present: bool, path: String| {
+            if level <= self && !present {
+                missing.push(MissingSection { level, path });
+            }
+        };
+        let checks = contract.quality_checks.as_ref();
+
+        require(
+            Self::Bronze,
+            has_text(contract.description.as_deref()),
+            "description".to_string(),
+        );
+        require(
+            Self::Silver,
+            checks.is_some_and(|c| c.completeness.is_some()),
+            "quality_checks.completeness".to_string(),
+        );
+        require(Self::Gold, contract.sla.is_some(), "sla".to_string());
+        require(
+            Self::Gold,
+            checks.is_some_and(|c| c.freshness.is_some()),
+            "quality_checks.freshness".to_string(),
+        );
+        require(
+            Self::Gold,
+            checks.is_some_and(|c| c.uniqueness.is_some()),
+            "quality_checks.uniqueness".to_string(),
+        );
+        for field in &contract.schema.fields {
+            require(
+                Self::Gold,
+                has_text(field.description.as_deref()),
+                format!("schema.fields.{}.description", field.name),
+            );
+        }
+        missing
+    }
+
+    /// Returns the highest level `contract` meets, if any.
+    pub fn achieved(contract: &Contract) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .rev()
+            .find(|level| level.missing_sections(contract).is_empty())
+    }
+}
+
+fn has_text(text: Option<&str>) -> bool {
+    text.is_some_and(|t| !t.trim().is_empty())
+}
+
+impl fmt::Display for ConformanceLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Bronze => "bronze",
+            Self::Silver => "silver",
+            Self::Gold => "gold",
+        })
+    }
+}
+
+impl FromStr for ConformanceLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|level| level.to_string().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("unknown conformance level '{}'", s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        CompletenessCheck, ContractBuilder, DataFormat, FieldBuilder, FreshnessCheck,
+        QualityChecks, SLA, UniquenessCheck,
+    };
+
+    #[test]
+    fn test_levels_require_the_sections_below_them() {
+        let mut contract = ContractBuilder::new("orders", "sales")
+            .location("s3://lake/orders")
+            .format(DataFormat::Iceberg)
+            .field(
+                FieldBuilder::new("id", "string")
+                    .description("Order ID")
+                    .build(),
+            )
+            .field(FieldBuilder::new("total", "float64").build())
+            .build();
+        assert_eq!(ConformanceLevel::achieved(&contract), None);
+
+        let paths = |level: ConformanceLevel, contract: &Contract| -> Vec<String> {
+            level
+                .missing_sections(contract)
+                .into_iter()
+                .map(|m| m.path)
+                .collect()
+        };
+        assert_eq!(
+            paths(ConformanceLevel::Gold, &contract),
+            vec![
+                "description",
+                "quality_checks.completeness",
+                "sla",
+                "quality_checks.freshness",
+                "quality_checks.uniqueness",
+                "schema.fields.total.description",
+            ]
+        );
+
+        contract.description = Some("Customer orders".to_string());
+        contract.quality_checks = Some(QualityChecks {
+            completeness: Some(CompletenessCheck {
+                threshold: 0.99,
+                fields: vec!["id".to_string()],
+                min_sample_size: None,
+                severity: None,
+            }),
+            ..Default::default()
+        });
+        assert_eq!(
+            ConformanceLevel::achieved(&contract),
+            Some(ConformanceLevel::Silver)
+        );
+
+        let checks = contract.quality_checks.as_mut().unwrap();
+        checks.freshness = Some(FreshnessCheck {
+            max_delay: "1h".to_string(),
+            metric: "updated_at".to_string(),
+            source: None,
+            allowed_lateness: None,
+            severity: None,
+        });
+        checks.uniqueness = Some(UniquenessCheck {
+            fields: vec!["id".to_string()],
+            scope: None,
+            min_sample_size: None,
+            severity: None,
+            approximate: None,
+        });
+        contract.sla = Some(SLA {
+            availability: Some(0.99),
+            response_time: None,
+            penalties: None,
+        });
+        contract.schema.fields[1].description = Some("Order total in EUR".to_string());
+        assert!(paths(ConformanceLevel::Gold, &contract).is_empty());
+        assert_eq!(
+            ConformanceLevel::achieved(&contract),
+            Some(ConformanceLevel::Gold)
+        );
+        assert_eq!("Gold".parse(), Ok(ConformanceLevel::Gold));
+    }
+}
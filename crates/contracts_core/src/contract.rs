@@ -3,9 +3,15 @@
 //! This module contains the core types for defining data contracts, including
 //! schemas, quality checks, and service level agreements.
 
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
 
+use crate::conformance::ConformanceLevel;
 use crate::datatype::DataType;
+use crate::versioning::ChangelogEntry;
 
 /// A data contract defining the structure, quality, and SLA for a dataset.
 ///
@@ -27,12 +33,21 @@ use crate::datatype::DataType;
 ///         fields: vec![],
 ///         format: DataFormat::Iceberg,
 ///         location: "s3://data/user_events".to_string(),
+///         catalog: None,
+///         namespace: None,
+///         table: None,
+///         connection: None,
 ///     },
 ///     quality_checks: None,
 ///     sla: None,
+///     conformance_level: None,
+///     cdc: None,
+///     datasets: None,
+///     changelog: None,
+///     extensions: None,
 /// };
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Contract {
     /// Semantic version of the contract (e.g., "1.0.0")
     pub version: String,
@@ -44,16 +59,475 @@ pub struct Contract {
     pub owner: String,
 
     /// Human-readable description of the dataset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
     /// Schema definition including fields and format
     pub schema: Schema,
 
     /// Optional quality validation rules
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub quality_checks: Option<QualityChecks>,
 
     /// Optional service level agreement
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub sla: Option<SLA>,
+
+    /// Conformance level the contract is held to, see
+    /// [`ConformanceLevel`](crate::ConformanceLevel)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conformance_level: Option<ConformanceLevel>,
+
+    /// Change data capture envelopes the rows arrive in
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cdc: Option<CdcConfig>,
+
+    /// Related datasets the contract also covers, by name
+    ///
+    /// `schema` and `quality_checks` describe the primary dataset; each entry
+    /// here is validated as a contract of its own (see
+    /// [`Contract::dataset_contracts`]). Also accepted as `models:`.
+    #[serde(alias = "models", default, skip_serializing_if = "Option::is_none")]
+    pub datasets: Option<BTreeMap<String, Dataset>>,
+
+    /// Releases of the contract, newest first, with what each changed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub changelog: Option<Vec<ChangelogEntry>>,
+
+    /// Free-form organization metadata (cost center, data tier, ...)
+    ///
+    /// Written as `x-extensions:` (or `custom_metadata:`) and carried through
+    /// parsing and serialization untouched. Validation ignores it unless an
+    /// extensions JSON Schema is supplied to the linter.
+    #[serde(
+        rename = "x-extensions",
+        alias = "custom_metadata",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub extensions: Option<BTreeMap<String, serde_json::Value>>,
+}
+
+impl Contract {
+    /// Returns a copy of the contract with implicit defaults written out.
+    ///
+    /// Tools consuming the canonical structure (e.g. `dce check --format json`)
+    /// then see the values validation actually uses: the connection catalog
+    /// type, the empty-data policy, the uniqueness scope and error tolerance,
+    /// referential thresholds and the optional thresholds of ML checks. Field
+    /// types serialize in their canonical spelling. Values that depend on how
+    /// validation is run, such as the severity of checks without one, stay
+    /// unset and are left out when serialized. Related datasets get the same
+    /// defaults as the contract's own schema and checks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use contracts_core::{ConnectionConfig, ContractBuilder, DataFormat};
+    ///
+    /// let mut contract = ContractBuilder::new("orders", "sales")
+    ///     .location("s3://lake/orders")
+    ///     .format(DataFormat::Iceberg)
+    ///     .build();
+    /// contract.schema.connection = Some(ConnectionConfig::default());
+    ///
+    /// let normalized = contract.normalized();
+    /// let connection = normalized.schema.connection.unwrap();
+    /// assert_eq!(connection.catalog_type.as_deref(), Some("rest"));
+    /// ```
+    pub fn normalized(&self) -> Contract {
+        let mut contract = self.clone();
+
+        fill_defaults(&mut contract.schema, contract.quality_checks.as_mut());
+        for dataset in contract.datasets.iter_mut().flat_map(|d| d.values_mut()) {
+            fill_defaults(&mut dataset.schema, dataset.quality_checks.as_mut());
+        }
+
+        contract
+    }
+
+    /// Rewrites the contract in canonical form, for comparison and hashing.
+    ///
+    /// Applies [`normalized`](Self::normalized), then orders everything whose
+    /// order carries no meaning: schema fields by name, field tags and
+    /// constraints, allowed values, the field lists of quality and ML checks,
+    /// and custom checks by name, in the contract's own schema and checks and
+    /// in those of every related dataset. Duplicate tags and list entries are
+    /// dropped.
+    /// Unlike `normalized`, the result is meant for machines, not for display.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use contracts_core::{ContractBuilder, DataFormat, FieldBuilder};
+    ///
+    /// let contract = |fields: [&str; 2]| {
+    ///     ContractBuilder::new("orders", "sales")
+    ///         .location("s3://lake/orders")
+    ///         .format(DataFormat::Iceberg)
+    ///         .fields(fields.map(|name| FieldBuilder::new(name, "string").build()).to_vec())
+    ///         .build()
+    /// };
+    ///
+    /// let (mut a, mut b) = (contract(["id", "total"]), contract(["total", "id"]));
+    /// assert_ne!(a, b);
+    /// a.normalize();
+    /// b.normalize();
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn normalize(&mut self) {
+        *self = self.normalized();
+
+        sort_schema(&mut self.schema);
+        if let Some(qc) = &mut self.quality_checks {
+            sort_checks(qc);
+        }
+        for dataset in self.datasets.iter_mut().flat_map(|d| d.values_mut()) {
+            sort_schema(&mut dataset.schema);
+            if let Some(qc) = &mut dataset.quality_checks {
+                sort_checks(qc);
+            }
+        }
+    }
+
+    /// Returns a hex-encoded SHA-256 of the contract's canonical form.
+    ///
+    /// Contracts that differ only in what [`normalize`](Self::normalize)
+    /// makes canonical (field order, implicit defaults, ...) hash the same,
+    /// so the hash can key caches and spot duplicates.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use contracts_core::{ContractBuilder, DataFormat};
+    ///
+    /// let contract = ContractBuilder::new("orders", "sales")
+    ///     .location("s3://lake/orders")
+    ///     .format(DataFormat::Iceberg)
+    ///     .build();
+    ///
+    /// let mut bumped = contract.clone();
+    /// bumped.version = "2.0.0".to_string();
+    ///
+    /// assert_eq!(contract.content_hash().len(), 64);
+    /// assert_ne!(contract.content_hash(), bumped.content_hash());
+    /// ```
+    pub fn content_hash(&self) -> String {
+        let mut canonical = self.clone();
+        canonical.normalize();
+        // Through `Value`, so map keys (connection properties) are sorted too
+        let json = serde_json::to_value(&canonical)
+            .map(|value| value.to_string())
+            .unwrap_or_default();
+
+        let digest = Sha256::digest(json.as_bytes());
+        let mut hex = String::with_capacity(digest.len() * 2);
+        for byte in digest {
+            let _ = write!(hex, "{:02x}", byte);
+        }
+        hex
+    }
+}
+
+/// Multi-dataset contracts.
+impl Contract {
+    /// Returns each related dataset's name and standalone contract, in name
+    /// order.
+    ///
+    /// Each is named `{contract}.{dataset}` and keeps the version, owner,
+    /// SLA and extensions of the contract, with the dataset's own
+    /// description, schema and checks. The primary dataset is the contract
+    /// itself.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use contracts_core::{ContractBuilder, DataFormat, Dataset, Schema};
+    ///
+    /// let items = Schema {
+    ///     fields: vec![],
+    ///     format: DataFormat::Iceberg,
+    ///     location: "s3://lake/order_items".to_string(),
+    ///     catalog: None,
+    ///     namespace: None,
+    ///     table: None,
+    ///     connection: None,
+    /// };
+    /// let contract = ContractBuilder::new("orders", "sales")
+    ///     .location("s3://lake/orders")
+    ///     .format(DataFormat::Iceberg)
+    ///     .dataset("items", Dataset { description: None, schema: items, quality_checks: None })
+    ///     .build();
+    ///
+    /// let datasets = contract.dataset_contracts();
+    /// let (name, items) = &datasets[0];
+    /// assert_eq!(*name, "items");
+    /// assert_eq!(items.name, "orders.items");
+    /// assert_eq!(items.schema.location, "s3://lake/order_items");
+    /// ```
+    pub fn dataset_contracts(&self) -> Vec<(&str, Contract)> {
+        self.datasets
+            .iter()
+            .flatten()
+            .map(|(name, dataset)| {
+                let contract = Contract {
+                    version: self.version.clone(),
+                    name: format!("{}.{}", self.name, name),
+                    owner: self.owner.clone(),
+                    description: dataset.description.clone(),
+                    schema: dataset.schema.clone(),
+                    quality_checks: dataset.quality_checks.clone(),
+                    sla: self.sla.clone(),
+                    conformance_level: None,
+                    cdc: None,
+                    datasets: None,
+                    changelog: None,
+                    extensions: self.extensions.clone(),
+                };
+                (name.as_str(), contract)
+            })
+            .collect()
+    }
+}
+
+/// Field lookups.
+impl Contract {
+    /// Returns the schema of the dataset called `name`: the contract's own
+    /// under its name, or one of its related datasets.
+    pub fn dataset_schema(&self, name: &str) -> Option<&Schema> {
+        if name == self.name {
+            return Some(&self.schema);
+        }
+        self.datasets
+            .as_ref()?
+            .get(name)
+            .map(|dataset| &dataset.schema)
+    }
+
+    /// Returns the top-level field called `name`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use contracts_core::{ContractBuilder, DataFormat, FieldBuilder};
+    ///
+    /// let contract = ContractBuilder::new("users", "team")
+    ///     .location("s3://lake/users")
+    ///     .format(DataFormat::Iceberg)
+    ///     .field(FieldBuilder::new("id", "int64").nullable(false).build())
+    ///     .field(FieldBuilder::new("email", "string").tags(vec!["pii".to_string()]).build())
+    ///     .build();
+    ///
+    /// assert!(contract.field("email").is_some());
+    /// assert!(contract.field("phone").is_none());
+    /// assert_eq!(contract.fields_with_tag("pii").count(), 1);
+    /// assert_eq!(contract.required_fields().next().unwrap().name, "id");
+    /// ```
+    pub fn field(&self, name: &str) -> Option<&Field> {
+        self.schema.fields.iter().find(|f| f.name == name)
+    }
+
+    /// Returns true if the schema declares a top-level field called `name`.
+    pub fn has_field(&self, name: &str) -> bool {
+        self.field(name).is_some()
+    }
+
+    /// Iterates over the top-level fields tagged `tag`.
+    pub fn fields_with_tag<'a>(&'a self, tag: &'a str) -> impl Iterator<Item = &'a Field> {
+        self.schema.fields.iter().filter(move |f| {
+            f.tags
+                .as_ref()
+                .is_some_and(|tags| tags.iter().any(|t| t == tag))
+        })
+    }
+
+    /// Iterates over the top-level fields that must be present in every
+    /// record.
+    pub fn required_fields(&self) -> impl Iterator<Item = &Field> {
+        self.schema.fields.iter().filter(|f| f.is_required())
+    }
+
+    /// Returns every field of the schema, nested struct fields included, in
+    /// depth-first order.
+    ///
+    /// Nested fields are named by their dotted path (`address.city`). Fields
+    /// of structs inside lists and maps are reached through the list element
+    /// or map value and named the same way (`items.sku`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use contracts_core::{ContractBuilder, DataFormat, FieldBuilder};
+    ///
+    /// let contract = ContractBuilder::new("orders", "sales")
+    ///     .location("s3://lake/orders")
+    ///     .format(DataFormat::Iceberg)
+    ///     .field(FieldBuilder::new("id", "int64").build())
+    ///     .field(FieldBuilder::new("items", "list<struct<sku:string,qty:int32>>").build())
+    ///     .build();
+    ///
+    /// let paths: Vec<String> = contract.nested_fields().into_iter().map(|f| f.path).collect();
+    /// assert_eq!(paths, ["id", "items", "items.sku", "items.qty"]);
+    /// ```
+    pub fn nested_fields(&self) -> Vec<NestedField<'_>> {
+        let mut fields = Vec::new();
+        for field in &self.schema.fields {
+            fields.push(NestedField {
+                path: field.name.clone(),
+                data_type: &field.field_type,
+                nullable: field.nullable,
+            });
+            collect_nested(&field.name, &field.field_type, &mut fields);
+        }
+        fields
+    }
+}
+
+/// A field of the schema at any depth, as returned by
+/// [`Contract::nested_fields`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct NestedField<'a> {
+    /// Dotted path from the top-level field
+    pub path: String,
+
+    /// Type of the field
+    pub data_type: &'a DataType,
+
+    /// Whether the field can be null
+    pub nullable: bool,
+}
+
+/// Adds the struct fields reachable from `data_type` below `path`.
+fn collect_nested<'a>(path: &str, data_type: &'a DataType, fields: &mut Vec<NestedField<'a>>) {
+    match data_type {
+        DataType::Primitive(_) => {}
+        DataType::List { element_type, .. } => collect_nested(path, element_type, fields),
+        DataType::Map { value_type, .. } => collect_nested(path, value_type, fields),
+        DataType::Struct { fields: children } => {
+            for child in children {
+                let child_path = format!("{}.{}", path, child.name);
+                fields.push(NestedField {
+                    path: child_path.clone(),
+                    data_type: &child.data_type,
+                    nullable: child.nullable,
+                });
+                collect_nested(&child_path, &child.data_type, fields);
+            }
+        }
+    }
+}
+
+/// Fills in the implicit defaults of a schema and its quality checks.
+fn fill_defaults(schema: &mut Schema, quality_checks: Option<&mut QualityChecks>) {
+    if let Some(connection) = &mut schema.connection {
+        connection
+            .catalog_type
+            .get_or_insert_with(|| ConnectionConfig::DEFAULT_CATALOG_TYPE.to_string());
+    }
+
+    let Some(qc) = quality_checks else {
+        return;
+    };
+    qc.on_empty.get_or_insert_default();
+    if let Some(uniqueness) = &mut qc.uniqueness {
+        uniqueness
+            .scope
+            .get_or_insert_with(|| UniquenessScope::Global.to_string());
+        if let Some(approximate) = &mut uniqueness.approximate {
+            approximate
+                .error_tolerance
+                .get_or_insert(ApproximateUniqueness::DEFAULT_ERROR_TOLERANCE);
+        }
+    }
+    for check in qc.referential.iter_mut().flatten() {
+        check
+            .threshold
+            .get_or_insert(ReferentialCheck::DEFAULT_THRESHOLD);
+    }
+
+    if let Some(ml) = &mut qc.ml_checks {
+        if let Some(drift) = &mut ml.feature_drift {
+            drift
+                .num_bins
+                .get_or_insert(FeatureDriftCheck::DEFAULT_NUM_BINS);
+            drift
+                .threshold
+                .get_or_insert(FeatureDriftCheck::DEFAULT_THRESHOLD);
+        }
+        if let Some(leakage) = &mut ml.target_leakage {
+            leakage
+                .max_correlation
+                .get_or_insert(TargetLeakageCheck::DEFAULT_MAX_CORRELATION);
+        }
+        if let Some(null_rate) = &mut ml.null_rate_by_group {
+            null_rate
+                .max_null_rate_diff
+                .get_or_insert(NullRateByGroupCheck::DEFAULT_MAX_NULL_RATE_DIFF);
+        }
+    }
+}
+
+/// Orders the fields of a schema by name, and their tags and constraints.
+fn sort_schema(schema: &mut Schema) {
+    schema.fields.sort_by(|a, b| a.name.cmp(&b.name));
+    for field in &mut schema.fields {
+        if field.required == Some(!field.nullable) {
+            field.required = None;
+        }
+        if let Some(tags) = &mut field.tags {
+            sort_dedup(tags);
+        }
+        if let Some(constraints) = &mut field.constraints {
+            for constraint in constraints.iter_mut() {
+                match constraint {
+                    FieldConstraints::AllowedValues { values } => sort_dedup(values),
+                    FieldConstraints::MapKeys {
+                        allowed: Some(allowed),
+                        ..
+                    } => sort_dedup(allowed),
+                    _ => {}
+                }
+            }
+            constraints.sort_by_cached_key(|c| serde_json::to_string(c).unwrap_or_default());
+            constraints.dedup();
+        }
+    }
+}
+
+/// Orders the field lists of quality and ML checks, and checks by name.
+fn sort_checks(qc: &mut QualityChecks) {
+    if let Some(completeness) = &mut qc.completeness {
+        sort_dedup(&mut completeness.fields);
+    }
+    if let Some(uniqueness) = &mut qc.uniqueness {
+        sort_dedup(&mut uniqueness.fields);
+    }
+    if let Some(custom) = &mut qc.custom_checks {
+        custom.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+    if let Some(rows) = &mut qc.row_checks {
+        rows.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+    if let Some(ml) = &mut qc.ml_checks {
+        if let Some(no_overlap) = &mut ml.no_overlap {
+            sort_dedup(&mut no_overlap.key_fields);
+        }
+        if let Some(drift) = &mut ml.feature_drift {
+            sort_dedup(&mut drift.feature_fields);
+        }
+        if let Some(leakage) = &mut ml.target_leakage {
+            sort_dedup(&mut leakage.feature_fields);
+        }
+        if let Some(null_rate) = &mut ml.null_rate_by_group {
+            sort_dedup(&mut null_rate.check_fields);
+        }
+    }
+}
+
+/// Sorts `values` and drops duplicates.
+fn sort_dedup(values: &mut Vec<String>) {
+    values.sort();
+    values.dedup();
 }
 
 /// Supported data format types for the dataset.
@@ -87,7 +561,7 @@ pub enum DataFormat {
 ///
 /// Describes the structure of the data including field definitions,
 /// storage format, and physical location.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Schema {
     /// List of field definitions in the schema
     pub fields: Vec<Field>,
@@ -97,13 +571,146 @@ pub struct Schema {
 
     /// Physical location of the data (e.g., S3 path, database URI)
     pub location: String,
+
+    /// Catalog holding the table, for catalog-backed formats such as Iceberg
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub catalog: Option<String>,
+
+    /// Namespace of the table within the catalog (dot-separated, e.g. "db.schema")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+
+    /// Name of the table within the namespace
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub table: Option<String>,
+
+    /// How to connect to the catalog holding the data
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connection: Option<ConnectionConfig>,
+}
+
+/// Change data capture envelopes the rows of a contract arrive in.
+///
+/// Debezium-style change events wrap each row: `op` is the operation (`c`
+/// create, `u` update, `d` delete, `r` snapshot read), `before` and `after`
+/// are the row images around it and `ts_ms` is when the change happened.
+/// The schema and checks apply to the `after` images; deletes have none.
+///
+/// # Example
+///
+/// ```yaml
+/// cdc:
+///   key: [order_id]
+///   allowed_ops: [c, u, d]
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CdcConfig {
+    /// Fields identifying a row; the changes of each key must arrive in
+    /// `ts_ms` order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub key: Vec<String>,
+
+    /// Operation codes accepted in `op` (default: `c`, `u`, `d` and `r`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_ops: Option<Vec<String>>,
+}
+
+impl CdcConfig {
+    /// Operation codes accepted when `allowed_ops` is not set.
+    pub const DEFAULT_OPS: [&'static str; 4] = ["c", "u", "d", "r"];
+
+    /// Returns the accepted operation codes.
+    pub fn allowed_ops(&self) -> Vec<&str> {
+        match &self.allowed_ops {
+            Some(ops) => ops.iter().map(String::as_str).collect(),
+            None => Self::DEFAULT_OPS.to_vec(),
+        }
+    }
+}
+
+/// A related dataset of a multi-dataset contract.
+///
+/// # Example
+///
+/// ```yaml
+/// datasets:
+///   order_items:
+///     description: Line items of each order
+///     schema:
+///       format: iceberg
+///       location: s3://lake/sales/order_items
+///       fields:
+///         - name: order_id
+///           type: int64
+///           nullable: false
+///     quality_checks:
+///       completeness:
+///         threshold: 0.99
+///         fields: [order_id]
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Dataset {
+    /// Human-readable description of the dataset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Schema definition including fields and format
+    pub schema: Schema,
+
+    /// Optional quality validation rules
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quality_checks: Option<QualityChecks>,
+}
+
+/// Catalog connection settings embedded in a contract.
+///
+/// String values may contain secret references that are resolved at
+/// connection time instead of being stored in the contract:
+/// `${env:NAME}` reads an environment variable and `${file:/path}` reads a file.
+///
+/// # Example
+///
+/// ```yaml
+/// connection:
+///   type: rest
+///   uri: https://catalog.example.com
+///   warehouse: s3://lake/warehouse
+///   properties:
+///     token: ${env:CATALOG_TOKEN}
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConnectionConfig {
+    /// Catalog type: `rest`, `glue`, `hms` or `fileio` (defaults to `rest`)
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub catalog_type: Option<String>,
+
+    /// Catalog endpoint URI (REST and HMS catalogs)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+
+    /// Warehouse location or identifier
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warehouse: Option<String>,
+
+    /// Additional catalog properties passed through to the catalog client
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub properties: HashMap<String, String>,
+}
+
+impl ConnectionConfig {
+    /// Catalog type used when `type` is omitted.
+    pub const DEFAULT_CATALOG_TYPE: &'static str = "rest";
 }
 
 /// A single field definition in a schema.
 ///
 /// Represents a column or field in the dataset with its type,
 /// nullability, and optional constraints.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Presence and nullability are separate conditions: `required` says the
+/// field must be present in every record, `nullable` says whether a present
+/// value may be null.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Field {
     /// Field name
     pub name: String,
@@ -115,20 +722,128 @@ pub struct Field {
     /// Whether the field can contain null values
     pub nullable: bool,
 
+    /// Whether the field must be present in every record; when unset, a
+    /// field is required exactly when it is not nullable
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required: Option<bool>,
+
     /// Optional human-readable description
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
     /// Optional tags for categorization or metadata
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tags: Option<Vec<String>>,
 
     /// Optional validation constraints
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub constraints: Option<Vec<FieldConstraints>>,
+
+    /// How constraint violations on this field are reported; unset means
+    /// they are errors. Missing fields, nulls and type mismatches are always
+    /// errors.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
+
+    /// Value producers write when they leave the field out, as in an Avro
+    /// or SQL column default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<serde_json::Value>,
+
+    /// Marks the field as deprecated, with the date after which producers
+    /// may stop writing it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<Deprecation>,
+
+    /// Who may read the field, for governance tools and catalog column tags
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub access: Option<FieldAccess>,
+}
+
+impl Field {
+    /// Returns true if the field must be present in every record.
+    ///
+    /// Contracts written before `required` existed used `nullable: false`
+    /// for both conditions, so an unset `required` follows `nullable`.
+    pub fn is_required(&self) -> bool {
+        self.required.unwrap_or(!self.nullable)
+    }
+
+    /// Returns true if a present value may be null: the field is nullable
+    /// and has no [`FieldConstraints::NotNull`] constraint.
+    pub fn allows_null(&self) -> bool {
+        self.nullable
+            && !self
+                .constraints
+                .iter()
+                .flatten()
+                .any(|c| matches!(c, FieldConstraints::NotNull))
+    }
+
+    /// Returns true if the field is deprecated and `today` is past its
+    /// removal date.
+    pub fn is_expired(&self, today: NaiveDate) -> bool {
+        self.deprecated
+            .as_ref()
+            .is_some_and(|d| d.is_expired(today))
+    }
+}
+
+/// Deprecation of a field.
+///
+/// A deprecated field stays in the contract until its `remove_after` date
+/// passes. From then on the field is expired: data should no longer populate
+/// it, and removing it from the contract is not a breaking change.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Deprecation {
+    /// Contract version or date the field was deprecated in
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub since: Option<String>,
+
+    /// Last day the field is guaranteed to be kept (`YYYY-MM-DD`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remove_after: Option<NaiveDate>,
+
+    /// Field consumers should read instead
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replacement: Option<String>,
+}
+
+impl Deprecation {
+    /// Returns true if `today` is past the removal date.
+    pub fn is_expired(&self, today: NaiveDate) -> bool {
+        self.remove_after.is_some_and(|date| today > date)
+    }
+}
+
+/// Access expectations of a field.
+///
+/// Catalogs and governance tools keep the same information as column tags;
+/// [`AccessDrift`](crate::AccessDrift) lists where they disagree with the
+/// contract.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldAccess {
+    /// Sensitivity class of the values (e.g., "public", "internal", "pii")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub classification: Option<String>,
+
+    /// Roles allowed to read the field; empty when the contract does not
+    /// restrict them
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_roles: Vec<String>,
+}
+
+impl FieldAccess {
+    /// Returns true if neither a classification nor roles are declared.
+    pub fn is_empty(&self) -> bool {
+        self.classification.is_none() && self.allowed_roles.is_empty()
+    }
 }
 
 /// Validation constraints that can be applied to a field.
 ///
 /// Defines rules that field values must satisfy for the data to be valid.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "lowercase")]
 pub enum FieldConstraints {
     /// Field value must be one of the allowed values
@@ -151,79 +866,750 @@ pub enum FieldConstraints {
         regex: String,
     },
 
-    /// Custom constraint with arbitrary definition
+    /// String field must have a number of characters within the bounds
+    Length {
+        /// Minimum number of characters (inclusive)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min: Option<usize>,
+        /// Maximum number of characters (inclusive)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max: Option<usize>,
+    },
+
+    /// String or binary field must take a number of bytes within the bounds,
+    /// strings measured in UTF-8
+    ByteLength {
+        /// Minimum number of bytes (inclusive)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min: Option<usize>,
+        /// Maximum number of bytes (inclusive)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max: Option<usize>,
+    },
+
+    /// List field must have a number of elements within the bounds
+    ListLength {
+        /// Minimum number of elements (inclusive)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min: Option<usize>,
+        /// Maximum number of elements (inclusive)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max: Option<usize>,
+    },
+
+    /// Keys of a map field must be allowed and/or match a pattern
+    MapKeys {
+        /// Regular expression every key must match
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pattern: Option<String>,
+        /// Keys that may appear in the map
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        allowed: Option<Vec<String>>,
+    },
+
+    /// List or map field must have at least one element
+    NonEmpty,
+
+    /// Field value must not be null when present, without making the field
+    /// required
+    NotNull,
+
+    /// At most this share of the records may have a null or missing value
+    MaxNullFraction {
+        /// Largest accepted share of nulls, from 0.0 to 1.0
+        fraction: f64,
+    },
+
+    /// Numeric field must fit a decimal type: at most `scale` fractional
+    /// digits and `precision - scale` integer digits
+    Precision {
+        /// Total number of significant digits
+        precision: u8,
+        /// Number of digits after the decimal point
+        scale: u8,
+    },
+
+    /// Timestamp or date field must be within the bounds, each an absolute
+    /// timestamp or relative to the time of validation (`now`, `now-30d`,
+    /// `now+1h`)
+    TimeRange {
+        /// Earliest accepted time (inclusive)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min: Option<String>,
+        /// Latest accepted time (inclusive)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max: Option<String>,
+        /// Whether values may be later than the time of validation
+        #[serde(default = "allow_future_default")]
+        allow_future: bool,
+    },
+
+    /// Number of distinct non-null values must be within the bounds
+    Cardinality {
+        /// Fewest distinct values (inclusive)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min_distinct: Option<usize>,
+        /// Most distinct values (inclusive)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max_distinct: Option<usize>,
+    },
+
+    /// Rows matching `when` must also match `require`, such as
+    /// `closed_at IS NOT NULL` on rows where `status = 'closed'`
+    Conditional {
+        /// SQL predicate selecting the rows the rule applies to
+        when: String,
+        /// SQL predicate those rows must satisfy
+        require: String,
+    },
+
+    /// Every non-null value must satisfy an expression over the field
+    /// (`value`) and the rest of the row, such as `value < len(code)`
     Custom {
-        /// Custom constraint definition
+        /// Expression the values must satisfy
         definition: String,
     },
 }
 
+fn allow_future_default() -> bool {
+    true
+}
+
+/// Short form of a constraint for messages, e.g. `range [0, 100]`.
+impl std::fmt::Display for FieldConstraints {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AllowedValues { values } => write!(f, "allowedvalues [{}]", values.join(", ")),
+            Self::Range { min, max } => write!(f, "range [{}, {}]", min, max),
+            Self::Pattern { regex } => write!(f, "pattern '{}'", regex),
+            Self::Length { min, max } => write!(
+                f,
+                "length [{}, {}]",
+                min.map_or("-".to_string(), |m| m.to_string()),
+                max.map_or("-".to_string(), |m| m.to_string())
+            ),
+            Self::ByteLength { min, max } => write!(
+                f,
+                "bytelength [{}, {}]",
+                min.map_or("-".to_string(), |m| m.to_string()),
+                max.map_or("-".to_string(), |m| m.to_string())
+            ),
+            Self::ListLength { min, max } => write!(
+                f,
+                "listlength [{}, {}]",
+                min.map_or("-".to_string(), |m| m.to_string()),
+                max.map_or("-".to_string(), |m| m.to_string())
+            ),
+            Self::MapKeys { pattern, allowed } => {
+                let mut parts = Vec::new();
+                if let Some(pattern) = pattern {
+                    parts.push(format!("pattern '{}'", pattern));
+                }
+                if let Some(allowed) = allowed {
+                    parts.push(format!("allowed [{}]", allowed.join(", ")));
+                }
+                write!(f, "mapkeys {}", parts.join(" "))
+            }
+            Self::NonEmpty => write!(f, "nonempty"),
+            Self::NotNull => write!(f, "notnull"),
+            Self::MaxNullFraction { fraction } => write!(f, "maxnullfraction {}", fraction),
+            Self::Precision { precision, scale } => {
+                write!(f, "precision ({}, {})", precision, scale)
+            }
+            Self::TimeRange {
+                min,
+                max,
+                allow_future,
+            } => {
+                write!(
+                    f,
+                    "timerange [{}, {}]",
+                    min.as_deref().unwrap_or("-"),
+                    max.as_deref().unwrap_or("-")
+                )?;
+                if !allow_future {
+                    write!(f, " no future")?;
+                }
+                Ok(())
+            }
+            Self::Cardinality {
+                min_distinct,
+                max_distinct,
+            } => write!(
+                f,
+                "cardinality [{}, {}]",
+                min_distinct.map_or("-".to_string(), |m| m.to_string()),
+                max_distinct.map_or("-".to_string(), |m| m.to_string())
+            ),
+            Self::Conditional { when, require } => {
+                write!(f, "conditional when '{}' require '{}'", when, require)
+            }
+            Self::Custom { definition } => write!(f, "custom '{}'", definition),
+        }
+    }
+}
+
 /// Quality check definitions for data validation.
 ///
 /// Specifies rules for data quality including completeness, uniqueness,
-/// freshness, and custom validation checks.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// freshness, and custom validation checks. Every check is optional, so
+/// literals name the checks they set and fill in the rest with
+/// `..Default::default()`, or use [`QualityChecksBuilder`](crate::QualityChecksBuilder).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct QualityChecks {
     /// Check for null/missing values
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub completeness: Option<CompletenessCheck>,
 
     /// Check for duplicate values
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub uniqueness: Option<UniquenessCheck>,
 
     /// Check for data staleness
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub freshness: Option<FreshnessCheck>,
 
+    /// Minimum number of records per event-time bucket
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub volume: Option<VolumeCheck>,
+
     /// User-defined validation checks
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub custom_checks: Option<Vec<CustomCheck>>,
 
     /// ML-specific quality checks
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub ml_checks: Option<MlChecks>,
+
+    /// What to do when there are no records to check (default: warn)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_empty: Option<EmptyDataPolicy>,
+
+    /// Limit on the share of rows hidden by row-level deletes (Iceberg only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_rows: Option<DeletedRowsCheck>,
+
+    /// Column marking soft-deleted rows, left out of completeness and
+    /// uniqueness
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub soft_delete: Option<SoftDelete>,
+
+    /// Foreign-key style checks against fields of other datasets
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub referential: Option<Vec<ReferentialCheck>>,
+
+    /// Expressions every row must satisfy
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub row_checks: Option<Vec<RowCheck>>,
+}
+
+/// Outcome of validating a dataset that has no records.
+///
+/// An empty sample cannot violate any data check, so without a policy a
+/// broken reader would look like a clean pass.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EmptyDataPolicy {
+    /// Pass without comment
+    Pass,
+    /// Pass with a warning
+    #[default]
+    Warn,
+    /// Fail validation
+    Fail,
+}
+
+impl std::str::FromStr for EmptyDataPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pass" => Ok(Self::Pass),
+            "warn" => Ok(Self::Warn),
+            "fail" => Ok(Self::Fail),
+            other => Err(format!(
+                "unknown empty data policy '{}' (expected pass, warn or fail)",
+                other
+            )),
+        }
+    }
 }
 
 /// Freshness check to ensure data is up-to-date.
 ///
 /// Validates that data is not stale by checking the time
 /// since the last update against a maximum allowed delay.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FreshnessCheck {
     /// Maximum allowed delay (e.g., "1h", "30m", "1d")
     pub max_delay: String,
 
-    /// Metric to measure freshness (e.g., "created_at", "updated_at")
+    /// Metric to measure freshness (e.g., "created_at", "updated_at");
+    /// not needed when the source is `snapshot_committed_at`
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub metric: String,
+
+    /// Where the time of the last update is read; unset reads the metric
+    /// column from the rows
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<FreshnessSource>,
+
+    /// How late records may still arrive (e.g., "15m"); the newest record
+    /// may then be up to `max_delay` plus this window old
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_lateness: Option<String>,
+
+    /// How a failure is reported; unset follows the strict/non-strict split
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
+}
+
+impl FreshnessCheck {
+    /// Returns where the check reads the time of the last update.
+    pub fn source(&self) -> FreshnessSource {
+        self.source.unwrap_or_default()
+    }
+
+    /// Returns the column the check reads, if it reads one.
+    pub fn column(&self) -> Option<&str> {
+        match self.source() {
+            FreshnessSource::SnapshotCommittedAt => None,
+            FreshnessSource::Column | FreshnessSource::Partition => Some(&self.metric),
+        }
+    }
+}
+
+/// Where a freshness check reads the time of the last update.
+///
+/// The metadata sources are evaluated from Iceberg table metadata, without
+/// reading rows; other formats only support [`FreshnessSource::Column`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FreshnessSource {
+    /// Newest value of the metric column in the validated rows
+    #[default]
+    Column,
+    /// Commit time of the validated Iceberg snapshot
+    SnapshotCommittedAt,
+    /// Newest value of the partition derived from the metric column, read
+    /// from the Iceberg manifests
+    Partition,
+}
+
+impl FreshnessSource {
+    /// Returns the name of the source, as written in contracts.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Column => "column",
+            Self::SnapshotCommittedAt => "snapshot_committed_at",
+            Self::Partition => "partition",
+        }
+    }
+
+    /// Returns true if the source is read from table metadata.
+    pub fn is_metadata(&self) -> bool {
+        !matches!(self, Self::Column)
+    }
+}
+
+/// Minimum number of records per event-time bucket.
+///
+/// Records are grouped into buckets of `bucket` width, aligned on the Unix
+/// epoch, by the event time in `metric`. Every bucket from the first one
+/// with records up to the last closed one must hold at least `min_rows`.
+///
+/// Streams deliver some records after their bucket has ended. A bucket is
+/// only checked once `allowed_lateness` has passed since its end, and when
+/// `arrival_metric` names the column recording when each record arrived, a
+/// record arriving after its bucket's end plus that window no longer counts
+/// toward the bucket.
+///
+/// # Example
+///
+/// ```yaml
+/// quality_checks:
+///   volume:
+///     metric: event_time
+///     bucket: 1h
+///     min_rows: 1000
+///     allowed_lateness: 15m
+///     arrival_metric: ingested_at
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VolumeCheck {
+    /// Timestamp column assigning records to buckets (e.g., "event_time")
+    pub metric: String,
+
+    /// Width of a bucket (e.g., "1h", "1d")
+    pub bucket: String,
+
+    /// Fewest records a closed bucket may hold
+    pub min_rows: u64,
+
+    /// Timestamp column recording when each record arrived; unset counts
+    /// every record toward its bucket
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub arrival_metric: Option<String>,
+
+    /// How long after a bucket ends its records may still arrive (e.g., "15m")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_lateness: Option<String>,
+
+    /// How a failure is reported; unset follows the strict/non-strict split
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
 }
 
 /// Completeness check for null/missing values.
 ///
 /// Ensures that specified fields have values in at least
 /// a certain percentage of records.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CompletenessCheck {
     /// Minimum percentage of non-null values (0.0 to 1.0)
     pub threshold: f64,
 
     /// List of fields to check
     pub fields: Vec<String>,
+
+    /// Minimum number of validated records needed for a meaningful result.
+    /// Below it the check is reported as inconclusive instead of evaluated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_sample_size: Option<usize>,
+
+    /// How a failure is reported; unset follows the strict/non-strict split
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
+}
+
+/// Limit on rows deleted through delete files.
+///
+/// Merge-on-read tables record deletes in separate files and leave the data
+/// files untouched until compaction. Reads apply the deletes, but a large
+/// backlog slows every query and usually means compaction is not running.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeletedRowsCheck {
+    /// Largest accepted share of deleted rows (0.0 to 1.0)
+    pub max_fraction: f64,
+}
+
+/// Column marking soft-deleted rows.
+///
+/// Tables that delete by flagging rows keep tombstones next to live data. A
+/// row is deleted when a boolean column is `true`, or when a column of any
+/// other type (such as a `_deleted_at` timestamp) is set. Completeness and
+/// uniqueness are computed over the live rows only, unless `include_deleted`
+/// is set.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SoftDelete {
+    /// Column holding the deletion flag or time
+    pub column: String,
+
+    /// Whether completeness and uniqueness still count deleted rows
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub include_deleted: bool,
+}
+
+impl SoftDelete {
+    /// Returns the soft-delete column if the checks leave deleted rows out.
+    pub fn excluded_column(&self) -> Option<&str> {
+        (!self.include_deleted).then_some(self.column.as_str())
+    }
+}
+
+/// Foreign-key style check that the values of a field exist in a field of
+/// another dataset.
+///
+/// `references` names the dataset and field as `dataset.field`, where the
+/// dataset is the contract itself (by its name) or one of its `datasets:`.
+/// Null values reference nothing and are not checked.
+///
+/// # Example
+///
+/// ```yaml
+/// quality_checks:
+///   referential:
+///     - field: customer_id
+///       references: customers.id
+///       threshold: 0.999
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReferentialCheck {
+    /// Field holding the references
+    pub field: String,
+
+    /// Referenced dataset and field, as `dataset.field`
+    pub references: String,
+
+    /// Share of non-null values that must be found (default: 1.0)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threshold: Option<f64>,
+
+    /// How a failure is reported; unset follows the strict/non-strict split
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
+}
+
+impl ReferentialCheck {
+    /// Share of values that must be found when `threshold` is omitted.
+    pub const DEFAULT_THRESHOLD: f64 = 1.0;
+
+    /// Returns the referenced dataset and field, or `None` if `references`
+    /// is not of the form `dataset.field`.
+    pub fn target(&self) -> Option<(&str, &str)> {
+        self.references
+            .rsplit_once('.')
+            .filter(|(dataset, field)| !dataset.is_empty() && !field.is_empty())
+    }
+
+    /// Returns the share of non-null values that must be found.
+    pub fn threshold(&self) -> f64 {
+        self.threshold.unwrap_or(Self::DEFAULT_THRESHOLD)
+    }
+}
+
+/// Check that every row satisfies an expression over its fields.
+///
+/// The expression is written in the language of `custom` constraints, with
+/// every field of the row bound by name. A row for which it is null passes,
+/// as in a SQL `CHECK`.
+///
+/// # Example
+///
+/// ```yaml
+/// quality_checks:
+///   row_checks:
+///     - name: shipped_after_ordered
+///       expression: status != 'shipped' || shipped_at >= ordered_at
+///     - name: discount_below_total
+///       expression: discount * 2 < total
+///       severity: warning
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RowCheck {
+    /// Name of the check
+    pub name: String,
+
+    /// Expression every row must satisfy
+    pub expression: String,
+
+    /// How a failure is reported; unset follows the strict/non-strict split
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
 }
 
 /// Uniqueness check for duplicate detection.
 ///
 /// Validates that combinations of specified fields are unique
 /// within a defined scope.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct UniquenessCheck {
     /// Fields that should be unique together
     pub fields: Vec<String>,
 
-    /// Optional scope for uniqueness (e.g., "per_day", "global")
+    /// Groups of rows duplicates are looked for in, parsed by
+    /// [`UniquenessCheck::parsed_scope`]: `global` (the default), a period
+    /// of a timestamp column such as `per_day(created_at)`, or partition
+    /// columns such as `per_partition(region)`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub scope: Option<String>,
+
+    /// Minimum number of validated records needed for a meaningful result.
+    /// Below it the check is reported as inconclusive instead of evaluated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_sample_size: Option<usize>,
+
+    /// How a failure is reported; unset follows the strict/non-strict split
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
+
+    /// Counts duplicates approximately, in bounded memory, instead of
+    /// keeping every key
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approximate: Option<ApproximateUniqueness>,
+}
+
+/// Approximate duplicate counting for a uniqueness check.
+///
+/// The keys are folded into a HyperLogLog sketch whose size depends on the
+/// error tolerance, not on the number of rows, so multi-million-row samples
+/// are checked in a few KiB.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ApproximateUniqueness {
+    /// Relative error accepted on the number of distinct keys, above 0 and
+    /// below 1; defaults to 0.01
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_tolerance: Option<f64>,
+
+    /// Most memory the sketch may take, in KiB; caps its precision when the
+    /// tolerance would need more
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_memory_kb: Option<usize>,
+}
+
+impl ApproximateUniqueness {
+    /// Relative error accepted when `error_tolerance` is omitted.
+    pub const DEFAULT_ERROR_TOLERANCE: f64 = 0.01;
+
+    /// Returns the relative error accepted on the number of distinct keys.
+    pub fn error_tolerance(&self) -> f64 {
+        self.error_tolerance
+            .unwrap_or(Self::DEFAULT_ERROR_TOLERANCE)
+    }
+}
+
+impl UniquenessCheck {
+    /// Parses the scope of the check; unset is [`UniquenessScope::Global`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use contracts_core::{ScopePeriod, UniquenessCheck, UniquenessScope};
+    ///
+    /// let check = UniquenessCheck {
+    ///     fields: vec!["order_id".to_string()],
+    ///     scope: Some("per_day(created_at)".to_string()),
+    ///     min_sample_size: None,
+    ///     severity: None,
+    ///     approximate: None,
+    /// };
+    /// assert_eq!(
+    ///     check.parsed_scope(),
+    ///     Ok(UniquenessScope::Period {
+    ///         period: ScopePeriod::Day,
+    ///         column: Some("created_at".to_string()),
+    ///     })
+    /// );
+    /// ```
+    pub fn parsed_scope(&self) -> Result<UniquenessScope, String> {
+        self.scope
+            .as_deref()
+            .map_or(Ok(UniquenessScope::Global), str::parse)
+    }
+}
+
+/// Groups of rows a uniqueness check looks for duplicates in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UniquenessScope {
+    /// Every row (`global`)
+    Global,
+    /// Rows in the same period of a timestamp column (`per_day(created_at)`);
+    /// without a column, the freshness check's metric
+    Period {
+        /// Length of the periods
+        period: ScopePeriod,
+        /// Timestamp or date column the periods are taken from
+        column: Option<String>,
+    },
+    /// Rows with the same values of the partition columns
+    /// (`per_partition(region, day)`)
+    Partition {
+        /// Columns the table is partitioned by
+        columns: Vec<String>,
+    },
+}
+
+/// Period of a [`UniquenessScope::Period`], in UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScopePeriod {
+    /// Calendar hour
+    Hour,
+    /// Calendar day
+    Day,
+    /// Calendar month
+    Month,
+    /// Calendar year
+    Year,
+}
+
+impl ScopePeriod {
+    /// Returns the name of the period, as in `per_<name>`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Hour => "hour",
+            Self::Day => "day",
+            Self::Month => "month",
+            Self::Year => "year",
+        }
+    }
+}
+
+impl std::str::FromStr for UniquenessScope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (name, columns) = match s.split_once('(') {
+            Some((name, rest)) => {
+                let columns = rest
+                    .strip_suffix(')')
+                    .ok_or_else(|| format!("missing ')' in scope '{}'", s))?;
+                let columns: Vec<String> = columns
+                    .split(',')
+                    .map(|c| c.trim().to_string())
+                    .filter(|c| !c.is_empty())
+                    .collect();
+                (name.trim(), Some(columns))
+            }
+            None => (s, None),
+        };
+        let period = match name {
+            "global" if columns.is_none() => return Ok(Self::Global),
+            "per_partition" => {
+                return match columns {
+                    Some(columns) if !columns.is_empty() => Ok(Self::Partition { columns }),
+                    _ => Err("per_partition needs the partition columns, as in \
+                         per_partition(region)"
+                        .to_string()),
+                };
+            }
+            "per_hour" => ScopePeriod::Hour,
+            "per_day" => ScopePeriod::Day,
+            "per_month" => ScopePeriod::Month,
+            "per_year" => ScopePeriod::Year,
+            _ => {
+                return Err(format!(
+                    "unknown scope '{}', expected global, per_hour, per_day, per_month, \
+                     per_year or per_partition",
+                    s
+                ));
+            }
+        };
+        match columns.as_deref() {
+            None => Ok(Self::Period {
+                period,
+                column: None,
+            }),
+            Some([column]) => Ok(Self::Period {
+                period,
+                column: Some(column.clone()),
+            }),
+            Some(_) => Err(format!("{} takes a single timestamp column", name)),
+        }
+    }
+}
+
+impl std::fmt::Display for UniquenessScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Global => f.write_str("global"),
+            Self::Period { period, column } => {
+                write!(f, "per_{}", period.as_str())?;
+                if let Some(column) = column {
+                    write!(f, "({})", column)?;
+                }
+                Ok(())
+            }
+            Self::Partition { columns } => write!(f, "per_partition({})", columns.join(", ")),
+        }
+    }
 }
 
 /// Custom validation check with user-defined logic.
 ///
 /// Allows arbitrary validation rules to be specified
 /// using a custom definition language or SQL expression.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CustomCheck {
     /// Name of the custom check
     pub name: String,
@@ -232,7 +1618,58 @@ pub struct CustomCheck {
     pub definition: String,
 
     /// Severity level (e.g., "error", "warning", "info")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub severity: Option<String>,
+
+    /// Number of offending rows returned by the query to attach to the
+    /// report when the check fails; unset attaches none
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capture_rows: Option<usize>,
+}
+
+/// How a failed check is reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// Fails validation
+    Error,
+    /// Reported without failing validation
+    Warning,
+    /// Recorded for information only
+    Info,
+}
+
+impl Severity {
+    /// Severity of a check without its own: an error in strict mode,
+    /// otherwise a warning.
+    pub fn default_for(strict: bool) -> Self {
+        if strict { Self::Error } else { Self::Warning }
+    }
+
+    /// Returns the lowercase name of the severity, as written in contracts.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+            Self::Info => "info",
+        }
+    }
+}
+
+impl std::str::FromStr for Severity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "error" => Ok(Self::Error),
+            "warning" => Ok(Self::Warning),
+            "info" => Ok(Self::Info),
+            other => Err(format!(
+                "unknown severity '{}' (expected error, warning or info)",
+                other
+            )),
+        }
+    }
 }
 
 /// ML-specific quality checks for machine learning datasets.
@@ -240,24 +1677,30 @@ pub struct CustomCheck {
 /// These checks ensure that datasets used for ML training and evaluation
 /// follow best practices around data splitting, class balance, and
 /// feature-target separation.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MlChecks {
     /// Ensures train/test/validation splits have no overlapping rows
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub no_overlap: Option<NoOverlapCheck>,
 
     /// Validates temporal ordering in train/test splits
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub temporal_split: Option<TemporalSplitCheck>,
 
     /// Validates class label distribution is not overly skewed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub class_balance: Option<ClassBalanceCheck>,
 
     /// Detects feature distribution drift between splits using PSI
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub feature_drift: Option<FeatureDriftCheck>,
 
     /// Detects features with suspiciously high correlation to the target
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub target_leakage: Option<TargetLeakageCheck>,
 
     /// Detects disparate null rates across groups/splits
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub null_rate_by_group: Option<NullRateByGroupCheck>,
 }
 
@@ -265,7 +1708,7 @@ pub struct MlChecks {
 ///
 /// For ML pipelines, it is critical that the train, validation, and test sets
 /// share no rows. This check validates uniqueness of a key field across splits.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NoOverlapCheck {
     /// The field that denotes the split (e.g., "split" with values "train"/"test"/"val")
     pub split_field: String,
@@ -282,7 +1725,7 @@ pub struct NoOverlapCheck {
 /// When `split_order` is provided, validates all adjacent pairs in order
 /// (e.g., `["train", "val", "test"]` checks train <= val and val <= test).
 /// Otherwise falls back to the two-field `train_split`/`test_split` behavior.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TemporalSplitCheck {
     /// The field that denotes the split (e.g., "split")
     pub split_field: String,
@@ -298,6 +1741,7 @@ pub struct TemporalSplitCheck {
 
     /// Ordered list of split names for N-way temporal validation.
     /// When present, overrides `train_split`/`test_split`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub split_order: Option<Vec<String>>,
 }
 
@@ -305,7 +1749,7 @@ pub struct TemporalSplitCheck {
 ///
 /// Extremely imbalanced datasets can silently degrade model quality.
 /// This check ensures no single class exceeds a maximum proportion.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ClassBalanceCheck {
     /// The label/target field to check
     pub label_field: String,
@@ -316,14 +1760,20 @@ pub struct ClassBalanceCheck {
 
     /// Minimum allowed proportion for any single class (0.0 to 1.0)
     /// e.g., 0.01 means every class must be >=1% of the data
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub min_proportion: Option<f64>,
+
+    /// Minimum number of validated records needed for a meaningful result.
+    /// Below it the check is reported as inconclusive instead of evaluated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_sample_size: Option<usize>,
 }
 
 /// Detects feature distribution drift between a reference and current split
 /// using Population Stability Index (PSI).
 ///
 /// PSI > 0.1 suggests moderate drift; > 0.2 suggests significant drift.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FeatureDriftCheck {
     /// The field that denotes the split (e.g., "split")
     pub split_field: String,
@@ -338,10 +1788,25 @@ pub struct FeatureDriftCheck {
     pub feature_fields: Vec<String>,
 
     /// Number of bins for PSI calculation (default: 10)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub num_bins: Option<usize>,
 
     /// PSI threshold above which drift is flagged (default: 0.2)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub threshold: Option<f64>,
+
+    /// Minimum number of validated records needed for a meaningful result.
+    /// Below it the check is reported as inconclusive instead of evaluated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_sample_size: Option<usize>,
+}
+
+impl FeatureDriftCheck {
+    /// Number of PSI bins used when `num_bins` is omitted.
+    pub const DEFAULT_NUM_BINS: usize = 10;
+
+    /// PSI threshold used when `threshold` is omitted.
+    pub const DEFAULT_THRESHOLD: f64 = 0.2;
 }
 
 /// Detects features with suspiciously high correlation to the target,
@@ -349,7 +1814,7 @@ pub struct FeatureDriftCheck {
 ///
 /// Computes Pearson correlation between each feature and the target.
 /// Features exceeding `max_correlation` are flagged.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TargetLeakageCheck {
     /// The target/label field
     pub target_field: String,
@@ -358,14 +1823,25 @@ pub struct TargetLeakageCheck {
     pub feature_fields: Vec<String>,
 
     /// Maximum allowed absolute correlation (default: 0.95)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub max_correlation: Option<f64>,
+
+    /// Minimum number of validated records needed for a meaningful result.
+    /// Below it the check is reported as inconclusive instead of evaluated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_sample_size: Option<usize>,
+}
+
+impl TargetLeakageCheck {
+    /// Correlation limit used when `max_correlation` is omitted.
+    pub const DEFAULT_MAX_CORRELATION: f64 = 0.95;
 }
 
 /// Detects disparate null rates across groups or splits.
 ///
 /// Flags fields where the difference in null rates between groups
 /// exceeds a threshold, indicating potential data quality issues.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NullRateByGroupCheck {
     /// The field used to group rows (e.g., "split", "region")
     pub group_field: String,
@@ -374,21 +1850,206 @@ pub struct NullRateByGroupCheck {
     pub check_fields: Vec<String>,
 
     /// Maximum allowed difference in null rates across groups (default: 0.1)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub max_null_rate_diff: Option<f64>,
+
+    /// Minimum number of validated records needed for a meaningful result.
+    /// Below it the check is reported as inconclusive instead of evaluated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_sample_size: Option<usize>,
+}
+
+impl NullRateByGroupCheck {
+    /// Null rate difference allowed when `max_null_rate_diff` is omitted.
+    pub const DEFAULT_MAX_NULL_RATE_DIFF: f64 = 0.1;
 }
 
 /// Service Level Agreement for data availability and performance.
 ///
 /// Defines guarantees about data availability, query response times,
 /// and consequences for SLA violations.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SLA {
     /// Guaranteed availability percentage (0.0 to 1.0)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub availability: Option<f64>,
 
     /// Maximum response time for queries (e.g., "100ms", "1s")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub response_time: Option<String>,
 
     /// Description of penalties for SLA violations
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub penalties: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ContractBuilder, FieldBuilder, QualityChecksBuilder};
+
+    fn contract(fields: &[&str], tags: &[&str], unique: &[&str]) -> Contract {
+        let tags: Vec<String> = tags.iter().map(|t| t.to_string()).collect();
+        ContractBuilder::new("orders", "sales")
+            .location("s3://lake/orders")
+            .format(DataFormat::Iceberg)
+            .fields(
+                fields
+                    .iter()
+                    .map(|name| {
+                        FieldBuilder::new(*name, "string")
+                            .tags(tags.clone())
+                            .build()
+                    })
+                    .collect(),
+            )
+            .quality_checks(
+                QualityChecksBuilder::new()
+                    .uniqueness(UniquenessCheck {
+                        fields: unique.iter().map(|f| f.to_string()).collect(),
+                        scope: None,
+                        min_sample_size: None,
+                        severity: None,
+                        approximate: None,
+                    })
+                    .build(),
+            )
+            .build()
+    }
+
+    #[test]
+    fn test_content_hash_ignores_order() {
+        let a = contract(&["id", "total"], &["pii", "finance"], &["id", "total"]);
+        let b = contract(
+            &["total", "id"],
+            &["finance", "pii", "pii"],
+            &["total", "id"],
+        );
+        assert_ne!(a, b);
+        assert_eq!(a.content_hash(), b.content_hash());
+
+        let mut with_default = a.clone();
+        with_default
+            .quality_checks
+            .as_mut()
+            .unwrap()
+            .on_empty
+            .get_or_insert_default();
+        assert_eq!(a.content_hash(), with_default.content_hash());
+
+        let other = contract(&["id", "total"], &["pii"], &["id"]);
+        assert_ne!(a.content_hash(), other.content_hash());
+
+        // Related datasets are made canonical the same way
+        let with_dataset = |related: Contract| {
+            let mut contract = a.clone();
+            contract.datasets = Some(BTreeMap::from([(
+                "items".to_string(),
+                Dataset {
+                    description: None,
+                    schema: related.schema,
+                    quality_checks: related.quality_checks,
+                },
+            )]));
+            contract
+        };
+        let items = with_dataset(contract(
+            &["sku", "qty"],
+            &["pii", "finance"],
+            &["sku", "qty"],
+        ));
+        let reordered = with_dataset(contract(
+            &["qty", "sku"],
+            &["finance", "pii"],
+            &["qty", "sku"],
+        ));
+        assert_ne!(items, reordered);
+        assert_eq!(items.content_hash(), reordered.content_hash());
+        assert_ne!(items.content_hash(), a.content_hash());
+    }
+
+    #[test]
+    fn test_nested_fields_follow_structs_through_maps() {
+        let contract = ContractBuilder::new("users", "team")
+            .location("s3://lake/users")
+            .format(DataFormat::Iceberg)
+            .field(
+                FieldBuilder::new(
+                    "address",
+                    "struct<city:string,geo:struct<lat:float64,lon:float64>>",
+                )
+                .build(),
+            )
+            .field(FieldBuilder::new("devices", "map<string,struct<os:string>>").build())
+            .build();
+
+        let fields = contract.nested_fields();
+        let paths: Vec<&str> = fields.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "address",
+                "address.city",
+                "address.geo",
+                "address.geo.lat",
+                "address.geo.lon",
+                "devices",
+                "devices.os"
+            ]
+        );
+        assert_eq!(fields[3].data_type.to_string(), "float64");
+    }
+
+    #[test]
+    fn test_content_hash_sorts_connection_properties() {
+        let with_properties = |pairs: &[(&str, &str)]| {
+            let mut contract = contract(&["id"], &[], &["id"]);
+            contract.schema.connection = Some(ConnectionConfig {
+                properties: pairs
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+                ..Default::default()
+            });
+            contract
+        };
+        let pairs: Vec<(String, String)> =
+            (0..16).map(|i| (format!("k{i}"), i.to_string())).collect();
+        let pairs: Vec<(&str, &str)> = pairs
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+        let reversed: Vec<_> = pairs.iter().rev().copied().collect();
+
+        assert_eq!(
+            with_properties(&pairs).content_hash(),
+            with_properties(&reversed).content_hash()
+        );
+    }
+
+    #[test]
+    fn test_parse_uniqueness_scopes() {
+        let parse = |s: &str| s.parse::<UniquenessScope>();
+        assert_eq!(parse("global"), Ok(UniquenessScope::Global));
+        assert_eq!(
+            parse("per_month"),
+            Ok(UniquenessScope::Period {
+                period: ScopePeriod::Month,
+                column: None,
+            })
+        );
+        assert_eq!(
+            parse(" per_partition(region, day) "),
+            Ok(UniquenessScope::Partition {
+                columns: vec!["region".to_string(), "day".to_string()],
+            })
+        );
+        for scope in ["per_day(created_at)", "per_partition(region, day)"] {
+            assert_eq!(parse(scope).unwrap().to_string(), scope);
+        }
+        assert!(parse("per_week").is_err());
+        assert!(parse("per_partition").is_err());
+        assert!(parse("per_day(a, b)").is_err());
+        assert!(parse("per_day(created_at").is_err());
+    }
+}
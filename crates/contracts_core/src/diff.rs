@@ -0,0 +1,1085 @@
+//! Differences between two versions of a contract.
+//!
+//! [`ContractDiff::compare`] lists the schema changes from an old contract
+//! to a new one and classifies each as breaking or not, so contract changes
+//! can be gated in CI. The related datasets of a multi-dataset contract are
+//! compared by name, the same way as its own schema.
+//!
+//! A change is breaking when a consumer relying on the old contract can be
+//! broken by it (a field disappears or changes type), or when data that
+//! satisfied the old contract can fail the new one (a field becomes
+//! required, a constraint is added or tightened). A required field added
+//! with a default is not breaking, since records written without it read
+//! as the default, and neither is removing a deprecated field whose removal
+//! date has passed, since consumers were told to stop reading it.
+//!
+//! A changed constraint lists the parameters that changed with their old
+//! and new values, and the items added to or removed from list parameters,
+//! so changelogs can be generated from the diff.
+
+use crate::{Contract, DataFormat, DataType, Field, FieldConstraints, PrimitiveType, Schema};
+use chrono::{NaiveDate, Utc};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// A single schema change between two contract versions.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Change {
+    /// The data format changed
+    FormatChanged {
+        /// Related dataset whose format changed, `None` for the contract's own
+        #[serde(skip_serializing_if = "Option::is_none")]
+        dataset: Option<String>,
+        /// Old format
+        from: DataFormat,
+        /// New format
+        to: DataFormat,
+    },
+
+    /// The data location changed
+    LocationChanged {
+        /// Related dataset whose location changed, `None` for the contract's own
+        #[serde(skip_serializing_if = "Option::is_none")]
+        dataset: Option<String>,
+        /// Old location
+        from: String,
+        /// New location
+        to: String,
+    },
+
+    /// A related dataset was added
+    DatasetAdded {
+        /// Dataset name
+        dataset: String,
+    },
+
+    /// A related dataset was removed
+    DatasetRemoved {
+        /// Dataset name
+        dataset: String,
+    },
+
+    /// A field was added
+    FieldAdded {
+        /// Field name
+        field: String,
+        /// Whether the new field may be null
+        nullable: bool,
+        /// Whether the new field must be present
+        required: bool,
+        /// Whether the new field declares a default for records written
+        /// without it
+        has_default: bool,
+    },
+
+    /// A field was removed
+    FieldRemoved {
+        /// Field name
+        field: String,
+        /// Whether the field was deprecated and past its removal date
+        expired: bool,
+    },
+
+    /// A field's type changed
+    TypeChanged {
+        /// Field name
+        field: String,
+        /// Old type
+        from: String,
+        /// New type
+        to: String,
+        /// Whether every old value is representable in the new type
+        widened: bool,
+    },
+
+    /// A nullable field became required
+    NullabilityTightened {
+        /// Field name
+        field: String,
+    },
+
+    /// A required field became nullable
+    NullabilityRelaxed {
+        /// Field name
+        field: String,
+    },
+
+    /// A field that could be absent must now be present
+    PresenceTightened {
+        /// Field name
+        field: String,
+    },
+
+    /// A field that had to be present may now be absent
+    PresenceRelaxed {
+        /// Field name
+        field: String,
+    },
+
+    /// A constraint was added to a field
+    ConstraintAdded {
+        /// Field name
+        field: String,
+        /// The new constraint
+        constraint: FieldConstraints,
+    },
+
+    /// A constraint was removed from a field
+    ConstraintRemoved {
+        /// Field name
+        field: String,
+        /// The old constraint
+        constraint: FieldConstraints,
+    },
+
+    /// A constraint of a field changed its parameters
+    ConstraintChanged {
+        /// Field name
+        field: String,
+        /// The old constraint
+        from: FieldConstraints,
+        /// The new constraint
+        to: FieldConstraints,
+        /// The parameters that changed
+        parameters: Vec<ParameterChange>,
+    },
+}
+
+/// A parameter of a constraint whose value changed.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ParameterChange {
+    /// Parameter name, as written in contracts
+    pub parameter: String,
+
+    /// Old value, `None` when the parameter was not set
+    pub from: Option<Value>,
+
+    /// New value, `None` when the parameter is no longer set
+    pub to: Option<Value>,
+
+    /// Items of a list parameter the old value lacks
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub added: Vec<Value>,
+
+    /// Items of a list parameter the new value lacks
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub removed: Vec<Value>,
+}
+
+impl ParameterChange {
+    /// Lists the parameters that differ between two constraints of the same
+    /// kind, by name.
+    pub fn between(from: &FieldConstraints, to: &FieldConstraints) -> Vec<Self> {
+        let parameters = |constraint: &FieldConstraints| match serde_json::to_value(constraint) {
+            Ok(Value::Object(mut map)) => {
+                map.remove("type");
+                map.into_iter()
+                    .filter(|(_, value)| !value.is_null())
+                    .collect::<BTreeMap<_, _>>()
+            }
+            _ => BTreeMap::new(),
+        };
+        let (mut old, new) = (parameters(from), parameters(to));
+
+        let mut changes = Vec::new();
+        for (parameter, value) in new {
+            let previous = old.remove(&parameter);
+            if previous.as_ref() != Some(&value) {
+                changes.push(Self::new(parameter, previous, Some(value)));
+            }
+        }
+        for (parameter, value) in old {
+            changes.push(Self::new(parameter, Some(value), None));
+        }
+        changes.sort_by(|a, b| a.parameter.cmp(&b.parameter));
+        changes
+    }
+
+    fn new(parameter: String, from: Option<Value>, to: Option<Value>) -> Self {
+        let (added, removed) = match (&from, &to) {
+            (Some(Value::Array(old)), Some(Value::Array(new))) => (
+                new.iter().filter(|v| !old.contains(v)).cloned().collect(),
+                old.iter().filter(|v| !new.contains(v)).cloned().collect(),
+            ),
+            _ => (Vec::new(), Vec::new()),
+        };
+        Self {
+            parameter,
+            from,
+            to,
+            added,
+            removed,
+        }
+    }
+}
+
+/// Short form of a parameter change, e.g. `max 120 → 150` or
+/// `values added void`.
+impl fmt::Display for ParameterChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.added.is_empty() || !self.removed.is_empty() {
+            write!(f, "{}", self.parameter)?;
+            let mut separator = " ";
+            for (verb, items) in [("added", &self.added), ("removed", &self.removed)] {
+                if !items.is_empty() {
+                    let items: Vec<String> = items.iter().map(show).collect();
+                    write!(f, "{}{} {}", separator, verb, items.join(", "))?;
+                    separator = ", ";
+                }
+            }
+            return Ok(());
+        }
+        write!(
+            f,
+            "{} {} → {}",
+            self.parameter,
+            self.from.as_ref().map_or("-".to_string(), show),
+            self.to.as_ref().map_or("-".to_string(), show)
+        )
+    }
+}
+
+/// Formats a parameter value as contracts write it, strings unquoted and
+/// whole floats without a fraction.
+fn show(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Number(n) if n.is_f64() => n.as_f64().map_or(n.to_string(), |f| f.to_string()),
+        Value::Array(items) => format!(
+            "[{}]",
+            items.iter().map(show).collect::<Vec<_>>().join(", ")
+        ),
+        value => value.to_string(),
+    }
+}
+
+impl Change {
+    /// Returns true if the change can break consumers or invalidate data
+    /// that satisfied the old contract.
+    pub fn is_breaking(&self) -> bool {
+        match self {
+            Self::FormatChanged { .. } | Self::LocationChanged { .. } => true,
+            Self::DatasetAdded { .. } => false,
+            Self::DatasetRemoved { .. } => true,
+            Self::FieldAdded {
+                required,
+                has_default,
+                ..
+            } => *required && !has_default,
+            Self::FieldRemoved { expired, .. } => !expired,
+            Self::TypeChanged { widened, .. } => !widened,
+            Self::NullabilityTightened { .. } => true,
+            Self::NullabilityRelaxed { .. } => false,
+            Self::PresenceTightened { .. } => true,
+            Self::PresenceRelaxed { .. } => false,
+            Self::ConstraintAdded { .. } => true,
+            Self::ConstraintRemoved { .. } => false,
+            Self::ConstraintChanged { from, to, .. } => !relaxes(from, to),
+        }
+    }
+
+    /// Returns the field the change applies to, if any.
+    pub fn field(&self) -> Option<&str> {
+        match self {
+            Self::FormatChanged { .. }
+            | Self::LocationChanged { .. }
+            | Self::DatasetAdded { .. }
+            | Self::DatasetRemoved { .. } => None,
+            Self::FieldAdded { field, .. }
+            | Self::FieldRemoved { field, .. }
+            | Self::TypeChanged { field, .. }
+            | Self::NullabilityTightened { field }
+            | Self::NullabilityRelaxed { field }
+            | Self::PresenceTightened { field }
+            | Self::PresenceRelaxed { field }
+            | Self::ConstraintAdded { field, .. }
+            | Self::ConstraintRemoved { field, .. }
+            | Self::ConstraintChanged { field, .. } => Some(field),
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FormatChanged { dataset, from, to } => {
+                write_dataset(f, dataset)?;
+                write!(f, "format changed from {:?} to {:?}", from, to)
+            }
+            Self::LocationChanged { dataset, from, to } => {
+                write_dataset(f, dataset)?;
+                write!(f, "location changed from {} to {}", from, to)
+            }
+            Self::DatasetAdded { dataset } => write!(f, "dataset '{}' added", dataset),
+            Self::DatasetRemoved { dataset } => write!(f, "dataset '{}' removed", dataset),
+            Self::FieldAdded {
+                field,
+                nullable,
+                required,
+                has_default,
+            } => {
+                let kind = match (required, nullable) {
+                    (true, _) => "required",
+                    (false, true) => "nullable",
+                    (false, false) => "optional",
+                };
+                write!(f, "{} field '{}' added", kind, field)?;
+                if *has_default {
+                    f.write_str(" with a default")?;
+                }
+                Ok(())
+            }
+            Self::FieldRemoved { field, expired } => {
+                write!(f, "field '{}' removed", field)?;
+                if *expired {
+                    f.write_str(" after its removal date")?;
+                }
+                Ok(())
+            }
+            Self::TypeChanged {
+                field, from, to, ..
+            } => write!(f, "field '{}' changed type from {} to {}", field, from, to),
+            Self::NullabilityTightened { field } => {
+                write!(f, "field '{}' is no longer nullable", field)
+            }
+            Self::NullabilityRelaxed { field } => write!(f, "field '{}' is now nullable", field),
+            Self::PresenceTightened { field } => {
+                write!(f, "field '{}' must now be present", field)
+            }
+            Self::PresenceRelaxed { field } => write!(f, "field '{}' may now be absent", field),
+            Self::ConstraintAdded { field, constraint } => {
+                write!(f, "field '{}' gained constraint {}", field, constraint)
+            }
+            Self::ConstraintRemoved { field, constraint } => {
+                write!(f, "field '{}' lost constraint {}", field, constraint)
+            }
+            Self::ConstraintChanged {
+                field,
+                from,
+                to,
+                parameters,
+            } => {
+                if parameters.is_empty() {
+                    return write!(f, "field '{}' changed constraint {} to {}", field, from, to);
+                }
+                let parameters: Vec<String> = parameters.iter().map(|p| p.to_string()).collect();
+                write!(
+                    f,
+                    "field '{}' changed constraint {}: {}",
+                    field,
+                    kind(to),
+                    parameters.join(", ")
+                )
+            }
+        }
+    }
+}
+
+/// Names the related dataset a change applies to, if any.
+fn write_dataset(f: &mut fmt::Formatter<'_>, dataset: &Option<String>) -> fmt::Result {
+    match dataset {
+        Some(dataset) => write!(f, "dataset '{}' ", dataset),
+        None => Ok(()),
+    }
+}
+
+/// The schema changes from one contract version to the next.
+///
+/// # Example
+///
+/// ```rust
+/// use contracts_core::{ContractBuilder, ContractDiff, DataFormat, FieldBuilder};
+///
+/// let contract = |nullable: bool| {
+///     ContractBuilder::new("orders", "sales")
+///         .location("s3://lake/orders")
+///         .format(DataFormat::Iceberg)
+///         .field(FieldBuilder::new("id", "int64").nullable(false).build())
+///         .field(FieldBuilder::new("coupon", "string").nullable(nullable).build())
+///         .build()
+/// };
+///
+/// let diff = ContractDiff::compare(&contract(true), &contract(false));
+/// assert_eq!(diff.changes.len(), 1);
+/// assert!(diff.is_breaking());
+/// assert_eq!(diff.changes[0].to_string(), "field 'coupon' is no longer nullable");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ContractDiff {
+    /// Every change, in schema order of the new contract, removals last
+    pub changes: Vec<Change>,
+}
+
+impl ContractDiff {
+    /// Compares the schemas of `old` and `new`.
+    ///
+    /// Fields are matched by name, so a renamed field shows up as removed and
+    /// added. Related datasets are matched by name too: their schemas are
+    /// compared the same way, with field names prefixed by the dataset name
+    /// (`items.line`), and a removed dataset is breaking. Quality checks, SLA
+    /// and metadata are not compared. Deprecated fields are expired as of
+    /// today's date in UTC.
+    pub fn compare(old: &Contract, new: &Contract) -> Self {
+        let today = Utc::now().date_naive();
+        let mut changes = Vec::new();
+        compare_schemas(&old.schema, &new.schema, None, today, &mut changes);
+
+        let old_datasets: BTreeMap<&str, Contract> = old.dataset_contracts().into_iter().collect();
+        let new_datasets = new.dataset_contracts();
+        for (name, dataset) in &new_datasets {
+            match old_datasets.get(name) {
+                Some(previous) => {
+                    compare_schemas(
+                        &previous.schema,
+                        &dataset.schema,
+                        Some(name),
+                        today,
+                        &mut changes,
+                    );
+                }
+                None => changes.push(Change::DatasetAdded {
+                    dataset: name.to_string(),
+                }),
+            }
+        }
+        for name in old_datasets.keys() {
+            if !new_datasets.iter().any(|(n, _)| n == name) {
+                changes.push(Change::DatasetRemoved {
+                    dataset: name.to_string(),
+                });
+            }
+        }
+
+        Self { changes }
+    }
+
+    /// Returns true if the contracts have the same schema.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Returns true if any change is breaking.
+    pub fn is_breaking(&self) -> bool {
+        self.changes.iter().any(Change::is_breaking)
+    }
+
+    /// Returns the breaking changes.
+    pub fn breaking(&self) -> impl Iterator<Item = &Change> {
+        self.changes.iter().filter(|c| c.is_breaking())
+    }
+}
+
+/// Compares two schemas, naming fields with the `dataset` they belong to,
+/// if any.
+fn compare_schemas(
+    old: &Schema,
+    new: &Schema,
+    dataset: Option<&str>,
+    today: NaiveDate,
+    changes: &mut Vec<Change>,
+) {
+    let name = |field: &Field| match dataset {
+        Some(dataset) => format!("{}.{}", dataset, field.name),
+        None => field.name.clone(),
+    };
+
+    if old.format != new.format {
+        changes.push(Change::FormatChanged {
+            dataset: dataset.map(str::to_string),
+            from: old.format.clone(),
+            to: new.format.clone(),
+        });
+    }
+    if old.location != new.location {
+        changes.push(Change::LocationChanged {
+            dataset: dataset.map(str::to_string),
+            from: old.location.clone(),
+            to: new.location.clone(),
+        });
+    }
+
+    let old_fields: BTreeMap<&str, &Field> =
+        old.fields.iter().map(|f| (f.name.as_str(), f)).collect();
+    for field in &new.fields {
+        match old_fields.get(field.name.as_str()) {
+            Some(previous) => compare_field(previous, field, name(field), changes),
+            None => changes.push(Change::FieldAdded {
+                field: name(field),
+                nullable: field.nullable,
+                required: field.is_required(),
+                has_default: field.default.is_some(),
+            }),
+        }
+    }
+    for field in &old.fields {
+        if !new.fields.iter().any(|f| f.name == field.name) {
+            changes.push(Change::FieldRemoved {
+                field: name(field),
+                expired: field.is_expired(today),
+            });
+        }
+    }
+}
+
+fn compare_field(old: &Field, new: &Field, name: String, changes: &mut Vec<Change>) {
+    let field = || name.clone();
+
+    if old.field_type != new.field_type {
+        changes.push(Change::TypeChanged {
+            field: field(),
+            from: old.field_type.to_string(),
+            to: new.field_type.to_string(),
+            widened: widens(&old.field_type, &new.field_type),
+        });
+    }
+    match (old.nullable, new.nullable) {
+        (true, false) => changes.push(Change::NullabilityTightened { field: field() }),
+        (false, true) => changes.push(Change::NullabilityRelaxed { field: field() }),
+        _ => {}
+    }
+    // A presence change that only follows the nullability change is not
+    // reported twice
+    if old.required.is_some() || new.required.is_some() {
+        match (old.is_required(), new.is_required()) {
+            (false, true) => changes.push(Change::PresenceTightened { field: field() }),
+            (true, false) => changes.push(Change::PresenceRelaxed { field: field() }),
+            _ => {}
+        }
+    }
+
+    // Constraints are paired by kind, in declaration order
+    let by_kind = |field: &Field| {
+        let mut kinds: BTreeMap<&'static str, Vec<FieldConstraints>> = BTreeMap::new();
+        for constraint in field.constraints.iter().flatten() {
+            kinds
+                .entry(kind(constraint))
+                .or_default()
+                .push(constraint.clone());
+        }
+        kinds
+    };
+    let (mut old_kinds, new_kinds) = (by_kind(old), by_kind(new));
+    for (name, new_constraints) in new_kinds {
+        let mut old_constraints = old_kinds.remove(name).unwrap_or_default().into_iter();
+        for constraint in new_constraints {
+            match old_constraints.next() {
+                Some(previous) if previous == constraint => {}
+                Some(previous) => changes.push(Change::ConstraintChanged {
+                    field: field(),
+                    parameters: ParameterChange::between(&previous, &constraint),
+                    from: previous,
+                    to: constraint,
+                }),
+                None => changes.push(Change::ConstraintAdded {
+                    field: field(),
+                    constraint,
+                }),
+            }
+        }
+        changes.extend(old_constraints.map(|constraint| Change::ConstraintRemoved {
+            field: field(),
+            constraint,
+        }));
+    }
+    for constraint in old_kinds.into_values().flatten() {
+        changes.push(Change::ConstraintRemoved {
+            field: field(),
+            constraint,
+        });
+    }
+}
+
+/// Returns true for the type promotions Iceberg allows: int32 to int64 and
+/// float32 to float64.
+fn widens(from: &DataType, to: &DataType) -> bool {
+    matches!(
+        (from, to),
+        (
+            DataType::Primitive(PrimitiveType::Int32),
+            DataType::Primitive(PrimitiveType::Int64)
+        ) | (
+            DataType::Primitive(PrimitiveType::Float32),
+            DataType::Primitive(PrimitiveType::Float64)
+        )
+    )
+}
+
+/// Returns true if every value accepted by `from` is accepted by `to`.
+///
+/// Patterns and custom constraints cannot be compared and count as tightened.
+fn relaxes(from: &FieldConstraints, to: &FieldConstraints) -> bool {
+    use FieldConstraints::*;
+
+    let within =
+        |old: Option<usize>, new: Option<usize>, looser: fn(usize, usize) -> bool| match (old, new)
+        {
+            (_, None) => true,
+            (None, Some(_)) => false,
+            (Some(old), Some(new)) => looser(old, new),
+        };
+    match (from, to) {
+        (AllowedValues { values: old }, AllowedValues { values: new }) => {
+            old.iter().all(|v| new.contains(v))
+        }
+        (
+            Range {
+                min: old_min,
+                max: old_max,
+            },
+            Range {
+                min: new_min,
+                max: new_max,
+            },
+        ) => new_min <= old_min && new_max >= old_max,
+        (
+            Length {
+                min: old_min,
+                max: old_max,
+            },
+            Length {
+                min: new_min,
+                max: new_max,
+            },
+        )
+        | (
+            ByteLength {
+                min: old_min,
+                max: old_max,
+            },
+            ByteLength {
+                min: new_min,
+                max: new_max,
+            },
+        )
+        | (
+            ListLength {
+                min: old_min,
+                max: old_max,
+            },
+            ListLength {
+                min: new_min,
+                max: new_max,
+            },
+        )
+        | (
+            Cardinality {
+                min_distinct: old_min,
+                max_distinct: old_max,
+            },
+            Cardinality {
+                min_distinct: new_min,
+                max_distinct: new_max,
+            },
+        ) => {
+            within(*old_min, *new_min, |old, new| new <= old)
+                && within(*old_max, *new_max, |old, new| new >= old)
+        }
+        (MaxNullFraction { fraction: old }, MaxNullFraction { fraction: new }) => new >= old,
+        (
+            Precision {
+                precision: old_precision,
+                scale: old_scale,
+            },
+            Precision {
+                precision: new_precision,
+                scale: new_scale,
+            },
+        ) => {
+            new_scale >= old_scale
+                && new_precision.saturating_sub(*new_scale)
+                    >= old_precision.saturating_sub(*old_scale)
+        }
+        (
+            TimeRange {
+                min: old_min,
+                max: old_max,
+                allow_future: old_future,
+            },
+            TimeRange {
+                min: new_min,
+                max: new_max,
+                allow_future: new_future,
+            },
+        ) => {
+            (new_min.is_none() || new_min == old_min)
+                && (new_max.is_none() || new_max == old_max)
+                && (*new_future || !old_future)
+        }
+        (
+            MapKeys {
+                pattern: old_pattern,
+                allowed: old_allowed,
+            },
+            MapKeys {
+                pattern: new_pattern,
+                allowed: new_allowed,
+            },
+        ) => {
+            (new_pattern.is_none() || new_pattern == old_pattern)
+                && match (old_allowed, new_allowed) {
+                    (_, None) => true,
+                    (None, Some(_)) => false,
+                    (Some(old), Some(new)) => old.iter().all(|k| new.contains(k)),
+                }
+        }
+        _ => false,
+    }
+}
+
+/// Name of a constraint kind, as written in contracts.
+fn kind(constraint: &FieldConstraints) -> &'static str {
+    match constraint {
+        FieldConstraints::AllowedValues { .. } => "allowedvalues",
+        FieldConstraints::Range { .. } => "range",
+        FieldConstraints::Pattern { .. } => "pattern",
+        FieldConstraints::Length { .. } => "length",
+        FieldConstraints::ByteLength { .. } => "bytelength",
+        FieldConstraints::ListLength { .. } => "listlength",
+        FieldConstraints::MapKeys { .. } => "mapkeys",
+        FieldConstraints::NonEmpty => "nonempty",
+        FieldConstraints::NotNull => "notnull",
+        FieldConstraints::MaxNullFraction { .. } => "maxnullfraction",
+        FieldConstraints::Precision { .. } => "precision",
+        FieldConstraints::TimeRange { .. } => "timerange",
+        FieldConstraints::Cardinality { .. } => "cardinality",
+        FieldConstraints::Conditional { .. } => "conditional",
+        FieldConstraints::Custom { .. } => "custom",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ContractBuilder, Dataset, Deprecation, FieldBuilder};
+
+    fn contract(fields: Vec<Field>) -> Contract {
+        ContractBuilder::new("orders", "sales")
+            .location("s3://lake/orders")
+            .format(DataFormat::Iceberg)
+            .fields(fields)
+            .build()
+    }
+
+    fn range(field: &str, min: f64, max: f64) -> Field {
+        FieldBuilder::new(field, "float64")
+            .constraint(FieldConstraints::Range { min, max })
+            .build()
+    }
+
+    #[test]
+    fn test_field_changes() {
+        let old = contract(vec![
+            FieldBuilder::new("id", "int32").nullable(false).build(),
+            FieldBuilder::new("name", "string").build(),
+            FieldBuilder::new("legacy", "string").build(),
+        ]);
+        let new = contract(vec![
+            FieldBuilder::new("id", "int64").nullable(false).build(),
+            FieldBuilder::new("name", "int64").nullable(false).build(),
+            FieldBuilder::new("note", "string").build(),
+            FieldBuilder::new("total", "float64")
+                .nullable(false)
+                .build(),
+            FieldBuilder::new("currency", "string")
+                .nullable(false)
+                .default_value("EUR")
+                .build(),
+        ]);
+
+        let diff = ContractDiff::compare(&old, &new);
+        let summary: Vec<(String, bool)> = diff
+            .changes
+            .iter()
+            .map(|c| (c.to_string(), c.is_breaking()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "field 'id' changed type from int32 to int64".to_string(),
+                    false
+                ),
+                (
+                    "field 'name' changed type from string to int64".to_string(),
+                    true
+                ),
+                ("field 'name' is no longer nullable".to_string(), true),
+                ("nullable field 'note' added".to_string(), false),
+                ("required field 'total' added".to_string(), true),
+                (
+                    "required field 'currency' added with a default".to_string(),
+                    false
+                ),
+                ("field 'legacy' removed".to_string(), true),
+            ]
+        );
+        assert_eq!(diff.breaking().count(), 4);
+        assert!(ContractDiff::compare(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn test_dataset_changes() {
+        let dataset = |location: &str, fields: Vec<Field>| Dataset {
+            description: None,
+            schema: Schema {
+                fields,
+                format: DataFormat::Iceberg,
+                location: location.to_string(),
+                catalog: None,
+                namespace: None,
+                table: None,
+                connection: None,
+            },
+            quality_checks: None,
+        };
+        let with_datasets = |datasets: Vec<(&str, Dataset)>| {
+            let mut contract = contract(vec![FieldBuilder::new("id", "int64").build()]);
+            contract.datasets = Some(
+                datasets
+                    .into_iter()
+                    .map(|(name, dataset)| (name.to_string(), dataset))
+                    .collect(),
+            );
+            contract
+        };
+        let old = with_datasets(vec![
+            (
+                "items",
+                dataset(
+                    "s3://lake/items",
+                    vec![
+                        FieldBuilder::new("order_id", "int64").build(),
+                        FieldBuilder::new("sku", "string").build(),
+                    ],
+                ),
+            ),
+            ("refunds", dataset("s3://lake/refunds", vec![])),
+        ]);
+        let new = with_datasets(vec![
+            (
+                "items",
+                dataset(
+                    "s3://lake/order_items",
+                    vec![
+                        FieldBuilder::new("order_id", "int64")
+                            .nullable(false)
+                            .build(),
+                    ],
+                ),
+            ),
+            ("shipments", dataset("s3://lake/shipments", vec![])),
+        ]);
+
+        let diff = ContractDiff::compare(&old, &new);
+        let summary: Vec<(String, bool)> = diff
+            .changes
+            .iter()
+            .map(|c| (c.to_string(), c.is_breaking()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "dataset 'items' location changed from s3://lake/items to s3://lake/order_items"
+                        .to_string(),
+                    true
+                ),
+                (
+                    "field 'items.order_id' is no longer nullable".to_string(),
+                    true
+                ),
+                ("field 'items.sku' removed".to_string(), true),
+                ("dataset 'shipments' added".to_string(), false),
+                ("dataset 'refunds' removed".to_string(), true),
+            ]
+        );
+        assert!(ContractDiff::compare(&old, &old).is_empty());
+        assert!(!new.is_backward_compatible_with(&old));
+    }
+
+    #[test]
+    fn test_presence_changes() {
+        let old = contract(vec![
+            FieldBuilder::new("id", "int64").nullable(false).build(),
+            FieldBuilder::new("email", "string").build(),
+        ]);
+        let new = contract(vec![
+            FieldBuilder::new("id", "int64")
+                .nullable(false)
+                .required(false)
+                .build(),
+            FieldBuilder::new("email", "string").required(true).build(),
+            FieldBuilder::new("code", "string")
+                .nullable(false)
+                .required(false)
+                .build(),
+        ]);
+
+        let diff = ContractDiff::compare(&old, &new);
+        let summary: Vec<(String, bool)> = diff
+            .changes
+            .iter()
+            .map(|c| (c.to_string(), c.is_breaking()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("field 'id' may now be absent".to_string(), false),
+                ("field 'email' must now be present".to_string(), true),
+                ("optional field 'code' added".to_string(), false),
+            ]
+        );
+
+        // Spelling out the default is not a change
+        let explicit = contract(vec![
+            FieldBuilder::new("id", "int64")
+                .nullable(false)
+                .required(true)
+                .build(),
+            FieldBuilder::new("email", "string").build(),
+        ]);
+        assert!(ContractDiff::compare(&old, &explicit).is_empty());
+    }
+
+    #[test]
+    fn test_removing_expired_deprecated_field() {
+        let deprecated = |name: &str, remove_after: (i32, u32, u32)| {
+            let (y, m, d) = remove_after;
+            FieldBuilder::new(name, "string")
+                .deprecated(Deprecation {
+                    since: Some("1.2.0".to_string()),
+                    remove_after: NaiveDate::from_ymd_opt(y, m, d),
+                    replacement: Some("id".to_string()),
+                })
+                .build()
+        };
+        let old = contract(vec![
+            FieldBuilder::new("id", "int64").nullable(false).build(),
+            deprecated("legacy_id", (2020, 1, 31)),
+            deprecated("old_code", (2999, 1, 31)),
+        ]);
+        let new = contract(vec![
+            FieldBuilder::new("id", "int64").nullable(false).build(),
+        ]);
+
+        let diff = ContractDiff::compare(&old, &new);
+        let summary: Vec<(String, bool)> = diff
+            .changes
+            .iter()
+            .map(|c| (c.to_string(), c.is_breaking()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "field 'legacy_id' removed after its removal date".to_string(),
+                    false
+                ),
+                ("field 'old_code' removed".to_string(), true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_constraint_changes() {
+        let compare = |old: Field, new: Field| {
+            ContractDiff::compare(&contract(vec![old]), &contract(vec![new]))
+        };
+
+        let widened = compare(range("total", 0.0, 100.0), range("total", -10.0, 100.0));
+        assert_eq!(widened.changes.len(), 1);
+        assert!(!widened.is_breaking());
+
+        let narrowed = compare(range("total", 0.0, 100.0), range("total", 0.0, 50.0));
+        assert!(narrowed.is_breaking());
+
+        let added = compare(
+            FieldBuilder::new("total", "float64").build(),
+            range("total", 0.0, 1.0),
+        );
+        assert!(matches!(added.changes[0], Change::ConstraintAdded { .. }));
+        assert!(added.is_breaking());
+
+        let removed = compare(
+            range("total", 0.0, 1.0),
+            FieldBuilder::new("total", "float64").build(),
+        );
+        assert!(matches!(
+            removed.changes[0],
+            Change::ConstraintRemoved { .. }
+        ));
+        assert!(!removed.is_breaking());
+
+        let allowed = |values: &[&str]| {
+            FieldBuilder::new("status", "string")
+                .constraint(FieldConstraints::AllowedValues {
+                    values: values.iter().map(|v| v.to_string()).collect(),
+                })
+                .build()
+        };
+        assert!(
+            !compare(allowed(&["new", "paid"]), allowed(&["new", "paid", "void"])).is_breaking()
+        );
+        assert!(compare(allowed(&["new", "paid"]), allowed(&["new"])).is_breaking());
+    }
+
+    #[test]
+    fn test_constraint_parameter_changes() {
+        let compare = |old: Field, new: Field| {
+            ContractDiff::compare(&contract(vec![old]), &contract(vec![new]))
+        };
+
+        let diff = compare(range("age", 0.0, 120.0), range("age", 0.0, 150.0));
+        assert_eq!(
+            diff.changes[0].to_string(),
+            "field 'age' changed constraint range: max 120 → 150"
+        );
+        let Change::ConstraintChanged { parameters, .. } = &diff.changes[0] else {
+            panic!("expected a constraint change");
+        };
+        assert_eq!(
+            parameters,
+            &vec![ParameterChange {
+                parameter: "max".to_string(),
+                from: Some(serde_json::json!(120.0)),
+                to: Some(serde_json::json!(150.0)),
+                added: Vec::new(),
+                removed: Vec::new(),
+            }]
+        );
+
+        let allowed = |values: &[&str]| {
+            FieldBuilder::new("status", "string")
+                .constraint(FieldConstraints::AllowedValues {
+                    values: values.iter().map(|v| v.to_string()).collect(),
+                })
+                .build()
+        };
+        let diff = compare(
+            allowed(&["new", "paid"]),
+            allowed(&["new", "void", "refunded"]),
+        );
+        assert_eq!(
+            diff.changes[0].to_string(),
+            "field 'status' changed constraint allowedvalues: values added void, refunded, \
+             removed paid"
+        );
+
+        let length = |min: Option<usize>, max: Option<usize>| {
+            FieldBuilder::new("code", "string")
+                .constraint(FieldConstraints::Length { min, max })
+                .build()
+        };
+        let diff = compare(length(Some(2), None), length(None, Some(8)));
+        assert_eq!(
+            diff.changes[0].to_string(),
+            "field 'code' changed constraint length: max - → 8, min 2 → -"
+        );
+        let value = serde_json::to_value(&diff.changes[0]).unwrap();
+        assert_eq!(value["parameters"][0]["from"], serde_json::Value::Null);
+        assert_eq!(value["parameters"][0]["to"], 8);
+    }
+}
@@ -0,0 +1,344 @@
+//! Drift between a contract and the table it describes.
+//!
+//! Tables evolve outside the contract: columns are added by writers, types
+//! are widened, constraints are dropped. [`SchemaDrift::compare`] lines up
+//! the fields of a contract with the schema read from the live table and
+//! lists where they disagree, so the contract can be brought back in sync
+//! before validation starts failing.
+
+use crate::{Field, Schema};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// A field on which a contract and its table disagree.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum FieldDrift {
+    /// A contract field the table does not have
+    Missing {
+        /// Field name
+        field: String,
+        /// Type declared by the contract
+        contract_type: String,
+        /// Whether the contract requires the field in every record
+        required: bool,
+    },
+
+    /// A table column the contract does not declare
+    Extra {
+        /// Column name
+        field: String,
+        /// Type of the column
+        table_type: String,
+        /// Whether the column may hold nulls
+        nullable: bool,
+    },
+
+    /// The table column has another type than the contract field
+    TypeChanged {
+        /// Field name
+        field: String,
+        /// Type declared by the contract
+        contract_type: String,
+        /// Type of the column
+        table_type: String,
+    },
+
+    /// The table column and the contract field disagree on nulls
+    NullabilityChanged {
+        /// Field name
+        field: String,
+        /// Whether the contract allows nulls
+        contract_nullable: bool,
+        /// Whether the column may hold nulls
+        table_nullable: bool,
+    },
+}
+
+impl FieldDrift {
+    /// Returns the field the drift applies to.
+    pub fn field(&self) -> &str {
+        match self {
+            Self::Missing { field, .. }
+            | Self::Extra { field, .. }
+            | Self::TypeChanged { field, .. }
+            | Self::NullabilityChanged { field, .. } => field,
+        }
+    }
+
+    /// Returns true if data read from the table can fail the contract
+    /// because of the drift: a required field is gone, a type changed, or
+    /// a column the contract declares non-nullable accepts nulls.
+    pub fn is_breaking(&self) -> bool {
+        match self {
+            Self::Missing { required, .. } => *required,
+            Self::Extra { .. } => false,
+            Self::TypeChanged { .. } => true,
+            Self::NullabilityChanged {
+                contract_nullable,
+                table_nullable,
+                ..
+            } => *table_nullable && !contract_nullable,
+        }
+    }
+}
+
+impl fmt::Display for FieldDrift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing {
+                field,
+                contract_type,
+                ..
+            } => write!(
+                f,
+                "field '{}' ({}) is missing from the table",
+                field, contract_type
+            ),
+            Self::Extra {
+                field, table_type, ..
+            } => write!(
+                f,
+                "column '{}' ({}) is not declared by the contract",
+                field, table_type
+            ),
+            Self::TypeChanged {
+                field,
+                contract_type,
+                table_type,
+            } => write!(
+                f,
+                "field '{}' is {} in the table, {} in the contract",
+                field, table_type, contract_type
+            ),
+            Self::NullabilityChanged {
+                field,
+                table_nullable,
+                ..
+            } => {
+                let (table, contract) = if *table_nullable {
+                    ("nullable", "non-nullable")
+                } else {
+                    ("non-nullable", "nullable")
+                };
+                write!(
+                    f,
+                    "field '{}' is {} in the table, {} in the contract",
+                    field, table, contract
+                )
+            }
+        }
+    }
+}
+
+/// The differences between a contract schema and the schema of its table.
+///
+/// # Example
+///
+/// ```rust
+/// use contracts_core::{ContractBuilder, DataFormat, FieldBuilder, FieldDrift, SchemaDrift};
+///
+/// let schema = |fields: Vec<_>| {
+///     fields
+///         .into_iter()
+///         .fold(
+///             ContractBuilder::new("orders", "sales")
+///                 .location("s3://lake/orders")
+///                 .format(DataFormat::Iceberg),
+///             |builder, field| builder.field(field),
+///         )
+///         .build()
+///         .schema
+/// };
+/// let contract = schema(vec![
+///     FieldBuilder::new("id", "int64").nullable(false).build(),
+///     FieldBuilder::new("email", "string").build(),
+/// ]);
+/// let table = schema(vec![
+///     FieldBuilder::new("id", "int64").build(),
+///     FieldBuilder::new("country", "string").build(),
+/// ]);
+///
+/// let drift = SchemaDrift::compare(&contract, &table);
+/// assert_eq!(drift.changes.len(), 3);
+/// assert_eq!(
+///     drift.changes[0].to_string(),
+///     "field 'id' is nullable in the table, non-nullable in the contract"
+/// );
+/// assert!(matches!(drift.changes[2], FieldDrift::Extra { .. }));
+/// assert!(drift.is_breaking());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct SchemaDrift {
+    /// Every drift, in contract field order, extra columns last
+    pub changes: Vec<FieldDrift>,
+}
+
+impl SchemaDrift {
+    /// Compares the fields of a contract schema with those of the table.
+    ///
+    /// Fields are matched by name, so a renamed column shows up as missing
+    /// and extra.
+    pub fn compare(contract: &Schema, table: &Schema) -> Self {
+        let columns: BTreeMap<&str, &Field> =
+            table.fields.iter().map(|f| (f.name.as_str(), f)).collect();
+        let mut changes = Vec::new();
+
+        for field in &contract.fields {
+            let Some(column) = columns.get(field.name.as_str()) else {
+                changes.push(FieldDrift::Missing {
+                    field: field.name.clone(),
+                    contract_type: field.field_type.to_string(),
+                    required: field.is_required(),
+                });
+                continue;
+            };
+            if field.field_type != column.field_type {
+                changes.push(FieldDrift::TypeChanged {
+                    field: field.name.clone(),
+                    contract_type: field.field_type.to_string(),
+                    table_type: column.field_type.to_string(),
+                });
+            }
+            if field.nullable != column.nullable {
+                changes.push(FieldDrift::NullabilityChanged {
+                    field: field.name.clone(),
+                    contract_nullable: field.nullable,
+                    table_nullable: column.nullable,
+                });
+            }
+        }
+        for column in &table.fields {
+            if !contract.fields.iter().any(|f| f.name == column.name) {
+                changes.push(FieldDrift::Extra {
+                    field: column.name.clone(),
+                    table_type: column.field_type.to_string(),
+                    nullable: column.nullable,
+                });
+            }
+        }
+
+        Self { changes }
+    }
+
+    /// Brings a contract schema in line with `table`, the schema the drift
+    /// was found against.
+    ///
+    /// Missing fields are removed, extra columns are appended as the table
+    /// declares them, and changed fields take the table's type and
+    /// nullability. Everything else a field declares, such as its
+    /// constraints and description, is kept.
+    pub fn apply(&self, schema: &mut Schema, table: &Schema) {
+        for change in &self.changes {
+            match change {
+                FieldDrift::Missing { field, .. } => schema.fields.retain(|f| &f.name != field),
+                FieldDrift::Extra { field, .. } => {
+                    if let Some(column) = table.fields.iter().find(|f| &f.name == field) {
+                        schema.fields.push(column.clone());
+                    }
+                }
+                FieldDrift::TypeChanged { field, .. } => {
+                    let column = table.fields.iter().find(|f| &f.name == field);
+                    let target = schema.fields.iter_mut().find(|f| &f.name == field);
+                    if let (Some(column), Some(target)) = (column, target) {
+                        target.field_type = column.field_type.clone();
+                    }
+                }
+                FieldDrift::NullabilityChanged {
+                    field,
+                    table_nullable,
+                    ..
+                } => {
+                    if let Some(target) = schema.fields.iter_mut().find(|f| &f.name == field) {
+                        target.nullable = *table_nullable;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns true if the contract matches the table.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Returns true if any drift is breaking.
+    pub fn is_breaking(&self) -> bool {
+        self.changes.iter().any(FieldDrift::is_breaking)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ContractBuilder, DataFormat, FieldBuilder};
+
+    fn schema(fields: Vec<Field>) -> Schema {
+        let mut contract = ContractBuilder::new("orders", "sales")
+            .location("s3://lake/orders")
+            .format(DataFormat::Iceberg)
+            .build();
+        contract.schema.fields = fields;
+        contract.schema
+    }
+
+    #[test]
+    fn test_matching_schemas_have_no_drift() {
+        let fields = vec![
+            FieldBuilder::new("id", "int64").nullable(false).build(),
+            FieldBuilder::new("name", "string").build(),
+        ];
+        assert!(SchemaDrift::compare(&schema(fields.clone()), &schema(fields)).is_empty());
+    }
+
+    #[test]
+    fn test_drift_kinds_and_severity() {
+        let contract = schema(vec![
+            FieldBuilder::new("id", "int64").nullable(false).build(),
+            FieldBuilder::new("amount", "float64").build(),
+            FieldBuilder::new("coupon", "string").build(),
+            FieldBuilder::new("status", "string").build(),
+        ]);
+        let table = schema(vec![
+            FieldBuilder::new("id", "int64").nullable(false).build(),
+            FieldBuilder::new("amount", "decimal").build(),
+            FieldBuilder::new("status", "string")
+                .nullable(false)
+                .build(),
+            FieldBuilder::new("channel", "string").build(),
+        ]);
+
+        let drift = SchemaDrift::compare(&contract, &table);
+        let summary: Vec<(&str, bool)> = drift
+            .changes
+            .iter()
+            .map(|d| (d.field(), d.is_breaking()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("amount", true),
+                ("coupon", false),
+                ("status", false),
+                ("channel", false),
+            ]
+        );
+        assert!(drift.is_breaking());
+
+        let mut updated = contract.clone();
+        drift.apply(&mut updated, &table);
+        assert!(SchemaDrift::compare(&updated, &table).is_empty());
+        let names: Vec<&str> = updated.fields.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["id", "amount", "status", "channel"]);
+        assert_eq!(
+            serde_json::to_value(&drift.changes[0]).unwrap(),
+            serde_json::json!({
+                "kind": "type_changed",
+                "field": "amount",
+                "contract_type": "float64",
+                "table_type": "decimal",
+            })
+        );
+    }
+}
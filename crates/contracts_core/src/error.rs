@@ -4,11 +4,48 @@
 //! data contracts, including validation errors, schema mismatches, and
 //! constraint violations.
 
+use crate::codes::{self, Coded, ErrorCode};
 use thiserror::Error;
 
 /// Result type for data contract operations.
 pub type Result<T> = std::result::Result<T, ContractError>;
 
+/// Boxed error kept as the `source()` of errors that wrap a lower-level failure.
+pub type BoxError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// Renders an error followed by each of its causes, separated by `: `.
+///
+/// A cause whose message is already part of the previous one is skipped, so
+/// errors that embed their source in their own message are not repeated.
+///
+/// # Example
+///
+/// ```rust
+/// use contracts_core::error_chain;
+///
+/// #[derive(Debug, thiserror::Error)]
+/// #[error("Failed to load REST catalog")]
+/// struct LoadError(#[source] std::io::Error);
+///
+/// let err = LoadError(std::io::Error::other("connection refused"));
+/// assert_eq!(error_chain(&err), "Failed to load REST catalog: connection refused");
+/// ```
+pub fn error_chain(error: &(dyn std::error::Error + 'static)) -> String {
+    let mut rendered = error.to_string();
+    let mut last = rendered.clone();
+    let mut source = error.source();
+    while let Some(cause) = source {
+        let message = cause.to_string();
+        if !last.contains(&message) {
+            rendered.push_str(": ");
+            rendered.push_str(&message);
+        }
+        last = message;
+        source = cause.source();
+    }
+    rendered
+}
+
 /// Main error type for data contract operations.
 #[derive(Error, Debug)]
 pub enum ContractError {
@@ -47,6 +84,15 @@ pub enum ContractError {
         actual: String,
     },
 
+    /// Contract version is not a semantic version
+    #[error("Invalid contract version '{version}': {message}")]
+    InvalidVersion {
+        /// Version as written
+        version: String,
+        /// Why it does not parse
+        message: String,
+    },
+
     /// Missing required field
     #[error("Missing required field: {0}")]
     MissingField(String),
@@ -122,3 +168,33 @@ pub enum ValidationError {
         message: String,
     },
 }
+
+impl Coded for ContractError {
+    fn error_code(&self) -> &'static ErrorCode {
+        match self {
+            Self::SchemaValidation(_) => &codes::SCHEMA_ERROR,
+            Self::ConstraintViolation { .. } => &codes::CONSTRAINT_VIOLATION,
+            Self::QualityCheckFailed { .. } => &codes::QUALITY_CHECK_FAILED,
+            Self::SlaViolation(_) => &codes::SLA_VIOLATION,
+            Self::VersionMismatch { .. } => &codes::VERSION_MISMATCH,
+            Self::InvalidVersion { .. } => &codes::INVALID_VERSION,
+            Self::MissingField(_) => &codes::MISSING_FIELD,
+            Self::InvalidFieldType { .. } => &codes::TYPE_MISMATCH,
+            Self::UnsupportedFormat(_) => &codes::UNSUPPORTED_FORMAT,
+            Self::Serialization(_) => &codes::SERIALIZATION,
+            Self::Other(_) => &codes::GENERAL,
+        }
+    }
+}
+
+impl Coded for ValidationError {
+    fn error_code(&self) -> &'static ErrorCode {
+        match self {
+            Self::NullConstraint(_) => &codes::NULL_CONSTRAINT_VIOLATION,
+            Self::NotInAllowedValues { .. } => &codes::NOT_IN_ALLOWED_VALUES,
+            Self::OutOfRange { .. } => &codes::OUT_OF_RANGE,
+            Self::PatternMismatch { .. } => &codes::PATTERN_MISMATCH,
+            Self::CustomValidation { .. } => &codes::CUSTOM_CHECK_FAILED,
+        }
+    }
+}
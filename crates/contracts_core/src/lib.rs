@@ -29,27 +29,59 @@
 //!                 name: "user_id".to_string(),
 //!                 field_type: DataType::from("string"),
 //!                 nullable: false,
+//!                 required: None,
 //!                 description: Some("Unique user identifier".to_string()),
 //!                 tags: None,
 //!                 constraints: None,
+//!                 severity: None,
+//!                 default: None,
+//!                 deprecated: None,
+//!                 access: None,
 //!             },
 //!         ],
 //!         format: DataFormat::Iceberg,
 //!         location: "s3://data/user_events".to_string(),
+//!         catalog: None,
+//!         namespace: None,
+//!         table: None,
+//!         connection: None,
 //!     },
 //!     quality_checks: None,
 //!     sla: None,
+//!     conformance_level: None,
+//!     cdc: None,
+//!     datasets: None,
+//!     changelog: None,
+//!     extensions: None,
 //! };
 //! ```
 
+pub mod access;
 pub mod builder;
+pub mod capabilities;
+pub mod codes;
+pub mod conformance;
 pub mod contract;
 pub mod datatype;
+pub mod diff;
+pub mod drift;
 pub mod error;
+pub mod taxonomy;
 pub mod validator;
+pub mod versioning;
+pub mod violation;
 
+pub use access::{AccessChange, AccessDrift, declared_access};
 pub use builder::*;
+pub use capabilities::Capabilities;
+pub use codes::{Coded, ErrorCode};
+pub use conformance::{ConformanceLevel, MissingSection};
 pub use contract::*;
 pub use datatype::*;
+pub use diff::{Change, ContractDiff, ParameterChange};
+pub use drift::{FieldDrift, SchemaDrift};
 pub use error::*;
+pub use taxonomy::{TagDefinition, TagTaxonomy};
 pub use validator::*;
+pub use versioning::{ChangelogEntry, Version, VersionBump, parse_version};
+pub use violation::{CheckKind, Violation};
@@ -0,0 +1,155 @@
+//! Workspace taxonomy of field tags.
+//!
+//! Field tags are free-form strings, so across many contracts the same idea
+//! ends up spelled several ways (`pii`, `PII`, `personal`). A taxonomy lists
+//! the tags a workspace accepts, as a tree whose paths are written with dots
+//! (`pii.direct`), and marks the ones being phased out.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The tags a workspace accepts.
+///
+/// # Example
+///
+/// ```rust
+/// use contracts_core::TagTaxonomy;
+///
+/// let taxonomy: TagTaxonomy = serde_json::from_value(serde_json::json!({
+///     "tags": {
+///         "pii": { "children": { "direct": {}, "indirect": {} } },
+///         "personal": { "deprecated": true, "replacement": "pii" },
+///     }
+/// }))
+/// .unwrap();
+///
+/// assert!(taxonomy.get("pii.direct").is_some());
+/// assert!(taxonomy.get("pii.email").is_none());
+/// assert_eq!(taxonomy.deprecation("personal").unwrap().replacement.as_deref(), Some("pii"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TagTaxonomy {
+    /// Top-level tags, by name
+    #[serde(default)]
+    pub tags: BTreeMap<String, TagDefinition>,
+}
+
+/// A tag of the taxonomy and the tags below it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TagDefinition {
+    /// What the tag means
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Whether the tag, and every tag below it, is being phased out
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub deprecated: bool,
+
+    /// Tag to use instead of a deprecated one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replacement: Option<String>,
+
+    /// Tags below this one, by name; `direct` under `pii` is `pii.direct`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub children: BTreeMap<String, TagDefinition>,
+}
+
+impl TagTaxonomy {
+    /// Returns the definition of a dotted tag path, or `None` if the taxonomy
+    /// does not declare it.
+    pub fn get(&self, tag: &str) -> Option<&TagDefinition> {
+        let mut segments = tag.split('.');
+        let mut definition = self.tags.get(segments.next()?)?;
+        for segment in segments {
+            definition = definition.children.get(segment)?;
+        }
+        Some(definition)
+    }
+
+    /// Returns the deprecated definition `tag` falls under: its own, or that
+    /// of the closest deprecated ancestor.
+    pub fn deprecation(&self, tag: &str) -> Option<&TagDefinition> {
+        let mut prefixes: Vec<&str> = tag.match_indices('.').map(|(i, _)| &tag[..i]).collect();
+        prefixes.push(tag);
+        prefixes
+            .into_iter()
+            .rev()
+            .filter_map(|prefix| self.get(prefix))
+            .find(|definition| definition.deprecated)
+    }
+
+    /// Lists every tag path of the taxonomy, parents before their children.
+    pub fn paths(&self) -> Vec<String> {
+        fn walk(prefix: &str, tags: &BTreeMap<String, TagDefinition>, paths: &mut Vec<String>) {
+            for (name, definition) in tags {
+                let path = if prefix.is_empty() {
+                    name.clone()
+                } else {
+                    format!("{}.{}", prefix, name)
+                };
+                paths.push(path.clone());
+                walk(&path, &definition.children, paths);
+            }
+        }
+
+        let mut paths = Vec::new();
+        walk("", &self.tags, &mut paths);
+        paths
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn taxonomy() -> TagTaxonomy {
+        let tag = |deprecated, children: &[(&str, TagDefinition)]| TagDefinition {
+            deprecated,
+            children: children
+                .iter()
+                .map(|(name, definition)| (name.to_string(), definition.clone()))
+                .collect(),
+            ..TagDefinition::default()
+        };
+        TagTaxonomy {
+            tags: BTreeMap::from([
+                ("finance".to_string(), tag(false, &[])),
+                (
+                    "pii".to_string(),
+                    tag(
+                        false,
+                        &[
+                            ("direct", tag(false, &[])),
+                            ("legacy", tag(true, &[("email", tag(false, &[]))])),
+                        ],
+                    ),
+                ),
+            ]),
+        }
+    }
+
+    #[test]
+    fn test_deprecation_is_inherited() {
+        let taxonomy = taxonomy();
+        assert!(taxonomy.deprecation("pii.direct").is_none());
+        assert!(taxonomy.deprecation("pii.legacy").is_some());
+        assert!(taxonomy.deprecation("pii.legacy.email").is_some());
+        assert!(taxonomy.deprecation("pii.unknown").is_none());
+    }
+
+    #[test]
+    fn test_paths_list_parents_first() {
+        assert_eq!(
+            taxonomy().paths(),
+            vec![
+                "finance",
+                "pii",
+                "pii.direct",
+                "pii.legacy",
+                "pii.legacy.email"
+            ]
+        );
+    }
+}
@@ -4,7 +4,12 @@
 //! Different implementations can validate contracts against various data formats
 //! (Iceberg, Delta Lake, etc.).
 
-use crate::{Contract, ContractError};
+use crate::{
+    CheckKind, ConformanceLevel, Contract, ContractError, EmptyDataPolicy, ErrorCode, Severity,
+    Violation, codes, error_chain,
+};
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeMap, HashMap};
 
 /// Result type for validation operations.
 pub type ValidationResult<T = ()> = std::result::Result<T, ContractError>;
@@ -95,6 +100,62 @@ pub struct ValidationContext {
     /// Maximum number of records to sample for quality checks
     pub sample_size: Option<usize>,
 
+    /// How the sampled records are chosen when there are more than `sample_size`
+    pub sampling: SamplingStrategy,
+
+    /// Policy for empty data, overriding the contract's `on_empty`
+    pub on_empty: Option<EmptyDataPolicy>,
+
+    /// Only validate rows whose timestamp falls in this window
+    ///
+    /// Applied before sampling. Row-based validation filters the dataset
+    /// itself; SQL sources must be registered with the window's predicate
+    /// (see `register_file_as_table` in `contracts_validator`).
+    pub time_window: Option<TimeWindow>,
+
+    /// Only validate rows matching this SQL predicate, such as
+    /// `status = 'failed'`
+    ///
+    /// Applied with the time window, before sampling (see `row_predicate` in
+    /// `contracts_validator`).
+    pub row_filter: Option<String>,
+
+    /// Also validate the rows of each value of this column on their own
+    pub segment_by: Option<String>,
+
+    /// Treat fields missing from a record as holding the field's `default`
+    ///
+    /// Applied to row-based validation, before any check runs, so a producer
+    /// that omits a defaulted field is validated as a reader would see it.
+    pub fill_defaults: bool,
+
+    /// Threads row-based schema and constraint validation splits the rows
+    /// across
+    ///
+    /// Unset or `1` validates on the calling thread and `0` uses one thread
+    /// per core. Errors are reported in row order either way.
+    pub parallelism: Option<usize>,
+
+    /// Most errors a report lists
+    ///
+    /// Further errors are dropped and counted in
+    /// [`ValidationStats::truncated_violations`]. At least one error is
+    /// always listed, so a failed report keeps its reason.
+    pub max_errors: Option<usize>,
+
+    /// Most violations of any severity one row-level check lists
+    ///
+    /// A check is the schema or constraint violations with one code on one
+    /// field, such as a pattern constraint failing row after row. Dropped
+    /// violations are counted like those beyond `max_errors`.
+    pub max_errors_per_check: Option<usize>,
+
+    /// Stop the run at its first error
+    ///
+    /// The report lists that error and skips the checks that have not run
+    /// yet; row-based checks stop at the chunk of rows the error is in.
+    pub fail_fast: bool,
+
     /// Additional metadata for the validation
     pub metadata: std::collections::HashMap<String, String>,
 }
@@ -123,6 +184,102 @@ impl ValidationContext {
         self
     }
 
+    /// Sets how sampled records are chosen.
+    pub fn with_sampling(mut self, sampling: SamplingStrategy) -> Self {
+        self.sampling = sampling;
+        self
+    }
+
+    /// Sets the policy for empty data, overriding the contract.
+    pub fn with_on_empty(mut self, policy: EmptyDataPolicy) -> Self {
+        self.on_empty = Some(policy);
+        self
+    }
+
+    /// Restricts validation to rows inside `window`.
+    pub fn with_time_window(mut self, window: TimeWindow) -> Self {
+        self.time_window = Some(window);
+        self
+    }
+
+    /// Restricts validation to rows matching the SQL `predicate`.
+    pub fn with_row_filter(mut self, predicate: impl Into<String>) -> Self {
+        self.row_filter = Some(predicate.into());
+        self
+    }
+
+    /// Returns whether a time window or a row filter selects the rows to
+    /// validate, so that sampling must happen after reading.
+    pub fn filters_rows(&self) -> bool {
+        self.time_window.is_some() || self.row_filter.is_some()
+    }
+
+    /// Reports results per value of `column` next to the overall result.
+    pub fn with_segment_by(mut self, column: impl Into<String>) -> Self {
+        self.segment_by = Some(column.into());
+        self
+    }
+
+    /// Fills fields missing from a record with their contract defaults.
+    pub fn with_fill_defaults(mut self, fill_defaults: bool) -> Self {
+        self.fill_defaults = fill_defaults;
+        self
+    }
+
+    /// Validates rows on `threads` threads, or one per core for `0`.
+    pub fn with_parallelism(mut self, threads: usize) -> Self {
+        self.parallelism = Some(threads);
+        self
+    }
+
+    /// Lists at most `max` errors in the report.
+    pub fn with_max_errors(mut self, max: usize) -> Self {
+        self.max_errors = Some(max);
+        self
+    }
+
+    /// Lists at most `max` violations of each check in the report.
+    pub fn with_max_errors_per_check(mut self, max: usize) -> Self {
+        self.max_errors_per_check = Some(max);
+        self
+    }
+
+    /// Stops the run at its first error.
+    pub fn with_fail_fast(mut self, fail_fast: bool) -> Self {
+        self.fail_fast = fail_fast;
+        self
+    }
+
+    /// Returns the most errors a report lists: one when failing fast, then
+    /// `max_errors`, and never fewer than one.
+    pub fn error_limit(&self) -> Option<usize> {
+        if self.fail_fast {
+            Some(1)
+        } else {
+            self.max_errors.map(|max| max.max(1))
+        }
+    }
+
+    /// Returns the column the time window filters on for `contract`: the
+    /// window's own column, then the freshness check's metric.
+    ///
+    /// Returns `None` when no window is set or no column can be found.
+    pub fn time_window_column<'a>(&'a self, contract: &'a Contract) -> Option<&'a str> {
+        let window = self.time_window.as_ref()?;
+        window.column.as_deref().or_else(|| {
+            let freshness = contract.quality_checks.as_ref()?.freshness.as_ref()?;
+            freshness.column()
+        })
+    }
+
+    /// Returns the empty data policy for `contract`: the context's, then the
+    /// contract's, then [`EmptyDataPolicy::Warn`].
+    pub fn empty_data_policy(&self, contract: &Contract) -> EmptyDataPolicy {
+        self.on_empty
+            .or_else(|| contract.quality_checks.as_ref()?.on_empty)
+            .unwrap_or_default()
+    }
+
     /// Adds metadata to the context.
     pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
         self.metadata.insert(key.into(), value.into());
@@ -130,6 +287,99 @@ impl ValidationContext {
     }
 }
 
+/// A half-open time range `[since, until)` selecting the rows to validate.
+///
+/// Lets scheduled jobs validate one period, such as yesterday's partition,
+/// instead of a head sample of the whole table. Rows whose timestamp is null
+/// or unreadable fall outside every window.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use contracts_core::{TimeWindow, ValidationContext};
+///
+/// let context = ValidationContext::new().with_time_window(TimeWindow {
+///     column: Some("event_time".to_string()),
+///     since: Some(Utc.with_ymd_and_hms(2026, 10, 15, 0, 0, 0).unwrap()),
+///     until: Some(Utc.with_ymd_and_hms(2026, 10, 16, 0, 0, 0).unwrap()),
+/// });
+/// assert!(context.time_window.is_some());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TimeWindow {
+    /// Timestamp column to filter on; defaults to the freshness check's metric
+    pub column: Option<String>,
+
+    /// Earliest timestamp included in the window
+    pub since: Option<DateTime<Utc>>,
+
+    /// First timestamp after the window (exclusive)
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl TimeWindow {
+    /// Returns true if `timestamp` lies inside the window.
+    pub fn contains(&self, timestamp: DateTime<Utc>) -> bool {
+        self.since.is_none_or(|since| timestamp >= since)
+            && self.until.is_none_or(|until| timestamp < until)
+    }
+}
+
+/// How a sample is drawn from data holding more records than the sample size.
+///
+/// Taking the first records biases every quality check towards whatever was
+/// written first; the random strategies draw the same records for the same
+/// seed, so a failing run can be reproduced.
+///
+/// # Example
+///
+/// ```rust
+/// use contracts_core::{SamplingStrategy, ValidationContext};
+///
+/// let context = ValidationContext::new()
+///     .with_sample_size(10_000)
+///     .with_sampling(SamplingStrategy::Random { seed: 42 });
+/// assert!(context.sampling.is_random());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum SamplingStrategy {
+    /// The first records, in the order they are read
+    #[default]
+    Head,
+
+    /// Records drawn uniformly at random
+    Random {
+        /// Seed of the random draw
+        seed: u64,
+    },
+
+    /// Records drawn at random from every stratum, in proportion to the
+    /// number of records in each
+    Stratified {
+        /// Column whose values are the strata; table formats with
+        /// partitions use their partitions when unset
+        column: Option<String>,
+        /// Seed of the random draw
+        seed: u64,
+    },
+}
+
+impl SamplingStrategy {
+    /// Returns true for the strategies that draw records at random.
+    pub fn is_random(&self) -> bool {
+        !matches!(self, Self::Head)
+    }
+
+    /// Returns the seed of the random draw, if any.
+    pub fn seed(&self) -> Option<u64> {
+        match self {
+            Self::Head => None,
+            Self::Random { seed } | Self::Stratified { seed, .. } => Some(*seed),
+        }
+    }
+}
+
 /// Report of validation results.
 ///
 /// Contains detailed information about validation outcomes,
@@ -139,14 +389,109 @@ pub struct ValidationReport {
     /// Whether validation passed overall
     pub passed: bool,
 
-    /// List of errors encountered
-    pub errors: Vec<String>,
+    /// Failed checks with `error` severity
+    pub errors: Vec<Violation>,
+
+    /// Failed checks with `warning` severity
+    pub warnings: Vec<Violation>,
+
+    /// Checks that were not evaluated because too few records were validated
+    pub inconclusive: Vec<Violation>,
 
-    /// List of warnings
-    pub warnings: Vec<String>,
+    /// Failed checks with `info` severity, which do not fail validation
+    pub info: Vec<Violation>,
 
     /// Validation statistics
     pub stats: ValidationStats,
+
+    /// Where the validated contract came from, if known
+    pub provenance: Option<ContractProvenance>,
+
+    /// Conformance level the validated contract declares
+    pub conformance_level: Option<ConformanceLevel>,
+
+    /// ID of the table snapshot the data was read from, for table formats
+    /// with snapshots
+    pub snapshot_id: Option<i64>,
+
+    /// For incremental runs, the snapshot the increment starts after: only
+    /// rows committed after it, up to `snapshot_id`, were validated
+    pub base_snapshot_id: Option<i64>,
+
+    /// Per-segment results when the run was segmented by a column
+    pub segments: Vec<SegmentReport>,
+
+    /// Results of the related datasets of a multi-dataset contract
+    pub datasets: Vec<DatasetReport>,
+}
+
+/// Validation outcome for the rows sharing one value of the segment column.
+///
+/// Aggregate checks such as completeness can pass for a whole table while
+/// one country or tenant is badly broken; segments make that visible.
+#[derive(Debug, Clone)]
+pub struct SegmentReport {
+    /// Column the rows were grouped by
+    pub column: String,
+
+    /// The segment's value as text (`null` for rows without one)
+    pub value: String,
+
+    /// Report for the segment's rows alone
+    pub report: ValidationReport,
+}
+
+/// Validation outcome for one related dataset of a multi-dataset contract.
+#[derive(Debug, Clone)]
+pub struct DatasetReport {
+    /// Name of the dataset in the contract's `datasets:`
+    pub name: String,
+
+    /// Report for the dataset alone
+    pub report: ValidationReport,
+}
+
+/// Overall outcome of a validation run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationStatus {
+    /// Every evaluated check passed
+    Passed,
+    /// At least one check failed
+    Failed,
+    /// Nothing failed, but some checks had too few records to be evaluated
+    Inconclusive,
+}
+
+/// Origin of a contract definition.
+///
+/// Lets validation results be traced back to the exact revision of the
+/// contract file that produced them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContractProvenance {
+    /// Path of the contract file, relative to the repository root when the
+    /// file is tracked by git
+    pub path: String,
+
+    /// Commit hash of `HEAD` in the repository containing the contract
+    pub commit: Option<String>,
+
+    /// Whether the contract file has uncommitted changes relative to `commit`
+    pub dirty: Option<bool>,
+}
+
+impl ContractProvenance {
+    /// Creates provenance information for a contract loaded from `path`.
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns a short form of the commit hash, if available.
+    pub fn short_commit(&self) -> Option<&str> {
+        self.commit.as_deref().map(|c| &c[..c.len().min(12)])
+    }
 }
 
 /// Statistics about validation execution.
@@ -163,6 +508,146 @@ pub struct ValidationStats {
 
     /// Validation duration in milliseconds
     pub duration_ms: u64,
+
+    /// Observed statistics per column, when the validator collected them
+    pub columns: BTreeMap<String, ColumnStats>,
+
+    /// Row-level deletes in the validated table, for formats that have them
+    pub deletes: Option<DeleteStats>,
+
+    /// Violations left out of the report by the context's `max_errors`,
+    /// `max_errors_per_check` or `fail_fast`
+    pub truncated_violations: usize,
+}
+
+/// Admits violations into a report up to the limits of a
+/// [`ValidationContext`], counting the ones left out.
+///
+/// # Example
+///
+/// ```rust
+/// use contracts_core::{CheckKind, ValidationContext, Violation, ViolationLimits, codes};
+///
+/// let context = ValidationContext::new().with_max_errors_per_check(2);
+/// let mut limits = ViolationLimits::new(&context);
+/// let null = |row| {
+///     Violation::new(&codes::NULL_CONSTRAINT_VIOLATION, CheckKind::Schema, "null id")
+///         .with_field("id")
+///         .with_row(row)
+/// };
+///
+/// let admitted = (0..5).filter(|&row| limits.admit(&null(row))).count();
+/// assert_eq!(admitted, 2);
+/// assert_eq!(limits.truncated(), 3);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ViolationLimits {
+    max_errors: Option<usize>,
+    max_per_check: Option<usize>,
+    errors: usize,
+    /// Violations admitted per row-level check: kind, code and field
+    per_check: HashMap<(CheckKind, &'static str, Option<String>), usize>,
+    truncated: usize,
+}
+
+impl ViolationLimits {
+    /// Starts counting against the limits of `context`.
+    pub fn new(context: &ValidationContext) -> Self {
+        Self {
+            max_errors: context.error_limit(),
+            max_per_check: context.max_errors_per_check.map(|max| max.max(1)),
+            ..Self::default()
+        }
+    }
+
+    /// Returns true if no limit is set, so every violation is admitted.
+    pub fn is_unlimited(&self) -> bool {
+        self.max_errors.is_none() && self.max_per_check.is_none()
+    }
+
+    /// Returns whether `violation` fits in the report, counting it as
+    /// admitted or truncated.
+    pub fn admit(&mut self, violation: &Violation) -> bool {
+        let is_error = violation.severity == Severity::Error;
+        if is_error && self.max_errors.is_some_and(|max| self.errors >= max) {
+            self.truncated += 1;
+            return false;
+        }
+        if let Some(max) = self.max_per_check
+            && matches!(
+                violation.check_kind,
+                CheckKind::Schema | CheckKind::Constraint
+            )
+        {
+            let check = (
+                violation.check_kind,
+                violation.code.code,
+                violation.field.clone(),
+            );
+            let admitted = self.per_check.entry(check).or_default();
+            if *admitted >= max {
+                self.truncated += 1;
+                return false;
+            }
+            *admitted += 1;
+        }
+        if is_error {
+            self.errors += 1;
+        }
+        true
+    }
+
+    /// Returns the number of violations left out so far.
+    pub fn truncated(&self) -> usize {
+        self.truncated
+    }
+}
+
+/// Row-level deletes recorded in a table, from its snapshot summary.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DeleteStats {
+    /// Rows in the table's data files, deleted or not
+    pub data_records: u64,
+
+    /// Rows deleted by position delete files
+    pub position_deletes: u64,
+
+    /// Rows in equality delete files
+    ///
+    /// Each one deletes the rows matching its values, usually a single row.
+    pub equality_deletes: u64,
+}
+
+impl DeleteStats {
+    /// Estimated share of the data file rows that are deleted, from 0.0 to 1.0.
+    pub fn deleted_fraction(&self) -> f64 {
+        if self.data_records == 0 {
+            return 0.0;
+        }
+        let deleted = self.position_deletes.saturating_add(self.equality_deletes);
+        (deleted as f64 / self.data_records as f64).min(1.0)
+    }
+}
+
+/// Statistics observed for a single column of the validated data.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ColumnStats {
+    /// Number of null or missing values
+    pub null_count: usize,
+
+    /// Smallest non-null value, rendered as a string
+    pub min: Option<String>,
+
+    /// Largest non-null value, rendered as a string
+    pub max: Option<String>,
+
+    /// Number of distinct non-null values
+    ///
+    /// Exact when `distinct_exact` is true, otherwise a lower bound.
+    pub distinct_count: usize,
+
+    /// Whether `distinct_count` is exact
+    pub distinct_exact: bool,
 }
 
 impl ValidationReport {
@@ -172,28 +657,125 @@ impl ValidationReport {
             passed: true,
             errors: Vec::new(),
             warnings: Vec::new(),
+            inconclusive: Vec::new(),
+            info: Vec::new(),
             stats: ValidationStats::default(),
+            provenance: None,
+            conformance_level: None,
+            snapshot_id: None,
+            base_snapshot_id: None,
+            segments: Vec::new(),
+            datasets: Vec::new(),
         }
     }
 
     /// Creates a new failed validation report with an error.
-    pub fn failure(error: impl Into<String>) -> Self {
-        Self {
-            passed: false,
-            errors: vec![error.into()],
-            warnings: Vec::new(),
-            stats: ValidationStats::default(),
+    pub fn failure(error: Violation) -> Self {
+        let mut report = Self::success();
+        report.add_error(error);
+        report
+    }
+
+    /// Creates a failed report for an error that stopped validation.
+    ///
+    /// The error is recorded under `code` with all of its causes, so it is
+    /// listed like any failed check instead of being lost outside the report.
+    pub fn from_error(code: &'static ErrorCode, error: &(dyn std::error::Error + 'static)) -> Self {
+        Self::failure(Violation::new(
+            code,
+            CheckKind::Execution,
+            error_chain(error),
+        ))
+    }
+
+    /// Adds a violation under its own severity.
+    pub fn add_violation(&mut self, violation: Violation) {
+        match violation.severity {
+            Severity::Error => {
+                self.errors.push(violation);
+                self.passed = false;
+            }
+            Severity::Warning => self.warnings.push(violation),
+            Severity::Info => self.info.push(violation),
+        }
+    }
+
+    /// Adds a violation as an error.
+    pub fn add_error(&mut self, error: Violation) {
+        self.add_violation(error.with_severity(Severity::Error));
+    }
+
+    /// Adds a violation as a warning.
+    pub fn add_warning(&mut self, warning: Violation) {
+        self.add_violation(warning.with_severity(Severity::Warning));
+    }
+
+    /// Records a check whose result is inconclusive.
+    ///
+    /// Inconclusive checks do not fail the report, but they keep it from
+    /// being reported as a plain pass.
+    pub fn add_inconclusive(&mut self, check: Violation) {
+        self.inconclusive.push(check.with_severity(Severity::Info));
+    }
+
+    /// Attaches the reports of the related datasets, failing the run with a
+    /// [`DATASET_FAILED`](codes::DATASET_FAILED) error for each one that
+    /// failed.
+    pub fn with_datasets(mut self, datasets: Vec<DatasetReport>) -> Self {
+        for dataset in &datasets {
+            if dataset.report.passed {
+                continue;
+            }
+            self.add_error(Violation::new(
+                &codes::DATASET_FAILED,
+                CheckKind::Quality,
+                format!(
+                    "Dataset '{}' fails with {} error(s)",
+                    dataset.name,
+                    dataset.report.errors.len()
+                ),
+            ));
+        }
+        self.datasets = datasets;
+        self
+    }
+
+    /// Drops the violations beyond the limits of `context`, errors first,
+    /// and adds them to [`ValidationStats::truncated_violations`].
+    ///
+    /// Violations already left out when the report was built are not
+    /// counted twice, so validators can apply the limits again after adding
+    /// violations of their own.
+    pub fn apply_limits(&mut self, context: &ValidationContext) {
+        let mut limits = ViolationLimits::new(context);
+        if limits.is_unlimited() {
+            return;
         }
+        for violations in [&mut self.errors, &mut self.warnings, &mut self.info] {
+            violations.retain(|violation| limits.admit(violation));
+        }
+        self.stats.truncated_violations += limits.truncated();
+    }
+
+    /// Returns every failed check: errors, then warnings, then info.
+    pub fn violations(&self) -> impl Iterator<Item = &Violation> {
+        self.errors.iter().chain(&self.warnings).chain(&self.info)
     }
 
-    /// Adds an error to the report.
-    pub fn add_error(&mut self, error: impl Into<String>) {
-        self.errors.push(error.into());
-        self.passed = false;
+    /// Returns the overall outcome of the run.
+    pub fn status(&self) -> ValidationStatus {
+        if !self.passed {
+            ValidationStatus::Failed
+        } else if self.inconclusive.is_empty() {
+            ValidationStatus::Passed
+        } else {
+            ValidationStatus::Inconclusive
+        }
     }
 
-    /// Adds a warning to the report.
-    pub fn add_warning(&mut self, warning: impl Into<String>) {
-        self.warnings.push(warning.into());
+    /// Attaches contract provenance to the report.
+    pub fn with_provenance(mut self, provenance: ContractProvenance) -> Self {
+        self.provenance = Some(provenance);
+        self
     }
 }
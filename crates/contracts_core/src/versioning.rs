@@ -0,0 +1,249 @@
+//! Contract versions and their history.
+//!
+//! A contract's `version` is a semantic version. [`Contract::required_bump`]
+//! derives the increment a change calls for from the [`ContractDiff`]
+//! between two versions: a major release for breaking schema changes, a
+//! minor one for any other schema change, a patch otherwise. The optional
+//! `changelog:` section records what each release changed.
+
+use crate::{Contract, ContractDiff, ContractError, Result};
+use chrono::NaiveDate;
+pub use semver::Version;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// One release in a contract's `changelog:`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChangelogEntry {
+    /// Contract version the entry describes
+    pub version: String,
+
+    /// Release date (`YYYY-MM-DD`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<NaiveDate>,
+
+    /// What the release changed, one item per change
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changes: Vec<String>,
+}
+
+/// Part of a semantic version a release increments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VersionBump {
+    /// No schema change
+    Patch,
+    /// Schema changes that keep old data valid and consumers working
+    Minor,
+    /// Breaking schema changes
+    Major,
+}
+
+impl VersionBump {
+    /// Returns the increment from `older` to `newer`, or `None` if `newer`
+    /// is not greater.
+    ///
+    /// As in Cargo, before 1.0.0 a minor increment counts as major and a
+    /// patch increment as minor. A release of a pre-release (`2.0.0-rc.1` to
+    /// `2.0.0`) counts as the increment the release itself makes.
+    pub fn between(older: &Version, newer: &Version) -> Option<Self> {
+        if newer <= older {
+            return None;
+        }
+        let bump = if newer.major != older.major {
+            Self::Major
+        } else if newer.minor != older.minor {
+            Self::Minor
+        } else if newer.patch != older.patch {
+            Self::Patch
+        } else if newer.minor == 0 && newer.patch == 0 {
+            Self::Major
+        } else if newer.patch == 0 {
+            Self::Minor
+        } else {
+            Self::Patch
+        };
+        Some(match (newer.major, bump) {
+            (0, Self::Minor) => Self::Major,
+            (0, Self::Patch) => Self::Minor,
+            (_, bump) => bump,
+        })
+    }
+
+    /// Returns the lowercase name of the increment.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Patch => "patch",
+            Self::Minor => "minor",
+            Self::Major => "major",
+        }
+    }
+}
+
+impl fmt::Display for VersionBump {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Parses a contract version.
+///
+/// # Errors
+///
+/// Returns [`ContractError::InvalidVersion`] if `version` is not a semantic
+/// version such as `1.2.0` or `2.0.0-rc.1`.
+pub fn parse_version(version: &str) -> Result<Version> {
+    Version::parse(version).map_err(|e| ContractError::InvalidVersion {
+        version: version.to_string(),
+        message: e.to_string(),
+    })
+}
+
+impl Contract {
+    /// Returns the contract version as a semantic version.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ContractError::InvalidVersion`] if the version is not a
+    /// semantic version.
+    pub fn semver(&self) -> Result<Version> {
+        parse_version(&self.version)
+    }
+
+    /// Returns true if no schema change from `older` is breaking, so
+    /// consumers of `older` keep working and data valid for it stays valid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use contracts_core::{ContractBuilder, DataFormat, FieldBuilder, VersionBump};
+    ///
+    /// let v1 = ContractBuilder::new("orders", "sales")
+    ///     .version("1.0.0")
+    ///     .location("s3://lake/orders")
+    ///     .format(DataFormat::Iceberg)
+    ///     .field(FieldBuilder::new("id", "int64").build())
+    ///     .build();
+    /// let mut v2 = v1.clone();
+    /// v2.version = "1.1.0".to_string();
+    /// v2.schema.fields.push(FieldBuilder::new("note", "string").build());
+    ///
+    /// assert!(v2.is_backward_compatible_with(&v1));
+    /// assert_eq!(v2.required_bump(&v1), VersionBump::Minor);
+    /// assert!(v2.check_version_bump(&v1).is_ok());
+    /// ```
+    pub fn is_backward_compatible_with(&self, older: &Contract) -> bool {
+        !ContractDiff::compare(older, self).is_breaking()
+    }
+
+    /// Returns the smallest increment from `older` the schema changes call
+    /// for.
+    pub fn required_bump(&self, older: &Contract) -> VersionBump {
+        let diff = ContractDiff::compare(older, self);
+        if diff.is_breaking() {
+            VersionBump::Major
+        } else if !diff.is_empty() {
+            VersionBump::Minor
+        } else {
+            VersionBump::Patch
+        }
+    }
+
+    /// Checks that the version is greater than the version of `older` by at
+    /// least the increment the schema changes call for.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ContractError::InvalidVersion`] if either version is not a
+    /// semantic version, and [`ContractError::VersionMismatch`] if the
+    /// version is not increased enough.
+    pub fn check_version_bump(&self, older: &Contract) -> Result<()> {
+        let (old, new) = (older.semver()?, self.semver()?);
+        let required = self.required_bump(older);
+        match VersionBump::between(&old, &new) {
+            Some(bump) if bump >= required => Ok(()),
+            _ => Err(ContractError::VersionMismatch {
+                expected: format!("a {} version increment from {}", required, old),
+                actual: new.to_string(),
+            }),
+        }
+    }
+
+    /// Returns the changelog entry of `version`, if the contract has one.
+    pub fn changelog_entry(&self, version: &Version) -> Option<&ChangelogEntry> {
+        self.changelog
+            .iter()
+            .flatten()
+            .find(|entry| Version::parse(&entry.version).is_ok_and(|v| &v == version))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ContractBuilder, DataFormat, FieldBuilder};
+
+    fn version(s: &str) -> Version {
+        Version::parse(s).unwrap()
+    }
+
+    #[test]
+    fn test_bump_between_versions() {
+        let bump = |old, new| VersionBump::between(&version(old), &version(new));
+        assert_eq!(bump("1.2.3", "2.0.0"), Some(VersionBump::Major));
+        assert_eq!(bump("1.2.3", "1.3.0"), Some(VersionBump::Minor));
+        assert_eq!(bump("1.2.3", "1.2.4"), Some(VersionBump::Patch));
+        assert_eq!(bump("0.2.3", "0.3.0"), Some(VersionBump::Major));
+        assert_eq!(bump("0.2.3", "0.2.4"), Some(VersionBump::Minor));
+        assert_eq!(bump("2.0.0-rc.1", "2.0.0"), Some(VersionBump::Major));
+        assert_eq!(bump("1.2.3", "1.2.3"), None);
+        assert_eq!(bump("1.2.3", "1.2.0"), None);
+    }
+
+    #[test]
+    fn test_breaking_change_requires_major_bump() {
+        let v1 = ContractBuilder::new("orders", "sales")
+            .version("1.4.0")
+            .location("s3://lake/orders")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("id", "int64").build())
+            .field(FieldBuilder::new("note", "string").build())
+            .build();
+        let mut v2 = v1.clone();
+        v2.schema.fields.pop();
+        assert!(!v2.is_backward_compatible_with(&v1));
+        assert_eq!(v2.required_bump(&v1), VersionBump::Major);
+
+        v2.version = "1.5.0".to_string();
+        let err = v2.check_version_bump(&v1).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Incompatible contract version: expected a major version increment from 1.4.0, got 1.5.0"
+        );
+        v2.version = "2.0.0".to_string();
+        assert!(v2.check_version_bump(&v1).is_ok());
+
+        v2.version = "two".to_string();
+        assert!(matches!(
+            v2.check_version_bump(&v1),
+            Err(ContractError::InvalidVersion { .. })
+        ));
+    }
+
+    #[test]
+    fn test_changelog_entry() {
+        let mut contract = ContractBuilder::new("orders", "sales")
+            .version("1.1.0")
+            .location("s3://lake/orders")
+            .format(DataFormat::Iceberg)
+            .build();
+        contract.changelog = Some(vec![ChangelogEntry {
+            version: "1.1.0".to_string(),
+            date: NaiveDate::from_ymd_opt(2026, 3, 1),
+            changes: vec!["Added note".to_string()],
+        }]);
+        let entry = contract.changelog_entry(&contract.semver().unwrap());
+        assert_eq!(entry.unwrap().changes, vec!["Added note"]);
+        assert!(contract.changelog_entry(&version("1.0.0")).is_none());
+    }
+}
@@ -0,0 +1,153 @@
+//! Typed validation findings.
+//!
+//! Validators report every failed check as a [`Violation`] carrying its
+//! error code, the field and row it concerns, the kind of check and its
+//! severity, so reports can be filtered and aggregated without parsing
+//! messages. Rendering to text happens only where reports are displayed.
+
+use crate::{ErrorCode, Severity};
+use std::fmt;
+
+/// Kind of check that produced a [`Violation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CheckKind {
+    /// Checks of the contract definition itself, run without data
+    Definition,
+    /// Field presence, types and nullability
+    Schema,
+    /// Field constraints
+    Constraint,
+    /// Dataset-level quality checks: completeness, uniqueness, empty data,
+    /// deleted rows and segments
+    Quality,
+    /// Freshness check
+    Freshness,
+    /// Custom SQL checks
+    Custom,
+    /// Machine learning dataset checks
+    Ml,
+    /// Service level agreement checks
+    Sla,
+    /// Errors that stopped validation, such as unreadable data
+    Execution,
+}
+
+impl CheckKind {
+    /// Returns the lowercase name of the kind, as used in machine-readable
+    /// output.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Definition => "definition",
+            Self::Schema => "schema",
+            Self::Constraint => "constraint",
+            Self::Quality => "quality",
+            Self::Freshness => "freshness",
+            Self::Custom => "custom",
+            Self::Ml => "ml",
+            Self::Sla => "sla",
+            Self::Execution => "execution",
+        }
+    }
+}
+
+impl fmt::Display for CheckKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A failed check.
+///
+/// # Example
+///
+/// ```rust
+/// use contracts_core::{CheckKind, Severity, Violation, codes};
+///
+/// let violation = Violation::new(
+///     &codes::NULL_CONSTRAINT_VIOLATION,
+///     CheckKind::Schema,
+///     "Field 'id' is null but nullability is not allowed",
+/// )
+/// .with_field("id")
+/// .with_row(3);
+///
+/// assert_eq!(violation.severity, Severity::Error);
+/// assert_eq!(violation.field.as_deref(), Some("id"));
+/// assert_eq!(
+///     violation.to_string(),
+///     "[DCE0102] Field 'id' is null but nullability is not allowed"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation {
+    /// Stable code of the condition
+    pub code: &'static ErrorCode,
+
+    /// Contract field the violation is about, if any
+    pub field: Option<String>,
+
+    /// Index of the offending row in the validated records, when known
+    pub row_index: Option<usize>,
+
+    /// Kind of check that failed
+    pub check_kind: CheckKind,
+
+    /// Human-readable description, without the code
+    pub message: String,
+
+    /// How the violation is reported
+    pub severity: Severity,
+
+    /// Offending rows attached by the check, as JSON objects by column
+    pub rows: Vec<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl Violation {
+    /// Creates an error-severity violation without field or row.
+    pub fn new(
+        code: &'static ErrorCode,
+        check_kind: CheckKind,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            code,
+            field: None,
+            row_index: None,
+            check_kind,
+            message: message.into(),
+            severity: Severity::Error,
+            rows: Vec::new(),
+        }
+    }
+
+    /// Sets the field the violation is about.
+    pub fn with_field(mut self, field: impl Into<String>) -> Self {
+        self.field = Some(field.into());
+        self
+    }
+
+    /// Sets the index of the offending row.
+    pub fn with_row(mut self, row_index: usize) -> Self {
+        self.row_index = Some(row_index);
+        self
+    }
+
+    /// Attaches offending rows, such as those returned by a custom check.
+    pub fn with_rows(mut self, rows: Vec<serde_json::Map<String, serde_json::Value>>) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    /// Sets how the violation is reported.
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+}
+
+impl fmt::Display for Violation {
+    /// Writes the message prefixed with its code, e.g. `[DCE0101] ...`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}", self.code.code, self.message)
+    }
+}
@@ -0,0 +1,308 @@
+//! Typing of the text values read from CSV and NDJSON files.
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime};
+use contracts_core::{DataType, PrimitiveType};
+use contracts_validator::DataValue;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// How values read as text are given a type.
+///
+/// CSV holds only text, and NDJSON has no date, time, timestamp or decimal
+/// values. A value that cannot be parsed as its column type is kept as a
+/// string, so validation reports it as a type mismatch instead of the read
+/// failing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TypeInference {
+    /// Parse contract fields as their declared type and infer the type of
+    /// other columns from their values (the default)
+    #[default]
+    Contract,
+
+    /// Infer the type of every column from its values: boolean, int64,
+    /// float64 or string. NDJSON values keep their JSON type
+    Data,
+
+    /// Keep every CSV value as a string. NDJSON values keep their JSON type
+    Strings,
+}
+
+impl FromStr for TypeInference {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "contract" => Ok(Self::Contract),
+            "data" => Ok(Self::Data),
+            "strings" => Ok(Self::Strings),
+            other => Err(format!(
+                "unknown type inference '{}' (expected contract, data or strings)",
+                other
+            )),
+        }
+    }
+}
+
+/// Infers the type of a CSV column from its non-empty values.
+///
+/// Columns holding only `true`/`false` are boolean, then int64 and float64
+/// are tried; anything else, and columns without values, are strings.
+pub(crate) fn infer_column<'a>(values: impl Iterator<Item = &'a str>) -> DataType {
+    let (mut any, mut all_bool, mut all_int, mut all_float) = (false, true, true, true);
+    for value in values.filter(|value| !value.is_empty()) {
+        any = true;
+        all_bool &= parse_bool(value).is_some();
+        all_int &= value.parse::<i64>().is_ok();
+        all_float &= parse_float(value).is_some();
+        if !(all_bool || all_float) {
+            break;
+        }
+    }
+
+    let primitive = match (any, all_bool, all_int, all_float) {
+        (false, ..) => PrimitiveType::String,
+        (true, true, _, _) => PrimitiveType::Boolean,
+        (true, _, true, _) => PrimitiveType::Int64,
+        (true, _, _, true) => PrimitiveType::Float64,
+        _ => PrimitiveType::String,
+    };
+    DataType::Primitive(primitive)
+}
+
+/// Parses a non-empty text value as `data_type`.
+///
+/// Values that do not parse are returned as [`DataValue::String`]. Lists,
+/// maps and structs are read from JSON text.
+pub(crate) fn parse_text(text: &str, data_type: &DataType) -> DataValue {
+    let parsed = match data_type {
+        DataType::Primitive(primitive) => match primitive {
+            PrimitiveType::String | PrimitiveType::Uuid | PrimitiveType::Binary => None,
+            PrimitiveType::Int32 | PrimitiveType::Int64 => text
+                .parse()
+                .map(DataValue::Int)
+                .or_else(|_| text.parse().map(DataValue::UInt))
+                .ok(),
+            PrimitiveType::Float32 | PrimitiveType::Float64 => {
+                parse_float(text).map(DataValue::Float)
+            }
+            PrimitiveType::Boolean => parse_bool(text).map(DataValue::Bool),
+            PrimitiveType::Timestamp => {
+                is_timestamp(text).then(|| DataValue::Timestamp(text.to_string()))
+            }
+            PrimitiveType::Date => NaiveDate::parse_from_str(text, "%Y-%m-%d")
+                .ok()
+                .map(DataValue::Date),
+            PrimitiveType::Time => text.parse::<NaiveTime>().ok().map(DataValue::Time),
+            PrimitiveType::Decimal => parse_decimal(text),
+        },
+        DataType::List { .. } | DataType::Map { .. } | DataType::Struct { .. } => {
+            serde_json::from_str(text)
+                .ok()
+                .map(|value| json_value(value, Some(data_type)))
+        }
+    };
+    parsed.unwrap_or_else(|| DataValue::String(text.to_string()))
+}
+
+/// Converts a JSON value, parsing strings of a declared date, time,
+/// timestamp or decimal type.
+///
+/// Strings of other declared types are kept, so a quoted number in an
+/// integer field is reported as a type mismatch.
+pub(crate) fn json_value(value: serde_json::Value, declared: Option<&DataType>) -> DataValue {
+    use serde_json::Value;
+
+    match value {
+        Value::Null => DataValue::Null,
+        Value::Bool(b) => DataValue::Bool(b),
+        Value::Number(n) => match declared {
+            Some(decimal @ DataType::Primitive(PrimitiveType::Decimal)) => {
+                parse_text(&n.to_string(), decimal)
+            }
+            _ => n
+                .as_i64()
+                .map(DataValue::Int)
+                .or_else(|| n.as_u64().map(DataValue::UInt))
+                .or_else(|| n.as_f64().map(DataValue::Float))
+                .unwrap_or(DataValue::Null),
+        },
+        Value::String(s) => match declared {
+            Some(
+                primitive @ DataType::Primitive(
+                    PrimitiveType::Timestamp
+                    | PrimitiveType::Date
+                    | PrimitiveType::Time
+                    | PrimitiveType::Decimal,
+                ),
+            ) => parse_text(&s, primitive),
+            _ => DataValue::String(s),
+        },
+        Value::Array(items) => {
+            let element = match declared {
+                Some(DataType::List { element_type, .. }) => Some(&**element_type),
+                _ => None,
+            };
+            DataValue::List(
+                items
+                    .into_iter()
+                    .map(|item| json_value(item, element))
+                    .collect(),
+            )
+        }
+        Value::Object(entries) => DataValue::Map(
+            entries
+                .into_iter()
+                .map(|(key, value)| {
+                    let value_type = match declared {
+                        Some(DataType::Map { value_type, .. }) => Some(&**value_type),
+                        Some(DataType::Struct { fields }) => fields
+                            .iter()
+                            .find(|field| field.name == key)
+                            .map(|field| &field.data_type),
+                        _ => None,
+                    };
+                    let value = json_value(value, value_type);
+                    (key, value)
+                })
+                .collect::<HashMap<_, _>>(),
+        ),
+    }
+}
+
+fn parse_bool(text: &str) -> Option<bool> {
+    if text.eq_ignore_ascii_case("true") {
+        Some(true)
+    } else if text.eq_ignore_ascii_case("false") {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Parses a finite decimal or scientific number (not `inf` or `NaN`).
+fn parse_float(text: &str) -> Option<f64> {
+    text.bytes()
+        .all(|b| b.is_ascii_digit() || matches!(b, b'+' | b'-' | b'.' | b'e' | b'E'))
+        .then(|| text.parse().ok())
+        .flatten()
+}
+
+/// Parses `[-+]digits[.digits]` as an exact decimal.
+fn parse_decimal(text: &str) -> Option<DataValue> {
+    let unsigned = text.strip_prefix(['-', '+']).unwrap_or(text);
+    let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    if whole.is_empty() && fraction.is_empty()
+        || !whole
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let scale = i8::try_from(fraction.len()).ok()?;
+    let digits: i128 = format!("{whole}{fraction}").parse().ok()?;
+    Some(DataValue::Decimal {
+        value: if text.starts_with('-') {
+            -digits
+        } else {
+            digits
+        },
+        scale,
+    })
+}
+
+/// Accepts RFC 3339 timestamps and `YYYY-MM-DD HH:MM:SS[.fff]`, with a space
+/// or a `T` between date and time.
+fn is_timestamp(text: &str) -> bool {
+    DateTime::parse_from_rfc3339(text).is_ok()
+        || ["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f"]
+            .iter()
+            .any(|format| NaiveDateTime::parse_from_str(text, format).is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn primitive(primitive: PrimitiveType) -> DataType {
+        DataType::Primitive(primitive)
+    }
+
+    #[test]
+    fn test_infer_column() {
+        let infer = |values: &[&str]| infer_column(values.iter().copied()).to_string();
+
+        assert_eq!(infer(&["true", "", "FALSE"]), "boolean");
+        assert_eq!(infer(&["1", "-2"]), "int64");
+        assert_eq!(infer(&["1", "2.5", "1e3"]), "float64");
+        assert_eq!(infer(&["1", "inf"]), "string");
+        assert_eq!(infer(&["", ""]), "string");
+    }
+
+    #[test]
+    fn test_parse_text_as_declared_type() {
+        assert_eq!(
+            parse_text("42", &primitive(PrimitiveType::Int32)),
+            DataValue::Int(42)
+        );
+        assert_eq!(
+            parse_text("-12.340", &primitive(PrimitiveType::Decimal)),
+            DataValue::Decimal {
+                value: -12340,
+                scale: 3
+            }
+        );
+        assert_eq!(
+            parse_text("2024-01-31", &primitive(PrimitiveType::Date)),
+            DataValue::Date(NaiveDate::from_ymd_opt(2024, 1, 31).unwrap())
+        );
+        assert_eq!(
+            parse_text("2024-01-31 10:00:00", &primitive(PrimitiveType::Timestamp)),
+            DataValue::Timestamp("2024-01-31 10:00:00".to_string())
+        );
+        assert_eq!(
+            parse_text("[1, 2]", &"list<int64>".parse().unwrap()),
+            DataValue::List(vec![DataValue::Int(1), DataValue::Int(2)])
+        );
+
+        // Unparseable values are kept for validation to report
+        assert_eq!(
+            parse_text("n/a", &primitive(PrimitiveType::Int64)),
+            DataValue::String("n/a".to_string())
+        );
+        assert_eq!(
+            parse_text("1.5", &primitive(PrimitiveType::Int64)),
+            DataValue::String("1.5".to_string())
+        );
+    }
+
+    #[test]
+    fn test_json_value_parses_declared_strings() {
+        let value = serde_json::json!({
+            "at": "2024-01-31T10:00:00Z",
+            "amount": 12.5,
+            "count": "3",
+        });
+        let declared: DataType = "struct<at:timestamp,amount:decimal,count:int64>"
+            .parse()
+            .unwrap();
+
+        let DataValue::Map(entries) = json_value(value, Some(&declared)) else {
+            panic!("expected a map");
+        };
+        assert_eq!(
+            entries["at"],
+            DataValue::Timestamp("2024-01-31T10:00:00Z".to_string())
+        );
+        assert_eq!(
+            entries["amount"],
+            DataValue::Decimal {
+                value: 125,
+                scale: 1
+            }
+        );
+        assert_eq!(entries["count"], DataValue::String("3".to_string()));
+    }
+}
@@ -0,0 +1,128 @@
+//! CSV and newline-delimited JSON file integration for Data Contracts Engine.
+//!
+//! This module validates data contracts against CSV and NDJSON files on local
+//! disk or in S3. Rows are parsed into a [`DataSet`](contracts_validator::DataSet)
+//! and validated by [`DataValidator`](contracts_validator::DataValidator).
+//! Text values are typed from the contract by default, see [`TypeInference`].
+//!
+//! A location names either one file or a directory (prefix) of files with the
+//! format's extension:
+//!
+//! - `data/events.csv`, `/abs/path/events/` or `file:///abs/path/events`
+//! - `s3://bucket/path/events.jsonl` or `s3://bucket/path/events/`
+//!
+//! S3 credentials and region are taken from the standard `AWS_*` environment
+//! variables.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use contracts_core::ValidationContext;
+//! use contracts_files::{FileFormat, FileValidator};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let validator = FileValidator::new("s3://bucket/events/", FileFormat::Csv).await?;
+//!
+//! // Load contract
+//! // let contract = ...;
+//!
+//! // Validate files against contract
+//! // let report = validator.validate(&contract, &ValidationContext::new()).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use contracts_core::{BoxError, Coded, ErrorCode, codes};
+use thiserror::Error;
+
+mod inference;
+mod location;
+mod reader;
+mod validator;
+
+pub use inference::TypeInference;
+pub use location::FileLocation;
+pub use reader::{FileFormat, FileOptions};
+pub use validator::FileValidator;
+
+/// Error types specific to CSV and NDJSON files.
+#[derive(Error, Debug)]
+pub enum FilesError {
+    /// The location is not a path or a supported URL
+    #[error("Invalid file location: {0}")]
+    InvalidLocation(String),
+
+    /// No data file exists at the location
+    #[error("No data files found at {0}")]
+    NotFound(String),
+
+    /// A file could not be read from its store
+    #[error("Failed to read data file: {message}")]
+    ReadError {
+        /// What was being read
+        message: String,
+        /// The underlying failure
+        #[source]
+        source: BoxError,
+    },
+
+    /// A file is not valid CSV or NDJSON
+    #[error("Failed to parse data file: {message}")]
+    ParseError {
+        /// The file and line that could not be parsed
+        message: String,
+        /// The underlying failure
+        #[source]
+        source: BoxError,
+    },
+}
+
+impl FilesError {
+    /// Creates a [`FilesError::ReadError`] caused by `source`.
+    pub fn read(message: impl Into<String>, source: impl Into<BoxError>) -> Self {
+        Self::ReadError {
+            message: message.into(),
+            source: source.into(),
+        }
+    }
+
+    /// Creates a [`FilesError::ParseError`] caused by `source`.
+    pub fn parse(message: impl Into<String>, source: impl Into<BoxError>) -> Self {
+        Self::ParseError {
+            message: message.into(),
+            source: source.into(),
+        }
+    }
+}
+
+impl Coded for FilesError {
+    fn error_code(&self) -> &'static ErrorCode {
+        match self {
+            Self::InvalidLocation(_) => &codes::INVALID_LOCATION,
+            Self::NotFound(_) => &codes::FILE_NOT_FOUND,
+            Self::ReadError { .. } => &codes::FILE_READ,
+            Self::ParseError { .. } => &codes::FILE_PARSE,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::error::Error as _;
+
+    #[test]
+    fn test_error_display() {
+        let err = FilesError::parse("events.jsonl, line 3", "expected value at column 1");
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse data file: events.jsonl, line 3"
+        );
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            "expected value at column 1"
+        );
+        assert_eq!(err.error_code().code, "DCE2203");
+    }
+}
@@ -0,0 +1,338 @@
+//! Parsing of CSV and NDJSON files into rows.
+
+use crate::FilesError;
+use crate::inference::{TypeInference, infer_column, json_value, parse_text};
+use contracts_core::{Contract, DataFormat, DataType, PrimitiveType, StructField};
+use contracts_validator::{DataRow, DataValue};
+use std::collections::HashMap;
+
+/// Format of the files read by a [`FileValidator`](crate::FileValidator).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileFormat {
+    /// Comma-separated values with a header row
+    Csv,
+
+    /// One JSON object per line
+    NdJson,
+}
+
+impl FileFormat {
+    /// Returns the file format of a contract's data format, if this crate
+    /// reads it.
+    pub fn from_data_format(format: &DataFormat) -> Option<Self> {
+        match format {
+            DataFormat::Csv => Some(Self::Csv),
+            DataFormat::Json => Some(Self::NdJson),
+            _ => None,
+        }
+    }
+
+    /// Returns the extensions of the files read from directory locations.
+    pub fn extensions(self) -> &'static [&'static str] {
+        match self {
+            Self::Csv => &[".csv"],
+            Self::NdJson => &[".json", ".jsonl", ".ndjson"],
+        }
+    }
+}
+
+/// How files are parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileOptions {
+    /// How text values are typed
+    pub inference: TypeInference,
+
+    /// Values of each CSV column read to infer its type
+    pub infer_max_records: usize,
+
+    /// CSV field delimiter
+    pub delimiter: u8,
+
+    /// Whether the first CSV record names the columns. Without a header,
+    /// columns take the names of the contract fields, in order
+    pub has_header: bool,
+}
+
+impl Default for FileOptions {
+    fn default() -> Self {
+        Self {
+            inference: TypeInference::default(),
+            infer_max_records: 1000,
+            delimiter: b',',
+            has_header: true,
+        }
+    }
+}
+
+/// Declared type of each contract field, by name.
+fn declared_types(contract: &Contract) -> HashMap<&str, &DataType> {
+    contract
+        .schema
+        .fields
+        .iter()
+        .map(|field| (field.name.as_str(), &field.field_type))
+        .collect()
+}
+
+/// Type of the `before` and `after` row images of a contract with CDC
+/// envelopes: a struct of the contract fields.
+fn image_type(contract: &Contract) -> Option<DataType> {
+    contract.cdc.as_ref()?;
+    let fields = contract
+        .schema
+        .fields
+        .iter()
+        .map(|field| StructField {
+            name: field.name.clone(),
+            data_type: field.field_type.clone(),
+            nullable: field.nullable,
+        })
+        .collect();
+    Some(DataType::Struct { fields })
+}
+
+/// A parsed row and the line of the file it starts on.
+pub(crate) type Line = (u64, DataRow);
+
+/// Parses at most `limit` CSV records into rows. Empty values are null.
+pub(crate) fn parse_csv(
+    bytes: &[u8],
+    url: &str,
+    contract: &Contract,
+    options: &FileOptions,
+    limit: usize,
+) -> Result<Vec<Line>, FilesError> {
+    let parse_error = |e: csv::Error| {
+        let line = e.position().map_or(0, |position| position.line());
+        FilesError::parse(format!("{url}, line {line}"), e)
+    };
+
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(options.delimiter)
+        .has_headers(options.has_header)
+        .from_reader(bytes);
+    let records = reader
+        .records()
+        .take(limit)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(parse_error)?;
+
+    let columns: Vec<String> = if options.has_header {
+        reader
+            .headers()
+            .map_err(parse_error)?
+            .iter()
+            .map(str::to_string)
+            .collect()
+    } else {
+        let width = records.first().map_or(0, csv::StringRecord::len);
+        (0..width)
+            .map(|i| match contract.schema.fields.get(i) {
+                Some(field) => field.name.clone(),
+                None => format!("column_{}", i + 1),
+            })
+            .collect()
+    };
+
+    let declared = declared_types(contract);
+    let types: Vec<DataType> = columns
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let inferred = || {
+                infer_column(
+                    records
+                        .iter()
+                        .take(options.infer_max_records)
+                        .map(|record| record.get(i).unwrap_or_default()),
+                )
+            };
+            match options.inference {
+                TypeInference::Contract => declared
+                    .get(name.as_str())
+                    .map_or_else(inferred, |&declared| declared.clone()),
+                TypeInference::Data => inferred(),
+                TypeInference::Strings => DataType::Primitive(PrimitiveType::String),
+            }
+        })
+        .collect();
+
+    Ok(records
+        .iter()
+        .map(|record| {
+            let line = record.position().map_or(0, csv::Position::line);
+            let row = columns
+                .iter()
+                .zip(&types)
+                .zip(record.iter())
+                .map(|((name, data_type), text)| {
+                    let value = if text.is_empty() {
+                        DataValue::Null
+                    } else {
+                        parse_text(text, data_type)
+                    };
+                    (name.clone(), value)
+                })
+                .collect();
+            (line, row)
+        })
+        .collect())
+}
+
+/// Parses at most `limit` NDJSON lines into rows. Blank lines are skipped.
+pub(crate) fn parse_ndjson(
+    bytes: &[u8],
+    url: &str,
+    contract: &Contract,
+    options: &FileOptions,
+    limit: usize,
+) -> Result<Vec<Line>, FilesError> {
+    let text = std::str::from_utf8(bytes)
+        .map_err(|e| FilesError::parse(format!("{url} is not valid UTF-8"), e))?;
+    let declared = declared_types(contract);
+    let image = image_type(contract);
+
+    let mut rows = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        if rows.len() >= limit {
+            break;
+        }
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let position = || format!("{url}, line {}", index + 1);
+        let value: serde_json::Value =
+            serde_json::from_str(line).map_err(|e| FilesError::parse(position(), e))?;
+        let serde_json::Value::Object(entries) = value else {
+            return Err(FilesError::parse(
+                position(),
+                format!("expected a JSON object, found {value}"),
+            ));
+        };
+
+        let row = entries
+            .into_iter()
+            .map(|(name, value)| {
+                let declared = match options.inference {
+                    TypeInference::Contract => match (name.as_str(), &image) {
+                        ("before" | "after", Some(image)) => Some(image),
+                        (name, _) => declared.get(name).copied(),
+                    },
+                    TypeInference::Data | TypeInference::Strings => None,
+                };
+                let value = json_value(value, declared);
+                (name, value)
+            })
+            .collect();
+        rows.push((index as u64 + 1, row));
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use contracts_core::{ContractBuilder, FieldBuilder};
+    use pretty_assertions::assert_eq;
+
+    fn contract() -> Contract {
+        ContractBuilder::new("orders", "team")
+            .location("orders.csv")
+            .format(DataFormat::Csv)
+            .field(FieldBuilder::new("id", "int64").build())
+            .field(FieldBuilder::new("placed_on", "date").build())
+            .build()
+    }
+
+    #[test]
+    fn test_parse_csv_types_columns() {
+        let csv = b"id,placed_on,total\n1,2024-01-31,9.5\n2,,12\n";
+        let parse = |inference| {
+            let options = FileOptions {
+                inference,
+                ..FileOptions::default()
+            };
+            parse_csv(csv, "orders.csv", &contract(), &options, usize::MAX).unwrap()
+        };
+
+        let rows = parse(TypeInference::Contract);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1].0, 3);
+        assert_eq!(rows[0].1["id"], DataValue::Int(1));
+        assert!(matches!(rows[0].1["placed_on"], DataValue::Date(_)));
+        assert_eq!(rows[1].1["placed_on"], DataValue::Null);
+        // Undeclared columns are inferred: 12 is read as a float like 9.5
+        assert_eq!(rows[1].1["total"], DataValue::Float(12.0));
+
+        let rows = parse(TypeInference::Data);
+        assert_eq!(
+            rows[0].1["placed_on"],
+            DataValue::String("2024-01-31".to_string())
+        );
+
+        let rows = parse(TypeInference::Strings);
+        assert_eq!(rows[0].1["id"], DataValue::String("1".to_string()));
+    }
+
+    #[test]
+    fn test_parse_csv_without_header() {
+        let options = FileOptions {
+            delimiter: b';',
+            has_header: false,
+            ..FileOptions::default()
+        };
+        let rows = parse_csv(b"1;2024-01-31;x\n", "orders.csv", &contract(), &options, 10).unwrap();
+
+        assert_eq!(rows[0].1["id"], DataValue::Int(1));
+        assert_eq!(rows[0].1["column_3"], DataValue::String("x".to_string()));
+    }
+
+    #[test]
+    fn test_parse_csv_reports_line_of_ragged_record() {
+        let err = parse_csv(
+            b"id,placed_on\n1,2024-01-31\n2\n",
+            "orders.csv",
+            &contract(),
+            &FileOptions::default(),
+            usize::MAX,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse data file: orders.csv, line 3"
+        );
+    }
+
+    #[test]
+    fn test_parse_ndjson() {
+        let json = b"{\"id\": 1, \"placed_on\": \"2024-01-31\"}\n\n{\"id\": \"2\"}\n[1]\n";
+
+        let rows = parse_ndjson(
+            json,
+            "orders.jsonl",
+            &contract(),
+            &FileOptions::default(),
+            2,
+        )
+        .unwrap();
+        assert_eq!(rows.len(), 2);
+        assert!(matches!(rows[0].1["placed_on"], DataValue::Date(_)));
+        // Blank lines are skipped but still counted
+        assert_eq!(rows[1].0, 3);
+        assert_eq!(rows[1].1["id"], DataValue::String("2".to_string()));
+
+        let err = parse_ndjson(
+            json,
+            "orders.jsonl",
+            &contract(),
+            &FileOptions::default(),
+            usize::MAX,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse data file: orders.jsonl, line 4"
+        );
+    }
+}
@@ -0,0 +1,274 @@
+//! Main CSV and NDJSON validator implementation.
+
+use crate::reader::{Line, parse_csv, parse_ndjson};
+use crate::{FileFormat, FileLocation, FileOptions, FilesError};
+use contracts_core::{CheckKind, Contract, ValidationContext, ValidationReport, Violation, codes};
+use contracts_validator::{DataSet, DataValidator, DataValue, SchemaValidator};
+use object_store::ObjectMeta;
+use tracing::{debug, info, warn};
+
+/// Validator for CSV and NDJSON files against data contracts.
+///
+/// Reads the files into a [`DataSet`], typing values as configured by
+/// [`FileOptions::inference`], and validates it with [`DataValidator`].
+pub struct FileValidator {
+    location: FileLocation,
+    format: FileFormat,
+    options: FileOptions,
+    files: Vec<ObjectMeta>,
+}
+
+impl FileValidator {
+    /// Creates a validator for the file or directory at `location`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the location is invalid or holds no file of the
+    /// format.
+    pub async fn new(location: &str, format: FileFormat) -> Result<Self, FilesError> {
+        info!("Initializing {:?} validator for: {}", format, location);
+        Self::with_location(FileLocation::parse(location)?, format).await
+    }
+
+    /// Creates a validator for an already resolved location.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the location holds no file of the format.
+    pub async fn with_location(
+        location: FileLocation,
+        format: FileFormat,
+    ) -> Result<Self, FilesError> {
+        let files = location.list_files(format.extensions()).await?;
+        info!(
+            "Validating {} {:?} file(s) at {}",
+            files.len(),
+            format,
+            location.url()
+        );
+        Ok(Self {
+            location,
+            format,
+            options: FileOptions::default(),
+            files,
+        })
+    }
+
+    /// Sets how the files are parsed.
+    pub fn with_options(mut self, options: FileOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Returns the URLs of the files read by this validator.
+    pub fn files(&self) -> Vec<String> {
+        self.files
+            .iter()
+            .map(|meta| self.location.object_url(&meta.location))
+            .collect()
+    }
+
+    /// Reads at most `limit` rows, from the files in path order.
+    ///
+    /// Values are typed with the contract's field types, depending on
+    /// [`FileOptions::inference`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a file cannot be read or is not valid for the
+    /// format.
+    pub async fn read_data_set(
+        &self,
+        contract: &Contract,
+        limit: usize,
+    ) -> Result<DataSet, FilesError> {
+        let lines = self.read_lines(contract, limit).await?;
+        Ok(DataSet::from_rows(
+            lines.into_iter().map(|(_, (_, row))| row).collect(),
+        ))
+    }
+
+    /// Reads at most `limit` rows, with the index of their file and their line.
+    async fn read_lines(
+        &self,
+        contract: &Contract,
+        limit: usize,
+    ) -> Result<Vec<(usize, Line)>, FilesError> {
+        let mut lines = Vec::new();
+        for (file, meta) in self.files.iter().enumerate() {
+            if lines.len() >= limit {
+                break;
+            }
+
+            let url = self.location.object_url(&meta.location);
+            debug!("Reading {} ({} bytes)", url, meta.size);
+            let bytes = self
+                .location
+                .store()
+                .get(&meta.location)
+                .await
+                .map_err(|e| FilesError::read(format!("Failed to open {url}"), e))?
+                .bytes()
+                .await
+                .map_err(|e| FilesError::read(format!("Failed to read {url}"), e))?;
+
+            let remaining = limit - lines.len();
+            let parsed = match self.format {
+                FileFormat::Csv => parse_csv(&bytes, &url, contract, &self.options, remaining)?,
+                FileFormat::NdJson => {
+                    parse_ndjson(&bytes, &url, contract, &self.options, remaining)?
+                }
+            };
+            lines.extend(parsed.into_iter().map(|line| (file, line)));
+        }
+        Ok(lines)
+    }
+
+    /// Splits off the rows holding a value that is not of its contract
+    /// field's type, and reports a type mismatch for each such field.
+    ///
+    /// Validation runs on typed columns, so these rows are left out of the
+    /// other checks.
+    fn split_mistyped(
+        &self,
+        contract: &Contract,
+        lines: Vec<(usize, Line)>,
+    ) -> (DataSet, usize, Vec<Violation>) {
+        let mut mismatches: Vec<Option<Mismatch>> = vec![None; contract.schema.fields.len()];
+        let mut rows = Vec::with_capacity(lines.len());
+        let mut mistyped = 0;
+
+        for (file, (line, row)) in lines {
+            let mut typed = true;
+            for (field, mismatch) in contract.schema.fields.iter().zip(&mut mismatches) {
+                let Some(value) = row.get(&field.name).filter(|value| !value.is_null()) else {
+                    continue;
+                };
+                if SchemaValidator::type_matches(&field.field_type, value) {
+                    continue;
+                }
+                typed = false;
+                match mismatch {
+                    Some(mismatch) => mismatch.rows += 1,
+                    None => {
+                        *mismatch = Some(Mismatch {
+                            found: match value {
+                                DataValue::String(s) => format!("'{s}'"),
+                                other => other.type_name().to_string(),
+                            },
+                            at: format!(
+                                "{}, line {}",
+                                self.location.object_url(&self.files[file].location),
+                                line
+                            ),
+                            rows: 1,
+                        })
+                    }
+                }
+            }
+            if typed {
+                rows.push(row);
+            } else {
+                mistyped += 1;
+            }
+        }
+
+        let violations = contract
+            .schema
+            .fields
+            .iter()
+            .zip(mismatches)
+            .filter_map(|(field, mismatch)| {
+                let mismatch = mismatch?;
+                let mut message = format!(
+                    "Type mismatch for field '{}': expected {}, found {} at {}",
+                    field.name, field.field_type, mismatch.found, mismatch.at
+                );
+                if mismatch.rows > 1 {
+                    message.push_str(&format!(" and in {} more rows", mismatch.rows - 1));
+                }
+                Some(
+                    Violation::new(&codes::TYPE_MISMATCH, CheckKind::Schema, message)
+                        .with_field(&field.name),
+                )
+            })
+            .collect();
+        (DataSet::from_rows(rows), mistyped, violations)
+    }
+
+    /// Validates the files against a contract.
+    ///
+    /// Sampled runs read only the first rows; time-windowed and filtered runs
+    /// read every row, so the window and filter are applied before sampling. Values that do not
+    /// parse as their field's type are reported as type mismatches, with the
+    /// file and line of the first one.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract` - The data contract to validate against
+    /// * `context` - Validation context with options (sample_size, schema_only, strict, etc.)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the files cannot be read or parsed.
+    pub async fn validate(
+        &self,
+        contract: &Contract,
+        context: &ValidationContext,
+    ) -> Result<ValidationReport, FilesError> {
+        info!(
+            "Validating {:?} files against contract: {}",
+            self.format, contract.name
+        );
+
+        let (dataset, mistyped, mismatches) = if context.schema_only {
+            (DataSet::empty(), 0, Vec::new())
+        } else {
+            let limit = if context.filters_rows() {
+                usize::MAX
+            } else {
+                context.sample_size.unwrap_or(usize::MAX)
+            };
+            let lines = self.read_lines(contract, limit).await?;
+            self.split_mistyped(contract, lines)
+        };
+        debug!(
+            "Read {} rows for validation, {} with mistyped values",
+            dataset.len() + mistyped,
+            mistyped
+        );
+
+        let mut validator = DataValidator::new();
+        let mut report = validator
+            .validate_with_data_async(contract, &dataset, context)
+            .await;
+        if !mismatches.is_empty() {
+            report.stats.records_validated += mistyped;
+            for violation in mismatches {
+                report.add_error(violation);
+            }
+            report.passed = false;
+            report.apply_limits(context);
+        }
+
+        if report.passed {
+            info!("Validation passed for: {}", self.location.url());
+        } else {
+            warn!(
+                "Validation failed for: {} with {} errors",
+                self.location.url(),
+                report.errors.len()
+            );
+        }
+        Ok(report)
+    }
+}
+
+/// First value of a field that is not of the field's type, and how many rows
+/// hold such a value.
+#[derive(Clone)]
+struct Mismatch {
+    found: String,
+    at: String,
+    rows: usize,
+}
@@ -0,0 +1,263 @@
+//! Integration tests for CSV and NDJSON validation.
+
+use contracts_core::{
+    CdcConfig, Contract, ContractBuilder, DataFormat, FieldBuilder, FieldConstraints,
+    ValidationContext,
+};
+use contracts_files::{FileFormat, FileOptions, FileValidator, FilesError, TypeInference};
+use pretty_assertions::assert_eq;
+use std::path::Path;
+use tempfile::TempDir;
+
+fn users_contract(location: &Path, format: DataFormat) -> Contract {
+    ContractBuilder::new("users", "team")
+        .location(location.display().to_string())
+        .format(format)
+        .field(
+            FieldBuilder::new("id", "int64")
+                .nullable(false)
+                .constraint(FieldConstraints::Range {
+                    min: 1.0,
+                    max: 100.0,
+                })
+                .build(),
+        )
+        .field(FieldBuilder::new("name", "string").nullable(false).build())
+        .field(FieldBuilder::new("joined", "date").build())
+        .build()
+}
+
+#[tokio::test]
+async fn test_validate_csv_passes() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("users.csv");
+    std::fs::write(&path, "id,name,joined\n1,ada,2024-01-31\n2,bob,\n").unwrap();
+
+    let validator = FileValidator::new(path.to_str().unwrap(), FileFormat::Csv)
+        .await
+        .unwrap();
+    let report = validator
+        .validate(
+            &users_contract(&path, DataFormat::Csv),
+            &ValidationContext::new(),
+        )
+        .await
+        .unwrap();
+
+    assert!(report.passed, "unexpected errors: {:?}", report.errors);
+    assert_eq!(report.stats.records_validated, 2);
+}
+
+#[tokio::test]
+async fn test_validate_csv_reports_unparseable_values() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("users.csv");
+    std::fs::write(&path, "id,name,joined\n1,ada,31/01/2024\nn/a,bob,\n").unwrap();
+
+    let validator = FileValidator::new(path.to_str().unwrap(), FileFormat::Csv)
+        .await
+        .unwrap();
+    let report = validator
+        .validate(
+            &users_contract(&path, DataFormat::Csv),
+            &ValidationContext::new(),
+        )
+        .await
+        .unwrap();
+
+    assert!(!report.passed);
+    let mut fields: Vec<(&str, Option<&str>)> = report
+        .errors
+        .iter()
+        .map(|e| (e.code.code, e.field.as_deref()))
+        .collect();
+    fields.sort();
+    assert_eq!(
+        fields,
+        vec![("DCE0103", Some("id")), ("DCE0103", Some("joined"))]
+    );
+
+    let id = report
+        .errors
+        .iter()
+        .find(|e| e.field.as_deref() == Some("id"));
+    let message = &id.unwrap().message;
+    assert!(message.contains("found 'n/a' at "), "{message}");
+    assert!(message.ends_with("users.csv, line 3"), "{message}");
+}
+
+#[tokio::test]
+async fn test_validate_ndjson_directory_reads_every_file() {
+    let dir = TempDir::new().unwrap();
+    std::fs::write(
+        dir.path().join("part-0.jsonl"),
+        "{\"id\": 1, \"name\": \"ada\", \"joined\": \"2024-01-31\"}\n",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join("part-1.ndjson"),
+        "{\"id\": 2, \"name\": null}\n\n{\"id\": 500, \"name\": \"eve\"}\n",
+    )
+    .unwrap();
+    std::fs::write(dir.path().join("_SUCCESS"), "").unwrap();
+
+    let validator = FileValidator::new(dir.path().to_str().unwrap(), FileFormat::NdJson)
+        .await
+        .unwrap();
+    assert_eq!(validator.files().len(), 2);
+
+    let report = validator
+        .validate(
+            &users_contract(dir.path(), DataFormat::Json),
+            &ValidationContext::new(),
+        )
+        .await
+        .unwrap();
+
+    assert!(!report.passed);
+    assert_eq!(report.stats.records_validated, 3);
+    let mut codes: Vec<&str> = report.errors.iter().map(|e| e.code.code).collect();
+    codes.sort();
+    assert_eq!(codes, vec!["DCE0102", "DCE0203"]);
+}
+
+#[tokio::test]
+async fn test_validate_cdc_events_checks_after_images() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("users.jsonl");
+    let events = [
+        r#"{"op": "c", "before": null, "after": {"id": 1, "name": "ada", "joined": "2024-01-31"}, "ts_ms": 100}"#,
+        r#"{"op": "u", "before": {"id": 1, "name": "ada"}, "after": {"id": 1, "name": "ada lovelace"}, "ts_ms": 50}"#,
+        r#"{"op": "d", "before": {"id": 1, "name": "ada lovelace"}, "after": null, "ts_ms": 200}"#,
+        r#"{"op": "x", "before": null, "after": {"id": 2, "name": "bob"}, "ts_ms": 300}"#,
+    ];
+    std::fs::write(&path, events.join("\n")).unwrap();
+
+    let mut contract = users_contract(&path, DataFormat::Json);
+    contract.cdc = Some(CdcConfig {
+        key: vec!["id".to_string()],
+        allowed_ops: None,
+    });
+    let validator = FileValidator::new(path.to_str().unwrap(), FileFormat::NdJson)
+        .await
+        .unwrap();
+    let report = validator
+        .validate(&contract, &ValidationContext::new())
+        .await
+        .unwrap();
+
+    // The after images of the create, update and unknown operation are
+    // validated, with `joined` read as a date
+    assert_eq!(report.stats.records_validated, 3);
+    let codes = |violations: &[contracts_core::Violation]| {
+        violations.iter().map(|v| v.code.code).collect::<Vec<_>>()
+    };
+    assert_eq!(codes(&report.errors), vec!["DCE0208"]);
+    assert!(report.errors[0].message.contains("'x' at row 3"));
+    assert_eq!(codes(&report.warnings), vec!["DCE0308"]);
+}
+
+#[tokio::test]
+async fn test_validate_sample_reads_first_rows() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("users.csv");
+    std::fs::write(&path, "id,name\n1,ada\n2,bob\n500,\n").unwrap();
+
+    let validator = FileValidator::new(path.to_str().unwrap(), FileFormat::Csv)
+        .await
+        .unwrap();
+    let report = validator
+        .validate(
+            &users_contract(&path, DataFormat::Csv),
+            &ValidationContext::new().with_sample_size(2),
+        )
+        .await
+        .unwrap();
+
+    assert!(report.passed, "unexpected errors: {:?}", report.errors);
+    assert_eq!(report.stats.records_validated, 2);
+}
+
+#[tokio::test]
+async fn test_validate_row_filter_reads_every_row() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("users.csv");
+    std::fs::write(&path, "id,name\n500,\n1,ada\n2,bob\n600,eve\n").unwrap();
+
+    let validator = FileValidator::new(path.to_str().unwrap(), FileFormat::Csv)
+        .await
+        .unwrap();
+    let report = validator
+        .validate(
+            &users_contract(&path, DataFormat::Csv),
+            &ValidationContext::new()
+                .with_row_filter("id > 100")
+                .with_sample_size(3),
+        )
+        .await
+        .unwrap();
+
+    // Both rows above 100 are read, past the sample size
+    assert_eq!(report.stats.records_validated, 2);
+    let mut codes: Vec<&str> = report.errors.iter().map(|e| e.code.code).collect();
+    codes.sort();
+    assert_eq!(codes, vec!["DCE0102", "DCE0203"]);
+}
+
+#[tokio::test]
+async fn test_string_inference_keeps_text() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("users.csv");
+    std::fs::write(&path, "id,name\n1,ada\n").unwrap();
+
+    let validator = FileValidator::new(path.to_str().unwrap(), FileFormat::Csv)
+        .await
+        .unwrap()
+        .with_options(FileOptions {
+            inference: TypeInference::Strings,
+            ..FileOptions::default()
+        });
+    let report = validator
+        .validate(
+            &users_contract(&path, DataFormat::Csv),
+            &ValidationContext::new(),
+        )
+        .await
+        .unwrap();
+
+    assert!(!report.passed);
+    assert_eq!(report.errors[0].field.as_deref(), Some("id"));
+}
+
+#[tokio::test]
+async fn test_invalid_ndjson_reports_line() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("users.jsonl");
+    std::fs::write(&path, "{\"id\": 1, \"name\": \"ada\"}\n{\"id\": 2,\n").unwrap();
+
+    let validator = FileValidator::new(path.to_str().unwrap(), FileFormat::NdJson)
+        .await
+        .unwrap();
+    let err = validator
+        .validate(
+            &users_contract(&path, DataFormat::Json),
+            &ValidationContext::new(),
+        )
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, FilesError::ParseError { .. }));
+    assert!(err.to_string().ends_with("users.jsonl, line 2"), "{err}");
+}
+
+#[tokio::test]
+async fn test_missing_location_is_not_found() {
+    let dir = TempDir::new().unwrap();
+    let missing = dir.path().join("missing");
+
+    let err = FileValidator::new(missing.to_str().unwrap(), FileFormat::Csv)
+        .await
+        .err()
+        .unwrap();
+    assert!(matches!(err, FilesError::NotFound(_)));
+}
@@ -46,6 +46,25 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("✓ Configuration with options created!");
     println!("  Includes catalog ID and region settings\n");
 
+    // Cross-account access: assume a role in the account that owns the catalog
+    let _cross_account = IcebergConfig::builder()
+        .glue_catalog_with_options(
+            "s3://other-account-bucket/warehouse",
+            Some("210987654321".to_string()),
+            Some("us-west-2".to_string()),
+        )
+        .glue_assume_role(
+            "arn:aws:iam::210987654321:role/dce-reader",
+            Some("dce-external-id"),
+        )
+        .glue_session_tag("team", "data-platform")
+        .namespace(vec!["my_database".to_string()])
+        .table_name("user_events")
+        .build()?;
+
+    println!("✓ Cross-account configuration created!");
+    println!("  Assumes a role in the catalog owner's account\n");
+
     // With custom properties
     let config_with_props = IcebergConfig::builder()
         .glue_catalog("s3://my-bucket/warehouse")
@@ -0,0 +1,121 @@
+//! Column access metadata kept with an Iceberg table.
+//!
+//! Iceberg has no column tags of its own, so access metadata lives in table
+//! properties, two per column:
+//!
+//! ```text
+//! dce.access.email.classification = pii
+//! dce.access.email.allowed-roles  = analytics,support
+//! ```
+//!
+//! [`access_properties`] renders the `access:` blocks of a contract in this
+//! form, and jobs that sync column tags from Glue/Lake Formation or Unity
+//! Catalog can write the same keys, so
+//! [`IcebergValidator::detect_access_drift`](crate::IcebergValidator::detect_access_drift)
+//! can compare declared and actual access.
+
+use contracts_core::{FieldAccess, Schema, declared_access};
+use std::collections::{BTreeMap, HashMap};
+
+/// Prefix of the table properties holding column access metadata.
+pub const ACCESS_PROPERTY_PREFIX: &str = "dce.access.";
+
+const CLASSIFICATION: &str = "classification";
+const ALLOWED_ROLES: &str = "allowed-roles";
+
+/// Reads the column access metadata in table `properties`, by column name.
+///
+/// Roles are separated by commas. Properties under the prefix with another
+/// attribute are ignored.
+pub fn column_access(properties: &HashMap<String, String>) -> BTreeMap<String, FieldAccess> {
+    let mut access: BTreeMap<String, FieldAccess> = BTreeMap::new();
+    for (key, value) in properties {
+        // Column names may contain dots, the attribute never does
+        let Some((column, attribute)) = key
+            .strip_prefix(ACCESS_PROPERTY_PREFIX)
+            .and_then(|rest| rest.rsplit_once('.'))
+        else {
+            continue;
+        };
+        match attribute {
+            CLASSIFICATION => {
+                let value = value.trim();
+                if !value.is_empty() {
+                    access.entry(column.to_string()).or_default().classification =
+                        Some(value.to_string());
+                }
+            }
+            ALLOWED_ROLES => {
+                access.entry(column.to_string()).or_default().allowed_roles = value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|role| !role.is_empty())
+                    .map(str::to_string)
+                    .collect();
+            }
+            _ => {}
+        }
+    }
+    access.retain(|_, access| !access.is_empty());
+    access
+}
+
+/// Returns the table properties carrying the access metadata declared by
+/// the fields of `schema`.
+pub fn access_properties(schema: &Schema) -> BTreeMap<String, String> {
+    let mut properties = BTreeMap::new();
+    for (column, access) in declared_access(schema) {
+        if let Some(classification) = access.classification {
+            properties.insert(
+                format!("{}{}.{}", ACCESS_PROPERTY_PREFIX, column, CLASSIFICATION),
+                classification,
+            );
+        }
+        if !access.allowed_roles.is_empty() {
+            properties.insert(
+                format!("{}{}.{}", ACCESS_PROPERTY_PREFIX, column, ALLOWED_ROLES),
+                access.allowed_roles.join(","),
+            );
+        }
+    }
+    properties
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use contracts_core::{ContractBuilder, DataFormat, FieldBuilder};
+
+    #[test]
+    fn test_access_properties_round_trip() {
+        let contract = ContractBuilder::new("users", "identity")
+            .location("s3://lake/users")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("id", "int64").build())
+            .field(
+                FieldBuilder::new("contact.email", "string")
+                    .access(FieldAccess {
+                        classification: Some("pii".to_string()),
+                        allowed_roles: vec!["support".to_string(), "analytics".to_string()],
+                    })
+                    .build(),
+            )
+            .build();
+
+        let properties = access_properties(&contract.schema);
+        assert_eq!(
+            properties.get("dce.access.contact.email.allowed-roles"),
+            Some(&"support,analytics".to_string())
+        );
+        assert_eq!(properties.len(), 2);
+
+        let mut table_properties: HashMap<String, String> = properties.into_iter().collect();
+        table_properties.insert("write.format.default".to_string(), "parquet".to_string());
+        table_properties.insert("dce.access.id.owner".to_string(), "nobody".to_string());
+        table_properties.insert("dce.access.id.allowed-roles".to_string(), " ,".to_string());
+        assert_eq!(
+            column_access(&table_properties),
+            declared_access(&contract.schema)
+        );
+    }
+}
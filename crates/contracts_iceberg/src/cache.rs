@@ -0,0 +1,175 @@
+//! Sharing of catalog clients across validations.
+
+use crate::{
+    IcebergError, RequestLimiter, RequestLimits,
+    catalog::load_catalog,
+    config::{CatalogType, IcebergConfig},
+};
+use iceberg::Catalog;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tracing::debug;
+
+/// Properties that identify a table rather than the catalog connection.
+const TABLE_PROPERTIES: &[&str] = &["metadata_location"];
+
+/// A cache of catalog clients keyed by connection settings.
+///
+/// Validating many contracts usually touches only a handful of catalogs.
+/// Passing the same cache to [`IcebergValidator::with_cache`](crate::IcebergValidator::with_cache)
+/// connects once per distinct catalog type, URI, warehouse and property set,
+/// and reuses the client for every table in that catalog.
+///
+/// The cache also carries the [`RequestLimiter`] those validators share, so
+/// limits set with [`with_limits`](Self::with_limits) hold across all tables.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use contracts_iceberg::{CatalogCache, IcebergConfig, IcebergValidator};
+///
+/// # async fn example() -> Result<(), contracts_iceberg::IcebergError> {
+/// let cache = CatalogCache::new();
+/// for table in ["orders", "customers"] {
+///     let config = IcebergConfig::builder()
+///         .rest_catalog("http://localhost:8181", "s3://warehouse")
+///         .namespace(vec!["sales".to_string()])
+///         .table_name(table)
+///         .build()?;
+///     let validator = IcebergValidator::with_cache(config, &cache).await?;
+/// }
+/// assert_eq!(cache.len().await, 1);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct CatalogCache {
+    catalogs: Mutex<HashMap<String, Arc<dyn Catalog>>>,
+    limiter: RequestLimiter,
+}
+
+impl CatalogCache {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty cache whose validators share `limits`.
+    pub fn with_limits(limits: RequestLimits) -> Self {
+        Self {
+            catalogs: Mutex::default(),
+            limiter: RequestLimiter::new(limits),
+        }
+    }
+
+    /// Returns the limiter shared by validators created from this cache.
+    pub fn limiter(&self) -> &RequestLimiter {
+        &self.limiter
+    }
+
+    /// Returns the catalog client for `config`, connecting on first use.
+    ///
+    /// Returns `None` for [`CatalogType::FileIO`], which has no catalog client.
+    pub async fn get_or_load(
+        &self,
+        config: &IcebergConfig,
+    ) -> Result<Option<Arc<dyn Catalog>>, IcebergError> {
+        if config.catalog == CatalogType::FileIO {
+            return Ok(None);
+        }
+
+        let key = connection_key(config)?;
+
+        // The lock is held while connecting so concurrent callers for the
+        // same catalog wait for the first connection instead of opening more.
+        let mut catalogs = self.catalogs.lock().await;
+        if let Some(catalog) = catalogs.get(&key) {
+            debug!("Reusing cached catalog client: {:?}", config.catalog);
+            return Ok(Some(Arc::clone(catalog)));
+        }
+
+        let _permit = self.limiter.acquire().await;
+        let catalog: Arc<dyn Catalog> = Arc::from(load_catalog(config).await?);
+        catalogs.insert(key, Arc::clone(&catalog));
+        Ok(Some(catalog))
+    }
+
+    /// Returns the number of distinct catalogs connected so far.
+    pub async fn len(&self) -> usize {
+        self.catalogs.lock().await.len()
+    }
+
+    /// Returns true if no catalog has been connected yet.
+    pub async fn is_empty(&self) -> bool {
+        self.catalogs.lock().await.is_empty()
+    }
+}
+
+/// Builds the cache key for the connection part of `config`.
+fn connection_key(config: &IcebergConfig) -> Result<String, IcebergError> {
+    let catalog = serde_json::to_string(&config.catalog)
+        .map_err(|e| IcebergError::Other(format!("Failed to serialize catalog config: {}", e)))?;
+
+    let mut properties: Vec<(&String, &String)> = config
+        .properties
+        .iter()
+        .filter(|(k, _)| !TABLE_PROPERTIES.contains(&k.as_str()))
+        .collect();
+    properties.sort();
+
+    Ok(format!("{}{:?}", catalog, properties))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(uri: &str, table: &str) -> IcebergConfig {
+        IcebergConfig::builder()
+            .rest_catalog(uri, "s3://warehouse")
+            .namespace(vec!["db".to_string()])
+            .table_name(table)
+            .property("metadata_location", format!("/tmp/{}.json", table))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_connection_key_ignores_table() {
+        let a = connection_key(&config("http://a:8181", "orders")).unwrap();
+        let b = connection_key(&config("http://a:8181", "customers")).unwrap();
+        let c = connection_key(&config("http://b:8181", "orders")).unwrap();
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_connection_key_includes_properties() {
+        let plain = config("http://a:8181", "orders");
+        let mut with_token = plain.clone();
+        with_token
+            .properties
+            .insert("token".to_string(), "secret".to_string());
+
+        assert_ne!(
+            connection_key(&plain).unwrap(),
+            connection_key(&with_token).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_file_io_is_not_cached() {
+        let cache = CatalogCache::new();
+        let config = IcebergConfig::builder()
+            .file_io()
+            .namespace(vec!["db".to_string()])
+            .table_name("t")
+            .build()
+            .unwrap();
+
+        assert!(cache.get_or_load(&config).await.unwrap().is_none());
+        assert!(cache.is_empty().await);
+    }
+}
@@ -4,11 +4,13 @@ use crate::{
     IcebergError,
     config::{CatalogType, IcebergConfig},
 };
-use iceberg::io::FileIO;
+use iceberg::io::{FileIO, FileIOBuilder, LocalFsStorageFactory, StorageFactory};
 use iceberg::{Catalog, CatalogBuilder, TableIdent};
 
 #[cfg(feature = "glue-catalog")]
-use iceberg_catalog_glue::{GLUE_CATALOG_PROP_WAREHOUSE, GlueCatalogBuilder};
+use iceberg_catalog_glue::{
+    AWS_ACCESS_KEY_ID, AWS_SECRET_ACCESS_KEY, AWS_SESSION_TOKEN, GlueCatalogBuilder,
+};
 
 #[cfg(feature = "hms-catalog")]
 use iceberg_catalog_hms::{HMS_CATALOG_PROP_URI, HMS_CATALOG_PROP_WAREHOUSE, HmsCatalogBuilder};
@@ -18,9 +20,31 @@ use iceberg_catalog_rest::{
     REST_CATALOG_PROP_URI, REST_CATALOG_PROP_WAREHOUSE, RestCatalogBuilder,
 };
 
+use iceberg_storage_opendal::OpenDalStorageFactory;
 use std::collections::HashMap;
+use std::sync::Arc;
 use tracing::{debug, info};
 
+/// Returns the catalog types this build can load tables from, as named by
+/// `connection.type`.
+///
+/// `fileio` is always available; the others depend on the `rest-catalog`,
+/// `glue-catalog` and `hms-catalog` features.
+pub fn supported_catalogs() -> Vec<&'static str> {
+    let mut catalogs = Vec::new();
+    if cfg!(feature = "rest-catalog") {
+        catalogs.push("rest");
+    }
+    if cfg!(feature = "glue-catalog") {
+        catalogs.push("glue");
+    }
+    if cfg!(feature = "hms-catalog") {
+        catalogs.push("hms");
+    }
+    catalogs.push("fileio");
+    catalogs
+}
+
 /// Loads an Iceberg catalog based on the provided configuration.
 ///
 /// Supports REST, Glue, HMS, and direct FileIO catalogs.
@@ -38,19 +62,7 @@ pub async fn load_catalog(config: &IcebergConfig) -> Result<Box<dyn Catalog>, Ic
             "REST catalog support not enabled. Enable the 'rest-catalog' feature.".to_string(),
         )),
         #[cfg(feature = "glue-catalog")]
-        CatalogType::Glue {
-            warehouse,
-            catalog_id,
-            region,
-        } => {
-            load_glue_catalog(
-                warehouse,
-                catalog_id.as_deref(),
-                region.as_deref(),
-                &config.properties,
-            )
-            .await
-        }
+        CatalogType::Glue { .. } => load_glue_catalog(&config.catalog, &config.properties).await,
         #[cfg(not(feature = "glue-catalog"))]
         CatalogType::Glue { .. } => Err(IcebergError::UnsupportedOperation(
             "Glue catalog support not enabled. Enable the 'glue-catalog' feature.".to_string(),
@@ -109,42 +121,54 @@ async fn load_rest_catalog(
         props.insert(key.clone(), value.clone());
     }
 
-    debug!("REST catalog properties: {:?}", props);
+    debug!("REST catalog properties: {:?}", property_keys(&props));
+
+    // REST warehouses are often catalog names rather than URIs; those are
+    // backed by object storage, so default to S3
+    let scheme = location_scheme(warehouse).unwrap_or("s3");
 
     let catalog = RestCatalogBuilder::default()
+        .with_storage_factory(storage_factory(scheme)?)
         .load("rest", props)
         .await
-        .map_err(|e| {
-            IcebergError::ConnectionError(format!("Failed to load REST catalog: {}", e))
-        })?;
+        .map_err(|e| IcebergError::connection("Failed to load REST catalog", e))?;
 
     Ok(Box::new(catalog))
 }
 
-/// Loads an AWS Glue catalog.
+/// Session name used when assuming a role for a Glue catalog.
 #[cfg(feature = "glue-catalog")]
-async fn load_glue_catalog(
-    warehouse: &str,
-    catalog_id: Option<&str>,
-    region: Option<&str>,
-    properties: &HashMap<String, String>,
-) -> Result<Box<dyn Catalog>, IcebergError> {
-    info!("Loading AWS Glue catalog");
+const GLUE_ASSUME_ROLE_SESSION_NAME: &str = "dce-validation";
 
+/// Builds the properties for loading a Glue catalog.
+///
+/// Keys match the constants of `iceberg_catalog_glue`, which is only available
+/// with the `glue-catalog` feature. Explicit `properties` take precedence.
+#[cfg_attr(not(feature = "glue-catalog"), allow(dead_code))]
+fn glue_catalog_properties(
+    catalog: &CatalogType,
+    properties: &HashMap<String, String>,
+) -> HashMap<String, String> {
     let mut props = HashMap::new();
-    props.insert(
-        GLUE_CATALOG_PROP_WAREHOUSE.to_string(),
-        warehouse.to_string(),
-    );
 
-    // Add optional catalog ID
-    if let Some(id) = catalog_id {
-        props.insert("glue.catalog-id".to_string(), id.to_string());
-    }
+    if let CatalogType::Glue {
+        warehouse,
+        catalog_id,
+        region,
+        ..
+    } = catalog
+    {
+        props.insert("warehouse".to_string(), warehouse.clone());
+
+        // Add optional catalog ID (the owning account for cross-account access)
+        if let Some(id) = catalog_id {
+            props.insert("catalog_id".to_string(), id.clone());
+        }
 
-    // Add optional region
-    if let Some(r) = region {
-        props.insert("aws.region".to_string(), r.to_string());
+        // Add optional region
+        if let Some(r) = region {
+            props.insert("region_name".to_string(), r.clone());
+        }
     }
 
     // Merge additional properties
@@ -152,18 +176,92 @@ async fn load_glue_catalog(
         props.insert(key.clone(), value.clone());
     }
 
-    debug!("Glue catalog properties: {:?}", props);
+    props
+}
+
+/// Loads an AWS Glue catalog.
+///
+/// When a role is configured, it is assumed through STS first and the
+/// temporary credentials are used for both the Glue API and table storage.
+#[cfg(feature = "glue-catalog")]
+async fn load_glue_catalog(
+    catalog: &CatalogType,
+    properties: &HashMap<String, String>,
+) -> Result<Box<dyn Catalog>, IcebergError> {
+    info!("Loading AWS Glue catalog");
+
+    let mut props = glue_catalog_properties(catalog, properties);
+
+    if let CatalogType::Glue {
+        role_arn: Some(role_arn),
+        external_id,
+        session_tags,
+        region,
+        ..
+    } = catalog
+    {
+        let credentials = assume_glue_role(
+            role_arn,
+            external_id.as_deref(),
+            session_tags,
+            region.as_deref(),
+        )
+        .await?;
+        props.insert(
+            AWS_ACCESS_KEY_ID.to_string(),
+            credentials.access_key_id().to_string(),
+        );
+        props.insert(
+            AWS_SECRET_ACCESS_KEY.to_string(),
+            credentials.secret_access_key().to_string(),
+        );
+        if let Some(token) = credentials.session_token() {
+            props.insert(AWS_SESSION_TOKEN.to_string(), token.to_string());
+        }
+    }
+
+    debug!("Glue catalog properties: {:?}", property_keys(&props));
 
     let catalog = GlueCatalogBuilder::default()
         .load("glue", props)
         .await
-        .map_err(|e| {
-            IcebergError::ConnectionError(format!("Failed to load Glue catalog: {}", e))
-        })?;
+        .map_err(|e| IcebergError::connection("Failed to load Glue catalog", e))?;
 
     Ok(Box::new(catalog))
 }
 
+/// Assumes `role_arn` with the default credential chain as the source identity.
+#[cfg(feature = "glue-catalog")]
+async fn assume_glue_role(
+    role_arn: &str,
+    external_id: Option<&str>,
+    session_tags: &std::collections::BTreeMap<String, String>,
+    region: Option<&str>,
+) -> Result<aws_credential_types::Credentials, IcebergError> {
+    use aws_credential_types::provider::ProvideCredentials;
+
+    info!("Assuming role {} for Glue catalog access", role_arn);
+
+    let mut builder = aws_config::sts::AssumeRoleProvider::builder(role_arn)
+        .session_name(GLUE_ASSUME_ROLE_SESSION_NAME);
+    if let Some(id) = external_id {
+        builder = builder.external_id(id);
+    }
+    if !session_tags.is_empty() {
+        builder = builder.tags(session_tags.iter().map(|(k, v)| (k.as_str(), v.as_str())));
+    }
+    if let Some(region) = region {
+        builder = builder.region(aws_config::Region::new(region.to_string()));
+    }
+
+    builder
+        .build()
+        .await
+        .provide_credentials()
+        .await
+        .map_err(|e| IcebergError::connection(format!("Failed to assume role {}", role_arn), e))
+}
+
 /// Loads a Hive Metastore catalog.
 #[cfg(feature = "hms-catalog")]
 async fn load_hms_catalog(
@@ -185,16 +283,28 @@ async fn load_hms_catalog(
         props.insert(key.clone(), value.clone());
     }
 
-    debug!("HMS catalog properties: {:?}", props);
+    debug!("HMS catalog properties: {:?}", property_keys(&props));
 
     let catalog = HmsCatalogBuilder::default()
         .load("hms", props)
         .await
-        .map_err(|e| IcebergError::ConnectionError(format!("Failed to load HMS catalog: {}", e)))?;
+        .map_err(|e| IcebergError::connection("Failed to load HMS catalog", e))?;
 
     Ok(Box::new(catalog))
 }
 
+/// Returns the sorted property keys, for logging without leaking credentials.
+#[cfg(any(
+    feature = "rest-catalog",
+    feature = "glue-catalog",
+    feature = "hms-catalog"
+))]
+fn property_keys(props: &HashMap<String, String>) -> Vec<&str> {
+    let mut keys: Vec<&str> = props.keys().map(String::as_str).collect();
+    keys.sort_unstable();
+    keys
+}
+
 /// Creates a TableIdent from namespace and table name.
 pub fn create_table_ident(
     namespace: &[String],
@@ -207,26 +317,53 @@ pub fn create_table_ident(
         .map_err(|e| IcebergError::ConfigurationError(format!("Invalid table identifier: {}", e)))
 }
 
-/// Builds a FileIO instance based on the warehouse location scheme.
-pub fn build_file_io(warehouse: Option<&str>) -> Result<FileIO, IcebergError> {
-    let scheme = warehouse
-        .and_then(|w| w.split("://").next())
-        .unwrap_or("file");
+/// Builds a FileIO instance for files under `location`.
+///
+/// Local paths use the local filesystem. S3 locations (`s3://`, `s3a://`,
+/// `s3n://`) honour the `s3.*` properties, so S3-compatible stores such as
+/// MinIO or Ceph can be reached through `s3.endpoint` and
+/// `s3.path-style-access`.
+pub fn build_file_io(
+    location: Option<&str>,
+    properties: &HashMap<String, String>,
+) -> Result<FileIO, IcebergError> {
+    let scheme = location.and_then(location_scheme).unwrap_or("file");
 
     info!("Building FileIO for scheme: {}", scheme);
 
+    Ok(FileIOBuilder::new(storage_factory(scheme)?)
+        .with_props(properties.clone())
+        .build())
+}
+
+/// Returns the storage factory for a location scheme.
+fn storage_factory(scheme: &str) -> Result<Arc<dyn StorageFactory>, IcebergError> {
     match scheme {
-        "file" => Ok(FileIO::new_with_fs()),
+        "file" => Ok(Arc::new(LocalFsStorageFactory)),
+        "s3" | "s3a" | "s3n" => Ok(Arc::new(OpenDalStorageFactory::S3 {
+            configured_scheme: scheme.to_string(),
+            customized_credential_load: None,
+        })),
         _ => Err(IcebergError::UnsupportedOperation(format!(
-            "Unsupported FileIO scheme '{}'. For S3/GCS/Azure, use a catalog-based approach instead.",
+            "Unsupported storage scheme '{}'. Supported schemes: file, s3, s3a, s3n",
             scheme
         ))),
     }
 }
 
+/// Returns the URI scheme of `location`, or `file` for absolute local paths.
+fn location_scheme(location: &str) -> Option<&str> {
+    match location.split_once("://") {
+        Some((scheme, _)) => Some(scheme),
+        None if location.starts_with('/') => Some("file"),
+        None => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use iceberg::io::{S3_ENDPOINT, S3_PATH_STYLE_ACCESS, S3_REGION};
 
     #[test]
     fn test_create_table_ident() {
@@ -246,22 +383,79 @@ mod tests {
         assert_eq!(ident.to_string(), "db.users");
     }
 
+    #[test]
+    fn test_glue_catalog_properties() {
+        let catalog = CatalogType::Glue {
+            warehouse: "s3://bucket/warehouse".to_string(),
+            catalog_id: Some("123456789012".to_string()),
+            region: Some("eu-west-1".to_string()),
+            role_arn: Some("arn:aws:iam::123456789012:role/dce-reader".to_string()),
+            external_id: None,
+            session_tags: Default::default(),
+        };
+        let overrides = HashMap::from([("region_name".to_string(), "us-east-1".to_string())]);
+
+        let props = glue_catalog_properties(&catalog, &overrides);
+
+        assert_eq!(
+            props.get("catalog_id").map(String::as_str),
+            Some("123456789012")
+        );
+        assert_eq!(
+            props.get("region_name").map(String::as_str),
+            Some("us-east-1")
+        );
+        assert_eq!(
+            props.get("warehouse").map(String::as_str),
+            Some("s3://bucket/warehouse")
+        );
+    }
+
     #[test]
     fn test_build_file_io_s3() {
-        let result = build_file_io(Some("s3://bucket/path"));
-        // This may fail without proper AWS config, but tests the structure
-        assert!(result.is_ok() || result.is_err());
+        let result = build_file_io(Some("s3://bucket/path"), &HashMap::new());
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_build_file_io_s3_compatible_endpoint() {
+        let props = HashMap::from([
+            (S3_ENDPOINT.to_string(), "http://localhost:9000".to_string()),
+            (S3_REGION.to_string(), "us-east-1".to_string()),
+            (S3_PATH_STYLE_ACCESS.to_string(), "true".to_string()),
+        ]);
+        let file_io = build_file_io(Some("s3a://warehouse/db/table"), &props).unwrap();
+
+        // Building the input is enough to exercise the S3 configuration
+        assert!(
+            file_io
+                .new_input("s3a://warehouse/db/table/metadata/v1.json")
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_build_file_io_unsupported_scheme() {
+        let result = build_file_io(Some("ftp://host/warehouse"), &HashMap::new());
+        assert!(matches!(result, Err(IcebergError::UnsupportedOperation(_))));
+    }
+
+    #[test]
+    fn test_location_scheme() {
+        assert_eq!(location_scheme("s3://bucket/wh"), Some("s3"));
+        assert_eq!(location_scheme("/tmp/warehouse"), Some("file"));
+        assert_eq!(location_scheme("demo"), None);
     }
 
     #[test]
     fn test_build_file_io_file() {
-        let result = build_file_io(Some("file:///tmp/warehouse"));
+        let result = build_file_io(Some("file:///tmp/warehouse"), &HashMap::new());
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_build_file_io_default() {
-        let result = build_file_io(None);
+        let result = build_file_io(None, &HashMap::new());
         assert!(result.is_ok());
     }
 }
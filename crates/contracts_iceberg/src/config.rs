@@ -1,8 +1,14 @@
 //! Configuration for Iceberg connections.
 
-use crate::IcebergError;
+use crate::scan::{SCAN_BATCH_SIZE, SCAN_PREFETCH_DEPTH, SCAN_TARGET_SPLIT_SIZE};
+use crate::{IcebergError, ScanOptions, SnapshotSelector, TableReference, resolve_secret_refs};
+use chrono::{DateTime, Utc};
+use contracts_core::ConnectionConfig;
+use iceberg::io::{
+    S3_ACCESS_KEY_ID, S3_ENDPOINT, S3_PATH_STYLE_ACCESS, S3_REGION, S3_SECRET_ACCESS_KEY,
+};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Type of Iceberg catalog to use.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -27,6 +33,15 @@ pub enum CatalogType {
         catalog_id: Option<String>,
         /// Optional AWS region
         region: Option<String>,
+        /// IAM role to assume, for catalogs owned by another account
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        role_arn: Option<String>,
+        /// External ID required by the role's trust policy
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        external_id: Option<String>,
+        /// Session tags attached to the assumed-role session
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        session_tags: BTreeMap<String, String>,
     },
 
     /// Hive Metastore catalog
@@ -54,6 +69,10 @@ pub struct IcebergConfig {
 
     /// Additional properties for catalog configuration
     pub properties: HashMap<String, String>,
+
+    /// Snapshot to validate instead of the current one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snapshot: Option<SnapshotSelector>,
 }
 
 impl IcebergConfig {
@@ -76,9 +95,83 @@ impl IcebergConfig {
             ));
         }
 
+        self.scan_options()?;
+
         Ok(())
     }
 
+    /// Returns the scan tuning options set in the `scan.*` properties.
+    pub fn scan_options(&self) -> Result<ScanOptions, IcebergError> {
+        ScanOptions::from_properties(&self.properties)
+    }
+
+    /// Builds a configuration from a contract `connection:` block.
+    ///
+    /// Secret references in the URI, warehouse and property values are
+    /// resolved (see [`resolve_secret_refs`]). The catalog type defaults to
+    /// `rest`; when no warehouse is configured, the catalog name from `table`
+    /// is used as the warehouse identifier.
+    pub fn from_connection(
+        connection: &ConnectionConfig,
+        table: &TableReference,
+    ) -> Result<Self, IcebergError> {
+        let resolve =
+            |value: &Option<String>| value.as_deref().map(resolve_secret_refs).transpose();
+
+        let uri = resolve(&connection.uri)?;
+        let warehouse = resolve(&connection.warehouse)?.or_else(|| table.catalog.clone());
+        let properties = connection
+            .properties
+            .iter()
+            .map(|(k, v)| Ok((k.clone(), resolve_secret_refs(v)?)))
+            .collect::<Result<HashMap<_, _>, IcebergError>>()?;
+
+        let missing = |what: &str, kind: &str| {
+            IcebergError::ConfigurationError(format!(
+                "connection.{} is required for {} catalogs",
+                what, kind
+            ))
+        };
+
+        let kind = connection
+            .catalog_type
+            .as_deref()
+            .unwrap_or(ConnectionConfig::DEFAULT_CATALOG_TYPE)
+            .to_lowercase();
+        let catalog = match kind.as_str() {
+            "rest" => CatalogType::Rest {
+                uri: uri.ok_or_else(|| missing("uri", "rest"))?,
+                warehouse: warehouse.ok_or_else(|| missing("warehouse", "rest"))?,
+            },
+            "glue" => CatalogType::Glue {
+                warehouse: warehouse.ok_or_else(|| missing("warehouse", "glue"))?,
+                catalog_id: None,
+                region: None,
+                role_arn: None,
+                external_id: None,
+                session_tags: BTreeMap::new(),
+            },
+            "hms" => CatalogType::Hms {
+                uri: uri.ok_or_else(|| missing("uri", "hms"))?,
+                warehouse: warehouse.ok_or_else(|| missing("warehouse", "hms"))?,
+            },
+            "fileio" => CatalogType::FileIO,
+            other => {
+                return Err(IcebergError::ConfigurationError(format!(
+                    "Unsupported catalog type '{}'. Supported types: rest, glue, hms, fileio",
+                    other
+                )));
+            }
+        };
+
+        IcebergConfig::builder()
+            .catalog(catalog)
+            .namespace(table.namespace.clone())
+            .table_name(table.table.clone())
+            .properties(properties)
+            .build()
+    }
+
     /// Returns the warehouse location from the catalog configuration.
     pub fn warehouse(&self) -> Option<&str> {
         match &self.catalog {
@@ -97,6 +190,10 @@ pub struct IcebergConfigBuilder {
     namespace: Option<Vec<String>>,
     table_name: Option<String>,
     properties: HashMap<String, String>,
+    snapshot: Option<SnapshotSelector>,
+    role_arn: Option<String>,
+    external_id: Option<String>,
+    session_tags: BTreeMap<String, String>,
 }
 
 impl IcebergConfigBuilder {
@@ -124,6 +221,9 @@ impl IcebergConfigBuilder {
             warehouse: warehouse.into(),
             catalog_id: None,
             region: None,
+            role_arn: None,
+            external_id: None,
+            session_tags: BTreeMap::new(),
         });
         self
     }
@@ -140,10 +240,35 @@ impl IcebergConfigBuilder {
             warehouse: warehouse.into(),
             catalog_id,
             region,
+            role_arn: None,
+            external_id: None,
+            session_tags: BTreeMap::new(),
         });
         self
     }
 
+    /// Assumes an IAM role when connecting to a Glue catalog.
+    ///
+    /// Used to validate tables in a catalog owned by another AWS account.
+    /// `external_id` is required when the role's trust policy demands one.
+    #[must_use]
+    pub fn glue_assume_role<S: Into<String>>(
+        mut self,
+        role_arn: S,
+        external_id: Option<S>,
+    ) -> Self {
+        self.role_arn = Some(role_arn.into());
+        self.external_id = external_id.map(Into::into);
+        self
+    }
+
+    /// Adds a session tag to the assumed-role session of a Glue catalog.
+    #[must_use]
+    pub fn glue_session_tag<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.session_tags.insert(key.into(), value.into());
+        self
+    }
+
     /// Sets the catalog type to Hive Metastore.
     #[must_use]
     pub fn hms_catalog<S: Into<String>>(mut self, uri: S, warehouse: S) -> Self {
@@ -175,6 +300,61 @@ impl IcebergConfigBuilder {
         self
     }
 
+    /// Sets the S3 endpoint, for S3-compatible stores such as MinIO or Ceph.
+    ///
+    /// Sets the `s3.endpoint` property (e.g. `http://localhost:9000`).
+    #[must_use]
+    pub fn s3_endpoint<S: Into<String>>(self, endpoint: S) -> Self {
+        self.property(S3_ENDPOINT, endpoint)
+    }
+
+    /// Sets the S3 region (`s3.region` property).
+    ///
+    /// S3-compatible stores usually accept any region, but one must be set
+    /// when no AWS configuration is available.
+    #[must_use]
+    pub fn s3_region<S: Into<String>>(self, region: S) -> Self {
+        self.property(S3_REGION, region)
+    }
+
+    /// Enables or disables path-style S3 access (`s3.path-style-access` property).
+    ///
+    /// Most S3-compatible stores require path-style access
+    /// (`http://host/bucket/key` rather than `http://bucket.host/key`).
+    #[must_use]
+    pub fn s3_path_style_access(self, enabled: bool) -> Self {
+        self.property(S3_PATH_STYLE_ACCESS, enabled.to_string())
+    }
+
+    /// Sets static S3 credentials (`s3.access-key-id` / `s3.secret-access-key`).
+    #[must_use]
+    pub fn s3_credentials<S: Into<String>>(self, access_key_id: S, secret_access_key: S) -> Self {
+        self.property(S3_ACCESS_KEY_ID, access_key_id)
+            .property(S3_SECRET_ACCESS_KEY, secret_access_key)
+    }
+
+    /// Sets the number of rows per record batch (`scan.batch-size` property).
+    #[must_use]
+    pub fn scan_batch_size(self, rows: usize) -> Self {
+        self.property(SCAN_BATCH_SIZE, rows.to_string())
+    }
+
+    /// Splits data files larger than `bytes` into parts of about that size
+    /// that are read concurrently (`scan.target-split-size` property).
+    #[must_use]
+    pub fn scan_target_split_size(self, bytes: u64) -> Self {
+        self.property(SCAN_TARGET_SPLIT_SIZE, bytes.to_string())
+    }
+
+    /// Sets the number of data files read ahead concurrently
+    /// (`scan.prefetch-depth` property).
+    ///
+    /// Object stores benefit from a deeper prefetch than local files.
+    #[must_use]
+    pub fn scan_prefetch_depth(self, files: usize) -> Self {
+        self.property(SCAN_PREFETCH_DEPTH, files.to_string())
+    }
+
     /// Adds a property to the configuration.
     #[must_use]
     pub fn property<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
@@ -189,14 +369,55 @@ impl IcebergConfigBuilder {
         self
     }
 
+    /// Pins validation to the snapshot with ID `snapshot_id`.
+    #[must_use]
+    pub fn snapshot_id(mut self, snapshot_id: i64) -> Self {
+        self.snapshot = Some(SnapshotSelector::Id(snapshot_id));
+        self
+    }
+
+    /// Pins validation to the snapshot that was current on `main` at `time`.
+    #[must_use]
+    pub fn snapshot_as_of(mut self, time: DateTime<Utc>) -> Self {
+        self.snapshot = Some(SnapshotSelector::AsOf(time));
+        self
+    }
+
     /// Builds the `IcebergConfig`.
     ///
     /// Returns an error if required fields are missing.
     pub fn build(self) -> Result<IcebergConfig, IcebergError> {
+        let mut catalog = self.catalog.ok_or_else(|| {
+            IcebergError::ConfigurationError("catalog type is required".to_string())
+        })?;
+
+        let assumes_role = self.role_arn.is_some() || !self.session_tags.is_empty();
+        match &mut catalog {
+            CatalogType::Glue {
+                role_arn,
+                external_id,
+                session_tags,
+                ..
+            } if assumes_role => {
+                if self.role_arn.is_none() {
+                    return Err(IcebergError::ConfigurationError(
+                        "Glue session tags require a role to assume".to_string(),
+                    ));
+                }
+                *role_arn = self.role_arn;
+                *external_id = self.external_id;
+                session_tags.extend(self.session_tags);
+            }
+            _ if assumes_role => {
+                return Err(IcebergError::ConfigurationError(
+                    "assume-role options are only supported for Glue catalogs".to_string(),
+                ));
+            }
+            _ => {}
+        }
+
         let config = IcebergConfig {
-            catalog: self.catalog.ok_or_else(|| {
-                IcebergError::ConfigurationError("catalog type is required".to_string())
-            })?,
+            catalog,
             namespace: self.namespace.ok_or_else(|| {
                 IcebergError::ConfigurationError("namespace is required".to_string())
             })?,
@@ -204,6 +425,7 @@ impl IcebergConfigBuilder {
                 IcebergError::ConfigurationError("table_name is required".to_string())
             })?,
             properties: self.properties,
+            snapshot: self.snapshot,
         };
 
         config.validate()?;
@@ -252,6 +474,108 @@ mod tests {
         assert_eq!(config.warehouse(), Some("s3://bucket/warehouse"));
     }
 
+    #[test]
+    fn test_config_builder_glue_assume_role() {
+        let config = IcebergConfig::builder()
+            .glue_catalog("s3://bucket/warehouse")
+            .glue_assume_role(
+                "arn:aws:iam::123456789012:role/dce-reader",
+                Some("dce-external-id"),
+            )
+            .glue_session_tag("team", "data-platform")
+            .namespace(vec!["database".to_string()])
+            .table_name("events")
+            .build()
+            .unwrap();
+
+        match config.catalog {
+            CatalogType::Glue {
+                role_arn,
+                external_id,
+                session_tags,
+                ..
+            } => {
+                assert_eq!(
+                    role_arn.as_deref(),
+                    Some("arn:aws:iam::123456789012:role/dce-reader")
+                );
+                assert_eq!(external_id.as_deref(), Some("dce-external-id"));
+                assert_eq!(
+                    session_tags.get("team").map(String::as_str),
+                    Some("data-platform")
+                );
+            }
+            other => panic!("expected Glue catalog, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_config_builder_assume_role_requires_glue() {
+        let result = IcebergConfig::builder()
+            .rest_catalog("http://localhost:8181", "s3://warehouse")
+            .glue_assume_role("arn:aws:iam::123456789012:role/dce-reader", None)
+            .namespace(vec!["db".to_string()])
+            .table_name("events")
+            .build();
+        assert!(result.is_err());
+
+        let result = IcebergConfig::builder()
+            .glue_catalog("s3://warehouse")
+            .glue_session_tag("team", "data-platform")
+            .namespace(vec!["db".to_string()])
+            .table_name("events")
+            .build();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_config_builder_s3_compatible_endpoint() {
+        let config = IcebergConfig::builder()
+            .rest_catalog("http://localhost:8181", "s3://warehouse")
+            .s3_endpoint("http://localhost:9000")
+            .s3_region("us-east-1")
+            .s3_path_style_access(true)
+            .s3_credentials("minioadmin", "minioadmin")
+            .namespace(vec!["db".to_string()])
+            .table_name("events")
+            .build()
+            .unwrap();
+
+        let prop = |key: &str| config.properties.get(key).map(String::as_str);
+        assert_eq!(prop("s3.endpoint"), Some("http://localhost:9000"));
+        assert_eq!(prop("s3.region"), Some("us-east-1"));
+        assert_eq!(prop("s3.path-style-access"), Some("true"));
+        assert_eq!(prop("s3.access-key-id"), Some("minioadmin"));
+        assert_eq!(prop("s3.secret-access-key"), Some("minioadmin"));
+    }
+
+    #[test]
+    fn test_config_builder_scan_options() {
+        let config = IcebergConfig::builder()
+            .file_io()
+            .namespace(vec!["db".to_string()])
+            .table_name("events")
+            .scan_batch_size(8192)
+            .scan_target_split_size(64 * 1024 * 1024)
+            .scan_prefetch_depth(16)
+            .build()
+            .unwrap();
+
+        let options = config.scan_options().unwrap();
+        assert_eq!(options.batch_size, Some(8192));
+        assert_eq!(options.target_split_size, Some(64 * 1024 * 1024));
+        assert_eq!(options.prefetch_depth, Some(16));
+
+        let err = IcebergConfig::builder()
+            .file_io()
+            .namespace(vec!["db".to_string()])
+            .table_name("events")
+            .property("scan.prefetch-depth", "0")
+            .build()
+            .unwrap_err();
+        assert!(err.to_string().contains("scan.prefetch-depth"));
+    }
+
     #[test]
     fn test_config_builder_hms() {
         let config = IcebergConfig::builder()
@@ -331,4 +655,60 @@ mod tests {
         let deserialized: CatalogType = serde_json::from_str(&json).unwrap();
         assert_eq!(catalog, deserialized);
     }
+
+    fn table() -> TableReference {
+        TableReference {
+            catalog: Some("prod".to_string()),
+            namespace: vec!["sales".to_string()],
+            table: "orders".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_from_connection_rest() {
+        let connection = ConnectionConfig {
+            catalog_type: Some("REST".to_string()),
+            uri: Some("http://localhost:8181".to_string()),
+            warehouse: Some("s3://lake/warehouse".to_string()),
+            properties: HashMap::from([("token".to_string(), "${env:PATH}".to_string())]),
+        };
+
+        let config = IcebergConfig::from_connection(&connection, &table()).unwrap();
+        assert_eq!(config.warehouse(), Some("s3://lake/warehouse"));
+        assert_eq!(config.table_name, "orders");
+        assert_eq!(
+            config.properties.get("token"),
+            Some(&std::env::var("PATH").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_from_connection_defaults() {
+        let connection = ConnectionConfig {
+            uri: Some("http://localhost:8181".to_string()),
+            ..Default::default()
+        };
+
+        // Type defaults to rest and the catalog name is used as warehouse
+        let config = IcebergConfig::from_connection(&connection, &table()).unwrap();
+        assert!(matches!(config.catalog, CatalogType::Rest { .. }));
+        assert_eq!(config.warehouse(), Some("prod"));
+    }
+
+    #[test]
+    fn test_from_connection_errors() {
+        let missing_uri = ConnectionConfig {
+            catalog_type: Some("hms".to_string()),
+            warehouse: Some("s3://lake".to_string()),
+            ..Default::default()
+        };
+        assert!(IcebergConfig::from_connection(&missing_uri, &table()).is_err());
+
+        let unknown = ConnectionConfig {
+            catalog_type: Some("nessie".to_string()),
+            ..Default::default()
+        };
+        let err = IcebergConfig::from_connection(&unknown, &table()).unwrap_err();
+        assert!(err.to_string().contains("Unsupported catalog type"));
+    }
 }
@@ -1,11 +1,37 @@
 //! Type conversion between Iceberg and DCE types.
 
 use crate::IcebergError;
+use arrow_array::RecordBatch;
 use contracts_core::{DataType, PrimitiveType as DcePrimitiveType, StructField as DceStructField};
-use contracts_validator::DataValue;
+use contracts_validator::{DataRow, DataValue};
 use iceberg::spec::{PrimitiveType, Type as IcebergType};
+use std::collections::HashMap;
+use thiserror::Error;
 use tracing::warn;
 
+/// A value read from a table that could not be converted to a [`DataValue`].
+///
+/// Carries the position of the value in the scan so the offending data file
+/// rows can be located.
+#[derive(Error, Debug, Clone, PartialEq)]
+#[error("column '{column}' ({arrow_type}) at batch {batch}, row {row}: {message}")]
+pub struct ConversionError {
+    /// Name of the column holding the value
+    pub column: String,
+
+    /// Arrow type of the column
+    pub arrow_type: arrow_schema::DataType,
+
+    /// Index of the record batch in the scan
+    pub batch: usize,
+
+    /// Index of the row within the batch
+    pub row: usize,
+
+    /// Why the conversion failed
+    pub message: String,
+}
+
 /// Converts an Iceberg type to a DCE `DataType`.
 ///
 /// Maps Iceberg's type system to the structured DCE type representation,
@@ -73,228 +99,271 @@ fn primitive_to_dce(prim_type: &PrimitiveType) -> DcePrimitiveType {
     }
 }
 
-/// Converts an Arrow/Iceberg value to a DCE DataValue.
+/// Converts one value of an Arrow column to a DCE `DataValue`.
+pub(crate) type ColumnConverter<'a> = Box<dyn Fn(usize) -> Result<DataValue, IcebergError> + 'a>;
+
+/// Builds the converter from Arrow values of `array` to DCE `DataValue`s.
 ///
 /// This is used when reading actual data from Iceberg tables for validation.
-pub fn arrow_value_to_data_value(
-    value: &arrow_array::array::ArrayRef,
-    row_idx: usize,
-) -> Result<DataValue, IcebergError> {
-    use arrow_array::array::*;
-
-    // Check if value is null
-    if value.is_null(row_idx) {
-        return Ok(DataValue::Null);
-    }
+/// The Arrow type is matched and the array downcast once, so converting a
+/// value only reads it. Columns that cannot be downcast produce a converter
+/// that fails for every non-null value.
+pub(crate) fn column_converter(array: &arrow_array::array::ArrayRef) -> ColumnConverter<'_> {
+    let convert = match value_converter(array) {
+        Ok(convert) => convert,
+        Err(e) => {
+            let message = match e {
+                IcebergError::TypeConversionError(message) => message,
+                other => other.to_string(),
+            };
+            Box::new(move |_| Err(IcebergError::TypeConversionError(message.clone())))
+        }
+    };
 
-    // Match on array type and extract value
-    match value.data_type() {
-        arrow_schema::DataType::Boolean => {
-            let array = value
-                .as_any()
-                .downcast_ref::<BooleanArray>()
-                .ok_or_else(|| {
-                    IcebergError::TypeConversionError(
-                        "Failed to downcast to BooleanArray".to_string(),
-                    )
-                })?;
-            Ok(DataValue::Bool(array.value(row_idx)))
+    Box::new(move |row_idx| {
+        if array.is_null(row_idx) {
+            Ok(DataValue::Null)
+        } else {
+            convert(row_idx)
         }
-        arrow_schema::DataType::Int32 => {
-            let array = value.as_any().downcast_ref::<Int32Array>().ok_or_else(|| {
-                IcebergError::TypeConversionError("Failed to downcast to Int32Array".to_string())
-            })?;
-            Ok(DataValue::Int(array.value(row_idx) as i64))
+    })
+}
+
+/// Builds the converter for the non-null values of `array`.
+fn value_converter(
+    array: &arrow_array::array::ArrayRef,
+) -> Result<ColumnConverter<'_>, IcebergError> {
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::*;
+    use arrow_schema::{DataType as ArrowType, TimeUnit};
+
+    let converter: ColumnConverter<'_> = match array.data_type() {
+        ArrowType::Boolean => {
+            let array = array
+                .as_boolean_opt()
+                .ok_or_else(|| downcast_error("BooleanArray"))?;
+            Box::new(move |row| Ok(DataValue::Bool(array.value(row))))
         }
-        arrow_schema::DataType::Int64 => {
-            let array = value.as_any().downcast_ref::<Int64Array>().ok_or_else(|| {
-                IcebergError::TypeConversionError("Failed to downcast to Int64Array".to_string())
-            })?;
-            Ok(DataValue::Int(array.value(row_idx)))
+        ArrowType::Int8 => int_converter::<Int8Type>(array, "Int8Array")?,
+        ArrowType::Int16 => int_converter::<Int16Type>(array, "Int16Array")?,
+        ArrowType::Int32 => int_converter::<Int32Type>(array, "Int32Array")?,
+        ArrowType::Int64 => int_converter::<Int64Type>(array, "Int64Array")?,
+        ArrowType::UInt8 => int_converter::<UInt8Type>(array, "UInt8Array")?,
+        ArrowType::UInt16 => int_converter::<UInt16Type>(array, "UInt16Array")?,
+        ArrowType::UInt32 => int_converter::<UInt32Type>(array, "UInt32Array")?,
+        ArrowType::UInt64 => {
+            // Kept unsigned: values above i64::MAX have no lossless Int form
+            let array = primitive::<UInt64Type>(array, "UInt64Array")?;
+            Box::new(move |row| Ok(DataValue::UInt(array.value(row))))
         }
-        arrow_schema::DataType::Float32 => {
-            let array = value
-                .as_any()
-                .downcast_ref::<Float32Array>()
-                .ok_or_else(|| {
-                    IcebergError::TypeConversionError(
-                        "Failed to downcast to Float32Array".to_string(),
-                    )
-                })?;
-            Ok(DataValue::Float(array.value(row_idx) as f64))
+        ArrowType::Float32 => {
+            let array = primitive::<Float32Type>(array, "Float32Array")?;
+            Box::new(move |row| Ok(DataValue::Float(array.value(row) as f64)))
         }
-        arrow_schema::DataType::Float64 => {
-            let array = value
-                .as_any()
-                .downcast_ref::<Float64Array>()
-                .ok_or_else(|| {
-                    IcebergError::TypeConversionError(
-                        "Failed to downcast to Float64Array".to_string(),
-                    )
-                })?;
-            Ok(DataValue::Float(array.value(row_idx)))
+        ArrowType::Float64 => {
+            let array = primitive::<Float64Type>(array, "Float64Array")?;
+            Box::new(move |row| Ok(DataValue::Float(array.value(row))))
         }
-        arrow_schema::DataType::Utf8 => {
-            let array = value
-                .as_any()
-                .downcast_ref::<StringArray>()
-                .ok_or_else(|| {
-                    IcebergError::TypeConversionError(
-                        "Failed to downcast to StringArray".to_string(),
-                    )
-                })?;
-            Ok(DataValue::String(array.value(row_idx).to_string()))
+        ArrowType::Utf8 => {
+            let array = array
+                .as_string_opt::<i32>()
+                .ok_or_else(|| downcast_error("StringArray"))?;
+            Box::new(move |row| Ok(DataValue::String(array.value(row).to_string())))
         }
-        arrow_schema::DataType::LargeUtf8 => {
-            let array = value
-                .as_any()
-                .downcast_ref::<LargeStringArray>()
-                .ok_or_else(|| {
-                    IcebergError::TypeConversionError(
-                        "Failed to downcast to LargeStringArray".to_string(),
-                    )
-                })?;
-            Ok(DataValue::String(array.value(row_idx).to_string()))
+        ArrowType::LargeUtf8 => {
+            let array = array
+                .as_string_opt::<i64>()
+                .ok_or_else(|| downcast_error("LargeStringArray"))?;
+            Box::new(move |row| Ok(DataValue::String(array.value(row).to_string())))
         }
-        arrow_schema::DataType::Timestamp(unit, _) => {
-            use arrow_schema::TimeUnit;
-
-            let datetime = match unit {
-                TimeUnit::Second => {
-                    let array = value
-                        .as_any()
-                        .downcast_ref::<TimestampSecondArray>()
-                        .ok_or_else(|| {
-                            IcebergError::TypeConversionError(
-                                "Failed to downcast to TimestampSecondArray".to_string(),
-                            )
-                        })?;
-                    let ts_value = array.value(row_idx);
-                    chrono::DateTime::from_timestamp(ts_value, 0)
-                }
-                TimeUnit::Millisecond => {
-                    let array = value
-                        .as_any()
-                        .downcast_ref::<TimestampMillisecondArray>()
-                        .ok_or_else(|| {
-                            IcebergError::TypeConversionError(
-                                "Failed to downcast to TimestampMillisecondArray".to_string(),
-                            )
-                        })?;
-                    let ts_value = array.value(row_idx);
-                    chrono::DateTime::from_timestamp(
-                        ts_value / 1_000,
-                        ((ts_value % 1_000) * 1_000_000) as u32,
-                    )
-                }
-                TimeUnit::Microsecond => {
-                    let array = value
-                        .as_any()
-                        .downcast_ref::<TimestampMicrosecondArray>()
-                        .ok_or_else(|| {
-                            IcebergError::TypeConversionError(
-                                "Failed to downcast to TimestampMicrosecondArray".to_string(),
-                            )
-                        })?;
-                    let ts_value = array.value(row_idx);
-                    chrono::DateTime::from_timestamp(
-                        ts_value / 1_000_000,
-                        ((ts_value % 1_000_000) * 1000) as u32,
-                    )
-                }
-                TimeUnit::Nanosecond => {
-                    let array = value
-                        .as_any()
-                        .downcast_ref::<TimestampNanosecondArray>()
-                        .ok_or_else(|| {
-                            IcebergError::TypeConversionError(
-                                "Failed to downcast to TimestampNanosecondArray".to_string(),
-                            )
-                        })?;
-                    let ts_value = array.value(row_idx);
-                    chrono::DateTime::from_timestamp(
-                        ts_value / 1_000_000_000,
-                        (ts_value % 1_000_000_000) as u32,
-                    )
-                }
+        ArrowType::Timestamp(unit, _) => match unit {
+            TimeUnit::Second => {
+                timestamp_converter::<TimestampSecondType>(array, "TimestampSecondArray")?
             }
-            .ok_or_else(|| {
-                IcebergError::TypeConversionError("Invalid timestamp value".to_string())
-            })?;
-
-            Ok(DataValue::Timestamp(datetime.to_rfc3339()))
+            TimeUnit::Millisecond => {
+                timestamp_converter::<TimestampMillisecondType>(array, "TimestampMillisecondArray")?
+            }
+            TimeUnit::Microsecond => {
+                timestamp_converter::<TimestampMicrosecondType>(array, "TimestampMicrosecondArray")?
+            }
+            TimeUnit::Nanosecond => {
+                timestamp_converter::<TimestampNanosecondType>(array, "TimestampNanosecondArray")?
+            }
+        },
+        // Date32 is days and Date64 milliseconds since Unix epoch
+        ArrowType::Date32 => date_converter::<Date32Type>(array, "Date32Array")?,
+        ArrowType::Date64 => date_converter::<Date64Type>(array, "Date64Array")?,
+        ArrowType::Time32(TimeUnit::Second) => {
+            time_converter::<Time32SecondType>(array, "Time32SecondArray")?
         }
-        arrow_schema::DataType::Date32 => {
-            // Date32 is days since Unix epoch
-            let array = value
-                .as_any()
-                .downcast_ref::<Date32Array>()
-                .ok_or_else(|| {
-                    IcebergError::TypeConversionError(
-                        "Failed to downcast to Date32Array".to_string(),
-                    )
-                })?;
-            let days = array.value(row_idx);
-            let datetime =
-                chrono::DateTime::from_timestamp(days as i64 * 86400, 0).ok_or_else(|| {
-                    IcebergError::TypeConversionError("Invalid date value".to_string())
-                })?;
-            Ok(DataValue::String(datetime.format("%Y-%m-%d").to_string()))
+        ArrowType::Time32(TimeUnit::Millisecond) => {
+            time_converter::<Time32MillisecondType>(array, "Time32MillisecondArray")?
         }
-        arrow_schema::DataType::Date64 => {
-            // Date64 is milliseconds since Unix epoch
-            let array = value
-                .as_any()
-                .downcast_ref::<Date64Array>()
-                .ok_or_else(|| {
-                    IcebergError::TypeConversionError(
-                        "Failed to downcast to Date64Array".to_string(),
-                    )
-                })?;
-            let millis = array.value(row_idx);
-            let datetime =
-                chrono::DateTime::from_timestamp(millis / 1000, (millis % 1000) as u32 * 1_000_000)
-                    .ok_or_else(|| {
-                        IcebergError::TypeConversionError("Invalid date value".to_string())
-                    })?;
-            Ok(DataValue::String(datetime.format("%Y-%m-%d").to_string()))
+        ArrowType::Time64(TimeUnit::Microsecond) => {
+            time_converter::<Time64MicrosecondType>(array, "Time64MicrosecondArray")?
         }
-        arrow_schema::DataType::Decimal128(_precision, scale) => {
-            let array = value
-                .as_any()
-                .downcast_ref::<Decimal128Array>()
-                .ok_or_else(|| {
-                    IcebergError::TypeConversionError(
-                        "Failed to downcast to Decimal128Array".to_string(),
-                    )
-                })?;
-            let decimal_value = array.value(row_idx);
-            // Convert to float for validation purposes
-            let divisor = 10_i128.pow(*scale as u32);
-            let float_value = decimal_value as f64 / divisor as f64;
-            Ok(DataValue::Float(float_value))
+        ArrowType::Time64(TimeUnit::Nanosecond) => {
+            time_converter::<Time64NanosecondType>(array, "Time64NanosecondArray")?
         }
-        arrow_schema::DataType::Decimal256(_precision, _scale) => {
-            let array = value
-                .as_any()
-                .downcast_ref::<Decimal256Array>()
-                .ok_or_else(|| {
-                    IcebergError::TypeConversionError(
-                        "Failed to downcast to Decimal256Array".to_string(),
-                    )
+        ArrowType::Decimal128(_precision, scale) => {
+            let array = primitive::<Decimal128Type>(array, "Decimal128Array")?;
+            let scale = *scale;
+            Box::new(move |row| {
+                Ok(DataValue::Decimal {
+                    value: array.value(row),
+                    scale,
+                })
+            })
+        }
+        ArrowType::Decimal256(_precision, scale) => {
+            let array = primitive::<Decimal256Type>(array, "Decimal256Array")?;
+            let scale = *scale;
+            Box::new(move |row| {
+                // Decimal values hold 128 bits; wider values are rejected, not rounded
+                let value = array.value(row).to_i128().ok_or_else(|| {
+                    IcebergError::TypeConversionError(format!(
+                        "Decimal256 value {} overflows the 128-bit decimal range",
+                        array.value_as_string(row)
+                    ))
                 })?;
-            // Decimal256 values are represented as i256, convert to string for precision
-            let decimal_str = array.value_as_string(row_idx);
-            // Try to parse as float for validation
-            let float_value = decimal_str.parse::<f64>().map_err(|_| {
-                IcebergError::TypeConversionError("Failed to parse Decimal256 value".to_string())
-            })?;
-            Ok(DataValue::Float(float_value))
+                Ok(DataValue::Decimal { value, scale })
+            })
         }
         other => {
             warn!("Unsupported Arrow type for conversion: {:?}", other);
-            Ok(DataValue::Null)
+            Box::new(|_| Ok(DataValue::Null))
         }
+    };
+
+    Ok(converter)
+}
+
+fn downcast_error(array_type: &str) -> IcebergError {
+    IcebergError::TypeConversionError(format!("Failed to downcast to {}", array_type))
+}
+
+fn primitive<'a, T: arrow_array::ArrowPrimitiveType>(
+    array: &'a arrow_array::array::ArrayRef,
+    array_type: &str,
+) -> Result<&'a arrow_array::PrimitiveArray<T>, IcebergError> {
+    use arrow_array::cast::AsArray;
+
+    array
+        .as_primitive_opt::<T>()
+        .ok_or_else(|| downcast_error(array_type))
+}
+
+fn int_converter<'a, T>(
+    array: &'a arrow_array::array::ArrayRef,
+    array_type: &str,
+) -> Result<ColumnConverter<'a>, IcebergError>
+where
+    T: arrow_array::ArrowPrimitiveType,
+    T::Native: Into<i64>,
+{
+    let array = primitive::<T>(array, array_type)?;
+    Ok(Box::new(move |row| {
+        Ok(DataValue::Int(array.value(row).into()))
+    }))
+}
+
+fn timestamp_converter<'a, T>(
+    array: &'a arrow_array::array::ArrayRef,
+    array_type: &str,
+) -> Result<ColumnConverter<'a>, IcebergError>
+where
+    T: arrow_array::types::ArrowTemporalType,
+    i64: From<T::Native>,
+{
+    let array = primitive::<T>(array, array_type)?;
+    Ok(Box::new(move |row| {
+        let datetime = array.value_as_datetime(row).ok_or_else(|| {
+            IcebergError::TypeConversionError("Invalid timestamp value".to_string())
+        })?;
+        Ok(DataValue::Timestamp(datetime.and_utc().to_rfc3339()))
+    }))
+}
+
+fn date_converter<'a, T>(
+    array: &'a arrow_array::array::ArrayRef,
+    array_type: &str,
+) -> Result<ColumnConverter<'a>, IcebergError>
+where
+    T: arrow_array::types::ArrowTemporalType,
+    i64: From<T::Native>,
+{
+    let array = primitive::<T>(array, array_type)?;
+    Ok(Box::new(move |row| {
+        let date = array
+            .value_as_date(row)
+            .ok_or_else(|| IcebergError::TypeConversionError("Invalid date value".to_string()))?;
+        Ok(DataValue::Date(date))
+    }))
+}
+
+fn time_converter<'a, T>(
+    array: &'a arrow_array::array::ArrayRef,
+    array_type: &str,
+) -> Result<ColumnConverter<'a>, IcebergError>
+where
+    T: arrow_array::types::ArrowTemporalType,
+    i64: From<T::Native>,
+{
+    let array = primitive::<T>(array, array_type)?;
+    Ok(Box::new(move |row| {
+        let time = array
+            .value_as_time(row)
+            .ok_or_else(|| IcebergError::TypeConversionError("Invalid time value".to_string()))?;
+        Ok(DataValue::Time(time))
+    }))
+}
+
+/// Converts the rows of one record batch of a scan.
+///
+/// Column converters are built once when the batch converter is created and
+/// reused for every row.
+pub(crate) struct BatchConverter<'a> {
+    batch_idx: usize,
+    columns: Vec<(
+        String,
+        &'a arrow_array::array::ArrayRef,
+        ColumnConverter<'a>,
+    )>,
+}
+
+impl<'a> BatchConverter<'a> {
+    /// Prepares the conversion of `batch`, the `batch_idx`-th batch of a scan.
+    pub(crate) fn new(batch: &'a RecordBatch, batch_idx: usize) -> Self {
+        let columns = batch
+            .schema()
+            .fields()
+            .iter()
+            .zip(batch.columns())
+            .map(|(field, column)| (field.name().clone(), column, column_converter(column)))
+            .collect();
+
+        Self { batch_idx, columns }
+    }
+
+    /// Converts row `row_idx` of the batch.
+    pub(crate) fn convert_row(&self, row_idx: usize) -> Result<DataRow, ConversionError> {
+        let mut row = HashMap::with_capacity(self.columns.len());
+
+        for (name, column, convert) in &self.columns {
+            let value = convert(row_idx).map_err(|e| ConversionError {
+                column: name.clone(),
+                arrow_type: column.data_type().clone(),
+                batch: self.batch_idx,
+                row: row_idx,
+                message: match e {
+                    IcebergError::TypeConversionError(message) => message,
+                    other => other.to_string(),
+                },
+            })?;
+            row.insert(name.clone(), value);
+        }
+
+        Ok(row)
     }
 }
 
@@ -303,6 +372,13 @@ mod tests {
     use super::*;
     use iceberg::spec::{PrimitiveType, Type as IcebergType};
 
+    fn arrow_value_to_data_value(
+        value: &arrow_array::array::ArrayRef,
+        row_idx: usize,
+    ) -> Result<DataValue, IcebergError> {
+        column_converter(value)(row_idx)
+    }
+
     #[test]
     fn test_primitive_type_conversion() {
         assert_eq!(
@@ -389,4 +465,154 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), DataValue::Null);
     }
+
+    #[test]
+    fn test_batch_converter_error_context() {
+        use arrow_array::{Int64Array, TimestampMicrosecondArray};
+        use arrow_schema::{Field, Schema, TimeUnit};
+        use std::sync::Arc;
+
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("id", arrow_schema::DataType::Int64, false),
+                Field::new(
+                    "created_at",
+                    arrow_schema::DataType::Timestamp(TimeUnit::Microsecond, None),
+                    false,
+                ),
+            ])),
+            vec![
+                Arc::new(Int64Array::from(vec![1, 2])),
+                Arc::new(TimestampMicrosecondArray::from(vec![0, i64::MAX])),
+            ],
+        )
+        .unwrap();
+
+        let converter = BatchConverter::new(&batch, 3);
+        let row = converter.convert_row(0).unwrap();
+        assert_eq!(row.get("id"), Some(&DataValue::Int(1)));
+
+        let err = converter.convert_row(1).unwrap_err();
+        assert_eq!(err.column, "created_at");
+        assert_eq!(err.batch, 3);
+        assert_eq!(err.row, 1);
+        assert_eq!(err.message, "Invalid timestamp value");
+        assert_eq!(
+            err.to_string(),
+            "column 'created_at' (Timestamp(µs)) at batch 3, row 1: Invalid timestamp value"
+        );
+    }
+
+    #[test]
+    fn test_arrow_unsigned_conversion() {
+        use arrow_array::{UInt32Array, UInt64Array};
+        use std::sync::Arc;
+
+        let array: Arc<dyn arrow_array::Array> = Arc::new(UInt64Array::from(vec![u64::MAX]));
+        assert_eq!(
+            arrow_value_to_data_value(&array, 0).unwrap(),
+            DataValue::UInt(u64::MAX)
+        );
+
+        let array: Arc<dyn arrow_array::Array> = Arc::new(UInt32Array::from(vec![u32::MAX]));
+        assert_eq!(
+            arrow_value_to_data_value(&array, 0).unwrap(),
+            DataValue::Int(u32::MAX as i64)
+        );
+    }
+
+    #[test]
+    fn test_arrow_decimal_conversion() {
+        use arrow_array::types::{ArrowPrimitiveType, Decimal256Type};
+        use arrow_array::{Decimal128Array, Decimal256Array};
+        type I256 = <Decimal256Type as ArrowPrimitiveType>::Native;
+        use std::sync::Arc;
+
+        // 20 significant digits, more than f64 can represent exactly
+        let exact = 12_345_678_901_234_567_891_i128;
+        let array: Arc<dyn arrow_array::Array> = Arc::new(
+            Decimal128Array::from(vec![exact])
+                .with_precision_and_scale(38, 2)
+                .unwrap(),
+        );
+        assert_eq!(
+            arrow_value_to_data_value(&array, 0).unwrap(),
+            DataValue::Decimal {
+                value: exact,
+                scale: 2
+            }
+        );
+
+        let array: Arc<dyn arrow_array::Array> = Arc::new(
+            Decimal256Array::from(vec![I256::from_i128(exact), I256::MAX])
+                .with_precision_and_scale(76, 2)
+                .unwrap(),
+        );
+        assert_eq!(
+            arrow_value_to_data_value(&array, 0).unwrap(),
+            DataValue::Decimal {
+                value: exact,
+                scale: 2
+            }
+        );
+        assert!(matches!(
+            arrow_value_to_data_value(&array, 1),
+            Err(IcebergError::TypeConversionError(msg)) if msg.contains("overflows")
+        ));
+    }
+
+    #[test]
+    fn test_arrow_date_and_time_conversion() {
+        use arrow_array::{
+            Date32Array, Date64Array, Time32MillisecondArray, Time64MicrosecondArray,
+        };
+        use chrono::{NaiveDate, NaiveTime};
+        use std::sync::Arc;
+
+        let day = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+
+        let array: Arc<dyn arrow_array::Array> = Arc::new(Date32Array::from(vec![19_782]));
+        assert_eq!(
+            arrow_value_to_data_value(&array, 0).unwrap(),
+            DataValue::Date(day)
+        );
+
+        let array: Arc<dyn arrow_array::Array> =
+            Arc::new(Date64Array::from(vec![19_782 * 86_400_000]));
+        assert_eq!(
+            arrow_value_to_data_value(&array, 0).unwrap(),
+            DataValue::Date(day)
+        );
+
+        let array: Arc<dyn arrow_array::Array> =
+            Arc::new(Time32MillisecondArray::from(vec![49_500_250]));
+        assert_eq!(
+            arrow_value_to_data_value(&array, 0).unwrap(),
+            DataValue::Time(NaiveTime::from_hms_milli_opt(13, 45, 0, 250).unwrap())
+        );
+
+        let array: Arc<dyn arrow_array::Array> =
+            Arc::new(Time64MicrosecondArray::from(vec![86_400_000_001]));
+        assert!(arrow_value_to_data_value(&array, 0).is_err());
+    }
+
+    #[test]
+    fn test_column_converter_reuse() {
+        use arrow_array::StringArray;
+        use std::sync::Arc;
+
+        let array: Arc<dyn arrow_array::Array> =
+            Arc::new(StringArray::from(vec![Some("a"), None, Some("c")]));
+        let convert = column_converter(&array);
+
+        let values: Vec<DataValue> = (0..3).map(|row| convert(row).unwrap()).collect();
+        assert_eq!(
+            values,
+            vec![
+                DataValue::String("a".to_string()),
+                DataValue::Null,
+                DataValue::String("c".to_string()),
+            ]
+        );
+    }
 }
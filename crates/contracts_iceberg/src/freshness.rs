@@ -0,0 +1,269 @@
+//! Freshness read from table metadata instead of rows.
+//!
+//! A freshness check with a metadata source is answered from the validated
+//! snapshot: its commit time, or the newest partition derived from the
+//! check's column. Neither needs a data file to be opened, and both see the
+//! whole table even when the rows are sampled.
+
+use crate::IcebergError;
+use chrono::{DateTime, Months, NaiveDate, Utc};
+use contracts_core::{Contract, FreshnessCheck, FreshnessSource, ValidationContext};
+use iceberg::{
+    spec::{
+        DataContentType, Literal, ManifestContentType, PartitionSpec, PrimitiveLiteral,
+        PrimitiveType, Schema, Snapshot, Transform,
+    },
+    table::Table,
+};
+use std::borrow::Cow;
+use tracing::debug;
+
+/// Returns the contract's freshness check if it reads table metadata.
+pub(crate) fn metadata_freshness(contract: &Contract) -> Option<&FreshnessCheck> {
+    contract
+        .quality_checks
+        .as_ref()?
+        .freshness
+        .as_ref()
+        .filter(|check| check.source().is_metadata())
+}
+
+/// Returns `contract` and `context` for validating rows when the freshness
+/// check reads table metadata: the check is left out, and a time window
+/// that defaulted to its column keeps filtering on it.
+pub(crate) fn without_metadata_freshness<'a>(
+    contract: &'a Contract,
+    context: &'a ValidationContext,
+) -> (Cow<'a, Contract>, Cow<'a, ValidationContext>) {
+    let Some(check) = metadata_freshness(contract) else {
+        return (Cow::Borrowed(contract), Cow::Borrowed(context));
+    };
+
+    let context = match (&context.time_window, check.column()) {
+        (Some(window), Some(column)) if window.column.is_none() => {
+            let mut context = context.clone();
+            if let Some(window) = &mut context.time_window {
+                window.column = Some(column.to_string());
+            }
+            Cow::Owned(context)
+        }
+        _ => Cow::Borrowed(context),
+    };
+    debug!(
+        "Reading freshness from table metadata ({})",
+        check.source().as_str()
+    );
+
+    let mut contract = contract.clone();
+    if let Some(checks) = &mut contract.quality_checks {
+        checks.freshness = None;
+    }
+    (Cow::Owned(contract), context)
+}
+
+/// Returns the time `snapshot` says the table was last updated, as read by
+/// `check`.
+///
+/// # Errors
+///
+/// Returns an error if a partition source names a column the table is not
+/// partitioned by, or if the manifests cannot be read.
+pub(crate) async fn updated_at(
+    table: &Table,
+    snapshot: &Snapshot,
+    check: &FreshnessCheck,
+) -> Result<Option<DateTime<Utc>>, IcebergError> {
+    match check.source() {
+        FreshnessSource::Column => Ok(None),
+        FreshnessSource::SnapshotCommittedAt => Ok(snapshot.timestamp().ok()),
+        FreshnessSource::Partition => newest_partition(table, snapshot, &check.metric).await,
+    }
+}
+
+/// Returns the start of the newest partition derived from `column` among
+/// the live data files of `snapshot`.
+async fn newest_partition(
+    table: &Table,
+    snapshot: &Snapshot,
+    column: &str,
+) -> Result<Option<DateTime<Utc>>, IcebergError> {
+    let metadata = table.metadata();
+    let schema = snapshot
+        .schema(metadata)
+        .map_err(|e| IcebergError::data_read("Failed to load snapshot schema", e))?;
+    let source = schema.field_by_name(column).ok_or_else(|| {
+        IcebergError::UnsupportedOperation(format!(
+            "freshness column '{}' is not in the table schema",
+            column
+        ))
+    })?;
+    if !metadata
+        .partition_specs_iter()
+        .any(|spec| partition_position(spec, source.id).is_some())
+    {
+        return Err(IcebergError::UnsupportedOperation(format!(
+            "the table is not partitioned by a time transform of '{}'",
+            column
+        )));
+    }
+
+    let manifests = snapshot
+        .load_manifest_list(table.file_io(), metadata)
+        .await
+        .map_err(|e| IcebergError::data_read("Failed to load manifest list", e))?;
+    let mut newest = None;
+    for manifest in manifests.entries() {
+        if manifest.content != ManifestContentType::Data {
+            continue;
+        }
+        let manifest = manifest
+            .load_manifest(table.file_io())
+            .await
+            .map_err(|e| IcebergError::data_read("Failed to load manifest", e))?;
+        // Files written before the table was partitioned by the column have
+        // no partition value for it
+        let spec = manifest.metadata().partition_spec();
+        let Some(position) = partition_position(spec, source.id) else {
+            continue;
+        };
+        let transform = spec.fields()[position].transform;
+        for entry in manifest.entries() {
+            let file = entry.data_file();
+            if !entry.is_alive() || file.content_type() != DataContentType::Data {
+                continue;
+            }
+            let value = file
+                .partition()
+                .fields()
+                .get(position)
+                .and_then(Option::as_ref)
+                .and_then(|value| partition_start(value, transform, &schema, source.id));
+            newest = newest.max(value);
+        }
+    }
+    Ok(newest)
+}
+
+/// Returns the position of the partition field of `spec` that holds a time
+/// derived from field `source_id`.
+fn partition_position(spec: &PartitionSpec, source_id: i32) -> Option<usize> {
+    spec.fields().iter().position(|field| {
+        field.source_id == source_id
+            && matches!(
+                field.transform,
+                Transform::Identity
+                    | Transform::Year
+                    | Transform::Month
+                    | Transform::Day
+                    | Transform::Hour
+            )
+    })
+}
+
+/// Returns the time a partition value starts at.
+///
+/// Values of the time transforms count from the Unix epoch in their unit;
+/// identity partitions hold the source value, which must be a date or a
+/// timestamp.
+fn partition_start(
+    value: &Literal,
+    transform: Transform,
+    schema: &Schema,
+    source_id: i32,
+) -> Option<DateTime<Utc>> {
+    let Literal::Primitive(value) = value else {
+        return None;
+    };
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1)?;
+    let start_of = |date: NaiveDate| Some(date.and_hms_opt(0, 0, 0)?.and_utc());
+    match (transform, value) {
+        (Transform::Year, PrimitiveLiteral::Int(years)) => {
+            start_of(epoch.checked_add_months(Months::new(u32::try_from(*years).ok()? * 12))?)
+        }
+        (Transform::Month, PrimitiveLiteral::Int(months)) => {
+            start_of(epoch.checked_add_months(Months::new(u32::try_from(*months).ok()?))?)
+        }
+        (Transform::Day, PrimitiveLiteral::Int(days)) => {
+            start_of(epoch + chrono::Duration::days(i64::from(*days)))
+        }
+        (Transform::Hour, PrimitiveLiteral::Int(hours)) => {
+            DateTime::from_timestamp(i64::from(*hours) * 3600, 0)
+        }
+        (Transform::Identity, value) => {
+            let source_type = schema
+                .field_by_id(source_id)?
+                .field_type
+                .as_primitive_type()?
+                .clone();
+            match (source_type, value) {
+                (PrimitiveType::Date, PrimitiveLiteral::Int(days)) => {
+                    start_of(epoch + chrono::Duration::days(i64::from(*days)))
+                }
+                (
+                    PrimitiveType::Timestamp | PrimitiveType::Timestamptz,
+                    PrimitiveLiteral::Long(micros),
+                ) => DateTime::from_timestamp_micros(*micros),
+                (
+                    PrimitiveType::TimestampNs | PrimitiveType::TimestamptzNs,
+                    PrimitiveLiteral::Long(nanos),
+                ) => Some(DateTime::from_timestamp_nanos(*nanos)),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iceberg::spec::{NestedField, Type};
+
+    fn schema() -> Schema {
+        Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "day", Type::Primitive(PrimitiveType::Date)).into(),
+                NestedField::required(2, "ts", Type::Primitive(PrimitiveType::Timestamptz)).into(),
+            ])
+            .build()
+            .unwrap()
+    }
+
+    fn start(value: Literal, transform: Transform, source_id: i32) -> Option<String> {
+        partition_start(&value, transform, &schema(), source_id).map(|ts| ts.to_rfc3339())
+    }
+
+    #[test]
+    fn test_partition_start() {
+        let utc = |s: &str| Some(format!("{}+00:00", s));
+        assert_eq!(
+            start(Literal::int(54), Transform::Year, 2),
+            utc("2024-01-01T00:00:00")
+        );
+        assert_eq!(
+            start(Literal::int(650), Transform::Month, 2),
+            utc("2024-03-01T00:00:00")
+        );
+        assert_eq!(
+            start(Literal::date(19_723), Transform::Day, 2),
+            utc("2024-01-01T00:00:00")
+        );
+        assert_eq!(
+            start(Literal::int(473_354), Transform::Hour, 2),
+            utc("2024-01-01T02:00:00")
+        );
+        assert_eq!(
+            start(Literal::date(19_723), Transform::Identity, 1),
+            utc("2024-01-01T00:00:00")
+        );
+        assert_eq!(
+            start(
+                Literal::long(1_704_067_200_000_000_i64),
+                Transform::Identity,
+                2
+            ),
+            utc("2024-01-01T00:00:00")
+        );
+        assert_eq!(start(Literal::int(3), Transform::Bucket(16), 1), None);
+    }
+}
@@ -0,0 +1,239 @@
+//! Identifier fields: the table's primary key, as declared in Iceberg metadata.
+//!
+//! Writers use identifier fields to match rows for upserts, but Iceberg does
+//! not enforce them. A contract states its key with `primary_key` tags on
+//! fields, or failing that with its uniqueness check; both are compared with
+//! the table, and the identifier values are checked for duplicates in the
+//! sample.
+
+use contracts_core::{
+    CheckKind, Contract, QualityChecksBuilder, Severity, UniquenessCheck, ValidationReport,
+    Violation, codes,
+};
+use iceberg::spec::Schema;
+use std::borrow::Cow;
+
+/// Field tag marking a contract field as part of the primary key.
+pub(crate) const PRIMARY_KEY_TAG: &str = "primary_key";
+
+/// Names of the identifier fields of `schema`, sorted.
+pub(crate) fn identifier_fields(schema: &Schema) -> Vec<String> {
+    let mut names: Vec<String> = schema
+        .identifier_field_ids()
+        .filter_map(|id| schema.name_by_field_id(id))
+        .map(str::to_string)
+        .collect();
+    names.sort();
+    names
+}
+
+/// The key declared by the contract, sorted: its `primary_key` fields, or
+/// else the fields of its uniqueness check.
+pub(crate) fn contract_key(contract: &Contract) -> Option<Vec<String>> {
+    let mut tagged: Vec<String> = contract
+        .fields_with_tag(PRIMARY_KEY_TAG)
+        .map(|f| f.name.clone())
+        .collect();
+    if tagged.is_empty() {
+        tagged = contract
+            .quality_checks
+            .as_ref()
+            .and_then(|qc| qc.uniqueness.as_ref())
+            .map(|check| check.fields.clone())?;
+    }
+    tagged.sort();
+    Some(tagged)
+}
+
+/// Returns the contract the data is validated with.
+///
+/// A contract without a uniqueness check gets one over `identifiers`, so
+/// duplicate keys in the sample are reported like any uniqueness failure.
+pub(crate) fn with_identifier_uniqueness<'a>(
+    contract: &'a Contract,
+    identifiers: &[String],
+) -> Cow<'a, Contract> {
+    let has_uniqueness = contract
+        .quality_checks
+        .as_ref()
+        .is_some_and(|qc| qc.uniqueness.is_some());
+    if identifiers.is_empty() || has_uniqueness {
+        return Cow::Borrowed(contract);
+    }
+
+    let mut contract = contract.clone();
+    let checks = contract
+        .quality_checks
+        .get_or_insert_with(|| QualityChecksBuilder::new().build());
+    checks.uniqueness = Some(UniquenessCheck {
+        fields: identifiers.to_vec(),
+        scope: None,
+        min_sample_size: None,
+        severity: None,
+        approximate: None,
+    });
+    Cow::Owned(contract)
+}
+
+/// Reports where the contract's key differs from the table's identifier fields.
+///
+/// A different key is an error. A key the contract does not declare is a
+/// warning, or an error in strict mode.
+pub(crate) fn check_identifiers(
+    contract: &Contract,
+    identifiers: &[String],
+    strict: bool,
+    report: &mut ValidationReport,
+) {
+    if identifiers.is_empty() {
+        return;
+    }
+
+    match contract_key(contract) {
+        Some(key) if key == identifiers => {}
+        Some(key) => report.add_error(Violation::new(
+            &codes::IDENTIFIER_MISMATCH,
+            CheckKind::Schema,
+            format!(
+                "Contract key [{}] does not match the table's identifier fields [{}]",
+                key.join(", "),
+                identifiers.join(", ")
+            ),
+        )),
+        None => report.add_violation(
+            Violation::new(
+                &codes::IDENTIFIER_MISMATCH,
+                CheckKind::Schema,
+                format!(
+                    "Table identifier fields [{}] are not declared in the contract; \
+                     tag them `{}`",
+                    identifiers.join(", "),
+                    PRIMARY_KEY_TAG
+                ),
+            )
+            .with_severity(Severity::default_for(strict)),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use contracts_core::{ContractBuilder, DataFormat, FieldBuilder};
+    use iceberg::spec::{NestedField, PrimitiveType, Type};
+
+    fn table_schema(identifiers: Vec<i32>) -> Schema {
+        Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "tenant", Type::Primitive(PrimitiveType::String)).into(),
+                NestedField::required(2, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                NestedField::optional(3, "name", Type::Primitive(PrimitiveType::String)).into(),
+            ])
+            .with_identifier_field_ids(identifiers)
+            .build()
+            .unwrap()
+    }
+
+    fn contract(tagged: &[&str]) -> Contract {
+        let field = |name: &str, field_type: &str| {
+            let field = FieldBuilder::new(name, field_type);
+            if tagged.contains(&name) {
+                field.tags(vec![PRIMARY_KEY_TAG.to_string()]).build()
+            } else {
+                field.build()
+            }
+        };
+        ContractBuilder::new("accounts", "team")
+            .location("iceberg://test/db/accounts")
+            .format(DataFormat::Iceberg)
+            .field(field("tenant", "string"))
+            .field(field("id", "int64"))
+            .field(field("name", "string"))
+            .build()
+    }
+
+    #[test]
+    fn test_identifier_fields_are_named_and_sorted() {
+        assert_eq!(
+            identifier_fields(&table_schema(vec![2, 1])),
+            ["id", "tenant"]
+        );
+        assert!(identifier_fields(&table_schema(vec![])).is_empty());
+    }
+
+    #[test]
+    fn test_contract_key_prefers_tags_over_uniqueness() {
+        let mut tagged = contract(&["tenant", "id"]);
+        tagged.quality_checks = Some(
+            QualityChecksBuilder::new()
+                .uniqueness(UniquenessCheck {
+                    fields: vec!["name".to_string()],
+                    scope: None,
+                    min_sample_size: None,
+                    severity: None,
+                    approximate: None,
+                })
+                .build(),
+        );
+        assert_eq!(contract_key(&tagged).unwrap(), ["id", "tenant"]);
+
+        tagged.schema.fields.iter_mut().for_each(|f| f.tags = None);
+        assert_eq!(contract_key(&tagged).unwrap(), ["name"]);
+        assert!(contract_key(&contract(&[])).is_none());
+    }
+
+    #[test]
+    fn test_check_identifiers() {
+        let identifiers = identifier_fields(&table_schema(vec![1, 2]));
+
+        let mut report = ValidationReport::success();
+        check_identifiers(
+            &contract(&["id", "tenant"]),
+            &identifiers,
+            false,
+            &mut report,
+        );
+        assert!(report.passed && report.warnings.is_empty());
+
+        let mut report = ValidationReport::success();
+        check_identifiers(&contract(&["id"]), &identifiers, false, &mut report);
+        assert!(!report.passed);
+        assert!(
+            report.errors[0]
+                .message
+                .contains("Contract key [id] does not match")
+        );
+
+        let mut report = ValidationReport::success();
+        check_identifiers(&contract(&[]), &identifiers, false, &mut report);
+        assert!(report.passed);
+        assert_eq!(report.warnings[0].code.code, "DCE0105");
+
+        let mut report = ValidationReport::success();
+        check_identifiers(&contract(&[]), &identifiers, true, &mut report);
+        assert!(!report.passed);
+    }
+
+    #[test]
+    fn test_identifier_uniqueness_is_added_when_missing() {
+        let identifiers = vec!["id".to_string()];
+        let plain = contract(&[]);
+
+        let checked = with_identifier_uniqueness(&plain, &identifiers);
+        let uniqueness = checked
+            .quality_checks
+            .as_ref()
+            .and_then(|qc| qc.uniqueness.as_ref())
+            .unwrap();
+        assert_eq!(uniqueness.fields, identifiers);
+
+        assert!(matches!(
+            with_identifier_uniqueness(&plain, &[]),
+            Cow::Borrowed(_)
+        ));
+        assert!(matches!(
+            with_identifier_uniqueness(&checked, &["name".to_string()]),
+            Cow::Borrowed(_)
+        ));
+    }
+}
@@ -32,27 +32,65 @@
 //! # }
 //! ```
 
+use contracts_core::{BoxError, Coded, ErrorCode, codes};
 use thiserror::Error;
 
+mod access;
+mod cache;
 mod catalog;
 mod config;
 mod converter;
+mod freshness;
+mod identifiers;
+mod location;
+mod pushdown;
+mod scan;
 mod schema;
+mod secrets;
+mod snapshot;
+mod source;
+mod stats;
+mod storage;
+#[cfg(feature = "testing")]
+pub mod testing;
 mod validator;
+pub mod wap;
 
+pub use access::{ACCESS_PROPERTY_PREFIX, access_properties, column_access};
+pub use cache::CatalogCache;
+pub use catalog::supported_catalogs;
 pub use config::{CatalogType, IcebergConfig};
-pub use validator::IcebergValidator;
+pub use contracts_validator::{RequestLimiter, RequestLimits, RequestPermit};
+pub use converter::ConversionError;
+pub use location::TableReference;
+pub use scan::{SCAN_BATCH_SIZE, SCAN_PREFETCH_DEPTH, SCAN_TARGET_SPLIT_SIZE, ScanOptions};
+pub use secrets::resolve_secret_refs;
+pub use snapshot::{SnapshotInfo, SnapshotSelector};
+pub use source::{BatchSink, DataReader, ReadRange, ReadRequest, TableLoader};
+pub use validator::{ConversionErrorPolicy, IcebergValidator, SampleData};
 
 /// Error types specific to Iceberg operations.
 #[derive(Error, Debug)]
 pub enum IcebergError {
     /// Failed to connect to Iceberg catalog
-    #[error("Failed to connect to Iceberg catalog: {0}")]
-    ConnectionError(String),
+    #[error("Failed to connect to Iceberg catalog: {message}")]
+    ConnectionError {
+        /// What was being attempted
+        message: String,
+        /// The underlying failure
+        #[source]
+        source: BoxError,
+    },
 
     /// Table not found
-    #[error("Iceberg table not found: {0}")]
-    TableNotFound(String),
+    #[error("Iceberg table not found: {message}")]
+    TableNotFound {
+        /// The table that could not be loaded
+        message: String,
+        /// The underlying failure
+        #[source]
+        source: BoxError,
+    },
 
     /// Schema extraction failed
     #[error("Failed to extract schema from Iceberg table: {0}")]
@@ -62,9 +100,19 @@ pub enum IcebergError {
     #[error("Failed to convert Iceberg type to DCE type: {0}")]
     TypeConversionError(String),
 
+    /// A value read from the table could not be converted
+    #[error("Failed to convert value in {0}")]
+    ValueConversionError(Box<ConversionError>),
+
     /// Data reading error
-    #[error("Failed to read data from Iceberg table: {0}")]
-    DataReadError(String),
+    #[error("Failed to read data from Iceberg table: {message}")]
+    DataReadError {
+        /// What was being read
+        message: String,
+        /// The underlying failure
+        #[source]
+        source: BoxError,
+    },
 
     /// Configuration error
     #[error("Invalid Iceberg configuration: {0}")]
@@ -74,14 +122,60 @@ pub enum IcebergError {
     #[error("Unsupported Iceberg operation: {0}")]
     UnsupportedOperation(String),
 
+    /// Error returned by the Iceberg library
+    #[error("Iceberg error: {0}")]
+    Iceberg(#[from] iceberg::Error),
+
     /// Generic Iceberg error
     #[error("Iceberg error: {0}")]
     Other(String),
 }
 
-impl From<iceberg::Error> for IcebergError {
-    fn from(err: iceberg::Error) -> Self {
-        IcebergError::Other(err.to_string())
+impl IcebergError {
+    /// Creates a [`IcebergError::ConnectionError`] caused by `source`.
+    pub fn connection(message: impl Into<String>, source: impl Into<BoxError>) -> Self {
+        Self::ConnectionError {
+            message: message.into(),
+            source: source.into(),
+        }
+    }
+
+    /// Creates a [`IcebergError::TableNotFound`] caused by `source`.
+    pub fn table_not_found(message: impl Into<String>, source: impl Into<BoxError>) -> Self {
+        Self::TableNotFound {
+            message: message.into(),
+            source: source.into(),
+        }
+    }
+
+    /// Creates a [`IcebergError::DataReadError`] caused by `source`.
+    pub fn data_read(message: impl Into<String>, source: impl Into<BoxError>) -> Self {
+        Self::DataReadError {
+            message: message.into(),
+            source: source.into(),
+        }
+    }
+}
+
+impl Coded for IcebergError {
+    fn error_code(&self) -> &'static ErrorCode {
+        match self {
+            Self::ConnectionError { .. } => &codes::CATALOG_CONNECTION,
+            Self::TableNotFound { .. } => &codes::TABLE_NOT_FOUND,
+            Self::SchemaExtractionError(_) => &codes::SCHEMA_EXTRACTION,
+            Self::TypeConversionError(_) => &codes::TYPE_CONVERSION,
+            Self::ValueConversionError(_) => &codes::VALUE_CONVERSION,
+            Self::DataReadError { .. } => &codes::DATA_READ,
+            Self::ConfigurationError(_) => &codes::ICEBERG_CONFIGURATION,
+            Self::UnsupportedOperation(_) => &codes::UNSUPPORTED_OPERATION,
+            Self::Iceberg(_) | Self::Other(_) => &codes::ICEBERG_OTHER,
+        }
+    }
+}
+
+impl From<ConversionError> for IcebergError {
+    fn from(err: ConversionError) -> Self {
+        IcebergError::ValueConversionError(Box::new(err))
     }
 }
 
@@ -89,16 +183,21 @@ impl From<iceberg::Error> for IcebergError {
 mod tests {
     use super::*;
 
+    use std::error::Error as _;
+
     #[test]
     fn test_error_display() {
-        let err = IcebergError::TableNotFound("test_table".to_string());
+        let err = IcebergError::table_not_found("test_table", "no such table");
         assert_eq!(err.to_string(), "Iceberg table not found: test_table");
+        assert_eq!(err.source().unwrap().to_string(), "no such table");
     }
 
     #[test]
     fn test_error_from_iceberg() {
         let iceberg_err = iceberg::Error::new(iceberg::ErrorKind::Unexpected, "test error");
         let err = IcebergError::from(iceberg_err);
-        assert!(matches!(err, IcebergError::Other(_)));
+        assert!(matches!(err, IcebergError::Iceberg(_)));
+        let source = err.source().unwrap();
+        assert!(source.downcast_ref::<iceberg::Error>().is_some());
     }
 }
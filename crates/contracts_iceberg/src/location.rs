@@ -0,0 +1,310 @@
+//! Resolution of Iceberg table references from contract schemas.
+//!
+//! A table can be identified, in order of precedence, by:
+//!
+//! 1. The explicit `catalog` / `namespace` / `table` fields of the schema.
+//! 2. An `iceberg://<catalog>/<namespace>/<table>` location URI.
+//! 3. A storage location such as `s3://bucket/warehouse/db/table`, from which
+//!    namespace and table are inferred heuristically.
+
+use crate::IcebergError;
+use contracts_core::Schema;
+
+/// URI scheme for explicit Iceberg table references.
+const ICEBERG_SCHEME: &str = "iceberg://";
+
+/// Trailing path segments that belong to the table layout, not its identity.
+const TABLE_LAYOUT_DIRS: &[&str] = &["metadata", "data"];
+
+/// A fully-resolved reference to an Iceberg table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableReference {
+    /// Catalog name, when known
+    pub catalog: Option<String>,
+
+    /// Namespace levels (e.g. `["db", "schema"]`)
+    pub namespace: Vec<String>,
+
+    /// Table name
+    pub table: String,
+}
+
+impl TableReference {
+    /// Resolves the table referenced by a contract schema.
+    ///
+    /// Explicit schema fields take precedence, then `iceberg://` locations.
+    /// Other locations fall back to [`TableReference::from_location`], using
+    /// `warehouse` (if known) to strip the warehouse prefix.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use contracts_core::{ContractBuilder, DataFormat};
+    /// use contracts_iceberg::TableReference;
+    ///
+    /// let contract = ContractBuilder::new("events", "team")
+    ///     .location("iceberg://lake/analytics.web/events")
+    ///     .format(DataFormat::Iceberg)
+    ///     .build();
+    ///
+    /// let table = TableReference::resolve(&contract.schema, None).unwrap();
+    /// assert_eq!(table.catalog.as_deref(), Some("lake"));
+    /// assert_eq!(table.namespace, vec!["analytics", "web"]);
+    /// assert_eq!(table.table, "events");
+    /// ```
+    pub fn resolve(schema: &Schema, warehouse: Option<&str>) -> Result<Self, IcebergError> {
+        match (&schema.namespace, &schema.table) {
+            (Some(namespace), Some(table)) => {
+                let namespace = split_namespace(namespace);
+                if namespace.is_empty() || table.trim().is_empty() {
+                    return Err(IcebergError::ConfigurationError(
+                        "schema.namespace and schema.table must not be empty".to_string(),
+                    ));
+                }
+                let catalog = schema.catalog.clone().or_else(|| {
+                    Self::parse_uri(&schema.location)
+                        .ok()
+                        .and_then(|r| r.catalog)
+                });
+                Ok(Self {
+                    catalog,
+                    namespace,
+                    table: table.clone(),
+                })
+            }
+            (Some(_), None) | (None, Some(_)) => Err(IcebergError::ConfigurationError(
+                "schema.namespace and schema.table must be set together".to_string(),
+            )),
+            (None, None) => {
+                let mut reference = if schema.location.starts_with(ICEBERG_SCHEME) {
+                    Self::parse_uri(&schema.location)?
+                } else {
+                    Self::from_location(&schema.location, warehouse)?
+                };
+                if schema.catalog.is_some() {
+                    reference.catalog = schema.catalog.clone();
+                }
+                Ok(reference)
+            }
+        }
+    }
+
+    /// Parses an `iceberg://<catalog>/<namespace>/<table>` URI.
+    ///
+    /// Nested namespaces can be given either as extra path segments
+    /// (`iceberg://cat/db/schema/table`) or dot-separated (`iceberg://cat/db.schema/table`).
+    pub fn parse_uri(uri: &str) -> Result<Self, IcebergError> {
+        let rest = uri.strip_prefix(ICEBERG_SCHEME).ok_or_else(|| {
+            IcebergError::ConfigurationError(format!(
+                "Invalid Iceberg URI '{}': expected {}<catalog>/<namespace>/<table>",
+                uri, ICEBERG_SCHEME
+            ))
+        })?;
+
+        let parts: Vec<&str> = rest.split('/').filter(|s| !s.is_empty()).collect();
+        if parts.len() < 3 {
+            return Err(IcebergError::ConfigurationError(format!(
+                "Invalid Iceberg URI '{}': expected {}<catalog>/<namespace>/<table>",
+                uri, ICEBERG_SCHEME
+            )));
+        }
+
+        let namespace = parts[1..parts.len() - 1]
+            .iter()
+            .flat_map(|p| split_namespace(p))
+            .collect();
+
+        Ok(Self {
+            catalog: Some(parts[0].to_string()),
+            namespace,
+            table: parts[parts.len() - 1].to_string(),
+        })
+    }
+
+    /// Infers namespace and table from a storage location.
+    ///
+    /// Trailing `metadata`/`data` directories and metadata files are ignored,
+    /// and Hive-style `<db>.db` directories are recognised. When `warehouse`
+    /// is a prefix of the location, every segment between the warehouse and
+    /// the table is treated as a namespace level; otherwise the segment
+    /// directly above the table is used.
+    ///
+    /// Examples:
+    /// - `s3://warehouse/db/table` -> `(["db"], "table")`
+    /// - `/warehouse/db.schema/table` -> `(["db", "schema"], "table")`
+    /// - `s3://lake/wh/sales.db/orders/metadata` -> `(["sales"], "orders")`
+    pub fn from_location(location: &str, warehouse: Option<&str>) -> Result<Self, IcebergError> {
+        let invalid = || {
+            IcebergError::ConfigurationError(format!(
+                "Cannot infer namespace and table from location '{}'. Expected \
+                 <warehouse>/<namespace>/<table>, an {}<catalog>/<namespace>/<table> URI, \
+                 or explicit schema.namespace and schema.table fields",
+                location, ICEBERG_SCHEME
+            ))
+        };
+
+        let relative = warehouse
+            .map(|w| w.trim_end_matches('/'))
+            .filter(|w| !w.is_empty())
+            .and_then(|w| location.strip_prefix(w))
+            .filter(|rest| rest.is_empty() || rest.starts_with('/'));
+        let path = match relative {
+            Some(rest) => rest,
+            None => strip_scheme(location),
+        };
+
+        let mut parts: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        while let Some(last) = parts.last() {
+            if TABLE_LAYOUT_DIRS.contains(last) || last.ends_with(".metadata.json") {
+                parts.pop();
+            } else {
+                break;
+            }
+        }
+
+        if parts.len() < 2 {
+            return Err(invalid());
+        }
+
+        let table = parts.pop().ok_or_else(invalid)?.to_string();
+        let namespace_parts: &[&str] = if relative.is_some() {
+            &parts
+        } else {
+            &parts[parts.len() - 1..]
+        };
+
+        let namespace: Vec<String> = namespace_parts
+            .iter()
+            .map(|p| p.strip_suffix(".db").unwrap_or(p))
+            .flat_map(split_namespace)
+            .collect();
+
+        if namespace.is_empty() {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            catalog: None,
+            namespace,
+            table,
+        })
+    }
+
+    /// Returns the namespace joined with dots (e.g. `db.schema`).
+    pub fn namespace_string(&self) -> String {
+        self.namespace.join(".")
+    }
+}
+
+fn split_namespace(namespace: &str) -> Vec<String> {
+    namespace
+        .split('.')
+        .filter(|s| !s.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+fn strip_scheme(location: &str) -> &str {
+    match location.split_once("://") {
+        // Drop the bucket/authority: it identifies storage, not the table.
+        Some((_, rest)) => rest.split_once('/').map(|(_, path)| path).unwrap_or(""),
+        None => location,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use contracts_core::{ContractBuilder, DataFormat};
+
+    fn schema(location: &str) -> ContractBuilder {
+        ContractBuilder::new("t", "o")
+            .location(location)
+            .format(DataFormat::Iceberg)
+    }
+
+    #[test]
+    fn test_parse_iceberg_uri() {
+        let r = TableReference::parse_uri("iceberg://prod/sales/orders").unwrap();
+        assert_eq!(r.catalog.as_deref(), Some("prod"));
+        assert_eq!(r.namespace, vec!["sales"]);
+        assert_eq!(r.table, "orders");
+
+        let r = TableReference::parse_uri("iceberg://prod/sales/eu/orders").unwrap();
+        assert_eq!(r.namespace, vec!["sales", "eu"]);
+
+        let r = TableReference::parse_uri("iceberg://prod/sales.eu/orders").unwrap();
+        assert_eq!(r.namespace, vec!["sales", "eu"]);
+
+        assert!(TableReference::parse_uri("iceberg://prod/orders").is_err());
+        assert!(TableReference::parse_uri("s3://prod/sales/orders").is_err());
+    }
+
+    #[test]
+    fn test_from_location_heuristics() {
+        let r = TableReference::from_location("s3://warehouse/db/table", None).unwrap();
+        assert_eq!(
+            (r.namespace, r.table),
+            (vec!["db".to_string()], "table".to_string())
+        );
+
+        let r = TableReference::from_location("/warehouse/db.schema/table", None).unwrap();
+        assert_eq!(r.namespace, vec!["db", "schema"]);
+
+        let r = TableReference::from_location("s3://lake/wh/nested/sales.db/orders/metadata", None)
+            .unwrap();
+        assert_eq!(r.namespace, vec!["sales"]);
+        assert_eq!(r.table, "orders");
+
+        let r = TableReference::from_location("s3://lake/t/metadata/00001-abc.metadata.json", None);
+        assert!(r.is_err(), "bucket alone is not a namespace");
+
+        assert!(TableReference::from_location("s3://bucket/table", None).is_err());
+    }
+
+    #[test]
+    fn test_from_location_with_nested_warehouse() {
+        let r = TableReference::from_location(
+            "s3://lake/warehouses/prod/sales/eu/orders",
+            Some("s3://lake/warehouses/prod/"),
+        )
+        .unwrap();
+        assert_eq!(r.namespace, vec!["sales", "eu"]);
+        assert_eq!(r.table, "orders");
+
+        // A warehouse that is not a prefix falls back to the heuristic
+        let r =
+            TableReference::from_location("s3://lake/a/sales/orders", Some("s3://other")).unwrap();
+        assert_eq!(r.namespace, vec!["sales"]);
+    }
+
+    #[test]
+    fn test_resolve_prefers_explicit_fields() {
+        let contract = schema("s3://lake/some/odd/layout")
+            .catalog("glue_prod")
+            .namespace("analytics.web")
+            .table("events")
+            .build();
+        let r = TableReference::resolve(&contract.schema, None).unwrap();
+        assert_eq!(r.catalog.as_deref(), Some("glue_prod"));
+        assert_eq!(r.namespace, vec!["analytics", "web"]);
+        assert_eq!(r.table, "events");
+    }
+
+    #[test]
+    fn test_resolve_requires_namespace_and_table_together() {
+        let contract = schema("s3://lake/db/t").table("events").build();
+        assert!(matches!(
+            TableReference::resolve(&contract.schema, None),
+            Err(IcebergError::ConfigurationError(_))
+        ));
+    }
+
+    #[test]
+    fn test_resolve_uri_and_catalog_override() {
+        let contract = schema("iceberg://lake/db/events").catalog("other").build();
+        let r = TableReference::resolve(&contract.schema, None).unwrap();
+        assert_eq!(r.catalog.as_deref(), Some("other"));
+        assert_eq!(r.namespace_string(), "db");
+    }
+}
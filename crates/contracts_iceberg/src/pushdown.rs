@@ -0,0 +1,232 @@
+//! Column pruning and predicate pushdown for validation reads.
+//!
+//! A validation only looks at the columns its contract names, so reads of
+//! wide tables select those columns instead of every column of the table. A
+//! time window is pushed into the scan as well, so data files whose column
+//! statistics lie outside the window are never opened.
+//!
+//! Field constraints such as ranges or allowed values are deliberately not
+//! pushed down: a filter on them would skip exactly the rows that violate
+//! them. Pushed predicates only prune; the validator still applies the
+//! window to every row it reads.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use contracts_core::{Contract, TimeWindow, ValidationContext};
+use iceberg::{
+    expr::{Predicate, Reference},
+    spec::{Datum, PrimitiveType, Schema, Type},
+};
+
+/// Returns the columns a validation of `contract` reads, or `None` when it
+/// may need every column of the table.
+///
+/// These are the contract's fields, the fields its quality checks name and
+/// the context's time window and segment columns. Custom SQL and ML checks
+/// can reference any column, so contracts with them read every column.
+pub(crate) fn read_columns(
+    contract: &Contract,
+    context: &ValidationContext,
+) -> Option<Vec<String>> {
+    let mut columns: Vec<&str> = contract
+        .schema
+        .fields
+        .iter()
+        .map(|field| field.name.as_str())
+        .collect();
+
+    if let Some(checks) = &contract.quality_checks {
+        if checks
+            .custom_checks
+            .as_ref()
+            .is_some_and(|custom| !custom.is_empty())
+            || checks.ml_checks.is_some()
+        {
+            return None;
+        }
+        if let Some(completeness) = &checks.completeness {
+            columns.extend(completeness.fields.iter().map(String::as_str));
+        }
+        if let Some(uniqueness) = &checks.uniqueness {
+            columns.extend(uniqueness.fields.iter().map(String::as_str));
+        }
+        if let Some(freshness) = &checks.freshness {
+            columns.extend(freshness.column());
+        }
+        if let Some(soft_delete) = &checks.soft_delete {
+            columns.push(&soft_delete.column);
+        }
+    }
+    columns.extend(context.time_window_column(contract));
+    columns.extend(context.segment_by.as_deref());
+
+    let mut unique: Vec<String> = Vec::with_capacity(columns.len());
+    for column in columns {
+        if !unique.iter().any(|seen| seen == column) {
+            unique.push(column.to_string());
+        }
+    }
+    Some(unique)
+}
+
+/// Returns a predicate selecting the rows of the context's time window, for
+/// window columns of an Iceberg timestamp or date type in `schema`.
+///
+/// Date bounds are widened to whole days, since the validator compares
+/// dates from the start of the day.
+pub(crate) fn window_filter(
+    contract: &Contract,
+    context: &ValidationContext,
+    schema: &Schema,
+) -> Option<Predicate> {
+    let window = context.time_window.as_ref()?;
+    let column = context.time_window_column(contract)?;
+    let field = schema.as_struct().field_by_name(column)?;
+    let Type::Primitive(field_type) = field.field_type.as_ref() else {
+        return None;
+    };
+
+    let TimeWindow { since, until, .. } = window;
+    let lower = since.and_then(|since| datum(field_type, since));
+    let upper = until.and_then(|until| datum(field_type, until));
+    let lower = lower.map(|datum| Reference::new(column).greater_than_or_equal_to(datum));
+    let upper = upper.map(|datum| match field_type {
+        // Days before the one the window ends on can still hold rows inside it
+        PrimitiveType::Date => Reference::new(column).less_than_or_equal_to(datum),
+        _ => Reference::new(column).less_than(datum),
+    });
+    match (lower, upper) {
+        (Some(lower), Some(upper)) => Some(lower.and(upper)),
+        (lower, upper) => lower.or(upper),
+    }
+}
+
+/// Converts a window bound to a value of `field_type`, truncated to the day
+/// for dates, or `None` for other types and bounds out of its range.
+fn datum(field_type: &PrimitiveType, timestamp: DateTime<Utc>) -> Option<Datum> {
+    match field_type {
+        PrimitiveType::Timestamp => Some(Datum::timestamp_micros(timestamp.timestamp_micros())),
+        PrimitiveType::Timestamptz => Some(Datum::timestamptz_micros(timestamp.timestamp_micros())),
+        PrimitiveType::TimestampNs => timestamp.timestamp_nanos_opt().map(Datum::timestamp_nanos),
+        PrimitiveType::TimestamptzNs => timestamp
+            .timestamp_nanos_opt()
+            .map(Datum::timestamptz_nanos),
+        PrimitiveType::Date => {
+            let days = timestamp
+                .date_naive()
+                .signed_duration_since(NaiveDate::default())
+                .num_days();
+            i32::try_from(days).ok().map(Datum::date)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use contracts_core::{
+        ContractBuilder, CustomCheck, DataFormat, FieldBuilder, QualityChecksBuilder,
+        UniquenessCheck,
+    };
+    use iceberg::spec::{NestedField, Type};
+    use std::sync::Arc;
+
+    fn contract() -> Contract {
+        ContractBuilder::new("events", "team")
+            .location("s3://bucket/events")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("id", "int64").build())
+            .field(FieldBuilder::new("ts", "timestamp").build())
+            .build()
+    }
+
+    fn schema(ts_type: PrimitiveType) -> Schema {
+        Schema::builder()
+            .with_fields(vec![
+                Arc::new(NestedField::required(
+                    1,
+                    "id",
+                    Type::Primitive(PrimitiveType::Long),
+                )),
+                Arc::new(NestedField::optional(2, "ts", Type::Primitive(ts_type))),
+            ])
+            .build()
+            .unwrap()
+    }
+
+    fn window(since: Option<u32>, until: Option<u32>) -> ValidationContext {
+        let day = |d| Utc.with_ymd_and_hms(2026, 10, d, 12, 0, 0).unwrap();
+        ValidationContext::new().with_time_window(TimeWindow {
+            column: Some("ts".to_string()),
+            since: since.map(day),
+            until: until.map(day),
+        })
+    }
+
+    #[test]
+    fn test_read_columns() {
+        let mut contract = contract();
+        let mut checks = QualityChecksBuilder::new().build();
+        checks.uniqueness = Some(UniquenessCheck {
+            fields: vec!["id".to_string(), "tenant".to_string()],
+            scope: None,
+            min_sample_size: None,
+            severity: None,
+            approximate: None,
+        });
+        contract.quality_checks = Some(checks);
+        let context = ValidationContext::new().with_segment_by("region");
+        assert_eq!(
+            read_columns(&contract, &context).unwrap(),
+            vec!["id", "ts", "tenant", "region"]
+        );
+
+        contract.quality_checks.as_mut().unwrap().custom_checks = Some(vec![CustomCheck {
+            name: "positive".to_string(),
+            definition: "SELECT COUNT(*) FROM data WHERE other < 0".to_string(),
+            severity: None,
+            capture_rows: None,
+        }]);
+        assert!(read_columns(&contract, &context).is_none());
+    }
+
+    #[test]
+    fn test_window_filter() {
+        let contract = contract();
+        let filter = window_filter(
+            &contract,
+            &window(Some(15), Some(16)),
+            &schema(PrimitiveType::Timestamptz),
+        )
+        .unwrap();
+        let text = filter.to_string();
+        assert!(text.contains("ts >="), "{}", text);
+        assert!(text.contains("ts <"), "{}", text);
+
+        let lower = window_filter(
+            &contract,
+            &window(Some(15), None),
+            &schema(PrimitiveType::Date),
+        );
+        assert!(lower.unwrap().to_string().contains("ts >="));
+
+        // Strings are parsed by the validator, not compared by the scan
+        assert!(
+            window_filter(
+                &contract,
+                &window(Some(15), None),
+                &schema(PrimitiveType::String)
+            )
+            .is_none()
+        );
+        assert!(
+            window_filter(
+                &contract,
+                &ValidationContext::new(),
+                &schema(PrimitiveType::Timestamp)
+            )
+            .is_none()
+        );
+    }
+}
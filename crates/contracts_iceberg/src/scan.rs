@@ -0,0 +1,182 @@
+//! Tuning options for table scans.
+//!
+//! The best values depend heavily on where the data lives: local files read
+//! fastest in large batches with little concurrency, while object stores
+//! such as S3 hide their latency behind many concurrent reads. The options
+//! are read from the properties of an [`IcebergConfig`](crate::IcebergConfig),
+//! so they can also be set in a contract's `connection:` block.
+
+use crate::IcebergError;
+use std::collections::HashMap;
+
+/// Property with the number of rows per record batch read from data files.
+pub const SCAN_BATCH_SIZE: &str = "scan.batch-size";
+
+/// Property with the target size in bytes of the parts large data files are
+/// split into, so they can be read concurrently.
+pub const SCAN_TARGET_SPLIT_SIZE: &str = "scan.target-split-size";
+
+/// Property with the number of data files, or parts of them, read ahead
+/// concurrently.
+pub const SCAN_PREFETCH_DEPTH: &str = "scan.prefetch-depth";
+
+/// Tuning options applied when reading data files.
+///
+/// Every option left unset keeps the reader's default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScanOptions {
+    /// Rows per record batch (1024 when unset)
+    pub batch_size: Option<usize>,
+
+    /// Data files larger than this many bytes are split at row group
+    /// boundaries into parts of about this size (no splitting when unset)
+    pub target_split_size: Option<u64>,
+
+    /// Data files, or parts of them, read concurrently (one per CPU when unset)
+    pub prefetch_depth: Option<usize>,
+}
+
+impl ScanOptions {
+    /// Batch size used when none is configured.
+    pub const DEFAULT_BATCH_SIZE: usize = 1024;
+
+    /// Parses the `scan.*` properties of a configuration.
+    ///
+    /// Returns an error if a value is not a positive integer.
+    pub fn from_properties(properties: &HashMap<String, String>) -> Result<Self, IcebergError> {
+        Ok(Self {
+            batch_size: positive(properties, SCAN_BATCH_SIZE)?,
+            target_split_size: positive(properties, SCAN_TARGET_SPLIT_SIZE)?,
+            prefetch_depth: positive(properties, SCAN_PREFETCH_DEPTH)?,
+        })
+    }
+
+    /// Returns the `scan.*` properties of the options that are set.
+    ///
+    /// Extending a configuration's properties with them overrides the
+    /// options it already had.
+    pub fn properties(&self) -> HashMap<String, String> {
+        [
+            (SCAN_BATCH_SIZE, self.batch_size.map(|n| n.to_string())),
+            (
+                SCAN_TARGET_SPLIT_SIZE,
+                self.target_split_size.map(|n| n.to_string()),
+            ),
+            (
+                SCAN_PREFETCH_DEPTH,
+                self.prefetch_depth.map(|n| n.to_string()),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(key, value)| Some((key.to_string(), value?)))
+        .collect()
+    }
+
+    /// Returns the batch size, or [`Self::DEFAULT_BATCH_SIZE`] when unset.
+    pub fn batch_size(&self) -> usize {
+        self.batch_size.unwrap_or(Self::DEFAULT_BATCH_SIZE)
+    }
+}
+
+/// Reads property `key` as a positive integer, if set.
+fn positive<T>(properties: &HashMap<String, String>, key: &str) -> Result<Option<T>, IcebergError>
+where
+    T: std::str::FromStr + Default + PartialEq,
+{
+    let Some(value) = properties.get(key) else {
+        return Ok(None);
+    };
+    match value.trim().parse::<T>() {
+        Ok(parsed) if parsed != T::default() => Ok(Some(parsed)),
+        _ => Err(IcebergError::ConfigurationError(format!(
+            "{} must be a positive integer, got '{}'",
+            key, value
+        ))),
+    }
+}
+
+/// Splits a data file of `file_size` bytes into byte ranges of about
+/// `target_size` bytes, cut only at the row group starts in `offsets`.
+///
+/// The reader reads the row groups that overlap a range, so cutting at row
+/// group starts never reads a row twice. Returns a single range
+/// covering the whole file when it cannot be split.
+pub(crate) fn split_ranges(file_size: u64, offsets: &[i64], target_size: u64) -> Vec<(u64, u64)> {
+    let mut starts: Vec<u64> = offsets
+        .iter()
+        .filter_map(|&offset| u64::try_from(offset).ok())
+        .filter(|&offset| offset < file_size)
+        .collect();
+    starts.sort_unstable();
+    starts.dedup();
+
+    // The first range starts at the beginning of the file, before the first row group
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    for offset in starts.into_iter().skip(1) {
+        if offset - start >= target_size {
+            ranges.push((start, offset - start));
+            start = offset;
+        }
+    }
+    ranges.push((start, file_size - start));
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn properties(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_scan_options_from_properties() {
+        let options = ScanOptions::from_properties(&properties(&[
+            (SCAN_BATCH_SIZE, "8192"),
+            (SCAN_TARGET_SPLIT_SIZE, "134217728"),
+            (SCAN_PREFETCH_DEPTH, "16"),
+        ]))
+        .unwrap();
+        assert_eq!(options.batch_size(), 8192);
+        assert_eq!(options.target_split_size, Some(134_217_728));
+        assert_eq!(options.prefetch_depth, Some(16));
+
+        assert_eq!(
+            ScanOptions::from_properties(&options.properties()).unwrap(),
+            options
+        );
+
+        let defaults = ScanOptions::from_properties(&HashMap::new()).unwrap();
+        assert!(defaults.properties().is_empty());
+        assert_eq!(defaults, ScanOptions::default());
+        assert_eq!(defaults.batch_size(), ScanOptions::DEFAULT_BATCH_SIZE);
+    }
+
+    #[test]
+    fn test_scan_options_reject_invalid_values() {
+        for value in ["0", "-1", "lots"] {
+            let err = ScanOptions::from_properties(&properties(&[(SCAN_BATCH_SIZE, value)]))
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains(SCAN_BATCH_SIZE), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_split_ranges() {
+        // Row groups of 100 bytes each, after the 4-byte magic number
+        let offsets = [4, 104, 204, 304];
+        assert_eq!(split_ranges(404, &offsets, 200), vec![(0, 204), (204, 200)]);
+        assert_eq!(
+            split_ranges(404, &offsets, 50),
+            vec![(0, 104), (104, 100), (204, 100), (304, 100)]
+        );
+        assert_eq!(split_ranges(404, &offsets, 1000), vec![(0, 404)]);
+        assert_eq!(split_ranges(404, &[], 50), vec![(0, 404)]);
+    }
+}
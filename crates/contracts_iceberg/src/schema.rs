@@ -1,13 +1,17 @@
 //! Schema extraction from Iceberg tables.
 
+use crate::identifiers::PRIMARY_KEY_TAG;
 use crate::{IcebergError, converter::iceberg_type_to_dce_type};
-use contracts_core::{DataFormat, Field as ContractField, Schema as ContractSchema};
-use iceberg::spec::{NestedField, Schema as IcebergSchema};
+use contracts_core::{
+    DataFormat, Field as ContractField, FieldConstraints, Schema as ContractSchema,
+};
+use iceberg::spec::{NestedField, PrimitiveType, Schema as IcebergSchema, Type};
 use tracing::{debug, info};
 
 /// Extracts a DCE schema from an Iceberg table schema.
 ///
 /// Converts Iceberg's schema representation to the DCE contract schema format.
+/// Identifier fields are tagged `primary_key`.
 pub fn extract_schema_from_iceberg(
     iceberg_schema: &IcebergSchema,
     location: &str,
@@ -19,7 +23,13 @@ pub fn extract_schema_from_iceberg(
     // Access fields via as_struct() which returns the struct type containing fields
     let struct_type = iceberg_schema.as_struct();
     for field in struct_type.fields() {
-        let contract_field = convert_iceberg_field(field)?;
+        let mut contract_field = convert_iceberg_field(field)?;
+        if iceberg_schema
+            .identifier_field_ids()
+            .any(|id| id == field.id)
+        {
+            contract_field.tags = Some(vec![PRIMARY_KEY_TAG.to_string()]);
+        }
         fields.push(contract_field);
     }
 
@@ -29,10 +39,17 @@ pub fn extract_schema_from_iceberg(
         fields,
         format: DataFormat::Iceberg,
         location: location.to_string(),
+        catalog: None,
+        namespace: None,
+        table: None,
+        connection: None,
     })
 }
 
 /// Converts an Iceberg field to a DCE contract field.
+///
+/// A `decimal(p, s)` field gets a `precision` constraint, since the contract
+/// type does not carry its precision and scale.
 fn convert_iceberg_field(field: &NestedField) -> Result<ContractField, IcebergError> {
     let field_type = iceberg_type_to_dce_type(&field.field_type)?;
 
@@ -45,12 +62,37 @@ fn convert_iceberg_field(field: &NestedField) -> Result<ContractField, IcebergEr
         name: field.name.clone(),
         field_type,
         nullable: !field.required,
+        required: None,
         description: field.doc.clone(),
         tags: None,
-        constraints: None,
+        constraints: decimal_precision(&field.field_type).map(|constraint| vec![constraint]),
+        severity: None,
+        default: write_default(field),
+        deprecated: None,
+        access: None,
     })
 }
 
+/// Returns the `precision` constraint of a decimal type.
+fn decimal_precision(field_type: &Type) -> Option<FieldConstraints> {
+    match field_type {
+        Type::Primitive(PrimitiveType::Decimal { precision, scale }) => {
+            Some(FieldConstraints::Precision {
+                precision: u8::try_from(*precision).ok()?,
+                scale: u8::try_from(*scale).ok()?,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Returns the value Iceberg writers use for `field` when a row leaves it
+/// out, as JSON.
+fn write_default(field: &NestedField) -> Option<serde_json::Value> {
+    let default = field.write_default.clone()?;
+    default.try_into_json(&field.field_type).ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -88,6 +130,27 @@ mod tests {
         assert!(contract_field.nullable);
     }
 
+    #[test]
+    fn test_convert_decimal_field() {
+        let field = NestedField::required(
+            4,
+            "amount",
+            IcebergType::Primitive(PrimitiveType::Decimal {
+                precision: 12,
+                scale: 2,
+            }),
+        );
+
+        let contract_field = convert_iceberg_field(&field).unwrap();
+        assert_eq!(
+            contract_field.constraints,
+            Some(vec![FieldConstraints::Precision {
+                precision: 12,
+                scale: 2
+            }])
+        );
+    }
+
     #[test]
     fn test_convert_field_with_doc() {
         let field = NestedField::required(
@@ -112,6 +175,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_convert_field_with_write_default() {
+        use iceberg::spec::Literal;
+
+        let field =
+            NestedField::optional(4, "country", IcebergType::Primitive(PrimitiveType::String))
+                .with_write_default(Literal::string("IT"));
+
+        let contract_field = convert_iceberg_field(&field).unwrap();
+        assert_eq!(contract_field.default, Some(serde_json::json!("IT")));
+
+        let field =
+            NestedField::optional(5, "notes", IcebergType::Primitive(PrimitiveType::String));
+        assert_eq!(convert_iceberg_field(&field).unwrap().default, None);
+    }
+
     #[test]
     fn test_extract_schema() {
         use std::sync::Arc;
@@ -134,6 +213,7 @@ mod tests {
                     IcebergType::Primitive(PrimitiveType::Boolean),
                 )),
             ])
+            .with_identifier_field_ids(vec![1])
             .build()
             .unwrap();
 
@@ -148,5 +228,11 @@ mod tests {
         assert_eq!(schema.fields[0].name, "id");
         assert_eq!(schema.fields[1].name, "name");
         assert_eq!(schema.fields[2].name, "active");
+
+        assert_eq!(
+            schema.fields[0].tags.as_deref(),
+            Some(&["primary_key".to_string()][..])
+        );
+        assert!(schema.fields[1].tags.is_none());
     }
 }
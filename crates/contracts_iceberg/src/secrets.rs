@@ -0,0 +1,108 @@
+//! Resolution of secret references in connection settings.
+//!
+//! Contracts are usually committed to version control, so credentials in a
+//! `connection:` block are written as references that are resolved at
+//! connection time:
+//!
+//! - `${env:NAME}` is replaced with the value of the environment variable `NAME`
+//! - `${file:/path/to/secret}` is replaced with the trimmed contents of the file
+
+use crate::IcebergError;
+
+/// Replaces every secret reference in `value` with the referenced secret.
+///
+/// Values without references are returned unchanged.
+///
+/// # Example
+///
+/// ```rust
+/// use contracts_iceberg::resolve_secret_refs;
+///
+/// assert_eq!(resolve_secret_refs("plain").unwrap(), "plain");
+/// assert!(resolve_secret_refs("${env:DCE_SURELY_UNSET_VARIABLE}").is_err());
+/// ```
+pub fn resolve_secret_refs(value: &str) -> Result<String, IcebergError> {
+    let mut resolved = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(start) = rest.find("${") {
+        resolved.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let end = after.find('}').ok_or_else(|| {
+            IcebergError::ConfigurationError(format!(
+                "Unterminated secret reference in '{}'",
+                value
+            ))
+        })?;
+
+        resolved.push_str(&resolve_reference(&after[..end])?);
+        rest = &after[end + 1..];
+    }
+
+    resolved.push_str(rest);
+    Ok(resolved)
+}
+
+fn resolve_reference(reference: &str) -> Result<String, IcebergError> {
+    match reference.split_once(':') {
+        Some(("env", name)) => std::env::var(name).map_err(|_| {
+            IcebergError::ConfigurationError(format!(
+                "Environment variable '{}' referenced by the connection config is not set",
+                name
+            ))
+        }),
+        Some(("file", path)) => std::fs::read_to_string(path)
+            .map(|s| s.trim().to_string())
+            .map_err(|e| {
+                IcebergError::ConfigurationError(format!(
+                    "Failed to read secret file '{}': {}",
+                    path, e
+                ))
+            }),
+        _ => Err(IcebergError::ConfigurationError(format!(
+            "Unsupported secret reference '${{{}}}'. Use ${{env:NAME}} or ${{file:/path}}",
+            reference
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain_values_are_unchanged() {
+        assert_eq!(
+            resolve_secret_refs("s3://bucket/warehouse").unwrap(),
+            "s3://bucket/warehouse"
+        );
+    }
+
+    #[test]
+    fn test_env_reference() {
+        // PATH is set in every test environment
+        let path = std::env::var("PATH").unwrap();
+        assert_eq!(
+            resolve_secret_refs("prefix-${env:PATH}-suffix").unwrap(),
+            format!("prefix-{}-suffix", path)
+        );
+    }
+
+    #[test]
+    fn test_file_reference() {
+        let file = std::env::temp_dir().join(format!("dce-secret-{}", std::process::id()));
+        std::fs::write(&file, "s3cr3t\n").unwrap();
+
+        let value = format!("${{file:{}}}", file.display());
+        assert_eq!(resolve_secret_refs(&value).unwrap(), "s3cr3t");
+
+        std::fs::remove_file(&file).unwrap();
+    }
+
+    #[test]
+    fn test_invalid_references() {
+        assert!(resolve_secret_refs("${env:DCE_TEST_UNSET_VARIABLE_123}").is_err());
+        assert!(resolve_secret_refs("${vault:secret/data}").is_err());
+        assert!(resolve_secret_refs("${env:PATH").is_err());
+    }
+}
@@ -0,0 +1,100 @@
+//! Table snapshots, as seen by incremental and time-travel validation.
+
+use crate::IcebergError;
+use chrono::{DateTime, Utc};
+use contracts_core::DeleteStats;
+use iceberg::spec::{Snapshot, TableMetadata};
+use serde::{Deserialize, Serialize};
+
+/// The snapshot of a table to read instead of its current one.
+///
+/// Pinning a run to a snapshot makes it reproducible: the same snapshot
+/// always holds the same rows, however many commits followed it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SnapshotSelector {
+    /// The snapshot with this ID
+    Id(i64),
+
+    /// The snapshot that was current on `main` at this time
+    AsOf(DateTime<Utc>),
+}
+
+impl SnapshotSelector {
+    /// Returns the ID of the selected snapshot in `metadata`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the snapshot is not in the metadata, or if the
+    /// table had no snapshot yet at the requested time.
+    pub fn resolve(&self, metadata: &TableMetadata) -> Result<i64, IcebergError> {
+        match *self {
+            Self::Id(snapshot_id) => match metadata.snapshot_by_id(snapshot_id) {
+                Some(_) => Ok(snapshot_id),
+                None => Err(IcebergError::Other(format!(
+                    "Snapshot {} is no longer in the table metadata",
+                    snapshot_id
+                ))),
+            },
+            Self::AsOf(time) => metadata
+                .history()
+                .iter()
+                .rev()
+                .find(|entry| entry.timestamp_ms <= time.timestamp_millis())
+                .map(|entry| entry.snapshot_id)
+                .ok_or_else(|| {
+                    IcebergError::Other(format!(
+                        "The table had no snapshot as of {}",
+                        time.to_rfc3339()
+                    ))
+                }),
+        }
+    }
+}
+
+/// A committed version of an Iceberg table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotInfo {
+    /// Snapshot ID
+    pub snapshot_id: i64,
+
+    /// ID of the snapshot this one was committed on top of
+    pub parent_snapshot_id: Option<i64>,
+
+    /// Commit time, when the metadata records a valid one
+    pub committed_at: Option<DateTime<Utc>>,
+
+    /// Operation that produced the snapshot (`append`, `overwrite`, ...)
+    pub operation: String,
+
+    /// Rows added by the snapshot, from its summary
+    pub added_records: Option<u64>,
+
+    /// Row-level deletes in the table as of this snapshot, when the summary
+    /// records the table totals
+    pub deletes: Option<DeleteStats>,
+}
+
+impl From<&Snapshot> for SnapshotInfo {
+    fn from(snapshot: &Snapshot) -> Self {
+        let summary = snapshot.summary();
+        let count = |key: &str| {
+            summary
+                .additional_properties
+                .get(key)
+                .and_then(|count| count.parse().ok())
+        };
+        Self {
+            snapshot_id: snapshot.snapshot_id(),
+            parent_snapshot_id: snapshot.parent_snapshot_id(),
+            committed_at: snapshot.timestamp().ok(),
+            operation: summary.operation.as_str().to_string(),
+            added_records: count("added-records"),
+            deletes: count("total-records").map(|data_records| DeleteStats {
+                data_records,
+                position_deletes: count("total-position-deletes").unwrap_or(0),
+                equality_deletes: count("total-equality-deletes").unwrap_or(0),
+            }),
+        }
+    }
+}
@@ -0,0 +1,1039 @@
+//! Access to table metadata and data for the validator.
+//!
+//! [`IcebergValidator`](crate::IcebergValidator) never talks to a catalog
+//! directly. It asks a [`TableLoader`] for the table schema and a
+//! [`DataReader`] for rows, so schema and sampling logic can run against
+//! in-memory fakes such as [`MockTable`](crate::testing::MockTable).
+
+use crate::{
+    IcebergError, RequestLimiter, ScanOptions,
+    catalog::{build_file_io, create_table_ident},
+    config::{CatalogType, IcebergConfig},
+    scan::split_ranges,
+    storage::limit_table,
+};
+use arrow_array::RecordBatch;
+use async_trait::async_trait;
+use contracts_core::SamplingStrategy;
+use contracts_validator::{Sampler, proportional_quotas};
+use futures::{StreamExt, TryStreamExt, stream};
+use iceberg::{
+    Catalog,
+    arrow::{ArrowReader, ArrowReaderBuilder},
+    expr::{Bind, Predicate},
+    io::FileIO,
+    scan::FileScanTask,
+    spec::{ManifestContentType, Schema, SchemaRef, Struct},
+    table::{StaticTable, Table},
+};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tracing::{debug, info};
+
+/// Loads the metadata of the table being validated.
+#[async_trait]
+pub trait TableLoader: Send + Sync {
+    /// Returns the current schema of the table.
+    async fn load_schema(&self) -> Result<SchemaRef, IcebergError>;
+
+    /// Returns the underlying Iceberg table, if there is one.
+    ///
+    /// The native DataFusion path scans this table directly. Loaders that
+    /// return `None` (the default) are validated from the batches of the
+    /// [`DataReader`] instead.
+    async fn load_table(&self) -> Result<Option<Table>, IcebergError> {
+        Ok(None)
+    }
+}
+
+/// The rows of the table a read covers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReadRange {
+    /// The table at its current snapshot
+    #[default]
+    Current,
+
+    /// The table as of a snapshot
+    At(i64),
+
+    /// The rows of data files added after a snapshot
+    Since(i64),
+
+    /// The rows of data files added after the first snapshot, up to and
+    /// including the second
+    Between(i64, i64),
+}
+
+/// A read of at most `limit` rows of a range, with the columns and rows it
+/// may be pruned to.
+#[derive(Debug, Clone)]
+pub struct ReadRequest {
+    /// Rows of the table to read
+    pub range: ReadRange,
+
+    /// Most rows to read
+    pub limit: usize,
+
+    /// How rows are chosen when the range holds more than `limit`
+    pub sampling: SamplingStrategy,
+
+    /// Top-level columns to read; every column when `None`
+    pub columns: Option<Vec<String>>,
+
+    /// Predicate rows must match; files and rows that cannot match it may be
+    /// skipped
+    pub filter: Option<Predicate>,
+}
+
+impl ReadRequest {
+    /// Creates a request for the first `limit` rows of `range`, with every
+    /// column.
+    pub fn new(range: ReadRange, limit: usize) -> Self {
+        Self {
+            range,
+            limit,
+            sampling: SamplingStrategy::Head,
+            columns: None,
+            filter: None,
+        }
+    }
+
+    /// Chooses the rows read with `sampling`.
+    pub fn with_sampling(mut self, sampling: SamplingStrategy) -> Self {
+        self.sampling = sampling;
+        self
+    }
+
+    /// Reads only `columns`, ignoring those the table does not have.
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Prunes the files and rows that cannot match `filter`.
+    pub fn with_filter(mut self, filter: Option<Predicate>) -> Self {
+        self.filter = filter;
+        self
+    }
+}
+
+/// Receives record batches as they are read; an error stops the read.
+pub type BatchSink<'a> = dyn FnMut(RecordBatch) -> Result<(), IcebergError> + Send + 'a;
+
+/// Reads rows of the table being validated.
+#[async_trait]
+pub trait DataReader: Send + Sync {
+    /// Reads at most `limit` rows as Arrow record batches.
+    ///
+    /// Rows removed by position or equality delete files must not be
+    /// returned, so merge-on-read tables are validated as readers see them.
+    async fn read_batches(&self, limit: usize) -> Result<Vec<RecordBatch>, IcebergError>;
+
+    /// Reads at most `limit` rows of the table as of snapshot `snapshot_id`.
+    ///
+    /// Readers without snapshot history return
+    /// [`IcebergError::UnsupportedOperation`] (the default).
+    async fn read_batches_at(
+        &self,
+        snapshot_id: i64,
+        limit: usize,
+    ) -> Result<Vec<RecordBatch>, IcebergError> {
+        let _ = (snapshot_id, limit);
+        Err(IcebergError::UnsupportedOperation(
+            "reads at a snapshot need a table with snapshot history".to_string(),
+        ))
+    }
+
+    /// Reads at most `limit` rows from the data files added after snapshot
+    /// `snapshot_id`.
+    ///
+    /// Readers without snapshot history return
+    /// [`IcebergError::UnsupportedOperation`] (the default).
+    async fn read_batches_since(
+        &self,
+        snapshot_id: i64,
+        limit: usize,
+    ) -> Result<Vec<RecordBatch>, IcebergError> {
+        let _ = (snapshot_id, limit);
+        Err(IcebergError::UnsupportedOperation(
+            "incremental reads need a table with snapshot history".to_string(),
+        ))
+    }
+
+    /// Reads at most `limit` rows from the data files added after snapshot
+    /// `from_snapshot`, up to and including snapshot `to_snapshot`.
+    ///
+    /// Readers without snapshot history return
+    /// [`IcebergError::UnsupportedOperation`] (the default).
+    async fn read_batches_between(
+        &self,
+        from_snapshot: i64,
+        to_snapshot: i64,
+        limit: usize,
+    ) -> Result<Vec<RecordBatch>, IcebergError> {
+        let _ = (from_snapshot, to_snapshot, limit);
+        Err(IcebergError::UnsupportedOperation(
+            "incremental reads need a table with snapshot history".to_string(),
+        ))
+    }
+
+    /// Reads at most `limit` rows of `range`, handing each batch to `sink`
+    /// as soon as it is read, so callers can go through more rows than fit
+    /// in memory.
+    ///
+    /// The default reads every batch with the `read_batches` method for the
+    /// range first, then hands them over one by one.
+    async fn stream_batches(
+        &self,
+        range: ReadRange,
+        limit: usize,
+        sink: &mut BatchSink<'_>,
+    ) -> Result<(), IcebergError> {
+        let batches = match range {
+            ReadRange::Current => self.read_batches(limit).await?,
+            ReadRange::At(snapshot_id) => self.read_batches_at(snapshot_id, limit).await?,
+            ReadRange::Since(snapshot_id) => self.read_batches_since(snapshot_id, limit).await?,
+            ReadRange::Between(from, to) => self.read_batches_between(from, to, limit).await?,
+        };
+        batches.into_iter().try_for_each(sink)
+    }
+
+    /// Reads the rows of `request`, handing each batch to `sink`.
+    ///
+    /// The request's columns and filter only prune the read: readers may
+    /// return other columns and rows that do not match, and the default
+    /// ignores them. [`SamplingStrategy::Head`] reads like
+    /// [`stream_batches`](Self::stream_batches). The default reads every row
+    /// of the range for the other strategies and draws the sample from them
+    /// in memory, stratifying as if the table had a single partition.
+    async fn stream_rows(
+        &self,
+        request: &ReadRequest,
+        sink: &mut BatchSink<'_>,
+    ) -> Result<(), IcebergError> {
+        let Some(seed) = request.sampling.seed() else {
+            return self
+                .stream_batches(request.range, request.limit, sink)
+                .await;
+        };
+
+        let mut batches = Vec::new();
+        self.stream_batches(request.range, usize::MAX, &mut |batch| {
+            batches.push(batch);
+            Ok(())
+        })
+        .await?;
+
+        let total = batches.iter().map(RecordBatch::num_rows).sum();
+        let mut picked = PickedRows::new(Sampler::new(seed).positions(total, request.limit));
+        batches
+            .into_iter()
+            .try_for_each(|batch| picked.select(&batch, sink))
+    }
+}
+
+/// Hands only the rows at sampled positions of a run of batches to a sink.
+struct PickedRows {
+    /// Sampled positions, ascending, counted from the first row of the run
+    positions: Vec<usize>,
+    /// Index in `positions` of the next position to hand over
+    next: usize,
+    /// Position of the first row of the next batch
+    offset: usize,
+}
+
+impl PickedRows {
+    fn new(positions: Vec<usize>) -> Self {
+        Self {
+            positions,
+            next: 0,
+            offset: 0,
+        }
+    }
+
+    /// Returns true once every sampled position has been handed over.
+    fn is_done(&self) -> bool {
+        self.next == self.positions.len()
+    }
+
+    /// Hands the sampled rows of the next batch of the run to `sink`, as
+    /// slices of consecutive rows.
+    fn select(
+        &mut self,
+        batch: &RecordBatch,
+        sink: &mut BatchSink<'_>,
+    ) -> Result<(), IcebergError> {
+        let end = self.offset + batch.num_rows();
+        while let Some(&first) = self.positions.get(self.next)
+            && first < end
+        {
+            let mut len = 1;
+            while self
+                .positions
+                .get(self.next + len)
+                .is_some_and(|&position| position == first + len && position < end)
+            {
+                len += 1;
+            }
+            sink(batch.slice(first - self.offset, len))?;
+            self.next += len;
+        }
+        self.offset = end;
+        Ok(())
+    }
+}
+
+/// Reads a table through its catalog, or from a metadata file for FileIO.
+///
+/// Every table load waits for `limiter` first, and so does every storage
+/// request made through the loaded table.
+pub(crate) struct IcebergSource {
+    config: IcebergConfig,
+    catalog: Option<Arc<dyn Catalog>>,
+    file_io: Option<FileIO>,
+    limiter: RequestLimiter,
+    scan: ScanOptions,
+}
+
+impl IcebergSource {
+    /// Creates a source for `config`, using `catalog` for catalog-based types.
+    pub(crate) fn new(
+        config: IcebergConfig,
+        catalog: Option<Arc<dyn Catalog>>,
+        limiter: RequestLimiter,
+    ) -> Result<Self, IcebergError> {
+        // Only build FileIO for FileIO catalog type (local filesystem access).
+        // Catalog-based paths (REST, Glue, HMS) handle storage access internally.
+        let file_io = match &config.catalog {
+            CatalogType::FileIO => Some(build_file_io(
+                config
+                    .properties
+                    .get("metadata_location")
+                    .map(String::as_str),
+                &config.properties,
+            )?),
+            _ => None,
+        };
+        let scan = config.scan_options()?;
+
+        Ok(Self {
+            config,
+            catalog,
+            file_io,
+            limiter,
+            scan,
+        })
+    }
+
+    /// Loads the Iceberg table from the configured location, with its storage
+    /// requests limited by `limiter`.
+    async fn table(&self) -> Result<Table, IcebergError> {
+        let table = {
+            let _permit = self.limiter.acquire().await;
+            self.load().await?
+        };
+        limit_table(table, &self.limiter)
+    }
+
+    /// Loads the Iceberg table from the configured location.
+    ///
+    /// Supports both catalog-based loading (REST, Glue, HMS) and direct FileIO loading.
+    async fn load(&self) -> Result<Table, IcebergError> {
+        let table_ident = create_table_ident(&self.config.namespace, &self.config.table_name)?;
+
+        info!("Loading Iceberg table: {}", table_ident);
+
+        if let Some(catalog) = &self.catalog {
+            // Load table from catalog
+            catalog
+                .load_table(&table_ident)
+                .await
+                .map_err(|e| IcebergError::table_not_found(table_ident.to_string(), e))
+        } else {
+            // For FileIO, we need a direct metadata file path
+            // This should be provided in the properties
+            let metadata_path =
+                self.config
+                    .properties
+                    .get("metadata_location")
+                    .ok_or_else(|| {
+                        IcebergError::ConfigurationError(
+                            "FileIO catalog requires 'metadata_location' property".to_string(),
+                        )
+                    })?;
+
+            info!("Loading table from metadata file: {}", metadata_path);
+
+            let file_io = self.file_io.clone().ok_or_else(|| {
+                IcebergError::ConfigurationError(
+                    "FileIO not available for FileIO catalog type".to_string(),
+                )
+            })?;
+
+            StaticTable::from_metadata_file(metadata_path, table_ident, file_io)
+                .await
+                .map(|static_table| static_table.into_table())
+                .map_err(|e| IcebergError::table_not_found(metadata_path.clone(), e))
+        }
+    }
+
+    /// Reads at most `limit` rows of `range` into memory.
+    async fn read_range(
+        &self,
+        range: ReadRange,
+        limit: usize,
+    ) -> Result<Vec<RecordBatch>, IcebergError> {
+        let mut batches = Vec::new();
+        self.stream_batches(range, limit, &mut |batch| {
+            batches.push(batch);
+            Ok(())
+        })
+        .await?;
+        Ok(batches)
+    }
+}
+
+/// Plans the data files holding the rows of a request's range, pruned by its
+/// columns and filter.
+async fn plan_range(
+    table: &Table,
+    request: &ReadRequest,
+) -> Result<Vec<FileScanTask>, IcebergError> {
+    let (columns, filter) = (request.columns.as_deref(), request.filter.as_ref());
+    match request.range {
+        ReadRange::Current => plan_files(table, None, columns, filter).await,
+        ReadRange::At(snapshot_id) => {
+            check_snapshot(table, snapshot_id)?;
+            plan_files(table, Some(snapshot_id), columns, filter).await
+        }
+        ReadRange::Since(from_snapshot) => {
+            plan_added(table, from_snapshot, None, columns, filter).await
+        }
+        ReadRange::Between(from_snapshot, to_snapshot) => {
+            check_snapshot(table, to_snapshot)?;
+            plan_added(table, from_snapshot, Some(to_snapshot), columns, filter).await
+        }
+    }
+}
+
+/// Returns the snapshot whose manifests list the data files of `range`.
+fn range_snapshot(table: &Table, range: ReadRange) -> Option<i64> {
+    match range {
+        ReadRange::Current | ReadRange::Since(_) => table.metadata().current_snapshot_id(),
+        ReadRange::At(snapshot_id) | ReadRange::Between(_, snapshot_id) => Some(snapshot_id),
+    }
+}
+
+/// Fails if snapshot `snapshot_id` has expired from the table metadata.
+fn check_snapshot(table: &Table, snapshot_id: i64) -> Result<(), IcebergError> {
+    if table.metadata().snapshot_by_id(snapshot_id).is_none() {
+        return Err(IcebergError::Other(format!(
+            "Snapshot {} is no longer in the table metadata",
+            snapshot_id
+        )));
+    }
+    Ok(())
+}
+
+/// Plans the data files present at `to_snapshot`, or at the current
+/// snapshot, that were not present at `from_snapshot`.
+async fn plan_added(
+    table: &Table,
+    from_snapshot: i64,
+    to_snapshot: Option<i64>,
+    columns: Option<&[String]>,
+    filter: Option<&Predicate>,
+) -> Result<Vec<FileScanTask>, IcebergError> {
+    let to = to_snapshot.or(table.metadata().current_snapshot_id());
+    if to == Some(from_snapshot) {
+        return Ok(Vec::new());
+    }
+    check_snapshot(table, from_snapshot)?;
+
+    // Files already planned at the base snapshot hold rows seen before; the
+    // base is planned unpruned, since its schema may predate the columns
+    let seen: HashSet<String> = plan_files(table, Some(from_snapshot), None, None)
+        .await?
+        .into_iter()
+        .map(|task| task.data_file_path)
+        .collect();
+    let tasks: Vec<FileScanTask> = plan_files(table, to_snapshot, columns, filter)
+        .await?
+        .into_iter()
+        .filter(|task| !seen.contains(&task.data_file_path))
+        .collect();
+    debug!(
+        "{} data file(s) added since snapshot {}",
+        tasks.len(),
+        from_snapshot
+    );
+    Ok(tasks)
+}
+
+#[async_trait]
+impl TableLoader for IcebergSource {
+    async fn load_schema(&self) -> Result<SchemaRef, IcebergError> {
+        let table = self.table().await?;
+        Ok(table.metadata().current_schema().clone())
+    }
+
+    async fn load_table(&self) -> Result<Option<Table>, IcebergError> {
+        self.table().await.map(Some)
+    }
+}
+
+#[async_trait]
+impl DataReader for IcebergSource {
+    async fn read_batches(&self, limit: usize) -> Result<Vec<RecordBatch>, IcebergError> {
+        self.read_range(ReadRange::Current, limit).await
+    }
+
+    async fn read_batches_at(
+        &self,
+        snapshot_id: i64,
+        limit: usize,
+    ) -> Result<Vec<RecordBatch>, IcebergError> {
+        self.read_range(ReadRange::At(snapshot_id), limit).await
+    }
+
+    async fn read_batches_since(
+        &self,
+        snapshot_id: i64,
+        limit: usize,
+    ) -> Result<Vec<RecordBatch>, IcebergError> {
+        self.read_range(ReadRange::Since(snapshot_id), limit).await
+    }
+
+    async fn read_batches_between(
+        &self,
+        from_snapshot: i64,
+        to_snapshot: i64,
+        limit: usize,
+    ) -> Result<Vec<RecordBatch>, IcebergError> {
+        self.read_range(ReadRange::Between(from_snapshot, to_snapshot), limit)
+            .await
+    }
+
+    async fn stream_batches(
+        &self,
+        range: ReadRange,
+        limit: usize,
+        sink: &mut BatchSink<'_>,
+    ) -> Result<(), IcebergError> {
+        self.stream_rows(&ReadRequest::new(range, limit), sink)
+            .await
+    }
+
+    async fn stream_rows(
+        &self,
+        request: &ReadRequest,
+        sink: &mut BatchSink<'_>,
+    ) -> Result<(), IcebergError> {
+        let table = self.table().await?;
+
+        let Some(seed) = request.sampling.seed() else {
+            // Planned tasks carry their delete files, which the reader applies
+            let tasks = plan_range(&table, request).await?;
+            let row_groups = match self.scan.target_split_size {
+                Some(target_size) => {
+                    let snapshot_id = range_snapshot(&table, request.range);
+                    row_group_offsets(&table, snapshot_id, &tasks, target_size).await?
+                }
+                None => HashMap::new(),
+            };
+            return read_plan(&table, tasks, request.limit, &self.scan, &row_groups, sink).await;
+        };
+
+        // Sampled positions are drawn from the row counts of whole files,
+        // which a row filter would no longer match
+        let unfiltered = request.clone().with_filter(None);
+
+        // Files are planned concurrently, so order them for a seed to pick
+        // the same rows on every run
+        let mut tasks = plan_range(&table, &unfiltered).await?;
+        tasks.sort_by(|a, b| a.data_file_path.cmp(&b.data_file_path));
+
+        let stratified = matches!(request.sampling, SamplingStrategy::Stratified { .. });
+        read_sampled(
+            &table,
+            tasks,
+            request.limit,
+            &self.scan,
+            stratified,
+            seed,
+            sink,
+        )
+        .await
+    }
+}
+
+/// Plans a scan of `columns`, or of every column, at `snapshot_id` or at the
+/// current snapshot, skipping the files that cannot match `filter`.
+///
+/// Columns missing from the snapshot's schema are left out, and a filter on
+/// one is dropped, so reads of older snapshots still plan.
+async fn plan_files(
+    table: &Table,
+    snapshot_id: Option<i64>,
+    columns: Option<&[String]>,
+    filter: Option<&Predicate>,
+) -> Result<Vec<FileScanTask>, IcebergError> {
+    let metadata = table.metadata();
+    let schema = match snapshot_id.and_then(|snapshot_id| metadata.snapshot_by_id(snapshot_id)) {
+        Some(snapshot) => snapshot
+            .schema(metadata)
+            .map_err(|e| IcebergError::data_read("Failed to load snapshot schema", e))?,
+        None => metadata.current_schema().clone(),
+    };
+
+    let mut scan = match columns.map(|columns| present_columns(&schema, columns)) {
+        Some(columns) if !columns.is_empty() => table.scan().select(columns),
+        _ => table.scan().select_all(),
+    };
+    if let Some(filter) = filter.filter(|filter| filter.bind(schema.clone(), true).is_ok()) {
+        scan = scan.with_filter(filter.clone());
+    }
+    if let Some(snapshot_id) = snapshot_id {
+        scan = scan.snapshot_id(snapshot_id);
+    }
+    let scan = scan
+        .build()
+        .map_err(|e| IcebergError::data_read("Failed to build scan", e))?;
+
+    scan.plan_files()
+        .await
+        .map_err(|e| IcebergError::data_read("Failed to plan scan", e))?
+        .try_collect()
+        .await
+        .map_err(|e| IcebergError::data_read("Failed to plan scan", e))
+}
+
+/// Returns the `columns` that are top-level fields of `schema`.
+fn present_columns<'a>(schema: &Schema, columns: &'a [String]) -> Vec<&'a str> {
+    columns
+        .iter()
+        .filter(|column| schema.as_struct().field_by_name(column).is_some())
+        .map(String::as_str)
+        .collect()
+}
+
+/// Loads the row group offsets recorded in the manifests of `snapshot_id`
+/// for the data files of `tasks` larger than `target_size` bytes.
+///
+/// Manifests are only read when some file is large enough to be split.
+async fn row_group_offsets(
+    table: &Table,
+    snapshot_id: Option<i64>,
+    tasks: &[FileScanTask],
+    target_size: u64,
+) -> Result<HashMap<String, Vec<i64>>, IcebergError> {
+    let large: HashSet<&str> = tasks
+        .iter()
+        .filter(|task| task.file_size_in_bytes > target_size && task.deletes.is_empty())
+        .map(|task| task.data_file_path.as_str())
+        .collect();
+    let metadata = table.metadata();
+    let snapshot = snapshot_id.and_then(|snapshot_id| metadata.snapshot_by_id(snapshot_id));
+    let (false, Some(snapshot)) = (large.is_empty(), snapshot) else {
+        return Ok(HashMap::new());
+    };
+
+    let manifests = snapshot
+        .load_manifest_list(table.file_io(), metadata)
+        .await
+        .map_err(|e| IcebergError::data_read("Failed to load manifest list", e))?;
+    let mut offsets = HashMap::new();
+    for manifest in manifests.entries() {
+        if manifest.content != ManifestContentType::Data {
+            continue;
+        }
+        let manifest = manifest
+            .load_manifest(table.file_io())
+            .await
+            .map_err(|e| IcebergError::data_read("Failed to load manifest", e))?;
+        for entry in manifest.entries() {
+            if !entry.is_alive() || !large.contains(entry.file_path()) {
+                continue;
+            }
+            if let Some(split_offsets) = entry.data_file().split_offsets() {
+                offsets.insert(entry.file_path().to_string(), split_offsets.to_vec());
+            }
+        }
+    }
+    debug!(
+        "Loaded row group offsets of {} data file(s) to split",
+        offsets.len()
+    );
+    Ok(offsets)
+}
+
+/// Reads at most `limit` rows from planned `tasks`, sampled across
+/// partitions, into `sink`.
+///
+/// Files listed in `row_groups` are split into parts of about
+/// `scan.target_split_size` bytes, which the reader reads concurrently.
+async fn read_plan(
+    table: &Table,
+    tasks: Vec<FileScanTask>,
+    limit: usize,
+    scan: &ScanOptions,
+    row_groups: &HashMap<String, Vec<i64>>,
+    sink: &mut BatchSink<'_>,
+) -> Result<(), IcebergError> {
+    let reader = arrow_reader(table, scan);
+    for (tasks, quota) in sample_plan(tasks, limit) {
+        let tasks = match scan.target_split_size {
+            Some(target_size) => split_tasks(tasks, row_groups, target_size),
+            None => tasks,
+        };
+        read_tasks(reader.clone(), tasks, quota, sink).await?;
+    }
+    Ok(())
+}
+
+/// Builds a reader of data files tuned by `scan`.
+fn arrow_reader(table: &Table, scan: &ScanOptions) -> ArrowReader {
+    let mut reader =
+        ArrowReaderBuilder::new(table.file_io().clone()).with_batch_size(scan.batch_size());
+    if let Some(depth) = scan.prefetch_depth {
+        reader = reader.with_data_file_concurrency_limit(depth);
+    }
+    reader.build()
+}
+
+/// Reads a random sample of at most `limit` rows from planned `tasks` into
+/// `sink`, drawn with `seed`.
+///
+/// Row positions are drawn from the record counts in the manifests, so only
+/// the files holding a sampled row are read. With `stratified`, every
+/// partition gets a share of `limit` proportional to its row count, like
+/// [`sample_plan`]; otherwise rows are drawn from the whole table. Row counts
+/// ignore delete files, so a sample from files with deleted rows may fall
+/// short of `limit`. Plans with files without a record count are read like a
+/// head sample.
+async fn read_sampled(
+    table: &Table,
+    tasks: Vec<FileScanTask>,
+    limit: usize,
+    scan: &ScanOptions,
+    stratified: bool,
+    seed: u64,
+    sink: &mut BatchSink<'_>,
+) -> Result<(), IcebergError> {
+    if tasks.iter().any(|task| task.record_count.is_none()) {
+        debug!("Some data files have no record count, reading a head sample");
+        return read_plan(table, tasks, limit, scan, &HashMap::new(), sink).await;
+    }
+
+    let reader = arrow_reader(table, scan);
+    let groups = if stratified {
+        sample_plan(tasks, limit)
+    } else {
+        vec![(tasks, limit)]
+    };
+    let mut sampler = Sampler::new(seed);
+    for (tasks, quota) in groups {
+        let row_counts: Vec<usize> = tasks
+            .iter()
+            .map(|task| task.record_count.unwrap_or(0) as usize)
+            .collect();
+        let mut positions = sampler
+            .positions(row_counts.iter().sum(), quota)
+            .into_iter()
+            .peekable();
+
+        let mut first = 0;
+        for (task, rows) in tasks.into_iter().zip(row_counts) {
+            let end = first + rows;
+            let mut local = Vec::new();
+            while let Some(position) = positions.next_if(|&position| position < end) {
+                local.push(position - first);
+            }
+            first = end;
+            if !local.is_empty() {
+                read_rows(reader.clone(), task, local, sink).await?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Reads the rows at `positions` of one data file into `sink`.
+async fn read_rows(
+    reader: ArrowReader,
+    task: FileScanTask,
+    positions: Vec<usize>,
+    sink: &mut BatchSink<'_>,
+) -> Result<(), IcebergError> {
+    let mut stream = reader
+        .read(stream::iter([Ok(task)]).boxed())
+        .map_err(|e| IcebergError::data_read("Failed to create arrow stream", e))?;
+
+    let mut picked = PickedRows::new(positions);
+    while !picked.is_done()
+        && let Some(batch) = stream
+            .try_next()
+            .await
+            .map_err(|e| IcebergError::data_read("Failed to read record batch", e))?
+    {
+        picked.select(&batch, sink)?;
+    }
+    Ok(())
+}
+
+/// Splits the tasks of the files in `row_groups` into byte ranges of about
+/// `target_size` bytes, cut at row group boundaries.
+///
+/// Files with delete files are never split, since deletes are applied by
+/// row position across the whole file.
+fn split_tasks(
+    tasks: Vec<FileScanTask>,
+    row_groups: &HashMap<String, Vec<i64>>,
+    target_size: u64,
+) -> Vec<FileScanTask> {
+    let mut split = Vec::with_capacity(tasks.len());
+    for task in tasks {
+        let offsets = match row_groups.get(&task.data_file_path) {
+            Some(offsets) if task.deletes.is_empty() => offsets,
+            _ => {
+                split.push(task);
+                continue;
+            }
+        };
+        let ranges = split_ranges(task.file_size_in_bytes, offsets, target_size);
+        if ranges.len() == 1 {
+            split.push(task);
+            continue;
+        }
+        debug!(
+            "Splitting {} into {} part(s)",
+            task.data_file_path,
+            ranges.len()
+        );
+        for (start, length) in ranges {
+            split.push(FileScanTask {
+                start,
+                length,
+                // Row counts are per file, not per part
+                record_count: None,
+                ..task.clone()
+            });
+        }
+    }
+    split
+}
+
+/// Splits a scan into reads that together return at most `limit` rows.
+///
+/// When the table holds more than `limit` rows, every partition gets a share
+/// of `limit` proportional to its row count in the manifests, so the sample
+/// reflects the whole table instead of the files that are planned first.
+/// Row counts ignore delete files, so a partition with deleted rows may fall
+/// short of its share. Tables that fit in `limit`, or that have files without
+/// a record count, are read in planning order.
+fn sample_plan(tasks: Vec<FileScanTask>, limit: usize) -> Vec<(Vec<FileScanTask>, usize)> {
+    let total: Option<u64> = tasks.iter().map(|task| task.record_count).sum();
+    if total.is_none_or(|total| total <= limit as u64) {
+        return vec![(tasks, limit)];
+    }
+
+    // Partitions in the order their first file was planned
+    let mut partitions: Vec<(Vec<FileScanTask>, u64)> = Vec::new();
+    let mut index: HashMap<Option<Struct>, usize> = HashMap::new();
+    for task in tasks {
+        let slot = *index.entry(task.partition.clone()).or_insert_with(|| {
+            partitions.push((Vec::new(), 0));
+            partitions.len() - 1
+        });
+        partitions[slot].1 += task.record_count.unwrap_or(0);
+        partitions[slot].0.push(task);
+    }
+
+    let row_counts: Vec<u64> = partitions.iter().map(|(_, rows)| *rows).collect();
+    let quotas = proportional_quotas(&row_counts, limit);
+    debug!(
+        "Sampling {} rows across {} partition(s): {:?}",
+        limit,
+        partitions.len(),
+        quotas
+    );
+
+    partitions
+        .into_iter()
+        .zip(quotas)
+        .filter(|(_, quota)| *quota > 0)
+        .map(|((tasks, _), quota)| (tasks, quota))
+        .collect()
+}
+
+/// Reads at most `limit` rows from `tasks` into `sink`, slicing the batch
+/// that crosses the limit.
+async fn read_tasks(
+    reader: ArrowReader,
+    tasks: Vec<FileScanTask>,
+    limit: usize,
+    sink: &mut BatchSink<'_>,
+) -> Result<(), IcebergError> {
+    let mut stream = reader
+        .read(stream::iter(tasks.into_iter().map(Ok)).boxed())
+        .map_err(|e| IcebergError::data_read("Failed to create arrow stream", e))?;
+
+    debug!("Arrow stream created, reading record batches");
+
+    let mut remaining = limit;
+    while remaining > 0
+        && let Some(batch) = stream
+            .try_next()
+            .await
+            .map_err(|e| IcebergError::data_read("Failed to read record batch", e))?
+    {
+        debug!("Read batch with {} rows", batch.num_rows());
+        let batch = if batch.num_rows() > remaining {
+            batch.slice(0, remaining)
+        } else {
+            batch
+        };
+        remaining -= batch.num_rows();
+        sink(batch)?;
+    }
+
+    Ok(())
+}
+
+/// Drops rows beyond the first `limit`, slicing the batch that crosses it.
+#[cfg(any(test, feature = "testing"))]
+pub(crate) fn truncate_batches(batches: Vec<RecordBatch>, limit: usize) -> Vec<RecordBatch> {
+    let mut remaining = limit;
+    let mut truncated = Vec::with_capacity(batches.len());
+
+    for batch in batches {
+        if remaining == 0 {
+            break;
+        }
+        if batch.num_rows() > remaining {
+            truncated.push(batch.slice(0, remaining));
+            break;
+        }
+        remaining -= batch.num_rows();
+        truncated.push(batch);
+    }
+
+    truncated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::Int32Array;
+    use arrow_schema::{DataType, Field, Schema};
+
+    fn batch(values: Vec<i32>) -> RecordBatch {
+        RecordBatch::try_new(
+            Arc::new(Schema::new(vec![Field::new("id", DataType::Int32, false)])),
+            vec![Arc::new(Int32Array::from(values))],
+        )
+        .unwrap()
+    }
+
+    fn row_counts(batches: &[RecordBatch]) -> Vec<usize> {
+        batches.iter().map(RecordBatch::num_rows).collect()
+    }
+
+    #[test]
+    fn test_picked_rows_slice_runs_across_batches() {
+        let mut picked = PickedRows::new(vec![1, 2, 3, 5]);
+        let mut selected = Vec::new();
+        for values in [vec![0, 1, 2], vec![3, 4, 5], vec![6]] {
+            picked
+                .select(&batch(values), &mut |batch| {
+                    selected.push(batch);
+                    Ok(())
+                })
+                .unwrap();
+        }
+        assert!(picked.is_done());
+
+        // Runs of consecutive positions stay one slice within a batch
+        assert_eq!(row_counts(&selected), vec![2, 1, 1]);
+        let ids: Vec<i32> = selected
+            .iter()
+            .flat_map(|batch| {
+                let ids = batch.column(0).as_any().downcast_ref::<Int32Array>();
+                ids.unwrap().values().to_vec()
+            })
+            .collect();
+        assert_eq!(ids, vec![1, 2, 3, 5]);
+    }
+
+    #[test]
+    fn test_truncate_batches() {
+        let batches = || vec![batch(vec![1, 2, 3]), batch(vec![4, 5]), batch(vec![6])];
+
+        assert_eq!(row_counts(&truncate_batches(batches(), 10)), vec![3, 2, 1]);
+        assert_eq!(row_counts(&truncate_batches(batches(), 5)), vec![3, 2]);
+        assert_eq!(row_counts(&truncate_batches(batches(), 4)), vec![3, 1]);
+        assert!(truncate_batches(batches(), 0).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_stream_rows_prunes_columns_and_files() {
+        use crate::{RequestLimits, testing::TestWarehouse};
+        use arrow_array::{Int64Array, StringArray};
+        use iceberg::{expr::Reference, spec::Datum};
+
+        let users = |ids: Vec<i64>| {
+            let names: Vec<String> = ids.iter().map(|id| format!("user_{}", id)).collect();
+            RecordBatch::try_new(
+                Arc::new(Schema::new(vec![
+                    Field::new("id", DataType::Int64, false),
+                    Field::new("name", DataType::Utf8, true),
+                ])),
+                vec![
+                    Arc::new(Int64Array::from(ids)),
+                    Arc::new(StringArray::from(names)),
+                ],
+            )
+            .unwrap()
+        };
+        let warehouse = TestWarehouse::new().await.unwrap();
+        let table = warehouse
+            .create_table(&["db"], "users", &users(vec![1, 2]))
+            .await
+            .unwrap();
+        let table = warehouse.append(&table, &users(vec![3, 4])).await.unwrap();
+        let source = IcebergSource::new(
+            table.config(),
+            None,
+            RequestLimiter::new(RequestLimits::unlimited()),
+        )
+        .unwrap();
+
+        let read = |request: ReadRequest| {
+            let source = &source;
+            async move {
+                let mut batches = Vec::new();
+                source
+                    .stream_rows(&request, &mut |batch| {
+                        batches.push(batch);
+                        Ok(())
+                    })
+                    .await
+                    .unwrap();
+                batches
+            }
+        };
+
+        // Columns the table does not have are left out of the projection
+        let columns = Some(vec!["id".to_string(), "missing".to_string()]);
+        let batches = read(ReadRequest::new(ReadRange::Current, 10).with_columns(columns)).await;
+        assert_eq!(row_counts(&batches).iter().sum::<usize>(), 4);
+        assert!(batches.iter().all(|batch| batch.num_columns() == 1));
+
+        // The first file's statistics rule it out
+        let filter = Reference::new("id").greater_than_or_equal_to(Datum::long(3));
+        let batches =
+            read(ReadRequest::new(ReadRange::Current, 10).with_filter(Some(filter))).await;
+        assert_eq!(row_counts(&batches).iter().sum::<usize>(), 2);
+        assert!(batches.iter().all(|batch| batch.num_columns() == 2));
+    }
+}
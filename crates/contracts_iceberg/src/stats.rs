@@ -0,0 +1,177 @@
+//! Column statistics summed over the data files of a snapshot.
+//!
+//! Every manifest entry records the file's row count and, per column, its
+//! null count and lower and upper bounds. [`table_stats`] adds them up into
+//! the [`ColumnStats`] that
+//! [`check_column_stats`](contracts_validator::check_column_stats) evaluates,
+//! so a whole table is checked by reading its manifests only.
+
+use crate::IcebergError;
+use chrono::DateTime;
+use contracts_core::ColumnStats;
+use iceberg::{
+    spec::{
+        DataContentType, Datum, ManifestContentType, PrimitiveLiteral, PrimitiveType, Snapshot,
+    },
+    table::Table,
+};
+use std::collections::{BTreeMap, HashMap};
+use tracing::debug;
+
+/// Row count and column statistics of a snapshot's live data files.
+#[derive(Debug, Default)]
+pub(crate) struct TableStats {
+    /// Rows in the data files, before row-level deletes are applied
+    pub records: u64,
+    /// Statistics of every column all data files have null counts for
+    pub columns: BTreeMap<String, ColumnStats>,
+}
+
+#[derive(Debug, Default)]
+struct ColumnAccumulator {
+    nulls: u64,
+    /// Set once a file has no null count for the column
+    missing_nulls: bool,
+    lower: Option<Datum>,
+    upper: Option<Datum>,
+    /// Set once a file with non-null values has no bounds for the column
+    missing_bounds: bool,
+}
+
+/// Sums the statistics of the data files of `snapshot`.
+///
+/// Deleted rows are still counted: statistics are written with the data
+/// files, and delete files carry none for the rows they remove.
+pub(crate) async fn table_stats(
+    table: &Table,
+    snapshot: &Snapshot,
+) -> Result<TableStats, IcebergError> {
+    let metadata = table.metadata();
+    let schema = snapshot
+        .schema(metadata)
+        .map_err(|e| IcebergError::data_read("Failed to load snapshot schema", e))?;
+    let manifests = snapshot
+        .load_manifest_list(table.file_io(), metadata)
+        .await
+        .map_err(|e| IcebergError::data_read("Failed to load manifest list", e))?;
+
+    let mut records = 0;
+    let mut files = 0;
+    let mut columns: HashMap<i32, ColumnAccumulator> = schema
+        .field_id_to_name_map()
+        .keys()
+        .map(|id| (*id, ColumnAccumulator::default()))
+        .collect();
+    for manifest in manifests.entries() {
+        if manifest.content != ManifestContentType::Data {
+            continue;
+        }
+        let manifest = manifest
+            .load_manifest(table.file_io())
+            .await
+            .map_err(|e| IcebergError::data_read("Failed to load manifest", e))?;
+        for entry in manifest.entries() {
+            let file = entry.data_file();
+            if !entry.is_alive() || file.content_type() != DataContentType::Data {
+                continue;
+            }
+            records += file.record_count();
+            files += 1;
+
+            // A column is only known if every file has statistics for it
+            for (id, column) in &mut columns {
+                let Some(nulls) = file.null_value_counts().get(id) else {
+                    column.missing_nulls = true;
+                    continue;
+                };
+                column.nulls += nulls;
+                if *nulls == file.record_count() {
+                    continue;
+                }
+                match (file.lower_bounds().get(id), file.upper_bounds().get(id)) {
+                    (Some(lower), Some(upper)) => {
+                        if column.lower.as_ref().is_none_or(|min| lower < min) {
+                            column.lower = Some(lower.clone());
+                        }
+                        if column.upper.as_ref().is_none_or(|max| upper > max) {
+                            column.upper = Some(upper.clone());
+                        }
+                    }
+                    _ => column.missing_bounds = true,
+                }
+            }
+        }
+    }
+    debug!(
+        "Summed statistics of {} data file(s) with {} rows",
+        files, records
+    );
+
+    let columns = columns
+        .into_iter()
+        .filter(|(_, column)| !column.missing_nulls)
+        .filter_map(|(id, column)| {
+            let name = schema.name_by_field_id(id)?.to_string();
+            let (min, max) = match column.missing_bounds {
+                true => (None, None),
+                false => (
+                    column.lower.as_ref().and_then(render),
+                    column.upper.as_ref().and_then(render),
+                ),
+            };
+            let stats = ColumnStats {
+                null_count: column.nulls as usize,
+                min,
+                max,
+                // Iceberg does not keep distinct counts per file
+                distinct_count: 0,
+                distinct_exact: false,
+            };
+            Some((name, stats))
+        })
+        .collect();
+    Ok(TableStats { records, columns })
+}
+
+/// Renders a numeric or temporal bound the way rows are rendered.
+///
+/// Bounds of other types, such as truncated string prefixes, are left out.
+fn render(bound: &Datum) -> Option<String> {
+    let micros = |value: i64| DateTime::from_timestamp_micros(value).map(|ts| ts.to_rfc3339());
+    match (bound.data_type(), bound.literal()) {
+        (PrimitiveType::Timestamp | PrimitiveType::Timestamptz, PrimitiveLiteral::Long(value)) => {
+            micros(*value)
+        }
+        (
+            PrimitiveType::TimestampNs | PrimitiveType::TimestamptzNs,
+            PrimitiveLiteral::Long(value),
+        ) => Some(DateTime::from_timestamp_nanos(*value).to_rfc3339()),
+        (
+            PrimitiveType::Int
+            | PrimitiveType::Long
+            | PrimitiveType::Float
+            | PrimitiveType::Double
+            | PrimitiveType::Decimal { .. }
+            | PrimitiveType::Date,
+            _,
+        ) => Some(bound.to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_bounds() {
+        assert_eq!(render(&Datum::long(42)).as_deref(), Some("42"));
+        assert_eq!(render(&Datum::double(-1.5)).as_deref(), Some("-1.5"));
+        assert_eq!(render(&Datum::date(19_723)).as_deref(), Some("2024-01-01"));
+        assert_eq!(
+            render(&Datum::timestamptz_micros(1_704_067_200_000_000)).as_deref(),
+            Some("2024-01-01T00:00:00+00:00")
+        );
+        assert_eq!(render(&Datum::string("abc")), None);
+    }
+}
@@ -0,0 +1,245 @@
+//! Request limits on the storage reads of loaded tables.
+//!
+//! Scans, manifest reads and the native DataFusion engine all read through
+//! the [`FileIO`] of a [`Table`]. [`limit_table`] swaps it for one whose
+//! every storage request waits for the shared [`RequestLimiter`].
+
+use crate::{IcebergError, RequestLimiter};
+use async_trait::async_trait;
+use bytes::Bytes;
+use iceberg::io::{
+    FileIO, FileIOBuilder, FileMetadata, FileRead, FileWrite, InputFile, OutputFile, Storage,
+    StorageConfig, StorageFactory,
+};
+use iceberg::table::Table;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::ops::Range;
+use std::sync::Arc;
+
+/// Returns `table` with its storage requests limited by `limiter`.
+pub(crate) fn limit_table(table: Table, limiter: &RequestLimiter) -> Result<Table, IcebergError> {
+    let file_io = FileIOBuilder::new(Arc::new(LimitedStorageFactory {
+        file_io: table.file_io().clone(),
+        limiter: limiter.clone(),
+    }))
+    .with_props(table.file_io().config().props().clone())
+    .build();
+
+    let builder = Table::builder()
+        .metadata(table.metadata_ref())
+        .identifier(table.identifier().clone())
+        .readonly(table.readonly())
+        .file_io(file_io);
+    let builder = match table.metadata_location() {
+        Some(location) => builder.metadata_location(location),
+        None => builder,
+    };
+    builder
+        .build()
+        .map_err(|e| IcebergError::Other(format!("Failed to limit table storage: {}", e)))
+}
+
+/// Builds [`LimitedStorage`] over the storage of an existing [`FileIO`].
+#[derive(Debug)]
+struct LimitedStorageFactory {
+    file_io: FileIO,
+    limiter: RequestLimiter,
+}
+
+#[typetag::serde]
+impl StorageFactory for LimitedStorageFactory {
+    fn build(&self, _config: &StorageConfig) -> iceberg::Result<Arc<dyn Storage>> {
+        Ok(Arc::new(LimitedStorage {
+            file_io: self.file_io.clone(),
+            limiter: self.limiter.clone(),
+        }))
+    }
+}
+
+/// Storage that waits for a [`RequestLimiter`] before each request.
+///
+/// Readers and writers take a slot per range read or chunk written, rather
+/// than for their whole lifetime.
+#[derive(Debug, Clone)]
+struct LimitedStorage {
+    file_io: FileIO,
+    limiter: RequestLimiter,
+}
+
+#[async_trait]
+#[typetag::serde]
+impl Storage for LimitedStorage {
+    async fn exists(&self, path: &str) -> iceberg::Result<bool> {
+        let _permit = self.limiter.acquire().await;
+        self.file_io.exists(path).await
+    }
+
+    async fn metadata(&self, path: &str) -> iceberg::Result<FileMetadata> {
+        let _permit = self.limiter.acquire().await;
+        self.file_io.new_input(path)?.metadata().await
+    }
+
+    async fn read(&self, path: &str) -> iceberg::Result<Bytes> {
+        let _permit = self.limiter.acquire().await;
+        self.file_io.new_input(path)?.read().await
+    }
+
+    async fn reader(&self, path: &str) -> iceberg::Result<Box<dyn FileRead>> {
+        let reader = {
+            let _permit = self.limiter.acquire().await;
+            self.file_io.new_input(path)?.reader().await?
+        };
+        Ok(Box::new(LimitedFileRead {
+            reader,
+            limiter: self.limiter.clone(),
+        }))
+    }
+
+    async fn write(&self, path: &str, bs: Bytes) -> iceberg::Result<()> {
+        let _permit = self.limiter.acquire().await;
+        self.file_io.new_output(path)?.write(bs).await
+    }
+
+    async fn writer(&self, path: &str) -> iceberg::Result<Box<dyn FileWrite>> {
+        let writer = {
+            let _permit = self.limiter.acquire().await;
+            self.file_io.new_output(path)?.writer().await?
+        };
+        Ok(Box::new(LimitedFileWrite {
+            writer,
+            limiter: self.limiter.clone(),
+        }))
+    }
+
+    async fn delete(&self, path: &str) -> iceberg::Result<()> {
+        let _permit = self.limiter.acquire().await;
+        self.file_io.delete(path).await
+    }
+
+    async fn delete_prefix(&self, path: &str) -> iceberg::Result<()> {
+        let _permit = self.limiter.acquire().await;
+        self.file_io.delete_prefix(path).await
+    }
+
+    fn new_input(&self, path: &str) -> iceberg::Result<InputFile> {
+        Ok(InputFile::new(Arc::new(self.clone()), path.to_string()))
+    }
+
+    fn new_output(&self, path: &str) -> iceberg::Result<OutputFile> {
+        Ok(OutputFile::new(Arc::new(self.clone()), path.to_string()))
+    }
+}
+
+struct LimitedFileRead {
+    reader: Box<dyn FileRead>,
+    limiter: RequestLimiter,
+}
+
+#[async_trait]
+impl FileRead for LimitedFileRead {
+    async fn read(&self, range: Range<u64>) -> iceberg::Result<Bytes> {
+        let _permit = self.limiter.acquire().await;
+        self.reader.read(range).await
+    }
+}
+
+struct LimitedFileWrite {
+    writer: Box<dyn FileWrite>,
+    limiter: RequestLimiter,
+}
+
+#[async_trait]
+impl FileWrite for LimitedFileWrite {
+    async fn write(&mut self, bs: Bytes) -> iceberg::Result<()> {
+        let _permit = self.limiter.acquire().await;
+        self.writer.write(bs).await
+    }
+
+    async fn close(&mut self) -> iceberg::Result<()> {
+        let _permit = self.limiter.acquire().await;
+        self.writer.close().await
+    }
+}
+
+// Storage must be serializable for iceberg's typetag registry, but a
+// limiter only means something within the process that created it.
+macro_rules! local_only {
+    ($($name:ident),*) => {$(
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+                Err(serde::ser::Error::custom(concat!(
+                    stringify!($name),
+                    " cannot be serialized"
+                )))
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(_deserializer: D) -> Result<Self, D::Error> {
+                Err(serde::de::Error::custom(concat!(
+                    stringify!($name),
+                    " cannot be deserialized"
+                )))
+            }
+        }
+    )*};
+}
+
+local_only!(LimitedStorage, LimitedStorageFactory);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RequestLimits;
+    use crate::testing::TestWarehouse;
+    use arrow_array::{Int64Array, RecordBatch};
+    use arrow_schema::{DataType, Field, Schema};
+    use futures::TryStreamExt;
+    use iceberg::{Catalog, TableIdent};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_limited_table_reads_wait_for_the_limiter() {
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![Field::new("id", DataType::Int64, false)])),
+            vec![Arc::new(Int64Array::from(vec![1, 2, 3]))],
+        )
+        .unwrap();
+        let warehouse = TestWarehouse::new().await.unwrap();
+        let created = warehouse
+            .create_table(&["db"], "events", &batch)
+            .await
+            .unwrap();
+        let table = warehouse
+            .catalog()
+            .load_table(&TableIdent::from_strs(["db", "events"]).unwrap())
+            .await
+            .unwrap();
+        let limiter = RequestLimiter::new(RequestLimits::unlimited().max_concurrent(1));
+        let table = limit_table(table, &limiter).unwrap();
+        let metadata = created.metadata_location;
+
+        let held = limiter.acquire().await;
+        let blocked = tokio::time::timeout(
+            Duration::from_millis(50),
+            table.file_io().new_input(&metadata).unwrap().read(),
+        )
+        .await;
+        assert!(blocked.is_err(), "read ran while the only slot was held");
+        drop(held);
+
+        // A whole scan takes one slot at a time, so it completes under a
+        // limit of one
+        let batches: Vec<RecordBatch> = table
+            .scan()
+            .build()
+            .unwrap()
+            .to_arrow()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(batches.iter().map(RecordBatch::num_rows).sum::<usize>(), 3);
+    }
+}
@@ -0,0 +1,495 @@
+//! In-process Iceberg warehouses for tests.
+//!
+//! [`TestWarehouse`] runs an in-memory catalog over a temporary local
+//! directory and writes small Iceberg tables from Arrow record batches, so the
+//! validator and CLI can be tested end to end without an external catalog.
+//! [`MockTable`] skips storage entirely and serves a schema and batches from
+//! memory through the [`TableLoader`] and [`DataReader`] traits.
+//!
+//! Requires the `testing` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use arrow_array::{Int64Array, RecordBatch, StringArray};
+//! use arrow_schema::{DataType, Field, Schema};
+//! use contracts_iceberg::{IcebergValidator, testing::TestWarehouse};
+//! use std::sync::Arc;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let schema = Arc::new(Schema::new(vec![
+//!     Field::new("id", DataType::Int64, false),
+//!     Field::new("name", DataType::Utf8, true),
+//! ]));
+//! let batch = RecordBatch::try_new(
+//!     schema,
+//!     vec![
+//!         Arc::new(Int64Array::from(vec![1, 2])),
+//!         Arc::new(StringArray::from(vec![Some("a"), None])),
+//!     ],
+//! )?;
+//!
+//! let warehouse = TestWarehouse::new().await?;
+//! let table = warehouse.create_table(&["sales"], "orders", &batch).await?;
+//!
+//! let validator = IcebergValidator::new(table.config()).await?;
+//! let data = validator.read_sample_data(10).await?;
+//! assert_eq!(data.len(), 2);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::source::truncate_batches;
+use crate::{DataReader, IcebergConfig, IcebergError, TableLoader};
+use arrow_array::RecordBatch;
+use async_trait::async_trait;
+use iceberg::arrow::{arrow_schema_to_schema_auto_assign_ids, schema_to_arrow_schema};
+use iceberg::io::LocalFsStorageFactory;
+use iceberg::memory::{MEMORY_CATALOG_WAREHOUSE, MemoryCatalogBuilder};
+use iceberg::spec::{
+    DataFileFormat, Literal, PartitionKey, Schema, SchemaRef, Struct, Transform,
+    UnboundPartitionSpec,
+};
+use iceberg::table::Table;
+use iceberg::transaction::{ApplyTransactionAction, Transaction};
+use iceberg::writer::base_writer::data_file_writer::DataFileWriterBuilder;
+use iceberg::writer::file_writer::ParquetWriterBuilder;
+use iceberg::writer::file_writer::location_generator::{
+    DefaultFileNameGenerator, DefaultLocationGenerator,
+};
+use iceberg::writer::file_writer::rolling_writer::RollingFileWriterBuilder;
+use iceberg::writer::{IcebergWriter, IcebergWriterBuilder};
+use iceberg::{Catalog, CatalogBuilder, MemoryCatalog, NamespaceIdent, TableCreation, TableIdent};
+use parquet::file::properties::WriterProperties;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tempfile::TempDir;
+
+/// A temporary warehouse with an in-memory catalog.
+///
+/// Table files live in a temporary directory that is removed on drop.
+pub struct TestWarehouse {
+    dir: TempDir,
+    catalog: MemoryCatalog,
+}
+
+/// A table written by [`TestWarehouse`].
+#[derive(Debug, Clone)]
+pub struct TestTable {
+    /// Namespace levels
+    pub namespace: Vec<String>,
+
+    /// Table name
+    pub name: String,
+
+    /// Location of the current metadata file
+    pub metadata_location: String,
+}
+
+impl TestWarehouse {
+    /// Creates an empty warehouse in a new temporary directory.
+    pub async fn new() -> Result<Self, IcebergError> {
+        let dir = TempDir::new()
+            .map_err(|e| IcebergError::Other(format!("Failed to create warehouse dir: {}", e)))?;
+
+        let catalog = MemoryCatalogBuilder::default()
+            .with_storage_factory(Arc::new(LocalFsStorageFactory))
+            .load(
+                "test",
+                HashMap::from([(
+                    MEMORY_CATALOG_WAREHOUSE.to_string(),
+                    dir.path().display().to_string(),
+                )]),
+            )
+            .await
+            .map_err(catalog_error)?;
+
+        Ok(Self { dir, catalog })
+    }
+
+    /// Returns the warehouse directory.
+    pub fn path(&self) -> &Path {
+        self.dir.path()
+    }
+
+    /// Returns the in-memory catalog holding the warehouse tables.
+    pub fn catalog(&self) -> &MemoryCatalog {
+        &self.catalog
+    }
+
+    /// Creates a table with the schema of `batch` and writes `batch` to it.
+    ///
+    /// The namespace is created if needed. Field IDs are assigned
+    /// automatically, so `batch` does not need Iceberg field metadata.
+    pub async fn create_table(
+        &self,
+        namespace: &[&str],
+        name: &str,
+        batch: &RecordBatch,
+    ) -> Result<TestTable, IcebergError> {
+        self.create(namespace, name, batch, None).await
+    }
+
+    /// Creates a table partitioned by `transform` of `column`, and writes
+    /// `batch` to it as the partition with value `partition`.
+    ///
+    /// The partition value is not checked against the rows of `batch`.
+    pub async fn create_partitioned_table(
+        &self,
+        namespace: &[&str],
+        name: &str,
+        batch: &RecordBatch,
+        column: &str,
+        transform: Transform,
+        partition: Literal,
+    ) -> Result<TestTable, IcebergError> {
+        self.create(namespace, name, batch, Some((column, transform, partition)))
+            .await
+    }
+
+    async fn create(
+        &self,
+        namespace: &[&str],
+        name: &str,
+        batch: &RecordBatch,
+        partitioning: Option<(&str, Transform, Literal)>,
+    ) -> Result<TestTable, IcebergError> {
+        let namespace_ident = NamespaceIdent::from_strs(namespace).map_err(config_error)?;
+        if !self
+            .catalog
+            .namespace_exists(&namespace_ident)
+            .await
+            .map_err(catalog_error)?
+        {
+            self.catalog
+                .create_namespace(&namespace_ident, HashMap::new())
+                .await
+                .map_err(catalog_error)?;
+        }
+
+        let schema = arrow_schema_to_schema_auto_assign_ids(batch.schema().as_ref())
+            .map_err(config_error)?;
+        let (spec, partition) = match partitioning {
+            Some((column, transform, partition)) => {
+                let source = schema.field_by_name(column).ok_or_else(|| {
+                    IcebergError::ConfigurationError(format!(
+                        "No column '{}' to partition by",
+                        column
+                    ))
+                })?;
+                let spec = UnboundPartitionSpec::builder()
+                    .add_partition_field(source.id, format!("{}_{}", column, transform), transform)
+                    .map_err(config_error)?
+                    .build();
+                (Some(spec), Some(partition))
+            }
+            None => (None, None),
+        };
+        let creation = TableCreation::builder()
+            .name(name.to_string())
+            .schema(schema)
+            .partition_spec_opt(spec)
+            .build();
+        let table = self
+            .catalog
+            .create_table(&namespace_ident, creation)
+            .await
+            .map_err(catalog_error)?;
+
+        self.write(table, batch, partition).await
+    }
+
+    /// Appends `batch` to an existing table as a new snapshot.
+    pub async fn append(
+        &self,
+        table: &TestTable,
+        batch: &RecordBatch,
+    ) -> Result<TestTable, IcebergError> {
+        self.append_to(table, batch, None).await
+    }
+
+    /// Appends `batch` to a table made with
+    /// [`create_partitioned_table`](Self::create_partitioned_table) as the
+    /// partition with value `partition`.
+    pub async fn append_partition(
+        &self,
+        table: &TestTable,
+        batch: &RecordBatch,
+        partition: Literal,
+    ) -> Result<TestTable, IcebergError> {
+        self.append_to(table, batch, Some(partition)).await
+    }
+
+    async fn append_to(
+        &self,
+        table: &TestTable,
+        batch: &RecordBatch,
+        partition: Option<Literal>,
+    ) -> Result<TestTable, IcebergError> {
+        let ident = TableIdent::from_strs(table.namespace.iter().chain([&table.name]))
+            .map_err(config_error)?;
+        let table = self
+            .catalog
+            .load_table(&ident)
+            .await
+            .map_err(catalog_error)?;
+
+        self.write(table, batch, partition).await
+    }
+
+    /// Sets table properties of an existing table.
+    pub async fn set_properties(
+        &self,
+        table: &TestTable,
+        properties: impl IntoIterator<Item = (String, String)>,
+    ) -> Result<TestTable, IcebergError> {
+        let ident = TableIdent::from_strs(table.namespace.iter().chain([&table.name]))
+            .map_err(config_error)?;
+        let table = self
+            .catalog
+            .load_table(&ident)
+            .await
+            .map_err(catalog_error)?;
+
+        let transaction = Transaction::new(&table);
+        let action = properties.into_iter().fold(
+            transaction.update_table_properties(),
+            |action, (key, value)| action.set(key, value),
+        );
+        let transaction = action.apply(transaction).map_err(config_error)?;
+        let table = transaction
+            .commit(&self.catalog)
+            .await
+            .map_err(catalog_error)?;
+        test_table(&table)
+    }
+
+    /// Writes `batch` as a Parquet data file and commits it with a fast append.
+    async fn write(
+        &self,
+        table: Table,
+        batch: &RecordBatch,
+        partition: Option<Literal>,
+    ) -> Result<TestTable, IcebergError> {
+        let data_error =
+            |e: iceberg::Error| IcebergError::data_read("Failed to write test data", e);
+        let metadata = table.metadata();
+
+        // Rebuild the batch with the table schema, which carries field IDs
+        let arrow_schema = schema_to_arrow_schema(metadata.current_schema()).map_err(data_error)?;
+        let batch = RecordBatch::try_new(Arc::new(arrow_schema), batch.columns().to_vec())
+            .map_err(|e| IcebergError::data_read("Failed to write test data", e))?;
+
+        let location_generator =
+            DefaultLocationGenerator::new(metadata.clone()).map_err(data_error)?;
+        // File names restart at zero for every writer, so prefix them per snapshot
+        let file_name_generator = DefaultFileNameGenerator::new(
+            format!("data-{}", metadata.snapshots().count()),
+            None,
+            DataFileFormat::Parquet,
+        );
+        let parquet_writer = ParquetWriterBuilder::new(
+            WriterProperties::default(),
+            metadata.current_schema().clone(),
+        );
+        let rolling_writer = RollingFileWriterBuilder::new_with_default_file_size(
+            parquet_writer,
+            table.file_io().clone(),
+            location_generator,
+            file_name_generator,
+        );
+
+        let partition_key = partition.map(|value| {
+            PartitionKey::new(
+                metadata.default_partition_spec().as_ref().clone(),
+                metadata.current_schema().clone(),
+                Struct::from_iter([Some(value)]),
+            )
+        });
+        let mut writer = DataFileWriterBuilder::new(rolling_writer)
+            .build(partition_key)
+            .await
+            .map_err(data_error)?;
+        writer.write(batch).await.map_err(data_error)?;
+        let data_files = writer.close().await.map_err(data_error)?;
+
+        let transaction = Transaction::new(&table);
+        let transaction = transaction
+            .fast_append()
+            .add_data_files(data_files)
+            .apply(transaction)
+            .map_err(data_error)?;
+        let table = transaction
+            .commit(&self.catalog)
+            .await
+            .map_err(catalog_error)?;
+
+        test_table(&table)
+    }
+}
+
+/// Describes a committed table of the warehouse.
+fn test_table(table: &Table) -> Result<TestTable, IcebergError> {
+    let ident = table.identifier();
+    Ok(TestTable {
+        namespace: ident.namespace().clone().inner(),
+        name: ident.name().to_string(),
+        metadata_location: table
+            .metadata_location()
+            .ok_or_else(|| IcebergError::Other("Table has no metadata location".to_string()))?
+            .to_string(),
+    })
+}
+
+impl TestTable {
+    /// Returns a FileIO configuration that reads this table's current snapshot.
+    pub fn config(&self) -> IcebergConfig {
+        IcebergConfig::builder()
+            .file_io()
+            .namespace(self.namespace.clone())
+            .table_name(self.name.clone())
+            .property("metadata_location", self.metadata_location.clone())
+            .build()
+            .expect("test table configuration is complete")
+    }
+}
+
+/// An in-memory table for [`IcebergValidator::with_source`](crate::IcebergValidator::with_source).
+///
+/// Serves a fixed schema and fixed record batches, and counts how often data
+/// was read.
+///
+/// # Example
+///
+/// ```rust
+/// use arrow_array::{Int64Array, RecordBatch};
+/// use arrow_schema::{DataType, Field, Schema};
+/// use contracts_iceberg::{IcebergConfig, IcebergValidator, testing::MockTable};
+/// use std::sync::Arc;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let batch = RecordBatch::try_new(
+///     Arc::new(Schema::new(vec![Field::new("id", DataType::Int64, false)])),
+///     vec![Arc::new(Int64Array::from(vec![1, 2, 3]))],
+/// )?;
+/// let table = Arc::new(MockTable::from_batches(vec![batch])?);
+///
+/// let config = IcebergConfig::builder()
+///     .file_io()
+///     .namespace(vec!["db".to_string()])
+///     .table_name("ids")
+///     .build()?;
+/// let validator = IcebergValidator::with_source(config, table.clone(), table.clone())?;
+///
+/// assert_eq!(validator.read_sample_data(2).await?.len(), 2);
+/// assert_eq!(table.reads(), 1);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct MockTable {
+    schema: SchemaRef,
+    batches: Vec<RecordBatch>,
+    reads: AtomicUsize,
+}
+
+impl MockTable {
+    /// Creates a table with an Iceberg schema and the batches it contains.
+    pub fn new(schema: Schema, batches: Vec<RecordBatch>) -> Self {
+        Self {
+            schema: Arc::new(schema),
+            batches,
+            reads: AtomicUsize::new(0),
+        }
+    }
+
+    /// Creates a table whose schema is derived from the first batch.
+    ///
+    /// Field IDs are assigned automatically.
+    pub fn from_batches(batches: Vec<RecordBatch>) -> Result<Self, IcebergError> {
+        let first = batches.first().ok_or_else(|| {
+            IcebergError::ConfigurationError("MockTable needs at least one batch".to_string())
+        })?;
+        let schema = arrow_schema_to_schema_auto_assign_ids(first.schema().as_ref())
+            .map_err(config_error)?;
+
+        Ok(Self::new(schema, batches))
+    }
+
+    /// Returns how many times data was read from this table.
+    pub fn reads(&self) -> usize {
+        self.reads.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait]
+impl TableLoader for MockTable {
+    async fn load_schema(&self) -> Result<SchemaRef, IcebergError> {
+        Ok(self.schema.clone())
+    }
+}
+
+#[async_trait]
+impl DataReader for MockTable {
+    async fn read_batches(&self, limit: usize) -> Result<Vec<RecordBatch>, IcebergError> {
+        self.reads.fetch_add(1, Ordering::SeqCst);
+        Ok(truncate_batches(self.batches.clone(), limit))
+    }
+}
+
+fn config_error(e: iceberg::Error) -> IcebergError {
+    IcebergError::ConfigurationError(e.to_string())
+}
+
+fn catalog_error(e: iceberg::Error) -> IcebergError {
+    IcebergError::connection("Test catalog request failed", e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{Int64Array, StringArray};
+    use arrow_schema::{DataType, Field, Schema};
+
+    fn batch(ids: Vec<i64>) -> RecordBatch {
+        let names: Vec<String> = ids.iter().map(|id| format!("user_{}", id)).collect();
+        RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("id", DataType::Int64, false),
+                Field::new("name", DataType::Utf8, true),
+            ])),
+            vec![
+                Arc::new(Int64Array::from(ids)),
+                Arc::new(StringArray::from(names)),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_create_and_append() {
+        let warehouse = TestWarehouse::new().await.unwrap();
+        let table = warehouse
+            .create_table(&["db"], "users", &batch(vec![1, 2, 3]))
+            .await
+            .unwrap();
+
+        assert_eq!(table.namespace, vec!["db"]);
+        assert!(
+            table
+                .metadata_location
+                .starts_with(&*warehouse.path().to_string_lossy())
+        );
+
+        let appended = warehouse.append(&table, &batch(vec![4])).await.unwrap();
+        assert_ne!(appended.metadata_location, table.metadata_location);
+
+        let ident = TableIdent::from_strs(["db", "users"]).unwrap();
+        let loaded = warehouse.catalog().load_table(&ident).await.unwrap();
+        assert_eq!(loaded.metadata().snapshots().count(), 2);
+    }
+}
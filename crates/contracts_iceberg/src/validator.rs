@@ -1,31 +1,68 @@
 //! Main Iceberg validator implementation.
 
 use crate::{
-    IcebergError,
-    catalog::{build_file_io, create_table_ident, load_catalog},
+    CatalogCache, DataReader, IcebergError, RequestLimiter, TableLoader,
+    catalog::load_catalog,
+    column_access,
     config::{CatalogType, IcebergConfig},
-    converter::arrow_value_to_data_value,
+    converter::{BatchConverter, ConversionError},
+    freshness::{metadata_freshness, updated_at, without_metadata_freshness},
+    identifiers::{check_identifiers, identifier_fields, with_identifier_uniqueness},
+    pushdown::{read_columns, window_filter},
     schema::extract_schema_from_iceberg,
+    snapshot::SnapshotInfo,
+    source::{IcebergSource, ReadRange, ReadRequest},
+    stats::table_stats,
+    wap::{StagedRef, StagedSnapshot},
 };
-use contracts_core::{Contract, ValidationContext, ValidationReport};
-use contracts_validator::{DataSet, DataValidator};
-use futures::TryStreamExt;
-use iceberg::{
-    Catalog,
-    io::FileIO,
-    table::{StaticTable, Table},
+use arrow_array::RecordBatch;
+use contracts_core::{
+    AccessDrift, CheckKind, Contract, DeleteStats, SamplingStrategy, SchemaDrift, Severity,
+    ValidationContext, ValidationReport, Violation, codes,
 };
-use std::collections::HashMap;
+use contracts_validator::{
+    DataRow, DataSet, DataValidator, StatsCollector, StreamingValidator, check_column_stats,
+    check_metadata_freshness,
+};
+use std::collections::BTreeMap;
+use std::sync::Arc;
 use tracing::{debug, info, warn};
 
+/// How sampling handles values that cannot be converted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConversionErrorPolicy {
+    /// Fail the read on the first bad value (the default)
+    #[default]
+    Fail,
+
+    /// Skip rows with bad values and report them in [`SampleData::skipped`]
+    Skip,
+}
+
+/// Rows sampled from a table, with the rows that had to be skipped.
+#[derive(Debug, Clone)]
+pub struct SampleData {
+    /// Successfully converted rows
+    pub data: DataSet,
+
+    /// One error per skipped row, under [`ConversionErrorPolicy::Skip`]
+    pub skipped: Vec<ConversionError>,
+}
+
 /// Validator for Apache Iceberg tables against data contracts.
 ///
 /// Provides functionality to connect to Iceberg tables, extract schemas,
 /// read data, and validate against DCE contracts.
+#[derive(Clone)]
 pub struct IcebergValidator {
     config: IcebergConfig,
-    catalog: Option<Box<dyn Catalog>>,
-    file_io: Option<FileIO>,
+    loader: Arc<dyn TableLoader>,
+    reader: Arc<dyn DataReader>,
+    conversion_policy: ConversionErrorPolicy,
+    base_snapshot: Option<i64>,
+    snapshot: Option<i64>,
+    chunked: bool,
+    sampling: SamplingStrategy,
 }
 
 impl IcebergValidator {
@@ -50,63 +87,251 @@ impl IcebergValidator {
         // Load catalog if not FileIO
         let catalog = match &config.catalog {
             CatalogType::FileIO => None,
-            _ => Some(load_catalog(&config).await?),
+            _ => Some(Arc::from(load_catalog(&config).await?)),
         };
 
-        // Only build FileIO for FileIO catalog type (local filesystem access).
-        // Catalog-based paths (REST, Glue, HMS) handle storage access internally.
-        let file_io = match &config.catalog {
-            CatalogType::FileIO => Some(build_file_io(config.warehouse())?),
-            _ => None,
-        };
+        Self::from_catalog(config, catalog, RequestLimiter::default())
+    }
+
+    /// Creates a validator that takes its catalog client from `cache`.
+    ///
+    /// Validators created from the same cache share one client per distinct
+    /// catalog connection, so validating many tables connects only once.
+    /// They also share the cache's [`RequestLimiter`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid or connection fails.
+    pub async fn with_cache(
+        config: IcebergConfig,
+        cache: &CatalogCache,
+    ) -> Result<Self, IcebergError> {
+        config.validate()?;
+
+        let catalog = cache.get_or_load(&config).await?;
+        Self::from_catalog(config, catalog, cache.limiter().clone())
+    }
+
+    /// Creates a validator that reads the table through custom sources.
+    ///
+    /// No catalog is contacted: the schema comes from `loader` and sample
+    /// data from `reader`. This is how tests inject in-memory tables such as
+    /// [`MockTable`](crate::testing::MockTable).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid.
+    pub fn with_source(
+        config: IcebergConfig,
+        loader: Arc<dyn TableLoader>,
+        reader: Arc<dyn DataReader>,
+    ) -> Result<Self, IcebergError> {
+        config.validate()?;
 
         Ok(Self {
             config,
-            catalog,
-            file_io,
+            loader,
+            reader,
+            conversion_policy: ConversionErrorPolicy::default(),
+            base_snapshot: None,
+            snapshot: None,
+            chunked: false,
+            sampling: SamplingStrategy::default(),
         })
     }
 
-    /// Loads the Iceberg table from the configured location.
+    fn from_catalog(
+        config: IcebergConfig,
+        catalog: Option<Arc<dyn iceberg::Catalog>>,
+        limiter: RequestLimiter,
+    ) -> Result<Self, IcebergError> {
+        let source = Arc::new(IcebergSource::new(config.clone(), catalog, limiter)?);
+
+        Ok(Self {
+            config,
+            loader: source.clone(),
+            reader: source,
+            conversion_policy: ConversionErrorPolicy::default(),
+            base_snapshot: None,
+            snapshot: None,
+            chunked: false,
+            sampling: SamplingStrategy::default(),
+        })
+    }
+
+    /// Sets how sampling handles values that cannot be converted.
+    ///
+    /// Applies to [`read_sample_data`](Self::read_sample_data), and to
+    /// validation when the `native-datafusion` feature is disabled.
+    pub fn with_conversion_policy(mut self, policy: ConversionErrorPolicy) -> Self {
+        self.conversion_policy = policy;
+        self
+    }
+
+    /// Only reads the rows of data files added after snapshot `snapshot_id`.
     ///
-    /// Supports both catalog-based loading (REST, Glue, HMS) and direct FileIO loading.
-    async fn load_table(&self) -> Result<Table, IcebergError> {
-        let table_ident = create_table_ident(&self.config.namespace, &self.config.table_name)?;
+    /// Validation then covers what was committed since that snapshot
+    /// instead of the whole table; rows rewritten by compaction count as
+    /// added. Applies to every read, including
+    /// [`read_sample_data`](Self::read_sample_data).
+    pub fn with_base_snapshot(mut self, snapshot_id: i64) -> Self {
+        self.base_snapshot = Some(snapshot_id);
+        self
+    }
 
-        info!("Loading Iceberg table: {}", table_ident);
+    /// Validates the table as of snapshot `snapshot_id` instead of its
+    /// current snapshot.
+    ///
+    /// The snapshot does not need to be on `main`: this is how snapshots
+    /// staged on a branch or with a WAP ID are audited before they are
+    /// published. Takes precedence over the configuration's `snapshot`; with
+    /// a [base snapshot](Self::with_base_snapshot), only the rows added
+    /// between the two are read.
+    pub fn with_snapshot(mut self, snapshot_id: i64) -> Self {
+        self.snapshot = Some(snapshot_id);
+        self
+    }
 
-        if let Some(catalog) = &self.catalog {
-            // Load table from catalog
-            catalog
-                .load_table(&table_ident)
-                .await
-                .map_err(|e| IcebergError::TableNotFound(format!("{}: {}", table_ident, e)))
-        } else {
-            // For FileIO, we need a direct metadata file path
-            // This should be provided in the properties
-            let metadata_path =
-                self.config
-                    .properties
-                    .get("metadata_location")
-                    .ok_or_else(|| {
-                        IcebergError::ConfigurationError(
-                            "FileIO catalog requires 'metadata_location' property".to_string(),
-                        )
-                    })?;
-
-            info!("Loading table from metadata file: {}", metadata_path);
-
-            let file_io = self.file_io.clone().ok_or_else(|| {
-                IcebergError::ConfigurationError(
-                    "FileIO not available for FileIO catalog type".to_string(),
-                )
-            })?;
+    /// Validates rows batch by batch as they are read, instead of reading the
+    /// whole sample before validating it.
+    ///
+    /// Memory then stays proportional to the batch size rather than to the
+    /// sample, so much larger samples, or whole tables, can be validated.
+    /// Checks that need every row at once are not evaluated; see
+    /// [`StreamingValidator`] for which ones.
+    pub fn with_chunked_validation(mut self, chunked: bool) -> Self {
+        self.chunked = chunked;
+        self
+    }
 
-            StaticTable::from_metadata_file(metadata_path, table_ident, file_io)
-                .await
-                .map(|static_table| static_table.into_table())
-                .map_err(|e| IcebergError::TableNotFound(format!("Failed to load table: {}", e)))
-        }
+    /// Sets how [`read_sample_data`](Self::read_sample_data) chooses rows.
+    ///
+    /// [`validate_table`](Self::validate_table) takes the strategy from its
+    /// [`ValidationContext`] instead. Stratified samples of Iceberg tables are
+    /// stratified by partition.
+    pub fn with_sampling(mut self, sampling: SamplingStrategy) -> Self {
+        self.sampling = sampling;
+        self
+    }
+
+    /// Returns the table's current snapshot, or `None` for an empty table or
+    /// a source without snapshot history.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the table cannot be loaded.
+    pub async fn current_snapshot(&self) -> Result<Option<SnapshotInfo>, IcebergError> {
+        let Some(table) = self.loader.load_table().await? else {
+            return Ok(None);
+        };
+        Ok(table
+            .metadata()
+            .current_snapshot()
+            .map(|snapshot| SnapshotInfo::from(snapshot.as_ref())))
+    }
+
+    /// Finds a staged snapshot, and how it can be published to `main`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the table cannot be loaded, has no snapshot
+    /// history, or does not hold the staged snapshot.
+    pub async fn staged_snapshot(
+        &self,
+        staged: &StagedRef,
+    ) -> Result<StagedSnapshot, IcebergError> {
+        let table = self.loader.load_table().await?.ok_or_else(|| {
+            IcebergError::UnsupportedOperation(
+                "staged snapshots need a table with snapshot history".to_string(),
+            )
+        })?;
+        StagedSnapshot::resolve(table.metadata(), staged)
+    }
+
+    /// Returns the snapshot reads are pinned to: the one set with
+    /// [`with_snapshot`](Self::with_snapshot), or the one selected by the
+    /// configuration's `snapshot`.
+    async fn pinned_snapshot(&self) -> Result<Option<i64>, IcebergError> {
+        let selector = match (self.snapshot, self.config.snapshot) {
+            (Some(snapshot_id), _) => return Ok(Some(snapshot_id)),
+            (None, None) => return Ok(None),
+            (None, Some(selector)) => selector,
+        };
+        let table = self.loader.load_table().await?.ok_or_else(|| {
+            IcebergError::UnsupportedOperation(
+                "pinned snapshots need a table with snapshot history".to_string(),
+            )
+        })?;
+        selector.resolve(table.metadata()).map(Some)
+    }
+
+    /// Returns the validated snapshot: the pinned one, or the current one.
+    async fn validated_snapshot(&self) -> Result<Option<SnapshotInfo>, IcebergError> {
+        let Some(snapshot_id) = self.pinned_snapshot().await? else {
+            return self.current_snapshot().await;
+        };
+        let Some(table) = self.loader.load_table().await? else {
+            return Ok(None);
+        };
+        Ok(table
+            .metadata()
+            .snapshot_by_id(snapshot_id)
+            .map(|snapshot| SnapshotInfo::from(snapshot.as_ref())))
+    }
+
+    /// Returns the rows reads cover: the table at the pinned snapshot, or the
+    /// rows added since the base snapshot when one is set.
+    async fn read_range(&self) -> Result<ReadRange, IcebergError> {
+        Ok(match (self.pinned_snapshot().await?, self.base_snapshot) {
+            (Some(to), Some(from)) => ReadRange::Between(from, to),
+            (Some(snapshot_id), None) => ReadRange::At(snapshot_id),
+            (None, Some(snapshot_id)) => ReadRange::Since(snapshot_id),
+            (None, None) => ReadRange::Current,
+        })
+    }
+
+    /// Returns a request for at most `limit` rows of the range reads cover,
+    /// chosen by `sampling`.
+    async fn read_request(
+        &self,
+        limit: usize,
+        sampling: &SamplingStrategy,
+    ) -> Result<ReadRequest, IcebergError> {
+        Ok(ReadRequest::new(self.read_range().await?, limit).with_sampling(sampling.clone()))
+    }
+
+    /// Returns a read request like [`read_request`](Self::read_request),
+    /// pruned to the columns a validation of `contract` looks at and to the
+    /// context's time window.
+    async fn validation_request(
+        &self,
+        contract: &Contract,
+        context: &ValidationContext,
+        limit: usize,
+        sampling: &SamplingStrategy,
+    ) -> Result<ReadRequest, IcebergError> {
+        let filter = match context.time_window {
+            Some(_) => window_filter(contract, context, &*self.loader.load_schema().await?),
+            None => None,
+        };
+        Ok(self
+            .read_request(limit, sampling)
+            .await?
+            .with_columns(read_columns(contract, context))
+            .with_filter(filter))
+    }
+
+    /// Reads the rows of `request` into memory.
+    async fn read_batches(&self, request: &ReadRequest) -> Result<Vec<RecordBatch>, IcebergError> {
+        check_sampling(&request.sampling)?;
+        let mut batches = Vec::new();
+        self.reader
+            .stream_rows(request, &mut |batch| {
+                batches.push(batch);
+                Ok(())
+            })
+            .await?;
+        Ok(batches)
     }
 
     /// Extracts the schema from the Iceberg table.
@@ -115,8 +340,7 @@ impl IcebergValidator {
     ///
     /// Returns an error if the table cannot be loaded or schema extraction fails.
     pub async fn extract_schema(&self) -> Result<contracts_core::Schema, IcebergError> {
-        let table = self.load_table().await?;
-        let iceberg_schema = table.metadata().current_schema();
+        let iceberg_schema = self.loader.load_schema().await?;
 
         let location = self
             .config
@@ -137,7 +361,55 @@ impl IcebergValidator {
                 )
             });
 
-        extract_schema_from_iceberg(iceberg_schema, &location)
+        extract_schema_from_iceberg(&iceberg_schema, &location)
+    }
+
+    /// Compares the live schema of the table with the fields of a contract.
+    ///
+    /// Only the schema is loaded; no data is read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the table cannot be loaded or schema extraction fails.
+    pub async fn detect_drift(&self, contract: &Contract) -> Result<SchemaDrift, IcebergError> {
+        let table = self.extract_schema().await?;
+        let drift = SchemaDrift::compare(&contract.schema, &table);
+        debug!(
+            "Found {} schema drifts between contract {} and table {}.{}",
+            drift.changes.len(),
+            contract.name,
+            self.config.namespace.join("."),
+            self.config.table_name
+        );
+        Ok(drift)
+    }
+
+    /// Compares the access metadata in the table's properties with the
+    /// `access:` blocks of a contract (see [`column_access`]).
+    ///
+    /// Returns `None` when the table carries no access metadata, or the
+    /// source has no table to load it from. No data is read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the table cannot be loaded.
+    pub async fn detect_access_drift(
+        &self,
+        contract: &Contract,
+    ) -> Result<Option<AccessDrift>, IcebergError> {
+        let Some(table) = self.loader.load_table().await? else {
+            return Ok(None);
+        };
+        let actual = column_access(table.metadata().properties());
+        if actual.is_empty() {
+            debug!(
+                "Table {}.{} carries no access metadata",
+                self.config.namespace.join("."),
+                self.config.table_name
+            );
+            return Ok(None);
+        }
+        Ok(Some(AccessDrift::compare(&contract.schema, &actual)))
     }
 
     /// Validates an Iceberg table against a contract.
@@ -166,20 +438,78 @@ impl IcebergValidator {
             contract.name
         );
 
+        // Identifier values are checked for duplicates even without a
+        // uniqueness check in the contract.
+        let identifiers = identifier_fields(&*self.loader.load_schema().await?);
+        let checked = with_identifier_uniqueness(contract, &identifiers);
+        // Freshness read from table metadata is checked after the rows
+        let (checked, rows_context) = without_metadata_freshness(&checked, context);
+
+        if !context.schema_only {
+            check_sampling(&context.sampling)?;
+        }
+
         // Check if schema-only validation is requested
+        let mut report = if context.schema_only {
+            self.validate_schema_only(&checked, context).await?
+        } else if self.chunked {
+            self.validate_table_chunked(&checked, &rows_context).await?
+        } else {
+            #[cfg(feature = "native-datafusion")]
+            let report = self.validate_table_native(&checked, &rows_context).await?;
+
+            #[cfg(not(feature = "native-datafusion"))]
+            let report = self.validate_table_dataset(&checked, &rows_context).await?;
+
+            report
+        };
+
+        check_identifiers(contract, &identifiers, context.strict, &mut report);
         if context.schema_only {
-            return self.validate_schema_only(contract, context).await;
+            report.apply_limits(context);
+            return Ok(report);
         }
-
-        #[cfg(feature = "native-datafusion")]
-        {
-            return self.validate_table_native(contract, context).await;
+        report.base_snapshot_id = self.base_snapshot;
+        if let Some(snapshot) = self.validated_snapshot().await? {
+            report.snapshot_id = Some(snapshot.snapshot_id);
+            if let Some(deletes) = snapshot.deletes {
+                check_deletes(contract, context, deletes, &mut report);
+            }
         }
+        self.validate_metadata_freshness(contract, context, &mut report)
+            .await?;
+        report.apply_limits(context);
+        Ok(report)
+    }
 
-        #[cfg(not(feature = "native-datafusion"))]
-        {
-            return self.validate_table_dataset(contract, context).await;
-        }
+    /// Evaluates a freshness check read from table metadata at the
+    /// validated snapshot.
+    async fn validate_metadata_freshness(
+        &self,
+        contract: &Contract,
+        context: &ValidationContext,
+        report: &mut ValidationReport,
+    ) -> Result<(), IcebergError> {
+        let Some(check) = metadata_freshness(contract) else {
+            return Ok(());
+        };
+        let table = self.loader.load_table().await?.ok_or_else(|| {
+            IcebergError::UnsupportedOperation(format!(
+                "freshness source '{}' needs a table with snapshot history",
+                check.source().as_str()
+            ))
+        })?;
+        let metadata = table.metadata();
+        let snapshot = match self.pinned_snapshot().await? {
+            Some(snapshot_id) => metadata.snapshot_by_id(snapshot_id),
+            None => metadata.current_snapshot(),
+        };
+        let latest = match snapshot {
+            Some(snapshot) => updated_at(&table, snapshot, check).await?,
+            None => None,
+        };
+        check_metadata_freshness(contract, context, latest, report);
+        Ok(())
     }
 
     /// Validates using the DataSet-based path (legacy).
@@ -192,9 +522,18 @@ impl IcebergValidator {
         contract: &Contract,
         context: &ValidationContext,
     ) -> Result<ValidationReport, IcebergError> {
-        let sample_size = context.sample_size.unwrap_or(1000);
+        // A time window or row filter is applied to the rows read, so such
+        // runs read the whole table and leave sampling to the validator.
+        let (sample_size, sampling) = if context.filters_rows() {
+            (usize::MAX, &SamplingStrategy::Head)
+        } else {
+            (context.sample_size.unwrap_or(1000), &context.sampling)
+        };
 
-        let dataset = self.read_sample_data(sample_size).await?;
+        let request = self
+            .validation_request(contract, context, sample_size, sampling)
+            .await?;
+        let dataset = self.read_sample_data_with(&request).await?;
 
         info!("Read {} rows for validation", dataset.len());
 
@@ -208,46 +547,135 @@ impl IcebergValidator {
         Ok(report)
     }
 
+    /// Validates batches with a [`StreamingValidator`] as the reader returns
+    /// them, so no more than one batch of converted rows is held at a time.
+    async fn validate_table_chunked(
+        &self,
+        contract: &Contract,
+        context: &ValidationContext,
+    ) -> Result<ValidationReport, IcebergError> {
+        // Filtered runs read the whole table; the validator caps the rows
+        // that match at the sample size, keeping the first ones.
+        let (limit, sampling) = match context.sample_size {
+            Some(sample_size) if !context.filters_rows() => (sample_size, &context.sampling),
+            _ => (usize::MAX, &SamplingStrategy::Head),
+        };
+        let request = self
+            .validation_request(contract, context, limit, sampling)
+            .await?;
+        info!("Validating up to {} rows batch by batch", limit);
+
+        let mut validator = StreamingValidator::new(contract, context);
+        let mut batch_idx = 0;
+        let mut skipped = Vec::new();
+        self.reader
+            .stream_rows(&request, &mut |batch| {
+                if validator.is_done() {
+                    return Ok(());
+                }
+                let rows = self.convert_batch(&batch, batch_idx, usize::MAX, &mut skipped)?;
+                validator.push(&DataSet::from_rows(rows));
+                batch_idx += 1;
+                Ok(())
+            })
+            .await?;
+
+        info!(
+            "Validated {} rows from {} batch(es)",
+            validator.rows(),
+            batch_idx
+        );
+        if let Some(first) = skipped.first() {
+            warn!(
+                "Skipped {} rows with unconvertible values, first: {}",
+                skipped.len(),
+                first
+            );
+        }
+
+        let report = validator.finish();
+        self.log_result(&report);
+        Ok(report)
+    }
+
     /// Validates by registering the Iceberg table directly with DataFusion.
     ///
     /// This zero-copy path avoids the intermediate `DataSet` representation,
     /// enabling predicate/projection pushdown and streaming execution.
+    /// Sampled runs read their rows through the data reader first, so the
+    /// sample is spread across partitions and drawn with the context's
+    /// sampling strategy like on the `DataSet` path. Time-windowed and
+    /// filtered runs scan the table with the predicate pushed down and take
+    /// the first matching rows instead.
     #[cfg(feature = "native-datafusion")]
     async fn validate_table_native(
         &self,
         contract: &Contract,
         context: &ValidationContext,
     ) -> Result<ValidationReport, IcebergError> {
+        use datafusion::catalog::TableProvider;
         use datafusion::prelude::SessionContext;
         use iceberg_datafusion::IcebergStaticTableProvider;
-        use std::sync::Arc;
 
         info!("Using native DataFusion path for Iceberg table validation");
 
-        let table = self.load_table().await?;
+        let filter = match contracts_validator::row_predicate(contract, context) {
+            Ok(filter) => filter,
+            Err(e) => return Ok(ValidationReport::failure(e.to_violation())),
+        };
 
-        let provider = IcebergStaticTableProvider::try_new_from_table(table)
-            .await
-            .map_err(|e| {
-                IcebergError::DataReadError(format!("Failed to create Iceberg table provider: {e}"))
-            })?;
+        // Samples come from the data reader, which spreads them across
+        // partitions; full and windowed runs scan the table in place.
+        let pinned = self.pinned_snapshot().await?;
+        let provider: Arc<dyn TableProvider> = match (context.sample_size, &filter) {
+            (Some(limit), None) => {
+                let request = self
+                    .validation_request(contract, context, limit, &context.sampling)
+                    .await?;
+                self.memory_table(&request).await?
+            }
+            // Incremental runs only see the added files, which a table scan cannot select
+            _ if self.base_snapshot.is_some() => {
+                let request = self
+                    .validation_request(contract, context, usize::MAX, &SamplingStrategy::Head)
+                    .await?;
+                self.memory_table(&request).await?
+            }
+            _ => match self.loader.load_table().await? {
+                Some(table) => Arc::new(
+                    match pinned {
+                        Some(snapshot_id) => {
+                            IcebergStaticTableProvider::try_new_from_table_snapshot(
+                                table,
+                                snapshot_id,
+                            )
+                            .await
+                        }
+                        None => IcebergStaticTableProvider::try_new_from_table(table).await,
+                    }
+                    .map_err(|e| {
+                        IcebergError::data_read("Failed to create Iceberg table provider", e)
+                    })?,
+                ),
+                None => {
+                    let request = self
+                        .validation_request(contract, context, usize::MAX, &SamplingStrategy::Head)
+                        .await?;
+                    self.memory_table(&request).await?
+                }
+            },
+        };
 
         let ctx = SessionContext::new();
-
-        if let Some(limit) = context.sample_size {
-            ctx.register_table("iceberg_raw", Arc::new(provider))
-                .map_err(|e| IcebergError::DataReadError(e.to_string()))?;
-            ctx.sql(&format!(
-                "CREATE VIEW data AS SELECT * FROM iceberg_raw LIMIT {limit}"
-            ))
-            .await
-            .map_err(|e| IcebergError::DataReadError(e.to_string()))?
-            .collect()
-            .await
-            .map_err(|e| IcebergError::DataReadError(e.to_string()))?;
+        if filter.is_some() {
+            ctx.register_table("raw_data", provider)
+                .map_err(|e| IcebergError::data_read("Failed to register table", e))?;
+            contracts_validator::create_data_view(&ctx, filter.as_deref(), context.sample_size)
+                .await
+                .map_err(|e| IcebergError::data_read("Failed to filter rows", e))?;
         } else {
-            ctx.register_table("data", Arc::new(provider))
-                .map_err(|e| IcebergError::DataReadError(e.to_string()))?;
+            ctx.register_table("data", provider)
+                .map_err(|e| IcebergError::data_read("Failed to register table", e))?;
         }
 
         let mut validator = DataValidator::new();
@@ -260,6 +688,26 @@ impl IcebergValidator {
         Ok(report)
     }
 
+    /// Builds an in-memory DataFusion table from the batches the data reader
+    /// returns for `request`.
+    #[cfg(feature = "native-datafusion")]
+    async fn memory_table(
+        &self,
+        request: &ReadRequest,
+    ) -> Result<Arc<datafusion::datasource::MemTable>, IcebergError> {
+        use iceberg::arrow::schema_to_arrow_schema;
+
+        let batches = self.read_batches(request).await?;
+        let schema = match batches.first() {
+            Some(batch) => batch.schema(),
+            None => Arc::new(schema_to_arrow_schema(&*self.loader.load_schema().await?)?),
+        };
+
+        datafusion::datasource::MemTable::try_new(schema, vec![batches])
+            .map(Arc::new)
+            .map_err(|e| IcebergError::data_read("Failed to build in-memory table", e))
+    }
+
     fn log_result(&self, report: &ValidationReport) {
         if report.passed {
             info!(
@@ -277,6 +725,34 @@ impl IcebergValidator {
         }
     }
 
+    /// Validates only the rows of data files added after snapshot
+    /// `from_snapshot`, so a large table can be checked commit by commit
+    /// instead of being read in full every time.
+    ///
+    /// The increment ends at the pinned snapshot, or at the current one. The
+    /// report's `base_snapshot_id` and `snapshot_id` give the range covered;
+    /// rows rewritten by compaction in that range count as added.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the table has no snapshot history, if
+    /// `from_snapshot` has expired, or if validation cannot be performed.
+    pub async fn validate_incremental(
+        &self,
+        contract: &Contract,
+        from_snapshot: i64,
+        context: &ValidationContext,
+    ) -> Result<ValidationReport, IcebergError> {
+        info!(
+            "Validating rows added after snapshot {} against contract: {}",
+            from_snapshot, contract.name
+        );
+        self.clone()
+            .with_base_snapshot(from_snapshot)
+            .validate_table(contract, context)
+            .await
+    }
+
     /// Validates only the schema of an Iceberg table against a contract (no data reading).
     ///
     /// This is faster than full validation as it doesn't read any data from the table.
@@ -330,74 +806,184 @@ impl IcebergValidator {
         Ok(report)
     }
 
+    /// Validates a contract against the statistics in the table's manifests,
+    /// without reading any data.
+    ///
+    /// The schema is checked as in [`validate_schema_only`](Self::validate_schema_only).
+    /// Null counts, row counts and numeric and temporal bounds summed over
+    /// the snapshot's data files then answer nullability, completeness,
+    /// `range` constraints, freshness and the empty data policy for the whole
+    /// table (see [`check_column_stats`]). Checks that need the values, such
+    /// as uniqueness or patterns, are reported as inconclusive (`DCE0307`).
+    ///
+    /// Statistics are written with the data files, so rows hidden by
+    /// row-level deletes still count.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source has no table to read manifests from,
+    /// if a base snapshot, row filter, time window or segment column is set,
+    /// since statistics cover whole files, or if the manifests cannot be read.
+    pub async fn validate_from_stats(
+        &self,
+        contract: &Contract,
+        context: &ValidationContext,
+    ) -> Result<ValidationReport, IcebergError> {
+        info!(
+            "Validating table statistics against contract: {}",
+            contract.name
+        );
+        if self.base_snapshot.is_some() || context.filters_rows() || context.segment_by.is_some() {
+            return Err(IcebergError::UnsupportedOperation(
+                "statistics cover whole data files, so they cannot be narrowed to \
+                 incremental rows, a row filter, a time window or segments"
+                    .to_string(),
+            ));
+        }
+        let table = self.loader.load_table().await?.ok_or_else(|| {
+            IcebergError::UnsupportedOperation(
+                "statistics-only validation needs a table with manifests".to_string(),
+            )
+        })?;
+        let identifiers = identifier_fields(&*self.loader.load_schema().await?);
+
+        let mut report = self.validate_schema_only(contract, context).await?;
+        let metadata = table.metadata();
+        let snapshot = match self.pinned_snapshot().await? {
+            Some(snapshot_id) => metadata.snapshot_by_id(snapshot_id),
+            None => metadata.current_snapshot(),
+        };
+        match snapshot {
+            Some(snapshot) => {
+                let stats = table_stats(&table, snapshot).await?;
+                check_column_stats(
+                    contract,
+                    context,
+                    stats.records as usize,
+                    &stats.columns,
+                    &mut report,
+                );
+                let info = SnapshotInfo::from(snapshot.as_ref());
+                report.snapshot_id = Some(info.snapshot_id);
+                if let Some(deletes) = info.deletes {
+                    check_deletes(contract, context, deletes, &mut report);
+                }
+            }
+            // A table without snapshots has no rows
+            None => check_column_stats(contract, context, 0, &BTreeMap::new(), &mut report),
+        }
+        self.validate_metadata_freshness(contract, context, &mut report)
+            .await?;
+        check_identifiers(contract, &identifiers, context.strict, &mut report);
+        report.apply_limits(context);
+        Ok(report)
+    }
+
     /// Reads sample data from the Iceberg table.
     ///
+    /// Under [`ConversionErrorPolicy::Skip`], rows with values that cannot be
+    /// converted are left out and logged; use [`read_sample`](Self::read_sample)
+    /// to inspect them.
+    ///
     /// # Arguments
     ///
     /// * `limit` - Maximum number of rows to read
     ///
     /// # Errors
     ///
-    /// Returns an error if data cannot be read from the table.
+    /// Returns an error if data cannot be read from the table, or if a value
+    /// cannot be converted under [`ConversionErrorPolicy::Fail`].
     pub async fn read_sample_data(&self, limit: usize) -> Result<DataSet, IcebergError> {
-        info!("Reading sample data (limit: {}) from table", limit);
+        let request = self.read_request(limit, &self.sampling).await?;
+        self.read_sample_data_with(&request).await
+    }
 
-        let table = self.load_table().await?;
+    /// Reads the rows of `request`, logging skipped rows.
+    async fn read_sample_data_with(&self, request: &ReadRequest) -> Result<DataSet, IcebergError> {
+        let sample = self.read_sample_with(request).await?;
 
-        // Create a table scan with all columns
-        let scan = table
-            .scan()
-            .select_all()
-            .with_batch_size(Some(1024))
-            .build()
-            .map_err(|e| IcebergError::DataReadError(format!("Failed to build scan: {}", e)))?;
+        if let Some(first) = sample.skipped.first() {
+            warn!(
+                "Skipped {} rows with unconvertible values, first: {}",
+                sample.skipped.len(),
+                first
+            );
+        }
 
-        // Convert to Arrow stream
-        let mut stream = scan.to_arrow().await.map_err(|e| {
-            IcebergError::DataReadError(format!("Failed to create arrow stream: {}", e))
-        })?;
+        Ok(sample.data)
+    }
 
-        debug!("Arrow stream created, reading record batches");
+    /// Reads up to `limit` rows, reporting the rows skipped by the conversion policy.
+    ///
+    /// Column statistics of the converted rows are collected along the way
+    /// and attached to the returned [`DataSet`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if data cannot be read from the table, or if a value
+    /// cannot be converted under [`ConversionErrorPolicy::Fail`].
+    pub async fn read_sample(&self, limit: usize) -> Result<SampleData, IcebergError> {
+        let request = self.read_request(limit, &self.sampling).await?;
+        self.read_sample_with(&request).await
+    }
 
-        let mut rows = Vec::new();
-        let mut total_rows = 0;
+    /// Reads the rows of `request`.
+    async fn read_sample_with(&self, request: &ReadRequest) -> Result<SampleData, IcebergError> {
+        let limit = request.limit;
+        info!("Reading sample data (limit: {}) from table", limit);
 
-        // Read record batches from stream
-        while let Some(batch) = stream.try_next().await.map_err(|e| {
-            IcebergError::DataReadError(format!("Failed to read record batch: {}", e))
-        })? {
-            debug!("Processing batch with {} rows", batch.num_rows());
+        let batches = self.read_batches(request).await?;
 
-            let schema = batch.schema();
-            let num_rows = batch.num_rows();
+        let mut rows = Vec::new();
+        let mut skipped = Vec::new();
+        let mut stats = StatsCollector::new();
 
-            // Convert each row in the batch
-            for row_idx in 0..num_rows {
-                if total_rows >= limit {
-                    break;
-                }
+        for (batch_idx, batch) in batches.iter().enumerate() {
+            let remaining = limit.saturating_sub(rows.len() + skipped.len());
+            for row in self.convert_batch(batch, batch_idx, remaining, &mut skipped)? {
+                stats.observe(&row);
+                rows.push(row);
+            }
+        }
 
-                let mut row = HashMap::new();
+        info!("Read {} rows from Iceberg table", rows.len());
 
-                // Convert each column value
-                for (col_idx, field) in schema.fields().iter().enumerate() {
-                    let column = batch.column(col_idx);
-                    let value = arrow_value_to_data_value(column, row_idx)?;
-                    row.insert(field.name().clone(), value);
-                }
+        Ok(SampleData {
+            data: DataSet::from_rows(rows).with_column_stats(stats.finish()),
+            skipped,
+        })
+    }
 
-                rows.push(row);
-                total_rows += 1;
+    /// Converts up to `limit` rows of `batch`, counting skipped rows against
+    /// the limit. Rows skipped under [`ConversionErrorPolicy::Skip`] are
+    /// added to `skipped`.
+    fn convert_batch(
+        &self,
+        batch: &RecordBatch,
+        batch_idx: usize,
+        limit: usize,
+        skipped: &mut Vec<ConversionError>,
+    ) -> Result<Vec<DataRow>, IcebergError> {
+        debug!("Processing batch with {} rows", batch.num_rows());
+
+        let converter = BatchConverter::new(batch, batch_idx);
+        let mut rows = Vec::new();
+        let skipped_before = skipped.len();
+        for row_idx in 0..batch.num_rows() {
+            if rows.len() + skipped.len() - skipped_before >= limit {
+                break;
             }
 
-            if total_rows >= limit {
-                break;
+            match converter.convert_row(row_idx) {
+                Ok(row) => rows.push(row),
+                Err(e) if self.conversion_policy == ConversionErrorPolicy::Skip => {
+                    debug!("Skipping row: {}", e);
+                    skipped.push(e);
+                }
+                Err(e) => return Err(e.into()),
             }
         }
-
-        info!("Read {} rows from Iceberg table", rows.len());
-
-        Ok(DataSet::from_rows(rows))
+        Ok(rows)
     }
 
     /// Returns the configuration used by this validator.
@@ -406,9 +992,106 @@ impl IcebergValidator {
     }
 }
 
+/// Fails for samples stratified by a column, which Iceberg reads cannot
+/// draw: they are stratified by the table's partitions instead.
+fn check_sampling(sampling: &SamplingStrategy) -> Result<(), IcebergError> {
+    match sampling {
+        SamplingStrategy::Stratified {
+            column: Some(column),
+            ..
+        } => Err(IcebergError::UnsupportedOperation(format!(
+            "Iceberg samples are stratified by table partition, not by column '{}'",
+            column
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Records the table's row-level deletes in `report` and applies the
+/// contract's `deleted_rows` check.
+///
+/// Reads already skip deleted rows; the share of them is reported because a
+/// growing backlog of delete files slows every reader of the table.
+fn check_deletes(
+    contract: &Contract,
+    context: &ValidationContext,
+    deletes: DeleteStats,
+    report: &mut ValidationReport,
+) {
+    report.stats.deletes = Some(deletes);
+
+    let Some(check) = contract
+        .quality_checks
+        .as_ref()
+        .and_then(|qc| qc.deleted_rows.as_ref())
+    else {
+        return;
+    };
+    let fraction = deletes.deleted_fraction();
+    if fraction <= check.max_fraction {
+        return;
+    }
+
+    let message = format!(
+        "Quality check failed: {:.2}% of the table's rows are deleted \
+         ({} position and {} equality deletes over {} rows) > {:.2}% (max_fraction)",
+        fraction * 100.0,
+        deletes.position_deletes,
+        deletes.equality_deletes,
+        deletes.data_records,
+        check.max_fraction * 100.0
+    );
+    report.add_violation(
+        Violation::new(&codes::DELETED_ROWS, CheckKind::Quality, message)
+            .with_severity(Severity::default_for(context.strict)),
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::MockTable;
+    use arrow_array::{Int64Array, RecordBatch, StringArray};
+    use arrow_schema::{DataType, Field, Schema};
+    use contracts_core::{
+        ContractBuilder, DataFormat, DeletedRowsCheck, FieldBuilder, QualityChecksBuilder,
+    };
+
+    fn users(ids: Vec<i64>, names: Vec<Option<&str>>) -> RecordBatch {
+        RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("id", DataType::Int64, false),
+                Field::new("name", DataType::Utf8, true),
+            ])),
+            vec![
+                Arc::new(Int64Array::from(ids)),
+                Arc::new(StringArray::from(names)),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn mock_validator(batches: Vec<RecordBatch>) -> (IcebergValidator, Arc<MockTable>) {
+        let config = IcebergConfig::builder()
+            .file_io()
+            .namespace(vec!["db".to_string()])
+            .table_name("users")
+            .build()
+            .unwrap();
+        let table = Arc::new(MockTable::from_batches(batches).unwrap());
+        let validator =
+            IcebergValidator::with_source(config, table.clone(), table.clone()).unwrap();
+        (validator, table)
+    }
+
+    fn contract() -> Contract {
+        ContractBuilder::new("users", "test-team")
+            .location("iceberg://test/db/users")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("id", "int64").nullable(false).build())
+            .field(FieldBuilder::new("name", "string").nullable(false).build())
+            .build()
+    }
 
     #[tokio::test]
     async fn test_validator_config_file_io() {
@@ -430,6 +1113,22 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_validator_with_cache_file_io() {
+        let cache = CatalogCache::new();
+        let config = IcebergConfig::builder()
+            .file_io()
+            .namespace(vec!["test".to_string()])
+            .table_name("my_table")
+            .property("metadata_location", "/tmp/metadata.json")
+            .build()
+            .unwrap();
+
+        let validator = IcebergValidator::with_cache(config, &cache).await.unwrap();
+        assert_eq!(validator.config().table_name, "my_table");
+        assert!(cache.is_empty().await);
+    }
+
     #[test]
     fn test_validator_with_invalid_config() {
         let result = IcebergConfig::builder().build();
@@ -453,4 +1152,372 @@ mod tests {
         // We expect this to fail without actual catalog, but it tests the code path
         assert!(result.is_err() || result.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_extract_schema_from_source() {
+        let (validator, table) = mock_validator(vec![users(vec![1], vec![Some("ada")])]);
+
+        let schema = validator.extract_schema().await.unwrap();
+        let fields: Vec<(&str, bool)> = schema
+            .fields
+            .iter()
+            .map(|f| (f.name.as_str(), f.nullable))
+            .collect();
+        assert_eq!(fields, vec![("id", false), ("name", true)]);
+        assert_eq!(table.reads(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_detect_drift_from_source() {
+        let (validator, table) = mock_validator(vec![users(vec![1], vec![Some("ada")])]);
+        let mut contract = contract();
+        contract.schema.fields[0].field_type = "string".into();
+        contract
+            .schema
+            .fields
+            .push(FieldBuilder::new("email", "string").nullable(false).build());
+
+        let drift = validator.detect_drift(&contract).await.unwrap();
+        let kinds: Vec<String> = drift
+            .changes
+            .iter()
+            .map(|d| {
+                serde_json::to_value(d).unwrap()["kind"]
+                    .as_str()
+                    .unwrap()
+                    .to_string()
+            })
+            .collect();
+        assert_eq!(
+            kinds,
+            vec!["type_changed", "nullability_changed", "missing"]
+        );
+        assert!(drift.is_breaking());
+        assert_eq!(table.reads(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_read_sample_data_limits_rows_across_batches() {
+        let (validator, _) = mock_validator(vec![
+            users(vec![1, 2], vec![Some("a"), Some("b")]),
+            users(vec![3, 4], vec![Some("c"), Some("d")]),
+        ]);
+
+        assert_eq!(validator.read_sample_data(3).await.unwrap().len(), 3);
+        assert_eq!(validator.read_sample_data(10).await.unwrap().len(), 4);
+        assert!(validator.read_sample_data(0).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_read_sample_data_draws_random_rows() {
+        let ids: Vec<i64> = (1..=100).collect();
+        let names = vec![Some("a"); 100];
+        let (validator, _) = mock_validator(vec![users(ids, names)]);
+        let validator = validator.with_sampling(SamplingStrategy::Random { seed: 3 });
+
+        let ids = |data: DataSet| -> Vec<String> {
+            data.rows().map(|row| format!("{:?}", row["id"])).collect()
+        };
+        let sample = ids(validator.read_sample_data(10).await.unwrap());
+        assert_eq!(sample.len(), 10);
+        assert_ne!(
+            sample,
+            ids(validator
+                .clone()
+                .with_sampling(SamplingStrategy::Head)
+                .read_sample_data(10)
+                .await
+                .unwrap())
+        );
+        assert_eq!(sample, ids(validator.read_sample_data(10).await.unwrap()));
+
+        let by_column = validator.with_sampling(SamplingStrategy::Stratified {
+            column: Some("name".to_string()),
+            seed: 3,
+        });
+        assert!(matches!(
+            by_column.read_sample_data(10).await,
+            Err(IcebergError::UnsupportedOperation(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_read_sample_collects_column_stats() {
+        let (validator, _) = mock_validator(vec![
+            users(vec![3, 1], vec![Some("b"), None]),
+            users(vec![2, 3], vec![Some("a"), Some("b")]),
+        ]);
+
+        let data = validator.read_sample_data(10).await.unwrap();
+        let stats = data.column_stats().expect("stats attached");
+
+        assert_eq!(stats["id"].min.as_deref(), Some("1"));
+        assert_eq!(stats["id"].max.as_deref(), Some("3"));
+        assert_eq!(stats["id"].distinct_count, 3);
+        assert_eq!(stats["name"].null_count, 1);
+        assert_eq!(stats["name"].distinct_count, 2);
+    }
+
+    #[tokio::test]
+    async fn test_validate_table_from_source() {
+        let (validator, _) = mock_validator(vec![users(vec![1, 2], vec![Some("a"), None])]);
+
+        let report = validator
+            .validate_table(&contract(), &ValidationContext::default())
+            .await
+            .unwrap();
+        assert!(!report.passed);
+        assert!(report.errors.iter().any(|e| e.message.contains("name")));
+
+        // The null is outside the sample
+        let context = ValidationContext::new().with_sample_size(1);
+        let report = validator
+            .validate_table(&contract(), &context)
+            .await
+            .unwrap();
+        assert!(report.passed, "unexpected errors: {:?}", report.errors);
+    }
+
+    #[tokio::test]
+    async fn test_chunked_validation_spans_batches() {
+        let (validator, table) = mock_validator(vec![
+            users(vec![1, 2], vec![Some("a"), Some("b")]),
+            users(vec![3, 2], vec![None, Some("d")]),
+            users(vec![5], vec![Some("e")]),
+        ]);
+        let validator = validator.with_chunked_validation(true);
+        let mut contract = contract();
+        contract.quality_checks = Some(
+            QualityChecksBuilder::new()
+                .uniqueness(contracts_core::UniquenessCheck {
+                    fields: vec!["id".to_string()],
+                    scope: None,
+                    min_sample_size: None,
+                    severity: None,
+                    approximate: None,
+                })
+                .build(),
+        );
+
+        let context = ValidationContext::new().with_sample_size(4);
+        let report = validator.validate_table(&contract, &context).await.unwrap();
+
+        assert_eq!(table.reads(), 1);
+        assert_eq!(report.stats.records_validated, 4);
+        assert!(!report.passed);
+        // The null is in the second batch, the duplicate spans two batches
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].row_index, Some(2));
+        assert!(
+            report
+                .warnings
+                .iter()
+                .any(|w| w.message.contains("found 1 duplicate(s)"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_time_window_selects_rows_before_sampling() {
+        use arrow_array::Date32Array;
+        use chrono::{TimeZone, Utc};
+        use contracts_core::TimeWindow;
+
+        // Days 20740..=20743 are 2026-10-14 through 2026-10-17
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("id", DataType::Int64, false),
+                Field::new("day", DataType::Date32, true),
+            ])),
+            vec![
+                Arc::new(Int64Array::from(vec![1, 2, 3, 4])),
+                Arc::new(Date32Array::from(vec![20740, 20741, 20742, 20743])),
+            ],
+        )
+        .unwrap();
+        let (validator, _) = mock_validator(vec![batch]);
+        let contract = ContractBuilder::new("days", "test-team")
+            .location("iceberg://test/db/days")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("id", "int64").nullable(false).build())
+            .field(FieldBuilder::new("day", "date").build())
+            .build();
+
+        let context = ValidationContext::new()
+            .with_sample_size(1)
+            .with_time_window(TimeWindow {
+                column: Some("day".to_string()),
+                since: Some(Utc.with_ymd_and_hms(2026, 10, 16, 0, 0, 0).unwrap()),
+                until: None,
+            });
+        let report = validator.validate_table(&contract, &context).await.unwrap();
+        assert!(report.passed, "unexpected errors: {:?}", report.errors);
+        assert_eq!(report.stats.records_validated, 1);
+
+        let context = ValidationContext::new().with_time_window(TimeWindow {
+            column: Some("day".to_string()),
+            since: Some(Utc.with_ymd_and_hms(2026, 10, 16, 0, 0, 0).unwrap()),
+            until: None,
+        });
+        let report = validator.validate_table(&contract, &context).await.unwrap();
+        assert_eq!(report.stats.records_validated, 2);
+
+        // The row filter combines with the window
+        let context = context.with_row_filter("id <> 3");
+        let report = validator.validate_table(&contract, &context).await.unwrap();
+        assert_eq!(report.stats.records_validated, 1);
+
+        let context = ValidationContext::new().with_row_filter("missing = 1");
+        let report = validator.validate_table(&contract, &context).await.unwrap();
+        assert_eq!(report.errors[0].code.code, "DCE0011");
+    }
+
+    #[test]
+    fn test_check_deletes_reports_backlog() {
+        let contract = || {
+            let mut contract = contract();
+            contract.quality_checks = Some(
+                QualityChecksBuilder::new()
+                    .deleted_rows(DeletedRowsCheck { max_fraction: 0.1 })
+                    .build(),
+            );
+            contract
+        };
+        let deletes = |position_deletes| DeleteStats {
+            data_records: 1000,
+            position_deletes,
+            equality_deletes: 50,
+        };
+
+        let mut report = ValidationReport::success();
+        check_deletes(
+            &contract(),
+            &ValidationContext::new(),
+            deletes(50),
+            &mut report,
+        );
+        assert!(report.passed && report.warnings.is_empty());
+        assert_eq!(report.stats.deletes, Some(deletes(50)));
+
+        let mut report = ValidationReport::success();
+        check_deletes(
+            &contract(),
+            &ValidationContext::new(),
+            deletes(150),
+            &mut report,
+        );
+        assert!(report.passed);
+        assert!(
+            report.warnings[0]
+                .message
+                .contains("20.00% of the table's rows are deleted")
+        );
+
+        let strict = ValidationContext::new().with_strict(true);
+        let mut report = ValidationReport::success();
+        check_deletes(&contract(), &strict, deletes(150), &mut report);
+        assert!(!report.passed);
+        assert_eq!(report.errors[0].code.code, "DCE0306");
+    }
+
+    #[tokio::test]
+    async fn test_schema_only_reads_no_data() {
+        let (validator, table) = mock_validator(vec![users(vec![1], vec![None])]);
+
+        let context = ValidationContext::new().with_schema_only(true);
+        let report = validator
+            .validate_table(&contract(), &context)
+            .await
+            .unwrap();
+        assert!(report.passed);
+        assert_eq!(table.reads(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_identifier_fields_are_checked() {
+        use iceberg::arrow::arrow_schema_to_schema_auto_assign_ids;
+
+        let batch = users(vec![1, 1], vec![Some("a"), Some("b")]);
+        let schema = arrow_schema_to_schema_auto_assign_ids(batch.schema().as_ref()).unwrap();
+        let id = schema.field_id_by_name("id").unwrap();
+        let schema = schema
+            .into_builder()
+            .with_identifier_field_ids(vec![id])
+            .build()
+            .unwrap();
+        let config = IcebergConfig::builder()
+            .file_io()
+            .namespace(vec!["db".to_string()])
+            .table_name("users")
+            .build()
+            .unwrap();
+        let table = Arc::new(MockTable::new(schema, vec![batch]));
+        let validator = IcebergValidator::with_source(config, table.clone(), table).unwrap();
+
+        // Undeclared key: the duplicate id is still found
+        let report = validator
+            .validate_table(&contract(), &ValidationContext::default())
+            .await
+            .unwrap();
+        assert!(report.warnings.iter().any(|w| w.code.code == "DCE0105"));
+        assert!(
+            report
+                .warnings
+                .iter()
+                .any(|w| w.message.contains("duplicate"))
+        );
+
+        let mut keyed = contract();
+        keyed.schema.fields[1].tags = Some(vec!["primary_key".to_string()]);
+        let report = validator
+            .validate_table(&keyed, &ValidationContext::new().with_schema_only(true))
+            .await
+            .unwrap();
+        assert!(!report.passed);
+        assert!(
+            report.errors[0]
+                .message
+                .contains("Contract key [name] does not match")
+        );
+    }
+
+    fn events(timestamps: Vec<i64>) -> RecordBatch {
+        use arrow_array::TimestampMicrosecondArray;
+        use arrow_schema::TimeUnit;
+
+        RecordBatch::try_new(
+            Arc::new(Schema::new(vec![Field::new(
+                "created_at",
+                DataType::Timestamp(TimeUnit::Microsecond, None),
+                false,
+            )])),
+            vec![Arc::new(TimestampMicrosecondArray::from(timestamps))],
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_conversion_errors_carry_position() {
+        let (validator, _) = mock_validator(vec![events(vec![0]), events(vec![0, i64::MAX])]);
+
+        match validator.read_sample_data(10).await {
+            Err(IcebergError::ValueConversionError(e)) => {
+                assert_eq!(e.column, "created_at");
+                assert_eq!((e.batch, e.row), (1, 1));
+            }
+            other => panic!("expected a value conversion error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_skip_policy_counts_bad_rows() {
+        let (validator, _) = mock_validator(vec![events(vec![0, i64::MAX, 1, i64::MAX])]);
+        let validator = validator.with_conversion_policy(ConversionErrorPolicy::Skip);
+
+        let sample = validator.read_sample(10).await.unwrap();
+        assert_eq!(sample.data.len(), 2);
+        let rows: Vec<usize> = sample.skipped.iter().map(|e| e.row).collect();
+        assert_eq!(rows, vec![1, 3]);
+
+        assert_eq!(validator.read_sample_data(10).await.unwrap().len(), 2);
+    }
 }
@@ -0,0 +1,332 @@
+//! Write-audit-publish: validating staged snapshots before they reach `main`.
+//!
+//! Writers stage a snapshot on a branch, or commit it with a `wap.id` while
+//! `write.wap.enabled` is set, so readers of `main` do not see it yet. The
+//! staged snapshot is validated with
+//! [`IcebergValidator::with_snapshot`](crate::IcebergValidator::with_snapshot),
+//! and published when it passes.
+//!
+//! iceberg-rust has no API to move a table's refs, so publishing is left to
+//! an engine: [`StagedSnapshot::publish_sql`] returns the Spark procedure
+//! call that publishes the snapshot.
+
+use crate::{IcebergError, SnapshotInfo};
+use iceberg::spec::TableMetadata;
+use std::fmt;
+
+/// Name of the branch readers see.
+pub const MAIN_BRANCH: &str = "main";
+
+/// Summary property recording the WAP ID a snapshot was staged with.
+const WAP_ID_PROPERTY: &str = "wap.id";
+
+/// How a staged snapshot is found in the table metadata.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StagedRef {
+    /// The head of a branch
+    Branch(String),
+
+    /// A snapshot ID
+    Snapshot(i64),
+
+    /// The latest snapshot committed with a `wap.id` summary property
+    WapId(String),
+}
+
+impl fmt::Display for StagedRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Branch(name) => write!(f, "branch '{name}'"),
+            Self::Snapshot(id) => write!(f, "snapshot {id}"),
+            Self::WapId(id) => write!(f, "WAP ID '{id}'"),
+        }
+    }
+}
+
+/// How a staged snapshot can be published to `main`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Publication {
+    /// The snapshot is already part of `main`
+    UpToDate,
+
+    /// `main` is an ancestor of the snapshot and can be moved to it
+    FastForward,
+
+    /// The snapshot is the only change on top of a snapshot of `main`, and
+    /// can be replayed on `main`
+    CherryPick,
+
+    /// Several snapshots, oldest first, are on top of a snapshot of `main`
+    /// that `main` has moved past; they must be published one by one
+    Diverged {
+        /// The staged snapshots missing from `main`
+        snapshots: Vec<i64>,
+    },
+}
+
+/// A staged snapshot, with how it relates to `main`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StagedSnapshot {
+    /// The staged snapshot
+    pub snapshot: SnapshotInfo,
+
+    /// Branch the snapshot was staged on, if it was found by branch
+    pub branch: Option<String>,
+
+    /// Snapshot `main` points to, if the table has one
+    pub main_snapshot_id: Option<i64>,
+
+    /// How the snapshot can be published
+    pub publication: Publication,
+}
+
+impl StagedSnapshot {
+    /// Finds the staged snapshot in `metadata`, and how it can be published.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the branch, snapshot or WAP ID is not in the
+    /// metadata.
+    pub fn resolve(metadata: &TableMetadata, staged: &StagedRef) -> Result<Self, IcebergError> {
+        let not_found = || IcebergError::Other(format!("No {staged} in the table metadata"));
+        let snapshot = match staged {
+            StagedRef::Branch(name) => metadata.snapshot_for_ref(name),
+            StagedRef::Snapshot(id) => metadata.snapshot_by_id(*id),
+            StagedRef::WapId(id) => metadata
+                .snapshots()
+                .filter(|snapshot| {
+                    snapshot
+                        .summary()
+                        .additional_properties
+                        .get(WAP_ID_PROPERTY)
+                        == Some(id)
+                })
+                .max_by_key(|snapshot| snapshot.sequence_number()),
+        }
+        .ok_or_else(not_found)?;
+
+        let main_snapshot_id = metadata
+            .snapshot_for_ref(MAIN_BRANCH)
+            .map(|snapshot| snapshot.snapshot_id());
+        let publication = publication(metadata, snapshot.snapshot_id(), main_snapshot_id);
+
+        Ok(Self {
+            snapshot: SnapshotInfo::from(snapshot.as_ref()),
+            branch: match staged {
+                StagedRef::Branch(name) => Some(name.clone()),
+                _ => None,
+            },
+            main_snapshot_id,
+            publication,
+        })
+    }
+
+    /// Returns the Spark SQL call that publishes the snapshot to `main`.
+    ///
+    /// `catalog` is the Spark catalog name and `table` the table identifier
+    /// within it, such as `db.events`. Returns `None` when there is nothing
+    /// to publish or the snapshot has [`Publication::Diverged`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use contracts_iceberg::{SnapshotInfo, wap::{Publication, StagedSnapshot}};
+    /// let staged = StagedSnapshot {
+    ///     snapshot: SnapshotInfo {
+    ///         snapshot_id: 42,
+    ///         parent_snapshot_id: Some(7),
+    ///         committed_at: None,
+    ///         operation: "append".to_string(),
+    ///         added_records: Some(10),
+    ///         deletes: None,
+    ///     },
+    ///     branch: Some("audit".to_string()),
+    ///     main_snapshot_id: Some(7),
+    ///     publication: Publication::FastForward,
+    /// };
+    /// assert_eq!(
+    ///     staged.publish_sql("lake", "db.events").unwrap(),
+    ///     "CALL lake.system.fast_forward('db.events', 'main', 'audit')"
+    /// );
+    /// ```
+    pub fn publish_sql(&self, catalog: &str, table: &str) -> Option<String> {
+        let snapshot_id = self.snapshot.snapshot_id;
+        match (&self.publication, &self.branch) {
+            (Publication::FastForward, Some(branch)) => Some(format!(
+                "CALL {catalog}.system.fast_forward('{table}', '{MAIN_BRANCH}', '{branch}')"
+            )),
+            (Publication::FastForward, None) => Some(format!(
+                "CALL {catalog}.system.set_current_snapshot('{table}', {snapshot_id})"
+            )),
+            (Publication::CherryPick, _) => Some(format!(
+                "CALL {catalog}.system.cherrypick_snapshot('{table}', {snapshot_id})"
+            )),
+            (Publication::UpToDate | Publication::Diverged { .. }, _) => None,
+        }
+    }
+}
+
+/// Works out how `snapshot_id` can be published to a `main` at `main_id`.
+fn publication(metadata: &TableMetadata, snapshot_id: i64, main_id: Option<i64>) -> Publication {
+    let Some(main_id) = main_id else {
+        return Publication::FastForward;
+    };
+    let main_lineage = ancestors(metadata, main_id);
+    if main_lineage.contains(&snapshot_id) {
+        return Publication::UpToDate;
+    }
+
+    // Staged snapshots missing from main, down to where the lineages meet
+    let lineage = ancestors(metadata, snapshot_id);
+    let staged = lineage
+        .iter()
+        .position(|id| main_lineage.contains(id))
+        .unwrap_or(lineage.len());
+    if lineage.get(staged) == Some(&main_id) {
+        return Publication::FastForward;
+    }
+    if staged == 1 {
+        return Publication::CherryPick;
+    }
+    Publication::Diverged {
+        snapshots: lineage[..staged].iter().rev().copied().collect(),
+    }
+}
+
+/// Returns `snapshot_id` and its ancestors still in the metadata, newest first.
+fn ancestors(metadata: &TableMetadata, snapshot_id: i64) -> Vec<i64> {
+    let mut lineage = Vec::new();
+    let mut next = Some(snapshot_id);
+    while let Some(id) = next {
+        lineage.push(id);
+        next = metadata
+            .snapshot_by_id(id)
+            .and_then(|snapshot| snapshot.parent_snapshot_id());
+    }
+    lineage
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iceberg::spec::{
+        FormatVersion, NestedField, Operation, PrimitiveType, Schema, Snapshot, SnapshotReference,
+        SnapshotRetention, SortOrder, Summary, TableMetadataBuilder, Type, UnboundPartitionSpec,
+    };
+    use std::collections::HashMap;
+
+    fn snapshot(id: i64, parent: Option<i64>, wap_id: Option<&str>) -> Snapshot {
+        let additional_properties = wap_id
+            .map(|wap_id| HashMap::from([(WAP_ID_PROPERTY.to_string(), wap_id.to_string())]))
+            .unwrap_or_default();
+        Snapshot::builder()
+            .with_snapshot_id(id)
+            .with_parent_snapshot_id(parent)
+            .with_sequence_number(id)
+            .with_timestamp_ms(1_700_000_000_000 + id)
+            .with_manifest_list(format!("/warehouse/snap-{id}.avro"))
+            .with_summary(Summary {
+                operation: Operation::Append,
+                additional_properties,
+            })
+            .with_schema_id(0)
+            .build()
+    }
+
+    /// `main` at 2 on top of 1; `audit` at 3 on top of 2; 4 staged with
+    /// `job-1` on top of 1, and 5 on top of 4.
+    fn metadata() -> TableMetadata {
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+            ])
+            .build()
+            .unwrap();
+        let branch = |id| SnapshotReference::new(id, SnapshotRetention::branch(None, None, None));
+
+        TableMetadataBuilder::new(
+            schema,
+            UnboundPartitionSpec::builder().build(),
+            SortOrder::unsorted_order(),
+            "/warehouse/events".to_string(),
+            FormatVersion::V2,
+            HashMap::new(),
+        )
+        .unwrap()
+        .add_snapshot(snapshot(1, None, None))
+        .unwrap()
+        .add_snapshot(snapshot(2, Some(1), None))
+        .unwrap()
+        .add_snapshot(snapshot(3, Some(2), None))
+        .unwrap()
+        .add_snapshot(snapshot(4, Some(1), Some("job-1")))
+        .unwrap()
+        .add_snapshot(snapshot(5, Some(4), None))
+        .unwrap()
+        .set_ref(MAIN_BRANCH, branch(2))
+        .unwrap()
+        .set_ref("audit", branch(3))
+        .unwrap()
+        .build()
+        .unwrap()
+        .metadata
+    }
+
+    #[test]
+    fn test_resolve_publications() {
+        let metadata = metadata();
+        let resolve = |staged| StagedSnapshot::resolve(&metadata, &staged).unwrap();
+
+        let audit = resolve(StagedRef::Branch("audit".to_string()));
+        assert_eq!(audit.snapshot.snapshot_id, 3);
+        assert_eq!(audit.main_snapshot_id, Some(2));
+        assert_eq!(audit.publication, Publication::FastForward);
+
+        let staged = resolve(StagedRef::WapId("job-1".to_string()));
+        assert_eq!(staged.snapshot.snapshot_id, 4);
+        assert_eq!(staged.publication, Publication::CherryPick);
+
+        assert_eq!(
+            resolve(StagedRef::Snapshot(5)).publication,
+            Publication::Diverged {
+                snapshots: vec![4, 5]
+            }
+        );
+        assert_eq!(
+            resolve(StagedRef::Snapshot(1)).publication,
+            Publication::UpToDate
+        );
+
+        let err = StagedSnapshot::resolve(&metadata, &StagedRef::Branch("missing".to_string()))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Iceberg error: No branch 'missing' in the table metadata"
+        );
+    }
+
+    #[test]
+    fn test_publish_sql() {
+        let metadata = metadata();
+        let sql = |staged| {
+            StagedSnapshot::resolve(&metadata, &staged)
+                .unwrap()
+                .publish_sql("lake", "db.events")
+        };
+
+        assert_eq!(
+            sql(StagedRef::Branch("audit".to_string())).unwrap(),
+            "CALL lake.system.fast_forward('db.events', 'main', 'audit')"
+        );
+        assert_eq!(
+            sql(StagedRef::Snapshot(3)).unwrap(),
+            "CALL lake.system.set_current_snapshot('db.events', 3)"
+        );
+        assert_eq!(
+            sql(StagedRef::WapId("job-1".to_string())).unwrap(),
+            "CALL lake.system.cherrypick_snapshot('db.events', 4)"
+        );
+        assert_eq!(sql(StagedRef::Snapshot(5)), None);
+        assert_eq!(sql(StagedRef::Snapshot(2)), None);
+    }
+}
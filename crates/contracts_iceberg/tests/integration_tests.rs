@@ -186,6 +186,7 @@ fn test_config_validation_empty_table_name() {
         namespace: vec!["db".to_string()],
         table_name: "".to_string(),
         properties: Default::default(),
+        snapshot: None,
     };
 
     assert!(config.validate().is_err());
@@ -202,7 +203,557 @@ fn test_config_validation_empty_namespace() {
         namespace: vec![],
         table_name: "table".to_string(),
         properties: Default::default(),
+        snapshot: None,
     };
 
     assert!(config.validate().is_err());
 }
+
+// ============================================================================
+// End-to-end tests against a local warehouse
+// ============================================================================
+
+mod local_warehouse {
+    use arrow_array::{Date32Array, Int64Array, RecordBatch, StringArray};
+    use arrow_schema::{DataType, Field, Schema};
+    use contracts_core::{
+        AccessChange, Contract, ContractBuilder, DataFormat, FieldAccess, FieldBuilder,
+        FieldConstraints, FreshnessCheck, FreshnessSource, QualityChecksBuilder, SamplingStrategy,
+        Severity, ValidationContext, ValidationReport,
+    };
+    use contracts_iceberg::testing::TestWarehouse;
+    use contracts_iceberg::wap::{Publication, StagedRef};
+    use contracts_iceberg::{IcebergValidator, SnapshotSelector, access_properties};
+    use iceberg::spec::{Literal, Transform};
+    use std::sync::Arc;
+
+    fn users(names: Vec<Option<&str>>) -> RecordBatch {
+        let ids: Vec<i64> = (1..=names.len() as i64).collect();
+        RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("id", DataType::Int64, false),
+                Field::new("name", DataType::Utf8, true),
+            ])),
+            vec![
+                Arc::new(Int64Array::from(ids)),
+                Arc::new(StringArray::from(names)),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn contract() -> contracts_core::Contract {
+        ContractBuilder::new("users", "test-team")
+            .location("iceberg://test/db/users")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("id", "int64").nullable(false).build())
+            .field(FieldBuilder::new("name", "string").nullable(false).build())
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_validate_local_table() {
+        let warehouse = TestWarehouse::new().await.unwrap();
+        let table = warehouse
+            .create_table(&["db"], "users", &users(vec![Some("ada"), Some("grace")]))
+            .await
+            .unwrap();
+
+        let validator = IcebergValidator::new(table.config()).await.unwrap();
+
+        let schema = validator.extract_schema().await.unwrap();
+        assert_eq!(schema.fields.len(), 2);
+
+        let report = validator
+            .validate_table(&contract(), &ValidationContext::default())
+            .await
+            .unwrap();
+        assert!(report.passed, "unexpected errors: {:?}", report.errors);
+
+        // Append-only table: the summary counts the rows and no deletes
+        let deletes = report
+            .stats
+            .deletes
+            .expect("delete stats from the snapshot");
+        assert_eq!(deletes.data_records, 2);
+        assert_eq!(deletes.deleted_fraction(), 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_read_local_table_with_scan_options() {
+        let warehouse = TestWarehouse::new().await.unwrap();
+        let table = warehouse
+            .create_table(&["db"], "users", &users(vec![Some("ada"), Some("grace")]))
+            .await
+            .unwrap();
+        let table = warehouse
+            .append(&table, &users(vec![Some("alan")]))
+            .await
+            .unwrap();
+
+        // Every file is larger than the split size, so its row group offsets
+        // are loaded; no row may be read twice.
+        let mut config = table.config();
+        config.properties.extend([
+            ("scan.batch-size".to_string(), "1".to_string()),
+            ("scan.target-split-size".to_string(), "1".to_string()),
+            ("scan.prefetch-depth".to_string(), "2".to_string()),
+        ]);
+        let validator = IcebergValidator::new(config).await.unwrap();
+
+        let data = validator.read_sample_data(10).await.unwrap();
+        assert_eq!(data.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_read_local_table_random_sample() {
+        let warehouse = TestWarehouse::new().await.unwrap();
+        let names: Vec<Option<&str>> = vec![Some("user"); 50];
+        let table = warehouse
+            .create_table(&["db"], "users", &users(names.clone()))
+            .await
+            .unwrap();
+        let table = warehouse.append(&table, &users(names)).await.unwrap();
+
+        let validator = IcebergValidator::new(table.config())
+            .await
+            .unwrap()
+            .with_sampling(SamplingStrategy::Random { seed: 11 });
+        let sample = validator.read_sample_data(20).await.unwrap();
+        assert_eq!(sample.len(), 20);
+
+        // The same seed draws the same rows from the same files
+        let again = validator.read_sample_data(20).await.unwrap();
+        assert_eq!(
+            sample.rows().collect::<Vec<_>>(),
+            again.rows().collect::<Vec<_>>()
+        );
+
+        let stratified = validator.with_sampling(SamplingStrategy::Stratified {
+            column: None,
+            seed: 11,
+        });
+        assert_eq!(stratified.read_sample_data(20).await.unwrap().len(), 20);
+    }
+
+    #[tokio::test]
+    async fn test_validate_local_table_reports_violations() {
+        let warehouse = TestWarehouse::new().await.unwrap();
+        let table = warehouse
+            .create_table(&["db"], "users", &users(vec![Some("ada"), None]))
+            .await
+            .unwrap();
+
+        let validator = IcebergValidator::new(table.config()).await.unwrap();
+        let report = validator
+            .validate_table(&contract(), &ValidationContext::default())
+            .await
+            .unwrap();
+
+        assert!(!report.passed);
+        assert!(report.errors.iter().any(|e| e.message.contains("name")));
+    }
+
+    #[tokio::test]
+    async fn test_validate_rows_added_since_snapshot() {
+        let warehouse = TestWarehouse::new().await.unwrap();
+        let table = warehouse
+            .create_table(&["db"], "users", &users(vec![Some("ada"), None]))
+            .await
+            .unwrap();
+        let base = IcebergValidator::new(table.config())
+            .await
+            .unwrap()
+            .current_snapshot()
+            .await
+            .unwrap()
+            .expect("snapshot after the first write");
+        assert_eq!(base.operation, "append");
+        assert_eq!(base.added_records, Some(2));
+
+        let table = warehouse
+            .append(
+                &table,
+                &users(vec![Some("grace"), Some("linus"), Some("ken")]),
+            )
+            .await
+            .unwrap();
+        let validator = IcebergValidator::new(table.config())
+            .await
+            .unwrap()
+            .with_base_snapshot(base.snapshot_id);
+
+        let current = validator.current_snapshot().await.unwrap().unwrap();
+        assert_eq!(current.parent_snapshot_id, Some(base.snapshot_id));
+        assert_eq!(current.added_records, Some(3));
+
+        // The null name is in the first snapshot, outside the increment
+        assert_eq!(validator.read_sample_data(100).await.unwrap().len(), 3);
+        let report = validator
+            .validate_table(&contract(), &ValidationContext::default())
+            .await
+            .unwrap();
+        assert!(report.passed, "unexpected errors: {:?}", report.errors);
+        assert_eq!(report.stats.records_validated, 3);
+
+        let unchanged = IcebergValidator::new(table.config())
+            .await
+            .unwrap()
+            .with_base_snapshot(current.snapshot_id);
+        assert!(unchanged.read_sample_data(100).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_validate_incremental_between_snapshots() {
+        let warehouse = TestWarehouse::new().await.unwrap();
+        let table = warehouse
+            .create_table(&["db"], "users", &users(vec![Some("ada"), None]))
+            .await
+            .unwrap();
+        let snapshot = |table: &contracts_iceberg::testing::TestTable| {
+            let config = table.config();
+            async move {
+                IcebergValidator::new(config)
+                    .await
+                    .unwrap()
+                    .current_snapshot()
+                    .await
+                    .unwrap()
+                    .unwrap()
+                    .snapshot_id
+            }
+        };
+        let first = snapshot(&table).await;
+        let table = warehouse
+            .append(
+                &table,
+                &users(vec![Some("grace"), Some("linus"), Some("ken")]),
+            )
+            .await
+            .unwrap();
+        let second = snapshot(&table).await;
+        let table = warehouse.append(&table, &users(vec![None])).await.unwrap();
+        let third = snapshot(&table).await;
+        let validator = IcebergValidator::new(table.config()).await.unwrap();
+
+        // Up to the current snapshot, the null name of the last commit is read
+        let report = validator
+            .validate_incremental(&contract(), first, &ValidationContext::default())
+            .await
+            .unwrap();
+        assert!(!report.passed);
+        assert_eq!(report.stats.records_validated, 4);
+        assert_eq!(report.base_snapshot_id, Some(first));
+        assert_eq!(report.snapshot_id, Some(third));
+
+        // Pinned to the second snapshot, only its own rows are read
+        let report = validator
+            .clone()
+            .with_snapshot(second)
+            .validate_incremental(&contract(), first, &ValidationContext::default())
+            .await
+            .unwrap();
+        assert!(report.passed, "unexpected errors: {:?}", report.errors);
+        assert_eq!(report.stats.records_validated, 3);
+        assert_eq!(report.snapshot_id, Some(second));
+    }
+
+    #[tokio::test]
+    async fn test_validate_table_at_snapshot() {
+        let warehouse = TestWarehouse::new().await.unwrap();
+        let table = warehouse
+            .create_table(&["db"], "users", &users(vec![Some("ada"), None]))
+            .await
+            .unwrap();
+        let first = IcebergValidator::new(table.config())
+            .await
+            .unwrap()
+            .current_snapshot()
+            .await
+            .unwrap()
+            .unwrap();
+        let table = warehouse
+            .append(&table, &users(vec![Some("grace")]))
+            .await
+            .unwrap();
+
+        let validator = IcebergValidator::new(table.config())
+            .await
+            .unwrap()
+            .with_snapshot(first.snapshot_id);
+        assert_eq!(validator.read_sample_data(100).await.unwrap().len(), 2);
+        let report = validator
+            .validate_table(&contract(), &ValidationContext::default())
+            .await
+            .unwrap();
+        assert!(!report.passed);
+        assert_eq!(report.stats.records_validated, 2);
+        assert_eq!(report.stats.deletes.unwrap().data_records, 2);
+
+        // The snapshot is already an ancestor of main
+        let staged = validator
+            .staged_snapshot(&StagedRef::Snapshot(first.snapshot_id))
+            .await
+            .unwrap();
+        assert_eq!(staged.publication, Publication::UpToDate);
+        assert_eq!(staged.publish_sql("lake", "db.users"), None);
+        assert!(
+            validator
+                .staged_snapshot(&StagedRef::Branch("audit".to_string()))
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_validate_table_pinned_by_config() {
+        let warehouse = TestWarehouse::new().await.unwrap();
+        let table = warehouse
+            .create_table(&["db"], "users", &users(vec![Some("ada"), None]))
+            .await
+            .unwrap();
+        let first = IcebergValidator::new(table.config())
+            .await
+            .unwrap()
+            .current_snapshot()
+            .await
+            .unwrap()
+            .unwrap();
+        let table = warehouse
+            .append(&table, &users(vec![Some("grace")]))
+            .await
+            .unwrap();
+        let validate = |config| async move {
+            IcebergValidator::new(config)
+                .await
+                .unwrap()
+                .validate_table(&contract(), &ValidationContext::default())
+                .await
+        };
+
+        // Unpinned runs record the current snapshot
+        let current = validate(table.config()).await.unwrap();
+        assert_eq!(current.stats.records_validated, 3);
+        assert_ne!(current.snapshot_id, Some(first.snapshot_id));
+
+        let mut config = table.config();
+        config.snapshot = Some(SnapshotSelector::Id(first.snapshot_id));
+        let report = validate(config).await.unwrap();
+        assert_eq!(report.stats.records_validated, 2);
+        assert_eq!(report.snapshot_id, Some(first.snapshot_id));
+
+        let mut config = table.config();
+        config.snapshot = first.committed_at.map(SnapshotSelector::AsOf);
+        let report = validate(config).await.unwrap();
+        assert_eq!(report.snapshot_id, Some(first.snapshot_id));
+
+        // Before the first commit there is nothing to validate
+        let mut config = table.config();
+        config.snapshot = first
+            .committed_at
+            .map(|time| SnapshotSelector::AsOf(time - chrono::Duration::days(1)));
+        assert!(validate(config).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_detect_access_drift_from_table_properties() {
+        let warehouse = TestWarehouse::new().await.unwrap();
+        let table = warehouse
+            .create_table(&["db"], "users", &users(vec![Some("ada")]))
+            .await
+            .unwrap();
+        let mut contract = contract();
+        contract.schema.fields[1].access = Some(FieldAccess {
+            classification: Some("pii".to_string()),
+            allowed_roles: vec!["support".to_string()],
+        });
+
+        // Without access properties there is nothing to compare
+        let validator = IcebergValidator::new(table.config()).await.unwrap();
+        assert!(
+            validator
+                .detect_access_drift(&contract)
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        let table = warehouse
+            .set_properties(&table, access_properties(&contract.schema))
+            .await
+            .unwrap();
+        let validator = IcebergValidator::new(table.config()).await.unwrap();
+        let drift = validator.detect_access_drift(&contract).await.unwrap();
+        assert!(drift.unwrap().is_empty());
+
+        let table = warehouse
+            .set_properties(
+                &table,
+                [(
+                    "dce.access.name.allowed-roles".to_string(),
+                    "support,marketing".to_string(),
+                )],
+            )
+            .await
+            .unwrap();
+        let validator = IcebergValidator::new(table.config()).await.unwrap();
+        let drift = validator
+            .detect_access_drift(&contract)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(drift.is_breaking());
+        assert!(matches!(
+            drift.changes.as_slice(),
+            [AccessChange::RolesChanged { field, .. }] if field == "name"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_validate_from_stats() {
+        let warehouse = TestWarehouse::new().await.unwrap();
+        let table = warehouse
+            .create_table(&["db"], "users", &users(vec![Some("ada"), None]))
+            .await
+            .unwrap();
+        let table = warehouse
+            .append(&table, &users(vec![Some("alan")]))
+            .await
+            .unwrap();
+        let contract = ContractBuilder::new("users", "test-team")
+            .location("iceberg://test/db/users")
+            .format(DataFormat::Iceberg)
+            .field(
+                FieldBuilder::new("id", "int64")
+                    .nullable(false)
+                    .constraint(FieldConstraints::Range { min: 1.0, max: 1.0 })
+                    .build(),
+            )
+            .field(
+                FieldBuilder::new("name", "string")
+                    .nullable(false)
+                    .constraint(FieldConstraints::Pattern {
+                        regex: "^[a-z]+$".to_string(),
+                    })
+                    .build(),
+            )
+            .build();
+
+        let validator = IcebergValidator::new(table.config()).await.unwrap();
+        let report = validator
+            .validate_from_stats(&contract, &ValidationContext::default())
+            .await
+            .unwrap();
+
+        // Both files are counted, the second one holds the newer rows
+        assert_eq!(report.stats.records_validated, 3);
+        assert_eq!(report.stats.columns["name"].null_count, 1);
+        assert_eq!(report.stats.columns["id"].max.as_deref(), Some("2"));
+        assert!(report.snapshot_id.is_some());
+
+        let codes: Vec<&str> = report.errors.iter().map(|error| error.code.code).collect();
+        assert_eq!(codes, vec!["DCE0203", "DCE0102"]);
+        assert_eq!(report.inconclusive.len(), 1);
+        assert_eq!(report.inconclusive[0].code.code, "DCE0307");
+
+        // Statistics cannot be narrowed to the rows added since a snapshot
+        let err = validator
+            .clone()
+            .with_base_snapshot(report.snapshot_id.unwrap())
+            .validate_from_stats(&contract, &ValidationContext::default())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("incremental rows"));
+    }
+
+    fn events(days: i32) -> RecordBatch {
+        RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("id", DataType::Int64, false),
+                Field::new("day", DataType::Date32, false),
+            ])),
+            vec![
+                Arc::new(Int64Array::from(vec![1])),
+                Arc::new(Date32Array::from(vec![days])),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn events_contract(max_delay: &str, metric: &str, source: FreshnessSource) -> Contract {
+        ContractBuilder::new("events", "test-team")
+            .location("iceberg://test/db/events")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("id", "int64").nullable(false).build())
+            .field(FieldBuilder::new("day", "date").nullable(false).build())
+            .quality_checks(
+                QualityChecksBuilder::new()
+                    .freshness(FreshnessCheck {
+                        max_delay: max_delay.to_string(),
+                        metric: metric.to_string(),
+                        source: Some(source),
+                        allowed_lateness: None,
+                        severity: Some(Severity::Error),
+                    })
+                    .build(),
+            )
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_freshness_from_table_metadata() {
+        let today =
+            (chrono::Utc::now().date_naive() - chrono::NaiveDate::default()).num_days() as i32;
+        let warehouse = TestWarehouse::new().await.unwrap();
+        let table = warehouse
+            .create_partitioned_table(
+                &["db"],
+                "events",
+                &events(today - 10),
+                "day",
+                Transform::Day,
+                Literal::date(today - 10),
+            )
+            .await
+            .unwrap();
+        let table = warehouse
+            .append_partition(&table, &events(today - 1), Literal::date(today - 1))
+            .await
+            .unwrap();
+        let validator = IcebergValidator::new(table.config()).await.unwrap();
+        let context = ValidationContext::default();
+        let errors = |report: ValidationReport| -> Vec<String> {
+            report.errors.into_iter().map(|e| e.message).collect()
+        };
+
+        // The newest partition started yesterday
+        let fresh = events_contract("2d", "day", FreshnessSource::Partition);
+        let report = validator.validate_table(&fresh, &context).await.unwrap();
+        assert!(report.passed, "{:?}", report.errors);
+        let stale = events_contract("12h", "day", FreshnessSource::Partition);
+        let report = validator.validate_table(&stale, &context).await.unwrap();
+        assert_eq!(report.errors.len(), 1);
+        assert!(errors(report)[0].contains("data is stale by 1d"));
+
+        // The last commit just happened, whatever the rows say
+        let committed = events_contract("1h", "", FreshnessSource::SnapshotCommittedAt);
+        let report = validator
+            .validate_table(&committed, &context)
+            .await
+            .unwrap();
+        assert!(report.passed, "{:?}", report.errors);
+        let report = validator
+            .validate_from_stats(&committed, &context)
+            .await
+            .unwrap();
+        assert!(report.passed, "{:?}", report.errors);
+        assert_eq!(report.stats.constraints_evaluated, 3);
+
+        let unpartitioned = events_contract("1d", "id", FreshnessSource::Partition);
+        let err = validator
+            .validate_table(&unpartitioned, &context)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("not partitioned by"));
+    }
+}
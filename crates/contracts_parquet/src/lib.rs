@@ -0,0 +1,112 @@
+//! Parquet file integration for Data Contracts Engine.
+//!
+//! This module validates data contracts against Parquet files on local disk
+//! or in S3. The schema is read from the file footers, so schema-only runs
+//! never touch row data; data validation streams the row groups through the
+//! DataFusion engine of [`DataValidator`](contracts_validator::DataValidator).
+//!
+//! A location names either one file or a directory (prefix) of `.parquet`
+//! files sharing one schema:
+//!
+//! - `data/events.parquet`, `/abs/path/events/` or `file:///abs/path/events`
+//! - `s3://bucket/path/events.parquet` or `s3://bucket/path/events/`
+//!
+//! S3 credentials and region are taken from the standard `AWS_*` environment
+//! variables.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use contracts_core::ValidationContext;
+//! use contracts_parquet::ParquetValidator;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let validator = ParquetValidator::new("s3://bucket/events/").await?;
+//!
+//! // Load contract
+//! // let contract = ...;
+//!
+//! // Validate files against contract
+//! // let report = validator.validate(&contract, &ValidationContext::new()).await?;
+//!
+//! // Or validate only the footer schema (no row data read)
+//! // let report = validator.validate_schema_only(&contract).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use contracts_core::{BoxError, Coded, ErrorCode, codes};
+use thiserror::Error;
+
+mod location;
+mod schema;
+mod validator;
+
+pub use location::ParquetLocation;
+pub use schema::{arrow_type_to_dce_type, check_file_schema, extract_schema_from_arrow};
+pub use validator::ParquetValidator;
+
+/// Error types specific to Parquet operations.
+#[derive(Error, Debug)]
+pub enum ParquetError {
+    /// The location is not a path or a supported URL
+    #[error("Invalid Parquet location: {0}")]
+    InvalidLocation(String),
+
+    /// No Parquet file exists at the location
+    #[error("No Parquet files found at {0}")]
+    NotFound(String),
+
+    /// A file or its footer could not be read
+    #[error("Failed to read Parquet data: {message}")]
+    ReadError {
+        /// What was being read
+        message: String,
+        /// The underlying failure
+        #[source]
+        source: BoxError,
+    },
+
+    /// An Arrow type of the file has no DCE equivalent
+    #[error("Failed to convert Parquet type to DCE type: {0}")]
+    TypeConversionError(String),
+}
+
+impl ParquetError {
+    /// Creates a [`ParquetError::ReadError`] caused by `source`.
+    pub fn read(message: impl Into<String>, source: impl Into<BoxError>) -> Self {
+        Self::ReadError {
+            message: message.into(),
+            source: source.into(),
+        }
+    }
+}
+
+impl Coded for ParquetError {
+    fn error_code(&self) -> &'static ErrorCode {
+        match self {
+            Self::InvalidLocation(_) => &codes::INVALID_LOCATION,
+            Self::NotFound(_) => &codes::PARQUET_NOT_FOUND,
+            Self::ReadError { .. } => &codes::PARQUET_READ,
+            Self::TypeConversionError(_) => &codes::PARQUET_TYPE_CONVERSION,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::error::Error as _;
+
+    #[test]
+    fn test_error_display() {
+        let err = ParquetError::read("footer of events.parquet", "unexpected end of file");
+        assert_eq!(
+            err.to_string(),
+            "Failed to read Parquet data: footer of events.parquet"
+        );
+        assert_eq!(err.source().unwrap().to_string(), "unexpected end of file");
+        assert_eq!(err.error_code().code, "DCE2102");
+    }
+}
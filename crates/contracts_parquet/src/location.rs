@@ -0,0 +1,235 @@
+//! Resolution of contract locations to Parquet files in an object store.
+
+use crate::ParquetError;
+use contracts_validator::{LimitedStore, RequestLimiter};
+use futures::TryStreamExt;
+use object_store::local::LocalFileSystem;
+use object_store::path::Path;
+use object_store::{ObjectMeta, ObjectStore};
+use std::sync::Arc;
+use tracing::debug;
+use url::Url;
+
+/// Extension of the files read from directory locations.
+const PARQUET_EXTENSION: &str = ".parquet";
+
+/// A Parquet file or directory, with the object store holding it.
+#[derive(Debug, Clone)]
+pub struct ParquetLocation {
+    store: Arc<dyn ObjectStore>,
+    store_url: Url,
+    path: Path,
+}
+
+impl ParquetLocation {
+    /// Resolves a contract location.
+    ///
+    /// Local paths (relative, absolute or `file://` URLs) are read from the
+    /// local file system. `s3://bucket/key` locations are read from S3, with
+    /// credentials and region taken from the `AWS_*` environment variables.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use contracts_parquet::ParquetLocation;
+    ///
+    /// let location = ParquetLocation::parse("/data/events/").unwrap();
+    /// assert_eq!(location.path(), "data/events");
+    ///
+    /// assert!(ParquetLocation::parse("ftp://host/events.parquet").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the location has an unsupported scheme, or if the
+    /// S3 client cannot be configured.
+    pub fn parse(location: &str) -> Result<Self, ParquetError> {
+        let invalid =
+            |reason: String| ParquetError::InvalidLocation(format!("{location}: {reason}"));
+
+        if !location.contains("://") {
+            let path = std::path::absolute(location).map_err(|e| invalid(e.to_string()))?;
+            return Self::local(&path).map_err(invalid);
+        }
+
+        let url = Url::parse(location).map_err(|e| invalid(e.to_string()))?;
+        match url.scheme() {
+            "file" => {
+                let path = url
+                    .to_file_path()
+                    .map_err(|()| invalid("not a local file URL".to_string()))?;
+                Self::local(&path).map_err(invalid)
+            }
+            "s3" => Self::s3(&url).map_err(invalid),
+            scheme => Err(invalid(format!(
+                "unsupported scheme '{scheme}', expected a local path or s3://"
+            ))),
+        }
+    }
+
+    fn local(path: &std::path::Path) -> Result<Self, String> {
+        Ok(Self {
+            store: Arc::new(LocalFileSystem::new()),
+            store_url: Url::parse("file:///").map_err(|e| e.to_string())?,
+            path: Path::from_absolute_path(path).map_err(|e| e.to_string())?,
+        })
+    }
+
+    #[cfg(feature = "s3")]
+    fn s3(url: &Url) -> Result<Self, String> {
+        use object_store::aws::AmazonS3Builder;
+
+        let bucket = url
+            .host_str()
+            .filter(|bucket| !bucket.is_empty())
+            .ok_or_else(|| "missing bucket name".to_string())?;
+        let store = AmazonS3Builder::from_env()
+            .with_bucket_name(bucket)
+            .build()
+            .map_err(|e| e.to_string())?;
+
+        Ok(Self {
+            store: Arc::new(store),
+            store_url: Url::parse(&format!("s3://{bucket}")).map_err(|e| e.to_string())?,
+            path: Path::from_url_path(url.path()).map_err(|e| e.to_string())?,
+        })
+    }
+
+    #[cfg(not(feature = "s3"))]
+    fn s3(_url: &Url) -> Result<Self, String> {
+        Err("s3:// locations need the `s3` feature".to_string())
+    }
+
+    /// Makes every request to the object store wait for `limiter`, so the
+    /// files are read under the same limits as the rest of a run.
+    #[must_use]
+    pub fn with_limiter(mut self, limiter: RequestLimiter) -> Self {
+        self.store = Arc::new(LimitedStore::new(self.store, limiter));
+        self
+    }
+
+    /// Returns the object store holding the files.
+    pub fn store(&self) -> &Arc<dyn ObjectStore> {
+        &self.store
+    }
+
+    /// Returns the URL of the object store, such as `s3://bucket`.
+    pub fn store_url(&self) -> &Url {
+        &self.store_url
+    }
+
+    /// Returns the path of the file or directory within the store.
+    pub fn path(&self) -> &str {
+        self.path.as_ref()
+    }
+
+    /// Lists the Parquet files at the location.
+    ///
+    /// A location naming an object returns that object; otherwise every
+    /// `.parquet` object under it is returned, in path order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParquetError::NotFound`] if no file exists at the location,
+    /// or an error if the store cannot be listed.
+    pub async fn list_files(&self) -> Result<Vec<ObjectMeta>, ParquetError> {
+        match self.store.head(&self.path).await {
+            Ok(meta) => return Ok(vec![meta]),
+            Err(object_store::Error::NotFound { .. }) => {}
+            Err(e) => {
+                return Err(ParquetError::read(
+                    format!("Failed to access {}", self.url()),
+                    e,
+                ));
+            }
+        }
+
+        let mut files: Vec<ObjectMeta> = self
+            .store
+            .list(Some(&self.path))
+            .try_filter(|meta| {
+                futures::future::ready(meta.location.as_ref().ends_with(PARQUET_EXTENSION))
+            })
+            .try_collect()
+            .await
+            .map_err(|e| ParquetError::read(format!("Failed to list {}", self.url()), e))?;
+        files.sort_by(|a, b| a.location.cmp(&b.location));
+
+        debug!("Found {} Parquet files under {}", files.len(), self.url());
+        if files.is_empty() {
+            return Err(ParquetError::NotFound(self.url()));
+        }
+        Ok(files)
+    }
+
+    /// Returns the URL of `path` in the store, as DataFusion expects it.
+    pub(crate) fn object_url(&self, path: &Path) -> String {
+        format!(
+            "{}/{}",
+            self.store_url
+                .as_str()
+                .strip_suffix('/')
+                .unwrap_or(self.store_url.as_str()),
+            path.as_ref()
+        )
+    }
+
+    /// Returns the URL of the location, as DataFusion expects it.
+    pub(crate) fn url(&self) -> String {
+        self.object_url(&self.path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_local_paths() {
+        let location = ParquetLocation::parse("/data/events.parquet").unwrap();
+        assert_eq!(location.path(), "data/events.parquet");
+        assert_eq!(location.url(), "file:///data/events.parquet");
+
+        let location = ParquetLocation::parse("file:///data/events").unwrap();
+        assert_eq!(location.path(), "data/events");
+
+        let relative = ParquetLocation::parse("events.parquet").unwrap();
+        assert!(relative.path().ends_with("/events.parquet"));
+    }
+
+    #[cfg(feature = "s3")]
+    #[test]
+    fn test_parse_s3_locations() {
+        let location = ParquetLocation::parse("s3://bucket/lake/events/").unwrap();
+        assert_eq!(location.store_url().as_str(), "s3://bucket");
+        assert_eq!(location.path(), "lake/events");
+        assert_eq!(location.url(), "s3://bucket/lake/events");
+    }
+
+    #[tokio::test]
+    async fn test_limited_location_waits_for_the_limiter() {
+        use contracts_validator::RequestLimits;
+        use std::time::Duration;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(dir.path().join("events.parquet"), "").unwrap();
+        let limiter = RequestLimiter::new(RequestLimits::unlimited().max_concurrent(1));
+        let location = ParquetLocation::parse(dir.path().to_str().unwrap())
+            .unwrap()
+            .with_limiter(limiter.clone());
+
+        let held = limiter.acquire().await;
+        let blocked = tokio::time::timeout(Duration::from_millis(50), location.list_files()).await;
+        assert!(blocked.is_err(), "listing ran while the only slot was held");
+        drop(held);
+
+        assert_eq!(location.list_files().await.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_parse_rejects_unsupported_schemes() {
+        let err = ParquetLocation::parse("gs://bucket/events.parquet").unwrap_err();
+        assert!(matches!(err, ParquetError::InvalidLocation(_)));
+        assert!(err.to_string().contains("unsupported scheme 'gs'"));
+    }
+}
@@ -0,0 +1,360 @@
+//! Schema extraction from Parquet footers and comparison with contracts.
+
+use crate::ParquetError;
+use arrow_schema::{DataType as ArrowType, Field as ArrowField, Schema as ArrowSchema};
+use contracts_core::{
+    CheckKind, Contract, DataFormat, DataType, Field as ContractField, PrimitiveType,
+    Schema as ContractSchema, StructField, Violation, codes,
+};
+use tracing::{debug, info};
+
+/// Converts the Arrow type of a Parquet column to a DCE `DataType`.
+///
+/// Narrow integers map to `int32` and wide ones to `int64`; every timestamp
+/// precision maps to `timestamp`, and every decimal width to `decimal`.
+///
+/// # Errors
+///
+/// Returns an error for Arrow types without a DCE equivalent, such as
+/// intervals or unions.
+///
+/// # Example
+///
+/// ```rust
+/// use arrow_schema::DataType as ArrowType;
+/// use contracts_parquet::arrow_type_to_dce_type;
+///
+/// let dce = arrow_type_to_dce_type(&ArrowType::Int16).unwrap();
+/// assert_eq!(dce.to_string(), "int32");
+/// ```
+pub fn arrow_type_to_dce_type(arrow_type: &ArrowType) -> Result<DataType, ParquetError> {
+    let primitive = match arrow_type {
+        ArrowType::Boolean => PrimitiveType::Boolean,
+        ArrowType::Int8
+        | ArrowType::Int16
+        | ArrowType::Int32
+        | ArrowType::UInt8
+        | ArrowType::UInt16 => PrimitiveType::Int32,
+        ArrowType::Int64 | ArrowType::UInt32 | ArrowType::UInt64 => PrimitiveType::Int64,
+        ArrowType::Float16 | ArrowType::Float32 => PrimitiveType::Float32,
+        ArrowType::Float64 => PrimitiveType::Float64,
+        ArrowType::Utf8 | ArrowType::LargeUtf8 | ArrowType::Utf8View => PrimitiveType::String,
+        ArrowType::Binary
+        | ArrowType::LargeBinary
+        | ArrowType::BinaryView
+        | ArrowType::FixedSizeBinary(_) => PrimitiveType::Binary,
+        ArrowType::Timestamp(_, _) => PrimitiveType::Timestamp,
+        ArrowType::Date32 | ArrowType::Date64 => PrimitiveType::Date,
+        ArrowType::Time32(_) | ArrowType::Time64(_) => PrimitiveType::Time,
+        ArrowType::Decimal32(_, _)
+        | ArrowType::Decimal64(_, _)
+        | ArrowType::Decimal128(_, _)
+        | ArrowType::Decimal256(_, _) => PrimitiveType::Decimal,
+        ArrowType::List(element)
+        | ArrowType::LargeList(element)
+        | ArrowType::ListView(element)
+        | ArrowType::LargeListView(element)
+        | ArrowType::FixedSizeList(element, _) => {
+            return Ok(DataType::List {
+                element_type: Box::new(arrow_type_to_dce_type(element.data_type())?),
+                contains_null: element.is_nullable(),
+            });
+        }
+        ArrowType::Map(entries, _) => {
+            let ArrowType::Struct(kv) = entries.data_type() else {
+                return Err(unsupported(arrow_type));
+            };
+            let [key, value] = kv.iter().collect::<Vec<_>>()[..] else {
+                return Err(unsupported(arrow_type));
+            };
+            return Ok(DataType::Map {
+                key_type: Box::new(arrow_type_to_dce_type(key.data_type())?),
+                value_type: Box::new(arrow_type_to_dce_type(value.data_type())?),
+                value_contains_null: value.is_nullable(),
+            });
+        }
+        ArrowType::Struct(fields) => {
+            return Ok(DataType::Struct {
+                fields: fields
+                    .iter()
+                    .map(|f| {
+                        Ok(StructField {
+                            name: f.name().clone(),
+                            data_type: arrow_type_to_dce_type(f.data_type())?,
+                            nullable: f.is_nullable(),
+                        })
+                    })
+                    .collect::<Result<_, ParquetError>>()?,
+            });
+        }
+        ArrowType::Dictionary(_, value) => return arrow_type_to_dce_type(value),
+        _ => return Err(unsupported(arrow_type)),
+    };
+    Ok(DataType::Primitive(primitive))
+}
+
+fn unsupported(arrow_type: &ArrowType) -> ParquetError {
+    ParquetError::TypeConversionError(format!("unsupported Arrow type {arrow_type}"))
+}
+
+/// Extracts a DCE schema from the Arrow schema of a Parquet footer.
+///
+/// # Errors
+///
+/// Returns an error if a column type has no DCE equivalent.
+pub fn extract_schema_from_arrow(
+    arrow_schema: &ArrowSchema,
+    location: &str,
+) -> Result<ContractSchema, ParquetError> {
+    info!("Extracting schema from Parquet files at {}", location);
+
+    let fields = arrow_schema
+        .fields()
+        .iter()
+        .map(|field| convert_arrow_field(field))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    debug!("Extracted {} fields from Parquet schema", fields.len());
+
+    Ok(ContractSchema {
+        fields,
+        format: DataFormat::Parquet,
+        location: location.to_string(),
+        catalog: None,
+        namespace: None,
+        table: None,
+        connection: None,
+    })
+}
+
+/// Converts an Arrow field to a DCE contract field.
+fn convert_arrow_field(field: &ArrowField) -> Result<ContractField, ParquetError> {
+    let field_type = arrow_type_to_dce_type(field.data_type()).map_err(|e| match e {
+        ParquetError::TypeConversionError(message) => {
+            ParquetError::TypeConversionError(format!("column '{}': {}", field.name(), message))
+        }
+        other => other,
+    })?;
+
+    Ok(ContractField {
+        name: field.name().clone(),
+        field_type,
+        nullable: field.is_nullable(),
+        required: None,
+        description: None,
+        tags: None,
+        constraints: None,
+        severity: None,
+        default: None,
+        deprecated: None,
+        access: None,
+    })
+}
+
+/// Compares the schema of a Parquet footer with a contract.
+///
+/// Reports required contract fields missing from the file, and fields whose
+/// column type cannot hold values of the declared type. Columns may be
+/// narrower than declared: an `int32` column satisfies an `int64` field, a
+/// `float32` column a `float64` field, and a string or binary column a
+/// `uuid` field. Nullability is left to data validation, since an optional
+/// column may hold no nulls.
+///
+/// # Example
+///
+/// ```rust
+/// use arrow_schema::{DataType, Field, Schema};
+/// use contracts_core::{ContractBuilder, DataFormat, FieldBuilder};
+/// use contracts_parquet::check_file_schema;
+///
+/// let contract = ContractBuilder::new("events", "team")
+///     .location("events.parquet")
+///     .format(DataFormat::Parquet)
+///     .field(FieldBuilder::new("id", "int64").build())
+///     .field(FieldBuilder::new("name", "string").required(true).build())
+///     .build();
+/// let file = Schema::new(vec![Field::new("id", DataType::Utf8, false)]);
+///
+/// let violations = check_file_schema(&contract, &file);
+/// assert_eq!(violations.len(), 2);
+/// assert_eq!(violations[0].code.code, "DCE0103");
+/// assert_eq!(violations[1].code.code, "DCE0101");
+/// ```
+pub fn check_file_schema(contract: &Contract, file_schema: &ArrowSchema) -> Vec<Violation> {
+    let mut violations = Vec::new();
+
+    for field in &contract.schema.fields {
+        let Ok(column) = file_schema.field_with_name(&field.name) else {
+            if field.is_required() {
+                violations.push(
+                    Violation::new(
+                        &codes::MISSING_FIELD,
+                        CheckKind::Schema,
+                        format!(
+                            "Field '{}' is declared in the contract but missing from the data",
+                            field.name
+                        ),
+                    )
+                    .with_field(&field.name),
+                );
+            }
+            continue;
+        };
+
+        let column_type = arrow_type_to_dce_type(column.data_type());
+        let compatible = column_type
+            .as_ref()
+            .is_ok_and(|column_type| holds(&field.field_type, column_type));
+        if !compatible {
+            let found = match column_type {
+                Ok(column_type) => column_type.to_string(),
+                Err(_) => column.data_type().to_string(),
+            };
+            violations.push(
+                Violation::new(
+                    &codes::TYPE_MISMATCH,
+                    CheckKind::Schema,
+                    format!(
+                        "Field '{}' is declared as {} but the Parquet column is {}",
+                        field.name, field.field_type, found
+                    ),
+                )
+                .with_field(&field.name),
+            );
+        }
+    }
+    violations
+}
+
+/// Returns whether values of a `column` type can be read as `declared`.
+fn holds(declared: &DataType, column: &DataType) -> bool {
+    use PrimitiveType::*;
+
+    match (declared, column) {
+        (DataType::Primitive(declared), DataType::Primitive(column)) => {
+            matches!(
+                (declared, column),
+                (Int64, Int32) | (Float64, Float32) | (Uuid, String | Binary)
+            ) || declared == column
+        }
+        (
+            DataType::List {
+                element_type: declared,
+                ..
+            },
+            DataType::List {
+                element_type: column,
+                ..
+            },
+        ) => holds(declared, column),
+        (
+            DataType::Map {
+                key_type: declared_key,
+                value_type: declared_value,
+                ..
+            },
+            DataType::Map {
+                key_type: column_key,
+                value_type: column_value,
+                ..
+            },
+        ) => holds(declared_key, column_key) && holds(declared_value, column_value),
+        (DataType::Struct { fields: declared }, DataType::Struct { fields: column }) => {
+            declared.iter().all(|declared| {
+                column
+                    .iter()
+                    .find(|c| c.name == declared.name)
+                    .is_some_and(|c| holds(&declared.data_type, &c.data_type))
+            })
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_schema::{Fields, TimeUnit};
+    use contracts_core::{ContractBuilder, FieldBuilder};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_arrow_type_to_dce_type() {
+        let cases = [
+            (ArrowType::UInt32, "int64"),
+            (
+                ArrowType::Timestamp(TimeUnit::Nanosecond, None),
+                "timestamp",
+            ),
+            (ArrowType::Decimal128(10, 2), "decimal"),
+            (
+                ArrowType::Dictionary(Box::new(ArrowType::Int32), Box::new(ArrowType::Utf8)),
+                "string",
+            ),
+            (
+                ArrowType::List(Arc::new(ArrowField::new("item", ArrowType::Int64, true))),
+                "list<int64>",
+            ),
+            (
+                ArrowType::Struct(Fields::from(vec![ArrowField::new(
+                    "city",
+                    ArrowType::Utf8,
+                    true,
+                )])),
+                "struct<city:string>",
+            ),
+        ];
+        for (arrow_type, expected) in cases {
+            assert_eq!(
+                arrow_type_to_dce_type(&arrow_type).unwrap().to_string(),
+                expected
+            );
+        }
+        assert!(arrow_type_to_dce_type(&ArrowType::Null).is_err());
+    }
+
+    #[test]
+    fn test_check_file_schema_accepts_narrower_columns() {
+        let contract = ContractBuilder::new("events", "team")
+            .location("events.parquet")
+            .format(DataFormat::Parquet)
+            .field(FieldBuilder::new("id", "int64").nullable(false).build())
+            .field(FieldBuilder::new("score", "float64").build())
+            .field(FieldBuilder::new("tags", "list<string>").build())
+            .field(
+                FieldBuilder::new("legacy", "string")
+                    .required(false)
+                    .build(),
+            )
+            .build();
+        let file = ArrowSchema::new(vec![
+            ArrowField::new("id", ArrowType::Int32, true),
+            ArrowField::new("score", ArrowType::Float32, true),
+            ArrowField::new(
+                "tags",
+                ArrowType::List(Arc::new(ArrowField::new("item", ArrowType::Utf8, true))),
+                true,
+            ),
+        ]);
+
+        assert!(check_file_schema(&contract, &file).is_empty());
+    }
+
+    #[test]
+    fn test_check_file_schema_reports_wider_columns() {
+        let contract = ContractBuilder::new("events", "team")
+            .location("events.parquet")
+            .format(DataFormat::Parquet)
+            .field(FieldBuilder::new("id", "int32").build())
+            .build();
+        let file = ArrowSchema::new(vec![ArrowField::new("id", ArrowType::Int64, false)]);
+
+        let violations = check_file_schema(&contract, &file);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].field.as_deref(), Some("id"));
+        assert_eq!(
+            violations[0].message,
+            "Field 'id' is declared as int32 but the Parquet column is int64"
+        );
+    }
+}
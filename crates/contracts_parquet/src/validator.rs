@@ -0,0 +1,238 @@
+//! Main Parquet validator implementation.
+
+use crate::{ParquetError, ParquetLocation, check_file_schema, extract_schema_from_arrow};
+use arrow_schema::SchemaRef;
+use contracts_core::{Contract, ValidationContext, ValidationReport, codes};
+use contracts_validator::{DataSet, DataValidator};
+use datafusion::prelude::{ParquetReadOptions, SessionContext};
+use futures::{StreamExt, TryStreamExt};
+use object_store::ObjectMeta;
+use parquet::arrow::arrow_reader::{ArrowReaderMetadata, ArrowReaderOptions};
+use parquet::arrow::async_reader::ParquetObjectReader;
+use tracing::{debug, info, warn};
+
+/// Number of footers read concurrently.
+const FOOTER_CONCURRENCY: usize = 16;
+
+/// Validator for Parquet files against data contracts.
+///
+/// Reads schemas from file footers and streams row groups through the
+/// DataFusion engine for data validation.
+pub struct ParquetValidator {
+    location: ParquetLocation,
+    files: Vec<ObjectMeta>,
+}
+
+impl ParquetValidator {
+    /// Creates a validator for the Parquet file or directory at `location`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the location is invalid or holds no Parquet files.
+    pub async fn new(location: &str) -> Result<Self, ParquetError> {
+        info!("Initializing Parquet validator for: {}", location);
+        Self::with_location(ParquetLocation::parse(location)?).await
+    }
+
+    /// Creates a validator for an already resolved location.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the location holds no Parquet files.
+    pub async fn with_location(location: ParquetLocation) -> Result<Self, ParquetError> {
+        let files = location.list_files().await?;
+        info!(
+            "Validating {} Parquet file(s) at {}",
+            files.len(),
+            location.url()
+        );
+        Ok(Self { location, files })
+    }
+
+    /// Returns the URLs of the files read by this validator.
+    pub fn files(&self) -> Vec<String> {
+        self.files
+            .iter()
+            .map(|meta| self.location.object_url(&meta.location))
+            .collect()
+    }
+
+    /// Reads the Arrow schema shared by the files, from their footers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a footer cannot be read, or if the files do not
+    /// share one schema.
+    pub async fn arrow_schema(&self) -> Result<SchemaRef, ParquetError> {
+        let footers: Vec<(String, SchemaRef)> = futures::stream::iter(&self.files)
+            .map(|meta| self.read_footer(meta))
+            .buffered(FOOTER_CONCURRENCY)
+            .try_collect()
+            .await?;
+
+        let (first_file, schema) = &footers[0];
+        for (file, other) in &footers[1..] {
+            if other.fields() != schema.fields() {
+                return Err(ParquetError::read(
+                    format!("Schema of {file} differs from {first_file}"),
+                    format!("expected {schema}, found {other}"),
+                ));
+            }
+        }
+        Ok(schema.clone())
+    }
+
+    async fn read_footer(&self, meta: &ObjectMeta) -> Result<(String, SchemaRef), ParquetError> {
+        let url = self.location.object_url(&meta.location);
+        debug!("Reading footer of {}", url);
+
+        let mut reader =
+            ParquetObjectReader::new(self.location.store().clone(), meta.location.clone())
+                .with_file_size(meta.size);
+        let metadata = ArrowReaderMetadata::load_async(&mut reader, ArrowReaderOptions::default())
+            .await
+            .map_err(|e| ParquetError::read(format!("Failed to read footer of {url}"), e))?;
+
+        debug!(
+            "{} holds {} rows in {} row groups",
+            url,
+            metadata.metadata().file_metadata().num_rows(),
+            metadata.metadata().num_row_groups()
+        );
+        Ok((url, metadata.schema().clone()))
+    }
+
+    /// Extracts the DCE schema of the files.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a footer cannot be read or a column type has no
+    /// DCE equivalent.
+    pub async fn extract_schema(&self) -> Result<contracts_core::Schema, ParquetError> {
+        extract_schema_from_arrow(&*self.arrow_schema().await?, &self.location.url())
+    }
+
+    /// Validates the files against a contract.
+    ///
+    /// Column types are checked against the footers, then the row groups are
+    /// streamed through [`DataValidator::validate_with_context`]. Sampled,
+    /// time-windowed and filtered runs read only the rows they need.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract` - The data contract to validate against
+    /// * `context` - Validation context with options (sample_size, schema_only, strict, etc.)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the files cannot be read.
+    pub async fn validate(
+        &self,
+        contract: &Contract,
+        context: &ValidationContext,
+    ) -> Result<ValidationReport, ParquetError> {
+        if context.schema_only {
+            return self.validate_schema_only(contract, context).await;
+        }
+
+        info!(
+            "Validating Parquet files against contract: {}",
+            contract.name
+        );
+
+        let file_schema = self.arrow_schema().await?;
+        let filter = match contracts_validator::row_predicate(contract, context) {
+            Ok(filter) => filter,
+            Err(e) => return Ok(ValidationReport::failure(e.to_violation())),
+        };
+
+        let ctx = SessionContext::new();
+        ctx.register_object_store(self.location.store_url(), self.location.store().clone());
+        let table = ctx
+            .read_parquet(
+                self.files(),
+                ParquetReadOptions::default().schema(&file_schema),
+            )
+            .await
+            .map_err(|e| ParquetError::read(format!("Failed to open {}", self.location.url()), e))?
+            .into_view();
+
+        if filter.is_some() || context.sample_size.is_some() {
+            ctx.register_table("raw_data", table)
+                .map_err(|e| ParquetError::read("Failed to register table", e))?;
+            contracts_validator::create_data_view(&ctx, filter.as_deref(), context.sample_size)
+                .await
+                .map_err(|e| ParquetError::read("Failed to sample rows", e))?;
+        } else {
+            ctx.register_table("data", table)
+                .map_err(|e| ParquetError::read("Failed to register table", e))?;
+        }
+
+        let mut validator = DataValidator::new();
+        let mut report = validator
+            .validate_with_context(contract, &ctx, context)
+            .await;
+
+        // Missing fields are reported by the engine from the registered table
+        for violation in check_file_schema(contract, &file_schema) {
+            if violation.code != &codes::MISSING_FIELD {
+                report.add_violation(violation);
+            }
+        }
+        report.apply_limits(context);
+
+        self.log_result(&report);
+        Ok(report)
+    }
+
+    /// Validates only the footer schema of the files against a contract (no
+    /// data reading).
+    ///
+    /// # Arguments
+    ///
+    /// * `contract` - The data contract to validate against
+    /// * `context` - Validation context (schema_only flag is enforced to true)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a footer cannot be read.
+    pub async fn validate_schema_only(
+        &self,
+        contract: &Contract,
+        context: &ValidationContext,
+    ) -> Result<ValidationReport, ParquetError> {
+        info!(
+            "Validating schema only for Parquet files against contract: {}",
+            contract.name
+        );
+
+        let file_schema = self.arrow_schema().await?;
+
+        let mut schema_context = context.clone();
+        schema_context.schema_only = true;
+
+        let mut validator = DataValidator::new();
+        let mut report = validator
+            .validate_with_data_async(contract, &DataSet::empty(), &schema_context)
+            .await;
+        for violation in check_file_schema(contract, &file_schema) {
+            report.add_violation(violation);
+        }
+        report.apply_limits(context);
+
+        self.log_result(&report);
+        Ok(report)
+    }
+
+    fn log_result(&self, report: &ValidationReport) {
+        if report.passed {
+            info!("Validation passed for: {}", self.location.url());
+        } else {
+            warn!(
+                "Validation failed for: {} with {} errors",
+                self.location.url(),
+                report.errors.len()
+            );
+        }
+    }
+}
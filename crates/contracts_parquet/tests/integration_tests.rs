@@ -0,0 +1,209 @@
+//! Integration tests for Parquet validation.
+
+use arrow_array::{ArrayRef, Int64Array, RecordBatch, StringArray};
+use arrow_schema::{DataType, Field, Schema};
+use contracts_core::{
+    Contract, ContractBuilder, DataFormat, FieldBuilder, FieldConstraints, ValidationContext,
+};
+use contracts_parquet::{ParquetError, ParquetValidator};
+use parquet::arrow::ArrowWriter;
+use pretty_assertions::assert_eq;
+use std::path::Path;
+use std::sync::Arc;
+use tempfile::TempDir;
+
+fn write_users(path: &Path, ids: Vec<i64>, names: Vec<Option<&str>>) {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Int64, false),
+        Field::new("name", DataType::Utf8, true),
+    ]));
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(Int64Array::from(ids)) as ArrayRef,
+            Arc::new(StringArray::from(names)) as ArrayRef,
+        ],
+    )
+    .unwrap();
+
+    let mut writer =
+        ArrowWriter::try_new(std::fs::File::create(path).unwrap(), schema, None).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+}
+
+fn users_contract(location: &Path) -> Contract {
+    ContractBuilder::new("users", "team")
+        .location(location.display().to_string())
+        .format(DataFormat::Parquet)
+        .field(
+            FieldBuilder::new("id", "int64")
+                .nullable(false)
+                .constraint(FieldConstraints::Range {
+                    min: 1.0,
+                    max: 100.0,
+                })
+                .build(),
+        )
+        .field(FieldBuilder::new("name", "string").nullable(false).build())
+        .build()
+}
+
+#[tokio::test]
+async fn test_validate_file_passes() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("users.parquet");
+    write_users(&path, vec![1, 2], vec![Some("ada"), Some("bob")]);
+
+    let validator = ParquetValidator::new(path.to_str().unwrap()).await.unwrap();
+    let report = validator
+        .validate(&users_contract(&path), &ValidationContext::new())
+        .await
+        .unwrap();
+
+    assert!(report.passed, "unexpected errors: {:?}", report.errors);
+    assert_eq!(report.stats.records_validated, 2);
+}
+
+#[tokio::test]
+async fn test_validate_directory_reads_every_file() {
+    let dir = TempDir::new().unwrap();
+    write_users(
+        &dir.path().join("part-0.parquet"),
+        vec![1, 2],
+        vec![Some("ada"), Some("bob")],
+    );
+    write_users(
+        &dir.path().join("part-1.parquet"),
+        vec![3, 500],
+        vec![None, Some("eve")],
+    );
+    std::fs::write(dir.path().join("_SUCCESS"), "").unwrap();
+
+    let validator = ParquetValidator::new(dir.path().to_str().unwrap())
+        .await
+        .unwrap();
+    assert_eq!(validator.files().len(), 2);
+
+    let report = validator
+        .validate(&users_contract(dir.path()), &ValidationContext::new())
+        .await
+        .unwrap();
+
+    assert!(!report.passed);
+    let mut codes: Vec<&str> = report.errors.iter().map(|e| e.code.code).collect();
+    codes.sort();
+    assert_eq!(codes, vec!["DCE0102", "DCE0203"]);
+}
+
+#[tokio::test]
+async fn test_validate_sample_reads_first_rows() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("users.parquet");
+    write_users(&path, vec![1, 2, 500], vec![Some("ada"), Some("bob"), None]);
+
+    let validator = ParquetValidator::new(path.to_str().unwrap()).await.unwrap();
+    let report = validator
+        .validate(
+            &users_contract(&path),
+            &ValidationContext::new().with_sample_size(2),
+        )
+        .await
+        .unwrap();
+
+    assert!(report.passed, "unexpected errors: {:?}", report.errors);
+    assert_eq!(report.stats.records_validated, 2);
+}
+
+#[tokio::test]
+async fn test_validate_row_filter_selects_rows_before_sampling() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("users.parquet");
+    write_users(
+        &path,
+        vec![500, 1, 2, 3],
+        vec![Some("eve"), None, Some("bob"), Some("cy")],
+    );
+
+    let validator = ParquetValidator::new(path.to_str().unwrap()).await.unwrap();
+    let report = validator
+        .validate(
+            &users_contract(&path),
+            &ValidationContext::new()
+                .with_row_filter("name IS NOT NULL AND id < 100")
+                .with_sample_size(1),
+        )
+        .await
+        .unwrap();
+
+    assert!(report.passed, "unexpected errors: {:?}", report.errors);
+    assert_eq!(report.stats.records_validated, 1);
+}
+
+#[tokio::test]
+async fn test_validate_schema_only_checks_footer() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("users.parquet");
+    write_users(&path, vec![1], vec![None]);
+
+    let contract = ContractBuilder::new("users", "team")
+        .location(path.display().to_string())
+        .format(DataFormat::Parquet)
+        .field(FieldBuilder::new("id", "string").build())
+        .field(FieldBuilder::new("name", "string").nullable(false).build())
+        .field(FieldBuilder::new("email", "string").required(true).build())
+        .build();
+
+    let validator = ParquetValidator::new(path.to_str().unwrap()).await.unwrap();
+    let report = validator
+        .validate(&contract, &ValidationContext::new().with_schema_only(true))
+        .await
+        .unwrap();
+
+    // The null name is only found by reading rows
+    let errors: Vec<(&str, Option<&str>)> = report
+        .errors
+        .iter()
+        .map(|e| (e.code.code, e.field.as_deref()))
+        .collect();
+    assert_eq!(
+        errors,
+        vec![("DCE0103", Some("id")), ("DCE0101", Some("email"))]
+    );
+}
+
+#[tokio::test]
+async fn test_extract_schema_from_footer() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("users.parquet");
+    write_users(&path, vec![1], vec![Some("ada")]);
+
+    let validator = ParquetValidator::new(path.to_str().unwrap()).await.unwrap();
+    let schema = validator.extract_schema().await.unwrap();
+
+    let fields: Vec<(String, String, bool)> = schema
+        .fields
+        .iter()
+        .map(|f| (f.name.clone(), f.field_type.to_string(), f.nullable))
+        .collect();
+    assert_eq!(
+        fields,
+        vec![
+            ("id".to_string(), "int64".to_string(), false),
+            ("name".to_string(), "string".to_string(), true),
+        ]
+    );
+    assert_eq!(schema.format, DataFormat::Parquet);
+}
+
+#[tokio::test]
+async fn test_missing_location_is_not_found() {
+    let dir = TempDir::new().unwrap();
+    let missing = dir.path().join("missing");
+
+    let err = ParquetValidator::new(missing.to_str().unwrap())
+        .await
+        .err()
+        .unwrap();
+    assert!(matches!(err, ParquetError::NotFound(_)));
+}
@@ -0,0 +1,408 @@
+//! Packaging of a contract into a self-contained bundle.
+//!
+//! A bundle is a tar archive holding an [OCI image layout] with a single
+//! artifact: the contract file as written, the resolved contract as JSON
+//! (implicit defaults written out, see [`Contract::normalized`]) and any
+//! files included alongside it, such as a tag taxonomy or an extensions
+//! schema:
+//!
+//! ```text
+//! oci-layout
+//! index.json
+//! blobs/sha256/<manifest>    layers: orders.yml, contract.json, includes/tags.yml
+//! blobs/sha256/<file>...
+//! ```
+//!
+//! The manifest lists the SHA-256 of every file and records where the
+//! contract came from (see [`capture_provenance`](crate::capture_provenance)),
+//! and its digest identifies the bundle. Archives are reproducible: bundling
+//! the same files from the same commit again gives the same digest, so a
+//! bundle can be promoted between environments and checked on arrival. The same blobs can be pushed to an OCI registry as
+//! an artifact, by `dce push` or tools such as `oras` and `skopeo`.
+//!
+//! [OCI image layout]: https://github.com/opencontainers/image-spec/blob/main/image-layout.md
+
+use crate::lockfile::sha256_hex;
+use crate::{ContractFormat, ParserError, Result, capture_provenance, detect_format, parse_file};
+use contracts_core::{Contract, ContractProvenance};
+use serde_json::{Value, json};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Artifact type of bundle manifests.
+pub const BUNDLE_ARTIFACT_TYPE: &str = "application/vnd.dce.contract.bundle.v1";
+
+/// Media type of the resolved contract.
+const CONTRACT_MEDIA_TYPE: &str = "application/vnd.dce.contract.v1+json";
+
+/// Media type of bundle manifests.
+pub const MANIFEST_MEDIA_TYPE: &str = "application/vnd.oci.image.manifest.v1+json";
+
+const INDEX_MEDIA_TYPE: &str = "application/vnd.oci.image.index.v1+json";
+
+/// The empty `{}` config blob OCI artifacts without a config point to.
+const EMPTY_MEDIA_TYPE: &str = "application/vnd.oci.empty.v1+json";
+const EMPTY_CONFIG: &[u8] = b"{}";
+
+const TAR_BLOCK: usize = 512;
+
+/// A file packaged in a bundle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BundleFile {
+    /// Path of the file within the bundle, such as `includes/tags.yml`
+    pub name: String,
+
+    /// Media type recorded in the manifest
+    pub media_type: &'static str,
+
+    /// Content of the file
+    pub content: Vec<u8>,
+}
+
+/// A contract packaged with the files it is checked against.
+///
+/// # Example
+///
+/// ```no_run
+/// use contracts_parser::ContractBundle;
+/// use std::path::Path;
+///
+/// let mut bundle = ContractBundle::from_file(Path::new("contracts/orders.yml")).unwrap();
+/// bundle.include(Path::new("tags.yml")).unwrap();
+/// let (archive, digest) = bundle.to_tar();
+/// std::fs::write("orders-1.2.0.tar", archive).unwrap();
+/// println!("{digest}");
+/// ```
+#[derive(Debug, Clone)]
+pub struct ContractBundle {
+    /// The bundled contract
+    pub contract: Contract,
+
+    /// Packaged files, the contract file and the resolved contract first
+    pub files: Vec<BundleFile>,
+
+    /// Where the contract file was bundled from
+    pub provenance: ContractProvenance,
+}
+
+impl ContractBundle {
+    /// Bundles the contract file at `path` with its resolved form.
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let contract = parse_file(path)?;
+        let source = BundleFile {
+            name: file_name(path)?,
+            media_type: match detect_format(path)? {
+                ContractFormat::Yaml => "application/yaml",
+                ContractFormat::Toml => "application/toml",
+            },
+            content: std::fs::read(path)?,
+        };
+        let resolved = BundleFile {
+            name: "contract.json".to_string(),
+            media_type: CONTRACT_MEDIA_TYPE,
+            content: serde_json::to_vec_pretty(&contract.normalized())
+                .map_err(|e| ParserError::InvalidBundle(e.to_string()))?,
+        };
+        Ok(Self {
+            contract,
+            files: vec![source, resolved],
+            provenance: capture_provenance(path),
+        })
+    }
+
+    /// Adds the file at `path` under `includes/`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the file cannot be read or another included file has the
+    /// same name.
+    pub fn include(&mut self, path: &Path) -> Result<()> {
+        let name = format!("includes/{}", file_name(path)?);
+        if self.files.iter().any(|file| file.name == name) {
+            return Err(ParserError::InvalidBundle(format!(
+                "two included files are named '{}'",
+                name
+            )));
+        }
+        let media_type = match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => "application/json",
+            Some("yml" | "yaml") => "application/yaml",
+            Some("toml") => "application/toml",
+            _ => "application/octet-stream",
+        };
+        self.files.push(BundleFile {
+            name,
+            media_type,
+            content: std::fs::read(path)?,
+        });
+        Ok(())
+    }
+
+    /// Returns the OCI manifest of the bundle.
+    ///
+    /// The provenance of the contract is recorded as the `dce.contract.path`,
+    /// `dce.contract.commit` and `dce.contract.dirty` annotations, the last
+    /// two only when the contract was bundled from a git repository.
+    pub fn manifest(&self) -> Value {
+        let layers: Vec<Value> = self
+            .files
+            .iter()
+            .map(|file| {
+                json!({
+                    "mediaType": file.media_type,
+                    "digest": blob_digest(&file.content),
+                    "size": file.content.len(),
+                    "annotations": { "org.opencontainers.image.title": file.name },
+                })
+            })
+            .collect();
+        let mut annotations = json!({
+            "dce.contract.name": self.contract.name,
+            "dce.contract.version": self.contract.version,
+            "dce.contract.owner": self.contract.owner,
+            "dce.contract.path": self.provenance.path,
+        });
+        // Annotation values are strings
+        if let Some(commit) = &self.provenance.commit {
+            annotations["dce.contract.commit"] = json!(commit);
+        }
+        if let Some(dirty) = self.provenance.dirty {
+            annotations["dce.contract.dirty"] = json!(dirty.to_string());
+        }
+        json!({
+            "schemaVersion": 2,
+            "mediaType": MANIFEST_MEDIA_TYPE,
+            "artifactType": BUNDLE_ARTIFACT_TYPE,
+            "config": {
+                "mediaType": EMPTY_MEDIA_TYPE,
+                "digest": blob_digest(EMPTY_CONFIG),
+                "size": EMPTY_CONFIG.len(),
+            },
+            "layers": layers,
+            "annotations": annotations,
+        })
+    }
+
+    /// Returns the blobs the manifest points to, the empty config and the
+    /// file contents, by digest. Identical files are stored once.
+    pub fn blobs(&self) -> BTreeMap<String, &[u8]> {
+        let mut blobs: BTreeMap<String, &[u8]> = self
+            .files
+            .iter()
+            .map(|file| (blob_digest(&file.content), file.content.as_slice()))
+            .collect();
+        blobs.insert(blob_digest(EMPTY_CONFIG), EMPTY_CONFIG);
+        blobs
+    }
+
+    /// Renders the bundle as a tar archive and returns it with the digest
+    /// of its manifest.
+    pub fn to_tar(&self) -> (Vec<u8>, String) {
+        let manifest = self.manifest().to_string().into_bytes();
+        let manifest_digest = blob_digest(&manifest);
+        let index = json!({
+            "schemaVersion": 2,
+            "mediaType": INDEX_MEDIA_TYPE,
+            "manifests": [{
+                "mediaType": MANIFEST_MEDIA_TYPE,
+                "artifactType": BUNDLE_ARTIFACT_TYPE,
+                "digest": manifest_digest,
+                "size": manifest.len(),
+                "annotations": {
+                    "org.opencontainers.image.ref.name": self.contract.version,
+                },
+            }],
+        });
+
+        let mut blobs = self.blobs();
+        blobs.insert(manifest_digest.clone(), &manifest);
+
+        let mut archive = Vec::new();
+        append_tar_entry(
+            &mut archive,
+            "oci-layout",
+            br#"{"imageLayoutVersion":"1.0.0"}"#,
+        );
+        append_tar_entry(&mut archive, "index.json", index.to_string().as_bytes());
+        for (digest, content) in blobs {
+            let name = format!("blobs/{}", digest.replace(':', "/"));
+            append_tar_entry(&mut archive, &name, content);
+        }
+        archive.resize(archive.len() + 2 * TAR_BLOCK, 0);
+        (archive, manifest_digest)
+    }
+}
+
+/// Returns the name and digest of the contract file in a bundle manifest.
+///
+/// # Errors
+///
+/// Fails if the manifest is not the manifest of a contract bundle.
+pub fn contract_layer(manifest: &Value) -> Result<(String, String)> {
+    if manifest["artifactType"] != BUNDLE_ARTIFACT_TYPE {
+        return Err(ParserError::InvalidBundle(format!(
+            "artifact type {} is not {}",
+            manifest["artifactType"], BUNDLE_ARTIFACT_TYPE
+        )));
+    }
+    // The contract file as written is the first layer, see from_file
+    let layer = &manifest["layers"][0];
+    match (
+        layer["annotations"]["org.opencontainers.image.title"].as_str(),
+        layer["digest"].as_str(),
+    ) {
+        (Some(name), Some(digest)) => Ok((name.to_string(), digest.to_string())),
+        _ => Err(ParserError::InvalidBundle(
+            "the manifest lists no contract file".to_string(),
+        )),
+    }
+}
+
+/// Returns the provenance recorded in a bundle manifest, if any.
+///
+/// Bundles written before provenance was recorded have none.
+pub fn manifest_provenance(manifest: &Value) -> Option<ContractProvenance> {
+    let annotations = &manifest["annotations"];
+    let path = annotations["dce.contract.path"].as_str()?;
+    Some(ContractProvenance {
+        path: path.to_string(),
+        commit: annotations["dce.contract.commit"]
+            .as_str()
+            .map(str::to_string),
+        dirty: annotations["dce.contract.dirty"]
+            .as_str()
+            .and_then(|dirty| dirty.parse().ok()),
+    })
+}
+
+/// Returns the OCI digest of `bytes`, `sha256:<hex>`.
+pub fn blob_digest(bytes: &[u8]) -> String {
+    format!("sha256:{}", sha256_hex(bytes))
+}
+
+fn file_name(path: &Path) -> Result<String> {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .ok_or_else(|| ParserError::InvalidBundle(format!("'{}' is not a file", path.display())))
+}
+
+/// Appends a regular file to a ustar archive, with fixed ownership and
+/// modification time so archives are reproducible.
+fn append_tar_entry(archive: &mut Vec<u8>, name: &str, content: &[u8]) {
+    let mut header = [0u8; TAR_BLOCK];
+    let mut put = |offset: usize, value: &[u8]| {
+        header[offset..offset + value.len()].copy_from_slice(value);
+    };
+    put(0, name.as_bytes());
+    put(100, b"0000644\0");
+    put(108, b"0000000\0");
+    put(116, b"0000000\0");
+    put(124, format!("{:011o}\0", content.len()).as_bytes());
+    put(136, b"00000000000\0");
+    put(148, b"        ");
+    put(156, b"0");
+    put(257, b"ustar\0");
+    put(263, b"00");
+    let checksum: u32 = header.iter().map(|&b| u32::from(b)).sum();
+    header[148..156].copy_from_slice(format!("{:06o}\0 ", checksum).as_bytes());
+
+    archive.extend_from_slice(&header);
+    archive.extend_from_slice(content);
+    archive.resize(archive.len().div_ceil(TAR_BLOCK) * TAR_BLOCK, 0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    /// Lists the entries of a ustar archive.
+    fn tar_entries(archive: &[u8]) -> BTreeMap<String, Vec<u8>> {
+        let mut entries = BTreeMap::new();
+        let mut offset = 0;
+        while archive[offset] != 0 {
+            let header = &archive[offset..offset + TAR_BLOCK];
+            let name = String::from_utf8_lossy(&header[..100])
+                .trim_end_matches('\0')
+                .to_string();
+            let size_field = String::from_utf8_lossy(&header[124..135]).to_string();
+            let size = usize::from_str_radix(&size_field, 8).unwrap();
+            offset += TAR_BLOCK;
+            entries.insert(name, archive[offset..offset + size].to_vec());
+            offset += size.div_ceil(TAR_BLOCK) * TAR_BLOCK;
+        }
+        entries
+    }
+
+    #[test]
+    fn test_bundle_layout() {
+        let dir = TempDir::new().unwrap();
+        let contract = dir.path().join("orders.yml");
+        std::fs::write(
+            &contract,
+            "version: \"1.2.0\"\nname: orders\nowner: sales\nschema:\n  format: iceberg\n  location: s3://lake/orders\n  fields:\n    - name: id\n      type: string\n      nullable: false\n",
+        )
+        .unwrap();
+        let taxonomy = dir.path().join("tags.yml");
+        std::fs::write(&taxonomy, "tags: [pii]\n").unwrap();
+
+        let mut bundle = ContractBundle::from_file(&contract).unwrap();
+        bundle.include(&taxonomy).unwrap();
+        assert!(matches!(
+            bundle.include(&taxonomy),
+            Err(ParserError::InvalidBundle(_))
+        ));
+
+        let (archive, manifest_digest) = bundle.to_tar();
+        assert_eq!(archive.len() % TAR_BLOCK, 0);
+        assert_eq!(bundle.to_tar().1, manifest_digest);
+
+        let entries = tar_entries(&archive);
+        let index: Value = serde_json::from_slice(&entries["index.json"]).unwrap();
+        assert_eq!(index["manifests"][0]["digest"], manifest_digest);
+        assert_eq!(
+            index["manifests"][0]["annotations"]["org.opencontainers.image.ref.name"],
+            "1.2.0"
+        );
+
+        let manifest_blob = format!("blobs/sha256/{}", &manifest_digest["sha256:".len()..]);
+        let manifest: Value = serde_json::from_slice(&entries[&manifest_blob]).unwrap();
+        assert_eq!(manifest["artifactType"], BUNDLE_ARTIFACT_TYPE);
+        let titles: Vec<&str> = manifest["layers"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|layer| {
+                layer["annotations"]["org.opencontainers.image.title"]
+                    .as_str()
+                    .unwrap()
+            })
+            .collect();
+        assert_eq!(
+            titles,
+            vec!["orders.yml", "contract.json", "includes/tags.yml"]
+        );
+
+        // Every layer is stored under its digest
+        for layer in manifest["layers"].as_array().unwrap() {
+            let hex = &layer["digest"].as_str().unwrap()["sha256:".len()..];
+            let blob = &entries[&format!("blobs/sha256/{}", hex)];
+            assert_eq!(sha256_hex(blob), hex);
+        }
+        let resolved: Contract = serde_json::from_slice(&bundle.files[1].content).unwrap();
+        assert_eq!(resolved.name, "orders");
+        assert_eq!(
+            contract_layer(&manifest).unwrap(),
+            (
+                "orders.yml".to_string(),
+                blob_digest(&bundle.files[0].content)
+            )
+        );
+        assert!(contract_layer(&json!({ "artifactType": "application/other" })).is_err());
+        assert_eq!(
+            manifest_provenance(&manifest),
+            Some(bundle.provenance.clone())
+        );
+        assert_eq!(manifest_provenance(&json!({ "annotations": {} })), None);
+    }
+}
@@ -0,0 +1,558 @@
+//! Workspace settings read from a `dce.toml` file.
+//!
+//! Settings that belong to the environment running `dce` rather than to any
+//! one contract, such as where to send metrics or publish documentation,
+//! live in `dce.toml` next to the contracts:
+//!
+//! ```toml
+//! [metrics.statsd]
+//! address = "127.0.0.1:8125"
+//! prefix = "dce"
+//! protocol = "dogstatsd"
+//! tags = { env = "prod" }
+//!
+//! [publish.confluence]
+//! url = "https://example.atlassian.net/wiki"
+//! space = "DATA"
+//! user = "${env:CONFLUENCE_USER}"
+//! token = "${env:CONFLUENCE_TOKEN}"
+//! pages = { user_events = "98765" }
+//!
+//! [lint]
+//! tag_taxonomy = "tags.yml"
+//!
+//! [lint.descriptions]
+//! min_length = 20
+//!
+//! [[hooks.pre]]
+//! url = "https://gate.example.com/dce"
+//! headers = { Authorization = "Bearer ${env:GATE_TOKEN}" }
+//!
+//! [[hooks.post]]
+//! url = "https://archive.example.com/reports"
+//!
+//! [[sinks]]
+//! type = "s3"
+//! path = "s3://lake/dce-reports/{contract}/{date}/{timestamp}.json"
+//!
+//! [limits]
+//! max_concurrent = 8
+//! per_second = 20.0
+//! ```
+//!
+//! Credentials may be written as `${env:NAME}` or `${file:/path}` references,
+//! resolved when they are used.
+
+use crate::{ContractFormat, ParserError, Result, detect_format};
+use contracts_core::TagTaxonomy;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Default workspace configuration file name, looked up in the working
+/// directory.
+pub const CONFIG_NAME: &str = "dce.toml";
+
+/// Settings of a `dce` workspace.
+///
+/// # Example
+///
+/// ```rust
+/// use contracts_parser::{StatsdProtocol, WorkspaceConfig};
+///
+/// let config: WorkspaceConfig = toml::from_str(
+///     r#"
+///     [metrics.statsd]
+///     address = "datadog-agent:8125"
+///     "#,
+/// )
+/// .unwrap();
+///
+/// let statsd = config.metrics.statsd.unwrap();
+/// assert_eq!(statsd.prefix, "dce");
+/// assert_eq!(statsd.protocol, StatsdProtocol::DogStatsd);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WorkspaceConfig {
+    /// Where validation metrics are sent
+    #[serde(default)]
+    pub metrics: MetricsConfig,
+
+    /// Where contract documentation is published
+    #[serde(default)]
+    pub publish: PublishConfig,
+
+    /// Optional lint rules applied by `dce check`
+    #[serde(default)]
+    pub lint: LintConfig,
+
+    /// HTTP hooks called before and after each validation
+    #[serde(default)]
+    pub hooks: HooksConfig,
+
+    /// Durable locations every validation report is written to
+    #[serde(default)]
+    pub sinks: Vec<SinkConfig>,
+
+    /// Limits on catalog and storage requests
+    #[serde(default)]
+    pub limits: LimitsConfig,
+}
+
+/// Metrics exporters; none is enabled by default.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MetricsConfig {
+    /// StatsD or DogStatsD agent receiving validation metrics
+    pub statsd: Option<StatsdConfig>,
+}
+
+/// A StatsD or DogStatsD agent.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StatsdConfig {
+    /// `host:port` of the agent, `127.0.0.1:8125` by default
+    #[serde(default = "default_statsd_address")]
+    pub address: String,
+
+    /// Prefix of every metric name, `dce` by default
+    #[serde(default = "default_statsd_prefix")]
+    pub prefix: String,
+
+    /// Wire format of the metrics
+    #[serde(default)]
+    pub protocol: StatsdProtocol,
+
+    /// Tags added to every metric, after the contract and owner tags
+    #[serde(default)]
+    pub tags: BTreeMap<String, String>,
+}
+
+/// Wire format of StatsD metrics.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StatsdProtocol {
+    /// Datadog's extension, with tags after `|#`
+    #[default]
+    DogStatsd,
+    /// Plain StatsD, which has no tags: the contract name is part of the
+    /// metric name instead
+    Statsd,
+}
+
+/// Documentation targets; none is enabled by default.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PublishConfig {
+    /// Confluence space receiving one page per contract
+    pub confluence: Option<ConfluenceConfig>,
+
+    /// Notion page receiving one child page per contract
+    pub notion: Option<NotionConfig>,
+}
+
+/// A Confluence space contract pages are published to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConfluenceConfig {
+    /// Base URL of the wiki, such as `https://example.atlassian.net/wiki`
+    pub url: String,
+
+    /// Key of the space pages are created in
+    pub space: String,
+
+    /// ID of the page new pages are created under, or the space root
+    pub parent: Option<String>,
+
+    /// User the API token belongs to
+    pub user: String,
+
+    /// API token
+    pub token: String,
+
+    /// ID of the page of each contract, by contract name
+    ///
+    /// Contracts without one are published to the page of the space titled
+    /// with the contract name, created if missing.
+    #[serde(default)]
+    pub pages: BTreeMap<String, String>,
+}
+
+/// A Notion page contract pages are published under.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NotionConfig {
+    /// Internal integration token
+    pub token: String,
+
+    /// ID of the page new pages are created under
+    pub parent: String,
+
+    /// ID of the page of each contract, by contract name
+    ///
+    /// Contracts without one are published to the child page of `parent`
+    /// titled with the contract name, created if missing.
+    #[serde(default)]
+    pub pages: BTreeMap<String, String>,
+}
+
+/// Optional lint rules; none is enabled by default.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LintConfig {
+    /// YAML or TOML file listing the field tags contracts may use
+    pub tag_taxonomy: Option<String>,
+
+    /// Checks that descriptions are informative, enabled by the section even
+    /// when empty
+    pub descriptions: Option<DescriptionLintConfig>,
+}
+
+/// Overrides of the description rules' defaults.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DescriptionLintConfig {
+    /// Shortest accepted description, in characters
+    pub min_length: Option<usize>,
+
+    /// Descriptions reported as placeholders, replacing the default list
+    pub placeholders: Option<Vec<String>>,
+
+    /// Words accepted as units of numeric fields, replacing the default list
+    pub units: Option<Vec<String>>,
+
+    /// Whether descriptions of numeric fields must state a unit
+    pub require_units: Option<bool>,
+}
+
+/// Validation hooks; none is called by default.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HooksConfig {
+    /// Hooks called before each validation, which may veto it or add to its
+    /// context
+    #[serde(default)]
+    pub pre: Vec<HookConfig>,
+
+    /// Hooks called with the report after each validation
+    #[serde(default)]
+    pub post: Vec<HookConfig>,
+}
+
+/// An HTTP endpoint receiving a JSON `POST` around validations.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HookConfig {
+    /// URL the payload is posted to
+    pub url: String,
+
+    /// Headers sent with the payload, such as an `Authorization` token
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+
+    /// Seconds to wait for a response, 10 by default
+    #[serde(default = "default_hook_timeout")]
+    pub timeout_secs: u64,
+}
+
+/// A durable location validation reports are written to.
+///
+/// Paths are templates: `{contract}`, `{version}`, `{date}` (`2024-05-31`)
+/// and `{timestamp}` (`20240531T142500Z`) are replaced for each report, and
+/// the extension of the path picks the format, as with `--output`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase", deny_unknown_fields)]
+pub enum SinkConfig {
+    /// A file on the local file system
+    File {
+        /// Path template of the report files
+        path: String,
+    },
+
+    /// An S3 object, with credentials and region taken from the `AWS_*`
+    /// environment variables
+    S3 {
+        /// `s3://bucket/key` template of the report objects
+        path: String,
+    },
+
+    /// A Google Cloud Storage object
+    Gcs {
+        /// `gs://bucket/name` template of the report objects
+        path: String,
+
+        /// OAuth access token allowed to create objects in the bucket
+        token: String,
+    },
+
+    /// A PostgreSQL table, receiving one row per report; written with the
+    /// `postgres` feature of the CLI
+    Postgres {
+        /// Connection string, such as `postgres://dce:${env:PGPASSWORD}@db/quality`;
+        /// its `sslmode` picks whether TLS is used
+        url: String,
+
+        /// Table the rows are inserted into, optionally schema-qualified;
+        /// created when missing
+        #[serde(default = "default_postgres_table")]
+        table: String,
+    },
+
+    /// A database table behind a PostgREST endpoint, receiving one row per
+    /// report
+    Postgrest {
+        /// URL of the table, such as `https://db.example.com/validation_reports`
+        url: String,
+
+        /// Headers sent with each row, such as an `Authorization` token
+        #[serde(default)]
+        headers: BTreeMap<String, String>,
+    },
+}
+
+/// Limits on the catalog and storage requests of a run, shared by every
+/// contract it validates; both are off by default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LimitsConfig {
+    /// Maximum number of requests in flight at once
+    pub max_concurrent: Option<usize>,
+
+    /// Maximum number of requests started per second
+    pub per_second: Option<f64>,
+}
+
+fn default_hook_timeout() -> u64 {
+    10
+}
+
+fn default_postgres_table() -> String {
+    "dce_validation_reports".to_string()
+}
+
+fn default_statsd_address() -> String {
+    "127.0.0.1:8125".to_string()
+}
+
+fn default_statsd_prefix() -> String {
+    "dce".to_string()
+}
+
+impl WorkspaceConfig {
+    /// Reads the configuration at `path`, or returns the defaults if it does
+    /// not exist.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)?;
+        toml::from_str(&content)
+            .map_err(|e| ParserError::TomlError(format!("{}: {}", path.display(), e)))
+    }
+
+    /// Reads the tag taxonomy named by `lint.tag_taxonomy`, if any.
+    pub fn tag_taxonomy(&self) -> Result<Option<TagTaxonomy>> {
+        let Some(path) = &self.lint.tag_taxonomy else {
+            return Ok(None);
+        };
+        let path = Path::new(path);
+        let content = std::fs::read_to_string(path)?;
+        let taxonomy = match detect_format(path)? {
+            ContractFormat::Yaml => serde_yaml_ng::from_str(&content)?,
+            ContractFormat::Toml => toml::from_str(&content)
+                .map_err(|e| ParserError::TomlError(format!("{}: {}", path.display(), e)))?,
+        };
+        Ok(Some(taxonomy))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_load_missing_config_is_default() {
+        let dir = TempDir::new().unwrap();
+        let config = WorkspaceConfig::load(&dir.path().join(CONFIG_NAME)).unwrap();
+        assert_eq!(config, WorkspaceConfig::default());
+    }
+
+    #[test]
+    fn test_load_statsd_config() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(CONFIG_NAME);
+        std::fs::write(
+            &path,
+            "[metrics.statsd]\nprefix = \"data.contracts\"\nprotocol = \"statsd\"\ntags = { env = \"prod\" }\n",
+        )
+        .unwrap();
+
+        let statsd = WorkspaceConfig::load(&path)
+            .unwrap()
+            .metrics
+            .statsd
+            .unwrap();
+        assert_eq!(
+            statsd,
+            StatsdConfig {
+                address: "127.0.0.1:8125".to_string(),
+                prefix: "data.contracts".to_string(),
+                protocol: StatsdProtocol::Statsd,
+                tags: BTreeMap::from([("env".to_string(), "prod".to_string())]),
+            }
+        );
+    }
+
+    #[test]
+    fn test_load_publish_config() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(CONFIG_NAME);
+        std::fs::write(
+            &path,
+            r#"
+[publish.notion]
+token = "${env:NOTION_TOKEN}"
+parent = "3f0c"
+
+[publish.notion.pages]
+orders = "9a1b"
+"#,
+        )
+        .unwrap();
+
+        let config = WorkspaceConfig::load(&path).unwrap();
+        assert_eq!(config.publish.confluence, None);
+        let notion = config.publish.notion.unwrap();
+        assert_eq!(notion.token, "${env:NOTION_TOKEN}");
+        assert_eq!(notion.pages["orders"], "9a1b");
+    }
+
+    #[test]
+    fn test_empty_lint_section_enables_rules() {
+        let config: WorkspaceConfig = toml::from_str("[lint.descriptions]\n").unwrap();
+        assert_eq!(
+            config.lint.descriptions,
+            Some(DescriptionLintConfig::default())
+        );
+        assert_eq!(WorkspaceConfig::default().lint.descriptions, None);
+    }
+
+    #[test]
+    fn test_load_tag_taxonomy() {
+        let dir = TempDir::new().unwrap();
+        let taxonomy = dir.path().join("tags.yml");
+        std::fs::write(
+            &taxonomy,
+            "tags:\n  pii:\n    children:\n      direct: {}\n  personal:\n    deprecated: true\n    replacement: pii\n",
+        )
+        .unwrap();
+
+        let mut config = WorkspaceConfig::default();
+        assert_eq!(config.tag_taxonomy().unwrap(), None);
+        config.lint.tag_taxonomy = Some(taxonomy.display().to_string());
+        let taxonomy = config.tag_taxonomy().unwrap().unwrap();
+        assert_eq!(taxonomy.paths(), vec!["personal", "pii", "pii.direct"]);
+    }
+
+    #[test]
+    fn test_load_hooks_config() {
+        let config: WorkspaceConfig = toml::from_str(
+            r#"
+[[hooks.pre]]
+url = "http://localhost:8080/gate"
+headers = { Authorization = "Bearer ${env:GATE_TOKEN}" }
+
+[[hooks.post]]
+url = "http://localhost:8080/archive"
+timeout_secs = 30
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.hooks.pre,
+            vec![HookConfig {
+                url: "http://localhost:8080/gate".to_string(),
+                headers: BTreeMap::from([(
+                    "Authorization".to_string(),
+                    "Bearer ${env:GATE_TOKEN}".to_string()
+                )]),
+                timeout_secs: 10,
+            }]
+        );
+        assert_eq!(config.hooks.post[0].timeout_secs, 30);
+        assert_eq!(WorkspaceConfig::default().hooks, HooksConfig::default());
+    }
+
+    #[test]
+    fn test_load_sinks_config() {
+        let config: WorkspaceConfig = toml::from_str(
+            r#"
+[[sinks]]
+type = "file"
+path = "reports/{contract}/{date}.html"
+
+[[sinks]]
+type = "postgrest"
+url = "https://db.example.com/validation_reports"
+headers = { Authorization = "Bearer ${env:DB_TOKEN}" }
+
+[[sinks]]
+type = "postgres"
+url = "postgres://dce@db/quality"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.sinks[0],
+            SinkConfig::File {
+                path: "reports/{contract}/{date}.html".to_string()
+            }
+        );
+        assert!(
+            matches!(&config.sinks[1], SinkConfig::Postgrest { headers, .. } if headers.len() == 1)
+        );
+        assert_eq!(
+            config.sinks[2],
+            SinkConfig::Postgres {
+                url: "postgres://dce@db/quality".to_string(),
+                table: "dce_validation_reports".to_string(),
+            }
+        );
+        assert!(
+            toml::from_str::<WorkspaceConfig>("[[sinks]]\ntype = \"ftp\"\npath = \"x\"\n").is_err()
+        );
+    }
+
+    #[test]
+    fn test_load_limits_config() {
+        let config: WorkspaceConfig =
+            toml::from_str("[limits]\nmax_concurrent = 8\nper_second = 2.5\n").unwrap();
+        assert_eq!(
+            config.limits,
+            LimitsConfig {
+                max_concurrent: Some(8),
+                per_second: Some(2.5),
+            }
+        );
+        assert_eq!(
+            toml::from_str::<WorkspaceConfig>("").unwrap().limits,
+            LimitsConfig::default()
+        );
+    }
+
+    #[test]
+    fn test_load_rejects_unknown_keys() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(CONFIG_NAME);
+        std::fs::write(&path, "[metrics.statsd]\nhost = \"agent\"\n").unwrap();
+
+        let err = WorkspaceConfig::load(&path).unwrap_err();
+        assert!(matches!(err, ParserError::TomlError(_)));
+        assert!(err.to_string().contains("host"), "{err}");
+    }
+}
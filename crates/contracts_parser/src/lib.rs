@@ -26,10 +26,33 @@
 //! assert_eq!(contract.name, "user_events");
 //! ```
 
-use contracts_core::Contract;
+use contracts_core::{Coded, Contract, ContractProvenance, ErrorCode, codes};
 use std::path::Path;
 use thiserror::Error;
 
+mod bundle;
+mod config;
+mod lockfile;
+mod owners;
+mod provenance;
+mod registry;
+
+pub use bundle::{
+    BUNDLE_ARTIFACT_TYPE, BundleFile, ContractBundle, MANIFEST_MEDIA_TYPE, blob_digest,
+    contract_layer, manifest_provenance,
+};
+pub use config::{
+    CONFIG_NAME, ConfluenceConfig, DescriptionLintConfig, HookConfig, HooksConfig, LimitsConfig,
+    LintConfig, MetricsConfig, NotionConfig, PublishConfig, SinkConfig, StatsdConfig,
+    StatsdProtocol, WorkspaceConfig,
+};
+pub use lockfile::{LOCKFILE_NAME, LockedContract, Lockfile};
+pub use owners::{
+    CODEOWNERS_LOCATIONS, CodeOwners, CodeOwnersRule, OrgDirectory, Team, same_owner,
+};
+pub use provenance::capture_provenance;
+pub use registry::{ContractRegistry, RegistryRef, VersionSpec};
+
 /// Errors that can occur during contract parsing.
 #[derive(Debug, Error)]
 pub enum ParserError {
@@ -52,6 +75,43 @@ pub enum ParserError {
     /// Invalid file extension
     #[error("Invalid or missing file extension")]
     InvalidExtension,
+
+    /// Malformed `name@version` registry reference
+    #[error("Invalid registry reference: {0}")]
+    InvalidReference(String),
+
+    /// The requested contract is not published in the registry
+    #[error("Contract not found in registry: {0}")]
+    NotInRegistry(String),
+
+    /// A registry contract no longer matches its `dce.lock` entry
+    #[error("Lockfile mismatch: {0}")]
+    LockMismatch(String),
+
+    /// The files given for a contract bundle cannot be packaged together
+    #[error("Invalid bundle: {0}")]
+    InvalidBundle(String),
+
+    /// A different file is already published for a contract version
+    #[error("Contract version already published: {0}")]
+    AlreadyPublished(String),
+}
+
+impl Coded for ParserError {
+    fn error_code(&self) -> &'static ErrorCode {
+        match self {
+            Self::YamlError(_) => &codes::YAML_PARSE,
+            Self::TomlError(_) => &codes::TOML_PARSE,
+            Self::IoError(_) => &codes::CONTRACT_IO,
+            Self::UnsupportedFormat(_) => &codes::UNSUPPORTED_CONTRACT_FORMAT,
+            Self::InvalidExtension => &codes::INVALID_EXTENSION,
+            Self::InvalidReference(_) => &codes::INVALID_REGISTRY_REFERENCE,
+            Self::NotInRegistry(_) => &codes::NOT_IN_REGISTRY,
+            Self::LockMismatch(_) => &codes::LOCK_MISMATCH,
+            Self::InvalidBundle(_) => &codes::INVALID_BUNDLE,
+            Self::AlreadyPublished(_) => &codes::ALREADY_PUBLISHED,
+        }
+    }
 }
 
 /// Result type alias for parser operations.
@@ -183,10 +243,56 @@ pub fn parse_file(path: &Path) -> Result<Contract> {
     }
 }
 
+/// Parse a contract from a file and capture its provenance.
+///
+/// Equivalent to [`parse_file`] followed by [`capture_provenance`].
+///
+/// # Example
+///
+/// ```no_run
+/// use contracts_parser::parse_file_with_provenance;
+/// use std::path::Path;
+///
+/// let (contract, provenance) =
+///     parse_file_with_provenance(Path::new("contracts/user_events.yml")).unwrap();
+/// println!("{} from {}", contract.name, provenance.path);
+/// ```
+pub fn parse_file_with_provenance(path: &Path) -> Result<(Contract, ContractProvenance)> {
+    let contract = parse_file(path)?;
+    Ok((contract, capture_provenance(path)))
+}
+
+/// Write a contract to a file, in the format given by its extension.
+///
+/// The contract is serialized in full, so the comments and layout of a file
+/// being overwritten are not kept.
+///
+/// # Example
+///
+/// ```no_run
+/// use contracts_parser::{parse_file, write_file};
+/// use std::path::Path;
+///
+/// let path = Path::new("contracts/user_events.yml");
+/// let mut contract = parse_file(path).unwrap();
+/// contract.version = "1.1.0".to_string();
+/// write_file(path, &contract).unwrap();
+/// ```
+pub fn write_file(path: &Path, contract: &Contract) -> Result<()> {
+    let content = match detect_format(path)? {
+        ContractFormat::Yaml => serde_yaml_ng::to_string(contract)?,
+        ContractFormat::Toml => {
+            toml::to_string(contract).map_err(|e| ParserError::TomlError(e.to_string()))?
+        }
+    };
+    std::fs::write(path, content)?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use contracts_core::{DataFormat, Field, Schema};
+    use contracts_core::{DataFormat, Field, FreshnessSource, Schema, Severity};
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -209,10 +315,95 @@ schema:
         assert_eq!(contract.description, None);
         assert_eq!(contract.schema.location, "s3://test/data");
         assert!(contract.schema.fields.is_empty());
+        assert!(contract.schema.catalog.is_none());
+        assert!(contract.schema.table.is_none());
         assert!(contract.quality_checks.is_none());
         assert!(contract.sla.is_none());
     }
 
+    #[test]
+    fn test_parse_yaml_with_changelog() {
+        let yaml = r#"
+version: "1.1.0"
+name: orders
+owner: sales
+schema:
+  format: parquet
+  location: s3://test/orders
+  fields: []
+changelog:
+  - version: "1.1.0"
+    date: 2026-03-01
+    changes:
+      - Added the nullable `note` field
+  - version: "1.0.0"
+"#;
+
+        let contract = parse_yaml(yaml).unwrap();
+        let changelog = contract.changelog.as_deref().unwrap();
+        assert_eq!(changelog.len(), 2);
+        assert_eq!(changelog[0].date.unwrap().to_string(), "2026-03-01");
+        assert_eq!(
+            changelog[0].changes,
+            vec!["Added the nullable `note` field"]
+        );
+        assert!(changelog[1].changes.is_empty());
+        let entry = contract.changelog_entry(&contract.semver().unwrap());
+        assert_eq!(entry, Some(&changelog[0]));
+    }
+
+    #[test]
+    fn test_parse_yaml_with_table_identifier() {
+        let yaml = r#"
+version: "1.0.0"
+name: orders
+owner: sales
+schema:
+  format: iceberg
+  location: s3://lake/warehouse/sales/orders
+  catalog: prod
+  namespace: sales.eu
+  table: orders
+  fields: []
+"#;
+
+        let contract = parse_yaml(yaml).expect("Failed to parse valid YAML");
+
+        assert_eq!(contract.schema.catalog.as_deref(), Some("prod"));
+        assert_eq!(contract.schema.namespace.as_deref(), Some("sales.eu"));
+        assert_eq!(contract.schema.table.as_deref(), Some("orders"));
+    }
+
+    #[test]
+    fn test_parse_yaml_with_connection() {
+        let yaml = r#"
+version: "1.0.0"
+name: orders
+owner: sales
+schema:
+  format: iceberg
+  location: iceberg://prod/sales/orders
+  connection:
+    type: rest
+    uri: http://localhost:8181
+    warehouse: s3://lake/warehouse
+    properties:
+      token: ${env:CATALOG_TOKEN}
+  fields: []
+"#;
+
+        let contract = parse_yaml(yaml).expect("Failed to parse valid YAML");
+        let connection = contract.schema.connection.expect("connection block");
+
+        assert_eq!(connection.catalog_type.as_deref(), Some("rest"));
+        assert_eq!(connection.uri.as_deref(), Some("http://localhost:8181"));
+        assert_eq!(connection.warehouse.as_deref(), Some("s3://lake/warehouse"));
+        assert_eq!(
+            connection.properties.get("token").map(String::as_str),
+            Some("${env:CATALOG_TOKEN}")
+        );
+    }
+
     #[test]
     fn test_parse_valid_yaml_with_fields() {
         let yaml = r#"
@@ -260,6 +451,319 @@ schema:
         assert!(email.constraints.is_some());
     }
 
+    #[test]
+    fn test_parse_yaml_with_collection_constraints() {
+        let yaml = r#"
+version: "1.0.0"
+name: products
+owner: catalog
+schema:
+  format: iceberg
+  location: s3://data/products
+  fields:
+    - name: tags
+      type: list<string>
+      nullable: false
+      constraints:
+        - type: nonempty
+        - type: listlength
+          max: 10
+    - name: attributes
+      type: map<string,string>
+      nullable: true
+      constraints:
+        - type: mapkeys
+          pattern: ^[a-z_]+$
+          allowed: [color, size]
+"#;
+
+        let contract = parse_yaml(yaml).expect("Failed to parse collection constraints");
+
+        let tags = contract.schema.fields[0].constraints.as_ref().unwrap();
+        assert!(matches!(
+            tags[0],
+            contracts_core::FieldConstraints::NonEmpty
+        ));
+        assert!(matches!(
+            tags[1],
+            contracts_core::FieldConstraints::ListLength {
+                min: None,
+                max: Some(10)
+            }
+        ));
+
+        let attributes = contract.schema.fields[1].constraints.as_ref().unwrap();
+        match &attributes[0] {
+            contracts_core::FieldConstraints::MapKeys { pattern, allowed } => {
+                assert_eq!(pattern.as_deref(), Some("^[a-z_]+$"));
+                assert_eq!(allowed.as_ref().unwrap().len(), 2);
+            }
+            other => panic!("Expected MapKeys constraint, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_distribution_constraints() {
+        let yaml = r#"
+version: "1.0.0"
+name: orders
+owner: sales
+schema:
+  format: iceberg
+  location: s3://data/orders
+  fields:
+    - name: region
+      type: string
+      nullable: true
+      constraints:
+        - type: maxnullfraction
+          fraction: 0.1
+        - type: cardinality
+          max_distinct: 12
+"#;
+
+        let contract = parse_yaml(yaml).expect("Failed to parse distribution constraints");
+
+        let region = contract.schema.fields[0].constraints.as_ref().unwrap();
+        assert!(matches!(
+            region[0],
+            contracts_core::FieldConstraints::MaxNullFraction { fraction } if fraction == 0.1
+        ));
+        assert!(matches!(
+            region[1],
+            contracts_core::FieldConstraints::Cardinality {
+                min_distinct: None,
+                max_distinct: Some(12)
+            }
+        ));
+    }
+
+    #[test]
+    fn test_parse_yaml_with_defaults() {
+        let yaml = r#"
+version: "1.0.0"
+name: users
+owner: accounts
+schema:
+  format: iceberg
+  location: s3://data/users
+  fields:
+    - name: country
+      type: string
+      nullable: false
+      default: IT
+    - name: tags
+      type: list<string>
+      nullable: true
+      default: []
+    - name: email
+      type: string
+      nullable: true
+"#;
+
+        let contract = parse_yaml(yaml).expect("Failed to parse defaults");
+        let fields = &contract.schema.fields;
+
+        assert_eq!(fields[0].default, Some(serde_json::json!("IT")));
+        assert_eq!(fields[1].default, Some(serde_json::json!([])));
+        assert_eq!(fields[2].default, None);
+    }
+
+    #[test]
+    fn test_parse_yaml_with_deprecations() {
+        let yaml = r#"
+version: "2.0.0"
+name: users
+owner: accounts
+schema:
+  format: iceberg
+  location: s3://data/users
+  fields:
+    - name: user_id
+      type: int64
+      nullable: false
+    - name: legacy_id
+      type: string
+      nullable: true
+      deprecated:
+        since: "1.4.0"
+        remove_after: 2026-12-31
+        replacement: user_id
+"#;
+
+        let contract = parse_yaml(yaml).expect("Failed to parse deprecations");
+        let fields = &contract.schema.fields;
+
+        assert_eq!(fields[0].deprecated, None);
+        let deprecation = fields[1].deprecated.as_ref().unwrap();
+        assert_eq!(deprecation.since.as_deref(), Some("1.4.0"));
+        assert_eq!(
+            deprecation.remove_after.map(|d| d.to_string()).as_deref(),
+            Some("2026-12-31")
+        );
+        assert_eq!(deprecation.replacement.as_deref(), Some("user_id"));
+    }
+
+    #[test]
+    fn test_parse_yaml_with_access() {
+        let yaml = r#"
+version: "1.0.0"
+name: users
+owner: accounts
+schema:
+  format: iceberg
+  location: s3://data/users
+  fields:
+    - name: user_id
+      type: int64
+      nullable: false
+    - name: email
+      type: string
+      nullable: true
+      access:
+        classification: pii
+        allowed_roles: [support, analytics]
+"#;
+
+        let contract = parse_yaml(yaml).expect("Failed to parse access");
+        let fields = &contract.schema.fields;
+
+        assert_eq!(fields[0].access, None);
+        let access = fields[1].access.as_ref().unwrap();
+        assert_eq!(access.classification.as_deref(), Some("pii"));
+        assert_eq!(access.allowed_roles, vec!["support", "analytics"]);
+    }
+
+    #[test]
+    fn test_parse_yaml_with_severities() {
+        let yaml = r#"
+version: "1.0.0"
+name: users
+owner: accounts
+schema:
+  format: iceberg
+  location: s3://data/users
+  fields:
+    - name: country
+      type: string
+      nullable: true
+      severity: warning
+      constraints:
+        - type: allowedvalues
+          values: [IT, FR]
+quality_checks:
+  completeness:
+    threshold: 0.99
+    fields: [country]
+    severity: error
+  uniqueness:
+    fields: [country]
+    severity: info
+  freshness:
+    max_delay: 1h
+    metric: updated_at
+"#;
+
+        let contract = parse_yaml(yaml).expect("Failed to parse severities");
+        let qc = contract.quality_checks.as_ref().unwrap();
+
+        assert_eq!(contract.schema.fields[0].severity, Some(Severity::Warning));
+        assert_eq!(
+            qc.completeness.as_ref().unwrap().severity,
+            Some(Severity::Error)
+        );
+        assert_eq!(
+            qc.uniqueness.as_ref().unwrap().severity,
+            Some(Severity::Info)
+        );
+        assert_eq!(qc.freshness.as_ref().unwrap().severity, None);
+
+        let snapshot = yaml.replace("metric: updated_at", "source: snapshot_committed_at");
+        let contract = parse_yaml(&snapshot).expect("Failed to parse freshness source");
+        let freshness = contract.quality_checks.unwrap().freshness.unwrap();
+        assert_eq!(freshness.source, Some(FreshnessSource::SnapshotCommittedAt));
+        assert_eq!(freshness.column(), None);
+
+        let invalid = yaml.replace("severity: info", "severity: fatal");
+        assert!(parse_yaml(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_parse_yaml_with_required_and_not_null() {
+        let yaml = r#"
+version: "1.0.0"
+name: users
+owner: accounts
+schema:
+  format: iceberg
+  location: s3://data/users
+  fields:
+    - name: id
+      type: string
+      nullable: false
+    - name: nickname
+      type: string
+      nullable: true
+      required: true
+    - name: referrer
+      type: string
+      nullable: true
+      constraints:
+        - type: notnull
+"#;
+
+        let contract = parse_yaml(yaml).expect("Failed to parse presence settings");
+        let fields = &contract.schema.fields;
+
+        assert_eq!(fields[0].required, None);
+        assert!(fields[0].is_required());
+        assert!(fields[1].is_required() && fields[1].allows_null());
+        assert!(!fields[2].is_required() && !fields[2].allows_null());
+    }
+
+    #[test]
+    fn test_extensions_round_trip() {
+        let yaml = r#"
+version: "1.0.0"
+name: invoices
+owner: finance
+schema:
+  format: iceberg
+  location: s3://data/invoices
+  fields: []
+x-extensions:
+  cost_center: FIN-042
+  data_tier: gold
+  reviewers: [alice, bob]
+"#;
+
+        let contract = parse_yaml(yaml).expect("Failed to parse extensions");
+        let extensions = contract.extensions.as_ref().unwrap();
+        assert_eq!(extensions["cost_center"], "FIN-042");
+        assert_eq!(extensions["reviewers"][1], "bob");
+
+        let serialized = serde_yaml_ng::to_string(&contract).expect("Failed to serialize");
+        assert!(serialized.contains("x-extensions:"));
+        let reparsed = parse_yaml(&serialized).expect("Failed to re-parse");
+        assert_eq!(reparsed.extensions, contract.extensions);
+
+        let toml = r#"
+version = "1.0.0"
+name = "invoices"
+owner = "finance"
+
+[schema]
+format = "iceberg"
+location = "s3://data/invoices"
+fields = []
+
+[custom_metadata]
+data_tier = "silver"
+"#;
+        let contract = parse_toml(toml).expect("Failed to parse custom_metadata alias");
+        assert_eq!(contract.extensions.unwrap()["data_tier"], "silver");
+    }
+
     #[test]
     fn test_parse_yaml_with_quality_checks() {
         let yaml = r#"
@@ -287,6 +791,7 @@ quality_checks:
     - name: valid_types
       definition: "SELECT COUNT(*) = 0 FROM events WHERE type NOT IN ('a', 'b')"
       severity: error
+      capture_rows: 5
 "#;
 
         let contract = parse_yaml(yaml).expect("Failed to parse YAML with quality checks");
@@ -309,12 +814,14 @@ quality_checks:
         let freshness = qc.freshness.expect("Freshness should be present");
         assert_eq!(freshness.max_delay, "1h");
         assert_eq!(freshness.metric, "event_timestamp");
+        assert_eq!(freshness.source, None);
 
         // Custom checks
         let custom = qc.custom_checks.expect("Custom checks should be present");
         assert_eq!(custom.len(), 1);
         assert_eq!(custom[0].name, "valid_types");
         assert_eq!(custom[0].severity, Some("error".to_string()));
+        assert_eq!(custom[0].capture_rows, Some(5));
     }
 
     #[test]
@@ -425,6 +932,39 @@ nullable = true
         assert!(!user_id.nullable);
     }
 
+    #[test]
+    fn test_parse_yaml_with_datasets() {
+        let yaml = r#"
+version: "1.0.0"
+name: orders
+owner: sales
+schema:
+  format: iceberg
+  location: s3://lake/orders
+  fields: []
+models:
+  order_items:
+    description: Line items of each order
+    schema:
+      format: iceberg
+      location: s3://lake/order_items
+      fields:
+        - name: order_id
+          type: int64
+          nullable: false
+"#;
+
+        let contract = parse_yaml(yaml).expect("Failed to parse multi-dataset contract");
+        let datasets = contract.datasets.as_ref().unwrap();
+        assert_eq!(datasets["order_items"].schema.fields.len(), 1);
+        assert!(datasets["order_items"].quality_checks.is_none());
+
+        // Written back under the canonical `datasets:` key
+        let written = serde_yaml_ng::to_string(&contract).unwrap();
+        assert!(written.contains("datasets:"));
+        assert_eq!(parse_yaml(&written).unwrap(), contract);
+    }
+
     #[test]
     fn test_parse_invalid_toml() {
         let invalid_toml = r#"
@@ -494,6 +1034,23 @@ name = "test"
         }
     }
 
+    #[test]
+    fn test_write_file_round_trip() {
+        let example =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("../../examples/contracts/user_events.yml");
+        let original = parse_file(&example).unwrap();
+        let dir = tempfile::TempDir::new().unwrap();
+        for name in ["contract.yml", "contract.toml"] {
+            let path = dir.path().join(name);
+            write_file(&path, &original).unwrap();
+            assert_eq!(parse_file(&path).unwrap(), original, "{}", name);
+        }
+        assert!(matches!(
+            write_file(&dir.path().join("contract.json"), &original),
+            Err(ParserError::UnsupportedFormat(_))
+        ));
+    }
+
     #[test]
     fn test_round_trip_yaml() {
         // Create a contract, serialize to YAML, parse it back
@@ -507,15 +1064,29 @@ name = "test"
                     name: "id".to_string(),
                     field_type: contracts_core::DataType::from("string"),
                     nullable: false,
+                    required: None,
                     description: Some("ID field".to_string()),
                     tags: Some(vec!["key".to_string()]),
                     constraints: None,
+                    severity: None,
+                    default: None,
+                    deprecated: None,
+                    access: None,
                 }],
                 format: DataFormat::Parquet,
                 location: "s3://test".to_string(),
+                catalog: None,
+                namespace: None,
+                table: None,
+                connection: None,
             },
             quality_checks: None,
             sla: None,
+            conformance_level: None,
+            cdc: None,
+            datasets: None,
+            changelog: None,
+            extensions: None,
         };
 
         // Serialize to YAML
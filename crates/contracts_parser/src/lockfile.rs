@@ -0,0 +1,248 @@
+//! Pinning of registry references in a `dce.lock` file.
+//!
+//! A reference such as `orders@latest` resolves to whatever the registry
+//! holds at the time. The lockfile records the version each reference
+//! resolved to and the SHA-256 of that file, so every machine validates the
+//! same contract until the lock is refreshed with `dce update`.
+
+use crate::{ContractRegistry, ParserError, RegistryRef, Result, VersionSpec};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+/// Default lockfile name, looked up in the working directory.
+pub const LOCKFILE_NAME: &str = "dce.lock";
+
+/// Version of the lockfile layout written by this release.
+const LOCKFILE_VERSION: u32 = 1;
+
+const HEADER: &str = "# Generated by dce. Refresh with `dce update` instead of editing.\n\n";
+
+/// Resolved registry references of a workspace.
+///
+/// # Example
+///
+/// ```no_run
+/// use contracts_parser::{ContractRegistry, Lockfile};
+/// use std::path::Path;
+///
+/// let registry = ContractRegistry::new("registry");
+/// let mut lock = Lockfile::load(Path::new("dce.lock")).unwrap();
+///
+/// let path = lock
+///     .resolve(&registry, &"orders@latest".parse().unwrap())
+///     .unwrap();
+/// lock.save(Path::new("dce.lock")).unwrap();
+/// println!("validating {}", path.display());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Lockfile {
+    /// Layout version of the file
+    pub version: u32,
+
+    /// Pinned contracts, keyed by the reference as written (`name@version`)
+    #[serde(default)]
+    pub contracts: BTreeMap<String, LockedContract>,
+}
+
+/// The version and content hash a reference was pinned to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockedContract {
+    /// Exact version the reference resolved to
+    pub version: String,
+
+    /// Hex-encoded SHA-256 of the contract file
+    pub sha256: String,
+}
+
+impl Default for Lockfile {
+    fn default() -> Self {
+        Self {
+            version: LOCKFILE_VERSION,
+            contracts: BTreeMap::new(),
+        }
+    }
+}
+
+impl Lockfile {
+    /// Reads the lockfile at `path`, or returns an empty one if it does not exist.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)?;
+        let lock: Self =
+            toml::from_str(&content).map_err(|e| ParserError::TomlError(e.to_string()))?;
+        if lock.version > LOCKFILE_VERSION {
+            return Err(ParserError::LockMismatch(format!(
+                "{} has layout version {}, this dce supports up to {}",
+                path.display(),
+                lock.version,
+                LOCKFILE_VERSION
+            )));
+        }
+        Ok(lock)
+    }
+
+    /// Writes the lockfile to `path`.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let body = toml::to_string(self).map_err(|e| ParserError::TomlError(e.to_string()))?;
+        std::fs::write(path, format!("{}{}", HEADER, body))?;
+        Ok(())
+    }
+
+    /// Resolves `reference` through the lock.
+    ///
+    /// A pinned reference resolves to its locked version, and the file must
+    /// still have the locked hash. An unpinned reference is resolved in the
+    /// registry and added to the lock.
+    pub fn resolve(
+        &mut self,
+        registry: &ContractRegistry,
+        reference: &RegistryRef,
+    ) -> Result<PathBuf> {
+        let key = reference.to_string();
+        let Some(locked) = self.contracts.get(&key) else {
+            let (path, locked) = pin(registry, reference)?;
+            self.contracts.insert(key, locked);
+            return Ok(path);
+        };
+
+        let pinned = RegistryRef {
+            name: reference.name.clone(),
+            version: VersionSpec::Exact(locked.version.clone()),
+        };
+        let path = registry.resolve(&pinned)?;
+        let sha256 = file_sha256(&path)?;
+        if sha256 != locked.sha256 {
+            return Err(ParserError::LockMismatch(format!(
+                "{} was locked as {} with sha256 {} but {} now has sha256 {}; run `dce update {}` if the change is intended",
+                key,
+                pinned,
+                locked.sha256,
+                path.display(),
+                sha256,
+                key
+            )));
+        }
+        Ok(path)
+    }
+
+    /// Re-resolves `references` (every pinned reference if empty) against the registry.
+    ///
+    /// Returns each reference with its previous pin, if any, and its new pin.
+    pub fn update(
+        &mut self,
+        registry: &ContractRegistry,
+        references: &[RegistryRef],
+    ) -> Result<Vec<(String, Option<LockedContract>, LockedContract)>> {
+        let references = if references.is_empty() {
+            self.contracts
+                .keys()
+                .map(|key| key.parse())
+                .collect::<Result<Vec<RegistryRef>>>()?
+        } else {
+            references.to_vec()
+        };
+
+        let mut changes = Vec::with_capacity(references.len());
+        for reference in &references {
+            let (_, locked) = pin(registry, reference)?;
+            let key = reference.to_string();
+            let previous = self.contracts.insert(key.clone(), locked.clone());
+            changes.push((key, previous, locked));
+        }
+        Ok(changes)
+    }
+}
+
+/// Resolves `reference` in the registry and records the version and hash found.
+fn pin(registry: &ContractRegistry, reference: &RegistryRef) -> Result<(PathBuf, LockedContract)> {
+    let path = registry.resolve(reference)?;
+    let version = match &reference.version {
+        VersionSpec::Exact(v) => v.clone(),
+        VersionSpec::Latest => path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or_default()
+            .to_string(),
+    };
+    let sha256 = file_sha256(&path)?;
+    Ok((path, LockedContract { version, sha256 }))
+}
+
+/// Returns the hex-encoded SHA-256 of the file at `path`.
+fn file_sha256(path: &Path) -> Result<String> {
+    Ok(sha256_hex(&std::fs::read(path)?))
+}
+
+/// Returns the hex-encoded SHA-256 of `bytes`.
+pub(crate) fn sha256_hex(bytes: &[u8]) -> String {
+    let digest = Sha256::digest(bytes);
+    let mut hex = String::with_capacity(digest.len() * 2);
+    for byte in digest {
+        let _ = write!(hex, "{:02x}", byte);
+    }
+    hex
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn publish(root: &Path, name: &str, version: &str, owner: &str) {
+        let dir = root.join(name);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join(format!("{}.yml", version)),
+            format!(
+                "version: \"{}\"\nname: {}\nowner: {}\nschema:\n  format: parquet\n  location: s3://data/{}\n  fields: []\n",
+                version, name, owner, name
+            ),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_latest_stays_pinned_until_update() {
+        let root = TempDir::new().unwrap();
+        publish(root.path(), "orders", "1.0.0", "sales");
+        let registry = ContractRegistry::new(root.path());
+        let reference: RegistryRef = "orders@latest".parse().unwrap();
+
+        let mut lock = Lockfile::default();
+        lock.resolve(&registry, &reference).unwrap();
+        let lock_path = root.path().join(LOCKFILE_NAME);
+        lock.save(&lock_path).unwrap();
+
+        publish(root.path(), "orders", "1.1.0", "sales");
+        let mut lock = Lockfile::load(&lock_path).unwrap();
+        let path = lock.resolve(&registry, &reference).unwrap();
+        assert!(path.ends_with("orders/1.0.0.yml"));
+
+        let changes = lock.update(&registry, &[]).unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].1.as_ref().unwrap().version, "1.0.0");
+        assert_eq!(changes[0].2.version, "1.1.0");
+        let path = lock.resolve(&registry, &reference).unwrap();
+        assert!(path.ends_with("orders/1.1.0.yml"));
+    }
+
+    #[test]
+    fn test_changed_contract_fails_hash_check() {
+        let root = TempDir::new().unwrap();
+        publish(root.path(), "orders", "1.0.0", "sales");
+        let registry = ContractRegistry::new(root.path());
+        let reference: RegistryRef = "orders@1.0.0".parse().unwrap();
+
+        let mut lock = Lockfile::default();
+        lock.resolve(&registry, &reference).unwrap();
+
+        publish(root.path(), "orders", "1.0.0", "finance");
+        let result = lock.resolve(&registry, &reference);
+        assert!(matches!(result, Err(ParserError::LockMismatch(_))));
+    }
+}
@@ -0,0 +1,284 @@
+//! Sources of truth for contract ownership.
+//!
+//! The `owner` of a contract is free text, so it drifts from reality as
+//! teams are renamed, merged and dissolved. Two files describe the real
+//! organization: the `CODEOWNERS` file of the repository, whose rules assign
+//! paths to GitHub teams and users, and an org directory exported as JSON,
+//! which lists each team with its members and channel.
+
+use crate::Result;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+/// Where GitHub looks for a `CODEOWNERS` file, relative to the repository root.
+pub const CODEOWNERS_LOCATIONS: &[&str] = &[".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+/// The rules of a `CODEOWNERS` file.
+///
+/// # Example
+///
+/// ```rust
+/// use contracts_parser::CodeOwners;
+///
+/// let codeowners = CodeOwners::parse(
+///     "*.yml @acme/data-platform\n/contracts/payments/ @acme/payments @alice\n",
+///     ".",
+/// );
+///
+/// let owners = codeowners.owners_of("contracts/payments/refunds.yml").unwrap();
+/// assert_eq!(owners, ["@acme/payments", "@alice"]);
+/// assert!(codeowners.owners_of("README.md").is_none());
+/// ```
+#[derive(Debug, Clone)]
+pub struct CodeOwners {
+    root: PathBuf,
+    rules: Vec<CodeOwnersRule>,
+}
+
+/// A line of a `CODEOWNERS` file.
+#[derive(Debug, Clone)]
+pub struct CodeOwnersRule {
+    /// Line number in the file, starting at 1
+    pub line: usize,
+
+    /// Path pattern, in `.gitignore` syntax
+    pub pattern: String,
+
+    /// Teams (`@org/team`), users (`@user`) and emails owning the matching paths;
+    /// empty when the rule removes ownership
+    pub owners: Vec<String>,
+
+    matcher: Regex,
+}
+
+impl CodeOwners {
+    /// Parses the content of a `CODEOWNERS` file whose patterns are relative
+    /// to the repository at `root`.
+    pub fn parse(content: &str, root: impl Into<PathBuf>) -> Self {
+        let rules = content
+            .lines()
+            .enumerate()
+            .filter_map(|(index, line)| {
+                let line_content = line.split_once('#').map_or(line, |(before, _)| before);
+                let mut parts = line_content.split_whitespace();
+                let pattern = parts.next()?;
+                Some(CodeOwnersRule {
+                    line: index + 1,
+                    pattern: pattern.to_string(),
+                    owners: parts.map(str::to_string).collect(),
+                    matcher: pattern_regex(pattern),
+                })
+            })
+            .collect();
+        Self {
+            root: root.into(),
+            rules,
+        }
+    }
+
+    /// Reads the `CODEOWNERS` file at `path`.
+    ///
+    /// The repository root is the directory of the file, or its parent when
+    /// the file is in `.github/` or `docs/`.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        let dir = path.parent().unwrap_or(Path::new("."));
+        let root = match dir.file_name().and_then(|name| name.to_str()) {
+            Some(".github" | "docs") => dir.parent().unwrap_or(Path::new(".")),
+            _ => dir,
+        };
+        Ok(Self::parse(&content, root))
+    }
+
+    /// Looks for a `CODEOWNERS` file at the usual locations below `root`.
+    pub fn find(root: &Path) -> Option<PathBuf> {
+        CODEOWNERS_LOCATIONS
+            .iter()
+            .map(|location| root.join(location))
+            .find(|path| path.is_file())
+    }
+
+    /// Returns the rules of the file, in file order.
+    pub fn rules(&self) -> &[CodeOwnersRule] {
+        &self.rules
+    }
+
+    /// Returns the owners of `path`, relative to the repository root, from
+    /// the last rule matching it, or `None` if no rule matches.
+    pub fn owners_of(&self, path: impl AsRef<Path>) -> Option<&[String]> {
+        let path = path.as_ref().to_string_lossy().replace('\\', "/");
+        let path = path.trim_start_matches("./");
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| rule.matcher.is_match(path))
+            .map(|rule| rule.owners.as_slice())
+    }
+
+    /// Returns the owners of the file at `path`, a path on disk, or `None` if
+    /// no rule matches or the file is outside the repository.
+    pub fn owners_of_file(&self, path: &Path) -> Option<&[String]> {
+        let root = std::fs::canonicalize(&self.root).ok()?;
+        let path = std::fs::canonicalize(path).ok()?;
+        self.owners_of(path.strip_prefix(root).ok()?)
+    }
+
+    /// Returns every owner named by a rule.
+    pub fn owners(&self) -> BTreeSet<&str> {
+        self.rules
+            .iter()
+            .flat_map(|rule| &rule.owners)
+            .map(String::as_str)
+            .collect()
+    }
+}
+
+/// Teams of an organization, by name, as exported from the org directory.
+///
+/// # Example
+///
+/// ```rust
+/// use contracts_parser::OrgDirectory;
+///
+/// let directory: OrgDirectory = serde_json::from_str(
+///     r##"{ "payments": { "members": ["alice", "bob"], "channel": "#payments" } }"##,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(directory.team("@acme/payments").unwrap().members.len(), 2);
+/// assert!(directory.team("billing").is_none());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct OrgDirectory {
+    /// Teams, by name
+    pub teams: BTreeMap<String, Team>,
+}
+
+/// A team of the org directory.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Team {
+    /// Users in the team; empty once a reorg has moved everyone out
+    #[serde(default)]
+    pub members: Vec<String>,
+
+    /// Chat channel of the team
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+}
+
+impl OrgDirectory {
+    /// Returns the team named `owner`, as a plain name or a `CODEOWNERS`
+    /// handle (`@org/team`).
+    pub fn team(&self, owner: &str) -> Option<&Team> {
+        self.teams
+            .iter()
+            .find(|(name, _)| same_owner(name, owner))
+            .map(|(_, team)| team)
+    }
+
+    /// Whether `owner` is a member of any team.
+    pub fn is_member(&self, owner: &str) -> bool {
+        self.teams
+            .values()
+            .flat_map(|team| &team.members)
+            .any(|member| same_owner(member, owner))
+    }
+}
+
+/// Whether two spellings name the same owner.
+///
+/// The comparison ignores case and a leading `@`, and a team handle
+/// `@org/team` also matches the bare team name.
+pub fn same_owner(a: &str, b: &str) -> bool {
+    let normalize = |owner: &str| owner.trim().trim_start_matches('@').to_lowercase();
+    let (a, b) = (normalize(a), normalize(b));
+    let team = |owner: &str| owner.rsplit_once('/').map(|(_, team)| team.to_string());
+    a == b || team(&a).is_some_and(|t| t == b) || team(&b).is_some_and(|t| t == a)
+}
+
+/// Translates a `CODEOWNERS` pattern into a regex over `/`-separated paths
+/// relative to the repository root.
+///
+/// As in `.gitignore`, a pattern with a `/` before its end is anchored at
+/// the root, other patterns match at any depth, and a pattern matching a
+/// directory matches everything below it.
+fn pattern_regex(pattern: &str) -> Regex {
+    let anchored = pattern.trim_end_matches('/').contains('/');
+    let pattern = pattern.trim_start_matches('/').trim_end_matches('/');
+
+    let mut regex = String::from(if anchored { "^" } else { "^(?:.*/)?" });
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    regex.push_str("(?:.*/)?");
+                } else {
+                    regex.push_str(".*");
+                }
+            }
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    regex.push_str("(?:/.*)?$");
+    Regex::new(&regex).expect("escaped pattern is a valid regex")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_patterns_follow_gitignore_rules() {
+        let codeowners = CodeOwners::parse(
+            "# Data contracts\n\
+             * @acme/platform\n\
+             contracts/ @acme/data\n\
+             /contracts/*.toml @acme/legacy\n\
+             docs/**/*.md @acme/writers  # docs\n\
+             /contracts/sandbox/\n",
+            ".",
+        );
+        let owners = |path| codeowners.owners_of(path).map(<[String]>::to_vec);
+
+        assert_eq!(owners("build.rs"), Some(vec!["@acme/platform".to_string()]));
+        assert_eq!(
+            owners("contracts/sales/orders.yml"),
+            Some(vec!["@acme/data".to_string()])
+        );
+        assert_eq!(
+            owners("nested/contracts/orders.yml"),
+            Some(vec!["@acme/data".to_string()])
+        );
+        assert_eq!(
+            owners("contracts/orders.toml"),
+            Some(vec!["@acme/legacy".to_string()])
+        );
+        assert_eq!(
+            owners("contracts/sales/orders.toml"),
+            Some(vec!["@acme/data".to_string()])
+        );
+        assert_eq!(
+            owners("docs/guides/setup.md"),
+            Some(vec!["@acme/writers".to_string()])
+        );
+        assert_eq!(owners("contracts/sandbox/tmp.yml"), Some(vec![]));
+        assert_eq!(codeowners.rules()[3].line, 5);
+    }
+
+    #[test]
+    fn test_same_owner() {
+        assert!(same_owner("@acme/Payments", "payments"));
+        assert!(same_owner("payments", "@acme/payments"));
+        assert!(same_owner("@alice", "alice"));
+        assert!(!same_owner("@acme/payments", "@other/billing"));
+        assert!(!same_owner("payments", "payments-team"));
+    }
+}
@@ -0,0 +1,124 @@
+//! Provenance capture for contract files.
+//!
+//! Resolves the git revision a contract file was loaded from, so validation
+//! results can be traced back to an exact contract version.
+
+use contracts_core::ContractProvenance;
+use std::path::Path;
+use std::process::Command;
+
+/// Captures provenance information for the contract file at `path`.
+///
+/// When the file lives in a git work tree, the path is made relative to the
+/// repository root and the `HEAD` commit and dirty state of the file are
+/// recorded. Outside a repository (or when git is not installed) only the
+/// path is filled in.
+///
+/// # Example
+///
+/// ```no_run
+/// use contracts_parser::capture_provenance;
+/// use std::path::Path;
+///
+/// let provenance = capture_provenance(Path::new("contracts/user_events.yml"));
+/// println!("{} @ {:?}", provenance.path, provenance.commit);
+/// ```
+pub fn capture_provenance(path: &Path) -> ContractProvenance {
+    let mut provenance = ContractProvenance::new(path.display().to_string());
+
+    let Some(file_name) = path.file_name() else {
+        return provenance;
+    };
+    let dir = match path.parent() {
+        Some(p) if !p.as_os_str().is_empty() => p,
+        _ => Path::new("."),
+    };
+
+    let Some(commit) = git(dir, &["rev-parse", "HEAD"]) else {
+        return provenance;
+    };
+
+    if let Some(prefix) = git(dir, &["rev-parse", "--show-prefix"]) {
+        provenance.path = format!("{}{}", prefix, file_name.to_string_lossy());
+    }
+
+    let file = file_name.to_string_lossy();
+    provenance.dirty =
+        git(dir, &["status", "--porcelain", "--", file.as_ref()]).map(|status| !status.is_empty());
+    provenance.commit = Some(commit);
+
+    provenance
+}
+
+/// Runs a git command in `dir`, returning its trimmed stdout on success.
+fn git(dir: &Path, args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn run_git(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+            .args(["-c", "commit.gpgsign=false"])
+            .arg("-C")
+            .arg(dir)
+            .args(args)
+            .status()
+            .expect("git should be available");
+        assert!(status.success());
+    }
+
+    #[test]
+    fn test_provenance_outside_repository() {
+        let dir = TempDir::new().unwrap();
+        let file = dir.path().join("contract.yml");
+        std::fs::write(&file, "name: x").unwrap();
+
+        let provenance = capture_provenance(&file);
+
+        // The temp dir may itself live inside a repository on some machines,
+        // so only assert on the path when no commit was found.
+        if provenance.commit.is_none() {
+            assert_eq!(provenance.path, file.display().to_string());
+            assert_eq!(provenance.dirty, None);
+        }
+    }
+
+    #[test]
+    fn test_provenance_tracks_commit_and_dirty_state() {
+        let dir = TempDir::new().unwrap();
+        let contracts = dir.path().join("contracts");
+        std::fs::create_dir_all(&contracts).unwrap();
+        let file = contracts.join("orders.yml");
+        std::fs::write(&file, "name: orders").unwrap();
+
+        run_git(dir.path(), &["init", "-q"]);
+        run_git(dir.path(), &["add", "."]);
+        run_git(dir.path(), &["commit", "-q", "-m", "init"]);
+
+        let provenance = capture_provenance(&file);
+        assert_eq!(provenance.path, "contracts/orders.yml");
+        assert_eq!(provenance.commit.as_ref().map(|c| c.len()), Some(40));
+        assert_eq!(provenance.dirty, Some(false));
+        assert_eq!(provenance.short_commit().map(str::len), Some(12));
+
+        std::fs::write(&file, "name: orders_v2").unwrap();
+        let provenance = capture_provenance(&file);
+        assert_eq!(provenance.dirty, Some(true));
+    }
+}
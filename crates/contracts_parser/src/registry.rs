@@ -0,0 +1,453 @@
+//! Contract lookup by name and version in a registry directory.
+//!
+//! A registry is a directory holding one subdirectory per contract name and
+//! one file per published version:
+//!
+//! ```text
+//! registry/
+//!   user_events/
+//!     1.0.0.yml
+//!     1.0.0.provenance.json
+//!     1.1.0.yml
+//!   orders/
+//!     2.0.0.toml
+//! ```
+//!
+//! Jobs refer to contracts as `name@version` (or `name@latest`) and always
+//! read the published file instead of a local copy. Published files are
+//! never replaced: a change ships as a new version. Where a version was
+//! published from, when known, is kept next to it in `<version>.provenance.json`.
+
+use crate::{
+    ContractFormat, ParserError, Result, capture_provenance, detect_format, parse_file, parse_toml,
+    parse_yaml,
+};
+use contracts_core::ContractProvenance;
+use serde_json::json;
+use std::cmp::Ordering;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// The version part of a [`RegistryRef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VersionSpec {
+    /// The highest published release (pre-releases such as `2.0.0-rc1` are skipped)
+    Latest,
+    /// One exact version
+    Exact(String),
+}
+
+/// A reference to a published contract, written `name@version` or `name@latest`.
+///
+/// # Example
+///
+/// ```rust
+/// use contracts_parser::{RegistryRef, VersionSpec};
+///
+/// let reference: RegistryRef = "user_events@1.2.0".parse().unwrap();
+/// assert_eq!(reference.name, "user_events");
+/// assert_eq!(reference.version, VersionSpec::Exact("1.2.0".to_string()));
+///
+/// assert!("user_events".parse::<RegistryRef>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegistryRef {
+    /// Contract name
+    pub name: String,
+    /// Requested version
+    pub version: VersionSpec,
+}
+
+impl FromStr for RegistryRef {
+    type Err = ParserError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || {
+            ParserError::InvalidReference(format!("'{}' (expected name@version or name@latest)", s))
+        };
+        let (name, version) = s.split_once('@').ok_or_else(invalid)?;
+        let is_segment = |part: &str| {
+            !part.is_empty() && !part.contains(['/', '\\']) && part != "." && part != ".."
+        };
+        if !is_segment(name) || !is_segment(version) {
+            return Err(invalid());
+        }
+
+        let version = match version {
+            "latest" => VersionSpec::Latest,
+            v => VersionSpec::Exact(v.to_string()),
+        };
+        Ok(Self {
+            name: name.to_string(),
+            version,
+        })
+    }
+}
+
+impl fmt::Display for RegistryRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.version {
+            VersionSpec::Latest => write!(f, "{}@latest", self.name),
+            VersionSpec::Exact(v) => write!(f, "{}@{}", self.name, v),
+        }
+    }
+}
+
+/// A directory of published contracts.
+#[derive(Debug, Clone)]
+pub struct ContractRegistry {
+    root: PathBuf,
+}
+
+impl ContractRegistry {
+    /// Opens the registry rooted at `root`.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Returns the registry root directory.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Lists the published versions of `name`, lowest first.
+    pub fn versions(&self, name: &str) -> Result<Vec<String>> {
+        let dir = self.root.join(name);
+        if !dir.is_dir() {
+            return Err(ParserError::NotInRegistry(format!(
+                "no contract named '{}' in {}",
+                name,
+                self.root.display()
+            )));
+        }
+
+        let mut versions = Vec::new();
+        for entry in std::fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.is_file()
+                && detect_format(&path).is_ok()
+                && let Some(stem) = path.file_stem().and_then(|s| s.to_str())
+            {
+                versions.push(stem.to_string());
+            }
+        }
+        versions.sort_by(|a, b| compare_versions(a, b));
+        versions.dedup();
+        Ok(versions)
+    }
+
+    /// Publishes the contract file `file_name` with `content`, as
+    /// `<name>/<version>` under the root, and returns its path.
+    ///
+    /// Publishing the same content again is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the content does not parse, or if a different file is
+    /// already published for the same name and version.
+    pub fn add(&self, file_name: &str, content: &str) -> Result<PathBuf> {
+        self.add_with_provenance(file_name, content, None)
+    }
+
+    /// Publishes the contract file at `path`, as [`add`](Self::add) does,
+    /// recording its git provenance (see [`capture_provenance`]).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use contracts_parser::ContractRegistry;
+    /// use std::path::Path;
+    ///
+    /// let registry = ContractRegistry::new("registry");
+    /// registry.publish(Path::new("contracts/orders.yml")).unwrap();
+    /// let provenance = registry.provenance(&"orders@1.0.0".parse().unwrap()).unwrap();
+    /// println!("{:?}", provenance.and_then(|p| p.commit));
+    /// ```
+    pub fn publish(&self, path: &Path) -> Result<PathBuf> {
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let content = std::fs::read_to_string(path)?;
+        self.add_with_provenance(&file_name, &content, Some(&capture_provenance(path)))
+    }
+
+    /// Publishes a contract as [`add`](Self::add) does, recording
+    /// `provenance` next to a newly published file.
+    pub fn add_with_provenance(
+        &self,
+        file_name: &str,
+        content: &str,
+        provenance: Option<&ContractProvenance>,
+    ) -> Result<PathBuf> {
+        let format = detect_format(Path::new(file_name))?;
+        let contract = match format {
+            ContractFormat::Yaml => parse_yaml(content)?,
+            ContractFormat::Toml => parse_toml(content)?,
+        };
+        RegistryRef::from_str(&format!("{}@{}", contract.name, contract.version))?;
+
+        let dir = self.root.join(&contract.name);
+        for ext in ["yml", "yaml", "toml"] {
+            let existing = dir.join(format!("{}.{}", contract.version, ext));
+            if existing.is_file() {
+                if std::fs::read_to_string(&existing)? == content {
+                    return Ok(existing);
+                }
+                return Err(ParserError::AlreadyPublished(format!(
+                    "{}@{} differs from {}",
+                    contract.name,
+                    contract.version,
+                    existing.display()
+                )));
+            }
+        }
+
+        let ext = match format {
+            ContractFormat::Yaml => "yml",
+            ContractFormat::Toml => "toml",
+        };
+        let path = dir.join(format!("{}.{}", contract.version, ext));
+        std::fs::create_dir_all(&dir)?;
+        std::fs::write(&path, content)?;
+        if let Some(provenance) = provenance {
+            let record = json!({
+                "path": provenance.path,
+                "commit": provenance.commit,
+                "dirty": provenance.dirty,
+            });
+            std::fs::write(
+                dir.join(format!("{}.provenance.json", contract.version)),
+                record.to_string(),
+            )?;
+        }
+        Ok(path)
+    }
+
+    /// Returns where the version `reference` points to was published from,
+    /// or `None` when it was published without provenance.
+    pub fn provenance(&self, reference: &RegistryRef) -> Result<Option<ContractProvenance>> {
+        let path = self.resolve(reference)?.with_extension("provenance.json");
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let record: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path)?)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        Ok(Some(ContractProvenance {
+            path: record["path"].as_str().unwrap_or_default().to_string(),
+            commit: record["commit"].as_str().map(str::to_string),
+            dirty: record["dirty"].as_bool(),
+        }))
+    }
+
+    /// Returns the file holding the contract `reference` points to.
+    ///
+    /// The file must declare the same name and version as the reference, so a
+    /// misplaced file is never validated in place of the published contract.
+    pub fn resolve(&self, reference: &RegistryRef) -> Result<PathBuf> {
+        let version = match &reference.version {
+            VersionSpec::Exact(v) => v.clone(),
+            VersionSpec::Latest => self
+                .versions(&reference.name)?
+                .into_iter()
+                .rfind(|v| !v.contains('-'))
+                .ok_or_else(|| {
+                    ParserError::NotInRegistry(format!(
+                        "no released version of '{}'",
+                        reference.name
+                    ))
+                })?,
+        };
+
+        let dir = self.root.join(&reference.name);
+        let path = ["yml", "yaml", "toml"]
+            .iter()
+            .map(|ext| dir.join(format!("{}.{}", version, ext)))
+            .find(|p| p.is_file())
+            .ok_or_else(|| {
+                ParserError::NotInRegistry(format!(
+                    "{}@{} not found in {}",
+                    reference.name,
+                    version,
+                    self.root.display()
+                ))
+            })?;
+
+        let contract = parse_file(&path)?;
+        if contract.name != reference.name || contract.version != version {
+            return Err(ParserError::NotInRegistry(format!(
+                "{} declares {}@{} instead of {}@{}",
+                path.display(),
+                contract.name,
+                contract.version,
+                reference.name,
+                version
+            )));
+        }
+        Ok(path)
+    }
+}
+
+/// Orders versions by their dot-separated numeric components, so `1.10.0`
+/// sorts after `1.9.0`. Non-numeric components compare as text.
+fn compare_versions(a: &str, b: &str) -> Ordering {
+    let mut left = a.split(['.', '-']);
+    let mut right = b.split(['.', '-']);
+    loop {
+        match (left.next(), right.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(l), Some(r)) => {
+                let ordering = match (l.parse::<u64>(), r.parse::<u64>()) {
+                    (Ok(l), Ok(r)) => l.cmp(&r),
+                    _ => l.cmp(r),
+                };
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn publish(root: &Path, name: &str, version: &str) {
+        let dir = root.join(name);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join(format!("{}.yml", version)),
+            format!(
+                "version: \"{}\"\nname: {}\nowner: team\nschema:\n  format: parquet\n  location: s3://data/{}\n  fields: []\n",
+                version, name, name
+            ),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_latest_skips_prereleases_and_sorts_numerically() {
+        let root = TempDir::new().unwrap();
+        for version in ["1.2.0", "1.10.0", "1.9.3", "2.0.0-rc1"] {
+            publish(root.path(), "orders", version);
+        }
+        let registry = ContractRegistry::new(root.path());
+
+        assert_eq!(
+            registry.versions("orders").unwrap(),
+            vec!["1.2.0", "1.9.3", "1.10.0", "2.0.0-rc1"]
+        );
+        let latest = registry.resolve(&"orders@latest".parse().unwrap()).unwrap();
+        assert!(latest.ends_with("orders/1.10.0.yml"));
+    }
+
+    #[test]
+    fn test_resolve_rejects_missing_and_mismatched_versions() {
+        let root = TempDir::new().unwrap();
+        publish(root.path(), "orders", "1.0.0");
+        std::fs::copy(
+            root.path().join("orders/1.0.0.yml"),
+            root.path().join("orders/1.1.0.yml"),
+        )
+        .unwrap();
+        let registry = ContractRegistry::new(root.path());
+
+        let missing = registry.resolve(&"orders@3.0.0".parse().unwrap());
+        assert!(matches!(missing, Err(ParserError::NotInRegistry(_))));
+        let mismatched = registry.resolve(&"orders@1.1.0".parse().unwrap());
+        assert!(matches!(mismatched, Err(ParserError::NotInRegistry(_))));
+        let unknown = registry.resolve(&"users@latest".parse().unwrap());
+        assert!(matches!(unknown, Err(ParserError::NotInRegistry(_))));
+    }
+
+    #[test]
+    fn test_add_never_replaces_published_files() {
+        let root = TempDir::new().unwrap();
+        let registry = ContractRegistry::new(root.path());
+        let content = "version: \"1.0.0\"\nname: orders\nowner: team\nschema:\n  format: parquet\n  location: s3://data/orders\n  fields: []\n";
+
+        let path = registry.add("orders.yaml", content).unwrap();
+        assert!(path.ends_with("orders/1.0.0.yml"));
+        assert_eq!(registry.add("orders.yaml", content).unwrap(), path);
+        let changed = registry.add("orders.yaml", &content.replace("team", "sales"));
+        assert!(matches!(changed, Err(ParserError::AlreadyPublished(_))));
+        assert!(
+            registry
+                .resolve(&"orders@latest".parse().unwrap())
+                .unwrap()
+                .ends_with("orders/1.0.0.yml")
+        );
+    }
+
+    #[test]
+    fn test_publish_records_git_provenance() {
+        let repo = TempDir::new().unwrap();
+        let git = |args: &[&str]| {
+            let status = std::process::Command::new("git")
+                .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+                .args(["-c", "commit.gpgsign=false"])
+                .arg("-C")
+                .arg(repo.path())
+                .args(args)
+                .status()
+                .unwrap();
+            assert!(status.success());
+        };
+        let file = repo.path().join("orders.yml");
+        std::fs::write(
+            &file,
+            "version: \"1.0.0\"\nname: orders\nowner: team\nschema:\n  format: parquet\n  location: s3://data/orders\n  fields: []\n",
+        )
+        .unwrap();
+        git(&["init", "-q"]);
+        git(&["add", "."]);
+        git(&["commit", "-q", "-m", "Add orders"]);
+        let head = std::process::Command::new("git")
+            .arg("-C")
+            .arg(repo.path())
+            .args(["rev-parse", "HEAD"])
+            .output()
+            .unwrap();
+        let head = String::from_utf8(head.stdout).unwrap().trim().to_string();
+
+        let root = TempDir::new().unwrap();
+        let registry = ContractRegistry::new(root.path());
+        registry.publish(&file).unwrap();
+
+        let provenance = registry
+            .provenance(&"orders@1.0.0".parse().unwrap())
+            .unwrap()
+            .unwrap();
+        assert_eq!(provenance.path, "orders.yml");
+        assert_eq!(provenance.commit, Some(head));
+        assert_eq!(provenance.dirty, Some(false));
+        // The record is not mistaken for a published version
+        assert_eq!(registry.versions("orders").unwrap(), vec!["1.0.0"]);
+
+        let content = std::fs::read_to_string(&file).unwrap();
+        registry
+            .add("orders.yml", &content.replace("1.0.0", "1.1.0"))
+            .unwrap();
+        assert_eq!(
+            registry
+                .provenance(&"orders@1.1.0".parse().unwrap())
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_reference_rejects_paths() {
+        assert!("../secrets@1.0.0".parse::<RegistryRef>().is_err());
+        assert!("orders@".parse::<RegistryRef>().is_err());
+        assert_eq!(
+            "orders@latest".parse::<RegistryRef>().unwrap().to_string(),
+            "orders@latest"
+        );
+    }
+}
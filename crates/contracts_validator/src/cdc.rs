@@ -0,0 +1,296 @@
+//! Change data capture envelopes.
+//!
+//! With a [`CdcConfig`] on the contract, each row read is a Debezium-style
+//! change event instead of a record. [`ChangeEvents`] checks the envelope of
+//! every event (an allowed `op`, row images that fit it and a `ts_ms`) and
+//! the order of the changes of each key, and hands the `after` images on to
+//! the schema, constraint and quality checks. It keeps the latest timestamp
+//! of every key, so events can arrive in chunks.
+
+use crate::quality::uniqueness_key;
+use crate::{DataRow, DataSet, DataValue, ValidationError};
+use contracts_core::{CdcConfig, Contract, Severity, Violation};
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+
+/// Events breaking one envelope or order rule: how many, and the first.
+#[derive(Debug, Default, Clone)]
+struct Tally {
+    count: usize,
+    first: Option<String>,
+}
+
+impl Tally {
+    fn add(&mut self, describe: impl FnOnce() -> String) {
+        self.count += 1;
+        if self.first.is_none() {
+            self.first = Some(describe());
+        }
+    }
+
+    /// Returns "{count} change(s) {what} (first: ...)" if any event was
+    /// counted.
+    fn message(&self, what: &str) -> Option<String> {
+        let first = self.first.as_deref()?;
+        Some(format!(
+            "{} change(s) {} (first: {})",
+            self.count, what, first
+        ))
+    }
+}
+
+/// Checks change events and unwraps their `after` images.
+#[derive(Debug, Clone)]
+pub(crate) struct ChangeEvents<'a> {
+    config: &'a CdcConfig,
+    allowed_ops: Vec<&'a str>,
+    /// Events seen so far, numbering the rows of later chunks
+    events: usize,
+    /// Newest `ts_ms` of each key
+    latest: HashMap<String, i64>,
+    invalid_ops: Tally,
+    mismatched_images: Tally,
+    missing_timestamps: Tally,
+    out_of_order: Tally,
+}
+
+impl<'a> ChangeEvents<'a> {
+    pub(crate) fn new(config: &'a CdcConfig) -> Self {
+        Self {
+            config,
+            allowed_ops: config.allowed_ops(),
+            events: 0,
+            latest: HashMap::new(),
+            invalid_ops: Tally::default(),
+            mismatched_images: Tally::default(),
+            missing_timestamps: Tally::default(),
+            out_of_order: Tally::default(),
+        }
+    }
+
+    /// Checks the events of `chunk` and returns their `after` images.
+    ///
+    /// Deletes have no `after` image and add no row.
+    pub(crate) fn unwrap(&mut self, chunk: &DataSet) -> DataSet {
+        let mut images = Vec::new();
+        for event in chunk.rows() {
+            let row = self.events;
+            self.events += 1;
+
+            let op = event.get("op").and_then(DataValue::as_string);
+            if !op.is_some_and(|op| self.allowed_ops.contains(&op)) {
+                self.invalid_ops.add(|| match op {
+                    Some(op) => format!("'{}' at row {}", op, row),
+                    None => format!("no op at row {}", row),
+                });
+            }
+
+            let before = image(event, "before");
+            let after = image(event, "after");
+            let fits = match op {
+                Some("d") => after.is_none(),
+                Some("c" | "r" | "u") => after.is_some(),
+                _ => true,
+            };
+            if !fits {
+                self.mismatched_images
+                    .add(|| format!("'{}' at row {}", op.unwrap_or_default(), row));
+            }
+
+            match event.get("ts_ms").and_then(DataValue::as_int) {
+                Some(ts) => self.check_order(after.or(before), ts, row),
+                None => self.missing_timestamps.add(|| format!("row {}", row)),
+            }
+
+            images.extend(after.cloned());
+        }
+        DataSet::from_rows(images)
+    }
+
+    /// Records `ts` as the time of a change to the key of `image`, counting
+    /// the change if the key already had a newer one.
+    fn check_order(&mut self, image: Option<&DataRow>, ts: i64, row: usize) {
+        if self.config.key.is_empty() {
+            return;
+        }
+        let Some(key) = image.and_then(|image| uniqueness_key(&self.config.key, image)) else {
+            return;
+        };
+        match self.latest.entry(key) {
+            // The newer change stays the latest
+            Entry::Occupied(latest) if ts < *latest.get() => {
+                self.out_of_order
+                    .add(|| format!("key '{}' at row {}", latest.key(), row));
+            }
+            Entry::Occupied(mut latest) => {
+                latest.insert(ts);
+            }
+            Entry::Vacant(latest) => {
+                latest.insert(ts);
+            }
+        }
+    }
+
+    /// Returns the violations of the events checked so far: envelope errors,
+    /// and out-of-order changes as errors in strict mode, warnings otherwise.
+    pub(crate) fn violations(&self, strict: bool) -> Vec<Violation> {
+        let allowed = format!(
+            "with an operation other than {}",
+            self.allowed_ops.join(", ")
+        );
+        let envelope = [
+            self.invalid_ops.message(&allowed),
+            self.mismatched_images
+                .message("whose after image does not fit their operation"),
+            self.missing_timestamps.message("without a ts_ms"),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|message| ValidationError::CdcEnvelope(message).to_violation());
+        let order = self
+            .out_of_order
+            .message("older than an earlier change of their key")
+            .map(|message| {
+                ValidationError::CdcOrder(message)
+                    .to_violation()
+                    .with_severity(Severity::default_for(strict))
+            });
+        envelope.chain(order).collect()
+    }
+}
+
+/// Returns the row image of an envelope field, if it holds one.
+fn image<'e>(event: &'e DataRow, name: &str) -> Option<&'e DataRow> {
+    match event.get(name) {
+        Some(DataValue::Map(image)) => Some(image),
+        _ => None,
+    }
+}
+
+/// Unwraps the change events of `dataset` when the contract declares CDC
+/// envelopes, returning their `after` images and the envelope and order
+/// violations.
+pub(crate) fn unwrap_events(
+    contract: &Contract,
+    dataset: &DataSet,
+    strict: bool,
+) -> Option<(DataSet, Vec<Violation>)> {
+    let mut events = ChangeEvents::new(contract.cdc.as_ref()?);
+    let images = events.unwrap(dataset);
+    Some((images, events.violations(strict)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(op: &str, id: i64, ts_ms: i64) -> DataRow {
+        let image = DataValue::Map(HashMap::from([("id".to_string(), DataValue::Int(id))]));
+        let (before, after) = match op {
+            "d" => (image, DataValue::Null),
+            "c" | "r" => (DataValue::Null, image),
+            _ => (image.clone(), image),
+        };
+        HashMap::from([
+            ("op".to_string(), DataValue::String(op.to_string())),
+            ("before".to_string(), before),
+            ("after".to_string(), after),
+            ("ts_ms".to_string(), DataValue::Int(ts_ms)),
+        ])
+    }
+
+    fn config() -> CdcConfig {
+        CdcConfig {
+            key: vec!["id".to_string()],
+            allowed_ops: None,
+        }
+    }
+
+    #[test]
+    fn test_unwrap_keeps_after_images() {
+        let config = config();
+        let mut events = ChangeEvents::new(&config);
+        let images = events.unwrap(&DataSet::from_rows(vec![
+            event("c", 1, 100),
+            event("u", 1, 200),
+            event("d", 1, 300),
+        ]));
+
+        assert_eq!(images.len(), 2);
+        assert_eq!(images.get_row(0).unwrap()["id"], DataValue::Int(1));
+        assert!(events.violations(false).is_empty());
+    }
+
+    #[test]
+    fn test_envelope_violations() {
+        let config = CdcConfig {
+            allowed_ops: Some(vec!["c".to_string(), "u".to_string()]),
+            ..config()
+        };
+        let mut events = ChangeEvents::new(&config);
+        let mut missing_after = event("c", 2, 100);
+        missing_after.insert("after".to_string(), DataValue::Null);
+        let mut missing_ts = event("u", 3, 100);
+        missing_ts.remove("ts_ms");
+        events.unwrap(&DataSet::from_rows(vec![
+            event("c", 1, 100),
+            event("d", 1, 200),
+            missing_after,
+            missing_ts,
+        ]));
+
+        let messages: Vec<_> = events
+            .violations(true)
+            .into_iter()
+            .map(|v| (v.code.code, v.message))
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                (
+                    "DCE0208",
+                    "Invalid CDC envelope: 1 change(s) with an operation other than c, u \
+                     (first: 'd' at row 1)"
+                        .to_string()
+                ),
+                (
+                    "DCE0208",
+                    "Invalid CDC envelope: 1 change(s) whose after image does not fit their \
+                     operation (first: 'c' at row 2)"
+                        .to_string()
+                ),
+                (
+                    "DCE0208",
+                    "Invalid CDC envelope: 1 change(s) without a ts_ms (first: row 3)".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_out_of_order_changes_across_chunks() {
+        let config = config();
+        let mut events = ChangeEvents::new(&config);
+        events.unwrap(&DataSet::from_rows(vec![
+            event("c", 1, 100),
+            event("c", 2, 500),
+            event("u", 1, 300),
+        ]));
+        // Key 2 changed at 500 in the previous chunk
+        events.unwrap(&DataSet::from_rows(vec![
+            event("u", 2, 400),
+            event("u", 1, 300),
+            event("d", 1, 200),
+        ]));
+
+        let violations = events.violations(false);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code.code, "DCE0308");
+        assert_eq!(violations[0].severity, Severity::Warning);
+        assert_eq!(
+            violations[0].message,
+            "CDC changes out of order: 2 change(s) older than an earlier change of their key \
+             (first: key '2' at row 3)"
+        );
+    }
+}
@@ -4,17 +4,36 @@
 //! - AllowedValues: Field must be one of a predefined set
 //! - Range: Numeric field must be within min/max bounds
 //! - Pattern: String field must match a regex pattern
-//! - Custom: User-defined constraint expressions
-
+//! - Length: String field must have a bounded number of characters
+//! - ByteLength: String field must take a bounded number of UTF-8 bytes
+//! - ListLength: List field must have a bounded number of elements
+//! - MapKeys: Map keys must be allowed and/or match a regex pattern
+//! - NonEmpty: List or map field must have at least one element
+//! - Precision: Numeric field must fit a decimal(precision, scale) type
+//! - TimeRange: Timestamp field must be within absolute or relative bounds
+//! - MaxNullFraction: At most a share of the records may be null
+//! - Cardinality: Field must have a bounded number of distinct values
+//! - Conditional: Rows matching a predicate must match a second one
+//! - Custom: Expressions over the value and the rest of the row
+
+use crate::custom::{parse_time_bound, parse_timestamp};
+use crate::parallel;
+use crate::row_filter::{Condition, Expression};
 use crate::{DataRow, DataSet, DataValue, ValidationError};
-use contracts_core::{Contract, Field, FieldConstraints};
+use chrono::{DateTime, NaiveTime, Utc};
+use contracts_core::{ColumnStats, Contract, Field, FieldConstraints};
 use regex::Regex;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Validates field constraints in a dataset.
+#[derive(Clone)]
 pub struct ConstraintValidator {
     /// Cache of compiled regex patterns
     regex_cache: HashMap<String, Regex>,
+
+    /// Time of validation, which relative `TimeRange` bounds are resolved
+    /// against
+    now: DateTime<Utc>,
 }
 
 impl ConstraintValidator {
@@ -22,6 +41,7 @@ impl ConstraintValidator {
     pub fn new() -> Self {
         Self {
             regex_cache: HashMap::new(),
+            now: Utc::now(),
         }
     }
 
@@ -29,15 +49,118 @@ impl ConstraintValidator {
     ///
     /// Returns a list of validation errors. An empty list indicates success.
     pub fn validate(&mut self, contract: &Contract, dataset: &DataSet) -> Vec<ValidationError> {
+        let mut errors = self.validate_rows(contract, dataset);
+        errors.extend(distribution_errors(contract, dataset));
+        errors
+    }
+
+    /// Validates all constraints like [`validate`](Self::validate), with the
+    /// rows split into chunks validated on `pool`, or on the global pool when
+    /// `None` (see [`crate::parallel`]).
+    pub(crate) fn validate_parallel(
+        &self,
+        contract: &Contract,
+        dataset: &DataSet,
+        pool: Option<&rayon::ThreadPool>,
+    ) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        if dataset.is_empty() {
+            return errors;
+        }
+
+        let predicates = RowPredicates::parse(contract, &mut errors);
+        errors.extend(parallel::in_chunks(
+            dataset.row_slice(),
+            pool,
+            |rows, first_row| {
+                // Each chunk compiles its own patterns
+                self.clone()
+                    .validate_slice(contract, &predicates, rows, first_row)
+            },
+        ));
+        errors.extend(distribution_errors(contract, dataset));
+        errors
+    }
+
+    /// Validates all constraints like [`validate`](Self::validate), `chunk_rows`
+    /// rows at a time, and stops after the first chunk with an error for
+    /// which `stops` holds.
+    pub(crate) fn validate_until(
+        &mut self,
+        contract: &Contract,
+        dataset: &DataSet,
+        chunk_rows: usize,
+        stops: impl Fn(&ValidationError) -> bool,
+    ) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        if dataset.is_empty() {
+            return errors;
+        }
+
+        let predicates = RowPredicates::parse(contract, &mut errors);
+        let mut stopped = errors.iter().any(&stops);
+        for (index, rows) in dataset.row_slice().chunks(chunk_rows).enumerate() {
+            if stopped {
+                return errors;
+            }
+            let chunk_errors = self.validate_slice(contract, &predicates, rows, index * chunk_rows);
+            stopped = chunk_errors.iter().any(&stops);
+            errors.extend(chunk_errors);
+        }
+        if !stopped {
+            errors.extend(distribution_errors(contract, dataset));
+        }
+        errors
+    }
+
+    /// Validates the constraints that apply to each row on its own, leaving
+    /// out the ones over the whole dataset (see [`check_distributions`]).
+    pub(crate) fn validate_rows(
+        &mut self,
+        contract: &Contract,
+        dataset: &DataSet,
+    ) -> Vec<ValidationError> {
         let mut errors = Vec::new();
 
         if dataset.is_empty() {
             return errors;
         }
 
+        let predicates = RowPredicates::parse(contract, &mut errors);
+        errors.extend(self.validate_slice(contract, &predicates, dataset.row_slice(), 0));
+        errors
+    }
+
+    /// Validates the row constraints of `rows`, the first of which is row
+    /// `first_row` of the dataset.
+    fn validate_slice(
+        &mut self,
+        contract: &Contract,
+        predicates: &RowPredicates<'_>,
+        rows: &[DataRow],
+        first_row: usize,
+    ) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+
         // Validate each row
-        for (row_idx, row) in dataset.rows().enumerate() {
-            errors.extend(self.validate_row(contract, row, row_idx));
+        for (row_idx, row) in rows.iter().enumerate() {
+            errors.extend(self.validate_row(contract, row, first_row + row_idx));
+            for (field, when, require, condition) in &predicates.conditions {
+                if condition.is_violated_by(row) {
+                    errors.push(ValidationError::constraint(
+                        &field.name,
+                        format!("Row fails {}", describe_condition(when, require)),
+                    ));
+                }
+            }
+            for (field, constraint, expression) in &predicates.expressions {
+                if expression.is_violated_by(row) {
+                    errors.push(ValidationError::constraint(
+                        &field.name,
+                        format!("Row fails {constraint}"),
+                    ));
+                }
+            }
         }
 
         errors
@@ -90,9 +213,44 @@ impl ConstraintValidator {
             FieldConstraints::Pattern { regex } => {
                 self.validate_pattern(field, value, regex, row_idx)
             }
-            FieldConstraints::Custom { definition } => {
-                self.validate_custom(field, value, definition, row_idx)
+            FieldConstraints::Length { min, max } => {
+                self.validate_string_length(field, value, *min, *max, false)
+            }
+            FieldConstraints::ByteLength { min, max } => {
+                self.validate_string_length(field, value, *min, *max, true)
             }
+            FieldConstraints::ListLength { min, max } => {
+                self.validate_list_length(field, value, *min, *max, row_idx)
+            }
+            FieldConstraints::MapKeys { pattern, allowed } => self.validate_map_keys(
+                field,
+                value,
+                pattern.as_deref(),
+                allowed.as_deref(),
+                row_idx,
+            ),
+            FieldConstraints::Precision { precision, scale } => {
+                self.validate_precision(field, value, *precision, *scale)
+            }
+            FieldConstraints::TimeRange {
+                min,
+                max,
+                allow_future,
+            } => self.validate_time_range(
+                field,
+                value,
+                min.as_deref(),
+                max.as_deref(),
+                *allow_future,
+            ),
+            FieldConstraints::NonEmpty => self.validate_non_empty(field, value, row_idx),
+            // Null values are reported by the schema validator
+            FieldConstraints::NotNull => None,
+            // Checked over the whole dataset by check_distributions
+            FieldConstraints::MaxNullFraction { .. } | FieldConstraints::Cardinality { .. } => None,
+            // Conditions and custom expressions apply to whole rows and are
+            // checked in validate
+            FieldConstraints::Conditional { .. } | FieldConstraints::Custom { .. } => None,
         }
     }
 
@@ -107,6 +265,13 @@ impl ConstraintValidator {
         let str_value = match value {
             DataValue::String(s) => s.as_str(),
             DataValue::Int(i) => return self.check_int_in_allowed(*i, allowed, field),
+            DataValue::UInt(u) => {
+                return self.check_string_in_allowed(&u.to_string(), allowed, field);
+            }
+            DataValue::Decimal { value, scale } => {
+                let decimal = DataValue::decimal_to_string(*value, *scale);
+                return self.check_string_in_allowed(&decimal, allowed, field);
+            }
             DataValue::Float(f) => return self.check_float_in_allowed(*f, allowed, field),
             DataValue::Bool(b) => {
                 let b_str = b.to_string();
@@ -217,6 +382,108 @@ impl ConstraintValidator {
         None
     }
 
+    /// Validates that a numeric value has at most `scale` fractional digits
+    /// and `precision - scale` integer digits.
+    fn validate_precision(
+        &self,
+        field: &Field,
+        value: &DataValue,
+        precision: u8,
+        scale: u8,
+    ) -> Option<ValidationError> {
+        let Some((integer, fraction)) = decimal_digits(value) else {
+            return Some(ValidationError::constraint(
+                &field.name,
+                format!(
+                    "Precision constraint requires numeric type, found {}",
+                    value.type_name()
+                ),
+            ));
+        };
+
+        let max_integer = u32::from(precision.saturating_sub(scale));
+        if fraction > u32::from(scale) || integer > max_integer {
+            let shown = match value {
+                DataValue::Decimal { value, scale } => DataValue::decimal_to_string(*value, *scale),
+                DataValue::Int(i) => i.to_string(),
+                DataValue::UInt(u) => u.to_string(),
+                DataValue::Float(f) => f.to_string(),
+                _ => unreachable!("decimal_digits only accepts numbers"),
+            };
+            return Some(ValidationError::constraint(
+                &field.name,
+                format!(
+                    "Value {} does not fit decimal({}, {})",
+                    shown, precision, scale
+                ),
+            ));
+        }
+
+        None
+    }
+
+    /// Validates that a timestamp or date value is within the bounds of a
+    /// `TimeRange` constraint.
+    fn validate_time_range(
+        &self,
+        field: &Field,
+        value: &DataValue,
+        min: Option<&str>,
+        max: Option<&str>,
+        allow_future: bool,
+    ) -> Option<ValidationError> {
+        let bounds = match TimeBounds::resolve(min, max, allow_future, self.now) {
+            Ok(bounds) => bounds,
+            Err(message) => return Some(ValidationError::constraint(&field.name, message)),
+        };
+        let time = match value {
+            DataValue::Timestamp(s) | DataValue::String(s) => {
+                parse_timestamp(s).map_err(|_| s.clone())
+            }
+            DataValue::Date(date) => Ok(date.and_time(NaiveTime::MIN).and_utc()),
+            DataValue::Int(epoch) => {
+                parse_timestamp(&epoch.to_string()).map_err(|_| epoch.to_string())
+            }
+            _ => {
+                return Some(ValidationError::constraint(
+                    &field.name,
+                    format!(
+                        "TimeRange constraint requires timestamp type, found {}",
+                        value.type_name()
+                    ),
+                ));
+            }
+        };
+        let time = match time {
+            Ok(time) => time,
+            Err(shown) => {
+                return Some(ValidationError::constraint(
+                    &field.name,
+                    format!("Value '{}' is not a valid timestamp", shown),
+                ));
+            }
+        };
+
+        if bounds.min.is_some_and(|min| time < min) || bounds.max.is_some_and(|max| time > max) {
+            return Some(ValidationError::constraint(
+                &field.name,
+                format!(
+                    "Timestamp {} outside time range {}",
+                    time.to_rfc3339(),
+                    describe_time_bounds(min, max)
+                ),
+            ));
+        }
+        if bounds.now.is_some_and(|now| time > now) {
+            return Some(ValidationError::constraint(
+                &field.name,
+                format!("Timestamp {} is in the future", time.to_rfc3339()),
+            ));
+        }
+
+        None
+    }
+
     /// Validates that a string value matches a regex pattern.
     fn validate_pattern(
         &mut self,
@@ -259,16 +526,180 @@ impl ConstraintValidator {
         None
     }
 
-    /// Validates a custom constraint (currently just syntax validation).
-    fn validate_custom(
+    /// Validates that a string has a number of characters, or of UTF-8 bytes
+    /// with `bytes`, within bounds.
+    fn validate_string_length(
+        &self,
+        field: &Field,
+        value: &DataValue,
+        min: Option<usize>,
+        max: Option<usize>,
+        bytes: bool,
+    ) -> Option<ValidationError> {
+        let (constraint, unit) = if bytes {
+            ("ByteLength", "byte(s)")
+        } else {
+            ("Length", "character(s)")
+        };
+        let DataValue::String(s) = value else {
+            return Some(ValidationError::constraint(
+                &field.name,
+                format!(
+                    "{} constraint requires string type, found {}",
+                    constraint,
+                    value.type_name()
+                ),
+            ));
+        };
+
+        let len = if bytes { s.len() } else { s.chars().count() };
+        if min.is_some_and(|min| len < min) || max.is_some_and(|max| len > max) {
+            return Some(ValidationError::constraint(
+                &field.name,
+                format!(
+                    "String of {} {} is outside length bounds {}",
+                    len,
+                    unit,
+                    describe_length_bounds(min, max)
+                ),
+            ));
+        }
+
+        None
+    }
+
+    /// Validates that a list has a number of elements within bounds.
+    fn validate_list_length(
         &self,
-        _field: &Field,
-        _value: &DataValue,
-        _definition: &str,
+        field: &Field,
+        value: &DataValue,
+        min: Option<usize>,
+        max: Option<usize>,
         _row_idx: usize,
     ) -> Option<ValidationError> {
-        // Custom constraints are not executed at this level
-        // They are validated at the quality check level
+        let DataValue::List(items) = value else {
+            return Some(ValidationError::constraint(
+                &field.name,
+                format!(
+                    "ListLength constraint requires list type, found {}",
+                    value.type_name()
+                ),
+            ));
+        };
+
+        let len = items.len();
+        if min.is_some_and(|min| len < min) || max.is_some_and(|max| len > max) {
+            return Some(ValidationError::constraint(
+                &field.name,
+                format!(
+                    "List of {} element(s) is outside length bounds {}",
+                    len,
+                    describe_length_bounds(min, max)
+                ),
+            ));
+        }
+
+        None
+    }
+
+    /// Validates that every key of a map is allowed and matches a pattern.
+    fn validate_map_keys(
+        &mut self,
+        field: &Field,
+        value: &DataValue,
+        pattern: Option<&str>,
+        allowed: Option<&[String]>,
+        _row_idx: usize,
+    ) -> Option<ValidationError> {
+        let DataValue::Map(entries) = value else {
+            return Some(ValidationError::constraint(
+                &field.name,
+                format!(
+                    "MapKeys constraint requires map type, found {}",
+                    value.type_name()
+                ),
+            ));
+        };
+
+        let mut keys: Vec<&String> = entries.keys().collect();
+        keys.sort();
+
+        if let Some(allowed) = allowed {
+            let unknown: Vec<&str> = keys
+                .iter()
+                .filter(|key| !allowed.contains(key))
+                .map(|key| key.as_str())
+                .collect();
+            if !unknown.is_empty() {
+                return Some(ValidationError::constraint(
+                    &field.name,
+                    format!(
+                        "Keys [{}] not in allowed keys: [{}]",
+                        unknown.join(", "),
+                        allowed.join(", ")
+                    ),
+                ));
+            }
+        }
+
+        if let Some(pattern) = pattern {
+            let regex = match self.get_or_compile_regex(pattern) {
+                Ok(r) => r,
+                Err(e) => {
+                    return Some(ValidationError::InvalidRegex {
+                        field: field.name.clone(),
+                        error: e,
+                    });
+                }
+            };
+            let mismatched: Vec<&str> = keys
+                .iter()
+                .filter(|key| !regex.is_match(key))
+                .map(|key| key.as_str())
+                .collect();
+            if !mismatched.is_empty() {
+                return Some(ValidationError::constraint(
+                    &field.name,
+                    format!(
+                        "Keys [{}] do not match key pattern '{}'",
+                        mismatched.join(", "),
+                        pattern
+                    ),
+                ));
+            }
+        }
+
+        None
+    }
+
+    /// Validates that a list or map has at least one element.
+    fn validate_non_empty(
+        &self,
+        field: &Field,
+        value: &DataValue,
+        _row_idx: usize,
+    ) -> Option<ValidationError> {
+        let is_empty = match value {
+            DataValue::List(items) => items.is_empty(),
+            DataValue::Map(entries) => entries.is_empty(),
+            _ => {
+                return Some(ValidationError::constraint(
+                    &field.name,
+                    format!(
+                        "NonEmpty constraint requires list or map type, found {}",
+                        value.type_name()
+                    ),
+                ));
+            }
+        };
+
+        if is_empty {
+            return Some(ValidationError::constraint(
+                &field.name,
+                "Value is an empty collection",
+            ));
+        }
+
         None
     }
 
@@ -282,6 +713,269 @@ impl ConstraintValidator {
     }
 }
 
+/// Conditional and custom constraints of a contract, parsed once per
+/// validation.
+struct RowPredicates<'a> {
+    conditions: Vec<(&'a Field, &'a str, &'a str, Condition)>,
+    expressions: Vec<(&'a Field, &'a FieldConstraints, Expression)>,
+}
+
+impl<'a> RowPredicates<'a> {
+    /// Parses the predicates of `contract`, adding an error for each one
+    /// that is invalid.
+    fn parse(contract: &'a Contract, errors: &mut Vec<ValidationError>) -> Self {
+        Self {
+            conditions: parse_conditions(contract, errors),
+            expressions: parse_expressions(contract, errors),
+        }
+    }
+}
+
+/// Checks the constraints over the whole dataset, from its attached stats or
+/// stats computed from its rows.
+fn distribution_errors(contract: &Contract, dataset: &DataSet) -> Vec<ValidationError> {
+    if dataset.is_empty() || !has_distribution_constraints(contract) {
+        return Vec::new();
+    }
+    let computed;
+    let columns = match dataset.column_stats() {
+        Some(columns) => columns,
+        None => {
+            computed = dataset.compute_column_stats();
+            &computed
+        }
+    };
+    check_distributions(contract, dataset.len(), columns)
+}
+
+/// Parses the conditional constraints of `contract`, adding an error for
+/// each one that is invalid.
+fn parse_conditions<'a>(
+    contract: &'a Contract,
+    errors: &mut Vec<ValidationError>,
+) -> Vec<(&'a Field, &'a str, &'a str, Condition)> {
+    let mut conditions = Vec::new();
+    for field in &contract.schema.fields {
+        for constraint in field.constraints.iter().flatten() {
+            if let FieldConstraints::Conditional { when, require } = constraint {
+                match Condition::parse(field, when, require, contract) {
+                    Ok(condition) => {
+                        conditions.push((field, when.as_str(), require.as_str(), condition))
+                    }
+                    Err(e) => errors.push(e),
+                }
+            }
+        }
+    }
+    conditions
+}
+
+/// Parses the custom constraints of `contract`, adding an error for each
+/// one that is invalid.
+fn parse_expressions<'a>(
+    contract: &'a Contract,
+    errors: &mut Vec<ValidationError>,
+) -> Vec<(&'a Field, &'a FieldConstraints, Expression)> {
+    let mut expressions = Vec::new();
+    for field in &contract.schema.fields {
+        for constraint in field.constraints.iter().flatten() {
+            if let FieldConstraints::Custom { definition } = constraint {
+                match Expression::parse(field, definition, contract) {
+                    Ok(expression) => expressions.push((field, constraint, expression)),
+                    Err(e) => errors.push(e),
+                }
+            }
+        }
+    }
+    expressions
+}
+
+/// Returns whether `contract` has constraints checked over the whole
+/// dataset.
+fn has_distribution_constraints(contract: &Contract) -> bool {
+    contract
+        .schema
+        .fields
+        .iter()
+        .flat_map(|field| field.constraints.iter().flatten())
+        .any(|constraint| {
+            matches!(
+                constraint,
+                FieldConstraints::MaxNullFraction { .. } | FieldConstraints::Cardinality { .. }
+            )
+        })
+}
+
+/// Checks the `maxnullfraction` and `cardinality` constraints of `contract`
+/// against the statistics of `rows` records.
+///
+/// A field without statistics is missing from every record, so all null.
+pub(crate) fn check_distributions(
+    contract: &Contract,
+    rows: usize,
+    columns: &BTreeMap<String, ColumnStats>,
+) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+    if rows == 0 {
+        return errors;
+    }
+    for field in &contract.schema.fields {
+        let stats = columns.get(&field.name);
+        for constraint in field.constraints.iter().flatten() {
+            let message = match constraint {
+                FieldConstraints::MaxNullFraction { fraction } => {
+                    let nulls = stats.map_or(rows, |stats| stats.null_count);
+                    null_fraction_message(nulls, rows, *fraction)
+                }
+                FieldConstraints::Cardinality {
+                    min_distinct,
+                    max_distinct,
+                } => {
+                    let (distinct, exact) = stats.map_or((0, true), |stats| {
+                        (stats.distinct_count, stats.distinct_exact)
+                    });
+                    cardinality_message(distinct, exact, *min_distinct, *max_distinct)
+                }
+                _ => None,
+            };
+            if let Some(message) = message {
+                errors.push(ValidationError::constraint(&field.name, message));
+            }
+        }
+    }
+    errors
+}
+
+/// Describes a `MaxNullFraction` violation, if `nulls` of `rows` values
+/// exceed `fraction`.
+pub(crate) fn null_fraction_message(nulls: usize, rows: usize, fraction: f64) -> Option<String> {
+    let actual = nulls as f64 / rows.max(1) as f64;
+    (actual > fraction).then(|| {
+        format!(
+            "{} of {} value(s) are null ({:.2}%), above the max null fraction of {:.2}%",
+            nulls,
+            rows,
+            actual * 100.0,
+            fraction * 100.0
+        )
+    })
+}
+
+/// Describes a `Cardinality` violation, if `distinct` is outside the bounds.
+///
+/// An inexact count is a lower bound: it can only exceed `max`.
+pub(crate) fn cardinality_message(
+    distinct: usize,
+    exact: bool,
+    min: Option<usize>,
+    max: Option<usize>,
+) -> Option<String> {
+    let below = exact && min.is_some_and(|min| distinct < min);
+    let above = max.is_some_and(|max| distinct > max);
+    (below || above).then(|| {
+        format!(
+            "{}{} distinct value(s) outside cardinality bounds {}",
+            if exact { "" } else { "at least " },
+            distinct,
+            describe_length_bounds(min, max)
+        )
+    })
+}
+
+/// Counts the integer and fractional digits of a number, without leading or
+/// trailing zeros, or returns `None` for other values.
+///
+/// Decimals are counted exactly. Floats are counted in their shortest
+/// representation, so `0.1` has one fractional digit; infinities and NaN fit
+/// no decimal type.
+fn decimal_digits(value: &DataValue) -> Option<(u32, u32)> {
+    let count = |n: u128| if n == 0 { 0 } else { n.ilog10() + 1 };
+    match value {
+        DataValue::Int(i) => Some((count(u128::from(i.unsigned_abs())), 0)),
+        DataValue::UInt(u) => Some((count(u128::from(*u)), 0)),
+        DataValue::Decimal { value, scale } => {
+            let (mut value, mut scale) = (value.unsigned_abs(), i32::from(*scale));
+            while scale > 0 && value != 0 && value % 10 == 0 {
+                value /= 10;
+                scale -= 1;
+            }
+            if value == 0 {
+                return Some((0, 0));
+            }
+            let digits = count(value) as i32;
+            Some(((digits - scale).max(0) as u32, scale.max(0) as u32))
+        }
+        DataValue::Float(f) if !f.is_finite() => Some((u32::MAX, 0)),
+        DataValue::Float(f) => {
+            let shown = f.abs().to_string();
+            let (integer, fraction) = shown.split_once('.').unwrap_or((&shown, ""));
+            let integer = integer.trim_start_matches('0').len() as u32;
+            Some((integer, fraction.len() as u32))
+        }
+        _ => None,
+    }
+}
+
+/// Bounds of a `TimeRange` constraint, resolved against the time of
+/// validation.
+pub(crate) struct TimeBounds {
+    /// Earliest accepted time
+    pub(crate) min: Option<DateTime<Utc>>,
+    /// Latest accepted time
+    pub(crate) max: Option<DateTime<Utc>>,
+    /// Time of validation, when values may not be later
+    pub(crate) now: Option<DateTime<Utc>>,
+}
+
+impl TimeBounds {
+    /// Resolves the bounds of a `TimeRange` constraint, or describes the
+    /// first one that does not parse.
+    pub(crate) fn resolve(
+        min: Option<&str>,
+        max: Option<&str>,
+        allow_future: bool,
+        now: DateTime<Utc>,
+    ) -> Result<Self, String> {
+        let parse = |bound: Option<&str>| {
+            bound
+                .map(|bound| {
+                    parse_time_bound(bound, now).map_err(|e| match e {
+                        ValidationError::InvalidDuration(message) => {
+                            format!("Invalid time bound '{}': {}", bound, message)
+                        }
+                        e => e.to_string(),
+                    })
+                })
+                .transpose()
+        };
+        Ok(Self {
+            min: parse(min)?,
+            max: parse(max)?,
+            now: (!allow_future).then_some(now),
+        })
+    }
+}
+
+/// Formats the bounds of a `TimeRange` constraint as written in the
+/// contract, e.g. `[now-30d, -]`.
+pub(crate) fn describe_time_bounds(min: Option<&str>, max: Option<&str>) -> String {
+    format!("[{}, {}]", min.unwrap_or("-"), max.unwrap_or("-"))
+}
+
+/// Describes a `Conditional` constraint for violation messages.
+pub(crate) fn describe_condition(when: &str, require: &str) -> String {
+    format!("condition 'when {when} require {require}'")
+}
+
+/// Formats the bounds of a length or `Cardinality` constraint as an
+/// interval.
+pub(crate) fn describe_length_bounds(min: Option<usize>, max: Option<usize>) -> String {
+    match max {
+        Some(max) => format!("[{}, {}]", min.unwrap_or(0), max),
+        None => format!("[{}, ∞)", min.unwrap_or(0)),
+    }
+}
+
 impl Default for ConstraintValidator {
     fn default() -> Self {
         Self::new()
@@ -291,7 +985,7 @@ impl Default for ConstraintValidator {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use contracts_core::{ContractBuilder, DataFormat, FieldBuilder};
+    use contracts_core::{Coded, ContractBuilder, DataFormat, FieldBuilder};
 
     #[test]
     fn test_allowed_values_valid() {
@@ -550,4 +1244,352 @@ mod tests {
         let errors = validator.validate(&contract, &dataset);
         assert_eq!(errors.len(), 0); // Null values skip constraint checks
     }
+
+    #[test]
+    fn test_collection_constraints() {
+        let contract = ContractBuilder::new("test", "owner")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(
+                FieldBuilder::new("tags", "list<string>")
+                    .constraint(FieldConstraints::ListLength {
+                        min: Some(1),
+                        max: Some(2),
+                    })
+                    .constraint(FieldConstraints::NonEmpty)
+                    .build(),
+            )
+            .field(
+                FieldBuilder::new("props", "map<string,string>")
+                    .constraint(FieldConstraints::MapKeys {
+                        pattern: Some("^[a-z]+$".to_string()),
+                        allowed: Some(vec!["color".to_string(), "size".to_string()]),
+                    })
+                    .build(),
+            )
+            .build();
+
+        let tag = |t: &str| DataValue::String(t.to_string());
+        let props = |keys: &[&str]| {
+            DataValue::Map(keys.iter().map(|k| (k.to_string(), tag("x"))).collect())
+        };
+        let valid = HashMap::from([
+            ("tags".to_string(), DataValue::List(vec![tag("a")])),
+            ("props".to_string(), props(&["color"])),
+        ]);
+        let invalid = HashMap::from([
+            ("tags".to_string(), DataValue::List(vec![])),
+            ("props".to_string(), props(&["color", "Weight"])),
+        ]);
+
+        let mut validator = ConstraintValidator::new();
+        let dataset = DataSet::from_rows(vec![valid]);
+        assert!(validator.validate(&contract, &dataset).is_empty());
+
+        let dataset = DataSet::from_rows(vec![invalid]);
+        let errors = validator.validate(&contract, &dataset);
+        let codes: Vec<&str> = errors.iter().map(|e| e.error_code().code).collect();
+        assert_eq!(codes, vec!["DCE0205", "DCE0205", "DCE0206"]);
+        assert!(
+            errors[0]
+                .to_string()
+                .contains("outside length bounds [1, 2]")
+        );
+        assert!(
+            errors[2]
+                .to_string()
+                .contains("Keys [Weight] not in allowed keys")
+        );
+    }
+
+    #[test]
+    fn test_conditional_constraint() {
+        let contract = ContractBuilder::new("test", "owner")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("status", "string").build())
+            .field(
+                FieldBuilder::new("closed_at", "timestamp")
+                    .constraint(FieldConstraints::Conditional {
+                        when: "status = 'closed'".to_string(),
+                        require: "closed_at IS NOT NULL".to_string(),
+                    })
+                    .build(),
+            )
+            .build();
+
+        let ticket = |status: &str, closed_at: Option<&str>| {
+            HashMap::from([
+                ("status".to_string(), DataValue::String(status.to_string())),
+                (
+                    "closed_at".to_string(),
+                    closed_at.map_or(DataValue::Null, |t| DataValue::Timestamp(t.to_string())),
+                ),
+            ])
+        };
+        let dataset = DataSet::from_rows(vec![
+            ticket("open", None),
+            ticket("closed", Some("2026-10-17T09:00:00Z")),
+            ticket("closed", None),
+        ]);
+
+        let mut validator = ConstraintValidator::new();
+        let errors = validator.validate(&contract, &dataset);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].error_code().code, "DCE0207");
+        assert_eq!(
+            errors[0].to_string(),
+            "Constraint violation for field 'closed_at': Row fails condition \
+             'when status = 'closed' require closed_at IS NOT NULL'"
+        );
+
+        let mut contract = contract;
+        contract.schema.fields[1].constraints = Some(vec![FieldConstraints::Conditional {
+            when: "state = 'closed'".to_string(),
+            require: "closed_at IS NOT NULL".to_string(),
+        }]);
+        let errors = validator.validate(&contract, &dataset);
+        // Reported once, not for every row
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].error_code().code, "DCE0012");
+    }
+
+    #[test]
+    fn test_custom_constraint() {
+        let contract = ContractBuilder::new("test", "owner")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(
+                FieldBuilder::new("code_length", "int64")
+                    .constraint(FieldConstraints::Custom {
+                        definition: "value > 0 && value <= len(code)".to_string(),
+                    })
+                    .build(),
+            )
+            .field(FieldBuilder::new("code", "string").build())
+            .build();
+
+        let row = |length: Option<i64>, code: &str| {
+            HashMap::from([
+                (
+                    "code_length".to_string(),
+                    length.map_or(DataValue::Null, DataValue::Int),
+                ),
+                ("code".to_string(), DataValue::String(code.to_string())),
+            ])
+        };
+        let dataset = DataSet::from_rows(vec![
+            row(Some(3), "abc"),
+            row(Some(4), "abc"),
+            row(None, "abc"),
+            row(Some(0), "abc"),
+        ]);
+
+        let mut validator = ConstraintValidator::new();
+        let errors = validator.validate(&contract, &dataset);
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].error_code().code, "DCE0200");
+        assert_eq!(
+            errors[0].to_string(),
+            "Constraint violation for field 'code_length': Row fails \
+             custom 'value > 0 && value <= len(code)'"
+        );
+
+        let mut contract = contract;
+        contract.schema.fields[0].constraints = Some(vec![FieldConstraints::Custom {
+            definition: "value > len(name)".to_string(),
+        }]);
+        let errors = validator.validate(&contract, &dataset);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].error_code().code, "DCE0016");
+    }
+
+    #[test]
+    fn test_string_length_constraints() {
+        let contract = ContractBuilder::new("test", "owner")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(
+                FieldBuilder::new("city", "string")
+                    .constraint(FieldConstraints::Length {
+                        min: Some(2),
+                        max: Some(6),
+                    })
+                    .constraint(FieldConstraints::ByteLength {
+                        min: None,
+                        max: Some(6),
+                    })
+                    .build(),
+            )
+            .build();
+
+        let row =
+            |city: &str| HashMap::from([("city".to_string(), DataValue::String(city.to_string()))]);
+        // "Zürich" has 6 characters but takes 7 bytes
+        let dataset = DataSet::from_rows(vec![row("Milano"), row("Zürich"), row("X")]);
+
+        let mut validator = ConstraintValidator::new();
+        let errors = validator.validate(&contract, &dataset);
+        let messages: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Constraint violation for field 'city': String of 7 byte(s) is outside \
+                 length bounds [0, 6]",
+                "Constraint violation for field 'city': String of 1 character(s) is \
+                 outside length bounds [2, 6]",
+            ]
+        );
+        assert!(errors.iter().all(|e| e.error_code().code == "DCE0205"));
+    }
+
+    #[test]
+    fn test_precision_constraint() {
+        let contract = ContractBuilder::new("test", "owner")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(
+                FieldBuilder::new("amount", "decimal")
+                    .constraint(FieldConstraints::Precision {
+                        precision: 5,
+                        scale: 2,
+                    })
+                    .build(),
+            )
+            .build();
+
+        let row = |amount: DataValue| HashMap::from([("amount".to_string(), amount)]);
+        let dataset = DataSet::from_rows(vec![
+            row(DataValue::Decimal {
+                value: 99999,
+                scale: 2,
+            }),
+            // Trailing zeros do not count: 12.3400 fits decimal(5, 2)
+            row(DataValue::Decimal {
+                value: 123400,
+                scale: 4,
+            }),
+            row(DataValue::Int(-999)),
+            row(DataValue::Float(0.05)),
+            row(DataValue::Decimal {
+                value: 12345,
+                scale: 3,
+            }),
+            row(DataValue::Int(1000)),
+            row(DataValue::Float(0.125)),
+            row(DataValue::String("1.5".to_string())),
+        ]);
+
+        let mut validator = ConstraintValidator::new();
+        let errors = validator.validate(&contract, &dataset);
+        let messages: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Constraint violation for field 'amount': Value 12.345 does not fit decimal(5, 2)",
+                "Constraint violation for field 'amount': Value 1000 does not fit decimal(5, 2)",
+                "Constraint violation for field 'amount': Value 0.125 does not fit decimal(5, 2)",
+                "Constraint violation for field 'amount': Precision constraint requires \
+                 numeric type, found string",
+            ]
+        );
+        assert!(errors[..3].iter().all(|e| e.error_code().code == "DCE0211"));
+    }
+
+    #[test]
+    fn test_time_range_constraint() {
+        let contract = ContractBuilder::new("test", "owner")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(
+                FieldBuilder::new("occurred_at", "timestamp")
+                    .constraint(FieldConstraints::TimeRange {
+                        min: Some("now-30d".to_string()),
+                        max: None,
+                        allow_future: false,
+                    })
+                    .build(),
+            )
+            .build();
+
+        let now = Utc::now();
+        let row = |value: DataValue| HashMap::from([("occurred_at".to_string(), value)]);
+        let at = |offset: chrono::Duration| DataValue::Timestamp((now + offset).to_rfc3339());
+        let dataset = DataSet::from_rows(vec![
+            row(at(chrono::Duration::days(-1))),
+            row(DataValue::Date(
+                (now - chrono::Duration::days(2)).date_naive(),
+            )),
+            row(DataValue::Timestamp("2020-01-01T00:00:00Z".to_string())),
+            row(at(chrono::Duration::hours(2))),
+            row(DataValue::String("yesterday".to_string())),
+            row(DataValue::Bool(true)),
+        ]);
+
+        let mut validator = ConstraintValidator::new();
+        let errors = validator.validate(&contract, &dataset);
+        let messages: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+        assert_eq!(messages.len(), 4);
+        assert_eq!(
+            messages[0],
+            "Constraint violation for field 'occurred_at': Timestamp \
+             2020-01-01T00:00:00+00:00 outside time range [now-30d, -]"
+        );
+        assert!(messages[1].ends_with("is in the future"));
+        assert_eq!(
+            messages[2..],
+            [
+                "Constraint violation for field 'occurred_at': Value 'yesterday' is not a \
+                 valid timestamp",
+                "Constraint violation for field 'occurred_at': TimeRange constraint requires \
+                 timestamp type, found boolean",
+            ]
+        );
+        assert!(errors[..2].iter().all(|e| e.error_code().code == "DCE0212"));
+    }
+
+    #[test]
+    fn test_distribution_constraints() {
+        let contract = ContractBuilder::new("test", "owner")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(
+                FieldBuilder::new("region", "string")
+                    .constraint(FieldConstraints::MaxNullFraction { fraction: 0.25 })
+                    .constraint(FieldConstraints::Cardinality {
+                        min_distinct: Some(3),
+                        max_distinct: None,
+                    })
+                    .build(),
+            )
+            .build();
+
+        let row = |region: Option<&str>| {
+            HashMap::from([(
+                "region".to_string(),
+                region.map_or(DataValue::Null, |r| DataValue::String(r.to_string())),
+            )])
+        };
+        let dataset =
+            DataSet::from_rows(vec![row(Some("EU")), row(Some("US")), row(None), row(None)]);
+
+        let mut validator = ConstraintValidator::new();
+        let errors = validator.validate(&contract, &dataset);
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].error_code().code, "DCE0209");
+        assert_eq!(
+            errors[0].to_string(),
+            "Constraint violation for field 'region': 2 of 4 value(s) are null \
+             (50.00%), above the max null fraction of 25.00%"
+        );
+        assert_eq!(errors[1].error_code().code, "DCE0210");
+
+        let dataset = DataSet::from_rows(vec![
+            row(Some("EU")),
+            row(Some("US")),
+            row(Some("APAC")),
+            row(None),
+        ]);
+        assert!(validator.validate(&contract, &dataset).is_empty());
+    }
 }
@@ -4,16 +4,20 @@
 //! - Freshness checks: Validates data staleness based on timestamps
 //! - Custom SQL checks: Syntax validation (execution deferred to Phase 2)
 
-use crate::{DataSet, ValidationError, datafusion_engine};
+use crate::{DataSet, DataValue, ValidationError, datafusion_engine};
 use arrow_array::Array;
-use chrono::{DateTime, Duration, Utc};
-use contracts_core::{Contract, CustomCheck, Field, FreshnessCheck};
+use chrono::{DateTime, Duration, NaiveTime, Utc};
+use contracts_core::{Contract, CustomCheck, Field, FreshnessCheck, FreshnessSource};
+use datafusion::arrow::json::{WriterBuilder, writer::JsonArray};
+use datafusion::arrow::record_batch::RecordBatch;
 use datafusion::prelude::*;
+use tracing::warn;
 
 #[cfg(test)]
 use chrono::Timelike;
 
 /// Validates custom checks and freshness requirements.
+#[derive(Clone)]
 pub struct CustomValidator;
 
 impl CustomValidator {
@@ -110,43 +114,8 @@ impl CustomValidator {
         check: &FreshnessCheck,
         dataset: &DataSet,
     ) -> Result<(), ValidationError> {
-        let max_delay = parse_duration(&check.max_delay)?;
-        let now = Utc::now();
-
-        // Find the most recent timestamp in the metric field
-        let mut most_recent: Option<DateTime<Utc>> = None;
-
-        for row in dataset.rows() {
-            if let Some(value) = row.get(&check.metric)
-                && let Some(ts_str) = value.as_timestamp()
-            {
-                match parse_timestamp(ts_str) {
-                    Ok(ts) => {
-                        if most_recent.is_none() || ts > most_recent.unwrap() {
-                            most_recent = Some(ts);
-                        }
-                    }
-                    Err(_) => continue, // Skip invalid timestamps
-                }
-            }
-        }
-
-        let most_recent = most_recent.ok_or_else(|| {
-            ValidationError::quality_check(format!(
-                "Freshness check failed: no valid timestamps found in field '{}'",
-                check.metric
-            ))
-        })?;
-
-        let age = now.signed_duration_since(most_recent);
-
-        if age > max_delay {
-            return Err(ValidationError::StaleData {
-                delay: format_duration(age),
-            });
-        }
-
-        Ok(())
+        require_column_source(check)?;
+        check_freshness(check, latest_timestamp(dataset.column(&check.metric)))
     }
 
     /// Validates custom SQL checks (syntax only, no execution).
@@ -193,8 +162,12 @@ impl CustomValidator {
             Some(f) => f,
             None => return errors,
         };
+        if let Err(e) = require_column_source(freshness) {
+            errors.push(e);
+            return errors;
+        }
 
-        let max_delay = match parse_duration(&freshness.max_delay) {
+        let max_delay = match tolerated_delay(freshness) {
             Ok(d) => d,
             Err(e) => {
                 errors.push(e);
@@ -463,11 +436,55 @@ impl CustomValidator {
             }
         }
 
-        // Non-empty result set = failure
-        Ok(Some(ValidationError::custom_check(
-            &check.name,
-            format!("returned {total_rows} row(s) (expected empty result)"),
-        )))
+        // Non-empty result set = failure, with the first rows attached if asked
+        let rows = match check.capture_rows {
+            Some(limit) if limit > 0 => capture_rows(&check.name, &batches, limit),
+            _ => Vec::new(),
+        };
+        Ok(Some(ValidationError::CustomCheckFailed {
+            name: check.name.clone(),
+            message: format!("returned {total_rows} row(s) (expected empty result)"),
+            rows,
+        }))
+    }
+}
+
+/// Returns the first `limit` rows of `batches` as JSON objects, with nulls
+/// written out so every row lists every column.
+///
+/// Rows that cannot be rendered are logged and left out: the check has
+/// failed either way.
+fn capture_rows(
+    check: &str,
+    batches: &[RecordBatch],
+    limit: usize,
+) -> Vec<serde_json::Map<String, serde_json::Value>> {
+    let mut remaining = limit;
+    let batches: Vec<RecordBatch> = batches
+        .iter()
+        .filter(|batch| batch.num_rows() > 0)
+        .map_while(|batch| {
+            let rows = batch.num_rows().min(remaining);
+            remaining -= rows;
+            (rows > 0).then(|| batch.slice(0, rows))
+        })
+        .collect();
+
+    let mut writer = WriterBuilder::new()
+        .with_explicit_nulls(true)
+        .build::<_, JsonArray>(Vec::new());
+    let written = writer
+        .write_batches(&batches.iter().collect::<Vec<_>>())
+        .and_then(|()| writer.finish());
+    let rows = written
+        .map_err(|e| e.to_string())
+        .and_then(|()| serde_json::from_slice(&writer.into_inner()).map_err(|e| e.to_string()));
+    match rows {
+        Ok(rows) => rows,
+        Err(e) => {
+            warn!("Failed to capture rows of custom check '{}': {}", check, e);
+            Vec::new()
+        }
     }
 }
 
@@ -477,8 +494,81 @@ impl Default for CustomValidator {
     }
 }
 
+/// Returns the most recent timestamp or date among `values`, skipping values
+/// that are neither or cannot be parsed.
+pub(crate) fn latest_timestamp<'a>(
+    values: impl IntoIterator<Item = &'a DataValue>,
+) -> Option<DateTime<Utc>> {
+    values
+        .into_iter()
+        .filter_map(|value| match value {
+            DataValue::Timestamp(ts_str) => parse_timestamp(ts_str).ok(),
+            // A date counts as fresh from the start of that day
+            DataValue::Date(date) => Some(date.and_time(NaiveTime::MIN).and_utc()),
+            _ => None,
+        })
+        .max()
+}
+
+/// Checks the most recent timestamp of a freshness metric against the
+/// check's maximum delay.
+pub(crate) fn check_freshness(
+    check: &FreshnessCheck,
+    most_recent: Option<DateTime<Utc>>,
+) -> Result<(), ValidationError> {
+    let max_delay = tolerated_delay(check)?;
+    let now = Utc::now();
+
+    let most_recent = most_recent.ok_or_else(|| {
+        ValidationError::quality_check(match check.source() {
+            FreshnessSource::Column => format!(
+                "Freshness check failed: no valid timestamps found in field '{}'",
+                check.metric
+            ),
+            FreshnessSource::SnapshotCommittedAt => {
+                "Freshness check failed: the table has no snapshot".to_string()
+            }
+            FreshnessSource::Partition => format!(
+                "Freshness check failed: no partition values found for field '{}'",
+                check.metric
+            ),
+        })
+    })?;
+
+    let age = now.signed_duration_since(most_recent);
+
+    if age > max_delay {
+        return Err(ValidationError::StaleData {
+            delay: format_duration(age),
+        });
+    }
+
+    Ok(())
+}
+
+/// Returns the age a freshness check tolerates: its `max_delay`, plus the
+/// `allowed_lateness` window for records still on their way.
+pub(crate) fn tolerated_delay(check: &FreshnessCheck) -> Result<Duration, ValidationError> {
+    let max_delay = parse_duration(&check.max_delay)?;
+    match &check.allowed_lateness {
+        Some(lateness) => Ok(max_delay + parse_duration(lateness)?),
+        None => Ok(max_delay),
+    }
+}
+
+/// Fails a freshness check read from table metadata, which rows do not carry.
+pub(crate) fn require_column_source(check: &FreshnessCheck) -> Result<(), ValidationError> {
+    match check.source() {
+        FreshnessSource::Column => Ok(()),
+        source => Err(ValidationError::quality_check(format!(
+            "Freshness source '{}' is only available for Iceberg tables",
+            source.as_str()
+        ))),
+    }
+}
+
 /// Parses a duration string like "1h", "30m", "1d".
-fn parse_duration(duration_str: &str) -> Result<Duration, ValidationError> {
+pub(crate) fn parse_duration(duration_str: &str) -> Result<Duration, ValidationError> {
     let duration_str = duration_str.trim();
 
     if duration_str.is_empty() {
@@ -520,6 +610,30 @@ fn parse_duration(duration_str: &str) -> Result<Duration, ValidationError> {
     Ok(duration)
 }
 
+/// Parses a bound of a `TimeRange` constraint: `now`, `now` shifted by a
+/// duration (`now-30d`, `now+1h`) or an absolute timestamp.
+pub(crate) fn parse_time_bound(
+    bound: &str,
+    now: DateTime<Utc>,
+) -> Result<DateTime<Utc>, ValidationError> {
+    let Some(offset) = bound.trim().strip_prefix("now") else {
+        return parse_timestamp(bound);
+    };
+    let offset = offset.trim();
+    if offset.is_empty() {
+        return Ok(now);
+    }
+    if let Some(duration) = offset.strip_prefix('-') {
+        Ok(now - parse_duration(duration)?)
+    } else if let Some(duration) = offset.strip_prefix('+') {
+        Ok(now + parse_duration(duration)?)
+    } else {
+        Err(ValidationError::InvalidDuration(
+            "Expected now, now-<duration>, now+<duration> or a timestamp".to_string(),
+        ))
+    }
+}
+
 /// Parses a timestamp string in multiple formats.
 ///
 /// Supports:
@@ -636,14 +750,14 @@ mod tests {
                     .build(),
             )
             .quality_checks(QualityChecks {
-                completeness: None,
-                uniqueness: None,
                 freshness: Some(FreshnessCheck {
                     max_delay: "1h".to_string(),
                     metric: "timestamp".to_string(),
+                    source: None,
+                    allowed_lateness: None,
+                    severity: None,
                 }),
-                custom_checks: None,
-                ml_checks: None,
+                ..Default::default()
             })
             .build();
 
@@ -675,14 +789,14 @@ mod tests {
                     .build(),
             )
             .quality_checks(QualityChecks {
-                completeness: None,
-                uniqueness: None,
                 freshness: Some(FreshnessCheck {
                     max_delay: "1h".to_string(),
                     metric: "timestamp".to_string(),
+                    source: None,
+                    allowed_lateness: None,
+                    severity: None,
                 }),
-                custom_checks: None,
-                ml_checks: None,
+                ..Default::default()
             })
             .build();
 
@@ -711,15 +825,13 @@ mod tests {
             .format(DataFormat::Iceberg)
             .field(FieldBuilder::new("id", "string").nullable(false).build())
             .quality_checks(QualityChecks {
-                completeness: None,
-                uniqueness: None,
-                freshness: None,
                 custom_checks: Some(vec![CustomCheck {
                     name: "test_check".to_string(),
                     definition: "SELECT COUNT(*) FROM table".to_string(),
                     severity: Some("error".to_string()),
+                    capture_rows: None,
                 }]),
-                ml_checks: None,
+                ..Default::default()
             })
             .build();
 
@@ -740,15 +852,13 @@ mod tests {
             .format(DataFormat::Iceberg)
             .field(FieldBuilder::new("id", "string").nullable(false).build())
             .quality_checks(QualityChecks {
-                completeness: None,
-                uniqueness: None,
-                freshness: None,
                 custom_checks: Some(vec![CustomCheck {
                     name: "empty_check".to_string(),
                     definition: "".to_string(),
                     severity: Some("error".to_string()),
+                    capture_rows: None,
                 }]),
-                ml_checks: None,
+                ..Default::default()
             })
             .build();
 
@@ -841,6 +951,26 @@ mod tests {
         assert!(parse_timestamp(&ts_str).is_ok());
     }
 
+    #[test]
+    fn test_parse_time_bound() {
+        let now = parse_timestamp("2024-01-31T12:00:00Z").unwrap();
+        assert_eq!(parse_time_bound("now", now).unwrap(), now);
+        assert_eq!(
+            parse_time_bound("now-30d", now).unwrap(),
+            parse_timestamp("2024-01-01T12:00:00Z").unwrap()
+        );
+        assert_eq!(
+            parse_time_bound(" now + 1h ", now).unwrap(),
+            parse_timestamp("2024-01-31T13:00:00Z").unwrap()
+        );
+        assert_eq!(
+            parse_time_bound("2020-01-01", now).unwrap(),
+            parse_timestamp("2020-01-01T00:00:00Z").unwrap()
+        );
+        assert!(parse_time_bound("now*2", now).is_err());
+        assert!(parse_time_bound("now-1y", now).is_err());
+    }
+
     #[test]
     fn test_freshness_with_unix_epoch() {
         let contract = ContractBuilder::new("test", "owner")
@@ -852,14 +982,14 @@ mod tests {
                     .build(),
             )
             .quality_checks(QualityChecks {
-                completeness: None,
-                uniqueness: None,
                 freshness: Some(FreshnessCheck {
                     max_delay: "1h".to_string(),
                     metric: "timestamp".to_string(),
+                    source: None,
+                    allowed_lateness: None,
+                    severity: None,
                 }),
-                custom_checks: None,
-                ml_checks: None,
+                ..Default::default()
             })
             .build();
 
@@ -892,14 +1022,14 @@ mod tests {
                     .build(),
             )
             .quality_checks(QualityChecks {
-                completeness: None,
-                uniqueness: None,
                 freshness: Some(FreshnessCheck {
                     max_delay: "7d".to_string(), // 7 days
                     metric: "date".to_string(),
+                    source: None,
+                    allowed_lateness: None,
+                    severity: None,
                 }),
-                custom_checks: None,
-                ml_checks: None,
+                ..Default::default()
             })
             .build();
 
@@ -915,4 +1045,54 @@ mod tests {
         let errors = validator.validate(&contract, &dataset);
         assert_eq!(errors.len(), 0);
     }
+
+    #[test]
+    fn test_freshness_with_date_values() {
+        let contract = |max_delay: &str| {
+            ContractBuilder::new("test", "owner")
+                .location("s3://test")
+                .format(DataFormat::Iceberg)
+                .field(FieldBuilder::new("day", "date").nullable(false).build())
+                .quality_checks(QualityChecks {
+                    freshness: Some(FreshnessCheck {
+                        max_delay: max_delay.to_string(),
+                        metric: "day".to_string(),
+                        source: None,
+                        allowed_lateness: None,
+                        severity: None,
+                    }),
+                    ..Default::default()
+                })
+                .build()
+        };
+
+        let week_ago = (Utc::now() - Duration::days(7)).date_naive();
+        let mut row = HashMap::new();
+        row.insert("day".to_string(), DataValue::Date(week_ago));
+        let dataset = DataSet::from_rows(vec![row]);
+        let validator = CustomValidator::new();
+
+        assert!(validator.validate(&contract("10d"), &dataset).is_empty());
+        assert_eq!(validator.validate(&contract("3d"), &dataset).len(), 1);
+    }
+
+    #[test]
+    fn test_freshness_tolerates_allowed_lateness() {
+        let check = |allowed_lateness: Option<&str>| FreshnessCheck {
+            max_delay: "1h".to_string(),
+            metric: "ts".to_string(),
+            source: None,
+            allowed_lateness: allowed_lateness.map(String::from),
+            severity: None,
+        };
+        let latest = Some(Utc::now() - Duration::minutes(80));
+
+        assert!(check_freshness(&check(None), latest).is_err());
+        assert!(check_freshness(&check(Some("30m")), latest).is_ok());
+        assert!(check_freshness(&check(Some("10m")), latest).is_err());
+        assert!(matches!(
+            check_freshness(&check(Some("later")), latest),
+            Err(ValidationError::InvalidDuration(_))
+        ));
+    }
 }
@@ -4,15 +4,29 @@
 //! DataFusion against an in-memory Arrow table.  This provides vectorized,
 //! batch-level validation instead of row-by-row iteration.
 
-use crate::{DataSet, DataValue};
+use crate::constraints::{
+    TimeBounds, cardinality_message, describe_condition, describe_length_bounds,
+    describe_time_bounds, null_fraction_message,
+};
+use crate::findings::Findings;
+use crate::quality::{
+    APPROXIMATE_MARGIN, ScopeGroups, approximate_uniqueness_error, row_check_error,
+    uniqueness_error,
+};
+use crate::row_filter::{Condition, Expression, RowRule};
+use crate::soft_delete;
+use crate::volume::{BucketWindow, VolumeCounter};
+use crate::{DataSet, DataValue, ValidationError};
 use arrow_array::Array;
 use arrow_array::RecordBatch;
 use arrow_array::builder::*;
 use arrow_schema::{DataType as ArrowDataType, Field as ArrowField, Schema as ArrowSchema};
+use contracts_core::{CheckKind, ErrorCode, codes};
 use contracts_core::{
     ClassBalanceCheck, CompletenessCheck, Contract, DataType, FeatureDriftCheck, Field,
-    FieldConstraints, MlChecks, NullRateByGroupCheck, PrimitiveType, QualityChecks,
-    TargetLeakageCheck, UniquenessCheck, ValidationContext, ValidationReport, ValidationStats,
+    FieldConstraints, MlChecks, NullRateByGroupCheck, PrimitiveType, QualityChecks, RowCheck,
+    Severity, TargetLeakageCheck, UniquenessCheck, ValidationContext, ValidationReport,
+    ValidationStats, Violation, VolumeCheck,
 };
 use datafusion::prelude::*;
 use std::sync::Arc;
@@ -39,72 +53,77 @@ impl DataFusionEngine {
         context: &ValidationContext,
     ) -> ValidationReport {
         let start = Instant::now();
-        let mut errors: Vec<String> = Vec::new();
-        let mut warnings: Vec<String> = Vec::new();
+        let mut findings = Findings::new(context);
 
         if dataset.is_empty() {
-            return self.build_report(errors, warnings, contract, dataset, start);
+            return self.build_report(findings, contract, dataset, start);
         }
 
         // Build Arrow RecordBatch from dataset
         let batch = match dataset_to_record_batch(&contract.schema.fields, dataset) {
             Ok(b) => b,
             Err(e) => {
-                errors.push(format!("Failed to create Arrow batch: {e}"));
-                return self.build_report(errors, warnings, contract, dataset, start);
+                findings.execution_error(
+                    &codes::GENERAL,
+                    format!("Failed to create Arrow batch: {e}"),
+                );
+                return self.build_report(findings, contract, dataset, start);
             }
         };
 
         // Create DataFusion context and register the table
         let ctx = SessionContext::new();
         if let Err(e) = ctx.register_batch("data", batch) {
-            errors.push(format!("Failed to register table: {e}"));
-            return self.build_report(errors, warnings, contract, dataset, start);
+            findings.execution_error(&codes::GENERAL, format!("Failed to register table: {e}"));
+            return self.build_report(findings, contract, dataset, start);
         }
 
         // --- 0. Schema presence checks ---
         let presence_errs = self.check_schema_presence(contract, &ctx).await;
-        errors.extend(presence_errs);
+        findings.extend(Severity::Error, presence_errs);
 
         // --- 1. Schema / nullability checks ---
         let null_errs = self.check_nullability(contract, &ctx).await;
-        errors.extend(null_errs);
+        findings.extend(Severity::Error, null_errs);
 
-        if context.strict && !errors.is_empty() {
-            return self.build_report(errors, warnings, contract, dataset, start);
+        if (context.strict && !findings.errors.is_empty()) || findings.fails_fast(context) {
+            return self.build_report(findings, contract, dataset, start);
         }
 
         // --- 2. Field constraints ---
-        let constraint_errs = self.check_constraints(contract, &ctx).await;
-        errors.extend(constraint_errs);
+        for violation in self.check_constraints(contract, &ctx).await {
+            findings.push(violation);
+        }
 
-        if context.schema_only {
-            return self.build_report(errors, warnings, contract, dataset, start);
+        if context.schema_only || findings.fails_fast(context) {
+            return self.build_report(findings, contract, dataset, start);
         }
 
         // --- 3. Quality checks ---
+        let default = Severity::default_for(context.strict);
         if let Some(ref qc) = contract.quality_checks {
-            let qc_errs = self.check_quality(qc, &ctx).await;
-            if context.strict {
-                errors.extend(qc_errs);
-            } else {
-                warnings.extend(qc_errs);
+            let live = soft_delete::live_predicate(contract);
+            for (severity, violations) in self
+                .check_quality(contract, qc, live.as_deref(), &ctx)
+                .await
+            {
+                findings.extend(severity.unwrap_or(default), violations);
             }
         }
 
         // --- 4. ML checks (SQL-based) ---
-        if let Some(ref qc) = contract.quality_checks
+        if !findings.fails_fast(context)
+            && let Some(ref qc) = contract.quality_checks
             && let Some(ref ml) = qc.ml_checks
         {
             let ml_errs = self.check_ml(ml, &ctx).await;
-            if context.strict {
-                errors.extend(ml_errs);
-            } else {
-                warnings.extend(ml_errs);
-            }
+            findings.extend(
+                default,
+                violations(&codes::QUALITY_CHECK_FAILED, CheckKind::Ml, ml_errs),
+            );
         }
 
-        self.build_report(errors, warnings, contract, dataset, start)
+        self.build_report(findings, contract, dataset, start)
     }
 
     /// Validate against a `SessionContext` that already has a `"data"` table registered.
@@ -118,56 +137,57 @@ impl DataFusionEngine {
         context: &ValidationContext,
     ) -> ValidationReport {
         let start = Instant::now();
-        let mut errors: Vec<String> = Vec::new();
-        let mut warnings: Vec<String> = Vec::new();
+        let mut findings = Findings::new(context);
 
         // --- 0. Schema presence checks ---
         let presence_errs = self.check_schema_presence(contract, ctx).await;
-        errors.extend(presence_errs);
+        findings.extend(Severity::Error, presence_errs);
 
         // --- 1. Schema / nullability checks ---
         let null_errs = self.check_nullability(contract, ctx).await;
-        errors.extend(null_errs);
+        findings.extend(Severity::Error, null_errs);
 
-        if context.strict && !errors.is_empty() {
+        if (context.strict && !findings.errors.is_empty()) || findings.fails_fast(context) {
             return self
-                .build_report_from_context(errors, warnings, contract, ctx, start)
+                .build_report_from_context(findings, contract, ctx, start)
                 .await;
         }
 
         // --- 2. Field constraints ---
-        let constraint_errs = self.check_constraints(contract, ctx).await;
-        errors.extend(constraint_errs);
+        for violation in self.check_constraints(contract, ctx).await {
+            findings.push(violation);
+        }
 
-        if context.schema_only {
+        if context.schema_only || findings.fails_fast(context) {
             return self
-                .build_report_from_context(errors, warnings, contract, ctx, start)
+                .build_report_from_context(findings, contract, ctx, start)
                 .await;
         }
 
         // --- 3. Quality checks ---
+        let default = Severity::default_for(context.strict);
         if let Some(ref qc) = contract.quality_checks {
-            let qc_errs = self.check_quality(qc, ctx).await;
-            if context.strict {
-                errors.extend(qc_errs);
-            } else {
-                warnings.extend(qc_errs);
+            let live = soft_delete::live_predicate(contract);
+            for (severity, violations) in
+                self.check_quality(contract, qc, live.as_deref(), ctx).await
+            {
+                findings.extend(severity.unwrap_or(default), violations);
             }
         }
 
         // --- 4. ML checks (SQL-based) ---
-        if let Some(ref qc) = contract.quality_checks
+        if !findings.fails_fast(context)
+            && let Some(ref qc) = contract.quality_checks
             && let Some(ref ml) = qc.ml_checks
         {
             let ml_errs = self.check_ml(ml, ctx).await;
-            if context.strict {
-                errors.extend(ml_errs);
-            } else {
-                warnings.extend(ml_errs);
-            }
+            findings.extend(
+                default,
+                violations(&codes::QUALITY_CHECK_FAILED, CheckKind::Ml, ml_errs),
+            );
         }
 
-        self.build_report_from_context(errors, warnings, contract, ctx, start)
+        self.build_report_from_context(findings, contract, ctx, start)
             .await
     }
 
@@ -176,18 +196,18 @@ impl DataFusionEngine {
     /// Obtains the row count via `SELECT COUNT(*) FROM data` instead of `dataset.len()`.
     async fn build_report_from_context(
         &self,
-        errors: Vec<String>,
-        warnings: Vec<String>,
+        mut findings: Findings,
         contract: &Contract,
         ctx: &SessionContext,
         start: Instant,
     ) -> ValidationReport {
-        let mut errors = errors;
-
         let records_validated = match count_query(ctx, "SELECT COUNT(*) AS cnt FROM data").await {
             Ok(count) => count as usize,
             Err(e) => {
-                errors.push(format!("Failed to count validated records: {e}"));
+                findings.execution_error(
+                    &codes::GENERAL,
+                    format!("Failed to count validated records: {e}"),
+                );
                 0
             }
         };
@@ -213,9 +233,15 @@ impl DataFusionEngine {
                 if qc.freshness.is_some() {
                     n += 1;
                 }
+                if qc.volume.is_some() {
+                    n += 1;
+                }
                 if let Some(ref c) = qc.custom_checks {
                     n += c.len();
                 }
+                if let Some(ref r) = qc.row_checks {
+                    n += r.len();
+                }
                 // Only count SQL-executed ML checks. Row-only checks
                 // (no_overlap, temporal_split) are skipped in the native
                 // context path and should not inflate constraints_evaluated.
@@ -237,16 +263,27 @@ impl DataFusionEngine {
             })
             .unwrap_or(0);
 
+        let truncated_violations = findings.truncated();
         ValidationReport {
-            passed: errors.is_empty(),
-            errors,
-            warnings,
+            passed: findings.errors.is_empty(),
+            errors: findings.errors,
+            warnings: findings.warnings,
+            inconclusive: Vec::new(),
+            info: findings.info,
             stats: ValidationStats {
                 records_validated,
                 fields_checked: contract.schema.fields.len(),
                 constraints_evaluated: constraints_evaluated + quality_checks_count,
                 duration_ms: start.elapsed().as_millis() as u64,
+                truncated_violations,
+                ..Default::default()
             },
+            provenance: None,
+            conformance_level: contract.conformance_level,
+            snapshot_id: None,
+            base_snapshot_id: None,
+            segments: Vec::new(),
+            datasets: Vec::new(),
         }
     }
 
@@ -254,12 +291,12 @@ impl DataFusionEngine {
     // Nullability
     // -----------------------------------------------------------------------
 
-    /// Check that every field declared in the contract exists in the data table.
+    /// Check that every required field of the contract exists in the data table.
     async fn check_schema_presence(
         &self,
         contract: &Contract,
         ctx: &SessionContext,
-    ) -> Vec<String> {
+    ) -> Vec<Violation> {
         let mut errs = Vec::new();
 
         // Retrieve the table's column names from DataFusion.
@@ -274,21 +311,28 @@ impl DataFusionEngine {
                 Err(_) => return errs, // table not accessible, will be caught later
             };
 
-        for field in &contract.schema.fields {
+        for field in contract.required_fields() {
             if !table_columns.contains(&field.name) {
-                errs.push(format!(
-                    "Field '{}' is declared in the contract but missing from the data",
-                    field.name
-                ));
+                errs.push(
+                    Violation::new(
+                        &codes::MISSING_FIELD,
+                        CheckKind::Schema,
+                        format!(
+                            "Field '{}' is declared in the contract but missing from the data",
+                            field.name
+                        ),
+                    )
+                    .with_field(&field.name),
+                );
             }
         }
         errs
     }
 
-    async fn check_nullability(&self, contract: &Contract, ctx: &SessionContext) -> Vec<String> {
+    async fn check_nullability(&self, contract: &Contract, ctx: &SessionContext) -> Vec<Violation> {
         let mut errs = Vec::new();
         for field in &contract.schema.fields {
-            if field.nullable {
+            if field.allows_null() {
                 continue;
             }
             let sql = format!(
@@ -297,10 +341,17 @@ impl DataFusionEngine {
             );
             match count_query(ctx, &sql).await {
                 Ok(cnt) if cnt > 0 => {
-                    errs.push(format!(
-                        "Field '{}' is null but nullability is not allowed ({cnt} row(s))",
-                        field.name
-                    ));
+                    errs.push(
+                        Violation::new(
+                            &codes::NULL_CONSTRAINT_VIOLATION,
+                            CheckKind::Schema,
+                            format!(
+                                "Field '{}' is null but nullability is not allowed ({cnt} row(s))",
+                                field.name
+                            ),
+                        )
+                        .with_field(&field.name),
+                    );
                 }
                 Ok(_) => {}
                 Err(_) => {} // column may not exist; already reported by check_schema_presence
@@ -313,34 +364,456 @@ impl DataFusionEngine {
     // Constraints
     // -----------------------------------------------------------------------
 
-    async fn check_constraints(&self, contract: &Contract, ctx: &SessionContext) -> Vec<String> {
+    /// Checks every field constraint; violations carry the field's severity,
+    /// errors by default.
+    async fn check_constraints(&self, contract: &Contract, ctx: &SessionContext) -> Vec<Violation> {
         let mut errs = Vec::new();
         for field in &contract.schema.fields {
             let constraints = match &field.constraints {
                 Some(c) => c,
                 None => continue,
             };
+            let severity = field.severity.unwrap_or(Severity::Error);
             for c in constraints {
-                let field_errs = self.check_one_constraint(field, c, ctx).await;
-                errs.extend(field_errs);
+                let field_errs = self.check_one_constraint(contract, field, c, ctx).await;
+                errs.extend(field_errs.into_iter().map(|v| v.with_severity(severity)));
             }
         }
         errs
     }
 
     async fn check_one_constraint(
+        &self,
+        contract: &Contract,
+        field: &Field,
+        constraint: &FieldConstraints,
+        ctx: &SessionContext,
+    ) -> Vec<Violation> {
+        let (code, messages) = match constraint {
+            FieldConstraints::AllowedValues { values } => (
+                &codes::NOT_IN_ALLOWED_VALUES,
+                self.check_allowed_values(field, values, ctx).await,
+            ),
+            FieldConstraints::Range { min, max } => (
+                &codes::OUT_OF_RANGE,
+                self.check_range(field, *min, *max, ctx).await,
+            ),
+            FieldConstraints::Pattern { regex } => (
+                &codes::PATTERN_MISMATCH,
+                self.check_pattern(field, regex, ctx).await,
+            ),
+            FieldConstraints::Length { min, max } => (
+                &codes::INVALID_LENGTH,
+                self.check_length(
+                    field,
+                    format!("character_length(\"{}\")", field.name),
+                    " in characters",
+                    *min,
+                    *max,
+                    ctx,
+                )
+                .await,
+            ),
+            FieldConstraints::ByteLength { min, max } => (
+                &codes::INVALID_LENGTH,
+                self.check_length(
+                    field,
+                    format!("octet_length(\"{}\")", field.name),
+                    " in bytes",
+                    *min,
+                    *max,
+                    ctx,
+                )
+                .await,
+            ),
+            // array_length is NULL rather than 0 for an empty list
+            FieldConstraints::ListLength { min, max } => (
+                &codes::INVALID_LENGTH,
+                self.check_length(
+                    field,
+                    format!("coalesce(array_length(\"{}\"), 0)", field.name),
+                    "",
+                    *min,
+                    *max,
+                    ctx,
+                )
+                .await,
+            ),
+            FieldConstraints::MapKeys { pattern, allowed } => (
+                &codes::INVALID_MAP_KEY,
+                self.check_map_keys(field, pattern.as_deref(), allowed.as_deref(), ctx)
+                    .await,
+            ),
+            FieldConstraints::NonEmpty => (
+                &codes::INVALID_LENGTH,
+                self.check_non_empty(field, ctx).await,
+            ),
+            FieldConstraints::Conditional { when, require } => {
+                match Condition::parse(field, when, require, contract) {
+                    Ok(condition) => (
+                        &codes::CONDITION_VIOLATION,
+                        self.check_condition(field, when, require, &condition, ctx)
+                            .await,
+                    ),
+                    Err(e) => return vec![e.to_violation()],
+                }
+            }
+            FieldConstraints::Custom { definition } => {
+                match Expression::parse(field, definition, contract) {
+                    Ok(expression) => (
+                        &codes::CONSTRAINT_VIOLATION,
+                        self.check_expression(field, constraint, &expression, ctx)
+                            .await,
+                    ),
+                    Err(e) => return vec![e.to_violation()],
+                }
+            }
+            FieldConstraints::MaxNullFraction { fraction } => (
+                &codes::NULL_FRACTION,
+                self.check_null_fraction(field, *fraction, ctx).await,
+            ),
+            FieldConstraints::Cardinality {
+                min_distinct,
+                max_distinct,
+            } => (
+                &codes::CARDINALITY,
+                self.check_cardinality(field, *min_distinct, *max_distinct, ctx)
+                    .await,
+            ),
+            FieldConstraints::Precision { precision, scale } => (
+                &codes::INVALID_PRECISION,
+                self.check_precision(field, *precision, *scale, ctx).await,
+            ),
+            FieldConstraints::TimeRange {
+                min,
+                max,
+                allow_future,
+            } => (
+                &codes::OUT_OF_TIME_RANGE,
+                self.check_time_range(field, min.as_deref(), max.as_deref(), *allow_future, ctx)
+                    .await,
+            ),
+            // Null counts are reported by check_nullability
+            FieldConstraints::NotNull => return Vec::new(),
+        };
+        violations(code, CheckKind::Constraint, messages)
+            .into_iter()
+            .map(|v| v.with_field(&field.name))
+            .collect()
+    }
+
+    async fn check_condition(
+        &self,
+        field: &Field,
+        when: &str,
+        require: &str,
+        condition: &Condition,
+        ctx: &SessionContext,
+    ) -> Vec<String> {
+        let sql = format!(
+            "SELECT COUNT(*) AS cnt FROM data WHERE {}",
+            condition.violation_predicate()
+        );
+        match count_query(ctx, &sql).await {
+            Ok(cnt) if cnt > 0 => vec![format!(
+                "Constraint violation for field '{}': {cnt} row(s) fail {}",
+                field.name,
+                describe_condition(when, require)
+            )],
+            _ => Vec::new(),
+        }
+    }
+
+    async fn check_null_fraction(
+        &self,
+        field: &Field,
+        fraction: f64,
+        ctx: &SessionContext,
+    ) -> Vec<String> {
+        let rows = count_query(ctx, "SELECT COUNT(*) AS cnt FROM data").await;
+        let nulls = count_query(
+            ctx,
+            &format!(
+                "SELECT COUNT(*) AS cnt FROM data WHERE \"{}\" IS NULL",
+                field.name
+            ),
+        )
+        .await;
+        match (rows, nulls) {
+            (Ok(rows), Ok(nulls)) if rows > 0 => {
+                null_fraction_message(nulls as usize, rows as usize, fraction)
+                    .map(|message| {
+                        format!("Constraint violation for field '{}': {message}", field.name)
+                    })
+                    .into_iter()
+                    .collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    async fn check_cardinality(
+        &self,
+        field: &Field,
+        min: Option<usize>,
+        max: Option<usize>,
+        ctx: &SessionContext,
+    ) -> Vec<String> {
+        let rows = count_query(ctx, "SELECT COUNT(*) AS cnt FROM data").await;
+        let distinct = count_query(
+            ctx,
+            &format!("SELECT COUNT(DISTINCT \"{}\") AS cnt FROM data", field.name),
+        )
+        .await;
+        match (rows, distinct) {
+            (Ok(rows), Ok(distinct)) if rows > 0 => {
+                cardinality_message(distinct as usize, true, min, max)
+                    .map(|message| {
+                        format!("Constraint violation for field '{}': {message}", field.name)
+                    })
+                    .into_iter()
+                    .collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    async fn check_expression(
         &self,
         field: &Field,
         constraint: &FieldConstraints,
+        expression: &Expression,
+        ctx: &SessionContext,
+    ) -> Vec<String> {
+        let sql = format!(
+            "SELECT COUNT(*) AS cnt FROM data WHERE {}",
+            expression.violation_predicate()
+        );
+        match count_query(ctx, &sql).await {
+            Ok(0) => Vec::new(),
+            Ok(cnt) => vec![format!(
+                "Constraint violation for field '{}': {cnt} row(s) fail {constraint}",
+                field.name
+            )],
+            // Such as a cast of text to a number, so no row passes for sure
+            Err(error) => vec![format!(
+                "Constraint violation for field '{}': rows fail {constraint}, which could not \
+                 be evaluated: {error}",
+                field.name
+            )],
+        }
+    }
+
+    /// Counts the non-null values whose `length`, a SQL expression over the
+    /// field, is outside the bounds; `unit` is appended to the message.
+    async fn check_length(
+        &self,
+        field: &Field,
+        length: String,
+        unit: &str,
+        min: Option<usize>,
+        max: Option<usize>,
+        ctx: &SessionContext,
+    ) -> Vec<String> {
+        let bounds: Vec<String> = min
+            .map(|min| format!("{length} < {min}"))
+            .into_iter()
+            .chain(max.map(|max| format!("{length} > {max}")))
+            .collect();
+        if bounds.is_empty() {
+            return Vec::new();
+        }
+        let sql = format!(
+            "SELECT COUNT(*) AS cnt FROM data WHERE \"{}\" IS NOT NULL AND ({})",
+            field.name,
+            bounds.join(" OR ")
+        );
+        match count_query(ctx, &sql).await {
+            Ok(cnt) if cnt > 0 => vec![format!(
+                "Constraint violation for field '{}': {cnt} row(s) outside length bounds {}{unit}",
+                field.name,
+                describe_length_bounds(min, max)
+            )],
+            _ => Vec::new(),
+        }
+    }
+
+    /// Counts the non-null values with more than `scale` fractional or
+    /// `precision - scale` integer digits.
+    ///
+    /// Floats are compared numerically; decimals and integers are matched on
+    /// their text, which keeps decimals exact.
+    async fn check_precision(
+        &self,
+        field: &Field,
+        precision: u8,
+        scale: u8,
+        ctx: &SessionContext,
+    ) -> Vec<String> {
+        let integer = precision.saturating_sub(scale);
+        let outside = if matches!(
+            field.field_type,
+            DataType::Primitive(PrimitiveType::Float32 | PrimitiveType::Float64)
+        ) {
+            format!(
+                "\"{0}\" <> round(\"{0}\", {scale}) OR abs(\"{0}\") >= power(10.0, {integer}) \
+                 OR isnan(\"{0}\")",
+                field.name
+            )
+        } else {
+            format!(
+                "regexp_match(CAST(\"{}\" AS VARCHAR), '^[+-]?0*[0-9]{{0,{integer}}}(\\.[0-9]{{0,{scale}}}0*)?$') IS NULL",
+                field.name
+            )
+        };
+        let sql = format!(
+            "SELECT COUNT(*) AS cnt FROM data WHERE \"{}\" IS NOT NULL AND ({outside})",
+            field.name
+        );
+        match count_query(ctx, &sql).await {
+            Ok(cnt) if cnt > 0 => vec![format!(
+                "Constraint violation for field '{}': {cnt} row(s) with values that do not fit decimal({precision}, {scale})",
+                field.name
+            )],
+            _ => Vec::new(),
+        }
+    }
+
+    /// Counts the non-null values outside the bounds of a `TimeRange`
+    /// constraint and, unless `allow_future`, the values later than now.
+    ///
+    /// Values are compared as whole seconds since the epoch.
+    async fn check_time_range(
+        &self,
+        field: &Field,
+        min: Option<&str>,
+        max: Option<&str>,
+        allow_future: bool,
+        ctx: &SessionContext,
+    ) -> Vec<String> {
+        let bounds = match TimeBounds::resolve(min, max, allow_future, chrono::Utc::now()) {
+            Ok(bounds) => bounds,
+            Err(message) => {
+                return vec![format!(
+                    "Constraint violation for field '{}': {message}",
+                    field.name
+                )];
+            }
+        };
+        let seconds = format!("to_unixtime(\"{}\")", field.name);
+        let mut errs = Vec::new();
+
+        let outside: Vec<String> = [
+            bounds
+                .min
+                .map(|min| format!("{seconds} < {}", min.timestamp())),
+            bounds
+                .max
+                .map(|max| format!("{seconds} > {}", max.timestamp())),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if !outside.is_empty() {
+            let sql = format!(
+                "SELECT COUNT(*) AS cnt FROM data WHERE \"{}\" IS NOT NULL AND ({})",
+                field.name,
+                outside.join(" OR ")
+            );
+            if let Ok(cnt) = count_query(ctx, &sql).await
+                && cnt > 0
+            {
+                errs.push(format!(
+                    "Constraint violation for field '{}': {cnt} row(s) outside time range {}",
+                    field.name,
+                    describe_time_bounds(min, max)
+                ));
+            }
+        }
+
+        if let Some(now) = bounds.now {
+            let sql = format!(
+                "SELECT COUNT(*) AS cnt FROM data WHERE \"{}\" IS NOT NULL AND {seconds} > {}",
+                field.name,
+                now.timestamp()
+            );
+            if let Ok(cnt) = count_query(ctx, &sql).await
+                && cnt > 0
+            {
+                errs.push(format!(
+                    "Constraint violation for field '{}': {cnt} row(s) with timestamps in the future",
+                    field.name
+                ));
+            }
+        }
+
+        errs
+    }
+
+    async fn check_map_keys(
+        &self,
+        field: &Field,
+        pattern: Option<&str>,
+        allowed: Option<&[String]>,
         ctx: &SessionContext,
     ) -> Vec<String> {
-        match constraint {
-            FieldConstraints::AllowedValues { values } => {
-                self.check_allowed_values(field, values, ctx).await
+        let mut errs = Vec::new();
+
+        if let Some(allowed) = allowed {
+            let keys: String = allowed
+                .iter()
+                .map(|k| format!("'{}'", k.replace('\'', "''")))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let sql = format!(
+                "SELECT COUNT(*) AS cnt FROM data \
+                 WHERE \"{}\" IS NOT NULL AND NOT array_has_all(make_array({keys}), map_keys(\"{}\"))",
+                field.name, field.name
+            );
+            if let Ok(cnt) = count_query(ctx, &sql).await
+                && cnt > 0
+            {
+                errs.push(format!(
+                    "Constraint violation for field '{}': {cnt} row(s) with keys not in allowed keys [{}]",
+                    field.name,
+                    allowed.join(", ")
+                ));
+            }
+        }
+
+        if let Some(pattern) = pattern {
+            let escaped = pattern.replace('\'', "''");
+            let sql = format!(
+                "SELECT COUNT(*) AS cnt FROM \
+                 (SELECT unnest(map_keys(\"{}\")) AS k FROM data WHERE \"{}\" IS NOT NULL) \
+                 WHERE NOT regexp_like(k, '{escaped}')",
+                field.name, field.name
+            );
+            if let Ok(cnt) = count_query(ctx, &sql).await
+                && cnt > 0
+            {
+                errs.push(format!(
+                    "Constraint violation for field '{}': {cnt} key(s) do not match key pattern '{pattern}'",
+                    field.name
+                ));
             }
-            FieldConstraints::Range { min, max } => self.check_range(field, *min, *max, ctx).await,
-            FieldConstraints::Pattern { regex } => self.check_pattern(field, regex, ctx).await,
-            FieldConstraints::Custom { .. } => Vec::new(),
+        }
+
+        errs
+    }
+
+    async fn check_non_empty(&self, field: &Field, ctx: &SessionContext) -> Vec<String> {
+        // cardinality is NULL rather than 0 for an empty list or map
+        let sql = format!(
+            "SELECT COUNT(*) AS cnt FROM data WHERE \"{}\" IS NOT NULL AND coalesce(cardinality(\"{}\"), 0) = 0",
+            field.name, field.name
+        );
+        match count_query(ctx, &sql).await {
+            Ok(cnt) if cnt > 0 => vec![format!(
+                "Constraint violation for field '{}': {cnt} row(s) with an empty collection",
+                field.name
+            )],
+            _ => Vec::new(),
         }
     }
 
@@ -425,28 +898,154 @@ impl DataFusionEngine {
     // Quality checks
     // -----------------------------------------------------------------------
 
-    async fn check_quality(&self, qc: &QualityChecks, ctx: &SessionContext) -> Vec<String> {
+    /// Runs the completeness, uniqueness and row checks over the rows
+    /// matching the `live` predicate, grouping the failures of each with the
+    /// severity it declares.
+    async fn check_quality(
+        &self,
+        contract: &Contract,
+        qc: &QualityChecks,
+        live: Option<&str>,
+        ctx: &SessionContext,
+    ) -> Vec<(Option<Severity>, Vec<Violation>)> {
+        let filter = live.map(|p| format!(" WHERE {p}")).unwrap_or_default();
         let mut errs = Vec::new();
         if let Some(ref comp) = qc.completeness {
-            errs.extend(self.check_completeness(comp, ctx).await);
+            errs.push((
+                comp.severity,
+                self.check_completeness(comp, &filter, ctx).await,
+            ));
         }
         if let Some(ref uniq) = qc.uniqueness {
-            errs.extend(self.check_uniqueness(uniq, ctx).await);
+            errs.push((
+                uniq.severity,
+                self.check_uniqueness(uniq, contract, &filter, ctx).await,
+            ));
+        }
+        for check in qc.row_checks.iter().flatten() {
+            match RowRule::parse(check, contract) {
+                Ok(rule) => errs.push((
+                    check.severity,
+                    self.check_row(check, &rule, live, ctx).await,
+                )),
+                Err(e) => errs.push((Some(Severity::Error), vec![e.to_violation()])),
+            }
+        }
+        if let Some(ref volume) = qc.volume {
+            errs.push((volume.severity, self.check_volume(volume, live, ctx).await));
         }
         errs
     }
 
+    /// Counts the rows matching `live` in each event-time bucket of a volume
+    /// check, leaving out records that arrived after the lateness window.
+    async fn check_volume(
+        &self,
+        check: &VolumeCheck,
+        live: Option<&str>,
+        ctx: &SessionContext,
+    ) -> Vec<Violation> {
+        let window = match BucketWindow::parse(check) {
+            Ok(window) => window,
+            Err(e) => return vec![e.to_violation()],
+        };
+        let epoch = |column: &str| format!("to_unixtime(TRY_CAST(\"{column}\" AS TIMESTAMP))");
+        let arrival = check
+            .arrival_metric
+            .as_deref()
+            .map_or_else(|| "CAST(NULL AS BIGINT)".to_string(), epoch);
+        let live = live.map(|p| format!(" WHERE {p}")).unwrap_or_default();
+        let (width, deadline) = (window.width, window.width + window.lateness);
+        let sql = format!(
+            "SELECT bucket, \
+                    SUM(CASE WHEN arrival IS NULL OR arrival <= bucket + {deadline} \
+                        THEN 1 ELSE 0 END) AS cnt \
+             FROM ( \
+                 SELECT event - ((event % {width}) + {width}) % {width} AS bucket, arrival \
+                 FROM (SELECT {} AS event, {arrival} AS arrival FROM data{live}) \
+                 WHERE event IS NOT NULL \
+             ) \
+             GROUP BY bucket",
+            epoch(&check.metric)
+        );
+
+        let batches = match ctx.sql(&sql).await {
+            Ok(df) => df.collect().await,
+            Err(e) => Err(e),
+        };
+        let batches = match batches {
+            Ok(batches) => batches,
+            Err(e) => {
+                return vec![Violation::new(
+                    &codes::GENERAL,
+                    CheckKind::Execution,
+                    format!("Failed to run volume check on '{}': {e}", check.metric),
+                )];
+            }
+        };
+
+        let mut counter = VolumeCounter::new(check);
+        for batch in &batches {
+            let buckets = batch
+                .column(0)
+                .as_any()
+                .downcast_ref::<arrow_array::Int64Array>();
+            let counts = batch
+                .column(1)
+                .as_any()
+                .downcast_ref::<arrow_array::Int64Array>();
+            let (Some(buckets), Some(counts)) = (buckets, counts) else {
+                continue;
+            };
+            for i in 0..batch.num_rows() {
+                counter.add(buckets.value(i), counts.value(i) as u64);
+            }
+        }
+        counter
+            .error(chrono::Utc::now())
+            .map(|e| e.to_violation())
+            .into_iter()
+            .collect()
+    }
+
+    /// Counts the rows matching `live` for which a row check is false.
+    async fn check_row(
+        &self,
+        check: &RowCheck,
+        rule: &RowRule,
+        live: Option<&str>,
+        ctx: &SessionContext,
+    ) -> Vec<Violation> {
+        let live = live.map(|p| format!("{p} AND ")).unwrap_or_default();
+        let sql = format!(
+            "SELECT COUNT(*) AS cnt FROM data WHERE {live}{}",
+            rule.violation_predicate()
+        );
+        match count_query(ctx, &sql).await {
+            Ok(cnt) => row_check_error(check, cnt as usize)
+                .map(|e| e.to_violation())
+                .into_iter()
+                .collect(),
+            Err(e) => vec![Violation::new(
+                &codes::GENERAL,
+                CheckKind::Execution,
+                format!("Failed to run row check '{}': {e}", check.name),
+            )],
+        }
+    }
+
     async fn check_completeness(
         &self,
         check: &CompletenessCheck,
+        filter: &str,
         ctx: &SessionContext,
-    ) -> Vec<String> {
+    ) -> Vec<Violation> {
         let mut errs = Vec::new();
         for field_name in &check.fields {
             let sql = format!(
                 "SELECT \
                      CAST(COUNT(\"{field_name}\") AS DOUBLE) / CAST(COUNT(*) AS DOUBLE) AS ratio \
-                 FROM data"
+                 FROM data{filter}"
             );
             if let Ok(batches) = ctx.sql(&sql).await
                 && let Ok(batches) = batches.collect().await
@@ -457,12 +1056,19 @@ impl DataFusionEngine {
                 if let Some(arr) = col.as_any().downcast_ref::<arrow_array::Float64Array>() {
                     let ratio = arr.value(0);
                     if ratio < check.threshold {
-                        errs.push(format!(
-                            "Quality check failed: Completeness check failed for field '{}': {:.2}% < {:.2}% (threshold)",
-                            field_name,
-                            ratio * 100.0,
-                            check.threshold * 100.0
-                        ));
+                        errs.push(
+                            Violation::new(
+                                &codes::QUALITY_CHECK_FAILED,
+                                CheckKind::Quality,
+                                format!(
+                                    "Quality check failed: Completeness check failed for field '{}': {:.2}% < {:.2}% (threshold)",
+                                    field_name,
+                                    ratio * 100.0,
+                                    check.threshold * 100.0
+                                ),
+                            )
+                            .with_field(field_name),
+                        );
                     }
                 }
             }
@@ -470,24 +1076,99 @@ impl DataFusionEngine {
         errs
     }
 
-    async fn check_uniqueness(&self, check: &UniquenessCheck, ctx: &SessionContext) -> Vec<String> {
+    /// Counts the rows repeating the key of a uniqueness check, within each
+    /// group of its scope. Rows with a null group value are left out.
+    async fn check_uniqueness(
+        &self,
+        check: &UniquenessCheck,
+        contract: &Contract,
+        filter: &str,
+        ctx: &SessionContext,
+    ) -> Vec<Violation> {
+        let groups = match ScopeGroups::resolve(check, contract) {
+            Ok(groups) => groups,
+            Err(message) => return vec![ValidationError::quality_check(message).to_violation()],
+        };
         let cols = check
             .fields
             .iter()
             .map(|f| format!("\"{}\"", f))
             .collect::<Vec<_>>()
             .join(", ");
-        let sql = format!("SELECT COUNT(*) - COUNT(DISTINCT ({cols})) AS dupes FROM data");
+        let (filter, group_by) = match groups {
+            None => (filter.to_string(), String::new()),
+            Some(groups) => {
+                let groups = groups.sql_groups();
+                let present = groups
+                    .iter()
+                    .map(|g| format!("{g} IS NOT NULL"))
+                    .collect::<Vec<_>>()
+                    .join(" AND ");
+                let filter = match filter.strip_prefix(" WHERE ") {
+                    Some(live) => format!(" WHERE ({live}) AND {present}"),
+                    None => format!(" WHERE {present}"),
+                };
+                (filter, format!(" GROUP BY {}", groups.join(", ")))
+            }
+        };
+
+        if check.approximate.is_some() {
+            return self
+                .check_approximate_uniqueness(check, &filter, &group_by, ctx)
+                .await;
+        }
+        let sql = format!(
+            "SELECT COALESCE(SUM(dupes), 0) AS dupes FROM \
+             (SELECT COUNT(*) - COUNT(DISTINCT ({cols})) AS dupes FROM data{filter}{group_by})"
+        );
         match count_query(ctx, &sql).await {
-            Ok(cnt) if cnt > 0 => vec![format!(
-                "Quality check failed: Uniqueness check failed for fields [{}]: found {} duplicate(s)",
-                check.fields.join(", "),
-                cnt
-            )],
+            Ok(cnt) if cnt > 0 => uniqueness_error(check, cnt as usize)
+                .map(|e| e.to_violation())
+                .into_iter()
+                .collect(),
             _ => Vec::new(),
         }
     }
 
+    /// Estimates the duplicates of an approximate uniqueness check with
+    /// `approx_distinct`, whose HyperLogLog sketch has 2^14 registers
+    /// whatever the check's tolerance.
+    async fn check_approximate_uniqueness(
+        &self,
+        check: &UniquenessCheck,
+        filter: &str,
+        group_by: &str,
+        ctx: &SessionContext,
+    ) -> Vec<Violation> {
+        const STANDARD_ERROR: f64 = 1.04 / 128.0;
+
+        let key = match check.fields.as_slice() {
+            [field] => format!("\"{}\"", field),
+            fields => format!(
+                "concat_ws('|', {})",
+                fields
+                    .iter()
+                    .map(|f| format!("CAST(\"{}\" AS VARCHAR)", f))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        };
+        let sql = format!(
+            "SELECT COALESCE(SUM(keys), 0) AS keys, COALESCE(SUM(distinct_keys), 0) AS distinct_keys \
+             FROM (SELECT COUNT(*) AS keys, CAST(approx_distinct({key}) AS BIGINT) AS distinct_keys \
+             FROM data{filter}{group_by})"
+        );
+        let Ok([keys, distinct]) = count_row_query(ctx, &sql).await else {
+            return Vec::new();
+        };
+        let distinct = distinct.min(keys);
+        let margin = (APPROXIMATE_MARGIN * STANDARD_ERROR * distinct as f64).ceil() as usize;
+        approximate_uniqueness_error(check, (keys - distinct) as usize, margin)
+            .map(|e| e.to_violation())
+            .into_iter()
+            .collect()
+    }
+
     // -----------------------------------------------------------------------
     // ML checks (SQL-based)
     // -----------------------------------------------------------------------
@@ -520,7 +1201,9 @@ impl DataFusionEngine {
         check: &TargetLeakageCheck,
         ctx: &SessionContext,
     ) -> Vec<String> {
-        let max_corr = check.max_correlation.unwrap_or(0.95);
+        let max_corr = check
+            .max_correlation
+            .unwrap_or(TargetLeakageCheck::DEFAULT_MAX_CORRELATION);
         let mut errs = Vec::new();
 
         for field in &check.feature_fields {
@@ -712,8 +1395,12 @@ impl DataFusionEngine {
         check: &FeatureDriftCheck,
         ctx: &SessionContext,
     ) -> Vec<String> {
-        let num_bins = check.num_bins.unwrap_or(10);
-        let threshold = check.threshold.unwrap_or(0.2);
+        let num_bins = check
+            .num_bins
+            .unwrap_or(FeatureDriftCheck::DEFAULT_NUM_BINS);
+        let threshold = check
+            .threshold
+            .unwrap_or(FeatureDriftCheck::DEFAULT_THRESHOLD);
         let epsilon = 1e-6;
         let mut errs = Vec::new();
 
@@ -952,7 +1639,9 @@ impl DataFusionEngine {
         check: &NullRateByGroupCheck,
         ctx: &SessionContext,
     ) -> Vec<String> {
-        let max_diff = check.max_null_rate_diff.unwrap_or(0.1);
+        let max_diff = check
+            .max_null_rate_diff
+            .unwrap_or(NullRateByGroupCheck::DEFAULT_MAX_NULL_RATE_DIFF);
         let mut errs = Vec::new();
 
         for field in &check.check_fields {
@@ -1063,8 +1752,7 @@ impl DataFusionEngine {
 
     fn build_report(
         &self,
-        errors: Vec<String>,
-        warnings: Vec<String>,
+        findings: Findings,
         contract: &Contract,
         dataset: &DataSet,
         start: Instant,
@@ -1090,9 +1778,15 @@ impl DataFusionEngine {
                 if qc.freshness.is_some() {
                     n += 1;
                 }
+                if qc.volume.is_some() {
+                    n += 1;
+                }
                 if let Some(ref c) = qc.custom_checks {
                     n += c.len();
                 }
+                if let Some(ref r) = qc.row_checks {
+                    n += r.len();
+                }
                 if let Some(ref ml) = qc.ml_checks {
                     if ml.no_overlap.is_some() {
                         n += 1;
@@ -1117,16 +1811,28 @@ impl DataFusionEngine {
             })
             .unwrap_or(0);
 
+        let truncated_violations = findings.truncated();
         ValidationReport {
-            passed: errors.is_empty(),
-            errors,
-            warnings,
+            passed: findings.errors.is_empty(),
+            errors: findings.errors,
+            warnings: findings.warnings,
+            inconclusive: Vec::new(),
+            info: findings.info,
             stats: ValidationStats {
                 records_validated: dataset.len(),
                 fields_checked: contract.schema.fields.len(),
                 constraints_evaluated: constraints_evaluated + quality_checks_count,
                 duration_ms: start.elapsed().as_millis() as u64,
+                columns: dataset.column_stats().cloned().unwrap_or_default(),
+                deletes: None,
+                truncated_violations,
             },
+            provenance: None,
+            conformance_level: contract.conformance_level,
+            snapshot_id: None,
+            base_snapshot_id: None,
+            segments: Vec::new(),
+            datasets: Vec::new(),
         }
     }
 }
@@ -1162,35 +1868,63 @@ pub(crate) fn dataset_to_record_batch(
 
     // Derive the schema from the actual arrays so that complex inner types
     // (which may have been collapsed to Utf8 by the builders) are consistent.
+    // Every column is nullable: nulls in non-nullable fields are reported by
+    // the nullability check, not rejected here.
     let arrow_fields: Vec<ArrowField> = fields
         .iter()
         .zip(columns.iter())
-        .map(|(f, col)| ArrowField::new(&f.name, col.data_type().clone(), f.nullable))
+        .map(|(f, col)| ArrowField::new(&f.name, col.data_type().clone(), true))
         .collect();
     let schema = Arc::new(ArrowSchema::new(arrow_fields));
 
     RecordBatch::try_new(schema, columns).map_err(|e| e.to_string())
 }
 
+/// Wraps the messages produced by one kind of check as violations with its
+/// error code.
+fn violations(
+    code: &'static ErrorCode,
+    check_kind: CheckKind,
+    messages: Vec<String>,
+) -> Vec<Violation> {
+    messages
+        .into_iter()
+        .map(|m| Violation::new(code, check_kind, m))
+        .collect()
+}
+
 /// Run a SQL query that returns a single count column and extract the i64 result.
 pub(crate) async fn count_query(ctx: &SessionContext, sql: &str) -> Result<i64, String> {
+    let [count] = count_row_query(ctx, sql).await?;
+    Ok(count)
+}
+
+/// Runs a query returning one row of `N` counts.
+async fn count_row_query<const N: usize>(
+    ctx: &SessionContext,
+    sql: &str,
+) -> Result<[i64; N], String> {
     let df = ctx.sql(sql).await.map_err(|e| e.to_string())?;
     let batches = df.collect().await.map_err(|e| e.to_string())?;
     let batch = batches.first().ok_or("no batches")?;
+    let mut counts = [0; N];
     if batch.num_rows() == 0 {
-        return Ok(0);
-    }
-    let col = batch.column(0);
-    if let Some(a) = col.as_any().downcast_ref::<arrow_array::Int64Array>() {
-        Ok(a.value(0))
-    } else if let Some(a) = col.as_any().downcast_ref::<arrow_array::UInt64Array>() {
-        Ok(a.value(0) as i64)
-    } else {
-        Err(format!(
-            "unexpected count column type: {:?}",
-            col.data_type()
-        ))
+        return Ok(counts);
     }
+    for (i, count) in counts.iter_mut().enumerate() {
+        let col = batch.column(i);
+        *count = if let Some(a) = col.as_any().downcast_ref::<arrow_array::Int64Array>() {
+            a.value(0)
+        } else if let Some(a) = col.as_any().downcast_ref::<arrow_array::UInt64Array>() {
+            a.value(0) as i64
+        } else {
+            return Err(format!(
+                "unexpected count column type: {:?}",
+                col.data_type()
+            ));
+        };
+    }
+    Ok(counts)
 }
 
 fn build_arrow_column(
@@ -1219,7 +1953,13 @@ fn build_arrow_array(
                 match row.get(col_name) {
                     Some(DataValue::String(s)) => builder.append_value(s),
                     Some(DataValue::Timestamp(s)) => builder.append_value(s),
+                    Some(DataValue::Date(d)) => builder.append_value(d.to_string()),
+                    Some(DataValue::Time(t)) => builder.append_value(t.to_string()),
                     Some(DataValue::Int(i)) => builder.append_value(i.to_string()),
+                    Some(DataValue::UInt(u)) => builder.append_value(u.to_string()),
+                    Some(DataValue::Decimal { value, scale }) => {
+                        builder.append_value(DataValue::decimal_to_string(*value, *scale))
+                    }
                     Some(DataValue::Float(f)) => builder.append_value(f.to_string()),
                     Some(DataValue::Bool(b)) => builder.append_value(b.to_string()),
                     Some(DataValue::Null) | None => builder.append_null(),
@@ -1233,6 +1973,12 @@ fn build_arrow_array(
             for row in rows {
                 match row.get(col_name) {
                     Some(DataValue::Int(i)) => builder.append_value(*i),
+                    Some(DataValue::UInt(u)) => {
+                        let value = i64::try_from(*u).map_err(|_| {
+                            format!("value {u} in column '{col_name}' overflows int64")
+                        })?;
+                        builder.append_value(value)
+                    }
                     Some(DataValue::Float(f)) => builder.append_value(*f as i64),
                     Some(DataValue::Null) | None => builder.append_null(),
                     _ => builder.append_null(),
@@ -1246,6 +1992,9 @@ fn build_arrow_array(
                 match row.get(col_name) {
                     Some(DataValue::Float(f)) => builder.append_value(*f),
                     Some(DataValue::Int(i)) => builder.append_value(*i as f64),
+                    Some(v @ (DataValue::UInt(_) | DataValue::Decimal { .. })) => {
+                        builder.append_value(v.as_float().unwrap_or_default())
+                    }
                     Some(DataValue::Null) | None => builder.append_null(),
                     _ => builder.append_null(),
                 }
@@ -1304,9 +2053,15 @@ fn append_primitive_value(
             match val {
                 Some(DataValue::String(s)) => b.append_value(s),
                 Some(DataValue::Int(i)) => b.append_value(i.to_string()),
+                Some(DataValue::UInt(u)) => b.append_value(u.to_string()),
+                Some(DataValue::Decimal { value, scale }) => {
+                    b.append_value(DataValue::decimal_to_string(*value, *scale))
+                }
                 Some(DataValue::Float(f)) => b.append_value(f.to_string()),
                 Some(DataValue::Bool(v)) => b.append_value(v.to_string()),
                 Some(DataValue::Timestamp(s)) => b.append_value(s),
+                Some(DataValue::Date(d)) => b.append_value(d.to_string()),
+                Some(DataValue::Time(t)) => b.append_value(t.to_string()),
                 _ => b.append_null(),
             }
         }
@@ -1314,6 +2069,7 @@ fn append_primitive_value(
             let b = builder.as_any_mut().downcast_mut::<Int64Builder>().unwrap();
             match val {
                 Some(DataValue::Int(i)) => b.append_value(*i),
+                Some(DataValue::UInt(u)) => b.append_option(i64::try_from(*u).ok()),
                 Some(DataValue::Float(f)) => b.append_value(*f as i64),
                 _ => b.append_null(),
             }
@@ -1326,6 +2082,9 @@ fn append_primitive_value(
             match val {
                 Some(DataValue::Float(f)) => b.append_value(*f),
                 Some(DataValue::Int(i)) => b.append_value(*i as f64),
+                Some(v @ (DataValue::UInt(_) | DataValue::Decimal { .. })) => {
+                    b.append_option(v.as_float())
+                }
                 _ => b.append_null(),
             }
         }
@@ -1617,6 +2376,7 @@ mod ml_tests {
             target_field: "target".to_string(),
             feature_fields: vec!["feature".to_string()],
             max_correlation: Some(0.95),
+            min_sample_size: None,
         };
 
         let errs = engine.check_ml_target_leakage(&check, &ctx).await;
@@ -1643,6 +2403,7 @@ mod ml_tests {
             target_field: "target".to_string(),
             feature_fields: vec!["feature".to_string()],
             max_correlation: Some(0.95),
+            min_sample_size: None,
         };
 
         let errs = engine.check_ml_target_leakage(&check, &ctx).await;
@@ -1667,6 +2428,7 @@ mod ml_tests {
             target_field: "target".to_string(),
             feature_fields: vec!["feature".to_string()],
             max_correlation: Some(0.95),
+            min_sample_size: None,
         };
 
         let errs = engine.check_ml_target_leakage(&check, &ctx).await;
@@ -1700,6 +2462,7 @@ mod ml_tests {
             label_field: "label".to_string(),
             max_proportion: 0.9,
             min_proportion: None,
+            min_sample_size: None,
         };
 
         let errs = engine.check_ml_class_balance(&check, &ctx).await;
@@ -1731,6 +2494,7 @@ mod ml_tests {
             label_field: "label".to_string(),
             max_proportion: 0.9,
             min_proportion: None,
+            min_sample_size: None,
         };
 
         let errs = engine.check_ml_class_balance(&check, &ctx).await;
@@ -1781,6 +2545,7 @@ mod ml_tests {
             group_field: "group".to_string(),
             check_fields: vec!["value".to_string()],
             max_null_rate_diff: Some(0.1),
+            min_sample_size: None,
         };
 
         let errs = engine.check_ml_null_rate_by_group(&check, &ctx).await;
@@ -1832,6 +2597,7 @@ mod ml_tests {
             feature_fields: vec!["feature".to_string()],
             num_bins: Some(10),
             threshold: Some(0.2),
+            min_sample_size: None,
         };
 
         let errs = engine.check_ml_feature_drift(&check, &ctx).await;
@@ -1878,6 +2644,7 @@ mod ml_tests {
             feature_fields: vec!["feature".to_string()],
             num_bins: Some(10),
             threshold: Some(0.2),
+            min_sample_size: None,
         };
 
         let errs = engine.check_ml_feature_drift(&check, &ctx).await;
@@ -1892,6 +2659,7 @@ mod ml_tests {
 #[cfg(test)]
 mod complex_type_tests {
     use super::*;
+    use arrow_array::{Int64Array, StringArray};
     use contracts_core::{PrimitiveType, StructField as DceStructField};
 
     #[test]
@@ -1961,6 +2729,11 @@ mod complex_type_tests {
             description: None,
             constraints: None,
             tags: None,
+            required: None,
+            severity: None,
+            default: None,
+            deprecated: None,
+            access: None,
         };
 
         let mut row1 = std::collections::HashMap::new();
@@ -2009,6 +2782,11 @@ mod complex_type_tests {
             description: None,
             constraints: None,
             tags: None,
+            required: None,
+            severity: None,
+            default: None,
+            deprecated: None,
+            access: None,
         };
 
         let mut inner = std::collections::HashMap::new();
@@ -2040,6 +2818,11 @@ mod complex_type_tests {
             description: None,
             constraints: None,
             tags: None,
+            required: None,
+            severity: None,
+            default: None,
+            deprecated: None,
+            access: None,
         };
 
         let mut inner = std::collections::HashMap::new();
@@ -2056,4 +2839,690 @@ mod complex_type_tests {
         let array = result.unwrap();
         assert_eq!(array.len(), 1);
     }
+
+    #[test]
+    fn build_int64_column_rejects_overflowing_uint() {
+        let field = Field {
+            name: "count".to_string(),
+            field_type: DataType::Primitive(PrimitiveType::Int64),
+            nullable: true,
+            description: None,
+            constraints: None,
+            tags: None,
+            required: None,
+            severity: None,
+            default: None,
+            deprecated: None,
+            access: None,
+        };
+
+        let row = |v: DataValue| std::collections::HashMap::from([("count".to_string(), v)]);
+
+        let fits = vec![row(DataValue::UInt(42))];
+        let array = build_arrow_column(&field, &fits, 1).unwrap();
+        let array = array.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(array.value(0), 42);
+
+        let overflows = vec![row(DataValue::UInt(u64::MAX))];
+        let err = build_arrow_column(&field, &overflows, 1).unwrap_err();
+        assert!(err.contains("overflows int64"), "unexpected error: {err}");
+    }
+
+    #[test]
+    fn build_string_column_keeps_decimal_digits() {
+        let field = Field {
+            name: "amount".to_string(),
+            field_type: DataType::Primitive(PrimitiveType::Decimal),
+            nullable: true,
+            description: None,
+            constraints: None,
+            tags: None,
+            required: None,
+            severity: None,
+            default: None,
+            deprecated: None,
+            access: None,
+        };
+
+        let value = DataValue::Decimal {
+            value: 12_345_678_901_234_567_890_123,
+            scale: 4,
+        };
+        let rows = vec![std::collections::HashMap::from([(
+            "amount".to_string(),
+            value,
+        )])];
+        let array = build_arrow_column(&field, &rows, 1).unwrap();
+        let array = array.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(array.value(0), "1234567890123456789.0123");
+    }
+
+    #[tokio::test]
+    async fn collection_constraints_run_as_sql() {
+        use contracts_core::{ContractBuilder, DataFormat, FieldBuilder, ValidationContext};
+
+        let contract = ContractBuilder::new("test", "owner")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(
+                FieldBuilder::new("tags", "list<string>")
+                    .constraint(FieldConstraints::ListLength {
+                        min: Some(1),
+                        max: Some(2),
+                    })
+                    .constraint(FieldConstraints::NonEmpty)
+                    .build(),
+            )
+            .field(
+                FieldBuilder::new("props", "map<string,string>")
+                    .constraint(FieldConstraints::MapKeys {
+                        pattern: Some("^[a-z]+$".to_string()),
+                        allowed: Some(vec!["color".to_string(), "size".to_string()]),
+                    })
+                    .build(),
+            )
+            .build();
+
+        let tags = |n: usize| DataValue::List(vec![DataValue::String("t".to_string()); n]);
+        let props = |keys: &[&str]| {
+            DataValue::Map(
+                keys.iter()
+                    .map(|k| (k.to_string(), DataValue::String("x".to_string())))
+                    .collect(),
+            )
+        };
+        let row = |tags, props| {
+            std::collections::HashMap::from([
+                ("tags".to_string(), tags),
+                ("props".to_string(), props),
+            ])
+        };
+        let dataset = DataSet::from_rows(vec![
+            row(tags(1), props(&["color"])),
+            row(tags(3), props(&["size", "Weight"])),
+            row(tags(0), props(&[])),
+        ]);
+
+        let report = DataFusionEngine::new()
+            .validate(&contract, &dataset, &ValidationContext::new())
+            .await;
+        let messages: Vec<String> = report
+            .errors
+            .iter()
+            .chain(&report.warnings)
+            .map(|v| v.to_string())
+            .collect();
+        let has = |needle: &str| messages.iter().any(|m| m.contains(needle));
+        assert!(
+            has(
+                "[DCE0205] Constraint violation for field 'tags': 2 row(s) outside length bounds [1, 2]"
+            ),
+            "{messages:?}"
+        );
+        assert!(
+            has(
+                "[DCE0205] Constraint violation for field 'tags': 1 row(s) with an empty collection"
+            ),
+            "{messages:?}"
+        );
+        assert!(
+            has(
+                "[DCE0206] Constraint violation for field 'props': 1 row(s) with keys not in allowed keys"
+            ),
+            "{messages:?}"
+        );
+        assert!(
+            has(
+                "[DCE0206] Constraint violation for field 'props': 1 key(s) do not match key pattern"
+            ),
+            "{messages:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn conditional_constraints_run_as_sql() {
+        use contracts_core::{ContractBuilder, DataFormat, FieldBuilder, ValidationContext};
+
+        let conditional = |when: &str, require: &str| FieldConstraints::Conditional {
+            when: when.to_string(),
+            require: require.to_string(),
+        };
+        let contract = ContractBuilder::new("tickets", "support")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("status", "string").build())
+            .field(
+                FieldBuilder::new("closed_at", "timestamp")
+                    .constraint(conditional("status = 'closed'", "closed_at IS NOT NULL"))
+                    .build(),
+            )
+            .field(
+                FieldBuilder::new("refund", "float64")
+                    .constraint(conditional("refund IS NOT NULL", "refund <= total"))
+                    .build(),
+            )
+            .field(FieldBuilder::new("total", "float64").build())
+            .build();
+
+        let ticket = |status: &str, closed_at: Option<&str>, refund: Option<f64>, total: f64| {
+            std::collections::HashMap::from([
+                ("status".to_string(), DataValue::String(status.to_string())),
+                (
+                    "closed_at".to_string(),
+                    closed_at.map_or(DataValue::Null, |t| DataValue::Timestamp(t.to_string())),
+                ),
+                (
+                    "refund".to_string(),
+                    refund.map_or(DataValue::Null, DataValue::Float),
+                ),
+                ("total".to_string(), DataValue::Float(total)),
+            ])
+        };
+        let dataset = DataSet::from_rows(vec![
+            ticket("open", None, None, 10.0),
+            ticket("closed", Some("2026-10-17T09:00:00Z"), Some(5.0), 10.0),
+            ticket("closed", None, Some(15.0), 10.0),
+            ticket("closed", None, None, 10.0),
+        ]);
+
+        let report = DataFusionEngine::new()
+            .validate(&contract, &dataset, &ValidationContext::new())
+            .await;
+        let messages: Vec<String> = report.errors.iter().map(|v| v.to_string()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "[DCE0207] Constraint violation for field 'closed_at': 2 row(s) fail condition \
+                 'when status = 'closed' require closed_at IS NOT NULL'",
+                "[DCE0207] Constraint violation for field 'refund': 1 row(s) fail condition \
+                 'when refund IS NOT NULL require refund <= total'",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn custom_constraints_run_as_sql() {
+        use contracts_core::{ContractBuilder, DataFormat, FieldBuilder, ValidationContext};
+
+        let contract = ContractBuilder::new("orders", "sales")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(
+                FieldBuilder::new("discount", "float64")
+                    .constraint(FieldConstraints::Custom {
+                        definition: "value >= 0 && value < total / 2".to_string(),
+                    })
+                    .build(),
+            )
+            .field(FieldBuilder::new("total", "float64").build())
+            .field(
+                FieldBuilder::new("sku", "string")
+                    .constraint(FieldConstraints::Custom {
+                        definition: "len(value) == 6".to_string(),
+                    })
+                    .build(),
+            )
+            .build();
+
+        let order = |discount: Option<f64>, total: f64, sku: &str| {
+            std::collections::HashMap::from([
+                (
+                    "discount".to_string(),
+                    discount.map_or(DataValue::Null, DataValue::Float),
+                ),
+                ("total".to_string(), DataValue::Float(total)),
+                ("sku".to_string(), DataValue::String(sku.to_string())),
+            ])
+        };
+        let dataset = DataSet::from_rows(vec![
+            order(Some(2.0), 10.0, "AB-123"),
+            order(Some(6.0), 10.0, "AB-124"),
+            order(None, 10.0, "AB-1"),
+            order(Some(-1.0), 10.0, "AB-125"),
+        ]);
+
+        let report = DataFusionEngine::new()
+            .validate(&contract, &dataset, &ValidationContext::new())
+            .await;
+        let messages: Vec<String> = report.errors.iter().map(|v| v.to_string()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "[DCE0200] Constraint violation for field 'discount': 2 row(s) fail \
+                 custom 'value >= 0 && value < total / 2'",
+                "[DCE0200] Constraint violation for field 'sku': 1 row(s) fail \
+                 custom 'len(value) == 6'",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn custom_constraints_report_evaluation_failures() {
+        use contracts_core::{ContractBuilder, DataFormat, FieldBuilder, ValidationContext};
+
+        let contract = ContractBuilder::new("orders", "sales")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(
+                FieldBuilder::new("quantity", "int64")
+                    .constraint(FieldConstraints::Custom {
+                        definition: "100 / value > 1".to_string(),
+                    })
+                    .build(),
+            )
+            .build();
+        let dataset = DataSet::from_rows(
+            [50, 0]
+                .into_iter()
+                .map(|quantity| {
+                    std::collections::HashMap::from([(
+                        "quantity".to_string(),
+                        DataValue::Int(quantity),
+                    )])
+                })
+                .collect(),
+        );
+
+        let report = DataFusionEngine::new()
+            .validate(&contract, &dataset, &ValidationContext::new())
+            .await;
+        assert_eq!(report.errors.len(), 1);
+        assert!(
+            report.errors[0]
+                .message
+                .contains("fail custom '100 / value > 1', which could not be evaluated"),
+            "{}",
+            report.errors[0]
+        );
+    }
+
+    #[tokio::test]
+    async fn row_checks_run_as_sql() {
+        use contracts_core::{
+            ContractBuilder, DataFormat, FieldBuilder, QualityChecksBuilder, ValidationContext,
+        };
+
+        let check = |name: &str, expression: &str| RowCheck {
+            name: name.to_string(),
+            expression: expression.to_string(),
+            severity: None,
+        };
+        let contract = ContractBuilder::new("orders", "sales")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("discount", "float64").build())
+            .field(FieldBuilder::new("total", "float64").build())
+            .field(FieldBuilder::new("sku", "string").build())
+            .quality_checks(
+                QualityChecksBuilder::new()
+                    .row_check(check("discount_below_half", "discount * 2 < total"))
+                    .row_check(check("sku_length", "len(sku) == 6 && !(sku LIKE 'X%')"))
+                    .row_check(check("typo", "totl > 0"))
+                    .build(),
+            )
+            .build();
+        let order = |discount: Option<f64>, total: f64, sku: &str| {
+            std::collections::HashMap::from([
+                (
+                    "discount".to_string(),
+                    discount.map_or(DataValue::Null, DataValue::Float),
+                ),
+                ("total".to_string(), DataValue::Float(total)),
+                ("sku".to_string(), DataValue::String(sku.to_string())),
+            ])
+        };
+        let dataset = DataSet::from_rows(vec![
+            order(Some(2.0), 10.0, "AB-123"),
+            order(Some(6.0), 10.0, "AB-124"),
+            order(None, 10.0, "XB-125"),
+        ]);
+
+        let context = ValidationContext::new().with_strict(true);
+        let report = DataFusionEngine::new()
+            .validate(&contract, &dataset, &context)
+            .await;
+        let messages: Vec<String> = report.errors.iter().map(|v| v.to_string()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "[DCE0401] Row check 'discount_below_half' failed: 1 row(s) fail \
+                 'discount * 2 < total'",
+                "[DCE0401] Row check 'sku_length' failed: 1 row(s) fail \
+                 'len(sku) == 6 && !(sku LIKE 'X%')'",
+                "[DCE0015] Invalid row check 'typo': 'totl > 0': unknown field 'totl'",
+            ]
+        );
+
+        // The row engine reports the same failures
+        let rows = crate::DataValidator::new().validate_with_data(&contract, &dataset, &context);
+        let row_messages: Vec<String> = rows.errors.iter().map(|v| v.to_string()).collect();
+        assert_eq!(row_messages, messages);
+    }
+
+    #[tokio::test]
+    async fn string_length_constraints_run_as_sql() {
+        use contracts_core::{ContractBuilder, DataFormat, FieldBuilder, ValidationContext};
+
+        let contract = ContractBuilder::new("events", "platform")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(
+                FieldBuilder::new("payload", "string")
+                    .constraint(FieldConstraints::Length {
+                        min: Some(2),
+                        max: None,
+                    })
+                    .constraint(FieldConstraints::ByteLength {
+                        min: None,
+                        max: Some(4),
+                    })
+                    .build(),
+            )
+            .build();
+
+        let event = |payload: &str| {
+            std::collections::HashMap::from([(
+                "payload".to_string(),
+                DataValue::String(payload.to_string()),
+            )])
+        };
+        let dataset = DataSet::from_rows(vec![event("ok"), event("€€"), event("a")]);
+
+        let report = DataFusionEngine::new()
+            .validate(&contract, &dataset, &ValidationContext::new())
+            .await;
+        let messages: Vec<String> = report.errors.iter().map(|v| v.to_string()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "[DCE0205] Constraint violation for field 'payload': 1 row(s) outside \
+                 length bounds [2, ∞) in characters",
+                "[DCE0205] Constraint violation for field 'payload': 1 row(s) outside \
+                 length bounds [0, 4] in bytes",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn precision_constraints_run_as_sql() {
+        use contracts_core::{ContractBuilder, DataFormat, FieldBuilder, ValidationContext};
+
+        let precision = FieldConstraints::Precision {
+            precision: 5,
+            scale: 2,
+        };
+        let contract = ContractBuilder::new("payments", "finance")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(
+                FieldBuilder::new("amount", "decimal")
+                    .constraint(precision.clone())
+                    .build(),
+            )
+            .field(
+                FieldBuilder::new("rate", "float64")
+                    .constraint(precision)
+                    .build(),
+            )
+            .build();
+
+        let payment = |amount: i128, scale: i8, rate: f64| {
+            std::collections::HashMap::from([
+                (
+                    "amount".to_string(),
+                    DataValue::Decimal {
+                        value: amount,
+                        scale,
+                    },
+                ),
+                ("rate".to_string(), DataValue::Float(rate)),
+            ])
+        };
+        let dataset = DataSet::from_rows(vec![
+            payment(-99999, 2, 0.25),
+            payment(123400, 4, 999.99),
+            payment(12345, 3, 0.125),
+            payment(1000, 0, 1000.0),
+        ]);
+
+        let report = DataFusionEngine::new()
+            .validate(&contract, &dataset, &ValidationContext::new())
+            .await;
+        let messages: Vec<String> = report.errors.iter().map(|v| v.to_string()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "[DCE0211] Constraint violation for field 'amount': 2 row(s) with values \
+                 that do not fit decimal(5, 2)",
+                "[DCE0211] Constraint violation for field 'rate': 2 row(s) with values \
+                 that do not fit decimal(5, 2)",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn time_range_constraints_run_as_sql() {
+        use contracts_core::{ContractBuilder, DataFormat, FieldBuilder, ValidationContext};
+
+        let contract = ContractBuilder::new("events", "tracking")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(
+                FieldBuilder::new("occurred_at", "timestamp")
+                    .constraint(FieldConstraints::TimeRange {
+                        min: Some("2020-01-01".to_string()),
+                        max: None,
+                        allow_future: false,
+                    })
+                    .build(),
+            )
+            .build();
+
+        let tomorrow = (chrono::Utc::now() + chrono::Duration::days(1)).to_rfc3339();
+        let dataset = DataSet::from_rows(
+            ["2024-05-01T10:00:00Z", "2019-12-31T23:59:59Z", &tomorrow]
+                .into_iter()
+                .map(|t| {
+                    std::collections::HashMap::from([(
+                        "occurred_at".to_string(),
+                        DataValue::Timestamp(t.to_string()),
+                    )])
+                })
+                .collect(),
+        );
+
+        let report = DataFusionEngine::new()
+            .validate(&contract, &dataset, &ValidationContext::new())
+            .await;
+        let messages: Vec<String> = report.errors.iter().map(|v| v.to_string()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "[DCE0212] Constraint violation for field 'occurred_at': 1 row(s) outside \
+                 time range [2020-01-01, -]",
+                "[DCE0212] Constraint violation for field 'occurred_at': 1 row(s) with \
+                 timestamps in the future",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn scoped_uniqueness_runs_as_sql() {
+        use contracts_core::{
+            ContractBuilder, DataFormat, FieldBuilder, QualityChecksBuilder, UniquenessCheck,
+            ValidationContext,
+        };
+
+        let contract = |scope: &str| {
+            ContractBuilder::new("events", "tracking")
+                .location("s3://test")
+                .format(DataFormat::Iceberg)
+                .field(FieldBuilder::new("event_id", "string").build())
+                .field(FieldBuilder::new("occurred_at", "timestamp").build())
+                .quality_checks(
+                    QualityChecksBuilder::new()
+                        .uniqueness(UniquenessCheck {
+                            fields: vec!["event_id".to_string()],
+                            scope: Some(scope.to_string()),
+                            min_sample_size: None,
+                            severity: None,
+                            approximate: None,
+                        })
+                        .build(),
+                )
+                .build()
+        };
+        let dataset = DataSet::from_rows(
+            [
+                ("e1", "2026-09-30T08:00:00Z"),
+                ("e1", "2026-10-01T08:00:00Z"),
+                ("e1", "2026-10-01T20:00:00Z"),
+                ("e2", "2026-10-02T08:00:00Z"),
+            ]
+            .into_iter()
+            .map(|(id, at)| {
+                std::collections::HashMap::from([
+                    ("event_id".to_string(), DataValue::String(id.to_string())),
+                    (
+                        "occurred_at".to_string(),
+                        DataValue::Timestamp(at.to_string()),
+                    ),
+                ])
+            })
+            .collect(),
+        );
+
+        let errors = |scope: &'static str| {
+            let dataset = dataset.clone();
+            async move {
+                let report = DataFusionEngine::new()
+                    .validate(&contract(scope), &dataset, &ValidationContext::new())
+                    .await;
+                report
+                    .warnings
+                    .iter()
+                    .chain(&report.errors)
+                    .map(|v| v.message.clone())
+                    .collect::<Vec<_>>()
+            }
+        };
+        assert_eq!(
+            errors("per_day(occurred_at)").await,
+            vec![
+                "Quality check failed: Uniqueness check failed for fields [event_id] \
+                 per_day(occurred_at): found 1 duplicate(s)"
+            ]
+        );
+        assert!(errors("per_month(occurred_at)").await[0].contains("found 1 duplicate(s)"));
+        assert!(errors("global").await[0].contains("found 2 duplicate(s)"));
+    }
+
+    #[tokio::test]
+    async fn approximate_uniqueness_runs_as_sql() {
+        use contracts_core::{
+            ApproximateUniqueness, ContractBuilder, DataFormat, FieldBuilder, QualityChecksBuilder,
+            UniquenessCheck, ValidationContext,
+        };
+
+        let contract = ContractBuilder::new("orders", "sales")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("id", "string").build())
+            .field(FieldBuilder::new("region", "string").build())
+            .quality_checks(
+                QualityChecksBuilder::new()
+                    .uniqueness(UniquenessCheck {
+                        fields: vec!["id".to_string(), "region".to_string()],
+                        scope: None,
+                        min_sample_size: None,
+                        severity: None,
+                        approximate: Some(ApproximateUniqueness::default()),
+                    })
+                    .build(),
+            )
+            .build();
+        let orders = |distinct: usize| {
+            DataSet::from_rows(
+                (0..20_000)
+                    .map(|i| {
+                        std::collections::HashMap::from([
+                            (
+                                "id".to_string(),
+                                DataValue::String(format!("order-{}", i % distinct)),
+                            ),
+                            ("region".to_string(), DataValue::String("eu".to_string())),
+                        ])
+                    })
+                    .collect(),
+            )
+        };
+
+        let engine = DataFusionEngine::new();
+        let context = ValidationContext::new();
+        let findings = |distinct: usize| {
+            let dataset = orders(distinct);
+            let (engine, contract, context) = (&engine, &contract, &context);
+            async move {
+                let report = engine.validate(contract, &dataset, context).await;
+                report
+                    .warnings
+                    .iter()
+                    .chain(&report.errors)
+                    .map(|v| v.message.clone())
+                    .collect::<Vec<_>>()
+            }
+        };
+        assert!(findings(20_000).await.is_empty());
+
+        let messages = findings(5_000).await;
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].contains("found about"), "{:?}", messages);
+    }
+
+    #[tokio::test]
+    async fn distribution_constraints_run_as_sql() {
+        use contracts_core::{ContractBuilder, DataFormat, FieldBuilder, ValidationContext};
+
+        let contract = ContractBuilder::new("orders", "sales")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(
+                FieldBuilder::new("coupon", "string")
+                    .constraint(FieldConstraints::MaxNullFraction { fraction: 0.5 })
+                    .constraint(FieldConstraints::Cardinality {
+                        min_distinct: None,
+                        max_distinct: Some(1),
+                    })
+                    .build(),
+            )
+            .build();
+
+        let coupon = |code: Option<&str>| {
+            std::collections::HashMap::from([(
+                "coupon".to_string(),
+                code.map_or(DataValue::Null, |c| DataValue::String(c.to_string())),
+            )])
+        };
+        let dataset = DataSet::from_rows(vec![
+            coupon(Some("SPRING")),
+            coupon(Some("SUMMER")),
+            coupon(None),
+            coupon(None),
+            coupon(None),
+        ]);
+
+        let report = DataFusionEngine::new()
+            .validate(&contract, &dataset, &ValidationContext::new())
+            .await;
+        let messages: Vec<String> = report.errors.iter().map(|v| v.to_string()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "[DCE0209] Constraint violation for field 'coupon': 3 of 5 value(s) are \
+                 null (60.00%), above the max null fraction of 50.00%",
+                "[DCE0210] Constraint violation for field 'coupon': 2 distinct value(s) \
+                 outside cardinality bounds [0, 1]",
+            ]
+        );
+    }
 }
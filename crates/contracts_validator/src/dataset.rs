@@ -2,12 +2,22 @@
 //!
 //! This module provides types for representing data to be validated against contracts.
 
-use std::collections::HashMap;
+use crate::stats::value_key;
+use crate::{Sampler, StatsCollector, proportional_quotas};
+use chrono::{NaiveDate, NaiveTime};
+use contracts_core::{ColumnStats, SamplingStrategy};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 
 /// A value in a dataset.
 ///
 /// Represents different types of values that can appear in data records.
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Values serialize externally tagged with the variant name in snake case,
+/// e.g. `{"int": 42}`, `{"date": "2024-01-31"}` or `"null"`, so that a
+/// round trip through JSON keeps the exact variant.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum DataValue {
     /// Null/missing value
     Null,
@@ -15,12 +25,20 @@ pub enum DataValue {
     String(String),
     /// Integer value
     Int(i64),
+    /// Unsigned integer value, for sources whose values may exceed `i64::MAX`
+    UInt(u64),
+    /// Exact decimal value, equal to `value * 10^-scale`
+    Decimal { value: i128, scale: i8 },
     /// Floating point value
     Float(f64),
     /// Boolean value
     Bool(bool),
     /// Timestamp value (ISO 8601 string)
     Timestamp(String),
+    /// Calendar date without a time zone
+    Date(NaiveDate),
+    /// Time of day without a date or time zone
+    Time(NaiveTime),
     /// Map/struct value
     Map(HashMap<String, DataValue>),
     /// List/array value
@@ -39,9 +57,13 @@ impl DataValue {
             DataValue::Null => "null",
             DataValue::String(_) => "string",
             DataValue::Int(_) => "int64",
+            DataValue::UInt(_) => "uint64",
+            DataValue::Decimal { .. } => "decimal",
             DataValue::Float(_) => "float64",
             DataValue::Bool(_) => "boolean",
             DataValue::Timestamp(_) => "timestamp",
+            DataValue::Date(_) => "date",
+            DataValue::Time(_) => "time",
             DataValue::Map(_) => "map",
             DataValue::List(_) => "list",
         }
@@ -56,22 +78,71 @@ impl DataValue {
     }
 
     /// Attempts to get this value as an integer.
+    ///
+    /// Unsigned values are returned only if they fit in `i64`.
     pub fn as_int(&self) -> Option<i64> {
         match self {
             DataValue::Int(i) => Some(*i),
+            DataValue::UInt(u) => i64::try_from(*u).ok(),
+            _ => None,
+        }
+    }
+
+    /// Attempts to get this value as an unsigned integer.
+    ///
+    /// Signed values are returned only if they are not negative.
+    pub fn as_uint(&self) -> Option<u64> {
+        match self {
+            DataValue::UInt(u) => Some(*u),
+            DataValue::Int(i) => u64::try_from(*i).ok(),
             _ => None,
         }
     }
 
     /// Attempts to get this value as a float.
+    ///
+    /// Integers and decimals are converted, which may round values that
+    /// have more significant digits than `f64` can hold.
     pub fn as_float(&self) -> Option<f64> {
         match self {
             DataValue::Float(f) => Some(*f),
             DataValue::Int(i) => Some(*i as f64),
+            DataValue::UInt(u) => Some(*u as f64),
+            DataValue::Decimal { value, scale } => Some(*value as f64 / 10f64.powi(*scale as i32)),
             _ => None,
         }
     }
 
+    /// Formats an exact decimal as text without losing digits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use contracts_validator::DataValue;
+    ///
+    /// assert_eq!(DataValue::decimal_to_string(-12345, 2), "-123.45");
+    /// assert_eq!(DataValue::decimal_to_string(5, 3), "0.005");
+    /// assert_eq!(DataValue::decimal_to_string(7, -2), "700");
+    /// ```
+    pub fn decimal_to_string(value: i128, scale: i8) -> String {
+        let digits = value.unsigned_abs().to_string();
+        let sign = if value < 0 { "-" } else { "" };
+
+        if scale <= 0 {
+            let zeros = if value == 0 {
+                0
+            } else {
+                scale.unsigned_abs() as usize
+            };
+            return format!("{}{}{}", sign, digits, "0".repeat(zeros));
+        }
+
+        let scale = scale as usize;
+        let digits = format!("{:0>width$}", digits, width = scale + 1);
+        let (int_part, frac_part) = digits.split_at(digits.len() - scale);
+        format!("{}{}.{}", sign, int_part, frac_part)
+    }
+
     /// Attempts to get this value as a boolean.
     pub fn as_bool(&self) -> Option<bool> {
         match self {
@@ -87,6 +158,22 @@ impl DataValue {
             _ => None,
         }
     }
+
+    /// Attempts to get this value as a date.
+    pub fn as_date(&self) -> Option<NaiveDate> {
+        match self {
+            DataValue::Date(d) => Some(*d),
+            _ => None,
+        }
+    }
+
+    /// Attempts to get this value as a time of day.
+    pub fn as_time(&self) -> Option<NaiveTime> {
+        match self {
+            DataValue::Time(t) => Some(*t),
+            _ => None,
+        }
+    }
 }
 
 impl From<String> for DataValue {
@@ -107,12 +194,30 @@ impl From<i64> for DataValue {
     }
 }
 
+impl From<u64> for DataValue {
+    fn from(u: u64) -> Self {
+        DataValue::UInt(u)
+    }
+}
+
 impl From<f64> for DataValue {
     fn from(f: f64) -> Self {
         DataValue::Float(f)
     }
 }
 
+impl From<NaiveDate> for DataValue {
+    fn from(d: NaiveDate) -> Self {
+        DataValue::Date(d)
+    }
+}
+
+impl From<NaiveTime> for DataValue {
+    fn from(t: NaiveTime) -> Self {
+        DataValue::Time(t)
+    }
+}
+
 impl From<bool> for DataValue {
     fn from(b: bool) -> Self {
         DataValue::Bool(b)
@@ -122,24 +227,141 @@ impl From<bool> for DataValue {
 /// A single row of data.
 pub type DataRow = HashMap<String, DataValue>;
 
+/// Value yielded by [`DataSet::column`] for rows that lack the column.
+static MISSING: DataValue = DataValue::Null;
+
 /// A dataset containing multiple rows.
 ///
 /// Represents a collection of data records to be validated against a contract.
-#[derive(Debug, Clone)]
+///
+/// A dataset serializes as a JSON array of rows. Column statistics and the
+/// column index are not serialized.
+///
+/// # Example
+///
+/// ```rust
+/// use contracts_validator::{DataSet, DataValue};
+///
+/// let json = r#"[{"id": {"int": 1}, "email": {"string": "a@example.com"}}, {"id": "null"}]"#;
+/// let dataset: DataSet = serde_json::from_str(json).unwrap();
+///
+/// assert_eq!(dataset.len(), 2);
+/// assert_eq!(dataset.get_row(1).unwrap()["id"], DataValue::Null);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct DataSet {
     /// The data rows
     rows: Vec<DataRow>,
+
+    /// Statistics describing `rows`, cleared whenever the rows change
+    #[serde(skip)]
+    column_stats: Option<BTreeMap<String, ColumnStats>>,
+
+    /// Column-major copy of `rows`, cleared whenever the rows change
+    #[serde(skip)]
+    column_index: Option<HashMap<String, Vec<DataValue>>>,
 }
 
 impl DataSet {
     /// Creates a new empty dataset.
     pub fn empty() -> Self {
-        Self { rows: Vec::new() }
+        Self::from_rows(Vec::new())
     }
 
     /// Creates a new dataset from rows.
     pub fn from_rows(rows: Vec<DataRow>) -> Self {
-        Self { rows }
+        Self {
+            rows,
+            column_stats: None,
+            column_index: None,
+        }
+    }
+
+    /// Attaches per-column statistics gathered while the rows were produced.
+    ///
+    /// The stats must describe exactly these rows: quality checks trust them
+    /// instead of scanning the rows.
+    pub fn with_column_stats(mut self, stats: BTreeMap<String, ColumnStats>) -> Self {
+        self.column_stats = Some(stats);
+        self
+    }
+
+    /// Returns the attached per-column statistics, if any.
+    pub fn column_stats(&self) -> Option<&BTreeMap<String, ColumnStats>> {
+        self.column_stats.as_ref()
+    }
+
+    /// Computes per-column statistics by scanning the rows.
+    pub fn compute_column_stats(&self) -> BTreeMap<String, ColumnStats> {
+        let mut collector = StatsCollector::new();
+        for row in &self.rows {
+            collector.observe(row);
+        }
+        collector.finish()
+    }
+
+    /// Returns the values of one column, in row order.
+    ///
+    /// Rows that lack the column yield [`DataValue::Null`]. Reads come from the
+    /// column index when one was built with [`index_columns`](Self::index_columns),
+    /// and from the rows otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use contracts_validator::{DataRow, DataSet, DataValue};
+    ///
+    /// let mut row = DataRow::new();
+    /// row.insert("id".to_string(), DataValue::Int(7));
+    /// let dataset = DataSet::from_rows(vec![row, DataRow::new()]);
+    ///
+    /// let ids: Vec<_> = dataset.column("id").collect();
+    /// assert_eq!(ids, vec![&DataValue::Int(7), &DataValue::Null]);
+    /// ```
+    pub fn column<'a>(&'a self, name: &'a str) -> ColumnValues<'a> {
+        let inner = match self.column_index.as_ref().and_then(|index| index.get(name)) {
+            Some(values) => ColumnSource::Indexed(values.iter()),
+            None => ColumnSource::Rows {
+                rows: self.rows.iter(),
+                name,
+            },
+        };
+        ColumnValues { inner }
+    }
+
+    /// Builds a column-major copy of the rows, used by [`column`](Self::column).
+    ///
+    /// Checks that read a handful of fields then touch contiguous values
+    /// instead of hashing into every row. The index doubles the memory held
+    /// by the dataset and is dropped when the rows change.
+    pub fn index_columns(&mut self) {
+        let mut index: HashMap<String, Vec<DataValue>> = HashMap::new();
+        for (row_idx, row) in self.rows.iter().enumerate() {
+            for (name, value) in row {
+                index
+                    .entry(name.clone())
+                    .or_insert_with(|| vec![DataValue::Null; row_idx])
+                    .push(value.clone());
+            }
+            for values in index.values_mut() {
+                if values.len() == row_idx {
+                    values.push(DataValue::Null);
+                }
+            }
+        }
+        self.column_index = Some(index);
+    }
+
+    /// Builds the column index and returns the dataset, see [`index_columns`](Self::index_columns).
+    pub fn with_column_index(mut self) -> Self {
+        self.index_columns();
+        self
+    }
+
+    /// Returns true if a column index has been built.
+    pub fn has_column_index(&self) -> bool {
+        self.column_index.is_some()
     }
 
     /// Returns the number of rows in the dataset.
@@ -157,8 +379,15 @@ impl DataSet {
         self.rows.iter()
     }
 
+    /// Returns the rows as a slice, for splitting them into chunks.
+    pub(crate) fn row_slice(&self) -> &[DataRow] {
+        &self.rows
+    }
+
     /// Returns a mutable iterator over the rows.
     pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut DataRow> {
+        self.column_stats = None;
+        self.column_index = None;
         self.rows.iter_mut()
     }
 
@@ -169,20 +398,123 @@ impl DataSet {
 
     /// Adds a row to the dataset.
     pub fn add_row(&mut self, row: DataRow) {
+        self.column_stats = None;
+        self.column_index = None;
         self.rows.push(row);
     }
 
     /// Takes a sample of rows from the dataset.
     ///
     /// If `size` is greater than the number of rows, returns all rows.
+    /// Column statistics and the column index are kept only in that case.
     pub fn sample(&self, size: usize) -> DataSet {
-        let sample_size = size.min(self.rows.len());
-        DataSet {
-            rows: self.rows.iter().take(sample_size).cloned().collect(),
+        if size >= self.rows.len() {
+            return self.clone();
         }
+        DataSet::from_rows(self.rows.iter().take(size).cloned().collect())
+    }
+
+    /// Takes a sample of rows chosen by `strategy`.
+    ///
+    /// Random and stratified samples keep the rows in their original order.
+    /// Stratifying without a column draws from all rows at random. If `size`
+    /// is greater than the number of rows, returns all rows, with their
+    /// column statistics and index.
+    pub fn sample_with(&self, size: usize, strategy: &SamplingStrategy) -> DataSet {
+        if size >= self.rows.len() {
+            return self.clone();
+        }
+        let positions = match strategy {
+            SamplingStrategy::Head => return self.sample(size),
+            SamplingStrategy::Random { seed }
+            | SamplingStrategy::Stratified { column: None, seed } => {
+                Sampler::new(*seed).positions(self.rows.len(), size)
+            }
+            SamplingStrategy::Stratified {
+                column: Some(column),
+                seed,
+            } => self.stratified_positions(column, size, *seed),
+        };
+        DataSet::from_rows(
+            positions
+                .into_iter()
+                .map(|i| self.rows[i].clone())
+                .collect(),
+        )
+    }
+
+    /// Draws `size` row positions across the values of `column`, each value
+    /// getting a share proportional to its row count.
+    fn stratified_positions(&self, column: &str, size: usize, seed: u64) -> Vec<usize> {
+        // Strata in the order their first row appears; missing values form one
+        let mut strata: Vec<Vec<usize>> = Vec::new();
+        let mut index: HashMap<String, usize> = HashMap::new();
+        for (i, row) in self.rows.iter().enumerate() {
+            let key = row
+                .get(column)
+                .map_or_else(|| "NULL".to_string(), value_key);
+            let slot = *index.entry(key).or_insert_with(|| {
+                strata.push(Vec::new());
+                strata.len() - 1
+            });
+            strata[slot].push(i);
+        }
+
+        let counts: Vec<u64> = strata.iter().map(|rows| rows.len() as u64).collect();
+        let mut sampler = Sampler::new(seed);
+        let mut positions: Vec<usize> = strata
+            .iter()
+            .zip(proportional_quotas(&counts, size))
+            .flat_map(|(rows, quota)| {
+                sampler
+                    .positions(rows.len(), quota)
+                    .into_iter()
+                    .map(|i| rows[i])
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        positions.sort_unstable();
+        positions
     }
 }
 
+/// Iterator over the values of one column, returned by [`DataSet::column`].
+#[derive(Debug, Clone)]
+pub struct ColumnValues<'a> {
+    inner: ColumnSource<'a>,
+}
+
+#[derive(Debug, Clone)]
+enum ColumnSource<'a> {
+    Indexed(std::slice::Iter<'a, DataValue>),
+    Rows {
+        rows: std::slice::Iter<'a, DataRow>,
+        name: &'a str,
+    },
+}
+
+impl<'a> Iterator for ColumnValues<'a> {
+    type Item = &'a DataValue;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            ColumnSource::Indexed(values) => values.next(),
+            ColumnSource::Rows { rows, name } => {
+                rows.next().map(|row| row.get(*name).unwrap_or(&MISSING))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            ColumnSource::Indexed(values) => values.size_hint(),
+            ColumnSource::Rows { rows, .. } => rows.size_hint(),
+        }
+    }
+}
+
+impl ExactSizeIterator for ColumnValues<'_> {}
+
 impl Default for DataSet {
     fn default() -> Self {
         Self::empty()
@@ -191,9 +523,7 @@ impl Default for DataSet {
 
 impl FromIterator<DataRow> for DataSet {
     fn from_iter<T: IntoIterator<Item = DataRow>>(iter: T) -> Self {
-        Self {
-            rows: iter.into_iter().collect(),
-        }
+        Self::from_rows(iter.into_iter().collect())
     }
 }
 
@@ -208,6 +538,43 @@ mod tests {
         assert_eq!(DataValue::Int(42).type_name(), "int64");
         assert_eq!(DataValue::Float(3.5).type_name(), "float64");
         assert_eq!(DataValue::Bool(true).type_name(), "boolean");
+        assert_eq!(DataValue::UInt(1).type_name(), "uint64");
+        assert_eq!(
+            DataValue::Decimal { value: 1, scale: 2 }.type_name(),
+            "decimal"
+        );
+    }
+
+    #[test]
+    fn test_date_and_time_values() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let time = NaiveTime::from_hms_milli_opt(13, 45, 0, 250).unwrap();
+
+        assert_eq!(DataValue::from(date).type_name(), "date");
+        assert_eq!(DataValue::from(time).type_name(), "time");
+        assert_eq!(DataValue::Date(date).as_date(), Some(date));
+        assert_eq!(DataValue::Time(time).as_time(), Some(time));
+        assert_eq!(DataValue::Date(date).as_timestamp(), None);
+    }
+
+    #[test]
+    fn test_large_numeric_conversions() {
+        let big = DataValue::UInt(u64::MAX);
+        assert_eq!(big.as_uint(), Some(u64::MAX));
+        assert_eq!(big.as_int(), None);
+        assert_eq!(DataValue::UInt(7).as_int(), Some(7));
+        assert_eq!(DataValue::Int(-1).as_uint(), None);
+
+        let price = DataValue::Decimal {
+            value: 1999,
+            scale: 2,
+        };
+        assert_eq!(price.as_float(), Some(19.99));
+        assert_eq!(
+            DataValue::decimal_to_string(i128::MAX, 10),
+            "17014118346046923173168730371.5884105727"
+        );
+        assert_eq!(DataValue::decimal_to_string(0, -3), "0");
     }
 
     #[test]
@@ -254,4 +621,138 @@ mod tests {
         let large_sample = dataset.sample(100);
         assert_eq!(large_sample.len(), 10); // Only has 10 rows
     }
+
+    #[test]
+    fn test_dataset_sample_with_strategy() {
+        // 80 rows in region "eu", then 20 in region "us"
+        let rows: Vec<DataRow> = (0..100)
+            .map(|i| {
+                let region = if i < 80 { "eu" } else { "us" };
+                HashMap::from([
+                    ("id".to_string(), DataValue::Int(i)),
+                    ("region".to_string(), DataValue::String(region.to_string())),
+                ])
+            })
+            .collect();
+        let dataset = DataSet::from_rows(rows);
+        let ids = |sample: &DataSet| -> Vec<i64> {
+            sample
+                .rows()
+                .map(|row| match row["id"] {
+                    DataValue::Int(id) => id,
+                    _ => unreachable!(),
+                })
+                .collect()
+        };
+
+        let head = dataset.sample_with(10, &SamplingStrategy::Head);
+        assert_eq!(ids(&head), (0..10).collect::<Vec<_>>());
+
+        let random = SamplingStrategy::Random { seed: 1 };
+        let sample = ids(&dataset.sample_with(10, &random));
+        assert_eq!(sample.len(), 10);
+        assert!(sample.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_ne!(sample, ids(&head));
+        assert_eq!(sample, ids(&dataset.sample_with(10, &random)));
+
+        let stratified = SamplingStrategy::Stratified {
+            column: Some("region".to_string()),
+            seed: 1,
+        };
+        let sample = ids(&dataset.sample_with(10, &stratified));
+        assert_eq!(sample.iter().filter(|&&id| id < 80).count(), 8);
+        assert_eq!(sample.iter().filter(|&&id| id >= 80).count(), 2);
+    }
+
+    #[test]
+    fn test_column_stats_follow_rows() {
+        let rows: Vec<DataRow> = (0..3)
+            .map(|i| HashMap::from([("id".to_string(), DataValue::Int(i))]))
+            .collect();
+        let dataset = DataSet::from_rows(rows);
+        let stats = dataset.compute_column_stats();
+        assert_eq!(stats["id"].distinct_count, 3);
+
+        let dataset = dataset.with_column_stats(stats);
+        assert!(dataset.sample(10).column_stats().is_some());
+        assert!(dataset.sample(2).column_stats().is_none());
+
+        let mut changed = dataset.clone();
+        changed.add_row(DataRow::new());
+        assert!(changed.column_stats().is_none());
+    }
+
+    #[test]
+    fn test_column_reads_rows_and_index_alike() {
+        let rows = vec![
+            HashMap::from([("a".to_string(), DataValue::Int(1))]),
+            HashMap::from([("b".to_string(), DataValue::Bool(true))]),
+            HashMap::from([
+                ("a".to_string(), DataValue::Int(3)),
+                ("b".to_string(), DataValue::Bool(false)),
+            ]),
+        ];
+        let dataset = DataSet::from_rows(rows);
+        let from_rows: Vec<DataValue> = dataset.column("b").cloned().collect();
+
+        let mut indexed = dataset.clone().with_column_index();
+        assert!(indexed.has_column_index());
+        assert_eq!(indexed.column("b").cloned().collect::<Vec<_>>(), from_rows);
+        assert_eq!(
+            indexed.column("a").cloned().collect::<Vec<_>>(),
+            vec![DataValue::Int(1), DataValue::Null, DataValue::Int(3)]
+        );
+        assert_eq!(indexed.column("missing").len(), 3);
+        assert!(indexed.column("missing").all(DataValue::is_null));
+
+        indexed.add_row(DataRow::new());
+        assert!(!indexed.has_column_index());
+        assert_eq!(indexed.column("a").len(), 4);
+    }
+
+    #[test]
+    fn test_json_round_trip_keeps_variants() {
+        let row: DataRow = HashMap::from([
+            ("null".to_string(), DataValue::Null),
+            ("uint".to_string(), DataValue::UInt(u64::MAX)),
+            (
+                "decimal".to_string(),
+                DataValue::Decimal {
+                    value: i128::MAX,
+                    scale: 4,
+                },
+            ),
+            (
+                "ts".to_string(),
+                DataValue::Timestamp("2024-01-31T12:00:00Z".to_string()),
+            ),
+            (
+                "date".to_string(),
+                DataValue::Date(NaiveDate::from_ymd_opt(2024, 1, 31).unwrap()),
+            ),
+            (
+                "time".to_string(),
+                DataValue::Time(NaiveTime::from_hms_opt(8, 30, 0).unwrap()),
+            ),
+            (
+                "list".to_string(),
+                DataValue::List(vec![DataValue::Int(1), DataValue::Bool(false)]),
+            ),
+            (
+                "map".to_string(),
+                DataValue::Map(HashMap::from([("k".to_string(), DataValue::Float(0.5))])),
+            ),
+        ]);
+        let dataset = DataSet::from_rows(vec![row.clone()]).with_column_index();
+
+        let json = serde_json::to_string(&dataset).unwrap();
+        let parsed: DataSet = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed.get_row(0), Some(&row));
+        assert!(!parsed.has_column_index());
+        assert_eq!(
+            serde_json::to_value(DataValue::Int(3)).unwrap(),
+            serde_json::json!({"int": 3})
+        );
+    }
 }
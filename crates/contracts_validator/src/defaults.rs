@@ -0,0 +1,252 @@
+//! Field default values.
+//!
+//! A field's `default` is the value producers write when they leave the
+//! field out. With [`ValidationContext::fill_defaults`] set, row-based
+//! validation fills missing fields with it before any check runs, so records
+//! are validated the way a reader resolving defaults would see them.
+//!
+//! [`ValidationContext::fill_defaults`]: contracts_core::ValidationContext::fill_defaults
+
+use crate::{DataSet, DataValue, SchemaValidator, ValidationError};
+use chrono::{NaiveDate, NaiveTime};
+use contracts_core::{Contract, DataType, Field, PrimitiveType};
+use std::collections::HashMap;
+
+/// Returns the default of `field` as a value of its type, or `None` when the
+/// field has no default.
+///
+/// # Errors
+///
+/// Fails when the default is not a value of the field's type, or is null on
+/// a field that does not allow nulls.
+///
+/// # Example
+///
+/// ```rust
+/// use contracts_core::FieldBuilder;
+/// use contracts_validator::{DataValue, default_value};
+///
+/// let field = FieldBuilder::new("retries", "int32").default_value(3).build();
+/// assert_eq!(default_value(&field).unwrap(), Some(DataValue::Int(3)));
+///
+/// let field = FieldBuilder::new("retries", "int32").default_value("three").build();
+/// assert!(default_value(&field).is_err());
+/// ```
+pub fn default_value(field: &Field) -> Result<Option<DataValue>, ValidationError> {
+    let Some(default) = &field.default else {
+        return Ok(None);
+    };
+    if default.is_null() && !field.allows_null() {
+        return Err(ValidationError::invalid_default(
+            &field.name,
+            "null default on a field that does not allow nulls",
+        ));
+    }
+    json_to_value(default, &field.field_type)
+        .filter(|value| value.is_null() || SchemaValidator::type_matches(&field.field_type, value))
+        .map(Some)
+        .ok_or_else(|| {
+            ValidationError::invalid_default(
+                &field.name,
+                format!("{} is not a {} value", default, field.field_type),
+            )
+        })
+}
+
+/// Fills the fields missing from each row of `dataset` with their defaults.
+///
+/// Returns `None` when no field declares a default, so callers can keep the
+/// original dataset.
+pub(crate) fn fill_defaults(
+    dataset: &DataSet,
+    contract: &Contract,
+) -> Result<Option<DataSet>, ValidationError> {
+    let mut defaults = Vec::new();
+    for field in &contract.schema.fields {
+        if let Some(value) = default_value(field)? {
+            defaults.push((field.name.as_str(), value));
+        }
+    }
+    if defaults.is_empty() {
+        return Ok(None);
+    }
+
+    let rows = dataset
+        .rows()
+        .map(|row| {
+            let mut row = row.clone();
+            for (name, value) in &defaults {
+                if !row.contains_key(*name) {
+                    row.insert(name.to_string(), value.clone());
+                }
+            }
+            row
+        })
+        .collect();
+    Ok(Some(DataSet::from_rows(rows)))
+}
+
+/// Converts a JSON default to a value of `data_type`, or `None` if it is not
+/// one.
+///
+/// Decimal, UUID and binary defaults are kept as written, since the schema
+/// validator accepts any value for those types.
+fn json_to_value(json: &serde_json::Value, data_type: &DataType) -> Option<DataValue> {
+    use serde_json::Value;
+
+    if json.is_null() {
+        return Some(DataValue::Null);
+    }
+    match data_type {
+        DataType::Primitive(primitive) => match (primitive, json) {
+            (PrimitiveType::String, Value::String(s)) => Some(DataValue::String(s.clone())),
+            (PrimitiveType::Int32 | PrimitiveType::Int64, Value::Number(n)) => n
+                .as_i64()
+                .map(DataValue::Int)
+                .or_else(|| n.as_u64().map(DataValue::UInt)),
+            (PrimitiveType::Float32 | PrimitiveType::Float64, Value::Number(n)) => {
+                n.as_f64().map(DataValue::Float)
+            }
+            (PrimitiveType::Boolean, Value::Bool(b)) => Some(DataValue::Bool(*b)),
+            (PrimitiveType::Timestamp, Value::String(s)) => Some(DataValue::Timestamp(s.clone())),
+            (PrimitiveType::Date, Value::String(s)) => NaiveDate::parse_from_str(s, "%Y-%m-%d")
+                .ok()
+                .map(DataValue::Date),
+            (PrimitiveType::Time, Value::String(s)) => {
+                s.parse::<NaiveTime>().ok().map(DataValue::Time)
+            }
+            (PrimitiveType::Decimal, Value::Number(n)) => n.as_f64().map(DataValue::Float),
+            (
+                PrimitiveType::Decimal | PrimitiveType::Uuid | PrimitiveType::Binary,
+                Value::String(s),
+            ) => Some(DataValue::String(s.clone())),
+            _ => None,
+        },
+        DataType::List { element_type, .. } => match json {
+            Value::Array(items) => items
+                .iter()
+                .map(|item| json_to_value(item, element_type))
+                .collect::<Option<Vec<_>>>()
+                .map(DataValue::List),
+            _ => None,
+        },
+        DataType::Map { value_type, .. } => match json {
+            Value::Object(entries) => entries
+                .iter()
+                .map(|(key, value)| Some((key.clone(), json_to_value(value, value_type)?)))
+                .collect::<Option<HashMap<_, _>>>()
+                .map(DataValue::Map),
+            _ => None,
+        },
+        DataType::Struct { fields } => match json {
+            Value::Object(entries) => {
+                if entries
+                    .keys()
+                    .any(|key| !fields.iter().any(|f| &f.name == key))
+                {
+                    return None;
+                }
+                fields
+                    .iter()
+                    .filter_map(|field| {
+                        let value = entries.get(&field.name)?;
+                        Some(
+                            json_to_value(value, &field.data_type).map(|v| (field.name.clone(), v)),
+                        )
+                    })
+                    .collect::<Option<HashMap<_, _>>>()
+                    .map(DataValue::Map)
+            }
+            _ => None,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use contracts_core::{ContractBuilder, DataFormat, FieldBuilder};
+    use serde_json::json;
+
+    #[test]
+    fn test_default_value_converts_by_type() {
+        let cases = [
+            ("string", json!("IT"), DataValue::String("IT".to_string())),
+            ("int64", json!(-1), DataValue::Int(-1)),
+            ("float64", json!(1), DataValue::Float(1.0)),
+            ("boolean", json!(false), DataValue::Bool(false)),
+            (
+                "date",
+                json!("2026-01-31"),
+                DataValue::Date(NaiveDate::from_ymd_opt(2026, 1, 31).unwrap()),
+            ),
+            (
+                "list<string>",
+                json!(["a"]),
+                DataValue::List(vec![DataValue::String("a".to_string())]),
+            ),
+        ];
+        for (field_type, default, expected) in cases {
+            let field = FieldBuilder::new("f", field_type)
+                .default_value(default)
+                .build();
+            assert_eq!(default_value(&field).unwrap(), Some(expected));
+        }
+    }
+
+    #[test]
+    fn test_default_value_rejects_mismatches() {
+        let field = FieldBuilder::new("f", "boolean")
+            .default_value("yes")
+            .build();
+        assert!(matches!(
+            default_value(&field),
+            Err(ValidationError::InvalidDefault { .. })
+        ));
+
+        let field = FieldBuilder::new("f", "date")
+            .default_value("31/01/2026")
+            .build();
+        assert!(default_value(&field).is_err());
+
+        let field = FieldBuilder::new("f", "string")
+            .nullable(false)
+            .default_value(serde_json::Value::Null)
+            .build();
+        assert!(default_value(&field).is_err());
+
+        let field = FieldBuilder::new("f", "string").build();
+        assert_eq!(default_value(&field).unwrap(), None);
+    }
+
+    #[test]
+    fn test_fill_defaults_only_fills_missing_fields() {
+        let contract = ContractBuilder::new("test", "owner")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("id", "int64").build())
+            .field(
+                FieldBuilder::new("country", "string")
+                    .default_value("IT")
+                    .build(),
+            )
+            .build();
+        let dataset = DataSet::from_rows(vec![
+            HashMap::from([("id".to_string(), DataValue::Int(1))]),
+            HashMap::from([
+                ("id".to_string(), DataValue::Int(2)),
+                ("country".to_string(), DataValue::Null),
+            ]),
+        ]);
+
+        let filled = fill_defaults(&dataset, &contract).unwrap().unwrap();
+        assert_eq!(
+            filled.get_row(0).unwrap().get("country"),
+            Some(&DataValue::String("IT".to_string()))
+        );
+        assert_eq!(
+            filled.get_row(1).unwrap().get("country"),
+            Some(&DataValue::Null)
+        );
+    }
+}
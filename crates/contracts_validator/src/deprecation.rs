@@ -0,0 +1,149 @@
+//! Deprecated fields past their removal date.
+//!
+//! Once a deprecated field's `remove_after` date has passed, producers are
+//! expected to stop writing it. Validation warns about every expired field
+//! that still holds non-null values, so the field can be dropped without
+//! surprising anyone who still reads it.
+
+use crate::datafusion_engine::count_query;
+use crate::{DataSet, DataValue};
+use chrono::{NaiveDate, Utc};
+use contracts_core::{CheckKind, ColumnStats, Contract, Field, Severity, Violation, codes};
+use datafusion::prelude::SessionContext;
+use std::collections::BTreeMap;
+
+/// Warns about expired fields populated in rows of `dataset`.
+pub(crate) fn check_rows(contract: &Contract, dataset: &DataSet) -> Vec<Violation> {
+    expired_fields(contract, today())
+        .filter_map(|field| {
+            let populated = dataset
+                .column(&field.name)
+                .filter(|value| !matches!(value, DataValue::Null))
+                .count();
+            (populated > 0).then(|| populated_warning(field, populated))
+        })
+        .collect()
+}
+
+/// Warns about expired fields populated in `rows` rows described by `stats`.
+///
+/// Columns without stats were never seen, so they are not populated.
+pub(crate) fn check_stats(
+    contract: &Contract,
+    rows: usize,
+    stats: &BTreeMap<String, ColumnStats>,
+) -> Vec<Violation> {
+    expired_fields(contract, today())
+        .filter_map(|field| {
+            let populated = stats
+                .get(&field.name)
+                .map_or(0, |column| rows.saturating_sub(column.null_count));
+            (populated > 0).then(|| populated_warning(field, populated))
+        })
+        .collect()
+}
+
+/// Warns about expired fields populated in the `data` table of `ctx`.
+///
+/// Columns missing from the table are not populated, so they are skipped.
+pub(crate) async fn check_context(contract: &Contract, ctx: &SessionContext) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    for field in expired_fields(contract, today()) {
+        let sql = format!(
+            "SELECT COUNT(*) AS cnt FROM data WHERE \"{}\" IS NOT NULL",
+            field.name
+        );
+        if let Ok(populated) = count_query(ctx, &sql).await
+            && populated > 0
+        {
+            violations.push(populated_warning(field, populated as usize));
+        }
+    }
+    violations
+}
+
+fn today() -> NaiveDate {
+    Utc::now().date_naive()
+}
+
+fn expired_fields(contract: &Contract, today: NaiveDate) -> impl Iterator<Item = &Field> {
+    contract
+        .schema
+        .fields
+        .iter()
+        .filter(move |field| field.is_expired(today))
+}
+
+fn populated_warning(field: &Field, rows: usize) -> Violation {
+    let deprecation = field.deprecated.as_ref();
+    let mut message = format!(
+        "Field '{}' is still populated in {} row(s) after its removal date {}",
+        field.name,
+        rows,
+        deprecation
+            .and_then(|d| d.remove_after)
+            .map_or_else(String::new, |date| date.to_string())
+    );
+    if let Some(replacement) = deprecation.and_then(|d| d.replacement.as_deref()) {
+        message.push_str(&format!("; write '{}' instead", replacement));
+    }
+    Violation::new(
+        &codes::DEPRECATED_FIELD_POPULATED,
+        CheckKind::Schema,
+        message,
+    )
+    .with_field(&field.name)
+    .with_severity(Severity::Warning)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use contracts_core::{ContractBuilder, DataFormat, Deprecation, FieldBuilder};
+    use std::collections::HashMap;
+
+    fn deprecated(name: &str, remove_after: NaiveDate) -> Field {
+        FieldBuilder::new(name, "string")
+            .deprecated(Deprecation {
+                since: Some("1.0.0".to_string()),
+                remove_after: Some(remove_after),
+                replacement: Some("id".to_string()),
+            })
+            .build()
+    }
+
+    #[test]
+    fn test_check_rows_warns_about_populated_expired_fields() {
+        let past = NaiveDate::from_ymd_opt(2020, 1, 31).unwrap();
+        let future = NaiveDate::from_ymd_opt(2999, 1, 31).unwrap();
+        let contract = ContractBuilder::new("test", "owner")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("id", "string").build())
+            .field(deprecated("legacy_id", past))
+            .field(deprecated("old_code", future))
+            .field(deprecated("unused", past))
+            .build();
+        let row = |legacy: DataValue| {
+            HashMap::from([
+                ("id".to_string(), DataValue::String("a".to_string())),
+                ("legacy_id".to_string(), legacy),
+                ("old_code".to_string(), DataValue::String("x".to_string())),
+                ("unused".to_string(), DataValue::Null),
+            ])
+        };
+        let dataset = DataSet::from_rows(vec![
+            row(DataValue::String("a".to_string())),
+            row(DataValue::Null),
+        ]);
+
+        let violations = check_rows(&contract, &dataset);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].field.as_deref(), Some("legacy_id"));
+        assert_eq!(violations[0].severity, Severity::Warning);
+        assert_eq!(
+            violations[0].message,
+            "Field 'legacy_id' is still populated in 1 row(s) after its removal date 2020-01-31; write 'id' instead"
+        );
+    }
+}
@@ -0,0 +1,403 @@
+//! Heuristic checks of contract and field descriptions.
+//!
+//! A description is only useful if it says something the name does not.
+//! These rules flag the usual ways it fails to: placeholders left in place
+//! (`TBD`), the field name repeated as prose, text too short to explain
+//! anything, and numeric fields that never say what unit they are in. They
+//! are opt-in, through [`ContractLinter::with_description_rules`].
+//!
+//! [`ContractLinter::with_description_rules`]: crate::ContractLinter::with_description_rules
+
+use crate::LintFinding;
+use contracts_core::{Contract, DataType, Field, PrimitiveType};
+
+/// Identifiers of the rules in this pack.
+pub const DESCRIPTION_RULES: &[&str] = &[
+    "placeholder-description",
+    "name-as-description",
+    "short-description",
+    "missing-unit",
+];
+
+/// Descriptions reported as placeholders by default, compared case-insensitively.
+pub const DEFAULT_PLACEHOLDERS: &[&str] = &[
+    "tbd",
+    "tba",
+    "todo",
+    "fixme",
+    "test",
+    "xxx",
+    "n/a",
+    "na",
+    "none",
+    "null",
+    "-",
+    "?",
+    "description",
+    "no description",
+    "placeholder",
+];
+
+/// Words accepted as units of numeric fields by default.
+///
+/// Besides physical units, the list holds words that make a number
+/// self-explanatory without one, such as `count` or `ratio`.
+pub const DEFAULT_UNITS: &[&str] = &[
+    // Time
+    "ns",
+    "us",
+    "ms",
+    "s",
+    "sec",
+    "secs",
+    "second",
+    "seconds",
+    "millisecond",
+    "milliseconds",
+    "microsecond",
+    "microseconds",
+    "nanosecond",
+    "nanoseconds",
+    "min",
+    "mins",
+    "minute",
+    "minutes",
+    "h",
+    "hr",
+    "hrs",
+    "hour",
+    "hours",
+    "day",
+    "days",
+    "week",
+    "weeks",
+    "month",
+    "months",
+    "year",
+    "years",
+    "epoch",
+    "unix",
+    // Data size
+    "bit",
+    "bits",
+    "byte",
+    "bytes",
+    "kb",
+    "kib",
+    "mb",
+    "mib",
+    "gb",
+    "gib",
+    "tb",
+    "tib",
+    // Money
+    "usd",
+    "eur",
+    "gbp",
+    "jpy",
+    "chf",
+    "cent",
+    "cents",
+    "currency",
+    // Length, mass, temperature
+    "mm",
+    "cm",
+    "m",
+    "km",
+    "meter",
+    "meters",
+    "metre",
+    "metres",
+    "mile",
+    "miles",
+    "g",
+    "kg",
+    "gram",
+    "grams",
+    "kilogram",
+    "kilograms",
+    "lb",
+    "lbs",
+    "celsius",
+    "fahrenheit",
+    "kelvin",
+    // Dimensionless
+    "%",
+    "percent",
+    "percentage",
+    "pct",
+    "ratio",
+    "fraction",
+    "proportion",
+    "rate",
+    "count",
+    "number",
+    "score",
+    "rank",
+    "index",
+];
+
+/// Rules applied to descriptions.
+///
+/// # Example
+///
+/// ```rust
+/// use contracts_core::{ContractBuilder, DataFormat, FieldBuilder};
+/// use contracts_validator::{ContractLinter, DescriptionRules};
+///
+/// let contract = ContractBuilder::new("orders", "sales")
+///     .description("One row per order placed in the web shop")
+///     .location("s3://lake/orders")
+///     .format(DataFormat::Iceberg)
+///     .field(FieldBuilder::new("order_id", "string").description("Order ID").build())
+///     .field(
+///         FieldBuilder::new("amount", "decimal")
+///             .description("Amount paid for the order")
+///             .build(),
+///     )
+///     .build();
+///
+/// let findings = ContractLinter::new()
+///     .with_description_rules(DescriptionRules::default())
+///     .lint(&contract);
+/// let rules: Vec<_> = findings.iter().map(|f| f.rule).collect();
+/// assert_eq!(rules, vec!["name-as-description", "missing-unit"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DescriptionRules {
+    /// Shortest accepted description, in characters
+    pub min_length: usize,
+
+    /// Descriptions that are placeholders, compared case-insensitively and
+    /// ignoring surrounding punctuation
+    pub placeholders: Vec<String>,
+
+    /// Words that state the unit of a numeric field
+    pub units: Vec<String>,
+
+    /// Whether descriptions of numeric fields must state a unit
+    pub require_units: bool,
+}
+
+impl Default for DescriptionRules {
+    fn default() -> Self {
+        Self {
+            min_length: 10,
+            placeholders: DEFAULT_PLACEHOLDERS.iter().map(|s| s.to_string()).collect(),
+            units: DEFAULT_UNITS.iter().map(|s| s.to_string()).collect(),
+            require_units: true,
+        }
+    }
+}
+
+impl DescriptionRules {
+    /// Adds a warning for each weak description of `contract`.
+    ///
+    /// Missing descriptions are left to the `missing-description` rule, and
+    /// a description gets at most one of the placeholder, name and length
+    /// findings.
+    pub(crate) fn lint(&self, contract: &Contract, findings: &mut Vec<LintFinding>) {
+        if let Some(description) = &contract.description {
+            self.lint_text(&contract.name, description, "description", findings);
+        }
+
+        for field in &contract.schema.fields {
+            let Some(description) = &field.description else {
+                continue;
+            };
+            let path = format!("schema.fields.{}.description", field.name);
+            self.lint_text(&field.name, description, &path, findings);
+
+            if self.require_units && needs_unit(field) && !self.states_unit(field, description) {
+                findings.push(LintFinding::warning(
+                    "missing-unit",
+                    path,
+                    format!(
+                        "Description of numeric field '{}' does not state its unit",
+                        field.name
+                    ),
+                ));
+            }
+        }
+    }
+
+    fn lint_text(&self, name: &str, text: &str, path: &str, findings: &mut Vec<LintFinding>) {
+        let trimmed = text.trim();
+        if trimmed.is_empty() {
+            return;
+        }
+
+        let bare = trimmed
+            .trim_matches(|c: char| c.is_ascii_punctuation() && !matches!(c, '-' | '?'))
+            .to_lowercase();
+        if self
+            .placeholders
+            .iter()
+            .any(|placeholder| placeholder.to_lowercase() == bare)
+        {
+            findings.push(LintFinding::warning(
+                "placeholder-description",
+                path,
+                format!("Description '{}' of '{}' is a placeholder", trimmed, name),
+            ));
+        } else if letters(trimmed) == letters(name) {
+            findings.push(LintFinding::warning(
+                "name-as-description",
+                path,
+                format!("Description of '{}' only repeats its name", name),
+            ));
+        } else if trimmed.chars().count() < self.min_length {
+            findings.push(LintFinding::warning(
+                "short-description",
+                path,
+                format!(
+                    "Description of '{}' is shorter than {} characters",
+                    name, self.min_length
+                ),
+            ));
+        }
+    }
+
+    /// Whether the description or the name of `field` contains a unit word.
+    fn states_unit(&self, field: &Field, description: &str) -> bool {
+        let description = description.to_lowercase();
+        words(&description)
+            .chain(field.name.to_lowercase().split('_').map(str::to_string))
+            .any(|word| {
+                self.units
+                    .iter()
+                    .any(|unit| unit.eq_ignore_ascii_case(&word))
+            })
+            || (self.units.iter().any(|unit| unit == "%") && description.contains('%'))
+    }
+}
+
+/// Whether `field` holds a quantity, as opposed to an identifier.
+fn needs_unit(field: &Field) -> bool {
+    let numeric = matches!(
+        field.field_type,
+        DataType::Primitive(
+            PrimitiveType::Int32
+                | PrimitiveType::Int64
+                | PrimitiveType::Float32
+                | PrimitiveType::Float64
+                | PrimitiveType::Decimal
+        )
+    );
+    let name = field.name.to_lowercase();
+    numeric && name != "id" && !name.ends_with("_id")
+}
+
+/// The lowercase letters and digits of `text`, so that `User ID` and
+/// `user_id` compare equal.
+fn letters(text: &str) -> String {
+    text.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// The words of `text`, split at anything but letters and digits.
+fn words(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use contracts_core::{ContractBuilder, DataFormat, FieldBuilder};
+
+    fn lint(
+        rules: &DescriptionRules,
+        fields: &[(&str, &str, &str)],
+    ) -> Vec<(&'static str, String)> {
+        let mut builder = ContractBuilder::new("events", "team")
+            .description("Events emitted by the web shop")
+            .location("s3://lake/events")
+            .format(DataFormat::Iceberg);
+        for (name, field_type, description) in fields {
+            builder = builder.field(
+                FieldBuilder::new(*name, *field_type)
+                    .description(*description)
+                    .build(),
+            );
+        }
+        let mut findings = Vec::new();
+        rules.lint(&builder.build(), &mut findings);
+        findings.into_iter().map(|f| (f.rule, f.path)).collect()
+    }
+
+    #[test]
+    fn test_weak_descriptions() {
+        let findings = lint(
+            &DescriptionRules::default(),
+            &[
+                ("status", "string", "TBD."),
+                ("user_id", "string", "User ID"),
+                ("country", "string", "ISO code"),
+                ("page", "string", "Page the event was emitted on"),
+            ],
+        );
+        assert_eq!(
+            findings,
+            vec![
+                (
+                    "placeholder-description",
+                    "schema.fields.status.description".to_string()
+                ),
+                (
+                    "name-as-description",
+                    "schema.fields.user_id.description".to_string()
+                ),
+                (
+                    "short-description",
+                    "schema.fields.country.description".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_numeric_fields_state_units() {
+        let rules = DescriptionRules::default();
+        let findings = lint(
+            &rules,
+            &[
+                (
+                    "latency",
+                    "int64",
+                    "Time until the first response was received",
+                ),
+                (
+                    "latency_ms",
+                    "int64",
+                    "Time until the first response arrived",
+                ),
+                ("discount", "float64", "Share of the list price, in %"),
+                ("items", "int32", "Number of items in the cart"),
+                ("session_id", "int64", "Session the event belongs to"),
+            ],
+        );
+        assert_eq!(
+            findings,
+            vec![(
+                "missing-unit",
+                "schema.fields.latency.description".to_string()
+            )]
+        );
+
+        let relaxed = DescriptionRules {
+            require_units: false,
+            ..rules
+        };
+        assert!(
+            lint(
+                &relaxed,
+                &[("latency", "int64", "Time until the first byte")]
+            )
+            .is_empty()
+        );
+    }
+}
@@ -3,16 +3,33 @@
 //! This module provides the main `DataValidator` that orchestrates all validation
 //! checks including schema, constraints, quality checks, and custom validations.
 
+use crate::datafusion_engine::count_query;
+use crate::findings::{
+    Findings, constraint_severity, custom_severity, freshness_severity, ml_violation,
+};
+use crate::parallel::RowPool;
+use crate::sample_size::{self, Withheld};
 use crate::{
-    ConstraintValidator, CustomValidator, DataFusionEngine, DataSet, MlValidator, QualityValidator,
-    SchemaValidator,
+    ConstraintValidator, ContractLinter, CustomValidator, DataFusionEngine, DataRow, DataSet,
+    LintSeverity, MlValidator, QualityValidator, SchemaValidator, Stage, StreamingValidator,
+    ValidationError,
 };
+use crate::{cdc, defaults, deprecation, parallel, row_filter, segments, time_window};
 use contracts_core::{
-    Contract, ContractValidator, ValidationContext, ValidationReport, ValidationStats,
+    CheckKind, ColumnStats, Contract, ContractValidator, EmptyDataPolicy, SegmentReport, Severity,
+    ValidationContext, ValidationReport, ValidationStats, Violation, codes,
 };
 use datafusion::prelude::SessionContext;
+use futures::{Stream, StreamExt};
+use std::collections::BTreeMap;
 use std::time::Instant;
 
+/// Rows a stream is validated in at a time by [`DataValidator::validate_stream`].
+const STREAM_CHUNK_ROWS: usize = 1024;
+
+/// Rows a fail-fast run validates at a time before it looks for an error.
+const FAIL_FAST_ROWS: usize = 1024;
+
 /// Main validation engine for data contracts.
 ///
 /// Orchestrates all validation checks and produces comprehensive validation reports.
@@ -48,6 +65,7 @@ pub struct DataValidator {
     custom_validator: CustomValidator,
     ml_validator: MlValidator,
     datafusion_engine: DataFusionEngine,
+    row_pool: RowPool,
 }
 
 impl DataValidator {
@@ -60,6 +78,7 @@ impl DataValidator {
             custom_validator: CustomValidator::new(),
             ml_validator: MlValidator::new(),
             datafusion_engine: DataFusionEngine::new(),
+            row_pool: RowPool::default(),
         }
     }
 
@@ -74,59 +93,89 @@ impl DataValidator {
         dataset: &DataSet,
         context: &ValidationContext,
     ) -> ValidationReport {
-        let dataset_to_validate = self.sample_dataset(dataset, context);
+        let events = cdc::unwrap_events(contract, dataset, context.strict);
+        let dataset = events.as_ref().map_or(dataset, |(images, _)| images);
+        let dataset_to_validate = match self.sample_dataset(dataset, contract, context) {
+            Ok(dataset) => dataset,
+            Err(e) => return ValidationReport::failure(e.to_violation()),
+        };
+        let mut report = self
+            .validate_sampled_async(contract, &dataset_to_validate, context)
+            .await;
+        for violation in events.into_iter().flat_map(|(_, violations)| violations) {
+            report.add_violation(violation);
+        }
+
+        if !failed_fast(context, &report)
+            && let Some(column) = segment_column(contract, context, &mut report)
+        {
+            let (groups, truncated) = segments::split_dataset(&dataset_to_validate, column);
+            let mut reports = Vec::new();
+            for (value, rows) in groups {
+                let segment = self.validate_sampled_async(contract, &rows, context).await;
+                reports.push(segment_report(column, value, segment));
+            }
+            segments::attach(&mut report, column, reports, truncated, context.strict);
+        }
+        report.apply_limits(context);
+        report
+    }
+
+    /// Runs the async data checks on rows that are already windowed and sampled.
+    async fn validate_sampled_async(
+        &mut self,
+        contract: &Contract,
+        dataset: &DataSet,
+        context: &ValidationContext,
+    ) -> ValidationReport {
+        let withheld = withhold_for(contract, context, dataset.len());
+        let contract = withheld.as_ref().map_or(contract, |w| &w.contract);
         let mut report = self
             .datafusion_engine
-            .validate(contract, &dataset_to_validate, context)
+            .validate(contract, dataset, context)
             .await;
+        for violation in deprecation::check_rows(contract, dataset) {
+            report.add_violation(violation);
+        }
 
         // NoOverlap and TemporalSplit still use row-by-row iteration.
         // The remaining ML checks (ClassBalance, FeatureDrift, TargetLeakage,
         // NullRateByGroup) are now handled via SQL aggregates inside
         // DataFusionEngine::validate().
-        self.apply_row_only_ml_checks(
-            contract,
-            &dataset_to_validate,
-            context,
-            &mut report.errors,
-            &mut report.warnings,
-        );
+        if !failed_fast(context, &report) {
+            self.apply_row_only_ml_checks(contract, dataset, context, &mut report);
+        }
 
         // Execute custom SQL checks with actual DataFusion execution
-        if !context.schema_only {
-            let freshness_errors = self
+        if !context.schema_only && !failed_fast(context, &report) {
+            let default = Severity::default_for(context.strict);
+            let freshness = freshness_severity(contract, default);
+            for error in self
                 .custom_validator
-                .validate_freshness_only(contract, &dataset_to_validate);
-            if context.strict {
-                report
-                    .errors
-                    .extend(freshness_errors.iter().map(|e| e.to_string()));
-            } else {
-                report
-                    .warnings
-                    .extend(freshness_errors.iter().map(|e| e.to_string()));
+                .validate_freshness_only(contract, dataset)
+            {
+                report.add_violation(error.to_violation().with_severity(freshness));
             }
 
             let custom_outcomes = self
                 .custom_validator
-                .validate_custom_checks_with_data(
-                    contract,
-                    &dataset_to_validate,
-                    &contract.schema.fields,
-                )
+                .validate_custom_checks_with_data(contract, dataset, &contract.schema.fields)
                 .await;
 
             for (severity, error) in custom_outcomes {
-                match severity.as_deref() {
-                    Some("error") => report.errors.push(error.to_string()),
-                    Some("warning") | Some("info") => report.warnings.push(error.to_string()),
-                    Some(_) => report.warnings.push(error.to_string()),
-                    None if context.strict => report.errors.push(error.to_string()),
-                    None => report.warnings.push(error.to_string()),
-                }
+                report.add_violation(
+                    error
+                        .to_violation()
+                        .with_severity(custom_severity(severity.as_deref(), default)),
+                );
             }
         }
 
+        apply_empty_data_policy(contract, context, &mut report);
+        if let Some(withheld) = withheld {
+            report.inconclusive = withheld.messages;
+        }
+        report.apply_limits(context);
         report.passed = report.errors.is_empty();
         report
     }
@@ -137,32 +186,90 @@ impl DataValidator {
     /// This skips the `DataSet` → Arrow conversion entirely.
     ///
     /// Most ML checks run via SQL aggregates. NoOverlap and TemporalSplit require
-    /// a `DataSet` and will be skipped with a warning when defined.
+    /// a `DataSet` and will be skipped with a warning when defined. Contracts
+    /// with `cdc` envelopes fail, since change events are unwrapped from rows.
     pub async fn validate_with_context(
         &mut self,
         contract: &Contract,
         ctx: &SessionContext,
         context: &ValidationContext,
     ) -> ValidationReport {
+        if contract.cdc.is_some() {
+            return ValidationReport::failure(
+                ValidationError::General(
+                    "CDC change events are unwrapped row by row and cannot be validated \
+                     from a SQL table"
+                        .to_string(),
+                )
+                .to_violation(),
+            );
+        }
+        let mut report = self.validate_registered(contract, ctx, context).await;
+
+        if !failed_fast(context, &report)
+            && let Some(column) = segment_column(contract, context, &mut report)
+        {
+            match segments::split_context(ctx, column).await {
+                Ok((groups, truncated)) => {
+                    let mut reports = Vec::new();
+                    for (value, segment_ctx) in groups {
+                        let segment = self
+                            .validate_registered(contract, &segment_ctx, context)
+                            .await;
+                        reports.push(segment_report(column, value, segment));
+                    }
+                    segments::attach(&mut report, column, reports, truncated, context.strict);
+                }
+                Err(e) => report.add_error(
+                    Violation::new(
+                        &codes::GENERAL,
+                        CheckKind::Execution,
+                        format!("Failed to segment by '{}': {}", column, e),
+                    )
+                    .with_field(column),
+                ),
+            }
+        }
+        report.apply_limits(context);
+        report
+    }
+
+    /// Runs the checks against the `data` table registered in `ctx`.
+    async fn validate_registered(
+        &mut self,
+        contract: &Contract,
+        ctx: &SessionContext,
+        context: &ValidationContext,
+    ) -> ValidationReport {
+        // Only pay for a row count when some check declares a minimum sample.
+        let withheld =
+            if !context.schema_only && sample_size::required_sample_size(contract).is_some() {
+                count_query(ctx, "SELECT COUNT(*) FROM data")
+                    .await
+                    .ok()
+                    .and_then(|records| withhold_for(contract, context, records as usize))
+            } else {
+                None
+            };
+        let contract = withheld.as_ref().map_or(contract, |w| &w.contract);
         let mut report = self
             .datafusion_engine
             .validate_with_context(contract, ctx, context)
             .await;
+        for violation in deprecation::check_context(contract, ctx).await {
+            report.add_violation(violation);
+        }
 
-        if !context.schema_only {
+        if !context.schema_only && !failed_fast(context, &report) {
             // Freshness check via SQL
-            let freshness_errors = self
+            let default = Severity::default_for(context.strict);
+            let freshness = freshness_severity(contract, default);
+            for error in self
                 .custom_validator
                 .validate_freshness_with_context(contract, ctx)
-                .await;
-            if context.strict {
-                report
-                    .errors
-                    .extend(freshness_errors.iter().map(|e| e.to_string()));
-            } else {
-                report
-                    .warnings
-                    .extend(freshness_errors.iter().map(|e| e.to_string()));
+                .await
+            {
+                report.add_violation(error.to_violation().with_severity(freshness));
             }
 
             // Custom SQL checks using the same context
@@ -172,13 +279,11 @@ impl DataValidator {
                 .await;
 
             for (severity, error) in custom_outcomes {
-                match severity.as_deref() {
-                    Some("error") => report.errors.push(error.to_string()),
-                    Some("warning") | Some("info") => report.warnings.push(error.to_string()),
-                    Some(_) => report.warnings.push(error.to_string()),
-                    None if context.strict => report.errors.push(error.to_string()),
-                    None => report.warnings.push(error.to_string()),
-                }
+                report.add_violation(
+                    error
+                        .to_violation()
+                        .with_severity(custom_severity(severity.as_deref(), default)),
+                );
             }
 
             // NoOverlap and TemporalSplit require row-level DataSet iteration
@@ -187,14 +292,20 @@ impl DataValidator {
                 && let Some(ref ml) = qc.ml_checks
                 && (ml.no_overlap.is_some() || ml.temporal_split.is_some())
             {
-                report.warnings.push(
+                report.add_warning(Violation::new(
+                    &codes::GENERAL,
+                    CheckKind::Ml,
                     "NoOverlap and TemporalSplit ML checks require the DataSet-based \
-                     path and were skipped in native DataFusion context mode."
-                        .to_string(),
-                );
+                     path and were skipped in native DataFusion context mode.",
+                ));
             }
         }
 
+        apply_empty_data_policy(contract, context, &mut report);
+        if let Some(withheld) = withheld {
+            report.inconclusive = withheld.messages;
+        }
+        report.apply_limits(context);
         report.passed = report.errors.is_empty();
         report
     }
@@ -202,7 +313,9 @@ impl DataValidator {
     /// Validates a contract against a dataset.
     ///
     /// This is the main validation entry point. It runs all validation checks
-    /// and returns a comprehensive report.
+    /// and returns a comprehensive report. When the contract declares `cdc`
+    /// envelopes, the rows are change events: their envelopes are checked and
+    /// their `after` images validated.
     ///
     /// # Arguments
     ///
@@ -218,64 +331,172 @@ impl DataValidator {
         contract: &Contract,
         dataset: &DataSet,
         context: &ValidationContext,
+    ) -> ValidationReport {
+        let events = cdc::unwrap_events(contract, dataset, context.strict);
+        let dataset = events.as_ref().map_or(dataset, |(images, _)| images);
+        let dataset_to_validate = match self.sample_dataset(dataset, contract, context) {
+            Ok(dataset) => dataset,
+            Err(e) => return ValidationReport::failure(e.to_violation()),
+        };
+        let mut report = self.validate_sampled(contract, &dataset_to_validate, context);
+        for violation in events.into_iter().flat_map(|(_, violations)| violations) {
+            report.add_violation(violation);
+        }
+
+        if !failed_fast(context, &report)
+            && let Some(column) = segment_column(contract, context, &mut report)
+        {
+            let (groups, truncated) = segments::split_dataset(&dataset_to_validate, column);
+            let reports = groups
+                .into_iter()
+                .map(|(value, rows)| {
+                    let segment = self.validate_sampled(contract, &rows, context);
+                    segment_report(column, value, segment)
+                })
+                .collect();
+            segments::attach(&mut report, column, reports, truncated, context.strict);
+        }
+        report.apply_limits(context);
+        report
+    }
+
+    /// Runs the schema checks on each row, in parallel when the context asks
+    /// for it. A fail-fast run checks the rows a chunk at a time and stops at
+    /// the first chunk with an error.
+    fn schema_errors(
+        &mut self,
+        contract: &Contract,
+        dataset: &DataSet,
+        context: &ValidationContext,
+    ) -> Vec<ValidationError> {
+        if context.fail_fast {
+            let mut errors = Vec::new();
+            for (index, rows) in dataset.row_slice().chunks(FAIL_FAST_ROWS).enumerate() {
+                errors.extend(self.schema_validator.validate_slice(
+                    contract,
+                    rows,
+                    index * FAIL_FAST_ROWS,
+                ));
+                if !errors.is_empty() {
+                    break;
+                }
+            }
+            return errors;
+        }
+        match parallel::threads(context) {
+            Some(threads) => {
+                let pool = self.row_pool.get(threads);
+                parallel::in_chunks(dataset.row_slice(), pool.as_deref(), |rows, first_row| {
+                    self.schema_validator
+                        .validate_slice(contract, rows, first_row)
+                })
+            }
+            None => self.schema_validator.validate(contract, dataset),
+        }
+    }
+
+    /// Runs the constraint checks, over the rows in parallel when the context
+    /// asks for it. A fail-fast run stops at the first chunk of rows with an
+    /// error-severity violation.
+    fn constraint_errors(
+        &mut self,
+        contract: &Contract,
+        dataset: &DataSet,
+        context: &ValidationContext,
+    ) -> Vec<ValidationError> {
+        if context.fail_fast {
+            return self.constraint_validator.validate_until(
+                contract,
+                dataset,
+                FAIL_FAST_ROWS,
+                |error| constraint_severity(contract, error.field()) == Severity::Error,
+            );
+        }
+        match parallel::threads(context) {
+            Some(threads) => {
+                let pool = self.row_pool.get(threads);
+                self.constraint_validator
+                    .validate_parallel(contract, dataset, pool.as_deref())
+            }
+            None => self.constraint_validator.validate(contract, dataset),
+        }
+    }
+
+    /// Runs the row-based checks on rows that are already windowed and sampled.
+    fn validate_sampled(
+        &mut self,
+        contract: &Contract,
+        dataset: &DataSet,
+        context: &ValidationContext,
     ) -> ValidationReport {
         let start = Instant::now();
-        let mut errors = Vec::new();
-        let mut warnings = Vec::new();
+        let mut findings = Findings::new(context);
 
-        let dataset_to_validate = self.sample_dataset(dataset, context);
+        let withheld = withhold_for(contract, context, dataset.len());
+        let contract = withheld.as_ref().map_or(contract, |w| &w.contract);
 
         // 1. Schema validation (always runs)
-        let schema_errors = self
-            .schema_validator
-            .validate(contract, &dataset_to_validate);
-        errors.extend(schema_errors.iter().map(|e| e.to_string()));
+        let schema_errors = self.schema_errors(contract, dataset, context);
+        findings.extend_errors(Severity::Error, &schema_errors);
+        for violation in deprecation::check_rows(contract, dataset) {
+            findings.push(violation);
+        }
 
         // If schema validation fails and strict mode, stop here
-        if context.strict && !errors.is_empty() {
-            return self.build_report(errors, warnings, contract, &dataset_to_validate, start);
+        if (context.strict && !findings.errors.is_empty()) || findings.fails_fast(context) {
+            return self.build_report(findings, contract, dataset, start);
         }
 
         // 2. Constraint validation
-        let constraint_errors = self
-            .constraint_validator
-            .validate(contract, &dataset_to_validate);
-        errors.extend(constraint_errors.iter().map(|e| e.to_string()));
+        for error in self.constraint_errors(contract, dataset, context) {
+            let severity = constraint_severity(contract, error.field());
+            findings.push(error.to_violation().with_severity(severity));
+        }
 
         // Stop if in schema-only mode
-        if context.schema_only {
-            return self.build_report(errors, warnings, contract, &dataset_to_validate, start);
+        if context.schema_only || findings.fails_fast(context) {
+            return self.build_report(findings, contract, dataset, start);
         }
 
-        // 3. Quality checks
-        let quality_errors = self
+        // 3. Quality checks, warnings in non-strict mode unless they set a severity
+        let default = Severity::default_for(context.strict);
+        for (severity, error) in self
             .quality_validator
-            .validate(contract, &dataset_to_validate);
-
-        // Quality check errors can be warnings in non-strict mode
-        if context.strict {
-            errors.extend(quality_errors.iter().map(|e| e.to_string()));
-        } else {
-            warnings.extend(quality_errors.iter().map(|e| e.to_string()));
+            .validate_with_severity(contract, dataset)
+        {
+            findings.push(
+                error
+                    .to_violation()
+                    .with_severity(severity.unwrap_or(default)),
+            );
         }
 
-        self.apply_custom_and_ml_checks(
-            contract,
-            &dataset_to_validate,
-            context,
-            &mut errors,
-            &mut warnings,
-        );
+        if !findings.fails_fast(context) {
+            self.apply_custom_and_ml_checks(contract, dataset, context, &mut findings);
+        }
 
-        self.build_report(errors, warnings, contract, &dataset_to_validate, start)
+        let mut report = self.build_report(findings, contract, dataset, start);
+        apply_empty_data_policy(contract, context, &mut report);
+        if let Some(withheld) = withheld {
+            report.inconclusive = withheld.messages;
+        }
+        report
     }
 
-    fn sample_dataset(&self, dataset: &DataSet, context: &ValidationContext) -> DataSet {
-        if let Some(sample_size) = context.sample_size {
-            dataset.sample(sample_size)
-        } else {
-            dataset.clone()
-        }
+    /// Selects the rows of `dataset` to validate, then applies the context's
+    /// sample size to them.
+    fn sample_dataset(
+        &self,
+        dataset: &DataSet,
+        contract: &Contract,
+        context: &ValidationContext,
+    ) -> Result<DataSet, ValidationError> {
+        let selected = select_rows(dataset, contract, context)?;
+        let dataset = selected.as_ref().unwrap_or(dataset);
+        Ok(match context.sample_size {
+            Some(sample_size) => dataset.sample_with(sample_size, &context.sampling),
+            None => dataset.clone(),
+        })
     }
 
     /// Runs only the ML checks that require row-level iteration (NoOverlap,
@@ -286,8 +507,7 @@ impl DataValidator {
         contract: &Contract,
         dataset: &DataSet,
         context: &ValidationContext,
-        errors: &mut Vec<String>,
-        warnings: &mut Vec<String>,
+        report: &mut ValidationReport,
     ) {
         if context.schema_only {
             return;
@@ -296,11 +516,9 @@ impl DataValidator {
         if let Some(ref qc) = contract.quality_checks
             && let Some(ref ml) = qc.ml_checks
         {
-            let ml_errors = self.ml_validator.validate_row_only(ml, dataset);
-            if context.strict {
-                errors.extend(ml_errors.iter().map(|e| e.to_string()));
-            } else {
-                warnings.extend(ml_errors.iter().map(|e| e.to_string()));
+            let severity = Severity::default_for(context.strict);
+            for error in self.ml_validator.validate_row_only(ml, dataset) {
+                report.add_violation(ml_violation(&error).with_severity(severity));
             }
         }
     }
@@ -310,117 +528,44 @@ impl DataValidator {
         contract: &Contract,
         dataset: &DataSet,
         context: &ValidationContext,
-        errors: &mut Vec<String>,
-        warnings: &mut Vec<String>,
+        findings: &mut Findings,
     ) {
         if context.schema_only {
             return;
         }
 
+        let default = Severity::default_for(context.strict);
         let freshness_errors = self
             .custom_validator
             .validate_freshness_only(contract, dataset);
-        if context.strict {
-            errors.extend(freshness_errors.iter().map(|e| e.to_string()));
-        } else {
-            warnings.extend(freshness_errors.iter().map(|e| e.to_string()));
-        }
+        findings.extend_errors(freshness_severity(contract, default), &freshness_errors);
 
         for (severity, error) in self.custom_validator.validate_custom_checks_only(contract) {
-            match severity.as_deref() {
-                Some("error") => errors.push(error.to_string()),
-                Some("warning") | Some("info") => warnings.push(error.to_string()),
-                Some(_) => warnings.push(error.to_string()),
-                None if context.strict => errors.push(error.to_string()),
-                None => warnings.push(error.to_string()),
-            }
+            findings.push(
+                error
+                    .to_violation()
+                    .with_severity(custom_severity(severity.as_deref(), default)),
+            );
         }
 
         if let Some(ref qc) = contract.quality_checks
             && let Some(ref ml) = qc.ml_checks
         {
             let ml_errors = self.ml_validator.validate(ml, dataset);
-            if context.strict {
-                errors.extend(ml_errors.iter().map(|e| e.to_string()));
-            } else {
-                warnings.extend(ml_errors.iter().map(|e| e.to_string()));
-            }
+            findings.extend(default, ml_errors.iter().map(ml_violation));
         }
     }
 
-    /// Builds a validation report from collected errors and warnings.
+    /// Builds a validation report from the collected findings.
     fn build_report(
         &self,
-        errors: Vec<String>,
-        warnings: Vec<String>,
+        findings: Findings,
         contract: &Contract,
         dataset: &DataSet,
         start: Instant,
     ) -> ValidationReport {
-        let duration_ms = start.elapsed().as_millis() as u64;
-
-        // Count fields checked (number of fields in contract schema)
-        let fields_checked = contract.schema.fields.len();
-
-        // Count constraints evaluated across all fields
-        let constraints_evaluated: usize = contract
-            .schema
-            .fields
-            .iter()
-            .map(|field| field.constraints.as_ref().map(|c| c.len()).unwrap_or(0))
-            .sum();
-
-        // Add quality checks count if present
-        let quality_checks_count = if let Some(ref quality) = contract.quality_checks {
-            let mut count = 0;
-            if quality.completeness.is_some() {
-                count += 1;
-            }
-            if quality.uniqueness.is_some() {
-                count += 1;
-            }
-            if quality.freshness.is_some() {
-                count += 1;
-            }
-            if let Some(ref custom) = quality.custom_checks {
-                count += custom.len();
-            }
-            if let Some(ref ml) = quality.ml_checks {
-                if ml.no_overlap.is_some() {
-                    count += 1;
-                }
-                if ml.temporal_split.is_some() {
-                    count += 1;
-                }
-                if ml.class_balance.is_some() {
-                    count += 1;
-                }
-                if ml.feature_drift.is_some() {
-                    count += 1;
-                }
-                if ml.target_leakage.is_some() {
-                    count += 1;
-                }
-                if ml.null_rate_by_group.is_some() {
-                    count += 1;
-                }
-            }
-            count
-        } else {
-            0
-        };
-
-        ValidationReport {
-            passed: errors.is_empty(),
-            errors,
-            warnings,
-            stats: ValidationStats {
-                records_validated: dataset.len(),
-                fields_checked,
-                constraints_evaluated: constraints_evaluated + quality_checks_count,
-                duration_ms,
-            },
-        }
+        let columns = dataset.column_stats().cloned().unwrap_or_default();
+        report_from_findings(findings, contract, dataset.len(), columns, start)
     }
 
     /// Validates only quality checks (completeness, uniqueness, freshness, ML) against data.
@@ -430,41 +575,284 @@ impl DataValidator {
         dataset: &DataSet,
     ) -> ValidationReport {
         let start = Instant::now();
-        let errors = Vec::new();
-        let mut warnings = Vec::new();
+        let mut findings = Findings::default();
+        let withheld = sample_size::withhold_undersampled(contract, dataset.len());
+        let contract = withheld.as_ref().map_or(contract, |w| &w.contract);
 
-        let quality_errors = self.quality_validator.validate(contract, dataset);
-        warnings.extend(quality_errors.iter().map(|e| e.to_string()));
+        for (severity, error) in self
+            .quality_validator
+            .validate_with_severity(contract, dataset)
+        {
+            findings.push(
+                error
+                    .to_violation()
+                    .with_severity(severity.unwrap_or(Severity::Warning)),
+            );
+        }
 
         let freshness_errors = self
             .custom_validator
             .validate_freshness_only(contract, dataset);
-        warnings.extend(freshness_errors.iter().map(|e| e.to_string()));
+        findings.extend_errors(
+            freshness_severity(contract, Severity::Warning),
+            &freshness_errors,
+        );
 
         if let Some(ref qc) = contract.quality_checks
             && let Some(ref ml) = qc.ml_checks
         {
             let ml_errors = self.ml_validator.validate(ml, dataset);
-            warnings.extend(ml_errors.iter().map(|e| e.to_string()));
+            findings.extend(Severity::Warning, ml_errors.iter().map(ml_violation));
         }
 
-        self.build_report(errors, warnings, contract, dataset, start)
+        let mut report = self.build_report(findings, contract, dataset, start);
+        if let Some(withheld) = withheld {
+            report.inconclusive = withheld.messages;
+        }
+        report
     }
 
     /// Validates only ML checks against data.
     pub fn validate_ml_only(&self, contract: &Contract, dataset: &DataSet) -> ValidationReport {
         let start = Instant::now();
-        let errors = Vec::new();
-        let mut warnings = Vec::new();
+        let mut findings = Findings::default();
+        let withheld = sample_size::withhold_undersampled(contract, dataset.len());
+        let contract = withheld.as_ref().map_or(contract, |w| &w.contract);
 
         if let Some(ref qc) = contract.quality_checks
             && let Some(ref ml) = qc.ml_checks
         {
             let ml_errors = self.ml_validator.validate(ml, dataset);
-            warnings.extend(ml_errors.iter().map(|e| e.to_string()));
+            findings.extend(Severity::Warning, ml_errors.iter().map(ml_violation));
+        }
+
+        let mut report = self.build_report(findings, contract, dataset, start);
+        if let Some(withheld) = withheld {
+            report.inconclusive = withheld.messages;
+        }
+        report
+    }
+
+    /// Runs only the given stages against a dataset, each into its own report.
+    ///
+    /// The dataset is prepared once, as for `validate_with_data_async`, and
+    /// custom SQL checks are executed against it. Each report holds the
+    /// violations of its stage only; checks held back for lack of records are
+    /// inconclusive in the stage they belong to, and the empty data policy is
+    /// applied by the quality stage. A stage listed twice runs once, and a
+    /// fail-fast run stops after the first stage with an error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use contracts_validator::{DataValidator, DataSet, Stage};
+    /// use contracts_core::{ContractBuilder, DataFormat, FieldBuilder, ValidationContext};
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let contract = ContractBuilder::new("orders", "sales")
+    ///     .location("s3://lake/orders")
+    ///     .format(DataFormat::Iceberg)
+    ///     .field(FieldBuilder::new("id", "int64").nullable(false).build())
+    ///     .build();
+    ///
+    /// let mut validator = DataValidator::new();
+    /// let reports = validator
+    ///     .validate_stages(
+    ///         &contract,
+    ///         &DataSet::empty(),
+    ///         &ValidationContext::new(),
+    ///         &[Stage::Schema, Stage::Constraints],
+    ///     )
+    ///     .await;
+    ///
+    /// assert_eq!(reports.len(), 2);
+    /// assert!(reports[&Stage::Schema].passed);
+    /// # }
+    /// ```
+    pub async fn validate_stages(
+        &mut self,
+        contract: &Contract,
+        dataset: &DataSet,
+        context: &ValidationContext,
+        stages: &[Stage],
+    ) -> BTreeMap<Stage, ValidationReport> {
+        let dataset = match self.sample_dataset(dataset, contract, context) {
+            Ok(dataset) => dataset,
+            Err(e) => {
+                let failure = ValidationReport::failure(e.to_violation());
+                return stages
+                    .iter()
+                    .map(|stage| (*stage, failure.clone()))
+                    .collect();
+            }
+        };
+        let withheld = withhold_for(contract, context, dataset.len());
+        let contract = withheld.as_ref().map_or(contract, |w| &w.contract);
+        let default = Severity::default_for(context.strict);
+
+        let mut reports = BTreeMap::new();
+        for &stage in stages {
+            if reports.contains_key(&stage) {
+                continue;
+            }
+            let start = Instant::now();
+            let mut findings = Findings::new(context);
+
+            match stage {
+                Stage::Schema => {
+                    let errors = self.schema_errors(contract, &dataset, context);
+                    findings.extend_errors(Severity::Error, &errors);
+                    for violation in deprecation::check_rows(contract, &dataset) {
+                        findings.push(violation);
+                    }
+                }
+                Stage::Constraints => {
+                    for error in self.constraint_errors(contract, &dataset, context) {
+                        let severity = constraint_severity(contract, error.field());
+                        findings.push(error.to_violation().with_severity(severity));
+                    }
+                }
+                Stage::Quality => {
+                    for (severity, error) in self
+                        .quality_validator
+                        .validate_with_severity(contract, &dataset)
+                    {
+                        findings.push(
+                            error
+                                .to_violation()
+                                .with_severity(severity.unwrap_or(default)),
+                        );
+                    }
+                }
+                Stage::Freshness => {
+                    let errors = self
+                        .custom_validator
+                        .validate_freshness_only(contract, &dataset);
+                    findings.extend_errors(freshness_severity(contract, default), &errors);
+                }
+                Stage::Custom => {
+                    for (severity, error) in self
+                        .custom_validator
+                        .validate_custom_checks_with_data(
+                            contract,
+                            &dataset,
+                            &contract.schema.fields,
+                        )
+                        .await
+                    {
+                        findings.push(
+                            error
+                                .to_violation()
+                                .with_severity(custom_severity(severity.as_deref(), default)),
+                        );
+                    }
+                }
+                Stage::Ml => {
+                    if let Some(ref qc) = contract.quality_checks
+                        && let Some(ref ml) = qc.ml_checks
+                    {
+                        let errors = self.ml_validator.validate(ml, &dataset);
+                        findings.extend(default, errors.iter().map(ml_violation));
+                    }
+                }
+            }
+
+            let mut report = self.build_report(findings, contract, &dataset, start);
+            if stage == Stage::Quality {
+                apply_empty_data_policy(contract, context, &mut report);
+            }
+            if let Some(withheld) = &withheld {
+                report.inconclusive = withheld
+                    .messages
+                    .iter()
+                    .filter(|message| message.check_kind == stage.check_kind())
+                    .cloned()
+                    .collect();
+            }
+            report.passed = report.errors.is_empty();
+            let stop = failed_fast(context, &report);
+            reports.insert(stage, report);
+            if stop {
+                break;
+            }
         }
+        reports
+    }
 
-        self.build_report(errors, warnings, contract, dataset, start)
+    /// Validates a contract against a stream of rows, without buffering them.
+    ///
+    /// Rows are validated in chunks as the stream yields them, with the checks
+    /// and memory bounds of [`StreamingValidator`]. The run ends when the
+    /// stream does or the context's sample size is reached, so an unbounded
+    /// stream needs a sample size.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use contracts_core::{ContractBuilder, DataFormat, FieldBuilder, ValidationContext};
+    /// use contracts_validator::{DataRow, DataValidator, DataValue};
+    /// use futures::StreamExt;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let contract = ContractBuilder::new("events", "owner")
+    ///     .location("kafka://events")
+    ///     .format(DataFormat::Iceberg)
+    ///     .field(FieldBuilder::new("id", "int64").nullable(false).build())
+    ///     .build();
+    /// let rows = futures::stream::iter(0..)
+    ///     .map(|id| DataRow::from([("id".to_string(), DataValue::Int(id))]));
+    ///
+    /// let context = ValidationContext::new().with_sample_size(5_000);
+    /// let report = DataValidator::new()
+    ///     .validate_stream(&contract, rows, &context)
+    ///     .await;
+    ///
+    /// assert!(report.passed);
+    /// assert_eq!(report.stats.records_validated, 5_000);
+    /// # }
+    /// ```
+    pub async fn validate_stream<S>(
+        &self,
+        contract: &Contract,
+        rows: S,
+        context: &ValidationContext,
+    ) -> ValidationReport
+    where
+        S: Stream<Item = DataRow>,
+    {
+        self.validate_stream_with_progress(contract, rows, context, |_| {})
+            .await
+    }
+
+    /// Validates a contract against a stream of rows like
+    /// [`validate_stream`](Self::validate_stream), calling `progress` after
+    /// each chunk.
+    ///
+    /// `progress` can ask the run for a
+    /// [`partial_report`](StreamingValidator::partial_report) of the rows
+    /// validated so far, e.g. to publish one every million rows.
+    pub async fn validate_stream_with_progress<S, F>(
+        &self,
+        contract: &Contract,
+        rows: S,
+        context: &ValidationContext,
+        mut progress: F,
+    ) -> ValidationReport
+    where
+        S: Stream<Item = DataRow>,
+        F: FnMut(&StreamingValidator<'_>),
+    {
+        let mut validator = StreamingValidator::new(contract, context);
+        let mut chunks = std::pin::pin!(rows.ready_chunks(STREAM_CHUNK_ROWS));
+        while !validator.is_done()
+            && let Some(chunk) = chunks.next().await
+        {
+            validator.push(&DataSet::from_rows(chunk));
+            progress(&validator);
+        }
+        validator.finish()
     }
 
     /// Validates only the contract definition itself (no data).
@@ -473,27 +861,230 @@ impl DataValidator {
     /// to validate data against it.
     pub fn validate_definition(&self, contract: &Contract) -> ValidationReport {
         let start = Instant::now();
-        let errors: Vec<String> = self
+        let errors: Vec<Violation> = self
             .schema_validator
             .validate_schema_definition(contract)
             .iter()
-            .map(|e| e.to_string())
+            .map(|e| Violation {
+                check_kind: CheckKind::Definition,
+                ..e.to_violation()
+            })
             .collect();
 
         ValidationReport {
             passed: errors.is_empty(),
             errors,
             warnings: Vec::new(),
+            inconclusive: Vec::new(),
+            info: Vec::new(),
             stats: ValidationStats {
                 records_validated: 0,
                 fields_checked: contract.schema.fields.len(),
                 constraints_evaluated: 0,
                 duration_ms: start.elapsed().as_millis() as u64,
+                ..Default::default()
             },
+            provenance: None,
+            conformance_level: contract.conformance_level,
+            snapshot_id: None,
+            base_snapshot_id: None,
+            segments: Vec::new(),
+            datasets: Vec::new(),
+        }
+    }
+}
+
+/// Returns true once a fail-fast run has an error in `report`.
+fn failed_fast(context: &ValidationContext, report: &ValidationReport) -> bool {
+    context.fail_fast && !report.errors.is_empty()
+}
+
+/// Builds the report of a run over `records` rows from its findings.
+pub(crate) fn report_from_findings(
+    findings: Findings,
+    contract: &Contract,
+    records: usize,
+    columns: BTreeMap<String, ColumnStats>,
+    start: Instant,
+) -> ValidationReport {
+    let duration_ms = start.elapsed().as_millis() as u64;
+    let truncated_violations = findings.truncated();
+
+    // Count fields checked (number of fields in contract schema)
+    let fields_checked = contract.schema.fields.len();
+
+    // Count constraints evaluated across all fields
+    let constraints_evaluated: usize = contract
+        .schema
+        .fields
+        .iter()
+        .map(|field| field.constraints.as_ref().map(|c| c.len()).unwrap_or(0))
+        .sum();
+
+    // Add quality checks count if present
+    let quality_checks_count = if let Some(ref quality) = contract.quality_checks {
+        let mut count = 0;
+        if quality.completeness.is_some() {
+            count += 1;
+        }
+        if quality.uniqueness.is_some() {
+            count += 1;
+        }
+        if quality.freshness.is_some() {
+            count += 1;
+        }
+        if let Some(ref custom) = quality.custom_checks {
+            count += custom.len();
+        }
+        if let Some(ref rows) = quality.row_checks {
+            count += rows.len();
+        }
+        if let Some(ref ml) = quality.ml_checks {
+            if ml.no_overlap.is_some() {
+                count += 1;
+            }
+            if ml.temporal_split.is_some() {
+                count += 1;
+            }
+            if ml.class_balance.is_some() {
+                count += 1;
+            }
+            if ml.feature_drift.is_some() {
+                count += 1;
+            }
+            if ml.target_leakage.is_some() {
+                count += 1;
+            }
+            if ml.null_rate_by_group.is_some() {
+                count += 1;
+            }
+        }
+        count
+    } else {
+        0
+    };
+
+    ValidationReport {
+        passed: findings.errors.is_empty(),
+        errors: findings.errors,
+        warnings: findings.warnings,
+        inconclusive: Vec::new(),
+        info: findings.info,
+        stats: ValidationStats {
+            records_validated: records,
+            fields_checked,
+            constraints_evaluated: constraints_evaluated + quality_checks_count,
+            duration_ms,
+            columns,
+            deletes: None,
+            truncated_violations,
+        },
+        provenance: None,
+        conformance_level: contract.conformance_level,
+        snapshot_id: None,
+        base_snapshot_id: None,
+        segments: Vec::new(),
+        datasets: Vec::new(),
+    }
+}
+
+/// Fills missing fields with their defaults when the context asks for it,
+/// then applies the context's time window and its row filter to `dataset`.
+///
+/// Returns `None` when every row is kept unchanged.
+pub(crate) fn select_rows(
+    dataset: &DataSet,
+    contract: &Contract,
+    context: &ValidationContext,
+) -> Result<Option<DataSet>, ValidationError> {
+    let filled = if context.fill_defaults {
+        defaults::fill_defaults(dataset, contract)?
+    } else {
+        None
+    };
+    let dataset = filled.as_ref().unwrap_or(dataset);
+    let windowed = if context.schema_only {
+        None
+    } else {
+        time_window::filter_rows(dataset, contract, context)?
+    };
+    let dataset = windowed.as_ref().unwrap_or(dataset);
+    let filtered = if context.schema_only {
+        None
+    } else {
+        row_filter::filter_rows(dataset, contract, context)?
+    };
+    Ok(filtered.or(windowed).or(filled))
+}
+
+/// Holds back checks that need more records than the run validated.
+///
+/// Schema-only runs evaluate no statistical checks, so nothing is withheld.
+fn withhold_for(
+    contract: &Contract,
+    context: &ValidationContext,
+    records: usize,
+) -> Option<Withheld> {
+    if context.schema_only {
+        return None;
+    }
+    sample_size::withhold_undersampled(contract, records)
+}
+
+/// Returns the column to segment the run by, if segments should be built.
+///
+/// A column missing from the schema is recorded on `report` and disables
+/// segmenting; schema-only runs have no rows to segment.
+fn segment_column<'a>(
+    contract: &Contract,
+    context: &'a ValidationContext,
+    report: &mut ValidationReport,
+) -> Option<&'a str> {
+    let column = context.segment_by.as_deref()?;
+    if context.schema_only {
+        return None;
+    }
+    match segments::check_column(contract, column) {
+        Ok(()) => Some(column),
+        Err(e) => {
+            report.add_error(e.to_violation());
+            None
         }
     }
 }
 
+fn segment_report(column: &str, value: String, report: ValidationReport) -> SegmentReport {
+    SegmentReport {
+        column: column.to_string(),
+        value,
+        report,
+    }
+}
+
+/// Reports that no records were checked, as the empty data policy asks.
+///
+/// Every data check passes trivially on an empty sample, so the condition is
+/// surfaced instead of letting a reader that returned nothing look like a pass.
+pub(crate) fn apply_empty_data_policy(
+    contract: &Contract,
+    context: &ValidationContext,
+    report: &mut ValidationReport,
+) {
+    if context.schema_only || report.stats.records_validated > 0 {
+        return;
+    }
+    let message = Violation::new(
+        &codes::EMPTY_DATASET,
+        CheckKind::Quality,
+        "No records to validate; data checks were not run",
+    );
+    match context.empty_data_policy(contract) {
+        EmptyDataPolicy::Pass => {}
+        EmptyDataPolicy::Warn => report.add_warning(message),
+        EmptyDataPolicy::Fail => report.add_error(message),
+    }
+}
+
 impl Default for DataValidator {
     fn default() -> Self {
         Self::new()
@@ -529,21 +1120,78 @@ impl ContractValidator for DataValidator {
             ))
         }
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::DataValue;
-    use contracts_core::{
-        CompletenessCheck, ContractBuilder, CustomCheck, DataFormat, FieldBuilder,
-        FieldConstraints, QualityChecks,
-    };
-    use std::collections::HashMap;
 
-    #[test]
-    fn test_empty_dataset() {
-        let contract = ContractBuilder::new("test", "owner")
+    /// Checks the quality check definitions without reading data: thresholds
+    /// in range, referenced fields declared, durations parseable and custom
+    /// checks that look like SQL.
+    ///
+    /// Returns the first problem found; lint warnings count in strict mode.
+    fn validate_quality(
+        &self,
+        contract: &Contract,
+        context: &ValidationContext,
+    ) -> contracts_core::ValidationResult {
+        if let Some(finding) = ContractLinter::new()
+            .lint_quality(contract)
+            .into_iter()
+            .find(|f| context.strict || f.severity == LintSeverity::Error)
+        {
+            return Err(contracts_core::ContractError::QualityCheckFailed {
+                check: finding.path,
+                message: finding.message,
+            });
+        }
+
+        match self
+            .custom_validator
+            .validate_custom_checks_only(contract)
+            .into_iter()
+            .next()
+        {
+            Some((_, ValidationError::CustomCheckFailed { name, message, .. })) => {
+                Err(contracts_core::ContractError::QualityCheckFailed {
+                    check: name,
+                    message,
+                })
+            }
+            Some((_, error)) => Err(contracts_core::ContractError::QualityCheckFailed {
+                check: "custom_checks".to_string(),
+                message: error.to_string(),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks the SLA definition without reading data.
+    fn validate_sla(
+        &self,
+        contract: &Contract,
+        _context: &ValidationContext,
+    ) -> contracts_core::ValidationResult {
+        match ContractLinter::new().lint_sla(contract).into_iter().next() {
+            Some(finding) => Err(contracts_core::ContractError::SlaViolation(format!(
+                "{}: {}",
+                finding.path, finding.message
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DataValue;
+    use contracts_core::{
+        CompletenessCheck, ContractBuilder, CustomCheck, DataFormat, FieldBuilder,
+        FieldConstraints, QualityChecks, QualityChecksBuilder, SoftDelete, UniquenessCheck,
+        ValidationStatus, VolumeCheck,
+    };
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_empty_dataset() {
+        let contract = ContractBuilder::new("test", "owner")
             .location("s3://test")
             .format(DataFormat::Iceberg)
             .field(FieldBuilder::new("id", "string").nullable(false).build())
@@ -609,6 +1257,10 @@ mod tests {
         let report = validator.validate_with_data(&contract, &dataset, &context);
         assert!(!report.passed);
         assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].code.code, "DCE0102");
+        assert_eq!(report.errors[0].field.as_deref(), Some("id"));
+        assert_eq!(report.errors[0].row_index, Some(0));
+        assert_eq!(report.errors[0].check_kind, CheckKind::Schema);
     }
 
     #[test]
@@ -637,6 +1289,7 @@ mod tests {
         let report = validator.validate_with_data(&contract, &dataset, &context);
         assert!(!report.passed);
         assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].code.code, "DCE0203");
     }
 
     #[test]
@@ -649,11 +1302,10 @@ mod tests {
                 completeness: Some(CompletenessCheck {
                     threshold: 0.95,
                     fields: vec!["id".to_string()],
+                    min_sample_size: None,
+                    severity: None,
                 }),
-                uniqueness: None,
-                freshness: None,
-                custom_checks: None,
-                ml_checks: None,
+                ..Default::default()
             })
             .build();
 
@@ -677,6 +1329,307 @@ mod tests {
         assert_eq!(report.warnings.len(), 1);
     }
 
+    #[tokio::test]
+    async fn test_empty_data_policy() {
+        let mut contract = ContractBuilder::new("test", "owner")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("id", "string").nullable(false).build())
+            .build();
+        let empty = DataSet::empty();
+        let mut validator = DataValidator::new();
+
+        // Warns by default, on both the sync and the DataFusion path
+        let context = ValidationContext::new();
+        let report = validator.validate_with_data(&contract, &empty, &context);
+        assert!(report.passed);
+        assert_eq!(report.warnings[0].code.code, "DCE0303");
+        let report = validator
+            .validate_with_data_async(&contract, &empty, &context)
+            .await;
+        assert!(report.passed);
+        assert_eq!(report.warnings.len(), 1);
+
+        // The contract policy applies unless the context overrides it
+        contract.quality_checks = Some(
+            QualityChecksBuilder::new()
+                .on_empty(EmptyDataPolicy::Fail)
+                .build(),
+        );
+        let report = validator.validate_with_data(&contract, &empty, &context);
+        assert!(!report.passed);
+        assert_eq!(report.errors[0].code.code, "DCE0303");
+
+        let context = ValidationContext::new().with_on_empty(EmptyDataPolicy::Pass);
+        let report = validator.validate_with_data(&contract, &empty, &context);
+        assert!(report.passed);
+        assert!(report.warnings.is_empty());
+
+        // Schema-only runs read no data on purpose
+        let context = ValidationContext::new().with_schema_only(true);
+        let report = validator.validate_with_data(&contract, &empty, &context);
+        assert!(report.passed);
+    }
+
+    #[tokio::test]
+    async fn test_min_sample_size_marks_checks_inconclusive() {
+        let completeness = |min_sample_size| {
+            QualityChecksBuilder::new()
+                .completeness(CompletenessCheck {
+                    threshold: 0.99,
+                    fields: vec!["id".to_string()],
+                    min_sample_size,
+                    severity: None,
+                })
+                .build()
+        };
+        let mut contract = ContractBuilder::new("test", "owner")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("id", "string").nullable(true).build())
+            .quality_checks(completeness(Some(100)))
+            .build();
+
+        // Half of the 20 rows are null, which would fail completeness
+        let rows = (0..20)
+            .map(|i| {
+                let value = if i % 2 == 0 {
+                    DataValue::String(i.to_string())
+                } else {
+                    DataValue::Null
+                };
+                HashMap::from([("id".to_string(), value)])
+            })
+            .collect();
+        let dataset = DataSet::from_rows(rows);
+        let context = ValidationContext::new();
+        let mut validator = DataValidator::new();
+
+        let report = validator.validate_with_data(&contract, &dataset, &context);
+        assert!(report.passed);
+        assert!(report.warnings.is_empty());
+        assert_eq!(report.status(), ValidationStatus::Inconclusive);
+        assert_eq!(report.inconclusive[0].code.code, "DCE0304");
+        assert!(report.inconclusive[0].message.starts_with("Completeness"));
+
+        let report = validator
+            .validate_with_data_async(&contract, &dataset, &context)
+            .await;
+        assert!(report.warnings.is_empty());
+        assert_eq!(report.inconclusive.len(), 1);
+
+        // Enough records: the check runs and reports the null rate
+        contract.quality_checks = Some(completeness(Some(10)));
+        let report = validator.validate_with_data(&contract, &dataset, &context);
+        assert!(report.inconclusive.is_empty());
+        assert_eq!(report.warnings.len(), 1);
+        assert_eq!(report.status(), ValidationStatus::Passed);
+    }
+
+    #[tokio::test]
+    async fn test_volume_counts_records_within_the_lateness_window() {
+        let volume = |allowed_lateness: &str| {
+            QualityChecksBuilder::new()
+                .volume(VolumeCheck {
+                    metric: "event_time".to_string(),
+                    bucket: "1d".to_string(),
+                    min_rows: 2,
+                    arrival_metric: Some("ingested_at".to_string()),
+                    allowed_lateness: Some(allowed_lateness.to_string()),
+                    severity: None,
+                })
+                .build()
+        };
+        let mut contract = ContractBuilder::new("test", "owner")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("event_time", "timestamp").build())
+            .field(FieldBuilder::new("ingested_at", "timestamp").build())
+            .quality_checks(volume("1h"))
+            .build();
+
+        // The newest day whose late records can no longer arrive: one record
+        // arrived on time, the other half an hour after the day ended
+        let now = chrono::Utc::now();
+        let day = (now - chrono::Duration::hours(25)).date_naive();
+        let start = day.and_time(chrono::NaiveTime::MIN).and_utc();
+        let row = |event: chrono::Duration, arrival: chrono::Duration| {
+            HashMap::from([
+                (
+                    "event_time".to_string(),
+                    DataValue::Timestamp((start + event).to_rfc3339()),
+                ),
+                (
+                    "ingested_at".to_string(),
+                    DataValue::Timestamp((start + arrival).to_rfc3339()),
+                ),
+            ])
+        };
+        let dataset = DataSet::from_rows(vec![
+            row(chrono::Duration::hours(1), chrono::Duration::hours(2)),
+            row(
+                chrono::Duration::hours(23),
+                chrono::Duration::minutes(24 * 60 + 30),
+            ),
+        ]);
+        let context = ValidationContext::new();
+        let mut validator = DataValidator::new();
+
+        let report = validator.validate_with_data(&contract, &dataset, &context);
+        assert!(report.warnings.is_empty(), "{:?}", report.warnings);
+        let report = validator
+            .validate_with_data_async(&contract, &dataset, &context)
+            .await;
+        assert!(report.warnings.is_empty(), "{:?}", report.warnings);
+        let mut streaming = crate::StreamingValidator::new(&contract, &context);
+        streaming.push(&dataset);
+        assert!(streaming.finish().warnings.is_empty());
+
+        // Past a 10 minute window the late record no longer counts
+        contract.quality_checks = Some(volume("10m"));
+        let report = validator.validate_with_data(&contract, &dataset, &context);
+        assert_eq!(report.warnings.len(), 1);
+        assert_eq!(report.warnings[0].code.code, "DCE0311");
+        let report = validator
+            .validate_with_data_async(&contract, &dataset, &context)
+            .await;
+        assert_eq!(report.warnings.len(), 1);
+        assert_eq!(report.warnings[0].code.code, "DCE0311");
+        let mut streaming = crate::StreamingValidator::new(&contract, &context);
+        streaming.push(&dataset);
+        assert_eq!(streaming.finish().warnings.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_soft_deleted_rows_are_left_out_of_quality_checks() {
+        let checks = |include_deleted| {
+            QualityChecksBuilder::new()
+                .completeness(CompletenessCheck {
+                    threshold: 1.0,
+                    fields: vec!["name".to_string()],
+                    min_sample_size: None,
+                    severity: None,
+                })
+                .uniqueness(UniquenessCheck {
+                    fields: vec!["id".to_string()],
+                    scope: None,
+                    min_sample_size: None,
+                    severity: None,
+                    approximate: None,
+                })
+                .soft_delete(SoftDelete {
+                    column: "is_deleted".to_string(),
+                    include_deleted,
+                })
+                .build()
+        };
+        let mut contract = ContractBuilder::new("test", "owner")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("id", "int64").build())
+            .field(FieldBuilder::new("name", "string").build())
+            .field(FieldBuilder::new("is_deleted", "boolean").build())
+            .quality_checks(checks(false))
+            .build();
+
+        // The tombstone repeats a live id and has lost its name
+        let row = |id, name: Option<&str>, deleted| {
+            HashMap::from([
+                ("id".to_string(), DataValue::Int(id)),
+                (
+                    "name".to_string(),
+                    name.map_or(DataValue::Null, |n| DataValue::String(n.to_string())),
+                ),
+                ("is_deleted".to_string(), DataValue::Bool(deleted)),
+            ])
+        };
+        let dataset = DataSet::from_rows(vec![
+            row(1, Some("ada"), false),
+            row(2, Some("bob"), false),
+            row(1, None, true),
+        ]);
+        let context = ValidationContext::new();
+        let mut validator = DataValidator::new();
+
+        let report = validator.validate_with_data(&contract, &dataset, &context);
+        assert!(report.warnings.is_empty(), "{:?}", report.warnings);
+        let report = validator
+            .validate_with_data_async(&contract, &dataset, &context)
+            .await;
+        assert!(report.warnings.is_empty(), "{:?}", report.warnings);
+        let mut streaming = crate::StreamingValidator::new(&contract, &context);
+        streaming.push(&dataset);
+        assert!(streaming.finish().warnings.is_empty());
+
+        contract.quality_checks = Some(checks(true));
+        let report = validator.validate_with_data(&contract, &dataset, &context);
+        assert_eq!(report.warnings.len(), 2);
+        let report = validator
+            .validate_with_data_async(&contract, &dataset, &context)
+            .await;
+        assert_eq!(report.warnings.len(), 2);
+        let mut streaming = crate::StreamingValidator::new(&contract, &context);
+        streaming.push(&dataset);
+        assert_eq!(streaming.finish().warnings.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_validate_stages_reports_each_stage_separately() {
+        let contract = ContractBuilder::new("test", "owner")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(
+                FieldBuilder::new("age", "int64")
+                    .nullable(false)
+                    .constraint(FieldConstraints::Range {
+                        min: 0.0,
+                        max: 150.0,
+                    })
+                    .build(),
+            )
+            .quality_checks(
+                QualityChecksBuilder::new()
+                    .custom_check(CustomCheck {
+                        name: "no_minors".to_string(),
+                        definition: "SELECT COUNT(*) FROM data WHERE age < 18".to_string(),
+                        severity: Some("error".to_string()),
+                        capture_rows: None,
+                    })
+                    .build(),
+            )
+            .build();
+        let dataset = DataSet::from_rows(vec![
+            HashMap::from([("age".to_string(), DataValue::Int(12))]),
+            HashMap::from([("age".to_string(), DataValue::Int(200))]),
+        ]);
+        let mut validator = DataValidator::new();
+
+        let reports = validator
+            .validate_stages(
+                &contract,
+                &dataset,
+                &ValidationContext::new(),
+                &[Stage::Custom, Stage::Constraints, Stage::Custom],
+            )
+            .await;
+
+        assert_eq!(
+            reports.keys().copied().collect::<Vec<_>>(),
+            [Stage::Constraints, Stage::Custom]
+        );
+        let constraints = &reports[&Stage::Constraints];
+        assert_eq!(constraints.errors.len(), 1);
+        assert_eq!(constraints.errors[0].check_kind, CheckKind::Constraint);
+        let custom = &reports[&Stage::Custom];
+        assert!(!custom.passed);
+        assert!(
+            custom
+                .errors
+                .iter()
+                .all(|e| e.check_kind == CheckKind::Custom)
+        );
+    }
+
     #[test]
     fn test_strict_mode() {
         let contract = ContractBuilder::new("test", "owner")
@@ -687,11 +1640,10 @@ mod tests {
                 completeness: Some(CompletenessCheck {
                     threshold: 0.95,
                     fields: vec!["id".to_string()],
+                    min_sample_size: None,
+                    severity: None,
                 }),
-                uniqueness: None,
-                freshness: None,
-                custom_checks: None,
-                ml_checks: None,
+                ..Default::default()
             })
             .build();
 
@@ -725,11 +1677,10 @@ mod tests {
                 completeness: Some(CompletenessCheck {
                     threshold: 0.99,
                     fields: vec!["id".to_string()],
+                    min_sample_size: None,
+                    severity: None,
                 }),
-                uniqueness: None,
-                freshness: None,
-                custom_checks: None,
-                ml_checks: None,
+                ..Default::default()
             })
             .build();
 
@@ -782,6 +1733,79 @@ mod tests {
         assert!(report.passed);
     }
 
+    #[test]
+    fn test_trait_validates_quality_and_sla_definitions() {
+        use contracts_core::{ContractError, SLA, UniquenessCheck};
+
+        let validator = DataValidator::new();
+        let context = ValidationContext::new();
+        let contract = |quality: QualityChecks, availability: f64| {
+            ContractBuilder::new("test", "owner")
+                .location("s3://test")
+                .format(DataFormat::Iceberg)
+                .field(FieldBuilder::new("id", "string").build())
+                .quality_checks(quality)
+                .sla(SLA {
+                    availability: Some(availability),
+                    response_time: None,
+                    penalties: None,
+                })
+                .build()
+        };
+
+        let unique_id = QualityChecksBuilder::new()
+            .uniqueness(UniquenessCheck {
+                fields: vec!["id".to_string()],
+                scope: None,
+                min_sample_size: None,
+                severity: None,
+                approximate: None,
+            })
+            .build();
+        let valid = contract(unique_id.clone(), 0.999);
+        assert!(validator.validate_quality(&valid, &context).is_ok());
+        assert!(validator.validate_sla(&valid, &context).is_ok());
+
+        // A nullable unique field is only a lint warning
+        let strict = ValidationContext::new().with_strict(true);
+        assert!(matches!(
+            validator.validate_quality(&valid, &strict),
+            Err(ContractError::QualityCheckFailed { check, .. })
+                if check == "quality_checks.uniqueness.fields"
+        ));
+
+        let unknown = QualityChecksBuilder::new()
+            .completeness(CompletenessCheck {
+                threshold: 0.9,
+                fields: vec!["email".to_string()],
+                min_sample_size: None,
+                severity: None,
+            })
+            .build();
+        assert!(matches!(
+            validator.validate_quality(&contract(unknown, 0.999), &context),
+            Err(ContractError::QualityCheckFailed { message, .. }) if message.contains("email")
+        ));
+
+        let not_sql = QualityChecksBuilder::new()
+            .custom_check(CustomCheck {
+                name: "must_be_sql".to_string(),
+                definition: "not sql".to_string(),
+                severity: None,
+                capture_rows: None,
+            })
+            .build();
+        assert!(matches!(
+            validator.validate_quality(&contract(not_sql, 0.999), &context),
+            Err(ContractError::QualityCheckFailed { check, .. }) if check == "must_be_sql"
+        ));
+
+        assert!(matches!(
+            validator.validate_sla(&contract(unique_id, 99.9), &context),
+            Err(ContractError::SlaViolation(message)) if message.starts_with("sla.availability")
+        ));
+    }
+
     #[test]
     fn test_custom_check_error_severity_overrides_non_strict_mode() {
         let contract = ContractBuilder::new("test", "owner")
@@ -789,15 +1813,13 @@ mod tests {
             .format(DataFormat::Iceberg)
             .field(FieldBuilder::new("id", "string").nullable(false).build())
             .quality_checks(QualityChecks {
-                completeness: None,
-                uniqueness: None,
-                freshness: None,
                 custom_checks: Some(vec![CustomCheck {
                     name: "must_be_sql".to_string(),
                     definition: "not sql".to_string(),
                     severity: Some("error".to_string()),
+                    capture_rows: None,
                 }]),
-                ml_checks: None,
+                ..Default::default()
             })
             .build();
 
@@ -814,6 +1836,131 @@ mod tests {
         assert_eq!(report.warnings.len(), 0);
     }
 
+    #[tokio::test]
+    async fn test_check_severities_route_failures() {
+        let contract = ContractBuilder::new("test", "owner")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("id", "int64").nullable(false).build())
+            .field(
+                FieldBuilder::new("status", "string")
+                    .constraint(FieldConstraints::AllowedValues {
+                        values: vec!["a".to_string()],
+                    })
+                    .severity(Severity::Warning)
+                    .build(),
+            )
+            .field(FieldBuilder::new("email", "string").build())
+            .quality_checks(
+                QualityChecksBuilder::new()
+                    .completeness(CompletenessCheck {
+                        threshold: 1.0,
+                        fields: vec!["email".to_string()],
+                        min_sample_size: None,
+                        severity: Some(Severity::Error),
+                    })
+                    .uniqueness(UniquenessCheck {
+                        fields: vec!["id".to_string()],
+                        scope: None,
+                        min_sample_size: None,
+                        severity: Some(Severity::Info),
+                        approximate: None,
+                    })
+                    .build(),
+            )
+            .build();
+
+        let row = |status: &str, email: DataValue| {
+            let mut row = HashMap::new();
+            row.insert("id".to_string(), DataValue::Int(1));
+            row.insert("status".to_string(), DataValue::String(status.to_string()));
+            row.insert("email".to_string(), email);
+            row
+        };
+        let dataset = DataSet::from_rows(vec![
+            row("b", DataValue::Null),
+            row("a", DataValue::String("x@example.com".to_string())),
+        ]);
+        let context = ValidationContext::new();
+        let mut validator = DataValidator::new();
+
+        let sync = validator.validate_with_data(&contract, &dataset, &context);
+        let datafusion = validator
+            .validate_with_data_async(&contract, &dataset, &context)
+            .await;
+        for report in [sync, datafusion] {
+            assert!(!report.passed);
+            assert_eq!(report.errors.len(), 1, "errors: {:?}", report.errors);
+            assert!(report.errors[0].message.contains("Completeness"));
+            assert_eq!(report.warnings.len(), 1, "warnings: {:?}", report.warnings);
+            assert!(report.warnings[0].message.contains("'status'"));
+            assert_eq!(report.info.len(), 1, "info: {:?}", report.info);
+            assert!(report.info[0].message.contains("Uniqueness"));
+        }
+    }
+
+    #[test]
+    fn test_fill_defaults_treats_missing_fields_as_default() {
+        let contract = ContractBuilder::new("test", "owner")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("id", "int64").nullable(false).build())
+            .field(
+                FieldBuilder::new("country", "string")
+                    .nullable(false)
+                    .default_value("IT")
+                    .constraint(FieldConstraints::AllowedValues {
+                        values: vec!["IT".to_string(), "FR".to_string()],
+                    })
+                    .build(),
+            )
+            .build();
+
+        let mut row = HashMap::new();
+        row.insert("id".to_string(), DataValue::Int(1));
+        let dataset = DataSet::from_rows(vec![row]);
+        let mut validator = DataValidator::new();
+
+        let report = validator.validate_with_data(&contract, &dataset, &ValidationContext::new());
+        assert!(!report.passed);
+        assert!(report.errors[0].message.contains("'country' is missing"));
+
+        let context = ValidationContext::new().with_fill_defaults(true);
+        let report = validator.validate_with_data(&contract, &dataset, &context);
+        assert!(report.passed, "errors: {:?}", report.errors);
+    }
+
+    #[test]
+    fn test_custom_check_info_severity_is_reported_as_info() {
+        let contract = ContractBuilder::new("test", "owner")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("id", "string").nullable(false).build())
+            .quality_checks(
+                QualityChecksBuilder::new()
+                    .custom_check(CustomCheck {
+                        name: "must_be_sql".to_string(),
+                        definition: "not sql".to_string(),
+                        severity: Some("info".to_string()),
+                        capture_rows: None,
+                    })
+                    .build(),
+            )
+            .build();
+
+        let mut row = HashMap::new();
+        row.insert("id".to_string(), DataValue::String("1".to_string()));
+
+        let dataset = DataSet::from_rows(vec![row]);
+        let context = ValidationContext::new().with_strict(true);
+        let mut validator = DataValidator::new();
+
+        let report = validator.validate_with_data(&contract, &dataset, &context);
+        assert!(report.passed);
+        assert!(report.warnings.is_empty());
+        assert_eq!(report.info.len(), 1);
+    }
+
     #[tokio::test]
     async fn test_async_custom_sql_check_pass() {
         let contract = ContractBuilder::new("test", "owner")
@@ -821,15 +1968,13 @@ mod tests {
             .format(DataFormat::Iceberg)
             .field(FieldBuilder::new("age", "int64").nullable(false).build())
             .quality_checks(QualityChecks {
-                completeness: None,
-                uniqueness: None,
-                freshness: None,
                 custom_checks: Some(vec![CustomCheck {
                     name: "no_negative_ages".to_string(),
                     definition: "SELECT COUNT(*) FROM data WHERE age < 0".to_string(),
                     severity: Some("error".to_string()),
+                    capture_rows: None,
                 }]),
-                ml_checks: None,
+                ..Default::default()
             })
             .build();
 
@@ -857,15 +2002,13 @@ mod tests {
             .format(DataFormat::Iceberg)
             .field(FieldBuilder::new("age", "int64").nullable(false).build())
             .quality_checks(QualityChecks {
-                completeness: None,
-                uniqueness: None,
-                freshness: None,
                 custom_checks: Some(vec![CustomCheck {
                     name: "no_negative_ages".to_string(),
                     definition: "SELECT COUNT(*) FROM data WHERE age < 0".to_string(),
                     severity: Some("error".to_string()),
+                    capture_rows: None,
                 }]),
-                ml_checks: None,
+                ..Default::default()
             })
             .build();
 
@@ -880,7 +2023,60 @@ mod tests {
             .validate_with_data_async(&contract, &dataset, &context)
             .await;
         assert!(!report.passed);
-        assert!(report.errors.iter().any(|e| e.contains("no_negative_ages")));
+        assert!(
+            report
+                .errors
+                .iter()
+                .any(|e| e.message.contains("no_negative_ages"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_async_custom_sql_check_captures_rows() {
+        let contract = ContractBuilder::new("test", "owner")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("id", "int64").nullable(false).build())
+            .field(FieldBuilder::new("age", "int64").build())
+            .quality_checks(
+                QualityChecksBuilder::new()
+                    .custom_check(CustomCheck {
+                        name: "adults_only".to_string(),
+                        definition: "SELECT id, age FROM data WHERE age IS NULL OR age < 18 \
+                                     ORDER BY id"
+                            .to_string(),
+                        severity: Some("error".to_string()),
+                        capture_rows: Some(2),
+                    })
+                    .build(),
+            )
+            .build();
+
+        let rows = [(1, Some(12)), (2, Some(40)), (3, None), (4, Some(3))]
+            .into_iter()
+            .map(|(id, age)| {
+                let mut row = HashMap::new();
+                row.insert("id".to_string(), DataValue::Int(id));
+                row.insert(
+                    "age".to_string(),
+                    age.map_or(DataValue::Null, DataValue::Int),
+                );
+                row
+            })
+            .collect();
+        let dataset = DataSet::from_rows(rows);
+        let mut validator = DataValidator::new();
+
+        let report = validator
+            .validate_with_data_async(&contract, &dataset, &ValidationContext::new())
+            .await;
+        assert!(!report.passed);
+        let error = &report.errors[0];
+        assert!(error.message.contains("returned 3 row(s)"), "{}", error);
+        assert_eq!(
+            serde_json::Value::from(error.rows.clone()),
+            serde_json::json!([{"id": 1, "age": 12}, {"id": 3, "age": null}])
+        );
     }
 
     #[tokio::test]
@@ -912,4 +2108,99 @@ mod tests {
         assert!(!report.passed);
         assert_eq!(report.errors.len(), 1);
     }
+
+    fn broken_contract_and_rows() -> (Contract, DataSet) {
+        let contract = ContractBuilder::new("test", "owner")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("id", "int64").nullable(false).build())
+            .field(
+                FieldBuilder::new("age", "int64")
+                    .constraint(FieldConstraints::Range {
+                        min: 0.0,
+                        max: 150.0,
+                    })
+                    .build(),
+            )
+            .build();
+        // Every third id is null and every age out of range
+        let dataset = DataSet::from_rows(
+            (0..3000)
+                .map(|i| {
+                    let id = if i % 3 == 0 {
+                        DataValue::Null
+                    } else {
+                        DataValue::Int(i)
+                    };
+                    HashMap::from([
+                        ("id".to_string(), id),
+                        ("age".to_string(), DataValue::Int(200)),
+                    ])
+                })
+                .collect(),
+        );
+        (contract, dataset)
+    }
+
+    #[test]
+    fn test_error_limits_truncate_report() {
+        let (contract, dataset) = broken_contract_and_rows();
+        let mut validator = DataValidator::new();
+
+        let report = validator.validate_with_data(&contract, &dataset, &ValidationContext::new());
+        assert_eq!(report.errors.len(), 4000);
+        assert_eq!(report.stats.truncated_violations, 0);
+
+        let context = ValidationContext::new().with_max_errors(10);
+        let report = validator.validate_with_data(&contract, &dataset, &context);
+        assert!(!report.passed);
+        assert_eq!(report.errors.len(), 10);
+        assert_eq!(report.stats.truncated_violations, 3990);
+
+        let context = ValidationContext::new().with_max_errors_per_check(5);
+        let report = validator.validate_with_data(&contract, &dataset, &context);
+        let fields: Vec<_> = report
+            .errors
+            .iter()
+            .map(|e| e.field.as_deref().unwrap())
+            .collect();
+        assert_eq!(
+            fields,
+            ["id"; 5].into_iter().chain(["age"; 5]).collect::<Vec<_>>()
+        );
+        assert_eq!(report.stats.truncated_violations, 3990);
+    }
+
+    #[test]
+    fn test_fail_fast_stops_at_first_error() {
+        let (contract, dataset) = broken_contract_and_rows();
+        let context = ValidationContext::new().with_fail_fast(true);
+        let report = DataValidator::new().validate_with_data(&contract, &dataset, &context);
+
+        assert!(!report.passed);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].row_index, Some(0));
+        // The first chunk of rows holds 342 null ids, constraints never run
+        assert_eq!(report.stats.truncated_violations, 341);
+    }
+
+    #[tokio::test]
+    async fn test_async_path_honors_error_limits() {
+        let (contract, dataset) = broken_contract_and_rows();
+        let mut validator = DataValidator::new();
+
+        let context = ValidationContext::new().with_fail_fast(true);
+        let report = validator
+            .validate_with_data_async(&contract, &dataset, &context)
+            .await;
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].field.as_deref(), Some("id"));
+
+        let context = ValidationContext::new().with_max_errors(1);
+        let report = validator
+            .validate_with_data_async(&contract, &dataset, &context)
+            .await;
+        assert_eq!(report.errors.len(), 1);
+        assert!(report.stats.truncated_violations > 0);
+    }
 }
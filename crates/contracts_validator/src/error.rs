@@ -1,5 +1,6 @@
 //! Error types for validation operations.
 
+use contracts_core::{CheckKind, Coded, ErrorCode, Violation, codes};
 use thiserror::Error;
 
 /// Errors that can occur during validation.
@@ -33,9 +34,17 @@ pub enum ValidationError {
     #[error("Quality check failed: {0}")]
     QualityCheckFailed(String),
 
-    /// Custom check failed
+    /// Custom check failed, with the offending rows it captured
     #[error("Custom check '{name}' failed: {message}")]
-    CustomCheckFailed { name: String, message: String },
+    CustomCheckFailed {
+        name: String,
+        message: String,
+        rows: Vec<serde_json::Map<String, serde_json::Value>>,
+    },
+
+    /// Rows fail the expression of a row check
+    #[error("Row check '{name}' failed: {message}")]
+    RowCheckFailed { name: String, message: String },
 
     /// Invalid regex pattern
     #[error("Invalid regex pattern for field '{field}': {error}")]
@@ -49,6 +58,54 @@ pub enum ValidationError {
     #[error("Invalid time duration format: {0}")]
     InvalidDuration(String),
 
+    /// Schema location is malformed or unsupported for the data format
+    #[error("Invalid location '{location}' for {format} format: {reason}")]
+    InvalidLocation {
+        location: String,
+        format: String,
+        reason: String,
+    },
+
+    /// Field default is not a value of the field's type
+    #[error("Invalid default for field '{field}': {reason}")]
+    InvalidDefault { field: String, reason: String },
+
+    /// Row filter is not a supported predicate over the contract fields
+    #[error("Invalid row filter '{filter}': {reason}")]
+    InvalidRowFilter { filter: String, reason: String },
+
+    /// Contract or changelog version is not a valid semantic version
+    #[error("Invalid version '{version}': {reason}")]
+    InvalidVersion { version: String, reason: String },
+
+    /// Conditional constraint is not made of supported predicates
+    #[error("Invalid condition for field '{field}': {reason}")]
+    InvalidCondition { field: String, reason: String },
+
+    /// Custom constraint definition is not a supported expression
+    #[error("Invalid expression for field '{field}': {reason}")]
+    InvalidExpression { field: String, reason: String },
+
+    /// Row check expression is not supported over the contract fields
+    #[error("Invalid row check '{name}': {reason}")]
+    InvalidRowCheck { name: String, reason: String },
+
+    /// Change event does not fit the CDC envelope
+    #[error("Invalid CDC envelope: {0}")]
+    CdcEnvelope(String),
+
+    /// Change events of a key arrived out of `ts_ms` order
+    #[error("CDC changes out of order: {0}")]
+    CdcOrder(String),
+
+    /// Values of a field are not found in the field they reference
+    #[error("Referential integrity violation for field '{field}': {message}")]
+    ReferentialIntegrity { field: String, message: String },
+
+    /// Event-time buckets hold fewer records than a volume check requires
+    #[error("Volume check failed for '{field}': {message}")]
+    LowVolume { field: String, message: String },
+
     /// Generic validation error
     #[error("Validation error: {0}")]
     General(String),
@@ -99,11 +156,204 @@ impl ValidationError {
         Self::QualityCheckFailed(message.into())
     }
 
+    /// Creates a new invalid location error.
+    pub fn invalid_location(
+        location: impl Into<String>,
+        format: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self::InvalidLocation {
+            location: location.into(),
+            format: format.into(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Creates a new invalid default error.
+    pub fn invalid_default(field: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::InvalidDefault {
+            field: field.into(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Creates a new invalid row filter error.
+    pub fn invalid_row_filter(filter: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::InvalidRowFilter {
+            filter: filter.into(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Creates a new invalid version error.
+    pub fn invalid_version(version: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::InvalidVersion {
+            version: version.into(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Creates a new invalid condition error.
+    pub fn invalid_condition(field: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::InvalidCondition {
+            field: field.into(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Creates a new invalid expression error.
+    pub fn invalid_expression(field: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::InvalidExpression {
+            field: field.into(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Creates a new invalid row check error.
+    pub fn invalid_row_check(name: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::InvalidRowCheck {
+            name: name.into(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Creates a new row check error.
+    pub fn row_check(name: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::RowCheckFailed {
+            name: name.into(),
+            message: message.into(),
+        }
+    }
+
     /// Creates a new custom check error.
     pub fn custom_check(name: impl Into<String>, message: impl Into<String>) -> Self {
         Self::CustomCheckFailed {
             name: name.into(),
             message: message.into(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Returns the contract field the error is about, if any.
+    pub fn field(&self) -> Option<&str> {
+        match self {
+            Self::TypeMismatch { field, .. }
+            | Self::NullConstraintViolation { field, .. }
+            | Self::ConstraintViolation { field, .. }
+            | Self::InvalidRegex { field, .. }
+            | Self::InvalidDefault { field, .. }
+            | Self::InvalidCondition { field, .. }
+            | Self::InvalidExpression { field, .. }
+            | Self::ReferentialIntegrity { field, .. }
+            | Self::LowVolume { field, .. } => Some(field),
+            Self::MissingField(field) => Some(field),
+            _ => None,
+        }
+    }
+
+    /// Returns the kind of check that reports this error.
+    pub fn check_kind(&self) -> CheckKind {
+        match self {
+            Self::SchemaError(_)
+            | Self::TypeMismatch { .. }
+            | Self::MissingField(_)
+            | Self::NullConstraintViolation { .. } => CheckKind::Schema,
+            Self::ConstraintViolation { .. } | Self::InvalidRegex { .. } | Self::CdcEnvelope(_) => {
+                CheckKind::Constraint
+            }
+            Self::QualityCheckFailed(_)
+            | Self::CdcOrder(_)
+            | Self::ReferentialIntegrity { .. }
+            | Self::LowVolume { .. } => CheckKind::Quality,
+            Self::CustomCheckFailed { .. } | Self::RowCheckFailed { .. } => CheckKind::Custom,
+            Self::StaleData { .. } | Self::InvalidDuration(_) => CheckKind::Freshness,
+            Self::InvalidLocation { .. }
+            | Self::InvalidDefault { .. }
+            | Self::InvalidRowFilter { .. }
+            | Self::InvalidVersion { .. }
+            | Self::InvalidCondition { .. }
+            | Self::InvalidExpression { .. }
+            | Self::InvalidRowCheck { .. } => CheckKind::Definition,
+            Self::General(_) => CheckKind::Execution,
+        }
+    }
+
+    /// Converts the error to an error-severity [`Violation`] with its code,
+    /// field and row.
+    pub fn to_violation(&self) -> Violation {
+        let mut violation = Violation::new(self.error_code(), self.check_kind(), self.to_string());
+        violation.field = self.field().map(str::to_string);
+        match self {
+            Self::NullConstraintViolation { row: Some(row), .. } => {
+                violation.row_index = Some(*row);
+            }
+            Self::CustomCheckFailed { rows, .. } => violation.rows = rows.clone(),
+            _ => {}
+        }
+        violation
+    }
+}
+
+impl Coded for ValidationError {
+    fn error_code(&self) -> &'static ErrorCode {
+        match self {
+            Self::SchemaError(_) => &codes::SCHEMA_ERROR,
+            Self::TypeMismatch { .. } => &codes::TYPE_MISMATCH,
+            Self::MissingField(_) => &codes::MISSING_FIELD,
+            Self::NullConstraintViolation { .. } => &codes::NULL_CONSTRAINT_VIOLATION,
+            // The row validator words violations the same way as the
+            // DataFusion engine, so both report the specific code.
+            Self::ConstraintViolation { message, .. } => {
+                // Checked first: the predicates quoted in the message could
+                // contain any of the phrases below
+                if message.starts_with("Row fails custom '") {
+                    &codes::CONSTRAINT_VIOLATION
+                } else if message.contains(" condition 'when ") {
+                    &codes::CONDITION_VIOLATION
+                } else if message.contains("not in allowed values") {
+                    &codes::NOT_IN_ALLOWED_VALUES
+                } else if message.contains("out of range") {
+                    &codes::OUT_OF_RANGE
+                } else if message.contains("does not match pattern") {
+                    &codes::PATTERN_MISMATCH
+                } else if message.contains("outside length bounds")
+                    || message.contains("empty collection")
+                {
+                    &codes::INVALID_LENGTH
+                } else if message.contains("allowed keys") || message.contains("key pattern") {
+                    &codes::INVALID_MAP_KEY
+                } else if message.contains("above the max null fraction") {
+                    &codes::NULL_FRACTION
+                } else if message.contains("outside cardinality bounds") {
+                    &codes::CARDINALITY
+                } else if message.contains("does not fit decimal(") {
+                    &codes::INVALID_PRECISION
+                } else if message.contains("outside time range")
+                    || message.contains("is in the future")
+                {
+                    &codes::OUT_OF_TIME_RANGE
+                } else {
+                    &codes::CONSTRAINT_VIOLATION
+                }
+            }
+            Self::QualityCheckFailed(_) => &codes::QUALITY_CHECK_FAILED,
+            Self::CustomCheckFailed { .. } => &codes::CUSTOM_CHECK_FAILED,
+            Self::RowCheckFailed { .. } => &codes::ROW_CHECK_FAILED,
+            Self::InvalidRegex { .. } => &codes::INVALID_REGEX,
+            Self::StaleData { .. } => &codes::STALE_DATA,
+            Self::InvalidDuration(_) => &codes::INVALID_DURATION,
+            Self::InvalidLocation { .. } => &codes::INVALID_LOCATION,
+            Self::InvalidDefault { .. } => &codes::INVALID_DEFAULT,
+            Self::InvalidRowFilter { .. } => &codes::INVALID_ROW_FILTER,
+            Self::InvalidVersion { .. } => &codes::INVALID_VERSION,
+            Self::InvalidCondition { .. } => &codes::INVALID_CONDITION,
+            Self::InvalidExpression { .. } => &codes::INVALID_EXPRESSION,
+            Self::InvalidRowCheck { .. } => &codes::INVALID_ROW_CHECK,
+            Self::CdcEnvelope(_) => &codes::CDC_ENVELOPE,
+            Self::CdcOrder(_) => &codes::CDC_ORDER,
+            Self::ReferentialIntegrity { .. } => &codes::REFERENTIAL_INTEGRITY,
+            Self::LowVolume { .. } => &codes::LOW_VOLUME,
+            Self::General(_) => &codes::GENERAL,
         }
     }
 }
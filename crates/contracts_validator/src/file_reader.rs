@@ -10,8 +10,10 @@ use tracing::info;
 /// Registers a local file as a DataFusion table named `"data"`.
 ///
 /// Uses DataFusion's built-in readers for Parquet, CSV, and NDJSON formats.
-/// When `sample_size` is provided, the table is wrapped in a `LIMIT` view
-/// (same pattern as the Iceberg native-datafusion path).
+/// When `filter` or `sample_size` is provided, the table is wrapped in a view
+/// that applies the SQL predicate (e.g. from
+/// [`row_predicate`](crate::row_predicate)) and then the
+/// `LIMIT` (same pattern as the Iceberg native-datafusion path).
 ///
 /// # Errors
 ///
@@ -21,10 +23,11 @@ pub async fn register_file_as_table(
     format: &DataFormat,
     path: &str,
     sample_size: Option<usize>,
+    filter: Option<&str>,
 ) -> Result<SessionContext, String> {
     let ctx = SessionContext::new();
 
-    let table_name = if sample_size.is_some() {
+    let table_name = if sample_size.is_some() || filter.is_some() {
         "raw_data"
     } else {
         "data"
@@ -57,17 +60,41 @@ pub async fn register_file_as_table(
         }
     }
 
+    if table_name == "raw_data" {
+        create_data_view(&ctx, filter, sample_size).await?;
+    }
+
+    Ok(ctx)
+}
+
+/// Creates the `data` view over a table registered as `"raw_data"`.
+///
+/// The view keeps the rows matching `filter`, then takes the first
+/// `sample_size` of them, so sources registered under `raw_data` (files or
+/// Iceberg tables) are windowed and filtered before they are sampled.
+///
+/// # Errors
+///
+/// Returns an error if the predicate is not valid SQL for the table.
+pub async fn create_data_view(
+    ctx: &SessionContext,
+    filter: Option<&str>,
+    sample_size: Option<usize>,
+) -> Result<(), String> {
+    let mut view = "CREATE VIEW data AS SELECT * FROM raw_data".to_string();
+    if let Some(filter) = filter {
+        info!("Filtering rows: {}", filter);
+        view.push_str(&format!(" WHERE {filter}"));
+    }
     if let Some(limit) = sample_size {
         info!("Applying sample size limit: {}", limit);
-        ctx.sql(&format!(
-            "CREATE VIEW data AS SELECT * FROM raw_data LIMIT {limit}"
-        ))
+        view.push_str(&format!(" LIMIT {limit}"));
+    }
+    ctx.sql(&view)
         .await
         .map_err(|e| format!("Failed to create sampled view: {e}"))?
         .collect()
         .await
         .map_err(|e| format!("Failed to materialise sampled view: {e}"))?;
-    }
-
-    Ok(ctx)
+    Ok(())
 }
@@ -0,0 +1,117 @@
+//! Violations collected during a run, filed by severity.
+//!
+//! Checks that declare a [`Severity`] are filed under it. Checks without one
+//! are filed under a default: constraint violations are errors, quality
+//! checks follow [`Severity::default_for`] the run's strict flag.
+//!
+//! Violations beyond the context's error limits are counted instead of
+//! filed, so a run over a badly broken dataset keeps a bounded report.
+
+use crate::ValidationError;
+use contracts_core::{
+    CheckKind, Contract, ErrorCode, Severity, ValidationContext, Violation, ViolationLimits,
+};
+
+/// Violations of the failed checks of one run.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct Findings {
+    pub(crate) errors: Vec<Violation>,
+    pub(crate) warnings: Vec<Violation>,
+    pub(crate) info: Vec<Violation>,
+    limits: ViolationLimits,
+}
+
+impl Findings {
+    /// Starts filing the violations of a run with the limits of `context`.
+    pub(crate) fn new(context: &ValidationContext) -> Self {
+        Self {
+            limits: ViolationLimits::new(context),
+            ..Self::default()
+        }
+    }
+
+    /// Files `violation` under its severity, unless the limits leave it out.
+    pub(crate) fn push(&mut self, violation: Violation) {
+        if !self.limits.admit(&violation) {
+            return;
+        }
+        match violation.severity {
+            Severity::Error => self.errors.push(violation),
+            Severity::Warning => self.warnings.push(violation),
+            Severity::Info => self.info.push(violation),
+        }
+    }
+
+    /// Files every violation under `severity`.
+    pub(crate) fn extend(
+        &mut self,
+        severity: Severity,
+        violations: impl IntoIterator<Item = Violation>,
+    ) {
+        for violation in violations {
+            self.push(violation.with_severity(severity));
+        }
+    }
+
+    /// Files validation errors under `severity`.
+    pub(crate) fn extend_errors<'a>(
+        &mut self,
+        severity: Severity,
+        errors: impl IntoIterator<Item = &'a ValidationError>,
+    ) {
+        self.extend(severity, errors.into_iter().map(|e| e.to_violation()));
+    }
+
+    /// Returns true once a fail-fast run has an error, so the checks that
+    /// have not run yet are skipped.
+    pub(crate) fn fails_fast(&self, context: &ValidationContext) -> bool {
+        context.fail_fast && !self.errors.is_empty()
+    }
+
+    /// Returns the number of violations the limits left out.
+    pub(crate) fn truncated(&self) -> usize {
+        self.limits.truncated()
+    }
+
+    /// Records an error that kept a check from running.
+    pub(crate) fn execution_error(&mut self, code: &'static ErrorCode, message: String) {
+        self.push(Violation::new(code, CheckKind::Execution, message));
+    }
+}
+
+/// Severity of a constraint violation on `field`: the field's own, or error.
+pub(crate) fn constraint_severity(contract: &Contract, field: Option<&str>) -> Severity {
+    field
+        .and_then(|name| contract.field(name))
+        .and_then(|f| f.severity)
+        .unwrap_or(Severity::Error)
+}
+
+/// Severity of a custom check, from its free-form `severity` string.
+///
+/// Unknown values are warnings; checks without a severity follow `default`.
+pub(crate) fn custom_severity(severity: Option<&str>, default: Severity) -> Severity {
+    match severity {
+        Some(severity) => severity.parse().unwrap_or(Severity::Warning),
+        None => default,
+    }
+}
+
+/// Severity of the contract's freshness check, or `default` when it has none.
+pub(crate) fn freshness_severity(contract: &Contract, default: Severity) -> Severity {
+    contract
+        .quality_checks
+        .as_ref()
+        .and_then(|qc| qc.freshness.as_ref())
+        .and_then(|check| check.severity)
+        .unwrap_or(default)
+}
+
+/// Marks violations reported by ML checks, which share the quality check
+/// error types.
+pub(crate) fn ml_violation(error: &ValidationError) -> Violation {
+    Violation {
+        check_kind: CheckKind::Ml,
+        ..error.to_violation()
+    }
+}
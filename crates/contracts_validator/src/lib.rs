@@ -33,24 +33,65 @@
 //! }
 //! ```
 
+mod cdc;
 mod constraints;
 mod custom;
 mod datafusion_engine;
 mod dataset;
+mod defaults;
+mod deprecation;
+mod descriptions;
 mod engine;
 mod error;
 mod file_reader;
+mod findings;
+mod limits;
+mod lint;
+mod location;
 mod ml;
+mod parallel;
+mod preview;
+mod profile;
 mod quality;
+mod reconcile;
+mod referential;
+mod row_filter;
+mod sample_size;
+mod sampling;
 mod schema;
+mod segments;
+mod sketch;
+mod soft_delete;
+mod stages;
+mod stats;
+mod stats_checks;
+mod streaming;
+mod time_window;
+mod volume;
 
 pub use constraints::*;
 pub use custom::*;
 pub use datafusion_engine::*;
 pub use dataset::*;
+pub use defaults::default_value;
+pub use descriptions::*;
 pub use engine::*;
 pub use error::*;
 pub use file_reader::*;
+pub use limits::{LimitedStore, RequestLimiter, RequestLimits, RequestPermit};
+pub use lint::*;
+pub use location::*;
 pub use ml::*;
+pub use preview::*;
+pub use profile::*;
 pub use quality::*;
+pub use reconcile::*;
+pub use referential::check_references;
+pub use row_filter::row_predicate;
+pub use sampling::{Sampler, proportional_quotas};
 pub use schema::*;
+pub use stages::*;
+pub use stats::*;
+pub use stats_checks::{check_column_stats, check_metadata_freshness};
+pub use streaming::*;
+pub use time_window::*;
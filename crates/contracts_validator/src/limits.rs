@@ -0,0 +1,309 @@
+//! Concurrency and rate limits for catalog and storage requests.
+//!
+//! Validating many tables at once can overload a REST or Glue catalog or an
+//! object store. A [`RequestLimiter`] is shared by every reader of a run:
+//! each catalog connection and table load waits for it, and so does each
+//! object-store request once the store is wrapped in a [`LimitedStore`].
+
+use async_trait::async_trait;
+use futures::stream::{self, BoxStream, StreamExt};
+use object_store::path::Path;
+use object_store::{
+    GetOptions, GetResult, GetResultPayload, ListResult, MultipartUpload, ObjectMeta, ObjectStore,
+    PutMultipartOptions, PutOptions, PutPayload, PutResult,
+};
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Mutex, OwnedSemaphorePermit, Semaphore};
+use tokio::time::Instant;
+
+/// Limits applied to catalog and storage requests.
+///
+/// Both limits are off by default.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RequestLimits {
+    /// Maximum number of requests in flight at once
+    pub max_concurrent: Option<usize>,
+
+    /// Maximum number of requests started per second
+    pub per_second: Option<f64>,
+}
+
+impl RequestLimits {
+    /// No limits.
+    pub fn unlimited() -> Self {
+        Self::default()
+    }
+
+    /// Limits the number of requests in flight at once.
+    #[must_use]
+    pub fn max_concurrent(mut self, max: usize) -> Self {
+        self.max_concurrent = Some(max);
+        self
+    }
+
+    /// Limits the number of requests started per second.
+    #[must_use]
+    pub fn per_second(mut self, rate: f64) -> Self {
+        self.per_second = Some(rate);
+        self
+    }
+}
+
+/// Enforces [`RequestLimits`] across every request that shares it.
+///
+/// Cloning is cheap and clones share the same limits.
+///
+/// # Example
+///
+/// ```rust
+/// use contracts_validator::{RequestLimiter, RequestLimits};
+///
+/// # async fn example() {
+/// let limiter = RequestLimiter::new(RequestLimits::unlimited().max_concurrent(4).per_second(10.0));
+///
+/// let permit = limiter.acquire().await;
+/// // ... make the request while holding `permit` ...
+/// drop(permit);
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RequestLimiter {
+    limits: RequestLimits,
+    concurrency: Option<Arc<Semaphore>>,
+    /// Earliest time the next request may start, when rate limited
+    next_slot: Arc<Mutex<Option<Instant>>>,
+}
+
+/// Permission to make one request, released when dropped.
+#[must_use = "the request slot is released as soon as the permit is dropped"]
+#[derive(Debug)]
+pub struct RequestPermit {
+    _concurrency: Option<OwnedSemaphorePermit>,
+}
+
+impl RequestLimiter {
+    /// Creates a limiter enforcing `limits`.
+    ///
+    /// A concurrency limit of 0 is treated as 1, and a non-positive rate as no rate limit.
+    pub fn new(limits: RequestLimits) -> Self {
+        Self {
+            limits,
+            concurrency: limits
+                .max_concurrent
+                .map(|max| Arc::new(Semaphore::new(max.max(1)))),
+            next_slot: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Returns the limits this limiter enforces.
+    pub fn limits(&self) -> RequestLimits {
+        self.limits
+    }
+
+    /// Waits until a request may start under both limits.
+    pub async fn acquire(&self) -> RequestPermit {
+        let concurrency = match &self.concurrency {
+            Some(semaphore) => Some(
+                Arc::clone(semaphore)
+                    .acquire_owned()
+                    .await
+                    .expect("request semaphore is never closed"),
+            ),
+            None => None,
+        };
+
+        if let Some(rate) = self.limits.per_second.filter(|r| *r > 0.0) {
+            let interval = Duration::from_secs_f64(1.0 / rate);
+            let start = {
+                let mut next_slot = self.next_slot.lock().await;
+                let now = Instant::now();
+                let start = next_slot.map_or(now, |slot| slot.max(now));
+                *next_slot = Some(start + interval);
+                start
+            };
+            tokio::time::sleep_until(start).await;
+        }
+
+        RequestPermit {
+            _concurrency: concurrency,
+        }
+    }
+}
+
+/// An object store whose requests each wait for a [`RequestLimiter`].
+///
+/// A read holds its request slot until its body has been consumed, and a
+/// listing until its last page.
+///
+/// # Example
+///
+/// ```rust
+/// use contracts_validator::{LimitedStore, RequestLimiter, RequestLimits};
+/// use object_store::memory::InMemory;
+/// use std::sync::Arc;
+///
+/// let limiter = RequestLimiter::new(RequestLimits::unlimited().max_concurrent(4));
+/// let store = LimitedStore::new(Arc::new(InMemory::new()), limiter);
+/// ```
+#[derive(Debug)]
+pub struct LimitedStore {
+    inner: Arc<dyn ObjectStore>,
+    limiter: RequestLimiter,
+}
+
+impl LimitedStore {
+    /// Wraps `inner` so its requests wait for `limiter`.
+    pub fn new(inner: Arc<dyn ObjectStore>, limiter: RequestLimiter) -> Self {
+        Self { inner, limiter }
+    }
+}
+
+impl fmt::Display for LimitedStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Limited({})", self.inner)
+    }
+}
+
+#[async_trait]
+impl ObjectStore for LimitedStore {
+    async fn put_opts(
+        &self,
+        location: &Path,
+        payload: PutPayload,
+        opts: PutOptions,
+    ) -> object_store::Result<PutResult> {
+        let _permit = self.limiter.acquire().await;
+        self.inner.put_opts(location, payload, opts).await
+    }
+
+    async fn put_multipart_opts(
+        &self,
+        location: &Path,
+        opts: PutMultipartOptions,
+    ) -> object_store::Result<Box<dyn MultipartUpload>> {
+        let _permit = self.limiter.acquire().await;
+        self.inner.put_multipart_opts(location, opts).await
+    }
+
+    async fn get_opts(
+        &self,
+        location: &Path,
+        options: GetOptions,
+    ) -> object_store::Result<GetResult> {
+        let permit = self.limiter.acquire().await;
+        let mut result = self.inner.get_opts(location, options).await?;
+        // The body is streamed after the response arrives, so the slot is
+        // kept until the stream is dropped
+        result.payload = match result.payload {
+            GetResultPayload::Stream(body) => GetResultPayload::Stream(
+                body.map(move |chunk| {
+                    let _permit = &permit;
+                    chunk
+                })
+                .boxed(),
+            ),
+            payload => payload,
+        };
+        Ok(result)
+    }
+
+    async fn delete(&self, location: &Path) -> object_store::Result<()> {
+        let _permit = self.limiter.acquire().await;
+        self.inner.delete(location).await
+    }
+
+    fn list(&self, prefix: Option<&Path>) -> BoxStream<'static, object_store::Result<ObjectMeta>> {
+        let inner = Arc::clone(&self.inner);
+        let limiter = self.limiter.clone();
+        let prefix = prefix.cloned();
+        stream::once(async move { limiter.acquire().await })
+            .flat_map(move |permit| {
+                inner.list(prefix.as_ref()).map(move |meta| {
+                    let _permit = &permit;
+                    meta
+                })
+            })
+            .boxed()
+    }
+
+    async fn list_with_delimiter(&self, prefix: Option<&Path>) -> object_store::Result<ListResult> {
+        let _permit = self.limiter.acquire().await;
+        self.inner.list_with_delimiter(prefix).await
+    }
+
+    async fn copy(&self, from: &Path, to: &Path) -> object_store::Result<()> {
+        let _permit = self.limiter.acquire().await;
+        self.inner.copy(from, to).await
+    }
+
+    async fn copy_if_not_exists(&self, from: &Path, to: &Path) -> object_store::Result<()> {
+        let _permit = self.limiter.acquire().await;
+        self.inner.copy_if_not_exists(from, to).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn test_concurrency_limit() {
+        let limiter = RequestLimiter::new(RequestLimits::unlimited().max_concurrent(2));
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+
+        let tasks: Vec<_> = (0..6)
+            .map(|_| {
+                let limiter = limiter.clone();
+                let in_flight = Arc::clone(&in_flight);
+                let peak = Arc::clone(&peak);
+                tokio::spawn(async move {
+                    let _permit = limiter.acquire().await;
+                    let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(now, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                })
+            })
+            .collect();
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_spaces_requests() {
+        let limiter = RequestLimiter::new(RequestLimits::unlimited().per_second(50.0));
+        let started = Instant::now();
+        for _ in 0..4 {
+            let _permit = limiter.acquire().await;
+        }
+
+        // The first request starts immediately, the next three 20ms apart
+        assert!(started.elapsed() >= Duration::from_millis(60));
+    }
+
+    #[tokio::test]
+    async fn test_limited_store_holds_reads_until_consumed() {
+        use object_store::memory::InMemory;
+
+        let limiter = RequestLimiter::new(RequestLimits::unlimited().max_concurrent(1));
+        let store = LimitedStore::new(Arc::new(InMemory::new()), limiter);
+        let path = Path::from("events/part-0.csv");
+        store.put(&path, "id\n1\n".into()).await.unwrap();
+
+        let read = store.get(&path).await.unwrap();
+        let blocked = tokio::time::timeout(Duration::from_millis(50), store.head(&path)).await;
+        assert!(blocked.is_err(), "head ran while a read held the only slot");
+
+        assert_eq!(read.bytes().await.unwrap().as_ref(), b"id\n1\n");
+        assert_eq!(store.head(&path).await.unwrap().size, 5);
+        let listed: Vec<_> = store.list(None).collect().await;
+        assert_eq!(listed.len(), 1);
+    }
+}
@@ -0,0 +1,1340 @@
+//! Static checks of contract definitions.
+//!
+//! Linting looks only at the contract, never at data. Errors describe
+//! contracts that cannot validate correctly (a quality check naming a field
+//! the schema does not declare, a threshold outside `0..=1`); warnings flag
+//! definitions that are valid but likely unintended.
+
+use crate::constraints::{TimeBounds, describe_time_bounds};
+use crate::quality::ScopeGroups;
+use crate::sketch::HyperLogLog;
+use crate::{DescriptionRules, SchemaValidator, ValidationError, custom::parse_duration};
+use chrono::Utc;
+use contracts_core::{
+    Contract, DataFormat, DataType, FieldConstraints, PrimitiveType, TagTaxonomy,
+};
+use serde::Serialize;
+use std::collections::HashSet;
+
+/// How serious a lint finding is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintSeverity {
+    /// The contract cannot be validated as written
+    Error,
+    /// The contract is valid but probably not what was meant
+    Warning,
+}
+
+/// A single problem found in a contract definition.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LintFinding {
+    /// Severity of the finding
+    pub severity: LintSeverity,
+
+    /// Stable identifier of the rule that produced the finding
+    pub rule: &'static str,
+
+    /// Dotted path of the offending contract element
+    pub path: String,
+
+    /// Human-readable explanation
+    pub message: String,
+}
+
+impl LintFinding {
+    pub(crate) fn error(
+        rule: &'static str,
+        path: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            severity: LintSeverity::Error,
+            rule,
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+
+    pub(crate) fn warning(
+        rule: &'static str,
+        path: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            severity: LintSeverity::Warning,
+            rule,
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Checks contract definitions for mistakes before any data is read.
+///
+/// # Example
+///
+/// ```rust
+/// use contracts_core::{CompletenessCheck, ContractBuilder, DataFormat, FieldBuilder, QualityChecks};
+/// use contracts_validator::{ContractLinter, LintSeverity};
+///
+/// let contract = ContractBuilder::new("users", "team")
+///     .description("Registered users")
+///     .location("s3://lake/users")
+///     .format(DataFormat::Iceberg)
+///     .field(FieldBuilder::new("id", "int64").nullable(false).build())
+///     .quality_checks(QualityChecks {
+///         completeness: Some(CompletenessCheck {
+///             threshold: 0.99,
+///             fields: vec!["email".to_string()],
+///             min_sample_size: None,
+///             severity: None,
+///         }),
+///         ..Default::default()
+///     })
+///     .build();
+///
+/// let findings = ContractLinter::new().lint(&contract);
+/// assert_eq!(findings.len(), 1);
+/// assert_eq!(findings[0].severity, LintSeverity::Error);
+/// assert_eq!(findings[0].rule, "unknown-field");
+/// ```
+pub struct ContractLinter {
+    schema_validator: SchemaValidator,
+    extensions_schema: Option<jsonschema::Validator>,
+    description_rules: Option<DescriptionRules>,
+    tag_taxonomy: Option<TagTaxonomy>,
+}
+
+impl ContractLinter {
+    /// Creates a new linter.
+    pub fn new() -> Self {
+        Self {
+            schema_validator: SchemaValidator::new(),
+            extensions_schema: None,
+            description_rules: None,
+            tag_taxonomy: None,
+        }
+    }
+
+    /// Also checks the contract's `x-extensions` block against a JSON Schema.
+    ///
+    /// A contract without the block is checked as an empty object, so schemas
+    /// with `required` keys report it. Fails when `schema` is not itself a
+    /// valid JSON Schema.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use contracts_core::{ContractBuilder, DataFormat, FieldBuilder};
+    /// use contracts_validator::ContractLinter;
+    /// use serde_json::json;
+    ///
+    /// let linter = ContractLinter::new()
+    ///     .with_extensions_schema(&json!({
+    ///         "type": "object",
+    ///         "properties": { "data_tier": { "enum": ["gold", "silver", "bronze"] } },
+    ///         "required": ["data_tier"],
+    ///     }))
+    ///     .unwrap();
+    ///
+    /// let contract = ContractBuilder::new("users", "team")
+    ///     .description("Registered users")
+    ///     .location("s3://lake/users")
+    ///     .format(DataFormat::Iceberg)
+    ///     .field(FieldBuilder::new("id", "int64").nullable(false).build())
+    ///     .extension("data_tier", "platinum")
+    ///     .build();
+    ///
+    /// let findings = linter.lint(&contract);
+    /// assert_eq!(findings.len(), 1);
+    /// assert_eq!(findings[0].rule, "extensions-schema");
+    /// assert_eq!(findings[0].path, "x-extensions.data_tier");
+    /// ```
+    pub fn with_extensions_schema(
+        mut self,
+        schema: &serde_json::Value,
+    ) -> Result<Self, ValidationError> {
+        let validator = jsonschema::validator_for(schema).map_err(|e| {
+            ValidationError::General(format!("Invalid extensions JSON Schema: {}", e))
+        })?;
+        self.extensions_schema = Some(validator);
+        Ok(self)
+    }
+
+    /// Also checks that descriptions are informative: not placeholders, not
+    /// the field name again, long enough, and with a unit for numeric fields.
+    pub fn with_description_rules(mut self, rules: DescriptionRules) -> Self {
+        self.description_rules = Some(rules);
+        self
+    }
+
+    /// Also checks field tags against a taxonomy: tags it does not declare
+    /// are errors, deprecated ones warnings.
+    pub fn with_tag_taxonomy(mut self, taxonomy: TagTaxonomy) -> Self {
+        self.tag_taxonomy = Some(taxonomy);
+        self
+    }
+
+    /// Returns every finding for `contract`, errors first.
+    ///
+    /// The related datasets of a multi-dataset contract are checked like the
+    /// contract itself, under `datasets.<name>` paths.
+    pub fn lint(&self, contract: &Contract) -> Vec<LintFinding> {
+        let mut findings = self.lint_dataset(contract);
+
+        if contract
+            .description
+            .as_deref()
+            .is_none_or(|d| d.trim().is_empty())
+        {
+            findings.push(LintFinding::warning(
+                "missing-description",
+                "description",
+                "Contract has no description",
+            ));
+        }
+
+        self.lint_extensions(contract, &mut findings);
+        lint_conformance(contract, &mut findings);
+        findings.extend(self.lint_sla(contract));
+        lint_references(contract, &mut findings);
+
+        for (name, dataset) in contract.dataset_contracts() {
+            findings.extend(self.lint_dataset(&dataset).into_iter().map(|mut finding| {
+                finding.path = format!("datasets.{}.{}", name, finding.path);
+                finding
+            }));
+        }
+
+        findings.sort_by_key(|f| f.severity != LintSeverity::Error);
+        findings
+    }
+
+    /// Returns the findings for the schema and checks of one dataset.
+    fn lint_dataset(&self, contract: &Contract) -> Vec<LintFinding> {
+        let mut findings: Vec<LintFinding> = self
+            .schema_validator
+            .validate_schema_definition(contract)
+            .into_iter()
+            .map(|e| LintFinding::error("schema-definition", "schema", e.to_string()))
+            .collect();
+        findings.extend(self.lint_quality(contract));
+        self.lint_deprecations(contract, &mut findings);
+        lint_constraint_bounds(contract, &mut findings);
+        if let Some(rules) = &self.description_rules {
+            rules.lint(contract, &mut findings);
+        }
+        self.lint_tags(contract, &mut findings);
+        findings
+    }
+
+    /// Returns the findings for `quality_checks` alone.
+    pub(crate) fn lint_quality(&self, contract: &Contract) -> Vec<LintFinding> {
+        let mut findings = Vec::new();
+        self.lint_field_references(contract, &mut findings);
+        self.lint_quality_checks(contract, &mut findings);
+        findings
+    }
+
+    /// Returns the findings for `sla` alone.
+    pub(crate) fn lint_sla(&self, contract: &Contract) -> Vec<LintFinding> {
+        let mut findings = Vec::new();
+        let Some(sla) = &contract.sla else {
+            return findings;
+        };
+        if let Some(availability) = sla.availability {
+            check_ratio(&mut findings, "sla.availability", availability);
+        }
+        if sla
+            .response_time
+            .as_deref()
+            .is_some_and(|t| t.trim().is_empty())
+        {
+            findings.push(LintFinding::error(
+                "empty-response-time",
+                "sla.response_time",
+                "SLA response time is empty",
+            ));
+        }
+        findings
+    }
+
+    /// Reports checks that name fields missing from the schema.
+    fn lint_field_references(&self, contract: &Contract, findings: &mut Vec<LintFinding>) {
+        let declared: HashSet<&str> = contract
+            .schema
+            .fields
+            .iter()
+            .map(|f| f.name.as_str())
+            .collect();
+
+        for (path, field) in referenced_fields(contract) {
+            if !declared.contains(field) {
+                findings.push(LintFinding::error(
+                    "unknown-field",
+                    path,
+                    format!("Field '{}' is not declared in the schema", field),
+                ));
+            }
+        }
+    }
+
+    /// Reports checks that still use deprecated fields, expired fields still
+    /// declared, and replacements missing from the schema.
+    fn lint_deprecations(&self, contract: &Contract, findings: &mut Vec<LintFinding>) {
+        let today = Utc::now().date_naive();
+
+        for field in &contract.schema.fields {
+            let Some(deprecation) = &field.deprecated else {
+                continue;
+            };
+            let path = format!("schema.fields.{}.deprecated", field.name);
+            if let Some(replacement) = &deprecation.replacement
+                && !contract.has_field(replacement)
+            {
+                findings.push(LintFinding::error(
+                    "unknown-field",
+                    format!("{}.replacement", path),
+                    format!("Field '{}' is not declared in the schema", replacement),
+                ));
+            }
+            if let Some(date) = deprecation.remove_after
+                && deprecation.is_expired(today)
+            {
+                findings.push(LintFinding::warning(
+                    "expired-field",
+                    format!("{}.remove_after", path),
+                    format!(
+                        "Field '{}' is past its removal date {} and can be removed",
+                        field.name, date
+                    ),
+                ));
+            }
+        }
+
+        for (path, name) in referenced_fields(contract) {
+            let Some(deprecation) = contract.field(name).and_then(|f| f.deprecated.as_ref()) else {
+                continue;
+            };
+            let mut message = format!("Field '{}' is deprecated", name);
+            if let Some(date) = deprecation.remove_after {
+                message.push_str(&format!(" and may be removed after {}", date));
+            }
+            if let Some(replacement) = &deprecation.replacement {
+                message.push_str(&format!("; use '{}' instead", replacement));
+            }
+            findings.push(LintFinding::warning("deprecated-field", path, message));
+        }
+    }
+
+    fn lint_quality_checks(&self, contract: &Contract, findings: &mut Vec<LintFinding>) {
+        let Some(qc) = &contract.quality_checks else {
+            return;
+        };
+
+        if let Some(completeness) = &qc.completeness {
+            check_ratio(
+                findings,
+                "quality_checks.completeness.threshold",
+                completeness.threshold,
+            );
+        }
+
+        if let Some(uniqueness) = &qc.uniqueness
+            && let [name] = uniqueness.fields.as_slice()
+            && contract.field(name).is_some_and(|f| f.nullable)
+        {
+            findings.push(LintFinding::warning(
+                "nullable-unique-field",
+                "quality_checks.uniqueness.fields",
+                format!(
+                    "Field '{}' must be unique but is nullable; repeated nulls count as duplicates",
+                    name
+                ),
+            ));
+        }
+
+        if let Some(uniqueness) = &qc.uniqueness
+            && let Err(message) = ScopeGroups::resolve(uniqueness, contract)
+        {
+            findings.push(LintFinding::error(
+                "invalid-scope",
+                "quality_checks.uniqueness.scope",
+                message,
+            ));
+        }
+
+        if let Some(approximate) = qc.uniqueness.as_ref().and_then(|u| u.approximate.as_ref()) {
+            let tolerance = approximate.error_tolerance();
+            if tolerance <= 0.0 || tolerance >= 1.0 {
+                findings.push(LintFinding::error(
+                    "ratio-range",
+                    "quality_checks.uniqueness.approximate.error_tolerance",
+                    format!("{} must be above 0 and below 1", tolerance),
+                ));
+            } else if let Some(kb) = approximate.max_memory_kb {
+                let sketch = HyperLogLog::with_tolerance(tolerance, Some(kb * 1024));
+                if sketch.standard_error() > tolerance {
+                    findings.push(LintFinding::warning(
+                        "memory-cap",
+                        "quality_checks.uniqueness.approximate.max_memory_kb",
+                        format!(
+                            "{} KiB cannot reach an error tolerance of {}; the sketch's error is {:.4}",
+                            kb,
+                            tolerance,
+                            sketch.standard_error()
+                        ),
+                    ));
+                }
+            }
+        }
+
+        if let Some(freshness) = &qc.freshness {
+            if let Err(e) = parse_duration(&freshness.max_delay) {
+                findings.push(LintFinding::error(
+                    "invalid-duration",
+                    "quality_checks.freshness.max_delay",
+                    e.to_string(),
+                ));
+            }
+            if let Some(Err(e)) = freshness.allowed_lateness.as_deref().map(parse_duration) {
+                findings.push(LintFinding::error(
+                    "invalid-duration",
+                    "quality_checks.freshness.allowed_lateness",
+                    e.to_string(),
+                ));
+            }
+            let is_temporal = |f: &&contracts_core::Field| {
+                matches!(
+                    f.field_type,
+                    DataType::Primitive(PrimitiveType::Timestamp | PrimitiveType::Date)
+                )
+            };
+            let source = freshness.source();
+            if source.is_metadata() && contract.schema.format != DataFormat::Iceberg {
+                findings.push(LintFinding::error(
+                    "freshness-source-format",
+                    "quality_checks.freshness.source",
+                    format!(
+                        "Freshness source '{}' reads Iceberg table metadata, which {:?} data does not have",
+                        source.as_str(),
+                        contract.schema.format
+                    ),
+                ));
+            }
+            if freshness.column().is_some() && freshness.metric.is_empty() {
+                findings.push(LintFinding::error(
+                    "missing-freshness-metric",
+                    "quality_checks.freshness.metric",
+                    format!(
+                        "Freshness source '{}' needs a metric column",
+                        source.as_str()
+                    ),
+                ));
+            }
+            if let Some(metric) = contract.field(&freshness.metric)
+                && !is_temporal(&metric)
+            {
+                findings.push(LintFinding::warning(
+                    "freshness-metric-type",
+                    "quality_checks.freshness.metric",
+                    format!(
+                        "Freshness metric '{}' has type {}; only timestamp and date values are checked",
+                        metric.name, metric.field_type
+                    ),
+                ));
+            }
+        }
+
+        if let Some(volume) = &qc.volume {
+            match parse_duration(&volume.bucket) {
+                Err(e) => findings.push(LintFinding::error(
+                    "invalid-duration",
+                    "quality_checks.volume.bucket",
+                    e.to_string(),
+                )),
+                Ok(bucket) if bucket.num_seconds() <= 0 => findings.push(LintFinding::error(
+                    "invalid-duration",
+                    "quality_checks.volume.bucket",
+                    format!(
+                        "Volume bucket '{}' must be at least one second",
+                        volume.bucket
+                    ),
+                )),
+                Ok(_) => {}
+            }
+            if let Some(Err(e)) = volume.allowed_lateness.as_deref().map(parse_duration) {
+                findings.push(LintFinding::error(
+                    "invalid-duration",
+                    "quality_checks.volume.allowed_lateness",
+                    e.to_string(),
+                ));
+            }
+            let columns = [
+                ("metric", "metric", Some(&volume.metric)),
+                (
+                    "arrival_metric",
+                    "arrival metric",
+                    volume.arrival_metric.as_ref(),
+                ),
+            ];
+            for (key, label, column) in columns {
+                if let Some(field) = column.and_then(|c| contract.field(c))
+                    && !matches!(
+                        field.field_type,
+                        DataType::Primitive(PrimitiveType::Timestamp | PrimitiveType::Date)
+                    )
+                {
+                    findings.push(LintFinding::warning(
+                        "volume-metric-type",
+                        format!("quality_checks.volume.{}", key),
+                        format!(
+                            "Volume {} '{}' has type {}; only timestamp and date values are counted",
+                            label, field.name, field.field_type
+                        ),
+                    ));
+                }
+            }
+        }
+
+        for (i, check) in qc.custom_checks.iter().flatten().enumerate() {
+            if let Some(severity) = &check.severity
+                && !matches!(severity.as_str(), "error" | "warning" | "info")
+            {
+                findings.push(LintFinding::warning(
+                    "custom-check-severity",
+                    format!("quality_checks.custom_checks[{}].severity", i),
+                    format!(
+                        "Unknown severity '{}' for custom check '{}' is treated as a warning",
+                        severity, check.name
+                    ),
+                ));
+            }
+        }
+
+        if let Some(deleted) = &qc.deleted_rows {
+            check_ratio(
+                findings,
+                "quality_checks.deleted_rows.max_fraction",
+                deleted.max_fraction,
+            );
+            if contract.schema.format != DataFormat::Iceberg {
+                findings.push(LintFinding::warning(
+                    "deleted-rows-format",
+                    "quality_checks.deleted_rows",
+                    format!(
+                        "Only Iceberg tables have row-level deletes; the check is skipped for {:?} data",
+                        contract.schema.format
+                    ),
+                ));
+            }
+        }
+
+        if let Some(balance) = qc
+            .ml_checks
+            .as_ref()
+            .and_then(|ml| ml.class_balance.as_ref())
+        {
+            check_ratio(
+                findings,
+                "quality_checks.ml_checks.class_balance.max_proportion",
+                balance.max_proportion,
+            );
+            if let Some(min) = balance.min_proportion {
+                check_ratio(
+                    findings,
+                    "quality_checks.ml_checks.class_balance.min_proportion",
+                    min,
+                );
+            }
+        }
+    }
+
+    /// Reports field tags missing from the taxonomy or deprecated in it.
+    fn lint_tags(&self, contract: &Contract, findings: &mut Vec<LintFinding>) {
+        let Some(taxonomy) = &self.tag_taxonomy else {
+            return;
+        };
+
+        for field in &contract.schema.fields {
+            let path = format!("schema.fields.{}.tags", field.name);
+            for tag in field.tags.iter().flatten() {
+                if taxonomy.get(tag).is_none() {
+                    let mut message = format!(
+                        "Tag '{}' of field '{}' is not in the tag taxonomy",
+                        tag, field.name
+                    );
+                    // Suggest the tag with another case, or under a parent
+                    let suggestion = taxonomy.paths().into_iter().find(|known| {
+                        known.eq_ignore_ascii_case(tag)
+                            || known.rsplit('.').next() == Some(tag.as_str())
+                    });
+                    if let Some(suggestion) = suggestion {
+                        message.push_str(&format!("; did you mean '{}'?", suggestion));
+                    }
+                    findings.push(LintFinding::error("unknown-tag", &path, message));
+                } else if let Some(deprecated) = taxonomy.deprecation(tag) {
+                    let mut message =
+                        format!("Tag '{}' of field '{}' is deprecated", tag, field.name);
+                    if let Some(replacement) = &deprecated.replacement {
+                        message.push_str(&format!("; use '{}' instead", replacement));
+                    }
+                    findings.push(LintFinding::warning("deprecated-tag", &path, message));
+                }
+            }
+        }
+    }
+
+    /// Reports `x-extensions` entries rejected by the extensions schema.
+    fn lint_extensions(&self, contract: &Contract, findings: &mut Vec<LintFinding>) {
+        let Some(validator) = &self.extensions_schema else {
+            return;
+        };
+        let instance = serde_json::to_value(contract.extensions.clone().unwrap_or_default())
+            .unwrap_or_default();
+
+        for error in validator.iter_errors(&instance) {
+            let mut path = "x-extensions".to_string();
+            for segment in error.instance_path().to_string().split('/').skip(1) {
+                path.push('.');
+                path.push_str(&segment.replace("~1", "/").replace("~0", "~"));
+            }
+            findings.push(LintFinding::error(
+                "extensions-schema",
+                path,
+                error.to_string(),
+            ));
+        }
+    }
+}
+
+impl Default for ContractLinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Reports referential checks of the contract and its datasets whose
+/// `references` do not name a field of one of them.
+fn lint_references(contract: &Contract, findings: &mut Vec<LintFinding>) {
+    let datasets = contract.dataset_contracts();
+    let parts = std::iter::once(("", contract))
+        .chain(datasets.iter().map(|(name, dataset)| (*name, dataset)));
+    for (name, part) in parts {
+        let prefix = if name.is_empty() {
+            String::new()
+        } else {
+            format!("datasets.{}.", name)
+        };
+        let checks = part
+            .quality_checks
+            .as_ref()
+            .and_then(|qc| qc.referential.as_ref());
+        for (i, check) in checks.into_iter().flatten().enumerate() {
+            let path = format!("{}quality_checks.referential.{}", prefix, i);
+            if let Some(threshold) = check.threshold {
+                check_ratio(findings, &format!("{}.threshold", path), threshold);
+            }
+            let path = format!("{}.references", path);
+            let Some((dataset, field)) = check.target() else {
+                findings.push(LintFinding::error(
+                    "invalid-reference",
+                    path,
+                    format!("'{}' is not of the form dataset.field", check.references),
+                ));
+                continue;
+            };
+            match contract.dataset_schema(dataset) {
+                None => findings.push(LintFinding::error(
+                    "unknown-dataset",
+                    path,
+                    format!(
+                        "Dataset '{}' is neither the contract nor one of its datasets",
+                        dataset
+                    ),
+                )),
+                Some(schema) if !schema.fields.iter().any(|f| f.name == field) => {
+                    findings.push(LintFinding::error(
+                        "unknown-field",
+                        path,
+                        format!("Field '{}' is not declared in dataset '{}'", field, dataset),
+                    ))
+                }
+                Some(_) => {}
+            }
+        }
+    }
+}
+
+/// Reports `maxnullfraction` constraints outside 0..=1, length or
+/// `cardinality` constraints whose minimum exceeds their maximum and
+/// `precision` constraints with a scale above the precision, and `timerange`
+/// constraints whose bounds do not parse or are reversed.
+fn lint_constraint_bounds(contract: &Contract, findings: &mut Vec<LintFinding>) {
+    for field in &contract.schema.fields {
+        let path = format!("schema.fields.{}.constraints", field.name);
+        for constraint in field.constraints.iter().flatten() {
+            let (kind, min, max) = match constraint {
+                FieldConstraints::MaxNullFraction { fraction } => {
+                    check_ratio(findings, &format!("{}.maxnullfraction", path), *fraction);
+                    continue;
+                }
+                FieldConstraints::Precision { precision, scale } => {
+                    if *precision == 0 || scale > precision {
+                        findings.push(LintFinding::error(
+                            "invalid-bounds",
+                            format!("{}.precision", path),
+                            format!(
+                                "Precision {} cannot hold scale {} for field '{}'",
+                                precision, scale, field.name
+                            ),
+                        ));
+                    }
+                    continue;
+                }
+                FieldConstraints::TimeRange {
+                    min,
+                    max,
+                    allow_future,
+                } => {
+                    let path = format!("{}.timerange", path);
+                    match TimeBounds::resolve(
+                        min.as_deref(),
+                        max.as_deref(),
+                        *allow_future,
+                        Utc::now(),
+                    ) {
+                        Ok(TimeBounds {
+                            min: Some(start),
+                            max: Some(end),
+                            ..
+                        }) if start > end => findings.push(LintFinding::error(
+                            "invalid-bounds",
+                            path,
+                            format!(
+                                "Time range {} is empty for field '{}'",
+                                describe_time_bounds(min.as_deref(), max.as_deref()),
+                                field.name
+                            ),
+                        )),
+                        Ok(_) => {}
+                        Err(message) => findings.push(LintFinding::error(
+                            "invalid-bounds",
+                            path,
+                            format!("{} for field '{}'", message, field.name),
+                        )),
+                    }
+                    continue;
+                }
+                FieldConstraints::Length { min, max } => ("length", min, max),
+                FieldConstraints::ByteLength { min, max } => ("bytelength", min, max),
+                FieldConstraints::ListLength { min, max } => ("listlength", min, max),
+                FieldConstraints::Cardinality {
+                    min_distinct,
+                    max_distinct,
+                } => ("cardinality", min_distinct, max_distinct),
+                _ => continue,
+            };
+            if let (Some(min), Some(max)) = (min, max)
+                && min > max
+            {
+                findings.push(LintFinding::error(
+                    "invalid-bounds",
+                    format!("{}.{}", path, kind),
+                    format!(
+                        "Minimum {} is above maximum {} for field '{}'",
+                        min, max, field.name
+                    ),
+                ));
+            }
+        }
+    }
+}
+
+/// Reports the sections a contract lacks for its declared conformance level.
+fn lint_conformance(contract: &Contract, findings: &mut Vec<LintFinding>) {
+    let Some(level) = contract.conformance_level else {
+        return;
+    };
+    for missing in level.missing_sections(contract) {
+        findings.push(LintFinding::error(
+            "conformance-level",
+            &missing.path,
+            format!(
+                "Conformance level {} requires {} (a {} requirement)",
+                level, missing.path, missing.level
+            ),
+        ));
+    }
+}
+
+fn check_ratio(findings: &mut Vec<LintFinding>, path: &str, value: f64) {
+    if !(0.0..=1.0).contains(&value) {
+        findings.push(LintFinding::error(
+            "ratio-range",
+            path,
+            format!("{} must be between 0 and 1", value),
+        ));
+    }
+}
+
+/// Lists every field named by a quality or ML check or the CDC key, with the
+/// path naming it.
+fn referenced_fields(contract: &Contract) -> Vec<(&'static str, &str)> {
+    let mut refs = Vec::new();
+    if let Some(cdc) = &contract.cdc {
+        add_refs(&mut refs, "cdc.key", &cdc.key);
+    }
+    let Some(qc) = &contract.quality_checks else {
+        return refs;
+    };
+
+    if let Some(c) = &qc.completeness {
+        add_refs(&mut refs, "quality_checks.completeness.fields", &c.fields);
+    }
+    if let Some(c) = &qc.uniqueness {
+        add_refs(&mut refs, "quality_checks.uniqueness.fields", &c.fields);
+    }
+    if let Some(c) = &qc.freshness {
+        add_refs(
+            &mut refs,
+            "quality_checks.freshness.metric",
+            c.column().filter(|m| !m.is_empty()).map(|_| &c.metric),
+        );
+    }
+    if let Some(c) = &qc.volume {
+        add_refs(&mut refs, "quality_checks.volume.metric", [&c.metric]);
+        add_refs(
+            &mut refs,
+            "quality_checks.volume.arrival_metric",
+            &c.arrival_metric,
+        );
+    }
+    if let Some(c) = &qc.soft_delete {
+        add_refs(&mut refs, "quality_checks.soft_delete.column", [&c.column]);
+    }
+    for c in qc.referential.iter().flatten() {
+        add_refs(&mut refs, "quality_checks.referential.field", [&c.field]);
+    }
+
+    let Some(ml) = &qc.ml_checks else {
+        return refs;
+    };
+    if let Some(c) = &ml.no_overlap {
+        let fields = std::iter::once(&c.split_field).chain(&c.key_fields);
+        add_refs(&mut refs, "quality_checks.ml_checks.no_overlap", fields);
+    }
+    if let Some(c) = &ml.temporal_split {
+        let fields = [&c.split_field, &c.timestamp_field];
+        add_refs(&mut refs, "quality_checks.ml_checks.temporal_split", fields);
+    }
+    if let Some(c) = &ml.class_balance {
+        let fields = [&c.label_field];
+        add_refs(&mut refs, "quality_checks.ml_checks.class_balance", fields);
+    }
+    if let Some(c) = &ml.feature_drift {
+        let fields = std::iter::once(&c.split_field).chain(&c.feature_fields);
+        add_refs(&mut refs, "quality_checks.ml_checks.feature_drift", fields);
+    }
+    if let Some(c) = &ml.target_leakage {
+        let fields = std::iter::once(&c.target_field).chain(&c.feature_fields);
+        add_refs(&mut refs, "quality_checks.ml_checks.target_leakage", fields);
+    }
+    if let Some(c) = &ml.null_rate_by_group {
+        let fields = std::iter::once(&c.group_field).chain(&c.check_fields);
+        add_refs(
+            &mut refs,
+            "quality_checks.ml_checks.null_rate_by_group",
+            fields,
+        );
+    }
+
+    refs
+}
+
+fn add_refs<'a>(
+    refs: &mut Vec<(&'static str, &'a str)>,
+    path: &'static str,
+    fields: impl IntoIterator<Item = &'a String>,
+) {
+    refs.extend(fields.into_iter().map(|f| (path, f.as_str())));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use contracts_core::{
+        ApproximateUniqueness, CompletenessCheck, ConformanceLevel, ContractBuilder, CustomCheck,
+        DataFormat, Dataset, Deprecation, FieldBuilder, FreshnessCheck, FreshnessSource,
+        QualityChecks, ReferentialCheck, UniquenessCheck, VolumeCheck,
+    };
+
+    fn contract(quality_checks: QualityChecks) -> Contract {
+        ContractBuilder::new("events", "team")
+            .description("Events")
+            .location("s3://lake/events")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("id", "string").nullable(true).build())
+            .field(FieldBuilder::new("day", "string").nullable(false).build())
+            .quality_checks(quality_checks)
+            .build()
+    }
+
+    fn rules(findings: &[LintFinding]) -> Vec<&'static str> {
+        findings.iter().map(|f| f.rule).collect()
+    }
+
+    #[test]
+    fn test_clean_contract_has_no_findings() {
+        assert!(
+            ContractLinter::new()
+                .lint(&contract(QualityChecks::default()))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_dataset_findings_carry_dataset_path() {
+        let mut orders = contract(QualityChecks::default());
+        let items = contract(QualityChecks {
+            uniqueness: Some(UniquenessCheck {
+                fields: vec!["line".to_string()],
+                scope: None,
+                min_sample_size: None,
+                severity: None,
+                approximate: None,
+            }),
+            ..Default::default()
+        });
+        orders.datasets = Some(
+            [(
+                "items".to_string(),
+                Dataset {
+                    description: None,
+                    schema: items.schema,
+                    quality_checks: items.quality_checks,
+                },
+            )]
+            .into(),
+        );
+
+        let findings = ContractLinter::new().lint(&orders);
+        assert_eq!(rules(&findings), vec!["unknown-field"]);
+        assert_eq!(
+            findings[0].path,
+            "datasets.items.quality_checks.uniqueness.fields"
+        );
+    }
+
+    #[test]
+    fn test_reference_findings() {
+        let reference = |references: &str| ReferentialCheck {
+            field: "id".to_string(),
+            references: references.to_string(),
+            threshold: None,
+            severity: None,
+        };
+        let mut events = contract(QualityChecks::default());
+        let items = contract(QualityChecks {
+            referential: Some(vec![
+                reference("events.id"),
+                reference("events.user"),
+                reference("users.id"),
+                reference("id"),
+            ]),
+            ..Default::default()
+        });
+        events.datasets = Some(
+            [(
+                "items".to_string(),
+                Dataset {
+                    description: None,
+                    schema: items.schema,
+                    quality_checks: items.quality_checks,
+                },
+            )]
+            .into(),
+        );
+
+        let findings = ContractLinter::new().lint(&events);
+        assert_eq!(
+            rules(&findings),
+            vec!["unknown-field", "unknown-dataset", "invalid-reference"]
+        );
+        assert_eq!(
+            findings[0].path,
+            "datasets.items.quality_checks.referential.1.references"
+        );
+    }
+
+    #[test]
+    fn test_constraint_bound_findings() {
+        let mut contract = contract(QualityChecks::default());
+        contract.schema.fields[0].constraints = Some(vec![
+            FieldConstraints::MaxNullFraction { fraction: 1.5 },
+            FieldConstraints::Cardinality {
+                min_distinct: Some(10),
+                max_distinct: Some(2),
+            },
+            FieldConstraints::ByteLength {
+                min: Some(8),
+                max: Some(4),
+            },
+            FieldConstraints::Precision {
+                precision: 4,
+                scale: 6,
+            },
+            FieldConstraints::TimeRange {
+                min: Some("now".to_string()),
+                max: Some("now-1d".to_string()),
+                allow_future: true,
+            },
+            FieldConstraints::TimeRange {
+                min: Some("last week".to_string()),
+                max: None,
+                allow_future: false,
+            },
+        ]);
+
+        let findings = ContractLinter::new().lint(&contract);
+        assert_eq!(
+            rules(&findings),
+            vec![
+                "ratio-range",
+                "invalid-bounds",
+                "invalid-bounds",
+                "invalid-bounds",
+                "invalid-bounds",
+                "invalid-bounds"
+            ]
+        );
+        assert_eq!(findings[1].path, "schema.fields.id.constraints.cardinality");
+        assert_eq!(
+            findings[4].message,
+            "Time range [now, now-1d] is empty for field 'id'"
+        );
+        assert!(
+            findings[5]
+                .message
+                .starts_with("Invalid time bound 'last week'")
+        );
+    }
+
+    #[test]
+    fn test_conformance_level_findings() {
+        let mut contract = contract(QualityChecks::default());
+        assert!(!rules(&ContractLinter::new().lint(&contract)).contains(&"conformance-level"));
+
+        contract.conformance_level = Some(ConformanceLevel::Gold);
+        let findings: Vec<LintFinding> = ContractLinter::new()
+            .lint(&contract)
+            .into_iter()
+            .filter(|f| f.rule == "conformance-level")
+            .collect();
+        let paths: Vec<&str> = findings.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "quality_checks.completeness",
+                "sla",
+                "quality_checks.freshness",
+                "quality_checks.uniqueness",
+                "schema.fields.id.description",
+                "schema.fields.day.description",
+            ]
+        );
+        assert_eq!(
+            findings[0].message,
+            "Conformance level gold requires quality_checks.completeness (a silver requirement)"
+        );
+    }
+
+    #[test]
+    fn test_freshness_findings() {
+        let findings = ContractLinter::new().lint(&contract(QualityChecks {
+            freshness: Some(FreshnessCheck {
+                max_delay: "soon".to_string(),
+                metric: "day".to_string(),
+                source: None,
+                allowed_lateness: Some("a while".to_string()),
+                severity: None,
+            }),
+            ..Default::default()
+        }));
+
+        assert_eq!(
+            rules(&findings),
+            vec![
+                "invalid-duration",
+                "invalid-duration",
+                "freshness-metric-type"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_volume_findings() {
+        let findings = ContractLinter::new().lint(&contract(QualityChecks {
+            volume: Some(VolumeCheck {
+                metric: "day".to_string(),
+                bucket: "0h".to_string(),
+                min_rows: 10,
+                arrival_metric: Some("loaded_at".to_string()),
+                allowed_lateness: Some("a while".to_string()),
+                severity: None,
+            }),
+            ..Default::default()
+        }));
+
+        assert_eq!(
+            rules(&findings),
+            vec![
+                "unknown-field",
+                "invalid-duration",
+                "invalid-duration",
+                "volume-metric-type"
+            ]
+        );
+        assert_eq!(findings[0].path, "quality_checks.volume.arrival_metric");
+        assert_eq!(findings[1].path, "quality_checks.volume.bucket");
+        assert_eq!(findings[2].path, "quality_checks.volume.allowed_lateness");
+    }
+
+    #[test]
+    fn test_metadata_freshness_findings() {
+        let freshness = |source| {
+            contract(QualityChecks {
+                freshness: Some(FreshnessCheck {
+                    max_delay: "1d".to_string(),
+                    metric: String::new(),
+                    source: Some(source),
+                    allowed_lateness: None,
+                    severity: None,
+                }),
+                ..Default::default()
+            })
+        };
+
+        let snapshot = freshness(FreshnessSource::SnapshotCommittedAt);
+        assert!(ContractLinter::new().lint(&snapshot).is_empty());
+        let partition = freshness(FreshnessSource::Partition);
+        assert_eq!(
+            rules(&ContractLinter::new().lint(&partition)),
+            vec!["missing-freshness-metric"]
+        );
+
+        let mut parquet = snapshot;
+        parquet.schema.format = DataFormat::Parquet;
+        assert_eq!(
+            rules(&ContractLinter::new().lint(&parquet)),
+            vec!["freshness-source-format"]
+        );
+    }
+
+    #[test]
+    fn test_uniqueness_scope_findings() {
+        let scoped = |scope: &str| {
+            contract(QualityChecks {
+                uniqueness: Some(UniquenessCheck {
+                    fields: vec!["day".to_string()],
+                    scope: Some(scope.to_string()),
+                    min_sample_size: None,
+                    severity: None,
+                    approximate: None,
+                }),
+                ..Default::default()
+            })
+        };
+        let linter = ContractLinter::new();
+        assert!(linter.lint(&scoped("per_partition(id)")).is_empty());
+
+        for scope in ["per_week", "per_day", "per_day(created_at)"] {
+            let findings = linter.lint(&scoped(scope));
+            assert_eq!(rules(&findings), vec!["invalid-scope"], "{scope}");
+            assert_eq!(findings[0].path, "quality_checks.uniqueness.scope");
+        }
+    }
+
+    #[test]
+    fn test_approximate_uniqueness_findings() {
+        let approximate = |error_tolerance: f64, max_memory_kb: Option<usize>| {
+            contract(QualityChecks {
+                uniqueness: Some(UniquenessCheck {
+                    fields: vec!["day".to_string()],
+                    scope: None,
+                    min_sample_size: None,
+                    severity: None,
+                    approximate: Some(ApproximateUniqueness {
+                        error_tolerance: Some(error_tolerance),
+                        max_memory_kb,
+                    }),
+                }),
+                ..Default::default()
+            })
+        };
+        let linter = ContractLinter::new();
+        assert!(linter.lint(&approximate(0.01, Some(16))).is_empty());
+
+        let findings = linter.lint(&approximate(0.0, None));
+        assert_eq!(rules(&findings), vec!["ratio-range"]);
+        assert_eq!(
+            findings[0].path,
+            "quality_checks.uniqueness.approximate.error_tolerance"
+        );
+        assert_eq!(
+            rules(&linter.lint(&approximate(0.01, Some(4)))),
+            vec!["memory-cap"]
+        );
+    }
+
+    #[test]
+    fn test_warnings_follow_errors() {
+        let mut contract = contract(QualityChecks {
+            uniqueness: Some(UniquenessCheck {
+                fields: vec!["id".to_string()],
+                scope: None,
+                min_sample_size: None,
+                severity: None,
+                approximate: None,
+            }),
+            custom_checks: Some(vec![CustomCheck {
+                name: "positive".to_string(),
+                definition: "SELECT 1".to_string(),
+                severity: Some("fatal".to_string()),
+                capture_rows: None,
+            }]),
+            ..Default::default()
+        });
+        contract.description = None;
+        contract.schema.fields.clear();
+
+        let findings = ContractLinter::new().lint(&contract);
+        let severities: Vec<_> = findings.iter().map(|f| f.severity).collect();
+        assert_eq!(severities[0], LintSeverity::Error);
+        assert!(rules(&findings).contains(&"missing-description"));
+        assert!(rules(&findings).contains(&"custom-check-severity"));
+        assert!(rules(&findings).contains(&"unknown-field"));
+        assert!(severities.is_sorted_by_key(|s| *s != LintSeverity::Error));
+    }
+
+    #[test]
+    fn test_deprecation_findings() {
+        let mut contract = contract(QualityChecks {
+            completeness: Some(CompletenessCheck {
+                threshold: 0.9,
+                fields: vec!["day".to_string()],
+                min_sample_size: None,
+                severity: None,
+            }),
+            ..Default::default()
+        });
+        contract.schema.fields[1].deprecated = Some(Deprecation {
+            since: Some("2.0.0".to_string()),
+            remove_after: NaiveDate::from_ymd_opt(2999, 1, 31),
+            replacement: Some("date".to_string()),
+        });
+        contract.schema.fields[0].deprecated = Some(Deprecation {
+            remove_after: NaiveDate::from_ymd_opt(2020, 1, 31),
+            ..Default::default()
+        });
+
+        let findings = ContractLinter::new().lint(&contract);
+        assert_eq!(
+            rules(&findings),
+            vec!["unknown-field", "expired-field", "deprecated-field"]
+        );
+        assert_eq!(findings[0].path, "schema.fields.day.deprecated.replacement");
+        assert_eq!(findings[1].path, "schema.fields.id.deprecated.remove_after");
+        assert_eq!(findings[2].path, "quality_checks.completeness.fields");
+        assert_eq!(
+            findings[2].message,
+            "Field 'day' is deprecated and may be removed after 2999-01-31; use 'date' instead"
+        );
+    }
+
+    #[test]
+    fn test_tag_taxonomy_findings() {
+        let taxonomy: TagTaxonomy = serde_json::from_value(serde_json::json!({
+            "tags": {
+                "pii": { "children": { "direct": {} } },
+                "personal": { "deprecated": true, "replacement": "pii" },
+            }
+        }))
+        .unwrap();
+        let mut contract = contract(QualityChecks::default());
+        contract.schema.fields[0].tags = Some(vec![
+            "pii.direct".to_string(),
+            "direct".to_string(),
+            "personal".to_string(),
+            "internal".to_string(),
+        ]);
+
+        let findings = ContractLinter::new()
+            .with_tag_taxonomy(taxonomy)
+            .lint(&contract);
+        let messages: Vec<(&str, &str)> = findings
+            .iter()
+            .map(|f| (f.rule, f.message.as_str()))
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                (
+                    "unknown-tag",
+                    "Tag 'direct' of field 'id' is not in the tag taxonomy; did you mean 'pii.direct'?"
+                ),
+                (
+                    "unknown-tag",
+                    "Tag 'internal' of field 'id' is not in the tag taxonomy"
+                ),
+                (
+                    "deprecated-tag",
+                    "Tag 'personal' of field 'id' is deprecated; use 'pii' instead"
+                ),
+            ]
+        );
+        assert_eq!(findings[0].path, "schema.fields.id.tags");
+    }
+
+    #[test]
+    fn test_extensions_schema_findings() {
+        let linter = ContractLinter::new()
+            .with_extensions_schema(&serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "cost_center": { "type": "string", "pattern": "^[A-Z]+-[0-9]+$" },
+                },
+                "required": ["cost_center"],
+            }))
+            .unwrap();
+
+        let missing = linter.lint(&contract(QualityChecks::default()));
+        assert_eq!(rules(&missing), vec!["extensions-schema"]);
+        assert_eq!(missing[0].path, "x-extensions");
+
+        let mut tagged = contract(QualityChecks::default());
+        tagged.extensions = Some(
+            [("cost_center".to_string(), serde_json::json!("fin 42"))]
+                .into_iter()
+                .collect(),
+        );
+        let findings = linter.lint(&tagged);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].path, "x-extensions.cost_center");
+
+        tagged
+            .extensions
+            .as_mut()
+            .unwrap()
+            .insert("cost_center".to_string(), serde_json::json!("FIN-42"));
+        assert!(linter.lint(&tagged).is_empty());
+
+        assert!(
+            ContractLinter::new()
+                .with_extensions_schema(&serde_json::json!({ "type": 12 }))
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,280 @@
+//! Format-aware validation of contract schema locations.
+
+use crate::error::ValidationError;
+use contracts_core::DataFormat;
+
+/// URI schemes accepted for table and file locations.
+const STORAGE_SCHEMES: &[&str] = &[
+    "s3", "s3a", "s3n", "gs", "gcs", "abfs", "abfss", "wasb", "wasbs", "adl", "hdfs", "oss",
+    "file", "http", "https",
+];
+
+/// Scheme for explicit `iceberg://<catalog>/<namespace>/<table>` references.
+const ICEBERG_SCHEME: &str = "iceberg";
+
+/// Custom formats that refer to databases and are located by a connection string.
+const DATABASE_FORMATS: &[&str] = &[
+    "postgres",
+    "postgresql",
+    "mysql",
+    "mariadb",
+    "sqlserver",
+    "oracle",
+    "snowflake",
+    "redshift",
+    "bigquery",
+    "clickhouse",
+    "jdbc",
+];
+
+/// Validates a schema location against the syntax expected for `format`.
+///
+/// - Table formats (Iceberg, Delta, Hudi) require a URI with a supported
+///   storage scheme or an absolute local path. Iceberg also accepts
+///   `iceberg://<catalog>/<namespace>/<table>` references.
+/// - File formats (Parquet, JSON, CSV, Avro, ORC) also accept relative paths.
+/// - Database formats (custom formats such as `postgres` or `snowflake`)
+///   require a connection string like `postgres://host:5432/db` or `jdbc:...`.
+/// - Other custom formats only require a non-empty location.
+///
+/// # Example
+///
+/// ```rust
+/// use contracts_core::DataFormat;
+/// use contracts_validator::validate_location;
+///
+/// assert!(validate_location(&DataFormat::Iceberg, "s3://lake/db/events").is_ok());
+/// assert!(validate_location(&DataFormat::Iceberg, "ftp://lake/db/events").is_err());
+/// ```
+pub fn validate_location(format: &DataFormat, location: &str) -> Result<(), ValidationError> {
+    let format_name = format_name(format);
+    let fail = |reason: String| {
+        Err(ValidationError::invalid_location(
+            location,
+            format_name.clone(),
+            reason,
+        ))
+    };
+
+    if location.trim().is_empty() {
+        return fail("location must not be empty".to_string());
+    }
+    if location.trim() != location {
+        return fail("location must not have leading or trailing whitespace".to_string());
+    }
+
+    match format {
+        DataFormat::Iceberg if location.starts_with("iceberg://") => {
+            validate_iceberg_uri(location).or_else(fail)
+        }
+        DataFormat::Iceberg | DataFormat::Delta | DataFormat::Hudi => {
+            if has_scheme(location) {
+                validate_storage_uri(location).or_else(fail)
+            } else if location.starts_with('/') {
+                Ok(())
+            } else {
+                fail(format!(
+                    "expected a URI such as s3://bucket/path or an absolute path. {}",
+                    supported_schemes_hint()
+                ))
+            }
+        }
+        DataFormat::Parquet
+        | DataFormat::Json
+        | DataFormat::Csv
+        | DataFormat::Avro
+        | DataFormat::Orc => {
+            if has_scheme(location) {
+                validate_storage_uri(location).or_else(fail)
+            } else {
+                Ok(())
+            }
+        }
+        DataFormat::Custom(name) if is_database_format(name) => {
+            validate_connection_string(location).or_else(fail)
+        }
+        DataFormat::Custom(_) => Ok(()),
+    }
+}
+
+fn format_name(format: &DataFormat) -> String {
+    match format {
+        DataFormat::Iceberg => "iceberg".to_string(),
+        DataFormat::Parquet => "parquet".to_string(),
+        DataFormat::Json => "json".to_string(),
+        DataFormat::Csv => "csv".to_string(),
+        DataFormat::Avro => "avro".to_string(),
+        DataFormat::Orc => "orc".to_string(),
+        DataFormat::Delta => "delta".to_string(),
+        DataFormat::Hudi => "hudi".to_string(),
+        DataFormat::Custom(name) => name.clone(),
+    }
+}
+
+fn is_database_format(name: &str) -> bool {
+    DATABASE_FORMATS.contains(&name.to_lowercase().as_str())
+}
+
+fn supported_schemes_hint() -> String {
+    format!("Supported schemes: {}", STORAGE_SCHEMES.join(", "))
+}
+
+/// Returns true if `location` starts with `<scheme>://`.
+fn has_scheme(location: &str) -> bool {
+    location.contains("://")
+}
+
+/// Splits `scheme://rest`, validating the scheme syntax (RFC 3986).
+fn split_scheme(uri: &str) -> Result<(&str, &str), String> {
+    let (scheme, rest) = uri
+        .split_once("://")
+        .ok_or_else(|| "missing '://' after the scheme".to_string())?;
+
+    let mut chars = scheme.chars();
+    let valid = chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    if !valid {
+        return Err(format!("'{}' is not a valid URI scheme", scheme));
+    }
+
+    if rest.chars().any(char::is_whitespace) {
+        return Err("URIs must not contain whitespace; percent-encode it as %20".to_string());
+    }
+
+    Ok((scheme, rest))
+}
+
+fn validate_storage_uri(uri: &str) -> Result<(), String> {
+    let (scheme, rest) = split_scheme(uri)?;
+    let scheme = scheme.to_lowercase();
+
+    if !STORAGE_SCHEMES.contains(&scheme.as_str()) {
+        return Err(format!(
+            "unsupported scheme '{}'. {}",
+            scheme,
+            supported_schemes_hint()
+        ));
+    }
+
+    if scheme == "file" {
+        if rest.starts_with('/') {
+            return Ok(());
+        }
+        return Err("file URIs must use an absolute path, e.g. file:///data/table".to_string());
+    }
+
+    let authority = rest.split('/').next().unwrap_or_default();
+    if authority.is_empty() {
+        return Err(format!(
+            "missing bucket or host after '{}://', e.g. {}://my-bucket/path",
+            scheme, scheme
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_iceberg_uri(uri: &str) -> Result<(), String> {
+    let (_, rest) = split_scheme(uri)?;
+    let segments = rest.split('/').filter(|s| !s.is_empty()).count();
+    if segments < 3 {
+        return Err(format!(
+            "expected {}://<catalog>/<namespace>/<table>",
+            ICEBERG_SCHEME
+        ));
+    }
+    Ok(())
+}
+
+fn validate_connection_string(location: &str) -> Result<(), String> {
+    let uri = location.strip_prefix("jdbc:").unwrap_or(location);
+    if !has_scheme(uri) {
+        return Err(
+            "expected a connection string such as postgres://host:5432/database \
+             or jdbc:postgresql://host:5432/database"
+                .to_string(),
+        );
+    }
+
+    let (_, rest) = split_scheme(uri)?;
+    let authority = rest.split(['/', '?']).next().unwrap_or_default();
+    let host_port = authority.rsplit('@').next().unwrap_or_default();
+    if host_port.is_empty() {
+        return Err("connection string is missing a host".to_string());
+    }
+
+    if let Some((_, port)) = host_port.rsplit_once(':')
+        && !port.is_empty()
+        && port.parse::<u16>().is_err()
+    {
+        return Err(format!("'{}' is not a valid port number", port));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reason(format: &DataFormat, location: &str) -> String {
+        match validate_location(format, location) {
+            Err(ValidationError::InvalidLocation { reason, .. }) => reason,
+            other => panic!("expected invalid location for '{location}', got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_iceberg_locations() {
+        for ok in [
+            "s3://bucket/db/table",
+            "s3a://bucket/db/table",
+            "gs://bucket/table",
+            "abfss://container@account.dfs.core.windows.net/table",
+            "hdfs://namenode:8020/warehouse/table",
+            "file:///tmp/warehouse/table",
+            "/warehouse/db/table",
+            "iceberg://prod/sales/orders",
+        ] {
+            assert!(
+                validate_location(&DataFormat::Iceberg, ok).is_ok(),
+                "{ok} should be valid"
+            );
+        }
+
+        assert!(reason(&DataFormat::Iceberg, "ftp://host/table").contains("unsupported scheme"));
+        assert!(reason(&DataFormat::Iceberg, "iceberg://prod/orders").contains("<catalog>"));
+        assert!(reason(&DataFormat::Iceberg, "s3:///table").contains("missing bucket"));
+        assert!(reason(&DataFormat::Iceberg, "warehouse/table").contains("absolute path"));
+        assert!(reason(&DataFormat::Iceberg, "file://relative/path").contains("absolute path"));
+        assert!(reason(&DataFormat::Iceberg, "1s3://bucket").contains("not a valid URI scheme"));
+    }
+
+    #[test]
+    fn test_file_format_locations() {
+        assert!(validate_location(&DataFormat::Parquet, "data/events.parquet").is_ok());
+        assert!(validate_location(&DataFormat::Csv, "/tmp/events.csv").is_ok());
+        assert!(validate_location(&DataFormat::Json, "s3://bucket/events.json").is_ok());
+
+        assert!(reason(&DataFormat::Parquet, "s3://bucket/my file.parquet").contains("whitespace"));
+        assert!(reason(&DataFormat::Parquet, "   ").contains("empty"));
+        assert!(reason(&DataFormat::Csv, " data.csv").contains("whitespace"));
+    }
+
+    #[test]
+    fn test_database_connection_strings() {
+        let postgres = DataFormat::Custom("postgres".to_string());
+        assert!(validate_location(&postgres, "postgres://user@db.internal:5432/analytics").is_ok());
+        assert!(validate_location(&postgres, "jdbc:postgresql://db:5432/analytics").is_ok());
+
+        assert!(reason(&postgres, "analytics.events").contains("connection string"));
+        assert!(reason(&postgres, "postgres://db:port/analytics").contains("port"));
+        assert!(reason(&postgres, "postgres:///analytics").contains("host"));
+    }
+
+    #[test]
+    fn test_other_custom_formats_accept_any_location() {
+        let custom = DataFormat::Custom("kafka".to_string());
+        assert!(validate_location(&custom, "events-topic").is_ok());
+    }
+}
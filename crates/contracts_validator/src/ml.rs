@@ -198,6 +198,7 @@ impl MlValidator {
 
             let ts = match row.get(&check.timestamp_field) {
                 Some(DataValue::Timestamp(t)) => t.clone(),
+                Some(DataValue::Date(d)) => d.to_string(),
                 Some(DataValue::String(s)) => s.clone(),
                 _ => continue,
             };
@@ -289,10 +290,8 @@ impl MlValidator {
         let mut counts: HashMap<String, usize> = HashMap::new();
         let mut total: usize = 0;
 
-        for row in dataset.rows() {
-            if let Some(val) = row.get(&check.label_field)
-                && !val.is_null()
-            {
+        for val in dataset.column(&check.label_field) {
+            if !val.is_null() {
                 *counts.entry(value_to_key(val)).or_default() += 1;
                 total += 1;
             }
@@ -357,8 +356,12 @@ impl MlValidator {
         check: &FeatureDriftCheck,
         dataset: &DataSet,
     ) -> Vec<ValidationError> {
-        let num_bins = check.num_bins.unwrap_or(10);
-        let threshold = check.threshold.unwrap_or(0.2);
+        let num_bins = check
+            .num_bins
+            .unwrap_or(FeatureDriftCheck::DEFAULT_NUM_BINS);
+        let threshold = check
+            .threshold
+            .unwrap_or(FeatureDriftCheck::DEFAULT_THRESHOLD);
         let epsilon = 1e-6;
 
         // Group numeric values by split for each feature
@@ -463,24 +466,21 @@ impl MlValidator {
         check: &TargetLeakageCheck,
         dataset: &DataSet,
     ) -> Vec<ValidationError> {
-        let max_corr = check.max_correlation.unwrap_or(0.95);
+        let max_corr = check
+            .max_correlation
+            .unwrap_or(TargetLeakageCheck::DEFAULT_MAX_CORRELATION);
         let mut errors = Vec::new();
 
         for field in &check.feature_fields {
             let mut xs = Vec::new();
             let mut ys = Vec::new();
 
-            for row in dataset.rows() {
-                let target_val = match row.get(&check.target_field).and_then(value_to_float) {
-                    Some(v) => v,
-                    None => continue,
-                };
-                let feature_val = match row.get(field).and_then(value_to_float) {
-                    Some(v) => v,
-                    None => continue,
-                };
-                xs.push(feature_val);
-                ys.push(target_val);
+            let targets = dataset.column(&check.target_field);
+            for (target, feature) in targets.zip(dataset.column(field)) {
+                if let (Some(y), Some(x)) = (value_to_float(target), value_to_float(feature)) {
+                    xs.push(x);
+                    ys.push(y);
+                }
             }
 
             if xs.len() < 3 {
@@ -507,22 +507,28 @@ impl MlValidator {
         check: &NullRateByGroupCheck,
         dataset: &DataSet,
     ) -> Vec<ValidationError> {
-        let max_diff = check.max_null_rate_diff.unwrap_or(0.1);
+        let max_diff = check
+            .max_null_rate_diff
+            .unwrap_or(NullRateByGroupCheck::DEFAULT_MAX_NULL_RATE_DIFF);
 
         // group -> field -> (null_count, total_count)
         let mut stats: HashMap<String, HashMap<&str, (usize, usize)>> = HashMap::new();
 
-        for row in dataset.rows() {
-            let group = match row.get(&check.group_field) {
-                Some(v) if !v.is_null() => value_to_key(v),
-                _ => continue,
-            };
+        let groups: Vec<Option<String>> = dataset
+            .column(&check.group_field)
+            .map(|v| (!v.is_null()).then(|| value_to_key(v)))
+            .collect();
 
-            let group_stats = stats.entry(group).or_default();
-            for field in &check.check_fields {
-                let entry = group_stats.entry(field.as_str()).or_insert((0, 0));
+        for field in &check.check_fields {
+            for (group, value) in groups.iter().zip(dataset.column(field)) {
+                let Some(group) = group else { continue };
+                let entry = stats
+                    .entry(group.clone())
+                    .or_default()
+                    .entry(field.as_str())
+                    .or_insert((0, 0));
                 entry.1 += 1; // total
-                if row.get(field).is_none_or(|v| v.is_null()) {
+                if value.is_null() {
                     entry.0 += 1; // null
                 }
             }
@@ -590,9 +596,13 @@ fn value_to_key(v: &DataValue) -> String {
         DataValue::Null => "NULL".to_string(),
         DataValue::String(s) => s.clone(),
         DataValue::Int(i) => i.to_string(),
+        DataValue::UInt(u) => u.to_string(),
+        DataValue::Decimal { value, scale } => DataValue::decimal_to_string(*value, *scale),
         DataValue::Float(f) => f.to_string(),
         DataValue::Bool(b) => b.to_string(),
         DataValue::Timestamp(ts) => ts.clone(),
+        DataValue::Date(d) => d.to_string(),
+        DataValue::Time(t) => t.to_string(),
         DataValue::Map(_) => "[map]".to_string(),
         DataValue::List(_) => "[list]".to_string(),
     }
@@ -603,6 +613,7 @@ fn value_to_float(v: &DataValue) -> Option<f64> {
     match v {
         DataValue::Float(f) => Some(*f),
         DataValue::Int(i) => Some(*i as f64),
+        DataValue::UInt(_) | DataValue::Decimal { .. } => v.as_float(),
         DataValue::Bool(b) => Some(if *b { 1.0 } else { 0.0 }),
         _ => None,
     }
@@ -913,6 +924,7 @@ mod tests {
             label_field: "label".into(),
             max_proportion: 0.8,
             min_proportion: Some(0.1),
+            min_sample_size: None,
         });
 
         let rows = vec![
@@ -933,6 +945,7 @@ mod tests {
             label_field: "label".into(),
             max_proportion: 0.7,
             min_proportion: None,
+            min_sample_size: None,
         });
 
         let mut rows: Vec<_> = (0..9)
@@ -956,6 +969,7 @@ mod tests {
             label_field: "label".into(),
             max_proportion: 0.95,
             min_proportion: Some(0.15),
+            min_sample_size: None,
         });
 
         let mut rows: Vec<_> = (0..9)
@@ -982,6 +996,7 @@ mod tests {
                 feature_fields: vec!["score".into()],
                 num_bins: Some(5),
                 threshold: Some(0.2),
+                min_sample_size: None,
             }),
             ..default_ml_checks()
         };
@@ -1014,6 +1029,7 @@ mod tests {
                 feature_fields: vec!["score".into()],
                 num_bins: Some(5),
                 threshold: Some(0.2),
+                min_sample_size: None,
             }),
             ..default_ml_checks()
         };
@@ -1048,6 +1064,7 @@ mod tests {
                 target_field: "target".into(),
                 feature_fields: vec!["feature".into()],
                 max_correlation: Some(0.95),
+                min_sample_size: None,
             }),
             ..default_ml_checks()
         };
@@ -1077,6 +1094,7 @@ mod tests {
                 target_field: "target".into(),
                 feature_fields: vec!["feature".into()],
                 max_correlation: Some(0.95),
+                min_sample_size: None,
             }),
             ..default_ml_checks()
         };
@@ -1107,6 +1125,7 @@ mod tests {
                 group_field: "split".into(),
                 check_fields: vec!["score".into()],
                 max_null_rate_diff: Some(0.1),
+                min_sample_size: None,
             }),
             ..default_ml_checks()
         };
@@ -1143,6 +1162,7 @@ mod tests {
                 group_field: "split".into(),
                 check_fields: vec!["score".into()],
                 max_null_rate_diff: Some(0.1),
+                min_sample_size: None,
             }),
             ..default_ml_checks()
         };
@@ -1196,6 +1216,7 @@ mod tests {
                 label_field: "label".into(),
                 max_proportion: 0.8,
                 min_proportion: None,
+                min_sample_size: None,
             }),
             feature_drift: None,
             target_leakage: None,
@@ -0,0 +1,153 @@
+//! Parallel row validation.
+//!
+//! Schema and constraint checks look at each row on its own. When the
+//! context's `parallelism` asks for more than one thread, the rows are split
+//! into contiguous chunks validated on a rayon pool. Each chunk accumulates
+//! its own errors and the chunks are joined in row order, so the errors come
+//! out exactly as a sequential run reports them.
+//!
+//! A validator builds the pool of a thread count once, in a [`RowPool`], and
+//! reuses it for the schema and constraint passes of every run and segment.
+
+use crate::{DataRow, ValidationError};
+use contracts_core::ValidationContext;
+use rayon::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use std::sync::Arc;
+
+/// Fewest rows worth a chunk of their own; smaller datasets are validated on
+/// fewer threads.
+const MIN_CHUNK_ROWS: usize = 1024;
+
+/// Chunks per thread, so threads that finish early pick up more rows.
+const CHUNKS_PER_THREAD: usize = 4;
+
+/// Returns the threads `context` asks rows to be validated on, or `None` to
+/// validate them on the calling thread. `0` stands for one per core.
+pub(crate) fn threads(context: &ValidationContext) -> Option<usize> {
+    context.parallelism.filter(|&threads| threads != 1)
+}
+
+/// The rayon pool rows are validated on, built on first use and kept until
+/// a run asks for another thread count.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RowPool {
+    pool: Option<(usize, Arc<ThreadPool>)>,
+}
+
+impl RowPool {
+    /// Returns the pool of `threads` threads, or `None` to use the global
+    /// pool: for `0`, or when a pool of its own cannot be built.
+    pub(crate) fn get(&mut self, threads: usize) -> Option<Arc<ThreadPool>> {
+        if threads == 0 {
+            return None;
+        }
+        if let Some((built, pool)) = &self.pool
+            && *built == threads
+        {
+            return Some(Arc::clone(pool));
+        }
+        let pool = Arc::new(ThreadPoolBuilder::new().num_threads(threads).build().ok()?);
+        self.pool = Some((threads, Arc::clone(&pool)));
+        Some(pool)
+    }
+}
+
+/// Validates `rows` chunk by chunk on `pool`, or on the global pool when
+/// `None`, calling `validate` with each chunk and the index of its first row,
+/// and returns the errors of all chunks in row order.
+pub(crate) fn in_chunks<F>(
+    rows: &[DataRow],
+    pool: Option<&ThreadPool>,
+    validate: F,
+) -> Vec<ValidationError>
+where
+    F: Fn(&[DataRow], usize) -> Vec<ValidationError> + Sync,
+{
+    let run = || {
+        let chunks = rayon::current_num_threads() * CHUNKS_PER_THREAD;
+        let chunk_rows = rows.len().div_ceil(chunks).max(MIN_CHUNK_ROWS);
+        rows.par_chunks(chunk_rows)
+            .enumerate()
+            .map(|(index, chunk)| validate(chunk, index * chunk_rows))
+            .collect::<Vec<_>>()
+    };
+    let chunks = match pool {
+        Some(pool) => pool.install(run),
+        None => run(),
+    };
+    chunks.into_iter().flatten().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DataSet, DataValidator, DataValue};
+    use contracts_core::{ContractBuilder, DataFormat, FieldBuilder, FieldConstraints};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_parallel_errors_match_sequential_order() {
+        let contract = ContractBuilder::new("orders", "sales")
+            .location("s3://lake/orders")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("id", "int64").nullable(false).build())
+            .field(
+                FieldBuilder::new("status", "string")
+                    .constraint(FieldConstraints::AllowedValues {
+                        values: vec!["paid".to_string(), "shipped".to_string()],
+                    })
+                    .constraint(FieldConstraints::Pattern {
+                        regex: "^[a-z]+$".to_string(),
+                    })
+                    .build(),
+            )
+            .build();
+        let dataset = DataSet::from_rows(
+            (0..10_000)
+                .map(|i| {
+                    let id = match i % 997 {
+                        0 => DataValue::Null,
+                        _ => DataValue::Int(i),
+                    };
+                    let status = match i % 13 {
+                        0 => "Refunded",
+                        1 => "void",
+                        _ => "paid",
+                    };
+                    HashMap::from([
+                        ("id".to_string(), id),
+                        ("status".to_string(), DataValue::String(status.to_string())),
+                    ])
+                })
+                .collect(),
+        );
+        let messages = |context: &ValidationContext| {
+            let report = DataValidator::new().validate_with_data(&contract, &dataset, context);
+            report
+                .errors
+                .iter()
+                .chain(&report.warnings)
+                .map(|v| (v.to_string(), v.row_index))
+                .collect::<Vec<_>>()
+        };
+
+        let sequential = messages(&ValidationContext::new());
+        assert!(sequential.len() > 1000);
+        for threads in [0, 2, 3] {
+            let context = ValidationContext::new().with_parallelism(threads);
+            assert_eq!(messages(&context), sequential, "{threads} threads");
+        }
+    }
+
+    #[test]
+    fn test_row_pool_is_reused() {
+        let mut pools = RowPool::default();
+        assert!(pools.get(0).is_none());
+
+        let pool = pools.get(2).unwrap();
+        assert_eq!(pool.current_num_threads(), 2);
+        assert!(Arc::ptr_eq(&pool, &pools.get(2).unwrap()));
+        assert_eq!(pools.get(3).unwrap().current_num_threads(), 3);
+    }
+}
@@ -0,0 +1,242 @@
+//! Previews of a contract change against data.
+//!
+//! Before a new version of a contract is published, its owners want to know
+//! what it would break. Each version is evaluated over the same sample into a
+//! [`SampleOutcome`], and [`ChangePreview::compare`] lists the rows and the
+//! checks that fail under one version only, which is the blast radius of the
+//! change.
+
+use crate::{ConstraintValidator, DataSet, DataValidator, SchemaValidator};
+use contracts_core::{CheckKind, Contract, ValidationContext, ValidationReport, Violation};
+use std::collections::{BTreeSet, HashSet};
+
+/// How one version of a contract fares over a sample.
+#[derive(Debug, Clone)]
+pub struct SampleOutcome {
+    /// Report of validating the sample
+    pub report: ValidationReport,
+
+    /// Rows of the sample
+    pub rows: usize,
+
+    /// Indices of the rows violating the schema or a row constraint
+    pub failing_rows: BTreeSet<usize>,
+}
+
+impl SampleOutcome {
+    /// Validates `sample` against `contract`, then checks each row on its
+    /// own to tell which ones fail.
+    pub async fn evaluate(
+        contract: &Contract,
+        sample: &DataSet,
+        context: &ValidationContext,
+    ) -> Self {
+        let report = DataValidator::new()
+            .validate_with_data_async(contract, sample, context)
+            .await;
+        Self::from_report(report, contract, sample)
+    }
+
+    /// Builds the outcome of `sample` from its `report`, checking each row on
+    /// its own: violations do not all name the row they are about.
+    pub fn from_report(report: ValidationReport, contract: &Contract, sample: &DataSet) -> Self {
+        let schema = SchemaValidator::new();
+        let mut constraints = ConstraintValidator::new();
+        let failing_rows = sample
+            .rows()
+            .enumerate()
+            .filter(|(_, row)| {
+                let row = DataSet::from_rows(vec![(*row).clone()]);
+                !schema.validate(contract, &row).is_empty()
+                    || !constraints.validate_rows(contract, &row).is_empty()
+            })
+            .map(|(index, _)| index)
+            .collect();
+        Self {
+            report,
+            rows: sample.len(),
+            failing_rows,
+        }
+    }
+}
+
+/// Rows and checks whose outcome differs between two versions of a contract.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChangePreview {
+    /// Rows of the sample both versions were run against
+    pub rows: usize,
+
+    /// Whether the sample passes the old version
+    pub old_passed: bool,
+
+    /// Whether the sample passes the new version
+    pub new_passed: bool,
+
+    /// Rows failing the old version
+    pub old_failing_rows: usize,
+
+    /// Rows failing the new version
+    pub new_failing_rows: usize,
+
+    /// Indices of the rows failing the new version only
+    pub newly_failing_rows: Vec<usize>,
+
+    /// Indices of the rows failing the old version only
+    pub newly_passing_rows: Vec<usize>,
+
+    /// First error of each check failing the new version only
+    pub newly_failing_checks: Vec<Violation>,
+
+    /// First error of each check failing the old version only
+    pub newly_passing_checks: Vec<Violation>,
+}
+
+/// Identifies a check across versions: violations of the same kind, field
+/// and code are the same check, whatever their message says.
+type CheckKey = (CheckKind, Option<String>, &'static str);
+
+impl ChangePreview {
+    /// Compares the outcomes of the `old` and `new` versions of a contract
+    /// over the same sample.
+    ///
+    /// A check fails when the report holds an error for it, so under a
+    /// strict context warnings count too.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use contracts_core::{ContractBuilder, DataFormat, FieldBuilder, ValidationContext};
+    /// use contracts_validator::{ChangePreview, DataSet, DataValidator, DataValue, SampleOutcome};
+    /// use std::collections::HashMap;
+    ///
+    /// let sample = DataSet::from_rows(vec![
+    ///     HashMap::from([("email".to_string(), DataValue::String("a@b.c".to_string()))]),
+    ///     HashMap::from([("email".to_string(), DataValue::Null)]),
+    /// ]);
+    /// let outcome = |nullable: bool| {
+    ///     let contract = ContractBuilder::new("users", "identity")
+    ///         .location("s3://lake/users")
+    ///         .format(DataFormat::Iceberg)
+    ///         .field(FieldBuilder::new("email", "string").nullable(nullable).build())
+    ///         .build();
+    ///     let report =
+    ///         DataValidator::new().validate_with_data(&contract, &sample, &ValidationContext::new());
+    ///     SampleOutcome::from_report(report, &contract, &sample)
+    /// };
+    ///
+    /// let preview = ChangePreview::compare(&outcome(true), &outcome(false));
+    /// assert_eq!(preview.newly_failing_rows, vec![1]);
+    /// assert!(preview.breaks());
+    /// ```
+    pub fn compare(old: &SampleOutcome, new: &SampleOutcome) -> Self {
+        let old_checks = failing_checks(&old.report);
+        let new_checks = failing_checks(&new.report);
+
+        Self {
+            rows: new.rows,
+            old_passed: old.report.passed,
+            new_passed: new.report.passed,
+            old_failing_rows: old.failing_rows.len(),
+            new_failing_rows: new.failing_rows.len(),
+            newly_failing_rows: new
+                .failing_rows
+                .difference(&old.failing_rows)
+                .copied()
+                .collect(),
+            newly_passing_rows: old
+                .failing_rows
+                .difference(&new.failing_rows)
+                .copied()
+                .collect(),
+            newly_failing_checks: only_in(&new_checks, &old_checks),
+            newly_passing_checks: only_in(&old_checks, &new_checks),
+        }
+    }
+
+    /// Returns whether the new version fails rows or checks the old one
+    /// passes.
+    pub fn breaks(&self) -> bool {
+        !self.newly_failing_rows.is_empty() || !self.newly_failing_checks.is_empty()
+    }
+
+    /// Returns the fraction of the sample failing the new version only.
+    pub fn blast_radius(&self) -> f64 {
+        if self.rows == 0 {
+            0.0
+        } else {
+            self.newly_failing_rows.len() as f64 / self.rows as f64
+        }
+    }
+}
+
+/// Returns the first error of each failing check, in report order.
+fn failing_checks(report: &ValidationReport) -> Vec<(CheckKey, &Violation)> {
+    let mut seen = HashSet::new();
+    report
+        .errors
+        .iter()
+        .map(|e| ((e.check_kind, e.field.clone(), e.code.code), e))
+        .filter(|(key, _)| seen.insert(key.clone()))
+        .collect()
+}
+
+fn only_in(checks: &[(CheckKey, &Violation)], other: &[(CheckKey, &Violation)]) -> Vec<Violation> {
+    let other: HashSet<&CheckKey> = other.iter().map(|(key, _)| key).collect();
+    checks
+        .iter()
+        .filter(|(key, _)| !other.contains(key))
+        .map(|(_, violation)| (*violation).clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DataValue;
+    use contracts_core::{ContractBuilder, DataFormat, FieldBuilder, FieldConstraints};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_compare_versions_over_a_sample() {
+        let contract = |max: f64| {
+            ContractBuilder::new("orders", "sales")
+                .location("s3://lake/orders")
+                .format(DataFormat::Iceberg)
+                .field(
+                    FieldBuilder::new("total", "float64")
+                        .nullable(false)
+                        .constraint(FieldConstraints::Range { min: 0.0, max })
+                        .build(),
+                )
+                .build()
+        };
+        let dataset = DataSet::from_rows(
+            [10.0, 90.0, 120.0, 160.0]
+                .into_iter()
+                .map(|total| HashMap::from([("total".to_string(), DataValue::Float(total))]))
+                .collect(),
+        );
+        let context = ValidationContext::new();
+        let outcome = |max: f64| {
+            let contract = contract(max);
+            let report = DataValidator::new().validate_with_data(&contract, &dataset, &context);
+            SampleOutcome::from_report(report, &contract, &dataset)
+        };
+        let (loose, tight) = (outcome(150.0), outcome(100.0));
+
+        let preview = ChangePreview::compare(&loose, &tight);
+        assert!(!preview.old_passed && !preview.new_passed);
+        assert_eq!(preview.old_failing_rows, 1);
+        assert_eq!(preview.newly_failing_rows, vec![2]);
+        assert!(preview.newly_passing_rows.is_empty());
+        // The range check already failed, so no check fails anew
+        assert!(preview.newly_failing_checks.is_empty());
+        assert!(preview.breaks());
+        assert_eq!(preview.blast_radius(), 0.25);
+
+        let relaxed = ChangePreview::compare(&tight, &outcome(200.0));
+        assert_eq!(relaxed.newly_passing_rows, vec![2, 3]);
+        assert_eq!(relaxed.newly_passing_checks.len(), 1);
+        assert!(!relaxed.breaks());
+    }
+}
@@ -0,0 +1,279 @@
+//! Proposal of constraints and quality checks from sampled data.
+//!
+//! A contract generated from a table schema only lists its fields.
+//! [`propose_checks`] looks at a sample of the table and fills in what the
+//! data already shows: allowed values, ranges and patterns per field, and a
+//! completeness threshold. The proposals describe the sample, so they are a
+//! starting point for review rather than a specification.
+
+use crate::{DataSet, DataValue};
+use contracts_core::{
+    CompletenessCheck, Contract, DataType, Field, FieldConstraints, PrimitiveType,
+    QualityChecksBuilder,
+};
+use regex::Regex;
+use std::collections::BTreeSet;
+
+/// Most distinct values of a string field proposed as its allowed values.
+pub const MAX_ALLOWED_VALUES: usize = 10;
+
+/// Pattern proposed for string fields whose values are all UUIDs.
+pub const UUID_PATTERN: &str =
+    "^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$";
+
+/// Returns `contract` with constraints and a completeness check proposed from
+/// the rows of `sample`.
+///
+/// For each field without constraints of the same kind:
+///
+/// - string fields whose values are all UUIDs get [`UUID_PATTERN`]
+/// - other string fields with at most [`MAX_ALLOWED_VALUES`] distinct values,
+///   each seen at least twice on average, get those values as allowed values
+/// - numeric fields get the observed minimum and maximum as their range
+///
+/// Nullable fields that are non-null in at least half of the rows are checked
+/// for completeness, with the lowest observed non-null ratio (rounded down to
+/// a percent) as threshold. Sparser fields are left out so they do not lower
+/// the threshold of every other field. Existing quality checks are kept, and
+/// an empty sample proposes nothing.
+///
+/// # Example
+///
+/// ```rust
+/// use contracts_core::{ContractBuilder, DataFormat, FieldBuilder, FieldConstraints};
+/// use contracts_validator::{DataRow, DataSet, DataValue, propose_checks};
+///
+/// let contract = ContractBuilder::new("orders", "team")
+///     .location("s3://lake/orders")
+///     .format(DataFormat::Iceberg)
+///     .field(FieldBuilder::new("quantity", "int32").build())
+///     .build();
+/// let rows = [3, 12, 7].map(|quantity| {
+///     let mut row = DataRow::new();
+///     row.insert("quantity".to_string(), DataValue::Int(quantity));
+///     row
+/// });
+///
+/// let profiled = propose_checks(&contract, &DataSet::from_rows(rows.to_vec()));
+/// assert_eq!(
+///     profiled.schema.fields[0].constraints,
+///     Some(vec![FieldConstraints::Range { min: 3.0, max: 12.0 }])
+/// );
+/// ```
+pub fn propose_checks(contract: &Contract, sample: &DataSet) -> Contract {
+    let mut contract = contract.clone();
+    if sample.is_empty() {
+        return contract;
+    }
+
+    let uuid = Regex::new(UUID_PATTERN).expect("UUID_PATTERN is a valid regex");
+    let rows = sample.len();
+    let mut completeness: Vec<(String, f64)> = Vec::new();
+    for field in &mut contract.schema.fields {
+        let values: Vec<&DataValue> = sample
+            .column(&field.name)
+            .filter(|value| !value.is_null())
+            .collect();
+
+        if let Some(constraint) = propose_constraint(field, &values, &uuid)
+            && !has_constraint_like(field, &constraint)
+        {
+            field
+                .constraints
+                .get_or_insert_with(Vec::new)
+                .push(constraint);
+        }
+
+        let ratio = values.len() as f64 / rows as f64;
+        if field.nullable && ratio >= 0.5 {
+            completeness.push((field.name.clone(), ratio));
+        }
+    }
+
+    let quality = contract
+        .quality_checks
+        .get_or_insert_with(|| QualityChecksBuilder::new().build());
+    if quality.completeness.is_none() && !completeness.is_empty() {
+        let lowest = completeness
+            .iter()
+            .map(|(_, ratio)| *ratio)
+            .fold(1.0, f64::min);
+        quality.completeness = Some(CompletenessCheck {
+            threshold: (lowest * 100.0).floor() / 100.0,
+            fields: completeness.into_iter().map(|(name, _)| name).collect(),
+            min_sample_size: None,
+            severity: None,
+        });
+    }
+    if *quality == QualityChecksBuilder::new().build() {
+        contract.quality_checks = None;
+    }
+
+    contract
+}
+
+/// Proposes a constraint for a field from its non-null values.
+fn propose_constraint(
+    field: &Field,
+    values: &[&DataValue],
+    uuid: &Regex,
+) -> Option<FieldConstraints> {
+    if values.is_empty() {
+        return None;
+    }
+
+    let DataType::Primitive(primitive) = &field.field_type else {
+        return None;
+    };
+    match primitive {
+        PrimitiveType::String | PrimitiveType::Uuid => {
+            let strings: Vec<&str> = values
+                .iter()
+                .map(|value| value.as_string())
+                .collect::<Option<_>>()?;
+            if strings.iter().all(|s| uuid.is_match(s)) {
+                return Some(FieldConstraints::Pattern {
+                    regex: UUID_PATTERN.to_string(),
+                });
+            }
+
+            let distinct: BTreeSet<&str> = strings.iter().copied().collect();
+            (distinct.len() <= MAX_ALLOWED_VALUES && distinct.len() * 2 <= strings.len()).then(
+                || FieldConstraints::AllowedValues {
+                    values: distinct.into_iter().map(str::to_string).collect(),
+                },
+            )
+        }
+        PrimitiveType::Int32
+        | PrimitiveType::Int64
+        | PrimitiveType::Float32
+        | PrimitiveType::Float64
+        | PrimitiveType::Decimal => {
+            let numbers: Vec<f64> = values
+                .iter()
+                .map(|value| value.as_float())
+                .collect::<Option<_>>()?;
+            let min = numbers.iter().copied().fold(f64::INFINITY, f64::min);
+            let max = numbers.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+            Some(FieldConstraints::Range { min, max })
+        }
+        _ => None,
+    }
+}
+
+/// Whether `field` already has a constraint of the same kind as `constraint`.
+fn has_constraint_like(field: &Field, constraint: &FieldConstraints) -> bool {
+    field
+        .constraints
+        .iter()
+        .flatten()
+        .any(|existing| std::mem::discriminant(existing) == std::mem::discriminant(constraint))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DataRow;
+    use contracts_core::{ContractBuilder, DataFormat, FieldBuilder};
+    use pretty_assertions::assert_eq;
+
+    fn contract() -> Contract {
+        ContractBuilder::new("events", "team")
+            .location("s3://lake/events")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("id", "uuid").nullable(false).build())
+            .field(FieldBuilder::new("status", "string").build())
+            .field(FieldBuilder::new("email", "string").build())
+            .field(FieldBuilder::new("score", "float64").build())
+            .field(FieldBuilder::new("note", "string").build())
+            .build()
+    }
+
+    fn sample() -> DataSet {
+        let rows = (0..8)
+            .map(|i| {
+                let mut row = DataRow::new();
+                let id = format!("6f1c2a0e-3b4d-4e5f-8a9b-{:012x}", i);
+                row.insert("id".to_string(), DataValue::String(id));
+                let status = if i % 3 == 0 { "failed" } else { "done" };
+                row.insert("status".to_string(), DataValue::String(status.to_string()));
+                let email = format!("user{i}@example.com");
+                row.insert("email".to_string(), DataValue::String(email));
+                let score = if i == 7 {
+                    DataValue::Null
+                } else {
+                    DataValue::Float(i as f64 / 2.0)
+                };
+                row.insert("score".to_string(), score);
+                let note = if i == 0 {
+                    DataValue::String("n/a".to_string())
+                } else {
+                    DataValue::Null
+                };
+                row.insert("note".to_string(), note);
+                row
+            })
+            .collect();
+        DataSet::from_rows(rows)
+    }
+
+    fn constraints(contract: &Contract, name: &str) -> Option<Vec<FieldConstraints>> {
+        contract.field(name).unwrap().constraints.clone()
+    }
+
+    #[test]
+    fn test_proposes_constraints_by_field() {
+        let profiled = propose_checks(&contract(), &sample());
+
+        assert_eq!(
+            constraints(&profiled, "id"),
+            Some(vec![FieldConstraints::Pattern {
+                regex: UUID_PATTERN.to_string()
+            }])
+        );
+        assert_eq!(
+            constraints(&profiled, "status"),
+            Some(vec![FieldConstraints::AllowedValues {
+                values: vec!["done".to_string(), "failed".to_string()]
+            }])
+        );
+        // Every email is different, so none is proposed as allowed
+        assert_eq!(constraints(&profiled, "email"), None);
+        assert_eq!(
+            constraints(&profiled, "score"),
+            Some(vec![FieldConstraints::Range { min: 0.0, max: 3.0 }])
+        );
+        // A single value is not a pattern
+        assert_eq!(constraints(&profiled, "note"), None);
+    }
+
+    #[test]
+    fn test_proposes_completeness_from_null_rates() {
+        let profiled = propose_checks(&contract(), &sample());
+
+        let completeness = profiled.quality_checks.unwrap().completeness.unwrap();
+        // 7 of 8 scores are set; the mostly null note is left out
+        assert_eq!(completeness.threshold, 0.87);
+        assert_eq!(completeness.fields, vec!["status", "email", "score"]);
+    }
+
+    #[test]
+    fn test_keeps_existing_constraints_and_checks() {
+        let mut contract = contract();
+        contract.schema.fields[3].constraints = Some(vec![FieldConstraints::Range {
+            min: 0.0,
+            max: 100.0,
+        }]);
+        let profiled = propose_checks(&contract, &sample());
+        assert_eq!(
+            constraints(&profiled, "score"),
+            Some(vec![FieldConstraints::Range {
+                min: 0.0,
+                max: 100.0
+            }])
+        );
+
+        let empty = propose_checks(&contract, &DataSet::empty());
+        assert_eq!(empty, contract);
+    }
+}
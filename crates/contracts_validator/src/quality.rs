@@ -4,9 +4,23 @@
 //! - Completeness: Percentage of non-null values
 //! - Uniqueness: Detection of duplicate values
 //! - Freshness: Data staleness checks (implemented separately)
-
-use crate::{DataSet, DataValue, ValidationError};
-use contracts_core::{CompletenessCheck, Contract, UniquenessCheck};
+//!
+//! Rows marked by the contract's soft-delete column are left out of both
+//! checks. A scoped uniqueness check only compares rows of the same group,
+//! such as the same day of a timestamp column. An approximate uniqueness
+//! check counts its keys in a HyperLogLog sketch instead of a set. Row
+//! checks count the rows for which their expression is false, and volume
+//! checks the rows of each event-time bucket.
+
+use crate::row_filter::RowRule;
+use crate::sketch::HyperLogLog;
+use crate::time_window::row_timestamp;
+use crate::volume::VolumeCounter;
+use crate::{DataRow, DataSet, ValidationError, soft_delete, stats::value_key};
+use chrono::Utc;
+use contracts_core::{
+    CompletenessCheck, Contract, RowCheck, ScopePeriod, Severity, UniquenessCheck, UniquenessScope,
+};
 use std::collections::HashSet;
 
 /// Validates quality checks on a dataset.
@@ -22,6 +36,19 @@ impl QualityValidator {
     ///
     /// Returns a list of validation errors. An empty list indicates success.
     pub fn validate(&self, contract: &Contract, dataset: &DataSet) -> Vec<ValidationError> {
+        self.validate_with_severity(contract, dataset)
+            .into_iter()
+            .map(|(_, error)| error)
+            .collect()
+    }
+
+    /// Validates all quality checks, pairing each failure with the severity
+    /// declared by its check.
+    pub(crate) fn validate_with_severity(
+        &self,
+        contract: &Contract,
+        dataset: &DataSet,
+    ) -> Vec<(Option<Severity>, ValidationError)> {
         let mut errors = Vec::new();
 
         let quality_checks = match &contract.quality_checks {
@@ -34,14 +61,44 @@ impl QualityValidator {
             return errors;
         }
 
+        // Soft-deleted rows count for neither check
+        let live = soft_delete::live_rows(dataset, contract);
+        let dataset = live.as_ref().unwrap_or(dataset);
+
         // Completeness check
         if let Some(completeness) = &quality_checks.completeness {
-            errors.extend(self.validate_completeness(completeness, dataset));
+            errors.extend(
+                self.validate_completeness(completeness, dataset)
+                    .into_iter()
+                    .map(|error| (completeness.severity, error)),
+            );
         }
 
         // Uniqueness check
         if let Some(uniqueness) = &quality_checks.uniqueness {
-            errors.extend(self.validate_uniqueness(uniqueness, dataset));
+            errors.extend(
+                self.validate_uniqueness(uniqueness, contract, dataset)
+                    .into_iter()
+                    .map(|error| (uniqueness.severity, error)),
+            );
+        }
+
+        let mut row_checks = RowCheckCounter::new(contract);
+        for row in dataset.rows() {
+            row_checks.insert(row);
+        }
+        errors.extend(row_checks.errors());
+
+        if let Some(volume) = &quality_checks.volume {
+            let mut counter = VolumeCounter::new(volume);
+            for row in dataset.rows() {
+                counter.insert(row);
+            }
+            errors.extend(
+                counter
+                    .error(Utc::now())
+                    .map(|error| (volume.severity, error)),
+            );
         }
 
         errors
@@ -77,102 +134,366 @@ impl QualityValidator {
             return Ok(());
         }
 
-        let mut non_null_count = 0;
-
-        for row in dataset.rows() {
-            if let Some(value) = row.get(field_name)
-                && !value.is_null()
-            {
-                non_null_count += 1;
-            }
+        let non_null_count = match dataset.column_stats().and_then(|s| s.get(field_name)) {
+            Some(stats) => total_rows.saturating_sub(stats.null_count),
             // Missing field counts as null
-        }
-
-        let completeness_ratio = non_null_count as f64 / total_rows as f64;
+            None => dataset.column(field_name).filter(|v| !v.is_null()).count(),
+        };
 
-        if completeness_ratio < threshold {
-            return Err(ValidationError::quality_check(format!(
-                "Completeness check failed for field '{}': {:.2}% < {:.2}% (threshold)",
-                field_name,
-                completeness_ratio * 100.0,
-                threshold * 100.0
-            )));
+        match completeness_error(field_name, non_null_count, total_rows, threshold) {
+            Some(err) => Err(err),
+            None => Ok(()),
         }
-
-        Ok(())
     }
 
     /// Validates uniqueness requirements.
     fn validate_uniqueness(
         &self,
         check: &UniquenessCheck,
+        contract: &Contract,
         dataset: &DataSet,
     ) -> Vec<ValidationError> {
         let mut errors = Vec::new();
 
-        let duplicates = self.find_duplicates(&check.fields, dataset);
+        let groups = match ScopeGroups::resolve(check, contract) {
+            Ok(groups) => groups,
+            Err(message) => return vec![ValidationError::quality_check(message)],
+        };
+        let error = match groups {
+            Some(groups) => self.find_duplicates(check, Some(&groups), dataset),
+            None => match self.duplicates_from_stats(&check.fields, dataset) {
+                Some(count) => uniqueness_error(check, count),
+                None => self.find_duplicates(check, None, dataset),
+            },
+        };
 
-        if !duplicates.is_empty() {
-            errors.push(ValidationError::quality_check(format!(
-                "Uniqueness check failed for fields [{}]: found {} duplicate(s)",
-                check.fields.join(", "),
-                duplicates.len()
-            )));
-        }
+        errors.extend(error);
 
         errors
     }
 
-    /// Finds duplicate values in the specified fields.
-    fn find_duplicates(&self, fields: &[String], dataset: &DataSet) -> Vec<String> {
-        let mut seen = HashSet::new();
-        let mut duplicates = Vec::new();
+    /// Counts duplicates of a single non-null field from precomputed stats.
+    ///
+    /// Returns `None` when the stats cannot answer exactly, in which case the
+    /// rows have to be scanned.
+    fn duplicates_from_stats(&self, fields: &[String], dataset: &DataSet) -> Option<usize> {
+        let [field] = fields else {
+            return None;
+        };
+        let stats = dataset.column_stats()?.get(field)?;
+        if !stats.distinct_exact || stats.null_count > 0 {
+            return None;
+        }
+        Some(dataset.len().saturating_sub(stats.distinct_count))
+    }
+
+    /// Counts the rows repeating the values of the check's fields within
+    /// their scope group, and returns the failure if there are any.
+    fn find_duplicates(
+        &self,
+        check: &UniquenessCheck,
+        groups: Option<&ScopeGroups>,
+        dataset: &DataSet,
+    ) -> Option<ValidationError> {
+        let mut counter = DuplicateCounter::new(check);
 
         for row in dataset.rows() {
-            // Build a composite key from all uniqueness fields
-            let mut key_parts = Vec::new();
-            let mut has_all_fields = true;
-
-            for field in fields {
-                match row.get(field) {
-                    Some(value) => {
-                        key_parts.push(self.value_to_string(value));
-                    }
-                    None => {
-                        has_all_fields = false;
-                        break;
-                    }
+            let Some(key) = scoped_uniqueness_key(&check.fields, groups, row) else {
+                continue; // Skip rows with missing fields
+            };
+            counter.insert(key);
+        }
+
+        counter.error(check)
+    }
+}
+
+/// Returns the completeness failure of a field with `non_null_count` values
+/// in `total_rows` rows, if it falls below `threshold`.
+pub(crate) fn completeness_error(
+    field_name: &str,
+    non_null_count: usize,
+    total_rows: usize,
+    threshold: f64,
+) -> Option<ValidationError> {
+    let completeness_ratio = non_null_count as f64 / total_rows as f64;
+
+    (completeness_ratio < threshold).then(|| {
+        ValidationError::quality_check(format!(
+            "Completeness check failed for field '{}': {:.2}% < {:.2}% (threshold)",
+            field_name,
+            completeness_ratio * 100.0,
+            threshold * 100.0
+        ))
+    })
+}
+
+/// Returns the uniqueness failure for `duplicates` repeated keys, if any.
+pub(crate) fn uniqueness_error(
+    check: &UniquenessCheck,
+    duplicates: usize,
+) -> Option<ValidationError> {
+    (duplicates > 0).then(|| {
+        ValidationError::quality_check(format!(
+            "Uniqueness check failed for {}: found {} duplicate(s)",
+            describe_uniqueness(check),
+            duplicates
+        ))
+    })
+}
+
+/// Returns the failure of an approximate uniqueness check estimating
+/// `duplicates` repeated keys, if they exceed the `margin` of error of the
+/// estimate.
+pub(crate) fn approximate_uniqueness_error(
+    check: &UniquenessCheck,
+    duplicates: usize,
+    margin: usize,
+) -> Option<ValidationError> {
+    (duplicates > margin).then(|| {
+        ValidationError::quality_check(format!(
+            "Uniqueness check failed for {}: found about {} duplicate(s) (approximate, ±{})",
+            describe_uniqueness(check),
+            duplicates,
+            margin
+        ))
+    })
+}
+
+fn describe_uniqueness(check: &UniquenessCheck) -> String {
+    let scope = match check.parsed_scope() {
+        Ok(UniquenessScope::Global) | Err(_) => String::new(),
+        Ok(scope) => format!(" {}", scope),
+    };
+    format!("fields [{}]{}", check.fields.join(", "), scope)
+}
+
+/// Standard errors of a sketch's estimate allowed before an approximate
+/// check reports duplicates, keeping unique data from failing by chance.
+pub(crate) const APPROXIMATE_MARGIN: f64 = 3.0;
+
+/// Counts the repeated keys of a uniqueness check, exactly with the set of
+/// keys seen or approximately with a sketch of them.
+#[derive(Debug, Clone)]
+pub(crate) enum DuplicateCounter {
+    Exact {
+        seen: HashSet<String>,
+        duplicates: usize,
+    },
+    Approximate {
+        sketch: HyperLogLog,
+        keys: usize,
+    },
+}
+
+impl DuplicateCounter {
+    /// Creates the counter for `check`, approximate when it asks to be.
+    pub(crate) fn new(check: &UniquenessCheck) -> Self {
+        match &check.approximate {
+            Some(approximate) => Self::Approximate {
+                sketch: HyperLogLog::with_tolerance(
+                    approximate.error_tolerance(),
+                    approximate.max_memory_kb.map(|kb| kb * 1024),
+                ),
+                keys: 0,
+            },
+            None => Self::Exact {
+                seen: HashSet::new(),
+                duplicates: 0,
+            },
+        }
+    }
+
+    /// Counts `key`.
+    pub(crate) fn insert(&mut self, key: String) {
+        match self {
+            Self::Exact { seen, duplicates } => {
+                if !seen.insert(key) {
+                    *duplicates += 1;
                 }
             }
+            Self::Approximate { sketch, keys } => {
+                sketch.insert(&key);
+                *keys += 1;
+            }
+        }
+    }
 
-            if !has_all_fields {
-                continue; // Skip rows with missing fields
+    /// Returns the failure of `check` for the keys counted, if any.
+    pub(crate) fn error(&self, check: &UniquenessCheck) -> Option<ValidationError> {
+        match self {
+            Self::Exact { duplicates, .. } => uniqueness_error(check, *duplicates),
+            Self::Approximate { sketch, keys } => {
+                let distinct = sketch.estimate().min(*keys as f64);
+                let duplicates = (*keys as f64 - distinct).round() as usize;
+                let margin =
+                    (APPROXIMATE_MARGIN * sketch.standard_error() * distinct).ceil() as usize;
+                approximate_uniqueness_error(check, duplicates, margin)
             }
+        }
+    }
+}
 
-            let key = key_parts.join("|");
+/// Returns the failure of a row check that `failing` rows violate, if any.
+pub(crate) fn row_check_error(check: &RowCheck, failing: usize) -> Option<ValidationError> {
+    (failing > 0).then(|| {
+        ValidationError::row_check(
+            &check.name,
+            format!("{} row(s) fail '{}'", failing, check.expression),
+        )
+    })
+}
+
+/// Counts the rows failing each row check of a contract, as rows come in.
+#[derive(Debug, Clone)]
+pub(crate) struct RowCheckCounter<'a> {
+    contract: &'a Contract,
+    /// Each check, its parsed expression unless invalid, and its failing rows
+    checks: Vec<(&'a RowCheck, Option<RowRule>, usize)>,
+}
+
+impl<'a> RowCheckCounter<'a> {
+    /// Parses the row checks of `contract`.
+    pub(crate) fn new(contract: &'a Contract) -> Self {
+        let checks = contract
+            .quality_checks
+            .iter()
+            .flat_map(|qc| qc.row_checks.iter().flatten())
+            .map(|check| (check, RowRule::parse(check, contract).ok(), 0))
+            .collect();
+        Self { contract, checks }
+    }
 
-            if !seen.insert(key.clone()) {
-                // This is a duplicate
-                duplicates.push(key);
+    /// Counts `row` against every check.
+    pub(crate) fn insert(&mut self, row: &DataRow) {
+        for (_, rule, failing) in &mut self.checks {
+            if rule.as_ref().is_some_and(|rule| rule.is_violated_by(row)) {
+                *failing += 1;
             }
         }
+    }
 
-        duplicates
+    /// Returns the failure of each check with the severity it declares; an
+    /// expression that does not parse is an error.
+    pub(crate) fn errors(&self) -> Vec<(Option<Severity>, ValidationError)> {
+        self.checks
+            .iter()
+            .filter_map(|(check, rule, failing)| match rule {
+                Some(_) => row_check_error(check, *failing).map(|error| (check.severity, error)),
+                None => RowRule::parse(check, self.contract)
+                    .err()
+                    .map(|e| (Some(Severity::Error), e)),
+            })
+            .collect()
     }
+}
 
-    /// Converts a DataValue to a string representation for comparison.
-    fn value_to_string(&self, value: &DataValue) -> String {
-        match value {
-            DataValue::Null => "NULL".to_string(),
-            DataValue::String(s) => s.clone(),
-            DataValue::Int(i) => i.to_string(),
-            DataValue::Float(f) => f.to_string(),
-            DataValue::Bool(b) => b.to_string(),
-            DataValue::Timestamp(ts) => ts.clone(),
-            DataValue::Map(_) => "[map]".to_string(),
-            DataValue::List(_) => "[list]".to_string(),
+/// Builds the key of `row` within its scope group, or `None` if the row
+/// lacks one of the `fields` or a value of the group.
+pub(crate) fn scoped_uniqueness_key(
+    fields: &[String],
+    groups: Option<&ScopeGroups>,
+    row: &DataRow,
+) -> Option<String> {
+    let key = uniqueness_key(fields, row)?;
+    match groups {
+        Some(groups) => Some(format!("{}#{}", groups.key(row)?, key)),
+        None => Some(key),
+    }
+}
+
+/// The scope of a uniqueness check resolved against its contract: the
+/// columns whose values group the rows, truncated to a period for a
+/// period scope.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ScopeGroups {
+    pub(crate) period: Option<ScopePeriod>,
+    pub(crate) columns: Vec<String>,
+}
+
+impl ScopeGroups {
+    /// Resolves the scope of `check`, or returns `None` for a global one.
+    ///
+    /// A period scope without a column takes the freshness check's metric.
+    pub(crate) fn resolve(
+        check: &UniquenessCheck,
+        contract: &Contract,
+    ) -> Result<Option<Self>, String> {
+        let invalid = |message: String| {
+            format!(
+                "Invalid uniqueness scope '{}': {}",
+                check.scope.as_deref().unwrap_or_default(),
+                message
+            )
+        };
+        let (period, columns) = match check.parsed_scope().map_err(invalid)? {
+            UniquenessScope::Global => return Ok(None),
+            UniquenessScope::Period { period, column } => {
+                let column = column
+                    .or_else(|| {
+                        let freshness = contract.quality_checks.as_ref()?.freshness.as_ref()?;
+                        freshness.column().map(str::to_string)
+                    })
+                    .ok_or_else(|| {
+                        invalid("name a timestamp column or declare a freshness check".to_string())
+                    })?;
+                (Some(period), vec![column])
+            }
+            UniquenessScope::Partition { columns } => (None, columns),
+        };
+        if let Some(column) = columns.iter().find(|c| !contract.has_field(c)) {
+            return Err(invalid(format!("unknown field '{}'", column)));
         }
+        Ok(Some(Self { period, columns }))
     }
+
+    /// Returns the group of `row`, or `None` when a group value is missing
+    /// or, for a period scope, not a timestamp.
+    pub(crate) fn key(&self, row: &DataRow) -> Option<String> {
+        let values = self
+            .columns
+            .iter()
+            .map(|column| {
+                let value = row.get(column).filter(|v| !v.is_null())?;
+                let Some(period) = self.period else {
+                    return Some(value_key(value));
+                };
+                let format = match period {
+                    ScopePeriod::Hour => "%Y-%m-%dT%H",
+                    ScopePeriod::Day => "%Y-%m-%d",
+                    ScopePeriod::Month => "%Y-%m",
+                    ScopePeriod::Year => "%Y",
+                };
+                Some(row_timestamp(value)?.format(format).to_string())
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(values.join("|"))
+    }
+
+    /// Returns the SQL expressions grouping the rows of the scope.
+    pub(crate) fn sql_groups(&self) -> Vec<String> {
+        self.columns
+            .iter()
+            .map(|column| match self.period {
+                Some(period) => format!(
+                    "date_trunc('{}', CAST(\"{}\" AS TIMESTAMP))",
+                    period.as_str(),
+                    column
+                ),
+                None => format!("\"{}\"", column),
+            })
+            .collect()
+    }
+}
+
+/// Builds the composite key of `row` over the uniqueness `fields`, or `None`
+/// if the row lacks one of them.
+pub(crate) fn uniqueness_key(fields: &[String], row: &DataRow) -> Option<String> {
+    let key_parts = fields
+        .iter()
+        .map(|field| row.get(field).map(value_key))
+        .collect::<Option<Vec<_>>>()?;
+    Some(key_parts.join("|"))
 }
 
 impl Default for QualityValidator {
@@ -184,7 +505,11 @@ impl Default for QualityValidator {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use contracts_core::{ContractBuilder, DataFormat, FieldBuilder, QualityChecks};
+    use crate::DataValue;
+    use contracts_core::{
+        ApproximateUniqueness, ContractBuilder, DataFormat, FieldBuilder, QualityChecks,
+        QualityChecksBuilder,
+    };
     use std::collections::HashMap;
 
     #[test]
@@ -197,11 +522,10 @@ mod tests {
                 completeness: Some(CompletenessCheck {
                     threshold: 0.8,
                     fields: vec!["id".to_string()],
+                    min_sample_size: None,
+                    severity: None,
                 }),
-                uniqueness: None,
-                freshness: None,
-                custom_checks: None,
-                ml_checks: None,
+                ..Default::default()
             })
             .build();
 
@@ -234,11 +558,10 @@ mod tests {
                 completeness: Some(CompletenessCheck {
                     threshold: 0.95,
                     fields: vec!["id".to_string()],
+                    min_sample_size: None,
+                    severity: None,
                 }),
-                uniqueness: None,
-                freshness: None,
-                custom_checks: None,
-                ml_checks: None,
+                ..Default::default()
             })
             .build();
 
@@ -269,14 +592,14 @@ mod tests {
             .format(DataFormat::Iceberg)
             .field(FieldBuilder::new("id", "string").nullable(false).build())
             .quality_checks(QualityChecks {
-                completeness: None,
                 uniqueness: Some(UniquenessCheck {
                     fields: vec!["id".to_string()],
                     scope: None,
+                    min_sample_size: None,
+                    severity: None,
+                    approximate: None,
                 }),
-                freshness: None,
-                custom_checks: None,
-                ml_checks: None,
+                ..Default::default()
             })
             .build();
 
@@ -301,14 +624,14 @@ mod tests {
             .format(DataFormat::Iceberg)
             .field(FieldBuilder::new("id", "string").nullable(false).build())
             .quality_checks(QualityChecks {
-                completeness: None,
                 uniqueness: Some(UniquenessCheck {
                     fields: vec!["id".to_string()],
                     scope: None,
+                    min_sample_size: None,
+                    severity: None,
+                    approximate: None,
                 }),
-                freshness: None,
-                custom_checks: None,
-                ml_checks: None,
+                ..Default::default()
             })
             .build();
 
@@ -327,6 +650,51 @@ mod tests {
         assert!(matches!(errors[0], ValidationError::QualityCheckFailed(_)));
     }
 
+    #[test]
+    fn test_approximate_uniqueness() {
+        let contract = ContractBuilder::new("test", "owner")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("id", "string").nullable(false).build())
+            .quality_checks(
+                QualityChecksBuilder::new()
+                    .uniqueness(UniquenessCheck {
+                        fields: vec!["id".to_string()],
+                        scope: None,
+                        min_sample_size: None,
+                        severity: None,
+                        approximate: Some(ApproximateUniqueness {
+                            error_tolerance: Some(0.01),
+                            max_memory_kb: Some(16),
+                        }),
+                    })
+                    .build(),
+            )
+            .build();
+        let ids = |distinct: usize| {
+            DataSet::from_rows(
+                (0..20_000)
+                    .map(|i| {
+                        HashMap::from([(
+                            "id".to_string(),
+                            DataValue::String(format!("order-{}", i % distinct)),
+                        )])
+                    })
+                    .collect(),
+            )
+        };
+        let validator = QualityValidator::new();
+
+        // Unique keys stay within the margin of error of the sketch
+        assert!(validator.validate(&contract, &ids(20_000)).is_empty());
+
+        let errors = validator.validate(&contract, &ids(10_000));
+        assert_eq!(errors.len(), 1);
+        let message = errors[0].to_string();
+        assert!(message.contains("found about"), "{message}");
+        assert!(message.contains("(approximate, ±"), "{message}");
+    }
+
     #[test]
     fn test_composite_uniqueness() {
         let contract = ContractBuilder::new("test", "owner")
@@ -343,14 +711,14 @@ mod tests {
                     .build(),
             )
             .quality_checks(QualityChecks {
-                completeness: None,
                 uniqueness: Some(UniquenessCheck {
                     fields: vec!["user_id".to_string(), "event_id".to_string()],
                     scope: None,
+                    min_sample_size: None,
+                    severity: None,
+                    approximate: None,
                 }),
-                freshness: None,
-                custom_checks: None,
-                ml_checks: None,
+                ..Default::default()
             })
             .build();
 
@@ -384,11 +752,10 @@ mod tests {
                 completeness: Some(CompletenessCheck {
                     threshold: 0.99,
                     fields: vec!["id".to_string()],
+                    min_sample_size: None,
+                    severity: None,
                 }),
-                uniqueness: None,
-                freshness: None,
-                custom_checks: None,
-                ml_checks: None,
+                ..Default::default()
             })
             .build();
 
@@ -410,11 +777,10 @@ mod tests {
                 completeness: Some(CompletenessCheck {
                     threshold: 0.9,
                     fields: vec!["id".to_string(), "name".to_string()],
+                    min_sample_size: None,
+                    severity: None,
                 }),
-                uniqueness: None,
-                freshness: None,
-                custom_checks: None,
-                ml_checks: None,
+                ..Default::default()
             })
             .build();
 
@@ -437,4 +803,202 @@ mod tests {
         let errors = validator.validate(&contract, &dataset);
         assert_eq!(errors.len(), 1); // Only name field should fail
     }
+
+    #[test]
+    fn test_checks_use_attached_stats() {
+        let contract = ContractBuilder::new("test", "owner")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("id", "string").nullable(true).build())
+            .quality_checks(QualityChecks {
+                completeness: Some(CompletenessCheck {
+                    threshold: 0.9,
+                    fields: vec!["id".to_string()],
+                    min_sample_size: None,
+                    severity: None,
+                }),
+                uniqueness: Some(UniquenessCheck {
+                    fields: vec!["id".to_string()],
+                    scope: None,
+                    min_sample_size: None,
+                    severity: None,
+                    approximate: None,
+                }),
+                ..Default::default()
+            })
+            .build();
+
+        let rows: Vec<HashMap<String, DataValue>> = (0..10)
+            .map(|i| HashMap::from([("id".to_string(), DataValue::Int(i))]))
+            .collect();
+        let dataset = DataSet::from_rows(rows);
+        let validator = QualityValidator::new();
+        assert!(validator.validate(&contract, &dataset).is_empty());
+
+        // The checks trust the attached stats over the rows
+        let mut stats = dataset.compute_column_stats();
+        stats.get_mut("id").unwrap().distinct_count = 8;
+        let errors = validator.validate(&contract, &dataset.clone().with_column_stats(stats));
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].to_string().contains("found 2 duplicate(s)"));
+
+        let mut stats = dataset.compute_column_stats();
+        stats.get_mut("id").unwrap().null_count = 2;
+        let errors = validator.validate(&contract, &dataset.with_column_stats(stats));
+        assert!(errors[0].to_string().contains("Completeness"));
+    }
+
+    fn scoped_events(scope: &str) -> Contract {
+        ContractBuilder::new("events", "owner")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("event_id", "string").build())
+            .field(FieldBuilder::new("region", "string").build())
+            .field(FieldBuilder::new("occurred_at", "timestamp").build())
+            .quality_checks(
+                QualityChecksBuilder::new()
+                    .uniqueness(UniquenessCheck {
+                        fields: vec!["event_id".to_string()],
+                        scope: Some(scope.to_string()),
+                        min_sample_size: None,
+                        severity: None,
+                        approximate: None,
+                    })
+                    .build(),
+            )
+            .build()
+    }
+
+    fn events(rows: &[(&str, &str, &str)]) -> DataSet {
+        DataSet::from_rows(
+            rows.iter()
+                .map(|(id, region, at)| {
+                    HashMap::from([
+                        ("event_id".to_string(), DataValue::String(id.to_string())),
+                        ("region".to_string(), DataValue::String(region.to_string())),
+                        (
+                            "occurred_at".to_string(),
+                            DataValue::Timestamp(at.to_string()),
+                        ),
+                    ])
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_uniqueness_per_day() {
+        let dataset = events(&[
+            ("e1", "eu", "2026-10-15T08:00:00Z"),
+            ("e2", "eu", "2026-10-15T09:00:00Z"),
+            // Event IDs restart every day
+            ("e1", "eu", "2026-10-16T08:00:00Z"),
+            ("e1", "us", "2026-10-16T23:59:59Z"),
+        ]);
+        let validator = QualityValidator::new();
+
+        let errors = validator.validate(&scoped_events("per_day(occurred_at)"), &dataset);
+        assert_eq!(
+            errors[0].to_string(),
+            "Quality check failed: Uniqueness check failed for fields [event_id] \
+             per_day(occurred_at): found 1 duplicate(s)"
+        );
+
+        let errors = validator.validate(&scoped_events("per_partition(region)"), &dataset);
+        assert!(errors[0].to_string().contains("found 1 duplicate(s)"));
+        let errors = validator.validate(&scoped_events("global"), &dataset);
+        assert!(errors[0].to_string().contains("found 2 duplicate(s)"));
+    }
+
+    #[test]
+    fn test_uniqueness_per_month() {
+        let dataset = events(&[
+            ("e1", "eu", "2026-09-30T23:00:00Z"),
+            ("e1", "eu", "2026-10-01T00:00:00Z"),
+            ("e2", "eu", "2026-10-05T00:00:00Z"),
+            ("e2", "eu", "2026-10-31T00:00:00Z"),
+            // Rows without a period are not compared
+            ("e2", "eu", "not a timestamp"),
+        ]);
+        let validator = QualityValidator::new();
+
+        let errors = validator.validate(&scoped_events("per_month(occurred_at)"), &dataset);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].to_string().contains("found 1 duplicate(s)"));
+
+        // Without a column and a freshness check, the scope has no period
+        let errors = validator.validate(&scoped_events("per_month"), &dataset);
+        assert!(
+            errors[0]
+                .to_string()
+                .contains("Invalid uniqueness scope 'per_month'")
+        );
+    }
+
+    #[test]
+    fn test_row_checks() {
+        let check = |name: &str, expression: &str, severity: Option<Severity>| RowCheck {
+            name: name.to_string(),
+            expression: expression.to_string(),
+            severity,
+        };
+        let contract = ContractBuilder::new("orders", "sales")
+            .location("s3://lake/orders")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("discount", "float64").build())
+            .field(FieldBuilder::new("total", "float64").build())
+            .field(FieldBuilder::new("status", "string").build())
+            .quality_checks(
+                QualityChecksBuilder::new()
+                    .row_check(check(
+                        "discount_below_half",
+                        "discount * 2 < total || status == 'void'",
+                        Some(Severity::Warning),
+                    ))
+                    .row_check(check("known_total", "total > 0", None))
+                    .row_check(check("typo", "totl > 0", None))
+                    .build(),
+            )
+            .build();
+        let order = |discount: Option<f64>, total: f64, status: &str| {
+            HashMap::from([
+                (
+                    "discount".to_string(),
+                    discount.map_or(DataValue::Null, DataValue::Float),
+                ),
+                ("total".to_string(), DataValue::Float(total)),
+                ("status".to_string(), DataValue::String(status.to_string())),
+            ])
+        };
+        let dataset = DataSet::from_rows(vec![
+            order(Some(2.0), 10.0, "paid"),
+            order(Some(6.0), 10.0, "paid"),
+            order(Some(6.0), 10.0, "void"),
+            // A null expression passes
+            order(None, 10.0, "paid"),
+            order(Some(8.0), 12.0, "paid"),
+        ]);
+
+        let errors = QualityValidator::new().validate_with_severity(&contract, &dataset);
+        let messages: Vec<_> = errors
+            .iter()
+            .map(|(severity, error)| (*severity, error.to_string()))
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                (
+                    Some(Severity::Warning),
+                    "Row check 'discount_below_half' failed: 2 row(s) fail \
+                     'discount * 2 < total || status == 'void''"
+                        .to_string()
+                ),
+                (
+                    Some(Severity::Error),
+                    "Invalid row check 'typo': 'totl > 0': unknown field 'totl'".to_string()
+                ),
+            ]
+        );
+        assert_eq!(errors[0].1.to_violation().code.code, "DCE0401");
+    }
 }
@@ -0,0 +1,356 @@
+//! Reconciliation of two copies of a dataset.
+//!
+//! A migration or replication job should leave its target holding the same
+//! rows as its source. [`reconcile`] profiles both sides over the contract's
+//! fields (row count, order-independent checksums and per-column statistics)
+//! and lists every metric that differs.
+
+use crate::stats::value_key;
+use crate::{DataSet, DataValue, StatsCollector};
+use contracts_core::{ColumnStats, Contract};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Row count, checksums and statistics of one side of a reconciliation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableProfile {
+    /// Number of rows
+    pub rows: usize,
+
+    /// Sum of the hashes of every row over the contract fields, so it does
+    /// not depend on row order
+    pub checksum: u64,
+
+    /// Profile of each contract field, by name
+    pub columns: BTreeMap<String, ColumnProfile>,
+}
+
+/// Checksum and statistics of one column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnProfile {
+    /// Sum of the hashes of every value of the column
+    pub checksum: u64,
+
+    /// Null count, bounds and distinct count of the column
+    pub stats: ColumnStats,
+}
+
+/// A metric that differs between source and target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Difference {
+    /// Column the metric describes, or `None` for the whole table
+    pub column: Option<String>,
+
+    /// Name of the metric: `rows`, `checksum`, `null_count`,
+    /// `distinct_count`, `min` or `max`
+    pub metric: &'static str,
+
+    /// Value in the source
+    pub source: String,
+
+    /// Value in the target
+    pub target: String,
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.column {
+            Some(column) => write!(f, "{} of '{}'", self.metric, column)?,
+            None => write!(f, "{}", self.metric)?,
+        }
+        write!(
+            f,
+            " differs: {} in source, {} in target",
+            self.source, self.target
+        )
+    }
+}
+
+/// Result of comparing a source and a target dataset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reconciliation {
+    /// Profile of the source
+    pub source: TableProfile,
+
+    /// Profile of the target
+    pub target: TableProfile,
+
+    /// Every metric that differs, table metrics first, then columns in
+    /// contract order
+    pub differences: Vec<Difference>,
+}
+
+impl Reconciliation {
+    /// Returns whether source and target have the same profile.
+    pub fn matches(&self) -> bool {
+        self.differences.is_empty()
+    }
+}
+
+impl TableProfile {
+    /// Profiles `dataset` over the fields of `contract`.
+    ///
+    /// Values are hashed by their rendering, so an integer and a float with the
+    /// same value, as read from a CSV file and a table, hash alike. Columns
+    /// outside the contract are ignored.
+    pub fn of(contract: &Contract, dataset: &DataSet) -> Self {
+        let fields: Vec<&str> = contract
+            .schema
+            .fields
+            .iter()
+            .map(|field| field.name.as_str())
+            .collect();
+
+        let mut checksum = 0u64;
+        let mut checksums = vec![0u64; fields.len()];
+        let mut collector = StatsCollector::new();
+        for row in dataset.rows() {
+            let mut row_hash = Fnv::new();
+            for (field, column_checksum) in fields.iter().zip(&mut checksums) {
+                let value = row.get(*field).unwrap_or(&DataValue::Null);
+                let mut value_hash = Fnv::new();
+                value_hash.write_value(value);
+                *column_checksum = column_checksum.wrapping_add(value_hash.finish());
+                row_hash.write_value(value);
+            }
+            checksum = checksum.wrapping_add(row_hash.finish());
+            collector.observe(row);
+        }
+
+        let rows = collector.rows();
+        let mut stats = collector.finish();
+        let columns = fields
+            .iter()
+            .zip(checksums)
+            .map(|(field, checksum)| {
+                let stats = stats.remove(*field).unwrap_or(ColumnStats {
+                    null_count: rows,
+                    distinct_exact: true,
+                    ..ColumnStats::default()
+                });
+                (field.to_string(), ColumnProfile { checksum, stats })
+            })
+            .collect();
+
+        Self {
+            rows,
+            checksum,
+            columns,
+        }
+    }
+}
+
+/// Compares `source` and `target` over the fields of `contract`.
+///
+/// # Example
+///
+/// ```rust
+/// use contracts_core::{ContractBuilder, DataFormat, FieldBuilder};
+/// use contracts_validator::{DataRow, DataSet, DataValue, reconcile};
+///
+/// let contract = ContractBuilder::new("orders", "team")
+///     .location("orders.csv")
+///     .format(DataFormat::Csv)
+///     .field(FieldBuilder::new("id", "int64").build())
+///     .build();
+/// let rows = |ids: &[i64]| {
+///     let rows = ids.iter().map(|&id| {
+///         let mut row = DataRow::new();
+///         row.insert("id".to_string(), DataValue::Int(id));
+///         row
+///     });
+///     DataSet::from_rows(rows.collect())
+/// };
+///
+/// // Row order does not matter
+/// assert!(reconcile(&contract, &rows(&[1, 2]), &rows(&[2, 1])).matches());
+///
+/// let reconciliation = reconcile(&contract, &rows(&[1, 2]), &rows(&[1, 3]));
+/// assert_eq!(
+///     reconciliation.differences.last().unwrap().to_string(),
+///     "max of 'id' differs: 2 in source, 3 in target"
+/// );
+/// ```
+pub fn reconcile(contract: &Contract, source: &DataSet, target: &DataSet) -> Reconciliation {
+    let source = TableProfile::of(contract, source);
+    let target = TableProfile::of(contract, target);
+
+    let mut differences = Vec::new();
+    let mut compare = |column: Option<&str>, metric, source: String, target: String| {
+        if source != target {
+            differences.push(Difference {
+                column: column.map(str::to_string),
+                metric,
+                source,
+                target,
+            });
+        }
+    };
+
+    compare(
+        None,
+        "rows",
+        source.rows.to_string(),
+        target.rows.to_string(),
+    );
+    compare(None, "checksum", hex(source.checksum), hex(target.checksum));
+    for field in &contract.schema.fields {
+        let column = Some(field.name.as_str());
+        let (a, b) = (&source.columns[&field.name], &target.columns[&field.name]);
+        compare(column, "checksum", hex(a.checksum), hex(b.checksum));
+        compare(
+            column,
+            "null_count",
+            a.stats.null_count.to_string(),
+            b.stats.null_count.to_string(),
+        );
+        // A lower bound says nothing about the other side
+        if a.stats.distinct_exact && b.stats.distinct_exact {
+            compare(
+                column,
+                "distinct_count",
+                a.stats.distinct_count.to_string(),
+                b.stats.distinct_count.to_string(),
+            );
+        }
+        let bound = |value: &Option<String>| value.clone().unwrap_or_else(|| "-".to_string());
+        compare(column, "min", bound(&a.stats.min), bound(&b.stats.min));
+        compare(column, "max", bound(&a.stats.max), bound(&b.stats.max));
+    }
+
+    Reconciliation {
+        source,
+        target,
+        differences,
+    }
+}
+
+/// Renders a checksum as 16 hex digits.
+fn hex(checksum: u64) -> String {
+    format!("{checksum:016x}")
+}
+
+/// 64-bit FNV-1a, which unlike the standard hasher is stable across
+/// releases, so checksums can be compared between runs.
+struct Fnv(u64);
+
+impl Fnv {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    /// Writes a value followed by a separator; nulls get their own marker
+    /// so they differ from the string `NULL`.
+    fn write_value(&mut self, value: &DataValue) {
+        match value {
+            DataValue::Null => self.write(&[0]),
+            value => self.write(value_key(value).as_bytes()),
+        }
+        self.write(&[0x1f]);
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DataRow;
+    use contracts_core::{ContractBuilder, DataFormat, FieldBuilder};
+    use pretty_assertions::assert_eq;
+
+    fn contract() -> Contract {
+        ContractBuilder::new("orders", "team")
+            .location("orders")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("id", "int64").build())
+            .field(FieldBuilder::new("note", "string").build())
+            .build()
+    }
+
+    fn dataset(rows: &[(DataValue, DataValue)]) -> DataSet {
+        DataSet::from_rows(
+            rows.iter()
+                .enumerate()
+                .map(|(i, (id, note))| {
+                    let mut row = DataRow::new();
+                    row.insert("id".to_string(), id.clone());
+                    row.insert("note".to_string(), note.clone());
+                    // Outside the contract, so never compared
+                    row.insert("position".to_string(), DataValue::Int(i as i64));
+                    row
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_equal_values_of_other_types_match() {
+        let source = dataset(&[
+            (DataValue::Int(1), DataValue::String("a".to_string())),
+            (DataValue::Int(2), DataValue::Null),
+        ]);
+        let target = dataset(&[
+            (DataValue::Float(2.0), DataValue::Null),
+            (DataValue::UInt(1), DataValue::String("a".to_string())),
+        ]);
+
+        let reconciliation = reconcile(&contract(), &source, &target);
+        assert!(reconciliation.matches(), "{:?}", reconciliation.differences);
+        assert_eq!(reconciliation.source.rows, 2);
+    }
+
+    #[test]
+    fn test_swapped_values_change_only_the_table_checksum() {
+        let source = dataset(&[
+            (DataValue::Int(1), DataValue::String("a".to_string())),
+            (DataValue::Int(2), DataValue::String("b".to_string())),
+        ]);
+        let target = dataset(&[
+            (DataValue::Int(1), DataValue::String("b".to_string())),
+            (DataValue::Int(2), DataValue::String("a".to_string())),
+        ]);
+
+        let differences = reconcile(&contract(), &source, &target).differences;
+        let metrics: Vec<_> = differences.iter().map(|d| d.metric).collect();
+        assert_eq!(metrics, vec!["checksum"]);
+        assert_eq!(differences[0].column, None);
+    }
+
+    #[test]
+    fn test_null_differs_from_null_string_and_missing_field_is_null() {
+        let source = dataset(&[(DataValue::Int(1), DataValue::Null)]);
+        let target = dataset(&[(DataValue::Int(1), DataValue::String("NULL".to_string()))]);
+
+        let differences = reconcile(&contract(), &source, &target).differences;
+        let metrics: Vec<_> = differences
+            .iter()
+            .map(|d| (d.column.as_deref(), d.metric))
+            .collect();
+        assert_eq!(
+            metrics,
+            vec![
+                (None, "checksum"),
+                (Some("note"), "checksum"),
+                (Some("note"), "null_count"),
+                (Some("note"), "distinct_count"),
+                (Some("note"), "min"),
+                (Some("note"), "max"),
+            ]
+        );
+
+        let mut row = DataRow::new();
+        row.insert("id".to_string(), DataValue::Int(1));
+        let missing = DataSet::from_rows(vec![row]);
+        assert!(reconcile(&contract(), &source, &missing).matches());
+    }
+}
@@ -0,0 +1,139 @@
+//! Referential integrity across datasets.
+//!
+//! A [`ReferentialCheck`] asks that the values of a field exist in a field
+//! of another dataset, as a foreign key would. A validation run sees one
+//! dataset only, so the caller reads both sides and [`check_references`]
+//! compares them in memory.
+
+use crate::stats::value_key;
+use crate::{DataSet, DataValue, ValidationError};
+use contracts_core::{ReferentialCheck, Severity, Violation};
+use std::collections::HashSet;
+
+/// Checks that the non-null values of `check.field` in `data` are found in
+/// the referenced field of `referenced`.
+///
+/// Fails when the share of values found is below the check's threshold.
+/// Returns the violation under the check's severity, or the strict/non-strict
+/// default.
+///
+/// # Example
+///
+/// ```rust
+/// use contracts_core::ReferentialCheck;
+/// use contracts_validator::{DataSet, DataValue, check_references};
+/// use std::collections::HashMap;
+///
+/// let rows = |field: &str, ids: &[i64]| {
+///     let rows = ids
+///         .iter()
+///         .map(|id| HashMap::from([(field.to_string(), DataValue::Int(*id))]))
+///         .collect();
+///     DataSet::from_rows(rows)
+/// };
+/// let check = ReferentialCheck {
+///     field: "customer_id".to_string(),
+///     references: "customers.id".to_string(),
+///     threshold: None,
+///     severity: None,
+/// };
+///
+/// let customers = rows("id", &[1, 2]);
+/// assert!(check_references(&check, &rows("customer_id", &[1, 2, 1]), &customers, true).is_none());
+/// let orphan = check_references(&check, &rows("customer_id", &[1, 3]), &customers, true).unwrap();
+/// assert_eq!(orphan.code.code, "DCE0310");
+/// ```
+pub fn check_references(
+    check: &ReferentialCheck,
+    data: &DataSet,
+    referenced: &DataSet,
+    strict: bool,
+) -> Option<Violation> {
+    let referenced_field = check.target().map_or("", |(_, field)| field);
+    let known: HashSet<String> = referenced
+        .column(referenced_field)
+        .filter(|value| !matches!(value, DataValue::Null))
+        .map(value_key)
+        .collect();
+
+    let (mut total, mut missing) = (0usize, 0usize);
+    let mut first = None;
+    for (row, value) in data.column(&check.field).enumerate() {
+        if matches!(value, DataValue::Null) {
+            continue;
+        }
+        total += 1;
+        let key = value_key(value);
+        if !known.contains(&key) {
+            missing += 1;
+            first.get_or_insert((key, row));
+        }
+    }
+
+    let found = (total - missing) as f64 / total.max(1) as f64;
+    let (key, row) = first.filter(|_| found < check.threshold())?;
+    let error = ValidationError::ReferentialIntegrity {
+        field: check.field.clone(),
+        message: format!(
+            "{} of {} value(s) not found in {} (first: '{}' at row {})",
+            missing, total, check.references, key, row
+        ),
+    };
+    Some(
+        error
+            .to_violation()
+            .with_severity(check.severity.unwrap_or(Severity::default_for(strict))),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn ids(field: &str, values: Vec<DataValue>) -> DataSet {
+        DataSet::from_rows(
+            values
+                .into_iter()
+                .map(|value| HashMap::from([(field.to_string(), value)]))
+                .collect(),
+        )
+    }
+
+    fn check(threshold: Option<f64>) -> ReferentialCheck {
+        ReferentialCheck {
+            field: "customer_id".to_string(),
+            references: "customers.id".to_string(),
+            threshold,
+            severity: None,
+        }
+    }
+
+    #[test]
+    fn test_missing_references_below_threshold() {
+        let customers = ids("id", (1..=3).map(DataValue::Int).collect());
+        let orders = ids(
+            "customer_id",
+            vec![
+                DataValue::Int(1),
+                DataValue::Null,
+                DataValue::Int(7),
+                DataValue::Int(2),
+                DataValue::Int(3),
+            ],
+        );
+
+        let violation = check_references(&check(None), &orders, &customers, false).unwrap();
+        assert_eq!(violation.field.as_deref(), Some("customer_id"));
+        assert_eq!(violation.severity, Severity::Warning);
+        assert_eq!(
+            violation.message,
+            "Referential integrity violation for field 'customer_id': 1 of 4 value(s) not found \
+             in customers.id (first: '7' at row 2)"
+        );
+
+        // Three of the four non-null values are found
+        assert!(check_references(&check(Some(0.75)), &orders, &customers, false).is_none());
+        assert!(check_references(&check(Some(0.8)), &orders, &customers, false).is_some());
+    }
+}
@@ -0,0 +1,1004 @@
+//! Row selection by predicate.
+//!
+//! A row filter on the validation context, such as `status = 'failed'`,
+//! restricts a run to the rows it matches. SQL sources register the data
+//! through a view built from [`row_predicate`], so the filter is pushed down
+//! with the time window; row-based validation evaluates it on every row.
+//!
+//! Filters are SQL predicates over contract fields of primitive types, made
+//! of comparisons, `AND`, `OR`, `NOT`, `IS [NOT] NULL`, `[NOT] IN (...)`,
+//! `[NOT] BETWEEN` and `[NOT] LIKE` (with `%` and `_` wildcards), and of
+//! number, string, boolean, `NULL`, `DATE '...'` and `TIMESTAMP '...'`
+//! literals. Both paths accept the same predicates: the SQL one receives
+//! the parsed filter written back with quoted column names.
+//!
+//! The `when` and `require` predicates of conditional constraints are
+//! written in the same language and parsed into a [`Condition`].
+//!
+//! Custom constraints, such as `value > 0 && value < len(other_field)`, are
+//! parsed into an [`Expression`]. They extend the language with `value` for
+//! the constrained field, arithmetic (`+`, `-`, `*`, `/`, `%`), `len(...)`
+//! for the length of a string, and the `&&`, `||`, `!` and `==` spellings
+//! of `AND`, `OR`, `NOT` and `=`. Row checks are written in the same
+//! extended language, without `value`, and parsed into a [`RowRule`].
+//!
+//! Expressions are sandboxed: they are evaluated over the values of one row
+//! and can only read its fields, with no functions besides `len`, no side
+//! effects and a nesting depth bounded by the parser.
+
+use crate::custom::parse_timestamp;
+use crate::{DataRow, DataSet, DataValue, ValidationError, time_window_predicate};
+use chrono::{DateTime, NaiveTime, SecondsFormat, Utc};
+use contracts_core::{Contract, DataType, Field, PrimitiveType, RowCheck, ValidationContext};
+use datafusion::sql::sqlparser::ast::{self, BinaryOperator, Expr, UnaryOperator, Value};
+use datafusion::sql::sqlparser::dialect::GenericDialect;
+use datafusion::sql::sqlparser::parser::Parser;
+use datafusion::sql::sqlparser::tokenizer::{Token, Tokenizer};
+use std::cmp::Ordering;
+use std::fmt;
+
+/// Returns the SQL predicate selecting the rows of the context's row filter
+/// and time window, if any.
+///
+/// # Errors
+///
+/// Fails when the row filter is not a supported predicate over the contract
+/// fields, or the time window has no column (see [`time_window_predicate`]).
+///
+/// # Example
+///
+/// ```rust
+/// use contracts_core::{ContractBuilder, DataFormat, FieldBuilder, ValidationContext};
+/// use contracts_validator::row_predicate;
+///
+/// let contract = ContractBuilder::new("orders", "team")
+///     .location("orders.parquet")
+///     .format(DataFormat::Parquet)
+///     .field(FieldBuilder::new("status", "string").build())
+///     .build();
+/// let context = ValidationContext::new().with_row_filter("status = 'failed'");
+///
+/// assert_eq!(
+///     row_predicate(&contract, &context).unwrap().as_deref(),
+///     Some("(\"status\" = 'failed')"),
+/// );
+/// ```
+pub fn row_predicate(
+    contract: &Contract,
+    context: &ValidationContext,
+) -> Result<Option<String>, ValidationError> {
+    let filter = context
+        .row_filter
+        .as_deref()
+        .map(|sql| {
+            Node::parse(sql, &Scope::filter(contract))
+                .map_err(|reason| ValidationError::invalid_row_filter(sql, reason))
+        })
+        .transpose()?;
+    let window = time_window_predicate(contract, context)?;
+    Ok(match (filter, window) {
+        (Some(filter), Some(window)) => Some(format!("{filter} AND {window}")),
+        (Some(filter), None) => Some(filter.to_string()),
+        (None, window) => window,
+    })
+}
+
+/// Keeps the rows of `dataset` matching the context's row filter.
+///
+/// Rows for which the filter is null, such as `amount > 10` on a null
+/// amount, are dropped, as in a SQL `WHERE` clause.
+pub(crate) fn filter_rows(
+    dataset: &DataSet,
+    contract: &Contract,
+    context: &ValidationContext,
+) -> Result<Option<DataSet>, ValidationError> {
+    let Some(sql) = context.row_filter.as_deref() else {
+        return Ok(None);
+    };
+    let filter = Node::parse(sql, &Scope::filter(contract))
+        .map_err(|reason| ValidationError::invalid_row_filter(sql, reason))?;
+
+    let rows = dataset
+        .rows()
+        .filter(|row| filter.eval(row).truth() == Some(true))
+        .cloned()
+        .collect();
+    Ok(Some(DataSet::from_rows(rows)))
+}
+
+/// A parsed conditional constraint: rows matching `when` must match
+/// `require`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Condition {
+    when: Node,
+    require: Node,
+}
+
+impl Condition {
+    /// Parses the predicates of a conditional constraint on `field`.
+    pub(crate) fn parse(
+        field: &Field,
+        when: &str,
+        require: &str,
+        contract: &Contract,
+    ) -> Result<Self, ValidationError> {
+        let parse = |name: &str, sql: &str| {
+            Node::parse(sql, &Scope::filter(contract)).map_err(|reason| {
+                ValidationError::invalid_condition(&field.name, format!("{name} '{sql}': {reason}"))
+            })
+        };
+        Ok(Self {
+            when: parse("when", when)?,
+            require: parse("require", require)?,
+        })
+    }
+
+    /// Returns whether `row` matches `when` but not `require`.
+    ///
+    /// As in a SQL `CHECK`, a `require` that is null passes, and so does a
+    /// row for which `when` is null.
+    pub(crate) fn is_violated_by(&self, row: &DataRow) -> bool {
+        self.when.eval(row).truth() == Some(true) && self.require.eval(row).truth() == Some(false)
+    }
+
+    /// Returns the SQL predicate selecting the rows that violate the
+    /// condition.
+    pub(crate) fn violation_predicate(&self) -> String {
+        format!("{} AND (NOT {})", self.when, self.require)
+    }
+}
+
+/// A parsed custom constraint: an expression every non-null value of the
+/// field must satisfy.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Expression {
+    field: String,
+    node: Node,
+}
+
+impl Expression {
+    /// Parses the definition of a custom constraint on `field`.
+    pub(crate) fn parse(
+        field: &Field,
+        definition: &str,
+        contract: &Contract,
+    ) -> Result<Self, ValidationError> {
+        let node =
+            Node::parse(definition, &Scope::expression(contract, field)).map_err(|reason| {
+                ValidationError::invalid_expression(
+                    &field.name,
+                    format!("custom '{definition}': {reason}"),
+                )
+            })?;
+        Ok(Self {
+            field: field.name.clone(),
+            node,
+        })
+    }
+
+    /// Returns whether `row` has a value for the field that the expression
+    /// does not hold for.
+    ///
+    /// Null values pass. For a non-null value, an expression that cannot be
+    /// evaluated to true, such as a division by zero or a comparison of
+    /// text with a number, is a violation.
+    pub(crate) fn is_violated_by(&self, row: &DataRow) -> bool {
+        row.get(&self.field).is_some_and(|value| !value.is_null())
+            && self.node.eval(row).truth() != Some(true)
+    }
+
+    /// Returns the SQL predicate selecting the rows that violate the
+    /// expression, counting a null expression as false.
+    pub(crate) fn violation_predicate(&self) -> String {
+        format!(
+            "{} AND (NOT COALESCE({}, FALSE))",
+            Node::IsNull {
+                expr: Box::new(Node::Column(self.field.clone())),
+                negated: true,
+            },
+            self.node
+        )
+    }
+}
+
+/// A parsed row check: an expression every row must satisfy.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct RowRule {
+    node: Node,
+}
+
+impl RowRule {
+    /// Parses the expression of a row check.
+    pub(crate) fn parse(check: &RowCheck, contract: &Contract) -> Result<Self, ValidationError> {
+        let node = Node::parse(&check.expression, &Scope::row(contract)).map_err(|reason| {
+            ValidationError::invalid_row_check(
+                &check.name,
+                format!("'{}': {reason}", check.expression),
+            )
+        })?;
+        Ok(Self { node })
+    }
+
+    /// Returns whether the expression is false for `row`; a null expression
+    /// passes.
+    pub(crate) fn is_violated_by(&self, row: &DataRow) -> bool {
+        self.node.eval(row).truth() == Some(false)
+    }
+
+    /// Returns the SQL predicate selecting the rows that violate the
+    /// expression.
+    pub(crate) fn violation_predicate(&self) -> String {
+        format!("(NOT {})", self.node)
+    }
+}
+
+/// What a predicate may refer to: the contract fields and, in a custom
+/// constraint, the constrained field as `value`. Custom constraints and row
+/// checks also accept the extended expression syntax.
+struct Scope<'a> {
+    contract: &'a Contract,
+    value: Option<&'a str>,
+    extended: bool,
+}
+
+impl<'a> Scope<'a> {
+    fn filter(contract: &'a Contract) -> Self {
+        Self {
+            contract,
+            value: None,
+            extended: false,
+        }
+    }
+
+    fn expression(contract: &'a Contract, field: &'a Field) -> Self {
+        Self {
+            contract,
+            value: Some(&field.name),
+            extended: true,
+        }
+    }
+
+    fn row(contract: &'a Contract) -> Self {
+        Self {
+            contract,
+            value: None,
+            extended: true,
+        }
+    }
+}
+
+/// A parsed row filter.
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Column(String),
+    Null,
+    Bool(bool),
+    /// A number and its SQL text
+    Number(f64, String),
+    Text(String),
+    Timestamp(DateTime<Utc>),
+    Compare(Box<Node>, BinaryOperator, Box<Node>),
+    Arithmetic(Box<Node>, BinaryOperator, Box<Node>),
+    /// Number of characters of a string
+    Length(Box<Node>),
+    And(Box<Node>, Box<Node>),
+    Or(Box<Node>, Box<Node>),
+    Not(Box<Node>),
+    IsNull {
+        expr: Box<Node>,
+        negated: bool,
+    },
+    InList {
+        expr: Box<Node>,
+        list: Vec<Node>,
+        negated: bool,
+    },
+    Between {
+        expr: Box<Node>,
+        low: Box<Node>,
+        high: Box<Node>,
+        negated: bool,
+    },
+    Like {
+        expr: Box<Node>,
+        pattern: String,
+        negated: bool,
+    },
+}
+
+/// A value a filter evaluates to.
+#[derive(Debug, Clone, PartialEq)]
+enum Scalar {
+    Null,
+    Bool(bool),
+    Number(f64),
+    Text(String),
+    Time(DateTime<Utc>),
+}
+
+impl Node {
+    /// Parses `sql` as a predicate over the fields in `scope`, or returns
+    /// why it is not one.
+    fn parse(sql: &str, scope: &Scope) -> Result<Self, String> {
+        let dialect = GenericDialect {};
+        let mut tokens = Tokenizer::new(&dialect, sql)
+            .tokenize()
+            .map_err(|e| e.to_string())?;
+        if scope.extended {
+            // sqlparser reads `&&` and `||` as array overlap and string
+            // concatenation, so the C-style spellings become keywords
+            for token in &mut tokens {
+                *token = match token {
+                    Token::Overlap => Token::make_keyword("AND"),
+                    Token::StringConcat => Token::make_keyword("OR"),
+                    Token::ExclamationMark => Token::make_keyword("NOT"),
+                    Token::DoubleEq => Token::Eq,
+                    _ => continue,
+                };
+            }
+        }
+        let mut parser = Parser::new(&dialect).with_tokens(tokens);
+        let expr = parser.parse_expr().map_err(|e| e.to_string())?;
+        let next = parser.peek_token();
+        if next.token != Token::EOF {
+            return Err(format!("unexpected '{}'", next.token));
+        }
+
+        let node = Self::from_expr(expr, scope)?;
+        if !node.is_predicate(scope.contract) {
+            return Err("not a true/false condition".to_string());
+        }
+        Ok(node)
+    }
+
+    fn from_expr(expr: Expr, scope: &Scope) -> Result<Self, String> {
+        let node = |expr: Expr| Self::from_expr(expr, scope).map(Box::new);
+        let predicate = |expr: Expr| {
+            let text = expr.to_string();
+            let node = Self::from_expr(expr, scope)?;
+            if node.is_predicate(scope.contract) {
+                Ok(Box::new(node))
+            } else {
+                Err(format!("'{text}' is not a true/false condition"))
+            }
+        };
+
+        Ok(match expr {
+            Expr::Identifier(ident) => Self::column(&ident, scope)?,
+            Expr::Value(value) => Self::literal(value.value)?,
+            Expr::TypedString(typed) => {
+                let text = typed.value.clone().into_string();
+                match (&typed.data_type, text) {
+                    (ast::DataType::Date | ast::DataType::Timestamp(..), Some(text)) => {
+                        let timestamp = parse_timestamp(&text)
+                            .map_err(|_| format!("invalid {} '{}'", typed.data_type, text))?;
+                        Self::Timestamp(timestamp)
+                    }
+                    _ => return Err(format!("unsupported literal {}", Expr::TypedString(typed))),
+                }
+            }
+            Expr::Nested(expr) => Self::from_expr(*expr, scope)?,
+            Expr::UnaryOp {
+                op: UnaryOperator::Not,
+                expr,
+            } => Self::Not(predicate(*expr)?),
+            Expr::UnaryOp {
+                op: UnaryOperator::Minus,
+                expr,
+            } => match Self::from_expr(*expr, scope)? {
+                Self::Number(n, text) => Self::Number(-n, format!("-{text}")),
+                other => return Err(format!("cannot negate {other}")),
+            },
+            Expr::BinaryOp { left, op, right } => match op {
+                BinaryOperator::And => Self::And(predicate(*left)?, predicate(*right)?),
+                BinaryOperator::Or => Self::Or(predicate(*left)?, predicate(*right)?),
+                BinaryOperator::Eq
+                | BinaryOperator::NotEq
+                | BinaryOperator::Lt
+                | BinaryOperator::LtEq
+                | BinaryOperator::Gt
+                | BinaryOperator::GtEq => Self::Compare(node(*left)?, op, node(*right)?),
+                BinaryOperator::Plus
+                | BinaryOperator::Minus
+                | BinaryOperator::Multiply
+                | BinaryOperator::Divide
+                | BinaryOperator::Modulo
+                    if scope.extended =>
+                {
+                    Self::Arithmetic(node(*left)?, op, node(*right)?)
+                }
+                other => return Err(format!("unsupported operator {other}")),
+            },
+            Expr::IsNull(expr) => Self::IsNull {
+                expr: node(*expr)?,
+                negated: false,
+            },
+            Expr::IsNotNull(expr) => Self::IsNull {
+                expr: node(*expr)?,
+                negated: true,
+            },
+            Expr::InList {
+                expr,
+                list,
+                negated,
+            } => Self::InList {
+                expr: node(*expr)?,
+                list: list
+                    .into_iter()
+                    .map(|item| Self::from_expr(item, scope))
+                    .collect::<Result<_, _>>()?,
+                negated,
+            },
+            Expr::Between {
+                expr,
+                negated,
+                low,
+                high,
+            } => Self::Between {
+                expr: node(*expr)?,
+                low: node(*low)?,
+                high: node(*high)?,
+                negated,
+            },
+            Expr::Like {
+                negated,
+                any: false,
+                expr,
+                pattern,
+                escape_char: None,
+            } => match Self::from_expr(*pattern, scope)? {
+                Self::Text(pattern) => Self::Like {
+                    expr: node(*expr)?,
+                    pattern,
+                    negated,
+                },
+                other => return Err(format!("LIKE pattern {other} is not a string")),
+            },
+            Expr::Function(function) if scope.extended => {
+                let name = function.name.to_string().to_lowercase();
+                let ast::FunctionArguments::List(list) = &function.args else {
+                    return Err(format!("unsupported function '{function}'"));
+                };
+                match (name.as_str(), list.args.as_slice()) {
+                    (
+                        "len" | "length" | "char_length",
+                        [ast::FunctionArg::Unnamed(ast::FunctionArgExpr::Expr(arg))],
+                    ) => Self::Length(node(arg.clone())?),
+                    _ => return Err(format!("unsupported function '{function}'")),
+                }
+            }
+            other => return Err(format!("unsupported expression '{other}'")),
+        })
+    }
+
+    /// Resolves an identifier to a contract field: the exact name, then a
+    /// case-insensitive match for unquoted names. In a custom constraint,
+    /// an unquoted `value` is the constrained field.
+    fn column(ident: &ast::Ident, scope: &Scope) -> Result<Self, String> {
+        if let Some(value) = scope.value
+            && ident.quote_style.is_none()
+            && ident.value.eq_ignore_ascii_case("value")
+        {
+            return Ok(Self::Column(value.to_string()));
+        }
+        let contract = scope.contract;
+        let field = contract
+            .schema
+            .fields
+            .iter()
+            .find(|field| field.name == ident.value)
+            .or_else(|| {
+                ident.quote_style.is_none().then(|| {
+                    contract
+                        .schema
+                        .fields
+                        .iter()
+                        .find(|field| field.name.eq_ignore_ascii_case(&ident.value))
+                })?
+            })
+            .ok_or_else(|| format!("unknown field '{}'", ident.value))?;
+        if !matches!(field.field_type, DataType::Primitive(_)) {
+            return Err(format!(
+                "field '{}' is a {} and cannot be filtered on",
+                field.name, field.field_type
+            ));
+        }
+        Ok(Self::Column(field.name.clone()))
+    }
+
+    fn literal(value: Value) -> Result<Self, String> {
+        Ok(match value {
+            Value::Null => Self::Null,
+            Value::Boolean(b) => Self::Bool(b),
+            Value::Number(text, _) => {
+                let n = text
+                    .parse()
+                    .map_err(|_| format!("invalid number '{text}'"))?;
+                Self::Number(n, text)
+            }
+            Value::SingleQuotedString(text) => Self::Text(text),
+            other => return Err(format!("unsupported literal {other}")),
+        })
+    }
+
+    /// Returns whether the node evaluates to true, false or null.
+    fn is_predicate(&self, contract: &Contract) -> bool {
+        match self {
+            Self::Column(name) => contract.schema.fields.iter().any(|field| {
+                field.name == *name
+                    && field.field_type == DataType::Primitive(PrimitiveType::Boolean)
+            }),
+            Self::Bool(_) | Self::Null => true,
+            Self::Number(..)
+            | Self::Text(_)
+            | Self::Timestamp(_)
+            | Self::Arithmetic(..)
+            | Self::Length(_) => false,
+            _ => true,
+        }
+    }
+
+    fn eval(&self, row: &DataRow) -> Scalar {
+        match self {
+            Self::Column(name) => row.get(name).map_or(Scalar::Null, Scalar::from_value),
+            Self::Null => Scalar::Null,
+            Self::Bool(b) => Scalar::Bool(*b),
+            Self::Number(n, _) => Scalar::Number(*n),
+            Self::Text(text) => Scalar::Text(text.clone()),
+            Self::Timestamp(timestamp) => Scalar::Time(*timestamp),
+            Self::Compare(left, op, right) => {
+                let ordering = left.eval(row).compare(&right.eval(row));
+                Scalar::from_truth(ordering.map(|ordering| match op {
+                    BinaryOperator::Eq => ordering == Ordering::Equal,
+                    BinaryOperator::NotEq => ordering != Ordering::Equal,
+                    BinaryOperator::Lt => ordering == Ordering::Less,
+                    BinaryOperator::LtEq => ordering != Ordering::Greater,
+                    BinaryOperator::Gt => ordering == Ordering::Greater,
+                    _ => ordering != Ordering::Less,
+                }))
+            }
+            Self::Arithmetic(left, op, right) => {
+                match (left.eval(row).number(), right.eval(row).number()) {
+                    (Some(a), Some(b)) => {
+                        let result = match op {
+                            BinaryOperator::Plus => a + b,
+                            BinaryOperator::Minus => a - b,
+                            BinaryOperator::Multiply => a * b,
+                            BinaryOperator::Divide => a / b,
+                            _ => a % b,
+                        };
+                        // Division by zero is null, as in SQL
+                        if result.is_finite() {
+                            Scalar::Number(result)
+                        } else {
+                            Scalar::Null
+                        }
+                    }
+                    _ => Scalar::Null,
+                }
+            }
+            Self::Length(expr) => match expr.eval(row) {
+                Scalar::Text(text) => Scalar::Number(text.chars().count() as f64),
+                _ => Scalar::Null,
+            },
+            Self::And(left, right) => {
+                Scalar::from_truth(match (left.eval(row).truth(), right.eval(row).truth()) {
+                    (Some(false), _) | (_, Some(false)) => Some(false),
+                    (Some(true), Some(true)) => Some(true),
+                    _ => None,
+                })
+            }
+            Self::Or(left, right) => {
+                Scalar::from_truth(match (left.eval(row).truth(), right.eval(row).truth()) {
+                    (Some(true), _) | (_, Some(true)) => Some(true),
+                    (Some(false), Some(false)) => Some(false),
+                    _ => None,
+                })
+            }
+            Self::Not(expr) => Scalar::from_truth(expr.eval(row).truth().map(|b| !b)),
+            Self::IsNull { expr, negated } => {
+                Scalar::Bool((expr.eval(row) == Scalar::Null) != *negated)
+            }
+            Self::InList {
+                expr,
+                list,
+                negated,
+            } => {
+                let value = expr.eval(row);
+                let mut found = Some(false);
+                for item in list {
+                    match value.compare(&item.eval(row)) {
+                        Some(Ordering::Equal) => {
+                            found = Some(true);
+                            break;
+                        }
+                        Some(_) => {}
+                        None => found = None,
+                    }
+                }
+                Scalar::from_truth(found.map(|found| found != *negated))
+            }
+            Self::Between {
+                expr,
+                low,
+                high,
+                negated,
+            } => {
+                let value = expr.eval(row);
+                let above = value.compare(&low.eval(row)).map(Ordering::is_ge);
+                let below = value.compare(&high.eval(row)).map(Ordering::is_le);
+                let inside = match (above, below) {
+                    (Some(false), _) | (_, Some(false)) => Some(false),
+                    (Some(true), Some(true)) => Some(true),
+                    _ => None,
+                };
+                Scalar::from_truth(inside.map(|inside| inside != *negated))
+            }
+            Self::Like {
+                expr,
+                pattern,
+                negated,
+            } => Scalar::from_truth(match expr.eval(row) {
+                Scalar::Text(text) => Some(like(&text, pattern) != *negated),
+                _ => None,
+            }),
+        }
+    }
+}
+
+/// Writes the filter as SQL, with quoted column names and every operation
+/// in parentheses.
+impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let not = |negated: bool| if negated { "NOT " } else { "" };
+        match self {
+            Self::Column(name) => write!(f, "\"{}\"", name.replace('"', "\"\"")),
+            Self::Null => write!(f, "NULL"),
+            Self::Bool(b) => write!(f, "{}", if *b { "TRUE" } else { "FALSE" }),
+            Self::Number(_, text) => write!(f, "{text}"),
+            Self::Text(text) => write!(f, "'{}'", text.replace('\'', "''")),
+            Self::Timestamp(timestamp) => write!(
+                f,
+                "TIMESTAMP '{}'",
+                timestamp.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+            ),
+            Self::Compare(left, op, right) | Self::Arithmetic(left, op, right) => {
+                write!(f, "({left} {op} {right})")
+            }
+            Self::Length(expr) => write!(f, "character_length({expr})"),
+            Self::And(left, right) => write!(f, "({left} AND {right})"),
+            Self::Or(left, right) => write!(f, "({left} OR {right})"),
+            Self::Not(expr) => write!(f, "(NOT {expr})"),
+            Self::IsNull { expr, negated } => write!(f, "({expr} IS {}NULL)", not(*negated)),
+            Self::InList {
+                expr,
+                list,
+                negated,
+            } => {
+                let list = list
+                    .iter()
+                    .map(Node::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(f, "({expr} {}IN ({list}))", not(*negated))
+            }
+            Self::Between {
+                expr,
+                low,
+                high,
+                negated,
+            } => write!(f, "({expr} {}BETWEEN {low} AND {high})", not(*negated)),
+            Self::Like {
+                expr,
+                pattern,
+                negated,
+            } => write!(
+                f,
+                "({expr} {}LIKE '{}')",
+                not(*negated),
+                pattern.replace('\'', "''")
+            ),
+        }
+    }
+}
+
+impl Scalar {
+    fn from_value(value: &DataValue) -> Self {
+        match value {
+            DataValue::String(s) => Self::Text(s.clone()),
+            DataValue::Bool(b) => Self::Bool(*b),
+            DataValue::Timestamp(s) => {
+                parse_timestamp(s).map_or_else(|_| Self::Text(s.clone()), Self::Time)
+            }
+            DataValue::Date(date) => Self::Time(date.and_time(NaiveTime::MIN).and_utc()),
+            DataValue::Time(time) => Self::Text(time.to_string()),
+            DataValue::Null | DataValue::Map(_) | DataValue::List(_) => Self::Null,
+            number => number.as_float().map_or(Self::Null, Self::Number),
+        }
+    }
+
+    fn from_truth(truth: Option<bool>) -> Self {
+        truth.map_or(Self::Null, Self::Bool)
+    }
+
+    fn truth(&self) -> Option<bool> {
+        match self {
+            Self::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// Reads the value as a number, parsing strings as in comparisons.
+    fn number(&self) -> Option<f64> {
+        match self {
+            Self::Number(n) => Some(*n),
+            Self::Text(text) => text.trim().parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Orders two values, reading strings as numbers or timestamps when
+    /// compared with one. Nulls and incomparable values have no order.
+    fn compare(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Self::Number(a), Self::Number(b)) => a.partial_cmp(b),
+            (Self::Text(a), Self::Text(b)) => Some(a.cmp(b)),
+            (Self::Bool(a), Self::Bool(b)) => Some(a.cmp(b)),
+            (Self::Time(a), Self::Time(b)) => Some(a.cmp(b)),
+            (Self::Number(a), Self::Text(b)) => a.partial_cmp(&b.trim().parse().ok()?),
+            (Self::Time(a), Self::Text(b)) => Some(a.cmp(&parse_timestamp(b).ok()?)),
+            (Self::Text(_), Self::Number(_) | Self::Time(_)) => {
+                other.compare(self).map(Ordering::reverse)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Matches `text` against a SQL `LIKE` pattern, where `%` matches any
+/// sequence of characters and `_` any single character.
+fn like(text: &str, pattern: &str) -> bool {
+    let text: Vec<char> = text.chars().collect();
+    let pattern: Vec<char> = pattern.chars().collect();
+    let (mut t, mut p) = (0, 0);
+    // Pattern position after the last `%` seen, and the text position it
+    // has matched up to
+    let mut backtrack: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('%') => {
+                backtrack = Some((p + 1, t));
+                p += 1;
+            }
+            Some('_') => {
+                t += 1;
+                p += 1;
+            }
+            Some(c) if *c == text[t] => {
+                t += 1;
+                p += 1;
+            }
+            _ => match backtrack {
+                Some((resume, start)) => {
+                    p = resume;
+                    t = start + 1;
+                    backtrack = Some((resume, start + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '%')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use contracts_core::{ContractBuilder, DataFormat, FieldBuilder};
+    use std::collections::HashMap;
+
+    fn contract() -> Contract {
+        ContractBuilder::new("orders", "team")
+            .location("orders.parquet")
+            .format(DataFormat::Parquet)
+            .field(FieldBuilder::new("status", "string").build())
+            .field(FieldBuilder::new("amount", "decimal").build())
+            .field(FieldBuilder::new("placed_on", "date").build())
+            .field(FieldBuilder::new("paid", "boolean").build())
+            .field(FieldBuilder::new("tags", "list<string>").build())
+            .build()
+    }
+
+    fn order(status: &str, amount: Option<i64>, day: u32, paid: bool) -> DataRow {
+        HashMap::from([
+            ("status".to_string(), DataValue::from(status)),
+            (
+                "amount".to_string(),
+                amount.map_or(DataValue::Null, |a| DataValue::Decimal {
+                    value: a.into(),
+                    scale: 0,
+                }),
+            ),
+            (
+                "placed_on".to_string(),
+                DataValue::Date(NaiveDate::from_ymd_opt(2026, 10, day).unwrap()),
+            ),
+            ("paid".to_string(), DataValue::Bool(paid)),
+        ])
+    }
+
+    fn statuses(filter: &str) -> Vec<String> {
+        let dataset = DataSet::from_rows(vec![
+            order("failed", Some(120), 14, false),
+            order("shipped", Some(40), 15, true),
+            order("failed", None, 16, true),
+            order("refunded", Some(300), 16, false),
+        ]);
+        let context = ValidationContext::new().with_row_filter(filter);
+        filter_rows(&dataset, &contract(), &context)
+            .unwrap()
+            .unwrap()
+            .rows()
+            .map(|row| row["status"].as_string().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_filter_rows() {
+        assert_eq!(statuses("status = 'failed'"), ["failed", "failed"]);
+        assert_eq!(statuses("amount > 100"), ["failed", "refunded"]);
+        assert_eq!(statuses("NOT amount > 100"), ["shipped"]);
+        assert_eq!(statuses("amount IS NULL OR paid"), ["shipped", "failed"]);
+        assert_eq!(
+            statuses("status IN ('shipped', 'refunded') AND amount BETWEEN 0 AND 100"),
+            ["shipped"]
+        );
+        assert_eq!(statuses("status NOT LIKE '%ed'"), Vec::<String>::new());
+        assert_eq!(statuses("status LIKE 'r_f%d'"), ["refunded"]);
+        assert_eq!(
+            statuses("placed_on >= DATE '2026-10-16'"),
+            ["failed", "refunded"]
+        );
+        assert_eq!(statuses("placed_on < '2026-10-15'"), ["failed"]);
+        assert_eq!(statuses("Status = 'shipped'"), ["shipped"]);
+    }
+
+    #[test]
+    fn test_row_predicate_writes_quoted_sql() {
+        let predicate = |filter: &str| {
+            row_predicate(
+                &contract(),
+                &ValidationContext::new().with_row_filter(filter),
+            )
+            .unwrap()
+            .unwrap()
+        };
+
+        assert_eq!(
+            predicate("Status <> 'it''s' AND NOT (amount < -1.5 OR paid)"),
+            "((\"status\" <> 'it''s') AND (NOT ((\"amount\" < -1.5) OR \"paid\")))"
+        );
+        assert_eq!(
+            predicate("placed_on BETWEEN DATE '2026-10-01' AND TIMESTAMP '2026-10-31 12:00:00'"),
+            "(\"placed_on\" BETWEEN TIMESTAMP '2026-10-01T00:00:00Z' AND TIMESTAMP '2026-10-31T12:00:00Z')"
+        );
+        assert!(
+            row_predicate(&contract(), &ValidationContext::new())
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_invalid_filters() {
+        let error = |filter: &str| {
+            row_predicate(
+                &contract(),
+                &ValidationContext::new().with_row_filter(filter),
+            )
+            .unwrap_err()
+            .to_string()
+        };
+
+        assert!(error("customer = 'x'").contains("unknown field 'customer'"));
+        assert!(error("\"Status\" = 'x'").contains("unknown field 'Status'"));
+        assert!(error("tags IS NULL").contains("is a list<string>"));
+        assert!(error("amount + 1 > 2").contains("unsupported operator +"));
+        assert!(error("amount").contains("not a true/false condition"));
+        assert!(error("status = 'failed' extra").contains("unexpected"));
+        assert!(error("upper(status) = 'X'").contains("unsupported expression"));
+        assert!(error("status = ").starts_with("Invalid row filter 'status = ': "));
+    }
+
+    #[test]
+    fn test_condition() {
+        let contract = contract();
+        let field = contract.field("amount").unwrap();
+        let condition = Condition::parse(
+            field,
+            "status = 'refunded'",
+            "amount > 100 AND NOT paid",
+            &contract,
+        )
+        .unwrap();
+
+        assert!(!condition.is_violated_by(&order("refunded", Some(300), 16, false)));
+        assert!(condition.is_violated_by(&order("refunded", Some(40), 16, false)));
+        // Rows outside `when` and null requirements pass
+        assert!(!condition.is_violated_by(&order("shipped", Some(40), 15, true)));
+        assert!(!condition.is_violated_by(&order("refunded", None, 16, false)));
+        assert_eq!(
+            condition.violation_predicate(),
+            "(\"status\" = 'refunded') AND (NOT ((\"amount\" > 100) AND (NOT \"paid\")))"
+        );
+
+        let error = Condition::parse(field, "paid", "closed_at IS NOT NULL", &contract)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            error,
+            "Invalid condition for field 'amount': require 'closed_at IS NOT NULL': unknown field 'closed_at'"
+        );
+    }
+
+    #[test]
+    fn test_expression() {
+        let contract = contract();
+        let field = contract.field("amount").unwrap();
+        let expression = Expression::parse(
+            field,
+            "value > 0 && (value * 2 < len(status) * 100 || !paid)",
+            &contract,
+        )
+        .unwrap();
+
+        assert!(!expression.is_violated_by(&order("refunded", Some(300), 16, false)));
+        assert!(expression.is_violated_by(&order("refunded", Some(600), 16, true)));
+        assert!(expression.is_violated_by(&order("failed", Some(-5), 14, false)));
+        // Null values pass
+        assert!(!expression.is_violated_by(&order("failed", None, 16, true)));
+        assert_eq!(
+            expression.violation_predicate(),
+            "(\"amount\" IS NOT NULL) AND (NOT COALESCE(((\"amount\" > 0) AND (((\"amount\" * 2) < \
+             (character_length(\"status\") * 100)) OR (NOT \"paid\"))), FALSE))"
+        );
+
+        // Values the expression cannot be evaluated on are violations
+        let ratio = Expression::parse(field, "100 / value > 1", &contract).unwrap();
+        assert!(!ratio.is_violated_by(&order("paid", Some(50), 16, true)));
+        assert!(ratio.is_violated_by(&order("paid", Some(0), 16, true)));
+        let mut mistyped = order("paid", Some(50), 16, true);
+        mistyped.insert("amount".to_string(), DataValue::from("abc"));
+        assert!(expression.is_violated_by(&mistyped));
+
+        let error = |definition: &str| {
+            Expression::parse(field, definition, &contract)
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            error("value > len(customer)"),
+            "Invalid expression for field 'amount': custom 'value > len(customer)': \
+             unknown field 'customer'"
+        );
+        assert!(error("value + 1").contains("not a true/false condition"));
+        assert!(error("upper(status) == 'X'").contains("unsupported function"));
+        // Filters keep to plain SQL predicates
+        assert!(
+            Node::parse("value > 0", &Scope::filter(&contract))
+                .unwrap_err()
+                .contains("unknown field 'value'")
+        );
+    }
+
+    #[test]
+    fn test_like() {
+        assert!(like("refunded", "%ed"));
+        assert!(like("refunded", "r%u%d"));
+        assert!(like("", "%"));
+        assert!(!like("refunded", "%x%"));
+        assert!(!like("ab", "a_b"));
+        assert!(like("aXbXc", "a%c"));
+    }
+}
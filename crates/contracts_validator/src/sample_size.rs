@@ -0,0 +1,85 @@
+//! Minimum sample-size guard for statistical checks.
+//!
+//! Completeness, uniqueness and the distribution-based ML checks say little
+//! about a table when only a handful of rows were validated. Checks that set
+//! `min_sample_size` are held back on smaller runs and reported as
+//! inconclusive instead of passing or failing on noise.
+
+use contracts_core::{CheckKind, Contract, Violation, codes};
+
+/// A contract with its under-sampled checks removed.
+pub(crate) struct Withheld {
+    /// Copy of the contract that only keeps checks with enough records
+    pub contract: Contract,
+
+    /// One inconclusive result per check that was held back
+    pub messages: Vec<Violation>,
+}
+
+/// Returns the largest `min_sample_size` declared by any check, if any.
+pub(crate) fn required_sample_size(contract: &Contract) -> Option<usize> {
+    let qc = contract.quality_checks.as_ref()?;
+    let ml = qc.ml_checks.as_ref();
+    [
+        qc.completeness.as_ref().and_then(|c| c.min_sample_size),
+        qc.uniqueness.as_ref().and_then(|c| c.min_sample_size),
+        ml.and_then(|m| m.class_balance.as_ref()?.min_sample_size),
+        ml.and_then(|m| m.feature_drift.as_ref()?.min_sample_size),
+        ml.and_then(|m| m.target_leakage.as_ref()?.min_sample_size),
+        ml.and_then(|m| m.null_rate_by_group.as_ref()?.min_sample_size),
+    ]
+    .into_iter()
+    .flatten()
+    .max()
+}
+
+/// Holds back every check whose `min_sample_size` exceeds `records`.
+///
+/// Returns `None` when all checks have enough records, so the caller can keep
+/// validating the original contract. Empty runs are left to the empty data
+/// policy and never withhold anything.
+pub(crate) fn withhold_undersampled(contract: &Contract, records: usize) -> Option<Withheld> {
+    let required = required_sample_size(contract)?;
+    if records == 0 || records >= required {
+        return None;
+    }
+
+    let mut withheld = Withheld {
+        contract: contract.clone(),
+        messages: Vec::new(),
+    };
+    if let Some(qc) = withheld.contract.quality_checks.as_mut() {
+        let messages = &mut withheld.messages;
+        let mut guard = |name: &str, kind: CheckKind, required: Option<usize>| match required {
+            Some(required) if records < required => {
+                messages.push(Violation::new(
+                    &codes::INSUFFICIENT_SAMPLE,
+                    kind,
+                    format!(
+                        "{name} check is inconclusive: {records} record(s) validated, \
+                         at least {required} required"
+                    ),
+                ));
+                true
+            }
+            _ => false,
+        };
+
+        qc.completeness
+            .take_if(|c| guard("Completeness", CheckKind::Quality, c.min_sample_size));
+        qc.uniqueness
+            .take_if(|c| guard("Uniqueness", CheckKind::Quality, c.min_sample_size));
+        if let Some(ml) = qc.ml_checks.as_mut() {
+            ml.class_balance
+                .take_if(|c| guard("Class balance", CheckKind::Ml, c.min_sample_size));
+            ml.feature_drift
+                .take_if(|c| guard("Feature drift", CheckKind::Ml, c.min_sample_size));
+            ml.target_leakage
+                .take_if(|c| guard("Target leakage", CheckKind::Ml, c.min_sample_size));
+            ml.null_rate_by_group
+                .take_if(|c| guard("Null rate by group", CheckKind::Ml, c.min_sample_size));
+        }
+    }
+
+    Some(withheld)
+}
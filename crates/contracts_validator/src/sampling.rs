@@ -0,0 +1,106 @@
+//! Random and stratified sampling.
+//!
+//! [`DataSet::sample_with`](crate::DataSet::sample_with) and the table
+//! readers draw samples with the helpers here, so that a
+//! [`SamplingStrategy`](contracts_core::SamplingStrategy) seed picks the same
+//! positions whatever the data source.
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand::seq::index;
+
+/// Draws random positions from a seeded generator.
+///
+/// Successive draws continue the same random sequence, so drawing per
+/// stratum in a fixed order is reproducible too.
+///
+/// # Example
+///
+/// ```rust
+/// use contracts_validator::Sampler;
+///
+/// let positions = Sampler::new(42).positions(1000, 10);
+/// assert_eq!(positions.len(), 10);
+/// assert_eq!(positions, Sampler::new(42).positions(1000, 10));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Sampler {
+    rng: StdRng,
+}
+
+impl Sampler {
+    /// Creates a sampler seeded with `seed`.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// Returns `size` distinct positions below `len` in ascending order, or
+    /// every position when `size` is at least `len`.
+    pub fn positions(&mut self, len: usize, size: usize) -> Vec<usize> {
+        if size >= len {
+            return (0..len).collect();
+        }
+        let mut positions = index::sample(&mut self.rng, len, size).into_vec();
+        positions.sort_unstable();
+        positions
+    }
+}
+
+/// Splits `limit` rows across groups in proportion to their row counts.
+///
+/// Uses the largest remainder method, so the quotas add up to `limit` and no
+/// group is asked for more rows than it has.
+pub fn proportional_quotas(row_counts: &[u64], limit: usize) -> Vec<usize> {
+    let total: u128 = row_counts.iter().map(|&rows| u128::from(rows)).sum();
+    if total <= limit as u128 {
+        return row_counts.iter().map(|&rows| rows as usize).collect();
+    }
+
+    let mut quotas = Vec::with_capacity(row_counts.len());
+    let mut remainders = Vec::with_capacity(row_counts.len());
+    for (i, &rows) in row_counts.iter().enumerate() {
+        let share = limit as u128 * u128::from(rows);
+        quotas.push((share / total) as usize);
+        remainders.push((share % total, i));
+    }
+
+    // Hand the rows lost to rounding down to the largest remainders
+    let left = limit - quotas.iter().sum::<usize>();
+    remainders.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+    for (_, i) in remainders.into_iter().take(left) {
+        quotas[i] += 1;
+    }
+    quotas
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_positions_are_distinct_sorted_and_seeded() {
+        let positions = Sampler::new(7).positions(100, 20);
+        assert_eq!(positions.len(), 20);
+        assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(positions.iter().all(|&position| position < 100));
+        assert_eq!(positions, Sampler::new(7).positions(100, 20));
+        assert_ne!(positions, Sampler::new(8).positions(100, 20));
+
+        assert_eq!(Sampler::new(7).positions(3, 10), vec![0, 1, 2]);
+        assert!(Sampler::new(7).positions(0, 10).is_empty());
+    }
+
+    #[test]
+    fn test_proportional_quotas() {
+        assert_eq!(proportional_quotas(&[900, 100], 10), vec![9, 1]);
+        assert_eq!(proportional_quotas(&[50, 30, 20], 7), vec![4, 2, 1]);
+        // Ties go to the group listed first
+        assert_eq!(proportional_quotas(&[1, 1, 1], 2), vec![1, 1, 0]);
+        assert_eq!(proportional_quotas(&[0, 40], 4), vec![0, 4]);
+        // Nothing to split when every row fits
+        assert_eq!(proportional_quotas(&[3, 2], 10), vec![3, 2]);
+        assert_eq!(proportional_quotas(&[3, 2], 0), vec![0, 0]);
+    }
+}
@@ -3,14 +3,17 @@
 //! This module handles validation of data schemas against contract definitions,
 //! including field presence, type checking, and nullability constraints.
 
-use crate::{DataRow, DataSet, DataValue, ValidationError};
-use contracts_core::{Contract, DataType, Field, PrimitiveType};
+use crate::row_filter::{Condition, Expression, RowRule};
+use crate::{DataRow, DataSet, DataValue, ValidationError, default_value, validate_location};
+use chrono::{NaiveDate, NaiveTime};
+use contracts_core::{Contract, DataType, Field, FieldConstraints, PrimitiveType, Version};
 use std::collections::HashSet;
 
 /// Validates the schema of a dataset against a contract.
 ///
 /// Checks that all required fields are present, types match, and nullability
 /// constraints are satisfied.
+#[derive(Clone)]
 pub struct SchemaValidator;
 
 impl SchemaValidator {
@@ -23,18 +26,32 @@ impl SchemaValidator {
     ///
     /// Returns a list of validation errors. An empty list indicates success.
     pub fn validate(&self, contract: &Contract, dataset: &DataSet) -> Vec<ValidationError> {
-        let mut errors = Vec::new();
+        self.validate_from(contract, dataset, 0)
+    }
 
-        // If dataset is empty, only validate schema definition itself
-        if dataset.is_empty() {
-            return errors;
-        }
+    /// Validates rows that start at row `first_row` of a larger dataset, so
+    /// errors name the row in the whole dataset.
+    pub(crate) fn validate_from(
+        &self,
+        contract: &Contract,
+        dataset: &DataSet,
+        first_row: usize,
+    ) -> Vec<ValidationError> {
+        self.validate_slice(contract, dataset.row_slice(), first_row)
+    }
 
-        // Validate each row
-        for (row_idx, row) in dataset.rows().enumerate() {
-            errors.extend(self.validate_row(contract, row, row_idx));
+    /// Validates `rows`, the first of which is row `first_row` of the
+    /// dataset.
+    pub(crate) fn validate_slice(
+        &self,
+        contract: &Contract,
+        rows: &[DataRow],
+        first_row: usize,
+    ) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        for (row_idx, row) in rows.iter().enumerate() {
+            errors.extend(self.validate_row(contract, row, first_row + row_idx));
         }
-
         errors
     }
 
@@ -73,16 +90,15 @@ impl SchemaValidator {
         let value = match value {
             Some(v) => v,
             None => {
-                // Field is missing
-                if !field.nullable {
+                if field.is_required() {
                     return Some(ValidationError::missing_field(&field.name));
                 }
-                return None; // Missing nullable field is OK
+                return None; // Missing optional field is OK
             }
         };
 
         // Check nullability
-        if value.is_null() && !field.nullable {
+        if value.is_null() && !field.allows_null() {
             return Some(ValidationError::null_violation(&field.name, Some(row_idx)));
         }
 
@@ -114,17 +130,31 @@ impl SchemaValidator {
     }
 
     /// Recursively checks whether a value matches an expected DataType.
-    fn type_matches(expected: &DataType, value: &DataValue) -> bool {
+    pub fn type_matches(expected: &DataType, value: &DataValue) -> bool {
         match expected {
             DataType::Primitive(p) => match p {
                 PrimitiveType::String => matches!(value, DataValue::String(_)),
-                PrimitiveType::Int32 | PrimitiveType::Int64 => matches!(value, DataValue::Int(_)),
-                PrimitiveType::Float32 | PrimitiveType::Float64 => {
-                    matches!(value, DataValue::Float(_) | DataValue::Int(_))
-                }
+                PrimitiveType::Int32 | PrimitiveType::Int64 => value.as_int().is_some(),
+                PrimitiveType::Float32 | PrimitiveType::Float64 => matches!(
+                    value,
+                    DataValue::Float(_)
+                        | DataValue::Int(_)
+                        | DataValue::UInt(_)
+                        | DataValue::Decimal { .. }
+                ),
                 PrimitiveType::Boolean => matches!(value, DataValue::Bool(_)),
                 PrimitiveType::Timestamp => matches!(value, DataValue::Timestamp(_)),
-                // Lenient for date, time, decimal, uuid, binary — accept any value
+                PrimitiveType::Date => match value {
+                    DataValue::Date(_) => true,
+                    DataValue::String(s) => NaiveDate::parse_from_str(s, "%Y-%m-%d").is_ok(),
+                    _ => false,
+                },
+                PrimitiveType::Time => match value {
+                    DataValue::Time(_) => true,
+                    DataValue::String(s) => s.parse::<NaiveTime>().is_ok(),
+                    _ => false,
+                },
+                // Lenient for decimal, uuid, binary — accept any value
                 _ => true,
             },
             DataType::List {
@@ -177,9 +207,16 @@ impl SchemaValidator {
         }
     }
 
-    /// Validates that all required fields are present in the schema.
+    /// Validates the schema definition: fields must be present and unique,
+    /// defaults must match their field's type, conditional and custom
+    /// constraints must parse, the location must be well-formed for the
+    /// declared format, and versions must be semantic versions.
     pub fn validate_schema_definition(&self, contract: &Contract) -> Vec<ValidationError> {
-        let mut errors = Vec::new();
+        let mut errors = version_errors(contract);
+
+        if let Err(e) = validate_location(&contract.schema.format, &contract.schema.location) {
+            errors.push(e);
+        }
 
         if contract.schema.fields.is_empty() {
             errors.push(ValidationError::schema("Schema has no fields defined"));
@@ -194,12 +231,82 @@ impl SchemaValidator {
                     field.name
                 )));
             }
+            if let Err(e) = default_value(field) {
+                errors.push(e);
+            }
+            for constraint in field.constraints.iter().flatten() {
+                let parsed = match constraint {
+                    FieldConstraints::Conditional { when, require } => {
+                        Condition::parse(field, when, require, contract).map(drop)
+                    }
+                    FieldConstraints::Custom { definition } => {
+                        Expression::parse(field, definition, contract).map(drop)
+                    }
+                    _ => Ok(()),
+                };
+                if let Err(e) = parsed {
+                    errors.push(e);
+                }
+            }
+        }
+        let row_checks = contract
+            .quality_checks
+            .iter()
+            .flat_map(|qc| qc.row_checks.iter().flatten());
+        for check in row_checks {
+            if let Err(e) = RowRule::parse(check, contract) {
+                errors.push(e);
+            }
         }
 
         errors
     }
 }
 
+/// Checks that the contract version and the versions of its changelog are
+/// semantic versions, with at most one entry per version and none newer than
+/// the contract.
+fn version_errors(contract: &Contract) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+    let current = match contract.semver() {
+        Ok(version) => Some(version),
+        Err(_) => {
+            errors.push(ValidationError::invalid_version(
+                &contract.version,
+                "the contract version is not a semantic version (MAJOR.MINOR.PATCH)",
+            ));
+            None
+        }
+    };
+
+    let mut seen = HashSet::new();
+    for entry in contract.changelog.iter().flatten() {
+        let Ok(version) = Version::parse(&entry.version) else {
+            errors.push(ValidationError::invalid_version(
+                &entry.version,
+                "the changelog version is not a semantic version (MAJOR.MINOR.PATCH)",
+            ));
+            continue;
+        };
+        if current.as_ref().is_some_and(|current| &version > current) {
+            errors.push(ValidationError::invalid_version(
+                &entry.version,
+                format!(
+                    "the changelog entry is newer than the contract version {}",
+                    contract.version
+                ),
+            ));
+        }
+        if !seen.insert(version) {
+            errors.push(ValidationError::invalid_version(
+                &entry.version,
+                "the changelog lists the version more than once",
+            ));
+        }
+    }
+    errors
+}
+
 impl Default for SchemaValidator {
     fn default() -> Self {
         Self::new()
@@ -209,7 +316,10 @@ impl Default for SchemaValidator {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use contracts_core::{ContractBuilder, FieldBuilder};
+    use contracts_core::{
+        ChangelogEntry, Coded, ContractBuilder, DataFormat, FieldBuilder, FieldConstraints,
+        QualityChecksBuilder, RowCheck,
+    };
     use std::collections::HashMap;
 
     fn create_test_contract() -> Contract {
@@ -298,6 +408,38 @@ mod tests {
         assert_eq!(errors.len(), 0);
     }
 
+    #[test]
+    fn test_presence_and_nullability_are_checked_separately() {
+        let contract = ContractBuilder::new("test", "owner")
+            .location("s3://test")
+            .format(DataFormat::Iceberg)
+            // May be null, but must be present
+            .field(FieldBuilder::new("note", "string").required(true).build())
+            // May be absent, but not null when present
+            .field(
+                FieldBuilder::new("code", "string")
+                    .constraint(FieldConstraints::NotNull)
+                    .build(),
+            )
+            .build();
+        let validator = SchemaValidator::new();
+
+        let mut present = HashMap::new();
+        present.insert("note".to_string(), DataValue::Null);
+        let errors = validator.validate(&contract, &DataSet::from_rows(vec![present]));
+        assert!(errors.is_empty(), "Expected no errors, got: {:?}", errors);
+
+        let mut row = HashMap::new();
+        row.insert("code".to_string(), DataValue::Null);
+        let errors = validator.validate(&contract, &DataSet::from_rows(vec![row]));
+        assert_eq!(errors.len(), 2);
+        assert!(matches!(&errors[0], ValidationError::MissingField(f) if f == "note"));
+        assert!(matches!(
+            &errors[1],
+            ValidationError::NullConstraintViolation { field, .. } if field == "code"
+        ));
+    }
+
     #[test]
     fn test_type_mismatch() {
         let contract = create_test_contract();
@@ -316,6 +458,27 @@ mod tests {
         assert!(matches!(errors[0], ValidationError::TypeMismatch { .. }));
     }
 
+    #[test]
+    fn test_date_and_time_types() {
+        let date = DataType::Primitive(PrimitiveType::Date);
+        let time = DataType::Primitive(PrimitiveType::Time);
+        let day = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+        let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+
+        assert!(SchemaValidator::type_matches(&date, &DataValue::Date(day)));
+        assert!(SchemaValidator::type_matches(&date, &"2024-06-01".into()));
+        assert!(!SchemaValidator::type_matches(&date, &"01/06/2024".into()));
+        assert!(!SchemaValidator::type_matches(
+            &date,
+            &DataValue::Time(noon)
+        ));
+
+        assert!(SchemaValidator::type_matches(&time, &DataValue::Time(noon)));
+        assert!(SchemaValidator::type_matches(&time, &"12:00:00.5".into()));
+        assert!(!SchemaValidator::type_matches(&time, &DataValue::Int(12)));
+        assert!(!SchemaValidator::type_matches(&time, &DataValue::Date(day)));
+    }
+
     #[test]
     fn test_multiple_rows() {
         let contract = create_test_contract();
@@ -356,6 +519,144 @@ mod tests {
         assert_eq!(errors.len(), 1);
     }
 
+    #[test]
+    fn test_schema_definition_rejects_invalid_default() {
+        let contract = ContractBuilder::new("test_contract", "test-owner")
+            .location("s3://test/data")
+            .format(DataFormat::Iceberg)
+            .field(
+                FieldBuilder::new("id", "int64")
+                    .default_value("none")
+                    .build(),
+            )
+            .field(
+                FieldBuilder::new("tier", "string")
+                    .default_value("free")
+                    .build(),
+            )
+            .build();
+
+        let errors = SchemaValidator::new().validate_schema_definition(&contract);
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            &errors[0],
+            ValidationError::InvalidDefault { field, .. } if field == "id"
+        ));
+    }
+
+    #[test]
+    fn test_schema_definition_rejects_invalid_condition() {
+        let conditional = |require: &str| {
+            FieldBuilder::new("closed_at", "timestamp")
+                .constraint(FieldConstraints::Conditional {
+                    when: "status = 'closed'".to_string(),
+                    require: require.to_string(),
+                })
+                .build()
+        };
+        let contract = ContractBuilder::new("tickets", "support")
+            .location("s3://test/tickets")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("status", "string").build())
+            .field(conditional("closed_at IS NOT NULL"))
+            .build();
+        let validator = SchemaValidator::new();
+        assert!(validator.validate_schema_definition(&contract).is_empty());
+
+        let mut contract = contract;
+        contract.schema.fields[1] = conditional("closed_at");
+        let errors = validator.validate_schema_definition(&contract);
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            &errors[0],
+            ValidationError::InvalidCondition { field, reason }
+                if field == "closed_at" && reason.ends_with("not a true/false condition")
+        ));
+
+        contract.schema.fields[1] = FieldBuilder::new("closed_at", "timestamp")
+            .constraint(FieldConstraints::Custom {
+                definition: "value > opened_at".to_string(),
+            })
+            .build();
+        let errors = validator.validate_schema_definition(&contract);
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            &errors[0],
+            ValidationError::InvalidExpression { reason, .. }
+                if reason.ends_with("unknown field 'opened_at'")
+        ));
+
+        contract.schema.fields[1] = conditional("closed_at IS NOT NULL");
+        let row_check = |expression: &str| RowCheck {
+            name: "closed_after_open".to_string(),
+            expression: expression.to_string(),
+            severity: None,
+        };
+        contract.quality_checks = Some(
+            QualityChecksBuilder::new()
+                .row_check(row_check("status != 'closed' || len(status) * 2 > 0"))
+                .row_check(row_check("closed_at + 1"))
+                .build(),
+        );
+        let errors = validator.validate_schema_definition(&contract);
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            &errors[0],
+            ValidationError::InvalidRowCheck { name, reason }
+                if name == "closed_after_open" && reason.ends_with("not a true/false condition")
+        ));
+    }
+
+    #[test]
+    fn test_schema_definition_rejects_invalid_versions() {
+        let entry = |version: &str| ChangelogEntry {
+            version: version.to_string(),
+            date: None,
+            changes: Vec::new(),
+        };
+        let mut contract = create_test_contract();
+        contract.version = "1.2.0".to_string();
+        contract.changelog = Some(vec![entry("1.2.0"), entry("1.1.0")]);
+        let validator = SchemaValidator::new();
+        assert!(validator.validate_schema_definition(&contract).is_empty());
+
+        contract.changelog = Some(vec![
+            entry("1.3.0"),
+            entry("1.1"),
+            entry("1.2.0"),
+            entry("1.2.0"),
+        ]);
+        let versions: Vec<String> = validator
+            .validate_schema_definition(&contract)
+            .iter()
+            .map(|e| match e {
+                ValidationError::InvalidVersion { version, .. } => version.clone(),
+                other => panic!("unexpected error: {}", other),
+            })
+            .collect();
+        assert_eq!(versions, vec!["1.3.0", "1.1", "1.2.0"]);
+
+        contract.version = "v1".to_string();
+        contract.changelog = None;
+        let errors = validator.validate_schema_definition(&contract);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].error_code().code, "DCE0014");
+    }
+
+    #[test]
+    fn test_schema_definition_rejects_invalid_location() {
+        let contract = ContractBuilder::new("test", "owner")
+            .location("ftp://legacy/data")
+            .format(contracts_core::DataFormat::Iceberg)
+            .field(FieldBuilder::new("id", "string").build())
+            .build();
+        let validator = SchemaValidator::new();
+
+        let errors = validator.validate_schema_definition(&contract);
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(errors[0], ValidationError::InvalidLocation { .. }));
+    }
+
     #[test]
     fn test_int_to_float_coercion() {
         let contract = ContractBuilder::new("test", "owner")
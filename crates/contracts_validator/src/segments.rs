@@ -0,0 +1,196 @@
+//! Segmented validation.
+//!
+//! With `segment_by` set on the context, the rows that share a value of that
+//! column are validated again on their own and attached to the report as
+//! [`SegmentReport`]s. A segment that fails is flagged on the overall report,
+//! since a problem confined to one group is easily averaged away.
+
+use crate::{DataSet, DataValue, ValidationError, create_data_view};
+use contracts_core::{
+    CheckKind, Contract, SegmentReport, Severity, ValidationReport, Violation, codes,
+};
+use datafusion::arrow::array::{Array, StringArray};
+use datafusion::arrow::compute::cast;
+use datafusion::arrow::datatypes::DataType as ArrowType;
+use datafusion::prelude::SessionContext;
+use std::collections::BTreeMap;
+
+/// Most segments validated in one run; further values are reported as skipped.
+pub(crate) const MAX_SEGMENTS: usize = 100;
+
+/// Label of the segment holding rows without a value.
+const NULL_SEGMENT: &str = "null";
+
+/// Groups of rows to validate, keyed by segment value, and whether values
+/// beyond [`MAX_SEGMENTS`] were left out.
+pub(crate) type Groups<T> = (Vec<(String, T)>, bool);
+
+/// Fails unless `column` is declared in the contract's schema.
+pub(crate) fn check_column(contract: &Contract, column: &str) -> Result<(), ValidationError> {
+    if contract.has_field(column) {
+        Ok(())
+    } else {
+        Err(ValidationError::missing_field(column))
+    }
+}
+
+/// Splits `dataset` into one dataset per value of `column`, nulls first.
+pub(crate) fn split_dataset(dataset: &DataSet, column: &str) -> Groups<DataSet> {
+    let mut groups: BTreeMap<Option<String>, Vec<_>> = BTreeMap::new();
+    for row in dataset.rows() {
+        let value = row.get(column).and_then(label);
+        groups.entry(value).or_default().push(row.clone());
+    }
+
+    let truncated = groups.len() > MAX_SEGMENTS;
+    let groups = groups
+        .into_iter()
+        .take(MAX_SEGMENTS)
+        .map(|(value, rows)| {
+            let value = value.unwrap_or_else(|| NULL_SEGMENT.to_string());
+            (value, DataSet::from_rows(rows))
+        })
+        .collect();
+    (groups, truncated)
+}
+
+/// Builds one session per value of `column` in the `data` table of `ctx`,
+/// each with a `data` view holding only that segment's rows.
+pub(crate) async fn split_context(
+    ctx: &SessionContext,
+    column: &str,
+) -> Result<Groups<SessionContext>, String> {
+    let sql = format!(
+        "SELECT DISTINCT CAST(\"{column}\" AS VARCHAR) AS segment FROM data \
+         ORDER BY segment NULLS FIRST LIMIT {}",
+        MAX_SEGMENTS + 1
+    );
+    let batches = ctx
+        .sql(&sql)
+        .await
+        .map_err(|e| e.to_string())?
+        .collect()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut values = Vec::new();
+    for batch in &batches {
+        let strings = cast(batch.column(0), &ArrowType::Utf8).map_err(|e| e.to_string())?;
+        let strings = strings
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .ok_or("segment values are not strings")?;
+        values.extend(
+            (0..strings.len()).map(|i| (!strings.is_null(i)).then(|| strings.value(i).to_string())),
+        );
+    }
+
+    let truncated = values.len() > MAX_SEGMENTS;
+    let provider = ctx
+        .table_provider("data")
+        .await
+        .map_err(|e| e.to_string())?;
+    let mut groups = Vec::new();
+    for value in values.into_iter().take(MAX_SEGMENTS) {
+        let filter = match &value {
+            Some(value) => format!(
+                "CAST(\"{column}\" AS VARCHAR) = '{}'",
+                value.replace('\'', "''")
+            ),
+            None => format!("\"{column}\" IS NULL"),
+        };
+        let segment = SessionContext::new();
+        segment
+            .register_table("raw_data", provider.clone())
+            .map_err(|e| e.to_string())?;
+        create_data_view(&segment, Some(&filter), None).await?;
+        groups.push((value.unwrap_or_else(|| NULL_SEGMENT.to_string()), segment));
+    }
+    Ok((groups, truncated))
+}
+
+/// Attaches `segments` to `report` and flags the ones that failed.
+///
+/// Failed segments are errors in strict mode and warnings otherwise, like
+/// the quality checks whose failures they usually stem from.
+pub(crate) fn attach(
+    report: &mut ValidationReport,
+    column: &str,
+    segments: Vec<SegmentReport>,
+    truncated: bool,
+    strict: bool,
+) {
+    for segment in &segments {
+        if segment.report.passed {
+            continue;
+        }
+        let violation = Violation::new(
+            &codes::SEGMENT_FAILED,
+            CheckKind::Quality,
+            format!(
+                "Segment {}={} fails with {} error(s) on its {} record(s)",
+                column,
+                segment.value,
+                segment.report.errors.len(),
+                segment.report.stats.records_validated
+            ),
+        )
+        .with_field(column)
+        .with_severity(Severity::default_for(strict));
+        report.add_violation(violation);
+    }
+    if truncated {
+        report.add_warning(
+            Violation::new(
+                &codes::GENERAL,
+                CheckKind::Quality,
+                format!(
+                    "Only the first {} values of '{}' were validated as segments",
+                    MAX_SEGMENTS, column
+                ),
+            )
+            .with_field(column),
+        );
+    }
+    report.segments = segments;
+}
+
+/// Text form of a segment value; `None` for nulls.
+fn label(value: &DataValue) -> Option<String> {
+    Some(match value {
+        DataValue::Null => return None,
+        DataValue::String(s) | DataValue::Timestamp(s) => s.clone(),
+        DataValue::Int(i) => i.to_string(),
+        DataValue::UInt(u) => u.to_string(),
+        DataValue::Decimal { value, scale } => DataValue::decimal_to_string(*value, *scale),
+        DataValue::Float(f) => f.to_string(),
+        DataValue::Bool(b) => b.to_string(),
+        DataValue::Date(d) => d.to_string(),
+        DataValue::Time(t) => t.to_string(),
+        DataValue::Map(_) | DataValue::List(_) => serde_json::to_string(value).ok()?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_split_dataset_groups_rows_by_value() {
+        let rows = [Some("IT"), Some("FR"), None, Some("IT")]
+            .into_iter()
+            .map(|country| {
+                HashMap::from([(
+                    "country".to_string(),
+                    country.map_or(DataValue::Null, |c| DataValue::String(c.to_string())),
+                )])
+            })
+            .collect();
+
+        let (groups, truncated) = split_dataset(&DataSet::from_rows(rows), "country");
+        assert!(!truncated);
+        let sizes: Vec<(&str, usize)> = groups.iter().map(|(v, d)| (v.as_str(), d.len())).collect();
+        assert_eq!(sizes, vec![("null", 1), ("FR", 1), ("IT", 2)]);
+    }
+}
@@ -0,0 +1,118 @@
+//! HyperLogLog sketches for approximate distinct counts.
+//!
+//! A sketch hashes every key into one of `2^precision` one-byte registers
+//! keeping the longest run of leading zeros seen, so its memory does not
+//! grow with the number of keys. The standard error of the estimate is
+//! `1.04 / sqrt(2^precision)`.
+
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+/// Fewest index bits of a sketch (16 registers).
+const MIN_PRECISION: u8 = 4;
+
+/// Most index bits of a sketch (256 KiB of registers).
+const MAX_PRECISION: u8 = 18;
+
+/// A HyperLogLog sketch of the distinct keys inserted into it.
+#[derive(Debug, Clone)]
+pub(crate) struct HyperLogLog {
+    precision: u8,
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    /// Creates the smallest sketch whose standard error is within
+    /// `tolerance`, capped to `max_bytes` of registers.
+    pub(crate) fn with_tolerance(tolerance: f64, max_bytes: Option<usize>) -> Self {
+        let wanted = if tolerance > 0.0 && tolerance < 1.0 {
+            (1.04 / tolerance).powi(2).log2().ceil() as u8
+        } else {
+            MIN_PRECISION
+        };
+        let cap = max_bytes.map_or(MAX_PRECISION, |bytes| {
+            (bytes.max(1) as f64).log2().floor() as u8
+        });
+        let precision = wanted.min(cap).clamp(MIN_PRECISION, MAX_PRECISION);
+        Self {
+            precision,
+            registers: vec![0; 1 << precision],
+        }
+    }
+
+    /// Adds `key` to the sketch.
+    pub(crate) fn insert(&mut self, key: &str) {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        let index = (hash >> (64 - self.precision)) as usize;
+        // The guard bit bounds the rank when the remaining bits are all zero
+        let rest = (hash << self.precision) | (1 << (self.precision - 1));
+        let rank = rest.leading_zeros() as u8 + 1;
+        self.registers[index] = self.registers[index].max(rank);
+    }
+
+    /// Estimates the number of distinct keys inserted.
+    pub(crate) fn estimate(&self) -> f64 {
+        let m = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / m),
+        };
+        let sum: f64 = self
+            .registers
+            .iter()
+            .map(|&rank| 2f64.powi(-i32::from(rank)))
+            .sum();
+        let raw = alpha * m * m / sum;
+
+        // Linear counting is more accurate while registers are still empty
+        let empty = self.registers.iter().filter(|&&rank| rank == 0).count();
+        if raw <= 2.5 * m && empty > 0 {
+            m * (m / empty as f64).ln()
+        } else {
+            raw
+        }
+    }
+
+    /// Returns the standard error of the estimate, relative to it.
+    pub(crate) fn standard_error(&self) -> f64 {
+        1.04 / (self.registers.len() as f64).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_is_within_tolerance() {
+        let mut sketch = HyperLogLog::with_tolerance(0.01, None);
+        assert_eq!(sketch.registers.len(), 16 * 1024);
+        assert!(sketch.standard_error() <= 0.01);
+
+        for i in 0..200_000 {
+            sketch.insert(&format!("order-{}", i));
+            // Repeated keys do not change the estimate
+            sketch.insert(&format!("order-{}", i));
+        }
+        let error = (sketch.estimate() - 200_000.0).abs() / 200_000.0;
+        assert!(error < 0.03, "relative error {}", error);
+    }
+
+    #[test]
+    fn test_memory_cap_lowers_precision() {
+        let sketch = HyperLogLog::with_tolerance(0.001, Some(4 * 1024));
+        assert_eq!(sketch.registers.len(), 4 * 1024);
+        assert!(sketch.standard_error() > 0.001);
+
+        let mut small = HyperLogLog::with_tolerance(0.5, None);
+        assert_eq!(small.registers.len(), 16);
+        for key in ["a", "b", "c"] {
+            small.insert(key);
+        }
+        assert_eq!(small.estimate().round(), 3.0);
+    }
+}
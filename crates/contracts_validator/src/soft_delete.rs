@@ -0,0 +1,117 @@
+//! Soft-deleted rows.
+//!
+//! Tables that delete by flagging rows keep their tombstones next to live
+//! data. With a [`SoftDelete`](contracts_core::SoftDelete) column on the
+//! contract's quality checks, completeness and uniqueness are computed over
+//! the live rows only: row-based validation drops the deleted rows before
+//! the checks, SQL sources add [`live_predicate`] to the check queries.
+//! Schema, constraint and freshness checks still see every row.
+
+use crate::{DataRow, DataSet, DataValue};
+use contracts_core::{Contract, DataType, PrimitiveType};
+
+/// Returns the soft-delete column of `contract` whose deleted rows the
+/// checks leave out, if any.
+pub(crate) fn excluded_column(contract: &Contract) -> Option<&str> {
+    contract
+        .quality_checks
+        .as_ref()?
+        .soft_delete
+        .as_ref()?
+        .excluded_column()
+}
+
+/// Returns true if `row` is deleted: its `column` is `true`, or set to a
+/// value of another type. Rows without the column are live.
+pub(crate) fn is_deleted(row: &DataRow, column: &str) -> bool {
+    match row.get(column) {
+        None | Some(DataValue::Null) => false,
+        Some(DataValue::Bool(deleted)) => *deleted,
+        Some(_) => true,
+    }
+}
+
+/// Keeps the live rows of `dataset`.
+///
+/// Returns `None` when the contract has no soft-delete column to exclude.
+pub(crate) fn live_rows(dataset: &DataSet, contract: &Contract) -> Option<DataSet> {
+    let column = excluded_column(contract)?;
+    let rows = dataset
+        .rows()
+        .filter(|row| !is_deleted(row, column))
+        .cloned()
+        .collect();
+    Some(DataSet::from_rows(rows))
+}
+
+/// Returns the SQL predicate selecting the live rows, if the contract has a
+/// soft-delete column to exclude.
+///
+/// Boolean columns keep the rows that are not `true`; columns of any other
+/// type keep the rows where they are null.
+pub(crate) fn live_predicate(contract: &Contract) -> Option<String> {
+    let column = excluded_column(contract)?;
+    let is_flag = contract
+        .field(column)
+        .is_some_and(|f| f.field_type == DataType::Primitive(PrimitiveType::Boolean));
+    Some(if is_flag {
+        format!("\"{}\" IS NOT TRUE", column)
+    } else {
+        format!("\"{}\" IS NULL", column)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use contracts_core::{
+        ContractBuilder, DataFormat, FieldBuilder, QualityChecksBuilder, SoftDelete,
+    };
+
+    fn contract(column: &str, field_type: &str, include_deleted: bool) -> Contract {
+        ContractBuilder::new("users", "team")
+            .location("users.parquet")
+            .format(DataFormat::Parquet)
+            .field(FieldBuilder::new(column, field_type).build())
+            .quality_checks(
+                QualityChecksBuilder::new()
+                    .soft_delete(SoftDelete {
+                        column: column.to_string(),
+                        include_deleted,
+                    })
+                    .build(),
+            )
+            .build()
+    }
+
+    #[test]
+    fn test_live_rows_drop_tombstones() {
+        let row = |value: DataValue| DataRow::from([("is_deleted".to_string(), value)]);
+        let dataset = DataSet::from_rows(vec![
+            row(DataValue::Bool(false)),
+            row(DataValue::Bool(true)),
+            row(DataValue::Null),
+            DataRow::new(),
+        ]);
+
+        let live = live_rows(&dataset, &contract("is_deleted", "boolean", false)).unwrap();
+        assert_eq!(live.len(), 3);
+        assert!(live_rows(&dataset, &contract("is_deleted", "boolean", true)).is_none());
+    }
+
+    #[test]
+    fn test_live_predicate() {
+        assert_eq!(
+            live_predicate(&contract("is_deleted", "boolean", false)).as_deref(),
+            Some("\"is_deleted\" IS NOT TRUE")
+        );
+        assert_eq!(
+            live_predicate(&contract("_deleted_at", "timestamp", false)).as_deref(),
+            Some("\"_deleted_at\" IS NULL")
+        );
+        assert_eq!(
+            live_predicate(&contract("_deleted_at", "timestamp", true)),
+            None
+        );
+    }
+}
@@ -0,0 +1,88 @@
+//! Validation stages that can be run on their own.
+//!
+//! A full run reports every check in one [`ValidationReport`]. Embedders that
+//! show checks separately, or only need some of them, pick the stages to run
+//! with [`DataValidator::validate_stages`] and get one report per stage.
+//!
+//! [`ValidationReport`]: contracts_core::ValidationReport
+//! [`DataValidator::validate_stages`]: crate::DataValidator::validate_stages
+
+use contracts_core::CheckKind;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A group of checks that runs on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stage {
+    /// Field presence, types and nullability, and deprecated fields in use
+    Schema,
+    /// Field constraints
+    Constraints,
+    /// Completeness and uniqueness, and the empty data policy
+    Quality,
+    /// Freshness check
+    Freshness,
+    /// Custom SQL checks, executed against the data
+    Custom,
+    /// Machine learning dataset checks
+    Ml,
+}
+
+impl Stage {
+    /// Every stage, in the order a full run goes through them.
+    pub const ALL: [Stage; 6] = [
+        Stage::Schema,
+        Stage::Constraints,
+        Stage::Quality,
+        Stage::Freshness,
+        Stage::Custom,
+        Stage::Ml,
+    ];
+
+    /// Returns the lowercase name of the stage.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Schema => "schema",
+            Self::Constraints => "constraints",
+            Self::Quality => "quality",
+            Self::Freshness => "freshness",
+            Self::Custom => "custom",
+            Self::Ml => "ml",
+        }
+    }
+
+    /// Returns the kind of the violations the stage reports.
+    pub fn check_kind(&self) -> CheckKind {
+        match self {
+            Self::Schema => CheckKind::Schema,
+            Self::Constraints => CheckKind::Constraint,
+            Self::Quality => CheckKind::Quality,
+            Self::Freshness => CheckKind::Freshness,
+            Self::Custom => CheckKind::Custom,
+            Self::Ml => CheckKind::Ml,
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for Stage {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|stage| stage.as_str() == s)
+            .ok_or_else(|| {
+                format!(
+                    "unknown stage '{}' (expected schema, constraints, quality, freshness, custom or ml)",
+                    s
+                )
+            })
+    }
+}
@@ -0,0 +1,231 @@
+//! Per-column statistics gathered in a single pass over rows.
+//!
+//! Readers that already walk every value (such as the Iceberg sampler) feed
+//! rows into a [`StatsCollector`] as they produce them and attach the result
+//! to the [`DataSet`](crate::DataSet). Quality checks then read null and
+//! distinct counts from the stats instead of scanning the rows again.
+
+use crate::{DataRow, DataValue};
+use contracts_core::ColumnStats;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashSet};
+
+/// Number of distinct values tracked exactly per column.
+///
+/// Beyond this the distinct count stops growing and is reported as a lower bound.
+pub const MAX_TRACKED_DISTINCT: usize = 100_000;
+
+/// Accumulates [`ColumnStats`] for every column of the rows it observes.
+///
+/// A column missing from a row counts as null for that row.
+///
+/// # Example
+///
+/// ```rust
+/// use contracts_validator::{DataRow, DataValue, StatsCollector};
+///
+/// let mut collector = StatsCollector::new();
+/// for age in [Some(31), None, Some(27), Some(31)] {
+///     let mut row = DataRow::new();
+///     row.insert("age".to_string(), age.map_or(DataValue::Null, DataValue::Int));
+///     collector.observe(&row);
+/// }
+///
+/// let stats = &collector.finish()["age"];
+/// assert_eq!(stats.null_count, 1);
+/// assert_eq!(stats.min.as_deref(), Some("27"));
+/// assert_eq!(stats.max.as_deref(), Some("31"));
+/// assert_eq!(stats.distinct_count, 2);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct StatsCollector {
+    rows: usize,
+    columns: BTreeMap<String, ColumnAccumulator>,
+}
+
+#[derive(Debug, Clone)]
+struct ColumnAccumulator {
+    /// Rows in which the column was present, null or not
+    present: usize,
+    /// Explicit null values; rows without the column are added in `finish`
+    nulls: usize,
+    min: Option<DataValue>,
+    max: Option<DataValue>,
+    distinct: HashSet<String>,
+    distinct_exact: bool,
+}
+
+impl StatsCollector {
+    /// Creates an empty collector.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records every value of `row`.
+    pub fn observe(&mut self, row: &DataRow) {
+        for (column, value) in row {
+            self.columns
+                .entry(column.clone())
+                .or_insert_with(ColumnAccumulator::new)
+                .observe(value);
+        }
+        self.rows += 1;
+    }
+
+    /// Returns the number of rows observed so far.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the statistics of every column seen.
+    pub fn finish(self) -> BTreeMap<String, ColumnStats> {
+        let rows = self.rows;
+        self.columns
+            .into_iter()
+            .map(|(column, acc)| (column, acc.finish(rows)))
+            .collect()
+    }
+}
+
+impl ColumnAccumulator {
+    fn new() -> Self {
+        Self {
+            present: 0,
+            nulls: 0,
+            min: None,
+            max: None,
+            distinct: HashSet::new(),
+            distinct_exact: true,
+        }
+    }
+
+    fn observe(&mut self, value: &DataValue) {
+        self.present += 1;
+        if value.is_null() {
+            self.nulls += 1;
+            return;
+        }
+
+        if self.distinct_exact {
+            if self.distinct.len() < MAX_TRACKED_DISTINCT {
+                self.distinct.insert(value_key(value));
+            } else if !self.distinct.contains(&value_key(value)) {
+                self.distinct_exact = false;
+            }
+        }
+
+        if matches!(value, DataValue::Map(_) | DataValue::List(_)) {
+            return;
+        }
+        if self
+            .min
+            .as_ref()
+            .is_none_or(|min| compare(value, min) == Some(Ordering::Less))
+        {
+            self.min = Some(value.clone());
+        }
+        if self
+            .max
+            .as_ref()
+            .is_none_or(|max| compare(value, max) == Some(Ordering::Greater))
+        {
+            self.max = Some(value.clone());
+        }
+    }
+
+    fn finish(self, rows: usize) -> ColumnStats {
+        ColumnStats {
+            null_count: self.nulls + (rows - self.present),
+            min: self.min.as_ref().map(value_key),
+            max: self.max.as_ref().map(value_key),
+            distinct_count: self.distinct.len(),
+            distinct_exact: self.distinct_exact,
+        }
+    }
+}
+
+/// Orders two values of comparable kinds; numeric kinds compare by value.
+fn compare(a: &DataValue, b: &DataValue) -> Option<Ordering> {
+    match (a, b) {
+        (DataValue::Int(a), DataValue::Int(b)) => Some(a.cmp(b)),
+        (DataValue::UInt(a), DataValue::UInt(b)) => Some(a.cmp(b)),
+        (DataValue::String(a), DataValue::String(b)) => Some(a.cmp(b)),
+        (DataValue::Timestamp(a), DataValue::Timestamp(b)) => Some(a.cmp(b)),
+        (DataValue::Date(a), DataValue::Date(b)) => Some(a.cmp(b)),
+        (DataValue::Time(a), DataValue::Time(b)) => Some(a.cmp(b)),
+        (DataValue::Bool(a), DataValue::Bool(b)) => Some(a.cmp(b)),
+        _ => a.as_float()?.partial_cmp(&b.as_float()?),
+    }
+}
+
+/// Renders a value as the key used for distinct counting and duplicate detection.
+pub(crate) fn value_key(value: &DataValue) -> String {
+    match value {
+        DataValue::Null => "NULL".to_string(),
+        DataValue::String(s) | DataValue::Timestamp(s) => s.clone(),
+        DataValue::Int(i) => i.to_string(),
+        DataValue::UInt(u) => u.to_string(),
+        DataValue::Decimal { value, scale } => DataValue::decimal_to_string(*value, *scale),
+        DataValue::Float(f) => f.to_string(),
+        DataValue::Bool(b) => b.to_string(),
+        DataValue::Date(d) => d.to_string(),
+        DataValue::Time(t) => t.to_string(),
+        DataValue::Map(_) => "[map]".to_string(),
+        DataValue::List(_) => "[list]".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(values: &[(&str, DataValue)]) -> DataRow {
+        values
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_min_max_compare_numbers_by_value() {
+        let mut collector = StatsCollector::new();
+        collector.observe(&row(&[("n", DataValue::Int(9))]));
+        collector.observe(&row(&[("n", DataValue::Float(10.5))]));
+        collector.observe(&row(&[(
+            "n",
+            DataValue::Decimal {
+                value: -125,
+                scale: 2,
+            },
+        )]));
+
+        let stats = &collector.finish()["n"];
+        assert_eq!(stats.min.as_deref(), Some("-1.25"));
+        assert_eq!(stats.max.as_deref(), Some("10.5"));
+        assert_eq!(stats.distinct_count, 3);
+        assert!(stats.distinct_exact);
+    }
+
+    #[test]
+    fn test_missing_columns_count_as_null() {
+        let mut collector = StatsCollector::new();
+        collector.observe(&row(&[("a", DataValue::Int(1))]));
+        collector.observe(&row(&[("b", DataValue::Int(2))]));
+        collector.observe(&row(&[("a", DataValue::Null)]));
+
+        let stats = collector.finish();
+        assert_eq!(stats["a"].null_count, 2);
+        assert_eq!(stats["b"].null_count, 2);
+    }
+
+    #[test]
+    fn test_all_null_column_has_no_bounds() {
+        let mut collector = StatsCollector::new();
+        collector.observe(&row(&[("a", DataValue::Null)]));
+
+        let stats = &collector.finish()["a"];
+        assert_eq!(stats.null_count, 1);
+        assert_eq!(stats.min, None);
+        assert_eq!(stats.distinct_count, 0);
+    }
+}
@@ -0,0 +1,512 @@
+//! Checks answered from column statistics instead of rows.
+//!
+//! Table formats such as Iceberg keep a row count, null counts and value
+//! bounds for every data file. Summed over the files of a table they answer
+//! nullability, completeness, numeric range and freshness checks without
+//! reading any data. Checks that need the values themselves are listed as
+//! inconclusive, so a metadata-only run never looks like a full pass.
+
+use crate::ValidationError;
+use crate::constraints::null_fraction_message;
+use crate::custom::{check_freshness, parse_timestamp};
+use crate::engine::apply_empty_data_policy;
+use crate::findings::{constraint_severity, freshness_severity};
+use crate::quality::completeness_error;
+use chrono::{DateTime, Utc};
+use contracts_core::{
+    CheckKind, ColumnStats, Contract, FieldConstraints, Severity, ValidationContext,
+    ValidationReport, Violation, codes,
+};
+use std::collections::BTreeMap;
+
+/// Evaluates the data checks of `contract` that `columns` can answer for a
+/// dataset of `records` rows, adding the results to `report`.
+///
+/// `columns` holds the statistics of every column they are known for; a
+/// column missing from it has none. `min` and `max` only need to be set for
+/// numeric and temporal columns, and may be bounds rather than exact values:
+/// a range check fails only when a bound falls outside the range.
+///
+/// Schema checks are left to the caller, who knows the table schema, and so
+/// is a freshness check read from table metadata (see
+/// [`check_metadata_freshness`]).
+///
+/// # Example
+///
+/// ```rust
+/// use contracts_core::{
+///     ColumnStats, ContractBuilder, DataFormat, FieldBuilder, FieldConstraints,
+///     ValidationContext, ValidationReport,
+/// };
+/// use contracts_validator::check_column_stats;
+/// use std::collections::BTreeMap;
+///
+/// let contract = ContractBuilder::new("users", "identity")
+///     .location("s3://lake/users")
+///     .format(DataFormat::Iceberg)
+///     .field(
+///         FieldBuilder::new("age", "int32")
+///             .constraint(FieldConstraints::Range { min: 0.0, max: 120.0 })
+///             .build(),
+///     )
+///     .build();
+/// let columns = BTreeMap::from([(
+///     "age".to_string(),
+///     ColumnStats {
+///         null_count: 0,
+///         min: Some("3".to_string()),
+///         max: Some("131".to_string()),
+///         distinct_count: 0,
+///         distinct_exact: false,
+///     },
+/// )]);
+///
+/// let mut report = ValidationReport::success();
+/// check_column_stats(&contract, &ValidationContext::new(), 1_000, &columns, &mut report);
+/// assert!(!report.passed);
+/// assert_eq!(report.errors[0].code.code, "DCE0203");
+/// ```
+pub fn check_column_stats(
+    contract: &Contract,
+    context: &ValidationContext,
+    records: usize,
+    columns: &BTreeMap<String, ColumnStats>,
+    report: &mut ValidationReport,
+) {
+    report.stats.records_validated = records;
+    report.stats.fields_checked = contract.schema.fields.len();
+    report.stats.columns = columns.clone();
+    if records == 0 {
+        apply_empty_data_policy(contract, context, report);
+        return;
+    }
+
+    let mut evaluated = 0;
+    for field in &contract.schema.fields {
+        let stats = columns.get(&field.name);
+        if !field.allows_null() {
+            match stats {
+                Some(stats) => {
+                    if stats.null_count > 0 {
+                        report.add_error(
+                            ValidationError::null_violation(&field.name, None).to_violation(),
+                        );
+                    }
+                    evaluated += 1;
+                }
+                None => report.add_inconclusive(unanswered(
+                    CheckKind::Schema,
+                    format!("No null counts for field '{}'", field.name),
+                )),
+            }
+        }
+
+        for constraint in field.constraints.iter().flatten() {
+            match constraint {
+                // Covered by the null count above
+                FieldConstraints::NotNull => {}
+                FieldConstraints::Range { min, max } => {
+                    let bounds = stats.and_then(|stats| {
+                        let parse = |value: &Option<String>| value.as_deref()?.parse::<f64>().ok();
+                        Some((parse(&stats.min)?, parse(&stats.max)?))
+                    });
+                    // Columns that are all null have no bounds, and nothing out of range
+                    let all_null = stats.is_some_and(|stats| stats.null_count == records);
+                    match bounds {
+                        Some((lowest, highest)) if lowest < *min || highest > *max => {
+                            let severity = constraint_severity(contract, Some(&field.name));
+                            report.add_violation(
+                                ValidationError::constraint(
+                                    &field.name,
+                                    format!(
+                                        "Values from {} to {} out of range [{}, {}]",
+                                        lowest, highest, min, max
+                                    ),
+                                )
+                                .to_violation()
+                                .with_severity(severity),
+                            );
+                        }
+                        Some(_) => {}
+                        None if all_null => {}
+                        None => {
+                            report.add_inconclusive(unanswered(
+                                CheckKind::Constraint,
+                                format!(
+                                    "No numeric bounds for the range of field '{}'",
+                                    field.name
+                                ),
+                            ));
+                            continue;
+                        }
+                    }
+                    evaluated += 1;
+                }
+                FieldConstraints::MaxNullFraction { fraction } => match stats {
+                    Some(stats) => {
+                        if let Some(message) =
+                            null_fraction_message(stats.null_count, records, *fraction)
+                        {
+                            let severity = constraint_severity(contract, Some(&field.name));
+                            report.add_violation(
+                                ValidationError::constraint(&field.name, message)
+                                    .to_violation()
+                                    .with_severity(severity),
+                            );
+                        }
+                        evaluated += 1;
+                    }
+                    None => report.add_inconclusive(unanswered(
+                        CheckKind::Constraint,
+                        format!("No null counts for field '{}'", field.name),
+                    )),
+                },
+                other => report.add_inconclusive(unanswered(
+                    CheckKind::Constraint,
+                    format!(
+                        "Constraint {} of field '{}' needs the data",
+                        constraint_name(other),
+                        field.name
+                    ),
+                )),
+            }
+        }
+    }
+
+    let Some(quality) = &contract.quality_checks else {
+        report.stats.constraints_evaluated = evaluated;
+        return;
+    };
+    let default = Severity::default_for(context.strict);
+
+    if let Some(check) = &quality.completeness {
+        let deleted_column = quality
+            .soft_delete
+            .as_ref()
+            .and_then(|s| s.excluded_column());
+        for field in &check.fields {
+            if let Some(column) = deleted_column {
+                report.add_inconclusive(unanswered(
+                    CheckKind::Quality,
+                    format!(
+                        "Null counts of field '{}' include the rows soft-deleted through '{}'",
+                        field, column
+                    ),
+                ));
+                continue;
+            }
+            let Some(stats) = columns.get(field) else {
+                report.add_inconclusive(unanswered(
+                    CheckKind::Quality,
+                    format!("No null counts for the completeness of field '{}'", field),
+                ));
+                continue;
+            };
+            let non_null = records.saturating_sub(stats.null_count);
+            if let Some(error) = completeness_error(field, non_null, records, check.threshold) {
+                report.add_violation(
+                    error
+                        .to_violation()
+                        .with_severity(check.severity.unwrap_or(default)),
+                );
+            }
+            evaluated += 1;
+        }
+    }
+
+    if let Some(check) = quality
+        .freshness
+        .as_ref()
+        .filter(|c| !c.source().is_metadata())
+    {
+        match columns.get(&check.metric) {
+            Some(stats) => {
+                let latest = stats
+                    .max
+                    .as_deref()
+                    .and_then(|max| parse_timestamp(max).ok());
+                if let Err(error) = check_freshness(check, latest) {
+                    report.add_violation(
+                        error
+                            .to_violation()
+                            .with_severity(freshness_severity(contract, default)),
+                    );
+                }
+                evaluated += 1;
+            }
+            None => report.add_inconclusive(unanswered(
+                CheckKind::Freshness,
+                format!("No upper bound for freshness metric '{}'", check.metric),
+            )),
+        }
+    }
+
+    if quality.uniqueness.is_some() {
+        report.add_inconclusive(unanswered(
+            CheckKind::Quality,
+            "Uniqueness check needs the data",
+        ));
+    }
+    if quality.volume.is_some() {
+        report.add_inconclusive(unanswered(
+            CheckKind::Quality,
+            "Volume check needs the data",
+        ));
+    }
+    for check in quality.custom_checks.iter().flatten() {
+        report.add_inconclusive(unanswered(
+            CheckKind::Custom,
+            format!("Custom check '{}' needs the data", check.name),
+        ));
+    }
+    for check in quality.row_checks.iter().flatten() {
+        report.add_inconclusive(unanswered(
+            CheckKind::Custom,
+            format!("Row check '{}' needs the data", check.name),
+        ));
+    }
+    if quality.ml_checks.is_some() {
+        report.add_inconclusive(unanswered(CheckKind::Ml, "ML checks need the data"));
+    }
+
+    report.stats.constraints_evaluated = evaluated;
+}
+
+/// Evaluates the contract's freshness check against `updated_at`, the time
+/// table metadata gives for the last update, adding the result to `report`.
+///
+/// `updated_at` is the commit time of the validated snapshot for
+/// [`SnapshotCommittedAt`](contracts_core::FreshnessSource::SnapshotCommittedAt),
+/// or the start of the newest partition for
+/// [`Partition`](contracts_core::FreshnessSource::Partition); `None` means the table has
+/// no snapshot or no such partition. Does nothing unless the check's source
+/// is table metadata.
+pub fn check_metadata_freshness(
+    contract: &Contract,
+    context: &ValidationContext,
+    updated_at: Option<DateTime<Utc>>,
+    report: &mut ValidationReport,
+) {
+    let Some(check) = contract
+        .quality_checks
+        .as_ref()
+        .and_then(|qc| qc.freshness.as_ref())
+        .filter(|check| check.source().is_metadata())
+    else {
+        return;
+    };
+    if let Err(error) = check_freshness(check, updated_at) {
+        let severity = freshness_severity(contract, Severity::default_for(context.strict));
+        report.add_violation(error.to_violation().with_severity(severity));
+    }
+    report.stats.constraints_evaluated += 1;
+}
+
+/// A check the statistics cannot answer.
+fn unanswered(kind: CheckKind, message: impl Into<String>) -> Violation {
+    Violation::new(&codes::STATS_ONLY, kind, message)
+}
+
+fn constraint_name(constraint: &FieldConstraints) -> &'static str {
+    match constraint {
+        FieldConstraints::AllowedValues { .. } => "allowed_values",
+        FieldConstraints::Range { .. } => "range",
+        FieldConstraints::Pattern { .. } => "pattern",
+        FieldConstraints::Length { .. } => "length",
+        FieldConstraints::ByteLength { .. } => "byte_length",
+        FieldConstraints::ListLength { .. } => "list_length",
+        FieldConstraints::MapKeys { .. } => "map_keys",
+        FieldConstraints::NonEmpty => "non_empty",
+        FieldConstraints::NotNull => "not_null",
+        FieldConstraints::MaxNullFraction { .. } => "max_null_fraction",
+        FieldConstraints::Precision { .. } => "precision",
+        FieldConstraints::TimeRange { .. } => "time_range",
+        FieldConstraints::Cardinality { .. } => "cardinality",
+        FieldConstraints::Conditional { .. } => "conditional",
+        FieldConstraints::Custom { .. } => "custom",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use contracts_core::{
+        CompletenessCheck, ContractBuilder, DataFormat, FieldBuilder, FreshnessCheck,
+        FreshnessSource, QualityChecksBuilder, UniquenessCheck, ValidationStatus,
+    };
+
+    fn stats(null_count: usize, min: Option<&str>, max: Option<&str>) -> ColumnStats {
+        ColumnStats {
+            null_count,
+            min: min.map(str::to_string),
+            max: max.map(str::to_string),
+            distinct_count: 0,
+            distinct_exact: false,
+        }
+    }
+
+    fn contract() -> Contract {
+        ContractBuilder::new("orders", "sales")
+            .location("s3://lake/orders")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("id", "int64").nullable(false).build())
+            .field(
+                FieldBuilder::new("amount", "float64")
+                    .constraint(FieldConstraints::Range {
+                        min: 0.0,
+                        max: 1000.0,
+                    })
+                    .build(),
+            )
+            .field(
+                FieldBuilder::new("status", "string")
+                    .constraint(FieldConstraints::AllowedValues {
+                        values: vec!["open".to_string()],
+                    })
+                    .build(),
+            )
+            .field(FieldBuilder::new("updated_at", "timestamp").build())
+            .quality_checks(
+                QualityChecksBuilder::new()
+                    .completeness(CompletenessCheck {
+                        threshold: 0.9,
+                        fields: vec!["amount".to_string()],
+                        min_sample_size: None,
+                        severity: None,
+                    })
+                    .uniqueness(UniquenessCheck {
+                        fields: vec!["id".to_string()],
+                        scope: None,
+                        min_sample_size: None,
+                        severity: None,
+                        approximate: None,
+                    })
+                    .freshness(FreshnessCheck {
+                        max_delay: "1d".to_string(),
+                        metric: "updated_at".to_string(),
+                        source: None,
+                        allowed_lateness: None,
+                        severity: None,
+                    })
+                    .build(),
+            )
+            .build()
+    }
+
+    #[test]
+    fn test_checks_answered_from_stats() {
+        let now = chrono::Utc::now().to_rfc3339();
+        let columns = BTreeMap::from([
+            ("id".to_string(), stats(0, Some("1"), Some("100"))),
+            ("amount".to_string(), stats(5, Some("0.5"), Some("999"))),
+            ("updated_at".to_string(), stats(0, None, Some(&now))),
+        ]);
+        let mut report = ValidationReport::success();
+        check_column_stats(
+            &contract(),
+            &ValidationContext::new().with_strict(true),
+            100,
+            &columns,
+            &mut report,
+        );
+
+        assert!(report.passed, "{:?}", report.errors);
+        assert_eq!(report.status(), ValidationStatus::Inconclusive);
+        let unanswered: Vec<&str> = report
+            .inconclusive
+            .iter()
+            .map(|check| check.message.as_str())
+            .collect();
+        assert_eq!(
+            unanswered,
+            vec![
+                "Constraint allowed_values of field 'status' needs the data",
+                "Uniqueness check needs the data"
+            ]
+        );
+        assert_eq!(report.stats.records_validated, 100);
+        assert_eq!(report.stats.constraints_evaluated, 4);
+    }
+
+    #[test]
+    fn test_failures_from_stats() {
+        let columns = BTreeMap::from([
+            ("id".to_string(), stats(2, Some("1"), Some("100"))),
+            ("amount".to_string(), stats(50, Some("-3"), Some("20"))),
+            (
+                "updated_at".to_string(),
+                stats(0, None, Some("2020-01-01T00:00:00+00:00")),
+            ),
+        ]);
+        let mut report = ValidationReport::success();
+        check_column_stats(
+            &contract(),
+            &ValidationContext::new().with_strict(true),
+            100,
+            &columns,
+            &mut report,
+        );
+
+        let codes: Vec<&str> = report.errors.iter().map(|error| error.code.code).collect();
+        assert_eq!(codes, vec!["DCE0102", "DCE0203", "DCE0300", "DCE0301"]);
+        assert_eq!(
+            report.errors[1].message,
+            "Constraint violation for field 'amount': Values from -3 to 20 out of range [0, 1000]"
+        );
+    }
+
+    #[test]
+    fn test_missing_stats_are_inconclusive() {
+        let mut report = ValidationReport::success();
+        check_column_stats(
+            &contract(),
+            &ValidationContext::new(),
+            10,
+            &BTreeMap::new(),
+            &mut report,
+        );
+        assert!(report.passed);
+        assert!(
+            report
+                .inconclusive
+                .iter()
+                .all(|check| check.code == &codes::STATS_ONLY)
+        );
+        assert_eq!(report.inconclusive.len(), 6);
+        assert_eq!(report.stats.constraints_evaluated, 0);
+    }
+
+    #[test]
+    fn test_metadata_freshness() {
+        let mut contract = contract();
+        let check = contract
+            .quality_checks
+            .as_mut()
+            .and_then(|qc| qc.freshness.as_mut())
+            .unwrap();
+        check.source = Some(FreshnessSource::SnapshotCommittedAt);
+        let context = ValidationContext::new().with_strict(true);
+
+        // Left to the caller by the statistics
+        let mut report = ValidationReport::success();
+        check_column_stats(&contract, &context, 10, &BTreeMap::new(), &mut report);
+        assert!(report.errors.iter().all(|e| e.code.code != "DCE0301"));
+
+        let mut report = ValidationReport::success();
+        check_metadata_freshness(&contract, &context, Some(chrono::Utc::now()), &mut report);
+        assert!(report.passed);
+        assert_eq!(report.stats.constraints_evaluated, 1);
+
+        let mut report = ValidationReport::success();
+        let old = "2020-01-01T00:00:00Z".parse().unwrap();
+        check_metadata_freshness(&contract, &context, Some(old), &mut report);
+        assert_eq!(report.errors[0].code.code, "DCE0301");
+
+        let mut report = ValidationReport::success();
+        check_metadata_freshness(&contract, &context, None, &mut report);
+        assert_eq!(
+            report.errors[0].message,
+            "Quality check failed: Freshness check failed: the table has no snapshot"
+        );
+    }
+}
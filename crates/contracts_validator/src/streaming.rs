@@ -0,0 +1,636 @@
+//! Validation of rows that arrive in chunks.
+//!
+//! [`DataValidator`](crate::DataValidator) needs every row in one
+//! [`DataSet`]. Readers of tables larger than memory hand rows over chunk by
+//! chunk instead: a [`StreamingValidator`] checks the schema and constraints
+//! of each chunk as it arrives and keeps only what the table-wide checks
+//! need, so a chunk can be dropped as soon as it was pushed.
+//!
+//! [`DataValidator::validate_stream`](crate::DataValidator::validate_stream)
+//! drives a [`StreamingValidator`] from a [`Stream`](futures::Stream) of
+//! rows, for sources that never end or whose size is not known up front.
+
+use crate::cdc::ChangeEvents;
+use crate::constraints::check_distributions;
+use crate::custom::{check_freshness, latest_timestamp, require_column_source};
+use crate::engine::{apply_empty_data_policy, report_from_findings, select_rows};
+use crate::findings::{Findings, constraint_severity, custom_severity, freshness_severity};
+use crate::quality::{
+    DuplicateCounter, RowCheckCounter, ScopeGroups, completeness_error, scoped_uniqueness_key,
+};
+use crate::volume::VolumeCounter;
+use crate::{
+    ConstraintValidator, CustomValidator, DataSet, SchemaValidator, StatsCollector,
+    ValidationError, deprecation, sample_size, soft_delete,
+};
+use chrono::{DateTime, Utc};
+use contracts_core::{
+    CheckKind, Contract, Severity, ValidationContext, ValidationReport, Violation, codes,
+};
+use std::collections::HashMap;
+use std::time::Instant;
+
+/// Validates a contract against rows pushed one chunk at a time.
+///
+/// Schema, constraint and deprecation checks run on every chunk. Completeness,
+/// uniqueness, freshness and row checks are evaluated over all chunks when
+/// the run finishes: column statistics, the keys of the uniqueness check, the
+/// most recent freshness timestamp and the rows failing each row check are
+/// counted, the rows are not kept. Memory therefore
+/// grows with the chunk size and the number of distinct uniqueness keys, not
+/// with the number of rows. Change events of a `cdc` contract are unwrapped
+/// chunk by chunk, keeping the latest `ts_ms` of each key.
+///
+/// Checks that need every row at once are not evaluated: custom SQL checks
+/// are only checked for syntax, and ML checks and segments are skipped with a
+/// warning.
+///
+/// The context's time window, row filter and defaults apply to each chunk,
+/// and its sample size caps the rows validated over all chunks. Its error
+/// limits bound the violations kept over all chunks, and a fail-fast run
+/// ignores the chunks after the one with its first error.
+///
+/// # Example
+///
+/// ```rust
+/// use contracts_core::{
+///     ContractBuilder, DataFormat, FieldBuilder, QualityChecksBuilder, UniquenessCheck,
+///     ValidationContext,
+/// };
+/// use contracts_validator::{DataRow, DataSet, DataValue, StreamingValidator};
+///
+/// let contract = ContractBuilder::new("users", "owner")
+///     .location("s3://lake/users")
+///     .format(DataFormat::Iceberg)
+///     .field(FieldBuilder::new("id", "int64").nullable(false).build())
+///     .quality_checks(
+///         QualityChecksBuilder::new()
+///             .uniqueness(UniquenessCheck {
+///                 fields: vec!["id".to_string()],
+///                 scope: None,
+///                 min_sample_size: None,
+///                 severity: None,
+///                 approximate: None,
+///             })
+///             .build(),
+///     )
+///     .build();
+/// let chunk = |ids: &[i64]| {
+///     DataSet::from_rows(
+///         ids.iter()
+///             .map(|&id| DataRow::from([("id".to_string(), DataValue::Int(id))]))
+///             .collect(),
+///     )
+/// };
+///
+/// let context = ValidationContext::new().with_strict(true);
+/// let mut validator = StreamingValidator::new(&contract, &context);
+/// validator.push(&chunk(&[1, 2]));
+/// validator.push(&chunk(&[3, 1]));
+///
+/// let report = validator.finish();
+/// assert_eq!(report.stats.records_validated, 4);
+/// assert!(!report.passed);
+/// assert!(report.errors[0].message.contains("found 1 duplicate(s)"));
+/// ```
+#[derive(Clone)]
+pub struct StreamingValidator<'a> {
+    contract: &'a Contract,
+    context: &'a ValidationContext,
+    schema_validator: SchemaValidator,
+    constraint_validator: ConstraintValidator,
+    custom_validator: CustomValidator,
+    findings: Findings,
+    stats: StatsCollector,
+    /// Keys of the uniqueness check seen so far
+    duplicates: Option<DuplicateCounter>,
+    /// Rows failing each row check so far
+    row_checks: RowCheckCounter<'a>,
+    /// Rows of each bucket of the volume check so far
+    volume: Option<VolumeCounter<'a>>,
+    /// Rows not soft-deleted, when the contract excludes deleted rows
+    live_rows: usize,
+    /// Non-null values of each completeness field among the live rows
+    live_values: HashMap<String, usize>,
+    /// Most recent value of the freshness metric
+    latest: Option<DateTime<Utc>>,
+    /// Envelope checks of the change events, for contracts with `cdc`
+    events: Option<ChangeEvents<'a>>,
+    /// Strict run whose schema errors end the checks after schema validation
+    schema_failed: bool,
+    /// Error that keeps the run from validating any row
+    failure: Option<Violation>,
+    start: Instant,
+}
+
+impl<'a> StreamingValidator<'a> {
+    /// Starts a run of `contract` with the options of `context`.
+    pub fn new(contract: &'a Contract, context: &'a ValidationContext) -> Self {
+        Self {
+            contract,
+            context,
+            schema_validator: SchemaValidator::new(),
+            constraint_validator: ConstraintValidator::new(),
+            custom_validator: CustomValidator::new(),
+            findings: Findings::new(context),
+            stats: StatsCollector::new(),
+            duplicates: contract
+                .quality_checks
+                .as_ref()
+                .and_then(|qc| qc.uniqueness.as_ref())
+                .map(DuplicateCounter::new),
+            row_checks: RowCheckCounter::new(contract),
+            volume: contract
+                .quality_checks
+                .as_ref()
+                .and_then(|qc| qc.volume.as_ref())
+                .map(VolumeCounter::new),
+            live_rows: 0,
+            live_values: HashMap::new(),
+            latest: None,
+            events: contract.cdc.as_ref().map(ChangeEvents::new),
+            schema_failed: false,
+            failure: None,
+            start: Instant::now(),
+        }
+    }
+
+    /// Returns the number of rows validated so far.
+    pub fn rows(&self) -> usize {
+        self.stats.rows()
+    }
+
+    /// Returns true once the context's sample size is reached, the run
+    /// failed or a fail-fast run found an error; rows pushed after that are
+    /// ignored.
+    pub fn is_done(&self) -> bool {
+        self.failure.is_some()
+            || self.findings.fails_fast(self.context)
+            || self
+                .context
+                .sample_size
+                .is_some_and(|size| self.rows() >= size)
+    }
+
+    /// Validates the rows of `chunk`.
+    pub fn push(&mut self, chunk: &DataSet) {
+        if self.is_done() {
+            return;
+        }
+        let (contract, context) = (self.contract, self.context);
+
+        let images = self.events.as_mut().map(|events| events.unwrap(chunk));
+        let chunk = images.as_ref().unwrap_or(chunk);
+        let selected = match select_rows(chunk, contract, context) {
+            Ok(selected) => selected,
+            Err(e) => {
+                self.failure = Some(e.to_violation());
+                return;
+            }
+        };
+        let chunk = selected.as_ref().unwrap_or(chunk);
+        let sampled = context
+            .sample_size
+            .map(|size| size - self.rows())
+            .filter(|&remaining| remaining < chunk.len())
+            .map(|remaining| chunk.sample(remaining));
+        let chunk = sampled.as_ref().unwrap_or(chunk);
+        if chunk.is_empty() {
+            return;
+        }
+
+        let first_row = self.rows();
+        for row in chunk.rows() {
+            self.stats.observe(row);
+        }
+
+        let schema_errors = self
+            .schema_validator
+            .validate_from(contract, chunk, first_row);
+        self.findings.extend_errors(Severity::Error, &schema_errors);
+        if context.strict && !schema_errors.is_empty() {
+            self.schema_failed = true;
+        }
+        if self.schema_failed || self.findings.fails_fast(context) {
+            return;
+        }
+
+        for error in self.constraint_validator.validate_rows(contract, chunk) {
+            let severity = constraint_severity(contract, error.field());
+            self.findings
+                .push(error.to_violation().with_severity(severity));
+        }
+
+        if context.schema_only || self.findings.fails_fast(context) {
+            return;
+        }
+        let Some(qc) = &contract.quality_checks else {
+            return;
+        };
+        let deleted_column = soft_delete::excluded_column(contract);
+        let live_rows = || {
+            chunk
+                .rows()
+                .filter(move |row| !deleted_column.is_some_and(|c| soft_delete::is_deleted(row, c)))
+        };
+        if let Some(completeness) = qc
+            .completeness
+            .as_ref()
+            .filter(|_| deleted_column.is_some())
+        {
+            for row in live_rows() {
+                self.live_rows += 1;
+                for field in &completeness.fields {
+                    if row.get(field).is_some_and(|value| !value.is_null()) {
+                        *self.live_values.entry(field.clone()).or_default() += 1;
+                    }
+                }
+            }
+        }
+        // An invalid scope is reported when the run finishes
+        if let Some(uniqueness) = &qc.uniqueness
+            && let Ok(groups) = ScopeGroups::resolve(uniqueness, contract)
+            && let Some(counter) = self.duplicates.as_mut()
+        {
+            for row in live_rows() {
+                if let Some(key) = scoped_uniqueness_key(&uniqueness.fields, groups.as_ref(), row) {
+                    counter.insert(key);
+                }
+            }
+        }
+        for row in live_rows() {
+            self.row_checks.insert(row);
+            if let Some(volume) = self.volume.as_mut() {
+                volume.insert(row);
+            }
+        }
+        if let Some(freshness) = &qc.freshness {
+            self.latest = self
+                .latest
+                .max(latest_timestamp(chunk.column(&freshness.metric)));
+        }
+    }
+
+    /// Returns the report the run would finish with if no more rows came in.
+    ///
+    /// The table-wide checks are evaluated over the rows pushed so far on a
+    /// copy of the run's state, so the run goes on; the copy costs as much
+    /// memory as the state, mostly the keys of the uniqueness check.
+    pub fn partial_report(&self) -> ValidationReport {
+        self.clone().finish()
+    }
+
+    /// Runs the checks over all chunks and returns the report of the run.
+    pub fn finish(mut self) -> ValidationReport {
+        if let Some(failure) = self.failure {
+            return ValidationReport::failure(failure);
+        }
+        let (contract, context) = (self.contract, self.context);
+        let rows = self.stats.rows();
+        let columns = std::mem::take(&mut self.stats).finish();
+
+        for violation in deprecation::check_stats(contract, rows, &columns) {
+            self.findings.push(violation);
+        }
+        if !self.schema_failed {
+            for error in check_distributions(contract, rows, &columns) {
+                let severity = constraint_severity(contract, error.field());
+                self.findings
+                    .push(error.to_violation().with_severity(severity));
+            }
+        }
+        if let Some(events) = &self.events {
+            for violation in events.violations(context.strict) {
+                self.findings.push(violation);
+            }
+        }
+
+        let withheld = if context.schema_only {
+            None
+        } else {
+            sample_size::withhold_undersampled(contract, rows)
+        };
+        let checked = withheld.as_ref().map_or(contract, |w| &w.contract);
+        if !context.schema_only && !self.schema_failed && !self.findings.fails_fast(context) {
+            let default = Severity::default_for(context.strict);
+            if let Some(qc) = checked.quality_checks.as_ref().filter(|_| rows > 0) {
+                if let Some(completeness) = &qc.completeness {
+                    let severity = completeness.severity.unwrap_or(default);
+                    let excludes_deleted = soft_delete::excluded_column(contract).is_some();
+                    for field in &completeness.fields {
+                        let (non_null, total) = if excludes_deleted {
+                            let values = self.live_values.get(field).copied().unwrap_or(0);
+                            (values, self.live_rows)
+                        } else {
+                            // Missing field counts as null
+                            let nulls = columns.get(field).map_or(rows, |stats| stats.null_count);
+                            (rows.saturating_sub(nulls), rows)
+                        };
+                        if let Some(error) =
+                            completeness_error(field, non_null, total, completeness.threshold)
+                        {
+                            self.findings
+                                .push(error.to_violation().with_severity(severity));
+                        }
+                    }
+                }
+                if let Some(uniqueness) = &qc.uniqueness {
+                    let error = match ScopeGroups::resolve(uniqueness, contract) {
+                        Ok(_) => self
+                            .duplicates
+                            .as_ref()
+                            .and_then(|counter| counter.error(uniqueness)),
+                        Err(message) => Some(ValidationError::quality_check(message)),
+                    };
+                    if let Some(error) = error {
+                        let severity = uniqueness.severity.unwrap_or(default);
+                        self.findings
+                            .push(error.to_violation().with_severity(severity));
+                    }
+                }
+                if let Some(freshness) = &qc.freshness
+                    && let Err(error) = require_column_source(freshness)
+                        .and_then(|()| check_freshness(freshness, self.latest))
+                {
+                    let severity = freshness_severity(contract, default);
+                    self.findings
+                        .push(error.to_violation().with_severity(severity));
+                }
+                for (severity, error) in self.row_checks.errors() {
+                    self.findings.push(
+                        error
+                            .to_violation()
+                            .with_severity(severity.unwrap_or(default)),
+                    );
+                }
+                if let Some(volume) = &qc.volume
+                    && let Some(error) = self.volume.as_ref().and_then(|c| c.error(Utc::now()))
+                {
+                    let severity = volume.severity.unwrap_or(default);
+                    self.findings
+                        .push(error.to_violation().with_severity(severity));
+                }
+            }
+
+            for (severity, error) in self.custom_validator.validate_custom_checks_only(checked) {
+                self.findings.push(
+                    error
+                        .to_violation()
+                        .with_severity(custom_severity(severity.as_deref(), default)),
+                );
+            }
+            self.warn_skipped(checked);
+        }
+
+        let mut report = report_from_findings(self.findings, checked, rows, columns, self.start);
+        apply_empty_data_policy(contract, context, &mut report);
+        if let Some(withheld) = withheld {
+            report.inconclusive = withheld.messages;
+        }
+        report.passed = report.errors.is_empty();
+        report
+    }
+
+    /// Warns about the checks of `contract` that need every row at once.
+    fn warn_skipped(&mut self, contract: &Contract) {
+        let qc = contract.quality_checks.as_ref();
+        if qc.is_some_and(|qc| qc.custom_checks.as_ref().is_some_and(|c| !c.is_empty())) {
+            self.findings.push(
+                Violation::new(
+                    &codes::GENERAL,
+                    CheckKind::Custom,
+                    "Custom SQL checks need every row at once; chunked validation \
+                     only checked their syntax.",
+                )
+                .with_severity(Severity::Warning),
+            );
+        }
+        if qc.is_some_and(|qc| qc.ml_checks.is_some()) {
+            self.findings.push(
+                Violation::new(
+                    &codes::GENERAL,
+                    CheckKind::Ml,
+                    "ML checks need every row at once and were skipped in chunked validation.",
+                )
+                .with_severity(Severity::Warning),
+            );
+        }
+        if self.context.segment_by.is_some() {
+            self.findings.push(
+                Violation::new(
+                    &codes::GENERAL,
+                    CheckKind::Quality,
+                    "Segments need every row at once and were not built in chunked validation.",
+                )
+                .with_severity(Severity::Warning),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DataRow, DataValidator, DataValue};
+    use contracts_core::{
+        CompletenessCheck, ContractBuilder, DataFormat, FieldBuilder, QualityChecksBuilder,
+        UniquenessCheck,
+    };
+
+    fn contract() -> Contract {
+        ContractBuilder::new("users", "owner")
+            .location("s3://lake/users")
+            .format(DataFormat::Iceberg)
+            .field(FieldBuilder::new("id", "int64").nullable(false).build())
+            .field(FieldBuilder::new("email", "string").build())
+            .quality_checks(
+                QualityChecksBuilder::new()
+                    .completeness(CompletenessCheck {
+                        threshold: 0.75,
+                        fields: vec!["email".to_string()],
+                        min_sample_size: None,
+                        severity: None,
+                    })
+                    .uniqueness(UniquenessCheck {
+                        fields: vec!["id".to_string()],
+                        scope: None,
+                        min_sample_size: None,
+                        severity: None,
+                        approximate: None,
+                    })
+                    .build(),
+            )
+            .build()
+    }
+
+    fn chunk(rows: &[(Option<i64>, Option<&str>)]) -> DataSet {
+        rows.iter()
+            .map(|(id, email)| {
+                DataRow::from([
+                    ("id".to_string(), id.map_or(DataValue::Null, DataValue::Int)),
+                    (
+                        "email".to_string(),
+                        email.map_or(DataValue::Null, DataValue::from),
+                    ),
+                ])
+            })
+            .collect()
+    }
+
+    fn run(context: &ValidationContext, chunks: &[DataSet]) -> ValidationReport {
+        let contract = contract();
+        let mut validator = StreamingValidator::new(&contract, context);
+        for rows in chunks {
+            validator.push(rows);
+        }
+        validator.finish()
+    }
+
+    #[test]
+    fn test_checks_span_chunks() {
+        let chunks = [
+            chunk(&[(Some(1), Some("a@x.io")), (Some(2), None)]),
+            chunk(&[
+                (Some(3), None),
+                (None, Some("d@x.io")),
+                (Some(1), Some("e@x.io")),
+            ]),
+        ];
+        let report = run(&ValidationContext::new(), &chunks);
+
+        assert_eq!(report.stats.records_validated, 5);
+        assert_eq!(report.stats.columns["email"].null_count, 2);
+        // Rows are numbered across chunks
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].row_index, Some(3));
+        let warnings: Vec<&str> = report.warnings.iter().map(|w| w.message.as_str()).collect();
+        assert_eq!(warnings.len(), 2, "{:?}", warnings);
+        assert!(warnings[0].contains("Completeness check failed for field 'email': 60.00%"));
+        assert!(warnings[1].contains("found 1 duplicate(s)"));
+    }
+
+    #[test]
+    fn test_sample_size_caps_rows_over_chunks() {
+        let chunks = [
+            chunk(&[(Some(1), Some("a@x.io")), (Some(2), Some("b@x.io"))]),
+            chunk(&[(Some(1), Some("c@x.io")), (Some(4), Some("d@x.io"))]),
+            chunk(&[(Some(5), None)]),
+        ];
+        let context = ValidationContext::new()
+            .with_strict(true)
+            .with_sample_size(3);
+        let report = run(&context, &chunks);
+
+        assert_eq!(report.stats.records_validated, 3);
+        assert!(!report.passed);
+        assert_eq!(report.errors.len(), 1);
+        assert!(report.errors[0].message.contains("Uniqueness"));
+    }
+
+    #[tokio::test]
+    async fn test_stream_matches_chunks() {
+        let rows = chunk(&[
+            (Some(1), Some("a@x.io")),
+            (Some(2), None),
+            (Some(3), None),
+            (None, Some("d@x.io")),
+            (Some(1), Some("e@x.io")),
+        ]);
+        let contract = contract();
+        let context = ValidationContext::new();
+        let report = DataValidator::new()
+            .validate_stream(
+                &contract,
+                futures::stream::iter(rows.rows().cloned().collect::<Vec<_>>()),
+                &context,
+            )
+            .await;
+        let chunked = run(&context, &[rows]);
+
+        assert_eq!(report.stats.records_validated, 5);
+        assert_eq!(report.passed, chunked.passed);
+        assert_eq!(report.errors, chunked.errors);
+        assert_eq!(report.warnings, chunked.warnings);
+    }
+
+    #[test]
+    fn test_partial_report_keeps_the_run_going() {
+        let contract = contract();
+        let context = ValidationContext::new();
+        let mut validator = StreamingValidator::new(&contract, &context);
+        validator.push(&chunk(&[
+            (Some(1), Some("a@x.io")),
+            (Some(1), Some("b@x.io")),
+        ]));
+
+        let partial = validator.partial_report();
+        assert_eq!(partial.stats.records_validated, 2);
+        assert!(partial.warnings[0].message.contains("found 1 duplicate(s)"));
+
+        validator.push(&chunk(&[
+            (Some(2), Some("c@x.io")),
+            (Some(2), Some("d@x.io")),
+        ]));
+        let report = validator.finish();
+        assert_eq!(report.stats.records_validated, 4);
+        assert!(report.warnings[0].message.contains("found 2 duplicate(s)"));
+    }
+
+    #[tokio::test]
+    async fn test_unbounded_stream_stops_at_sample_size() {
+        let contract = contract();
+        let context = ValidationContext::new().with_sample_size(2_500);
+        let row = chunk(&[(Some(1), Some("a@x.io"))])
+            .rows()
+            .next()
+            .unwrap()
+            .clone();
+        let mut partials = Vec::new();
+        let report = DataValidator::new()
+            .validate_stream_with_progress(
+                &contract,
+                futures::stream::repeat(row),
+                &context,
+                |run| partials.push(run.rows()),
+            )
+            .await;
+
+        assert_eq!(report.stats.records_validated, 2_500);
+        assert_eq!(partials, vec![1024, 2048, 2_500]);
+    }
+
+    #[test]
+    fn test_fail_fast_ends_the_run() {
+        let contract = contract();
+        let context = ValidationContext::new().with_fail_fast(true);
+        let mut validator = StreamingValidator::new(&contract, &context);
+        validator.push(&chunk(&[
+            (Some(1), Some("a@x.io")),
+            (None, None),
+            (None, None),
+        ]));
+        assert!(validator.is_done());
+        validator.push(&chunk(&[(None, None)]));
+
+        let report = validator.finish();
+        assert_eq!(report.stats.records_validated, 3);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.stats.truncated_violations, 1);
+        // Table-wide checks are skipped
+        assert!(report.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_empty_run_applies_empty_data_policy() {
+        let report = run(&ValidationContext::new(), &[]);
+
+        assert_eq!(report.stats.records_validated, 0);
+        assert!(report.passed);
+        assert!(
+            report
+                .warnings
+                .iter()
+                .any(|w| w.code == &codes::EMPTY_DATASET)
+        );
+    }
+}
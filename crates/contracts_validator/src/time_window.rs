@@ -0,0 +1,209 @@
+//! Time-window row selection.
+//!
+//! A [`TimeWindow`](contracts_core::TimeWindow) on the validation context
+//! restricts a run to the rows of one period. Row-based validation filters the
+//! dataset before sampling; SQL sources are registered through a view built
+//! from [`time_window_predicate`], so the filter runs before any `LIMIT` and
+//! can be pushed down to the table scan.
+
+use crate::{DataSet, DataValue, ValidationError, custom::parse_timestamp};
+use chrono::{DateTime, NaiveTime, SecondsFormat, Utc};
+use contracts_core::{Contract, TimeWindow, ValidationContext};
+
+/// Returns the SQL predicate selecting the context's time window, if any.
+///
+/// Bounds are written as UTC `TIMESTAMP` literals compared directly with the
+/// column, so DataFusion can coerce date and string columns and push the
+/// filter into Parquet and Iceberg scans.
+///
+/// # Errors
+///
+/// Fails when a window is set but neither the window nor the contract's
+/// freshness check names a column, or the column is not in the schema.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use contracts_core::{ContractBuilder, DataFormat, FieldBuilder, TimeWindow, ValidationContext};
+/// use contracts_validator::time_window_predicate;
+///
+/// let contract = ContractBuilder::new("events", "team")
+///     .location("events.parquet")
+///     .format(DataFormat::Parquet)
+///     .field(FieldBuilder::new("ts", "timestamp").build())
+///     .build();
+/// let context = ValidationContext::new().with_time_window(TimeWindow {
+///     column: Some("ts".to_string()),
+///     since: Some(Utc.with_ymd_and_hms(2026, 10, 15, 0, 0, 0).unwrap()),
+///     until: None,
+/// });
+///
+/// assert_eq!(
+///     time_window_predicate(&contract, &context).unwrap().as_deref(),
+///     Some("\"ts\" >= TIMESTAMP '2026-10-15T00:00:00Z'"),
+/// );
+/// ```
+pub fn time_window_predicate(
+    contract: &Contract,
+    context: &ValidationContext,
+) -> Result<Option<String>, ValidationError> {
+    let Some((column, window)) = resolve(contract, context)? else {
+        return Ok(None);
+    };
+
+    let mut conditions = Vec::new();
+    if let Some(since) = window.since {
+        conditions.push(format!("\"{}\" >= {}", column, timestamp_literal(since)));
+    }
+    if let Some(until) = window.until {
+        conditions.push(format!("\"{}\" < {}", column, timestamp_literal(until)));
+    }
+    if conditions.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(conditions.join(" AND ")))
+}
+
+/// Keeps the rows of `dataset` inside the context's time window.
+///
+/// Timestamps, dates (from the start of the day) and strings in any format
+/// the freshness check reads are compared; other values drop the row.
+pub(crate) fn filter_rows(
+    dataset: &DataSet,
+    contract: &Contract,
+    context: &ValidationContext,
+) -> Result<Option<DataSet>, ValidationError> {
+    let Some((column, window)) = resolve(contract, context)? else {
+        return Ok(None);
+    };
+
+    let rows = dataset
+        .rows()
+        .filter(|row| {
+            row.get(column)
+                .and_then(row_timestamp)
+                .is_some_and(|ts| window.contains(ts))
+        })
+        .cloned()
+        .collect();
+    Ok(Some(DataSet::from_rows(rows)))
+}
+
+/// Returns the window and the column it filters on.
+fn resolve<'a>(
+    contract: &'a Contract,
+    context: &'a ValidationContext,
+) -> Result<Option<(&'a str, &'a TimeWindow)>, ValidationError> {
+    let Some(window) = context.time_window.as_ref() else {
+        return Ok(None);
+    };
+    let column = context.time_window_column(contract).ok_or_else(|| {
+        ValidationError::General(
+            "Time window needs a timestamp column: name one or declare a freshness check"
+                .to_string(),
+        )
+    })?;
+    if !contract.has_field(column) {
+        return Err(ValidationError::missing_field(column));
+    }
+    Ok(Some((column, window)))
+}
+
+pub(crate) fn row_timestamp(value: &DataValue) -> Option<DateTime<Utc>> {
+    match value {
+        DataValue::Timestamp(s) | DataValue::String(s) => parse_timestamp(s).ok(),
+        DataValue::Date(date) => Some(date.and_time(NaiveTime::MIN).and_utc()),
+        _ => None,
+    }
+}
+
+fn timestamp_literal(timestamp: DateTime<Utc>) -> String {
+    format!(
+        "TIMESTAMP '{}'",
+        timestamp.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, TimeZone};
+    use contracts_core::{ContractBuilder, DataFormat, FieldBuilder};
+    use std::collections::HashMap;
+
+    fn contract() -> Contract {
+        ContractBuilder::new("events", "team")
+            .location("events.parquet")
+            .format(DataFormat::Parquet)
+            .field(FieldBuilder::new("ts", "timestamp").build())
+            .build()
+    }
+
+    fn day(d: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, d, 0, 0, 0).unwrap()
+    }
+
+    fn window(since: Option<DateTime<Utc>>, until: Option<DateTime<Utc>>) -> ValidationContext {
+        ValidationContext::new().with_time_window(TimeWindow {
+            column: Some("ts".to_string()),
+            since,
+            until,
+        })
+    }
+
+    #[test]
+    fn test_filter_rows_keeps_half_open_window() {
+        let values = [
+            DataValue::Timestamp("2026-10-14T23:59:59Z".to_string()),
+            DataValue::Timestamp("2026-10-15T00:00:00Z".to_string()),
+            DataValue::String("2026-10-15 12:00:00".to_string()),
+            DataValue::Date(NaiveDate::from_ymd_opt(2026, 10, 15).unwrap()),
+            DataValue::Timestamp("2026-10-16T00:00:00Z".to_string()),
+            DataValue::Null,
+        ];
+        let dataset = DataSet::from_rows(
+            values
+                .into_iter()
+                .map(|v| HashMap::from([("ts".to_string(), v)]))
+                .collect(),
+        );
+
+        let filtered = filter_rows(&dataset, &contract(), &window(Some(day(15)), Some(day(16))))
+            .unwrap()
+            .unwrap();
+        assert_eq!(filtered.len(), 3);
+
+        assert!(
+            filter_rows(&dataset, &contract(), &ValidationContext::new())
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_predicate_and_column_resolution() {
+        assert_eq!(
+            time_window_predicate(&contract(), &window(Some(day(15)), Some(day(16))))
+                .unwrap()
+                .unwrap(),
+            "\"ts\" >= TIMESTAMP '2026-10-15T00:00:00Z' AND \"ts\" < TIMESTAMP '2026-10-16T00:00:00Z'"
+        );
+
+        let unnamed = ValidationContext::new().with_time_window(TimeWindow {
+            since: Some(day(15)),
+            ..Default::default()
+        });
+        assert!(time_window_predicate(&contract(), &unnamed).is_err());
+
+        let unknown = ValidationContext::new().with_time_window(TimeWindow {
+            column: Some("created_at".to_string()),
+            since: Some(day(15)),
+            until: None,
+        });
+        assert!(matches!(
+            time_window_predicate(&contract(), &unknown),
+            Err(ValidationError::MissingField(_))
+        ));
+    }
+}
@@ -0,0 +1,275 @@
+//! Per-bucket volume checks.
+//!
+//! A [`VolumeCheck`] groups records into event-time buckets and requires a
+//! minimum count in each closed bucket. A record that arrives late still
+//! counts toward the bucket of its event time, as long as it arrived within
+//! the check's `allowed_lateness` of the bucket's end; a bucket is only
+//! checked once that window has passed, so records still on their way do
+//! not fail it.
+
+use crate::custom::parse_duration;
+use crate::time_window::row_timestamp;
+use crate::{DataRow, ValidationError};
+use chrono::{DateTime, SecondsFormat, Utc};
+use contracts_core::VolumeCheck;
+use std::collections::BTreeMap;
+
+/// Short buckets named in a failure message.
+const EXAMPLES: usize = 3;
+
+/// Bucket width and lateness of a volume check, in seconds.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct BucketWindow {
+    pub(crate) width: i64,
+    pub(crate) lateness: i64,
+}
+
+impl BucketWindow {
+    /// Parses the durations of `check`; the bucket must be at least a second.
+    pub(crate) fn parse(check: &VolumeCheck) -> Result<Self, ValidationError> {
+        let width = parse_duration(&check.bucket)?.num_seconds();
+        if width <= 0 {
+            return Err(ValidationError::InvalidDuration(format!(
+                "Volume bucket '{}' must be at least one second",
+                check.bucket
+            )));
+        }
+        let lateness = match &check.allowed_lateness {
+            Some(lateness) => parse_duration(lateness)?.num_seconds(),
+            None => 0,
+        };
+        Ok(Self { width, lateness })
+    }
+
+    /// Returns the start of the bucket holding `event`, in epoch seconds.
+    pub(crate) fn bucket_start(&self, event: i64) -> i64 {
+        event - event.rem_euclid(self.width)
+    }
+
+    /// Returns true if a record arriving at `arrival` still counts toward
+    /// the bucket starting at `start`.
+    pub(crate) fn on_time(&self, start: i64, arrival: i64) -> bool {
+        arrival <= start + self.width + self.lateness
+    }
+}
+
+/// Counts the records of each bucket of a volume check, as rows come in.
+#[derive(Debug, Clone)]
+pub(crate) struct VolumeCounter<'a> {
+    check: &'a VolumeCheck,
+    /// The check's window, unless one of its durations does not parse
+    window: Option<BucketWindow>,
+    /// Records counting toward each bucket seen, by its start
+    counts: BTreeMap<i64, u64>,
+}
+
+impl<'a> VolumeCounter<'a> {
+    /// Creates a counter for `check`.
+    pub(crate) fn new(check: &'a VolumeCheck) -> Self {
+        Self {
+            check,
+            window: BucketWindow::parse(check).ok(),
+            counts: BTreeMap::new(),
+        }
+    }
+
+    /// Counts `row` toward the bucket of its event time, unless it arrived
+    /// after the lateness window. Rows without an event time are skipped;
+    /// rows without an arrival time count.
+    pub(crate) fn insert(&mut self, row: &DataRow) {
+        let Some(window) = self.window else {
+            return;
+        };
+        let Some(event) = row.get(&self.check.metric).and_then(row_timestamp) else {
+            return;
+        };
+        let start = window.bucket_start(event.timestamp());
+        let arrival = self
+            .check
+            .arrival_metric
+            .as_ref()
+            .and_then(|column| row.get(column))
+            .and_then(row_timestamp);
+        let on_time = arrival.is_none_or(|arrival| window.on_time(start, arrival.timestamp()));
+        self.add(start, u64::from(on_time));
+    }
+
+    /// Adds `rows` records to the bucket starting at `start`. A bucket added
+    /// with no records was seen, but only through late ones.
+    pub(crate) fn add(&mut self, start: i64, rows: u64) {
+        *self.counts.entry(start).or_default() += rows;
+    }
+
+    /// Checks every bucket from the first one seen up to the last one whose
+    /// lateness window has passed at `now`.
+    pub(crate) fn error(&self, now: DateTime<Utc>) -> Option<ValidationError> {
+        let window = match BucketWindow::parse(self.check) {
+            Ok(window) => window,
+            Err(e) => return Some(e),
+        };
+        let Some(&first) = self.counts.keys().next() else {
+            return Some(ValidationError::quality_check(format!(
+                "Volume check failed: no valid timestamps found in field '{}'",
+                self.check.metric
+            )));
+        };
+        // The newest bucket whose late records can no longer arrive
+        let last = window.bucket_start(now.timestamp() - window.width - window.lateness);
+        if last < first || self.check.min_rows == 0 {
+            return None;
+        }
+
+        let mut short = 0u64;
+        let mut examples = Vec::new();
+        let mut note = |start: i64, rows: u64, buckets: u64| {
+            short += buckets;
+            if examples.len() < EXAMPLES {
+                examples.push(format!("{} ({})", bucket_label(start), rows));
+            }
+        };
+        let mut expected = first;
+        for (&start, &rows) in self.counts.range(..=last) {
+            if start > expected {
+                note(expected, 0, ((start - expected) / window.width) as u64);
+            }
+            if rows < self.check.min_rows {
+                note(start, rows, 1);
+            }
+            expected = start + window.width;
+        }
+        if expected <= last {
+            note(expected, 0, ((last - expected) / window.width + 1) as u64);
+        }
+        if short == 0 {
+            return None;
+        }
+
+        let closed = (last - first) / window.width + 1;
+        Some(ValidationError::LowVolume {
+            field: self.check.metric.clone(),
+            message: format!(
+                "{} of {} bucket(s) of {} hold fewer than {} record(s): {}",
+                short,
+                closed,
+                self.check.bucket,
+                self.check.min_rows,
+                examples.join(", ")
+            ),
+        })
+    }
+}
+
+/// Formats the start of a bucket.
+fn bucket_label(start: i64) -> String {
+    DateTime::from_timestamp(start, 0)
+        .map(|start| start.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+        .unwrap_or_else(|| start.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DataValue;
+    use chrono::TimeZone;
+    use std::collections::HashMap;
+
+    fn check(allowed_lateness: Option<&str>) -> VolumeCheck {
+        VolumeCheck {
+            metric: "event_time".to_string(),
+            bucket: "1h".to_string(),
+            min_rows: 2,
+            arrival_metric: Some("ingested_at".to_string()),
+            allowed_lateness: allowed_lateness.map(String::from),
+            severity: None,
+        }
+    }
+
+    fn row(event: &str, arrival: &str) -> DataRow {
+        HashMap::from([
+            (
+                "event_time".to_string(),
+                DataValue::Timestamp(event.to_string()),
+            ),
+            (
+                "ingested_at".to_string(),
+                DataValue::Timestamp(arrival.to_string()),
+            ),
+        ])
+    }
+
+    fn count(check: &VolumeCheck, rows: &[DataRow], now: DateTime<Utc>) -> Option<String> {
+        let mut counter = VolumeCounter::new(check);
+        for row in rows {
+            counter.insert(row);
+        }
+        counter.error(now).map(|e| e.to_string())
+    }
+
+    #[test]
+    fn test_late_records_count_within_the_lateness_window() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 15, 11, 30, 0).unwrap();
+        // The 10:00 bucket gets one record on time and one 10 minutes late
+        let rows = [
+            row("2026-10-15T10:05:00Z", "2026-10-15T10:06:00Z"),
+            row("2026-10-15T10:55:00Z", "2026-10-15T11:10:00Z"),
+        ];
+
+        assert_eq!(count(&check(Some("15m")), &rows, now), None);
+
+        let error = count(&check(None), &rows, now).unwrap();
+        assert!(error.contains("1 of 1 bucket(s)"), "{error}");
+        assert!(error.contains("2026-10-15T10:00:00Z (1)"), "{error}");
+    }
+
+    #[test]
+    fn test_records_past_the_lateness_window_do_not_count() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 15, 12, 0, 0).unwrap();
+        let rows = [
+            row("2026-10-15T10:05:00Z", "2026-10-15T10:06:00Z"),
+            row("2026-10-15T10:55:00Z", "2026-10-15T11:20:00Z"),
+        ];
+
+        let error = count(&check(Some("15m")), &rows, now).unwrap();
+        assert!(error.contains("2026-10-15T10:00:00Z (1)"), "{error}");
+    }
+
+    #[test]
+    fn test_buckets_inside_the_lateness_window_are_not_checked() {
+        // 11:00 ends at 12:00; late records may still arrive until 12:15
+        let now = Utc.with_ymd_and_hms(2026, 10, 15, 12, 10, 0).unwrap();
+        let rows = [
+            row("2026-10-15T10:05:00Z", "2026-10-15T10:06:00Z"),
+            row("2026-10-15T10:06:00Z", "2026-10-15T10:07:00Z"),
+            row("2026-10-15T11:05:00Z", "2026-10-15T11:06:00Z"),
+        ];
+
+        assert_eq!(count(&check(Some("15m")), &rows, now), None);
+
+        let later = Utc.with_ymd_and_hms(2026, 10, 15, 12, 20, 0).unwrap();
+        let error = count(&check(Some("15m")), &rows, later).unwrap();
+        assert!(error.contains("1 of 2 bucket(s)"), "{error}");
+    }
+
+    #[test]
+    fn test_empty_buckets_between_records_are_short() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 15, 14, 0, 0).unwrap();
+        let mut check = check(None);
+        check.min_rows = 1;
+        let rows = [
+            row("2026-10-15T10:05:00Z", "2026-10-15T10:06:00Z"),
+            row("2026-10-15T13:05:00Z", "2026-10-15T13:06:00Z"),
+        ];
+
+        let error = count(&check, &rows, now).unwrap();
+        assert!(error.contains("2 of 4 bucket(s)"), "{error}");
+        assert!(error.contains("2026-10-15T11:00:00Z (0)"), "{error}");
+    }
+
+    #[test]
+    fn test_invalid_bucket() {
+        let mut check = check(None);
+        check.bucket = "0s".to_string();
+        let error = VolumeCounter::new(&check).error(Utc::now()).unwrap();
+        assert!(matches!(error, ValidationError::InvalidDuration(_)));
+    }
+}
@@ -3,13 +3,16 @@
 //! Each test writes a small file to a temp directory, registers it via
 //! `register_file_as_table`, and validates a contract against the data.
 
-use arrow_array::RecordBatch;
 use arrow_array::builder::{Int64Builder, StringBuilder};
-use arrow_schema::{DataType as ArrowDataType, Field as ArrowField, Schema as ArrowSchema};
+use arrow_array::{Date32Array, Int64Array, RecordBatch, TimestampMicrosecondArray};
+use arrow_schema::{
+    DataType as ArrowDataType, Field as ArrowField, Schema as ArrowSchema, TimeUnit,
+};
+use chrono::{TimeZone, Utc};
 use contracts_core::{
-    ContractBuilder, DataFormat, FieldBuilder, FieldConstraints, ValidationContext,
+    ContractBuilder, DataFormat, FieldBuilder, FieldConstraints, TimeWindow, ValidationContext,
 };
-use contracts_validator::{DataValidator, register_file_as_table};
+use contracts_validator::{DataValidator, register_file_as_table, time_window_predicate};
 use datafusion::dataframe::DataFrameWriteOptions;
 use datafusion::prelude::*;
 use std::sync::Arc;
@@ -107,7 +110,7 @@ async fn parquet_validation_passes_with_valid_data() {
     let dir = tempfile::tempdir().unwrap();
     let path = write_parquet(dir.path(), sample_batch()).await;
 
-    let ctx = register_file_as_table(&DataFormat::Parquet, &path, None)
+    let ctx = register_file_as_table(&DataFormat::Parquet, &path, None, None)
         .await
         .unwrap();
 
@@ -126,7 +129,7 @@ async fn parquet_validation_detects_null_violation() {
     let dir = tempfile::tempdir().unwrap();
     let path = write_parquet(dir.path(), sample_batch()).await;
 
-    let ctx = register_file_as_table(&DataFormat::Parquet, &path, None)
+    let ctx = register_file_as_table(&DataFormat::Parquet, &path, None, None)
         .await
         .unwrap();
 
@@ -144,7 +147,7 @@ async fn parquet_validation_detects_null_violation() {
         .await;
 
     assert!(!report.passed);
-    assert!(report.errors.iter().any(|e| e.contains("name")));
+    assert!(report.errors.iter().any(|e| e.message.contains("name")));
 }
 
 #[tokio::test]
@@ -152,7 +155,7 @@ async fn parquet_validation_with_sample_size() {
     let dir = tempfile::tempdir().unwrap();
     let path = write_parquet(dir.path(), sample_batch()).await;
 
-    let ctx = register_file_as_table(&DataFormat::Parquet, &path, Some(2))
+    let ctx = register_file_as_table(&DataFormat::Parquet, &path, Some(2), None)
         .await
         .unwrap();
 
@@ -165,6 +168,73 @@ async fn parquet_validation_with_sample_size() {
     assert!(report.stats.records_validated <= 2);
 }
 
+/// Helper: one row per day from 2026-10-14 to 2026-10-17, as a UTC
+/// timestamp and as a date.
+fn daily_batch() -> RecordBatch {
+    let days = [0i64, 1, 2, 3];
+    let schema = Arc::new(ArrowSchema::new(vec![
+        ArrowField::new("id", ArrowDataType::Int64, false),
+        ArrowField::new(
+            "event_time",
+            ArrowDataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+            true,
+        ),
+        ArrowField::new("event_date", ArrowDataType::Date32, true),
+    ]));
+    // 2026-10-14 is day 20740 of the Unix epoch
+    let first = 20_740i64;
+    RecordBatch::try_new(
+        schema,
+        vec![
+            Arc::new(Int64Array::from_iter_values(days)),
+            Arc::new(
+                TimestampMicrosecondArray::from_iter_values(
+                    days.iter()
+                        .map(|d| (first + d) * 86_400_000_000 + 3_600_000_000),
+                )
+                .with_timezone("UTC"),
+            ),
+            Arc::new(Date32Array::from_iter_values(
+                days.iter().map(|d| (first + d) as i32),
+            )),
+        ],
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn parquet_validation_with_time_window() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_parquet(dir.path(), daily_batch()).await;
+    let contract = ContractBuilder::new("file_test", "test-owner")
+        .location(&path)
+        .format(DataFormat::Parquet)
+        .field(FieldBuilder::new("id", "int64").nullable(false).build())
+        .field(FieldBuilder::new("event_time", "timestamp").build())
+        .field(FieldBuilder::new("event_date", "date").build())
+        .build();
+
+    for column in ["event_time", "event_date"] {
+        let context = ValidationContext::new().with_time_window(TimeWindow {
+            column: Some(column.to_string()),
+            since: Some(Utc.with_ymd_and_hms(2026, 10, 15, 0, 0, 0).unwrap()),
+            until: Some(Utc.with_ymd_and_hms(2026, 10, 17, 0, 0, 0).unwrap()),
+        });
+        let filter = time_window_predicate(&contract, &context).unwrap();
+        let ctx = register_file_as_table(&DataFormat::Parquet, &path, Some(10), filter.as_deref())
+            .await
+            .unwrap();
+
+        let mut validator = DataValidator::new();
+        let report = validator
+            .validate_with_context(&contract, &ctx, &context)
+            .await;
+
+        assert!(report.passed, "{column}: {:?}", report.errors);
+        assert_eq!(report.stats.records_validated, 2, "{column}");
+    }
+}
+
 // -----------------------------------------------------------------------
 // CSV tests
 // -----------------------------------------------------------------------
@@ -174,7 +244,7 @@ async fn csv_validation_passes_with_valid_data() {
     let dir = tempfile::tempdir().unwrap();
     let path = write_csv(dir.path(), sample_batch()).await;
 
-    let ctx = register_file_as_table(&DataFormat::Csv, &path, None)
+    let ctx = register_file_as_table(&DataFormat::Csv, &path, None, None)
         .await
         .unwrap();
 
@@ -197,7 +267,7 @@ async fn json_validation_passes_with_valid_data() {
     let dir = tempfile::tempdir().unwrap();
     let path = write_json(dir.path(), sample_batch()).await;
 
-    let ctx = register_file_as_table(&DataFormat::Json, &path, None)
+    let ctx = register_file_as_table(&DataFormat::Json, &path, None, None)
         .await
         .unwrap();
 
@@ -217,7 +287,7 @@ async fn json_validation_passes_with_valid_data() {
 
 #[tokio::test]
 async fn unsupported_format_returns_error() {
-    let result = register_file_as_table(&DataFormat::Delta, "/nonexistent", None).await;
+    let result = register_file_as_table(&DataFormat::Delta, "/nonexistent", None, None).await;
     let err = result
         .err()
         .expect("expected an error for unsupported format");
@@ -232,6 +302,7 @@ async fn register_file_does_not_panic_for_missing_path() {
         &DataFormat::Parquet,
         "/tmp/nonexistent_dce_test.parquet",
         None,
+        None,
     )
     .await;
 }
@@ -245,7 +316,7 @@ async fn parquet_validation_detects_constraint_violation() {
     let dir = tempfile::tempdir().unwrap();
     let path = write_parquet(dir.path(), sample_batch()).await;
 
-    let ctx = register_file_as_table(&DataFormat::Parquet, &path, None)
+    let ctx = register_file_as_table(&DataFormat::Parquet, &path, None, None)
         .await
         .unwrap();
 
@@ -269,5 +340,5 @@ async fn parquet_validation_detects_constraint_violation() {
         .await;
 
     assert!(!report.passed);
-    assert!(report.errors.iter().any(|e| e.contains("name")));
+    assert!(report.errors.iter().any(|e| e.message.contains("name")));
 }
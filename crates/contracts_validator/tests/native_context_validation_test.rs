@@ -50,10 +50,54 @@ async fn test_context_nullability_check() {
         .await;
 
     assert!(!report.passed);
-    assert!(report.errors.iter().any(|e| e.contains("null")));
+    assert!(report.errors.iter().any(|e| e.message.contains("null")));
     assert_eq!(report.stats.records_validated, 2);
 }
 
+#[tokio::test]
+async fn test_context_presence_and_not_null_checks() {
+    let schema = Arc::new(ArrowSchema::new(vec![ArrowField::new(
+        "code",
+        ArrowDataType::Utf8,
+        true,
+    )]));
+
+    let mut builder = StringBuilder::new();
+    builder.append_value("a");
+    builder.append_null();
+    let batch = RecordBatch::try_new(schema, vec![Arc::new(builder.finish())]).unwrap();
+
+    let ctx = make_context(batch);
+
+    let contract = ContractBuilder::new("test", "owner")
+        .location("s3://test")
+        .format(DataFormat::Iceberg)
+        .field(
+            FieldBuilder::new("code", "string")
+                .constraint(FieldConstraints::NotNull)
+                .build(),
+        )
+        .field(FieldBuilder::new("note", "string").required(true).build())
+        .field(FieldBuilder::new("extra", "string").build())
+        .build();
+
+    let context = ValidationContext::new();
+    let mut validator = DataValidator::new();
+    let report = validator
+        .validate_with_context(&contract, &ctx, &context)
+        .await;
+
+    assert!(!report.passed);
+    assert_eq!(report.errors.len(), 2, "errors: {:?}", report.errors);
+    assert!(report.errors.iter().any(|e| e.message.contains("'note'")));
+    assert!(
+        report
+            .errors
+            .iter()
+            .any(|e| e.message.contains("'code' is null") && e.message.contains("1 row(s)"))
+    );
+}
+
 #[tokio::test]
 async fn test_context_constraint_allowed_values() {
     let schema = Arc::new(ArrowSchema::new(vec![ArrowField::new(
@@ -90,7 +134,7 @@ async fn test_context_constraint_allowed_values() {
         .await;
 
     assert!(!report.passed);
-    assert!(report.errors.iter().any(|e| e.contains("allowed")));
+    assert!(report.errors.iter().any(|e| e.message.contains("allowed")));
 }
 
 #[tokio::test]
@@ -129,7 +173,7 @@ async fn test_context_range_constraint() {
         .await;
 
     assert!(!report.passed);
-    assert!(report.errors.iter().any(|e| e.contains("range")));
+    assert!(report.errors.iter().any(|e| e.message.contains("range")));
 }
 
 #[tokio::test]
@@ -157,11 +201,10 @@ async fn test_context_quality_completeness() {
             completeness: Some(CompletenessCheck {
                 threshold: 0.9, // 50% completeness will fail 90% threshold
                 fields: vec!["email".to_string()],
+                min_sample_size: None,
+                severity: None,
             }),
-            uniqueness: None,
-            freshness: None,
-            custom_checks: None,
-            ml_checks: None,
+            ..Default::default()
         })
         .build();
 
@@ -178,7 +221,7 @@ async fn test_context_quality_completeness() {
         report
             .warnings
             .iter()
-            .any(|w| w.to_lowercase().contains("completeness"))
+            .any(|w| w.message.to_lowercase().contains("completeness"))
     );
 }
 
@@ -207,15 +250,13 @@ async fn test_context_custom_sql_check() {
                 .build(),
         )
         .quality_checks(QualityChecks {
-            completeness: None,
-            uniqueness: None,
-            freshness: None,
             custom_checks: Some(vec![CustomCheck {
                 name: "no_negative_amounts".to_string(),
                 definition: "SELECT COUNT(*) FROM data WHERE amount < 0".to_string(),
                 severity: Some("error".to_string()),
+                capture_rows: None,
             }]),
-            ml_checks: None,
+            ..Default::default()
         })
         .build();
 
@@ -230,7 +271,7 @@ async fn test_context_custom_sql_check() {
         report
             .errors
             .iter()
-            .any(|e| e.contains("no_negative_amounts"))
+            .any(|e| e.message.contains("no_negative_amounts"))
     );
 }
 
@@ -308,10 +349,6 @@ async fn test_context_ml_checks_execute_via_sql() {
                 .build(),
         )
         .quality_checks(QualityChecks {
-            completeness: None,
-            uniqueness: None,
-            freshness: None,
-            custom_checks: None,
             ml_checks: Some(contracts_core::MlChecks {
                 no_overlap: None,
                 temporal_split: None,
@@ -321,9 +358,11 @@ async fn test_context_ml_checks_execute_via_sql() {
                     target_field: "target".to_string(),
                     feature_fields: vec!["feature".to_string()],
                     max_correlation: Some(0.9),
+                    min_sample_size: None,
                 }),
                 null_rate_by_group: None,
             }),
+            ..Default::default()
         })
         .build();
 
@@ -336,7 +375,10 @@ async fn test_context_ml_checks_execute_via_sql() {
 
     assert!(report.passed);
     assert!(
-        report.warnings.iter().any(|w| w.contains("TargetLeakage")),
+        report
+            .warnings
+            .iter()
+            .any(|w| w.message.contains("TargetLeakage")),
         "Expected TargetLeakage warning, got: {:?}",
         report.warnings,
     );
@@ -366,10 +408,6 @@ async fn test_context_row_only_ml_checks_skipped_with_warning() {
                 .build(),
         )
         .quality_checks(QualityChecks {
-            completeness: None,
-            uniqueness: None,
-            freshness: None,
-            custom_checks: None,
             ml_checks: Some(contracts_core::MlChecks {
                 no_overlap: Some(contracts_core::NoOverlapCheck {
                     split_field: "split".to_string(),
@@ -381,6 +419,7 @@ async fn test_context_row_only_ml_checks_skipped_with_warning() {
                 target_leakage: None,
                 null_rate_by_group: None,
             }),
+            ..Default::default()
         })
         .build();
 
@@ -395,7 +434,7 @@ async fn test_context_row_only_ml_checks_skipped_with_warning() {
         report
             .warnings
             .iter()
-            .any(|w| w.contains("NoOverlap and TemporalSplit")),
+            .any(|w| w.message.contains("NoOverlap and TemporalSplit")),
         "Expected row-only ML skip warning, got: {:?}",
         report.warnings,
     );
@@ -39,7 +39,7 @@ fn test_null_in_non_nullable_field_fails_schema_validation() {
     assert!(!report.passed, "Null in non-nullable field should fail");
     assert_eq!(report.errors.len(), 1);
     assert!(
-        report.errors[0].contains("null"),
+        report.errors[0].message.contains("null"),
         "Error should mention null: {}",
         report.errors[0]
     );
@@ -180,11 +180,10 @@ fn test_null_counted_in_completeness_check() {
             completeness: Some(CompletenessCheck {
                 threshold: 0.8, // 80% threshold
                 fields: vec!["id".to_string()],
+                min_sample_size: None,
+                severity: None,
             }),
-            uniqueness: None,
-            freshness: None,
-            custom_checks: None,
-            ml_checks: None,
+            ..Default::default()
         })
         .build();
 
@@ -213,7 +212,7 @@ fn test_null_counted_in_completeness_check() {
         "Should have completeness warning"
     );
     assert!(
-        report.warnings[0].contains("Completeness"),
+        report.warnings[0].message.contains("Completeness"),
         "Warning should be about completeness: {}",
         report.warnings[0]
     );
@@ -293,7 +292,7 @@ fn test_null_in_non_nullable_with_constraint() {
         1,
         "Should fail once at schema level, not also at constraint level"
     );
-    assert!(report.errors[0].contains("null"));
+    assert!(report.errors[0].message.contains("null"));
 }
 
 #[test]
@@ -307,11 +306,10 @@ fn test_completeness_with_missing_vs_null() {
             completeness: Some(CompletenessCheck {
                 threshold: 0.8,
                 fields: vec!["id".to_string()],
+                min_sample_size: None,
+                severity: None,
             }),
-            uniqueness: None,
-            freshness: None,
-            custom_checks: None,
-            ml_checks: None,
+            ..Default::default()
         })
         .build();
 
@@ -347,7 +345,7 @@ fn test_completeness_with_missing_vs_null() {
 
     assert!(report.passed); // Non-strict mode
     assert!(!report.warnings.is_empty());
-    assert!(report.warnings[0].contains("30.00%")); // Should show 30% completeness
+    assert!(report.warnings[0].message.contains("30.00%")); // Should show 30% completeness
 }
 
 #[test]
@@ -361,11 +359,10 @@ fn test_strict_mode_with_null_violations() {
             completeness: Some(CompletenessCheck {
                 threshold: 0.99,
                 fields: vec!["id".to_string()],
+                min_sample_size: None,
+                severity: None,
             }),
-            uniqueness: None,
-            freshness: None,
-            custom_checks: None,
-            ml_checks: None,
+            ..Default::default()
         })
         .build();
 
@@ -388,5 +385,5 @@ fn test_strict_mode_with_null_violations() {
 
     assert!(!report.passed, "Should fail in strict mode");
     assert!(!report.errors.is_empty());
-    assert!(report.errors[0].contains("Completeness"));
+    assert!(report.errors[0].message.contains("Completeness"));
 }
@@ -78,28 +78,38 @@ fn create_user_events_contract() -> Contract {
                     "event_type".to_string(),
                     "event_timestamp".to_string(),
                 ],
+                min_sample_size: None,
+                severity: None,
             }),
             uniqueness: Some(UniquenessCheck {
                 fields: vec!["event_id".to_string()],
                 scope: Some("global".to_string()),
+                min_sample_size: None,
+                severity: None,
+                approximate: None,
             }),
             freshness: Some(FreshnessCheck {
                 max_delay: "1h".to_string(),
                 metric: "event_timestamp".to_string(),
+                source: None,
+                allowed_lateness: None,
+                severity: None,
             }),
             custom_checks: Some(vec![
                 CustomCheck {
                     name: "valid_event_types".to_string(),
                     definition: "SELECT COUNT(*) = 0 FROM user_events WHERE event_type NOT IN ('page_view', 'button_click', 'form_submit', 'purchase', 'sign_up', 'sign_out')".to_string(),
                     severity: Some("error".to_string()),
+                    capture_rows: None,
                 },
                 CustomCheck {
                     name: "future_timestamps".to_string(),
                     definition: "SELECT COUNT(*) = 0 FROM user_events WHERE event_timestamp > CURRENT_TIMESTAMP()".to_string(),
                     severity: Some("error".to_string()),
+                    capture_rows: None,
                 },
             ]),
-            ml_checks: None,
+            ..Default::default()
         })
         .build()
 }
@@ -193,7 +203,7 @@ fn test_schema_violations() {
 
     assert!(!report.passed);
     assert!(!report.errors.is_empty());
-    assert!(report.errors[0].contains("null"));
+    assert!(report.errors[0].message.contains("null"));
 }
 
 #[test]
@@ -253,11 +263,10 @@ fn test_completeness_check() {
             completeness: Some(CompletenessCheck {
                 threshold: 0.95, // 95% threshold
                 fields: vec!["event_id".to_string()],
+                min_sample_size: None,
+                severity: None,
             }),
-            uniqueness: None,
-            freshness: None,
-            custom_checks: None,
-            ml_checks: None,
+            ..Default::default()
         })
         .build();
 
@@ -293,7 +302,7 @@ fn test_completeness_check() {
         report.errors
     );
     assert!(!report.warnings.is_empty());
-    assert!(report.warnings[0].contains("Completeness"));
+    assert!(report.warnings[0].message.contains("Completeness"));
 }
 
 #[test]
@@ -331,7 +340,12 @@ fn test_uniqueness_check() {
 
     assert!(report.passed); // Non-strict mode
     assert!(!report.warnings.is_empty());
-    assert!(report.warnings.iter().any(|w| w.contains("Uniqueness")));
+    assert!(
+        report
+            .warnings
+            .iter()
+            .any(|w| w.message.contains("Uniqueness"))
+    );
 }
 
 #[test]
@@ -367,7 +381,7 @@ fn test_freshness_check() {
 
     assert!(report.passed); // Non-strict mode
     assert!(!report.warnings.is_empty());
-    assert!(report.warnings.iter().any(|w| w.contains("stale")));
+    assert!(report.warnings.iter().any(|w| w.message.contains("stale")));
 }
 
 #[test]